@@ -1,8 +1,10 @@
 use super::kind::BenchmarkKindCommand;
 use super::props::{BenchmarkKindProps, BenchmarkTransportProps};
 use super::{defaults::*, transport::BenchmarkTransportCommand};
+use crate::batch_sizes::BatchSizes;
 use clap::error::ErrorKind;
 use clap::{CommandFactory, Parser};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use integration::test_server::Transport;
 use std::net::SocketAddr;
 use std::path::Path;
@@ -29,6 +31,14 @@ pub struct IggyBenchArgs {
     /// Server stdout visibility
     #[arg(long, short='v', default_value_t = DEFAULT_SERVER_STDOUT_VISIBILITY)]
     pub verbose: bool,
+
+    /// Client-side compression algorithm of the message payloads (none, gzip, zstd or lz4), disabled if not provided
+    #[arg(long, default_value = None)]
+    pub compression: Option<CompressionAlgorithm>,
+
+    /// Path of the JSON report with the benchmark settings and results, e.g. to track the regressions across the releases
+    #[arg(long, default_value = None)]
+    pub output_json: Option<String>,
 }
 
 fn validate_server_executable_path(v: &str) -> Result<String, String> {
@@ -80,6 +90,14 @@ impl IggyBenchArgs {
         self.benchmark_kind.inner().messages_per_batch()
     }
 
+    pub fn max_messages_per_batch(&self) -> u32 {
+        self.benchmark_kind.inner().max_messages_per_batch()
+    }
+
+    pub fn batch_sizes(&self) -> BatchSizes {
+        BatchSizes::new(self.messages_per_batch(), self.max_messages_per_batch())
+    }
+
     pub fn message_batches(&self) -> u32 {
         self.benchmark_kind.inner().message_batches()
     }
@@ -92,6 +110,10 @@ impl IggyBenchArgs {
         self.benchmark_kind.inner().number_of_streams()
     }
 
+    pub fn number_of_partitions(&self) -> u32 {
+        self.benchmark_kind.inner().number_of_partitions()
+    }
+
     pub fn consumers(&self) -> u32 {
         self.benchmark_kind.inner().consumers()
     }
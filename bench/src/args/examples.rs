@@ -29,7 +29,22 @@ const EXAMPLES: &str = r#"EXAMPLES:
 
     $ cargo r --bin iggy-bench -- send --message-size 2000 --messages-per-batch 1000 --message-batches 1000 --producers 5 --streams 5 tcp --server-address 142.250.203.142:8090
 
-4) Other options:
+4) Consumer groups, partitions and varying batch sizes:
+
+    Send the messages balanced between 4 partitions of each stream, in batches of 100 to 1000 messages,
+    then poll them using the consumer group of each stream, with 2 members per group:
+
+    $ cargo r --bin iggy-bench -r -- send --partitions 4 --messages-per-batch 100 --max-messages-per-batch 1000 --streams 5 --producers 5 tcp
+    $ cargo r --bin iggy-bench -r -- consumer-group-poll --streams 5 --consumers 10 tcp
+
+5) Mixed workload, compression and JSON report:
+
+    Each of 5 actors sends the batch and polls it back, the payloads are compressed using zstd,
+    the settings and results (including the latency percentiles) are saved as JSON report:
+
+    $ cargo r --bin iggy-bench -r -- --compression zstd --output-json reports/mixed.json mixed --actors 5 --streams 5 tcp
+
+6) Other options:
 
     If more options are needed, please refer to the help menu:
 
@@ -40,6 +55,8 @@ const EXAMPLES: &str = r#"EXAMPLES:
     $ cargo r --bin iggy-bench -r -- send --help
     $ cargo r --bin iggy-bench -r -- poll --help
     $ cargo r --bin iggy-bench -r -- send-and-poll --help
+    $ cargo r --bin iggy-bench -r -- consumer-group-poll --help
+    $ cargo r --bin iggy-bench -r -- mixed --help
 
     $ cargo r --bin iggy-bench -r -- send tcp --help
     $ cargo r --bin iggy-bench -r -- poll tcp --help
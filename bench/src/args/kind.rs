@@ -1,9 +1,10 @@
 use super::defaults::*;
 use super::examples::print_examples;
-use super::props::BenchmarkKindProps;
+use super::props::{BenchmarkKindProps, BenchmarkTransportProps};
 use super::transport::BenchmarkTransportCommand;
 use super::{common::IggyBenchArgs, simple::BenchmarkKind};
 use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand};
+use integration::test_server::Transport;
 use std::num::NonZeroU32;
 
 #[derive(Subcommand, Debug)]
@@ -11,6 +12,8 @@ pub enum BenchmarkKindCommand {
     Send(SendArgs),
     Poll(PollArgs),
     SendAndPoll(SendAndPollArgs),
+    ConsumerGroupPoll(ConsumerGroupPollArgs),
+    Mixed(MixedArgs),
 
     /// Prints examples
     Examples,
@@ -22,6 +25,8 @@ impl BenchmarkKindCommand {
             BenchmarkKindCommand::Send(_) => BenchmarkKind::Send,
             BenchmarkKindCommand::Poll(_) => BenchmarkKind::Poll,
             BenchmarkKindCommand::SendAndPoll(_) => BenchmarkKind::SendAndPoll,
+            BenchmarkKindCommand::ConsumerGroupPoll(_) => BenchmarkKind::ConsumerGroupPoll,
+            BenchmarkKindCommand::Mixed(_) => BenchmarkKind::Mixed,
             BenchmarkKindCommand::Examples => {
                 print_examples();
                 std::process::exit(0);
@@ -39,6 +44,10 @@ impl BenchmarkKindProps for BenchmarkKindCommand {
         self.inner().messages_per_batch()
     }
 
+    fn max_messages_per_batch(&self) -> u32 {
+        self.inner().max_messages_per_batch()
+    }
+
     fn message_batches(&self) -> u32 {
         self.inner().message_batches()
     }
@@ -47,6 +56,10 @@ impl BenchmarkKindProps for BenchmarkKindCommand {
         self.inner().number_of_streams()
     }
 
+    fn number_of_partitions(&self) -> u32 {
+        self.inner().number_of_partitions()
+    }
+
     fn consumers(&self) -> u32 {
         self.inner().consumers()
     }
@@ -72,6 +85,8 @@ impl BenchmarkKindProps for BenchmarkKindCommand {
             BenchmarkKindCommand::Send(args) => args,
             BenchmarkKindCommand::Poll(args) => args,
             BenchmarkKindCommand::SendAndPoll(args) => args,
+            BenchmarkKindCommand::ConsumerGroupPoll(args) => args,
+            BenchmarkKindCommand::Mixed(args) => args,
             BenchmarkKindCommand::Examples => {
                 print_examples();
                 std::process::exit(0);
@@ -94,6 +109,10 @@ pub struct SendArgs {
     #[arg(long, default_value_t = DEFAULT_MESSAGES_PER_BATCH)]
     pub messages_per_batch: NonZeroU32,
 
+    /// Maximum number of messages per batch, if set, the sizes of the batches vary between the number of messages per batch and this value
+    #[arg(long)]
+    pub max_messages_per_batch: Option<NonZeroU32>,
+
     /// Number of message batches
     #[arg(long, default_value_t = DEFAULT_MESSAGE_BATCHES)]
     pub message_batches: NonZeroU32,
@@ -110,6 +129,10 @@ pub struct SendArgs {
     #[arg(long, default_value_t = DEFAULT_NUMBER_OF_STREAMS)]
     pub streams: NonZeroU32,
 
+    /// Number of partitions in the topic of each stream, the messages are balanced between them
+    #[arg(long, default_value_t = DEFAULT_NUMBER_OF_PARTITIONS)]
+    pub partitions: NonZeroU32,
+
     /// Flag, disables parallel producers
     #[arg(long, default_value_t = DEFAULT_DISABLE_PARALLEL_PRODUCER_STREAMS)]
     pub disable_parallel_producers: bool,
@@ -128,10 +151,20 @@ impl BenchmarkKindProps for SendArgs {
         self.messages_per_batch.get()
     }
 
+    fn max_messages_per_batch(&self) -> u32 {
+        self.max_messages_per_batch
+            .unwrap_or(self.messages_per_batch)
+            .get()
+    }
+
     fn number_of_streams(&self) -> u32 {
         self.streams.get()
     }
 
+    fn number_of_partitions(&self) -> u32 {
+        self.partitions.get()
+    }
+
     fn consumers(&self) -> u32 {
         panic!("")
     }
@@ -153,6 +186,7 @@ impl BenchmarkKindProps for SendArgs {
     }
 
     fn validate(&self) {
+        validate_batch_sizes(self.messages_per_batch, self.max_messages_per_batch);
         let streams = self.streams.get();
         let producers = self.producers.get();
         let mut cmd = IggyBenchArgs::command();
@@ -177,6 +211,10 @@ pub struct PollArgs {
     #[arg(long, default_value_t = DEFAULT_MESSAGES_PER_BATCH)]
     pub messages_per_batch: NonZeroU32,
 
+    /// Maximum number of messages per batch, if set, the sizes of the batches vary between the number of messages per batch and this value
+    #[arg(long)]
+    pub max_messages_per_batch: Option<NonZeroU32>,
+
     /// Number of message batches
     #[arg(long, default_value_t = DEFAULT_MESSAGE_BATCHES)]
     pub message_batches: NonZeroU32,
@@ -211,10 +249,20 @@ impl BenchmarkKindProps for PollArgs {
         self.messages_per_batch.get()
     }
 
+    fn max_messages_per_batch(&self) -> u32 {
+        self.max_messages_per_batch
+            .unwrap_or(self.messages_per_batch)
+            .get()
+    }
+
     fn number_of_streams(&self) -> u32 {
         self.streams.get()
     }
 
+    fn number_of_partitions(&self) -> u32 {
+        1
+    }
+
     fn consumers(&self) -> u32 {
         self.consumers.get()
     }
@@ -236,6 +284,7 @@ impl BenchmarkKindProps for PollArgs {
     }
 
     fn validate(&self) {
+        validate_batch_sizes(self.messages_per_batch, self.max_messages_per_batch);
         let streams = self.streams.get();
         let consumers = self.consumers.get();
         let mut cmd = IggyBenchArgs::command();
@@ -260,6 +309,10 @@ pub struct SendAndPollArgs {
     #[arg(long, default_value_t = DEFAULT_MESSAGES_PER_BATCH)]
     pub messages_per_batch: NonZeroU32,
 
+    /// Maximum number of messages per batch, if set, the sizes of the batches vary between the number of messages per batch and this value
+    #[arg(long)]
+    pub max_messages_per_batch: Option<NonZeroU32>,
+
     /// Number of message batches
     #[arg(long, default_value_t = DEFAULT_MESSAGE_BATCHES)]
     pub message_batches: NonZeroU32,
@@ -298,6 +351,10 @@ impl BenchmarkKindProps for SendAndPollArgs {
         self.streams.get()
     }
 
+    fn number_of_partitions(&self) -> u32 {
+        1
+    }
+
     fn message_batches(&self) -> u32 {
         self.message_batches.get()
     }
@@ -306,6 +363,12 @@ impl BenchmarkKindProps for SendAndPollArgs {
         self.messages_per_batch.get()
     }
 
+    fn max_messages_per_batch(&self) -> u32 {
+        self.max_messages_per_batch
+            .unwrap_or(self.messages_per_batch)
+            .get()
+    }
+
     fn consumers(&self) -> u32 {
         self.consumers.get()
     }
@@ -327,6 +390,7 @@ impl BenchmarkKindProps for SendAndPollArgs {
     }
 
     fn validate(&self) {
+        validate_batch_sizes(self.messages_per_batch, self.max_messages_per_batch);
         let streams = self.streams.get();
         let consumers = self.consumers.get();
         let producers = self.producers.get();
@@ -349,3 +413,212 @@ impl BenchmarkKindProps for SendAndPollArgs {
         }
     }
 }
+
+/// Consumer group polling (reading) benchmark, each stream is consumed by its own consumer group
+#[derive(Parser, Debug)]
+pub struct ConsumerGroupPollArgs {
+    #[command(subcommand)]
+    pub transport: BenchmarkTransportCommand,
+
+    /// Number of messages per batch
+    #[arg(long, default_value_t = DEFAULT_MESSAGES_PER_BATCH)]
+    pub messages_per_batch: NonZeroU32,
+
+    /// Number of message batches
+    #[arg(long, default_value_t = DEFAULT_MESSAGE_BATCHES)]
+    pub message_batches: NonZeroU32,
+
+    /// Message size in bytes
+    #[arg(long, default_value_t = DEFAULT_MESSAGE_SIZE)]
+    pub message_size: NonZeroU32,
+
+    /// Number of consumers, assigned to the consumer groups of the streams in a round-robin fashion
+    #[arg(long, default_value_t = DEFAULT_NUMBER_OF_CONSUMERS)]
+    pub consumers: NonZeroU32,
+
+    /// Number of streams
+    #[arg(long, default_value_t = DEFAULT_NUMBER_OF_STREAMS)]
+    pub streams: NonZeroU32,
+}
+
+impl BenchmarkKindProps for ConsumerGroupPollArgs {
+    fn message_size(&self) -> u32 {
+        self.message_size.get()
+    }
+
+    fn message_batches(&self) -> u32 {
+        self.message_batches.get()
+    }
+
+    fn messages_per_batch(&self) -> u32 {
+        self.messages_per_batch.get()
+    }
+
+    fn max_messages_per_batch(&self) -> u32 {
+        self.messages_per_batch.get()
+    }
+
+    fn number_of_streams(&self) -> u32 {
+        self.streams.get()
+    }
+
+    fn number_of_partitions(&self) -> u32 {
+        1
+    }
+
+    fn consumers(&self) -> u32 {
+        self.consumers.get()
+    }
+
+    fn producers(&self) -> u32 {
+        panic!("")
+    }
+
+    fn disable_parallel_producer_streams(&self) -> bool {
+        false
+    }
+
+    fn disable_parallel_consumer_streams(&self) -> bool {
+        false
+    }
+
+    fn transport_command(&self) -> &BenchmarkTransportCommand {
+        &self.transport
+    }
+
+    fn validate(&self) {
+        let streams = self.streams.get();
+        let consumers = self.consumers.get();
+        let mut cmd = IggyBenchArgs::command();
+
+        if self.transport.transport() == &Transport::Http {
+            cmd.error(
+                ErrorKind::ArgumentConflict,
+                "Consumer groups cannot be joined using the HTTP transport.",
+            )
+            .exit();
+        }
+
+        if consumers < streams {
+            cmd.error(
+                ErrorKind::ArgumentConflict,
+                format!("The number of consumers ({consumers}) must be greater than or equal to the number of streams ({streams}), so each consumer group has at least one member."),
+            )
+            .exit();
+        }
+    }
+}
+
+/// Mixed sending and polling benchmark, each actor sends a batch and polls the same number of messages back
+#[derive(Parser, Debug)]
+pub struct MixedArgs {
+    #[command(subcommand)]
+    pub transport: BenchmarkTransportCommand,
+
+    /// Number of messages per batch
+    #[arg(long, default_value_t = DEFAULT_MESSAGES_PER_BATCH)]
+    pub messages_per_batch: NonZeroU32,
+
+    /// Maximum number of messages per batch, if set, the sizes of the batches vary between the number of messages per batch and this value
+    #[arg(long)]
+    pub max_messages_per_batch: Option<NonZeroU32>,
+
+    /// Number of message batches
+    #[arg(long, default_value_t = DEFAULT_MESSAGE_BATCHES)]
+    pub message_batches: NonZeroU32,
+
+    /// Message size in bytes
+    #[arg(long, default_value_t = DEFAULT_MESSAGE_SIZE)]
+    pub message_size: NonZeroU32,
+
+    /// Number of actors, each of them both sending and polling the messages
+    #[arg(long, default_value_t = DEFAULT_NUMBER_OF_ACTORS)]
+    pub actors: NonZeroU32,
+
+    /// Number of streams
+    #[arg(long, default_value_t = DEFAULT_NUMBER_OF_STREAMS)]
+    pub streams: NonZeroU32,
+
+    /// Flag, disables parallel actors
+    #[arg(long, default_value_t = DEFAULT_DISABLE_PARALLEL_PRODUCER_STREAMS)]
+    pub disable_parallel_actors: bool,
+}
+
+impl BenchmarkKindProps for MixedArgs {
+    fn message_size(&self) -> u32 {
+        self.message_size.get()
+    }
+
+    fn message_batches(&self) -> u32 {
+        self.message_batches.get()
+    }
+
+    fn messages_per_batch(&self) -> u32 {
+        self.messages_per_batch.get()
+    }
+
+    fn max_messages_per_batch(&self) -> u32 {
+        self.max_messages_per_batch
+            .unwrap_or(self.messages_per_batch)
+            .get()
+    }
+
+    fn number_of_streams(&self) -> u32 {
+        self.streams.get()
+    }
+
+    fn number_of_partitions(&self) -> u32 {
+        1
+    }
+
+    fn consumers(&self) -> u32 {
+        self.actors.get()
+    }
+
+    fn producers(&self) -> u32 {
+        self.actors.get()
+    }
+
+    fn disable_parallel_producer_streams(&self) -> bool {
+        self.disable_parallel_actors
+    }
+
+    fn disable_parallel_consumer_streams(&self) -> bool {
+        self.disable_parallel_actors
+    }
+
+    fn transport_command(&self) -> &BenchmarkTransportCommand {
+        &self.transport
+    }
+
+    fn validate(&self) {
+        validate_batch_sizes(self.messages_per_batch, self.max_messages_per_batch);
+        let streams = self.streams.get();
+        let actors = self.actors.get();
+        let mut cmd = IggyBenchArgs::command();
+
+        if !self.disable_parallel_actors && streams < actors {
+            cmd.error(
+                ErrorKind::ArgumentConflict,
+                format!("With parallel actors, the number of streams ({streams}) must be greater than or equal to the number of actors ({actors})."),
+            )
+            .exit();
+        }
+    }
+}
+
+fn validate_batch_sizes(
+    messages_per_batch: NonZeroU32,
+    max_messages_per_batch: Option<NonZeroU32>,
+) {
+    if let Some(max_messages_per_batch) = max_messages_per_batch {
+        if max_messages_per_batch < messages_per_batch {
+            IggyBenchArgs::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    format!("The maximum number of messages per batch ({max_messages_per_batch}) must be greater than or equal to the number of messages per batch ({messages_per_batch})."),
+                )
+                .exit();
+        }
+    }
+}
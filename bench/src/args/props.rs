@@ -4,8 +4,10 @@ use integration::test_server::Transport;
 pub trait BenchmarkKindProps {
     fn message_size(&self) -> u32;
     fn messages_per_batch(&self) -> u32;
+    fn max_messages_per_batch(&self) -> u32;
     fn message_batches(&self) -> u32;
     fn number_of_streams(&self) -> u32;
+    fn number_of_partitions(&self) -> u32;
     fn consumers(&self) -> u32;
     fn producers(&self) -> u32;
     fn disable_parallel_producer_streams(&self) -> bool;
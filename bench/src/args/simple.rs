@@ -1,6 +1,8 @@
 use derive_more::Display;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum BenchmarkKind {
     #[display(fmt = "send messages")]
     Send,
@@ -8,4 +10,8 @@ pub enum BenchmarkKind {
     Poll,
     #[display(fmt = "send and poll messages")]
     SendAndPoll,
+    #[display(fmt = "consumer group poll messages")]
+    ConsumerGroupPoll,
+    #[display(fmt = "mixed send and poll messages")]
+    Mixed,
 }
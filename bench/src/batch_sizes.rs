@@ -0,0 +1,54 @@
+use std::fmt::{Display, Formatter};
+
+/// The numbers of messages in the consecutive batches, either constant or varying between the min and max.
+/// The varying sizes are spread over the whole range, yet they're the same in each run (and for each client),
+/// so the consumers are able to poll exactly the batches sent by the producers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatchSizes {
+    min: u32,
+    max: u32,
+}
+
+// Knuth's multiplicative hash, to spread the consecutive batches over the range of sizes.
+const SPREAD_FACTOR: u64 = 2_654_435_761;
+
+impl BatchSizes {
+    pub fn new(min: u32, max: u32) -> Self {
+        Self {
+            min,
+            max: max.max(min),
+        }
+    }
+
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    pub fn is_varying(&self) -> bool {
+        self.min != self.max
+    }
+
+    /// Returns the number of messages in the batch with the given (zero-based) index.
+    pub fn get(&self, batch: u32) -> u32 {
+        if !self.is_varying() {
+            return self.min;
+        }
+
+        let range = (self.max - self.min) as u64 + 1;
+        self.min + (batch as u64 * SPREAD_FACTOR % range) as u32
+    }
+
+    /// Returns the total number of messages in the given number of batches.
+    pub fn total_messages(&self, batches: u32) -> u64 {
+        (0..batches).map(|batch| self.get(batch) as u64).sum()
+    }
+}
+
+impl Display for BatchSizes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.is_varying() {
+            true => write!(f, "{}-{}", self.min, self.max),
+            false => write!(f, "{}", self.min),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::args::common::IggyBenchArgs;
+use crate::args::simple::BenchmarkKind;
+use crate::benchmark_result::{BenchmarkResults, BenchmarkStatistics};
+use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::Serialize;
+use std::path::Path;
+use tracing::info;
+
+/// The machine-readable report of the benchmark, to compare the results across the releases.
+#[derive(Debug, Serialize)]
+pub struct BenchmarkReport {
+    pub benchmark: BenchmarkKind,
+    pub transport: String,
+    pub bench_version: &'static str,
+    /// The time of finishing the benchmark in microseconds since the Unix epoch.
+    pub timestamp: u64,
+    pub settings: BenchmarkSettings,
+    /// The statistics per kind of the clients, e.g. producers and consumers.
+    pub results: Vec<BenchmarkStatistics>,
+    /// The statistics of all the clients.
+    pub summary: BenchmarkStatistics,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BenchmarkSettings {
+    pub streams: u32,
+    pub partitions: u32,
+    pub messages_per_batch: u32,
+    pub max_messages_per_batch: u32,
+    pub message_batches: u32,
+    pub message_size: u32,
+    pub compression: Option<String>,
+}
+
+impl BenchmarkReport {
+    pub fn new(kind: BenchmarkKind, args: &IggyBenchArgs, results: &BenchmarkResults) -> Self {
+        Self {
+            benchmark: kind,
+            transport: args.transport().to_string(),
+            bench_version: env!("CARGO_PKG_VERSION"),
+            timestamp: IggyTimestamp::now().to_micros(),
+            settings: BenchmarkSettings {
+                streams: args.number_of_streams(),
+                partitions: args.number_of_partitions(),
+                messages_per_batch: args.messages_per_batch(),
+                max_messages_per_batch: args.max_messages_per_batch(),
+                message_batches: args.message_batches(),
+                message_size: args.message_size(),
+                compression: args
+                    .compression
+                    .as_ref()
+                    .map(|algorithm| algorithm.to_string()),
+            },
+            results: results
+                .kinds()
+                .into_iter()
+                .map(|kind| results.statistics(Some(kind)))
+                .collect(),
+            summary: results.statistics(None),
+        }
+    }
+
+    pub async fn save(&self, path: &str) -> Result<(), IggyError> {
+        let report = serde_json::to_string_pretty(self).map_err(|_| IggyError::InvalidFormat)?;
+        if let Some(directory) = Path::new(path).parent() {
+            if !directory.as_os_str().is_empty() {
+                tokio::fs::create_dir_all(directory).await?;
+            }
+        }
+        tokio::fs::write(path, report).await?;
+        info!("Saved the benchmark report to: {path}");
+        Ok(())
+    }
+}
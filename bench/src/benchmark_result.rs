@@ -1,22 +1,46 @@
 use crate::args::simple::BenchmarkKind;
 use colored::Colorize;
-use std::collections::HashSet;
+use hdrhistogram::Histogram;
+use serde::Serialize;
 use std::{
     fmt::{Display, Formatter},
     time::Duration,
 };
 use tokio::time::Instant;
 
+/// The kinds of results returned by the clients (actors) of the benchmarks, in the order they are displayed.
+const ACTOR_KINDS: [BenchmarkKind; 4] = [
+    BenchmarkKind::Send,
+    BenchmarkKind::Poll,
+    BenchmarkKind::ConsumerGroupPoll,
+    BenchmarkKind::Mixed,
+];
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct BenchmarkResult {
     pub kind: BenchmarkKind,
     pub start_timestamp: Instant,
     pub end_timestamp: Instant,
     pub average_latency: Duration,
+    /// The latencies of the batches in microseconds.
+    pub latencies: Histogram<u64>,
     pub total_size_bytes: u64,
     pub total_messages: u64,
 }
 
+// The latencies above 1 minute are recorded as 1 minute.
+const MAX_LATENCY_MICROS: u64 = 60_000_000;
+
+/// Creates the histogram of the batch latencies in microseconds, with 3 significant digits of precision.
+pub fn create_latency_histogram() -> Histogram<u64> {
+    Histogram::new_with_bounds(1, MAX_LATENCY_MICROS, 3)
+        .expect("Failed to create the latency histogram")
+}
+
+pub fn record_latency(latencies: &mut Histogram<u64>, latency: Duration) {
+    latencies.saturating_record(latency.as_micros() as u64);
+}
+
 pub struct BenchmarkResults {
     results: Vec<BenchmarkResult>,
 }
@@ -26,107 +50,111 @@ impl From<Vec<BenchmarkResult>> for BenchmarkResults {
         Self { results }
     }
 }
-#[derive(Debug, Clone, PartialEq)]
-struct BenchmarkStatistics {
-    total_throughput: f64,
-    messages_per_second: f64,
-    average_latency: f64,
-    average_throughput: f64,
-    total_duration: f64,
-}
 
-#[derive(Debug, Clone)]
-struct ImpossibleBenchmarkKind;
+/// The statistics of the results of the given kind, the throughputs are in MB/s,
+/// the latencies in milliseconds and the duration in seconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchmarkStatistics {
+    pub kind: Option<BenchmarkKind>,
+    pub actors: u32,
+    pub total_messages: u64,
+    pub total_size_bytes: u64,
+    pub total_throughput: f64,
+    pub messages_per_second: f64,
+    pub average_throughput: f64,
+    pub average_latency: f64,
+    pub p50_latency: f64,
+    pub p90_latency: f64,
+    pub p95_latency: f64,
+    pub p99_latency: f64,
+    pub p999_latency: f64,
+    pub max_latency: f64,
+    pub total_duration: f64,
+}
 
 impl BenchmarkResults {
-    fn get_test_type(&self) -> Result<BenchmarkKind, ImpossibleBenchmarkKind> {
-        let result_kinds = self
-            .results
-            .iter()
-            .map(|r| r.kind)
-            .collect::<HashSet<BenchmarkKind>>();
-        match (
-            result_kinds.contains(&BenchmarkKind::Poll),
-            result_kinds.contains(&BenchmarkKind::Send),
-        ) {
-            (true, true) => Ok(BenchmarkKind::SendAndPoll),
-            (true, false) => Ok(BenchmarkKind::Poll),
-            (false, true) => Ok(BenchmarkKind::Send),
-            (false, false) => Err(ImpossibleBenchmarkKind),
-        }
+    /// Returns the kinds of the results, e.g. both send and poll for the send and poll benchmark.
+    pub fn kinds(&self) -> Vec<BenchmarkKind> {
+        ACTOR_KINDS
+            .into_iter()
+            .filter(|kind| self.results.iter().any(|r| r.kind == *kind))
+            .collect()
     }
 
-    fn calculate_statitics<F>(&self, mut predicate: F) -> BenchmarkStatistics
-    where
-        F: FnMut(&&BenchmarkResult) -> bool,
-    {
-        let total_size_bytes = self
+    /// Returns the statistics of the results of the given kind, or of all the results if the kind is not provided.
+    pub fn statistics(&self, kind: Option<BenchmarkKind>) -> BenchmarkStatistics {
+        let results = self
             .results
             .iter()
-            .filter(&mut predicate)
-            .map(|r| r.total_size_bytes)
-            .sum::<u64>();
-        let total_duration = (self
-            .results
+            .filter(|r| kind.is_none() || kind == Some(r.kind))
+            .collect::<Vec<_>>();
+        let actors = results.len() as u32;
+        let total_size_bytes = results.iter().map(|r| r.total_size_bytes).sum::<u64>();
+        let total_messages = results.iter().map(|r| r.total_messages).sum::<u64>();
+        let total_duration = (results
             .iter()
-            .filter(&mut predicate)
             .map(|r| r.end_timestamp - r.start_timestamp)
             .sum::<Duration>()
-            / self.results.len() as u32)
+            / actors)
             .as_secs_f64();
-        let total_messages = self
-            .results
-            .iter()
-            .filter(&mut predicate)
-            .map(|r| r.total_messages)
-            .sum::<u64>();
-        let average_latency = (self
-            .results
-            .iter()
-            .filter(&mut predicate)
-            .map(|r| r.average_latency)
-            .sum::<Duration>()
-            / self.results.len() as u32)
-            .as_secs_f64()
-            * 1000.0;
-        let average_throughput =
-            total_size_bytes as f64 / total_duration / 1e6 / self.results.len() as f64;
+        let average_latency =
+            (results.iter().map(|r| r.average_latency).sum::<Duration>() / actors).as_secs_f64()
+                * 1000.0;
+        let mut latencies = create_latency_histogram();
+        for result in &results {
+            latencies
+                .add(&result.latencies)
+                .expect("Failed to merge the latency histograms");
+        }
+        let latency_at = |quantile: f64| latencies.value_at_quantile(quantile) as f64 / 1000.0;
         let total_throughput = total_size_bytes as f64 / total_duration / 1e6;
+        let average_throughput = total_throughput / actors as f64;
         let messages_per_second = total_messages as f64 / total_duration;
 
         BenchmarkStatistics {
+            kind,
+            actors,
+            total_messages,
+            total_size_bytes,
             total_throughput,
             messages_per_second,
-            average_latency,
             average_throughput,
+            average_latency,
+            p50_latency: latency_at(0.5),
+            p90_latency: latency_at(0.9),
+            p95_latency: latency_at(0.95),
+            p99_latency: latency_at(0.99),
+            p999_latency: latency_at(0.999),
+            max_latency: latencies.max() as f64 / 1000.0,
             total_duration,
         }
     }
 }
 
-impl Display for BenchmarkResults {
+impl Display for BenchmarkStatistics {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Ok(test_type) = self.get_test_type() {
-            if test_type == BenchmarkKind::SendAndPoll {
-                let producer_statics = self.calculate_statitics(|x| x.kind == BenchmarkKind::Send);
-                let consumer_statics = self.calculate_statitics(|x| x.kind == BenchmarkKind::Poll);
-
-                let producer_info = format!("Producer results: total throughput: {:.2} MB/s, {:.0} messages/s, average latency: {:.2} ms, average throughput: {:.2} MB/s, total duration: {:.2} s",
-                producer_statics.total_throughput, producer_statics.messages_per_second, producer_statics.average_latency, producer_statics.average_throughput, producer_statics.total_duration).green();
+        let name = match self.kind {
+            Some(BenchmarkKind::Send) => "Producer results",
+            Some(BenchmarkKind::Poll) => "Consumer results",
+            Some(BenchmarkKind::ConsumerGroupPoll) => "Consumer group results",
+            Some(BenchmarkKind::Mixed) => "Mixed (send and poll round trip) results",
+            Some(BenchmarkKind::SendAndPoll) | None => "Results",
+        };
+        let info = format!("{name}: total throughput: {:.2} MB/s, {:.0} messages/s, average latency: {:.2} ms, p50 latency: {:.2} ms, p90 latency: {:.2} ms, p95 latency: {:.2} ms, p99 latency: {:.2} ms, p99.9 latency: {:.2} ms, max latency: {:.2} ms, average throughput: {:.2} MB/s, total duration: {:.2} s",
+            self.total_throughput, self.messages_per_second, self.average_latency, self.p50_latency, self.p90_latency, self.p95_latency, self.p99_latency, self.p999_latency, self.max_latency, self.average_throughput, self.total_duration).green();
+        write!(f, "{info}")
+    }
+}
 
-                let consumer_info = format!("Consumer results: total throughput: {:.2} MB/s, {:.0} messages/s, average latency: {:.2} ms, average  throughput: {:.2} MB/s, total duration: {:.2} s",
-                consumer_statics.total_throughput, consumer_statics.messages_per_second, consumer_statics.average_latency, consumer_statics.average_throughput, consumer_statics.total_duration).green();
-                writeln!(f, "{}, {}", producer_info, consumer_info)?;
+impl Display for BenchmarkResults {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kinds = self.kinds();
+        if kinds.len() > 1 {
+            for kind in kinds {
+                writeln!(f, "{}", self.statistics(Some(kind)))?;
             }
         }
 
-        let results = self.calculate_statitics(|x| {
-            x.kind == BenchmarkKind::Send || x.kind == BenchmarkKind::Poll
-        });
-
-        let summary_info = format!("Results: total throughput: {:.2} MB/s, {:.0} messages/s, average latency: {:.2} ms, average  throughput: {:.2} MB/s, total duration: {:.2} s",
-        results.total_throughput, results.messages_per_second, results.average_latency, results.average_throughput, results.total_duration).green();
-
-        writeln!(f, "{}", summary_info)
+        writeln!(f, "{}", self.statistics(None))
     }
 }
@@ -1,9 +1,11 @@
 use crate::args::common::IggyBenchArgs;
+use crate::benchmark_report::BenchmarkReport;
 use crate::benchmark_result::BenchmarkResults;
 use crate::benchmarks::benchmark::Benchmarkable;
 use crate::server_starter::start_server_if_needed;
 use futures::future::select_all;
 use iggy::error::IggyError;
+use iggy::utils::buffer_pool::BufferPool;
 use integration::test_server::TestServer;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -29,6 +31,9 @@ impl BenchmarkRunner {
         let transport = args.transport();
         let server_addr = args.server_address();
         info!("Starting to benchmark: {transport} with server: {server_addr}",);
+        if let Some(compression) = &args.compression {
+            info!("Client-side compression of the message payloads: {compression}");
+        }
 
         let mut benchmark: Box<dyn Benchmarkable> = args.into();
         let mut join_handles = benchmark.run().await?;
@@ -53,6 +58,12 @@ impl BenchmarkRunner {
             .to_string()
             .split('\n')
             .for_each(|result| info!("{}", result));
+        info!("Client buffer pool: {}", BufferPool::global().stats());
+        if let Some(output_json) = &benchmark.args().output_json {
+            BenchmarkReport::new(benchmark.kind(), benchmark.args(), &results)
+                .save(output_json)
+                .await?;
+        }
         Ok(())
     }
 }
@@ -1,6 +1,7 @@
 use super::{
-    poll_benchmark::PollMessagesBenchmark, send_and_poll_benchmark::SendAndPollMessagesBenchmark,
-    send_benchmark::SendMessagesBenchmark,
+    consumer_group_poll_benchmark::ConsumerGroupPollMessagesBenchmark,
+    mixed_benchmark::MixedMessagesBenchmark, poll_benchmark::PollMessagesBenchmark,
+    send_and_poll_benchmark::SendAndPollMessagesBenchmark, send_benchmark::SendMessagesBenchmark,
 };
 use crate::{
     args::{common::IggyBenchArgs, simple::BenchmarkKind},
@@ -9,6 +10,8 @@ use crate::{
 };
 use async_trait::async_trait;
 use futures::Future;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::{
     client::{StreamClient, TopicClient},
     clients::client::{IggyClient, IggyClientConfig},
@@ -41,6 +44,13 @@ impl From<IggyBenchArgs> for Box<dyn Benchmarkable> {
                 Arc::new(args),
                 client_factory,
             )),
+            BenchmarkKind::ConsumerGroupPoll => Box::new(ConsumerGroupPollMessagesBenchmark::new(
+                Arc::new(args),
+                client_factory,
+            )),
+            BenchmarkKind::Mixed => {
+                Box::new(MixedMessagesBenchmark::new(Arc::new(args), client_factory))
+            }
         }
     }
 }
@@ -61,7 +71,7 @@ pub trait Benchmarkable {
         let start_stream_id = self.args().start_stream_id();
         let number_of_streams = self.args().number_of_streams();
         let topic_id: u32 = 1;
-        let partitions_count: u32 = 1;
+        let partitions_count = self.args().number_of_partitions();
         let client = self.client_factory().create_client().await;
         let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
         login_root(&client).await;
@@ -75,6 +85,7 @@ pub trait Benchmarkable {
                     .create_stream(&CreateStream {
                         stream_id: Some(stream_id),
                         name,
+                        ..CreateStream::default()
                     })
                     .await?;
 
@@ -92,6 +103,11 @@ pub trait Benchmarkable {
                         message_expiry: None,
                         max_topic_size: None,
                         replication_factor: 1,
+                        message_schema: None,
+                        ephemeral: false,
+                        timestamp_type: TimestampType::default(),
+                        consumer_limits: ConsumerLimits::default(),
+                        max_message_size: None,
                     })
                     .await?;
             }
@@ -120,9 +136,8 @@ pub trait Benchmarkable {
 
     /// Returns the total number of messages that will be sent or polled by the benchmark.
     fn total_messages(&self) -> u64 {
-        let messages_per_batch = self.args().messages_per_batch();
         let message_batches = self.args().message_batches();
         let streams = self.args().number_of_streams();
-        (messages_per_batch * message_batches * streams) as u64
+        self.args().batch_sizes().total_messages(message_batches) * streams as u64
     }
 }
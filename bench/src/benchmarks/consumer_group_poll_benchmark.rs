@@ -0,0 +1,171 @@
+use super::benchmark::{BenchmarkFutures, Benchmarkable};
+use crate::args::common::IggyBenchArgs;
+use crate::args::simple::BenchmarkKind;
+use crate::client_factory::create_logged_in_client;
+use crate::group_consumer::GroupConsumer;
+use async_trait::async_trait;
+use iggy::client::{ConsumerGroupClient, TopicClient};
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::topics::get_topic::GetTopic;
+use integration::test_server::ClientFactory;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use tracing::info;
+
+const CONSUMER_GROUP_NAME: &str = "bench";
+
+pub struct ConsumerGroupPollMessagesBenchmark {
+    args: Arc<IggyBenchArgs>,
+    client_factory: Arc<dyn ClientFactory>,
+}
+
+impl ConsumerGroupPollMessagesBenchmark {
+    pub fn new(args: Arc<IggyBenchArgs>, client_factory: Arc<dyn ClientFactory>) -> Self {
+        Self {
+            args,
+            client_factory,
+        }
+    }
+
+    /// Creates the consumer group for the topic of each stream and returns their IDs. The offsets of the deleted group
+    /// are still stored in the partitions, so the group left by the previous benchmark is replaced by the one with a new ID,
+    /// to poll the messages from the beginning of the partitions.
+    async fn init_consumer_groups(&self) -> Result<Vec<u32>, IggyError> {
+        let start_stream_id = self.args.start_stream_id();
+        let expected_messages = self.expected_messages_per_stream();
+        let client = create_logged_in_client(self.client_factory.as_ref(), None).await;
+        let topic_id = Identifier::numeric(1)?;
+        let mut consumer_group_ids = Vec::with_capacity(self.args.number_of_streams() as usize);
+        for i in 1..=self.args.number_of_streams() {
+            let stream_id = Identifier::numeric(start_stream_id + i)?;
+            let topic = client
+                .get_topic(&GetTopic {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                })
+                .await?;
+            if topic.messages_count < expected_messages {
+                return Err(IggyError::ResourceNotFound(format!(
+                    "Streams for testing are not properly initialized. Stream with id: {} contains {} messages, expected {}.",
+                    start_stream_id + i,
+                    topic.messages_count,
+                    expected_messages
+                )));
+            }
+
+            let consumer_groups = client
+                .get_consumer_groups(&GetConsumerGroups {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                })
+                .await?;
+            let consumer_group_id = consumer_groups
+                .iter()
+                .map(|consumer_group| consumer_group.id)
+                .max()
+                .unwrap_or(0)
+                + 1;
+            if let Some(consumer_group) = consumer_groups
+                .iter()
+                .find(|consumer_group| consumer_group.name == CONSUMER_GROUP_NAME)
+            {
+                client
+                    .delete_consumer_group(&DeleteConsumerGroup {
+                        stream_id: stream_id.clone(),
+                        topic_id: topic_id.clone(),
+                        consumer_group_id: Identifier::numeric(consumer_group.id)?,
+                    })
+                    .await?;
+            }
+
+            info!(
+                "Creating the consumer group {} for stream {} with {} partitions",
+                consumer_group_id,
+                start_stream_id + i,
+                topic.partitions_count
+            );
+            client
+                .create_consumer_group(&CreateConsumerGroup {
+                    stream_id,
+                    topic_id: topic_id.clone(),
+                    consumer_group_id,
+                    name: CONSUMER_GROUP_NAME.to_owned(),
+                })
+                .await?;
+            consumer_group_ids.push(consumer_group_id);
+        }
+        Ok(consumer_group_ids)
+    }
+
+    fn expected_messages_per_stream(&self) -> u64 {
+        (self.args.messages_per_batch() * self.args.message_batches()) as u64
+    }
+}
+
+#[async_trait]
+impl Benchmarkable for ConsumerGroupPollMessagesBenchmark {
+    async fn run(&mut self) -> BenchmarkFutures {
+        self.check_streams().await?;
+        let consumer_group_ids = self.init_consumer_groups().await?;
+        let start_stream_id = self.args.start_stream_id();
+        let streams = self.args.number_of_streams();
+        let consumers = self.args.consumers();
+        let messages_per_batch = self.args.messages_per_batch();
+        let expected_messages = self.expected_messages_per_stream();
+        let received_messages = (0..streams)
+            .map(|_| Arc::new(AtomicU64::new(0)))
+            .collect::<Vec<_>>();
+        info!("Creating {} client(s)...", consumers);
+
+        let mut futures: BenchmarkFutures = Ok(Vec::with_capacity(consumers as usize));
+        for consumer_id in 1..=consumers {
+            let stream_index = (consumer_id - 1) % streams;
+            let consumer = GroupConsumer::new(
+                self.client_factory.clone(),
+                consumer_id,
+                start_stream_id + stream_index + 1,
+                consumer_group_ids[stream_index as usize],
+                messages_per_batch,
+                expected_messages,
+                received_messages[stream_index as usize].clone(),
+                self.args.compression.clone(),
+            );
+            let future = Box::pin(async move { consumer.run().await });
+            futures.as_mut().unwrap().push(future);
+        }
+        info!("Created {} client(s).", consumers);
+        futures
+    }
+
+    fn kind(&self) -> BenchmarkKind {
+        BenchmarkKind::ConsumerGroupPoll
+    }
+
+    fn args(&self) -> &IggyBenchArgs {
+        &self.args
+    }
+
+    fn client_factory(&self) -> &Arc<dyn ClientFactory> {
+        &self.client_factory
+    }
+
+    fn display_settings(&self) {
+        let total_messages = self.total_messages();
+        let total_size_bytes = total_messages * self.args().message_size() as u64;
+        info!(
+                "\x1B[32mBenchmark: {}, total messages: {}, total size: {} bytes, {} streams, {} messages per batch, {} batches, {} bytes per message, {} consumers\x1B[0m",
+                self.kind(),
+                total_messages,
+                total_size_bytes,
+                self.args().number_of_streams(),
+                self.args().messages_per_batch(),
+                self.args().message_batches(),
+                self.args().message_size(),
+                self.args().consumers(),
+            );
+    }
+}
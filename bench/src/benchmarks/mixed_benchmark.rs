@@ -0,0 +1,92 @@
+use super::benchmark::{BenchmarkFutures, Benchmarkable};
+use crate::args::common::IggyBenchArgs;
+use crate::args::simple::BenchmarkKind;
+use crate::producing_consumer::ProducingConsumer;
+use async_trait::async_trait;
+use integration::test_server::ClientFactory;
+use std::sync::Arc;
+use tracing::info;
+
+pub struct MixedMessagesBenchmark {
+    args: Arc<IggyBenchArgs>,
+    client_factory: Arc<dyn ClientFactory>,
+}
+
+impl MixedMessagesBenchmark {
+    pub fn new(args: Arc<IggyBenchArgs>, client_factory: Arc<dyn ClientFactory>) -> Self {
+        Self {
+            args,
+            client_factory,
+        }
+    }
+}
+
+#[async_trait]
+impl Benchmarkable for MixedMessagesBenchmark {
+    async fn run(&mut self) -> BenchmarkFutures {
+        self.init_streams().await.expect("Failed to init streams!");
+        let start_stream_id = self.args.start_stream_id();
+        let actors = self.args.producers();
+        let parallel_actor_streams = !self.args.disable_parallel_producer_streams();
+        let batch_sizes = self.args.batch_sizes();
+        let message_batches = self.args.message_batches();
+        let message_size = self.args.message_size();
+        info!("Creating {} client(s)...", actors);
+
+        let mut futures: BenchmarkFutures = Ok(Vec::with_capacity(actors as usize));
+        for actor_id in 1..=actors {
+            let stream_id = match parallel_actor_streams {
+                true => start_stream_id + actor_id,
+                false => start_stream_id + 1,
+            };
+
+            let actor = ProducingConsumer::new(
+                self.client_factory.clone(),
+                actor_id,
+                stream_id,
+                batch_sizes,
+                message_batches,
+                message_size,
+                self.args.compression.clone(),
+            );
+            let future = Box::pin(async move { actor.run().await });
+            futures.as_mut().unwrap().push(future);
+        }
+        info!("Created {} client(s).", actors);
+        futures
+    }
+
+    fn kind(&self) -> BenchmarkKind {
+        BenchmarkKind::Mixed
+    }
+
+    fn total_messages(&self) -> u64 {
+        let message_batches = self.args.message_batches();
+        let actors = self.args.producers();
+        self.args.batch_sizes().total_messages(message_batches) * actors as u64
+    }
+
+    fn args(&self) -> &IggyBenchArgs {
+        &self.args
+    }
+
+    fn client_factory(&self) -> &Arc<dyn ClientFactory> {
+        &self.client_factory
+    }
+
+    fn display_settings(&self) {
+        let total_messages = self.total_messages();
+        let total_size_bytes = total_messages * self.args().message_size() as u64;
+        info!(
+                "\x1B[32mBenchmark: {}, total messages: {}, total size: {} bytes, {} streams, {} messages per batch, {} batches, {} bytes per message, {} actors\x1B[0m",
+                self.kind(),
+                total_messages,
+                total_size_bytes,
+                self.args().number_of_streams(),
+                self.args().batch_sizes(),
+                self.args().message_batches(),
+                self.args().message_size(),
+                self.args().producers(),
+            );
+    }
+}
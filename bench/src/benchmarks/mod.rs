@@ -1,4 +1,6 @@
 pub mod benchmark;
+pub mod consumer_group_poll_benchmark;
+pub mod mixed_benchmark;
 pub mod poll_benchmark;
 pub mod send_and_poll_benchmark;
 pub mod send_benchmark;
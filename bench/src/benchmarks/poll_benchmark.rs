@@ -27,7 +27,7 @@ impl Benchmarkable for PollMessagesBenchmark {
         self.check_streams().await?;
         let clients_count = self.args.consumers();
         info!("Creating {} client(s)...", clients_count);
-        let messages_per_batch = self.args.messages_per_batch();
+        let batch_sizes = self.args.batch_sizes();
         let message_batches = self.args.message_batches();
 
         let mut futures: BenchmarkFutures = Ok(Vec::with_capacity(clients_count as usize));
@@ -48,8 +48,9 @@ impl Benchmarkable for PollMessagesBenchmark {
                 client_factory,
                 client_id,
                 stream_id,
-                messages_per_batch,
+                batch_sizes,
                 message_batches,
+                args.compression.clone(),
             );
 
             let future = Box::pin(async move { consumer.run().await });
@@ -80,7 +81,7 @@ impl Benchmarkable for PollMessagesBenchmark {
                 total_messages,
                 total_size_bytes,
                 self.args().number_of_streams(),
-                self.args().messages_per_batch(),
+                self.args().batch_sizes(),
                 self.args().message_batches(),
                 self.args().message_size(),
                 self.args().consumers(),
@@ -38,7 +38,7 @@ impl Display for SendAndPollMessagesBenchmark {
                 total_messages_human_readable,
                 total_size_human_readable,
                 self.args().number_of_streams(),
-                self.args().messages_per_batch(),
+                self.args().batch_sizes(),
                 self.args().message_batches(),
                 self.args().message_size(),
                 self.args().producers(),
@@ -58,7 +58,7 @@ impl Benchmarkable for SendAndPollMessagesBenchmark {
         let consumers = self.args.consumers();
         let parallel_producer_streams = !self.args.disable_parallel_producer_streams();
         let parallel_consumer_streams = !self.args.disable_parallel_consumer_streams();
-        let messages_per_batch = self.args.messages_per_batch();
+        let batch_sizes = self.args.batch_sizes();
         let message_batches = self.args.message_batches();
         let message_size = self.args.message_size();
         let mut futures: BenchmarkFutures =
@@ -73,9 +73,11 @@ impl Benchmarkable for SendAndPollMessagesBenchmark {
                 self.client_factory.clone(),
                 producer_id,
                 stream_id,
-                messages_per_batch,
+                1,
+                batch_sizes,
                 message_batches,
                 message_size,
+                self.args.compression.clone(),
             );
             let future = Box::pin(async move { producer.run().await });
             futures.as_mut().unwrap().push(future);
@@ -90,8 +92,9 @@ impl Benchmarkable for SendAndPollMessagesBenchmark {
                 self.client_factory.clone(),
                 consumer_id,
                 stream_id,
-                messages_per_batch,
+                batch_sizes,
                 message_batches,
+                self.args.compression.clone(),
             );
             let future = Box::pin(async move { consumer.run().await });
             futures.as_mut().unwrap().push(future);
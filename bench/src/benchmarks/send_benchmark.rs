@@ -27,9 +27,10 @@ impl Benchmarkable for SendMessagesBenchmark {
         self.init_streams().await.expect("Failed to init streams!");
         let clients_count = self.args.producers();
         info!("Creating {} client(s)...", clients_count);
-        let messages_per_batch = self.args.messages_per_batch();
+        let batch_sizes = self.args.batch_sizes();
         let message_batches = self.args.message_batches();
         let message_size = self.args.message_size();
+        let partitions = self.args.number_of_partitions();
 
         let mut futures: BenchmarkFutures = Ok(Vec::with_capacity(clients_count as usize));
         for client_id in 1..=clients_count {
@@ -50,9 +51,11 @@ impl Benchmarkable for SendMessagesBenchmark {
                 client_factory,
                 client_id,
                 stream_id,
-                messages_per_batch,
+                partitions,
+                batch_sizes,
                 message_batches,
                 message_size,
+                args.compression.clone(),
             );
             let future = Box::pin(async move { producer.run().await });
             futures.as_mut().unwrap().push(future);
@@ -65,13 +68,6 @@ impl Benchmarkable for SendMessagesBenchmark {
         BenchmarkKind::Send
     }
 
-    fn total_messages(&self) -> u64 {
-        let messages_per_batch = self.args.messages_per_batch();
-        let message_batches = self.args.message_batches();
-        let streams = self.args.number_of_streams();
-        (messages_per_batch * message_batches * streams) as u64
-    }
-
     fn args(&self) -> &IggyBenchArgs {
         &self.args
     }
@@ -84,12 +80,13 @@ impl Benchmarkable for SendMessagesBenchmark {
         let total_messages = self.total_messages();
         let total_size_bytes = total_messages * self.args().message_size() as u64;
         info!(
-                "\x1B[32mBenchmark: {}, total messages: {}, total size: {} bytes, {} streams, {} messages per batch, {} batches, {} bytes per message, {} producers\x1B[0m",
+                "\x1B[32mBenchmark: {}, total messages: {}, total size: {} bytes, {} streams, {} partitions, {} messages per batch, {} batches, {} bytes per message, {} producers\x1B[0m",
                 self.kind(),
                 total_messages,
                 total_size_bytes,
                 self.args().number_of_streams(),
-                self.args().messages_per_batch(),
+                self.args().number_of_partitions(),
+                self.args().batch_sizes(),
                 self.args().message_batches(),
                 self.args().message_size(),
                 self.args().producers(),
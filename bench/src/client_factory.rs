@@ -1,8 +1,10 @@
 use crate::args::common::IggyBenchArgs;
+use iggy::clients::client::{CompressionConfig, IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use integration::http_client::HttpClientFactory;
 use integration::quic_client::QuicClientFactory;
 use integration::tcp_client::TcpClientFactory;
-use integration::test_server::{ClientFactory, Transport};
+use integration::test_server::{login_root, ClientFactory, Transport};
 use std::sync::Arc;
 
 pub fn create_client_factory(args: &IggyBenchArgs) -> Arc<dyn ClientFactory> {
@@ -18,3 +20,21 @@ pub fn create_client_factory(args: &IggyBenchArgs) -> Arc<dyn ClientFactory> {
         }),
     }
 }
+
+/// Creates the client logged in as the root user, compressing the message payloads if the algorithm is provided.
+pub async fn create_logged_in_client(
+    client_factory: &dyn ClientFactory,
+    compression: Option<CompressionAlgorithm>,
+) -> IggyClient {
+    let client = client_factory.create_client().await;
+    let config = IggyClientConfig {
+        compression: compression.map(|algorithm| CompressionConfig {
+            algorithms: vec![algorithm],
+            min_payload_size: 0,
+        }),
+        ..IggyClientConfig::default()
+    };
+    let client = IggyClient::create(client, config, None, None, None);
+    login_root(&client).await;
+    client
+}
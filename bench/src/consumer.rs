@@ -1,12 +1,14 @@
 use crate::args::simple::BenchmarkKind;
-use crate::benchmark_result::BenchmarkResult;
+use crate::batch_sizes::BatchSizes;
+use crate::benchmark_result::{create_latency_histogram, record_latency, BenchmarkResult};
+use crate::client_factory::create_logged_in_client;
 use iggy::client::MessageClient;
-use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::Consumer as IggyConsumer;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use integration::test_server::{login_root, ClientFactory};
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use integration::test_server::ClientFactory;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::Instant;
@@ -16,8 +18,9 @@ pub struct Consumer {
     client_factory: Arc<dyn ClientFactory>,
     consumer_id: u32,
     stream_id: u32,
-    messages_per_batch: u32,
+    batch_sizes: BatchSizes,
     message_batches: u32,
+    compression: Option<CompressionAlgorithm>,
 }
 
 impl Consumer {
@@ -25,32 +28,33 @@ impl Consumer {
         client_factory: Arc<dyn ClientFactory>,
         consumer_id: u32,
         stream_id: u32,
-        messages_per_batch: u32,
+        batch_sizes: BatchSizes,
         message_batches: u32,
+        compression: Option<CompressionAlgorithm>,
     ) -> Self {
         Self {
             client_factory,
             consumer_id,
             stream_id,
-            messages_per_batch,
+            batch_sizes,
             message_batches,
+            compression,
         }
     }
 
     pub async fn run(&self) -> Result<BenchmarkResult, IggyError> {
         let topic_id: u32 = 1;
         let partition_id: u32 = 1;
-        let total_messages = (self.messages_per_batch * self.message_batches) as u64;
-        let client = self.client_factory.create_client().await;
-        let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
-        login_root(&client).await;
+        let total_messages = self.batch_sizes.total_messages(self.message_batches);
+        let client =
+            create_logged_in_client(self.client_factory.as_ref(), self.compression.clone()).await;
         info!(
             "Consumer #{} → preparing the test messages...",
             self.consumer_id
         );
         info!(
             "Consumer #{} → polling {} messages in {} batches of {} messages...",
-            self.consumer_id, total_messages, self.message_batches, self.messages_per_batch
+            self.consumer_id, total_messages, self.message_batches, self.batch_sizes
         );
 
         let mut poll_messages = PollMessages {
@@ -59,18 +63,25 @@ impl Consumer {
             topic_id: Identifier::numeric(topic_id).unwrap(),
             partition_id: Some(partition_id),
             strategy: PollingStrategy::offset(0),
-            count: self.messages_per_batch,
+            count: self.batch_sizes.get(0),
             auto_commit: false,
+            filter: None,
+            mode: PollingMode::Consume,
+            max_wait_ms: None,
+            max_bytes: None,
         };
 
-        let mut latencies: Vec<Duration> = Vec::with_capacity(self.message_batches as usize);
+        let mut latencies = create_latency_histogram();
+        let mut total_latency = Duration::ZERO;
         let mut total_size_bytes = 0;
         let mut current_iteration = 0;
         let mut received_messages = 0;
         let start_timestamp = Instant::now();
         while received_messages < total_messages {
-            let offset = (current_iteration * self.messages_per_batch) as u64;
+            // The offset is the number of messages in the previous batches, as the same batch sizes are used by the producers.
+            let offset = received_messages;
             poll_messages.strategy.value = offset;
+            poll_messages.count = self.batch_sizes.get(current_iteration);
 
             let latency_start = Instant::now();
             let polled_messages = client.poll_messages(&poll_messages).await;
@@ -86,17 +97,18 @@ impl Consumer {
                 continue;
             }
 
-            if polled_messages.messages.len() != self.messages_per_batch as usize {
+            if polled_messages.messages.len() != poll_messages.count as usize {
                 trace!(
                     "Consumer #{} → expected {} messages, but got {} messages, retrying...",
                     self.consumer_id,
-                    self.messages_per_batch,
+                    poll_messages.count,
                     polled_messages.messages.len()
                 );
                 continue;
             }
 
-            latencies.push(latency_end);
+            record_latency(&mut latencies, latency_end);
+            total_latency += latency_end;
             received_messages += polled_messages.messages.len() as u64;
             for message in polled_messages.messages {
                 total_size_bytes += message.get_size_bytes() as u64;
@@ -106,18 +118,19 @@ impl Consumer {
 
         let end_timestamp = Instant::now();
         let duration = end_timestamp - start_timestamp;
-        let average_latency: Duration = latencies.iter().sum::<Duration>() / latencies.len() as u32;
+        let average_latency = total_latency / current_iteration;
         let average_throughput = total_size_bytes as f64 / duration.as_secs_f64() / 1e6;
 
         info!(
-        "Consumer #{} → polled {} messages ({} batches of {} messages in {} ms, total size: {} bytes, average latency: {:.2} ms, average throughput: {:.2} MB/s",
+        "Consumer #{} → polled {} messages ({} batches of {} messages in {} ms, total size: {} bytes, average latency: {:.2} ms, p99 latency: {:.2} ms, average throughput: {:.2} MB/s",
         self.consumer_id,
         total_messages,
         self.message_batches,
-        self.messages_per_batch,
+        self.batch_sizes,
         duration.as_millis(),
         total_size_bytes,
-        average_latency.as_millis(),
+        average_latency.as_secs_f64() * 1000.0,
+        latencies.value_at_quantile(0.99) as f64 / 1000.0,
         average_throughput
     );
 
@@ -126,6 +139,7 @@ impl Consumer {
             start_timestamp,
             end_timestamp,
             average_latency,
+            latencies,
             total_size_bytes,
             total_messages,
         })
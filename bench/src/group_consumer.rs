@@ -0,0 +1,172 @@
+use crate::args::simple::BenchmarkKind;
+use crate::benchmark_result::{create_latency_histogram, record_latency, BenchmarkResult};
+use crate::client_factory::create_logged_in_client;
+use iggy::client::{ConsumerGroupClient, MessageClient};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer as IggyConsumer;
+use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use integration::test_server::ClientFactory;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::{info, trace, warn};
+
+/// The member of the consumer group, polling the next messages from the partitions assigned by the server,
+/// until all the members of the group have received the expected number of messages.
+pub struct GroupConsumer {
+    client_factory: Arc<dyn ClientFactory>,
+    consumer_id: u32,
+    stream_id: u32,
+    consumer_group_id: u32,
+    messages_per_batch: u32,
+    expected_messages: u64,
+    received_messages: Arc<AtomicU64>,
+    compression: Option<CompressionAlgorithm>,
+}
+
+impl GroupConsumer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        client_factory: Arc<dyn ClientFactory>,
+        consumer_id: u32,
+        stream_id: u32,
+        consumer_group_id: u32,
+        messages_per_batch: u32,
+        expected_messages: u64,
+        received_messages: Arc<AtomicU64>,
+        compression: Option<CompressionAlgorithm>,
+    ) -> Self {
+        Self {
+            client_factory,
+            consumer_id,
+            stream_id,
+            consumer_group_id,
+            messages_per_batch,
+            expected_messages,
+            received_messages,
+            compression,
+        }
+    }
+
+    pub async fn run(&self) -> Result<BenchmarkResult, IggyError> {
+        let topic_id: u32 = 1;
+        let client =
+            create_logged_in_client(self.client_factory.as_ref(), self.compression.clone()).await;
+        let stream_id = Identifier::numeric(self.stream_id)?;
+        let topic_id = Identifier::numeric(topic_id)?;
+        let consumer_group_id = Identifier::numeric(self.consumer_group_id)?;
+        client
+            .join_consumer_group(&JoinConsumerGroup {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                consumer_group_id: consumer_group_id.clone(),
+            })
+            .await?;
+        info!(
+            "Consumer #{} → joined the consumer group: {} of stream: {}, polling {} messages in batches of {} messages...",
+            self.consumer_id, self.consumer_group_id, self.stream_id, self.expected_messages, self.messages_per_batch
+        );
+
+        let poll_messages = PollMessages {
+            consumer: IggyConsumer::group(consumer_group_id.clone()),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id: None,
+            strategy: PollingStrategy::next(),
+            count: self.messages_per_batch,
+            auto_commit: true,
+            filter: None,
+            mode: PollingMode::Consume,
+            max_wait_ms: None,
+            max_bytes: None,
+        };
+
+        let mut latencies = create_latency_histogram();
+        let mut total_latency = Duration::ZERO;
+        let mut polled_batches = 0;
+        let mut total_messages = 0;
+        let mut total_size_bytes = 0;
+        let start_timestamp = Instant::now();
+        while self.received_messages.load(Ordering::Acquire) < self.expected_messages {
+            let latency_start = Instant::now();
+            let polled_messages = client.poll_messages(&poll_messages).await;
+            let latency_end = latency_start.elapsed();
+            let polled_messages = match polled_messages {
+                Ok(polled_messages) => polled_messages,
+                Err(error) => {
+                    trace!(
+                        "Consumer #{} → failed to poll the messages: {error}, retrying...",
+                        self.consumer_id
+                    );
+                    continue;
+                }
+            };
+
+            if polled_messages.messages.is_empty() {
+                trace!(
+                    "Consumer #{} → messages are empty, retrying...",
+                    self.consumer_id
+                );
+                continue;
+            }
+
+            record_latency(&mut latencies, latency_end);
+            total_latency += latency_end;
+            polled_batches += 1;
+            total_messages += polled_messages.messages.len() as u64;
+            for message in &polled_messages.messages {
+                total_size_bytes += message.get_size_bytes() as u64;
+            }
+            self.received_messages
+                .fetch_add(polled_messages.messages.len() as u64, Ordering::AcqRel);
+        }
+
+        let end_timestamp = Instant::now();
+        let duration = end_timestamp - start_timestamp;
+        let average_latency = match polled_batches {
+            0 => Duration::ZERO,
+            _ => total_latency / polled_batches,
+        };
+        let average_throughput = total_size_bytes as f64 / duration.as_secs_f64() / 1e6;
+        if let Err(error) = client
+            .leave_consumer_group(&LeaveConsumerGroup {
+                stream_id,
+                topic_id,
+                consumer_group_id,
+            })
+            .await
+        {
+            warn!(
+                "Consumer #{} → failed to leave the consumer group: {error}",
+                self.consumer_id
+            );
+        }
+
+        info!(
+        "Consumer #{} → polled {} messages in {} batches in {} ms, total size: {} bytes, average latency: {:.2} ms, p99 latency: {:.2} ms, average throughput: {:.2} MB/s",
+        self.consumer_id,
+        total_messages,
+        polled_batches,
+        duration.as_millis(),
+        total_size_bytes,
+        average_latency.as_secs_f64() * 1000.0,
+        latencies.value_at_quantile(0.99) as f64 / 1000.0,
+        average_throughput
+    );
+
+        Ok(BenchmarkResult {
+            kind: BenchmarkKind::ConsumerGroupPoll,
+            start_timestamp,
+            end_timestamp,
+            average_latency,
+            latencies,
+            total_size_bytes,
+            total_messages,
+        })
+    }
+}
@@ -1,10 +1,14 @@
 mod args;
+mod batch_sizes;
+mod benchmark_report;
 mod benchmark_result;
 mod benchmark_runner;
 mod benchmarks;
 mod client_factory;
 mod consumer;
+mod group_consumer;
 mod producer;
+mod producing_consumer;
 mod server_starter;
 
 use crate::{args::common::IggyBenchArgs, benchmark_runner::BenchmarkRunner};
@@ -1,12 +1,14 @@
 use crate::args::simple::BenchmarkKind;
-use crate::benchmark_result::BenchmarkResult;
+use crate::batch_sizes::BatchSizes;
+use crate::benchmark_result::{create_latency_histogram, record_latency, BenchmarkResult};
+use crate::client_factory::create_logged_in_client;
+use bytes::Bytes;
 use iggy::client::MessageClient;
-use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
-use integration::test_server::{login_root, ClientFactory};
-use std::str::FromStr;
+use integration::test_server::ClientFactory;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::Instant;
@@ -16,83 +18,100 @@ pub struct Producer {
     client_factory: Arc<dyn ClientFactory>,
     producer_id: u32,
     stream_id: u32,
-    messages_per_batch: u32,
+    partitions: u32,
+    batch_sizes: BatchSizes,
     message_batches: u32,
     message_size: u32,
+    compression: Option<CompressionAlgorithm>,
 }
 
 impl Producer {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         client_factory: Arc<dyn ClientFactory>,
         producer_id: u32,
         stream_id: u32,
-        messages_per_batch: u32,
+        partitions: u32,
+        batch_sizes: BatchSizes,
         message_batches: u32,
         message_size: u32,
+        compression: Option<CompressionAlgorithm>,
     ) -> Self {
         Producer {
             client_factory,
             producer_id,
             stream_id,
-            messages_per_batch,
+            partitions,
+            batch_sizes,
             message_batches,
             message_size,
+            compression,
         }
     }
 
     pub async fn run(&self) -> Result<BenchmarkResult, IggyError> {
         let topic_id: u32 = 1;
         let partition_id: u32 = 1;
-        let total_messages = (self.messages_per_batch * self.message_batches) as u64;
-        let client = self.client_factory.create_client().await;
-        let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
-        login_root(&client).await;
+        let total_messages = self.batch_sizes.total_messages(self.message_batches);
+        let client =
+            create_logged_in_client(self.client_factory.as_ref(), self.compression.clone()).await;
         info!(
             "Producer #{} → preparing the test messages...",
             self.producer_id
         );
-        let payload = Self::create_payload(self.message_size);
-        let mut messages = Vec::with_capacity(self.messages_per_batch as usize);
-        for _ in 0..self.messages_per_batch {
-            let message = Message::from_str(&payload).unwrap();
-            messages.push(message);
-        }
-
+        let payload = Bytes::from(Self::create_payload(self.message_size));
+        let partitioning = match self.partitions {
+            1 => Partitioning::partition_id(partition_id),
+            _ => Partitioning::balanced(),
+        };
         let mut send_messages = SendMessages {
             stream_id: Identifier::numeric(self.stream_id)?,
             topic_id: Identifier::numeric(topic_id)?,
-            partitioning: Partitioning::partition_id(partition_id),
-            messages,
+            partitioning,
+            messages: Vec::with_capacity(self.batch_sizes.max() as usize),
+            acks: None,
         };
 
         info!(
             "Producer #{} → sending {} messages in {} batches of {} messages...",
-            self.producer_id, total_messages, self.message_batches, self.messages_per_batch
+            self.producer_id, total_messages, self.message_batches, self.batch_sizes
         );
 
         let start_timestamp = Instant::now();
-        let mut latencies: Vec<Duration> = Vec::with_capacity(self.message_batches as usize);
-        for _ in 0..self.message_batches {
+        let mut latencies = create_latency_histogram();
+        let mut total_latency = Duration::ZERO;
+        for batch in 0..self.message_batches {
+            // The messages are created for each batch, as the client-side compression replaces their payloads.
+            // It's cheap, as the payload is shared between the messages.
+            send_messages.messages.clear();
+            for _ in 0..self.batch_sizes.get(batch) {
+                send_messages
+                    .messages
+                    .push(Message::new(None, payload.clone(), None));
+            }
+
             let latency_start = Instant::now();
             client.send_messages(&mut send_messages).await?;
             let latency_end = latency_start.elapsed();
-            latencies.push(latency_end);
+            record_latency(&mut latencies, latency_end);
+            total_latency += latency_end;
         }
         let end_timestamp = Instant::now();
         let duration = end_timestamp - start_timestamp;
-        let average_latency: Duration = latencies.iter().sum::<Duration>() / latencies.len() as u32;
+        let average_latency = total_latency / self.message_batches;
         let total_size_bytes = total_messages * self.message_size as u64;
         let average_throughput = total_size_bytes as f64 / duration.as_secs_f64() / 1e6;
 
         info!(
-        "Producer #{} → sent {} messages in {} batches of {} messages in {:.2} s, total size: {} bytes, average latency: {:.2} ms, average throughput: {:.2} MB/s",
+        "Producer #{} → sent {} messages in {} batches of {} messages in {:.2} s, total size: {} bytes, average latency: {:.2} ms, p99 latency: {:.2} ms, average throughput: {:.2} MB/s",
         self.producer_id,
         total_messages,
         self.message_batches,
-        self.messages_per_batch,
+        self.batch_sizes,
         duration.as_secs_f64(),
         total_size_bytes,
         average_latency.as_secs_f64() * 1000.0,
+        latencies.value_at_quantile(0.99) as f64 / 1000.0,
         average_throughput
     );
 
@@ -101,12 +120,13 @@ impl Producer {
             start_timestamp,
             end_timestamp,
             average_latency,
+            latencies,
             total_size_bytes,
             total_messages,
         })
     }
 
-    fn create_payload(size: u32) -> String {
+    pub fn create_payload(size: u32) -> String {
         let mut payload = String::with_capacity(size as usize);
         for i in 0..size {
             let char = (i % 26 + 97) as u8 as char;
@@ -0,0 +1,148 @@
+use crate::args::simple::BenchmarkKind;
+use crate::batch_sizes::BatchSizes;
+use crate::benchmark_result::{create_latency_histogram, record_latency, BenchmarkResult};
+use crate::client_factory::create_logged_in_client;
+use crate::producer::Producer;
+use bytes::Bytes;
+use iggy::client::MessageClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer as IggyConsumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use integration::test_server::ClientFactory;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::{info, trace};
+
+/// The actor both sending and polling the messages using the same client: it sends a batch,
+/// then polls the next messages (using its own consumer offset) until it gets as many messages as it has sent.
+/// The latency of the batch is the whole round trip, so it includes sending and polling.
+pub struct ProducingConsumer {
+    client_factory: Arc<dyn ClientFactory>,
+    actor_id: u32,
+    stream_id: u32,
+    batch_sizes: BatchSizes,
+    message_batches: u32,
+    message_size: u32,
+    compression: Option<CompressionAlgorithm>,
+}
+
+impl ProducingConsumer {
+    pub fn new(
+        client_factory: Arc<dyn ClientFactory>,
+        actor_id: u32,
+        stream_id: u32,
+        batch_sizes: BatchSizes,
+        message_batches: u32,
+        message_size: u32,
+        compression: Option<CompressionAlgorithm>,
+    ) -> Self {
+        Self {
+            client_factory,
+            actor_id,
+            stream_id,
+            batch_sizes,
+            message_batches,
+            message_size,
+            compression,
+        }
+    }
+
+    pub async fn run(&self) -> Result<BenchmarkResult, IggyError> {
+        let topic_id: u32 = 1;
+        let partition_id: u32 = 1;
+        let total_messages = self.batch_sizes.total_messages(self.message_batches);
+        let client =
+            create_logged_in_client(self.client_factory.as_ref(), self.compression.clone()).await;
+        let payload = Bytes::from(Producer::create_payload(self.message_size));
+        let mut send_messages = SendMessages {
+            stream_id: Identifier::numeric(self.stream_id)?,
+            topic_id: Identifier::numeric(topic_id)?,
+            partitioning: Partitioning::partition_id(partition_id),
+            messages: Vec::with_capacity(self.batch_sizes.max() as usize),
+            acks: None,
+        };
+        let mut poll_messages = PollMessages {
+            consumer: IggyConsumer::new(Identifier::numeric(self.actor_id)?),
+            stream_id: Identifier::numeric(self.stream_id)?,
+            topic_id: Identifier::numeric(topic_id)?,
+            partition_id: Some(partition_id),
+            strategy: PollingStrategy::next(),
+            count: self.batch_sizes.max(),
+            auto_commit: true,
+            filter: None,
+            mode: PollingMode::Consume,
+            max_wait_ms: None,
+            max_bytes: None,
+        };
+
+        info!(
+            "Actor #{} → sending and polling {} messages in {} batches of {} messages...",
+            self.actor_id, total_messages, self.message_batches, self.batch_sizes
+        );
+
+        let start_timestamp = Instant::now();
+        let mut latencies = create_latency_histogram();
+        let mut total_latency = Duration::ZERO;
+        let mut total_size_bytes = 0;
+        for batch in 0..self.message_batches {
+            let batch_size = self.batch_sizes.get(batch);
+            send_messages.messages.clear();
+            for _ in 0..batch_size {
+                send_messages
+                    .messages
+                    .push(Message::new(None, payload.clone(), None));
+            }
+
+            let latency_start = Instant::now();
+            client.send_messages(&mut send_messages).await?;
+            let mut received_messages = 0;
+            while received_messages < batch_size {
+                poll_messages.count = batch_size - received_messages;
+                let polled_messages = client.poll_messages(&poll_messages).await?;
+                if polled_messages.messages.is_empty() {
+                    trace!("Actor #{} → messages are empty, retrying...", self.actor_id);
+                    continue;
+                }
+
+                received_messages += polled_messages.messages.len() as u32;
+                for message in &polled_messages.messages {
+                    total_size_bytes += message.get_size_bytes() as u64;
+                }
+            }
+            let latency_end = latency_start.elapsed();
+            record_latency(&mut latencies, latency_end);
+            total_latency += latency_end;
+        }
+        let end_timestamp = Instant::now();
+        let duration = end_timestamp - start_timestamp;
+        let average_latency = total_latency / self.message_batches;
+        let average_throughput = total_size_bytes as f64 / duration.as_secs_f64() / 1e6;
+
+        info!(
+        "Actor #{} → sent and polled {} messages in {} batches of {} messages in {:.2} s, total size: {} bytes, average latency: {:.2} ms, p99 latency: {:.2} ms, average throughput: {:.2} MB/s",
+        self.actor_id,
+        total_messages,
+        self.message_batches,
+        self.batch_sizes,
+        duration.as_secs_f64(),
+        total_size_bytes,
+        average_latency.as_secs_f64() * 1000.0,
+        latencies.value_at_quantile(0.99) as f64 / 1000.0,
+        average_throughput
+    );
+
+        Ok(BenchmarkResult {
+            kind: BenchmarkKind::Mixed,
+            start_timestamp,
+            end_timestamp,
+            average_latency,
+            latencies,
+            total_size_bytes,
+            total_messages,
+        })
+    }
+}
@@ -0,0 +1,12 @@
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ApplyArgs {
+    /// Path to the definition file (.yaml, .yml, .toml or .json)
+    #[arg(short, long)]
+    pub(crate) file: PathBuf,
+    /// Print the changes without applying them
+    #[arg(long, default_value_t = false)]
+    pub(crate) dry_run: bool,
+}
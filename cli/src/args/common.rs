@@ -1,10 +1,56 @@
-use clap::ValueEnum;
+use clap::{Args, ValueEnum};
 use iggy::cli::client::get_clients::GetClientsOutput;
+use iggy::cli::connectors::get_connectors::GetConnectorsOutput;
 use iggy::cli::consumer_group::get_consumer_groups::GetConsumerGroupsOutput;
+use iggy::cli::context::get_contexts::GetContextsOutput;
 use iggy::cli::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokensOutput;
 use iggy::cli::streams::get_streams::GetStreamsOutput;
 use iggy::cli::topics::get_topics::GetTopicsOutput;
 use iggy::cli::users::get_users::GetUsersOutput;
+use iggy::cli::utils::confirmation::Confirmation;
+use iggy::cli::utils::output_format::OutputFormat;
+use iggy::error::IggyError;
+use iggy::identifier::{Identifier, IdentifierPath};
+
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq)]
+pub(crate) enum OutputFormatArg {
+    Table,
+    Json,
+    Csv,
+}
+
+impl From<OutputFormatArg> for OutputFormat {
+    fn from(format: OutputFormatArg) -> Self {
+        match format {
+            OutputFormatArg::Table => OutputFormat::Table,
+            OutputFormatArg::Json => OutputFormat::Json,
+            OutputFormatArg::Csv => OutputFormat::Csv,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq)]
+pub(crate) enum ErrorFormatArg {
+    Text,
+    Json,
+}
+
+/// Options of the commands removing the data, which ask for the confirmation when run in the terminal
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ConfirmationArgs {
+    /// Print what would be removed without removing it
+    #[clap(long, default_value_t = false)]
+    pub(crate) dry_run: bool,
+    /// Remove without asking for the confirmation
+    #[clap(short, long, default_value_t = false, conflicts_with = "dry_run")]
+    pub(crate) yes: bool,
+}
+
+impl From<&ConfirmationArgs> for Confirmation {
+    fn from(args: &ConfirmationArgs) -> Self {
+        Confirmation::new(args.dry_run, args.yes)
+    }
+}
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub(crate) enum ListMode {
@@ -65,3 +111,38 @@ impl From<ListMode> for GetConsumerGroupsOutput {
         }
     }
 }
+
+impl From<ListMode> for GetConnectorsOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetConnectorsOutput::Table,
+            ListMode::List => GetConnectorsOutput::List,
+        }
+    }
+}
+
+impl From<ListMode> for GetContextsOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetContextsOutput::Table,
+            ListMode::List => GetContextsOutput::List,
+        }
+    }
+}
+
+/// Joins the stream path with the topic and partition given as the separate arguments,
+/// so that both `orders events 3` and `orders/events/3` address the same partition.
+pub(crate) fn join_identifier_path(
+    path: &IdentifierPath,
+    topic_id: &Option<Identifier>,
+    partition_id: Option<u32>,
+) -> Result<IdentifierPath, IggyError> {
+    let mut segments = Vec::new();
+    if let Some(topic_id) = topic_id {
+        segments.push(topic_id.to_string());
+    }
+    if let Some(partition_id) = partition_id {
+        segments.push(partition_id.to_string());
+    }
+    path.join(&segments)
+}
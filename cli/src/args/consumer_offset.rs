@@ -1,5 +1,7 @@
+use crate::args::common::join_identifier_path;
 use clap::{Args, Subcommand};
-use iggy::identifier::Identifier;
+use iggy::error::IggyError;
+use iggy::identifier::{Identifier, IdentifierPath};
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum ConsumerOffsetAction {
@@ -16,6 +18,7 @@ pub(crate) enum ConsumerOffsetAction {
     ///  iggy consumer-offset get consumer stream 5 1
     ///  iggy consumer-offset get consumer 3 topic 1
     ///  iggy consumer-offset get 1 stream topic 1
+    ///  iggy consumer-offset get 1 stream/topic/1
     ///  iggy consumer-offset get consumer stream topic 1
     #[clap(verbatim_doc_comment, visible_alias = "g")]
     Get(ConsumerOffsetGetArgs),
@@ -48,17 +51,27 @@ pub(crate) struct ConsumerOffsetGetArgs {
     pub(crate) consumer_id: Identifier,
     /// Stream ID for which consumer offset is retrieved
     ///
-    /// Stream ID can be specified as a stream name or ID
-    #[arg(value_parser = clap::value_parser!(Identifier))]
-    pub(crate) stream_id: Identifier,
+    /// Stream ID can be specified as a stream name or ID,
+    /// or together with the topic and partition as a path, e.g. orders/events/3
+    #[arg(value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) stream_id: IdentifierPath,
     /// Topic ID for which consumer offset is retrieved
     ///
-    /// Topic ID can be specified as a topic name or ID
+    /// Topic ID can be specified as a topic name or ID,
+    /// it's omitted when given in the stream path
     #[arg(value_parser = clap::value_parser!(Identifier))]
-    pub(crate) topic_id: Identifier,
+    pub(crate) topic_id: Option<Identifier>,
     /// Partitions ID for which consumer offset is retrieved
+    ///
+    /// Partition ID is omitted when given in the stream path
     #[arg(value_parser = clap::value_parser!(u32).range(1..))]
-    pub(crate) partition_id: u32,
+    pub(crate) partition_id: Option<u32>,
+}
+
+impl ConsumerOffsetGetArgs {
+    pub(crate) fn partition(&self) -> Result<(Identifier, Identifier, u32), IggyError> {
+        join_identifier_path(&self.stream_id, &self.topic_id, self.partition_id)?.partition()
+    }
 }
 
 #[derive(Debug, Clone, Args)]
@@ -0,0 +1,108 @@
+use crate::args::common::ListMode;
+use clap::{Args, Subcommand};
+use iggy::cli::context::contexts::Context;
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum ContextAction {
+    /// Add context with given name or update the existing one
+    ///
+    /// Context is a named server profile stored in the contexts.toml file
+    /// in the iggy directory inside the user's configuration directory
+    /// ($XDG_CONFIG_HOME or ~/.config). Options of the active context are used
+    /// by all commands unless they are provided in the command line.
+    ///
+    /// Examples:
+    ///  iggy context add local --server-address 127.0.0.1:8090 --username iggy
+    ///  iggy context add staging --transport quic --server-address 10.0.0.1:8080 --token-name staging
+    ///  iggy context add prod --server-address iggy.example.com:8090 --tls --tls-domain iggy.example.com
+    #[clap(verbatim_doc_comment, visible_alias = "a")]
+    Add(ContextAddArgs),
+    /// Set the context with given name as the active one
+    ///
+    /// Examples:
+    ///  iggy context use staging
+    #[clap(verbatim_doc_comment, visible_alias = "u")]
+    Use(ContextUseArgs),
+    /// List all contexts, the active one is marked with an asterisk
+    ///
+    /// Examples:
+    ///  iggy context list
+    ///  iggy context list --list-mode list
+    ///  iggy context list -l list
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    List(ContextListArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ContextAddArgs {
+    /// Name of the context
+    pub(crate) name: String,
+    /// Server transport
+    #[clap(long, value_parser = ["tcp", "quic", "http"])]
+    pub(crate) transport: Option<String>,
+    /// Server address
+    ///
+    /// Address of the server for the TCP and QUIC transports
+    /// or the API URL for the HTTP transport
+    #[clap(verbatim_doc_comment)]
+    #[clap(long)]
+    pub(crate) server_address: Option<String>,
+    /// Iggy server username
+    #[clap(long)]
+    pub(crate) username: Option<String>,
+    /// Iggy server password
+    ///
+    /// Password is stored in plain text, storing the personal access token
+    /// and using its name should be preferred.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, requires = "username")]
+    pub(crate) password: Option<String>,
+    /// Name of the stored personal access token
+    ///
+    /// Personal access token must be stored in the platform-specific
+    /// secure storage, see "iggy pat create --help" for details.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, conflicts_with = "username")]
+    pub(crate) token_name: Option<String>,
+    /// Enable TLS
+    ///
+    /// TLS is enabled for the TCP transport and the certificate
+    /// is validated for the QUIC transport.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, default_value_t = false)]
+    pub(crate) tls: bool,
+    /// TLS domain or QUIC server name
+    ///
+    /// Domain used for TLS by the TCP transport and
+    /// the server name used by the QUIC transport.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long)]
+    pub(crate) tls_domain: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ContextUseArgs {
+    /// Name of the context
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ContextListArgs {
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+impl From<&ContextAddArgs> for Context {
+    fn from(args: &ContextAddArgs) -> Self {
+        Context {
+            transport: args.transport.clone(),
+            server_address: args.server_address.clone(),
+            username: args.username.clone(),
+            password: args.password.clone(),
+            token_name: args.token_name.clone(),
+            tls_enabled: args.tls.then_some(true),
+            tls_domain: args.tls_domain.clone(),
+        }
+    }
+}
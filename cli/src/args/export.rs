@@ -0,0 +1,26 @@
+use clap::Args;
+use iggy::identifier::Identifier;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ExportArgs {
+    /// Stream ID from which the messages will be exported
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(short, long, value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream: Identifier,
+    /// Topic ID from which the messages will be exported
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(short, long, value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic: Identifier,
+    /// Partition ID from which the messages will be exported, all partitions by default
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: Option<u32>,
+    /// Path to the file to which the messages will be exported
+    #[arg(short, long)]
+    pub(crate) file: PathBuf,
+    /// Number of messages polled in a single request
+    #[arg(short, long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+}
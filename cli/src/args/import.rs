@@ -0,0 +1,23 @@
+use clap::Args;
+use iggy::identifier::Identifier;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ImportArgs {
+    /// Path to the file created by the export command
+    #[arg(short, long)]
+    pub(crate) file: PathBuf,
+    /// Stream ID to which the messages will be imported, the exported stream name by default
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(short, long, value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream: Option<Identifier>,
+    /// Topic ID to which the messages will be imported, the exported topic name by default
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(short, long, value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic: Option<Identifier>,
+    /// Number of messages sent in a single request
+    #[arg(short, long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+}
@@ -1,5 +1,14 @@
-use clap::{ArgGroup, Args, Subcommand};
-use iggy::identifier::Identifier;
+use crate::args::common::join_identifier_path;
+use clap::{ArgGroup, Args, Subcommand, ValueEnum};
+use iggy::cli::message::poll_messages::MessagesFormat;
+use iggy::cli::message::replay_messages::OffsetRange;
+use iggy::error::IggyError;
+use iggy::identifier::{Identifier, IdentifierPath};
+use iggy::messages::headers_filter::HeadersFilter;
+use iggy::messages::messages_query::MessagesQuery;
+use iggy::models::schema::SchemaFormat;
+use iggy::utils::duration::IggyDuration;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum MessageAction {
@@ -25,8 +34,42 @@ pub(crate) enum MessageAction {
     ///  iggy message poll --offset 0 stream 2 1
     ///  iggy message poll --offset 0 1 topic 1
     ///  iggy message poll --offset 0 stream topic 1
+    ///  iggy message poll --offset 0 stream/topic/1
+    ///  iggy message poll --offset 0 --filter "region == eu && type in [created, paid]" stream topic 1
+    ///  iggy message poll --offset 0 --schema-id 1 stream topic 1
+    ///  iggy message poll --offset 0 --schema-file order.proto --message-type Order stream topic 1
+    ///  iggy message poll --last --backward --message-count 50 stream topic 1
+    ///  iggy message poll --first --follow --format json stream topic 1
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Poll(PollMessagesArgs),
+    /// Query messages stored in the partition with the read-only, SQL-like query
+    ///
+    /// Query has the following form (keywords are case-insensitive):
+    /// SELECT <COLUMNS> FROM <STREAM>.<TOPIC>.<PARTITION> [WHERE <CONDITIONS>] [LIMIT <COUNT>]
+    /// Columns can be "*" or any of: offset, timestamp, id, state, checksum,
+    /// length, headers or headers.<KEY>. Conditions are joined with AND
+    /// and use =, !=, <, <=, > or >= operators. Consumer offsets are not affected.
+    ///
+    /// Examples:
+    ///  iggy message query "SELECT * FROM stream.topic.1 LIMIT 10"
+    ///  iggy message query "SELECT offset, headers.region FROM 1.2.1 WHERE offset >= 100"
+    ///  iggy message query "SELECT offset, length FROM stream.topic.1 WHERE timestamp > '2024-01-01T00:00:00Z'"
+    #[clap(verbatim_doc_comment, visible_alias = "q")]
+    Query(QueryMessagesArgs),
+    /// Replay messages with the given offsets from the partition to another topic
+    ///
+    /// Messages are sent again with their payload and headers, the messages
+    /// appended with the key are sent with the same key, otherwise they are
+    /// sent to the partition with the same ID (or to the given partition).
+    /// Offsets are given as START..END (end exclusive), START..=END or START..
+    /// (up to the last message appended before the replay has started).
+    ///
+    /// Examples:
+    ///  iggy message replay --from stream/topic/1 --offsets 1000..2000 --to stream2/topic2
+    ///  iggy message replay --from stream/topic/1 --offsets 1000.. --to stream2/topic2/1 --rate 100
+    ///  iggy message replay --from 1/2/1 --offsets 0..=99 --to 1/3 --replayed-from
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Replay(ReplayMessagesArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -66,20 +109,24 @@ pub(crate) struct SendMessagesArgs {
 pub(crate) struct PollMessagesArgs {
     /// ID of the stream from which message will be polled
     ///
-    /// Stream ID can be specified as a stream name or ID
-    #[arg(value_parser = clap::value_parser!(Identifier))]
-    pub(crate) stream_id: Identifier,
+    /// Stream ID can be specified as a stream name or ID,
+    /// or together with the topic and partition as a path, e.g. orders/events/3
+    #[arg(value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) stream_id: IdentifierPath,
     /// ID of the topic from which message will be polled
     ///
-    /// Topic ID can be specified as a topic name or ID
+    /// Topic ID can be specified as a topic name or ID,
+    /// it's omitted when given in the stream path
     #[arg(value_parser = clap::value_parser!(Identifier))]
-    pub(crate) topic_id: Identifier,
+    pub(crate) topic_id: Option<Identifier>,
     /// Partition ID from which message will be polled
+    ///
+    /// Partition ID is omitted when given in the stream path
     #[arg(value_parser = clap::value_parser!(u32).range(1..))]
-    pub(crate) partition_id: u32,
+    pub(crate) partition_id: Option<u32>,
     /// Number of messages to poll
     #[clap(verbatim_doc_comment)]
-    #[clap(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    #[clap(short, long, alias = "count", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
     pub(crate) message_count: u32,
     /// Auto commit offset
     ///
@@ -115,4 +162,172 @@ pub(crate) struct PollMessagesArgs {
     #[clap(verbatim_doc_comment)]
     #[clap(short, long, default_value_t = Identifier::default(), value_parser = clap::value_parser!(Identifier))]
     pub(crate) consumer: Identifier,
+    /// Filter over the message headers evaluated by the server
+    ///
+    /// Only the messages matching all the conditions are returned.
+    /// Conditions are separated with "&&" and can be one of:
+    /// "key == value", "key ^= prefix" or "key in [value1, value2]"
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_parser = clap::value_parser!(HeadersFilter))]
+    pub(crate) filter: Option<HeadersFilter>,
+    /// Peek messages without storing the consumer offset
+    ///
+    /// Messages are only browsed, so the consumers are not affected.
+    /// Cannot be used with the auto commit.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, default_value_t = false, conflicts_with = "auto_commit")]
+    pub(crate) peek: bool,
+    /// Peek messages backward, starting from the offset or the last message
+    ///
+    /// Messages are printed in the descending order of their offsets
+    /// and the consumer offset is not stored.
+    #[clap(verbatim_doc_comment)]
+    #[clap(
+        long,
+        default_value_t = false,
+        conflicts_with_all = ["auto_commit", "first", "next"]
+    )]
+    pub(crate) backward: bool,
+    /// Decode message payloads with the schema registered under given ID
+    ///
+    /// Payloads are printed as the structured JSON instead of the raw bytes
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, group = "payload_schema")]
+    pub(crate) schema_id: Option<u32>,
+    /// Decode message payloads with the schema read from given file
+    ///
+    /// Payloads are printed as the structured JSON instead of the raw bytes.
+    /// Schema format is inferred from the file extension (.avsc, .proto or .json)
+    /// unless it's specified with the schema format option.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, group = "payload_schema")]
+    pub(crate) schema_file: Option<PathBuf>,
+    /// Format of the schema read from the file
+    #[clap(long, requires = "schema_file")]
+    pub(crate) schema_format: Option<SchemaFormatArg>,
+    /// Name of the Protobuf message type used to decode message payloads
+    ///
+    /// By default the first message defined in the schema is used
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, requires = "payload_schema")]
+    pub(crate) message_type: Option<String>,
+    /// Output format of the polled messages
+    ///
+    /// "table" prints the messages as the table,
+    /// "raw" prints the payload of each message in a separate line,
+    /// "hex" prints the payload encoded as the hexadecimal string and
+    /// "json" prints each message including its headers as the JSON object.
+    /// Messages in these formats are printed also in the quiet mode.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_enum, default_value_t = MessagesFormatArg::Table)]
+    pub(crate) format: MessagesFormatArg,
+    /// Keep polling and printing the new messages until interrupted
+    ///
+    /// After each poll the messages are polled from the offset
+    /// following the last polled message.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, default_value_t = false, conflicts_with = "backward")]
+    pub(crate) follow: bool,
+    /// Interval between polls without new messages
+    ///
+    /// Used only in the follow mode
+    #[clap(verbatim_doc_comment)]
+    #[clap(
+        long,
+        value_name = "INTERVAL",
+        default_value = "1s",
+        requires = "follow"
+    )]
+    pub(crate) follow_interval: IggyDuration,
+}
+
+impl PollMessagesArgs {
+    pub(crate) fn partition(&self) -> Result<(Identifier, Identifier, u32), IggyError> {
+        join_identifier_path(&self.stream_id, &self.topic_id, self.partition_id)?.partition()
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct QueryMessagesArgs {
+    /// Query to execute
+    #[arg(value_parser = clap::value_parser!(MessagesQuery))]
+    pub(crate) query: MessagesQuery,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ReplayMessagesArgs {
+    /// Partition from which the messages will be replayed, e.g. orders/events/3
+    ///
+    /// Stream and topic can be specified as a name or ID
+    #[arg(long, value_name = "STREAM/TOPIC/PARTITION", value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) from: IdentifierPath,
+    /// Range of the offsets to replay, e.g. 1000..2000, 1000..=1999 or 1000..
+    #[arg(long, value_parser = clap::value_parser!(OffsetRange))]
+    pub(crate) offsets: OffsetRange,
+    /// Topic to which the messages will be replayed, optionally with the partition, e.g. orders/replayed/1
+    ///
+    /// Stream and topic can be specified as a name or ID
+    #[arg(long, value_name = "STREAM/TOPIC[/PARTITION]", value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) to: IdentifierPath,
+    /// Number of messages polled and sent in a single request
+    #[arg(short, long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+    /// Maximum number of messages replayed per second, unlimited by default
+    #[arg(short, long, value_name = "MESSAGES_PER_SECOND", value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) rate: Option<u32>,
+    /// Stamp each replayed message with the "replayed-from" header
+    ///
+    /// Value of the header is the stream, topic, partition and offset
+    /// of the original message, e.g. orders/events/3/1000
+    #[clap(verbatim_doc_comment)]
+    #[arg(long, default_value_t = false)]
+    pub(crate) replayed_from: bool,
+}
+
+impl ReplayMessagesArgs {
+    /// Returns the target stream and topic, and the optional target partition.
+    pub(crate) fn target(&self) -> Result<(Identifier, Identifier, Option<u32>), IggyError> {
+        let (stream_id, topic_id) = IdentifierPath {
+            partition_id: None,
+            ..self.to.clone()
+        }
+        .topic()?;
+        Ok((stream_id, topic_id, self.to.partition_id))
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq)]
+pub(crate) enum SchemaFormatArg {
+    Json,
+    Avro,
+    Protobuf,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq)]
+pub(crate) enum MessagesFormatArg {
+    Table,
+    Raw,
+    Hex,
+    Json,
+}
+
+impl From<MessagesFormatArg> for MessagesFormat {
+    fn from(value: MessagesFormatArg) -> Self {
+        match value {
+            MessagesFormatArg::Table => MessagesFormat::Table,
+            MessagesFormatArg::Raw => MessagesFormat::Raw,
+            MessagesFormatArg::Hex => MessagesFormat::Hex,
+            MessagesFormatArg::Json => MessagesFormat::Json,
+        }
+    }
+}
+
+impl From<SchemaFormatArg> for SchemaFormat {
+    fn from(value: SchemaFormatArg) -> Self {
+        match value {
+            SchemaFormatArg::Json => SchemaFormat::Json,
+            SchemaFormatArg::Avro => SchemaFormat::Avro,
+            SchemaFormatArg::Protobuf => SchemaFormat::Protobuf,
+        }
+    }
 }
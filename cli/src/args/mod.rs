@@ -1,7 +1,12 @@
+pub(crate) mod apply;
 pub(crate) mod client;
 pub(crate) mod common;
+pub(crate) mod connector;
 pub(crate) mod consumer_group;
 pub(crate) mod consumer_offset;
+pub(crate) mod context;
+pub(crate) mod export;
+pub(crate) mod import;
 pub(crate) mod message;
 pub(crate) mod partition;
 pub(crate) mod permissions;
@@ -13,16 +18,33 @@ pub(crate) mod user;
 
 use self::user::UserAction;
 use crate::args::{
-    client::ClientAction, consumer_group::ConsumerGroupAction,
-    consumer_offset::ConsumerOffsetAction, message::MessageAction, partition::PartitionAction,
-    personal_access_token::PersonalAccessTokenAction, stream::StreamAction, system::PingArgs,
+    apply::ApplyArgs,
+    client::ClientAction,
+    common::{ErrorFormatArg, OutputFormatArg},
+    connector::ConnectorAction,
+    consumer_group::ConsumerGroupAction,
+    consumer_offset::ConsumerOffsetAction,
+    context::ContextAction,
+    export::ExportArgs,
+    import::ImportArgs,
+    message::MessageAction,
+    partition::PartitionAction,
+    personal_access_token::PersonalAccessTokenAction,
+    stream::StreamAction,
+    system::{LoginArgs, PingArgs, StatsArgs},
     topic::TopicAction,
 };
-use clap::{Args, Command as ClapCommand};
+use crate::credentials::{ENV_IGGY_PASSWORD, ENV_IGGY_USERNAME};
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Args, Command as ClapCommand};
 use clap::{Parser, Subcommand};
 use clap_complete::{generate, Generator, Shell};
 use figlet_rs::FIGfont;
 use iggy::args::Args as IggyArgs;
+use iggy::cli::context::contexts::Context;
+use iggy::client_error::ClientError;
+use iggy::connection_string::ConnectionString;
+use std::env::var;
 use std::path::PathBuf;
 
 const QUIC_TRANSPORT: &str = "quic";
@@ -45,6 +67,30 @@ pub(crate) struct IggyConsoleArgs {
     #[clap(short, long, default_value_t = false)]
     pub(crate) quiet: bool,
 
+    /// Output format of the fetched resources
+    ///
+    /// Used by the commands getting and listing the resources and server statistics.
+    /// "json" and "csv" formats are meant to be piped into other tools, so they are
+    /// printed without the command explanation and also in the quiet mode.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_enum, default_value_t = OutputFormatArg::Table)]
+    pub(crate) output: OutputFormatArg,
+
+    /// Format of the error printed on the standard error output
+    ///
+    /// "json" prints the error as a single JSON object with the error class,
+    /// exit code, message and causes (and the server error code if any).
+    /// Exit code depends on the class of the error:
+    ///  1 - other error
+    ///  2 - invalid or missing command line arguments
+    ///  3 - connection error, server cannot be reached
+    ///  4 - authentication error, invalid credentials or missing permissions
+    ///  5 - resource (e.g. stream or topic) not found
+    ///  6 - validation error, request rejected by the server as invalid
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_enum, default_value_t = ErrorFormatArg::Text)]
+    pub(crate) error_format: ErrorFormatArg,
+
     /// Debug mode (verbose printing to given file)
     #[clap(short, long)]
     pub(crate) debug: Option<PathBuf>,
@@ -73,6 +119,28 @@ pub(crate) struct IggyConsoleArgs {
     #[clap(short = 'n', long, group = "credentials", verbatim_doc_comment)]
     pub(crate) token_name: Option<String>,
 
+    /// Use session stored by the login command
+    ///
+    /// Session token stored inside platform-specific secure storage by
+    /// "iggy login" command is used even if the credentials are provided
+    /// in the environment variables or the context. Without this option
+    /// the stored session is used only when no other credentials are found.
+    #[clap(
+        long,
+        group = "credentials",
+        default_value_t = false,
+        verbatim_doc_comment
+    )]
+    pub(crate) use_stored: bool,
+
+    /// Name of the context used instead of the active one
+    ///
+    /// Server address, transport, TLS options and credentials stored in the context
+    /// are used unless they are provided in the command line.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long)]
+    pub(crate) context: Option<String>,
+
     /// Shell completion generator for iggy command
     ///
     /// Option prints shell completion code on standard output for selected shell.
@@ -114,7 +182,7 @@ pub(crate) enum Command {
     ///
     /// Collect basic Iggy server statistics like number of streams, topics, partitions, etc.
     /// Server OS name, version, etc. are also collected.
-    Stats,
+    Stats(StatsArgs),
     /// personal access token operations
     #[command(subcommand)]
     Pat(PersonalAccessTokenAction),
@@ -130,9 +198,95 @@ pub(crate) enum Command {
     /// consumer offset operations
     #[command(subcommand, visible_alias = "o")]
     ConsumerOffset(ConsumerOffsetAction),
+    /// connector operations
+    ///
+    /// Connectors run inside the server and either append the records from
+    /// the external system to the topic (sources) or write the messages
+    /// consumed from the topic to the external system (sinks).
+    #[command(subcommand, verbatim_doc_comment)]
+    Connector(ConnectorAction),
     /// message operations
     #[command(subcommand, visible_alias = "m")]
     Message(MessageAction),
+    /// context operations
+    ///
+    /// Contexts are named server profiles which allow using iggy command
+    /// without providing the server address and credentials each time.
+    #[command(subcommand, verbatim_doc_comment)]
+    Context(ContextAction),
+    /// interactive shell
+    ///
+    /// Shell connects and logs in to the server once and executes the commands
+    /// typed without the "iggy" prefix using the same connection until "exit"
+    /// or "quit" is typed. Commands history is kept between the sessions and
+    /// the commands, options, stream and topic names are completed with Tab.
+    /// In addition to all commands the shell accepts the shorthand syntax:
+    ///  ls                  - list streams
+    ///  ls <STREAM>         - list topics of the stream
+    ///  ls <STREAM> <TOPIC> - get topic details
+    #[command(verbatim_doc_comment)]
+    Shell,
+    /// apply resources from definition file
+    ///
+    /// Streams, topics, partitions, users and permissions declared in the file
+    /// are created or updated to match the definition. The file has the same
+    /// structure as the server bootstrap definition (see configs/bootstrap.toml)
+    /// and can be written in YAML, TOML or JSON. Resources are matched by their
+    /// names, the ones missing in the file are not deleted.
+    ///
+    /// Examples:
+    ///  iggy apply -f resources.yaml
+    ///  iggy apply -f resources.yaml --dry-run
+    #[command(verbatim_doc_comment)]
+    Apply(ApplyArgs),
+    /// export messages from topic to file
+    ///
+    /// Messages (including their IDs, headers and timestamps) are polled from
+    /// all partitions of the topic, without affecting the consumer offsets,
+    /// and written to the portable file, one JSON object per line. Only the
+    /// messages appended before the export has started are exported.
+    /// Interrupted export continues from where it stopped when run again.
+    ///
+    /// Examples:
+    ///  iggy export --stream stream --topic topic --file dump.iggy
+    ///  iggy export -s 1 -t 2 -p 1 -f dump.iggy
+    #[command(verbatim_doc_comment)]
+    Export(ExportArgs),
+    /// import messages from file to topic
+    ///
+    /// Messages written by the export command are sent in their original order
+    /// to the same partitions (or with the same messages keys) of the topic,
+    /// which must already exist. The original timestamps are kept by the topics
+    /// using the create time as the message timestamp. Interrupted import
+    /// continues from where it stopped when run again.
+    ///
+    /// Examples:
+    ///  iggy import --file dump.iggy
+    ///  iggy import --file dump.iggy --stream stream --topic topic
+    #[command(verbatim_doc_comment)]
+    Import(ImportArgs),
+    /// login to iggy server and store the session
+    ///
+    /// Command logs in using the provided credentials, creates personal access
+    /// token named "iggy-cli-session" and stores it inside platform-specific
+    /// secure storage. Following commands use the stored session when no other
+    /// credentials are provided, so neither password nor token value is kept
+    /// in the shell history or environment variables.
+    ///
+    /// Examples:
+    ///  iggy -u iggy login
+    ///  iggy -u iggy login 7days
+    #[command(verbatim_doc_comment)]
+    Login(LoginArgs),
+    /// logout from iggy server and remove the stored session
+    ///
+    /// Personal access token created by the login command is deleted on the
+    /// server and removed from platform-specific secure storage.
+    ///
+    /// Examples:
+    ///  iggy logout
+    #[command(verbatim_doc_comment)]
+    Logout,
 }
 
 impl IggyConsoleArgs {
@@ -169,6 +323,112 @@ impl IggyConsoleArgs {
         }
     }
 
+    /// Applies the options of given context which were not provided in the command line or in the connection string.
+    pub(crate) fn apply_context(&mut self, matches: &ArgMatches, context: &Context) {
+        let connection_string_provided = self.iggy.connection_string.is_some();
+        let is_default = |id: &str| {
+            !connection_string_provided
+                && matches.value_source(id) != Some(ValueSource::CommandLine)
+        };
+
+        if let Some(transport) = &context.transport {
+            if is_default("transport") {
+                self.iggy.transport = transport.clone();
+            }
+        }
+
+        if let Some(server_address) = &context.server_address {
+            match self.iggy.transport.as_str() {
+                QUIC_TRANSPORT if is_default("quic_server_address") => {
+                    self.iggy.quic_server_address = server_address.clone()
+                }
+                HTTP_TRANSPORT if is_default("http_api_url") => {
+                    self.iggy.http_api_url = server_address.clone()
+                }
+                TCP_TRANSPORT if is_default("tcp_server_address") => {
+                    self.iggy.tcp_server_address = server_address.clone()
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(tls_enabled) = context.tls_enabled {
+            if is_default("tcp_tls_enabled") {
+                self.iggy.tcp_tls_enabled = tls_enabled;
+            }
+            if is_default("quic_validate_certificate") {
+                self.iggy.quic_validate_certificate = tls_enabled;
+            }
+        }
+
+        if let Some(tls_domain) = &context.tls_domain {
+            if is_default("tcp_tls_domain") {
+                self.iggy.tcp_tls_domain = tls_domain.clone();
+            }
+            if is_default("quic_server_name") {
+                self.iggy.quic_server_name = tls_domain.clone();
+            }
+        }
+
+        // Credentials from the command line or environment take precedence over the context ones
+        let credentials_provided = self.username.is_some()
+            || self.token.is_some()
+            || self.token_name.is_some()
+            || self.use_stored
+            || (var(ENV_IGGY_USERNAME).is_ok() && var(ENV_IGGY_PASSWORD).is_ok());
+        if !credentials_provided {
+            self.username = context.username.clone();
+            self.password = context.password.clone();
+            self.token_name = context.token_name.clone();
+        }
+    }
+
+    /// Applies the transport, server address, TLS options and credentials of the connection string (if provided),
+    /// so they take precedence over the context ones. Credentials from the command line or environment are still preferred.
+    pub(crate) fn apply_connection_string(&mut self) -> Result<(), ClientError> {
+        let Some(connection_string) = &self.iggy.connection_string else {
+            return Ok(());
+        };
+
+        let connection_string = connection_string.parse::<ConnectionString>()?;
+        self.iggy.transport = connection_string.transport.clone();
+        match self.iggy.transport.as_str() {
+            QUIC_TRANSPORT => {
+                self.iggy.quic_server_address = connection_string.server_address.clone();
+                if let Some(tls_enabled) = connection_string.tls {
+                    self.iggy.quic_validate_certificate = tls_enabled;
+                }
+                if let Some(tls_domain) = &connection_string.tls_domain {
+                    self.iggy.quic_server_name = tls_domain.clone();
+                }
+            }
+            HTTP_TRANSPORT => self.iggy.http_api_url = connection_string.http_api_url(),
+            TCP_TRANSPORT => {
+                self.iggy.tcp_server_address = connection_string.server_address.clone();
+                if let Some(tls_enabled) = connection_string.tls {
+                    self.iggy.tcp_tls_enabled = tls_enabled;
+                }
+                if let Some(tls_domain) = &connection_string.tls_domain {
+                    self.iggy.tcp_tls_domain = tls_domain.clone();
+                }
+            }
+            _ => {}
+        }
+
+        let credentials_provided = self.username.is_some()
+            || self.token.is_some()
+            || self.token_name.is_some()
+            || self.use_stored
+            || (var(ENV_IGGY_USERNAME).is_ok() && var(ENV_IGGY_PASSWORD).is_ok());
+        if !credentials_provided && connection_string.username.is_some() {
+            self.username = connection_string.username;
+            self.password = connection_string.password;
+            self.token_name = None;
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn generate_completion<G: Generator>(&self, generator: G) {
         generate(
             generator,
@@ -1,5 +1,8 @@
+use crate::args::common::{join_identifier_path, ConfirmationArgs};
 use clap::{Args, Subcommand};
-use iggy::identifier::Identifier;
+use iggy::error::IggyError;
+use iggy::identifier::{Identifier, IdentifierPath};
+use iggy::partitions::delete_partitions::DeletePartitionsMode;
 use std::convert::From;
 
 #[derive(Debug, Clone, Subcommand)]
@@ -15,6 +18,7 @@ pub(crate) enum PartitionAction {
     ///  iggy partition create prod 2 2
     ///  iggy partition create test sensor 2
     ///  iggy partition create 1 sensor 16
+    ///  iggy partition create 1 sensor 2 --repartition
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(PartitionCreateArgs),
     /// Delete partitions for the specified topic ID
@@ -22,14 +26,31 @@ pub(crate) enum PartitionAction {
     ///
     /// Stream ID can be specified as a stream name or ID
     /// Topic ID can be specified as a topic name or ID
+    /// Confirmation is asked when run in the terminal, unless --yes is given
     ///
     /// Examples
     ///  iggy partition delete 1 1 10
     ///  iggy partition delete prod 2 2
     ///  iggy partition delete test sensor 2
     ///  iggy partition delete 1 sensor 16
+    ///  iggy partition delete 1 sensor 2 --mode drain
+    ///  iggy partition delete 1 sensor 2 --mode discard --dry-run
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(PartitionDeleteArgs),
+    /// Get partition details for given partition ID, topic ID and stream ID
+    ///
+    /// Details include the current offset, messages count, size and the segments
+    /// with their offsets, sizes, closed state and message expiry.
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy partition get 1 1 1
+    ///  iggy partition get prod 2 1
+    ///  iggy partition get test sensor 3
+    ///  iggy partition get test/sensor/3
+    #[clap(verbatim_doc_comment, visible_alias = "g")]
+    Get(PartitionGetArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -47,6 +68,13 @@ pub(crate) struct PartitionCreateArgs {
     /// Partitions count to be created
     #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
     pub(crate) partitions_count: u32,
+    /// Copy the keyed messages to their new partitions
+    ///
+    /// The messages appended with the messages key partitioning are copied
+    /// in the background to the partitions their keys are mapped to after the change.
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) repartition: bool,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -64,4 +92,41 @@ pub(crate) struct PartitionDeleteArgs {
     /// Partitions count to be deleted
     #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
     pub(crate) partitions_count: u32,
+    /// Messages handling mode
+    ///
+    /// What happens to the messages stored in the deleted partitions
+    /// "refuse" - the partitions are deleted only if they contain no messages
+    /// "discard" - the messages are deleted along with the partitions
+    /// "drain" - the messages and consumer offsets are moved to the remaining partitions
+    #[arg(short, long, default_value = "refuse", verbatim_doc_comment)]
+    pub(crate) mode: DeletePartitionsMode,
+    #[clap(flatten)]
+    pub(crate) confirmation: ConfirmationArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionGetArgs {
+    /// Stream ID to get partition
+    ///
+    /// Stream ID can be specified as a stream name or ID,
+    /// or together with the topic and partition as a path, e.g. orders/events/3
+    #[arg(value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) stream_id: IdentifierPath,
+    /// Topic ID to get partition
+    ///
+    /// Topic ID can be specified as a topic name or ID,
+    /// it's omitted when given in the stream path
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Option<Identifier>,
+    /// Partition ID to get
+    ///
+    /// Partition ID is omitted when given in the stream path
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: Option<u32>,
+}
+
+impl PartitionGetArgs {
+    pub(crate) fn partition(&self) -> Result<(Identifier, Identifier, u32), IggyError> {
+        join_identifier_path(&self.stream_id, &self.topic_id, self.partition_id)?.partition()
+    }
 }
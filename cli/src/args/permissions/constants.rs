@@ -18,6 +18,8 @@ pub(crate) const POLL_MESSAGES_SHORT: &str = "p_msg";
 pub(crate) const POLL_MESSAGES_LONG: &str = "poll_messages";
 pub(crate) const SEND_MESSAGES_SHORT: &str = "s_msg";
 pub(crate) const SEND_MESSAGES_LONG: &str = "send_messages";
+pub(crate) const AUTO_CREATE_TOPICS_SHORT: &str = "a_top";
+pub(crate) const AUTO_CREATE_TOPICS_LONG: &str = "auto_create_topics";
 pub(crate) const MANAGE_STREAM_SHORT: &str = "m_str";
 pub(crate) const MANAGE_STREAM_LONG: &str = "manage_stream";
 pub(crate) const READ_STREAM_SHORT: &str = "r_str";
@@ -26,3 +28,7 @@ pub(crate) const MANAGE_TOPIC_SHORT: &str = "m_top";
 pub(crate) const MANAGE_TOPIC_LONG: &str = "manage_topic";
 pub(crate) const READ_TOPIC_SHORT: &str = "r_top";
 pub(crate) const READ_TOPIC_LONG: &str = "read_topic";
+pub(crate) const MANAGE_PARTITIONS_SHORT: &str = "m_par";
+pub(crate) const MANAGE_PARTITIONS_LONG: &str = "manage_partitions";
+pub(crate) const MANAGE_CONSUMER_GROUPS_SHORT: &str = "m_cgr";
+pub(crate) const MANAGE_CONSUMER_GROUPS_LONG: &str = "manage_consumer_groups";
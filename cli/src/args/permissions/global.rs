@@ -1,9 +1,9 @@
 use super::constants::{
-    MANAGE_SERVERS_LONG, MANAGE_SERVERS_SHORT, MANAGE_STREAMS_LONG, MANAGE_STREAMS_SHORT,
-    MANAGE_TOPICS_LONG, MANAGE_TOPICS_SHORT, MANAGE_USERS_LONG, MANAGE_USERS_SHORT,
-    POLL_MESSAGES_LONG, POLL_MESSAGES_SHORT, READ_SERVERS_LONG, READ_SERVERS_SHORT,
-    READ_STREAMS_LONG, READ_STREAMS_SHORT, READ_TOPICS_LONG, READ_TOPICS_SHORT, READ_USERS_LONG,
-    READ_USERS_SHORT, SEND_MESSAGES_LONG, SEND_MESSAGES_SHORT,
+    AUTO_CREATE_TOPICS_LONG, AUTO_CREATE_TOPICS_SHORT, MANAGE_SERVERS_LONG, MANAGE_SERVERS_SHORT,
+    MANAGE_STREAMS_LONG, MANAGE_STREAMS_SHORT, MANAGE_TOPICS_LONG, MANAGE_TOPICS_SHORT,
+    MANAGE_USERS_LONG, MANAGE_USERS_SHORT, POLL_MESSAGES_LONG, POLL_MESSAGES_SHORT,
+    READ_SERVERS_LONG, READ_SERVERS_SHORT, READ_STREAMS_LONG, READ_STREAMS_SHORT, READ_TOPICS_LONG,
+    READ_TOPICS_SHORT, READ_USERS_LONG, READ_USERS_SHORT, SEND_MESSAGES_LONG, SEND_MESSAGES_SHORT,
 };
 use iggy::models::permissions::GlobalPermissions;
 use std::str::FromStr;
@@ -20,6 +20,7 @@ pub(super) enum GlobalPermission {
     ReadTopics,
     PollMessages,
     SendMessages,
+    AutoCreateTopics,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -40,6 +41,9 @@ impl FromStr for GlobalPermission {
             READ_TOPICS_SHORT | READ_TOPICS_LONG => Ok(GlobalPermission::ReadTopics),
             POLL_MESSAGES_SHORT | POLL_MESSAGES_LONG => Ok(GlobalPermission::PollMessages),
             SEND_MESSAGES_SHORT | SEND_MESSAGES_LONG => Ok(GlobalPermission::SendMessages),
+            AUTO_CREATE_TOPICS_SHORT | AUTO_CREATE_TOPICS_LONG => {
+                Ok(GlobalPermission::AutoCreateTopics)
+            }
             "" => Err(GlobalPermissionError("[empty]".to_owned())),
             _ => Err(GlobalPermissionError(s.to_owned())),
         }
@@ -82,6 +86,7 @@ impl GlobalPermissionsArg {
             GlobalPermission::ReadTopics => self.permissions.read_topics = true,
             GlobalPermission::PollMessages => self.permissions.poll_messages = true,
             GlobalPermission::SendMessages => self.permissions.send_messages = true,
+            GlobalPermission::AutoCreateTopics => self.permissions.auto_create_topics = true,
         }
     }
 }
@@ -163,6 +168,10 @@ mod tests {
             GlobalPermission::from_str("send_messages").unwrap(),
             GlobalPermission::SendMessages
         );
+        assert_eq!(
+            GlobalPermission::from_str("auto_create_topics").unwrap(),
+            GlobalPermission::AutoCreateTopics
+        );
     }
 
     #[test]
@@ -207,6 +216,10 @@ mod tests {
             GlobalPermission::from_str("s_msg").unwrap(),
             GlobalPermission::SendMessages
         );
+        assert_eq!(
+            GlobalPermission::from_str("a_top").unwrap(),
+            GlobalPermission::AutoCreateTopics
+        );
     }
 
     #[test]
@@ -258,6 +271,7 @@ mod tests {
                     read_topics: true,
                     poll_messages: true,
                     send_messages: true,
+                    auto_create_topics: false,
                 }
             }
         );
@@ -275,6 +289,7 @@ mod tests {
                     read_topics: true,
                     poll_messages: false,
                     send_messages: false,
+                    auto_create_topics: false,
                 }
             }
         );
@@ -292,6 +307,7 @@ mod tests {
                     read_topics: false,
                     poll_messages: false,
                     send_messages: true,
+                    auto_create_topics: false,
                 }
             }
         );
@@ -316,6 +332,7 @@ mod tests {
                     read_topics: true,
                     poll_messages: true,
                     send_messages: true,
+                    auto_create_topics: false,
                 }
             }
         );
@@ -333,6 +350,7 @@ mod tests {
                     read_topics: true,
                     poll_messages: false,
                     send_messages: false,
+                    auto_create_topics: false,
                 }
             }
         );
@@ -350,6 +368,7 @@ mod tests {
                     read_topics: false,
                     poll_messages: false,
                     send_messages: true,
+                    auto_create_topics: false,
                 }
             }
         );
@@ -1,6 +1,7 @@
 use self::{global::GlobalPermissionsArg, stream::StreamPermissionsArg};
 use clap::ValueEnum;
 use iggy::models::{
+    permission_check::PermissionAction,
     permissions::{Permissions, StreamPermissions},
     user_status::UserStatus,
 };
@@ -9,6 +10,7 @@ use std::collections::HashMap;
 pub(crate) mod constants;
 pub(crate) mod global;
 pub(crate) mod stream;
+pub(crate) mod template;
 pub(crate) mod topic;
 
 pub(crate) struct PermissionsArgs {
@@ -75,6 +77,48 @@ impl From<UserStatusArg> for UserStatus {
     }
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq)]
+#[value(rename_all = "snake_case")]
+pub enum PermissionActionArg {
+    ReadServers,
+    ManageServers,
+    ReadUsers,
+    ManageUsers,
+    ReadStreams,
+    CreateStream,
+    ReadStream,
+    ManageStream,
+    ReadTopic,
+    CreateTopic,
+    ManageTopic,
+    PollMessages,
+    SendMessages,
+    ManagePartitions,
+    ManageConsumerGroups,
+}
+
+impl From<PermissionActionArg> for PermissionAction {
+    fn from(value: PermissionActionArg) -> Self {
+        match value {
+            PermissionActionArg::ReadServers => PermissionAction::ReadServers,
+            PermissionActionArg::ManageServers => PermissionAction::ManageServers,
+            PermissionActionArg::ReadUsers => PermissionAction::ReadUsers,
+            PermissionActionArg::ManageUsers => PermissionAction::ManageUsers,
+            PermissionActionArg::ReadStreams => PermissionAction::ReadStreams,
+            PermissionActionArg::CreateStream => PermissionAction::CreateStream,
+            PermissionActionArg::ReadStream => PermissionAction::ReadStream,
+            PermissionActionArg::ManageStream => PermissionAction::ManageStream,
+            PermissionActionArg::ReadTopic => PermissionAction::ReadTopic,
+            PermissionActionArg::CreateTopic => PermissionAction::CreateTopic,
+            PermissionActionArg::ManageTopic => PermissionAction::ManageTopic,
+            PermissionActionArg::PollMessages => PermissionAction::PollMessages,
+            PermissionActionArg::SendMessages => PermissionAction::SendMessages,
+            PermissionActionArg::ManagePartitions => PermissionAction::ManagePartitions,
+            PermissionActionArg::ManageConsumerGroups => PermissionAction::ManageConsumerGroups,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,6 @@
 use super::constants::{
+    AUTO_CREATE_TOPICS_LONG, AUTO_CREATE_TOPICS_SHORT, MANAGE_CONSUMER_GROUPS_LONG,
+    MANAGE_CONSUMER_GROUPS_SHORT, MANAGE_PARTITIONS_LONG, MANAGE_PARTITIONS_SHORT,
     MANAGE_STREAM_LONG, MANAGE_STREAM_SHORT, MANAGE_TOPICS_LONG, MANAGE_TOPICS_SHORT,
     POLL_MESSAGES_LONG, POLL_MESSAGES_SHORT, READ_STREAM_LONG, READ_STREAM_SHORT, READ_TOPICS_LONG,
     READ_TOPICS_SHORT, SEND_MESSAGES_LONG, SEND_MESSAGES_SHORT,
@@ -15,6 +17,9 @@ pub(super) enum StreamPermission {
     ReadTopics,
     PollMessages,
     SendMessages,
+    AutoCreateTopics,
+    ManagePartitions,
+    ManageConsumerGroups,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -31,6 +36,15 @@ impl FromStr for StreamPermission {
             READ_TOPICS_SHORT | READ_TOPICS_LONG => Ok(StreamPermission::ReadTopics),
             POLL_MESSAGES_SHORT | POLL_MESSAGES_LONG => Ok(StreamPermission::PollMessages),
             SEND_MESSAGES_SHORT | SEND_MESSAGES_LONG => Ok(StreamPermission::SendMessages),
+            AUTO_CREATE_TOPICS_SHORT | AUTO_CREATE_TOPICS_LONG => {
+                Ok(StreamPermission::AutoCreateTopics)
+            }
+            MANAGE_PARTITIONS_SHORT | MANAGE_PARTITIONS_LONG => {
+                Ok(StreamPermission::ManagePartitions)
+            }
+            MANAGE_CONSUMER_GROUPS_SHORT | MANAGE_CONSUMER_GROUPS_LONG => {
+                Ok(StreamPermission::ManageConsumerGroups)
+            }
             "" => Err(StreamPermissionError("[empty]".to_owned())),
             _ => Err(StreamPermissionError(s.to_owned())),
         }
@@ -84,6 +98,11 @@ impl StreamPermissionsArg {
             StreamPermission::ReadTopics => self.permissions.read_topics = true,
             StreamPermission::PollMessages => self.permissions.poll_messages = true,
             StreamPermission::SendMessages => self.permissions.send_messages = true,
+            StreamPermission::AutoCreateTopics => self.permissions.auto_create_topics = true,
+            StreamPermission::ManagePartitions => self.permissions.manage_partitions = true,
+            StreamPermission::ManageConsumerGroups => {
+                self.permissions.manage_consumer_groups = true
+            }
         }
     }
 }
@@ -210,6 +229,18 @@ mod tests {
             StreamPermission::from_str("send_messages").unwrap(),
             StreamPermission::SendMessages
         );
+        assert_eq!(
+            StreamPermission::from_str("auto_create_topics").unwrap(),
+            StreamPermission::AutoCreateTopics
+        );
+        assert_eq!(
+            StreamPermission::from_str("manage_partitions").unwrap(),
+            StreamPermission::ManagePartitions
+        );
+        assert_eq!(
+            StreamPermission::from_str("manage_consumer_groups").unwrap(),
+            StreamPermission::ManageConsumerGroups
+        );
     }
 
     #[test]
@@ -238,6 +269,18 @@ mod tests {
             StreamPermission::from_str("s_msg").unwrap(),
             StreamPermission::SendMessages
         );
+        assert_eq!(
+            StreamPermission::from_str("a_top").unwrap(),
+            StreamPermission::AutoCreateTopics
+        );
+        assert_eq!(
+            StreamPermission::from_str("m_par").unwrap(),
+            StreamPermission::ManagePartitions
+        );
+        assert_eq!(
+            StreamPermission::from_str("m_cgr").unwrap(),
+            StreamPermission::ManageConsumerGroups
+        );
     }
 
     #[test]
@@ -289,6 +332,9 @@ mod tests {
                     poll_messages: true,
                     send_messages: true,
                     topics: None,
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -304,6 +350,9 @@ mod tests {
                     poll_messages: false,
                     send_messages: false,
                     topics: None,
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -319,6 +368,9 @@ mod tests {
                     poll_messages: false,
                     send_messages: true,
                     topics: None,
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -334,6 +386,9 @@ mod tests {
                     poll_messages: false,
                     send_messages: false,
                     topics: None,
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -349,6 +404,9 @@ mod tests {
                     poll_messages: false,
                     send_messages: true,
                     topics: None,
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -383,6 +441,9 @@ mod tests {
                             }
                         )
                     ])),
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -418,6 +479,9 @@ mod tests {
                             }
                         )
                     ])),
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -437,6 +501,9 @@ mod tests {
                     poll_messages: true,
                     send_messages: true,
                     topics: None,
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -452,6 +519,9 @@ mod tests {
                     poll_messages: false,
                     send_messages: false,
                     topics: None,
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -467,6 +537,9 @@ mod tests {
                     poll_messages: false,
                     send_messages: true,
                     topics: None,
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -482,6 +555,9 @@ mod tests {
                     poll_messages: false,
                     send_messages: true,
                     topics: None,
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -516,6 +592,9 @@ mod tests {
                             }
                         )
                     ])),
+                    auto_create_topics: false,
+                    manage_partitions: false,
+                    manage_consumer_groups: false,
                 }
             }
         );
@@ -0,0 +1,70 @@
+use super::global::{GlobalPermission, GlobalPermissionsArg};
+use clap::ValueEnum;
+
+/// Presets of the global permissions for the most common user roles.
+#[derive(Debug, Clone, ValueEnum, PartialEq)]
+pub(crate) enum PermissionsTemplateArg {
+    /// read_streams, read_topics and send_messages
+    Producer,
+    /// read_streams, read_topics and poll_messages
+    Consumer,
+    /// all global permissions
+    Admin,
+}
+
+impl From<PermissionsTemplateArg> for GlobalPermissionsArg {
+    fn from(value: PermissionsTemplateArg) -> Self {
+        match value {
+            PermissionsTemplateArg::Producer => GlobalPermissionsArg::new(vec![
+                GlobalPermission::ReadStreams,
+                GlobalPermission::ReadTopics,
+                GlobalPermission::SendMessages,
+            ]),
+            PermissionsTemplateArg::Consumer => GlobalPermissionsArg::new(vec![
+                GlobalPermission::ReadStreams,
+                GlobalPermission::ReadTopics,
+                GlobalPermission::PollMessages,
+            ]),
+            PermissionsTemplateArg::Admin => GlobalPermissionsArg::new(vec![
+                GlobalPermission::ManageServers,
+                GlobalPermission::ReadServers,
+                GlobalPermission::ManageUsers,
+                GlobalPermission::ReadUsers,
+                GlobalPermission::ManageStreams,
+                GlobalPermission::ReadStreams,
+                GlobalPermission::ManageTopics,
+                GlobalPermission::ReadTopics,
+                GlobalPermission::PollMessages,
+                GlobalPermission::SendMessages,
+                GlobalPermission::AutoCreateTopics,
+            ]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::permissions::GlobalPermissions;
+
+    #[test]
+    fn should_convert_producer_template() {
+        let permissions: GlobalPermissions =
+            GlobalPermissionsArg::from(PermissionsTemplateArg::Producer).into();
+
+        assert!(permissions.send_messages);
+        assert!(permissions.read_topics);
+        assert!(!permissions.poll_messages);
+        assert!(!permissions.manage_streams);
+    }
+
+    #[test]
+    fn should_convert_admin_template() {
+        let permissions: GlobalPermissions =
+            GlobalPermissionsArg::from(PermissionsTemplateArg::Admin).into();
+
+        assert!(permissions.manage_servers);
+        assert!(permissions.manage_users);
+        assert!(permissions.auto_create_topics);
+    }
+}
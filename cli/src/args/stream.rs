@@ -1,34 +1,55 @@
-use crate::args::common::ListMode;
+use crate::args::common::{ConfirmationArgs, ListMode};
 use clap::{Args, Subcommand};
+use iggy::cli::utils::message_expiry::MessageExpiry;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
+use iggy::models::topic_defaults::TopicDefaults;
+use iggy::utils::byte_size::IggyByteSize;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum StreamAction {
     /// Create stream with given name
     ///
     /// If stream ID is not provided then the server will automatically assign it
+    /// Topic defaults are inherited by the topics created in the stream without explicit values
     ///
     /// Examples:
     ///  iggy stream create prod
     ///  iggy stream create -s 1 test
+    ///  iggy stream create prod --message-expiry 7days --max-topic-size 10GB
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(StreamCreateArgs),
     /// Delete stream with given ID
     ///
     /// Stream ID can be specified as a stream name or ID
+    /// Soft deleted stream is moved to the trash and can be restored until the trash retention expires
+    /// Confirmation is asked when run in the terminal, unless --yes is given
     ///
     /// Examples:
     ///  iggy stream delete 1
     ///  iggy stream delete test
+    ///  iggy stream delete test --soft
+    ///  iggy stream delete test --dry-run
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(StreamDeleteArgs),
+    /// Restore soft deleted stream with given ID from the trash
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    ///
+    /// Examples:
+    ///  iggy stream undelete 1
+    ///  iggy stream undelete test
+    #[clap(verbatim_doc_comment)]
+    Undelete(StreamUndeleteArgs),
     /// Update stream name for given stream ID
     ///
     /// Stream ID can be specified as a stream name or ID
+    /// Topic defaults are replaced as a whole if any of them is provided, otherwise they are kept
     ///
     /// Examples:
     ///  iggy stream update 1 production
     ///  iggy stream update test development
+    ///  iggy stream update test development --replication-factor 3
     #[clap(verbatim_doc_comment, visible_alias = "u")]
     Update(StreamUpdateArgs),
     /// Get details of a single stream with given ID
@@ -52,10 +73,12 @@ pub(crate) enum StreamAction {
     ///
     /// Command removes all messages from given stream
     /// Stream ID can be specified as a stream name or ID
+    /// Confirmation is asked when run in the terminal, unless --yes is given
     ///
     /// Examples:
     ///  iggy stream purge 1
     ///  iggy stream purge test
+    ///  iggy stream purge test --yes
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Purge(StreamPurgeArgs),
 }
@@ -67,6 +90,8 @@ pub(crate) struct StreamCreateArgs {
     pub(crate) stream_id: Option<u32>,
     /// Name of the stream
     pub(crate) name: String,
+    #[clap(flatten)]
+    pub(crate) topic_defaults: StreamTopicDefaultsArgs,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -75,6 +100,19 @@ pub(crate) struct StreamDeleteArgs {
     ///
     /// Stream ID can be specified as a stream name or ID
     pub(crate) stream_id: Identifier,
+    /// Move the stream to the trash instead of deleting it right away
+    #[clap(long, default_value_t = false)]
+    pub(crate) soft: bool,
+    #[clap(flatten)]
+    pub(crate) confirmation: ConfirmationArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct StreamUndeleteArgs {
+    /// Stream ID to restore from the trash
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    pub(crate) stream_id: Identifier,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -85,6 +123,58 @@ pub(crate) struct StreamUpdateArgs {
     pub(crate) stream_id: Identifier,
     /// New name for the stream
     pub(crate) name: String,
+    #[clap(flatten)]
+    pub(crate) topic_defaults: StreamTopicDefaultsArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct StreamTopicDefaultsArgs {
+    /// Default message expiry of the topics
+    ///
+    /// Human readable format like 15days 2min 2s
+    #[clap(long)]
+    pub(crate) message_expiry: Option<MessageExpiry>,
+    /// Default max size of the topics
+    #[clap(long)]
+    pub(crate) max_topic_size: Option<IggyByteSize>,
+    /// Default compression algorithm of the topics
+    ///
+    /// One of: none, gzip, zstd or lz4, the server default is used if not set
+    #[clap(long)]
+    pub(crate) compression_algorithm: Option<CompressionAlgorithm>,
+    /// Default replication factor of the topics
+    ///
+    /// 1 is used if not set
+    #[clap(long, value_parser = clap::value_parser!(u8).range(1..))]
+    pub(crate) replication_factor: Option<u8>,
+}
+
+impl StreamTopicDefaultsArgs {
+    fn is_empty(&self) -> bool {
+        self.message_expiry.is_none()
+            && self.max_topic_size.is_none()
+            && self.compression_algorithm.is_none()
+            && self.replication_factor.is_none()
+    }
+
+    pub(crate) fn to_topic_defaults(&self) -> TopicDefaults {
+        TopicDefaults {
+            message_expiry: self.message_expiry.as_ref().and_then(Option::<u32>::from),
+            max_topic_size: self
+                .max_topic_size
+                .filter(|max_topic_size| max_topic_size.as_bytes_u64() > 0),
+            compression_algorithm: self.compression_algorithm.clone(),
+            replication_factor: self.replication_factor,
+        }
+    }
+
+    /// Returns the topic defaults only if any of them is provided, so that the current ones are kept otherwise.
+    pub(crate) fn to_updated_topic_defaults(&self) -> Option<TopicDefaults> {
+        match self.is_empty() {
+            true => None,
+            false => Some(self.to_topic_defaults()),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Args)]
@@ -108,4 +198,6 @@ pub(crate) struct StreamPurgeArgs {
     ///
     /// Stream ID can be specified as a stream name or ID
     pub(crate) stream_id: Identifier,
+    #[clap(flatten)]
+    pub(crate) confirmation: ConfirmationArgs,
 }
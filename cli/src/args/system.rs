@@ -1,8 +1,45 @@
 use clap::Args;
+use iggy::cli::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use iggy::utils::duration::IggyDuration;
 
 #[derive(Debug, Clone, Args)]
 pub(crate) struct PingArgs {
     /// Stop after sending count Ping packets
     #[arg(short, long, default_value_t = 1)]
     pub(crate) count: u32,
+    /// Ping server using each of given transports and compare the response times
+    ///
+    /// Transports are used sequentially, each with the server address given
+    /// by the related option (e.g. --tcp-server-address for tcp). Statistics
+    /// of each transport are printed in the selected output format.
+    ///
+    /// Examples:
+    ///  iggy ping -c 10 --transports tcp,quic,http
+    ///  iggy --output json ping -c 10 --transports tcp,http
+    #[clap(verbatim_doc_comment)]
+    #[arg(long, value_delimiter = ',', value_parser = ["tcp", "quic", "http"])]
+    pub(crate) transports: Vec<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct StatsArgs {
+    /// Refresh the stats dashboard with given interval until interrupted
+    ///
+    /// Dashboard shows the server CPU and memory usage, read and write
+    /// throughput calculated from the consecutive stats, clients count
+    /// and sizes of the streams. In the "json" and "csv" output formats
+    /// the stats are printed with given interval instead.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_name = "INTERVAL")]
+    pub(crate) watch: Option<IggyDuration>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct LoginArgs {
+    /// Login session expiry time in human readable format
+    ///
+    /// Expiry time must be expressed in human readable format like 15days 2min 2s
+    /// ("none" or skipping parameter disables login session expiry)
+    #[arg(value_parser = clap::value_parser!(PersonalAccessTokenExpiry))]
+    pub(crate) expiry: Option<Vec<PersonalAccessTokenExpiry>>,
 }
@@ -1,7 +1,10 @@
-use crate::args::common::ListMode;
+use crate::args::common::{join_identifier_path, ConfirmationArgs, ListMode};
 use clap::{Args, Subcommand};
 use iggy::cli::utils::message_expiry::MessageExpiry;
-use iggy::identifier::Identifier;
+use iggy::cli::utils::topic_path::TopicPath;
+use iggy::error::IggyError;
+use iggy::identifier::{Identifier, IdentifierPath};
+use iggy::models::timestamp_type::TimestampType;
 use iggy::utils::byte_size::IggyByteSize;
 use std::convert::From;
 
@@ -23,14 +26,29 @@ pub(crate) enum TopicAction {
     ///
     /// Stream ID can be specified as a stream name or ID
     /// Topic ID can be specified as a topic name or ID
+    /// Confirmation is asked when run in the terminal, unless --yes is given
     ///
     /// Examples
     ///  iggy topic delete 1 1
     ///  iggy topic delete prod 2
     ///  iggy topic delete test debugs
     ///  iggy topic delete 2 debugs
+    ///  iggy topic delete test/debugs
+    ///  iggy topic delete test/debugs --soft
+    ///  iggy topic delete test/debugs --dry-run
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(TopicDeleteArgs),
+    /// Restore soft deleted topic with given ID in given stream ID from the trash
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy topic undelete 1 1
+    ///  iggy topic undelete test debugs
+    ///  iggy topic undelete test/debugs
+    #[clap(verbatim_doc_comment)]
+    Undelete(TopicUndeleteArgs),
     /// Update topic name an message expiry time for given topic ID in given stream ID
     ///
     /// Stream ID can be specified as a stream name or ID
@@ -54,6 +72,7 @@ pub(crate) enum TopicAction {
     ///  iggy topic get prod 2
     ///  iggy topic get test debugs
     ///  iggy topic get 2 debugs
+    ///  iggy topic get test/debugs
     #[clap(verbatim_doc_comment, visible_alias = "g")]
     Get(TopicGetArgs),
     /// List all topics in given stream ID
@@ -70,14 +89,55 @@ pub(crate) enum TopicAction {
     /// Command removes all messages from given topic
     /// Stream ID can be specified as a stream name or ID
     /// Topic ID can be specified as a topic name or ID
+    /// Confirmation is asked when run in the terminal, unless --yes is given
     ///
     /// Examples
     ///  iggy topic purge 1 1
     ///  iggy topic purge prod 2
     ///  iggy topic purge test debugs
     ///  iggy topic purge 2 debugs
+    ///  iggy topic purge test/debugs
+    ///  iggy topic purge test/debugs --yes
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Purge(TopicPurgeArgs),
+    /// Freeze topic with given ID in given stream ID
+    ///
+    /// Frozen topic rejects new messages, while the existing ones can still be polled
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy topic freeze 1 1
+    ///  iggy topic freeze prod 2
+    ///  iggy topic freeze test debugs
+    ///  iggy topic freeze test/debugs
+    #[clap(verbatim_doc_comment)]
+    Freeze(TopicFreezeArgs),
+    /// Unfreeze topic with given ID in given stream ID
+    ///
+    /// Unfrozen topic accepts new messages again
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy topic unfreeze 1 1
+    ///  iggy topic unfreeze prod 2
+    ///  iggy topic unfreeze test debugs
+    ///  iggy topic unfreeze test/debugs
+    #[clap(verbatim_doc_comment)]
+    Unfreeze(TopicUnfreezeArgs),
+    /// Clone topic configuration and optionally its messages to another topic
+    ///
+    /// Topics are specified as stream/topic, stream and topic can be given as a name or ID
+    /// Target stream is created if it does not exist, target topic must not exist
+    /// Target topic can be created on the server of given context
+    ///
+    /// Examples
+    ///  iggy topic clone --from prod/orders --to prod/orders-backup
+    ///  iggy topic clone --from prod/orders --to staging/orders --with-data
+    ///  iggy topic clone --from prod/orders --to prod/orders --to-context staging --with-data
+    #[clap(verbatim_doc_comment)]
+    Clone(TopicCloneArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -103,6 +163,34 @@ pub(crate) struct TopicCreateArgs {
     /// Replication factor for the topic
     #[arg(short, long, default_value = "1")]
     pub(crate) replication_factor: u8,
+    /// Timestamp type
+    ///
+    /// Stored with the messages and used by the time based polling and the message expiry
+    /// "log_append_time" - the time when the message was appended by the server
+    /// "create_time" - the time set by the producer in the "$iggy.create_timestamp" header
+    #[arg(long, default_value = "log_append_time", verbatim_doc_comment)]
+    pub(crate) timestamp_type: TimestampType,
+    /// Max consumer groups in the topic
+    ///
+    /// (skipping parameter allows unlimited number of consumer groups)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..), verbatim_doc_comment)]
+    pub(crate) max_groups: Option<u32>,
+    /// Max members in each consumer group
+    ///
+    /// (skipping parameter allows unlimited number of members)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..), verbatim_doc_comment)]
+    pub(crate) max_members: Option<u32>,
+    /// Max size of the message payload
+    ///
+    /// (skipping parameter uses the default max message size from the server config)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) max_message_size: Option<IggyByteSize>,
+    /// JSON Schema of the message payload
+    ///
+    /// The payloads of the messages sent to the topic must conform to it
+    /// (skipping parameter allows any message payload)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) message_schema: Option<String>,
     /// Message expiry time in human readable format like 15days 2min 2s
     ///
     /// ("unlimited" or skipping parameter disables message expiry functionality in topic)
@@ -114,14 +202,37 @@ pub(crate) struct TopicCreateArgs {
 pub(crate) struct TopicDeleteArgs {
     /// Stream ID to delete topic
     ///
-    /// Stream ID can be specified as a stream name or ID
-    #[arg(value_parser = clap::value_parser!(Identifier))]
-    pub(crate) stream_id: Identifier,
+    /// Stream ID can be specified as a stream name or ID,
+    /// or together with the topic as a path, e.g. orders/events
+    #[arg(value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) stream_id: IdentifierPath,
     /// Topic ID to delete
     ///
-    /// Topic ID can be specified as a topic name or ID
+    /// Topic ID can be specified as a topic name or ID,
+    /// it's omitted when given in the stream path
     #[arg(value_parser = clap::value_parser!(Identifier))]
-    pub(crate) topic_id: Identifier,
+    pub(crate) topic_id: Option<Identifier>,
+    /// Move the topic to the trash instead of deleting it right away
+    #[clap(long, default_value_t = false)]
+    pub(crate) soft: bool,
+    #[clap(flatten)]
+    pub(crate) confirmation: ConfirmationArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicUndeleteArgs {
+    /// Stream ID of the topic to restore
+    ///
+    /// Stream ID can be specified as a stream name or ID,
+    /// or together with the topic as a path, e.g. orders/events
+    #[arg(value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) stream_id: IdentifierPath,
+    /// Topic ID to restore from the trash
+    ///
+    /// Topic ID can be specified as a topic name or ID,
+    /// it's omitted when given in the stream path
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Option<Identifier>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -147,6 +258,37 @@ pub(crate) struct TopicUpdateArgs {
     #[arg(short, long, default_value = "1")]
     /// New replication factor for the topic
     pub(crate) replication_factor: u8,
+    /// New timestamp type
+    ///
+    /// Stored with the messages and used by the time based polling and the message expiry
+    /// "log_append_time" - the time when the message was appended by the server
+    /// "create_time" - the time set by the producer in the "$iggy.create_timestamp" header
+    #[arg(long, default_value = "log_append_time", verbatim_doc_comment)]
+    pub(crate) timestamp_type: TimestampType,
+    /// New max consumer groups in the topic
+    ///
+    /// (skipping parameter causes removal of the limit of consumer groups in topic)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..), verbatim_doc_comment)]
+    pub(crate) max_groups: Option<u32>,
+    /// New max members in each consumer group
+    ///
+    /// (skipping parameter causes removal of the limit of members in topic)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..), verbatim_doc_comment)]
+    pub(crate) max_members: Option<u32>,
+    /// New max size of the message payload
+    ///
+    /// (skipping parameter causes using the default max message size from the server config)
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) max_message_size: Option<IggyByteSize>,
+    /// New JSON Schema of the message payload
+    ///
+    /// The payloads of the messages sent to the topic must conform to it
+    /// (skipping parameter keeps the current message schema of the topic)
+    #[arg(long, conflicts_with = "remove_message_schema", verbatim_doc_comment)]
+    pub(crate) message_schema: Option<String>,
+    /// Remove the message schema of the topic
+    #[arg(long, default_value_t = false)]
+    pub(crate) remove_message_schema: bool,
     /// New message expiry time in human readable format like 15days 2min 2s
     ///
     /// ("unlimited" or skipping parameter causes removal of expiry parameter in topic)
@@ -158,14 +300,16 @@ pub(crate) struct TopicUpdateArgs {
 pub(crate) struct TopicGetArgs {
     /// Stream ID to get topic
     ///
-    /// Stream ID can be specified as a stream name or ID
-    #[arg(value_parser = clap::value_parser!(Identifier))]
-    pub(crate) stream_id: Identifier,
+    /// Stream ID can be specified as a stream name or ID,
+    /// or together with the topic as a path, e.g. orders/events
+    #[arg(value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) stream_id: IdentifierPath,
     /// Topic ID to get
     ///
-    /// Topic ID can be specified as a topic name or ID
+    /// Topic ID can be specified as a topic name or ID,
+    /// it's omitted when given in the stream path
     #[arg(value_parser = clap::value_parser!(Identifier))]
-    pub(crate) topic_id: Identifier,
+    pub(crate) topic_id: Option<Identifier>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -185,12 +329,102 @@ pub(crate) struct TopicListArgs {
 pub(crate) struct TopicPurgeArgs {
     /// Stream ID to purge topic
     ///
-    /// Stream ID can be specified as a stream name or ID
-    #[arg(value_parser = clap::value_parser!(Identifier))]
-    pub(crate) stream_id: Identifier,
+    /// Stream ID can be specified as a stream name or ID,
+    /// or together with the topic as a path, e.g. orders/events
+    #[arg(value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) stream_id: IdentifierPath,
     /// Topic ID to purge
     ///
-    /// Topic ID can be specified as a topic name or ID
+    /// Topic ID can be specified as a topic name or ID,
+    /// it's omitted when given in the stream path
     #[arg(value_parser = clap::value_parser!(Identifier))]
-    pub(crate) topic_id: Identifier,
+    pub(crate) topic_id: Option<Identifier>,
+    #[clap(flatten)]
+    pub(crate) confirmation: ConfirmationArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicFreezeArgs {
+    /// Stream ID to freeze topic
+    ///
+    /// Stream ID can be specified as a stream name or ID,
+    /// or together with the topic as a path, e.g. orders/events
+    #[arg(value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) stream_id: IdentifierPath,
+    /// Topic ID to freeze
+    ///
+    /// Topic ID can be specified as a topic name or ID,
+    /// it's omitted when given in the stream path
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Option<Identifier>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicUnfreezeArgs {
+    /// Stream ID to unfreeze topic
+    ///
+    /// Stream ID can be specified as a stream name or ID,
+    /// or together with the topic as a path, e.g. orders/events
+    #[arg(value_parser = clap::value_parser!(IdentifierPath))]
+    pub(crate) stream_id: IdentifierPath,
+    /// Topic ID to unfreeze
+    ///
+    /// Topic ID can be specified as a topic name or ID,
+    /// it's omitted when given in the stream path
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Option<Identifier>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicCloneArgs {
+    /// Topic to clone given as stream/topic
+    #[arg(long, value_parser = clap::value_parser!(TopicPath))]
+    pub(crate) from: TopicPath,
+    /// Topic to create given as stream/topic
+    #[arg(long, value_parser = clap::value_parser!(TopicPath))]
+    pub(crate) to: TopicPath,
+    /// Copy the messages of the topic preserving their partitions
+    #[arg(long, default_value_t = false)]
+    pub(crate) with_data: bool,
+    /// Name of the context of the server on which the topic is created
+    ///
+    /// Only the connection options and credentials stored in the context are used.
+    #[arg(long, verbatim_doc_comment)]
+    pub(crate) to_context: Option<String>,
+}
+
+impl TopicDeleteArgs {
+    pub(crate) fn topic(&self) -> Result<(Identifier, Identifier), IggyError> {
+        join_identifier_path(&self.stream_id, &self.topic_id, None)?.topic()
+    }
+}
+
+impl TopicUndeleteArgs {
+    pub(crate) fn topic(&self) -> Result<(Identifier, Identifier), IggyError> {
+        join_identifier_path(&self.stream_id, &self.topic_id, None)?.topic()
+    }
+}
+
+impl TopicGetArgs {
+    pub(crate) fn topic(&self) -> Result<(Identifier, Identifier), IggyError> {
+        join_identifier_path(&self.stream_id, &self.topic_id, None)?.topic()
+    }
+}
+
+impl TopicPurgeArgs {
+    pub(crate) fn topic(&self) -> Result<(Identifier, Identifier), IggyError> {
+        join_identifier_path(&self.stream_id, &self.topic_id, None)?.topic()
+    }
+}
+
+impl TopicFreezeArgs {
+    pub(crate) fn topic(&self) -> Result<(Identifier, Identifier), IggyError> {
+        join_identifier_path(&self.stream_id, &self.topic_id, None)?.topic()
+    }
+}
+
+impl TopicUnfreezeArgs {
+    pub(crate) fn topic(&self) -> Result<(Identifier, Identifier), IggyError> {
+        join_identifier_path(&self.stream_id, &self.topic_id, None)?.topic()
+    }
 }
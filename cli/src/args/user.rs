@@ -1,7 +1,9 @@
 use crate::args::common::ListMode;
 use crate::args::permissions::stream::StreamPermissionsArg;
-use crate::args::permissions::UserStatusArg;
+use crate::args::permissions::template::PermissionsTemplateArg;
+use crate::args::permissions::{PermissionActionArg, UserStatusArg};
 use clap::{Args, Subcommand};
+use iggy::cli::utils::user_expiry::UserExpiry;
 use iggy::identifier::Identifier;
 use std::convert::From;
 
@@ -81,8 +83,38 @@ pub(crate) enum UserAction {
     /// Examples:
     ///  iggy user permissions 2
     ///  iggy user permissions client
+    ///  iggy user permissions client --template consumer
+    ///  iggy user permissions edit client
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Permissions(UserPermissionsArgs),
+    /// Check whether action is allowed for user with given ID
+    ///
+    /// The user ID can be specified as either a username or an ID. Action is
+    /// checked against the effective permissions of the user (its own and its
+    /// user groups ones) and the permission which granted or denied it is printed.
+    /// Stream level actions require the stream ID and topic level actions require
+    /// both the stream ID and the topic ID.
+    ///
+    /// Examples:
+    ///  iggy user check 2 read_streams
+    ///  iggy user check client create_topic --stream-id 1
+    ///  iggy user check client poll_messages -s 1 -t 2
+    #[clap(verbatim_doc_comment, visible_alias = "chk")]
+    Check(UserCheckArgs),
+    /// Set expiry and inactivity window for user with given ID
+    ///
+    /// The user ID can be specified as either a username or an ID. User is
+    /// disabled once the expiry time passes or once it hasn't logged in for
+    /// the given number of days. Both settings are replaced, the skipped one
+    /// is removed.
+    ///
+    /// Examples:
+    ///  iggy user expiry 2 30days
+    ///  iggy user expiry contractor 90days --max-inactive-days 14
+    ///  iggy user expiry client none -i 30
+    ///  iggy user expiry client
+    #[clap(verbatim_doc_comment, visible_alias = "e")]
+    Expiry(UserExpiryArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -113,7 +145,8 @@ pub(crate) struct UserCreateArgs {
     /// Available permissions (long and short versions):  manage_servers / m_srv,
     /// read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
     /// manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
-    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg,
+    /// auto_create_topics / a_top
     ///
     /// Examples:
     ///  iggy user create guest guess --global-permissions p_msg,s_msg
@@ -121,6 +154,20 @@ pub(crate) struct UserCreateArgs {
     #[clap(short, long, verbatim_doc_comment)]
     #[arg(value_parser = clap::value_parser!(GlobalPermissionsArg))]
     pub(crate) global_permissions: Option<GlobalPermissionsArg>,
+    /// Set global permissions using template
+    ///
+    /// Template sets the global permissions for the most common user roles
+    /// and cannot be combined with the global permissions option.
+    ///
+    /// Examples:
+    ///  iggy user create sender s3n43r --template producer
+    #[clap(
+        long,
+        value_enum,
+        conflicts_with = "global_permissions",
+        verbatim_doc_comment
+    )]
+    pub(crate) template: Option<PermissionsTemplateArg>,
     /// Set stream permissions for created user
     ///
     /// Stream permissions are defined by each stream separately. Setting permission for stream
@@ -131,7 +178,9 @@ pub(crate) struct UserCreateArgs {
     /// iggy::models::permissions::StreamPermissions) and short variant.
     ///
     /// Available stream permissions: manage_stream / m_str, read_stream / r_str, manage_topics / m_top,
-    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg.
+    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg,
+    /// auto_create_topics / a_top, manage_partitions / m_par,
+    /// manage_consumer_groups / m_cgr.
     ///
     /// For each stream one can set permissions for each topic separately. Topic permissions
     /// are defined for each topic separately. Setting permission for topic allows to set each
@@ -151,6 +200,7 @@ pub(crate) struct UserCreateArgs {
     ///  iggy user create admin p@Ss! --stream-permissions 2:m_str,r_str,m_top,r_top,p_msg,s_msg
     ///  iggy user create sender s3n43r -s 3#1:s_msg#2:s_msg
     ///  iggy user create user1 test12 -s 4:manage_stream,r_top#1:s_msg,p_msg#2:manage_topic
+    ///  iggy user create orders_admin s3cr3t -s 5:manage_topics,manage_partitions,manage_consumer_groups
     #[clap(short, long, verbatim_doc_comment)]
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
@@ -172,6 +222,40 @@ pub(crate) struct UserGetArgs {
     pub(crate) user_id: Identifier,
 }
 
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UserCheckArgs {
+    /// User ID to check
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+    /// Action to check
+    #[arg(value_enum)]
+    pub(crate) action: PermissionActionArg,
+    /// Stream ID, required for stream and topic level actions
+    #[clap(short, long)]
+    pub(crate) stream_id: Option<u32>,
+    /// Topic ID, required for topic level actions
+    #[clap(short, long)]
+    pub(crate) topic_id: Option<u32>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UserExpiryArgs {
+    /// User ID to update
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+    /// User expiry time in human readable format, counted from now
+    ///
+    /// Expiry time must be expressed in human readable format like 15days 2min 2s
+    /// ("none" or skipping parameter disables user expiry)
+    #[arg(value_parser = clap::value_parser!(UserExpiry))]
+    pub(crate) expiry: Option<Vec<UserExpiry>>,
+    /// Number of days without any activity after which the user is disabled
+    #[clap(short = 'i', long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) max_inactive_days: Option<u32>,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct UserListArgs {
     /// List mode (table or list)
@@ -228,11 +312,15 @@ pub(crate) struct UserPasswordArgs {
 }
 
 #[derive(Debug, Clone, Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub(crate) struct UserPermissionsArgs {
+    #[clap(subcommand)]
+    pub(crate) action: Option<UserPermissionsAction>,
     /// User ID to update
     ///
     /// The user ID can be specified as either a username or an ID
-    pub(crate) user_id: Identifier,
+    #[arg(required = true)]
+    pub(crate) user_id: Option<Identifier>,
     /// Set global permissions for created user
     ///
     /// All global permissions by default are set to false and this command line option
@@ -244,7 +332,8 @@ pub(crate) struct UserPermissionsArgs {
     /// Available permissions (long and short versions):  manage_servers / m_srv,
     /// read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
     /// manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
-    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg,
+    /// auto_create_topics / a_top
     ///
     /// Examples:
     ///  iggy user create guest guess --global-permissions p_msg,s_msg
@@ -252,6 +341,20 @@ pub(crate) struct UserPermissionsArgs {
     #[clap(short, long, verbatim_doc_comment)]
     #[arg(value_parser = clap::value_parser!(GlobalPermissionsArg))]
     pub(crate) global_permissions: Option<GlobalPermissionsArg>,
+    /// Set global permissions using template
+    ///
+    /// Template sets the global permissions for the most common user roles
+    /// and cannot be combined with the global permissions option.
+    ///
+    /// Examples:
+    ///  iggy user permissions sender --template producer
+    #[clap(
+        long,
+        value_enum,
+        conflicts_with = "global_permissions",
+        verbatim_doc_comment
+    )]
+    pub(crate) template: Option<PermissionsTemplateArg>,
     /// Set stream permissions for created user
     ///
     /// Stream permissions are defined by each stream separately. Setting permission for stream
@@ -262,7 +365,9 @@ pub(crate) struct UserPermissionsArgs {
     /// iggy::models::permissions::StreamPermissions) and short variant.
     ///
     /// Available stream permissions: manage_stream / m_str, read_stream / r_str, manage_topics / m_top,
-    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg.
+    /// read_topics / r_top, poll_messages / p_msg, send_messages / s_msg,
+    /// auto_create_topics / a_top, manage_partitions / m_par,
+    /// manage_consumer_groups / m_cgr.
     ///
     /// For each stream one can set permissions for each topic separately. Topic permissions
     /// are defined for each topic separately. Setting permission for topic allows to set each
@@ -282,7 +387,35 @@ pub(crate) struct UserPermissionsArgs {
     ///  iggy user create admin p@Ss! --stream-permissions 2:m_str,r_str,m_top,r_top,p_msg,s_msg
     ///  iggy user create sender s3n43r -s 3#1:s_msg#2:s_msg
     ///  iggy user create user1 test12 -s 4:manage_stream,r_top#1:s_msg,p_msg#2:manage_topic
+    ///  iggy user create orders_admin s3cr3t -s 5:manage_topics,manage_partitions,manage_consumer_groups
     #[clap(short, long, verbatim_doc_comment)]
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
 }
+
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum UserPermissionsAction {
+    /// Edit permissions for user with given ID in text editor
+    ///
+    /// Current permissions are opened as TOML document in the editor set by
+    /// VISUAL or EDITOR environment variable (vi by default). After the editor
+    /// is closed the permissions are validated, the changes are printed and
+    /// applied. Permissions are not changed when the document is invalid or
+    /// the editor exits with an error.
+    ///
+    /// The user ID can be specified as either a username or an ID
+    ///
+    /// Examples:
+    ///  iggy user permissions edit 2
+    ///  EDITOR=nano iggy user permissions edit client
+    #[clap(verbatim_doc_comment, visible_alias = "e")]
+    Edit(UserPermissionsEditArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UserPermissionsEditArgs {
+    /// User ID to edit permissions for
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+}
@@ -1,6 +1,7 @@
 use crate::args::IggyConsoleArgs;
 use crate::error::{CmdToolError, IggyCmdError};
 use anyhow::Context;
+use iggy::cli::system::session::ServerSession;
 use iggy::cli_command::PRINT_TARGET;
 use iggy::client::{PersonalAccessTokenClient, UserClient};
 use iggy::clients::client::IggyClient;
@@ -11,8 +12,8 @@ use passterm::{isatty, prompt_password_stdin, prompt_password_tty, Stream};
 use std::env::var;
 use tracing::{event, Level};
 
-static ENV_IGGY_USERNAME: &str = "IGGY_USERNAME";
-static ENV_IGGY_PASSWORD: &str = "IGGY_PASSWORD";
+pub(crate) static ENV_IGGY_USERNAME: &str = "IGGY_USERNAME";
+pub(crate) static ENV_IGGY_PASSWORD: &str = "IGGY_PASSWORD";
 
 struct IggyUserClient {
     username: String,
@@ -60,6 +61,15 @@ impl<'a> IggyCredentials<'a> {
                 }
                 None => Err(IggyCmdError::CmdToolError(CmdToolError::MissingServerAddress).into()),
             }
+        } else if args.use_stored {
+            match Self::get_stored_session(args)? {
+                Some(token) => Ok(Self {
+                    credentials: Some(Credentials::PersonalAccessToken(token)),
+                    iggy_client: None,
+                    login_required,
+                }),
+                None => Err(IggyCmdError::CmdToolError(CmdToolError::NoStoredSession).into()),
+            }
         } else if let Some(token) = &args.token {
             Ok(Self {
                 credentials: Some(Credentials::PersonalAccessToken(token.clone())),
@@ -95,11 +105,28 @@ impl<'a> IggyCredentials<'a> {
                 iggy_client: None,
                 login_required,
             })
+        } else if let Ok(Some(token)) = Self::get_stored_session(args) {
+            Ok(Self {
+                credentials: Some(Credentials::PersonalAccessToken(token)),
+                iggy_client: None,
+                login_required,
+            })
         } else {
             Err(IggyCmdError::CmdToolError(CmdToolError::MissingCredentials).into())
         }
     }
 
+    fn get_stored_session(args: &IggyConsoleArgs) -> anyhow::Result<Option<String>, anyhow::Error> {
+        match args.get_server_address() {
+            Some(server_address) => {
+                event!(target: PRINT_TARGET, Level::DEBUG, "Checking session presence for server: {}",
+                    server_address);
+                ServerSession::new(server_address).get_token()
+            }
+            None => Err(IggyCmdError::CmdToolError(CmdToolError::MissingServerAddress).into()),
+        }
+    }
+
     pub(crate) fn set_iggy_client(&mut self, iggy_client: &'a IggyClient) {
         self.iggy_client = Some(iggy_client);
     }
@@ -1,3 +1,8 @@
+use crate::args::common::ErrorFormatArg;
+use iggy::client_error::ClientError;
+use iggy::error::IggyError;
+use serde_json::{json, Map, Value};
+use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
 use thiserror::Error;
 
@@ -5,6 +10,7 @@ use thiserror::Error;
 pub(crate) enum CmdToolError {
     MissingCredentials,
     MissingServerAddress,
+    NoStoredSession,
 }
 
 impl Display for CmdToolError {
@@ -16,6 +22,12 @@ impl Display for CmdToolError {
             Self::MissingServerAddress => {
                 write!(f, "Missing iggy server address")
             }
+            Self::NoStoredSession => {
+                write!(
+                    f,
+                    "Missing iggy server session, use login command to create it"
+                )
+            }
         }
     }
 }
@@ -34,3 +46,202 @@ pub(crate) enum IggyCmdError {
     #[error("Iggy command line tool error")]
     CmdToolError(#[from] CmdToolError),
 }
+
+/// Class of the failure, each class is reported with its own exit code, so the scripts
+/// can branch on the failure without parsing the error message. The exit codes are stable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ErrorClass {
+    /// Any other failure (exit code 1)
+    Other,
+    /// Invalid or missing command line arguments (exit code 2, also used by the arguments parser)
+    Usage,
+    /// Server cannot be reached or the connection is lost (exit code 3)
+    Connection,
+    /// Missing or invalid credentials or insufficient permissions (exit code 4)
+    Authentication,
+    /// Stream, topic, partition, user or any other resource doesn't exist (exit code 5)
+    NotFound,
+    /// Request rejected by the server as invalid, e.g. a name is taken or a limit is reached (exit code 6)
+    Validation,
+}
+
+impl ErrorClass {
+    pub(crate) fn exit_code(&self) -> u8 {
+        match self {
+            Self::Other => 1,
+            Self::Usage => 2,
+            Self::Connection => 3,
+            Self::Authentication => 4,
+            Self::NotFound => 5,
+            Self::Validation => 6,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Other => "other",
+            Self::Usage => "usage",
+            Self::Connection => "connection",
+            Self::Authentication => "authentication",
+            Self::NotFound => "not_found",
+            Self::Validation => "validation",
+        }
+    }
+
+    /// Classifies the error returned by the server using its code, the errors are the same for all transports.
+    fn from_code(code: u32) -> Self {
+        match IggyError::from_code_as_string(code) {
+            "unauthenticated"
+            | "unauthorized"
+            | "invalid_credentials"
+            | "invalid_username"
+            | "invalid_password"
+            | "user_inactive"
+            | "invalid_personal_access_token"
+            | "personal_access_token_expired"
+            | "jwt_missing"
+            | "refresh_token_missing"
+            | "invalid_refresh_token"
+            | "refresh_token_expired"
+            | "address_not_allowed" => Self::Authentication,
+            "not_connected" => Self::Connection,
+            name if name.ends_with("_not_found") => Self::NotFound,
+            name if name.starts_with("invalid_")
+                || name.ends_with("_already_exists")
+                || name.ends_with("_limit_reached")
+                || name.starts_with("too_")
+                || name.ends_with("_too_large")
+                || name.ends_with("_mismatch") =>
+            {
+                Self::Validation
+            }
+            _ => Self::Other,
+        }
+    }
+
+    fn from_iggy_error(error: &IggyError) -> Self {
+        match error {
+            IggyError::NotConnected
+            | IggyError::IoError(_)
+            | IggyError::WriteError(_)
+            | IggyError::ReadError(_)
+            | IggyError::ReadToEndError(_)
+            | IggyError::ConnectionError(_)
+            | IggyError::CannotCreateEndpoint
+            | IggyError::RequestError(_)
+            | IggyError::RequestMiddlewareError(_) => Self::Connection,
+            IggyError::CannotParseUrl | IggyError::CannotParseAddress(_) => Self::Usage,
+            IggyError::InvalidResponse(code) => Self::from_code(*code),
+            IggyError::HttpResponseError(status, _) => match server_error_code(error) {
+                Some(code) => Self::from_code(code),
+                None => match status {
+                    401 | 403 => Self::Authentication,
+                    404 => Self::NotFound,
+                    400 | 409 | 413 => Self::Validation,
+                    _ => Self::Other,
+                },
+            },
+            error => Self::from_code(error.as_code()),
+        }
+    }
+
+    fn from_client_error(error: &ClientError) -> Option<Self> {
+        match error {
+            ClientError::InvalidCommand
+            | ClientError::InvalidTransport(_)
+            | ClientError::InvalidConnectionString(_) => Some(Self::Usage),
+            ClientError::NoAvailableTransport(_) | ClientError::IoError(_) => {
+                Some(Self::Connection)
+            }
+            // Classified by the wrapped error
+            ClientError::SdkError(_) => None,
+        }
+    }
+}
+
+/// Returns the code of the error returned by the server, HTTP transport returns it in the response body.
+fn server_error_code(error: &IggyError) -> Option<u32> {
+    match error {
+        IggyError::InvalidResponse(code) => Some(*code),
+        IggyError::HttpResponseError(_, body) => serde_json::from_str::<Value>(body)
+            .ok()?
+            .get("id")?
+            .as_u64()
+            .map(|code| code as u32),
+        _ => None,
+    }
+}
+
+impl IggyCmdError {
+    /// Returns the errors from the outermost to the root cause.
+    fn chain(&self) -> impl Iterator<Item = &(dyn Error + 'static)> {
+        std::iter::successors(Some(self as &(dyn Error + 'static)), |&error| {
+            error.source()
+        })
+    }
+
+    /// Classifies the error using the first error in the chain which determines the class.
+    pub(crate) fn class(&self) -> ErrorClass {
+        for error in self.chain() {
+            if let Some(error) = error.downcast_ref::<IggyError>() {
+                return ErrorClass::from_iggy_error(error);
+            }
+            if let Some(class) = error
+                .downcast_ref::<ClientError>()
+                .and_then(ErrorClass::from_client_error)
+            {
+                return class;
+            }
+            if let Some(error) = error.downcast_ref::<CmdToolError>() {
+                return match error {
+                    CmdToolError::MissingCredentials | CmdToolError::NoStoredSession => {
+                        ErrorClass::Authentication
+                    }
+                    CmdToolError::MissingServerAddress => ErrorClass::Usage,
+                };
+            }
+        }
+
+        ErrorClass::Other
+    }
+
+    /// Prints the error on the standard error output in the given format.
+    pub(crate) fn print(&self, format: ErrorFormatArg) {
+        match format {
+            // The same output as for the error returned from main
+            ErrorFormatArg::Text => eprintln!("Error: {self:?}"),
+            ErrorFormatArg::Json => eprintln!("{}", self.to_json()),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let class = self.class();
+        // Errors wrapping the other errors don't say anything about the failure itself
+        let mut messages = self
+            .chain()
+            .filter(|error| {
+                error.downcast_ref::<IggyCmdError>().is_none()
+                    && !matches!(
+                        error.downcast_ref::<ClientError>(),
+                        Some(ClientError::SdkError(_))
+                    )
+            })
+            .map(|error| error.to_string());
+
+        let mut error = Map::new();
+        error.insert("class".into(), json!(class.as_str()));
+        error.insert("exit_code".into(), json!(class.exit_code()));
+        error.insert("message".into(), json!(messages.next()));
+        error.insert("causes".into(), json!(messages.collect::<Vec<_>>()));
+        if let Some(code) = self
+            .chain()
+            .find_map(|error| error.downcast_ref::<IggyError>())
+            .and_then(server_error_code)
+        {
+            error.insert("id".into(), json!(code));
+            error.insert("code".into(), json!(IggyError::from_code_as_string(code)));
+        }
+
+        json!({ "error": error })
+    }
+}
@@ -9,28 +9,23 @@ use tracing_subscriber::{
 
 pub(crate) struct Logging {
     file_guard: Option<WorkerGuard>,
-    stdout_guard: Option<WorkerGuard>,
 }
 
 impl Logging {
     pub(crate) fn new() -> Self {
-        Logging {
-            file_guard: None,
-            stdout_guard: None,
-        }
+        Logging { file_guard: None }
     }
 
     pub(crate) fn init(&mut self, quiet: bool, debug: &Option<PathBuf>) -> &mut Self {
         let mut layers = vec![];
 
         let stdout_filter = filter::filter_fn(|metadata| metadata.target().contains(PRINT_TARGET));
-        let (stdout_writer, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
-
         let stdout_layer = fmt::Layer::default()
             .without_time()
             .with_level(false)
             .with_target(false)
-            .with_writer(stdout_writer)
+            // Blocking writer keeps the output ordered with the prompt of the interactive shell
+            .with_writer(std::io::stdout)
             .with_filter(if quiet {
                 LevelFilter::OFF
             } else {
@@ -38,8 +33,6 @@ impl Logging {
             })
             .boxed();
 
-        self.stdout_guard = Some(stdout_guard);
-
         layers.push(stdout_layer.with_filter(stdout_filter).boxed());
 
         if let Some(file_path) = debug {
@@ -2,22 +2,33 @@ mod args;
 mod credentials;
 mod error;
 mod logging;
+mod shell;
 
 use crate::args::{
-    client::ClientAction, consumer_group::ConsumerGroupAction,
-    consumer_offset::ConsumerOffsetAction, permissions::PermissionsArgs,
+    client::ClientAction, common::OutputFormatArg, connector::ConnectorAction,
+    consumer_group::ConsumerGroupAction, consumer_offset::ConsumerOffsetAction,
+    context::ContextAction, permissions::PermissionsArgs,
     personal_access_token::PersonalAccessTokenAction, stream::StreamAction, topic::TopicAction,
     Command, IggyConsoleArgs,
 };
 use crate::credentials::IggyCredentials;
 use crate::error::IggyCmdError;
 use crate::logging::Logging;
+use crate::shell::ShellCmd;
 use args::message::MessageAction;
 use args::partition::PartitionAction;
-use args::user::UserAction;
-use clap::Parser;
+use args::user::{UserAction, UserPermissionsAction};
+use clap::error::ErrorKind;
+use clap::{ArgMatches, CommandFactory, FromArgMatches};
+use iggy::args::Args as IggyArgs;
 use iggy::cli::{
+    bootstrap::apply_definition::ApplyDefinitionCmd,
     client::{get_client::GetClientCmd, get_clients::GetClientsCmd},
+    connectors::{
+        create_connector::CreateConnectorCmd, delete_connector::DeleteConnectorCmd,
+        get_connector::GetConnectorCmd, get_connectors::GetConnectorsCmd,
+        restart_connector::RestartConnectorCmd,
+    },
     consumer_group::{
         create_consumer_group::CreateConsumerGroupCmd,
         delete_consumer_group::DeleteConsumerGroupCmd, get_consumer_group::GetConsumerGroupCmd,
@@ -26,8 +37,22 @@ use iggy::cli::{
     consumer_offset::{
         get_consumer_offset::GetConsumerOffsetCmd, set_consumer_offset::SetConsumerOffsetCmd,
     },
-    message::{poll_messages::PollMessagesCmd, send_messages::SendMessagesCmd},
-    partitions::{create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd},
+    context::{
+        add_context::AddContextCmd, contexts::Contexts, get_contexts::GetContextsCmd,
+        use_context::UseContextCmd,
+    },
+    message::{
+        export_messages::ExportMessagesCmd,
+        import_messages::ImportMessagesCmd,
+        poll_messages::{PayloadDecoding, PayloadSchema, PollMessagesCmd},
+        query_messages::QueryMessagesCmd,
+        replay_messages::ReplayMessagesCmd,
+        send_messages::SendMessagesCmd,
+    },
+    partitions::{
+        create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd,
+        get_partition::GetPartitionCmd,
+    },
     personal_access_tokens::{
         create_personal_access_token::CreatePersonalAccessTokenCmd,
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
@@ -35,80 +60,161 @@ use iggy::cli::{
     },
     streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
-        get_streams::GetStreamsCmd, purge_stream::PurgeStreamCmd, update_stream::UpdateStreamCmd,
+        get_streams::GetStreamsCmd, purge_stream::PurgeStreamCmd,
+        undelete_stream::UndeleteStreamCmd, update_stream::UpdateStreamCmd,
     },
-    system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
+    system::{login::LoginCmd, logout::LogoutCmd, me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
     topics::{
-        create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
-        get_topics::GetTopicsCmd, purge_topic::PurgeTopicCmd, update_topic::UpdateTopicCmd,
+        clone_topic::CloneTopicCmd, create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd,
+        freeze_topic::FreezeTopicCmd, get_topic::GetTopicCmd, get_topics::GetTopicsCmd,
+        purge_topic::PurgeTopicCmd, undelete_topic::UndeleteTopicCmd,
+        unfreeze_topic::UnfreezeTopicCmd, update_topic::UpdateTopicCmd,
     },
     users::{
         change_password::ChangePasswordCmd,
+        check_permission::CheckPermissionCmd,
         create_user::CreateUserCmd,
         delete_user::DeleteUserCmd,
+        edit_permissions::EditPermissionsCmd,
         get_user::GetUserCmd,
         get_users::GetUsersCmd,
         update_permissions::UpdatePermissionsCmd,
         update_user::{UpdateUserCmd, UpdateUserType},
+        update_user_expiry::UpdateUserExpiryCmd,
+    },
+    utils::{
+        output_format::OutputFormat, personal_access_token_expiry::PersonalAccessTokenExpiry,
+        target_client::TargetClient, user_expiry::UserExpiry,
     },
-    utils::personal_access_token_expiry::PersonalAccessTokenExpiry,
 };
 use iggy::cli_command::{CliCommand, PRINT_TARGET};
+use iggy::client::Client;
 use iggy::client_provider::{self, ClientProviderConfig};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::error::IggyError;
+use iggy::messages::poll_messages::PollingMode;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::update_topic::UpdateTopic;
 use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use std::process::ExitCode;
 use std::sync::Arc;
 use tracing::{event, Level};
 
-fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand> {
+fn get_command(command: Command, args: &IggyConsoleArgs) -> Result<Box<dyn CliCommand>, IggyError> {
+    let format: OutputFormat = args.output.into();
     #[warn(clippy::let_and_return)]
-    match command {
+    Ok(match command {
         Command::Stream(command) => match command {
-            StreamAction::Create(args) => {
-                Box::new(CreateStreamCmd::new(args.stream_id, args.name.clone()))
+            StreamAction::Create(args) => Box::new(CreateStreamCmd::new(
+                args.stream_id,
+                args.name.clone(),
+                args.topic_defaults.to_topic_defaults(),
+            )),
+            StreamAction::Delete(args) => Box::new(DeleteStreamCmd::new(
+                args.stream_id.clone(),
+                args.soft,
+                (&args.confirmation).into(),
+            )),
+            StreamAction::Undelete(args) => {
+                Box::new(UndeleteStreamCmd::new(args.stream_id.clone()))
             }
-            StreamAction::Delete(args) => Box::new(DeleteStreamCmd::new(args.stream_id.clone())),
             StreamAction::Update(args) => Box::new(UpdateStreamCmd::new(
                 args.stream_id.clone(),
                 args.name.clone(),
+                args.topic_defaults.to_updated_topic_defaults(),
+            )),
+            StreamAction::Get(args) => Box::new(GetStreamCmd::new(args.stream_id.clone(), format)),
+            StreamAction::List(args) => Box::new(GetStreamsCmd::new(args.list_mode.into(), format)),
+            StreamAction::Purge(args) => Box::new(PurgeStreamCmd::new(
+                args.stream_id.clone(),
+                (&args.confirmation).into(),
             )),
-            StreamAction::Get(args) => Box::new(GetStreamCmd::new(args.stream_id.clone())),
-            StreamAction::List(args) => Box::new(GetStreamsCmd::new(args.list_mode.into())),
-            StreamAction::Purge(args) => Box::new(PurgeStreamCmd::new(args.stream_id.clone())),
         },
         Command::Topic(command) => match command {
             TopicAction::Create(args) => Box::new(CreateTopicCmd::new(
-                args.stream_id.clone(),
-                args.topic_id,
-                args.partitions_count,
-                args.name.clone(),
+                CreateTopic {
+                    stream_id: args.stream_id.clone(),
+                    topic_id: args.topic_id,
+                    partitions_count: args.partitions_count,
+                    name: args.name.clone(),
+                    max_topic_size: Some(args.max_topic_size),
+                    replication_factor: args.replication_factor,
+                    timestamp_type: args.timestamp_type,
+                    consumer_limits: ConsumerLimits {
+                        max_consumer_groups: args.max_groups,
+                        max_group_members: args.max_members,
+                    },
+                    max_message_size: args.max_message_size,
+                    message_schema: args.message_schema.clone(),
+                    ..CreateTopic::default()
+                },
                 args.message_expiry.clone().into(),
-                args.max_topic_size,
-                args.replication_factor,
-            )),
-            TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
-                args.stream_id.clone(),
-                args.topic_id.clone(),
             )),
+            TopicAction::Delete(args) => {
+                let (stream_id, topic_id) = args.topic()?;
+                Box::new(DeleteTopicCmd::new(
+                    stream_id,
+                    topic_id,
+                    args.soft,
+                    (&args.confirmation).into(),
+                ))
+            }
+            TopicAction::Undelete(args) => {
+                let (stream_id, topic_id) = args.topic()?;
+                Box::new(UndeleteTopicCmd::new(stream_id, topic_id))
+            }
             TopicAction::Update(args) => Box::new(UpdateTopicCmd::new(
-                args.stream_id.clone(),
-                args.topic_id.clone(),
-                args.name.clone(),
+                UpdateTopic {
+                    stream_id: args.stream_id.clone(),
+                    topic_id: args.topic_id.clone(),
+                    name: args.name.clone(),
+                    max_topic_size: Some(args.max_topic_size),
+                    replication_factor: args.replication_factor,
+                    timestamp_type: args.timestamp_type,
+                    consumer_limits: ConsumerLimits {
+                        max_consumer_groups: args.max_groups,
+                        max_group_members: args.max_members,
+                    },
+                    max_message_size: args.max_message_size,
+                    message_schema: args.message_schema.clone(),
+                    ..UpdateTopic::default()
+                },
                 args.message_expiry.clone().into(),
-                args.max_topic_size,
-                args.replication_factor,
-            )),
-            TopicAction::Get(args) => Box::new(GetTopicCmd::new(
-                args.stream_id.clone(),
-                args.topic_id.clone(),
+                args.message_schema.is_none() && !args.remove_message_schema,
             )),
+            TopicAction::Get(args) => {
+                let (stream_id, topic_id) = args.topic()?;
+                Box::new(GetTopicCmd::new(stream_id, topic_id, format))
+            }
             TopicAction::List(args) => Box::new(GetTopicsCmd::new(
                 args.stream_id.clone(),
                 args.list_mode.into(),
+                format,
             )),
-            TopicAction::Purge(args) => Box::new(PurgeTopicCmd::new(
-                args.stream_id.clone(),
-                args.topic_id.clone(),
+            TopicAction::Purge(args) => {
+                let (stream_id, topic_id) = args.topic()?;
+                Box::new(PurgeTopicCmd::new(
+                    stream_id,
+                    topic_id,
+                    (&args.confirmation).into(),
+                ))
+            }
+            TopicAction::Freeze(args) => {
+                let (stream_id, topic_id) = args.topic()?;
+                Box::new(FreezeTopicCmd::new(stream_id, topic_id))
+            }
+            TopicAction::Unfreeze(args) => {
+                let (stream_id, topic_id) = args.topic()?;
+                Box::new(UnfreezeTopicCmd::new(stream_id, topic_id))
+            }
+            TopicAction::Clone(args) => Box::new(CloneTopicCmd::new(
+                args.from.clone(),
+                args.to.clone(),
+                args.with_data,
+                args.to_context
+                    .clone()
+                    .map(|name| (format!("context: {name}"), get_context_client(name))),
             )),
         },
         Command::Partition(command) => match command {
@@ -116,16 +222,42 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 args.stream_id.clone(),
                 args.topic_id.clone(),
                 args.partitions_count,
+                args.repartition,
             )),
             PartitionAction::Delete(args) => Box::new(DeletePartitionsCmd::new(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
                 args.partitions_count,
+                args.mode,
+                (&args.confirmation).into(),
             )),
+            PartitionAction::Get(args) => {
+                let (stream_id, topic_id, partition_id) = args.partition()?;
+                Box::new(GetPartitionCmd::new(
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    format,
+                ))
+            }
         },
-        Command::Ping(args) => Box::new(PingCmd::new(args.count)),
-        Command::Me => Box::new(GetMeCmd::new()),
-        Command::Stats => Box::new(GetStatsCmd::new()),
+        Command::Ping(ping_args) => Box::new(PingCmd::new(
+            ping_args.count,
+            format,
+            args.iggy.transport.clone(),
+            ping_args
+                .transports
+                .iter()
+                .map(|transport| {
+                    (
+                        transport.clone(),
+                        get_transport_client(&args.iggy, transport.clone()),
+                    )
+                })
+                .collect(),
+        )),
+        Command::Me => Box::new(GetMeCmd::new(format)),
+        Command::Stats(stats_args) => Box::new(GetStatsCmd::new(format, stats_args.watch)),
         Command::Pat(command) => match command {
             PersonalAccessTokenAction::Create(pat_create_args) => {
                 Box::new(CreatePersonalAccessTokenCmd::new(
@@ -152,7 +284,10 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 create_args.password.clone(),
                 create_args.user_status.clone().into(),
                 PermissionsArgs::new(
-                    create_args.global_permissions.clone(),
+                    create_args
+                        .global_permissions
+                        .clone()
+                        .or(create_args.template.clone().map(Into::into)),
                     create_args.stream_permissions.clone(),
                 )
                 .into(),
@@ -160,8 +295,24 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
             UserAction::Delete(delete_args) => {
                 Box::new(DeleteUserCmd::new(delete_args.user_id.clone()))
             }
-            UserAction::Get(get_args) => Box::new(GetUserCmd::new(get_args.user_id.clone())),
-            UserAction::List(list_args) => Box::new(GetUsersCmd::new(list_args.list_mode.into())),
+            UserAction::Get(get_args) => {
+                Box::new(GetUserCmd::new(get_args.user_id.clone(), format))
+            }
+            UserAction::Check(check_args) => Box::new(CheckPermissionCmd::new(
+                check_args.user_id,
+                check_args.action.into(),
+                check_args.stream_id,
+                check_args.topic_id,
+                format,
+            )),
+            UserAction::Expiry(expiry_args) => Box::new(UpdateUserExpiryCmd::new(
+                expiry_args.user_id,
+                UserExpiry::new(expiry_args.expiry),
+                expiry_args.max_inactive_days,
+            )),
+            UserAction::List(list_args) => {
+                Box::new(GetUsersCmd::new(list_args.list_mode.into(), format))
+            }
             UserAction::Name(name_args) => Box::new(UpdateUserCmd::new(
                 name_args.user_id.clone(),
                 UpdateUserType::Name(name_args.username.clone()),
@@ -175,19 +326,26 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 change_pwd_args.current_password,
                 change_pwd_args.new_password,
             )),
-            UserAction::Permissions(permissions_args) => Box::new(UpdatePermissionsCmd::new(
-                permissions_args.user_id.clone(),
-                PermissionsArgs::new(
-                    permissions_args.global_permissions.clone(),
-                    permissions_args.stream_permissions.clone(),
-                )
-                .into(),
-            )),
+            UserAction::Permissions(permissions_args) => match permissions_args.action {
+                Some(UserPermissionsAction::Edit(edit_args)) => {
+                    Box::new(EditPermissionsCmd::new(edit_args.user_id))
+                }
+                None => Box::new(UpdatePermissionsCmd::new(
+                    permissions_args.user_id.unwrap(),
+                    PermissionsArgs::new(
+                        permissions_args
+                            .global_permissions
+                            .or(permissions_args.template.map(Into::into)),
+                        permissions_args.stream_permissions,
+                    )
+                    .into(),
+                )),
+            },
         },
         Command::Client(command) => match command {
-            ClientAction::Get(get_args) => Box::new(GetClientCmd::new(get_args.client_id)),
+            ClientAction::Get(get_args) => Box::new(GetClientCmd::new(get_args.client_id, format)),
             ClientAction::List(list_args) => {
-                Box::new(GetClientsCmd::new(list_args.list_mode.into()))
+                Box::new(GetClientsCmd::new(list_args.list_mode.into(), format))
             }
         },
         Command::ConsumerGroup(command) => match command {
@@ -206,12 +364,35 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 get_args.stream_id.clone(),
                 get_args.topic_id.clone(),
                 get_args.consumer_group_id.clone(),
+                format,
             )),
             ConsumerGroupAction::List(list_args) => Box::new(GetConsumerGroupsCmd::new(
                 list_args.stream_id.clone(),
                 list_args.topic_id.clone(),
                 list_args.list_mode.into(),
+                format,
+            )),
+        },
+        Command::Connector(command) => match command {
+            ConnectorAction::Create(create_args) => Box::new(CreateConnectorCmd::new(
+                create_args.name.clone(),
+                create_args.kind.clone(),
+                create_args.stream.clone(),
+                create_args.topic.clone(),
+                create_args.properties.iter().cloned().collect(),
             )),
+            ConnectorAction::Delete(delete_args) => {
+                Box::new(DeleteConnectorCmd::new(delete_args.name.clone()))
+            }
+            ConnectorAction::Get(get_args) => {
+                Box::new(GetConnectorCmd::new(get_args.name.clone(), format))
+            }
+            ConnectorAction::List(list_args) => {
+                Box::new(GetConnectorsCmd::new(list_args.list_mode.into(), format))
+            }
+            ConnectorAction::Restart(restart_args) => {
+                Box::new(RestartConnectorCmd::new(restart_args.name.clone()))
+            }
         },
         Command::Message(command) => match command {
             MessageAction::Send(send_args) => Box::new(SendMessagesCmd::new(
@@ -221,26 +402,74 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 send_args.message_key.clone(),
                 send_args.messages.clone(),
             )),
-            MessageAction::Poll(poll_args) => Box::new(PollMessagesCmd::new(
-                poll_args.stream_id.clone(),
-                poll_args.topic_id.clone(),
-                poll_args.partition_id,
-                poll_args.message_count,
-                poll_args.auto_commit,
-                poll_args.offset,
-                poll_args.first,
-                poll_args.last,
-                poll_args.next,
-                poll_args.consumer.clone(),
-            )),
+            MessageAction::Poll(poll_args) => {
+                let (stream_id, topic_id, partition_id) = poll_args.partition()?;
+                Box::new(PollMessagesCmd::new(
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    poll_args.message_count,
+                    poll_args.auto_commit,
+                    poll_args.offset,
+                    poll_args.first,
+                    poll_args.last,
+                    poll_args.next,
+                    poll_args.consumer.clone(),
+                    poll_args.filter.clone(),
+                    match (poll_args.peek, poll_args.backward) {
+                        (_, true) => PollingMode::PeekBackward,
+                        (true, false) => PollingMode::Peek,
+                        (false, false) => PollingMode::Consume,
+                    },
+                    match (poll_args.schema_id, &poll_args.schema_file) {
+                        (Some(schema_id), _) => Some(PayloadDecoding {
+                            schema: PayloadSchema::Registered(schema_id),
+                            message_type: poll_args.message_type.clone(),
+                        }),
+                        (None, Some(schema_file)) => Some(PayloadDecoding {
+                            schema: PayloadSchema::File(
+                                schema_file.clone(),
+                                poll_args.schema_format.map(Into::into),
+                            ),
+                            message_type: poll_args.message_type.clone(),
+                        }),
+                        (None, None) => None,
+                    },
+                    poll_args.format.into(),
+                    poll_args.follow.then_some(poll_args.follow_interval),
+                ))
+            }
+            MessageAction::Query(query_args) => {
+                Box::new(QueryMessagesCmd::new(query_args.query.clone()))
+            }
+            MessageAction::Replay(replay_args) => {
+                let (stream_id, topic_id, partition_id) = replay_args.from.partition()?;
+                let (target_stream_id, target_topic_id, target_partition_id) =
+                    replay_args.target()?;
+                Box::new(ReplayMessagesCmd::new(
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                    replay_args.offsets,
+                    target_stream_id,
+                    target_topic_id,
+                    target_partition_id,
+                    replay_args.batch_size,
+                    replay_args.rate,
+                    replay_args.replayed_from,
+                ))
+            }
         },
         Command::ConsumerOffset(command) => match command {
-            ConsumerOffsetAction::Get(get_args) => Box::new(GetConsumerOffsetCmd::new(
-                get_args.consumer_id.clone(),
-                get_args.stream_id.clone(),
-                get_args.topic_id.clone(),
-                get_args.partition_id,
-            )),
+            ConsumerOffsetAction::Get(get_args) => {
+                let (stream_id, topic_id, partition_id) = get_args.partition()?;
+                Box::new(GetConsumerOffsetCmd::new(
+                    get_args.consumer_id.clone(),
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                ))
+            }
             ConsumerOffsetAction::Set(set_args) => Box::new(SetConsumerOffsetCmd::new(
                 set_args.consumer_id.clone(),
                 set_args.stream_id.clone(),
@@ -249,13 +478,137 @@ fn get_command(command: Command, args: &IggyConsoleArgs) -> Box<dyn CliCommand>
                 set_args.offset,
             )),
         },
+        Command::Context(command) => match command {
+            ContextAction::Add(add_args) => Box::new(AddContextCmd::new(
+                add_args.name.clone(),
+                (&add_args).into(),
+            )),
+            ContextAction::Use(use_args) => Box::new(UseContextCmd::new(use_args.name.clone())),
+            ContextAction::List(list_args) => {
+                Box::new(GetContextsCmd::new(list_args.list_mode.into()))
+            }
+        },
+        Command::Shell => Box::new(ShellCmd::new(args.iggy.clone())),
+        Command::Apply(apply_args) => Box::new(ApplyDefinitionCmd::new(
+            apply_args.file.clone(),
+            apply_args.dry_run,
+        )),
+        Command::Export(export_args) => Box::new(ExportMessagesCmd::new(
+            export_args.stream.clone(),
+            export_args.topic.clone(),
+            export_args.partition_id,
+            export_args.file.clone(),
+            export_args.batch_size,
+        )),
+        Command::Import(import_args) => Box::new(ImportMessagesCmd::new(
+            import_args.stream.clone(),
+            import_args.topic.clone(),
+            import_args.file.clone(),
+            import_args.batch_size,
+        )),
+        Command::Login(login_args) => Box::new(LoginCmd::new(
+            args.get_server_address().unwrap(),
+            PersonalAccessTokenExpiry::new(login_args.expiry.clone()),
+        )),
+        Command::Logout => Box::new(LogoutCmd::new(args.get_server_address().unwrap())),
+    })
+}
+
+fn apply_context(
+    args: &mut IggyConsoleArgs,
+    matches: &ArgMatches,
+) -> anyhow::Result<(), anyhow::Error> {
+    let contexts = match (Contexts::default_path(), &args.context) {
+        (Ok(path), _) => Contexts::load(&path)?,
+        (Err(error), Some(_)) => return Err(error),
+        (Err(_), None) => return Ok(()),
+    };
+
+    if let Some(context) = contexts.get(args.context.as_deref())? {
+        args.apply_context(matches, context);
     }
+
+    Ok(())
+}
+
+async fn create_client(args: &IggyArgs) -> Result<IggyClient, IggyCmdError> {
+    let encryptor: Option<Box<dyn Encryptor>> = match args.encryption_key.is_empty() {
+        true => None,
+        false => Some(Box::new(
+            Aes256GcmEncryptor::from_base64_key(&args.encryption_key).unwrap(),
+        )),
+    };
+    let client_provider_config = Arc::new(ClientProviderConfig::from_args(args.clone())?);
+    let client = client_provider::get_raw_client(client_provider_config).await?;
+
+    Ok(IggyClient::create(
+        client,
+        IggyClientConfig::default(),
+        None,
+        None,
+        encryptor,
+    ))
+}
+
+/// Returns the connection to the server of given context used by the commands operating
+/// on two servers, only the options stored in the context are used to connect and log in.
+fn get_context_client(name: String) -> TargetClient {
+    Box::new(move || {
+        Box::pin(async move {
+            let matches =
+                IggyConsoleArgs::command().try_get_matches_from([env!("CARGO_BIN_NAME")])?;
+            let mut args = IggyConsoleArgs::from_arg_matches(&matches)?;
+            args.iggy.connection_string = None;
+            let contexts = Contexts::load(&Contexts::default_path()?)?;
+            if let Some(context) = contexts.get(Some(&name))? {
+                args.apply_context(&matches, context);
+                // Credentials from the environment are meant for the current server, not the context one
+                if context.username.is_some() || context.token_name.is_some() {
+                    args.username = context.username.clone();
+                    args.password = context.password.clone();
+                    args.token_name = context.token_name.clone();
+                }
+            }
+
+            let client = create_client(&args.iggy).await?;
+            let mut credentials = IggyCredentials::new(&args, true)?;
+            credentials.set_iggy_client(&client);
+            credentials.login_user().await?;
+
+            Ok(Box::new(client) as Box<dyn Client>)
+        })
+    })
+}
+
+/// Returns the connection to the server using given transport, the connection options
+/// are the same as for the default transport.
+fn get_transport_client(args: &IggyArgs, transport: String) -> TargetClient {
+    let args = IggyArgs {
+        transport,
+        connection_string: None,
+        ..args.clone()
+    };
+    Box::new(move || {
+        Box::pin(async move { Ok(Box::new(create_client(&args).await?) as Box<dyn Client>) })
+    })
 }
 
 #[tokio::main]
-async fn main() -> Result<(), IggyCmdError> {
-    let args = IggyConsoleArgs::parse();
+async fn main() -> ExitCode {
+    let matches = IggyConsoleArgs::command().get_matches();
+    let args = IggyConsoleArgs::from_arg_matches(&matches).unwrap_or_else(|error| error.exit());
+    let error_format = args.error_format;
+
+    match run(args, &matches).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            error.print(error_format);
+            ExitCode::from(error.class().exit_code())
+        }
+    }
+}
 
+async fn run(mut args: IggyConsoleArgs, matches: &ArgMatches) -> Result<(), IggyCmdError> {
     if let Some(generator) = args.generator {
         args.generate_completion(generator);
         return Ok(());
@@ -271,30 +624,39 @@ async fn main() -> Result<(), IggyCmdError> {
 
     let command = args.command.clone().unwrap();
 
+    // Options of the connection string and then of the selected or active context are used
+    // unless they're provided in the command line
+    if !matches!(command, Command::Context(_)) {
+        args.apply_connection_string()?;
+        apply_context(&mut args, matches)?;
+    }
+
     // Get command based on command line arguments
-    let mut command = get_command(command, &args);
+    let mut command = get_command(command, &args).unwrap_or_else(|error| {
+        IggyConsoleArgs::command()
+            .error(ErrorKind::ValueValidation, error)
+            .exit()
+    });
 
     // Create credentials based on command line arguments and command
     let mut credentials = IggyCredentials::new(&args, command.login_required())?;
 
-    let encryptor: Option<Box<dyn Encryptor>> = match args.iggy.encryption_key.is_empty() {
-        true => None,
-        false => Some(Box::new(
-            Aes256GcmEncryptor::from_base64_key(&args.iggy.encryption_key).unwrap(),
-        )),
+    let client = match command.connection_required() {
+        true => create_client(&args.iggy).await?,
+        // Commands managing the local configuration don't communicate with the server
+        false => IggyClient::default(),
     };
-    let client_provider_config = Arc::new(ClientProviderConfig::from_args(args.iggy.clone())?);
-
-    let client = client_provider::get_raw_client(client_provider_config).await?;
-    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, encryptor);
 
     credentials.set_iggy_client(&client);
     credentials.login_user().await?;
 
-    if command.use_tracing() {
-        event!(target: PRINT_TARGET, Level::INFO, "Executing {}", command.explain());
-    } else {
-        println!("Executing {}", command.explain());
+    // Output in the JSON and CSV formats is piped into other tools, so it must not be prefixed
+    if args.output == OutputFormatArg::Table {
+        if command.use_tracing() {
+            event!(target: PRINT_TARGET, Level::INFO, "Executing {}", command.explain());
+        } else {
+            println!("Executing {}", command.explain());
+        }
     }
     command.execute_cmd(&client).await?;
 
@@ -0,0 +1,355 @@
+use crate::args::{common::OutputFormatArg, Command, IggyConsoleArgs};
+use crate::get_command;
+use anyhow::Error;
+use async_trait::async_trait;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+use iggy::args::Args as IggyArgs;
+use iggy::cli::context::contexts::Contexts;
+use iggy::cli_command::{CliCommand, PRINT_TARGET};
+use iggy::client::Client;
+use iggy::identifier::Identifier;
+use iggy::streams::get_streams::GetStreams;
+use iggy::topics::get_topics::GetTopics;
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Editor, Helper};
+use std::fs;
+use std::path::PathBuf;
+use tokio::runtime::Handle;
+use tracing::{event, Level};
+
+static PROMPT: &str = "iggy> ";
+static HISTORY_FILE: &str = "shell_history";
+static EXIT_COMMANDS: [&str; 2] = ["exit", "quit"];
+static LIST_SHORTHAND: &str = "ls";
+// Commands which take the stream ID as the first and the topic ID as the second argument
+static STREAM_TOPIC_COMMANDS: [&str; 5] =
+    ["stream", "topic", "partition", "consumer-group", "message"];
+
+/// Interactive shell executing the commands using the single connection
+/// to the server established (and authenticated) when the shell is started.
+pub(crate) struct ShellCmd {
+    iggy: IggyArgs,
+}
+
+impl ShellCmd {
+    pub(crate) fn new(iggy: IggyArgs) -> Self {
+        Self { iggy }
+    }
+
+    fn run(&self, client: &dyn Client) -> anyhow::Result<(), Error> {
+        let mut editor = Editor::<ShellHelper, DefaultHistory>::new()?;
+        editor.set_helper(Some(ShellHelper::new(client)));
+
+        let history = history_path();
+        if let Some(history) = &history {
+            // Missing history file is expected when the shell is used for the first time
+            let _ = editor.load_history(history);
+        }
+
+        loop {
+            let line = match editor.readline(PROMPT) {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(error) => return Err(error.into()),
+            };
+
+            let words = match shlex::split(&line) {
+                Some(words) => words,
+                None => {
+                    eprintln!("Error: unbalanced quotes in: {line}");
+                    continue;
+                }
+            };
+            if words.is_empty() {
+                continue;
+            }
+
+            editor.add_history_entry(line.as_str())?;
+            if EXIT_COMMANDS.contains(&words[0].as_str()) {
+                break;
+            }
+
+            self.execute_line(client, expand_shorthand(words));
+        }
+
+        if let Some(history) = &history {
+            if let Some(parent) = history.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            if let Err(error) = editor.save_history(history) {
+                event!(target: PRINT_TARGET, Level::DEBUG, "Cannot save shell history: {error}");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn execute_line(&self, client: &dyn Client, words: Vec<String>) {
+        let args = match IggyConsoleArgs::try_parse_from(
+            std::iter::once(String::from(env!("CARGO_BIN_NAME"))).chain(words),
+        ) {
+            Ok(args) => args,
+            Err(error) => {
+                // Prints the help and version as well as the parsing errors
+                let _ = error.print();
+                return;
+            }
+        };
+
+        let command = match args.command.clone() {
+            Some(Command::Shell) => {
+                eprintln!("Error: shell is already running");
+                return;
+            }
+            Some(Command::Logout) => {
+                // Logout ends the session of the connection used by the shell
+                eprintln!("Error: logout is not supported in shell");
+                return;
+            }
+            Some(command) => command,
+            None => {
+                let _ = IggyConsoleArgs::command().print_help();
+                return;
+            }
+        };
+
+        // Connection options given in the line are ignored, the shell uses the established connection
+        let args = IggyConsoleArgs {
+            iggy: self.iggy.clone(),
+            ..args
+        };
+        let mut command = match get_command(command, &args) {
+            Ok(command) => command,
+            Err(error) => {
+                let _ = IggyConsoleArgs::command()
+                    .error(ErrorKind::ValueValidation, error)
+                    .print();
+                return;
+            }
+        };
+        if args.output == OutputFormatArg::Table {
+            if command.use_tracing() {
+                event!(target: PRINT_TARGET, Level::INFO, "Executing {}", command.explain());
+            } else {
+                println!("Executing {}", command.explain());
+            }
+        }
+
+        if let Err(error) = Handle::current().block_on(command.execute_cmd(client)) {
+            eprintln!("Error: {error:#}");
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ShellCmd {
+    fn explain(&self) -> String {
+        "interactive shell".to_owned()
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), Error> {
+        // Reading the lines blocks the thread, so the commands are executed
+        // on the same thread using the current runtime.
+        tokio::task::block_in_place(|| self.run(client))
+    }
+}
+
+/// Shell history is stored next to the contexts file in the user's configuration directory.
+fn history_path() -> Option<PathBuf> {
+    Contexts::default_path()
+        .ok()
+        .map(|path| path.with_file_name(HISTORY_FILE))
+}
+
+/// Expands the shorthand syntax available only in the shell:
+/// `ls` lists streams, `ls <stream>` lists topics of the stream
+/// and `ls <stream> <topic>` gets the topic details.
+fn expand_shorthand(words: Vec<String>) -> Vec<String> {
+    if words[0] != LIST_SHORTHAND {
+        return words;
+    }
+
+    let expanded = match words.len() {
+        1 => vec!["stream", "list"],
+        2 => vec!["topic", "list"],
+        _ => vec!["topic", "get"],
+    };
+
+    expanded
+        .into_iter()
+        .map(String::from)
+        .chain(words.into_iter().skip(1))
+        .collect()
+}
+
+struct ShellHelper<'a> {
+    client: &'a dyn Client,
+    commands: clap::Command,
+}
+
+impl<'a> ShellHelper<'a> {
+    fn new(client: &'a dyn Client) -> Self {
+        Self {
+            client,
+            commands: IggyConsoleArgs::command(),
+        }
+    }
+
+    fn candidates(&self, words: &[&str], prefix: &str) -> Vec<String> {
+        let mut command = &self.commands;
+        let mut path = vec![];
+        let mut positionals = vec![];
+        let mut option_value = false;
+
+        for word in words {
+            if option_value {
+                option_value = false;
+                continue;
+            }
+
+            if let Some(long) = word.strip_prefix("--") {
+                option_value = command
+                    .get_arguments()
+                    .find(|arg| arg.get_long() == Some(long))
+                    .is_some_and(|arg| arg.get_action().takes_values());
+                continue;
+            }
+
+            if word.starts_with('-') {
+                continue;
+            }
+
+            if positionals.is_empty() {
+                if let Some(subcommand) = command.find_subcommand(word) {
+                    command = subcommand;
+                    path.push(subcommand.get_name());
+                    continue;
+                }
+            }
+
+            positionals.push(*word);
+        }
+
+        if prefix.starts_with('-') {
+            return command
+                .get_arguments()
+                .filter_map(|arg| arg.get_long())
+                .map(|long| format!("--{long}"))
+                .collect();
+        }
+
+        if path.is_empty() && positionals.is_empty() {
+            return command
+                .get_subcommands()
+                .map(|subcommand| subcommand.get_name().to_owned())
+                .chain([LIST_SHORTHAND].into_iter().map(String::from))
+                .chain(EXIT_COMMANDS.into_iter().map(String::from))
+                .collect();
+        }
+
+        if !path.is_empty() && command.has_subcommands() {
+            return command
+                .get_subcommands()
+                .map(|subcommand| subcommand.get_name().to_owned())
+                .collect();
+        }
+
+        // The shorthand itself is the first positional argument
+        let (stream_topic_command, arguments) = match path.first() {
+            Some(group) => (STREAM_TOPIC_COMMANDS.contains(group), &positionals[..]),
+            None => (positionals[0] == LIST_SHORTHAND, &positionals[1..]),
+        };
+        if !stream_topic_command {
+            return vec![];
+        }
+
+        match arguments {
+            [] => self.stream_names(),
+            [stream_id] if path.first() != Some(&"stream") => self.topic_names(stream_id),
+            _ => vec![],
+        }
+    }
+
+    fn stream_names(&self) -> Vec<String> {
+        Handle::current()
+            .block_on(self.client.get_streams(&GetStreams {}))
+            .map(|streams| streams.into_iter().map(|stream| stream.name).collect())
+            .unwrap_or_default()
+    }
+
+    fn topic_names(&self, stream_id: &str) -> Vec<String> {
+        let Ok(stream_id) = Identifier::from_str_value(stream_id) else {
+            return vec![];
+        };
+
+        Handle::current()
+            .block_on(self.client.get_topics(&GetTopics { stream_id }))
+            .map(|topics| topics.into_iter().map(|topic| topic.name).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl Completer for ShellHelper<'_> {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let line = &line[..pos];
+        let start = line.rfind(char::is_whitespace).map_or(0, |index| index + 1);
+        let prefix = &line[start..];
+        let words = line[..start].split_whitespace().collect::<Vec<_>>();
+
+        let candidates = self
+            .candidates(&words, prefix)
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(prefix))
+            .map(|candidate| Pair {
+                replacement: format!("{candidate} "),
+                display: candidate,
+            })
+            .collect();
+
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for ShellHelper<'_> {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper<'_> {}
+
+impl Validator for ShellHelper<'_> {}
+
+impl Helper for ShellHelper<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn should_expand_list_shorthand() {
+        assert_eq!(expand_shorthand(words("ls")), words("stream list"));
+        assert_eq!(expand_shorthand(words("ls prod")), words("topic list prod"));
+        assert_eq!(
+            expand_shorthand(words("ls prod events")),
+            words("topic get prod events")
+        );
+        assert_eq!(expand_shorthand(words("stream list")), words("stream list"));
+    }
+}
@@ -2,7 +2,7 @@ use iggy::client::{Client, UserClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::consumer::Consumer;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
 use iggy::models::messages::Message;
 use iggy::tcp::client::TcpClient;
 use iggy::tcp::config::TcpClientConfig;
@@ -73,6 +73,10 @@ async fn consume_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 strategy: PollingStrategy::offset(offset),
                 count: messages_per_batch,
                 auto_commit: false,
+                filter: None,
+                mode: PollingMode::Consume,
+                max_wait_ms: None,
+                max_bytes: None,
             })
             .await?;
         if polled_messages.messages.is_empty() {
@@ -2,6 +2,8 @@ use iggy::client::{Client, StreamClient, TopicClient, UserClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::tcp::client::TcpClient;
 use iggy::tcp::config::TcpClientConfig;
@@ -52,6 +54,7 @@ async fn init_system(client: &IggyClient) {
         .create_stream(&CreateStream {
             stream_id: Some(STREAM_ID),
             name: "sample-stream".to_string(),
+            ..CreateStream::default()
         })
         .await
     {
@@ -68,6 +71,11 @@ async fn init_system(client: &IggyClient) {
             message_expiry: None,
             max_topic_size: None,
             replication_factor: 1,
+            message_schema: None,
+            ephemeral: false,
+            timestamp_type: TimestampType::default(),
+            consumer_limits: ConsumerLimits::default(),
+            max_message_size: None,
         })
         .await
     {
@@ -108,6 +116,7 @@ async fn produce_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 topic_id: Identifier::numeric(TOPIC_ID)?,
                 partitioning: Partitioning::partition_id(PARTITION_ID),
                 messages,
+                acks: None,
             })
             .await?;
         sent_batches += 1;
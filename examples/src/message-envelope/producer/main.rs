@@ -61,6 +61,7 @@ async fn produce_messages(args: &Args, client: &IggyClient) -> Result<(), Box<dy
                 topic_id: Identifier::numeric(args.topic_id)?,
                 partitioning: Partitioning::partition_id(args.partition_id),
                 messages,
+                acks: None,
             })
             .await?;
         sent_batches += 1;
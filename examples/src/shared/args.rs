@@ -39,6 +39,9 @@ pub struct Args {
     #[arg(long, default_value = "tcp")]
     pub transport: String,
 
+    #[arg(long, env = "IGGY_CONNECTION_STRING", hide_env_values = true)]
+    pub connection_string: Option<String>,
+
     #[arg(long, default_value = "")]
     pub encryption_key: String,
 
@@ -110,6 +113,7 @@ impl Args {
     pub fn to_sdk_args(&self) -> iggy::args::Args {
         iggy::args::Args {
             transport: self.transport.clone(),
+            connection_string: self.connection_string.clone(),
             encryption_key: self.encryption_key.clone(),
             http_api_url: self.http_api_url.clone(),
             http_retries: self.http_retries,
@@ -3,8 +3,10 @@ use iggy::client::Client;
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::models::consumer_limits::ConsumerLimits;
 use iggy::models::messages::Message;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -81,6 +83,7 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Ig
         .create_stream(&CreateStream {
             stream_id: Some(args.stream_id),
             name: "sample".to_string(),
+            ..CreateStream::default()
         })
         .await?;
     client
@@ -92,6 +95,11 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Ig
             message_expiry: None,
             max_topic_size: None,
             replication_factor: 1,
+            message_schema: None,
+            ephemeral: false,
+            timestamp_type: TimestampType::default(),
+            consumer_limits: ConsumerLimits::default(),
+            max_message_size: None,
         })
         .await?;
     Ok(())
@@ -125,6 +133,10 @@ pub async fn consume_messages(
                 strategy: PollingStrategy::next(),
                 count: args.messages_per_batch,
                 auto_commit: true,
+                filter: None,
+                mode: PollingMode::Consume,
+                max_wait_ms: None,
+                max_bytes: None,
             })
             .await?;
         if polled_messages.messages.is_empty() {
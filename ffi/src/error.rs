@@ -0,0 +1,109 @@
+use iggy::client_error::ClientError;
+use iggy::error::IggyError;
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+/// The call has succeeded.
+pub const IGGY_OK: c_int = 0;
+/// The required pointer argument is null.
+pub const IGGY_ERROR_NULL_POINTER: c_int = -1;
+/// The string argument is not valid UTF-8.
+pub const IGGY_ERROR_INVALID_UTF8: c_int = -2;
+/// The argument is invalid, e.g. the connection string cannot be parsed.
+pub const IGGY_ERROR_INVALID_ARGUMENT: c_int = -3;
+/// The runtime of the client cannot be created or the IO error has occurred.
+pub const IGGY_ERROR_RUNTIME: c_int = -4;
+/// The call has panicked, the client should not be used anymore.
+pub const IGGY_ERROR_PANIC: c_int = -5;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// The error of the FFI call, mapped to the stable code returned to the caller.
+#[derive(Debug)]
+pub(crate) enum FfiError {
+    NullPointer(&'static str),
+    InvalidUtf8(&'static str),
+    InvalidArgument(String),
+    Runtime(String),
+    Iggy(IggyError),
+}
+
+impl FfiError {
+    fn code(&self) -> c_int {
+        match self {
+            FfiError::NullPointer(_) => IGGY_ERROR_NULL_POINTER,
+            FfiError::InvalidUtf8(_) => IGGY_ERROR_INVALID_UTF8,
+            FfiError::InvalidArgument(_) => IGGY_ERROR_INVALID_ARGUMENT,
+            FfiError::Runtime(_) => IGGY_ERROR_RUNTIME,
+            FfiError::Iggy(error) => error.as_code() as c_int,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            FfiError::NullPointer(argument) => format!("Argument: {argument} is null"),
+            FfiError::InvalidUtf8(argument) => format!("Argument: {argument} is not valid UTF-8"),
+            FfiError::InvalidArgument(message) => message.clone(),
+            FfiError::Runtime(message) => message.clone(),
+            FfiError::Iggy(error) => error.to_string(),
+        }
+    }
+}
+
+impl From<IggyError> for FfiError {
+    fn from(error: IggyError) -> Self {
+        FfiError::Iggy(error)
+    }
+}
+
+impl From<ClientError> for FfiError {
+    fn from(error: ClientError) -> Self {
+        match error {
+            ClientError::SdkError(error) => FfiError::Iggy(error),
+            ClientError::IoError(error) => FfiError::Runtime(error.to_string()),
+            error => FfiError::InvalidArgument(error.to_string()),
+        }
+    }
+}
+
+/// Runs the body of the exported function, stores the message of the error for `iggy_last_error_message()`
+/// and returns its code. The panics are caught, as unwinding across the FFI boundary is undefined behavior.
+pub(crate) fn ffi_call<F>(body: F) -> c_int
+where
+    F: FnOnce() -> Result<(), FfiError>,
+{
+    let (code, message) = match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => (IGGY_OK, None),
+        Ok(Err(error)) => (error.code(), Some(error.message())),
+        Err(_) => (
+            IGGY_ERROR_PANIC,
+            Some("Iggy FFI call has panicked".to_string()),
+        ),
+    };
+    set_last_error(message);
+    code
+}
+
+fn set_last_error(message: Option<String>) {
+    let message = message.map(|message| {
+        CString::new(message.replace('\0', ""))
+            .expect("Error message without the NUL characters is a valid C string")
+    });
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+}
+
+/// Returns the message of the error returned by the last call on the calling thread, or null if it has succeeded.
+/// The string is owned by the library and valid until the next call on the same thread.
+#[no_mangle]
+pub extern "C" fn iggy_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
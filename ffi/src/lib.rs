@@ -0,0 +1,20 @@
+//! The C ABI over the Iggy client, so the wrappers for Python, Node, C++ or any other language
+//! can be built without re-implementing the binary protocol.
+//!
+//! The client is blocking: each call runs the request on the Tokio runtime owned by the client handle
+//! and returns once the response has been received. The declarations are available in `include/iggy.h`.
+//!
+//! Every function returns `IGGY_OK` (0) on success, one of the negative `IGGY_ERROR_*` codes if the call
+//! itself is invalid (e.g. the null pointer), or the positive code of the `IggyError` returned by the client or the server.
+//! The message of the last error on the calling thread is returned by `iggy_last_error_message()`.
+
+mod client;
+mod error;
+mod messages;
+mod offsets;
+mod utils;
+
+pub use client::*;
+pub use error::*;
+pub use messages::*;
+pub use offsets::*;
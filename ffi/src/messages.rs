@@ -0,0 +1,220 @@
+use crate::client::IggyClientHandle;
+use crate::error::{ffi_call, FfiError};
+use crate::utils::{to_consumer, to_identifier, to_mut, to_ref};
+use bytes::Bytes;
+use iggy::client::MessageClient;
+use iggy::messages::poll_messages::{PollMessages, PollingKind, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::messages::PolledMessages;
+use std::ffi::{c_char, c_int};
+use std::{ptr, slice};
+
+/// The regular consumer, see `ConsumerKind`.
+pub const IGGY_CONSUMER: u8 = 1;
+/// The consumer group.
+pub const IGGY_CONSUMER_GROUP: u8 = 2;
+/// Poll the messages starting from the offset.
+pub const IGGY_POLLING_OFFSET: u8 = 1;
+/// Poll the messages starting from the timestamp.
+pub const IGGY_POLLING_TIMESTAMP: u8 = 2;
+/// Poll the messages starting from the first one.
+pub const IGGY_POLLING_FIRST: u8 = 3;
+/// Poll the last messages.
+pub const IGGY_POLLING_LAST: u8 = 4;
+/// Poll the messages following the stored consumer offset.
+pub const IGGY_POLLING_NEXT: u8 = 5;
+
+/// The message sent by `iggy_send_messages()`. The ID is the 16 bytes in the big-endian order (e.g. the UUID),
+/// all zeros let the server generate it. The payload is copied, so it can be released once the call returns.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IggyMessage {
+    pub id: [u8; 16],
+    pub payload: *const u8,
+    pub payload_length: usize,
+}
+
+/// The options of polling the messages by `iggy_poll_messages()`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IggyPollOptions {
+    /// The ID of the partition, 0 lets the server assign it to the consumer group member.
+    pub partition_id: u32,
+    /// The kind of the consumer, `IGGY_CONSUMER` (1) or `IGGY_CONSUMER_GROUP` (2).
+    pub consumer_kind: u8,
+    /// The NUL-terminated numeric ID or name of the consumer.
+    pub consumer: *const c_char,
+    /// The polling strategy, one of the `IGGY_POLLING_*` codes: offset (1), timestamp (2), first (3), last (4) or next (5).
+    pub strategy_kind: u8,
+    /// The offset or timestamp for the respective strategies, ignored by the other ones.
+    pub strategy_value: u64,
+    /// The maximum number of messages to poll.
+    pub count: u32,
+    /// Whether to store the offset of the last polled message as the consumer offset.
+    pub auto_commit: bool,
+}
+
+/// The message polled by `iggy_poll_messages()`, the payload is owned by the `IggyPolledMessages`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IggyPolledMessage {
+    pub offset: u64,
+    pub timestamp: u64,
+    /// The ID of the message, 16 bytes in the big-endian order.
+    pub id: [u8; 16],
+    pub checksum: u32,
+    pub payload: *const u8,
+    pub payload_length: usize,
+}
+
+/// The messages polled by `iggy_poll_messages()`, released by `iggy_polled_messages_free()`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct IggyPolledMessages {
+    pub partition_id: u32,
+    pub current_offset: u64,
+    pub messages: *const IggyPolledMessage,
+    pub count: usize,
+}
+
+/// Owns the polled messages referenced by the `IggyPolledMessages` at its beginning,
+/// so the pointer to the latter can be cast back to release them.
+#[repr(C)]
+struct PolledMessagesOwner {
+    polled_messages: IggyPolledMessages,
+    _messages: Vec<IggyPolledMessage>,
+    _source: PolledMessages,
+}
+
+/// Sends the messages to the partition of the topic. The stream and topic are the numeric IDs or names,
+/// the partition ID 0 balances the messages across the partitions.
+///
+/// # Safety
+/// `client` has to be the handle created by `iggy_client_new()`, `stream` and `topic` the NUL-terminated strings
+/// and `messages` the array of `count` messages with the valid payloads.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_send_messages(
+    client: *mut IggyClientHandle,
+    stream: *const c_char,
+    topic: *const c_char,
+    partition_id: u32,
+    messages: *const IggyMessage,
+    count: usize,
+) -> c_int {
+    ffi_call(|| {
+        let client = to_mut(client, "client")?;
+        let messages = match count {
+            0 => &[],
+            _ => slice::from_raw_parts(to_ref(messages, "messages")?, count),
+        };
+        let messages = messages
+            .iter()
+            .map(|message| {
+                let payload = match message.payload_length {
+                    0 => Bytes::new(),
+                    length => Bytes::copy_from_slice(slice::from_raw_parts(
+                        to_ref(message.payload, "payload")?,
+                        length,
+                    )),
+                };
+                Ok(Message::new(
+                    Some(u128::from_be_bytes(message.id)),
+                    payload,
+                    None,
+                ))
+            })
+            .collect::<Result<Vec<_>, FfiError>>()?;
+        let mut command = SendMessages {
+            stream_id: to_identifier(stream, "stream")?,
+            topic_id: to_identifier(topic, "topic")?,
+            partitioning: match partition_id {
+                0 => Partitioning::balanced(),
+                partition_id => Partitioning::partition_id(partition_id),
+            },
+            messages,
+            acks: None,
+        };
+        client.block_on(|iggy_client| async move { iggy_client.send_messages(&mut command).await })
+    })
+}
+
+/// Polls the messages from the topic, the result has to be released by `iggy_polled_messages_free()`.
+///
+/// # Safety
+/// `client` has to be the handle created by `iggy_client_new()`, `stream` and `topic` the NUL-terminated strings,
+/// `options` the valid options and `polled_messages` the valid pointer receiving the result.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_poll_messages(
+    client: *mut IggyClientHandle,
+    stream: *const c_char,
+    topic: *const c_char,
+    options: *const IggyPollOptions,
+    polled_messages: *mut *mut IggyPolledMessages,
+) -> c_int {
+    ffi_call(|| {
+        let client = to_mut(client, "client")?;
+        let options = to_ref(options, "options")?;
+        let polled_messages = to_mut(polled_messages, "polled_messages")?;
+        let command = PollMessages {
+            consumer: to_consumer(options.consumer_kind, options.consumer)?,
+            stream_id: to_identifier(stream, "stream")?,
+            topic_id: to_identifier(topic, "topic")?,
+            partition_id: match options.partition_id {
+                0 => None,
+                partition_id => Some(partition_id),
+            },
+            strategy: PollingStrategy {
+                kind: PollingKind::from_code(options.strategy_kind)?,
+                value: options.strategy_value,
+            },
+            count: options.count,
+            auto_commit: options.auto_commit,
+            filter: None,
+            mode: PollingMode::Consume,
+            max_wait_ms: None,
+            max_bytes: None,
+        };
+        let source = client
+            .block_on(|iggy_client| async move { iggy_client.poll_messages(&command).await })?;
+        let messages = source
+            .messages
+            .iter()
+            .map(|message| IggyPolledMessage {
+                offset: message.offset,
+                timestamp: message.timestamp,
+                id: message.id.to_be_bytes(),
+                checksum: message.checksum,
+                payload: message.payload.as_ptr(),
+                payload_length: message.payload.len(),
+            })
+            .collect::<Vec<_>>();
+        let owner = Box::new(PolledMessagesOwner {
+            polled_messages: IggyPolledMessages {
+                partition_id: source.partition_id,
+                current_offset: source.current_offset,
+                messages: match messages.is_empty() {
+                    true => ptr::null(),
+                    false => messages.as_ptr(),
+                },
+                count: messages.len(),
+            },
+            _messages: messages,
+            _source: source,
+        });
+        *polled_messages = Box::into_raw(owner).cast::<IggyPolledMessages>();
+        Ok(())
+    })
+}
+
+/// Releases the messages polled by `iggy_poll_messages()`, including their payloads. Passing null is a no-op.
+///
+/// # Safety
+/// `polled_messages` has to be null or the result of `iggy_poll_messages()`, which has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_polled_messages_free(polled_messages: *mut IggyPolledMessages) {
+    if polled_messages.is_null() {
+        return;
+    }
+
+    drop(Box::from_raw(polled_messages.cast::<PolledMessagesOwner>()));
+}
@@ -0,0 +1,83 @@
+use crate::client::IggyClientHandle;
+use crate::error::ffi_call;
+use crate::utils::{to_consumer, to_identifier, to_mut};
+use iggy::client::ConsumerOffsetClient;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use std::ffi::{c_char, c_int};
+
+/// The offsets of the consumer returned by `iggy_get_consumer_offset()`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IggyConsumerOffset {
+    pub partition_id: u32,
+    /// The offset of the last message in the partition.
+    pub current_offset: u64,
+    /// The offset stored by the consumer.
+    pub stored_offset: u64,
+}
+
+/// Stores the offset of the consumer of the given kind (`IGGY_CONSUMER` or `IGGY_CONSUMER_GROUP`) for the partition.
+///
+/// # Safety
+/// `client` has to be the handle created by `iggy_client_new()`, `stream`, `topic` and `consumer` the NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_store_consumer_offset(
+    client: *mut IggyClientHandle,
+    stream: *const c_char,
+    topic: *const c_char,
+    partition_id: u32,
+    consumer_kind: u8,
+    consumer: *const c_char,
+    offset: u64,
+) -> c_int {
+    ffi_call(|| {
+        let client = to_mut(client, "client")?;
+        let command = StoreConsumerOffset {
+            consumer: to_consumer(consumer_kind, consumer)?,
+            stream_id: to_identifier(stream, "stream")?,
+            topic_id: to_identifier(topic, "topic")?,
+            partition_id: Some(partition_id),
+            offset,
+        };
+        client.block_on(
+            |iggy_client| async move { iggy_client.store_consumer_offset(&command).await },
+        )
+    })
+}
+
+/// Gets the offset of the consumer of the given kind (`IGGY_CONSUMER` or `IGGY_CONSUMER_GROUP`) for the partition.
+///
+/// # Safety
+/// `client` has to be the handle created by `iggy_client_new()`, `stream`, `topic` and `consumer` the NUL-terminated strings
+/// and `consumer_offset` the valid pointer receiving the result.
+#[no_mangle]
+pub unsafe extern "C" fn iggy_get_consumer_offset(
+    client: *mut IggyClientHandle,
+    stream: *const c_char,
+    topic: *const c_char,
+    partition_id: u32,
+    consumer_kind: u8,
+    consumer: *const c_char,
+    consumer_offset: *mut IggyConsumerOffset,
+) -> c_int {
+    ffi_call(|| {
+        let client = to_mut(client, "client")?;
+        let consumer_offset = to_mut(consumer_offset, "consumer_offset")?;
+        let command = GetConsumerOffset {
+            consumer: to_consumer(consumer_kind, consumer)?,
+            stream_id: to_identifier(stream, "stream")?,
+            topic_id: to_identifier(topic, "topic")?,
+            partition_id: Some(partition_id),
+        };
+        let offset = client.block_on(|iggy_client| async move {
+            iggy_client.get_consumer_offset(&command).await
+        })?;
+        *consumer_offset = IggyConsumerOffset {
+            partition_id: offset.partition_id,
+            current_offset: offset.current_offset,
+            stored_offset: offset.stored_offset,
+        };
+        Ok(())
+    })
+}
@@ -0,0 +1,66 @@
+use crate::error::FfiError;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::identifier::Identifier;
+use std::ffi::{c_char, CStr};
+
+/// Returns the string behind the NUL-terminated C string.
+///
+/// # Safety
+/// The pointer has to be null or point to the NUL-terminated string valid for the duration of the call.
+pub(crate) unsafe fn to_str<'a>(
+    value: *const c_char,
+    argument: &'static str,
+) -> Result<&'a str, FfiError> {
+    if value.is_null() {
+        return Err(FfiError::NullPointer(argument));
+    }
+
+    CStr::from_ptr(value)
+        .to_str()
+        .map_err(|_| FfiError::InvalidUtf8(argument))
+}
+
+/// Returns the identifier of the stream, topic or consumer from its numeric ID or name, e.g. `"1"` or `"orders"`.
+///
+/// # Safety
+/// The pointer has to be null or point to the NUL-terminated string valid for the duration of the call.
+pub(crate) unsafe fn to_identifier(
+    value: *const c_char,
+    argument: &'static str,
+) -> Result<Identifier, FfiError> {
+    Ok(Identifier::from_str_value(to_str(value, argument)?)?)
+}
+
+/// Returns the consumer of the given kind (`IGGY_CONSUMER` or `IGGY_CONSUMER_GROUP`) with the given ID or name.
+///
+/// # Safety
+/// The pointer has to be null or point to the NUL-terminated string valid for the duration of the call.
+pub(crate) unsafe fn to_consumer(kind: u8, id: *const c_char) -> Result<Consumer, FfiError> {
+    let id = to_identifier(id, "consumer")?;
+    Ok(match ConsumerKind::from_code(kind)? {
+        ConsumerKind::Consumer => Consumer::new(id),
+        ConsumerKind::ConsumerGroup => Consumer::group(id),
+    })
+}
+
+/// Returns the mutable reference behind the pointer.
+///
+/// # Safety
+/// The pointer has to be null or valid and not aliased for the duration of the call.
+pub(crate) unsafe fn to_mut<'a, T>(
+    value: *mut T,
+    argument: &'static str,
+) -> Result<&'a mut T, FfiError> {
+    value.as_mut().ok_or(FfiError::NullPointer(argument))
+}
+
+/// Returns the reference behind the pointer.
+///
+/// # Safety
+/// The pointer has to be null or valid for the duration of the call.
+pub(crate) unsafe fn to_ref<'a, T>(
+    value: *const T,
+    argument: &'static str,
+) -> Result<&'a T, FfiError> {
+    value.as_ref().ok_or(FfiError::NullPointer(argument))
+}
@@ -450,6 +450,7 @@ pub async fn create_user(client: &IggyClient, username: &str) {
                     read_topics: true,
                     poll_messages: true,
                     send_messages: true,
+                    auto_create_topics: true,
                 },
                 streams: None,
             }),
@@ -0,0 +1,195 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::identifier::Identifier;
+use iggy::models::user_status::UserStatus;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::get_stream::GetStream;
+use iggy::users::get_user::GetUser;
+use predicates::str::diff;
+use serial_test::parallel;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+const DEFINITION: &str = r#"
+streams:
+  - name: existing
+    topics:
+      - name: events
+        partitions_count: 2
+  - id: 20
+    name: applied
+    topics:
+      - name: orders
+        message_expiry: 1 day
+users:
+  - username: applier
+    password: secret
+    status: inactive
+"#;
+
+struct TestApplyCmd {
+    path: PathBuf,
+    dry_run: bool,
+}
+
+impl TestApplyCmd {
+    fn new(dry_run: bool) -> Self {
+        Self {
+            path: std::env::temp_dir().join(format!("iggy-apply-{}.yaml", Uuid::new_v4())),
+            dry_run,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestApplyCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: Some(10),
+                name: String::from("existing"),
+                ..CreateStream::default()
+            })
+            .await;
+        assert!(stream.is_ok());
+        std::fs::write(&self.path, DEFINITION).unwrap();
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        let command = IggyCmdCommand::new()
+            .arg("apply")
+            .arg("--file")
+            .arg(self.path.to_str().unwrap())
+            .with_env_credentials();
+        match self.dry_run {
+            true => command.arg("--dry-run"),
+            false => command,
+        }
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let (mode, summary) = match self.dry_run {
+            true => (" in dry run mode", "Dry run, 4 change(s) not applied"),
+            false => ("", "4 change(s) applied"),
+        };
+        command_state.success().stdout(diff(format!(
+            "Executing apply resources from file: {}{mode}
++ topic: existing/events (partitions: 2, message expiry: unlimited, max topic size: unlimited, replication factor: 1)
++ stream: applied (ID: 20)
++ topic: applied/orders (partitions: 1, message expiry: 86400s, max topic size: unlimited, replication factor: 1)
++ user: applier (status: inactive)
+{summary}
+",
+            self.path.display()
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .get_stream(&GetStream {
+                stream_id: Identifier::named("applied").unwrap(),
+            })
+            .await;
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::named("applier").unwrap(),
+            })
+            .await;
+
+        if self.dry_run {
+            assert!(stream.is_err());
+            assert!(user.is_err());
+        } else {
+            let stream = stream.unwrap();
+            assert_eq!(stream.id, 20);
+            assert_eq!(stream.topics.len(), 1);
+            assert_eq!(stream.topics[0].message_expiry, Some(86400));
+            assert_eq!(user.unwrap().status, UserStatus::Inactive);
+        }
+
+        std::fs::remove_file(&self.path).unwrap();
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test.execute_test(TestApplyCmd::new(true)).await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_apply_changes() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test.execute_test(TestApplyCmd::new(false)).await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["apply", "--help"],
+            format!(
+                r#"apply resources from definition file
+
+Streams, topics, partitions, users and permissions declared in the file
+are created or updated to match the definition. The file has the same
+structure as the server bootstrap definition (see configs/bootstrap.toml)
+and can be written in YAML, TOML or JSON. Resources are matched by their
+names, the ones missing in the file are not deleted.
+
+Examples:
+ iggy apply -f resources.yaml
+ iggy apply -f resources.yaml --dry-run
+
+{USAGE_PREFIX} apply [OPTIONS] --file <FILE>
+
+Options:
+  -f, --file <FILE>
+{CLAP_INDENT}Path to the definition file (.yaml, .yml, .toml or .json)
+
+      --dry-run
+{CLAP_INDENT}Print the changes without applying them
+
+  -h, --help
+{CLAP_INDENT}Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["apply", "-h"],
+            format!(
+                r#"apply resources from definition file
+
+{USAGE_PREFIX} apply [OPTIONS] --file <FILE>
+
+Options:
+  -f, --file <FILE>  Path to the definition file (.yaml, .yml, .toml or .json)
+      --dry-run      Print the changes without applying them
+  -h, --help         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
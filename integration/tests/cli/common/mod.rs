@@ -13,7 +13,8 @@ use iggy::tcp::client::TcpClient;
 use iggy::tcp::config::TcpClientConfig;
 use iggy::users::defaults::*;
 use iggy::users::login_user::LoginUser;
-use integration::test_server::TestServer;
+use integration::test_server::{IpAddrKind, TestServer};
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter, Result};
 use std::io::Write;
 use std::process::{Command, Stdio};
@@ -84,7 +85,18 @@ pub(crate) struct IggyCmdTest {
 
 impl IggyCmdTest {
     pub(crate) fn new(start_server: bool) -> Self {
-        let mut server = TestServer::default();
+        Self::create(TestServer::default(), start_server)
+    }
+
+    /// Starts the server with the given environment variables, e.g. enabling the optional features.
+    pub(crate) fn with_server_envs(envs: HashMap<String, String>) -> Self {
+        Self::create(
+            TestServer::new(Some(envs), true, None, IpAddrKind::V4),
+            true,
+        )
+    }
+
+    fn create(mut server: TestServer, start_server: bool) -> Self {
         if start_server {
             server.start();
         }
@@ -0,0 +1,105 @@
+use crate::cli::common::IggyCmdTest;
+use iggy::client::Client;
+use iggy::connectors::create_connector::CreateConnector;
+use iggy::connectors::delete_connector::DeleteConnector;
+use iggy::identifier::Identifier;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+mod test_connector_create_command;
+mod test_connector_delete_command;
+mod test_connector_get_command;
+mod test_connector_help_command;
+mod test_connector_list_command;
+mod test_connector_restart_command;
+
+const STREAM_ID: u32 = 1;
+const STREAM_NAME: &str = "connectors";
+const TOPIC_NAME: &str = "lines";
+
+/// Returns the test running the server with the connectors enabled.
+fn connectors_test() -> IggyCmdTest {
+    IggyCmdTest::with_server_envs(HashMap::from([(
+        "IGGY_CONNECTORS_ENABLED".to_string(),
+        "true".to_string(),
+    )]))
+}
+
+/// The file source connector tailing the temporary file, appending to the topic of the test stream.
+struct TestFileSource {
+    name: String,
+    path: PathBuf,
+}
+
+impl TestFileSource {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            path: std::env::temp_dir().join(format!("iggy-connector-{}.log", Uuid::new_v4())),
+        }
+    }
+
+    fn command(&self) -> CreateConnector {
+        CreateConnector {
+            name: self.name.clone(),
+            kind: "file_source".to_string(),
+            stream: STREAM_NAME.to_string(),
+            topic: TOPIC_NAME.to_string(),
+            properties: HashMap::from([(
+                "path".to_string(),
+                self.path.to_str().unwrap().to_string(),
+            )]),
+        }
+    }
+
+    async fn create_stream_and_topic(&self, client: &dyn Client) {
+        std::fs::write(&self.path, "first\nsecond\n").unwrap();
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: Some(STREAM_ID),
+                name: STREAM_NAME.to_string(),
+                ..CreateStream::default()
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                name: TOPIC_NAME.to_string(),
+                ..CreateTopic::default()
+            })
+            .await;
+        assert!(topic.is_ok());
+    }
+
+    async fn create(&self, client: &dyn Client) {
+        self.create_stream_and_topic(client).await;
+        let connector = client.create_connector(&self.command()).await;
+        assert!(connector.is_ok());
+    }
+
+    async fn delete(&self, client: &dyn Client, connector_exists: bool) {
+        if connector_exists {
+            let connector = client
+                .delete_connector(&DeleteConnector {
+                    name: self.name.clone(),
+                })
+                .await;
+            assert!(connector.is_ok());
+        }
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(stream.is_ok());
+        std::fs::remove_file(&self.path).unwrap();
+    }
+}
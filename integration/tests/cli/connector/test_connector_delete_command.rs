@@ -0,0 +1,119 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use crate::cli::connector::{connectors_test, TestFileSource};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::connectors::get_connector::GetConnector;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestConnectorDeleteCmd {
+    connector: TestFileSource,
+}
+
+impl TestConnectorDeleteCmd {
+    fn new(name: &str) -> Self {
+        Self {
+            connector: TestFileSource::new(name),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConnectorDeleteCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        self.connector.create(client).await;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("connector")
+            .arg("delete")
+            .arg(self.connector.name.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let name = &self.connector.name;
+        command_state.success().stdout(diff(format!(
+            "Executing delete connector with name: {name}\nConnector with name: {name} deleted\n"
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let connector = client
+            .get_connector(&GetConnector {
+                name: self.connector.name.clone(),
+            })
+            .await;
+        assert!(connector.is_err());
+
+        self.connector.delete(client, false).await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = connectors_test();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestConnectorDeleteCmd::new("lines-source"))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["connector", "delete", "--help"],
+            format!(
+                r#"Delete connector with given name
+
+Connector is stopped and no longer restored after the server restart.
+
+Examples:
+ iggy connector delete orders-cdc
+
+{USAGE_PREFIX} connector delete <NAME>
+
+Arguments:
+  <NAME>
+          Connector name to delete
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["connector", "delete", "-h"],
+            format!(
+                r#"Delete connector with given name
+
+{USAGE_PREFIX} connector delete <NAME>
+
+Arguments:
+  <NAME>  Connector name to delete
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,114 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use crate::cli::connector::{connectors_test, TestFileSource};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestConnectorGetCmd {
+    connector: TestFileSource,
+}
+
+impl TestConnectorGetCmd {
+    fn new(name: &str) -> Self {
+        Self {
+            connector: TestFileSource::new(name),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConnectorGetCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        self.connector.create(client).await;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("connector")
+            .arg("get")
+            .arg(self.connector.name.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(starts_with(format!(
+                "Executing get connector with name: {}\n",
+                self.connector.name
+            )))
+            .stdout(contains(self.connector.name.clone()))
+            .stdout(contains("file_source"))
+            .stdout(contains(self.connector.path.to_str().unwrap()));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        self.connector.delete(client, true).await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = connectors_test();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestConnectorGetCmd::new("lines-source"))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["connector", "get", "--help"],
+            format!(
+                r#"Get details of a single connector with given name
+
+Examples:
+ iggy connector get orders-cdc
+
+{USAGE_PREFIX} connector get <NAME>
+
+Arguments:
+  <NAME>
+          Connector name to get
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["connector", "get", "-h"],
+            format!(
+                r#"Get details of a single connector with given name
+
+{USAGE_PREFIX} connector get <NAME>
+
+Arguments:
+  <NAME>  Connector name to get
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,36 @@
+use crate::cli::common::{help::TestHelpCmd, IggyCmdTest, USAGE_PREFIX};
+use serial_test::parallel;
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["connector", "help"],
+            format!(
+                r#"connector operations
+
+Connectors run inside the server and either append the records from
+the external system to the topic (sources) or write the messages
+consumed from the topic to the external system (sinks).
+
+{USAGE_PREFIX} connector <COMMAND>
+
+Commands:
+  create   Create connector with given name and kind for given stream and topic [aliases: c]
+  delete   Delete connector with given name [aliases: d]
+  get      Get details of a single connector with given name [aliases: g]
+  list     List all connectors [aliases: l]
+  restart  Restart connector with given name [aliases: r]
+  help     Print this message or the help of the given subcommand(s)
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
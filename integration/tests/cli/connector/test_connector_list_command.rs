@@ -0,0 +1,126 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, OutputFormat, TestHelpCmd, CLAP_INDENT,
+    USAGE_PREFIX,
+};
+use crate::cli::connector::{connectors_test, TestFileSource};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestConnectorListCmd {
+    connector: TestFileSource,
+    output: OutputFormat,
+}
+
+impl TestConnectorListCmd {
+    fn new(name: &str, output: OutputFormat) -> Self {
+        Self {
+            connector: TestFileSource::new(name),
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConnectorListCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        self.connector.create(client).await;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("connector")
+            .arg("list")
+            .args(self.output.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(starts_with(format!(
+                "Executing list connectors in {} mode",
+                self.output
+            )))
+            .stdout(contains(self.connector.name.clone()))
+            .stdout(contains("file_source"));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        self.connector.delete(client, true).await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = connectors_test();
+
+    iggy_cmd_test.setup().await;
+    for output in [
+        OutputFormat::Default,
+        OutputFormat::List,
+        OutputFormat::Table,
+    ] {
+        iggy_cmd_test
+            .execute_test(TestConnectorListCmd::new("lines-source", output))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["connector", "list", "--help"],
+            format!(
+                r#"List all connectors
+
+Examples:
+ iggy connector list
+ iggy connector list --list-mode table
+ iggy connector list -l list
+
+{USAGE_PREFIX} connector list [OPTIONS]
+
+Options:
+  -l, --list-mode <LIST_MODE>
+          List mode (table or list)
+{CLAP_INDENT}
+          [default: table]
+          [possible values: table, list]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["connector", "list", "-h"],
+            format!(
+                r#"List all connectors
+
+{USAGE_PREFIX} connector list [OPTIONS]
+
+Options:
+  -l, --list-mode <LIST_MODE>  List mode (table or list) [default: table] [possible values: table, list]
+  -h, --help                   Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
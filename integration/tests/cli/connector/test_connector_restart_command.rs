@@ -0,0 +1,121 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use crate::cli::connector::{connectors_test, TestFileSource};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::connectors::get_connector::GetConnector;
+use iggy::models::connector::ConnectorStatus;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestConnectorRestartCmd {
+    connector: TestFileSource,
+}
+
+impl TestConnectorRestartCmd {
+    fn new(name: &str) -> Self {
+        Self {
+            connector: TestFileSource::new(name),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConnectorRestartCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        self.connector.create(client).await;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("connector")
+            .arg("restart")
+            .arg(self.connector.name.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let name = &self.connector.name;
+        command_state.success().stdout(diff(format!(
+            "Executing restart connector with name: {name}\nConnector with name: {name} restarted\n"
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let connector = client
+            .get_connector(&GetConnector {
+                name: self.connector.name.clone(),
+            })
+            .await;
+        assert!(connector.is_ok());
+        assert_eq!(connector.unwrap().status, ConnectorStatus::Running);
+
+        self.connector.delete(client, true).await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = connectors_test();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestConnectorRestartCmd::new("lines-source"))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["connector", "restart", "--help"],
+            format!(
+                r#"Restart connector with given name
+
+Failed connector, which has exhausted the retries, is started again.
+
+Examples:
+ iggy connector restart orders-cdc
+
+{USAGE_PREFIX} connector restart <NAME>
+
+Arguments:
+  <NAME>
+          Connector name to restart
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["connector", "restart", "-h"],
+            format!(
+                r#"Restart connector with given name
+
+{USAGE_PREFIX} connector restart <NAME>
+
+Arguments:
+  <NAME>  Connector name to restart
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
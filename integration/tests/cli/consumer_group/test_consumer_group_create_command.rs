@@ -5,6 +5,8 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -73,6 +75,7 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -86,6 +89,11 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -133,6 +141,7 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic.is_ok());
@@ -140,6 +149,7 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
         let stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream.is_ok());
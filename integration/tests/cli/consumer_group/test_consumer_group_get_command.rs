@@ -6,6 +6,8 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -79,6 +81,7 @@ impl IggyCmdTestCase for TestConsumerGroupGetCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -92,6 +95,11 @@ impl IggyCmdTestCase for TestConsumerGroupGetCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -163,6 +171,7 @@ impl IggyCmdTestCase for TestConsumerGroupGetCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic.is_ok());
@@ -170,6 +179,7 @@ impl IggyCmdTestCase for TestConsumerGroupGetCmd {
         let stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream.is_ok());
@@ -9,6 +9,8 @@ use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -91,6 +93,7 @@ impl IggyCmdTestCase for TestConsumerOffsetGetCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -104,6 +107,11 @@ impl IggyCmdTestCase for TestConsumerOffsetGetCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -118,6 +126,7 @@ impl IggyCmdTestCase for TestConsumerOffsetGetCmd {
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
                 partitioning: Partitioning::partition_id(self.partition_id),
                 messages,
+                acks: None,
             })
             .await;
         assert!(send_status.is_ok());
@@ -188,6 +197,7 @@ impl IggyCmdTestCase for TestConsumerOffsetGetCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic.is_ok());
@@ -195,6 +205,7 @@ impl IggyCmdTestCase for TestConsumerOffsetGetCmd {
         let stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream.is_ok());
@@ -295,9 +306,10 @@ Examples:
  iggy consumer-offset get consumer stream 5 1
  iggy consumer-offset get consumer 3 topic 1
  iggy consumer-offset get 1 stream topic 1
+ iggy consumer-offset get 1 stream/topic/1
  iggy consumer-offset get consumer stream topic 1
 
-{USAGE_PREFIX} consumer-offset get <CONSUMER_ID> <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
+{USAGE_PREFIX} consumer-offset get <CONSUMER_ID> <STREAM_ID> [TOPIC_ID] [PARTITION_ID]
 
 Arguments:
   <CONSUMER_ID>
@@ -308,15 +320,17 @@ Arguments:
   <STREAM_ID>
           Stream ID for which consumer offset is retrieved
 {CLAP_INDENT}
-          Stream ID can be specified as a stream name or ID
+          Stream ID can be specified as a stream name or ID, or together with the topic and partition as a path, e.g. orders/events/3
 
-  <TOPIC_ID>
+  [TOPIC_ID]
           Topic ID for which consumer offset is retrieved
 {CLAP_INDENT}
-          Topic ID can be specified as a topic name or ID
+          Topic ID can be specified as a topic name or ID, it's omitted when given in the stream path
 
-  <PARTITION_ID>
+  [PARTITION_ID]
           Partitions ID for which consumer offset is retrieved
+{CLAP_INDENT}
+          Partition ID is omitted when given in the stream path
 
 Options:
   -h, --help
@@ -338,13 +352,13 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Retrieve the offset of a consumer for a given partition from the server
 
-{USAGE_PREFIX} consumer-offset get <CONSUMER_ID> <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
+{USAGE_PREFIX} consumer-offset get <CONSUMER_ID> <STREAM_ID> [TOPIC_ID] [PARTITION_ID]
 
 Arguments:
   <CONSUMER_ID>   Regular consumer for which the offset is retrieved
   <STREAM_ID>     Stream ID for which consumer offset is retrieved
-  <TOPIC_ID>      Topic ID for which consumer offset is retrieved
-  <PARTITION_ID>  Partitions ID for which consumer offset is retrieved
+  [TOPIC_ID]      Topic ID for which consumer offset is retrieved
+  [PARTITION_ID]  Partitions ID for which consumer offset is retrieved
 
 Options:
   -h, --help  Print help (see more with '--help')
@@ -9,6 +9,8 @@ use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -91,6 +93,7 @@ impl IggyCmdTestCase for TestConsumerOffsetSetCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -104,6 +107,11 @@ impl IggyCmdTestCase for TestConsumerOffsetSetCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -118,6 +126,7 @@ impl IggyCmdTestCase for TestConsumerOffsetSetCmd {
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
                 partitioning: Partitioning::partition_id(self.partition_id),
                 messages,
+                acks: None,
             })
             .await;
         assert!(send_status.is_ok());
@@ -192,6 +201,7 @@ impl IggyCmdTestCase for TestConsumerOffsetSetCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic.is_ok());
@@ -199,6 +209,7 @@ impl IggyCmdTestCase for TestConsumerOffsetSetCmd {
         let stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream.is_ok());
@@ -0,0 +1,4 @@
+mod test_context_add_command;
+mod test_context_help_command;
+mod test_context_list_command;
+mod test_context_use_command;
@@ -0,0 +1,235 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::cli::context::contexts::{Context, Contexts};
+use iggy::client::Client;
+use predicates::str::diff;
+use serial_test::parallel;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+struct TestContextAddCmd {
+    config_dir: PathBuf,
+    name: String,
+    args: Vec<String>,
+    existing: Option<Context>,
+    expected: Context,
+}
+
+impl TestContextAddCmd {
+    fn new(name: &str, args: Vec<&str>, existing: Option<Context>, expected: Context) -> Self {
+        Self {
+            config_dir: std::env::temp_dir().join(format!("iggy-cli-{}", Uuid::new_v4())),
+            name: name.into(),
+            args: args.into_iter().map(String::from).collect(),
+            existing,
+            expected,
+        }
+    }
+
+    fn contexts_path(&self) -> PathBuf {
+        self.config_dir.join("iggy").join("contexts.toml")
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestContextAddCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {
+        if let Some(context) = &self.existing {
+            let mut contexts = Contexts::default();
+            contexts.contexts.insert(self.name.clone(), context.clone());
+            contexts.save(&self.contexts_path()).unwrap();
+        }
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("context")
+            .arg("add")
+            .arg(self.name.clone())
+            .args(self.args.clone())
+            .env("XDG_CONFIG_HOME", self.config_dir.to_str().unwrap())
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let action = match self.existing {
+            Some(_) => "updated",
+            None => "added",
+        };
+        command_state.success().stdout(diff(format!(
+            "Executing add context: {}\nContext: {} {action}\n",
+            self.name, self.name
+        )));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {
+        let contexts = Contexts::load(&self.contexts_path()).unwrap();
+        assert!(contexts.active.is_none());
+        assert_eq!(contexts.contexts.get(&self.name), Some(&self.expected));
+
+        std::fs::remove_dir_all(&self.config_dir).unwrap();
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestContextAddCmd::new(
+            "local",
+            vec![
+                "--server-address",
+                "127.0.0.1:8090",
+                "--username",
+                "iggy",
+                "--password",
+                "secret",
+            ],
+            None,
+            Context {
+                server_address: Some("127.0.0.1:8090".into()),
+                username: Some("iggy".into()),
+                password: Some("secret".into()),
+                ..Default::default()
+            },
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestContextAddCmd::new(
+            "staging",
+            vec![
+                "--transport",
+                "quic",
+                "--server-address",
+                "10.0.0.1:8080",
+                "--token-name",
+                "staging",
+                "--tls",
+                "--tls-domain",
+                "staging.local",
+            ],
+            Some(Context {
+                username: Some("iggy".into()),
+                ..Default::default()
+            }),
+            Context {
+                transport: Some("quic".into()),
+                server_address: Some("10.0.0.1:8080".into()),
+                token_name: Some("staging".into()),
+                tls_enabled: Some(true),
+                tls_domain: Some("staging.local".into()),
+                ..Default::default()
+            },
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["context", "add", "--help"],
+            format!(
+                r#"Add context with given name or update the existing one
+
+Context is a named server profile stored in the contexts.toml file
+in the iggy directory inside the user's configuration directory
+($XDG_CONFIG_HOME or ~/.config). Options of the active context are used
+by all commands unless they are provided in the command line.
+
+Examples:
+ iggy context add local --server-address 127.0.0.1:8090 --username iggy
+ iggy context add staging --transport quic --server-address 10.0.0.1:8080 --token-name staging
+ iggy context add prod --server-address iggy.example.com:8090 --tls --tls-domain iggy.example.com
+
+{USAGE_PREFIX} context add [OPTIONS] <NAME>
+
+Arguments:
+  <NAME>
+          Name of the context
+
+Options:
+      --transport <TRANSPORT>
+          Server transport
+{CLAP_INDENT}
+          [possible values: tcp, quic, http]
+
+      --server-address <SERVER_ADDRESS>
+          Server address
+{CLAP_INDENT}
+          Address of the server for the TCP and QUIC transports
+          or the API URL for the HTTP transport
+
+      --username <USERNAME>
+          Iggy server username
+
+      --password <PASSWORD>
+          Iggy server password
+{CLAP_INDENT}
+          Password is stored in plain text, storing the personal access token
+          and using its name should be preferred.
+
+      --token-name <TOKEN_NAME>
+          Name of the stored personal access token
+{CLAP_INDENT}
+          Personal access token must be stored in the platform-specific
+          secure storage, see "iggy pat create --help" for details.
+
+      --tls
+          Enable TLS
+{CLAP_INDENT}
+          TLS is enabled for the TCP transport and the certificate
+          is validated for the QUIC transport.
+
+      --tls-domain <TLS_DOMAIN>
+          TLS domain or QUIC server name
+{CLAP_INDENT}
+          Domain used for TLS by the TCP transport and
+          the server name used by the QUIC transport.
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["context", "add", "-h"],
+            format!(
+                r#"Add context with given name or update the existing one
+
+{USAGE_PREFIX} context add [OPTIONS] <NAME>
+
+Arguments:
+  <NAME>  Name of the context
+
+Options:
+      --transport <TRANSPORT>            Server transport [possible values: tcp, quic, http]
+      --server-address <SERVER_ADDRESS>  Server address
+      --username <USERNAME>              Iggy server username
+      --password <PASSWORD>              Iggy server password
+      --token-name <TOKEN_NAME>          Name of the stored personal access token
+      --tls                              Enable TLS
+      --tls-domain <TLS_DOMAIN>          TLS domain or QUIC server name
+  -h, --help                             Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
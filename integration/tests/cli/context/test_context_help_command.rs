@@ -0,0 +1,33 @@
+use crate::cli::common::{help::TestHelpCmd, IggyCmdTest, USAGE_PREFIX};
+use serial_test::parallel;
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["context", "help"],
+            format!(
+                r#"context operations
+
+Contexts are named server profiles which allow using iggy command
+without providing the server address and credentials each time.
+
+{USAGE_PREFIX} context <COMMAND>
+
+Commands:
+  add   Add context with given name or update the existing one [aliases: a]
+  use   Set the context with given name as the active one [aliases: u]
+  list  List all contexts, the active one is marked with an asterisk [aliases: l]
+  help  Print this message or the help of the given subcommand(s)
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,158 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, OutputFormat, TestHelpCmd, CLAP_INDENT,
+    USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::cli::context::contexts::{Context, Contexts};
+use iggy::client::Client;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+struct TestContextListCmd {
+    config_dir: PathBuf,
+    output: OutputFormat,
+}
+
+impl TestContextListCmd {
+    fn new(output: OutputFormat) -> Self {
+        Self {
+            config_dir: std::env::temp_dir().join(format!("iggy-cli-{}", Uuid::new_v4())),
+            output,
+        }
+    }
+
+    fn to_args(&self) -> Vec<&str> {
+        self.output.to_args()
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestContextListCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {
+        let mut contexts = Contexts {
+            active: Some(String::from("local")),
+            ..Default::default()
+        };
+        contexts.contexts.insert(
+            String::from("local"),
+            Context {
+                server_address: Some(String::from("127.0.0.1:8090")),
+                username: Some(String::from("iggy")),
+                ..Default::default()
+            },
+        );
+        contexts.contexts.insert(
+            String::from("staging"),
+            Context {
+                transport: Some(String::from("quic")),
+                token_name: Some(String::from("staging")),
+                ..Default::default()
+            },
+        );
+        contexts
+            .save(&self.config_dir.join("iggy").join("contexts.toml"))
+            .unwrap();
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("context")
+            .arg("list")
+            .args(self.to_args())
+            .env("XDG_CONFIG_HOME", self.config_dir.to_str().unwrap())
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let command_state = command_state.success().stdout(starts_with(format!(
+            "Executing list contexts in {} mode",
+            self.output
+        )));
+        match self.output {
+            OutputFormat::List => command_state
+                .stdout(contains("*|local|"))
+                .stdout(contains("|staging|quic|")),
+            _ => command_state
+                .stdout(contains("127.0.0.1:8090"))
+                .stdout(contains("staging")),
+        };
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {
+        std::fs::remove_dir_all(&self.config_dir).unwrap();
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestContextListCmd::new(OutputFormat::Default))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestContextListCmd::new(OutputFormat::List))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestContextListCmd::new(OutputFormat::Table))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["context", "list", "--help"],
+            format!(
+                r#"List all contexts, the active one is marked with an asterisk
+
+Examples:
+ iggy context list
+ iggy context list --list-mode list
+ iggy context list -l list
+
+{USAGE_PREFIX} context list [OPTIONS]
+
+Options:
+  -l, --list-mode <LIST_MODE>
+          List mode (table or list)
+{CLAP_INDENT}
+          [default: table]
+          [possible values: table, list]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["context", "list", "-h"],
+            format!(
+                r#"List all contexts, the active one is marked with an asterisk
+
+{USAGE_PREFIX} context list [OPTIONS]
+
+Options:
+  -l, --list-mode <LIST_MODE>  List mode (table or list) [default: table] [possible values: table, list]
+  -h, --help                   Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,164 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::cli::context::contexts::{Context, Contexts};
+use iggy::client::Client;
+use predicates::str::{contains, diff, starts_with};
+use serial_test::parallel;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq)]
+enum Action {
+    Use,
+    UseMissing,
+    Me,
+}
+
+struct TestContextUseCmd {
+    config_dir: PathBuf,
+    name: String,
+    action: Action,
+}
+
+impl TestContextUseCmd {
+    fn new(name: &str, action: Action) -> Self {
+        Self {
+            config_dir: std::env::temp_dir().join(format!("iggy-cli-{}", Uuid::new_v4())),
+            name: name.into(),
+            action,
+        }
+    }
+
+    fn contexts_path(&self) -> PathBuf {
+        self.config_dir.join("iggy").join("contexts.toml")
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestContextUseCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {
+        let mut contexts = Contexts::default();
+        contexts.contexts.insert(
+            String::from("local"),
+            Context {
+                username: Some(String::from("iggy")),
+                password: Some(String::from("iggy")),
+                ..Default::default()
+            },
+        );
+        contexts.save(&self.contexts_path()).unwrap();
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        let command =
+            IggyCmdCommand::new().env("XDG_CONFIG_HOME", self.config_dir.to_str().unwrap());
+        match self.action {
+            Action::Use | Action::UseMissing => {
+                command.arg("context").arg("use").arg(self.name.clone())
+            }
+            Action::Me => command.opt("--context").opt(self.name.clone()).arg("me"),
+        }
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        match self.action {
+            Action::Use => {
+                command_state.success().stdout(diff(format!(
+                    "Executing use context: {}\nContext: {} is active\n",
+                    self.name, self.name
+                )));
+            }
+            Action::UseMissing => {
+                command_state
+                    .failure()
+                    .stderr(contains(format!("Context: {} does not exist", self.name)));
+            }
+            Action::Me => {
+                command_state
+                    .success()
+                    .stdout(starts_with("Executing me command\n"));
+            }
+        }
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {
+        let contexts = Contexts::load(&self.contexts_path()).unwrap();
+        match self.action {
+            Action::Use => assert_eq!(contexts.active, Some(self.name.clone())),
+            Action::UseMissing | Action::Me => assert!(contexts.active.is_none()),
+        }
+
+        std::fs::remove_dir_all(&self.config_dir).unwrap();
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestContextUseCmd::new("local", Action::Use))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestContextUseCmd::new("missing", Action::UseMissing))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestContextUseCmd::new("local", Action::Me))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["context", "use", "--help"],
+            format!(
+                r#"Set the context with given name as the active one
+
+Examples:
+ iggy context use staging
+
+{USAGE_PREFIX} context use <NAME>
+
+Arguments:
+  <NAME>
+          Name of the context
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["context", "use", "-h"],
+            format!(
+                r#"Set the context with given name as the active one
+
+{USAGE_PREFIX} context use <NAME>
+
+Arguments:
+  <NAME>  Name of the context
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1 @@
+mod test_export_import_command;
@@ -0,0 +1,299 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::client::Client;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::header::{HeaderKey, HeaderValue, PARTITION_KEY_HEADER};
+use iggy::models::messages::PolledMessages;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use predicates::str::diff;
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use uuid::Uuid;
+
+const SOURCE_STREAM: &str = "source";
+const TARGET_STREAM: &str = "target";
+const TOPIC: &str = "orders";
+const PARTITIONS_COUNT: u32 = 2;
+const MESSAGE_KEY: &str = "customer-1";
+
+async fn create_topic(client: &dyn Client, stream_id: u32, stream: &str) {
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(stream_id),
+            name: stream.to_string(),
+            ..CreateStream::default()
+        })
+        .await
+        .unwrap();
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::named(stream).unwrap(),
+            name: TOPIC.to_string(),
+            partitions_count: PARTITIONS_COUNT,
+            timestamp_type: TimestampType::CreateTime,
+            ..CreateTopic::default()
+        })
+        .await
+        .unwrap();
+}
+
+async fn poll_messages(client: &dyn Client, stream: &str, partition_id: u32) -> PolledMessages {
+    client
+        .poll_messages(&PollMessages {
+            consumer: Consumer::default(),
+            stream_id: Identifier::named(stream).unwrap(),
+            topic_id: Identifier::named(TOPIC).unwrap(),
+            partition_id: Some(partition_id),
+            strategy: PollingStrategy::offset(0),
+            count: 100,
+            auto_commit: false,
+            filter: None,
+            mode: PollingMode::Peek,
+            max_wait_ms: None,
+            max_bytes: None,
+        })
+        .await
+        .unwrap()
+}
+
+fn progress_path(path: &Path) -> PathBuf {
+    PathBuf::from(format!("{}.progress", path.display()))
+}
+
+struct TestExportCmd {
+    path: PathBuf,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestExportCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        create_topic(client, 1, SOURCE_STREAM).await;
+
+        let headers = HashMap::from([(
+            HeaderKey::new("region").unwrap(),
+            HeaderValue::from_str("eu").unwrap(),
+        )]);
+        let messages = (1..=3)
+            .map(|id| {
+                Message::new(
+                    Some(id),
+                    Bytes::from(format!("message-{id}")),
+                    Some(headers.clone()),
+                )
+            })
+            .collect();
+        client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::named(SOURCE_STREAM).unwrap(),
+                topic_id: Identifier::named(TOPIC).unwrap(),
+                partitioning: Partitioning::partition_id(1),
+                messages,
+                acks: None,
+            })
+            .await
+            .unwrap();
+
+        let messages = (4..=5)
+            .map(|id| Message::new(Some(id), Bytes::from(format!("message-{id}")), None))
+            .collect();
+        client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::named(SOURCE_STREAM).unwrap(),
+                topic_id: Identifier::named(TOPIC).unwrap(),
+                partitioning: Partitioning::messages_key_str(MESSAGE_KEY).unwrap(),
+                messages,
+                acks: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("export")
+            .arg("--stream")
+            .arg(SOURCE_STREAM)
+            .arg("--topic")
+            .arg(TOPIC)
+            .arg("--file")
+            .arg(self.path.to_str().unwrap())
+            .arg("--batch-size")
+            .arg("2")
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(diff(format!(
+            "Executing export messages from topic with ID: {TOPIC} and stream with ID: {SOURCE_STREAM} to file: {0}
+Exported 5 messages from topic: {TOPIC} and stream: {SOURCE_STREAM} to file: {0}
+",
+            self.path.display()
+        )));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {
+        let content = std::fs::read_to_string(&self.path).unwrap();
+        assert_eq!(content.lines().count(), 6);
+        assert!(!progress_path(&self.path).exists());
+    }
+}
+
+struct TestImportCmd {
+    path: PathBuf,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestImportCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        create_topic(client, 2, TARGET_STREAM).await;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("import")
+            .arg("--file")
+            .arg(self.path.to_str().unwrap())
+            .arg("--stream")
+            .arg(TARGET_STREAM)
+            .arg("--batch-size")
+            .arg("2")
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(diff(format!(
+            "Executing import messages from file: {0}
+Imported 5 messages to topic with ID: {TOPIC} and stream with ID: {TARGET_STREAM} from file: {0}
+",
+            self.path.display()
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        assert!(!progress_path(&self.path).exists());
+
+        for partition_id in 1..=PARTITIONS_COUNT {
+            let source = poll_messages(client, SOURCE_STREAM, partition_id).await;
+            let target = poll_messages(client, TARGET_STREAM, partition_id).await;
+            assert_eq!(source.messages.len(), target.messages.len());
+
+            for (source, target) in source.messages.iter().zip(target.messages.iter()) {
+                assert_eq!(source.offset, target.offset);
+                assert_eq!(source.id, target.id);
+                assert_eq!(source.timestamp, target.timestamp);
+                assert_eq!(source.payload, target.payload);
+
+                let source_headers = source.headers.as_ref().unwrap();
+                let target_headers = target.headers.as_ref().unwrap();
+                for key in ["region", PARTITION_KEY_HEADER] {
+                    let key = HeaderKey::new(key).unwrap();
+                    assert_eq!(source_headers.get(&key), target_headers.get(&key));
+                }
+            }
+        }
+
+        std::fs::remove_file(&self.path).unwrap();
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+    let path = std::env::temp_dir().join(format!("iggy-export-{}.iggy", Uuid::new_v4()));
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestExportCmd { path: path.clone() })
+        .await;
+    iggy_cmd_test.execute_test(TestImportCmd { path }).await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_export_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["export", "--help"],
+            format!(
+                r#"export messages from topic to file
+
+Messages (including their IDs, headers and timestamps) are polled from
+all partitions of the topic, without affecting the consumer offsets,
+and written to the portable file, one JSON object per line. Only the
+messages appended before the export has started are exported.
+Interrupted export continues from where it stopped when run again.
+
+Examples:
+ iggy export --stream stream --topic topic --file dump.iggy
+ iggy export -s 1 -t 2 -p 1 -f dump.iggy
+
+{USAGE_PREFIX} export [OPTIONS] --stream <STREAM> --topic <TOPIC> --file <FILE>
+
+Options:
+  -s, --stream <STREAM>
+          Stream ID from which the messages will be exported
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  -t, --topic <TOPIC>
+          Topic ID from which the messages will be exported
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  -p, --partition-id <PARTITION_ID>
+          Partition ID from which the messages will be exported, all partitions by default
+
+  -f, --file <FILE>
+          Path to the file to which the messages will be exported
+
+  -b, --batch-size <BATCH_SIZE>
+          Number of messages polled in a single request
+{CLAP_INDENT}
+          [default: 1000]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_import_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["import", "-h"],
+            format!(
+                r#"import messages from file to topic
+
+{USAGE_PREFIX} import [OPTIONS] --file <FILE>
+
+Options:
+  -f, --file <FILE>              Path to the file created by the export command
+  -s, --stream <STREAM>          Stream ID to which the messages will be imported, the exported stream name by default
+  -t, --topic <TOPIC>            Topic ID to which the messages will be imported, the exported topic name by default
+  -b, --batch-size <BATCH_SIZE>  Number of messages sent in a single request [default: 1000]
+  -h, --help                     Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
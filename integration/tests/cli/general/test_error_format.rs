@@ -0,0 +1,113 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::{diff, starts_with};
+use serial_test::parallel;
+
+enum ExpectedStderr {
+    Exact(&'static str),
+    StartsWith(&'static str),
+}
+
+struct TestErrorFormatCmd {
+    opts: Vec<&'static str>,
+    args: Vec<&'static str>,
+    exit_code: i32,
+    expected_stderr: ExpectedStderr,
+}
+
+impl TestErrorFormatCmd {
+    fn new(
+        opts: Vec<&'static str>,
+        args: Vec<&'static str>,
+        exit_code: i32,
+        expected_stderr: ExpectedStderr,
+    ) -> Self {
+        Self {
+            opts,
+            args,
+            exit_code,
+            expected_stderr,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestErrorFormatCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .opts(self.opts.clone())
+            .args(self.args.clone())
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let command_state = command_state.failure().code(self.exit_code);
+        match self.expected_stderr {
+            ExpectedStderr::Exact(expected) => command_state.stderr(diff(format!("{expected}\n"))),
+            ExpectedStderr::StartsWith(expected) => command_state.stderr(starts_with(expected)),
+        };
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_exit_with_error_class_code() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestErrorFormatCmd::new(
+            vec!["--error-format", "json"],
+            vec!["me"],
+            4,
+            ExpectedStderr::Exact(r#"{"error":{"causes":[],"class":"authentication","exit_code":4,"message":"Missing iggy server credentials"}}"#),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestErrorFormatCmd::new(
+            vec!["--error-format", "json", "--username", "iggy", "--password", "invalid"],
+            vec!["me"],
+            4,
+            ExpectedStderr::Exact(r#"{"error":{"causes":["Invalid response: 42"],"class":"authentication","code":"invalid_credentials","exit_code":4,"id":42,"message":"Problem with server login for username: iggy"}}"#),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestErrorFormatCmd::new(
+            vec!["--error-format", "json", "--username", "iggy", "--password", "iggy"],
+            vec!["stream", "get", "missing"],
+            5,
+            ExpectedStderr::Exact(r#"{"error":{"causes":["Invalid response: 1010"],"class":"not_found","code":"stream_name_not_found","exit_code":5,"id":1010,"message":"Problem getting stream with ID: missing"}}"#),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestErrorFormatCmd::new(
+            vec!["--username", "iggy", "--password", "iggy"],
+            vec!["stream", "get", "missing"],
+            5,
+            ExpectedStderr::StartsWith("Error: CommandError(Problem getting stream"),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_exit_with_usage_code_for_invalid_arguments() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestErrorFormatCmd::new(
+            vec!["--error-format", "yaml"],
+            vec!["me"],
+            2,
+            ExpectedStderr::StartsWith(
+                "error: invalid value 'yaml' for '--error-format <ERROR_FORMAT>'",
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,106 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::identifier::Identifier;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestOutputFormatCmd {
+    stream_id: u32,
+    name: String,
+    args: Vec<String>,
+    expected_prefix: String,
+    expected_output: Vec<String>,
+}
+
+impl TestOutputFormatCmd {
+    fn new(args: Vec<&str>, expected_prefix: &str, expected_output: Vec<&str>) -> Self {
+        Self {
+            stream_id: 1,
+            name: String::from("production"),
+            args: args.into_iter().map(String::from).collect(),
+            expected_prefix: expected_prefix.into(),
+            expected_output: expected_output.into_iter().map(String::from).collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestOutputFormatCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: Some(self.stream_id),
+                name: self.name.clone(),
+                ..CreateStream::default()
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .opts(self.args[..2].to_vec())
+            .args(self.args[2..].to_vec())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        // No "Executing ..." line is printed before the output in the JSON and CSV formats
+        let status = command_state
+            .success()
+            .stdout(starts_with(self.expected_prefix.clone()));
+        self.expected_output
+            .iter()
+            .fold(status, |status, output| status.stdout(contains(output)));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_print_resources_in_given_format() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestOutputFormatCmd::new(
+            vec!["--output", "json", "stream", "get", "1"],
+            "{\n",
+            vec![r#""id": 1,"#, r#""name": "production","#, r#""topics": []"#],
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestOutputFormatCmd::new(
+            vec!["--output", "json", "stream", "list"],
+            "[\n",
+            vec![r#""id": 1,"#, r#""name": "production","#],
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestOutputFormatCmd::new(
+            vec!["--output", "csv", "stream", "list"],
+            "ID,Created,Name,Size (B),Messages,Topics\n1,",
+            vec![",production,0 B,0,0\n"],
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestOutputFormatCmd::new(
+            vec!["--output", "csv", "stream", "get", "production"],
+            "Stream ID,Stream UUID,Created,Stream name,Stream size,Stream message count,Stream topics count,Default topic expiry,Default topic size,Default compression,Default replication\n1,",
+            vec![",production,0 B,0,0,unlimited,unlimited,server default,1\n"],
+        ))
+        .await;
+}
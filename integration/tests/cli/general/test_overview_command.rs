@@ -37,7 +37,15 @@ Commands:
   client           client operations [aliases: c]
   consumer-group   consumer group operations [aliases: g]
   consumer-offset  consumer offset operations [aliases: o]
+  connector        connector operations
   message          message operations [aliases: m]
+  context          context operations
+  shell            interactive shell
+  apply            apply resources from definition file
+  export           export messages from topic to file
+  import           import messages from file to topic
+  login            login to iggy server and store the session
+  logout           logout from iggy server and remove the stored session
   help             Print this message or the help of the given subcommand(s)
 
 
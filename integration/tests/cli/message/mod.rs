@@ -1,3 +1,5 @@
 mod test_message_help_command;
 mod test_message_poll_command;
+mod test_message_query_command;
+mod test_message_replay_command;
 mod test_message_send_command;
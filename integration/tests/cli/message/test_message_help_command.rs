@@ -15,9 +15,11 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} message <COMMAND>
 
 Commands:
-  send  Send messages to given topic ID and given stream ID [aliases: s]
-  poll  Poll messages from given topic ID and given stream ID [aliases: p]
-  help  Print this message or the help of the given subcommand(s)
+  send    Send messages to given topic ID and given stream ID [aliases: s]
+  poll    Poll messages from given topic ID and given stream ID [aliases: p]
+  query   Query messages stored in the partition with the read-only, SQL-like query [aliases: q]
+  replay  Replay messages with the given offsets from the partition to another topic [aliases: r]
+  help    Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
@@ -6,6 +6,9 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -13,6 +16,7 @@ use iggy::topics::delete_topic::DeleteTopic;
 use iggy::{client::Client, identifier::Identifier};
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 struct TestMessagePollCmd {
@@ -37,7 +41,7 @@ impl TestMessagePollCmd {
         topic_id: u32,
         topic_name: String,
         partitions_count: u32,
-        messages: &Vec<String>,
+        messages: &[String],
         partition_id: u32,
         message_count: usize,
         strategy: PollingStrategy,
@@ -53,7 +57,7 @@ impl TestMessagePollCmd {
             topic_id,
             topic_name,
             partitions_count,
-            messages: messages.clone(),
+            messages: messages.to_owned(),
             partition_id,
             message_count,
             strategy,
@@ -101,6 +105,7 @@ impl IggyCmdTestCase for TestMessagePollCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -114,6 +119,11 @@ impl IggyCmdTestCase for TestMessagePollCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -130,6 +140,7 @@ impl IggyCmdTestCase for TestMessagePollCmd {
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
                 partitioning: Partitioning::partition_id(self.partition_id),
                 messages,
+                acks: None,
             })
             .await;
         assert!(send_status.is_ok());
@@ -195,6 +206,7 @@ impl IggyCmdTestCase for TestMessagePollCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic.is_ok());
@@ -202,6 +214,125 @@ impl IggyCmdTestCase for TestMessagePollCmd {
         let stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+struct TestMessagePollFormatCmd {
+    stream_id: u32,
+    topic_id: u32,
+    messages: Vec<String>,
+    format: String,
+    expected_output: Vec<String>,
+}
+
+impl TestMessagePollFormatCmd {
+    fn new(messages: &[String], format: &str, expected_output: Vec<String>) -> Self {
+        Self {
+            stream_id: 1,
+            topic_id: 1,
+            messages: messages.to_owned(),
+            format: format.into(),
+            expected_output,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessagePollFormatCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: Some(self.stream_id),
+                name: String::from("stream"),
+                ..CreateStream::default()
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Some(self.topic_id),
+                partitions_count: 1,
+                name: String::from("topic"),
+                message_expiry: None,
+                max_topic_size: None,
+                replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let headers = HashMap::from([(
+            HeaderKey::new("region").unwrap(),
+            HeaderValue::from_str("eu").unwrap(),
+        )]);
+        let messages = self
+            .messages
+            .iter()
+            .map(|s| Message::new(None, s.clone().into(), Some(headers.clone())))
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partitioning: Partitioning::partition_id(1),
+                messages,
+                acks: None,
+            })
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .opt("-q")
+            .arg("message")
+            .arg("poll")
+            .args(vec![
+                "--first".into(),
+                "--message-count".into(),
+                format!("{}", self.messages.len()),
+                "--format".into(),
+                self.format.clone(),
+                format!("{}", self.stream_id),
+                format!("{}", self.topic_id),
+                "1".into(),
+            ])
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        // Messages in the raw, hex and JSON formats are printed even in the quiet mode
+        let status = command_state.success();
+        self.expected_output
+            .iter()
+            .fold(status, |status, output| status.stdout(contains(output)));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .delete_topic(&DeleteTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream.is_ok());
@@ -292,6 +423,43 @@ pub async fn should_be_successful() {
     }
 }
 
+#[tokio::test]
+#[parallel]
+pub async fn should_print_messages_in_given_format() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_messages: Vec<String> = vec!["hello".into(), "world".into()];
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestMessagePollFormatCmd::new(
+            &test_messages,
+            "raw",
+            vec!["hello\nworld\n".into()],
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestMessagePollFormatCmd::new(
+            &test_messages,
+            "hex",
+            vec!["68656c6c6f\n776f726c64\n".into()],
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestMessagePollFormatCmd::new(
+            &test_messages,
+            "json",
+            vec![
+                r#""region":{"kind":"string","value":"eu"}"#.into(),
+                r#""offset":0"#.into(),
+                r#""payload":"hello""#.into(),
+                r#""offset":1"#.into(),
+                r#""payload":"world""#.into(),
+            ],
+        ))
+        .await;
+}
+
 #[tokio::test]
 #[parallel]
 pub async fn should_help_match() {
@@ -311,22 +479,30 @@ Examples:
  iggy message poll --offset 0 stream 2 1
  iggy message poll --offset 0 1 topic 1
  iggy message poll --offset 0 stream topic 1
+ iggy message poll --offset 0 stream/topic/1
+ iggy message poll --offset 0 --filter "region == eu && type in [created, paid]" stream topic 1
+ iggy message poll --offset 0 --schema-id 1 stream topic 1
+ iggy message poll --offset 0 --schema-file order.proto --message-type Order stream topic 1
+ iggy message poll --last --backward --message-count 50 stream topic 1
+ iggy message poll --first --follow --format json stream topic 1
 
-{USAGE_PREFIX} message poll [OPTIONS] <--offset <OFFSET>|--first|--last|--next> <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
+{USAGE_PREFIX} message poll [OPTIONS] <--offset <OFFSET>|--first|--last|--next> <STREAM_ID> [TOPIC_ID] [PARTITION_ID]
 
 Arguments:
   <STREAM_ID>
           ID of the stream from which message will be polled
 {CLAP_INDENT}
-          Stream ID can be specified as a stream name or ID
+          Stream ID can be specified as a stream name or ID, or together with the topic and partition as a path, e.g. orders/events/3
 
-  <TOPIC_ID>
+  [TOPIC_ID]
           ID of the topic from which message will be polled
 {CLAP_INDENT}
-          Topic ID can be specified as a topic name or ID
+          Topic ID can be specified as a topic name or ID, it's omitted when given in the stream path
 
-  <PARTITION_ID>
+  [PARTITION_ID]
           Partition ID from which message will be polled
+{CLAP_INDENT}
+          Partition ID is omitted when given in the stream path
 
 Options:
   -m, --message-count <MESSAGE_COUNT>
@@ -364,6 +540,72 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --filter <FILTER>
+          Filter over the message headers evaluated by the server
+{CLAP_INDENT}
+          Only the messages matching all the conditions are returned.
+          Conditions are separated with "&&" and can be one of:
+          "key == value", "key ^= prefix" or "key in [value1, value2]"
+
+      --peek
+          Peek messages without storing the consumer offset
+{CLAP_INDENT}
+          Messages are only browsed, so the consumers are not affected.
+          Cannot be used with the auto commit.
+
+      --backward
+          Peek messages backward, starting from the offset or the last message
+{CLAP_INDENT}
+          Messages are printed in the descending order of their offsets
+          and the consumer offset is not stored.
+
+      --schema-id <SCHEMA_ID>
+          Decode message payloads with the schema registered under given ID
+{CLAP_INDENT}
+          Payloads are printed as the structured JSON instead of the raw bytes
+
+      --schema-file <SCHEMA_FILE>
+          Decode message payloads with the schema read from given file
+{CLAP_INDENT}
+          Payloads are printed as the structured JSON instead of the raw bytes.
+          Schema format is inferred from the file extension (.avsc, .proto or .json)
+          unless it's specified with the schema format option.
+
+      --schema-format <SCHEMA_FORMAT>
+          Format of the schema read from the file
+{CLAP_INDENT}
+          [possible values: json, avro, protobuf]
+
+      --message-type <MESSAGE_TYPE>
+          Name of the Protobuf message type used to decode message payloads
+{CLAP_INDENT}
+          By default the first message defined in the schema is used
+
+      --format <FORMAT>
+          Output format of the polled messages
+{CLAP_INDENT}
+          "table" prints the messages as the table,
+          "raw" prints the payload of each message in a separate line,
+          "hex" prints the payload encoded as the hexadecimal string and
+          "json" prints each message including its headers as the JSON object.
+          Messages in these formats are printed also in the quiet mode.
+{CLAP_INDENT}
+          [default: table]
+          [possible values: table, raw, hex, json]
+
+      --follow
+          Keep polling and printing the new messages until interrupted
+{CLAP_INDENT}
+          After each poll the messages are polled from the offset
+          following the last polled message.
+
+      --follow-interval <INTERVAL>
+          Interval between polls without new messages
+{CLAP_INDENT}
+          Used only in the follow mode
+{CLAP_INDENT}
+          [default: 1s]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -383,12 +625,12 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Poll messages from given topic ID and given stream ID
 
-{USAGE_PREFIX} message poll [OPTIONS] <--offset <OFFSET>|--first|--last|--next> <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
+{USAGE_PREFIX} message poll [OPTIONS] <--offset <OFFSET>|--first|--last|--next> <STREAM_ID> [TOPIC_ID] [PARTITION_ID]
 
 Arguments:
   <STREAM_ID>     ID of the stream from which message will be polled
-  <TOPIC_ID>      ID of the topic from which message will be polled
-  <PARTITION_ID>  Partition ID from which message will be polled
+  [TOPIC_ID]      ID of the topic from which message will be polled
+  [PARTITION_ID]  Partition ID from which message will be polled
 
 Options:
   -m, --message-count <MESSAGE_COUNT>  Number of messages to poll [default: 1]
@@ -398,6 +640,16 @@ Options:
   -l, --last                           Polling strategy - start polling from the last message in the partition
   -n, --next                           Polling strategy - start polling from the next message
   -c, --consumer <CONSUMER>            Regular consumer which will poll messages [default: 1]
+      --filter <FILTER>                Filter over the message headers evaluated by the server
+      --peek                           Peek messages without storing the consumer offset
+      --backward                       Peek messages backward, starting from the offset or the last message
+      --schema-id <SCHEMA_ID>          Decode message payloads with the schema registered under given ID
+      --schema-file <SCHEMA_FILE>      Decode message payloads with the schema read from given file
+      --schema-format <SCHEMA_FORMAT>  Format of the schema read from the file [possible values: json, avro, protobuf]
+      --message-type <MESSAGE_TYPE>    Name of the Protobuf message type used to decode message payloads
+      --format <FORMAT>                Output format of the polled messages [default: table] [possible values: table, raw, hex, json]
+      --follow                         Keep polling and printing the new messages until interrupted
+      --follow-interval <INTERVAL>     Interval between polls without new messages [default: 1s]
   -h, --help                           Print help (see more with '--help')
 "#,
             ),
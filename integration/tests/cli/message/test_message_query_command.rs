@@ -0,0 +1,233 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestMessageQueryCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    messages_count: u32,
+    query: String,
+    expected_query: String,
+    expected_offsets: Vec<u64>,
+}
+
+impl TestMessageQueryCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        messages_count: u32,
+        query: &str,
+        expected_query: &str,
+        expected_offsets: Vec<u64>,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            messages_count,
+            query: query.into(),
+            expected_query: expected_query.into(),
+            expected_offsets,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageQueryCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: Some(self.stream_id),
+                name: self.stream_name.clone(),
+                ..CreateStream::default()
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Some(self.topic_id),
+                partitions_count: 1,
+                name: self.topic_name.clone(),
+                message_expiry: None,
+                max_topic_size: None,
+                replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let messages = (0..self.messages_count)
+            .map(|index| Message::from_str(&format!("message-{index}")).unwrap())
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partitioning: Partitioning::partition_id(1),
+                messages,
+                acks: None,
+            })
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("message")
+            .arg("query")
+            .arg(self.query.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing query messages: {}\nQuery returned {} rows",
+            self.expected_query,
+            self.expected_offsets.len()
+        );
+
+        let status = command_state.success().stdout(starts_with(message));
+        self.expected_offsets.iter().fold(status, |status, offset| {
+            status.stdout(contains(format!("| {offset} ")))
+        });
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .delete_topic(&DeleteTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_parameters: Vec<(&str, &str, Vec<u64>)> = vec![
+        (
+            "select offset, length from stream.topic.1 limit 3",
+            "SELECT offset, length FROM stream.topic.1 LIMIT 3",
+            vec![0, 1, 2],
+        ),
+        (
+            "SELECT offset FROM 1.2.1 WHERE offset >= 5 AND offset < 8",
+            "SELECT offset FROM 1.2.1 WHERE offset >= 5 AND offset < 8 LIMIT 100",
+            vec![5, 6, 7],
+        ),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (query, expected_query, expected_offsets) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestMessageQueryCmd::new(
+                1,
+                String::from("stream"),
+                2,
+                String::from("topic"),
+                10,
+                query,
+                expected_query,
+                expected_offsets,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "query", "--help"],
+            format!(
+                r#"Query messages stored in the partition with the read-only, SQL-like query
+
+Query has the following form (keywords are case-insensitive):
+SELECT <COLUMNS> FROM <STREAM>.<TOPIC>.<PARTITION> [WHERE <CONDITIONS>] [LIMIT <COUNT>]
+Columns can be "*" or any of: offset, timestamp, id, state, checksum,
+length, headers or headers.<KEY>. Conditions are joined with AND
+and use =, !=, <, <=, > or >= operators. Consumer offsets are not affected.
+
+Examples:
+ iggy message query "SELECT * FROM stream.topic.1 LIMIT 10"
+ iggy message query "SELECT offset, headers.region FROM 1.2.1 WHERE offset >= 100"
+ iggy message query "SELECT offset, length FROM stream.topic.1 WHERE timestamp > '2024-01-01T00:00:00Z'"
+
+{USAGE_PREFIX} message query <QUERY>
+
+Arguments:
+  <QUERY>
+          Query to execute
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "query", "-h"],
+            format!(
+                r#"Query messages stored in the partition with the read-only, SQL-like query
+
+{USAGE_PREFIX} message query <QUERY>
+
+Arguments:
+  <QUERY>  Query to execute
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,263 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::consumer::Consumer;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const STREAM_ID: u32 = 1;
+const SOURCE_TOPIC_ID: u32 = 1;
+const TARGET_TOPIC_ID: u32 = 2;
+
+struct TestMessageReplayCmd {
+    offsets: String,
+    replayed_from: bool,
+    expected_offsets: Vec<u64>,
+}
+
+impl TestMessageReplayCmd {
+    fn new(offsets: &str, replayed_from: bool, expected_offsets: Vec<u64>) -> Self {
+        Self {
+            offsets: offsets.into(),
+            replayed_from,
+            expected_offsets,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageReplayCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: Some(STREAM_ID),
+                name: String::from("stream"),
+                ..CreateStream::default()
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        for (topic_id, name) in [(SOURCE_TOPIC_ID, "source"), (TARGET_TOPIC_ID, "target")] {
+            let topic = client
+                .create_topic(&CreateTopic {
+                    stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                    topic_id: Some(topic_id),
+                    partitions_count: 1,
+                    name: String::from(name),
+                    ..CreateTopic::default()
+                })
+                .await;
+            assert!(topic.is_ok());
+        }
+
+        let headers = HashMap::from([(
+            HeaderKey::new("region").unwrap(),
+            HeaderValue::from_str("eu").unwrap(),
+        )]);
+        let messages = (0..10)
+            .map(|index| {
+                Message::new(
+                    None,
+                    format!("message-{index}").into(),
+                    Some(headers.clone()),
+                )
+            })
+            .collect::<Vec<_>>();
+        let send_status = client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                topic_id: Identifier::numeric(SOURCE_TOPIC_ID).unwrap(),
+                partitioning: Partitioning::partition_id(1),
+                messages,
+                acks: None,
+            })
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        let command = IggyCmdCommand::new()
+            .arg("message")
+            .arg("replay")
+            .arg("--from")
+            .arg("stream/source/1")
+            .arg("--offsets")
+            .arg(self.offsets.clone())
+            .arg("--to")
+            .arg("stream/target")
+            .arg("--batch-size")
+            .arg("2")
+            .with_env_credentials();
+        match self.replayed_from {
+            true => command.arg("--replayed-from"),
+            false => command,
+        }
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(diff(format!(
+            "Executing replay messages with offsets: {} from partition with ID: 1 of topic with ID: source and stream with ID: stream to topic with ID: target and stream with ID: stream
+Replayed {} messages to topic with ID: target and stream with ID: stream
+",
+            self.offsets.replace("..=5", "..6"),
+            self.expected_offsets.len()
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let polled_messages = client
+            .poll_messages(&PollMessages {
+                consumer: Consumer::default(),
+                stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                topic_id: Identifier::numeric(TARGET_TOPIC_ID).unwrap(),
+                partition_id: Some(1),
+                strategy: PollingStrategy::offset(0),
+                count: 100,
+                auto_commit: false,
+                filter: None,
+                mode: PollingMode::Peek,
+                max_wait_ms: None,
+                max_bytes: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(polled_messages.messages.len(), self.expected_offsets.len());
+
+        for (message, offset) in polled_messages.messages.iter().zip(&self.expected_offsets) {
+            assert_eq!(message.payload, format!("message-{offset}").as_bytes());
+            let headers = message.headers.as_ref().unwrap();
+            assert_eq!(
+                headers.get(&HeaderKey::new("region").unwrap()),
+                Some(&HeaderValue::from_str("eu").unwrap())
+            );
+            let replayed_from = headers.get(&HeaderKey::new("replayed-from").unwrap());
+            match self.replayed_from {
+                true => assert_eq!(
+                    replayed_from,
+                    Some(&HeaderValue::from_str(&format!("stream/source/1/{offset}")).unwrap())
+                ),
+                false => assert!(replayed_from.is_none()),
+            }
+        }
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestMessageReplayCmd::new("3..=5", false, vec![3, 4, 5]))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestMessageReplayCmd::new("7..", true, vec![7, 8, 9]))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "replay", "--help"],
+            format!(
+                r#"Replay messages with the given offsets from the partition to another topic
+
+Messages are sent again with their payload and headers, the messages
+appended with the key are sent with the same key, otherwise they are
+sent to the partition with the same ID (or to the given partition).
+Offsets are given as START..END (end exclusive), START..=END or START..
+(up to the last message appended before the replay has started).
+
+Examples:
+ iggy message replay --from stream/topic/1 --offsets 1000..2000 --to stream2/topic2
+ iggy message replay --from stream/topic/1 --offsets 1000.. --to stream2/topic2/1 --rate 100
+ iggy message replay --from 1/2/1 --offsets 0..=99 --to 1/3 --replayed-from
+
+{USAGE_PREFIX} message replay [OPTIONS] --from <STREAM/TOPIC/PARTITION> --offsets <OFFSETS> --to <STREAM/TOPIC[/PARTITION]>
+
+Options:
+      --from <STREAM/TOPIC/PARTITION>
+          Partition from which the messages will be replayed, e.g. orders/events/3
+{CLAP_INDENT}
+          Stream and topic can be specified as a name or ID
+
+      --offsets <OFFSETS>
+          Range of the offsets to replay, e.g. 1000..2000, 1000..=1999 or 1000..
+
+      --to <STREAM/TOPIC[/PARTITION]>
+          Topic to which the messages will be replayed, optionally with the partition, e.g. orders/replayed/1
+{CLAP_INDENT}
+          Stream and topic can be specified as a name or ID
+
+  -b, --batch-size <BATCH_SIZE>
+          Number of messages polled and sent in a single request
+{CLAP_INDENT}
+          [default: 100]
+
+  -r, --rate <MESSAGES_PER_SECOND>
+          Maximum number of messages replayed per second, unlimited by default
+
+      --replayed-from
+          Stamp each replayed message with the "replayed-from" header
+{CLAP_INDENT}
+          Value of the header is the stream, topic, partition and offset
+          of the original message, e.g. orders/events/3/1000
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "replay", "-h"],
+            format!(
+                r#"Replay messages with the given offsets from the partition to another topic
+
+{USAGE_PREFIX} message replay [OPTIONS] --from <STREAM/TOPIC/PARTITION> --offsets <OFFSETS> --to <STREAM/TOPIC[/PARTITION]>
+
+Options:
+      --from <STREAM/TOPIC/PARTITION>  Partition from which the messages will be replayed, e.g. orders/events/3
+      --offsets <OFFSETS>              Range of the offsets to replay, e.g. 1000..2000, 1000..=1999 or 1000..
+      --to <STREAM/TOPIC[/PARTITION]>  Topic to which the messages will be replayed, optionally with the partition, e.g. orders/replayed/1
+  -b, --batch-size <BATCH_SIZE>        Number of messages polled and sent in a single request [default: 100]
+  -r, --rate <MESSAGES_PER_SECOND>     Maximum number of messages replayed per second, unlimited by default
+      --replayed-from                  Stamp each replayed message with the "replayed-from" header
+  -h, --help                           Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
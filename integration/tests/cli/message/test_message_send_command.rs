@@ -5,7 +5,9 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::consumer::Consumer;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -130,6 +132,7 @@ impl IggyCmdTestCase for TestMessageSendCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -143,6 +146,11 @@ impl IggyCmdTestCase for TestMessageSendCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -200,6 +208,10 @@ impl IggyCmdTestCase for TestMessageSendCmd {
                 strategy: PollingStrategy::offset(0),
                 count: self.messages.len() as u32,
                 auto_commit: false,
+                filter: None,
+                mode: PollingMode::Consume,
+                max_wait_ms: None,
+                max_bytes: None,
             })
             .await;
 
@@ -219,6 +231,7 @@ impl IggyCmdTestCase for TestMessageSendCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic.is_ok());
@@ -226,6 +239,7 @@ impl IggyCmdTestCase for TestMessageSendCmd {
         let stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream.is_ok());
@@ -1,11 +1,16 @@
+mod apply;
 mod client;
 mod common;
+mod connector;
 mod consumer_group;
 mod consumer_offset;
+mod context;
+mod export;
 mod general;
 mod message;
 mod partition;
 mod personal_access_token;
+mod shell;
 mod stream;
 mod system;
 mod topic;
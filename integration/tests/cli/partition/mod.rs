@@ -1,3 +1,4 @@
 mod test_partition_create_command;
 mod test_partition_delete_command;
+mod test_partition_get_command;
 mod test_partition_help_command;
@@ -4,6 +4,8 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -72,6 +74,7 @@ impl IggyCmdTestCase for TestPartitionCreateCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -85,6 +88,11 @@ impl IggyCmdTestCase for TestPartitionCreateCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -141,6 +149,7 @@ impl IggyCmdTestCase for TestPartitionCreateCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic.is_ok());
@@ -148,6 +157,7 @@ impl IggyCmdTestCase for TestPartitionCreateCmd {
         let stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream.is_ok());
@@ -230,8 +240,9 @@ Examples
  iggy partition create prod 2 2
  iggy partition create test sensor 2
  iggy partition create 1 sensor 16
+ iggy partition create 1 sensor 2 --repartition
 
-{USAGE_PREFIX} partition create <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
+{USAGE_PREFIX} partition create [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
 
 Arguments:
   <STREAM_ID>
@@ -248,6 +259,12 @@ Arguments:
           Partitions count to be created
 
 Options:
+  -r, --repartition
+          Copy the keyed messages to their new partitions
+{CLAP_INDENT}
+          The messages appended with the messages key partitioning are copied
+          in the background to the partitions their keys are mapped to after the change.
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -268,7 +285,7 @@ pub async fn should_short_help_match() {
                 r#"Create partitions for the specified topic ID
 and stream ID based on the given count.
 
-{USAGE_PREFIX} partition create <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
+{USAGE_PREFIX} partition create [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
 
 Arguments:
   <STREAM_ID>         Stream ID to create partitions
@@ -276,7 +293,8 @@ Arguments:
   <PARTITIONS_COUNT>  Partitions count to be created
 
 Options:
-  -h, --help  Print help (see more with '--help')
+  -r, --repartition  Copy the keyed messages to their new partitions
+  -h, --help         Print help (see more with '--help')
 "#,
             ),
         ))
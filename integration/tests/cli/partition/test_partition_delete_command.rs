@@ -4,6 +4,8 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -72,6 +74,7 @@ impl IggyCmdTestCase for TestPartitionDeleteCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -85,6 +88,11 @@ impl IggyCmdTestCase for TestPartitionDeleteCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -145,6 +153,7 @@ impl IggyCmdTestCase for TestPartitionDeleteCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic.is_ok());
@@ -152,6 +161,7 @@ impl IggyCmdTestCase for TestPartitionDeleteCmd {
         let stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream.is_ok());
@@ -228,14 +238,17 @@ and stream ID based on the given count.
 
 Stream ID can be specified as a stream name or ID
 Topic ID can be specified as a topic name or ID
+Confirmation is asked when run in the terminal, unless --yes is given
 
 Examples
  iggy partition delete 1 1 10
  iggy partition delete prod 2 2
  iggy partition delete test sensor 2
  iggy partition delete 1 sensor 16
+ iggy partition delete 1 sensor 2 --mode drain
+ iggy partition delete 1 sensor 2 --mode discard --dry-run
 
-{USAGE_PREFIX} partition delete <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
+{USAGE_PREFIX} partition delete [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
 
 Arguments:
   <STREAM_ID>
@@ -252,6 +265,22 @@ Arguments:
           Partitions count to be deleted
 
 Options:
+  -m, --mode <MODE>
+          Messages handling mode
+{CLAP_INDENT}
+          What happens to the messages stored in the deleted partitions
+          "refuse" - the partitions are deleted only if they contain no messages
+          "discard" - the messages are deleted along with the partitions
+          "drain" - the messages and consumer offsets are moved to the remaining partitions
+{CLAP_INDENT}
+          [default: refuse]
+
+      --dry-run
+          Print what would be removed without removing it
+
+  -y, --yes
+          Remove without asking for the confirmation
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -272,7 +301,7 @@ pub async fn should_short_help_match() {
                 r#"Delete partitions for the specified topic ID
 and stream ID based on the given count.
 
-{USAGE_PREFIX} partition delete <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
+{USAGE_PREFIX} partition delete [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT>
 
 Arguments:
   <STREAM_ID>         Stream ID to delete partitions
@@ -280,7 +309,10 @@ Arguments:
   <PARTITIONS_COUNT>  Partitions count to be deleted
 
 Options:
-  -h, --help  Print help (see more with '--help')
+  -m, --mode <MODE>  Messages handling mode [default: refuse]
+      --dry-run      Print what would be removed without removing it
+  -y, --yes          Remove without asking for the confirmation
+  -h, --help         Print help (see more with '--help')
 "#,
             ),
         ))
@@ -0,0 +1,316 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestPartitionGetCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partition_id: u32,
+    messages_count: u32,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    using_path: bool,
+}
+
+impl TestPartitionGetCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partition_id: u32,
+        messages_count: u32,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partition_id,
+            messages_count,
+            using_stream_id,
+            using_topic_id,
+            using_path: false,
+        }
+    }
+
+    fn with_path(self) -> Self {
+        Self {
+            using_path: true,
+            ..self
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        // The stream and topic are given as a path, while the partition is a separate argument
+        if self.using_path {
+            command = vec![command.join("/")];
+        }
+
+        command.push(format!("{}", self.partition_id));
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestPartitionGetCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: Some(self.stream_id),
+                name: self.stream_name.clone(),
+                ..CreateStream::default()
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Some(self.topic_id),
+                partitions_count: 2,
+                name: self.topic_name.clone(),
+                message_expiry: Some(3600),
+                max_topic_size: None,
+                replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let messages = (0..self.messages_count)
+            .map(|n| format!("message {}", n))
+            .filter_map(|s| Message::from_str(s.as_str()).ok())
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partitioning: Partitioning::partition_id(self.partition_id),
+                messages,
+                acks: None,
+            })
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("partition")
+            .arg("get")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let start_message = format!(
+            "Executing get partition with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.partition_id, topic_id, stream_id
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(start_message))
+            .stdout(contains(format!("Partition id   | {}", self.partition_id)))
+            .stdout(contains(format!(
+                "Current offset | {}",
+                self.messages_count - 1
+            )))
+            .stdout(contains(format!(
+                "Messages count | {}",
+                self.messages_count
+            )))
+            .stdout(contains("Segments count | 1"))
+            .stdout(contains(format!(
+                "| 0            | {}",
+                self.messages_count - 1
+            )))
+            .stdout(contains("| false  | 3600           | false   |"));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .delete_topic(&DeleteTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestPartitionGetCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            1,
+            10,
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestPartitionGetCmd::new(
+            2,
+            String::from("customer"),
+            3,
+            String::from("probe"),
+            2,
+            5,
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(
+            TestPartitionGetCmd::new(
+                3,
+                String::from("orders"),
+                2,
+                String::from("events"),
+                1,
+                3,
+                TestStreamId::Named,
+                TestTopicId::Numeric,
+            )
+            .with_path(),
+        )
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "get", "--help"],
+            format!(
+                r#"Get partition details for given partition ID, topic ID and stream ID
+
+Details include the current offset, messages count, size and the segments
+with their offsets, sizes, closed state and message expiry.
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy partition get 1 1 1
+ iggy partition get prod 2 1
+ iggy partition get test sensor 3
+ iggy partition get test/sensor/3
+
+{USAGE_PREFIX} partition get <STREAM_ID> [TOPIC_ID] [PARTITION_ID]
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to get partition
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID, or together with the topic and partition as a path, e.g. orders/events/3
+
+  [TOPIC_ID]
+          Topic ID to get partition
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID, it's omitted when given in the stream path
+
+  [PARTITION_ID]
+          Partition ID to get
+{CLAP_INDENT}
+          Partition ID is omitted when given in the stream path
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "get", "-h"],
+            format!(
+                r#"Get partition details for given partition ID, topic ID and stream ID
+
+{USAGE_PREFIX} partition get <STREAM_ID> [TOPIC_ID] [PARTITION_ID]
+
+Arguments:
+  <STREAM_ID>     Stream ID to get partition
+  [TOPIC_ID]      Topic ID to get partition
+  [PARTITION_ID]  Partition ID to get
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
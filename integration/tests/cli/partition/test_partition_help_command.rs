@@ -19,6 +19,7 @@ Commands:
               and stream ID based on the given count. [aliases: c]
   delete  Delete partitions for the specified topic ID
               and stream ID based on the given count. [aliases: d]
+  get     Get partition details for given partition ID, topic ID and stream ID [aliases: g]
   help    Print this message or the help of the given subcommand(s)
 
 Options:
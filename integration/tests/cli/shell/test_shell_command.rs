@@ -0,0 +1,139 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::identifier::Identifier;
+use iggy::streams::get_stream::GetStream;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+struct TestShellCmd {
+    config_dir: PathBuf,
+    stream_id: u32,
+    name: String,
+}
+
+impl TestShellCmd {
+    fn new(stream_id: u32, name: String) -> Self {
+        Self {
+            config_dir: std::env::temp_dir().join(format!("iggy-cli-{}", Uuid::new_v4())),
+            stream_id,
+            name,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestShellCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("shell")
+            .env("XDG_CONFIG_HOME", self.config_dir.to_str().unwrap())
+            .with_env_credentials()
+    }
+
+    fn provide_stdin_input(&self) -> Option<Vec<String>> {
+        Some(vec![
+            format!("stream create -s {} {}", self.stream_id, self.name),
+            String::from("ls"),
+            String::from("exit"),
+        ])
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(starts_with("Executing interactive shell\n"))
+            .stdout(contains(format!(
+                "Stream with name: {} and ID: {} created\n",
+                self.name, self.stream_id
+            )))
+            .stdout(contains("Executing list streams in table mode\n"))
+            .stdout(contains(format!("| {} ", self.name)));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .get_stream(&GetStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+            })
+            .await;
+        assert!(stream.is_ok());
+        assert_eq!(stream.unwrap().name, self.name);
+
+        let history =
+            std::fs::read_to_string(self.config_dir.join("iggy").join("shell_history")).unwrap();
+        assert!(history.contains(&format!(
+            "stream create -s {} {}",
+            self.stream_id, self.name
+        )));
+        std::fs::remove_dir_all(&self.config_dir).unwrap();
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestShellCmd::new(1, String::from("shell")))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["shell", "--help"],
+            format!(
+                r#"interactive shell
+
+Shell connects and logs in to the server once and executes the commands
+typed without the "iggy" prefix using the same connection until "exit"
+or "quit" is typed. Commands history is kept between the sessions and
+the commands, options, stream and topic names are completed with Tab.
+In addition to all commands the shell accepts the shorthand syntax:
+ ls                  - list streams
+ ls <STREAM>         - list topics of the stream
+ ls <STREAM> <TOPIC> - get topic details
+
+{USAGE_PREFIX} shell
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["shell", "-h"],
+            format!(
+                r#"interactive shell
+
+{USAGE_PREFIX} shell
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -1,8 +1,13 @@
-use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::models::topic_defaults::TopicDefaults;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::{client::Client, identifier::Identifier};
 use predicates::str::diff;
 use serial_test::parallel;
@@ -10,11 +15,16 @@ use serial_test::parallel;
 struct TestStreamCreateCmd {
     stream_id: Option<u32>,
     name: String,
+    topic_defaults: TopicDefaults,
 }
 
 impl TestStreamCreateCmd {
-    fn new(stream_id: Option<u32>, name: String) -> Self {
-        Self { stream_id, name }
+    fn new(stream_id: Option<u32>, name: String, topic_defaults: TopicDefaults) -> Self {
+        Self {
+            stream_id,
+            name,
+            topic_defaults,
+        }
     }
 
     fn to_args(&self) -> Vec<String> {
@@ -27,6 +37,26 @@ impl TestStreamCreateCmd {
 
         args.push(self.name.clone());
 
+        if let Some(message_expiry) = self.topic_defaults.message_expiry {
+            args.push("--message-expiry".to_string());
+            args.push(format!("{}s", message_expiry));
+        }
+
+        if let Some(max_topic_size) = self.topic_defaults.max_topic_size {
+            args.push("--max-topic-size".to_string());
+            args.push(format!("{}", max_topic_size.as_bytes_u64()));
+        }
+
+        if let Some(compression_algorithm) = &self.topic_defaults.compression_algorithm {
+            args.push("--compression-algorithm".to_string());
+            args.push(compression_algorithm.to_string());
+        }
+
+        if let Some(replication_factor) = self.topic_defaults.replication_factor {
+            args.push("--replication-factor".to_string());
+            args.push(format!("{}", replication_factor));
+        }
+
         args
     }
 }
@@ -66,6 +96,7 @@ impl IggyCmdTestCase for TestStreamCreateCmd {
         assert!(stream.is_ok());
         let stream = stream.unwrap();
         assert_eq!(stream.name, self.name);
+        assert_eq!(stream.topic_defaults, self.topic_defaults);
         if let Some(stream_id) = self.stream_id {
             assert_eq!(stream.id, stream_id);
         }
@@ -73,6 +104,7 @@ impl IggyCmdTestCase for TestStreamCreateCmd {
         let delete = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::named(self.name.as_str()).unwrap(),
+                soft: false,
             })
             .await;
         assert!(delete.is_ok());
@@ -86,12 +118,34 @@ pub async fn should_be_successful() {
 
     iggy_cmd_test.setup().await;
     iggy_cmd_test
-        .execute_test(TestStreamCreateCmd::new(Some(123), String::from("main")))
+        .execute_test(TestStreamCreateCmd::new(
+            Some(123),
+            String::from("main"),
+            TopicDefaults::default(),
+        ))
+        .await;
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestStreamCreateCmd::new(
+            None,
+            String::from("prod"),
+            TopicDefaults::default(),
+        ))
         .await;
 
     iggy_cmd_test.setup().await;
     iggy_cmd_test
-        .execute_test(TestStreamCreateCmd::new(None, String::from("prod")))
+        .execute_test(TestStreamCreateCmd::new(
+            None,
+            String::from("defaults"),
+            TopicDefaults {
+                message_expiry: Some(3600),
+                max_topic_size: Some(IggyByteSize::from(1_000_000_000)),
+                compression_algorithm: Some(CompressionAlgorithm::Zstd),
+                replication_factor: Some(2),
+            },
+        ))
         .await;
 }
 
@@ -107,10 +161,12 @@ pub async fn should_help_match() {
                 r#"Create stream with given name
 
 If stream ID is not provided then the server will automatically assign it
+Topic defaults are inherited by the topics created in the stream without explicit values
 
 Examples:
  iggy stream create prod
  iggy stream create -s 1 test
+ iggy stream create prod --message-expiry 7days --max-topic-size 10GB
 
 {USAGE_PREFIX} stream create [OPTIONS] <NAME>
 
@@ -122,6 +178,24 @@ Options:
   -s, --stream-id <STREAM_ID>
           Stream ID to create
 
+      --message-expiry <MESSAGE_EXPIRY>
+          Default message expiry of the topics
+{CLAP_INDENT}
+          Human readable format like 15days 2min 2s
+
+      --max-topic-size <MAX_TOPIC_SIZE>
+          Default max size of the topics
+
+      --compression-algorithm <COMPRESSION_ALGORITHM>
+          Default compression algorithm of the topics
+{CLAP_INDENT}
+          One of: none, gzip, zstd or lz4, the server default is used if not set
+
+      --replication-factor <REPLICATION_FACTOR>
+          Default replication factor of the topics
+{CLAP_INDENT}
+          1 is used if not set
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -147,8 +221,12 @@ Arguments:
   <NAME>  Name of the stream
 
 Options:
-  -s, --stream-id <STREAM_ID>  Stream ID to create
-  -h, --help                   Print help (see more with '--help')
+  -s, --stream-id <STREAM_ID>                          Stream ID to create
+      --message-expiry <MESSAGE_EXPIRY>                Default message expiry of the topics
+      --max-topic-size <MAX_TOPIC_SIZE>                Default max size of the topics
+      --compression-algorithm <COMPRESSION_ALGORITHM>  Default compression algorithm of the topics
+      --replication-factor <REPLICATION_FACTOR>        Default replication factor of the topics
+  -h, --help                                           Print help (see more with '--help')
 "#,
             ),
         ))
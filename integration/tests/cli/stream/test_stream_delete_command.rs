@@ -5,7 +5,10 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::identifier::Identifier;
 use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_stream::GetStream;
 use iggy::streams::get_streams::GetStreams;
 use predicates::str::diff;
 use serial_test::parallel;
@@ -40,6 +43,7 @@ impl IggyCmdTestCase for TestStreamDeleteCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -98,6 +102,74 @@ pub async fn should_be_successful() {
         .await;
 }
 
+struct TestStreamDeleteDryRunCmd {
+    stream_id: u32,
+    name: String,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestStreamDeleteDryRunCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: Some(self.stream_id),
+                name: self.name.clone(),
+                ..CreateStream::default()
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("stream")
+            .arg("delete")
+            .arg(self.name.clone())
+            .arg("--dry-run")
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing delete stream with ID: {}\nStream: {} (ID: {}) with 0 topics, 0 partitions and 0 messages of size 0 B will be deleted\nDry run, nothing has been removed\n",
+            self.name, self.name, self.stream_id
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .get_stream(&GetStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_not_delete_in_dry_run() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestStreamDeleteDryRunCmd {
+            stream_id: 3,
+            name: String::from("staging"),
+        })
+        .await;
+}
+
 #[tokio::test]
 #[parallel]
 pub async fn should_help_match() {
@@ -110,12 +182,16 @@ pub async fn should_help_match() {
                 r"Delete stream with given ID
 
 Stream ID can be specified as a stream name or ID
+Soft deleted stream is moved to the trash and can be restored until the trash retention expires
+Confirmation is asked when run in the terminal, unless --yes is given
 
 Examples:
  iggy stream delete 1
  iggy stream delete test
+ iggy stream delete test --soft
+ iggy stream delete test --dry-run
 
-{USAGE_PREFIX} stream delete <STREAM_ID>
+{USAGE_PREFIX} stream delete [OPTIONS] <STREAM_ID>
 
 Arguments:
   <STREAM_ID>
@@ -124,6 +200,15 @@ Arguments:
           Stream ID can be specified as a stream name or ID
 
 Options:
+      --soft
+          Move the stream to the trash instead of deleting it right away
+
+      --dry-run
+          Print what would be removed without removing it
+
+  -y, --yes
+          Remove without asking for the confirmation
+
   -h, --help
           Print help (see a summary with '-h')
 ",
@@ -143,13 +228,16 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Delete stream with given ID
 
-{USAGE_PREFIX} stream delete <STREAM_ID>
+{USAGE_PREFIX} stream delete [OPTIONS] <STREAM_ID>
 
 Arguments:
   <STREAM_ID>  Stream ID to delete
 
 Options:
-  -h, --help  Print help (see more with '--help')
+      --soft     Move the stream to the trash instead of deleting it right away
+      --dry-run  Print what would be removed without removing it
+  -y, --yes      Remove without asking for the confirmation
+  -h, --help     Print help (see more with '--help')
 "#,
             ),
         ))
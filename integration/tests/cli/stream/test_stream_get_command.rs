@@ -5,14 +5,18 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::identifier::Identifier;
 use iggy::streams::create_stream::CreateStream;
+use iggy::streams::get_stream::GetStream;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
+use uuid::Uuid;
 
 struct TestStreamGetCmd {
     stream_id: u32,
     name: String,
     using_identifier: TestStreamId,
+    uuid: Uuid,
 }
 
 impl TestStreamGetCmd {
@@ -21,6 +25,7 @@ impl TestStreamGetCmd {
             stream_id,
             name,
             using_identifier,
+            uuid: Uuid::nil(),
         }
     }
 
@@ -39,9 +44,17 @@ impl IggyCmdTestCase for TestStreamGetCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
+        let stream = client
+            .get_stream(&GetStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+            })
+            .await;
+        assert!(stream.is_ok());
+        self.uuid = stream.unwrap().uuid;
     }
 
     fn get_command(&self) -> IggyCmdCommand {
@@ -65,10 +78,15 @@ impl IggyCmdTestCase for TestStreamGetCmd {
                 "Stream ID            | {}",
                 self.stream_id
             )))
+            .stdout(contains(format!("Stream UUID          | {}", self.uuid)))
             .stdout(contains(format!("Stream name          | {}", self.name)))
             .stdout(contains("Stream size          | 0"))
             .stdout(contains("Stream message count | 0"))
-            .stdout(contains("Stream topics count  | 0"));
+            .stdout(contains("Stream topics count  | 0"))
+            .stdout(contains("Default topic expiry | unlimited"))
+            .stdout(contains("Default topic size   | unlimited"))
+            .stdout(contains("Default compression  | server default"))
+            .stdout(contains("Default replication  | 1"));
     }
 
     async fn verify_server_state(&self, _client: &dyn Client) {}
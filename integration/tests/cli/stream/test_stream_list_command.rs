@@ -36,6 +36,7 @@ impl IggyCmdTestCase for TestStreamListCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
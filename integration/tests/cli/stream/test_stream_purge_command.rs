@@ -7,6 +7,8 @@ use async_trait::async_trait;
 use iggy::client::Client;
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
@@ -49,6 +51,7 @@ impl IggyCmdTestCase for TestStreamPurgeCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -62,6 +65,11 @@ impl IggyCmdTestCase for TestStreamPurgeCmd {
                 max_topic_size: None,
                 replication_factor: 1,
                 name: self.topic_name.clone(),
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -77,6 +85,7 @@ impl IggyCmdTestCase for TestStreamPurgeCmd {
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
                 partitioning: Partitioning::default(),
                 messages,
+                acks: None,
             })
             .await;
         assert!(send_status.is_ok());
@@ -126,6 +135,7 @@ impl IggyCmdTestCase for TestStreamPurgeCmd {
         let stream_delete = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream_delete.is_ok());
@@ -167,12 +177,14 @@ pub async fn should_help_match() {
 
 Command removes all messages from given stream
 Stream ID can be specified as a stream name or ID
+Confirmation is asked when run in the terminal, unless --yes is given
 
 Examples:
  iggy stream purge 1
  iggy stream purge test
+ iggy stream purge test --yes
 
-{USAGE_PREFIX} stream purge <STREAM_ID>
+{USAGE_PREFIX} stream purge [OPTIONS] <STREAM_ID>
 
 Arguments:
   <STREAM_ID>
@@ -181,6 +193,12 @@ Arguments:
           Stream ID can be specified as a stream name or ID
 
 Options:
+      --dry-run
+          Print what would be removed without removing it
+
+  -y, --yes
+          Remove without asking for the confirmation
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -200,13 +218,15 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Purge all topics in given stream ID
 
-{USAGE_PREFIX} stream purge <STREAM_ID>
+{USAGE_PREFIX} stream purge [OPTIONS] <STREAM_ID>
 
 Arguments:
   <STREAM_ID>  Stream ID to purge
 
 Options:
-  -h, --help  Print help (see more with '--help')
+      --dry-run  Print what would be removed without removing it
+  -y, --yes      Remove without asking for the confirmation
+  -h, --help     Print help (see more with '--help')
 "#,
             ),
         ))
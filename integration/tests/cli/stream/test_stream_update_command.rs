@@ -44,6 +44,7 @@ impl IggyCmdTestCase for TestStreamUpdateCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -114,12 +115,14 @@ pub async fn should_help_match() {
                 r#"Update stream name for given stream ID
 
 Stream ID can be specified as a stream name or ID
+Topic defaults are replaced as a whole if any of them is provided, otherwise they are kept
 
 Examples:
  iggy stream update 1 production
  iggy stream update test development
+ iggy stream update test development --replication-factor 3
 
-{USAGE_PREFIX} stream update <STREAM_ID> <NAME>
+{USAGE_PREFIX} stream update [OPTIONS] <STREAM_ID> <NAME>
 
 Arguments:
   <STREAM_ID>
@@ -131,6 +134,24 @@ Arguments:
           New name for the stream
 
 Options:
+      --message-expiry <MESSAGE_EXPIRY>
+          Default message expiry of the topics
+{CLAP_INDENT}
+          Human readable format like 15days 2min 2s
+
+      --max-topic-size <MAX_TOPIC_SIZE>
+          Default max size of the topics
+
+      --compression-algorithm <COMPRESSION_ALGORITHM>
+          Default compression algorithm of the topics
+{CLAP_INDENT}
+          One of: none, gzip, zstd or lz4, the server default is used if not set
+
+      --replication-factor <REPLICATION_FACTOR>
+          Default replication factor of the topics
+{CLAP_INDENT}
+          1 is used if not set
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -150,14 +171,18 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Update stream name for given stream ID
 
-{USAGE_PREFIX} stream update <STREAM_ID> <NAME>
+{USAGE_PREFIX} stream update [OPTIONS] <STREAM_ID> <NAME>
 
 Arguments:
   <STREAM_ID>  Stream ID to update
   <NAME>       New name for the stream
 
 Options:
-  -h, --help  Print help (see more with '--help')
+      --message-expiry <MESSAGE_EXPIRY>                Default message expiry of the topics
+      --max-topic-size <MAX_TOPIC_SIZE>                Default max size of the topics
+      --compression-algorithm <COMPRESSION_ALGORITHM>  Default compression algorithm of the topics
+      --replication-factor <REPLICATION_FACTOR>        Default replication factor of the topics
+  -h, --help                                           Print help (see more with '--help')
 "#,
             ),
         ))
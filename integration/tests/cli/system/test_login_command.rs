@@ -0,0 +1,166 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::cli::system::session::{ServerSession, SESSION_TOKEN_NAME};
+use iggy::client::Client;
+use iggy::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
+use predicates::str::{contains, starts_with};
+use serial_test::{parallel, serial};
+
+const SERVER_ADDRESS: &str = "127.0.0.1";
+
+#[derive(Debug)]
+enum SessionAction {
+    Login,
+    Logout,
+}
+
+#[derive(Debug)]
+struct TestLoginCmd {
+    action: SessionAction,
+}
+
+impl TestLoginCmd {
+    fn new(action: SessionAction) -> Self {
+        Self { action }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestLoginCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        match self.action {
+            SessionAction::Login => IggyCmdCommand::new().with_cli_credentials().arg("login"),
+            SessionAction::Logout => IggyCmdCommand::new().arg("logout"),
+        }
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        match self.action {
+            SessionAction::Login => command_state
+                .success()
+                .stdout(starts_with(format!(
+                    "Executing login to server: {SERVER_ADDRESS} without session expire time\n"
+                )))
+                .stdout(contains(format!(
+                    "Successfully logged into server: {SERVER_ADDRESS}, session is stored as personal access token: {SESSION_TOKEN_NAME}"
+                ))),
+            SessionAction::Logout => command_state
+                .success()
+                .stdout(starts_with(format!(
+                    "Executing logout from server: {SERVER_ADDRESS}\n"
+                )))
+                .stdout(contains(format!(
+                    "Successfully logged out from server: {SERVER_ADDRESS}"
+                ))),
+        };
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let tokens = client
+            .get_personal_access_tokens(&GetPersonalAccessTokens {})
+            .await
+            .expect("Failed to get personal access tokens");
+        let token_exists = tokens.iter().any(|token| token.name == SESSION_TOKEN_NAME);
+        let stored_token = ServerSession::new(SERVER_ADDRESS.into())
+            .get_token()
+            .expect("Failed to read session token from keyring");
+
+        match self.action {
+            SessionAction::Login => {
+                assert!(token_exists);
+                assert!(stored_token.is_some());
+            }
+            SessionAction::Logout => {
+                assert!(!token_exists);
+                assert!(stored_token.is_none());
+            }
+        }
+    }
+}
+
+// Disable tests due to missing keyring on arm and aarch64 until #294 is implemented
+#[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
+#[tokio::test]
+#[serial]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestLoginCmd::new(SessionAction::Login))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestLoginCmd::new(SessionAction::Logout))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_login_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["login", "--help"],
+            format!(
+                r#"login to iggy server and store the session
+
+Command logs in using the provided credentials, creates personal access
+token named "iggy-cli-session" and stores it inside platform-specific
+secure storage. Following commands use the stored session when no other
+credentials are provided, so neither password nor token value is kept
+in the shell history or environment variables.
+
+Examples:
+ iggy -u iggy login
+ iggy -u iggy login 7days
+
+{USAGE_PREFIX} login [EXPIRY]...
+
+Arguments:
+  [EXPIRY]...
+          Login session expiry time in human readable format
+{CLAP_INDENT}
+          Expiry time must be expressed in human readable format like 15days 2min 2s ("none" or skipping parameter disables login session expiry)
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_logout_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["logout", "--help"],
+            format!(
+                r#"logout from iggy server and remove the stored session
+
+Personal access token created by the login command is deleted on the
+server and removed from platform-specific secure storage.
+
+Examples:
+ iggy logout
+
+{USAGE_PREFIX} logout
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -2,6 +2,7 @@ use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpC
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
 use integration::test_server::TestServer;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -11,6 +12,7 @@ use std::fmt::Display;
 enum Protocol {
     Tcp,
     Quic,
+    ConnectionString,
 }
 
 impl Protocol {
@@ -18,6 +20,7 @@ impl Protocol {
         match self {
             Self::Tcp => vec!["--transport", "tcp"],
             Self::Quic => vec!["--transport", "quic"],
+            Self::ConnectionString => vec![],
         }
     }
 }
@@ -31,7 +34,7 @@ impl Default for Protocol {
 impl Display for Protocol {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Tcp => write!(f, "TCP"),
+            Self::Tcp | Self::ConnectionString => write!(f, "TCP"),
             Self::Quic => write!(f, "QUIC"),
         }
     }
@@ -53,10 +56,12 @@ impl IggyCmdTestCase for TestMeCmd {
     async fn prepare_server_state(&mut self, _client: &dyn Client) {}
 
     fn get_command(&self) -> IggyCmdCommand {
-        IggyCmdCommand::new()
-            .with_env_credentials()
-            .opts(self.protocol.as_arg())
-            .arg("me")
+        let command = match self.protocol {
+            // Credentials are provided in the connection string
+            Protocol::ConnectionString => IggyCmdCommand::new(),
+            _ => IggyCmdCommand::new().with_env_credentials(),
+        };
+        command.opts(self.protocol.as_arg()).arg("me")
     }
 
     fn verify_command(&self, command_state: Assert) {
@@ -78,6 +83,13 @@ impl IggyCmdTestCase for TestMeCmd {
                 "--quic-server-address".into(),
                 server.get_quic_udp_addr().unwrap(),
             ],
+            Protocol::ConnectionString => vec![
+                "--connection-string".into(),
+                format!(
+                    "iggy+tcp://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{}?heartbeat=5s",
+                    server.get_raw_tcp_addr().unwrap()
+                ),
+            ],
         }
     }
 }
@@ -113,6 +125,17 @@ pub async fn should_be_successful_using_transport_quic() {
         .await;
 }
 
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful_using_connection_string() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestMeCmd::new(Protocol::ConnectionString))
+        .await;
+}
+
 #[tokio::test]
 #[parallel]
 pub async fn should_help_match() {
@@ -4,16 +4,27 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use integration::test_server::TestServer;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
 
 struct TestPingCmd {
     count: usize,
+    transports: Vec<String>,
 }
 
 impl Default for TestPingCmd {
     fn default() -> Self {
-        Self { count: 3 }
+        Self {
+            count: 3,
+            transports: vec![],
+        }
+    }
+}
+
+impl TestPingCmd {
+    fn new(count: usize, transports: Vec<String>) -> Self {
+        Self { count, transports }
     }
 }
 
@@ -22,10 +33,19 @@ impl IggyCmdTestCase for TestPingCmd {
     async fn prepare_server_state(&mut self, _client: &dyn Client) {}
 
     fn get_command(&self) -> IggyCmdCommand {
-        IggyCmdCommand::new()
-            .arg("ping")
-            .arg("-c")
-            .arg(format!("{}", self.count))
+        match self.transports.is_empty() {
+            true => IggyCmdCommand::new()
+                .arg("ping")
+                .arg("-c")
+                .arg(format!("{}", self.count)),
+            false => IggyCmdCommand::new()
+                .opts(vec!["--output", "json"])
+                .arg("ping")
+                .arg("-c")
+                .arg(format!("{}", self.count))
+                .arg("--transports")
+                .arg(self.transports.join(",")),
+        }
     }
 
     // Executing ping command
@@ -34,19 +54,39 @@ impl IggyCmdTestCase for TestPingCmd {
     // Ping sequence id:  3 time: 0.73 ms
 
     // Ping statistics for 3 ping commands
-    // min/avg/max77/mdev = 0.393/0.618/0.746/0.116 ms
+    // min/avg/p95/p99/max/mdev = 0.393/0.618/0.746/0.746/0.746/0.116 ms
 
     fn verify_command(&self, command_state: Assert) {
-        command_state
-            .success()
-            .stdout(starts_with("Executing ping command\n"))
-            .stdout(contains(format!(
-                "Ping statistics for {} ping commands",
-                self.count
-            )));
+        if self.transports.is_empty() {
+            command_state
+                .success()
+                .stdout(starts_with("Executing ping command\n"))
+                .stdout(contains(format!(
+                    "Ping statistics for {} ping commands",
+                    self.count
+                )))
+                .stdout(contains("min/avg/p95/p99/max/mdev = "));
+            return;
+        }
+
+        let mut command_state = command_state.success().stdout(starts_with("[\n"));
+        for transport in &self.transports {
+            command_state = command_state
+                .stdout(contains(format!("\"transport\": \"{transport}\"")))
+                .stdout(contains(format!("\"count\": {}", self.count)));
+        }
     }
 
     async fn verify_server_state(&self, _client: &dyn Client) {}
+
+    fn protocol(&self, server: &TestServer) -> Vec<String> {
+        vec![
+            "--tcp-server-address".into(),
+            server.get_raw_tcp_addr().unwrap(),
+            "--http-api-url".into(),
+            format!("http://{}", server.get_http_api_addr().unwrap()),
+        ]
+    }
 }
 
 #[tokio::test]
@@ -58,6 +98,20 @@ pub async fn should_be_successful() {
     iggy_cmd_test.execute_test(TestPingCmd::default()).await;
 }
 
+#[tokio::test]
+#[parallel]
+pub async fn should_compare_transports() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestPingCmd::new(
+            2,
+            vec![String::from("tcp"), String::from("http")],
+        ))
+        .await;
+}
+
 #[tokio::test]
 #[parallel]
 pub async fn should_help_match() {
@@ -79,6 +133,19 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --transports <TRANSPORTS>
+          Ping server using each of given transports and compare the response times
+{CLAP_INDENT}
+          Transports are used sequentially, each with the server address given
+          by the related option (e.g. --tcp-server-address for tcp). Statistics
+          of each transport are printed in the selected output format.
+{CLAP_INDENT}
+          Examples:
+           iggy ping -c 10 --transports tcp,quic,http
+           iggy --output json ping -c 10 --transports tcp,http
+{CLAP_INDENT}
+          [possible values: tcp, quic, http]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -101,8 +168,9 @@ pub async fn should_short_help_match() {
 {USAGE_PREFIX} ping [OPTIONS]
 
 Options:
-  -c, --count <COUNT>  Stop after sending count Ping packets [default: 1]
-  -h, --help           Print help (see more with '--help')
+  -c, --count <COUNT>            Stop after sending count Ping packets [default: 1]
+      --transports <TRANSPORTS>  Ping server using each of given transports and compare the response times [possible values: tcp, quic, http]
+  -h, --help                     Print help (see more with '--help')
 "#,
             ),
         ))
@@ -1,6 +1,10 @@
-use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::{client::Client, identifier::Identifier};
@@ -17,6 +21,7 @@ impl IggyCmdTestCase for TestStatsCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(1),
                 name: stream_id.as_string(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -30,6 +35,11 @@ impl IggyCmdTestCase for TestStatsCmd {
                 max_topic_size: None,
                 replication_factor: 1,
                 name: String::from("topic"),
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -77,9 +87,17 @@ pub async fn should_help_match() {
 
 Collect basic Iggy server statistics like number of streams, topics, partitions, etc. Server OS name, version, etc. are also collected.
 
-{USAGE_PREFIX} stats
+{USAGE_PREFIX} stats [OPTIONS]
 
 Options:
+      --watch <INTERVAL>
+          Refresh the stats dashboard with given interval until interrupted
+{CLAP_INDENT}
+          Dashboard shows the server CPU and memory usage, read and write
+          throughput calculated from the consecutive stats, clients count
+          and sizes of the streams. In the "json" and "csv" output formats
+          the stats are printed with given interval instead.
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -99,10 +117,11 @@ pub async fn should_short_help_match() {
             format!(
                 r#"get iggy server statistics
 
-{USAGE_PREFIX} stats
+{USAGE_PREFIX} stats [OPTIONS]
 
 Options:
-  -h, --help  Print help (see more with '--help')
+      --watch <INTERVAL>  Refresh the stats dashboard with given interval until interrupted
+  -h, --help              Print help (see more with '--help')
 "#,
             ),
         ))
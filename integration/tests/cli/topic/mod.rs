@@ -1,5 +1,7 @@
+mod test_topic_clone_command;
 mod test_topic_create_command;
 mod test_topic_delete_command;
+mod test_topic_freeze_command;
 mod test_topic_get_command;
 mod test_topic_help_command;
 mod test_topic_list_command;
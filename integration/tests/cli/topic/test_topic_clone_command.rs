@@ -0,0 +1,282 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestTopicCloneCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_name: String,
+    target_stream_name: String,
+    target_topic_name: String,
+    with_data: bool,
+}
+
+impl TestTopicCloneCmd {
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_name: String,
+        target_stream_name: String,
+        target_topic_name: String,
+        with_data: bool,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_name,
+            target_stream_name,
+            target_topic_name,
+            with_data,
+        }
+    }
+
+    fn from(&self) -> String {
+        format!("{}/{}", self.stream_name, self.topic_name)
+    }
+
+    fn to(&self) -> String {
+        format!("{}/{}", self.target_stream_name, self.target_topic_name)
+    }
+
+    async fn send_messages(&self, client: &dyn Client, partition_id: u32, count: u32) {
+        let messages = (0..count)
+            .map(|n| format!("message {partition_id}.{n}"))
+            .filter_map(|s| Message::from_str(s.as_str()).ok())
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::named(&self.topic_name).unwrap(),
+                partitioning: Partitioning::partition_id(partition_id),
+                messages,
+                acks: None,
+            })
+            .await;
+        assert!(send_status.is_ok());
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestTopicCloneCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: Some(self.stream_id),
+                name: self.stream_name.clone(),
+                ..CreateStream::default()
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: None,
+                partitions_count: 3,
+                name: self.topic_name.clone(),
+                message_expiry: Some(3600),
+                max_topic_size: None,
+                replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        self.send_messages(client, 1, 5).await;
+        self.send_messages(client, 3, 3).await;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        let command = IggyCmdCommand::new()
+            .arg("topic")
+            .arg("clone")
+            .arg("--from")
+            .arg(self.from())
+            .arg("--to")
+            .arg(self.to())
+            .with_env_credentials();
+
+        match self.with_data {
+            true => command.arg("--with-data"),
+            false => command,
+        }
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let mut message = match self.with_data {
+            true => format!(
+                "Executing clone topic: {} to topic: {} with messages\n",
+                self.from(),
+                self.to()
+            ),
+            false => format!(
+                "Executing clone topic: {} to topic: {}\n",
+                self.from(),
+                self.to()
+            ),
+        };
+        if self.target_stream_name != self.stream_name {
+            message.push_str(&format!("Stream: {} created\n", self.target_stream_name));
+        }
+        message.push_str(&format!(
+            "Topic: {} with 3 partition(s) created\n",
+            self.to()
+        ));
+        if self.with_data {
+            message.push_str(&format!(
+                "Partition: 1 copied 5/5 messages\nPartition: 3 copied 3/3 messages\n8 message(s) copied from topic: {} to topic: {}\n",
+                self.from(),
+                self.to()
+            ));
+        }
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .get_topic(&GetTopic {
+                stream_id: Identifier::named(&self.target_stream_name).unwrap(),
+                topic_id: Identifier::named(&self.target_topic_name).unwrap(),
+            })
+            .await;
+        assert!(topic.is_ok());
+        let topic = topic.unwrap();
+        assert_eq!(topic.partitions_count, 3);
+        assert_eq!(topic.message_expiry, Some(3600));
+        match self.with_data {
+            true => {
+                assert_eq!(topic.messages_count, 8);
+                assert_eq!(topic.partitions[0].messages_count, 5);
+                assert_eq!(topic.partitions[1].messages_count, 0);
+                assert_eq!(topic.partitions[2].messages_count, 3);
+            }
+            false => assert_eq!(topic.messages_count, 0),
+        }
+
+        for stream_name in [&self.stream_name, &self.target_stream_name] {
+            let _ = client
+                .delete_stream(&DeleteStream {
+                    stream_id: Identifier::named(stream_name).unwrap(),
+                    soft: false,
+                })
+                .await;
+        }
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestTopicCloneCmd::new(
+            1,
+            String::from("prod"),
+            String::from("orders"),
+            String::from("prod"),
+            String::from("orders-backup"),
+            false,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestTopicCloneCmd::new(
+            2,
+            String::from("main"),
+            String::from("events"),
+            String::from("staging"),
+            String::from("events"),
+            true,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic", "clone", "--help"],
+            format!(
+                r"Clone topic configuration and optionally its messages to another topic
+
+Topics are specified as stream/topic, stream and topic can be given as a name or ID
+Target stream is created if it does not exist, target topic must not exist
+Target topic can be created on the server of given context
+
+Examples
+ iggy topic clone --from prod/orders --to prod/orders-backup
+ iggy topic clone --from prod/orders --to staging/orders --with-data
+ iggy topic clone --from prod/orders --to prod/orders --to-context staging --with-data
+
+{USAGE_PREFIX} topic clone [OPTIONS] --from <FROM> --to <TO>
+
+Options:
+      --from <FROM>
+          Topic to clone given as stream/topic
+
+      --to <TO>
+          Topic to create given as stream/topic
+
+      --with-data
+          Copy the messages of the topic preserving their partitions
+
+      --to-context <TO_CONTEXT>
+          Name of the context of the server on which the topic is created
+{CLAP_INDENT}
+          Only the connection options and credentials stored in the context are used.
+
+  -h, --help
+          Print help (see a summary with '-h')
+",
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic", "clone", "-h"],
+            format!(
+                r#"Clone topic configuration and optionally its messages to another topic
+
+{USAGE_PREFIX} topic clone [OPTIONS] --from <FROM> --to <TO>
+
+Options:
+      --from <FROM>              Topic to clone given as stream/topic
+      --to <TO>                  Topic to create given as stream/topic
+      --with-data                Copy the messages of the topic preserving their partitions
+      --to-context <TO_CONTEXT>  Name of the context of the server on which the topic is created
+  -h, --help                     Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
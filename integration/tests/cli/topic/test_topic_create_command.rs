@@ -25,6 +25,7 @@ struct TestTopicCreateCmd {
     message_expiry: Option<Vec<String>>,
     max_topic_size: Option<IggyByteSize>,
     replication_factor: u8,
+    message_schema: Option<String>,
     using_identifier: TestStreamId,
 }
 
@@ -50,10 +51,16 @@ impl TestTopicCreateCmd {
             message_expiry,
             max_topic_size,
             replication_factor,
+            message_schema: None,
             using_identifier,
         }
     }
 
+    fn with_message_schema(mut self, message_schema: &str) -> Self {
+        self.message_schema = Some(message_schema.to_string());
+        self
+    }
+
     fn to_args(&self) -> Vec<String> {
         let mut args = Vec::new();
 
@@ -67,6 +74,10 @@ impl TestTopicCreateCmd {
             TestStreamId::Named => args.extend(vec![self.stream_name.clone()]),
         };
 
+        if let Some(message_schema) = &self.message_schema {
+            args.push(format!("--message-schema={}", message_schema));
+        }
+
         args.push(self.topic_name.clone());
         args.push(format!("{}", self.partitions_count));
         args.extend(self.message_expiry.clone().unwrap_or_default());
@@ -82,6 +93,7 @@ impl IggyCmdTestCase for TestTopicCreateCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -142,6 +154,7 @@ impl IggyCmdTestCase for TestTopicCreateCmd {
         assert_eq!(topic_details.name, self.topic_name);
         assert_eq!(topic_details.partitions_count, self.partitions_count);
         assert_eq!(topic_details.messages_count, 0);
+        assert_eq!(topic_details.message_schema, self.message_schema);
         if let Some(topic_id) = self.topic_id {
             assert_eq!(topic_details.id, topic_id);
         }
@@ -164,6 +177,7 @@ impl IggyCmdTestCase for TestTopicCreateCmd {
             .delete_topic(&DeleteTopic {
                 topic_id: Identifier::named(self.topic_name.as_str()).unwrap(),
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(delete_topic.is_ok());
@@ -171,6 +185,7 @@ impl IggyCmdTestCase for TestTopicCreateCmd {
         let delete_stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(delete_stream.is_ok());
@@ -240,6 +255,22 @@ pub async fn should_be_successful() {
             TestStreamId::Numeric,
         ))
         .await;
+    iggy_cmd_test
+        .execute_test(
+            TestTopicCreateCmd::new(
+                5,
+                String::from("schemas"),
+                None,
+                String::from("orders"),
+                1,
+                None,
+                None,
+                1,
+                TestStreamId::Numeric,
+            )
+            .with_message_schema(r#"{"type":"object","required":["id"]}"#),
+        )
+        .await;
 }
 
 #[tokio::test]
@@ -298,6 +329,36 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --timestamp-type <TIMESTAMP_TYPE>
+          Timestamp type
+{CLAP_INDENT}
+          Stored with the messages and used by the time based polling and the message expiry
+          "log_append_time" - the time when the message was appended by the server
+          "create_time" - the time set by the producer in the "$iggy.create_timestamp" header
+{CLAP_INDENT}
+          [default: log_append_time]
+
+      --max-groups <MAX_GROUPS>
+          Max consumer groups in the topic
+{CLAP_INDENT}
+          (skipping parameter allows unlimited number of consumer groups)
+
+      --max-members <MAX_MEMBERS>
+          Max members in each consumer group
+{CLAP_INDENT}
+          (skipping parameter allows unlimited number of members)
+
+      --max-message-size <MAX_MESSAGE_SIZE>
+          Max size of the message payload
+{CLAP_INDENT}
+          (skipping parameter uses the default max message size from the server config)
+
+      --message-schema <MESSAGE_SCHEMA>
+          JSON Schema of the message payload
+{CLAP_INDENT}
+          The payloads of the messages sent to the topic must conform to it
+          (skipping parameter allows any message payload)
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -328,6 +389,11 @@ Options:
   -t, --topic-id <TOPIC_ID>                      Topic ID to create
   -m, --max-topic-size <MAX_TOPIC_SIZE>          Max topic size [default: unlimited]
   -r, --replication-factor <REPLICATION_FACTOR>  Replication factor for the topic [default: 1]
+      --timestamp-type <TIMESTAMP_TYPE>          Timestamp type [default: log_append_time]
+      --max-groups <MAX_GROUPS>                  Max consumer groups in the topic
+      --max-members <MAX_MEMBERS>                Max members in each consumer group
+      --max-message-size <MAX_MESSAGE_SIZE>      Max size of the message payload
+      --message-schema <MESSAGE_SCHEMA>          JSON Schema of the message payload
   -h, --help                                     Print help (see more with '--help')
 "#,
             ),
@@ -4,6 +4,8 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::get_topics::GetTopics;
@@ -61,6 +63,7 @@ impl IggyCmdTestCase for TestTopicDeleteCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -74,6 +77,11 @@ impl IggyCmdTestCase for TestTopicDeleteCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -180,27 +188,40 @@ pub async fn should_help_match() {
 
 Stream ID can be specified as a stream name or ID
 Topic ID can be specified as a topic name or ID
+Confirmation is asked when run in the terminal, unless --yes is given
 
 Examples
  iggy topic delete 1 1
  iggy topic delete prod 2
  iggy topic delete test debugs
  iggy topic delete 2 debugs
+ iggy topic delete test/debugs
+ iggy topic delete test/debugs --soft
+ iggy topic delete test/debugs --dry-run
 
-{USAGE_PREFIX} topic delete <STREAM_ID> <TOPIC_ID>
+{USAGE_PREFIX} topic delete [OPTIONS] <STREAM_ID> [TOPIC_ID]
 
 Arguments:
   <STREAM_ID>
           Stream ID to delete topic
 {CLAP_INDENT}
-          Stream ID can be specified as a stream name or ID
+          Stream ID can be specified as a stream name or ID, or together with the topic as a path, e.g. orders/events
 
-  <TOPIC_ID>
+  [TOPIC_ID]
           Topic ID to delete
 {CLAP_INDENT}
-          Topic ID can be specified as a topic name or ID
+          Topic ID can be specified as a topic name or ID, it's omitted when given in the stream path
 
 Options:
+      --soft
+          Move the topic to the trash instead of deleting it right away
+
+      --dry-run
+          Print what would be removed without removing it
+
+  -y, --yes
+          Remove without asking for the confirmation
+
   -h, --help
           Print help (see a summary with '-h')
 ",
@@ -220,14 +241,17 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Delete topic with given ID in given stream ID
 
-{USAGE_PREFIX} topic delete <STREAM_ID> <TOPIC_ID>
+{USAGE_PREFIX} topic delete [OPTIONS] <STREAM_ID> [TOPIC_ID]
 
 Arguments:
   <STREAM_ID>  Stream ID to delete topic
-  <TOPIC_ID>   Topic ID to delete
+  [TOPIC_ID]   Topic ID to delete
 
 Options:
-  -h, --help  Print help (see more with '--help')
+      --soft     Move the topic to the trash instead of deleting it right away
+      --dry-run  Print what would be removed without removing it
+  -y, --yes      Remove without asking for the confirmation
+  -h, --help     Print help (see more with '--help')
 "#,
             ),
         ))
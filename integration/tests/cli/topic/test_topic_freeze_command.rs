@@ -0,0 +1,324 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::freeze_topic::FreezeTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestTopicFreezeCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    frozen: bool,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestTopicFreezeCmd {
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        frozen: bool,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            frozen,
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn action(&self) -> &'static str {
+        match self.frozen {
+            true => "freeze",
+            false => "unfreeze",
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestTopicFreezeCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: Some(self.stream_id),
+                name: self.stream_name.clone(),
+                ..CreateStream::default()
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Some(self.topic_id),
+                name: self.topic_name.clone(),
+                ..CreateTopic::default()
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        // The topic is frozen up front to be unfrozen by the command.
+        if !self.frozen {
+            let freeze = client
+                .freeze_topic(&FreezeTopic {
+                    stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                    topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                })
+                .await;
+            assert!(freeze.is_ok());
+        }
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("topic")
+            .arg(self.action())
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let state = match self.frozen {
+            true => "frozen",
+            false => "unfrozen",
+        };
+        let message = format!("Executing {} topic with ID: {} in stream with ID: {}\nTopic with ID: {} in stream with ID: {} {}\n",
+                              self.action(), topic_id, stream_id, topic_id, stream_id, state);
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .get_topic(&GetTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+            })
+            .await;
+        assert!(topic.is_ok());
+        assert_eq!(topic.unwrap().frozen, self.frozen);
+
+        let stream_delete = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
+            })
+            .await;
+        assert!(stream_delete.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestTopicFreezeCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            true,
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestTopicFreezeCmd::new(
+            2,
+            String::from("testing"),
+            2,
+            String::from("topic"),
+            true,
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestTopicFreezeCmd::new(
+            3,
+            String::from("prod"),
+            1,
+            String::from("named"),
+            false,
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestTopicFreezeCmd::new(
+            4,
+            String::from("big"),
+            1,
+            String::from("probe"),
+            false,
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic", "freeze", "--help"],
+            format!(
+                r"Freeze topic with given ID in given stream ID
+
+Frozen topic rejects new messages, while the existing ones can still be polled
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy topic freeze 1 1
+ iggy topic freeze prod 2
+ iggy topic freeze test debugs
+ iggy topic freeze test/debugs
+
+{USAGE_PREFIX} topic freeze <STREAM_ID> [TOPIC_ID]
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to freeze topic
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID, or together with the topic as a path, e.g. orders/events
+
+  [TOPIC_ID]
+          Topic ID to freeze
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID, it's omitted when given in the stream path
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+",
+            ),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic", "unfreeze", "--help"],
+            format!(
+                r"Unfreeze topic with given ID in given stream ID
+
+Unfrozen topic accepts new messages again
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy topic unfreeze 1 1
+ iggy topic unfreeze prod 2
+ iggy topic unfreeze test debugs
+ iggy topic unfreeze test/debugs
+
+{USAGE_PREFIX} topic unfreeze <STREAM_ID> [TOPIC_ID]
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to unfreeze topic
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID, or together with the topic as a path, e.g. orders/events
+
+  [TOPIC_ID]
+          Topic ID to unfreeze
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID, it's omitted when given in the stream path
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+",
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic", "freeze", "-h"],
+            format!(
+                r#"Freeze topic with given ID in given stream ID
+
+{USAGE_PREFIX} topic freeze <STREAM_ID> [TOPIC_ID]
+
+Arguments:
+  <STREAM_ID>  Stream ID to freeze topic
+  [TOPIC_ID]   Topic ID to freeze
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic", "unfreeze", "-h"],
+            format!(
+                r#"Unfreeze topic with given ID in given stream ID
+
+{USAGE_PREFIX} topic unfreeze <STREAM_ID> [TOPIC_ID]
+
+Arguments:
+  <STREAM_ID>  Stream ID to unfreeze topic
+  [TOPIC_ID]   Topic ID to unfreeze
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -4,6 +4,8 @@ use crate::cli::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -19,6 +21,7 @@ struct TestTopicGetCmd {
     topic_name: String,
     using_stream_id: TestStreamId,
     using_topic_id: TestTopicId,
+    using_path: bool,
 }
 
 impl TestTopicGetCmd {
@@ -37,6 +40,14 @@ impl TestTopicGetCmd {
             topic_name,
             using_stream_id,
             using_topic_id,
+            using_path: false,
+        }
+    }
+
+    fn with_path(self) -> Self {
+        Self {
+            using_path: true,
+            ..self
         }
     }
 
@@ -51,7 +62,10 @@ impl TestTopicGetCmd {
             TestTopicId::Named => self.topic_name.clone(),
         });
 
-        command
+        match self.using_path {
+            true => vec![command.join("/")],
+            false => command,
+        }
     }
 }
 
@@ -62,6 +76,7 @@ impl IggyCmdTestCase for TestTopicGetCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -75,6 +90,11 @@ impl IggyCmdTestCase for TestTopicGetCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -116,7 +136,12 @@ impl IggyCmdTestCase for TestTopicGetCmd {
             .stdout(contains("Message expiry      | unlimited"))
             .stdout(contains("Max topic size      | unlimited"))
             .stdout(contains("Topic message count | 0"))
-            .stdout(contains("Partitions count    | 1"));
+            .stdout(contains("Partitions count    | 1"))
+            .stdout(contains("Routing epoch       | 0"))
+            .stdout(contains("Compression         | none"))
+            .stdout(contains("Frozen              | false"))
+            .stdout(contains("Max consumer groups | unlimited"))
+            .stdout(contains("Max group members   | unlimited"));
     }
 
     async fn verify_server_state(&self, client: &dyn Client) {
@@ -124,6 +149,7 @@ impl IggyCmdTestCase for TestTopicGetCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic.is_ok());
@@ -131,6 +157,7 @@ impl IggyCmdTestCase for TestTopicGetCmd {
         let stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream.is_ok());
@@ -183,6 +210,19 @@ pub async fn should_be_successful() {
             TestTopicId::Numeric,
         ))
         .await;
+    iggy_cmd_test
+        .execute_test(
+            TestTopicGetCmd::new(
+                3,
+                String::from("orders"),
+                5,
+                String::from("events"),
+                TestStreamId::Named,
+                TestTopicId::Named,
+            )
+            .with_path(),
+        )
+        .await;
 }
 
 #[tokio::test]
@@ -204,19 +244,20 @@ Examples
  iggy topic get prod 2
  iggy topic get test debugs
  iggy topic get 2 debugs
+ iggy topic get test/debugs
 
-{USAGE_PREFIX} topic get <STREAM_ID> <TOPIC_ID>
+{USAGE_PREFIX} topic get <STREAM_ID> [TOPIC_ID]
 
 Arguments:
   <STREAM_ID>
           Stream ID to get topic
 {CLAP_INDENT}
-          Stream ID can be specified as a stream name or ID
+          Stream ID can be specified as a stream name or ID, or together with the topic as a path, e.g. orders/events
 
-  <TOPIC_ID>
+  [TOPIC_ID]
           Topic ID to get
 {CLAP_INDENT}
-          Topic ID can be specified as a topic name or ID
+          Topic ID can be specified as a topic name or ID, it's omitted when given in the stream path
 
 Options:
   -h, --help
@@ -238,11 +279,11 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Get topic detail for given topic ID and stream ID
 
-{USAGE_PREFIX} topic get <STREAM_ID> <TOPIC_ID>
+{USAGE_PREFIX} topic get <STREAM_ID> [TOPIC_ID]
 
 Arguments:
   <STREAM_ID>  Stream ID to get topic
-  <TOPIC_ID>   Topic ID to get
+  [TOPIC_ID]   Topic ID to get
 
 Options:
   -h, --help  Print help (see more with '--help')
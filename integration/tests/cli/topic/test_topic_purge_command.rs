@@ -5,6 +5,8 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -65,6 +67,7 @@ impl IggyCmdTestCase for TestTopicPurgeCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -78,6 +81,11 @@ impl IggyCmdTestCase for TestTopicPurgeCmd {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -93,6 +101,7 @@ impl IggyCmdTestCase for TestTopicPurgeCmd {
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
                 partitioning: Partitioning::default(),
                 messages,
+                acks: None,
             })
             .await;
         assert!(send_status.is_ok());
@@ -148,6 +157,7 @@ impl IggyCmdTestCase for TestTopicPurgeCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic_delete.is_ok());
@@ -155,6 +165,7 @@ impl IggyCmdTestCase for TestTopicPurgeCmd {
         let stream_delete = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream_delete.is_ok());
@@ -223,27 +234,36 @@ pub async fn should_help_match() {
 Command removes all messages from given topic
 Stream ID can be specified as a stream name or ID
 Topic ID can be specified as a topic name or ID
+Confirmation is asked when run in the terminal, unless --yes is given
 
 Examples
  iggy topic purge 1 1
  iggy topic purge prod 2
  iggy topic purge test debugs
  iggy topic purge 2 debugs
+ iggy topic purge test/debugs
+ iggy topic purge test/debugs --yes
 
-{USAGE_PREFIX} topic purge <STREAM_ID> <TOPIC_ID>
+{USAGE_PREFIX} topic purge [OPTIONS] <STREAM_ID> [TOPIC_ID]
 
 Arguments:
   <STREAM_ID>
           Stream ID to purge topic
 {CLAP_INDENT}
-          Stream ID can be specified as a stream name or ID
+          Stream ID can be specified as a stream name or ID, or together with the topic as a path, e.g. orders/events
 
-  <TOPIC_ID>
+  [TOPIC_ID]
           Topic ID to purge
 {CLAP_INDENT}
-          Topic ID can be specified as a topic name or ID
+          Topic ID can be specified as a topic name or ID, it's omitted when given in the stream path
 
 Options:
+      --dry-run
+          Print what would be removed without removing it
+
+  -y, --yes
+          Remove without asking for the confirmation
+
   -h, --help
           Print help (see a summary with '-h')
 ",
@@ -263,14 +283,16 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Purge topic with given ID in given stream ID
 
-{USAGE_PREFIX} topic purge <STREAM_ID> <TOPIC_ID>
+{USAGE_PREFIX} topic purge [OPTIONS] <STREAM_ID> [TOPIC_ID]
 
 Arguments:
   <STREAM_ID>  Stream ID to purge topic
-  <TOPIC_ID>   Topic ID to purge
+  [TOPIC_ID]   Topic ID to purge
 
 Options:
-  -h, --help  Print help (see more with '--help')
+      --dry-run  Print what would be removed without removing it
+  -y, --yes      Remove without asking for the confirmation
+  -h, --help     Print help (see more with '--help')
 "#,
             ),
         ))
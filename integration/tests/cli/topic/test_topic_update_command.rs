@@ -6,6 +6,8 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use humantime::Duration as HumanDuration;
 use iggy::cli::utils::message_expiry::MessageExpiry;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -29,6 +31,8 @@ struct TestTopicUpdateCmd {
     topic_new_message_expiry: Option<Vec<String>>,
     topic_new_max_size: Option<IggyByteSize>,
     topic_new_replication_factor: u8,
+    message_schema: Option<String>,
+    topic_new_message_schema: Option<String>,
     using_stream_id: TestStreamId,
     using_topic_id: TestTopicId,
 }
@@ -62,11 +66,23 @@ impl TestTopicUpdateCmd {
             topic_new_message_expiry,
             topic_new_max_size,
             topic_new_replication_factor,
+            message_schema: None,
+            topic_new_message_schema: None,
             using_stream_id,
             using_topic_id,
         }
     }
 
+    fn with_message_schemas(
+        mut self,
+        message_schema: Option<&str>,
+        topic_new_message_schema: Option<&str>,
+    ) -> Self {
+        self.message_schema = message_schema.map(|schema| schema.to_string());
+        self.topic_new_message_schema = topic_new_message_schema.map(|schema| schema.to_string());
+        self
+    }
+
     fn to_args(&self) -> Vec<String> {
         let mut command = match self.using_stream_id {
             TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
@@ -91,6 +107,10 @@ impl TestTopicUpdateCmd {
             ));
         }
 
+        if let Some(message_schema) = &self.topic_new_message_schema {
+            command.push(format!("--message-schema={}", message_schema));
+        }
+
         if let Some(message_expiry) = &self.topic_new_message_expiry {
             command.extend(message_expiry.clone());
         }
@@ -106,6 +126,7 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
             .create_stream(&CreateStream {
                 stream_id: Some(self.stream_id),
                 name: self.stream_name.clone(),
+                ..CreateStream::default()
             })
             .await;
         assert!(stream.is_ok());
@@ -131,6 +152,11 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: self.replication_factor,
+                message_schema: self.message_schema.clone(),
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -191,6 +217,13 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
         assert_eq!(topic_details.name, self.topic_new_name);
         assert_eq!(topic_details.id, self.topic_id);
         assert_eq!(topic_details.messages_count, 0);
+        // The current message schema is kept, unless the new one is given.
+        assert_eq!(
+            topic_details.message_schema,
+            self.topic_new_message_schema
+                .clone()
+                .or(self.message_schema.clone())
+        );
 
         if self.topic_new_message_expiry.is_some() {
             let duration: Duration = *self
@@ -210,6 +243,7 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
             .delete_topic(&DeleteTopic {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
                 topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(topic.is_ok());
@@ -217,6 +251,7 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
         let stream = client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                soft: false,
             })
             .await;
         assert!(stream.is_ok());
@@ -339,6 +374,49 @@ pub async fn should_be_successful() {
             TestTopicId::Named,
         ))
         .await;
+    iggy_cmd_test
+        .execute_test(
+            TestTopicUpdateCmd::new(
+                5,
+                String::from("schemas"),
+                1,
+                String::from("orders"),
+                None,
+                None,
+                1,
+                String::from("orders"),
+                None,
+                None,
+                1,
+                TestStreamId::Numeric,
+                TestTopicId::Numeric,
+            )
+            .with_message_schemas(Some(r#"{"type":"object","required":["id"]}"#), None),
+        )
+        .await;
+    iggy_cmd_test
+        .execute_test(
+            TestTopicUpdateCmd::new(
+                6,
+                String::from("schemas"),
+                1,
+                String::from("orders"),
+                None,
+                None,
+                1,
+                String::from("orders"),
+                None,
+                None,
+                1,
+                TestStreamId::Numeric,
+                TestTopicId::Numeric,
+            )
+            .with_message_schemas(
+                Some(r#"{"type":"object","required":["id"]}"#),
+                Some(r#"{"type":"object","required":["id","name"]}"#),
+            ),
+        )
+        .await;
 }
 
 #[tokio::test]
@@ -397,6 +475,39 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+      --timestamp-type <TIMESTAMP_TYPE>
+          New timestamp type
+{CLAP_INDENT}
+          Stored with the messages and used by the time based polling and the message expiry
+          "log_append_time" - the time when the message was appended by the server
+          "create_time" - the time set by the producer in the "$iggy.create_timestamp" header
+{CLAP_INDENT}
+          [default: log_append_time]
+
+      --max-groups <MAX_GROUPS>
+          New max consumer groups in the topic
+{CLAP_INDENT}
+          (skipping parameter causes removal of the limit of consumer groups in topic)
+
+      --max-members <MAX_MEMBERS>
+          New max members in each consumer group
+{CLAP_INDENT}
+          (skipping parameter causes removal of the limit of members in topic)
+
+      --max-message-size <MAX_MESSAGE_SIZE>
+          New max size of the message payload
+{CLAP_INDENT}
+          (skipping parameter causes using the default max message size from the server config)
+
+      --message-schema <MESSAGE_SCHEMA>
+          New JSON Schema of the message payload
+{CLAP_INDENT}
+          The payloads of the messages sent to the topic must conform to it
+          (skipping parameter keeps the current message schema of the topic)
+
+      --remove-message-schema
+          Remove the message schema of the topic
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -427,6 +538,12 @@ Arguments:
 Options:
   -m, --max-topic-size <MAX_TOPIC_SIZE>          New max topic size [default: unlimited]
   -r, --replication-factor <REPLICATION_FACTOR>  New replication factor for the topic [default: 1]
+      --timestamp-type <TIMESTAMP_TYPE>          New timestamp type [default: log_append_time]
+      --max-groups <MAX_GROUPS>                  New max consumer groups in the topic
+      --max-members <MAX_MEMBERS>                New max members in each consumer group
+      --max-message-size <MAX_MESSAGE_SIZE>      New max size of the message payload
+      --message-schema <MESSAGE_SCHEMA>          New JSON Schema of the message payload
+      --remove-message-schema                    Remove the message schema of the topic
   -h, --help                                     Print help (see more with '--help')
 "#,
             ),
@@ -3,6 +3,7 @@ use iggy::models::permissions::Permissions;
 #[derive(Debug, Clone, Default)]
 pub(crate) struct PermissionsTestArgs {
     pub(crate) global_permissions: Option<String>,
+    pub(crate) template: Option<String>,
     pub(crate) stream_permissions: Vec<String>,
     pub(crate) expected_permissions: Option<Permissions>,
 }
@@ -15,11 +16,17 @@ impl PermissionsTestArgs {
     ) -> Self {
         Self {
             global_permissions,
+            template: None,
             stream_permissions,
             expected_permissions,
         }
     }
 
+    pub(crate) fn with_template(mut self, template: &str) -> Self {
+        self.template = Some(String::from(template));
+        self
+    }
+
     pub(crate) fn as_arg(&self) -> Vec<String> {
         let mut args = vec![];
         if let Some(global_permissions) = &self.global_permissions {
@@ -27,6 +34,11 @@ impl PermissionsTestArgs {
             args.push(global_permissions.clone());
         }
 
+        if let Some(template) = &self.template {
+            args.push(String::from("--template"));
+            args.push(template.clone());
+        }
+
         args.extend(
             self.stream_permissions
                 .iter()
@@ -0,0 +1,239 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::identifier::Identifier;
+use iggy::models::permissions::{GlobalPermissions, Permissions, StreamPermissions};
+use iggy::models::user_status::UserStatus;
+use iggy::users::delete_user::DeleteUser;
+use iggy::{client::Client, users::create_user::CreateUser};
+use predicates::str::{contains, is_match, starts_with};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+struct TestUserCheckCmd {
+    username: String,
+    action: String,
+    stream_id: Option<u32>,
+    topic_id: Option<u32>,
+    permissions: Option<Permissions>,
+    expected_allowed: bool,
+    expected_rule: String,
+}
+
+impl TestUserCheckCmd {
+    fn new(
+        username: &str,
+        action: &str,
+        stream_id: Option<u32>,
+        topic_id: Option<u32>,
+        permissions: Option<Permissions>,
+        expected_allowed: bool,
+        expected_rule: &str,
+    ) -> Self {
+        Self {
+            username: username.into(),
+            action: action.into(),
+            stream_id,
+            topic_id,
+            permissions,
+            expected_allowed,
+            expected_rule: expected_rule.into(),
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = vec![self.username.clone(), self.action.clone()];
+        if let Some(stream_id) = self.stream_id {
+            args.push("--stream-id".into());
+            args.push(format!("{stream_id}"));
+        }
+        if let Some(topic_id) = self.topic_id {
+            args.push("--topic-id".into());
+            args.push(format!("{topic_id}"));
+        }
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserCheckCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let create_user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                status: UserStatus::Active,
+                permissions: self.permissions.clone(),
+                ..Default::default()
+            })
+            .await;
+        assert!(create_user.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("check")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let allowed = match self.expected_allowed {
+            true => "yes",
+            false => "no",
+        };
+
+        command_state
+            .success()
+            .stdout(starts_with(format!(
+                "Executing check permission: {} for user with ID: {}",
+                self.action, self.username
+            )))
+            .stdout(is_match(format!("| Allowed[ ]+| {allowed}")).unwrap())
+            .stdout(contains(self.expected_rule.as_str()));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let deleted = client
+            .delete_user(&DeleteUser {
+                user_id: Identifier::from_str_value(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserCheckCmd::new(
+            "reader",
+            "read_streams",
+            None,
+            None,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    ..Default::default()
+                },
+                streams: None,
+            }),
+            true,
+            "global.read_streams",
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserCheckCmd::new(
+            "consumer",
+            "poll_messages",
+            Some(1),
+            Some(2),
+            Some(Permissions {
+                global: Default::default(),
+                streams: Some(HashMap::from([(
+                    1,
+                    StreamPermissions {
+                        poll_messages: true,
+                        ..Default::default()
+                    },
+                )])),
+            }),
+            true,
+            "streams[1].poll_messages",
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserCheckCmd::new(
+            "guest",
+            "create_topic",
+            Some(1),
+            None,
+            None,
+            false,
+            "missing any of: global.manage_streams, global.manage_topics, streams[1].manage_topics",
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "check", "--help"],
+            format!(
+                r#"Check whether action is allowed for user with given ID
+
+The user ID can be specified as either a username or an ID. Action is
+checked against the effective permissions of the user (its own and its
+user groups ones) and the permission which granted or denied it is printed.
+Stream level actions require the stream ID and topic level actions require
+both the stream ID and the topic ID.
+
+Examples:
+ iggy user check 2 read_streams
+ iggy user check client create_topic --stream-id 1
+ iggy user check client poll_messages -s 1 -t 2
+
+{USAGE_PREFIX} user check [OPTIONS] <USER_ID> <ACTION>
+
+Arguments:
+  <USER_ID>
+          User ID to check
+{CLAP_INDENT}
+          The user ID can be specified as either a username or an ID
+
+  <ACTION>
+          Action to check
+{CLAP_INDENT}
+          [possible values: read_servers, manage_servers, read_users, manage_users, read_streams, create_stream, read_stream, manage_stream, read_topic, create_topic, manage_topic, poll_messages, send_messages, manage_partitions, manage_consumer_groups]
+
+Options:
+  -s, --stream-id <STREAM_ID>
+          Stream ID, required for stream and topic level actions
+
+  -t, --topic-id <TOPIC_ID>
+          Topic ID, required for topic level actions
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "check", "-h"],
+            format!(
+                r#"Check whether action is allowed for user with given ID
+
+{USAGE_PREFIX} user check [OPTIONS] <USER_ID> <ACTION>
+
+Arguments:
+  <USER_ID>  User ID to check
+  <ACTION>   Action to check [possible values: read_servers, manage_servers, read_users, manage_users, read_streams, create_stream, read_stream, manage_stream, read_topic, create_topic, manage_topic, poll_messages, send_messages, manage_partitions, manage_consumer_groups]
+
+Options:
+  -s, --stream-id <STREAM_ID>  Stream ID, required for stream and topic level actions
+  -t, --topic-id <TOPIC_ID>    Topic ID, required for topic level actions
+  -h, --help                   Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -162,6 +162,7 @@ pub async fn should_be_successful() {
                         read_topics: true,
                         poll_messages: false,
                         send_messages: false,
+                        auto_create_topics: false,
                     },
                     streams: None,
                 }),
@@ -232,6 +233,7 @@ pub async fn should_be_successful() {
                         read_topics: false,
                         poll_messages: false,
                         send_messages: false,
+                        auto_create_topics: false,
                     },
                     streams: Some(HashMap::from([(
                         2u32,
@@ -302,12 +304,27 @@ Options:
           Available permissions (long and short versions):  manage_servers / m_srv,
           read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
           manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
-          read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+          read_topics / r_top, poll_messages / p_msg, send_messages / s_msg,
+          auto_create_topics / a_top
 {CLAP_INDENT}
           Examples:
            iggy user create guest guess --global-permissions p_msg,s_msg
            iggy user create admin pass#1%X! -g m_srv,r_srv,m_usr,r_usr,m_str,r_str,m_top,r_top,p_msg,s_msg
 
+      --template <TEMPLATE>
+          Set global permissions using template
+{CLAP_INDENT}
+          Template sets the global permissions for the most common user roles
+          and cannot be combined with the global permissions option.
+{CLAP_INDENT}
+          Examples:
+           iggy user create sender s3n43r --template producer
+
+          Possible values:
+          - producer: read_streams, read_topics and send_messages
+          - consumer: read_streams, read_topics and poll_messages
+          - admin:    all global permissions
+
   -s, --stream-permissions <STREAM_PERMISSIONS>
           Set stream permissions for created user
 {CLAP_INDENT}
@@ -319,7 +336,9 @@ Options:
           iggy::models::permissions::StreamPermissions) and short variant.
 {CLAP_INDENT}
           Available stream permissions: manage_stream / m_str, read_stream / r_str, manage_topics / m_top,
-          read_topics / r_top, poll_messages / p_msg, send_messages / s_msg.
+          read_topics / r_top, poll_messages / p_msg, send_messages / s_msg,
+          auto_create_topics / a_top, manage_partitions / m_par,
+          manage_consumer_groups / m_cgr.
 {CLAP_INDENT}
           For each stream one can set permissions for each topic separately. Topic permissions
           are defined for each topic separately. Setting permission for topic allows to set each
@@ -339,6 +358,7 @@ Options:
            iggy user create admin p@Ss! --stream-permissions 2:m_str,r_str,m_top,r_top,p_msg,s_msg
            iggy user create sender s3n43r -s 3#1:s_msg#2:s_msg
            iggy user create user1 test12 -s 4:manage_stream,r_top#1:s_msg,p_msg#2:manage_topic
+           iggy user create orders_admin s3cr3t -s 5:manage_topics,manage_partitions,manage_consumer_groups
 
   -h, --help
           Print help (see a summary with '-h')
@@ -370,6 +390,8 @@ Options:
           User status [default: active] [possible values: active, inactive]
   -g, --global-permissions <GLOBAL_PERMISSIONS>
           Set global permissions for created user
+      --template <TEMPLATE>
+          Set global permissions using template [possible values: producer, consumer, admin]
   -s, --stream-permissions <STREAM_PERMISSIONS>
           Set stream permissions for created user
   -h, --help
@@ -0,0 +1,239 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestUserId, CLAP_INDENT,
+    USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::models::user_info::UserId;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::get_user::GetUser;
+use iggy::utils::timestamp::IggyTimestamp;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestUserExpiryCmd {
+    username: String,
+    expiry: Option<Vec<String>>,
+    max_inactive_days: Option<u32>,
+    using_identifier: TestUserId,
+    user_id: Option<UserId>,
+}
+
+impl TestUserExpiryCmd {
+    fn new(
+        username: String,
+        expiry: Option<Vec<String>>,
+        max_inactive_days: Option<u32>,
+        using_identifier: TestUserId,
+    ) -> Self {
+        Self {
+            username,
+            expiry,
+            max_inactive_days,
+            using_identifier,
+            user_id: None,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = match self.using_identifier {
+            TestUserId::Named => vec![self.username.clone()],
+            TestUserId::Numeric => vec![format!("{}", self.user_id.unwrap())],
+        };
+        args.extend(self.expiry.clone().unwrap_or_default());
+        if let Some(max_inactive_days) = self.max_inactive_days {
+            args.push(String::from("--max-inactive-days"));
+            args.push(format!("{max_inactive_days}"));
+        }
+
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserExpiryCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let create_user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                ..Default::default()
+            })
+            .await;
+        assert!(create_user.is_ok());
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::from_str_value(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        self.user_id = Some(user.unwrap().id);
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("expiry")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let user_id = match self.using_identifier {
+            TestUserId::Named => self.username.clone(),
+            TestUserId::Numeric => format!("{}", self.user_id.unwrap()),
+        };
+        let expiry = match &self.expiry {
+            Some(value) => format!("expiry: {}", value.join(" ")),
+            None => String::from("no expiry"),
+        };
+        let max_inactive_days = match self.max_inactive_days {
+            Some(days) => format!("max inactive days: {days}"),
+            None => String::from("no max inactive days"),
+        };
+        let message = format!(
+            "Executing update user with ID: {user_id} with {expiry} and {max_inactive_days}\n\
+            User with ID: {user_id} updated with {expiry} and {max_inactive_days}\n"
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::numeric(self.user_id.unwrap()).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap();
+        assert_eq!(user.expires_at.is_some(), self.expiry.is_some());
+        if let Some(expires_at) = user.expires_at {
+            assert!(expires_at > IggyTimestamp::now().to_micros());
+        }
+        assert_eq!(user.max_inactive_days, self.max_inactive_days);
+
+        let deleted = client
+            .delete_user(&DeleteUser {
+                user_id: Identifier::numeric(self.user_id.unwrap()).unwrap(),
+            })
+            .await;
+        assert!(deleted.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserExpiryCmd::new(
+            String::from("contractor"),
+            Some(vec![String::from("2days")]),
+            None,
+            TestUserId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserExpiryCmd::new(
+            String::from("consultant"),
+            Some(vec![String::from("7days"), String::from("12h")]),
+            Some(14),
+            TestUserId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserExpiryCmd::new(
+            String::from("client"),
+            None,
+            Some(30),
+            TestUserId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserExpiryCmd::new(
+            String::from("employee"),
+            None,
+            None,
+            TestUserId::Numeric,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "expiry", "--help"],
+            format!(
+                r#"Set expiry and inactivity window for user with given ID
+
+The user ID can be specified as either a username or an ID. User is
+disabled once the expiry time passes or once it hasn't logged in for
+the given number of days. Both settings are replaced, the skipped one
+is removed.
+
+Examples:
+ iggy user expiry 2 30days
+ iggy user expiry contractor 90days --max-inactive-days 14
+ iggy user expiry client none -i 30
+ iggy user expiry client
+
+{USAGE_PREFIX} user expiry [OPTIONS] <USER_ID> [EXPIRY]...
+
+Arguments:
+  <USER_ID>
+          User ID to update
+{CLAP_INDENT}
+          The user ID can be specified as either a username or an ID
+
+  [EXPIRY]...
+          User expiry time in human readable format, counted from now
+{CLAP_INDENT}
+          Expiry time must be expressed in human readable format like 15days 2min 2s ("none" or skipping parameter disables user expiry)
+
+Options:
+  -i, --max-inactive-days <MAX_INACTIVE_DAYS>
+          Number of days without any activity after which the user is disabled
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "expiry", "-h"],
+            format!(
+                r#"Set expiry and inactivity window for user with given ID
+
+{USAGE_PREFIX} user expiry [OPTIONS] <USER_ID> [EXPIRY]...
+
+Arguments:
+  <USER_ID>    User ID to update
+  [EXPIRY]...  User expiry time in human readable format, counted from now
+
+Options:
+  -i, --max-inactive-days <MAX_INACTIVE_DAYS>
+          Number of days without any activity after which the user is disabled
+  -h, --help
+          Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -132,7 +132,9 @@ impl IggyCmdTestCase for TestUserGetCmd {
                 user_id
             )))
             .stdout(is_match(format!("| Username[ ]+| {}", self.username)).unwrap())
-            .stdout(is_match(format!("| Status[ ]+| {}", self.status)).unwrap());
+            .stdout(is_match(format!("| Status[ ]+| {}", self.status)).unwrap())
+            .stdout(is_match("| Expires[ ]+| never").unwrap())
+            .stdout(is_match("| Max inactive days[ ]+| unlimited").unwrap());
 
         // Check global permissions
         let assert = if self.check_global_perms {
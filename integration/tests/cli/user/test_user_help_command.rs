@@ -23,6 +23,8 @@ Commands:
   status       Change status for user with given ID [aliases: s]
   password     Change password for user with given ID [aliases: pwd]
   permissions  Set permissions for user with given ID [aliases: p]
+  check        Check whether action is allowed for user with given ID [aliases: chk]
+  expiry       Set expiry and inactivity window for user with given ID [aliases: e]
   help         Print this message or the help of the given subcommand(s)
 
 Options:
@@ -132,6 +132,7 @@ pub async fn should_be_successful() {
                         read_topics: true,
                         poll_messages: true,
                         send_messages: true,
+                        auto_create_topics: false,
                     },
                     streams: None,
                 }),
@@ -199,6 +200,7 @@ pub async fn should_be_successful() {
                         read_topics: false,
                         poll_messages: false,
                         send_messages: false,
+                        auto_create_topics: false,
                     },
                     streams: Some(HashMap::from([(
                         2u32,
@@ -220,6 +222,26 @@ pub async fn should_be_successful() {
             TestUserId::Named,
         ))
         .await;
+    iggy_cmd_test
+        .execute_test(TestUserPermissionsCmd::new(
+            String::from("consumer"),
+            PermissionsTestArgs::new(
+                None,
+                vec![String::from("1")],
+                Some(Permissions {
+                    global: GlobalPermissions {
+                        read_streams: true,
+                        read_topics: true,
+                        poll_messages: true,
+                        ..Default::default()
+                    },
+                    streams: Some(HashMap::from([(1u32, StreamPermissions::default())])),
+                }),
+            )
+            .with_template("consumer"),
+            TestUserId::Named,
+        ))
+        .await;
 }
 
 #[tokio::test]
@@ -229,35 +251,35 @@ pub async fn should_help_match() {
 
     iggy_cmd_test
         .execute_test_for_help_command(TestHelpCmd::new(
-            vec!["user", "create", "--help"],
+            vec!["user", "permissions", "--help"],
             format!(
-                r#"Create user with given username and password
+                r#"Set permissions for user with given ID
 
-Examples
- iggy user create testuser pass#1%X!
- iggy user create guest guess --user-status inactive
+The user ID can be specified as either a username or an ID. Permissions
+are configured based on the options provided with this command. If no
+options are set, the default behavior is to remove permissions for the
+specified user.
 
-{USAGE_PREFIX} user create [OPTIONS] <USERNAME> <PASSWORD>
+Examples:
+ iggy user permissions 2
+ iggy user permissions client
+ iggy user permissions client --template consumer
+ iggy user permissions edit client
 
-Arguments:
-  <USERNAME>
-          Username
-{CLAP_INDENT}
-          Unique identifier for the user account on iggy server,
-          must be between 3 and 50 characters long.
+{USAGE_PREFIX} user permissions [OPTIONS] <USER_ID>
+       iggy user permissions <COMMAND>
 
-  <PASSWORD>
-          Password
-{CLAP_INDENT}
-          Password of the user, must be between 3 and 100 characters long.
+Commands:
+  edit  Edit permissions for user with given ID in text editor [aliases: e]
+  help  Print this message or the help of the given subcommand(s)
 
-Options:
-  -u, --user-status <USER_STATUS>
-          User status
+Arguments:
+  <USER_ID>
+          User ID to update
 {CLAP_INDENT}
-          [default: active]
-          [possible values: active, inactive]
+          The user ID can be specified as either a username or an ID
 
+Options:
   -g, --global-permissions <GLOBAL_PERMISSIONS>
           Set global permissions for created user
 {CLAP_INDENT}
@@ -270,12 +292,27 @@ Options:
           Available permissions (long and short versions):  manage_servers / m_srv,
           read_servers / r_srv, manage_users / m_usr, read_users / r_usr,
           manage_streams / m_str, read_streams / r_str, manage_topics / m_top,
-          read_topics / r_top, poll_messages / p_msg, send_messages / s_msg
+          read_topics / r_top, poll_messages / p_msg, send_messages / s_msg,
+          auto_create_topics / a_top
 {CLAP_INDENT}
           Examples:
            iggy user create guest guess --global-permissions p_msg,s_msg
            iggy user create admin pass#1%X! -g m_srv,r_srv,m_usr,r_usr,m_str,r_str,m_top,r_top,p_msg,s_msg
 
+      --template <TEMPLATE>
+          Set global permissions using template
+{CLAP_INDENT}
+          Template sets the global permissions for the most common user roles
+          and cannot be combined with the global permissions option.
+{CLAP_INDENT}
+          Examples:
+           iggy user permissions sender --template producer
+
+          Possible values:
+          - producer: read_streams, read_topics and send_messages
+          - consumer: read_streams, read_topics and poll_messages
+          - admin:    all global permissions
+
   -s, --stream-permissions <STREAM_PERMISSIONS>
           Set stream permissions for created user
 {CLAP_INDENT}
@@ -287,7 +324,9 @@ Options:
           iggy::models::permissions::StreamPermissions) and short variant.
 {CLAP_INDENT}
           Available stream permissions: manage_stream / m_str, read_stream / r_str, manage_topics / m_top,
-          read_topics / r_top, poll_messages / p_msg, send_messages / s_msg.
+          read_topics / r_top, poll_messages / p_msg, send_messages / s_msg,
+          auto_create_topics / a_top, manage_partitions / m_par,
+          manage_consumer_groups / m_cgr.
 {CLAP_INDENT}
           For each stream one can set permissions for each topic separately. Topic permissions
           are defined for each topic separately. Setting permission for topic allows to set each
@@ -307,6 +346,7 @@ Options:
            iggy user create admin p@Ss! --stream-permissions 2:m_str,r_str,m_top,r_top,p_msg,s_msg
            iggy user create sender s3n43r -s 3#1:s_msg#2:s_msg
            iggy user create user1 test12 -s 4:manage_stream,r_top#1:s_msg,p_msg#2:manage_topic
+           iggy user create orders_admin s3cr3t -s 5:manage_topics,manage_partitions,manage_consumer_groups
 
   -h, --help
           Print help (see a summary with '-h')
@@ -323,21 +363,25 @@ pub async fn should_short_help_match() {
 
     iggy_cmd_test
         .execute_test_for_help_command(TestHelpCmd::new(
-            vec!["user", "create", "-h"],
+            vec!["user", "permissions", "-h"],
             format!(
-                r#"Create user with given username and password
+                r#"Set permissions for user with given ID
+
+{USAGE_PREFIX} user permissions [OPTIONS] <USER_ID>
+       iggy user permissions <COMMAND>
 
-{USAGE_PREFIX} user create [OPTIONS] <USERNAME> <PASSWORD>
+Commands:
+  edit  Edit permissions for user with given ID in text editor [aliases: e]
+  help  Print this message or the help of the given subcommand(s)
 
 Arguments:
-  <USERNAME>  Username
-  <PASSWORD>  Password
+  <USER_ID>  User ID to update
 
 Options:
-  -u, --user-status <USER_STATUS>
-          User status [default: active] [possible values: active, inactive]
   -g, --global-permissions <GLOBAL_PERMISSIONS>
           Set global permissions for created user
+      --template <TEMPLATE>
+          Set global permissions using template [possible values: producer, consumer, admin]
   -s, --stream-permissions <STREAM_PERMISSIONS>
           Set stream permissions for created user
   -h, --help
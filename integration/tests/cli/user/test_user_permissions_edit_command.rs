@@ -0,0 +1,189 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::users::create_user::CreateUser;
+use iggy::users::get_user::GetUser;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestUserPermissionsEditCmd {
+    username: String,
+    editor: String,
+    expected_changes: Vec<String>,
+    expected_permissions: Permissions,
+}
+
+impl TestUserPermissionsEditCmd {
+    fn new(
+        username: String,
+        editor: String,
+        expected_changes: Vec<String>,
+        expected_permissions: Permissions,
+    ) -> Self {
+        Self {
+            username,
+            editor,
+            expected_changes,
+            expected_permissions,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserPermissionsEditCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let create_user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                ..Default::default()
+            })
+            .await;
+        assert!(create_user.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("permissions")
+            .arg("edit")
+            .arg(self.username.clone())
+            .env("VISUAL", self.editor.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let mut message = format!(
+            "Executing edit permissions for user with ID: {}\n",
+            self.username
+        );
+        match self.expected_changes.is_empty() {
+            true => message.push_str(&format!(
+                "No changes, permissions for user with ID: {} not updated\n",
+                self.username
+            )),
+            false => {
+                self.expected_changes
+                    .iter()
+                    .for_each(|change| message.push_str(&format!("{change}\n")));
+                message.push_str(&format!(
+                    "Permissions for user with ID: {} updated\n",
+                    self.username
+                ));
+            }
+        }
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::from_str_value(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap();
+        assert_eq!(
+            user.permissions.unwrap_or_default(),
+            self.expected_permissions
+        );
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserPermissionsEditCmd::new(
+            String::from("editor"),
+            String::from("sed -i s/poll_messages\\s=\\sfalse/poll_messages=true/"),
+            vec![String::from("~ global.poll_messages: false -> true")],
+            Permissions {
+                global: GlobalPermissions {
+                    poll_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+            },
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserPermissionsEditCmd::new(
+            String::from("unchanged"),
+            String::from("true"),
+            vec![],
+            Permissions::default(),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "permissions", "edit", "--help"],
+            format!(
+                r#"Edit permissions for user with given ID in text editor
+
+Current permissions are opened as TOML document in the editor set by
+VISUAL or EDITOR environment variable (vi by default). After the editor
+is closed the permissions are validated, the changes are printed and
+applied. Permissions are not changed when the document is invalid or
+the editor exits with an error.
+
+The user ID can be specified as either a username or an ID
+
+Examples:
+ iggy user permissions edit 2
+ EDITOR=nano iggy user permissions edit client
+
+{USAGE_PREFIX} user permissions edit <USER_ID>
+
+Arguments:
+  <USER_ID>
+          User ID to edit permissions for
+{CLAP_INDENT}
+          The user ID can be specified as either a username or an ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "permissions", "edit", "-h"],
+            format!(
+                r#"Edit permissions for user with given ID in text editor
+
+{USAGE_PREFIX} user permissions edit <USER_ID>
+
+Arguments:
+  <USER_ID>  User ID to edit permissions for
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -13,6 +13,8 @@ use iggy::client::UserClient;
 use iggy::clients::client::IggyClient;
 use iggy::clients::client::IggyClientConfig;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::system::ping::Ping;
 use iggy::tcp::client::TcpClient;
@@ -124,6 +126,7 @@ impl<'a> IggyExampleTest<'a> {
                 .create_stream(&CreateStream {
                     stream_id: Some(1),
                     name: "sample-stream".to_string(),
+                    ..CreateStream::default()
                 })
                 .await
                 .unwrap();
@@ -136,6 +139,11 @@ impl<'a> IggyExampleTest<'a> {
                     message_expiry: None,
                     max_topic_size: None,
                     replication_factor: 1,
+                    message_schema: None,
+                    ephemeral: false,
+                    timestamp_type: TimestampType::default(),
+                    consumer_limits: ConsumerLimits::default(),
+                    max_message_size: None,
                 })
                 .await
                 .unwrap();
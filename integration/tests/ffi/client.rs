@@ -0,0 +1,292 @@
+use iggy::bootstrap::definition::BootstrapDefinition;
+use iggy::error::IggyError;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy_ffi::*;
+use iggy_test::test_server::TestServer;
+use serial_test::parallel;
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+use std::slice;
+
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u64 = 10;
+
+#[tokio::test]
+#[parallel]
+async fn ffi_client_should_send_and_poll_messages_and_store_offset() {
+    let server = start_server().await;
+    let connection_string = connection_string(&server, true);
+
+    tokio::task::spawn_blocking(move || unsafe {
+        let client = connect(&connection_string);
+        let stream = CString::new("orders").unwrap();
+        let topic = CString::new("created").unwrap();
+        let payloads = (0..MESSAGES_COUNT)
+            .map(|offset| format!("message {offset}"))
+            .collect::<Vec<_>>();
+        let messages = payloads
+            .iter()
+            .enumerate()
+            .map(|(index, payload)| IggyMessage {
+                id: (index as u128 + 1).to_be_bytes(),
+                payload: payload.as_ptr(),
+                payload_length: payload.len(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            iggy_send_messages(
+                client,
+                stream.as_ptr(),
+                topic.as_ptr(),
+                PARTITION_ID,
+                messages.as_ptr(),
+                messages.len(),
+            ),
+            IGGY_OK
+        );
+        assert!(iggy_last_error_message().is_null());
+
+        let consumer = CString::new("tester").unwrap();
+        let options = IggyPollOptions {
+            partition_id: PARTITION_ID,
+            consumer_kind: IGGY_CONSUMER,
+            consumer: consumer.as_ptr(),
+            strategy_kind: IGGY_POLLING_FIRST,
+            strategy_value: 0,
+            count: MESSAGES_COUNT as u32,
+            auto_commit: false,
+        };
+        let mut polled_messages = ptr::null_mut();
+        assert_eq!(
+            iggy_poll_messages(
+                client,
+                stream.as_ptr(),
+                topic.as_ptr(),
+                &options,
+                &mut polled_messages,
+            ),
+            IGGY_OK
+        );
+        let polled = &*polled_messages;
+        assert_eq!(polled.partition_id, PARTITION_ID);
+        assert_eq!(polled.current_offset, MESSAGES_COUNT - 1);
+        let polled = slice::from_raw_parts(polled.messages, polled.count);
+        assert_eq!(polled.len(), MESSAGES_COUNT as usize);
+        for (index, message) in polled.iter().enumerate() {
+            let payload = slice::from_raw_parts(message.payload, message.payload_length);
+            assert_eq!(message.offset, index as u64);
+            assert_eq!(u128::from_be_bytes(message.id), index as u128 + 1);
+            assert_eq!(payload, payloads[index].as_bytes());
+        }
+        iggy_polled_messages_free(polled_messages);
+
+        assert_eq!(
+            iggy_store_consumer_offset(
+                client,
+                stream.as_ptr(),
+                topic.as_ptr(),
+                PARTITION_ID,
+                IGGY_CONSUMER,
+                consumer.as_ptr(),
+                5,
+            ),
+            IGGY_OK
+        );
+        let mut consumer_offset = IggyConsumerOffset::default();
+        assert_eq!(
+            iggy_get_consumer_offset(
+                client,
+                stream.as_ptr(),
+                topic.as_ptr(),
+                PARTITION_ID,
+                IGGY_CONSUMER,
+                consumer.as_ptr(),
+                &mut consumer_offset,
+            ),
+            IGGY_OK
+        );
+        assert_eq!(consumer_offset.partition_id, PARTITION_ID);
+        assert_eq!(consumer_offset.current_offset, MESSAGES_COUNT - 1);
+        assert_eq!(consumer_offset.stored_offset, 5);
+
+        let options = IggyPollOptions {
+            strategy_kind: IGGY_POLLING_NEXT,
+            ..options
+        };
+        let mut polled_messages = ptr::null_mut();
+        assert_eq!(
+            iggy_poll_messages(
+                client,
+                stream.as_ptr(),
+                topic.as_ptr(),
+                &options,
+                &mut polled_messages,
+            ),
+            IGGY_OK
+        );
+        let polled = &*polled_messages;
+        assert_eq!(polled.count, 4);
+        assert_eq!((*polled.messages).offset, 6);
+        iggy_polled_messages_free(polled_messages);
+
+        iggy_client_free(client);
+    })
+    .await
+    .unwrap();
+    server.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+#[parallel]
+async fn ffi_client_should_login_with_credentials() {
+    let server = start_server().await;
+    let connection_string = connection_string(&server, false);
+
+    tokio::task::spawn_blocking(move || unsafe {
+        let client = connect(&connection_string);
+        let stream = CString::new("orders").unwrap();
+        let topic = CString::new("created").unwrap();
+        let consumer = CString::new("1").unwrap();
+        let mut consumer_offset = IggyConsumerOffset::default();
+
+        let code = iggy_get_consumer_offset(
+            client,
+            stream.as_ptr(),
+            topic.as_ptr(),
+            PARTITION_ID,
+            IGGY_CONSUMER,
+            consumer.as_ptr(),
+            &mut consumer_offset,
+        );
+        assert_eq!(code, IggyError::Unauthenticated.as_code() as i32);
+
+        let username = CString::new(DEFAULT_ROOT_USERNAME).unwrap();
+        let password = CString::new(DEFAULT_ROOT_PASSWORD).unwrap();
+        assert_eq!(
+            iggy_client_login(client, username.as_ptr(), password.as_ptr()),
+            IGGY_OK
+        );
+        let code = iggy_get_consumer_offset(
+            client,
+            stream.as_ptr(),
+            topic.as_ptr(),
+            PARTITION_ID,
+            IGGY_CONSUMER,
+            consumer.as_ptr(),
+            &mut consumer_offset,
+        );
+        assert_eq!(code, IGGY_OK);
+        assert_eq!(iggy_client_logout(client), IGGY_OK);
+
+        iggy_client_free(client);
+    })
+    .await
+    .unwrap();
+    server.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+#[parallel]
+async fn ffi_client_should_return_error_codes_and_messages() {
+    let server = start_server().await;
+    let connection_string = connection_string(&server, true);
+
+    tokio::task::spawn_blocking(move || unsafe {
+        let invalid_connection_string = CString::new("tcp://localhost").unwrap();
+        let mut client = ptr::null_mut();
+        assert_eq!(
+            iggy_client_new(invalid_connection_string.as_ptr(), &mut client),
+            IGGY_ERROR_INVALID_ARGUMENT
+        );
+        assert!(client.is_null());
+        assert!(!last_error_message().is_empty());
+
+        let client = connect(&connection_string);
+        let topic = CString::new("created").unwrap();
+        let mut consumer_offset = IggyConsumerOffset::default();
+        let code = iggy_get_consumer_offset(
+            client,
+            ptr::null(),
+            topic.as_ptr(),
+            PARTITION_ID,
+            IGGY_CONSUMER,
+            topic.as_ptr(),
+            &mut consumer_offset,
+        );
+        assert_eq!(code, IGGY_ERROR_NULL_POINTER);
+        assert_eq!(last_error_message(), "Argument: stream is null");
+
+        let missing_stream = CString::new("missing").unwrap();
+        let code = iggy_store_consumer_offset(
+            client,
+            missing_stream.as_ptr(),
+            topic.as_ptr(),
+            PARTITION_ID,
+            IGGY_CONSUMER,
+            topic.as_ptr(),
+            1,
+        );
+        assert!(code > 0);
+        assert!(!last_error_message().is_empty());
+
+        let stream = CString::new("orders").unwrap();
+        let code = iggy_store_consumer_offset(
+            client,
+            stream.as_ptr(),
+            topic.as_ptr(),
+            PARTITION_ID,
+            3,
+            topic.as_ptr(),
+            1,
+        );
+        assert_eq!(code, IggyError::InvalidCommand.as_code() as i32);
+
+        iggy_client_free(client);
+        iggy_client_free(ptr::null_mut());
+        iggy_polled_messages_free(ptr::null_mut());
+    })
+    .await
+    .unwrap();
+    server.shutdown().await.unwrap();
+}
+
+async fn start_server() -> TestServer {
+    let fixture = BootstrapDefinition::from_toml(
+        r#"
+        [[streams]]
+        name = "orders"
+        topics = [{ name = "created", partitions_count = 1 }]
+        "#,
+    )
+    .unwrap();
+    TestServer::builder()
+        .fixture(fixture)
+        .start()
+        .await
+        .unwrap()
+}
+
+fn connection_string(server: &TestServer, with_credentials: bool) -> CString {
+    let credentials = match with_credentials {
+        true => format!("{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@"),
+        false => "".to_string(),
+    };
+    CString::new(format!("iggy+tcp://{credentials}{}", server.tcp_address())).unwrap()
+}
+
+unsafe fn connect(connection_string: &CString) -> *mut IggyClientHandle {
+    let mut client = ptr::null_mut();
+    assert_eq!(
+        iggy_client_new(connection_string.as_ptr(), &mut client),
+        IGGY_OK
+    );
+    assert!(!client.is_null());
+    assert_eq!(iggy_client_connect(client), IGGY_OK);
+    client
+}
+
+unsafe fn last_error_message() -> String {
+    let message: *const c_char = iggy_last_error_message();
+    assert!(!message.is_null());
+    CStr::from_ptr(message).to_string_lossy().to_string()
+}
@@ -2,5 +2,6 @@ mod bench;
 mod cli;
 mod config_provider;
 mod examples;
+mod ffi;
 mod server;
 mod streaming;
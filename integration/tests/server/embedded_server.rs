@@ -0,0 +1,257 @@
+use crate::server::scenarios::system_scenario;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::get_stream::GetStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{login_root, ClientFactory};
+use serial_test::parallel;
+use server::configs::server::ServerConfig;
+use server::configs::system::{StartupConfig, StorageBackend, StorageConfig, SystemConfig};
+use server::iggy_server::IggyServer;
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const STREAM_ID: u32 = 1;
+const STREAM_NAME: &str = "embedded";
+const TOPIC_ID: u32 = 1;
+const TOPIC_NAME: &str = "transient";
+const MESSAGES_COUNT: u32 = 10;
+
+#[tokio::test]
+#[parallel]
+async fn system_scenario_should_be_valid() {
+    let mut config = create_config(&create_system_path());
+    // The sizes are asserted for the messages without any headers, so the server must not stamp the system ones.
+    Arc::get_mut(&mut config.system)
+        .unwrap()
+        .system_headers
+        .enabled = false;
+    let system_path = config.system.get_system_path();
+    let server = IggyServer::start(config).await.unwrap();
+    let client_factory = create_client_factory(&server);
+
+    system_scenario::run(&client_factory).await;
+
+    server.shutdown().await.unwrap();
+    std::fs::remove_dir_all(system_path).unwrap();
+}
+
+#[tokio::test]
+#[parallel]
+async fn data_should_be_available_after_restart() {
+    let path = create_system_path();
+    let server = IggyServer::start(create_config(&path)).await.unwrap();
+    let client = create_client(&server).await;
+    create_stream(&client).await;
+    server.shutdown().await.unwrap();
+
+    let config = create_config(&path);
+    let system_path = config.system.get_system_path();
+    let server = IggyServer::start(config).await.unwrap();
+    let client = create_client(&server).await;
+    let stream = client
+        .get_stream(&GetStream {
+            stream_id: Identifier::named(STREAM_NAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(stream.name, STREAM_NAME);
+
+    server.shutdown().await.unwrap();
+    std::fs::remove_dir_all(system_path).unwrap();
+}
+
+#[tokio::test]
+#[parallel]
+async fn messages_should_be_available_after_restart_given_limited_loading_and_lazy_indexes() {
+    let path = create_system_path();
+    let server = IggyServer::start(create_config(&path)).await.unwrap();
+    let client = create_client(&server).await;
+    create_stream(&client).await;
+    create_topic(&client, false).await;
+    send_and_poll_messages(&client).await;
+    server.shutdown().await.unwrap();
+
+    let mut config = create_config(&path);
+    Arc::get_mut(&mut config.system).unwrap().startup = StartupConfig {
+        max_concurrent_partition_loads: 1,
+        lazy_segment_indexes: true,
+        ..StartupConfig::default()
+    };
+    let system_path = config.system.get_system_path();
+    let server = IggyServer::start(config).await.unwrap();
+    let client = create_client(&server).await;
+    send_and_poll_messages(&client).await;
+
+    server.shutdown().await.unwrap();
+    std::fs::remove_dir_all(system_path).unwrap();
+}
+
+#[tokio::test]
+#[parallel]
+async fn messages_should_not_be_persisted_given_memory_storage_backend() {
+    let path = create_system_path();
+    let server = IggyServer::start(create_memory_config(&path))
+        .await
+        .unwrap();
+    let client = create_client(&server).await;
+    create_stream(&client).await;
+    create_topic(&client, false).await;
+    send_and_poll_messages(&client).await;
+    let streams_path = server.config().system.get_streams_path();
+    assert_eq!(std::fs::read_dir(streams_path).unwrap().count(), 0);
+    server.shutdown().await.unwrap();
+
+    let config = create_memory_config(&path);
+    let system_path = config.system.get_system_path();
+    let server = IggyServer::start(config).await.unwrap();
+    let client = create_client(&server).await;
+    let stream = client
+        .get_stream(&GetStream {
+            stream_id: Identifier::named(STREAM_NAME).unwrap(),
+        })
+        .await;
+    assert!(stream.is_err());
+
+    server.shutdown().await.unwrap();
+    std::fs::remove_dir_all(system_path).unwrap();
+}
+
+#[tokio::test]
+#[parallel]
+async fn ephemeral_topic_should_not_be_available_after_restart() {
+    let path = create_system_path();
+    let server = IggyServer::start(create_config(&path)).await.unwrap();
+    let client = create_client(&server).await;
+    create_stream(&client).await;
+    create_topic(&client, true).await;
+    send_and_poll_messages(&client).await;
+    server.shutdown().await.unwrap();
+
+    let config = create_config(&path);
+    let system_path = config.system.get_system_path();
+    let server = IggyServer::start(config).await.unwrap();
+    let client = create_client(&server).await;
+    let stream = client
+        .get_stream(&GetStream {
+            stream_id: Identifier::named(STREAM_NAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(stream.topics_count, 0);
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::named(STREAM_NAME).unwrap(),
+            topic_id: Identifier::named(TOPIC_NAME).unwrap(),
+        })
+        .await;
+    assert!(topic.is_err());
+
+    server.shutdown().await.unwrap();
+    std::fs::remove_dir_all(system_path).unwrap();
+}
+
+async fn create_stream(client: &IggyClient) {
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(STREAM_ID),
+            name: STREAM_NAME.to_string(),
+            ..CreateStream::default()
+        })
+        .await
+        .unwrap();
+}
+
+async fn create_topic(client: &IggyClient, ephemeral: bool) {
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::named(STREAM_NAME).unwrap(),
+            topic_id: Some(TOPIC_ID),
+            name: TOPIC_NAME.to_string(),
+            ephemeral,
+            ..CreateTopic::default()
+        })
+        .await
+        .unwrap();
+}
+
+async fn send_and_poll_messages(client: &IggyClient) {
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::named(STREAM_NAME).unwrap(),
+        topic_id: Identifier::named(TOPIC_NAME).unwrap(),
+        partitioning: Partitioning::partition_id(1),
+        messages: (0..MESSAGES_COUNT)
+            .map(|index| Message::from_str(&format!("message {index}")).unwrap())
+            .collect(),
+        acks: None,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    let polled_messages = client
+        .poll_messages(&PollMessages {
+            stream_id: Identifier::named(STREAM_NAME).unwrap(),
+            topic_id: Identifier::named(TOPIC_NAME).unwrap(),
+            partition_id: Some(1),
+            strategy: PollingStrategy::offset(0),
+            count: MESSAGES_COUNT,
+            ..PollMessages::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        assert_eq!(message.offset, index as u64);
+        assert_eq!(message.payload, format!("message {index}").as_bytes());
+    }
+}
+
+fn create_system_path() -> String {
+    format!("local_data_{}", Uuid::new_v4().to_u128_le())
+}
+
+fn create_config(path: &str) -> ServerConfig {
+    let mut config = ServerConfig {
+        system: Arc::new(SystemConfig {
+            path: path.to_string(),
+            ..SystemConfig::default()
+        }),
+        ..ServerConfig::default()
+    };
+    config.tcp.address = "127.0.0.1:0".to_string();
+    config.http.enabled = false;
+    config.quic.enabled = false;
+    config
+}
+
+fn create_memory_config(path: &str) -> ServerConfig {
+    let mut config = create_config(path);
+    config.system = Arc::new(SystemConfig {
+        path: path.to_string(),
+        storage: StorageConfig {
+            backend: StorageBackend::Memory,
+            ..StorageConfig::default()
+        },
+        ..SystemConfig::default()
+    });
+    config
+}
+
+fn create_client_factory(server: &IggyServer) -> TcpClientFactory {
+    TcpClientFactory {
+        server_addr: server.tcp_address().unwrap().to_string(),
+    }
+}
+
+async fn create_client(server: &IggyServer) -> IggyClient {
+    let client = create_client_factory(server).create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    login_root(&client).await;
+    client
+}
@@ -1,13 +1,27 @@
 use crate::server::scenarios::{
-    message_headers_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
+    client_compression_scenario, connectors_scenario, frozen_topic_scenario, handshake_scenario,
+    http_binary_messages_scenario, http_etag_scenario, long_polling_scenario,
+    message_headers_scenario, resource_uuids_scenario, schema_registry_scenario,
+    stream_admin_scenario, stream_size_validation_scenario, stream_topic_defaults_scenario,
+    system_scenario, tenants_scenario, topic_auto_create_scenario, trash_scenario,
+    user_expiry_scenario, user_groups_scenario, user_scenario,
+};
+use integration::{
+    http_client::HttpClientFactory,
+    test_server::{IpAddrKind, TestServer},
 };
-use integration::{http_client::HttpClientFactory, test_server::TestServer};
 use serial_test::parallel;
+use std::collections::HashMap;
 
 #[tokio::test]
 #[parallel]
 async fn system_scenario_should_be_valid() {
-    let mut test_server = TestServer::default();
+    let mut test_server = TestServer::new(
+        Some(system_scenario::get_envs()),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
     test_server.start();
     let server_addr = test_server.get_http_api_addr().unwrap();
     let client_factory = HttpClientFactory { server_addr };
@@ -36,10 +50,218 @@ async fn message_headers_scenario_should_be_valid() {
 
 #[tokio::test]
 #[parallel]
-async fn stream_size_validation_scenario_should_be_valid() {
+async fn client_compression_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    client_compression_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn handshake_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    handshake_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn long_polling_scenario_should_be_valid() {
     let mut test_server = TestServer::default();
     test_server.start();
     let server_addr = test_server.get_http_api_addr().unwrap();
     let client_factory = HttpClientFactory { server_addr };
+    long_polling_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn stream_size_validation_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(
+        Some(stream_size_validation_scenario::get_envs()),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
     stream_size_validation_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn schema_registry_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    schema_registry_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn tenants_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    tenants_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn stream_admin_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    stream_admin_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn user_groups_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    user_groups_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn user_expiry_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(
+        Some(user_expiry_scenario::get_envs()),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    user_expiry_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn topic_auto_create_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(
+        Some(topic_auto_create_scenario::get_envs()),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    topic_auto_create_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn stream_topic_defaults_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    stream_topic_defaults_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn trash_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    trash_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn frozen_topic_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    frozen_topic_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn resource_uuids_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    resource_uuids_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn http_binary_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    http_binary_messages_scenario::run(&server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn http_etag_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    http_etag_scenario::run(&server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn base_path_should_be_supported() {
+    let mut test_server = TestServer::new(
+        Some(HashMap::from([(
+            "IGGY_HTTP_BASE_PATH".to_string(),
+            "/iggy".to_string(),
+        )])),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+
+    let response = reqwest::get(format!("http://{server_addr}/ping"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+    let response = reqwest::get(format!("http://{server_addr}/iggy/ping"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let client_factory = HttpClientFactory {
+        server_addr: format!("{server_addr}/iggy"),
+    };
+    user_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn connectors_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(
+        Some(connectors_scenario::get_envs()),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    connectors_scenario::run(&client_factory).await;
+}
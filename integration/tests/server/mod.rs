@@ -1,4 +1,6 @@
+mod embedded_server;
 mod http_server;
 mod quic_server;
 mod scenarios;
 mod tcp_server;
+mod test_harness;
@@ -3,13 +3,21 @@ use crate::server::scenarios::{
     consumer_group_with_single_client_polling_messages_scenario, message_headers_scenario,
     stream_size_validation_scenario, system_scenario, user_scenario,
 };
-use integration::{quic_client::QuicClientFactory, test_server::TestServer};
+use integration::{
+    quic_client::QuicClientFactory,
+    test_server::{IpAddrKind, TestServer},
+};
 use serial_test::parallel;
 
 #[tokio::test]
 #[parallel]
 async fn system_scenario_should_be_valid() {
-    let mut test_server = TestServer::default();
+    let mut test_server = TestServer::new(
+        Some(system_scenario::get_envs()),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
     test_server.start();
     let server_addr = test_server.get_quic_udp_addr().unwrap();
     let client_factory = QuicClientFactory { server_addr };
@@ -69,7 +77,12 @@ async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_b
 #[tokio::test]
 #[parallel]
 async fn stream_size_validation_scenario_should_be_valid() {
-    let mut test_server = TestServer::default();
+    let mut test_server = TestServer::new(
+        Some(stream_size_validation_scenario::get_envs()),
+        true,
+        None,
+        IpAddrKind::V4,
+    );
     test_server.start();
     let server_addr = test_server.get_quic_udp_addr().unwrap();
     let client_factory = QuicClientFactory { server_addr };
@@ -0,0 +1,164 @@
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{CompressionConfig, IggyClient, IggyClientConfig, COMPRESSION_HEADER};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::Message;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::header::HeaderKey;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::system::negotiate_compression::NegotiateCompression;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::users::login_user::LoginUser;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const MIN_PAYLOAD_SIZE: u32 = 64;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let raw_client = client_factory.create_client().await;
+    let client = IggyClient::create(
+        client_factory.create_client().await,
+        IggyClientConfig {
+            compression: Some(CompressionConfig {
+                algorithms: vec![CompressionAlgorithm::Zstd, CompressionAlgorithm::Gzip],
+                min_payload_size: MIN_PAYLOAD_SIZE,
+            }),
+            ..Default::default()
+        },
+        None,
+        None,
+        None,
+    );
+
+    raw_client
+        .login_user(&LoginUser {
+            username: DEFAULT_ROOT_USERNAME.to_string(),
+            password: DEFAULT_ROOT_PASSWORD.to_string(),
+        })
+        .await
+        .unwrap();
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Negotiate the compression algorithm, the first one is selected as the server doesn't prefer any of them
+    let algorithm = raw_client
+        .negotiate_compression(&NegotiateCompression {
+            algorithms: vec![CompressionAlgorithm::Lz4, CompressionAlgorithm::Gzip],
+        })
+        .await
+        .unwrap();
+    assert_eq!(algorithm, CompressionAlgorithm::Lz4);
+
+    // 2. Send the large JSON payload which is compressed and the small one which is sent as it is
+    let large_payload = format!(
+        "[{}]",
+        (0..100)
+            .map(|i| format!(r#"{{"id":{i},"name":"order","status":"created"}}"#))
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+    let small_payload = "small";
+    client
+        .send()
+        .stream(STREAM_NAME)
+        .topic(TOPIC_NAME)
+        .partition(PARTITION_ID)
+        .message(Message::from_str(&large_payload).unwrap())
+        .message(Message::from_str(small_payload).unwrap())
+        .await
+        .unwrap();
+
+    // 3. Poll the messages using the low-level client without decompression and ensure that only the large payload is compressed
+    let poll_messages = PollMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 2,
+        ..PollMessages::default()
+    };
+    let compression_header = HeaderKey::new(COMPRESSION_HEADER).unwrap();
+    let polled_messages = raw_client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len(), 2);
+    let compressed_message = &polled_messages.messages[0];
+    assert!(compressed_message.payload.len() < large_payload.len());
+    let algorithm = compressed_message
+        .headers
+        .as_ref()
+        .unwrap()
+        .get(&compression_header)
+        .unwrap();
+    assert_eq!(
+        algorithm.as_str().unwrap(),
+        CompressionAlgorithm::Zstd.as_str()
+    );
+    let uncompressed_message = &polled_messages.messages[1];
+    assert_eq!(uncompressed_message.payload, small_payload.as_bytes());
+    assert!(!has_user_headers(uncompressed_message));
+
+    // 4. Poll the messages with decompression and ensure that the original payloads are returned
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len(), 2);
+    let decompressed_message = &polled_messages.messages[0];
+    assert_eq!(decompressed_message.payload, large_payload.as_bytes());
+    assert!(!has_user_headers(decompressed_message));
+    assert_eq!(
+        polled_messages.messages[1].payload,
+        small_payload.as_bytes()
+    );
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+/// The messages are stamped with the system headers by the server, so only the other ones are checked.
+fn has_user_headers(message: &iggy::models::messages::Message) -> bool {
+    message
+        .headers
+        .as_ref()
+        .is_some_and(|headers| headers.keys().any(|key| !key.is_system()))
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        max_topic_size: None,
+        replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
@@ -0,0 +1,140 @@
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::Message;
+use iggy::metrics::{ClientMetrics, ClientOperation};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+
+#[derive(Debug, Default)]
+struct TestMetrics {
+    sent_messages: AtomicU32,
+    polled_messages: AtomicU32,
+    poll_errors: AtomicU32,
+}
+
+impl ClientMetrics for TestMetrics {
+    fn record_send(&self, messages_count: u32, _latency: Duration) {
+        self.sent_messages
+            .fetch_add(messages_count, Ordering::SeqCst);
+    }
+
+    fn record_poll(&self, messages_count: u32, _latency: Duration) {
+        self.polled_messages
+            .fetch_add(messages_count, Ordering::SeqCst);
+    }
+
+    fn record_error(&self, operation: ClientOperation, _error: &IggyError) {
+        if operation == ClientOperation::PollMessages {
+            self.poll_errors.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let metrics = Arc::new(TestMetrics::default());
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(
+        client,
+        IggyClientConfig {
+            metrics: Some(metrics.clone()),
+            ..Default::default()
+        },
+        None,
+        None,
+        None,
+    );
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the messages and ensure that the sent messages are recorded
+    client
+        .send()
+        .stream(STREAM_NAME)
+        .topic(TOPIC_NAME)
+        .partition(PARTITION_ID)
+        .messages((0..MESSAGES_COUNT).map(|i| Message::from_str(&format!("message {i}")).unwrap()))
+        .await
+        .unwrap();
+    assert_eq!(metrics.sent_messages.load(Ordering::SeqCst), MESSAGES_COUNT);
+
+    // 2. Poll the messages and ensure that the polled messages are recorded
+    let poll_messages = PollMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: MESSAGES_COUNT,
+        ..PollMessages::default()
+    };
+    client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(
+        metrics.polled_messages.load(Ordering::SeqCst),
+        MESSAGES_COUNT
+    );
+    assert_eq!(metrics.poll_errors.load(Ordering::SeqCst), 0);
+
+    // 3. Poll the messages from the non-existing topic and ensure that the error is recorded
+    let poll_messages = PollMessages {
+        topic_id: Identifier::numeric(TOPIC_ID + 1).unwrap(),
+        ..poll_messages
+    };
+    assert!(client.poll_messages(&poll_messages).await.is_err());
+    assert_eq!(metrics.poll_errors.load(Ordering::SeqCst), 1);
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        max_topic_size: None,
+        replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
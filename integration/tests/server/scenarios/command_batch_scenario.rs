@@ -0,0 +1,95 @@
+use iggy::client::{StreamClient, SystemClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::command::Command;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::partitions::create_partitions::CreatePartitions;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_streams::GetStreams;
+use iggy::system::batch::Batch;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const STREAM_ID: u32 = 1;
+const STREAM_NAME: &str = "batch-stream";
+const TOPIC_ID: u32 = 1;
+const TOPIC_NAME: &str = "batch-topic";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    // 1. Each batched command is authenticated on its own
+    let results = client
+        .batch(&Batch {
+            commands: vec![Command::CreateStream(create_stream())],
+        })
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].status, IggyError::Unauthenticated.as_code());
+
+    // 2. The commands are executed in order and the failed one doesn't stop the following ones
+    login_root(&client).await;
+    let results = client
+        .batch(&Batch {
+            commands: vec![
+                Command::CreateStream(create_stream()),
+                Command::CreateTopic(CreateTopic {
+                    stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                    topic_id: Some(TOPIC_ID),
+                    partitions_count: 1,
+                    name: TOPIC_NAME.to_string(),
+                    ..CreateTopic::default()
+                }),
+                Command::CreateStream(create_stream()),
+                Command::CreatePartitions(CreatePartitions {
+                    stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                    topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+                    partitions_count: 2,
+                    repartition: false,
+                }),
+                Command::GetStreams(GetStreams {}),
+            ],
+        })
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 5);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert_eq!(
+        results[2].status,
+        IggyError::StreamNameAlreadyExists(STREAM_NAME.to_string()).as_code()
+    );
+    assert!(results[3].is_ok());
+    assert!(results[4].clone().into_result().is_ok());
+    assert!(!results[4].payload.is_empty());
+
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.partitions_count, 3);
+
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+fn create_stream() -> CreateStream {
+    CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    }
+}
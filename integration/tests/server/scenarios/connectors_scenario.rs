@@ -0,0 +1,199 @@
+use iggy::client::{ConnectorClient, StreamClient, TopicClient, UserClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::connectors::create_connector::CreateConnector;
+use iggy::connectors::delete_connector::DeleteConnector;
+use iggy::connectors::get_connector::GetConnector;
+use iggy::connectors::get_connectors::GetConnectors;
+use iggy::connectors::restart_connector::RestartConnector;
+use iggy::identifier::Identifier;
+use iggy::models::connector::{ConnectorInfo, ConnectorStatus};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::users::update_permissions::UpdatePermissions;
+use integration::test_server::{
+    assert_clean_system, create_user, delete_user, login_root, login_user, ClientFactory,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::time::{sleep, Duration};
+use uuid::Uuid;
+
+const CONNECTOR_NAME: &str = "lines-source";
+const STREAM_ID: u32 = 1;
+const STREAM_NAME: &str = "connectors";
+const TOPIC_ID: u32 = 1;
+const TOPIC_NAME: &str = "lines";
+const USERNAME: &str = "connectors-reader";
+const LINES_COUNT: u64 = 3;
+
+pub fn get_envs() -> HashMap<String, String> {
+    HashMap::from([("IGGY_CONNECTORS_ENABLED".to_string(), "true".to_string())])
+}
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    let path = std::env::temp_dir().join(format!("iggy-connector-{}.log", Uuid::new_v4()));
+    std::fs::write(&path, "first\nsecond\nthird\n").unwrap();
+
+    // 1. Connector operations should be forbidden for unauthenticated users
+    let get_connectors = client.get_connectors(&GetConnectors {}).await;
+    assert!(get_connectors.is_err());
+
+    // 2. Create the stream and the topic to which the file source appends the lines
+    login_root(&client).await;
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(STREAM_ID),
+            name: STREAM_NAME.to_string(),
+            ..CreateStream::default()
+        })
+        .await
+        .unwrap();
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Some(TOPIC_ID),
+            name: TOPIC_NAME.to_string(),
+            ..CreateTopic::default()
+        })
+        .await
+        .unwrap();
+
+    // 3. Create the file source connector, which should be started immediately
+    let create_connector = get_create_connector(&path);
+    client.create_connector(&create_connector).await.unwrap();
+    let connectors = client.get_connectors(&GetConnectors {}).await.unwrap();
+    assert_eq!(connectors.len(), 1);
+    let connector = &connectors[0];
+    assert_eq!(connector.name, CONNECTOR_NAME);
+    assert_eq!(connector.kind, create_connector.kind);
+    assert_eq!(connector.stream, STREAM_NAME);
+    assert_eq!(connector.topic, TOPIC_NAME);
+    assert_eq!(connector.properties, create_connector.properties);
+    assert_eq!(connector.status, ConnectorStatus::Running);
+    assert!(connector.error.is_none());
+
+    // 4. The connector with the same name can't be created again
+    let create_connector = client.create_connector(&create_connector).await;
+    assert!(create_connector.is_err());
+
+    // 5. All the lines of the file should be appended to the topic
+    let connector = wait_for_processed_messages(&client).await;
+    assert_eq!(connector.processed_messages, LINES_COUNT);
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.messages_count, LINES_COUNT);
+
+    // 6. Restart the connector, which continues from the stored offset
+    client
+        .restart_connector(&RestartConnector {
+            name: CONNECTOR_NAME.to_string(),
+        })
+        .await
+        .unwrap();
+    let connector = get_connector(&client).await;
+    assert_eq!(connector.status, ConnectorStatus::Running);
+
+    // 7. The user without the permission to read the server info can't get the connectors
+    create_user(&client, USERNAME).await;
+    client
+        .update_permissions(&UpdatePermissions {
+            user_id: Identifier::named(USERNAME).unwrap(),
+            permissions: None,
+        })
+        .await
+        .unwrap();
+    login_user(&client, USERNAME).await;
+    let get_connector = client
+        .get_connector(&GetConnector {
+            name: CONNECTOR_NAME.to_string(),
+        })
+        .await;
+    assert!(get_connector.is_err());
+    let restart_connector = client
+        .restart_connector(&RestartConnector {
+            name: CONNECTOR_NAME.to_string(),
+        })
+        .await;
+    assert!(restart_connector.is_err());
+
+    // 8. Delete the connector
+    login_root(&client).await;
+    client
+        .delete_connector(&DeleteConnector {
+            name: CONNECTOR_NAME.to_string(),
+        })
+        .await
+        .unwrap();
+    let get_connector = client
+        .get_connector(&GetConnector {
+            name: CONNECTOR_NAME.to_string(),
+        })
+        .await;
+    assert!(get_connector.is_err());
+    let connectors = client.get_connectors(&GetConnectors {}).await.unwrap();
+    assert!(connectors.is_empty());
+
+    // 9. No new messages should be appended once the connector is deleted
+    std::fs::write(&path, "first\nsecond\nthird\nfourth\n").unwrap();
+    sleep(Duration::from_millis(1500)).await;
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.messages_count, LINES_COUNT);
+
+    // 10. Clean up
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+    delete_user(&client, USERNAME).await;
+    std::fs::remove_file(&path).unwrap();
+    assert_clean_system(&client).await;
+}
+
+fn get_create_connector(path: &Path) -> CreateConnector {
+    CreateConnector {
+        name: CONNECTOR_NAME.to_string(),
+        kind: "file_source".to_string(),
+        stream: STREAM_NAME.to_string(),
+        topic: TOPIC_NAME.to_string(),
+        properties: HashMap::from([("path".to_string(), path.to_str().unwrap().to_string())]),
+    }
+}
+
+async fn get_connector(client: &IggyClient) -> ConnectorInfo {
+    client
+        .get_connector(&GetConnector {
+            name: CONNECTOR_NAME.to_string(),
+        })
+        .await
+        .unwrap()
+}
+
+async fn wait_for_processed_messages(client: &IggyClient) -> ConnectorInfo {
+    for _ in 0..100 {
+        let connector = get_connector(client).await;
+        if connector.processed_messages >= LINES_COUNT {
+            return connector;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    panic!("The connector has not processed all the lines in time.");
+}
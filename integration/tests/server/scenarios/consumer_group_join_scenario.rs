@@ -6,6 +6,8 @@ use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
 use iggy::models::client_info::ClientInfoDetails;
 use iggy::models::consumer_group::ConsumerGroupDetails;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::system::get_me::GetMe;
 use iggy::topics::create_topic::CreateTopic;
@@ -36,6 +38,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let create_stream = CreateStream {
         stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
     };
     system_client.create_stream(&create_stream).await.unwrap();
 
@@ -48,6 +51,11 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
@@ -182,6 +190,7 @@ async fn cleanup(system_client: &IggyClient) {
 
     let delete_stream = iggy::streams::delete_stream::DeleteStream {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
     };
     system_client.delete_stream(&delete_stream).await.unwrap();
 }
@@ -5,9 +5,11 @@ use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
 use iggy::models::consumer_group::ConsumerGroupDetails;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::system::get_me::GetMe;
@@ -60,6 +62,7 @@ async fn init_system(
     let create_stream = CreateStream {
         stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
     };
     system_client.create_stream(&create_stream).await.unwrap();
 
@@ -72,6 +75,11 @@ async fn init_system(
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
@@ -140,6 +148,7 @@ async fn execute_using_messages_key_key(
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
             messages,
+            acks: None,
         };
         system_client
             .send_messages(&mut send_messages)
@@ -165,6 +174,10 @@ async fn poll_messages(client: &IggyClient) -> u32 {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
     };
 
     let mut total_read_messages_count = 0;
@@ -201,6 +214,7 @@ async fn execute_using_none_key(
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
             messages,
+            acks: None,
         };
         system_client
             .send_messages(&mut send_messages)
@@ -248,6 +262,10 @@ async fn validate_message_polling(client: &IggyClient, consumer_group: &Consumer
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
     };
 
     for i in 1..=MESSAGES_COUNT {
@@ -280,6 +298,7 @@ async fn cleanup(system_client: &IggyClient, delete_users: bool) {
     }
     let delete_stream = DeleteStream {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
     };
     system_client.delete_stream(&delete_stream).await.unwrap();
 }
@@ -5,8 +5,10 @@ use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::system::get_me::GetMe;
@@ -33,6 +35,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     client
         .delete_stream(&DeleteStream {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            soft: false,
         })
         .await
         .unwrap();
@@ -47,6 +50,7 @@ async fn init_system(client: &IggyClient) {
     let create_stream = CreateStream {
         stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
     };
     client.create_stream(&create_stream).await.unwrap();
 
@@ -59,6 +63,11 @@ async fn init_system(client: &IggyClient) {
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -111,6 +120,7 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
             messages,
+            acks: None,
         };
         client.send_messages(&mut send_messages).await.unwrap();
     }
@@ -124,6 +134,10 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
     };
 
     let mut total_read_messages_count = 0;
@@ -155,6 +169,7 @@ async fn execute_using_none_key(client: &IggyClient) {
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
             messages,
+            acks: None,
         };
         client.send_messages(&mut send_messages).await.unwrap();
     }
@@ -168,6 +183,10 @@ async fn execute_using_none_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
     };
 
     let mut partition_id = 1;
@@ -204,6 +223,7 @@ fn get_extended_message_payload(partition_id: u32, entity_id: u32) -> String {
 async fn cleanup(system_client: &IggyClient) {
     let delete_stream = DeleteStream {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
     };
     system_client.delete_stream(&delete_stream).await.unwrap();
 }
@@ -0,0 +1,161 @@
+use iggy::client::{ConsumerGroupClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::topics::update_topic::UpdateTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 3;
+const CONSUMER_GROUP_ID: u32 = 1;
+const OTHER_CONSUMER_GROUP_ID: u32 = 2;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let system_client = create_client(client_factory).await;
+    let client1 = create_client(client_factory).await;
+    let client2 = create_client(client_factory).await;
+    login_root(&system_client).await;
+    login_root(&client1).await;
+    login_root(&client2).await;
+
+    let consumer_limits = ConsumerLimits {
+        max_consumer_groups: Some(1),
+        max_group_members: Some(1),
+    };
+    init_system(&system_client, consumer_limits).await;
+
+    // 1. The topic returns its consumer limits
+    let topic = system_client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.consumer_limits, consumer_limits);
+
+    // 2. Only a single consumer group can be created
+    create_consumer_group(&system_client, CONSUMER_GROUP_ID)
+        .await
+        .unwrap();
+    let error = create_consumer_group(&system_client, OTHER_CONSUMER_GROUP_ID)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        IggyError::InvalidResponse(status) if status == IggyError::ConsumerGroupsLimitReached(0, 0, 0).as_code()
+    ));
+
+    // 3. Only a single member can join the consumer group, while the existing one can join it again
+    join_consumer_group(&client1).await.unwrap();
+    let error = join_consumer_group(&client2).await.unwrap_err();
+    assert!(matches!(
+        error,
+        IggyError::InvalidResponse(status) if status == IggyError::ConsumerGroupMembersLimitReached(0, 0, 0).as_code()
+    ));
+    join_consumer_group(&client1).await.unwrap();
+    assert_eq!(get_members_count(&system_client).await, 1);
+
+    // 4. Remove the limits, the next consumer group and member are accepted
+    system_client
+        .update_topic(&UpdateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            name: TOPIC_NAME.to_string(),
+            consumer_limits: ConsumerLimits::default(),
+            max_message_size: None,
+            ..UpdateTopic::default()
+        })
+        .await
+        .unwrap();
+    create_consumer_group(&system_client, OTHER_CONSUMER_GROUP_ID)
+        .await
+        .unwrap();
+    join_consumer_group(&client2).await.unwrap();
+    assert_eq!(get_members_count(&system_client).await, 2);
+
+    cleanup(&system_client).await;
+    assert_clean_system(&system_client).await;
+}
+
+async fn init_system(client: &IggyClient, consumer_limits: ConsumerLimits) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic with the consumer limits
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        consumer_limits,
+        max_message_size: None,
+        ..CreateTopic::default()
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn create_consumer_group(
+    client: &IggyClient,
+    consumer_group_id: u32,
+) -> Result<(), IggyError> {
+    client
+        .create_consumer_group(&CreateConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id,
+            name: format!("test-consumer-group-{consumer_group_id}"),
+        })
+        .await
+}
+
+async fn join_consumer_group(client: &IggyClient) -> Result<(), IggyError> {
+    client
+        .join_consumer_group(&JoinConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+        })
+        .await
+}
+
+async fn get_members_count(client: &IggyClient) -> u32 {
+    client
+        .get_consumer_group(&GetConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+        })
+        .await
+        .unwrap()
+        .members_count
+}
+
+async fn create_client(client_factory: &dyn ClientFactory) -> IggyClient {
+    let client = client_factory.create_client().await;
+    IggyClient::create(client, IggyClientConfig::default(), None, None, None)
+}
+
+async fn cleanup(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
@@ -0,0 +1,249 @@
+use bytes::Bytes;
+use iggy::client::{
+    ConsumerOffsetClient, MessageClient, PartitionClient, StreamClient, TopicClient,
+};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::partitions::delete_partitions::{DeletePartitions, DeletePartitionsMode};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 3;
+const FIRST_PARTITION_MESSAGES_COUNT: u32 = 5;
+const LAST_PARTITION_MESSAGES_COUNT: u32 = 10;
+const CAUGHT_UP_CONSUMER_ID: u32 = 1;
+const LAGGING_CONSUMER_ID: u32 = 2;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the messages to the first and the last partition, and store the consumer offsets
+    send_messages(&client, 1, FIRST_PARTITION_MESSAGES_COUNT).await;
+    send_messages(&client, PARTITIONS_COUNT, LAST_PARTITION_MESSAGES_COUNT).await;
+    store_consumer_offset(&client, CAUGHT_UP_CONSUMER_ID, 1, 4).await;
+    store_consumer_offset(&client, CAUGHT_UP_CONSUMER_ID, PARTITIONS_COUNT, 3).await;
+    store_consumer_offset(&client, LAGGING_CONSUMER_ID, 1, 2).await;
+    store_consumer_offset(&client, LAGGING_CONSUMER_ID, PARTITIONS_COUNT, 7).await;
+
+    // 2. Deleting the partition which contains the messages is refused by default
+    let error = delete_partitions(&client, 1, DeletePartitionsMode::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        IggyError::InvalidResponse(status) if status == IggyError::PartitionNotEmpty(PARTITIONS_COUNT, 0).as_code()
+    ));
+    assert_topic(
+        &client,
+        PARTITIONS_COUNT,
+        FIRST_PARTITION_MESSAGES_COUNT + LAST_PARTITION_MESSAGES_COUNT,
+    )
+    .await;
+
+    // 3. Drain the last partition, its messages are appended to the first partition in the same order
+    delete_partitions(&client, 1, DeletePartitionsMode::Drain)
+        .await
+        .unwrap();
+    assert_topic(
+        &client,
+        PARTITIONS_COUNT - 1,
+        FIRST_PARTITION_MESSAGES_COUNT + LAST_PARTITION_MESSAGES_COUNT,
+    )
+    .await;
+    let polled_messages = poll_messages(&client, 1).await;
+    assert_eq!(
+        polled_messages.len() as u32,
+        FIRST_PARTITION_MESSAGES_COUNT + LAST_PARTITION_MESSAGES_COUNT
+    );
+    for (offset, message) in polled_messages.iter().enumerate() {
+        assert_eq!(message.offset, offset as u64);
+    }
+    for (index, message) in polled_messages
+        .iter()
+        .skip(FIRST_PARTITION_MESSAGES_COUNT as usize)
+        .enumerate()
+    {
+        assert_eq!(message.payload, get_payload(PARTITIONS_COUNT, index as u32));
+    }
+
+    // 4. The offset of the consumer which was caught up is moved along with the messages, the other one is kept
+    let offset = get_consumer_offset(&client, CAUGHT_UP_CONSUMER_ID, 1).await;
+    assert_eq!(offset, FIRST_PARTITION_MESSAGES_COUNT as u64 + 3);
+    let offset = get_consumer_offset(&client, LAGGING_CONSUMER_ID, 1).await;
+    assert_eq!(offset, 2);
+
+    // 5. Deleting the empty partition succeeds in any mode
+    delete_partitions(&client, 1, DeletePartitionsMode::Refuse)
+        .await
+        .unwrap();
+    assert_topic(
+        &client,
+        1,
+        FIRST_PARTITION_MESSAGES_COUNT + LAST_PARTITION_MESSAGES_COUNT,
+    )
+    .await;
+
+    // 6. The last partition can't be drained, as there's nowhere to move the messages to
+    let error = delete_partitions(&client, 1, DeletePartitionsMode::Drain)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        IggyError::InvalidResponse(status) if status == IggyError::PartitionNotEmpty(1, 0).as_code()
+    ));
+
+    // 7. Discard the last partition along with its messages
+    delete_partitions(&client, 1, DeletePartitionsMode::Discard)
+        .await
+        .unwrap();
+    assert_topic(&client, 0, 0).await;
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        ..CreateTopic::default()
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn send_messages(client: &IggyClient, partition_id: u32, messages_count: u32) {
+    let messages = (0..messages_count)
+        .map(|index| Message::from_str(&get_payload_text(partition_id, index)).unwrap())
+        .collect();
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(partition_id),
+        messages,
+        acks: None,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn poll_messages(
+    client: &IggyClient,
+    partition_id: u32,
+) -> Vec<iggy::models::messages::Message> {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(partition_id),
+        strategy: PollingStrategy::offset(0),
+        count: FIRST_PARTITION_MESSAGES_COUNT + LAST_PARTITION_MESSAGES_COUNT,
+        auto_commit: false,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
+    };
+    client.poll_messages(&poll_messages).await.unwrap().messages
+}
+
+async fn delete_partitions(
+    client: &IggyClient,
+    partitions_count: u32,
+    mode: DeletePartitionsMode,
+) -> Result<(), IggyError> {
+    client
+        .delete_partitions(&DeletePartitions {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitions_count,
+            mode,
+        })
+        .await
+}
+
+async fn store_consumer_offset(
+    client: &IggyClient,
+    consumer_id: u32,
+    partition_id: u32,
+    offset: u64,
+) {
+    client
+        .store_consumer_offset(&StoreConsumerOffset {
+            consumer: Consumer::new(Identifier::numeric(consumer_id).unwrap()),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(partition_id),
+            offset,
+        })
+        .await
+        .unwrap();
+}
+
+async fn get_consumer_offset(client: &IggyClient, consumer_id: u32, partition_id: u32) -> u64 {
+    client
+        .get_consumer_offset(&GetConsumerOffset {
+            consumer: Consumer::new(Identifier::numeric(consumer_id).unwrap()),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(partition_id),
+        })
+        .await
+        .unwrap()
+        .stored_offset
+}
+
+async fn assert_topic(client: &IggyClient, partitions_count: u32, messages_count: u32) {
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.partitions_count, partitions_count);
+    assert_eq!(topic.messages_count, messages_count as u64);
+}
+
+fn get_payload_text(partition_id: u32, index: u32) -> String {
+    format!("partition-{partition_id}-message-{index}")
+}
+
+fn get_payload(partition_id: u32, index: u32) -> Bytes {
+    Bytes::from(get_payload_text(partition_id, index))
+}
@@ -0,0 +1,200 @@
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::create_fetch_session::{CreateFetchSession, FetchPartition};
+use iggy::messages::delete_fetch_session::DeleteFetchSession;
+use iggy::messages::fetch_messages::FetchMessages;
+use iggy::messages::send_messages::Message;
+use iggy::models::fetch_session::{FetchSession, FetchedMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 3;
+const MESSAGES_COUNT: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Register all the partitions of the topic in the fetch session
+    let fetch_session = create_fetch_session(&client).await;
+    assert_eq!(fetch_session.epoch, 0);
+
+    // 2. Nothing is fetched from the empty partitions
+    let fetched_messages = fetch_messages(&client, &fetch_session, 0).await.unwrap();
+    assert_eq!(fetched_messages.epoch, 1);
+    assert!(fetched_messages.partitions.is_empty());
+
+    // 3. Only the partitions with the new messages are returned
+    send_messages(&client, 1, 5).await;
+    send_messages(&client, 3, 3).await;
+    let fetched_messages = fetch_messages(&client, &fetch_session, 1).await.unwrap();
+    assert_eq!(fetched_messages.epoch, 2);
+    assert_fetched_offsets(
+        &fetched_messages,
+        &[(1, vec![0, 1, 2, 3, 4]), (3, vec![0, 1, 2])],
+    );
+
+    // 4. The stale epoch is rejected
+    let error = fetch_messages(&client, &fetch_session, 1)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        IggyError::InvalidResponse(status) if status == IggyError::InvalidFetchSessionEpoch(0, 0, 0).as_code()
+    ));
+
+    // 5. The next fetch continues from where the previous one ended, up to the count of messages per partition
+    send_messages(&client, 1, 12).await;
+    let fetched_messages = fetch_messages(&client, &fetch_session, 2).await.unwrap();
+    assert_eq!(fetched_messages.epoch, 3);
+    assert_fetched_offsets(&fetched_messages, &[(1, (5..15).collect())]);
+    let fetched_messages = fetch_messages(&client, &fetch_session, 3).await.unwrap();
+    assert_fetched_offsets(&fetched_messages, &[(1, vec![15, 16])]);
+
+    // 6. The offsets of the fetched messages are committed automatically
+    assert_eq!(get_stored_offset(&client, 1).await, 16);
+    assert_eq!(get_stored_offset(&client, 3).await, 2);
+
+    // 7. The deleted session can no longer be fetched
+    client
+        .delete_fetch_session(&DeleteFetchSession {
+            session_id: fetch_session.session_id,
+        })
+        .await
+        .unwrap();
+    let error = fetch_messages(&client, &fetch_session, 4)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        IggyError::InvalidResponse(status) if status == IggyError::FetchSessionNotFound(0).as_code()
+    ));
+
+    // 8. The new session starts from the committed offsets
+    let fetch_session = create_fetch_session(&client).await;
+    let fetched_messages = fetch_messages(&client, &fetch_session, 0).await.unwrap();
+    assert!(fetched_messages.partitions.is_empty());
+    send_messages(&client, 2, 1).await;
+    let fetched_messages = fetch_messages(&client, &fetch_session, 1).await.unwrap();
+    assert_fetched_offsets(&fetched_messages, &[(2, vec![0])]);
+
+    cleanup(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic with multiple partitions
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        ..CreateTopic::default()
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn create_fetch_session(client: &IggyClient) -> FetchSession {
+    client
+        .create_fetch_session(&CreateFetchSession {
+            consumer: Consumer::default(),
+            partitions: (1..=PARTITIONS_COUNT)
+                .map(|partition_id| FetchPartition {
+                    stream_id: Identifier::named(STREAM_NAME).unwrap(),
+                    topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+                    partition_id,
+                })
+                .collect(),
+            count: MESSAGES_COUNT,
+            auto_commit: true,
+        })
+        .await
+        .unwrap()
+}
+
+async fn fetch_messages(
+    client: &IggyClient,
+    fetch_session: &FetchSession,
+    epoch: u32,
+) -> Result<FetchedMessages, IggyError> {
+    client
+        .fetch_messages(&FetchMessages {
+            session_id: fetch_session.session_id,
+            epoch,
+        })
+        .await
+}
+
+async fn send_messages(client: &IggyClient, partition_id: u32, count: u32) {
+    client
+        .send()
+        .stream(STREAM_NAME)
+        .topic(TOPIC_NAME)
+        .partition(partition_id)
+        .messages((0..count).map(|i| Message::from_str(&format!("message {i}")).unwrap()))
+        .await
+        .unwrap();
+}
+
+fn assert_fetched_offsets(fetched_messages: &FetchedMessages, expected: &[(u32, Vec<u64>)]) {
+    let fetched = fetched_messages
+        .partitions
+        .iter()
+        .map(|partition| {
+            assert_eq!(partition.stream_id, STREAM_ID);
+            assert_eq!(partition.topic_id, TOPIC_ID);
+            (
+                partition.messages.partition_id,
+                partition
+                    .messages
+                    .messages
+                    .iter()
+                    .map(|message| message.offset)
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(fetched, expected);
+}
+
+async fn get_stored_offset(client: &IggyClient, partition_id: u32) -> u64 {
+    client
+        .get_consumer_offset(&GetConsumerOffset {
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(partition_id),
+        })
+        .await
+        .unwrap()
+        .stored_offset
+}
+
+async fn cleanup(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
@@ -0,0 +1,147 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::freeze_topic::FreezeTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::topics::unfreeze_topic::UnfreezeTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. The topic is writable by default
+    assert!(!is_frozen(&client).await);
+    send_messages(&client).await.unwrap();
+
+    // 2. Freeze the topic, the messages can no longer be sent to it
+    client.freeze_topic(&freeze_topic()).await.unwrap();
+    assert!(is_frozen(&client).await);
+    assert!(send_messages(&client).await.is_err());
+
+    // 3. The messages sent before the topic was frozen can still be polled
+    assert_eq!(poll_messages(&client).await, MESSAGES_COUNT);
+
+    // 4. Freezing the frozen topic again has no effect
+    client.freeze_topic(&freeze_topic()).await.unwrap();
+    assert!(is_frozen(&client).await);
+
+    // 5. Unfreeze the topic, the messages can be sent to it again
+    client
+        .unfreeze_topic(&UnfreezeTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert!(!is_frozen(&client).await);
+    send_messages(&client).await.unwrap();
+    assert_eq!(poll_messages(&client).await, 2 * MESSAGES_COUNT);
+
+    // 6. The topic which doesn't exist cannot be frozen
+    assert!(client
+        .freeze_topic(&FreezeTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID + 1).unwrap(),
+        })
+        .await
+        .is_err());
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        name: TOPIC_NAME.to_string(),
+        ..CreateTopic::default()
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn is_frozen(client: &IggyClient) -> bool {
+    let get_topic = GetTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+    };
+    client.get_topic(&get_topic).await.unwrap().frozen
+}
+
+async fn send_messages(client: &IggyClient) -> Result<(), iggy::error::IggyError> {
+    let messages = (0..MESSAGES_COUNT)
+        .map(|id| Message::new(None, Bytes::from(format!("message {id}")), None))
+        .collect();
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages,
+        acks: None,
+    };
+    client.send_messages(&mut send_messages).await
+}
+
+async fn poll_messages(client: &IggyClient) -> u32 {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 2 * MESSAGES_COUNT,
+        auto_commit: false,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
+    };
+    client
+        .poll_messages(&poll_messages)
+        .await
+        .unwrap()
+        .messages
+        .len() as u32
+}
+
+fn freeze_topic() -> FreezeTopic {
+    FreezeTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+    }
+}
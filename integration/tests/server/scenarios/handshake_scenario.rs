@@ -0,0 +1,40 @@
+use iggy::client::{Client, SystemClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::system::hello::{
+    compression_feature, Hello, BATCH_V1_FEATURE, HEARTBEAT_FEATURE, PROTOCOL_VERSION,
+};
+use integration::test_server::ClientFactory;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = IggyClient::new(client_factory.create_client().await);
+    assert!(client.hello_info().await.is_none());
+
+    // 1. The handshake is performed when connecting to the server
+    client.connect().await.unwrap();
+    let hello_info = client.hello_info().await.unwrap();
+    assert_eq!(hello_info.protocol_version, PROTOCOL_VERSION);
+    assert!(!hello_info.server_version.is_empty());
+    assert!(hello_info.supports(HEARTBEAT_FEATURE));
+    assert!(hello_info.supports(&compression_feature(&CompressionAlgorithm::Zstd)));
+
+    // 2. The newer protocol version is downgraded and the unknown features are skipped
+    let hello_info = client
+        .hello(&Hello {
+            protocol_version: PROTOCOL_VERSION + 1,
+            features: vec!["unknown".to_string(), BATCH_V1_FEATURE.to_string()],
+        })
+        .await
+        .unwrap();
+    assert_eq!(hello_info.protocol_version, PROTOCOL_VERSION);
+    assert_eq!(hello_info.features, vec![BATCH_V1_FEATURE.to_string()]);
+
+    // 3. The invalid protocol version is rejected
+    assert!(client
+        .hello(&Hello {
+            protocol_version: 0,
+            features: Vec::new(),
+        })
+        .await
+        .is_err());
+}
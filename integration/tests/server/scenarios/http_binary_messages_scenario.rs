@@ -0,0 +1,212 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient, UserClient};
+use iggy::consumer::Consumer;
+use iggy::http::client::HttpClient;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::models::header::{HeaderKey, CONTENT_TYPE_HEADER};
+use iggy::models::messages::PolledMessages;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::users::login_user::LoginUser;
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::StatusCode;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 2;
+const PARTITION_ID: u32 = 1;
+const BOUNDARY: &str = "message-boundary";
+
+pub async fn run(server_addr: &str) {
+    let client = HttpClient::new(&format!("http://{server_addr}")).unwrap();
+    let identity = client
+        .login_user(&LoginUser {
+            username: DEFAULT_ROOT_USERNAME.to_string(),
+            password: DEFAULT_ROOT_PASSWORD.to_string(),
+        })
+        .await
+        .unwrap();
+    let access_token = format!("Bearer {}", identity.tokens.unwrap().access_token.token);
+    init_system(&client).await;
+
+    let http = reqwest::Client::new();
+    let url = format!(
+        "http://{server_addr}/streams/{STREAM_ID}/topics/{TOPIC_ID}/messages?partition_id={PARTITION_ID}"
+    );
+
+    // 1. Send the raw bytes as a single message, the content type is stored in the message header
+    let binary_payload = Bytes::from_static(&[0, 159, 146, 150, 255]);
+    let response = http
+        .post(&url)
+        .header(AUTHORIZATION, &access_token)
+        .header(CONTENT_TYPE, "image/png")
+        .body(binary_payload.clone())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // 2. Send the multipart form with a message per part, the part without the content type has no header
+    let mut body = Vec::new();
+    for (content_type, payload) in [(Some("text/plain"), "first"), (None, "second")] {
+        body.extend_from_slice(
+            format!("--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"message\"\r\n")
+                .as_bytes(),
+        );
+        if let Some(content_type) = content_type {
+            body.extend_from_slice(format!("Content-Type: {content_type}\r\n").as_bytes());
+        }
+        body.extend_from_slice(format!("\r\n{payload}\r\n").as_bytes());
+    }
+    body.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+    let response = http
+        .post(&url)
+        .header(AUTHORIZATION, &access_token)
+        .header(
+            CONTENT_TYPE,
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        )
+        .body(body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // 3. Sending to the partition and with the messages key at the same time is rejected
+    let response = http
+        .post(format!("{url}&messages_key=key"))
+        .header(AUTHORIZATION, &access_token)
+        .header(CONTENT_TYPE, "text/plain")
+        .body("invalid")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // 4. Messages sent as binary are polled as JSON with the base64 encoded payloads and the content type header
+    let polled_messages = poll_messages(&client).await;
+    assert_eq!(polled_messages.messages.len(), 3);
+    let content_type_key = HeaderKey::new(CONTENT_TYPE_HEADER).unwrap();
+    let expected = [
+        (binary_payload.clone(), Some("image/png")),
+        (Bytes::from("first"), Some("text/plain")),
+        (Bytes::from("second"), None),
+    ];
+    for (message, (payload, content_type)) in polled_messages.messages.iter().zip(expected) {
+        assert_eq!(message.payload, payload);
+        let stored_content_type = message
+            .headers
+            .as_ref()
+            .and_then(|headers| headers.get(&content_type_key))
+            .map(|value| value.as_str().unwrap());
+        assert_eq!(stored_content_type, content_type);
+    }
+
+    // 5. Poll the payload of the first message as the raw bytes with its content type
+    let poll_url = format!(
+        "http://{server_addr}/streams/{STREAM_ID}/topics/{TOPIC_ID}/messages?consumer_id=1&partition_id={PARTITION_ID}&kind=offset&value=0&count=3"
+    );
+    let response = http
+        .get(&poll_url)
+        .header(AUTHORIZATION, &access_token)
+        .header(ACCEPT, "application/octet-stream")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "image/png");
+    assert_eq!(response.headers()["iggy-offset"], "0");
+    assert_eq!(response.headers()["iggy-partition-id"], "1");
+    assert_eq!(response.headers()["iggy-current-offset"], "2");
+    assert_eq!(response.bytes().await.unwrap(), binary_payload);
+
+    // 6. Poll all messages as the multipart response with a part per message
+    let response = http
+        .get(&poll_url)
+        .header(AUTHORIZATION, &access_token)
+        .header(ACCEPT, "multipart/mixed")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let content_type = response.headers()[CONTENT_TYPE].to_str().unwrap();
+    let boundary = content_type
+        .strip_prefix("multipart/mixed; boundary=")
+        .unwrap()
+        .to_string();
+    let body = response.bytes().await.unwrap();
+    let body = String::from_utf8_lossy(&body);
+    let parts = body
+        .split(&format!("--{boundary}"))
+        .filter(|part| part.starts_with("\r\n"))
+        .collect::<Vec<_>>();
+    assert_eq!(parts.len(), 3);
+    assert!(parts[1].contains("content-type: text/plain\r\n"));
+    assert!(parts[1].contains("iggy-offset: 1\r\n"));
+    assert!(parts[1].ends_with("\r\n\r\nfirst\r\n"));
+    assert!(parts[2].contains("content-type: application/octet-stream\r\n"));
+    assert!(parts[2].ends_with("\r\n\r\nsecond\r\n"));
+
+    // 7. Polling the raw bytes with no messages returns no content
+    let response = http
+        .get(poll_url.replace("value=0", "value=10"))
+        .header(AUTHORIZATION, &access_token)
+        .header(ACCEPT, "application/octet-stream")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+}
+
+async fn init_system(client: &HttpClient) {
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(STREAM_ID),
+            name: STREAM_NAME.to_string(),
+            ..CreateStream::default()
+        })
+        .await
+        .unwrap();
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Some(TOPIC_ID),
+            partitions_count: PARTITIONS_COUNT,
+            name: TOPIC_NAME.to_string(),
+            ..CreateTopic::default()
+        })
+        .await
+        .unwrap();
+}
+
+async fn poll_messages(client: &HttpClient) -> PolledMessages {
+    client
+        .poll_messages(&PollMessages {
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+            strategy: PollingStrategy::offset(0),
+            count: 10,
+            auto_commit: false,
+            filter: None,
+            mode: PollingMode::Peek,
+            max_wait_ms: None,
+            max_bytes: None,
+        })
+        .await
+        .unwrap()
+}
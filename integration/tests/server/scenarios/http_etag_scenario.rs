@@ -0,0 +1,105 @@
+use iggy::client::{StreamClient, UserClient};
+use iggy::http::client::HttpClient;
+use iggy::identifier::Identifier;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::users::login_user::LoginUser;
+use reqwest::header::{AUTHORIZATION, ETAG, IF_NONE_MATCH};
+use reqwest::StatusCode;
+
+const STREAM_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+
+pub async fn run(server_addr: &str) {
+    let client = HttpClient::new(&format!("http://{server_addr}")).unwrap();
+    let identity = client
+        .login_user(&LoginUser {
+            username: DEFAULT_ROOT_USERNAME.to_string(),
+            password: DEFAULT_ROOT_PASSWORD.to_string(),
+        })
+        .await
+        .unwrap();
+    let access_token = format!("Bearer {}", identity.tokens.unwrap().access_token.token);
+    let http = reqwest::Client::new();
+    let url = format!("http://{server_addr}/streams");
+
+    // 1. The resources are returned with the ETag
+    let response = http
+        .get(&url)
+        .header(AUTHORIZATION, &access_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers()[ETAG].clone();
+
+    // 2. Nothing is returned if the resources haven't changed since the ETag was returned
+    let response = http
+        .get(&url)
+        .header(AUTHORIZATION, &access_token)
+        .header(IF_NONE_MATCH, etag.clone())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[ETAG], etag);
+    assert!(response.bytes().await.unwrap().is_empty());
+
+    // 3. Changed resources are returned with the new ETag
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(STREAM_ID),
+            name: STREAM_NAME.to_string(),
+            ..CreateStream::default()
+        })
+        .await
+        .unwrap();
+    let response = http
+        .get(&url)
+        .header(AUTHORIZATION, &access_token)
+        .header(IF_NONE_MATCH, etag.clone())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(response.headers()[ETAG], etag);
+
+    // 4. Single resources and users are conditional too
+    for path in [format!("streams/{STREAM_ID}"), "users".to_string()] {
+        let url = format!("http://{server_addr}/{path}");
+        let response = http
+            .get(&url)
+            .header(AUTHORIZATION, &access_token)
+            .send()
+            .await
+            .unwrap();
+        let etag = response.headers()[ETAG].clone();
+        let response = http
+            .get(&url)
+            .header(AUTHORIZATION, &access_token)
+            .header(IF_NONE_MATCH, etag)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    }
+
+    // 5. Errors are returned without the ETag
+    let response = http
+        .get(format!("http://{server_addr}/streams/100"))
+        .header(AUTHORIZATION, &access_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert!(response.headers().get(ETAG).is_none());
+
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+}
@@ -0,0 +1,116 @@
+use iggy::client::{StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::Message;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    let producer = client_factory.create_client().await;
+    let producer = IggyClient::create(producer, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    login_root(&producer).await;
+    init_system(&client).await;
+
+    // 1. The empty partition is polled until the max wait time elapses
+    let now = Instant::now();
+    let polled_messages = client
+        .poll()
+        .stream(STREAM_NAME)
+        .topic(TOPIC_NAME)
+        .partition(PARTITION_ID)
+        .first(10)
+        .wait(Duration::from_millis(300))
+        .await
+        .unwrap();
+    assert!(polled_messages.messages.is_empty());
+    assert!(now.elapsed() >= Duration::from_millis(250));
+
+    // 2. The waiting poll returns as soon as the message is appended
+    let sender = tokio::spawn(async move {
+        sleep(Duration::from_millis(200)).await;
+        producer
+            .send()
+            .stream(STREAM_NAME)
+            .topic(TOPIC_NAME)
+            .partition(PARTITION_ID)
+            .messages([Message::from_str("message").unwrap()])
+            .await
+            .unwrap();
+    });
+    let now = Instant::now();
+    let polled_messages = client
+        .poll()
+        .stream(STREAM_NAME)
+        .topic(TOPIC_NAME)
+        .partition(PARTITION_ID)
+        .first(10)
+        .wait(Duration::from_secs(30))
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert!(now.elapsed() < Duration::from_secs(10));
+    sender.await.unwrap();
+
+    // 3. The available messages are returned without waiting
+    let now = Instant::now();
+    let polled_messages = client
+        .poll()
+        .stream(STREAM_NAME)
+        .topic(TOPIC_NAME)
+        .partition(PARTITION_ID)
+        .first(10)
+        .wait(Duration::from_secs(30))
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert!(now.elapsed() < Duration::from_secs(10));
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(STREAM_ID),
+            name: STREAM_NAME.to_string(),
+            ..CreateStream::default()
+        })
+        .await
+        .unwrap();
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Some(TOPIC_ID),
+            partitions_count: 1,
+            name: TOPIC_NAME.to_string(),
+            ..CreateTopic::default()
+        })
+        .await
+        .unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+}
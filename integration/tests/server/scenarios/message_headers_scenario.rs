@@ -3,12 +3,20 @@ use iggy::client::{MessageClient, StreamClient, TopicClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::consumer::Consumer;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::headers_filter::HeadersFilter;
+use iggy::messages::messages_query::MessagesQuery;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::query_messages::QueryMessages;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
-use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::header::{
+    HeaderKey, HeaderValue, RECEIVE_TIMESTAMP_HEADER, TRANSPORT_HEADER, USER_ID_HEADER,
+};
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
 use integration::test_server::{assert_clean_system, login_root, ClientFactory};
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -20,6 +28,8 @@ const TOPIC_NAME: &str = "test-topic";
 const PARTITIONS_COUNT: u32 = 3;
 const MESSAGES_COUNT: u32 = 1000;
 const PARTITION_ID: u32 = 1;
+const REGIONS: [&str; 3] = ["eu", "us", "ap"];
+const SYSTEM_HEADERS_COUNT: usize = 4;
 
 pub async fn run(client_factory: &dyn ClientFactory) {
     let client = client_factory.create_client().await;
@@ -33,7 +43,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     for offset in 0..MESSAGES_COUNT {
         let id = (offset + 1) as u128;
         let payload = get_message_payload(offset as u64);
-        let headers = get_message_headers();
+        let headers = get_message_headers(offset as u64);
         messages.push(Message {
             id,
             length: payload.len() as u32,
@@ -47,10 +57,11 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
         messages,
+        acks: None,
     };
     client.send_messages(&mut send_messages).await.unwrap();
 
-    // 2. Poll messages and validate the headers
+    // 2. Poll messages and validate the headers, including the system ones replacing the spoofed user ID
     let poll_messages = PollMessages {
         consumer: Consumer::default(),
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -59,6 +70,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -67,7 +82,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         let message = polled_messages.messages.get(i as usize).unwrap();
         assert!(message.headers.is_some());
         let headers = message.headers.as_ref().unwrap();
-        assert_eq!(headers.len(), 3);
+        assert_eq!(headers.len(), 4 + SYSTEM_HEADERS_COUNT);
         assert_eq!(
             headers
                 .get(&HeaderKey::new("key_1").unwrap())
@@ -89,7 +104,140 @@ pub async fn run(client_factory: &dyn ClientFactory) {
                 .unwrap(),
             123456
         );
+        assert_eq!(
+            headers
+                .get(&HeaderKey::new(USER_ID_HEADER).unwrap())
+                .unwrap()
+                .as_uint32()
+                .unwrap(),
+            DEFAULT_ROOT_USER_ID
+        );
+        assert_eq!(
+            headers
+                .get(&HeaderKey::new(RECEIVE_TIMESTAMP_HEADER).unwrap())
+                .unwrap()
+                .as_uint64()
+                .unwrap(),
+            message.timestamp
+        );
+        assert!(!headers
+            .get(&HeaderKey::new(TRANSPORT_HEADER).unwrap())
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .is_empty());
     }
+
+    // 3. Poll only the messages matching the headers filter
+    let poll_messages = PollMessages {
+        strategy: PollingStrategy::offset(0),
+        count: 10,
+        filter: Some(
+            HeadersFilter::new()
+                .equals("region", "eu")
+                .unwrap()
+                .equals("key-3", "123456")
+                .unwrap(),
+        ),
+        ..poll_messages
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    let offsets = get_offsets(&polled_messages.messages);
+    assert_eq!(offsets, (0..30).step_by(3).collect::<Vec<_>>());
+
+    // 4. Poll the next matching messages with the auto commit, the last scanned offset should be stored
+    let poll_messages = PollMessages {
+        strategy: PollingStrategy::next(),
+        count: 5,
+        auto_commit: true,
+        filter: Some(HeadersFilter::from_str("region in [us, ap] && key_1 ^= Value").unwrap()),
+        ..poll_messages
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![1, 2, 4, 5, 7]);
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(
+        get_offsets(&polled_messages.messages),
+        vec![8, 10, 11, 13, 14]
+    );
+
+    // 5. Poll the messages with the filter not matching any message
+    let poll_messages = PollMessages {
+        filter: Some(HeadersFilter::from_str("region == unknown").unwrap()),
+        ..poll_messages
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert!(polled_messages.messages.is_empty());
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert!(polled_messages.messages.is_empty());
+
+    // 6. Query the messages matching the conditions on the offset and headers
+    let query_messages = QueryMessages {
+        query: MessagesQuery::from_str(&format!(
+            "SELECT offset, length, headers.region FROM {STREAM_NAME}.{TOPIC_NAME}.{PARTITION_ID} \
+            WHERE offset >= 100 AND headers.region != 'eu' LIMIT 4"
+        ))
+        .unwrap(),
+    };
+    let result = client.query_messages(&query_messages).await.unwrap();
+    assert_eq!(result.columns, vec!["offset", "length", "headers.region"]);
+    assert_eq!(result.rows.len(), 4);
+    assert!(result.next_offset.is_none());
+    for (row, offset) in result.rows.iter().zip([100, 101, 103, 104]) {
+        let length = get_message_payload(offset).len() as u64;
+        let region = REGIONS[offset as usize % REGIONS.len()];
+        let row = row
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            row,
+            vec![
+                offset.to_string(),
+                length.to_string(),
+                format!("\"{region}\"")
+            ]
+        );
+    }
+
+    // 7. Query the messages not matching any condition, the consumer offset should not be affected
+    let query_messages = QueryMessages {
+        query: MessagesQuery::from_str(&format!(
+            "SELECT * FROM {STREAM_ID}.{TOPIC_ID}.{PARTITION_ID} WHERE offset > {MESSAGES_COUNT}"
+        ))
+        .unwrap(),
+    };
+    let result = client.query_messages(&query_messages).await.unwrap();
+    assert_eq!(result.columns.len(), 7);
+    assert!(result.rows.is_empty());
+
+    // 8. Peek the last messages backward
+    let poll_messages = PollMessages {
+        strategy: PollingStrategy::last(),
+        count: 5,
+        auto_commit: false,
+        filter: None,
+        mode: PollingMode::PeekBackward,
+        max_wait_ms: None,
+        max_bytes: None,
+        ..poll_messages
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(
+        get_offsets(&polled_messages.messages),
+        vec![999, 998, 997, 996, 995]
+    );
+
+    // 9. Peek the messages matching the headers filter backward from the given offset
+    let poll_messages = PollMessages {
+        strategy: PollingStrategy::offset(10),
+        count: 3,
+        filter: Some(HeadersFilter::from_str("region == eu").unwrap()),
+        ..poll_messages
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![9, 6, 3]);
+
     cleanup_system(&client).await;
     assert_clean_system(&client).await;
 }
@@ -99,6 +247,7 @@ async fn init_system(client: &IggyClient) {
     let create_stream = CreateStream {
         stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
     };
     client.create_stream(&create_stream).await.unwrap();
 
@@ -111,6 +260,11 @@ async fn init_system(client: &IggyClient) {
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
     };
     client.create_topic(&create_topic).await.unwrap();
 }
@@ -118,6 +272,7 @@ async fn init_system(client: &IggyClient) {
 async fn cleanup_system(client: &IggyClient) {
     let delete_stream = DeleteStream {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
     };
     client.delete_stream(&delete_stream).await.unwrap();
 }
@@ -126,7 +281,11 @@ fn get_message_payload(offset: u64) -> Bytes {
     Bytes::from(format!("message {}", offset))
 }
 
-fn get_message_headers() -> HashMap<HeaderKey, HeaderValue> {
+fn get_offsets(messages: &[iggy::models::messages::Message]) -> Vec<u64> {
+    messages.iter().map(|message| message.offset).collect()
+}
+
+fn get_message_headers(offset: u64) -> HashMap<HeaderKey, HeaderValue> {
     let mut headers = HashMap::new();
     headers.insert(
         HeaderKey::new("key_1").unwrap(),
@@ -140,5 +299,13 @@ fn get_message_headers() -> HashMap<HeaderKey, HeaderValue> {
         HeaderKey::new("key-3").unwrap(),
         HeaderValue::from_uint64(123456).unwrap(),
     );
+    headers.insert(
+        HeaderKey::new("region").unwrap(),
+        HeaderValue::from_str(REGIONS[offset as usize % REGIONS.len()]).unwrap(),
+    );
+    headers.insert(
+        HeaderKey::new(USER_ID_HEADER).unwrap(),
+        HeaderValue::from_uint32(DEFAULT_ROOT_USER_ID + 100).unwrap(),
+    );
     headers
 }
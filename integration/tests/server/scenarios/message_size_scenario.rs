@@ -0,0 +1,145 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::topics::update_topic::UpdateTopic;
+use iggy::utils::byte_size::IggyByteSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const MAX_MESSAGE_SIZE: u64 = 100;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. The topic returns its max message size
+    assert_eq!(
+        get_max_message_size(&client).await,
+        Some(IggyByteSize::from(MAX_MESSAGE_SIZE))
+    );
+
+    // 2. The messages not bigger than the max message size are accepted
+    send_messages(&client, &[10, MAX_MESSAGE_SIZE as usize])
+        .await
+        .unwrap();
+    assert_eq!(poll_messages(&client).await, 2);
+
+    // 3. The batch with a single message bigger than the max message size is rejected as a whole
+    let error = send_messages(&client, &[10, MAX_MESSAGE_SIZE as usize + 1, 10])
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        IggyError::InvalidResponse(status) if status == IggyError::MessageTooLarge(0, 0, 0).as_code()
+    ));
+    assert_eq!(poll_messages(&client).await, 2);
+
+    // 4. Remove the max message size, the server default (unlimited) is used and the bigger messages are accepted
+    client
+        .update_topic(&UpdateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            name: TOPIC_NAME.to_string(),
+            max_message_size: None,
+            ..UpdateTopic::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(get_max_message_size(&client).await, None);
+    send_messages(&client, &[10, MAX_MESSAGE_SIZE as usize + 1, 10])
+        .await
+        .unwrap();
+    assert_eq!(poll_messages(&client).await, 5);
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic with the max message size
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        name: TOPIC_NAME.to_string(),
+        max_message_size: Some(IggyByteSize::from(MAX_MESSAGE_SIZE)),
+        ..CreateTopic::default()
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn get_max_message_size(client: &IggyClient) -> Option<IggyByteSize> {
+    let get_topic = GetTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+    };
+    client.get_topic(&get_topic).await.unwrap().max_message_size
+}
+
+async fn send_messages(client: &IggyClient, payload_sizes: &[usize]) -> Result<(), IggyError> {
+    let messages = payload_sizes
+        .iter()
+        .map(|size| Message::new(None, Bytes::from(vec![b'x'; *size]), None))
+        .collect();
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages,
+        acks: None,
+    };
+    client.send_messages(&mut send_messages).await
+}
+
+async fn poll_messages(client: &IggyClient) -> u32 {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 10,
+        auto_commit: false,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
+    };
+    client
+        .poll_messages(&poll_messages)
+        .await
+        .unwrap()
+        .messages
+        .len() as u32
+}
@@ -1,7 +1,32 @@
+pub mod client_compression_scenario;
+pub mod client_metrics_scenario;
+pub mod command_batch_scenario;
+pub mod connectors_scenario;
 pub mod consumer_group_join_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
+pub mod consumer_limits_scenario;
+pub mod delete_partitions_scenario;
+pub mod fetch_session_scenario;
+pub mod frozen_topic_scenario;
+pub mod handshake_scenario;
+pub mod http_binary_messages_scenario;
+pub mod http_etag_scenario;
+pub mod long_polling_scenario;
 pub mod message_headers_scenario;
+pub mod message_size_scenario;
+pub mod offset_auto_commit_scenario;
+pub mod resource_uuids_scenario;
+pub mod routing_epoch_scenario;
+pub mod schema_registry_scenario;
+pub mod stream_admin_scenario;
 pub mod stream_size_validation_scenario;
+pub mod stream_topic_defaults_scenario;
 pub mod system_scenario;
+pub mod tenants_scenario;
+pub mod timestamp_type_scenario;
+pub mod topic_auto_create_scenario;
+pub mod trash_scenario;
+pub mod user_expiry_scenario;
+pub mod user_groups_scenario;
 pub mod user_scenario;
@@ -0,0 +1,144 @@
+use iggy::client::{ConsumerOffsetClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig, PollMessagesConfig, StoreOffsetKind};
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::Message;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::messages::Message as PolledMessage;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+const COMMIT_MESSAGES: u32 = 4;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the messages
+    client
+        .send()
+        .stream(STREAM_NAME)
+        .topic(TOPIC_NAME)
+        .partition(PARTITION_ID)
+        .messages((0..MESSAGES_COUNT).map(|i| Message::from_str(&format!("message {i}")).unwrap()))
+        .await
+        .unwrap();
+
+    // 2. Poll the messages in the background, storing the offset after every few processed messages
+    let processed_messages = Arc::new(AtomicU32::new(0));
+    let on_message = {
+        let processed_messages = processed_messages.clone();
+        move |_: PolledMessage| {
+            processed_messages.fetch_add(1, Ordering::SeqCst);
+        }
+    };
+    let poll_messages = PollMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: MESSAGES_COUNT,
+        ..PollMessages::default()
+    };
+    let polling = client.start_polling_messages(
+        poll_messages,
+        Some(on_message),
+        Some(PollMessagesConfig {
+            interval: 10,
+            store_offset_kind: StoreOffsetKind::AutoCommit {
+                interval: 60_000,
+                messages: COMMIT_MESSAGES,
+            },
+        }),
+    );
+
+    for _ in 0..500 {
+        if processed_messages.load(Ordering::SeqCst) == MESSAGES_COUNT {
+            break;
+        }
+        sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(processed_messages.load(Ordering::SeqCst), MESSAGES_COUNT);
+
+    // 3. Only the offset of the last full batch of processed messages should be stored
+    let last_committed_offset = (MESSAGES_COUNT / COMMIT_MESSAGES * COMMIT_MESSAGES - 1) as u64;
+    assert_eq!(get_stored_offset(&client).await, last_committed_offset);
+
+    // 4. Commit the pending offset of the remaining processed messages
+    client.commit_sync().await.unwrap();
+    assert_eq!(
+        get_stored_offset(&client).await,
+        (MESSAGES_COUNT - 1) as u64
+    );
+
+    polling.abort();
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn get_stored_offset(client: &IggyClient) -> u64 {
+    client
+        .get_consumer_offset(&GetConsumerOffset {
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+        })
+        .await
+        .unwrap()
+        .stored_offset
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        max_topic_size: None,
+        replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
@@ -0,0 +1,220 @@
+use iggy::client::{StreamClient, TopicClient, UserClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::identifier::Identifier;
+use iggy::models::user_status::UserStatus;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_stream::GetStream;
+use iggy::streams::get_streams::GetStreams;
+use iggy::streams::update_stream::UpdateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::topics::get_topics::GetTopics;
+use iggy::topics::update_topic::UpdateTopic;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::get_user::GetUser;
+use iggy::users::get_users::GetUsers;
+use iggy::users::update_user::UpdateUser;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use uuid::Uuid;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const USERNAME: &str = "test-user";
+const RENAMED_STREAM_NAME: &str = "renamed-stream";
+const RENAMED_TOPIC_NAME: &str = "renamed-topic";
+const RENAMED_USERNAME: &str = "renamed-user";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. The UUIDs are assigned on creation and returned by both the get and list responses
+    let stream = client
+        .get_stream(&GetStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    let stream_uuid = stream.uuid;
+    assert!(!stream_uuid.is_nil());
+    let topic = stream.topics.first().unwrap();
+    let topic_uuid = topic.uuid;
+    assert!(!topic_uuid.is_nil());
+    assert_ne!(topic_uuid, stream_uuid);
+    let streams = client.get_streams(&GetStreams {}).await.unwrap();
+    assert_eq!(streams.first().unwrap().uuid, stream_uuid);
+    let topics = client
+        .get_topics(&GetTopics {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topics.first().unwrap().uuid, topic_uuid);
+    let user = client
+        .get_user(&GetUser {
+            user_id: Identifier::named(USERNAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    let user_id = user.id;
+    let user_uuid = user.uuid;
+    assert!(!user_uuid.is_nil());
+    let users = client.get_users(&GetUsers {}).await.unwrap();
+    assert!(users.iter().all(|user| !user.uuid.is_nil()));
+    assert_eq!(
+        users.iter().find(|user| user.id == user_id).unwrap().uuid,
+        user_uuid
+    );
+
+    // 2. Rename the resources, their UUIDs are kept
+    client
+        .update_stream(&UpdateStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            name: RENAMED_STREAM_NAME.to_string(),
+            ..UpdateStream::default()
+        })
+        .await
+        .unwrap();
+    client
+        .update_topic(&UpdateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            name: RENAMED_TOPIC_NAME.to_string(),
+            replication_factor: 1,
+            ..UpdateTopic::default()
+        })
+        .await
+        .unwrap();
+    client
+        .update_user(&UpdateUser {
+            user_id: Identifier::numeric(user_id).unwrap(),
+            username: Some(RENAMED_USERNAME.to_string()),
+            status: None,
+        })
+        .await
+        .unwrap();
+
+    // 3. The resources are addressable by their UUIDs
+    let stream = client
+        .get_stream(&GetStream {
+            stream_id: Identifier::uuid(stream_uuid).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(stream.id, STREAM_ID);
+    assert_eq!(stream.name, RENAMED_STREAM_NAME);
+    assert_eq!(stream.uuid, stream_uuid);
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::uuid(stream_uuid).unwrap(),
+            topic_id: Identifier::uuid(topic_uuid).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.id, TOPIC_ID);
+    assert_eq!(topic.name, RENAMED_TOPIC_NAME);
+    assert_eq!(topic.uuid, topic_uuid);
+    let user = client
+        .get_user(&GetUser {
+            user_id: Identifier::uuid(user_uuid).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(user.id, user_id);
+    assert_eq!(user.username, RENAMED_USERNAME);
+    assert_eq!(user.uuid, user_uuid);
+
+    // 4. The unknown UUIDs aren't resolved
+    assert!(client
+        .get_stream(&GetStream {
+            stream_id: Identifier::uuid(Uuid::new_v4()).unwrap(),
+        })
+        .await
+        .is_err());
+    assert!(client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::uuid(stream_uuid).unwrap(),
+            topic_id: Identifier::uuid(stream_uuid).unwrap(),
+        })
+        .await
+        .is_err());
+
+    // 5. The stream recreated with the same ID gets the new UUID, while the previous one is no longer resolved
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::uuid(stream_uuid).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+    init_stream(&client).await;
+    let stream = client
+        .get_stream(&GetStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_ne!(stream.uuid, stream_uuid);
+    assert!(client
+        .get_stream(&GetStream {
+            stream_id: Identifier::uuid(stream_uuid).unwrap(),
+        })
+        .await
+        .is_err());
+
+    cleanup_system(&client, user_uuid).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    init_stream(client).await;
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        ..CreateTopic::default()
+    };
+    client.create_topic(&create_topic).await.unwrap();
+
+    // 3. Create the user
+    let create_user = CreateUser {
+        username: USERNAME.to_string(),
+        password: "secret".to_string(),
+        status: UserStatus::Active,
+        permissions: None,
+    };
+    client.create_user(&create_user).await.unwrap();
+}
+
+async fn init_stream(client: &IggyClient) {
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient, user_uuid: Uuid) {
+    let delete_user = DeleteUser {
+        user_id: Identifier::uuid(user_uuid).unwrap(),
+    };
+    client.delete_user(&delete_user).await.unwrap();
+
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
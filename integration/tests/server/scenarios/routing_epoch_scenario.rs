@@ -0,0 +1,199 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, PartitionClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::header::{HeaderKey, PARTITION_KEY_HEADER, ROUTING_EPOCH_HEADER};
+use iggy::partitions::create_partitions::CreatePartitions;
+use iggy::partitions::delete_partitions::{DeletePartitions, DeletePartitionsMode};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::time::Duration;
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const KEYS_COUNT: u32 = 20;
+const ADDED_PARTITIONS_COUNT: u32 = 2;
+const FIRST_ROUND: &str = "first";
+const SECOND_ROUND: &str = "second";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the keyed messages to the single partition, they're stamped with the key and the initial routing epoch
+    send_keyed_messages(&client, FIRST_ROUND).await;
+    assert_eq!(get_routing_epoch(&client).await, 0);
+    let messages = poll_messages(&client, 1).await;
+    assert_eq!(messages.len() as u32, KEYS_COUNT);
+    for (index, message) in messages.iter().enumerate() {
+        assert_eq!(get_partition_key(message), get_key(index as u32).as_bytes());
+        assert_eq!(get_routing_epoch_header(message), 0);
+    }
+
+    // 2. Add the partitions with the repartitioning, which changes the routing epoch
+    client
+        .create_partitions(&CreatePartitions {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitions_count: ADDED_PARTITIONS_COUNT,
+            repartition: true,
+        })
+        .await
+        .unwrap();
+    assert_eq!(get_routing_epoch(&client).await, 1);
+
+    // 3. Send the same keys again, they're routed to the new partitions with the new routing epoch
+    send_keyed_messages(&client, SECOND_ROUND).await;
+
+    // 4. Once the repartitioning is done, each key moved to the new partition has its first round message copied there
+    let mut is_repartitioned = false;
+    for _ in 0..100 {
+        let mut first_round_keys = Vec::new();
+        let mut second_round_keys = Vec::new();
+        for partition_id in 2..=1 + ADDED_PARTITIONS_COUNT {
+            for message in poll_messages(&client, partition_id).await {
+                assert_eq!(get_routing_epoch_header(&message), 1);
+                let key = get_partition_key(&message).to_vec();
+                match message.payload == FIRST_ROUND {
+                    true => first_round_keys.push((partition_id, key)),
+                    false => second_round_keys.push((partition_id, key)),
+                }
+            }
+        }
+
+        first_round_keys.sort();
+        second_round_keys.sort();
+        if !second_round_keys.is_empty() && first_round_keys == second_round_keys {
+            is_repartitioned = true;
+            break;
+        }
+
+        sleep(Duration::from_millis(50)).await;
+    }
+    assert!(is_repartitioned);
+
+    // 5. The original messages are kept in the first partition
+    let messages = poll_messages(&client, 1).await;
+    assert_eq!(
+        messages
+            .iter()
+            .filter(|message| message.payload == FIRST_ROUND)
+            .count() as u32,
+        KEYS_COUNT
+    );
+
+    // 6. Deleting the partitions changes the routing epoch as well
+    client
+        .delete_partitions(&DeletePartitions {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitions_count: ADDED_PARTITIONS_COUNT,
+            mode: DeletePartitionsMode::Discard,
+        })
+        .await
+        .unwrap();
+    assert_eq!(get_routing_epoch(&client).await, 2);
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic with the single partition
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        ..CreateTopic::default()
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn send_keyed_messages(client: &IggyClient, payload: &str) {
+    for index in 0..KEYS_COUNT {
+        let mut send_messages = SendMessages {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitioning: Partitioning::messages_key_str(&get_key(index)).unwrap(),
+            messages: vec![Message::new(None, Bytes::from(payload.to_string()), None)],
+            acks: None,
+        };
+        client.send_messages(&mut send_messages).await.unwrap();
+    }
+}
+
+async fn poll_messages(
+    client: &IggyClient,
+    partition_id: u32,
+) -> Vec<iggy::models::messages::Message> {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(partition_id),
+        strategy: PollingStrategy::offset(0),
+        count: 2 * KEYS_COUNT,
+        auto_commit: false,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
+    };
+    client.poll_messages(&poll_messages).await.unwrap().messages
+}
+
+async fn get_routing_epoch(client: &IggyClient) -> u32 {
+    client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap()
+        .routing_epoch
+}
+
+fn get_partition_key(message: &iggy::models::messages::Message) -> &[u8] {
+    message.headers.as_ref().unwrap()[&HeaderKey::new(PARTITION_KEY_HEADER).unwrap()]
+        .as_raw()
+        .unwrap()
+}
+
+fn get_routing_epoch_header(message: &iggy::models::messages::Message) -> u32 {
+    message.headers.as_ref().unwrap()[&HeaderKey::new(ROUTING_EPOCH_HEADER).unwrap()]
+        .as_uint32()
+        .unwrap()
+}
+
+fn get_key(index: u32) -> String {
+    format!("key-{index}")
+}
@@ -0,0 +1,152 @@
+use iggy::client::SchemaClient;
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::models::schema::{SchemaCompatibility, SchemaFormat, SchemaInfo};
+use iggy::schemas::get_schema::GetSchema;
+use iggy::schemas::get_schema_versions::GetSchemaVersions;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use integration::test_server::{login_root, ClientFactory};
+
+const SUBJECT: &str = "orders";
+const SCHEMA_V1: &str =
+    r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#;
+const SCHEMA_V2: &str = r#"{"type":"object","properties":{"id":{"type":"integer"},"note":{"type":"string"}},"required":["id"]}"#;
+const AVRO_SUBJECT: &str = "payments";
+const AVRO_SCHEMA: &str =
+    r#"{"type":"record","name":"Payment","fields":[{"name":"id","type":"long"}]}"#;
+const PROTOBUF_SCHEMA: &str = r#"syntax = "proto3"; message Payment { uint64 id = 1; }"#;
+const SCHEMA_V3: &str = r#"{"type":"object","properties":{"id":{"type":"integer"},"amount":{"type":"number"}},"required":["id","amount"]}"#;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    // 1. Schema operations should be forbidden for unauthenticated users
+    let get_schema = client.get_schema(&GetSchema { schema_id: 1 }).await;
+    assert!(get_schema.is_err());
+
+    // 2. Login as root user
+    login_root(&client).await;
+
+    // 3. Register the first version of the schema
+    let schema_v1 = register_schema(&client, SCHEMA_V1).await;
+    assert_eq!(schema_v1.subject, SUBJECT);
+    assert_eq!(schema_v1.version, 1);
+    assert!(schema_v1.created_at > 0);
+
+    // 4. Registering the same schema again should return the existing version
+    let schema = register_schema(&client, SCHEMA_V1).await;
+    assert_eq!(schema.id, schema_v1.id);
+    assert_eq!(schema.version, 1);
+
+    // 5. Register the backward compatible version of the schema
+    let schema_v2 = register_schema(&client, SCHEMA_V2).await;
+    assert_eq!(schema_v2.version, 2);
+    assert_ne!(schema_v2.id, schema_v1.id);
+
+    // 6. Registering the version with the new required property should fail
+    let register_result = client
+        .register_schema(&RegisterSchema {
+            subject: SUBJECT.to_string(),
+            format: SchemaFormat::Json,
+            schema: SCHEMA_V3.to_string(),
+        })
+        .await;
+    assert!(register_result.is_err());
+
+    // 7. Disable the compatibility checks and register the same version again
+    client
+        .update_schema_compatibility(&UpdateSchemaCompatibility {
+            subject: SUBJECT.to_string(),
+            compatibility: SchemaCompatibility::None,
+        })
+        .await
+        .unwrap();
+    let schema_v3 = register_schema(&client, SCHEMA_V3).await;
+    assert_eq!(schema_v3.version, 3);
+
+    // 8. Get the schema by ID
+    let schema = client
+        .get_schema(&GetSchema {
+            schema_id: schema_v2.id,
+        })
+        .await
+        .unwrap();
+    assert_eq!(schema.subject, SUBJECT);
+    assert_eq!(schema.version, 2);
+    assert_eq!(schema.schema, SCHEMA_V2);
+
+    // 9. Get all the versions of the schema
+    let versions = client
+        .get_schema_versions(&GetSchemaVersions {
+            subject: SUBJECT.to_string(),
+        })
+        .await
+        .unwrap();
+    let versions = versions
+        .iter()
+        .map(|schema| schema.version)
+        .collect::<Vec<_>>();
+    assert_eq!(versions, vec![1, 2, 3]);
+
+    // 10. Unknown schema and subject should not be found
+    let get_schema = client.get_schema(&GetSchema { schema_id: 1000 }).await;
+    assert!(get_schema.is_err());
+    let get_versions = client
+        .get_schema_versions(&GetSchemaVersions {
+            subject: "unknown".to_string(),
+        })
+        .await;
+    assert!(get_versions.is_err());
+
+    // 11. Register the Avro schema for another subject
+    let avro_schema = client
+        .register_schema(&RegisterSchema {
+            subject: AVRO_SUBJECT.to_string(),
+            format: SchemaFormat::Avro,
+            schema: AVRO_SCHEMA.to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(avro_schema.format, SchemaFormat::Avro);
+    assert_eq!(avro_schema.version, 1);
+    let schema = client
+        .get_schema(&GetSchema {
+            schema_id: avro_schema.id,
+        })
+        .await
+        .unwrap();
+    assert_eq!(schema.format, SchemaFormat::Avro);
+    assert_eq!(schema.schema, AVRO_SCHEMA);
+
+    // 12. Changing the format of the schema for the subject should fail
+    let register_result = client
+        .register_schema(&RegisterSchema {
+            subject: AVRO_SUBJECT.to_string(),
+            format: SchemaFormat::Protobuf,
+            schema: PROTOBUF_SCHEMA.to_string(),
+        })
+        .await;
+    assert!(register_result.is_err());
+
+    // 13. Registering the invalid Avro schema should fail
+    let register_result = client
+        .register_schema(&RegisterSchema {
+            subject: "invalid".to_string(),
+            format: SchemaFormat::Avro,
+            schema: r#"{"type":"record","name":"Invalid"}"#.to_string(),
+        })
+        .await;
+    assert!(register_result.is_err());
+}
+
+async fn register_schema(client: &IggyClient, schema: &str) -> SchemaInfo {
+    client
+        .register_schema(&RegisterSchema {
+            subject: SUBJECT.to_string(),
+            format: SchemaFormat::Json,
+            schema: schema.to_string(),
+        })
+        .await
+        .unwrap()
+}
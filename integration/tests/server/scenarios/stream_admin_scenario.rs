@@ -0,0 +1,245 @@
+use iggy::client::{
+    ConsumerGroupClient, MessageClient, PartitionClient, StreamClient, TopicClient, UserClient,
+};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollMessages;
+use iggy::models::permission_check::PermissionAction;
+use iggy::models::permissions::{Permissions, StreamPermissions};
+use iggy::partitions::create_partitions::CreatePartitions;
+use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::update_stream::UpdateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::topics::update_topic::UpdateTopic;
+use iggy::users::check_permission::CheckPermission;
+use iggy::users::update_permissions::UpdatePermissions;
+use integration::test_server::{
+    assert_clean_system, create_user, delete_user, login_root, login_user, ClientFactory,
+};
+use std::collections::HashMap;
+
+const STREAM_ADMIN_USERNAME: &str = "stream-admin";
+const STREAM_ID: u32 = 1;
+const STREAM_NAME: &str = "orders";
+const OTHER_STREAM_ID: u32 = 2;
+const OTHER_STREAM_NAME: &str = "payments";
+const TOPIC_ID: u32 = 1;
+const TOPIC_NAME: &str = "created";
+const CONSUMER_GROUP_ID: u32 = 1;
+const CONSUMER_GROUP_NAME: &str = "billing";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    // 1. Create the streams and the user administrating only one of them
+    login_root(&client).await;
+    for (stream_id, name) in [
+        (STREAM_ID, STREAM_NAME),
+        (OTHER_STREAM_ID, OTHER_STREAM_NAME),
+    ] {
+        client
+            .create_stream(&CreateStream {
+                stream_id: Some(stream_id),
+                name: name.to_string(),
+                ..CreateStream::default()
+            })
+            .await
+            .unwrap();
+    }
+    create_user(&client, STREAM_ADMIN_USERNAME).await;
+    client
+        .update_permissions(&UpdatePermissions {
+            user_id: Identifier::named(STREAM_ADMIN_USERNAME).unwrap(),
+            permissions: Some(get_stream_admin_permissions()),
+        })
+        .await
+        .unwrap();
+
+    let permission_check = client
+        .check_permission(&CheckPermission {
+            user_id: Identifier::named(STREAM_ADMIN_USERNAME).unwrap(),
+            action: PermissionAction::ManageConsumerGroups,
+            stream_id: Some(STREAM_ID),
+            topic_id: Some(TOPIC_ID),
+        })
+        .await
+        .unwrap();
+    assert!(permission_check.allowed);
+    assert_eq!(permission_check.rule, "streams[1].manage_topics");
+
+    // 2. The stream admin can create, update and delete the topics of the stream
+    login_user(&client, STREAM_ADMIN_USERNAME).await;
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Some(TOPIC_ID),
+            name: TOPIC_NAME.to_string(),
+            ..CreateTopic::default()
+        })
+        .await
+        .unwrap();
+    client
+        .update_topic(&UpdateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            name: TOPIC_NAME.to_string(),
+            message_expiry: Some(60),
+            ..UpdateTopic::default()
+        })
+        .await
+        .unwrap();
+
+    // 3. The stream admin can create and delete the partitions of the topic
+    client
+        .create_partitions(&CreatePartitions {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitions_count: 2,
+            ..CreatePartitions::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(get_partitions_count(&client).await, 3);
+    client
+        .delete_partitions(&DeletePartitions {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitions_count: 2,
+            ..DeletePartitions::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(get_partitions_count(&client).await, 1);
+
+    // 4. The stream admin can create and delete the consumer groups of the topic
+    client
+        .create_consumer_group(&CreateConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: CONSUMER_GROUP_ID,
+            name: CONSUMER_GROUP_NAME.to_string(),
+        })
+        .await
+        .unwrap();
+    let consumer_groups = get_consumer_groups(&client).await;
+    assert_eq!(consumer_groups, 1);
+    client
+        .delete_consumer_group(&DeleteConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    let consumer_groups = get_consumer_groups(&client).await;
+    assert_eq!(consumer_groups, 0);
+
+    // 5. The stream admin cannot poll the messages or manage the stream itself
+    let poll_messages = client
+        .poll_messages(&PollMessages {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            ..PollMessages::default()
+        })
+        .await;
+    assert!(poll_messages.is_err());
+
+    let update_stream = client
+        .update_stream(&UpdateStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            name: "renamed".to_string(),
+            ..UpdateStream::default()
+        })
+        .await;
+    assert!(update_stream.is_err());
+
+    let delete_stream = client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await;
+    assert!(delete_stream.is_err());
+
+    // 6. The stream admin cannot manage the topics of the other stream
+    let create_topic = client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(OTHER_STREAM_ID).unwrap(),
+            topic_id: Some(TOPIC_ID),
+            name: TOPIC_NAME.to_string(),
+            ..CreateTopic::default()
+        })
+        .await;
+    assert!(create_topic.is_err());
+
+    // 7. The stream admin can delete the topic of the stream
+    client
+        .delete_topic(&DeleteTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+
+    // 8. Clean up
+    login_root(&client).await;
+    delete_user(&client, STREAM_ADMIN_USERNAME).await;
+    for stream_id in [STREAM_ID, OTHER_STREAM_ID] {
+        client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(stream_id).unwrap(),
+                soft: false,
+            })
+            .await
+            .unwrap();
+    }
+    assert_clean_system(&client).await;
+}
+
+async fn get_partitions_count(client: &IggyClient) -> u32 {
+    client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap()
+        .partitions_count
+}
+
+async fn get_consumer_groups(client: &IggyClient) -> usize {
+    client
+        .get_consumer_groups(&GetConsumerGroups {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap()
+        .len()
+}
+
+fn get_stream_admin_permissions() -> Permissions {
+    let mut streams = HashMap::new();
+    streams.insert(
+        STREAM_ID,
+        StreamPermissions {
+            manage_topics: true,
+            manage_partitions: true,
+            manage_consumer_groups: true,
+            ..Default::default()
+        },
+    );
+    Permissions {
+        global: Default::default(),
+        streams: Some(streams),
+    }
+}
@@ -3,6 +3,8 @@ use iggy::client::{MessageClient, StreamClient, SystemClient, TopicClient, UserC
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
@@ -16,6 +18,7 @@ use iggy::topics::purge_topic::PurgeTopic;
 use iggy::users::defaults::*;
 use iggy::users::login_user::LoginUser;
 use integration::test_server::{assert_clean_system, ClientFactory};
+use std::collections::HashMap;
 use std::str::FromStr;
 
 const PARTITIONS_COUNT: u32 = 3;
@@ -25,9 +28,18 @@ const S2_NAME: &str = "test-stream-2";
 const T2_NAME: &str = "test-topic-2";
 const PARTITION_ID: u32 = 1;
 const MESSAGE_PAYLOAD_SIZE_BYTES: u64 = 57;
-const MSG_SIZE: u64 = 8 + 1 + 8 + 16 + 4 + 4 + 4 + MESSAGE_PAYLOAD_SIZE_BYTES; // number of bytes in a single message
+const BATCH_HEADER_SIZE: u64 = 8 + 1 + 4 + 8 + 8 + 8 + 8 + 2 + 4; // number of bytes in the header of a batch of messages
+const MSG_SIZE: u64 = 1 + 1 + 1 + 16 + 4 + 1 + 1 + MESSAGE_PAYLOAD_SIZE_BYTES; // number of bytes in a single message stored in a batch
 const MSGS_COUNT: u64 = 117; // number of messages in a single topic after one pass of appending
-const MSGS_SIZE: u64 = MSG_SIZE * MSGS_COUNT; // number of bytes in a single topic after one pass of appending
+const MSGS_SIZE: u64 = BATCH_HEADER_SIZE + MSG_SIZE * MSGS_COUNT; // number of bytes in a single topic after one pass of appending
+
+/// The sizes are calculated for the messages without any headers, so the server must not stamp the system ones.
+pub fn get_envs() -> HashMap<String, String> {
+    HashMap::from([(
+        "IGGY_SYSTEM_SYSTEM_HEADERS_ENABLED".to_string(),
+        "false".to_string(),
+    )])
+}
 
 pub async fn run(client_factory: &dyn ClientFactory) {
     let _ = tracing_subscriber::fmt::try_init();
@@ -146,6 +158,11 @@ async fn create_topic_assert_empty(client: &IggyClient, stream_name: &str, topic
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -158,6 +175,7 @@ async fn create_stream_assert_empty(client: &IggyClient, stream_name: &str) {
     let create_stream = CreateStream {
         stream_id: None,
         name: stream_name.to_string(),
+        ..CreateStream::default()
     };
     client.create_stream(&create_stream).await.unwrap();
 
@@ -178,6 +196,7 @@ async fn validate_operations_on_topic_twice(
         topic_id: Identifier::from_str(topic_name).unwrap(),
         partitioning: Partitioning::partition_id(partition_id),
         messages,
+        acks: None,
     };
     client.send_messages(&mut send_messages).await.unwrap();
 
@@ -191,6 +210,7 @@ async fn validate_operations_on_topic_twice(
         topic_id: Identifier::from_str(topic_name).unwrap(),
         partitioning: Partitioning::partition_id(partition_id),
         messages,
+        acks: None,
     };
     client.send_messages(&mut send_messages).await.unwrap();
 
@@ -249,6 +269,7 @@ async fn delete_topic(client: &IggyClient, stream_name: &str, topic_name: &str)
     let delete_topic = DeleteTopic {
         stream_id: Identifier::from_str(stream_name).unwrap(),
         topic_id: Identifier::from_str(topic_name).unwrap(),
+        soft: false,
     };
     client.delete_topic(&delete_topic).await.unwrap();
 }
@@ -264,6 +285,7 @@ async fn purge_topic(client: &IggyClient, stream_name: &str, topic_name: &str) {
 async fn delete_stream(client: &IggyClient, stream_name: &str) {
     let delete_stream = DeleteStream {
         stream_id: Identifier::from_str(stream_name).unwrap(),
+        soft: false,
     };
     client.delete_stream(&delete_stream).await.unwrap();
 }
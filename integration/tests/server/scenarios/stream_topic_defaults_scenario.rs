@@ -0,0 +1,159 @@
+use iggy::client::{StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::models::topic::TopicDetails;
+use iggy::models::topic_defaults::TopicDefaults;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_stream::GetStream;
+use iggy::streams::update_stream::UpdateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::utils::byte_size::IggyByteSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const STREAM_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const INHERITING_TOPIC_ID: u32 = 1;
+const OVERRIDING_TOPIC_ID: u32 = 2;
+const UPDATED_DEFAULTS_TOPIC_ID: u32 = 3;
+const KEPT_DEFAULTS_TOPIC_ID: u32 = 4;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+
+    // 1. Create the stream with the topic defaults
+    let topic_defaults = TopicDefaults {
+        message_expiry: Some(3600),
+        max_topic_size: Some(IggyByteSize::from(2_000_000_000)),
+        compression_algorithm: Some(CompressionAlgorithm::Gzip),
+        replication_factor: Some(2),
+    };
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(STREAM_ID),
+            name: STREAM_NAME.to_string(),
+            topic_defaults: topic_defaults.clone(),
+        })
+        .await
+        .unwrap();
+    let stream = client
+        .get_stream(&GetStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(stream.topic_defaults, topic_defaults);
+
+    // 2. The topic created without the explicit values inherits the defaults
+    create_topic(&client, INHERITING_TOPIC_ID, None, None, 0).await;
+    let topic = get_topic(&client, INHERITING_TOPIC_ID).await;
+    assert_eq!(topic.message_expiry, Some(3600));
+    assert_eq!(
+        topic.max_topic_size,
+        Some(IggyByteSize::from(2_000_000_000))
+    );
+    assert_eq!(topic.compression_algorithm, CompressionAlgorithm::Gzip);
+    assert_eq!(topic.replication_factor, 2);
+
+    // 3. The explicit values take precedence over the defaults
+    create_topic(
+        &client,
+        OVERRIDING_TOPIC_ID,
+        Some(60),
+        Some(IggyByteSize::from(3_000_000_000)),
+        3,
+    )
+    .await;
+    let topic = get_topic(&client, OVERRIDING_TOPIC_ID).await;
+    assert_eq!(topic.message_expiry, Some(60));
+    assert_eq!(
+        topic.max_topic_size,
+        Some(IggyByteSize::from(3_000_000_000))
+    );
+    assert_eq!(topic.compression_algorithm, CompressionAlgorithm::Gzip);
+    assert_eq!(topic.replication_factor, 3);
+
+    // 4. Update the defaults, which apply to the topics created afterwards only
+    let updated_topic_defaults = TopicDefaults {
+        message_expiry: Some(7200),
+        ..TopicDefaults::default()
+    };
+    update_stream(&client, Some(updated_topic_defaults.clone())).await;
+    create_topic(&client, UPDATED_DEFAULTS_TOPIC_ID, None, None, 0).await;
+    let topic = get_topic(&client, UPDATED_DEFAULTS_TOPIC_ID).await;
+    assert_eq!(topic.message_expiry, Some(7200));
+    assert_eq!(topic.max_topic_size, None);
+    assert_eq!(topic.compression_algorithm, CompressionAlgorithm::None);
+    assert_eq!(topic.replication_factor, 1);
+    let topic = get_topic(&client, INHERITING_TOPIC_ID).await;
+    assert_eq!(topic.message_expiry, Some(3600));
+
+    // 5. Update the stream without the defaults, the current ones are kept
+    update_stream(&client, None).await;
+    create_topic(&client, KEPT_DEFAULTS_TOPIC_ID, None, None, 0).await;
+    let topic = get_topic(&client, KEPT_DEFAULTS_TOPIC_ID).await;
+    assert_eq!(topic.message_expiry, Some(7200));
+    let stream = client
+        .get_stream(&GetStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(stream.topic_defaults, updated_topic_defaults);
+
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn create_topic(
+    client: &IggyClient,
+    topic_id: u32,
+    message_expiry: Option<u32>,
+    max_topic_size: Option<IggyByteSize>,
+    replication_factor: u8,
+) {
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Some(topic_id),
+            name: format!("test-topic-{topic_id}"),
+            message_expiry,
+            max_topic_size,
+            replication_factor,
+            ..CreateTopic::default()
+        })
+        .await
+        .unwrap();
+}
+
+async fn get_topic(client: &IggyClient, topic_id: u32) -> TopicDetails {
+    client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(topic_id).unwrap(),
+        })
+        .await
+        .unwrap()
+}
+
+async fn update_stream(client: &IggyClient, topic_defaults: Option<TopicDefaults>) {
+    client
+        .update_stream(&UpdateStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            name: STREAM_NAME.to_string(),
+            topic_defaults,
+        })
+        .await
+        .unwrap();
+}
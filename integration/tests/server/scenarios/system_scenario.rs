@@ -15,10 +15,14 @@ use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::partitions::create_partitions::CreatePartitions;
-use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::delete_partitions::{DeletePartitions, DeletePartitionsMode};
+use iggy::partitions::get_partition_details::GetPartitionDetails;
+use iggy::partitions::get_partition_end_offsets::GetPartitionEndOffsets;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
@@ -27,6 +31,7 @@ use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
 use iggy::system::get_clients::GetClients;
 use iggy::system::get_me::GetMe;
+use iggy::system::get_metadata::GetMetadata;
 use iggy::system::get_stats::GetStats;
 use iggy::system::ping::Ping;
 use iggy::topics::create_topic::CreateTopic;
@@ -39,6 +44,7 @@ use iggy::users::defaults::*;
 use iggy::users::login_user::LoginUser;
 use iggy::utils::byte_size::IggyByteSize;
 use integration::test_server::{assert_clean_system, ClientFactory};
+use std::collections::HashMap;
 
 const STREAM_ID: u32 = 1;
 const TOPIC_ID: u32 = 1;
@@ -52,6 +58,14 @@ const CONSUMER_GROUP_ID: u32 = 10;
 const CONSUMER_GROUP_NAME: &str = "test-consumer-group";
 const MESSAGES_COUNT: u32 = 1000;
 
+/// The sizes are asserted for the messages without any headers, so the server must not stamp the system ones.
+pub fn get_envs() -> HashMap<String, String> {
+    HashMap::from([(
+        "IGGY_SYSTEM_SYSTEM_HEADERS_ENABLED".to_string(),
+        "false".to_string(),
+    )])
+}
+
 pub async fn run(client_factory: &dyn ClientFactory) {
     let client = client_factory.create_client().await;
     let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
@@ -77,6 +91,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let mut create_stream = CreateStream {
         stream_id: Some(STREAM_ID),
         name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
     };
     client.create_stream(&create_stream).await.unwrap();
 
@@ -134,6 +149,11 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -227,6 +247,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
         messages,
+        acks: None,
     };
     client.send_messages(&mut send_messages).await.unwrap();
 
@@ -242,6 +263,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -251,6 +276,28 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         let message = polled_messages.messages.get(i as usize).unwrap();
         assert_message(message, offset);
     }
+    assert!(polled_messages.next_offset.is_none());
+
+    // The messages exceeding the max bytes are left out, except for the first one, and the offset to continue from is returned.
+    let truncated_messages = client
+        .poll_messages(&PollMessages {
+            consumer: Consumer {
+                kind: CONSUMER_KIND,
+                id: Identifier::numeric(CONSUMER_ID).unwrap(),
+            },
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+            strategy: PollingStrategy::offset(0),
+            count: MESSAGES_COUNT,
+            max_bytes: Some(1),
+            ..PollMessages::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(truncated_messages.messages.len(), 1);
+    assert_message(&truncated_messages.messages[0], 0);
+    assert_eq!(truncated_messages.next_offset, Some(1));
 
     // 19. Messages should be also polled in the smaller batches
     let batches_count = 10;
@@ -268,6 +315,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             strategy: PollingStrategy::offset(start_offset),
             count: batch_size,
             auto_commit: false,
+            filter: None,
+            mode: PollingMode::Consume,
+            max_wait_ms: None,
+            max_bytes: None,
         };
 
         let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -291,7 +342,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic.name, TOPIC_NAME);
     assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
     assert_eq!(topic.partitions.len(), PARTITIONS_COUNT as usize);
-    assert_eq!(topic.size, 55890);
+    assert_eq!(topic.size, 36813);
     assert_eq!(topic.messages_count, MESSAGES_COUNT as u64);
     let topic_partition = topic.partitions.get((PARTITION_ID - 1) as usize).unwrap();
     assert_eq!(topic_partition.id, PARTITION_ID);
@@ -300,6 +351,51 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic_partition.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(topic_partition.messages_count, MESSAGES_COUNT as u64);
 
+    let partition = client
+        .get_partition_details(&GetPartitionDetails {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: PARTITION_ID,
+        })
+        .await
+        .unwrap();
+    assert_eq!(partition.id, PARTITION_ID);
+    assert_eq!(partition.current_offset, (MESSAGES_COUNT - 1) as u64);
+    assert_eq!(partition.messages_count, MESSAGES_COUNT as u64);
+    assert_eq!(partition.segments_count, 1);
+    assert_eq!(partition.segments.len(), 1);
+    let segment = partition.segments.first().unwrap();
+    assert_eq!(segment.start_offset, 0);
+    assert_eq!(segment.end_offset, (MESSAGES_COUNT - 1) as u64);
+    assert_eq!(segment.size_bytes, partition.size_bytes);
+    assert!(!segment.is_closed);
+    assert!(!segment.is_expired);
+
+    let end_offsets = client
+        .get_partition_end_offsets(&GetPartitionEndOffsets {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(end_offsets.len() as u32, PARTITIONS_COUNT);
+    for end_offset in &end_offsets {
+        let expected_end_offset = match end_offset.partition_id {
+            PARTITION_ID => MESSAGES_COUNT as u64,
+            _ => 0,
+        };
+        assert_eq!(end_offset.end_offset, expected_end_offset);
+    }
+    let end_offset = client
+        .get_partition_end_offset(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            PARTITION_ID,
+        )
+        .await
+        .unwrap();
+    assert_eq!(end_offset, MESSAGES_COUNT as u64);
+
     // 21. Ensure that messages do not exist in the second partition in the same topic
     let poll_messages = PollMessages {
         consumer: Consumer {
@@ -312,6 +408,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
     };
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
     assert!(polled_messages.messages.is_empty());
@@ -379,6 +479,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::next(),
         count: messages_count,
         auto_commit: true,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -406,6 +510,21 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(offset.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(offset.stored_offset, expected_last_offset);
 
+    let consumer = Consumer {
+        kind: CONSUMER_KIND,
+        id: Identifier::numeric(CONSUMER_ID).unwrap(),
+    };
+    let lag = consumer
+        .lag(
+            &client,
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+        )
+        .await
+        .unwrap();
+    assert_eq!(lag, MESSAGES_COUNT as u64 - expected_last_offset - 1);
+
     // 27. Get the consumer groups and validate that there are no groups
     let consumer_groups = client
         .get_consumer_groups(&GetConsumerGroups {
@@ -527,12 +646,23 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert!(!stats.os_name.is_empty());
     assert!(!stats.os_version.is_empty());
     assert!(!stats.kernel_version.is_empty());
+    assert!(stats.memory_budget.as_bytes_u64() > 0);
+    assert!(stats.memory_budget_usage().as_bytes_u64() < stats.memory_budget.as_bytes_u64());
     assert_eq!(stats.streams_count, 1);
     assert_eq!(stats.topics_count, 1);
     assert_eq!(stats.partitions_count, PARTITIONS_COUNT);
     assert_eq!(stats.segments_count, PARTITIONS_COUNT);
     assert_eq!(stats.messages_count, MESSAGES_COUNT as u64);
 
+    let metadata = client.get_metadata(&GetMetadata {}).await.unwrap();
+    assert_eq!(metadata.streams.len(), 1);
+    let stream = metadata.get_stream(STREAM_NAME).unwrap();
+    assert_eq!(stream.id, STREAM_ID);
+    assert_eq!(stream.topics.len(), 1);
+    let topic = stream.get_topic(&TOPIC_ID.to_string()).unwrap();
+    assert_eq!(topic.name, TOPIC_NAME);
+    assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
+
     // 33. Delete the consumer group
     client
         .delete_consumer_group(&DeleteConsumerGroup {
@@ -549,6 +679,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitions_count: PARTITIONS_COUNT,
+            repartition: false,
         })
         .await
         .unwrap();
@@ -563,12 +694,17 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(topic.partitions_count, 2 * PARTITIONS_COUNT);
 
+    let metadata = client.get_metadata(&GetMetadata {}).await.unwrap();
+    let topic = metadata.streams[0].get_topic(TOPIC_NAME).unwrap();
+    assert_eq!(topic.partitions_count, 2 * PARTITIONS_COUNT);
+
     // 35. Delete the partitions and validate that the number of partitions is decreased
     client
         .delete_partitions(&DeletePartitions {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitions_count: PARTITIONS_COUNT,
+            mode: DeletePartitionsMode::Refuse,
         })
         .await
         .unwrap();
@@ -597,6 +733,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             message_expiry: Some(updated_message_expiry),
             max_topic_size: Some(updated_max_topic_size),
             replication_factor: updated_replication_factor,
+            message_schema: None,
+            timestamp_type: TimestampType::default(),
+            consumer_limits: ConsumerLimits::default(),
+            max_message_size: None,
         })
         .await
         .unwrap();
@@ -634,6 +774,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .update_stream(&UpdateStream {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             name: updated_stream_name.clone(),
+            ..UpdateStream::default()
         })
         .await
         .unwrap();
@@ -654,9 +795,43 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
         messages,
+        acks: None,
     };
+    let receipt = client
+        .send_messages_with_receipt(&mut send_messages)
+        .await
+        .unwrap();
+    assert_eq!(receipt.partition_id, PARTITION_ID);
+    assert_eq!(receipt.base_offset, 0);
+    assert_eq!(receipt.messages_count, MESSAGES_COUNT);
+    assert_eq!(receipt.offsets().end, MESSAGES_COUNT as u64);
+    assert!(receipt.timestamp > 0);
+
+    // The messages sent without acks are queued before the ones sent afterwards, and there's no receipt for them.
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages: create_messages(),
+        acks: Some(Acks::None),
+    };
+    let result = client.send_messages_with_receipt(&mut send_messages).await;
+    assert!(matches!(result, Err(IggyError::InvalidCommand)));
     client.send_messages(&mut send_messages).await.unwrap();
 
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages: create_messages(),
+        acks: Some(Acks::All),
+    };
+    let receipt = client
+        .send_messages_with_receipt(&mut send_messages)
+        .await
+        .unwrap();
+    assert_eq!(receipt.base_offset, 2 * MESSAGES_COUNT as u64);
+
     client
         .purge_stream(&PurgeStream {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -673,6 +848,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .delete_topic(&DeleteTopic {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            soft: false,
         })
         .await
         .unwrap();
@@ -690,6 +866,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let create_stream = CreateStream {
         stream_id: None,
         name: stream_name.clone(),
+        ..CreateStream::default()
     };
     client.create_stream(&create_stream).await.unwrap();
 
@@ -714,6 +891,11 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         message_expiry: None,
         max_topic_size: None,
         replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
     };
 
     client.create_topic(&create_topic).await.unwrap();
@@ -737,6 +919,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         client
             .delete_stream(&DeleteStream {
                 stream_id: Identifier::numeric(stream.id).unwrap(),
+                soft: false,
             })
             .await
             .unwrap();
@@ -0,0 +1,270 @@
+use iggy::client::{PartitionClient, StreamClient, TenantClient, TopicClient, UserClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::identifier::Identifier;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::tenant::TenantQuotas;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::partitions::create_partitions::CreatePartitions;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_stream::GetStream;
+use iggy::streams::get_streams::GetStreams;
+use iggy::tenants::add_tenant_user::AddTenantUser;
+use iggy::tenants::create_tenant::CreateTenant;
+use iggy::tenants::delete_tenant::DeleteTenant;
+use iggy::tenants::get_tenant::GetTenant;
+use iggy::tenants::get_tenants::GetTenants;
+use iggy::tenants::remove_tenant_user::RemoveTenantUser;
+use iggy::tenants::update_tenant::UpdateTenant;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::users::get_user::GetUser;
+use iggy::users::get_users::GetUsers;
+use integration::test_server::{
+    assert_clean_system, create_user, delete_user, login_root, login_user, ClientFactory,
+};
+
+const TENANT_NAME: &str = "team";
+const PUBLIC_STREAM_ID: u32 = 1;
+const PUBLIC_STREAM_NAME: &str = "public";
+const TENANT_STREAM_ID: u32 = 2;
+const TENANT_STREAM_NAME: &str = "orders";
+const TENANT_TOPIC_ID: u32 = 1;
+const PARTITIONS_COUNT: u32 = 2;
+const TENANT_USERNAME_1: &str = "tenant-user-1";
+const TENANT_USERNAME_2: &str = "tenant-user-2";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    // 1. Tenant operations should be forbidden for unauthenticated users
+    let get_tenants = client.get_tenants(&GetTenants {}).await;
+    assert!(get_tenants.is_err());
+
+    // 2. Create the tenant with the quotas
+    login_root(&client).await;
+    client
+        .create_tenant(&CreateTenant {
+            tenant_id: None,
+            name: TENANT_NAME.to_string(),
+            quotas: TenantQuotas {
+                max_streams: Some(1),
+                max_topics: Some(1),
+                max_partitions: Some(PARTITIONS_COUNT),
+                max_size: None,
+            },
+        })
+        .await
+        .unwrap();
+
+    let tenants = client.get_tenants(&GetTenants {}).await.unwrap();
+    assert_eq!(tenants.len(), 1);
+    let tenant = get_tenant(&client).await;
+    assert_eq!(tenant.id, 1);
+    assert_eq!(tenant.name, TENANT_NAME);
+    assert!(tenant.created_at > 0);
+    assert_eq!(tenant.quotas.max_streams, Some(1));
+    assert_eq!(tenant.streams_count, 0);
+    assert_eq!(tenant.users_count, 0);
+
+    // 3. Creating the tenant with the same name should fail
+    let create_tenant = client
+        .create_tenant(&CreateTenant {
+            tenant_id: None,
+            name: TENANT_NAME.to_string(),
+            quotas: TenantQuotas::default(),
+        })
+        .await;
+    assert!(create_tenant.is_err());
+
+    // 4. Create the stream not owned by any tenant and assign the user to the tenant
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(PUBLIC_STREAM_ID),
+            name: PUBLIC_STREAM_NAME.to_string(),
+            ..CreateStream::default()
+        })
+        .await
+        .unwrap();
+    create_user(&client, TENANT_USERNAME_1).await;
+    client
+        .add_tenant_user(&AddTenantUser {
+            tenant_id: Identifier::named(TENANT_NAME).unwrap(),
+            user_id: Identifier::named(TENANT_USERNAME_1).unwrap(),
+        })
+        .await
+        .unwrap();
+    let tenant = get_tenant(&client).await;
+    assert_eq!(tenant.users_count, 1);
+
+    // 5. The stream created by the tenant user should be owned by the tenant and scoped by its name
+    login_user(&client, TENANT_USERNAME_1).await;
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(TENANT_STREAM_ID),
+            name: TENANT_STREAM_NAME.to_string(),
+            ..CreateStream::default()
+        })
+        .await
+        .unwrap();
+    let streams = client.get_streams(&GetStreams {}).await.unwrap();
+    assert_eq!(streams.len(), 1);
+    let stream = streams.first().unwrap();
+    assert_eq!(stream.id, TENANT_STREAM_ID);
+    assert_eq!(stream.name, format!("{TENANT_NAME}.{TENANT_STREAM_NAME}"));
+
+    // 6. The tenant user cannot access the streams not owned by the tenant
+    let get_stream = client
+        .get_stream(&GetStream {
+            stream_id: Identifier::numeric(PUBLIC_STREAM_ID).unwrap(),
+        })
+        .await;
+    assert!(get_stream.is_err());
+
+    // 7. Creating the resources above the tenant quotas should fail
+    let create_stream = client
+        .create_stream(&CreateStream {
+            stream_id: None,
+            name: "payments".to_string(),
+            ..CreateStream::default()
+        })
+        .await;
+    assert!(create_stream.is_err());
+
+    client
+        .create_topic(&get_create_topic(Some(TENANT_TOPIC_ID), "topic-1"))
+        .await
+        .unwrap();
+    let create_topic = client
+        .create_topic(&get_create_topic(None, "topic-2"))
+        .await;
+    assert!(create_topic.is_err());
+
+    let create_partitions = client
+        .create_partitions(&CreatePartitions {
+            stream_id: Identifier::numeric(TENANT_STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TENANT_TOPIC_ID).unwrap(),
+            partitions_count: 1,
+            repartition: false,
+        })
+        .await;
+    assert!(create_partitions.is_err());
+
+    // 8. The user created by the tenant user should be assigned to the same tenant
+    create_user(&client, TENANT_USERNAME_2).await;
+    let users = client.get_users(&GetUsers {}).await.unwrap();
+    assert_eq!(users.len(), 2);
+    let get_root = client
+        .get_user(&GetUser {
+            user_id: Identifier::numeric(DEFAULT_ROOT_USER_ID).unwrap(),
+        })
+        .await;
+    assert!(get_root.is_err());
+
+    // 9. The tenant user can only read its own tenant and cannot manage the tenants
+    let tenants = client.get_tenants(&GetTenants {}).await.unwrap();
+    assert_eq!(tenants.len(), 1);
+    let update_tenant = client
+        .update_tenant(&UpdateTenant {
+            tenant_id: Identifier::named(TENANT_NAME).unwrap(),
+            quotas: TenantQuotas::default(),
+        })
+        .await;
+    assert!(update_tenant.is_err());
+
+    // 10. The tenant usage should include all its resources
+    login_root(&client).await;
+    let tenant = get_tenant(&client).await;
+    assert_eq!(tenant.streams_count, 1);
+    assert_eq!(tenant.topics_count, 1);
+    assert_eq!(tenant.partitions_count, PARTITIONS_COUNT);
+    assert_eq!(tenant.users_count, 2);
+
+    // 11. Lifting the quotas allows the tenant to create more resources
+    client
+        .update_tenant(&UpdateTenant {
+            tenant_id: Identifier::named(TENANT_NAME).unwrap(),
+            quotas: TenantQuotas::default(),
+        })
+        .await
+        .unwrap();
+    login_user(&client, TENANT_USERNAME_1).await;
+    client
+        .create_topic(&get_create_topic(None, "topic-2"))
+        .await
+        .unwrap();
+
+    // 12. The tenant which owns the streams or has the users assigned cannot be deleted
+    login_root(&client).await;
+    let delete_tenant = client
+        .delete_tenant(&DeleteTenant {
+            tenant_id: Identifier::named(TENANT_NAME).unwrap(),
+        })
+        .await;
+    assert!(delete_tenant.is_err());
+
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(TENANT_STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+    client
+        .remove_tenant_user(&RemoveTenantUser {
+            tenant_id: Identifier::named(TENANT_NAME).unwrap(),
+            user_id: Identifier::named(TENANT_USERNAME_1).unwrap(),
+        })
+        .await
+        .unwrap();
+    delete_user(&client, TENANT_USERNAME_2).await;
+    let tenant = get_tenant(&client).await;
+    assert_eq!(tenant.streams_count, 0);
+    assert_eq!(tenant.users_count, 0);
+
+    client
+        .delete_tenant(&DeleteTenant {
+            tenant_id: Identifier::named(TENANT_NAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    let tenants = client.get_tenants(&GetTenants {}).await.unwrap();
+    assert!(tenants.is_empty());
+
+    delete_user(&client, TENANT_USERNAME_1).await;
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(PUBLIC_STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn get_tenant(client: &IggyClient) -> iggy::models::tenant::Tenant {
+    client
+        .get_tenant(&GetTenant {
+            tenant_id: Identifier::named(TENANT_NAME).unwrap(),
+        })
+        .await
+        .unwrap()
+}
+
+fn get_create_topic(topic_id: Option<u32>, name: &str) -> CreateTopic {
+    CreateTopic {
+        stream_id: Identifier::numeric(TENANT_STREAM_ID).unwrap(),
+        topic_id,
+        partitions_count: PARTITIONS_COUNT,
+        name: name.to_string(),
+        message_expiry: None,
+        max_topic_size: None,
+        replication_factor: 1,
+        message_schema: None,
+        ephemeral: false,
+        timestamp_type: TimestampType::default(),
+        consumer_limits: ConsumerLimits::default(),
+        max_message_size: None,
+    }
+}
@@ -0,0 +1,215 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::header::{HeaderKey, HeaderValue, CREATE_TIMESTAMP_HEADER};
+use iggy::models::timestamp_type::TimestampType;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::update_topic::UpdateTopic;
+use iggy::utils::timestamp::IggyTimestamp;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const STREAM_ID: u32 = 1;
+const CREATE_TIME_TOPIC_ID: u32 = 1;
+const LOG_APPEND_TIME_TOPIC_ID: u32 = 2;
+const STREAM_NAME: &str = "test-stream";
+const CREATE_TIME_TOPIC_NAME: &str = "create-time-topic";
+const LOG_APPEND_TIME_TOPIC_NAME: &str = "log-append-time-topic";
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u64 = 10;
+const SECOND_MICROS: u64 = 1_000_000;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the messages created an hour ago, with the create timestamps out of order
+    let base_timestamp = IggyTimestamp::now().to_micros() - 3600 * SECOND_MICROS;
+    let create_timestamps = (0..MESSAGES_COUNT)
+        .map(|offset| base_timestamp + (offset * 7 % MESSAGES_COUNT) * SECOND_MICROS)
+        .collect::<Vec<_>>();
+    let append_timestamp = IggyTimestamp::now().to_micros();
+    for topic_id in [CREATE_TIME_TOPIC_ID, LOG_APPEND_TIME_TOPIC_ID] {
+        send_messages(&client, topic_id, &create_timestamps).await;
+    }
+
+    // 2. The create timestamps are stored for the create time topic, and the header is consumed
+    let polled_messages =
+        poll_messages(&client, CREATE_TIME_TOPIC_ID, PollingStrategy::offset(0)).await;
+    assert_eq!(polled_messages.len() as u64, MESSAGES_COUNT);
+    for (message, create_timestamp) in polled_messages.iter().zip(&create_timestamps) {
+        assert_eq!(message.timestamp, *create_timestamp);
+        assert!(!has_create_timestamp_header(message));
+    }
+
+    // 3. The time based polling starts at the first message created at or after the timestamp
+    let polled_messages = poll_messages(
+        &client,
+        CREATE_TIME_TOPIC_ID,
+        PollingStrategy::timestamp(base_timestamp + 5 * SECOND_MICROS),
+    )
+    .await;
+    let expected_offset = create_timestamps
+        .iter()
+        .position(|timestamp| *timestamp >= base_timestamp + 5 * SECOND_MICROS)
+        .unwrap() as u64;
+    assert_eq!(polled_messages[0].offset, expected_offset);
+
+    // 4. The append timestamps are stored for the log append time topic, and the header is removed
+    let polled_messages = poll_messages(
+        &client,
+        LOG_APPEND_TIME_TOPIC_ID,
+        PollingStrategy::offset(0),
+    )
+    .await;
+    assert_eq!(polled_messages.len() as u64, MESSAGES_COUNT);
+    for message in &polled_messages {
+        assert!(message.timestamp >= append_timestamp);
+        assert!(!has_create_timestamp_header(message));
+    }
+
+    // 5. The create timestamp which isn't uint64 is rejected
+    let mut invalid_send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(CREATE_TIME_TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages: vec![Message::new(
+            None,
+            Bytes::from("invalid"),
+            Some(HashMap::from([(
+                HeaderKey::new(CREATE_TIMESTAMP_HEADER).unwrap(),
+                HeaderValue::from_str("yesterday").unwrap(),
+            )])),
+        )],
+        acks: None,
+    };
+    assert!(client
+        .send_messages(&mut invalid_send_messages)
+        .await
+        .is_err());
+
+    // 6. Once the topic is updated to the log append time, the messages appended from now on get the append timestamp
+    let update_topic = UpdateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(CREATE_TIME_TOPIC_ID).unwrap(),
+        name: CREATE_TIME_TOPIC_NAME.to_string(),
+        timestamp_type: TimestampType::LogAppendTime,
+        ..UpdateTopic::default()
+    };
+    client.update_topic(&update_topic).await.unwrap();
+    let append_timestamp = IggyTimestamp::now().to_micros();
+    send_messages(&client, CREATE_TIME_TOPIC_ID, &[base_timestamp]).await;
+    let polled_messages = poll_messages(
+        &client,
+        CREATE_TIME_TOPIC_ID,
+        PollingStrategy::offset(MESSAGES_COUNT),
+    )
+    .await;
+    assert_eq!(polled_messages.len(), 1);
+    assert!(polled_messages[0].timestamp >= append_timestamp);
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topics with both timestamp types
+    for (topic_id, name, timestamp_type) in [
+        (
+            CREATE_TIME_TOPIC_ID,
+            CREATE_TIME_TOPIC_NAME,
+            TimestampType::CreateTime,
+        ),
+        (
+            LOG_APPEND_TIME_TOPIC_ID,
+            LOG_APPEND_TIME_TOPIC_NAME,
+            TimestampType::LogAppendTime,
+        ),
+    ] {
+        let create_topic = CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Some(topic_id),
+            name: name.to_string(),
+            timestamp_type,
+            ..CreateTopic::default()
+        };
+        client.create_topic(&create_topic).await.unwrap();
+    }
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn send_messages(client: &IggyClient, topic_id: u32, create_timestamps: &[u64]) {
+    let messages = create_timestamps
+        .iter()
+        .map(|create_timestamp| {
+            Message::new(
+                None,
+                Bytes::from(format!("created at {create_timestamp}")),
+                Some(HashMap::from([(
+                    HeaderKey::new(CREATE_TIMESTAMP_HEADER).unwrap(),
+                    HeaderValue::from_uint64(*create_timestamp).unwrap(),
+                )])),
+            )
+        })
+        .collect();
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(topic_id).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages,
+        acks: None,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn poll_messages(
+    client: &IggyClient,
+    topic_id: u32,
+    strategy: PollingStrategy,
+) -> Vec<iggy::models::messages::Message> {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(topic_id).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy,
+        count: MESSAGES_COUNT as u32,
+        auto_commit: false,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
+    };
+    client.poll_messages(&poll_messages).await.unwrap().messages
+}
+
+fn has_create_timestamp_header(message: &iggy::models::messages::Message) -> bool {
+    message.headers.as_ref().is_some_and(|headers| {
+        headers.contains_key(&HeaderKey::new(CREATE_TIMESTAMP_HEADER).unwrap())
+    })
+}
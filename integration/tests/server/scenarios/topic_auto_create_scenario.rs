@@ -0,0 +1,197 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient, UserClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::permissions::{GlobalPermissions, Permissions, StreamPermissions};
+use iggy::models::user_status::UserStatus;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::get_topic::GetTopic;
+use iggy::users::create_user::CreateUser;
+use iggy::users::login_user::LoginUser;
+use iggy::users::update_permissions::UpdatePermissions;
+use integration::test_server::{assert_clean_system, delete_user, login_root, ClientFactory};
+use std::collections::HashMap;
+
+const PARTITIONS_COUNT: u32 = 2;
+const STREAM_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "auto-topic";
+const USER_TOPIC_NAME: &str = "user-topic";
+const USERNAME: &str = "producer";
+const PASSWORD: &str = "secret";
+const MESSAGES_COUNT: u32 = 10;
+
+pub fn get_envs() -> HashMap<String, String> {
+    HashMap::from([
+        (
+            "IGGY_SYSTEM_TOPIC_AUTO_CREATE_ENABLED".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_TOPIC_AUTO_CREATE_PARTITIONS_COUNT".to_string(),
+            PARTITIONS_COUNT.to_string(),
+        ),
+    ])
+}
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(STREAM_ID),
+            name: STREAM_NAME.to_string(),
+            ..CreateStream::default()
+        })
+        .await
+        .unwrap();
+
+    // 1. Sending the messages to the missing topic referenced by its name should create the topic
+    let mut send_messages = get_send_messages(TOPIC_NAME);
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::named(TOPIC_NAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.name, TOPIC_NAME);
+    assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
+    assert_eq!(topic.messages_count, MESSAGES_COUNT as u64);
+
+    // 2. The messages sent to the auto-created topic can be polled
+    let polled_messages = client
+        .poll_messages(&PollMessages {
+            consumer: Consumer::default(),
+            stream_id: Identifier::named(STREAM_NAME).unwrap(),
+            topic_id: Identifier::named(TOPIC_NAME).unwrap(),
+            partition_id: Some(1),
+            strategy: PollingStrategy::offset(0),
+            count: MESSAGES_COUNT,
+            auto_commit: false,
+            filter: None,
+            mode: PollingMode::Peek,
+            max_wait_ms: None,
+            max_bytes: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+
+    // 3. Sending the messages to the missing topic referenced by its numeric ID should fail
+    let mut send_messages = SendMessages {
+        topic_id: Identifier::numeric(100).unwrap(),
+        ..get_send_messages(TOPIC_NAME)
+    };
+    assert!(client.send_messages(&mut send_messages).await.is_err());
+
+    // 4. Sending the messages to the missing stream should fail
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::named("missing-stream").unwrap(),
+        ..get_send_messages(TOPIC_NAME)
+    };
+    assert!(client.send_messages(&mut send_messages).await.is_err());
+
+    // 5. The user allowed only to send the messages cannot create the missing topic
+    client
+        .create_user(&CreateUser {
+            username: USERNAME.to_string(),
+            password: PASSWORD.to_string(),
+            status: UserStatus::Active,
+            permissions: Some(Permissions {
+                global: GlobalPermissions {
+                    send_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+            }),
+        })
+        .await
+        .unwrap();
+    login_producer(&client).await;
+    let mut send_messages = get_send_messages(USER_TOPIC_NAME);
+    assert!(client.send_messages(&mut send_messages).await.is_err());
+
+    // 6. Granting the auto_create_topics permission for the stream allows to create the missing topic
+    login_root(&client).await;
+    client
+        .update_permissions(&UpdatePermissions {
+            user_id: Identifier::named(USERNAME).unwrap(),
+            permissions: Some(Permissions {
+                global: GlobalPermissions {
+                    send_messages: true,
+                    ..Default::default()
+                },
+                streams: Some(HashMap::from([(
+                    STREAM_ID,
+                    StreamPermissions {
+                        auto_create_topics: true,
+                        ..Default::default()
+                    },
+                )])),
+            }),
+        })
+        .await
+        .unwrap();
+    login_producer(&client).await;
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    login_root(&client).await;
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::named(USER_TOPIC_NAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic.messages_count, MESSAGES_COUNT as u64);
+
+    delete_user(&client, USERNAME).await;
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn login_producer(client: &IggyClient) {
+    client
+        .login_user(&LoginUser {
+            username: USERNAME.to_string(),
+            password: PASSWORD.to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+fn get_send_messages(topic_name: &str) -> SendMessages {
+    let messages = (0..MESSAGES_COUNT)
+        .map(|id| {
+            let payload = Bytes::from(format!("message {id}"));
+            Message {
+                id: (id + 1) as u128,
+                length: payload.len() as u32,
+                payload,
+                headers: None,
+            }
+        })
+        .collect();
+    SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::named(topic_name).unwrap(),
+        partitioning: Partitioning::partition_id(1),
+        messages,
+        acks: None,
+    }
+}
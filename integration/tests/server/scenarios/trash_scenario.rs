@@ -0,0 +1,226 @@
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_stream::GetStream;
+use iggy::streams::undelete_stream::UndeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::topics::undelete_topic::UndeleteTopic;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const OTHER_TOPIC_ID: u32 = 2;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const OTHER_TOPIC_NAME: &str = "other-topic";
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Soft delete the topic, it's no longer available, but its ID remains reserved
+    delete_topic(&client, TOPIC_ID, true).await;
+    assert!(client.get_topic(&get_topic(TOPIC_ID)).await.is_err());
+    let stream = client.get_stream(&get_stream()).await.unwrap();
+    assert_eq!(stream.topics_count, 1);
+    assert_eq!(stream.messages_count, 0);
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Some(TOPIC_ID),
+        name: "another-topic".to_string(),
+        ..CreateTopic::default()
+    };
+    assert!(client.create_topic(&create_topic).await.is_err());
+
+    // 2. The topic cannot be restored while its name is taken by another topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: None,
+        name: TOPIC_NAME.to_string(),
+        ..CreateTopic::default()
+    };
+    client.create_topic(&create_topic).await.unwrap();
+    let topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::named(TOPIC_NAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_ne!(topic.id, TOPIC_ID);
+    assert!(client
+        .undelete_topic(&undelete_topic(Identifier::numeric(TOPIC_ID).unwrap()))
+        .await
+        .is_err());
+    delete_topic(&client, topic.id, false).await;
+
+    // 3. Undelete the topic by its name, along with all the messages
+    client
+        .undelete_topic(&undelete_topic(Identifier::named(TOPIC_NAME).unwrap()))
+        .await
+        .unwrap();
+    let topic = client.get_topic(&get_topic(TOPIC_ID)).await.unwrap();
+    assert_eq!(topic.name, TOPIC_NAME);
+    assert_eq!(topic.messages_count, MESSAGES_COUNT as u64);
+    assert_eq!(poll_messages(&client).await, MESSAGES_COUNT);
+    assert!(client
+        .undelete_topic(&undelete_topic(Identifier::numeric(TOPIC_ID).unwrap()))
+        .await
+        .is_err());
+
+    // 4. Soft delete the stream, it's no longer available, but its ID remains reserved
+    delete_topic(&client, OTHER_TOPIC_ID, true).await;
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: true,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+    assert!(client.get_stream(&get_stream()).await.is_err());
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: "another-stream".to_string(),
+        ..CreateStream::default()
+    };
+    assert!(client.create_stream(&create_stream).await.is_err());
+
+    // 5. Undelete the stream by its name, the topic trashed before the stream stays in the trash
+    client
+        .undelete_stream(&UndeleteStream {
+            stream_id: Identifier::named(STREAM_NAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    let stream = client.get_stream(&get_stream()).await.unwrap();
+    assert_eq!(stream.name, STREAM_NAME);
+    assert_eq!(stream.topics_count, 1);
+    assert_eq!(poll_messages(&client).await, MESSAGES_COUNT);
+    client
+        .undelete_topic(&undelete_topic(
+            Identifier::numeric(OTHER_TOPIC_ID).unwrap(),
+        ))
+        .await
+        .unwrap();
+    let topic = client.get_topic(&get_topic(OTHER_TOPIC_ID)).await.unwrap();
+    assert_eq!(topic.name, OTHER_TOPIC_NAME);
+
+    // 6. The stream which isn't in the trash cannot be restored
+    assert!(client
+        .undelete_stream(&UndeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID + 1).unwrap(),
+        })
+        .await
+        .is_err());
+
+    // 7. The hard delete removes the stream along with its trashed topics
+    delete_topic(&client, OTHER_TOPIC_ID, true).await;
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        soft: false,
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+    assert!(client
+        .undelete_stream(&UndeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        })
+        .await
+        .is_err());
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: Some(STREAM_ID),
+        name: STREAM_NAME.to_string(),
+        ..CreateStream::default()
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topics
+    for (topic_id, name) in [(TOPIC_ID, TOPIC_NAME), (OTHER_TOPIC_ID, OTHER_TOPIC_NAME)] {
+        let create_topic = CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Some(topic_id),
+            name: name.to_string(),
+            ..CreateTopic::default()
+        };
+        client.create_topic(&create_topic).await.unwrap();
+    }
+
+    // 3. Send the messages to the first topic
+    let messages = (0..MESSAGES_COUNT)
+        .map(|id| Message::new(None, Bytes::from(format!("message {id}")), None))
+        .collect();
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages,
+        acks: None,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn delete_topic(client: &IggyClient, topic_id: u32, soft: bool) {
+    let delete_topic = DeleteTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(topic_id).unwrap(),
+        soft,
+    };
+    client.delete_topic(&delete_topic).await.unwrap();
+}
+
+async fn poll_messages(client: &IggyClient) -> u32 {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: MESSAGES_COUNT,
+        auto_commit: false,
+        filter: None,
+        mode: PollingMode::Consume,
+        max_wait_ms: None,
+        max_bytes: None,
+    };
+    client
+        .poll_messages(&poll_messages)
+        .await
+        .unwrap()
+        .messages
+        .len() as u32
+}
+
+fn get_stream() -> GetStream {
+    GetStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    }
+}
+
+fn get_topic(topic_id: u32) -> GetTopic {
+    GetTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(topic_id).unwrap(),
+    }
+}
+
+fn undelete_topic(topic_id: Identifier) -> UndeleteTopic {
+    UndeleteTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id,
+    }
+}
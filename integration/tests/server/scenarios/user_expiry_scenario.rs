@@ -0,0 +1,126 @@
+use iggy::client::UserClient;
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::identifier::Identifier;
+use iggy::models::user_info::UserInfoDetails;
+use iggy::models::user_status::UserStatus;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::users::get_user::GetUser;
+use iggy::users::login_user::LoginUser;
+use iggy::users::update_user_expiry::UpdateUserExpiry;
+use iggy::utils::timestamp::IggyTimestamp;
+use integration::test_server::{
+    assert_clean_system, create_user, delete_user, login_root, login_user, ClientFactory,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const EXPIRING_USERNAME: &str = "contractor";
+const INACTIVE_USERNAME: &str = "auditor";
+const PASSWORD: &str = "secret";
+const MAX_INACTIVE_DAYS: u32 = 90;
+const EXPIRES_IN_MICROS: u64 = 1_000_000;
+const CHECK_ATTEMPTS: u32 = 50;
+
+pub fn get_envs() -> HashMap<String, String> {
+    HashMap::from([
+        (
+            "IGGY_SYSTEM_USER_EXPIRY_ENABLED".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_USER_EXPIRY_CHECK_INTERVAL".to_string(),
+            "1s".to_string(),
+        ),
+    ])
+}
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    // 1. Create the users, one expiring soon and the other one with the inactivity window
+    login_root(&client).await;
+    create_user(&client, EXPIRING_USERNAME).await;
+    create_user(&client, INACTIVE_USERNAME).await;
+    let expires_at = IggyTimestamp::now().to_micros() + EXPIRES_IN_MICROS;
+    client
+        .update_user_expiry(&UpdateUserExpiry {
+            user_id: Identifier::named(EXPIRING_USERNAME).unwrap(),
+            expires_at: Some(expires_at),
+            max_inactive_days: None,
+        })
+        .await
+        .unwrap();
+    client
+        .update_user_expiry(&UpdateUserExpiry {
+            user_id: Identifier::named(INACTIVE_USERNAME).unwrap(),
+            expires_at: None,
+            max_inactive_days: Some(MAX_INACTIVE_DAYS),
+        })
+        .await
+        .unwrap();
+
+    let user = get_user(&client, EXPIRING_USERNAME).await;
+    assert_eq!(user.expires_at, Some(expires_at));
+    assert!(user.max_inactive_days.is_none());
+    let user = get_user(&client, INACTIVE_USERNAME).await;
+    assert!(user.expires_at.is_none());
+    assert_eq!(user.max_inactive_days, Some(MAX_INACTIVE_DAYS));
+    // The inactivity window starts once it's set.
+    assert!(user.last_active_at.is_some());
+
+    // 2. The root user cannot expire
+    let update_root_expiry = client
+        .update_user_expiry(&UpdateUserExpiry {
+            user_id: Identifier::numeric(DEFAULT_ROOT_USER_ID).unwrap(),
+            expires_at: Some(expires_at),
+            max_inactive_days: None,
+        })
+        .await;
+    assert!(update_root_expiry.is_err());
+
+    // 3. The login of the user within the inactivity window is tracked
+    login_user(&client, INACTIVE_USERNAME).await;
+    login_root(&client).await;
+    let user = get_user(&client, INACTIVE_USERNAME).await;
+    assert!(user.last_active_at.is_some());
+
+    // 4. The expired user is disabled by the background job and cannot log in anymore
+    let mut status = UserStatus::Active;
+    for _ in 0..CHECK_ATTEMPTS {
+        sleep(Duration::from_millis(100)).await;
+        status = get_user(&client, EXPIRING_USERNAME).await.status;
+        if status == UserStatus::Inactive {
+            break;
+        }
+    }
+    assert_eq!(status, UserStatus::Inactive);
+    assert_eq!(
+        get_user(&client, INACTIVE_USERNAME).await.status,
+        UserStatus::Active
+    );
+
+    let login_user = client
+        .login_user(&LoginUser {
+            username: EXPIRING_USERNAME.to_string(),
+            password: PASSWORD.to_string(),
+        })
+        .await;
+    assert!(login_user.is_err());
+
+    // 5. Clean up
+    login_root(&client).await;
+    delete_user(&client, EXPIRING_USERNAME).await;
+    delete_user(&client, INACTIVE_USERNAME).await;
+    assert_clean_system(&client).await;
+}
+
+async fn get_user(client: &IggyClient, username: &str) -> UserInfoDetails {
+    client
+        .get_user(&GetUser {
+            user_id: Identifier::named(username).unwrap(),
+        })
+        .await
+        .unwrap()
+}
@@ -0,0 +1,224 @@
+use iggy::client::{StreamClient, UserClient, UserGroupClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::identifier::Identifier;
+use iggy::models::permission_check::{PermissionAction, PermissionCheck};
+use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_streams::GetStreams;
+use iggy::user_groups::add_user_group_member::AddUserGroupMember;
+use iggy::user_groups::create_user_group::CreateUserGroup;
+use iggy::user_groups::delete_user_group::DeleteUserGroup;
+use iggy::user_groups::get_user_group::GetUserGroup;
+use iggy::user_groups::get_user_groups::GetUserGroups;
+use iggy::user_groups::remove_user_group_member::RemoveUserGroupMember;
+use iggy::user_groups::update_user_group_permissions::UpdateUserGroupPermissions;
+use iggy::users::check_permission::CheckPermission;
+use iggy::users::update_permissions::UpdatePermissions;
+use integration::test_server::{
+    assert_clean_system, create_user, delete_user, login_root, login_user, ClientFactory,
+};
+
+const GROUP_NAME: &str = "readers";
+const STREAM_ID: u32 = 1;
+const STREAM_NAME: &str = "orders";
+const MEMBER_USERNAME: &str = "group-member";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    // 1. User group operations should be forbidden for unauthenticated users
+    let get_user_groups = client.get_user_groups(&GetUserGroups {}).await;
+    assert!(get_user_groups.is_err());
+
+    // 2. Create the stream and the user without any permissions
+    login_root(&client).await;
+    client
+        .create_stream(&CreateStream {
+            stream_id: Some(STREAM_ID),
+            name: STREAM_NAME.to_string(),
+            ..CreateStream::default()
+        })
+        .await
+        .unwrap();
+    create_user(&client, MEMBER_USERNAME).await;
+    client
+        .update_permissions(&UpdatePermissions {
+            user_id: Identifier::named(MEMBER_USERNAME).unwrap(),
+            permissions: None,
+        })
+        .await
+        .unwrap();
+
+    // 3. Create the user group with the permission to read the streams
+    client
+        .create_user_group(&CreateUserGroup {
+            group_id: None,
+            name: GROUP_NAME.to_string(),
+            permissions: Some(get_read_streams_permissions()),
+        })
+        .await
+        .unwrap();
+    let groups = client.get_user_groups(&GetUserGroups {}).await.unwrap();
+    assert_eq!(groups.len(), 1);
+    let group = groups.first().unwrap();
+    assert_eq!(group.id, 1);
+    assert_eq!(group.name, GROUP_NAME);
+    assert_eq!(group.members_count, 0);
+    assert!(group.created_at > 0);
+
+    // 4. Creating the user group with the same name should fail
+    let create_user_group = client
+        .create_user_group(&CreateUserGroup {
+            group_id: None,
+            name: GROUP_NAME.to_string(),
+            permissions: None,
+        })
+        .await;
+    assert!(create_user_group.is_err());
+
+    // 5. The user without any permissions cannot read the streams
+    let permission_check = check_read_streams_permission(&client).await;
+    assert!(!permission_check.allowed);
+    assert_eq!(
+        permission_check.rule,
+        "missing any of: global.manage_streams, global.read_streams"
+    );
+    assert_eq!(permission_check.group_id, None);
+
+    login_user(&client, MEMBER_USERNAME).await;
+    let get_streams = client.get_streams(&GetStreams {}).await;
+    assert!(get_streams.is_err());
+
+    // 6. The member of the group gets the permissions granted by the group
+    login_root(&client).await;
+    client
+        .add_user_group_member(&AddUserGroupMember {
+            group_id: Identifier::named(GROUP_NAME).unwrap(),
+            user_id: Identifier::named(MEMBER_USERNAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    let add_user_group_member = client
+        .add_user_group_member(&AddUserGroupMember {
+            group_id: Identifier::named(GROUP_NAME).unwrap(),
+            user_id: Identifier::named(MEMBER_USERNAME).unwrap(),
+        })
+        .await;
+    assert!(add_user_group_member.is_err());
+
+    let group = get_user_group(&client).await;
+    assert_eq!(group.members.len(), 1);
+    assert_eq!(group.permissions, Some(get_read_streams_permissions()));
+
+    let permission_check = check_read_streams_permission(&client).await;
+    assert!(permission_check.allowed);
+    assert_eq!(permission_check.rule, "global.read_streams");
+    assert_eq!(permission_check.group_id, Some(group.id));
+
+    login_user(&client, MEMBER_USERNAME).await;
+    let streams = client.get_streams(&GetStreams {}).await.unwrap();
+    assert_eq!(streams.len(), 1);
+
+    // 7. The member cannot manage the user groups
+    let get_user_groups = client.get_user_groups(&GetUserGroups {}).await;
+    assert!(get_user_groups.is_err());
+
+    // 8. Revoking the group permissions applies to all of its members
+    login_root(&client).await;
+    client
+        .update_user_group_permissions(&UpdateUserGroupPermissions {
+            group_id: Identifier::named(GROUP_NAME).unwrap(),
+            permissions: None,
+        })
+        .await
+        .unwrap();
+    login_user(&client, MEMBER_USERNAME).await;
+    let get_streams = client.get_streams(&GetStreams {}).await;
+    assert!(get_streams.is_err());
+
+    // 9. The removed member loses the permissions granted by the group
+    login_root(&client).await;
+    client
+        .update_user_group_permissions(&UpdateUserGroupPermissions {
+            group_id: Identifier::named(GROUP_NAME).unwrap(),
+            permissions: Some(get_read_streams_permissions()),
+        })
+        .await
+        .unwrap();
+    client
+        .remove_user_group_member(&RemoveUserGroupMember {
+            group_id: Identifier::named(GROUP_NAME).unwrap(),
+            user_id: Identifier::named(MEMBER_USERNAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    login_user(&client, MEMBER_USERNAME).await;
+    let get_streams = client.get_streams(&GetStreams {}).await;
+    assert!(get_streams.is_err());
+
+    // 10. The deleted user is removed from the group
+    login_root(&client).await;
+    client
+        .add_user_group_member(&AddUserGroupMember {
+            group_id: Identifier::named(GROUP_NAME).unwrap(),
+            user_id: Identifier::named(MEMBER_USERNAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    delete_user(&client, MEMBER_USERNAME).await;
+    let group = get_user_group(&client).await;
+    assert!(group.members.is_empty());
+
+    // 11. Delete the user group
+    client
+        .delete_user_group(&DeleteUserGroup {
+            group_id: Identifier::named(GROUP_NAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    let groups = client.get_user_groups(&GetUserGroups {}).await.unwrap();
+    assert!(groups.is_empty());
+
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            soft: false,
+        })
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn get_user_group(client: &IggyClient) -> iggy::models::user_group::UserGroupDetails {
+    client
+        .get_user_group(&GetUserGroup {
+            group_id: Identifier::named(GROUP_NAME).unwrap(),
+        })
+        .await
+        .unwrap()
+}
+
+async fn check_read_streams_permission(client: &IggyClient) -> PermissionCheck {
+    client
+        .check_permission(&CheckPermission {
+            user_id: Identifier::named(MEMBER_USERNAME).unwrap(),
+            action: PermissionAction::ReadStreams,
+            stream_id: None,
+            topic_id: None,
+        })
+        .await
+        .unwrap()
+}
+
+fn get_read_streams_permissions() -> Permissions {
+    Permissions {
+        global: GlobalPermissions {
+            read_streams: true,
+            read_topics: true,
+            ..Default::default()
+        },
+        streams: None,
+    }
+}
@@ -86,6 +86,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
                     read_topics: true,
                     poll_messages: true,
                     send_messages: true,
+                    auto_create_topics: false,
                 },
                 streams: None,
             }),
@@ -136,7 +137,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert!(create_user_result.is_err());
 
-    // 11. Updating your own password should be allowed
+    // 11. Updating your own password should be allowed and revoke the active sessions, so the user has to log in again
     let updated_test_password = "topsecret";
 
     client
@@ -148,6 +149,22 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
 
+    let get_user = client
+        .get_user(&GetUser {
+            user_id: Identifier::named(test_user).unwrap(),
+        })
+        .await;
+
+    assert!(get_user.is_err());
+
+    client
+        .login_user(&LoginUser {
+            username: test_user.to_string(),
+            password: updated_test_password.to_string(),
+        })
+        .await
+        .unwrap();
+
     // 12. Trying to change the password given the invalid current password should fail
     let change_password = client
         .change_password(&ChangePassword {
@@ -283,6 +300,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
                     read_topics: true,
                     poll_messages: true,
                     send_messages: true,
+                    auto_create_topics: false,
                 },
                 streams: None,
             }),
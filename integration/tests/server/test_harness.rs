@@ -0,0 +1,171 @@
+use iggy::bootstrap::definition::BootstrapDefinition;
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient};
+use iggy::clients::client::IggyClient;
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::streams::get_stream::GetStream;
+use iggy_test::assertions::{assert_offsets, await_message_count};
+use iggy_test::test_cluster::TestCluster;
+use iggy_test::test_server::{TestServer, TestStorage};
+use serial_test::parallel;
+use std::str::FromStr;
+use std::time::Duration;
+
+const STREAM_NAME: &str = "orders";
+const TOPIC_NAME: &str = "created";
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[tokio::test]
+#[parallel]
+async fn test_server_should_create_fixture_and_track_messages() {
+    let server = TestServer::builder()
+        .fixture(create_fixture())
+        .start()
+        .await
+        .unwrap();
+    let client = server.client().await.unwrap();
+
+    send_messages(&client).await;
+    await_message_count(
+        &client,
+        &stream_id(),
+        &topic_id(),
+        MESSAGES_COUNT as u64,
+        TIMEOUT,
+    )
+    .await;
+
+    let consumer = Consumer::new(Identifier::named("tester").unwrap());
+    client
+        .store_consumer_offset(&StoreConsumerOffset {
+            consumer: Consumer::new(Identifier::named("tester").unwrap()),
+            stream_id: stream_id(),
+            topic_id: topic_id(),
+            partition_id: Some(PARTITION_ID),
+            offset: 5,
+        })
+        .await
+        .unwrap();
+    assert_offsets(
+        &client,
+        &consumer,
+        &stream_id(),
+        &topic_id(),
+        &[(PARTITION_ID, 5)],
+    )
+    .await;
+
+    let path = server.path().to_path_buf();
+    server.shutdown().await.unwrap();
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+#[parallel]
+async fn test_server_should_keep_messages_after_restart_given_temp_dir_storage() {
+    let mut server = TestServer::builder()
+        .storage(TestStorage::TempDir)
+        .fixture(create_fixture())
+        .start()
+        .await
+        .unwrap();
+    send_messages(&server.client().await.unwrap()).await;
+
+    server.restart().await.unwrap();
+
+    let client = server.client().await.unwrap();
+    await_message_count(
+        &client,
+        &stream_id(),
+        &topic_id(),
+        MESSAGES_COUNT as u64,
+        TIMEOUT,
+    )
+    .await;
+    server.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+#[parallel]
+async fn test_server_should_lose_messages_after_restart_given_memory_storage() {
+    let mut server = TestServer::builder()
+        .fixture(create_fixture())
+        .start()
+        .await
+        .unwrap();
+    send_messages(&server.client().await.unwrap()).await;
+
+    server.restart().await.unwrap();
+
+    // The fixture is created again, but the messages are gone.
+    let client = server.client().await.unwrap();
+    await_message_count(&client, &stream_id(), &topic_id(), 0, TIMEOUT).await;
+    server.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+#[parallel]
+async fn test_cluster_nodes_should_be_independent() {
+    let cluster = TestCluster::start_with(TestServer::builder().fixture(create_fixture()), 2)
+        .await
+        .unwrap();
+    let clients = cluster.clients().await.unwrap();
+
+    send_messages(&clients[0]).await;
+
+    await_message_count(
+        &clients[0],
+        &stream_id(),
+        &topic_id(),
+        MESSAGES_COUNT as u64,
+        TIMEOUT,
+    )
+    .await;
+    await_message_count(&clients[1], &stream_id(), &topic_id(), 0, TIMEOUT).await;
+    for client in &clients {
+        let stream = client
+            .get_stream(&GetStream {
+                stream_id: stream_id(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(stream.id, 1);
+    }
+    cluster.shutdown().await.unwrap();
+}
+
+fn create_fixture() -> BootstrapDefinition {
+    BootstrapDefinition::from_toml(&format!(
+        r#"
+        [[streams]]
+        name = "{STREAM_NAME}"
+        topics = [{{ name = "{TOPIC_NAME}", partitions_count = 2 }}]
+        "#
+    ))
+    .unwrap()
+}
+
+async fn send_messages(client: &IggyClient) {
+    let mut send_messages = SendMessages {
+        stream_id: stream_id(),
+        topic_id: topic_id(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        messages: (0..MESSAGES_COUNT)
+            .map(|index| Message::from_str(&format!("message {index}")).unwrap())
+            .collect(),
+        acks: None,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+fn stream_id() -> Identifier {
+    Identifier::named(STREAM_NAME).unwrap()
+}
+
+fn topic_id() -> Identifier {
+    Identifier::named(TOPIC_NAME).unwrap()
+}
@@ -0,0 +1,149 @@
+use crate::streaming::common::test_setup::TestSetup;
+use iggy::identifier::Identifier;
+use iggy::models::user_status::UserStatus;
+use server::configs::bootstrap::BootstrapDefinition;
+use server::configs::server::PersonalAccessTokenConfig;
+use server::configs::system::SystemConfig;
+use server::streaming::systems::system::System;
+
+const DEFINITION: &str = r#"
+[[streams]]
+id = 10
+name = "orders"
+
+[[streams.topics]]
+id = 1
+name = "created"
+partitions_count = 2
+message_expiry = "1 hour"
+
+[[streams.topics]]
+name = "cancelled"
+
+[[users]]
+username = "producer"
+password = "secret"
+"#;
+
+const UPDATED_DEFINITION: &str = r#"
+[[streams]]
+name = "orders"
+
+[[streams.topics]]
+name = "created"
+partitions_count = 4
+message_expiry = "2 hours"
+
+[[users]]
+username = "producer"
+password = "secret"
+status = "inactive"
+"#;
+
+#[tokio::test]
+async fn should_create_missing_streams_topics_and_users() {
+    let setup = TestSetup::init().await;
+    let mut system = init_system(&setup).await;
+
+    system
+        .bootstrap(&BootstrapDefinition::from_toml(DEFINITION).unwrap())
+        .await
+        .unwrap();
+
+    let stream = system
+        .get_stream(&Identifier::named("orders").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(stream.stream_id, 10);
+    assert_eq!(stream.get_topics().len(), 2);
+    let topic = stream
+        .get_topic(&Identifier::named("created").unwrap())
+        .unwrap();
+    assert_eq!(topic.topic_id, 1);
+    assert_eq!(topic.get_partitions().len(), 2);
+    assert_eq!(topic.message_expiry, Some(3600));
+    let topic = stream
+        .get_topic(&Identifier::named("cancelled").unwrap())
+        .unwrap();
+    assert_eq!(topic.get_partitions().len(), 1);
+    assert_eq!(topic.message_expiry, None);
+
+    let user = system
+        .get_user(&Identifier::named("producer").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(user.status, UserStatus::Active);
+}
+
+#[tokio::test]
+async fn should_not_update_existing_resources_by_default() {
+    let setup = TestSetup::init().await;
+    let mut system = init_system(&setup).await;
+    system
+        .bootstrap(&BootstrapDefinition::from_toml(DEFINITION).unwrap())
+        .await
+        .unwrap();
+
+    system
+        .bootstrap(&BootstrapDefinition::from_toml(UPDATED_DEFINITION).unwrap())
+        .await
+        .unwrap();
+
+    let stream = system
+        .get_stream(&Identifier::named("orders").unwrap())
+        .await
+        .unwrap();
+    let topic = stream
+        .get_topic(&Identifier::named("created").unwrap())
+        .unwrap();
+    assert_eq!(topic.get_partitions().len(), 2);
+    assert_eq!(topic.message_expiry, Some(3600));
+    let user = system
+        .get_user(&Identifier::named("producer").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(user.status, UserStatus::Active);
+}
+
+#[tokio::test]
+async fn should_update_drifted_resources_when_enabled() {
+    let mut config = SystemConfig::default();
+    config.bootstrap.update_existing = true;
+    let setup = TestSetup::init_with_config(config).await;
+    let mut system = init_system(&setup).await;
+    system
+        .bootstrap(&BootstrapDefinition::from_toml(DEFINITION).unwrap())
+        .await
+        .unwrap();
+
+    system
+        .bootstrap(&BootstrapDefinition::from_toml(UPDATED_DEFINITION).unwrap())
+        .await
+        .unwrap();
+
+    let stream = system
+        .get_stream(&Identifier::named("orders").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(stream.get_topics().len(), 2);
+    let topic = stream
+        .get_topic(&Identifier::named("created").unwrap())
+        .unwrap();
+    assert_eq!(topic.get_partitions().len(), 4);
+    assert_eq!(topic.message_expiry, Some(7200));
+    let user = system
+        .get_user(&Identifier::named("producer").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(user.status, UserStatus::Inactive);
+}
+
+async fn init_system(setup: &TestSetup) -> System {
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    system.init().await.unwrap();
+    system
+}
@@ -0,0 +1,238 @@
+use crate::streaming::common::test_setup::TestSetup;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::{checksum, timestamp::IggyTimestamp};
+use server::streaming::persistence::persister::FilePersister;
+use server::streaming::segments::fault_injection::{FaultInjectingSegmentStorage, FaultScenario};
+use server::streaming::segments::segment::Segment;
+use server::streaming::storage::SystemStorage;
+use std::ops::Range;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::fs;
+use tokio::time::Instant;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const PARTITION_ID: u32 = 1;
+
+#[tokio::test]
+async fn torn_write_should_be_truncated_when_segment_is_loaded() {
+    let setup = TestSetup::init().await;
+    let mut segment = create_faulty_segment(
+        &setup,
+        r#"
+        [[faults]]
+        operation = "save_batches"
+        skip = 1
+        fault = { type = "partial_write", bytes = 20 }
+        "#,
+    )
+    .await;
+    append_messages(&mut segment, 0..5).await;
+    segment.persist_messages().await.unwrap();
+    let log_size = fs::metadata(&segment.log_path).await.unwrap().len();
+
+    append_messages(&mut segment, 5..10).await;
+    let result = segment.persist_messages().await;
+
+    assert!(matches!(
+        result,
+        Err(IggyError::CannotSaveMessagesToSegment(_))
+    ));
+    assert_eq!(
+        fs::metadata(&segment.log_path).await.unwrap().len(),
+        log_size + 20
+    );
+
+    let mut loaded_segment = create_segment(&setup, setup.storage.clone());
+    loaded_segment.load().await.unwrap();
+
+    assert_eq!(loaded_segment.current_offset, 4);
+    assert_eq!(loaded_segment.size_bytes as u64, log_size);
+    assert_eq!(
+        fs::metadata(&segment.log_path).await.unwrap().len(),
+        log_size
+    );
+    assert_offsets(&loaded_segment, 0..5).await;
+}
+
+#[tokio::test]
+async fn indexes_should_be_recovered_when_fsync_of_log_fails() {
+    let setup = TestSetup::init().await;
+    let mut segment = create_faulty_segment(
+        &setup,
+        r#"
+        [[faults]]
+        operation = "save_batches"
+        skip = 1
+        times = 1
+        fault = { type = "fsync_error" }
+        "#,
+    )
+    .await;
+    append_messages(&mut segment, 0..5).await;
+    segment.persist_messages().await.unwrap();
+    append_messages(&mut segment, 5..10).await;
+    let result = segment.persist_messages().await;
+
+    // The messages have been written to the log, but the indexes have not been saved.
+    assert!(matches!(
+        result,
+        Err(IggyError::CannotSaveMessagesToSegment(_))
+    ));
+    assert_eq!(
+        fs::metadata(&segment.index_path).await.unwrap().len(),
+        5 * 4
+    );
+    assert_eq!(
+        fs::metadata(&segment.time_index_path).await.unwrap().len(),
+        5 * 8
+    );
+
+    let mut loaded_segment = create_segment(&setup, setup.storage.clone());
+    loaded_segment.load().await.unwrap();
+
+    assert_eq!(loaded_segment.current_offset, 9);
+    assert_eq!(
+        fs::metadata(&segment.index_path).await.unwrap().len(),
+        10 * 4
+    );
+    assert_eq!(
+        fs::metadata(&segment.time_index_path).await.unwrap().len(),
+        10 * 8
+    );
+    assert_offsets(&loaded_segment, 0..10).await;
+}
+
+#[tokio::test]
+async fn indexes_should_be_recovered_when_saving_index_fails() {
+    let setup = TestSetup::init().await;
+    let mut segment = create_faulty_segment(
+        &setup,
+        r#"
+        [[faults]]
+        operation = "save_index"
+        times = 1
+        fault = { type = "io_error" }
+        "#,
+    )
+    .await;
+    append_messages(&mut segment, 0..5).await;
+
+    let result = segment.persist_messages().await;
+    assert!(matches!(
+        result,
+        Err(IggyError::CannotSaveIndexToSegment(_))
+    ));
+    assert_eq!(fs::metadata(&segment.index_path).await.unwrap().len(), 0);
+
+    // The log has already been saved, so the recovery restores the missing indexes.
+    let mut loaded_segment = create_segment(&setup, setup.storage.clone());
+    loaded_segment.load().await.unwrap();
+    assert_eq!(loaded_segment.current_offset, 4);
+    assert_offsets(&loaded_segment, 0..5).await;
+}
+
+#[tokio::test]
+async fn delay_should_be_injected_given_number_of_times() {
+    let setup = TestSetup::init().await;
+    let delay = Duration::from_millis(200);
+    let mut segment = create_faulty_segment(
+        &setup,
+        &format!(
+            r#"
+            [[faults]]
+            operation = "save_time_index"
+            partition_id = {PARTITION_ID}
+            times = 2
+            fault = {{ type = "delay", ms = {} }}
+            "#,
+            delay.as_millis()
+        ),
+    )
+    .await;
+
+    let started_at = Instant::now();
+    for offset in 0..3 {
+        append_messages(&mut segment, offset..offset + 1).await;
+        segment.persist_messages().await.unwrap();
+    }
+    let elapsed = started_at.elapsed();
+
+    assert!(elapsed >= delay * 2);
+    let mut loaded_segment = create_segment(&setup, setup.storage.clone());
+    loaded_segment.load().await.unwrap();
+    assert_offsets(&loaded_segment, 0..3).await;
+}
+
+async fn create_faulty_segment(setup: &TestSetup, scenario: &str) -> Segment {
+    let file_storage = SystemStorage::new(setup.db.clone(), Arc::new(FilePersister {}));
+    let scenario = FaultScenario::parse(scenario).unwrap();
+    let storage = Arc::new(SystemStorage {
+        segment: Arc::new(FaultInjectingSegmentStorage::new(
+            file_storage.segment.clone(),
+            scenario,
+        )),
+        ..file_storage
+    });
+    setup
+        .create_partition_directory(STREAM_ID, TOPIC_ID, PARTITION_ID)
+        .await;
+    let segment = create_segment(setup, storage);
+    segment.persist().await.unwrap();
+    segment
+}
+
+fn create_segment(setup: &TestSetup, storage: Arc<SystemStorage>) -> Segment {
+    Segment::create(
+        STREAM_ID,
+        TOPIC_ID,
+        PARTITION_ID,
+        0,
+        setup.config.clone(),
+        storage,
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    )
+}
+
+async fn append_messages(segment: &mut Segment, offsets: Range<u64>) {
+    let messages = offsets
+        .map(|offset| Arc::new(create_message(offset)))
+        .collect::<Vec<_>>();
+    segment.append_messages(&messages).await.unwrap();
+}
+
+async fn assert_offsets(segment: &Segment, expected_offsets: Range<u64>) {
+    let messages = segment
+        .get_messages(0, expected_offsets.end as u32)
+        .await
+        .unwrap();
+    let offsets = messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, expected_offsets.collect::<Vec<_>>());
+}
+
+fn create_message(offset: u64) -> Message {
+    let payload = Bytes::from(format!("message {offset}"));
+    let checksum = checksum::calculate(payload.as_ref());
+    Message::create(
+        offset,
+        MessageState::Available,
+        IggyTimestamp::now().to_micros(),
+        0,
+        payload,
+        checksum,
+        None,
+    )
+}
@@ -0,0 +1,145 @@
+use crate::streaming::common::test_setup::TestSetup;
+use iggy::identifier::Identifier;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::models::topic_defaults::TopicDefaults;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use server::configs::server::PersonalAccessTokenConfig;
+use server::streaming::metadata_log::entry::MetadataEntry;
+use server::streaming::metadata_log::log::MetadataLog;
+use server::streaming::session::Session;
+use server::streaming::systems::system::System;
+use std::net::{Ipv4Addr, SocketAddr};
+
+#[tokio::test]
+async fn should_replay_unfinished_entries_and_truncate_metadata_log_on_init() {
+    let setup = TestSetup::init().await;
+    let metadata_log = MetadataLog::new(&setup.config, setup.storage.metadata_log.clone());
+    let completed_entry_id = metadata_log
+        .append(MetadataEntry::CreateStream {
+            stream_id: 1,
+            name: "completed".to_string(),
+            tenant_id: None,
+            topic_defaults: TopicDefaults::default(),
+        })
+        .await
+        .unwrap();
+    metadata_log.complete(completed_entry_id).await.unwrap();
+    metadata_log
+        .append(MetadataEntry::CreateStream {
+            stream_id: 2,
+            name: "unfinished".to_string(),
+            tenant_id: None,
+            topic_defaults: TopicDefaults::default(),
+        })
+        .await
+        .unwrap();
+    metadata_log
+        .append(MetadataEntry::CreateTopic {
+            stream_id: 2,
+            topic_id: Some(1),
+            name: "topic".to_string(),
+            partitions_count: 3,
+            message_expiry: None,
+            max_topic_size: None,
+            replication_factor: 1,
+            message_schema: None,
+            ephemeral: false,
+            timestamp_type: TimestampType::default(),
+            consumer_limits: ConsumerLimits::default(),
+            max_message_size: None,
+        })
+        .await
+        .unwrap();
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+
+    system.init().await.unwrap();
+
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    let streams = system.find_streams(&session).await.unwrap();
+    assert_eq!(streams.len(), 1);
+    let stream = system
+        .find_stream(&session, &Identifier::numeric(2).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(stream.name, "unfinished");
+    let topic = stream.get_topic(&Identifier::numeric(1).unwrap()).unwrap();
+    assert_eq!(topic.get_partitions_count(), 3);
+    assert!(metadata_log.load_unfinished().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn should_not_replay_already_applied_entries() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    system
+        .create_stream(&session, Some(1), "test", TopicDefaults::default())
+        .await
+        .unwrap();
+    let metadata_log = MetadataLog::new(&setup.config, setup.storage.metadata_log.clone());
+    assert!(metadata_log.load_unfinished().await.unwrap().is_empty());
+    metadata_log
+        .append(MetadataEntry::DeleteStream {
+            stream_id: 2,
+            soft: false,
+        })
+        .await
+        .unwrap();
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+
+    system.init().await.unwrap();
+
+    let streams = system.find_streams(&session).await.unwrap();
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].name, "test");
+}
+
+#[tokio::test]
+async fn should_replay_unfinished_user_group_entries() {
+    let setup = TestSetup::init().await;
+    let metadata_log = MetadataLog::new(&setup.config, setup.storage.metadata_log.clone());
+    metadata_log
+        .append(MetadataEntry::CreateUserGroup {
+            group_id: 1,
+            name: "operators".to_string(),
+            permissions: None,
+        })
+        .await
+        .unwrap();
+    metadata_log
+        .append(MetadataEntry::AddUserGroupMember {
+            group_id: 1,
+            user_id: DEFAULT_ROOT_USER_ID,
+        })
+        .await
+        .unwrap();
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+
+    system.init().await.unwrap();
+
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    let group = system
+        .find_user_group(&session, &Identifier::numeric(1).unwrap())
+        .unwrap();
+    assert_eq!(group.name, "operators");
+    assert!(group.members.contains(&DEFAULT_ROOT_USER_ID));
+    assert!(metadata_log.load_unfinished().await.unwrap().is_empty());
+}
@@ -0,0 +1,332 @@
+use crate::streaming::common::test_setup::TestSetup;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::messages::{Message, MessageState};
+use iggy::models::topic_defaults::TopicDefaults;
+use iggy::utils::checksum;
+use server::configs::server::PersonalAccessTokenConfig;
+use server::configs::system::SystemConfig;
+use server::streaming::metadata_log::entry::MetadataEntry;
+use server::streaming::metadata_log::log::MetadataLog;
+use server::streaming::metadata_log::storage::{
+    read_format_version, HEADER_SIZE, METADATA_LOG_FORMAT_VERSION,
+};
+use server::streaming::migrations::migration::StorageMigration;
+use server::streaming::migrations::migrator::{Migrator, CURRENT_FORMAT_VERSION};
+use server::streaming::segments::segment::Segment;
+use server::streaming::segments::storage::{
+    read_segment_format_version, SegmentFileKind, SEGMENT_FORMAT_VERSION, SEGMENT_HEADER_SIZE,
+};
+use server::streaming::session::Session;
+use server::streaming::systems::info::SystemInfo;
+use server::streaming::systems::system::System;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::Path;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use tokio::fs;
+
+#[tokio::test]
+async fn should_set_current_format_version_for_new_system() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+
+    system.init().await.unwrap();
+
+    let system_info = load_system_info(&setup).await;
+    assert_eq!(system_info.format_version, CURRENT_FORMAT_VERSION);
+    assert!(system_info.migrations.is_empty());
+}
+
+#[tokio::test]
+async fn should_migrate_legacy_metadata_log_and_replay_it_on_init() {
+    let setup = TestSetup::init().await;
+    init_legacy_system(&setup).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+
+    system.init().await.unwrap();
+
+    let system_info = load_system_info(&setup).await;
+    assert_eq!(system_info.format_version, CURRENT_FORMAT_VERSION);
+    assert_eq!(system_info.migrations.len(), 2);
+    assert_eq!(system_info.migrations[0].id, 1);
+    assert_eq!(system_info.migrations[1].id, 2);
+    let metadata_log = fs::read(setup.config.get_metadata_log_path())
+        .await
+        .unwrap();
+    assert_eq!(
+        read_format_version(&metadata_log),
+        Some(METADATA_LOG_FORMAT_VERSION)
+    );
+    assert!(!Path::new(&setup.config.get_migration_backup_path())
+        .join("1")
+        .exists());
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    let stream = system
+        .find_stream(&session, &Identifier::numeric(1).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(stream.name, "legacy");
+}
+
+#[tokio::test]
+async fn should_not_start_with_pending_migrations_when_auto_migrate_is_disabled() {
+    let mut config = SystemConfig::default();
+    config.migration.auto_migrate = false;
+    let setup = TestSetup::init_with_config(config).await;
+    init_legacy_system(&setup).await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+
+    let result = system.init().await;
+
+    assert!(matches!(
+        result,
+        Err(IggyError::StorageMigrationRequired(
+            0,
+            CURRENT_FORMAT_VERSION
+        ))
+    ));
+
+    system.migrate().await.unwrap();
+    let system_info = load_system_info(&setup).await;
+    assert_eq!(system_info.format_version, CURRENT_FORMAT_VERSION);
+}
+
+#[tokio::test]
+async fn should_not_start_with_newer_format_version() {
+    let setup = TestSetup::init().await;
+    let mut system_info = SystemInfo::default();
+    system_info.update_version(env!("CARGO_PKG_VERSION"));
+    system_info.format_version = CURRENT_FORMAT_VERSION + 1;
+    setup.storage.info.save(&system_info).await.unwrap();
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+
+    let result = system.init().await;
+
+    assert!(matches!(
+        result,
+        Err(IggyError::UnsupportedStorageFormat(
+            _,
+            CURRENT_FORMAT_VERSION
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn should_add_headers_to_legacy_segment_files() {
+    let setup = TestSetup::init().await;
+    let segment = init_legacy_segment(&setup).await;
+    let mut system_info = SystemInfo {
+        format_version: 1,
+        ..Default::default()
+    };
+    let migrator = Migrator::new(setup.config.clone());
+
+    let applied_migrations = migrator
+        .migrate(&mut system_info, setup.storage.info.as_ref())
+        .await
+        .unwrap();
+
+    assert_eq!(applied_migrations, 1);
+    assert_eq!(system_info.format_version, CURRENT_FORMAT_VERSION);
+    for (path, kind) in [
+        (&segment.log_path, SegmentFileKind::Log),
+        (&segment.index_path, SegmentFileKind::Index),
+        (&segment.time_index_path, SegmentFileKind::TimeIndex),
+    ] {
+        assert_eq!(
+            read_segment_format_version(path, kind).await.unwrap(),
+            Some(SEGMENT_FORMAT_VERSION)
+        );
+    }
+    assert!(!Path::new(&setup.config.get_migration_backup_path())
+        .join("2")
+        .exists());
+
+    let mut loaded_segment = create_segment(&setup);
+    loaded_segment.load().await.unwrap();
+    assert_eq!(loaded_segment.size_bytes, segment.size_bytes);
+    let messages = loaded_segment.get_messages(0, 3).await.unwrap();
+    let offsets = messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec![0, 1, 2]);
+}
+
+#[tokio::test]
+async fn should_not_load_legacy_segment_files_without_migration() {
+    let setup = TestSetup::init().await;
+    init_legacy_segment(&setup).await;
+
+    let result = create_segment(&setup).load().await;
+
+    assert!(matches!(
+        result,
+        Err(IggyError::StorageMigrationRequired(
+            0,
+            SEGMENT_FORMAT_VERSION
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn should_restore_only_modified_files_when_migration_fails() {
+    let setup = TestSetup::init().await;
+    let segment = init_legacy_segment(&setup).await;
+    let log = fs::read(&segment.log_path).await.unwrap();
+    let index = fs::read(&segment.index_path).await.unwrap();
+    let mut system_info = SystemInfo {
+        format_version: CURRENT_FORMAT_VERSION,
+        ..Default::default()
+    };
+    let migrator = Migrator::with_migrations(
+        setup.config.clone(),
+        vec![Box::new(FailingMigration {
+            path: segment.log_path.clone(),
+        })],
+    );
+
+    let result = migrator
+        .migrate(&mut system_info, setup.storage.info.as_ref())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(IggyError::CannotApplyStorageMigration(
+            FAILING_MIGRATION_VERSION,
+            _
+        ))
+    ));
+    assert_eq!(system_info.format_version, CURRENT_FORMAT_VERSION);
+    assert_eq!(fs::read(&segment.log_path).await.unwrap(), log);
+    assert_eq!(fs::read(&segment.index_path).await.unwrap(), index);
+    assert!(!Path::new(&setup.config.get_migration_backup_path())
+        .join(FAILING_MIGRATION_VERSION.to_string())
+        .exists());
+}
+
+const FAILING_MIGRATION_VERSION: u32 = CURRENT_FORMAT_VERSION + 1;
+
+/// Corrupts the single file it declares as modified and fails.
+#[derive(Debug)]
+struct FailingMigration {
+    path: String,
+}
+
+#[async_trait]
+impl StorageMigration for FailingMigration {
+    fn version(&self) -> u32 {
+        FAILING_MIGRATION_VERSION
+    }
+
+    fn name(&self) -> &'static str {
+        "failing"
+    }
+
+    async fn get_modified_files(&self, _config: &SystemConfig) -> Result<Vec<String>, IggyError> {
+        Ok(vec![self.path.clone()])
+    }
+
+    async fn migrate(&self, _config: &SystemConfig) -> Result<(), IggyError> {
+        fs::write(&self.path, b"corrupted").await?;
+        Err(IggyError::InvalidCommand)
+    }
+}
+
+/// Stores the segment with the messages and strips the headers of its files, as written before the segment format versioning.
+async fn init_legacy_segment(setup: &TestSetup) -> Segment {
+    setup.create_partition_directory(1, 1, 1).await;
+    let mut segment = create_segment(setup);
+    segment.persist().await.unwrap();
+    let messages = (0..3)
+        .map(|offset| {
+            let payload = Bytes::from(format!("message {offset}"));
+            Arc::new(Message::create(
+                offset,
+                MessageState::Available,
+                1000 + offset,
+                offset as u128,
+                payload.clone(),
+                checksum::calculate(&payload),
+                None,
+            ))
+        })
+        .collect::<Vec<_>>();
+    segment.append_messages(&messages).await.unwrap();
+    segment.persist_messages().await.unwrap();
+    for path in [
+        &segment.log_path,
+        &segment.index_path,
+        &segment.time_index_path,
+    ] {
+        let bytes = fs::read(path).await.unwrap();
+        fs::write(path, &bytes[SEGMENT_HEADER_SIZE as usize..])
+            .await
+            .unwrap();
+    }
+    segment
+}
+
+fn create_segment(setup: &TestSetup) -> Segment {
+    Segment::create(
+        1,
+        1,
+        1,
+        0,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    )
+}
+
+/// Stores the system info and the metadata log as written before the storage format versioning.
+async fn init_legacy_system(setup: &TestSetup) {
+    let mut system_info = SystemInfo::default();
+    system_info.update_version(env!("CARGO_PKG_VERSION"));
+    setup.storage.info.save(&system_info).await.unwrap();
+
+    let metadata_log = MetadataLog::new(&setup.config, setup.storage.metadata_log.clone());
+    metadata_log
+        .append(MetadataEntry::CreateStream {
+            stream_id: 1,
+            name: "legacy".to_string(),
+            tenant_id: None,
+            topic_defaults: TopicDefaults::default(),
+        })
+        .await
+        .unwrap();
+    let path = setup.config.get_metadata_log_path();
+    let bytes = fs::read(&path).await.unwrap();
+    fs::write(&path, &bytes[HEADER_SIZE..]).await.unwrap();
+}
+
+async fn load_system_info(setup: &TestSetup) -> SystemInfo {
+    let mut system_info = SystemInfo::default();
+    setup.storage.info.load(&mut system_info).await.unwrap();
+    system_info
+}
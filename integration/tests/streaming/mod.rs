@@ -2,10 +2,15 @@ use bytes::Bytes;
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::checksum;
 
+mod bootstrap;
 mod common;
 mod consumer_group;
 mod consumer_offset;
+#[cfg(feature = "fault-injection")]
+mod fault_injection;
 mod messages;
+mod metadata_log;
+mod migration;
 mod partition;
 mod personal_access_token;
 mod segment;
@@ -1,9 +1,14 @@
 use crate::streaming::common::test_setup::TestSetup;
-use bytes::Bytes;
+use bytes::{BufMut, Bytes, BytesMut};
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::{checksum, timestamp::IggyTimestamp};
+use server::configs::system::SystemConfig;
+use server::streaming::segments::index::Index;
 use server::streaming::segments::segment;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
+use server::streaming::segments::storage::{SegmentFileKind, SEGMENT_HEADER_SIZE};
+use server::streaming::segments::time_index::TimeIndex;
+use std::mem::size_of;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use tokio::fs;
@@ -177,6 +182,205 @@ async fn should_persist_and_load_segment_with_messages() {
     assert_eq!(messages.len(), messages_count as usize);
 }
 
+#[tokio::test]
+async fn should_load_indexes_lazily_on_first_append_when_enabled() {
+    let mut config = SystemConfig::default();
+    config.startup.lazy_segment_indexes = true;
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let messages_count = 10;
+    for offset in 0..messages_count {
+        let message = create_message(offset, "test", IggyTimestamp::now().to_micros());
+        segment.append_messages(&[Arc::new(message)]).await.unwrap();
+    }
+    segment.persist_messages().await.unwrap();
+
+    // Only the time indexes are loaded, while the messages are read using the indexes stored on disk.
+    let mut loaded_segment =
+        create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    loaded_segment.load().await.unwrap();
+    let time_indexes_size = messages_count as usize * size_of::<TimeIndex>();
+    assert_eq!(
+        loaded_segment.get_cached_indexes_size_bytes(),
+        time_indexes_size as u64
+    );
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count as usize);
+
+    let message = create_message(messages_count, "test", IggyTimestamp::now().to_micros());
+    loaded_segment
+        .append_messages(&[Arc::new(message)])
+        .await
+        .unwrap();
+    let indexes_size = (messages_count as usize + 1) * size_of::<Index>();
+    assert_eq!(
+        loaded_segment.get_cached_indexes_size_bytes(),
+        (indexes_size + time_indexes_size + size_of::<TimeIndex>()) as u64
+    );
+}
+
+#[tokio::test]
+async fn should_load_messages_stored_one_by_one_along_with_batches() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let segment = create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+
+    // The messages stored one by one (format v1) by the previous server versions.
+    let legacy_messages_count = 5;
+    let mut log = BytesMut::new();
+    let mut index = BytesMut::from(&SegmentFileKind::Index.header()[..]);
+    let mut time_index = BytesMut::from(&SegmentFileKind::TimeIndex.header()[..]);
+    for offset in 0..legacy_messages_count {
+        let message = create_message(offset, "legacy", IggyTimestamp::now().to_micros());
+        index.put_u32_le(log.len() as u32);
+        time_index.put_u64_le(message.timestamp);
+        message.extend(&mut log);
+    }
+    let mut log_file = BytesMut::from(&SegmentFileKind::Log.header()[..]);
+    log_file.extend_from_slice(&log);
+    fs::write(&segment.log_path, &log_file).await.unwrap();
+    fs::write(&segment.index_path, &index).await.unwrap();
+    fs::write(&segment.time_index_path, &time_index)
+        .await
+        .unwrap();
+
+    let mut segment = create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    segment.load().await.unwrap();
+    assert_eq!(segment.current_offset, legacy_messages_count - 1);
+    for offsets in [5..8, 8..10] {
+        let messages = offsets
+            .map(|offset| {
+                Arc::new(create_message(
+                    offset,
+                    "batch",
+                    IggyTimestamp::now().to_micros(),
+                ))
+            })
+            .collect::<Vec<_>>();
+        segment.append_messages(&messages).await.unwrap();
+    }
+    segment.persist_messages().await.unwrap();
+
+    let mut loaded_segment =
+        create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    loaded_segment.load().await.unwrap();
+    assert_eq!(loaded_segment.current_offset, 9);
+    assert_eq!(loaded_segment.size_bytes, segment.size_bytes);
+
+    let messages = loaded_segment.get_messages(0, 10).await.unwrap();
+    assert_eq!(messages.len(), 10);
+    for (offset, message) in messages.iter().enumerate() {
+        assert_eq!(message.offset, offset as u64);
+        let payload = if offset < legacy_messages_count as usize {
+            "legacy"
+        } else {
+            "batch"
+        };
+        assert_eq!(message.payload, payload);
+    }
+
+    let messages = loaded_segment.get_messages(3, 3).await.unwrap();
+    let offsets = messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec![3, 4, 5]);
+
+    let messages = loaded_segment.get_messages(6, 3).await.unwrap();
+    let offsets = messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec![6, 7, 8]);
+}
+
+#[tokio::test]
+async fn should_recover_indexes_and_truncate_incomplete_batch_when_persisting_was_interrupted() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    for offsets in [0..3, 3..6, 6..10] {
+        let messages = offsets
+            .map(|offset| {
+                Arc::new(create_message(
+                    offset,
+                    "test",
+                    IggyTimestamp::now().to_micros(),
+                ))
+            })
+            .collect::<Vec<_>>();
+        segment.append_messages(&messages).await.unwrap();
+    }
+    segment.persist_messages().await.unwrap();
+    let log_size = fs::metadata(&segment.log_path).await.unwrap().len();
+
+    // The server has stopped after saving the messages, but before saving all of their indexes,
+    // and in the middle of saving the next batch.
+    let header_size = SEGMENT_HEADER_SIZE as usize;
+    let index = fs::read(&segment.index_path).await.unwrap();
+    fs::write(&segment.index_path, &index[..header_size + 4 * 4])
+        .await
+        .unwrap();
+    let time_index = fs::read(&segment.time_index_path).await.unwrap();
+    fs::write(&segment.time_index_path, &time_index[..header_size + 2 * 8])
+        .await
+        .unwrap();
+    let mut log = fs::read(&segment.log_path).await.unwrap();
+    let incomplete_batch = log[header_size..header_size + 20].to_vec();
+    log.extend_from_slice(&incomplete_batch);
+    fs::write(&segment.log_path, &log).await.unwrap();
+
+    let mut loaded_segment =
+        create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    loaded_segment.load().await.unwrap();
+
+    assert_eq!(loaded_segment.current_offset, 9);
+    assert_eq!(
+        loaded_segment.size_bytes as u64,
+        log_size - SEGMENT_HEADER_SIZE
+    );
+    assert_eq!(
+        fs::metadata(&segment.log_path).await.unwrap().len(),
+        log_size
+    );
+    assert_eq!(fs::read(&segment.index_path).await.unwrap(), index);
+    assert_eq!(
+        fs::read(&segment.time_index_path).await.unwrap(),
+        time_index
+    );
+    let messages = loaded_segment.get_messages(0, 10).await.unwrap();
+    let offsets = messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, (0..10).collect::<Vec<_>>());
+}
+
 #[tokio::test]
 async fn given_all_expired_messages_segment_should_be_expired() {
     let setup = TestSetup::init().await;
@@ -308,6 +512,30 @@ fn create_message(offset: u64, payload: &str, timestamp: u64) -> Message {
     )
 }
 
+fn create_segment(
+    setup: &TestSetup,
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    start_offset: u64,
+) -> segment::Segment {
+    segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    )
+}
+
 fn get_start_offsets() -> Vec<u64> {
     vec![
         0, 1, 2, 9, 10, 99, 100, 110, 200, 1000, 1234, 12345, 100000, 9999999,
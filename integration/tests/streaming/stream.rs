@@ -2,7 +2,9 @@ use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{Acks, Partitioning};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::streams::stream::Stream;
 use tokio::fs;
@@ -49,6 +51,7 @@ async fn should_load_existing_stream_from_disk() {
 
         assert_eq!(loaded_stream.stream_id, stream.stream_id);
         assert_eq!(loaded_stream.name, stream.name);
+        assert_eq!(loaded_stream.uuid, stream.uuid);
         assert_eq!(loaded_stream.path, stream.path);
         assert_eq!(loaded_stream.topics_path, stream.topics_path);
     }
@@ -94,7 +97,19 @@ async fn should_purge_existing_stream_on_disk() {
 
         let topic_id = 1;
         stream
-            .create_topic(Some(topic_id), "test", 1, None, None, 1)
+            .create_topic(
+                Some(topic_id),
+                "test",
+                1,
+                None,
+                None,
+                1,
+                None,
+                false,
+                TimestampType::default(),
+                ConsumerLimits::default(),
+                None,
+            )
             .await
             .unwrap();
 
@@ -104,7 +119,7 @@ async fn should_purge_existing_stream_on_disk() {
             .get_topic(&Identifier::numeric(topic_id).unwrap())
             .unwrap();
         topic
-            .append_messages(&Partitioning::partition_id(1), messages)
+            .append_messages(&Partitioning::partition_id(1), messages, Acks::Leader, None)
             .await
             .unwrap();
         let loaded_messages = topic
@@ -1,10 +1,23 @@
 use crate::streaming::common::test_setup::TestSetup;
+use bytes::Bytes;
+use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::messages::messages_query::MessagesQuery;
+use iggy::messages::send_messages::{Acks, Message, Partitioning};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::models::topic_defaults::TopicDefaults;
+use iggy::models::user_status::UserStatus;
 use server::configs::server::PersonalAccessTokenConfig;
+use server::configs::system::{ConsumerGroupExpiryConfig, LimitsConfig, SystemConfig};
+use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::session::Session;
 use server::streaming::systems::system::System;
 use std::net::{Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use std::time::Duration;
 use tokio::fs;
+use tokio::time::sleep;
 
 #[tokio::test]
 async fn should_initialize_system_and_base_directories() {
@@ -30,6 +43,30 @@ async fn should_initialize_system_and_base_directories() {
     assert!(names.contains(&setup.config.database.path));
 }
 
+#[tokio::test]
+async fn should_write_clean_shutdown_marker_on_shutdown_and_remove_it_on_init() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let marker_path = setup.config.get_clean_shutdown_marker_path();
+    system.init().await.unwrap();
+    assert!(fs::metadata(&marker_path).await.is_err());
+
+    system.shutdown().await.unwrap();
+    assert!(fs::metadata(&marker_path).await.is_ok());
+
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    system.init().await.unwrap();
+    assert!(fs::metadata(&marker_path).await.is_err());
+}
+
 #[tokio::test]
 async fn should_create_and_persist_stream() {
     let setup = TestSetup::init().await;
@@ -44,7 +81,12 @@ async fn should_create_and_persist_stream() {
     system.init().await.unwrap();
 
     system
-        .create_stream(&session, Some(stream_id), stream_name)
+        .create_stream(
+            &session,
+            Some(stream_id),
+            stream_name,
+            TopicDefaults::default(),
+        )
         .await
         .unwrap();
 
@@ -65,7 +107,7 @@ async fn should_create_and_persist_stream_with_automatically_generated_id() {
     system.init().await.unwrap();
 
     system
-        .create_stream(&session, None, stream_name)
+        .create_stream(&session, None, stream_name, TopicDefaults::default())
         .await
         .unwrap();
 
@@ -85,24 +127,328 @@ async fn should_delete_persisted_stream() {
     let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
     system.init().await.unwrap();
     system
-        .create_stream(&session, Some(stream_id), stream_name)
+        .create_stream(
+            &session,
+            Some(stream_id),
+            stream_name,
+            TopicDefaults::default(),
+        )
         .await
         .unwrap();
     assert_persisted_stream(&setup.config.get_streams_path(), stream_id).await;
     let stream_path = system
         .get_stream(&Identifier::numeric(stream_id).unwrap())
+        .await
         .unwrap()
         .path
         .clone();
 
     system
-        .delete_stream(&session, &Identifier::numeric(1).unwrap())
+        .delete_stream(&session, &Identifier::numeric(1).unwrap(), false)
         .await
         .unwrap();
 
     assert!(fs::metadata(stream_path).await.is_err());
 }
 
+#[tokio::test]
+async fn should_not_create_resources_exceeding_configured_limits() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        limits: LimitsConfig {
+            max_streams: 1,
+            max_topics_per_stream: 1,
+            max_partitions_per_topic: 3,
+            max_segments_per_partition: 0,
+            max_scanned_messages_per_query: 0,
+        },
+        ..Default::default()
+    })
+    .await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    system
+        .create_stream(&session, Some(1), "test-1", TopicDefaults::default())
+        .await
+        .unwrap();
+
+    let result = system
+        .create_stream(&session, Some(2), "test-2", TopicDefaults::default())
+        .await;
+    assert!(matches!(result, Err(IggyError::StreamsLimitReached(1))));
+
+    let result = system
+        .create_topic(
+            &session,
+            &stream_id,
+            Some(1),
+            "test-1",
+            4,
+            None,
+            None,
+            1,
+            None,
+            false,
+            TimestampType::default(),
+            ConsumerLimits::default(),
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(IggyError::PartitionsLimitReached(3))));
+
+    system
+        .create_topic(
+            &session,
+            &stream_id,
+            Some(1),
+            "test-1",
+            2,
+            None,
+            None,
+            1,
+            None,
+            false,
+            TimestampType::default(),
+            ConsumerLimits::default(),
+            None,
+        )
+        .await
+        .unwrap();
+    let result = system
+        .create_topic(
+            &session,
+            &stream_id,
+            Some(2),
+            "test-2",
+            1,
+            None,
+            None,
+            1,
+            None,
+            false,
+            TimestampType::default(),
+            ConsumerLimits::default(),
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(IggyError::TopicsLimitReached(1, 1))));
+
+    let result = system
+        .create_partitions(&session, &stream_id, &topic_id, 2, false)
+        .await;
+    assert!(matches!(result, Err(IggyError::PartitionsLimitReached(3))));
+    system
+        .create_partitions(&session, &stream_id, &topic_id, 1, false)
+        .await
+        .unwrap();
+
+    let stream = system.get_stream(&stream_id).await.unwrap();
+    assert_eq!(stream.get_topics_count(), 1);
+    assert_eq!(
+        stream.get_topic(&topic_id).unwrap().get_partitions_count(),
+        3
+    );
+    assert_eq!(system.get_streams().await.len(), 1);
+}
+
+#[tokio::test]
+async fn should_expire_offsets_and_then_inactive_consumer_group() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        consumer_group_expiry: ConsumerGroupExpiryConfig {
+            offsets_retention: "200 ms".parse().unwrap(),
+            group_retention: "400 ms".parse().unwrap(),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let consumer_group_id = 1;
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    system
+        .create_stream(&session, Some(1), "test", TopicDefaults::default())
+        .await
+        .unwrap();
+    system
+        .create_topic(
+            &session,
+            &stream_id,
+            Some(1),
+            "test",
+            2,
+            None,
+            None,
+            1,
+            None,
+            false,
+            TimestampType::default(),
+            ConsumerLimits::default(),
+            None,
+        )
+        .await
+        .unwrap();
+    system
+        .create_consumer_group(&session, &stream_id, &topic_id, consumer_group_id, "test")
+        .await
+        .unwrap();
+    {
+        let stream = system.get_stream(&stream_id).await.unwrap();
+        let topic = stream.get_topic(&topic_id).unwrap();
+        for partition in topic.get_partitions() {
+            let consumer = PollingConsumer::ConsumerGroup(consumer_group_id, 1);
+            let partition = partition.read().await;
+            partition.store_consumer_offset(consumer, 0).await.unwrap();
+        }
+    }
+
+    assert_eq!(system.expire_consumer_groups().await.unwrap(), (0, 0));
+
+    sleep(Duration::from_millis(250)).await;
+    assert_eq!(system.expire_consumer_groups().await.unwrap(), (1, 0));
+    {
+        let stream = system.get_stream(&stream_id).await.unwrap();
+        let topic = stream.get_topic(&topic_id).unwrap();
+        assert!(topic
+            .get_consumer_group_last_commit(consumer_group_id)
+            .await
+            .is_none());
+        assert_eq!(topic.get_consumer_groups().len(), 1);
+    }
+
+    sleep(Duration::from_millis(200)).await;
+    assert_eq!(system.expire_consumer_groups().await.unwrap(), (0, 1));
+    let stream = system.get_stream(&stream_id).await.unwrap();
+    let topic = stream.get_topic(&topic_id).unwrap();
+    assert!(topic.get_consumer_groups().is_empty());
+}
+
+#[tokio::test]
+async fn should_record_user_activity_and_start_inactivity_window_once_set() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    let active_user_id = Identifier::named("active").unwrap();
+    let inactive_user_id = Identifier::named("inactive").unwrap();
+    for username in ["active", "inactive"] {
+        system
+            .create_user(&session, username, "secret", UserStatus::Active, None)
+            .await
+            .unwrap();
+    }
+
+    // The window is counted from the moment it's set, rather than from the user's creation.
+    system
+        .update_user_expiry(&session, &inactive_user_id, None, Some(1))
+        .await
+        .unwrap();
+    let user = system.get_user(&inactive_user_id).await.unwrap();
+    assert!(user.last_active_at.is_some());
+    assert!(user.last_active_at.unwrap() >= user.created_at);
+
+    let user = system.get_user(&active_user_id).await.unwrap();
+    assert!(user.last_active_at.is_none());
+    system.record_user_activity(user.id).await.unwrap();
+    let last_active_at = system
+        .get_user(&active_user_id)
+        .await
+        .unwrap()
+        .last_active_at;
+    assert!(last_active_at.is_some());
+
+    // The activity isn't saved again within the interval.
+    system.record_user_activity(user.id).await.unwrap();
+    let user = system.get_user(&active_user_id).await.unwrap();
+    assert_eq!(user.last_active_at, last_active_at);
+}
+
+#[tokio::test]
+async fn should_stop_query_at_scanned_messages_limit_and_continue_from_next_offset() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        limits: LimitsConfig {
+            max_scanned_messages_per_query: 10,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
+    system.init().await.unwrap();
+    system
+        .create_stream(&session, Some(1), "test", TopicDefaults::default())
+        .await
+        .unwrap();
+    system
+        .create_topic(
+            &session,
+            &stream_id,
+            Some(1),
+            "test",
+            1,
+            None,
+            None,
+            1,
+            None,
+            false,
+            TimestampType::default(),
+            ConsumerLimits::default(),
+            None,
+        )
+        .await
+        .unwrap();
+    let messages = (0..25)
+        .map(|index| Message::new(None, Bytes::from(format!("message-{index}")), None))
+        .collect::<Vec<_>>();
+    system
+        .append_messages(
+            &session,
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            &messages,
+            Acks::Leader,
+        )
+        .await
+        .unwrap();
+
+    let mut start_offset = 0;
+    for (expected_rows, expected_next_offset) in [(10, Some(10)), (10, Some(20)), (5, None)] {
+        let query = MessagesQuery::from_str(&format!(
+            "SELECT offset FROM 1.1.1 WHERE offset >= {start_offset}"
+        ))
+        .unwrap();
+        let result = system.query_messages(&session, &query).await.unwrap();
+        assert_eq!(result.rows.len(), expected_rows);
+        assert_eq!(result.rows[0][0], start_offset);
+        assert_eq!(result.next_offset, expected_next_offset);
+        start_offset = result.next_offset.unwrap_or_default();
+    }
+}
+
 async fn assert_persisted_stream(streams_path: &str, stream_id: u32) {
     let streams_metadata = fs::metadata(streams_path).await.unwrap();
     assert!(streams_metadata.is_dir());
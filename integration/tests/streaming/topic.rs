@@ -3,9 +3,11 @@ use std::sync::Arc;
 
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
+use bytes::Bytes;
 use iggy::messages::poll_messages::PollingStrategy;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{Acks, Message, Partitioning};
 use server::streaming::polling_consumer::PollingConsumer;
+use server::streaming::topics::message_schema::MessageSchema;
 use server::streaming::topics::topic::Topic;
 use tokio::fs;
 
@@ -86,11 +88,50 @@ async fn should_load_existing_topic_from_disk() {
         assert_eq!(loaded_topic.stream_id, topic.stream_id);
         assert_eq!(loaded_topic.topic_id, topic.topic_id);
         assert_eq!(loaded_topic.name, topic.name);
+        assert_eq!(loaded_topic.uuid, topic.uuid);
         assert_eq!(loaded_topic.path, topic.path);
         assert_eq!(loaded_topic.get_partitions().len() as u32, partitions_count);
     }
 }
 
+#[tokio::test]
+async fn should_load_existing_topic_with_message_schema_from_disk() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let message_schema = r#"{"type": "object", "required": ["amount"]}"#;
+    setup.create_topics_directory(stream_id).await;
+    let mut topic = Topic::create(
+        stream_id,
+        topic_id,
+        "test",
+        1,
+        setup.config.clone(),
+        setup.storage.clone(),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        None,
+        None,
+        1,
+    )
+    .unwrap();
+    topic.message_schema = Some(MessageSchema::compile(message_schema).unwrap());
+    topic.persist().await.unwrap();
+
+    let mut loaded_topic = Topic::empty(
+        stream_id,
+        topic_id,
+        setup.config.clone(),
+        setup.storage.clone(),
+    );
+    loaded_topic.load().await.unwrap();
+
+    let loaded_message_schema = loaded_topic.message_schema.unwrap();
+    assert_eq!(loaded_message_schema.as_str(), message_schema);
+    let invalid_messages = vec![Message::new(Some(1), Bytes::from(r#"{"id": 1}"#), None)];
+    assert!(loaded_message_schema.validate(&invalid_messages).is_err());
+}
+
 #[tokio::test]
 async fn should_delete_existing_topic_from_disk() {
     let setup = TestSetup::init().await;
@@ -162,7 +203,7 @@ async fn should_purge_existing_topic_on_disk() {
         let messages = create_messages();
         let messages_count = messages.len();
         topic
-            .append_messages(&Partitioning::partition_id(1), messages)
+            .append_messages(&Partitioning::partition_id(1), messages, Acks::Leader, None)
             .await
             .unwrap();
         let loaded_messages = topic
@@ -1,7 +1,7 @@
 use crate::streaming::common::test_setup::TestSetup;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{Acks, Partitioning};
 use iggy::models::messages::Message;
 use iggy::utils::byte_size::IggyByteSize;
 use server::configs::resource_quota::MemoryResourceQuota;
@@ -71,7 +71,7 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
         sent_messages.push(get_message(from_utf8(&message.payload).unwrap()))
     }
     topic
-        .append_messages(&partitioning, messages)
+        .append_messages(&partitioning, messages, Acks::Leader, None)
         .await
         .unwrap();
 
@@ -111,7 +111,12 @@ async fn given_key_none_messages_should_be_appended_to_the_next_partition_using_
     for i in 1..=partitions_count * messages_per_partition_count {
         let payload = get_payload(i);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(
+                &partitioning,
+                vec![get_message(&payload)],
+                Acks::Leader,
+                None,
+            )
             .await
             .unwrap();
     }
@@ -131,7 +136,12 @@ async fn given_key_partition_id_messages_should_be_appended_to_the_chosen_partit
     for i in 1..=partitions_count * messages_per_partition_count {
         let payload = get_payload(i);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(
+                &partitioning,
+                vec![get_message(&payload)],
+                Acks::Leader,
+                None,
+            )
             .await
             .unwrap();
     }
@@ -155,7 +165,12 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
         let payload = get_payload(entity_id);
         let partitioning = Partitioning::messages_key_u32(entity_id);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(
+                &partitioning,
+                vec![get_message(&payload)],
+                Acks::Leader,
+                None,
+            )
             .await
             .unwrap();
     }
@@ -6,6 +6,7 @@ use iggy::models::user_status::UserStatus;
 use iggy::utils::timestamp::IggyTimestamp;
 use server::streaming::users::user::User;
 use std::collections::HashMap;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn single_user_should_be_saved_and_loaded() {
@@ -64,10 +65,14 @@ async fn user_should_be_deleted() {
 
 fn assert_user(user: &User, loaded_user: &User) {
     assert_eq!(loaded_user.id, user.id);
+    assert_eq!(loaded_user.uuid, user.uuid);
     assert_eq!(loaded_user.username, user.username);
     assert_eq!(loaded_user.password, user.password);
     assert_eq!(loaded_user.created_at, user.created_at);
     assert_eq!(loaded_user.status, user.status);
+    assert_eq!(loaded_user.expires_at, user.expires_at);
+    assert_eq!(loaded_user.max_inactive_days, user.max_inactive_days);
+    assert_eq!(loaded_user.last_active_at, user.last_active_at);
     if user.permissions.is_none() {
         assert!(loaded_user.permissions.is_none());
         return;
@@ -156,6 +161,7 @@ fn assert_user(user: &User, loaded_user: &User) {
 fn create_user(id: u32) -> User {
     User {
         id,
+        uuid: Uuid::new_v4(),
         username: format!("user{}", id),
         password: "secret".to_string(),
         created_at: IggyTimestamp::now().to_micros(),
@@ -172,6 +178,7 @@ fn create_user(id: u32) -> User {
                 poll_messages: false,
                 send_messages: false,
                 read_topics: true,
+                auto_create_topics: false,
             },
             streams: Some({
                 let mut map = HashMap::new();
@@ -197,10 +204,18 @@ fn create_user(id: u32) -> User {
                             );
                             map
                         }),
+                        auto_create_topics: false,
+                        manage_partitions: false,
+                        manage_consumer_groups: false,
                     },
                 );
                 map
             }),
         }),
+        tenant_id: None,
+        session_epoch: 0,
+        expires_at: None,
+        max_inactive_days: Some(90),
+        last_active_at: None,
     }
 }
@@ -0,0 +1,90 @@
+from types import TracebackType
+from typing import Awaitable, List, Optional, Sequence, Type, Union
+
+__version__: str
+
+class IggyError(Exception):
+    """The error returned by the client or the server, the arguments are the numeric code (0 for the client errors) and the message."""
+
+class ReceivedMessage:
+    offset: int
+    timestamp: int
+    id: int
+    checksum: int
+    payload: bytes
+
+class Producer:
+    @staticmethod
+    def connect(
+        connection_string: str, stream: str, topic: str, partition_id: Optional[int] = None
+    ) -> "Producer": ...
+    def send(self, messages: Sequence[Union[bytes, str]]) -> None: ...
+    def close(self) -> None: ...
+    def __enter__(self) -> "Producer": ...
+    def __exit__(
+        self,
+        exc_type: Optional[Type[BaseException]],
+        exc: Optional[BaseException],
+        traceback: Optional[TracebackType],
+    ) -> bool: ...
+
+class AsyncProducer:
+    @staticmethod
+    def connect(
+        connection_string: str, stream: str, topic: str, partition_id: Optional[int] = None
+    ) -> Awaitable["AsyncProducer"]: ...
+    def send(self, messages: Sequence[Union[bytes, str]]) -> Awaitable[None]: ...
+    def close(self) -> Awaitable[None]: ...
+    def __aenter__(self) -> Awaitable["AsyncProducer"]: ...
+    def __aexit__(
+        self,
+        exc_type: Optional[Type[BaseException]],
+        exc: Optional[BaseException],
+        traceback: Optional[TracebackType],
+    ) -> Awaitable[bool]: ...
+
+class Consumer:
+    @staticmethod
+    def connect(
+        connection_string: str,
+        stream: str,
+        topic: str,
+        consumer: str,
+        group: bool = False,
+        partition_id: Optional[int] = None,
+        batch_size: int = 100,
+        auto_commit: bool = True,
+    ) -> "Consumer": ...
+    def poll(self, count: Optional[int] = None) -> List[ReceivedMessage]: ...
+    def store_offset(self, offset: int, partition_id: Optional[int] = None) -> None: ...
+    def close(self) -> None: ...
+    def __enter__(self) -> "Consumer": ...
+    def __exit__(
+        self,
+        exc_type: Optional[Type[BaseException]],
+        exc: Optional[BaseException],
+        traceback: Optional[TracebackType],
+    ) -> bool: ...
+
+class AsyncConsumer:
+    @staticmethod
+    def connect(
+        connection_string: str,
+        stream: str,
+        topic: str,
+        consumer: str,
+        group: bool = False,
+        partition_id: Optional[int] = None,
+        batch_size: int = 100,
+        auto_commit: bool = True,
+    ) -> Awaitable["AsyncConsumer"]: ...
+    def poll(self, count: Optional[int] = None) -> Awaitable[List[ReceivedMessage]]: ...
+    def store_offset(self, offset: int, partition_id: Optional[int] = None) -> Awaitable[None]: ...
+    def close(self) -> Awaitable[None]: ...
+    def __aenter__(self) -> Awaitable["AsyncConsumer"]: ...
+    def __aexit__(
+        self,
+        exc_type: Optional[Type[BaseException]],
+        exc: Optional[BaseException],
+        traceback: Optional[TracebackType],
+    ) -> Awaitable[bool]: ...
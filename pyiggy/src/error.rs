@@ -0,0 +1,22 @@
+use iggy::client_error::ClientError;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::PyErr;
+
+create_exception!(
+    pyiggy,
+    IggyError,
+    PyException,
+    "The error returned by the client or the server, the arguments are the numeric code (0 for the client errors) and the message."
+);
+
+pub(crate) fn to_py_err(error: iggy::error::IggyError) -> PyErr {
+    IggyError::new_err((error.as_code(), error.to_string()))
+}
+
+pub(crate) fn client_to_py_err(error: ClientError) -> PyErr {
+    match error {
+        ClientError::SdkError(error) => to_py_err(error),
+        error => IggyError::new_err((0, error.to_string())),
+    }
+}
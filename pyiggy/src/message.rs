@@ -0,0 +1,48 @@
+use bytes::Bytes;
+use iggy::models::messages::Message;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+/// The message polled by the consumer.
+#[pyclass(frozen, module = "pyiggy")]
+pub struct ReceivedMessage {
+    #[pyo3(get)]
+    offset: u64,
+    #[pyo3(get)]
+    timestamp: u64,
+    #[pyo3(get)]
+    id: u128,
+    #[pyo3(get)]
+    checksum: u32,
+    payload: Bytes,
+}
+
+#[pymethods]
+impl ReceivedMessage {
+    #[getter]
+    fn payload<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.payload)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ReceivedMessage(offset={}, timestamp={}, id={}, payload_length={})",
+            self.offset,
+            self.timestamp,
+            self.id,
+            self.payload.len()
+        )
+    }
+}
+
+impl From<Message> for ReceivedMessage {
+    fn from(message: Message) -> Self {
+        Self {
+            offset: message.offset,
+            timestamp: message.timestamp,
+            id: message.id,
+            checksum: message.checksum,
+            payload: message.payload,
+        }
+    }
+}
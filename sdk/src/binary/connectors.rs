@@ -0,0 +1,55 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::client::ConnectorClient;
+use crate::command::*;
+use crate::connectors::create_connector::CreateConnector;
+use crate::connectors::delete_connector::DeleteConnector;
+use crate::connectors::get_connector::GetConnector;
+use crate::connectors::get_connectors::GetConnectors;
+use crate::connectors::restart_connector::RestartConnector;
+use crate::error::IggyError;
+use crate::models::connector::ConnectorInfo;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> ConnectorClient for B {
+    async fn get_connector(&self, command: &GetConnector) -> Result<ConnectorInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_CONNECTOR_CODE, command.as_bytes())
+            .await?;
+        mapper::map_connector(response)
+    }
+
+    async fn get_connectors(
+        &self,
+        command: &GetConnectors,
+    ) -> Result<Vec<ConnectorInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_CONNECTORS_CODE, command.as_bytes())
+            .await?;
+        mapper::map_connectors(response)
+    }
+
+    async fn create_connector(&self, command: &CreateConnector) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(CREATE_CONNECTOR_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn restart_connector(&self, command: &RestartConnector) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(RESTART_CONNECTOR_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_connector(&self, command: &DeleteConnector) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(DELETE_CONNECTOR_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+}
@@ -1,22 +1,36 @@
 use crate::bytes_serializable::BytesSerializable;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
+use crate::models::batch_result::BatchCommandResult;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
+use crate::models::connector::{ConnectorInfo, ConnectorStatus};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::fetch_session::{FetchSession, FetchedMessages, FetchedPartition};
+use crate::models::hello_info::HelloInfo;
 use crate::models::identity_info::IdentityInfo;
-use crate::models::messages::{Message, MessageState, PolledMessages};
-use crate::models::partition::Partition;
+use crate::models::messages::{Message, MessageState, PolledMessages, SendMessagesReceipt};
+use crate::models::metadata::{Metadata, StreamMetadata, TopicMetadata};
+use crate::models::partition::{Partition, PartitionDetails, PartitionEndOffset, Segment};
+use crate::models::permission_check::PermissionCheck;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
-use crate::models::stats::Stats;
+use crate::models::query_result::QueryResult;
+use crate::models::schema::{SchemaFormat, SchemaInfo};
+use crate::models::stats::{RuntimeStats, Stats};
 use crate::models::stream::{Stream, StreamDetails};
+use crate::models::tenant::{Tenant, TenantQuotas};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::user_group::{UserGroup, UserGroupDetails};
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
+use crate::streams::{read_topic_defaults, TOPIC_DEFAULTS_SIZE};
+use crate::topics::{read_consumer_limits, read_message_schema, CONSUMER_LIMITS_SIZE};
 use crate::utils::byte_size::IggyByteSize;
 use bytes::Bytes;
 use std::collections::HashMap;
 use std::str::from_utf8;
+use uuid::Uuid;
 
 const EMPTY_MESSAGES: Vec<Message> = vec![];
 const EMPTY_TOPICS: Vec<Topic> = vec![];
@@ -25,6 +39,10 @@ const EMPTY_CLIENTS: Vec<ClientInfo> = vec![];
 const EMPTY_USERS: Vec<UserInfo> = vec![];
 const EMPTY_PERSONAL_ACCESS_TOKENS: Vec<PersonalAccessTokenInfo> = vec![];
 const EMPTY_CONSUMER_GROUPS: Vec<ConsumerGroup> = vec![];
+const EMPTY_SCHEMAS: Vec<SchemaInfo> = vec![];
+const EMPTY_TENANTS: Vec<Tenant> = vec![];
+const EMPTY_USER_GROUPS: Vec<UserGroup> = vec![];
+const EMPTY_CONNECTORS: Vec<ConnectorInfo> = vec![];
 
 pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
     let process_id = u32::from_le_bytes(payload[..4].try_into()?);
@@ -67,6 +85,60 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
     let kernel_version =
         from_utf8(&payload[current_position + 4..current_position + 4 + kernel_version_length])?
             .to_string();
+    current_position += 4 + kernel_version_length;
+    // The memory budget usage is not available when connected to the older server.
+    let mut memory_budget_usage = [0u64; 4];
+    if payload.len() >= current_position + 32 {
+        for usage in memory_budget_usage.iter_mut() {
+            *usage =
+                u64::from_le_bytes(payload[current_position..current_position + 8].try_into()?);
+            current_position += 8;
+        }
+    }
+
+    // The runtimes stats are not available when connected to the older server.
+    let mut runtimes = Vec::new();
+    if payload.len() >= current_position + 4 {
+        let runtimes_count =
+            u32::from_le_bytes(payload[current_position..current_position + 4].try_into()?);
+        current_position += 4;
+        for _ in 0..runtimes_count {
+            let name_length = payload[current_position] as usize;
+            let name =
+                from_utf8(&payload[current_position + 1..current_position + 1 + name_length])?
+                    .to_string();
+            current_position += 1 + name_length;
+            let workers_count =
+                u32::from_le_bytes(payload[current_position..current_position + 4].try_into()?);
+            let alive_tasks_count = u64::from_le_bytes(
+                payload[current_position + 4..current_position + 12].try_into()?,
+            );
+            let global_queue_depth = u64::from_le_bytes(
+                payload[current_position + 12..current_position + 20].try_into()?,
+            );
+            let busy_duration = u64::from_le_bytes(
+                payload[current_position + 20..current_position + 28].try_into()?,
+            );
+            current_position += 28;
+            runtimes.push(RuntimeStats {
+                name,
+                workers_count,
+                alive_tasks_count,
+                global_queue_depth,
+                busy_duration,
+            });
+        }
+    }
+
+    // The disk space is not available when connected to the older server.
+    let mut disk_space = [0u64; 2];
+    if payload.len() >= current_position + 16 {
+        for space in disk_space.iter_mut() {
+            *space =
+                u64::from_le_bytes(payload[current_position..current_position + 8].try_into()?);
+            current_position += 8;
+        }
+    }
 
     Ok(Stats {
         process_id,
@@ -90,6 +162,66 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
         os_name,
         os_version,
         kernel_version,
+        unsaved_messages_size: memory_budget_usage[0].into(),
+        cached_messages_size: memory_budget_usage[1].into(),
+        cached_indexes_size: memory_budget_usage[2].into(),
+        memory_budget: memory_budget_usage[3].into(),
+        runtimes,
+        disk_free_space: disk_space[0].into(),
+        disk_total_space: disk_space[1].into(),
+    })
+}
+
+pub fn map_metadata(payload: Bytes) -> Result<Metadata, IggyError> {
+    let mut streams = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let topics_count = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+        let (name, read_bytes) = read_name(&payload, position + 8)?;
+        position += 8 + read_bytes;
+        let mut topics = Vec::with_capacity(topics_count as usize);
+        for _ in 0..topics_count {
+            let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+            let partitions_count =
+                u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+            let (name, read_bytes) = read_name(&payload, position + 8)?;
+            position += 8 + read_bytes;
+            topics.push(TopicMetadata {
+                id,
+                name,
+                partitions_count,
+            });
+        }
+        topics.sort_by_key(|x| x.id);
+        streams.push(StreamMetadata { id, name, topics });
+    }
+    streams.sort_by_key(|x| x.id);
+    Ok(Metadata { streams })
+}
+
+fn read_name(payload: &Bytes, position: usize) -> Result<(String, usize), IggyError> {
+    let name_length = payload[position] as usize;
+    let name = from_utf8(&payload[position + 1..position + 1 + name_length])?.to_string();
+    Ok((name, 1 + name_length))
+}
+
+pub fn map_send_messages_receipt(payload: Bytes) -> Result<SendMessagesReceipt, IggyError> {
+    // The older server responds with the empty payload.
+    if payload.is_empty() {
+        return Err(IggyError::FeatureUnavailable);
+    }
+
+    let partition_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let base_offset = u64::from_le_bytes(payload[4..12].try_into()?);
+    let messages_count = u32::from_le_bytes(payload[12..16].try_into()?);
+    let timestamp = u64::from_le_bytes(payload[16..24].try_into()?);
+    Ok(SendMessagesReceipt {
+        partition_id,
+        base_offset,
+        messages_count,
+        timestamp,
     })
 }
 
@@ -105,23 +237,41 @@ pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyErr
 }
 
 pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
-    let (user, position) = map_to_user_info(payload.clone(), 0)?;
+    let (user, mut position) = map_to_user_info(payload.clone(), 0)?;
     let has_permissions = payload[position];
+    position += 1;
     let permissions = if has_permissions == 1 {
         let permissions_length =
-            u32::from_le_bytes(payload[position + 1..position + 5].try_into()?) as usize;
-        let permissions = payload.slice(position + 5..position + 5 + permissions_length);
+            u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+        let permissions = payload.slice(position + 4..position + 4 + permissions_length);
+        position += 4 + permissions_length;
         Some(Permissions::from_bytes(permissions)?)
     } else {
         None
     };
+    let expires_at = u64::from_le_bytes(payload[position..position + 8].try_into()?);
+    let max_inactive_days = u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
+    let last_active_at = u64::from_le_bytes(payload[position + 12..position + 20].try_into()?);
 
     let user = UserInfoDetails {
         id: user.id,
+        uuid: user.uuid,
         created_at: user.created_at,
         status: user.status,
         username: user.username,
         permissions,
+        expires_at: match expires_at {
+            0 => None,
+            expires_at => Some(expires_at),
+        },
+        max_inactive_days: match max_inactive_days {
+            0 => None,
+            max_inactive_days => Some(max_inactive_days),
+        },
+        last_active_at: match last_active_at {
+            0 => None,
+            last_active_at => Some(last_active_at),
+        },
     };
     Ok(user)
 }
@@ -170,12 +320,275 @@ pub fn map_identity_info(payload: Bytes) -> Result<IdentityInfo, IggyError> {
     })
 }
 
+pub fn map_permission_check(payload: Bytes) -> Result<PermissionCheck, IggyError> {
+    let allowed = payload[0] == 1;
+    let has_group = payload[1];
+    let group_id = u32::from_le_bytes(payload[2..6].try_into()?);
+    let rule_length = u32::from_le_bytes(payload[6..10].try_into()?) as usize;
+    let rule = from_utf8(&payload[10..10 + rule_length])?.to_string();
+    Ok(PermissionCheck {
+        allowed,
+        rule,
+        group_id: if has_group == 1 { Some(group_id) } else { None },
+    })
+}
+
+pub fn map_compression_algorithm(payload: Bytes) -> Result<CompressionAlgorithm, IggyError> {
+    let code = u32::from_le_bytes(payload[..4].try_into()?);
+    CompressionAlgorithm::from_code(code as u8)
+}
+
+pub fn map_hello_info(payload: Bytes) -> Result<HelloInfo, IggyError> {
+    let protocol_version = u32::from_le_bytes(payload[..4].try_into()?);
+    let server_version_length = payload[4] as usize;
+    let mut position = 5 + server_version_length;
+    let server_version = from_utf8(&payload[5..position])?.to_string();
+    let mut features = Vec::new();
+    while position < payload.len() {
+        let feature_length = payload[position] as usize;
+        position += 1;
+        features.push(from_utf8(&payload[position..position + feature_length])?.to_string());
+        position += feature_length;
+    }
+
+    Ok(HelloInfo {
+        protocol_version,
+        server_version,
+        features,
+    })
+}
+
+pub fn map_batch_results(payload: Bytes) -> Result<Vec<BatchCommandResult>, IggyError> {
+    let count = u32::from_le_bytes(payload[..4].try_into()?) as usize;
+    let mut results = Vec::with_capacity(count);
+    let mut position = 4;
+    for _ in 0..count {
+        let status = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let length = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?) as usize;
+        position += 8;
+        let result_payload = payload.slice(position..position + length);
+        position += length;
+        results.push(BatchCommandResult {
+            status,
+            payload: result_payload,
+        });
+    }
+
+    Ok(results)
+}
+
 pub fn map_raw_pat(payload: Bytes) -> Result<RawPersonalAccessToken, IggyError> {
     let token_length = payload[0];
     let token = from_utf8(&payload[1..1 + token_length as usize])?.to_string();
     Ok(RawPersonalAccessToken { token })
 }
 
+pub fn map_schema(payload: Bytes) -> Result<SchemaInfo, IggyError> {
+    let (schema, _) = map_to_schema_info(payload, 0)?;
+    Ok(schema)
+}
+
+pub fn map_schemas(payload: Bytes) -> Result<Vec<SchemaInfo>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_SCHEMAS);
+    }
+
+    let mut schemas = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (schema, read_bytes) = map_to_schema_info(payload.clone(), position)?;
+        schemas.push(schema);
+        position += read_bytes;
+    }
+    schemas.sort_by_key(|x| x.version);
+    Ok(schemas)
+}
+
+pub fn map_tenant(payload: Bytes) -> Result<Tenant, IggyError> {
+    let (tenant, _) = map_to_tenant(payload, 0)?;
+    Ok(tenant)
+}
+
+pub fn map_tenants(payload: Bytes) -> Result<Vec<Tenant>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_TENANTS);
+    }
+
+    let mut tenants = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (tenant, read_bytes) = map_to_tenant(payload.clone(), position)?;
+        tenants.push(tenant);
+        position += read_bytes;
+    }
+    tenants.sort_by_key(|tenant| tenant.id);
+    Ok(tenants)
+}
+
+pub fn map_user_group(payload: Bytes) -> Result<UserGroupDetails, IggyError> {
+    let (group, mut position) = map_to_user_group(payload.clone(), 0)?;
+    let mut members = Vec::with_capacity(group.members_count as usize);
+    for _ in 0..group.members_count {
+        members.push(u32::from_le_bytes(
+            payload[position..position + 4].try_into()?,
+        ));
+        position += 4;
+    }
+
+    let has_permissions = payload[position];
+    let permissions = if has_permissions == 1 {
+        let permissions_length =
+            u32::from_le_bytes(payload[position + 1..position + 5].try_into()?) as usize;
+        let permissions = payload.slice(position + 5..position + 5 + permissions_length);
+        Some(Permissions::from_bytes(permissions)?)
+    } else {
+        None
+    };
+
+    Ok(UserGroupDetails {
+        id: group.id,
+        created_at: group.created_at,
+        name: group.name,
+        members,
+        permissions,
+    })
+}
+
+pub fn map_user_groups(payload: Bytes) -> Result<Vec<UserGroup>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_USER_GROUPS);
+    }
+
+    let mut groups = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (group, read_bytes) = map_to_user_group(payload.clone(), position)?;
+        groups.push(group);
+        position += read_bytes;
+    }
+    groups.sort_by_key(|group| group.id);
+    Ok(groups)
+}
+
+pub fn map_connector(payload: Bytes) -> Result<ConnectorInfo, IggyError> {
+    let (connector, _) = map_to_connector(payload, 0)?;
+    Ok(connector)
+}
+
+pub fn map_connectors(payload: Bytes) -> Result<Vec<ConnectorInfo>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_CONNECTORS);
+    }
+
+    let mut connectors = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (connector, read_bytes) = map_to_connector(payload.clone(), position)?;
+        connectors.push(connector);
+        position += read_bytes;
+    }
+    connectors.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(connectors)
+}
+
+pub fn map_fetch_session(payload: Bytes) -> Result<FetchSession, IggyError> {
+    if payload.len() != 8 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let session_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let epoch = u32::from_le_bytes(payload[4..8].try_into()?);
+    Ok(FetchSession { session_id, epoch })
+}
+
+pub fn map_fetched_messages(payload: Bytes) -> Result<FetchedMessages, IggyError> {
+    if payload.len() < 8 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let session_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let epoch = u32::from_le_bytes(payload[4..8].try_into()?);
+    let length = payload.len();
+    let mut position = 8;
+    let mut partitions = Vec::new();
+    while position < length {
+        if position + 12 > length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let stream_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let topic_id = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+        let messages_length =
+            u32::from_le_bytes(payload[position + 8..position + 12].try_into()?) as usize;
+        position += 12;
+        if position + messages_length > length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let messages = map_polled_messages(payload.slice(position..position + messages_length))?;
+        position += messages_length;
+        partitions.push(FetchedPartition {
+            stream_id,
+            topic_id,
+            messages,
+        });
+    }
+
+    Ok(FetchedMessages {
+        session_id,
+        epoch,
+        partitions,
+    })
+}
+
+pub fn map_query_result(payload: Bytes) -> Result<QueryResult, IggyError> {
+    let columns_count = u32::from_le_bytes(payload[..4].try_into()?);
+    let mut position = 4;
+    let mut columns = Vec::with_capacity(columns_count as usize);
+    for _ in 0..columns_count {
+        let column_length =
+            u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+        let column = from_utf8(&payload[position + 4..position + 4 + column_length])?;
+        columns.push(column.to_string());
+        position += 4 + column_length;
+    }
+
+    let rows_count = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    position += 4;
+    let mut rows = Vec::with_capacity(rows_count as usize);
+    for _ in 0..rows_count {
+        let mut row = Vec::with_capacity(columns.len());
+        for _ in 0..columns.len() {
+            let value_length =
+                u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+            let value = serde_json::from_slice(&payload[position + 4..position + 4 + value_length])
+                .map_err(|error| IggyError::CannotDeserializeResource(error.into()))?;
+            row.push(value);
+            position += 4 + value_length;
+        }
+        rows.push(row);
+    }
+
+    // The scan is never stopped before the first message, so the offset 0 means the scan wasn't stopped.
+    let next_offset = match payload.get(position..position + 8) {
+        Some(next_offset) => match u64::from_le_bytes(next_offset.try_into()?) {
+            0 => None,
+            next_offset => Some(next_offset),
+        },
+        None => None,
+    };
+
+    Ok(QueryResult {
+        columns,
+        rows,
+        next_offset,
+    })
+}
+
 pub fn map_client(payload: Bytes) -> Result<ClientInfoDetails, IggyError> {
     let (client, mut position) = map_to_client_info(payload.clone(), 0)?;
     let mut consumer_groups = Vec::new();
@@ -231,14 +644,14 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
             messages: EMPTY_MESSAGES,
             partition_id: 0,
             current_offset: 0,
+            next_offset: None,
         });
     }
 
     let length = payload.len();
     let partition_id = u32::from_le_bytes(payload[..4].try_into()?);
     let current_offset = u64::from_le_bytes(payload[4..12].try_into()?);
-    // Currently ignored
-    let _messages_count = u32::from_le_bytes(payload[12..16].try_into()?);
+    let messages_count = u32::from_le_bytes(payload[12..16].try_into()?);
     let mut position = 16;
     let mut messages = Vec::new();
     while position < length {
@@ -281,11 +694,20 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
         }
     }
 
-    messages.sort_by(|x, y| x.offset.cmp(&y.offset));
+    // The next offset follows the messages only if they have been truncated to the max bytes of the poll.
+    let next_offset = match length - position {
+        8 if messages.len() == messages_count as usize => {
+            Some(u64::from_le_bytes(payload[position..].try_into()?))
+        }
+        _ => None,
+    };
+
+    // The messages are returned in the order chosen by the server, e.g. descending when peeking backward.
     Ok(PolledMessages {
         partition_id,
         current_offset,
         messages,
+        next_offset,
     })
 }
 
@@ -319,11 +741,13 @@ pub fn map_stream(payload: Bytes) -> Result<StreamDetails, IggyError> {
     topics.sort_by(|x, y| x.id.cmp(&y.id));
     let stream = StreamDetails {
         id: stream.id,
+        uuid: stream.uuid,
         created_at: stream.created_at,
         topics_count: stream.topics_count,
         size_bytes: stream.size_bytes,
         messages_count: stream.messages_count,
         name: stream.name,
+        topic_defaults: stream.topic_defaults,
         topics,
     };
     Ok(stream)
@@ -335,18 +759,26 @@ fn map_to_stream(payload: Bytes, position: usize) -> Result<(Stream, usize), Igg
     let topics_count = u32::from_le_bytes(payload[position + 12..position + 16].try_into()?);
     let size_bytes = u64::from_le_bytes(payload[position + 16..position + 24].try_into()?).into();
     let messages_count = u64::from_le_bytes(payload[position + 24..position + 32].try_into()?);
-    let name_length = payload[position + 32];
+    let topic_defaults =
+        read_topic_defaults(&payload, position + 32)?.ok_or(IggyError::InvalidCommand)?;
+    let uuid_position = position + 32 + TOPIC_DEFAULTS_SIZE;
+    let uuid = Uuid::from_slice(&payload[uuid_position..uuid_position + 16])
+        .map_err(|_| IggyError::InvalidCommand)?;
+    let name_length = payload[uuid_position + 16];
+    let name_position = uuid_position + 17;
     let name =
-        from_utf8(&payload[position + 33..position + 33 + name_length as usize])?.to_string();
-    let read_bytes = 4 + 8 + 4 + 8 + 8 + 1 + name_length as usize;
+        from_utf8(&payload[name_position..name_position + name_length as usize])?.to_string();
+    let read_bytes = 4 + 8 + 4 + 8 + 8 + TOPIC_DEFAULTS_SIZE + 16 + 1 + name_length as usize;
     Ok((
         Stream {
             id,
+            uuid,
             created_at,
             name,
             size_bytes,
             messages_count,
             topics_count,
+            topic_defaults,
         },
         read_bytes,
     ))
@@ -371,6 +803,10 @@ pub fn map_topics(payload: Bytes) -> Result<Vec<Topic>, IggyError> {
 
 pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
     let (topic, mut position) = map_to_topic(payload.clone(), 0)?;
+    let message_schema = read_message_schema(&payload, position)?;
+    position += 4 + message_schema
+        .as_ref()
+        .map_or(0, |message_schema| message_schema.len());
     let mut partitions = Vec::new();
     let length = payload.len();
     while position < length {
@@ -382,6 +818,7 @@ pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
     partitions.sort_by(|x, y| x.id.cmp(&y.id));
     let topic = TopicDetails {
         id: topic.id,
+        uuid: topic.uuid,
         created_at: topic.created_at,
         name: topic.name,
         size: topic.size,
@@ -391,6 +828,12 @@ pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
         replication_factor: topic.replication_factor,
         #[allow(clippy::cast_possible_truncation)]
         partitions_count: partitions.len() as u32,
+        routing_epoch: topic.routing_epoch,
+        compression_algorithm: topic.compression_algorithm,
+        frozen: topic.frozen,
+        consumer_limits: topic.consumer_limits,
+        max_message_size: topic.max_message_size,
+        message_schema,
         partitions,
     };
     Ok(topic)
@@ -415,13 +858,40 @@ fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyE
         payload[position + 29..position + 37].try_into()?,
     ));
     let messages_count = u64::from_le_bytes(payload[position + 37..position + 45].try_into()?);
-    let name_length = payload[position + 45];
+    let routing_epoch = u32::from_le_bytes(payload[position + 45..position + 49].try_into()?);
+    let compression_algorithm = CompressionAlgorithm::from_code(payload[position + 49])?;
+    let frozen = payload[position + 50] == 1;
+    let consumer_limits = read_consumer_limits(&payload, position + 51)?;
+    let max_message_size =
+        match u64::from_le_bytes(payload[position + 59..position + 67].try_into()?) {
+            0 => None,
+            max_message_size => Some(IggyByteSize::from(max_message_size)),
+        };
+    let uuid = Uuid::from_slice(&payload[position + 67..position + 83])
+        .map_err(|_| IggyError::InvalidCommand)?;
+    let name_length = payload[position + 83];
     let name =
-        from_utf8(&payload[position + 46..position + 46 + name_length as usize])?.to_string();
-    let read_bytes = 4 + 8 + 4 + 4 + 8 + 8 + 8 + 1 + 1 + name_length as usize;
+        from_utf8(&payload[position + 84..position + 84 + name_length as usize])?.to_string();
+    let read_bytes = 4
+        + 8
+        + 4
+        + 4
+        + 8
+        + 8
+        + 8
+        + 4
+        + 1
+        + 1
+        + CONSUMER_LIMITS_SIZE
+        + 8
+        + 16
+        + 1
+        + 1
+        + name_length as usize;
     Ok((
         Topic {
             id,
+            uuid,
             created_at,
             name,
             partitions_count,
@@ -430,6 +900,76 @@ fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyE
             message_expiry,
             max_topic_size,
             replication_factor,
+            routing_epoch,
+            compression_algorithm,
+            frozen,
+            consumer_limits,
+            max_message_size,
+        },
+        read_bytes,
+    ))
+}
+
+pub fn map_partition_end_offsets(payload: Bytes) -> Result<Vec<PartitionEndOffset>, IggyError> {
+    let mut end_offsets = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let end_offset = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        end_offsets.push(PartitionEndOffset {
+            partition_id,
+            end_offset,
+        });
+        position += 12;
+    }
+    end_offsets.sort_by_key(|end_offset| end_offset.partition_id);
+    Ok(end_offsets)
+}
+
+pub fn map_partition(payload: Bytes) -> Result<PartitionDetails, IggyError> {
+    let (partition, mut position) = map_to_partition(payload.clone(), 0)?;
+    let mut segments = Vec::new();
+    let length = payload.len();
+    while position < length {
+        let (segment, read_bytes) = map_to_segment(payload.clone(), position)?;
+        segments.push(segment);
+        position += read_bytes;
+    }
+
+    segments.sort_by_key(|x| x.start_offset);
+    let partition = PartitionDetails {
+        id: partition.id,
+        created_at: partition.created_at,
+        segments_count: partition.segments_count,
+        current_offset: partition.current_offset,
+        size_bytes: partition.size_bytes,
+        messages_count: partition.messages_count,
+        segments,
+    };
+    Ok(partition)
+}
+
+fn map_to_segment(payload: Bytes, position: usize) -> Result<(Segment, usize), IggyError> {
+    let start_offset = u64::from_le_bytes(payload[position..position + 8].try_into()?);
+    let end_offset = u64::from_le_bytes(payload[position + 8..position + 16].try_into()?);
+    let size_bytes = u64::from_le_bytes(payload[position + 16..position + 24].try_into()?).into();
+    let is_closed = payload[position + 24] == 1;
+    let message_expiry = match u32::from_le_bytes(payload[position + 25..position + 29].try_into()?)
+    {
+        0 => None,
+        message_expiry => Some(message_expiry),
+    };
+    let is_expired = payload[position + 29] == 1;
+    let read_bytes = 8 + 8 + 8 + 1 + 4 + 1;
+    Ok((
+        Segment {
+            start_offset,
+            end_offset,
+            size_bytes,
+            is_closed,
+            message_expiry,
+            is_expired,
         },
         read_bytes,
     ))
@@ -557,6 +1097,9 @@ fn map_to_client_info(
     let transport = match transport {
         1 => "TCP",
         2 => "QUIC",
+        3 => "MQTT",
+        4 => "AMQP",
+        5 => "HTTP",
         _ => "Unknown",
     }
     .to_string();
@@ -585,14 +1128,17 @@ fn map_to_user_info(payload: Bytes, position: usize) -> Result<(UserInfo, usize)
     let created_at = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
     let status = payload[position + 12];
     let status = UserStatus::from_code(status)?;
-    let username_length = payload[position + 13];
+    let uuid = Uuid::from_slice(&payload[position + 13..position + 29])
+        .map_err(|_| IggyError::InvalidCommand)?;
+    let username_length = payload[position + 29];
     let username =
-        from_utf8(&payload[position + 14..position + 14 + username_length as usize])?.to_string();
-    let read_bytes = 4 + 8 + 1 + 1 + username_length as usize;
+        from_utf8(&payload[position + 30..position + 30 + username_length as usize])?.to_string();
+    let read_bytes = 4 + 8 + 1 + 16 + 1 + username_length as usize;
 
     Ok((
         UserInfo {
             id,
+            uuid,
             created_at,
             status,
             username,
@@ -617,3 +1163,129 @@ fn map_to_pat_info(
 
     Ok((PersonalAccessTokenInfo { name, expiry }, read_bytes))
 }
+
+fn map_to_schema_info(payload: Bytes, position: usize) -> Result<(SchemaInfo, usize), IggyError> {
+    let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let version = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+    let created_at = u64::from_le_bytes(payload[position + 8..position + 16].try_into()?);
+    let format = SchemaFormat::from_code(payload[position + 16])?;
+    let subject_length = payload[position + 17] as usize;
+    let subject = from_utf8(&payload[position + 18..position + 18 + subject_length])?.to_string();
+    let position = position + 18 + subject_length;
+    let schema_length = u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+    let schema = from_utf8(&payload[position + 4..position + 4 + schema_length])?.to_string();
+    let read_bytes = 4 + 4 + 8 + 1 + 1 + subject_length + 4 + schema_length;
+
+    Ok((
+        SchemaInfo {
+            id,
+            subject,
+            version,
+            format,
+            schema,
+            created_at,
+        },
+        read_bytes,
+    ))
+}
+
+fn map_to_tenant(payload: Bytes, position: usize) -> Result<(Tenant, usize), IggyError> {
+    let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let created_at = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+    let streams_count = u32::from_le_bytes(payload[position + 12..position + 16].try_into()?);
+    let topics_count = u32::from_le_bytes(payload[position + 16..position + 20].try_into()?);
+    let partitions_count = u32::from_le_bytes(payload[position + 20..position + 24].try_into()?);
+    let size = u64::from_le_bytes(payload[position + 24..position + 32].try_into()?);
+    let users_count = u32::from_le_bytes(payload[position + 32..position + 36].try_into()?);
+    let quotas_position = position + 36;
+    let quotas = TenantQuotas::from_bytes(
+        payload.slice(quotas_position..quotas_position + TenantQuotas::SIZE_BYTES),
+    )?;
+    let name_position = quotas_position + TenantQuotas::SIZE_BYTES;
+    let name_length = payload[name_position] as usize;
+    let name = from_utf8(&payload[name_position + 1..name_position + 1 + name_length])?.to_string();
+    let read_bytes = 36 + TenantQuotas::SIZE_BYTES + 1 + name_length;
+
+    Ok((
+        Tenant {
+            id,
+            created_at,
+            name,
+            quotas,
+            streams_count,
+            topics_count,
+            partitions_count,
+            size: IggyByteSize::from(size),
+            users_count,
+        },
+        read_bytes,
+    ))
+}
+
+fn map_to_connector(payload: Bytes, start: usize) -> Result<(ConnectorInfo, usize), IggyError> {
+    let mut position = start;
+    let read_short_string = |position: &mut usize| -> Result<String, IggyError> {
+        let length = payload[*position] as usize;
+        let value = from_utf8(&payload[*position + 1..*position + 1 + length])?.to_string();
+        *position += 1 + length;
+        Ok(value)
+    };
+    let name = read_short_string(&mut position)?;
+    let kind = read_short_string(&mut position)?;
+    let stream = read_short_string(&mut position)?;
+    let topic = read_short_string(&mut position)?;
+    let status = ConnectorStatus::from_code(payload[position])?;
+    let processed_messages = u64::from_le_bytes(payload[position + 1..position + 9].try_into()?);
+    let error_length =
+        u32::from_le_bytes(payload[position + 9..position + 13].try_into()?) as usize;
+    position += 13;
+    let error = match error_length {
+        0 => None,
+        _ => Some(from_utf8(&payload[position..position + error_length])?.to_string()),
+    };
+    position += error_length;
+
+    let properties_count = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    position += 4;
+    let mut properties = HashMap::with_capacity(properties_count as usize);
+    for _ in 0..properties_count {
+        let key = read_short_string(&mut position)?;
+        let value_length = u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+        let value = from_utf8(&payload[position + 4..position + 4 + value_length])?.to_string();
+        position += 4 + value_length;
+        properties.insert(key, value);
+    }
+
+    Ok((
+        ConnectorInfo {
+            name,
+            kind,
+            stream,
+            topic,
+            properties,
+            status,
+            error,
+            processed_messages,
+        },
+        position - start,
+    ))
+}
+
+fn map_to_user_group(payload: Bytes, position: usize) -> Result<(UserGroup, usize), IggyError> {
+    let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let created_at = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+    let members_count = u32::from_le_bytes(payload[position + 12..position + 16].try_into()?);
+    let name_length = payload[position + 16] as usize;
+    let name = from_utf8(&payload[position + 17..position + 17 + name_length])?.to_string();
+    let read_bytes = 17 + name_length;
+
+    Ok((
+        UserGroup {
+            id,
+            created_at,
+            name,
+            members_count,
+        },
+        read_bytes,
+    ))
+}
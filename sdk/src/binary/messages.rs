@@ -2,11 +2,20 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::client::MessageClient;
-use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::command::{
+    CREATE_FETCH_SESSION_CODE, DELETE_FETCH_SESSION_CODE, FETCH_MESSAGES_CODE, POLL_MESSAGES_CODE,
+    QUERY_MESSAGES_CODE, SEND_MESSAGES_CODE,
+};
 use crate::error::IggyError;
+use crate::messages::create_fetch_session::CreateFetchSession;
+use crate::messages::delete_fetch_session::DeleteFetchSession;
+use crate::messages::fetch_messages::FetchMessages;
 use crate::messages::poll_messages::PollMessages;
-use crate::messages::send_messages::SendMessages;
-use crate::models::messages::PolledMessages;
+use crate::messages::query_messages::QueryMessages;
+use crate::messages::send_messages::{Acks, SendMessages};
+use crate::models::fetch_session::{FetchSession, FetchedMessages};
+use crate::models::messages::{PolledMessages, SendMessagesReceipt};
+use crate::models::query_result::QueryResult;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> MessageClient for B {
@@ -24,4 +33,53 @@ impl<B: BinaryClient> MessageClient for B {
             .await?;
         Ok(())
     }
+
+    async fn send_messages_with_receipt(
+        &self,
+        command: &mut SendMessages,
+    ) -> Result<SendMessagesReceipt, IggyError> {
+        if command.acks == Some(Acks::None) {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(SEND_MESSAGES_CODE, command.as_bytes())
+            .await?;
+        mapper::map_send_messages_receipt(response)
+    }
+
+    async fn query_messages(&self, command: &QueryMessages) -> Result<QueryResult, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(QUERY_MESSAGES_CODE, command.as_bytes())
+            .await?;
+        mapper::map_query_result(response)
+    }
+
+    async fn create_fetch_session(
+        &self,
+        command: &CreateFetchSession,
+    ) -> Result<FetchSession, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(CREATE_FETCH_SESSION_CODE, command.as_bytes())
+            .await?;
+        mapper::map_fetch_session(response)
+    }
+
+    async fn fetch_messages(&self, command: &FetchMessages) -> Result<FetchedMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(FETCH_MESSAGES_CODE, command.as_bytes())
+            .await?;
+        mapper::map_fetched_messages(response)
+    }
+
+    async fn delete_fetch_session(&self, command: &DeleteFetchSession) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(DELETE_FETCH_SESSION_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
 }
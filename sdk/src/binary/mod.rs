@@ -2,15 +2,19 @@ use crate::binary::binary_client::{BinaryClient, ClientState};
 use crate::error::IggyError;
 
 pub mod binary_client;
+pub mod connectors;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 mod mapper;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod streams;
 pub mod system;
+pub mod tenants;
 pub mod topics;
+pub mod user_groups;
 pub mod users;
 
 async fn fail_if_not_authenticated(client: &dyn BinaryClient) -> Result<(), IggyError> {
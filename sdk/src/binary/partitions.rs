@@ -1,14 +1,42 @@
 use crate::binary::binary_client::BinaryClient;
-use crate::binary::fail_if_not_authenticated;
+use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::client::PartitionClient;
-use crate::command::{CREATE_PARTITIONS_CODE, DELETE_PARTITIONS_CODE};
+use crate::command::{
+    CREATE_PARTITIONS_CODE, DELETE_PARTITIONS_CODE, GET_PARTITION_DETAILS_CODE,
+    GET_PARTITION_END_OFFSETS_CODE,
+};
 use crate::error::IggyError;
+use crate::models::partition::{PartitionDetails, PartitionEndOffset};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_partition_details::GetPartitionDetails;
+use crate::partitions::get_partition_end_offsets::GetPartitionEndOffsets;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> PartitionClient for B {
+    async fn get_partition_details(
+        &self,
+        command: &GetPartitionDetails,
+    ) -> Result<PartitionDetails, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_PARTITION_DETAILS_CODE, command.as_bytes())
+            .await?;
+        mapper::map_partition(response)
+    }
+
+    async fn get_partition_end_offsets(
+        &self,
+        command: &GetPartitionEndOffsets,
+    ) -> Result<Vec<PartitionEndOffset>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_PARTITION_END_OFFSETS_CODE, command.as_bytes())
+            .await?;
+        mapper::map_partition_end_offsets(response)
+    }
+
     async fn create_partitions(&self, command: &CreatePartitions) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(CREATE_PARTITIONS_CODE, command.as_bytes())
@@ -0,0 +1,51 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::client::SchemaClient;
+use crate::command::*;
+use crate::error::IggyError;
+use crate::models::schema::SchemaInfo;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schema_versions::GetSchemaVersions;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> SchemaClient for B {
+    async fn get_schema(&self, command: &GetSchema) -> Result<SchemaInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_SCHEMA_CODE, command.as_bytes())
+            .await?;
+        mapper::map_schema(response)
+    }
+
+    async fn get_schema_versions(
+        &self,
+        command: &GetSchemaVersions,
+    ) -> Result<Vec<SchemaInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_SCHEMA_VERSIONS_CODE, command.as_bytes())
+            .await?;
+        mapper::map_schemas(response)
+    }
+
+    async fn register_schema(&self, command: &RegisterSchema) -> Result<SchemaInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(REGISTER_SCHEMA_CODE, command.as_bytes())
+            .await?;
+        mapper::map_schema(response)
+    }
+
+    async fn update_schema_compatibility(
+        &self,
+        command: &UpdateSchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UPDATE_SCHEMA_COMPATIBILITY_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::client::StreamClient;
 use crate::command::{
     CREATE_STREAM_CODE, DELETE_STREAM_CODE, GET_STREAMS_CODE, GET_STREAM_CODE, PURGE_STREAM_CODE,
-    UPDATE_STREAM_CODE,
+    UNDELETE_STREAM_CODE, UPDATE_STREAM_CODE,
 };
 use crate::error::IggyError;
 use crate::models::stream::{Stream, StreamDetails};
@@ -13,6 +13,7 @@ use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::undelete_stream::UndeleteStream;
 use crate::streams::update_stream::UpdateStream;
 
 #[async_trait::async_trait]
@@ -47,6 +48,13 @@ impl<B: BinaryClient> StreamClient for B {
         Ok(())
     }
 
+    async fn undelete_stream(&self, command: &UndeleteStream) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UNDELETE_STREAM_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
     async fn update_stream(&self, command: &UpdateStream) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(UPDATE_STREAM_CODE, command.as_bytes())
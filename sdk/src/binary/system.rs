@@ -2,14 +2,26 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::client::SystemClient;
-use crate::command::{GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_ME_CODE, GET_STATS_CODE, PING_CODE};
+use crate::command::{
+    BATCH_CODE, GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_METADATA_CODE, GET_ME_CODE, GET_STATS_CODE,
+    HEARTBEAT_CODE, HELLO_CODE, NEGOTIATE_COMPRESSION_CODE, PING_CODE,
+};
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
+use crate::models::batch_result::BatchCommandResult;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::hello_info::HelloInfo;
+use crate::models::metadata::Metadata;
 use crate::models::stats::Stats;
+use crate::system::batch::Batch;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_metadata::GetMetadata;
 use crate::system::get_stats::GetStats;
+use crate::system::heartbeat::Heartbeat;
+use crate::system::hello::Hello;
+use crate::system::negotiate_compression::NegotiateCompression;
 use crate::system::ping::Ping;
 
 #[async_trait::async_trait]
@@ -22,6 +34,14 @@ impl<B: BinaryClient> SystemClient for B {
         mapper::map_stats(response)
     }
 
+    async fn get_metadata(&self, command: &GetMetadata) -> Result<Metadata, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_METADATA_CODE, command.as_bytes())
+            .await?;
+        mapper::map_metadata(response)
+    }
+
     async fn get_me(&self, command: &GetMe) -> Result<ClientInfoDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -51,4 +71,35 @@ impl<B: BinaryClient> SystemClient for B {
             .await?;
         Ok(())
     }
+
+    async fn heartbeat(&self, command: &Heartbeat) -> Result<(), IggyError> {
+        self.send_with_response(HEARTBEAT_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn hello(&self, command: &Hello) -> Result<HelloInfo, IggyError> {
+        let response = self
+            .send_with_response(HELLO_CODE, command.as_bytes())
+            .await?;
+        mapper::map_hello_info(response)
+    }
+
+    async fn batch(&self, command: &Batch) -> Result<Vec<BatchCommandResult>, IggyError> {
+        let response = self
+            .send_with_response(BATCH_CODE, command.as_bytes())
+            .await?;
+        mapper::map_batch_results(response)
+    }
+
+    async fn negotiate_compression(
+        &self,
+        command: &NegotiateCompression,
+    ) -> Result<CompressionAlgorithm, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(NEGOTIATE_COMPRESSION_CODE, command.as_bytes())
+            .await?;
+        mapper::map_compression_algorithm(response)
+    }
 }
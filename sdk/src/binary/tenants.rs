@@ -0,0 +1,68 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::client::TenantClient;
+use crate::command::*;
+use crate::error::IggyError;
+use crate::models::tenant::Tenant;
+use crate::tenants::add_tenant_user::AddTenantUser;
+use crate::tenants::create_tenant::CreateTenant;
+use crate::tenants::delete_tenant::DeleteTenant;
+use crate::tenants::get_tenant::GetTenant;
+use crate::tenants::get_tenants::GetTenants;
+use crate::tenants::remove_tenant_user::RemoveTenantUser;
+use crate::tenants::update_tenant::UpdateTenant;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> TenantClient for B {
+    async fn get_tenant(&self, command: &GetTenant) -> Result<Tenant, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_TENANT_CODE, command.as_bytes())
+            .await?;
+        mapper::map_tenant(response)
+    }
+
+    async fn get_tenants(&self, command: &GetTenants) -> Result<Vec<Tenant>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_TENANTS_CODE, command.as_bytes())
+            .await?;
+        mapper::map_tenants(response)
+    }
+
+    async fn create_tenant(&self, command: &CreateTenant) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(CREATE_TENANT_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn update_tenant(&self, command: &UpdateTenant) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UPDATE_TENANT_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_tenant(&self, command: &DeleteTenant) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(DELETE_TENANT_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn add_tenant_user(&self, command: &AddTenantUser) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(ADD_TENANT_USER_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn remove_tenant_user(&self, command: &RemoveTenantUser) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(REMOVE_TENANT_USER_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+}
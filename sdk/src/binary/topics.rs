@@ -3,16 +3,19 @@ use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
 use crate::client::TopicClient;
 use crate::command::{
-    CREATE_TOPIC_CODE, DELETE_TOPIC_CODE, GET_TOPICS_CODE, GET_TOPIC_CODE, PURGE_TOPIC_CODE,
-    UPDATE_TOPIC_CODE,
+    CREATE_TOPIC_CODE, DELETE_TOPIC_CODE, FREEZE_TOPIC_CODE, GET_TOPICS_CODE, GET_TOPIC_CODE,
+    PURGE_TOPIC_CODE, UNDELETE_TOPIC_CODE, UNFREEZE_TOPIC_CODE, UPDATE_TOPIC_CODE,
 };
 use crate::error::IggyError;
 use crate::models::topic::{Topic, TopicDetails};
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
+use crate::topics::freeze_topic::FreezeTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::undelete_topic::UndeleteTopic;
+use crate::topics::unfreeze_topic::UnfreezeTopic;
 use crate::topics::update_topic::UpdateTopic;
 
 #[async_trait::async_trait]
@@ -47,6 +50,13 @@ impl<B: BinaryClient> TopicClient for B {
         Ok(())
     }
 
+    async fn undelete_topic(&self, command: &UndeleteTopic) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UNDELETE_TOPIC_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
     async fn update_topic(&self, command: &UpdateTopic) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(UPDATE_TOPIC_CODE, command.as_bytes())
@@ -60,4 +70,18 @@ impl<B: BinaryClient> TopicClient for B {
             .await?;
         Ok(())
     }
+
+    async fn freeze_topic(&self, command: &FreezeTopic) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(FREEZE_TOPIC_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn unfreeze_topic(&self, command: &UnfreezeTopic) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UNFREEZE_TOPIC_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
 }
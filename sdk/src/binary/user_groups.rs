@@ -0,0 +1,74 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::client::UserGroupClient;
+use crate::command::*;
+use crate::error::IggyError;
+use crate::models::user_group::{UserGroup, UserGroupDetails};
+use crate::user_groups::add_user_group_member::AddUserGroupMember;
+use crate::user_groups::create_user_group::CreateUserGroup;
+use crate::user_groups::delete_user_group::DeleteUserGroup;
+use crate::user_groups::get_user_group::GetUserGroup;
+use crate::user_groups::get_user_groups::GetUserGroups;
+use crate::user_groups::remove_user_group_member::RemoveUserGroupMember;
+use crate::user_groups::update_user_group_permissions::UpdateUserGroupPermissions;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> UserGroupClient for B {
+    async fn get_user_group(&self, command: &GetUserGroup) -> Result<UserGroupDetails, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_USER_GROUP_CODE, command.as_bytes())
+            .await?;
+        mapper::map_user_group(response)
+    }
+
+    async fn get_user_groups(&self, command: &GetUserGroups) -> Result<Vec<UserGroup>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(GET_USER_GROUPS_CODE, command.as_bytes())
+            .await?;
+        mapper::map_user_groups(response)
+    }
+
+    async fn create_user_group(&self, command: &CreateUserGroup) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(CREATE_USER_GROUP_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn update_user_group_permissions(
+        &self,
+        command: &UpdateUserGroupPermissions,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UPDATE_USER_GROUP_PERMISSIONS_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_user_group(&self, command: &DeleteUserGroup) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(DELETE_USER_GROUP_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn add_user_group_member(&self, command: &AddUserGroupMember) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(ADD_USER_GROUP_MEMBER_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn remove_user_group_member(
+        &self,
+        command: &RemoveUserGroupMember,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(REMOVE_USER_GROUP_MEMBER_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+}
@@ -5,8 +5,10 @@ use crate::client::UserClient;
 use crate::command::*;
 use crate::error::IggyError;
 use crate::models::identity_info::IdentityInfo;
+use crate::models::permission_check::PermissionCheck;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::users::change_password::ChangePassword;
+use crate::users::check_permission::CheckPermission;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
@@ -15,6 +17,7 @@ use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_expiry::UpdateUserExpiry;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> UserClient for B {
@@ -62,6 +65,13 @@ impl<B: BinaryClient> UserClient for B {
         Ok(())
     }
 
+    async fn update_user_expiry(&self, command: &UpdateUserExpiry) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(UPDATE_USER_EXPIRY_CODE, command.as_bytes())
+            .await?;
+        Ok(())
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(CHANGE_PASSWORD_CODE, command.as_bytes())
@@ -84,4 +94,15 @@ impl<B: BinaryClient> UserClient for B {
         self.set_state(ClientState::Connected).await;
         Ok(())
     }
+
+    async fn check_permission(
+        &self,
+        command: &CheckPermission,
+    ) -> Result<PermissionCheck, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(CHECK_PERMISSION_CODE, command.as_bytes())
+            .await?;
+        mapper::map_permission_check(response)
+    }
 }
@@ -0,0 +1,226 @@
+use crate::error::IggyError;
+use crate::models::permissions::Permissions;
+use crate::models::user_status::UserStatus;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
+
+/// The streams, topics and users that should exist on the server.
+///
+/// The definition is reconciled by the server at startup and applied by the command line tool.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BootstrapDefinition {
+    #[serde(default)]
+    pub streams: Vec<BootstrapStream>,
+    #[serde(default)]
+    pub users: Vec<BootstrapUser>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BootstrapStream {
+    pub id: Option<u32>,
+    pub name: String,
+    #[serde(default)]
+    pub topics: Vec<BootstrapTopic>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BootstrapTopic {
+    pub id: Option<u32>,
+    pub name: String,
+    #[serde(default = "default_partitions_count")]
+    pub partitions_count: u32,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub message_expiry: Option<IggyDuration>,
+    #[serde(default)]
+    pub max_topic_size: Option<IggyByteSize>,
+    #[serde(default = "default_replication_factor")]
+    pub replication_factor: u8,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BootstrapUser {
+    pub username: String,
+    pub password: String,
+    #[serde(default)]
+    pub status: UserStatus,
+    #[serde(default)]
+    pub permissions: Option<Permissions>,
+}
+
+fn default_partitions_count() -> u32 {
+    1
+}
+
+fn default_replication_factor() -> u8 {
+    1
+}
+
+impl BootstrapDefinition {
+    /// Parses the definition in the format matching given file extension (`toml`, `json`, `yaml` or `yml`).
+    pub fn from_extension(extension: &str, content: &str) -> Result<Self, IggyError> {
+        match extension {
+            "toml" => Self::from_toml(content),
+            "json" => Self::from_json(content),
+            "yaml" | "yml" => Self::from_yaml(content),
+            _ => Err(IggyError::InvalidBootstrapDefinition(format!(
+                "unsupported format: {extension}, only .toml, .json and .yaml are supported"
+            ))),
+        }
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self, IggyError> {
+        // TOML keys are always strings, the numeric IDs of the stream and topic permissions
+        // are parsed from the intermediate JSON value instead.
+        let value = toml::from_str::<serde_json::Value>(content)
+            .map_err(|error| IggyError::InvalidBootstrapDefinition(error.to_string()))?;
+        Self::from_value(value)
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, IggyError> {
+        let value = serde_json::from_str::<serde_json::Value>(content)
+            .map_err(|error| IggyError::InvalidBootstrapDefinition(error.to_string()))?;
+        Self::from_value(value)
+    }
+
+    pub fn from_yaml(content: &str) -> Result<Self, IggyError> {
+        // Same as for TOML, YAML keys of the permissions might be parsed as strings.
+        let value = serde_yaml::from_str::<serde_json::Value>(content)
+            .map_err(|error| IggyError::InvalidBootstrapDefinition(error.to_string()))?;
+        Self::from_value(value)
+    }
+
+    fn from_value(value: serde_json::Value) -> Result<Self, IggyError> {
+        serde_json::from_value(value)
+            .map_err(|error| IggyError::InvalidBootstrapDefinition(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_deserialized_from_toml() {
+        let definition = BootstrapDefinition::from_toml(
+            r#"
+            [[streams]]
+            id = 1
+            name = "orders"
+
+            [[streams.topics]]
+            name = "created"
+            partitions_count = 3
+            message_expiry = "1 day"
+
+            [[users]]
+            username = "producer"
+            password = "secret"
+
+            [users.permissions.global]
+            manage_servers = false
+            read_servers = false
+            manage_users = false
+            read_users = false
+            manage_streams = false
+            read_streams = false
+            manage_topics = false
+            read_topics = false
+            poll_messages = false
+            send_messages = true
+
+            [users.permissions.streams.1]
+            manage_stream = false
+            read_stream = true
+            manage_topics = false
+            read_topics = true
+            poll_messages = false
+            send_messages = true
+            auto_create_topics = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(definition.streams.len(), 1);
+        let stream = &definition.streams[0];
+        assert_eq!(stream.id, Some(1));
+        assert_eq!(stream.topics.len(), 1);
+        let topic = &stream.topics[0];
+        assert_eq!(topic.id, None);
+        assert_eq!(topic.partitions_count, 3);
+        assert_eq!(topic.message_expiry.unwrap().as_secs(), 86400);
+        assert!(topic.max_topic_size.is_none());
+        assert_eq!(topic.replication_factor, 1);
+
+        assert_eq!(definition.users.len(), 1);
+        let user = &definition.users[0];
+        assert_eq!(user.status, UserStatus::Active);
+        let permissions = user.permissions.as_ref().unwrap();
+        assert!(permissions.global.send_messages);
+        assert!(!permissions.global.poll_messages);
+        assert!(
+            permissions.streams.as_ref().unwrap()[&1].auto_create_topics,
+            "stream permissions should be keyed by the numeric stream ID"
+        );
+    }
+
+    #[test]
+    fn should_be_deserialized_from_yaml() {
+        let definition = BootstrapDefinition::from_yaml(
+            r#"
+streams:
+  - name: orders
+    topics:
+      - name: created
+        partitions_count: 2
+        message_expiry: 7 days
+        max_topic_size: 1 GB
+users:
+  - username: consumer
+    password: secret
+    status: inactive
+    permissions:
+      global:
+        manage_servers: false
+        read_servers: false
+        manage_users: false
+        read_users: false
+        manage_streams: false
+        read_streams: false
+        manage_topics: false
+        read_topics: false
+        poll_messages: true
+        send_messages: false
+      streams:
+        1:
+          manage_stream: false
+          read_stream: true
+          manage_topics: false
+          read_topics: true
+          poll_messages: true
+          send_messages: false
+"#,
+        )
+        .unwrap();
+
+        let topic = &definition.streams[0].topics[0];
+        assert_eq!(definition.streams[0].id, None);
+        assert_eq!(topic.partitions_count, 2);
+        assert_eq!(topic.message_expiry.unwrap().as_secs(), 7 * 86400);
+        assert_eq!(topic.max_topic_size.unwrap().as_bytes_u64(), 1_000_000_000);
+
+        let user = &definition.users[0];
+        assert_eq!(user.status, UserStatus::Inactive);
+        let permissions = user.permissions.as_ref().unwrap();
+        assert!(permissions.streams.as_ref().unwrap()[&1].poll_messages);
+    }
+
+    #[test]
+    fn unsupported_extension_should_fail() {
+        assert!(BootstrapDefinition::from_extension("xml", "").is_err());
+    }
+}
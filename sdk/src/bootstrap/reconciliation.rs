@@ -0,0 +1,555 @@
+use crate::bootstrap::definition::{
+    BootstrapDefinition, BootstrapStream, BootstrapTopic, BootstrapUser,
+};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::permissions::Permissions;
+use crate::models::stream::StreamDetails;
+use crate::models::topic::Topic;
+use crate::models::user_info::UserInfoDetails;
+use crate::models::user_status::UserStatus;
+use crate::users::defaults::DEFAULT_ROOT_USER_ID;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::text;
+use std::fmt::{Display, Formatter};
+
+/// Existing resources the definition is compared with, only the ones matching
+/// the names used in the definition are required.
+#[derive(Debug, Default)]
+pub struct ExistingResources {
+    pub streams: Vec<StreamDetails>,
+    pub users: Vec<UserInfoDetails>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReconciliationOptions {
+    /// Update the existing topics and users, otherwise only the missing resources are created.
+    pub update_existing: bool,
+    /// Message expiry (in seconds, `0` means unlimited) used when the topic doesn't declare it.
+    /// When not set, the message expiry of the existing topic is kept and the new topic uses the server default.
+    pub default_message_expiry: Option<u32>,
+}
+
+/// Single change required to make the existing resources match the definition.
+/// The streams, topics and users are identified by their names.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReconciliationAction {
+    CreateStream {
+        stream_id: Option<u32>,
+        name: String,
+    },
+    CreateTopic {
+        stream: String,
+        topic_id: Option<u32>,
+        name: String,
+        partitions_count: u32,
+        message_expiry: Option<u32>,
+        max_topic_size: Option<IggyByteSize>,
+        replication_factor: u8,
+    },
+    UpdateTopic {
+        stream: String,
+        topic: String,
+        message_expiry: Change<Option<u32>>,
+        max_topic_size: Change<Option<IggyByteSize>>,
+        replication_factor: Change<u8>,
+    },
+    CreatePartitions {
+        stream: String,
+        topic: String,
+        partitions_count: u32,
+    },
+    CreateUser {
+        username: String,
+        password: String,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+    },
+    UpdateUserStatus {
+        username: String,
+        status: Change<UserStatus>,
+    },
+    UpdatePermissions {
+        username: String,
+        permissions: Option<Permissions>,
+    },
+}
+
+/// Value of the existing resource and the one declared in the definition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change<T> {
+    pub from: T,
+    pub to: T,
+}
+
+/// Actions required to reconcile the existing resources with the definition,
+/// in the order they have to be applied, and the differences which can't be reconciled.
+#[derive(Debug, Default)]
+pub struct ReconciliationPlan {
+    pub actions: Vec<ReconciliationAction>,
+    pub warnings: Vec<String>,
+}
+
+impl ReconciliationPlan {
+    pub fn new(
+        definition: &BootstrapDefinition,
+        existing: &ExistingResources,
+        options: ReconciliationOptions,
+    ) -> Self {
+        let mut plan = Self::default();
+        for stream in &definition.streams {
+            plan.add_stream(stream, existing, options);
+        }
+        for user in &definition.users {
+            plan.add_user(user, existing, options);
+        }
+        plan
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    fn add_stream(
+        &mut self,
+        stream: &BootstrapStream,
+        existing: &ExistingResources,
+        options: ReconciliationOptions,
+    ) {
+        let existing_stream = existing
+            .streams
+            .iter()
+            .find(|existing_stream| same_name(&existing_stream.name, &stream.name));
+        if existing_stream.is_none() {
+            self.actions.push(ReconciliationAction::CreateStream {
+                stream_id: stream.id,
+                name: stream.name.clone(),
+            });
+        }
+
+        let existing_topics = existing_stream
+            .map(|existing_stream| existing_stream.topics.as_slice())
+            .unwrap_or_default();
+        for topic in &stream.topics {
+            let existing_topic = existing_topics
+                .iter()
+                .find(|existing_topic| same_name(&existing_topic.name, &topic.name));
+            self.add_topic(&stream.name, topic, existing_topic, options);
+        }
+    }
+
+    fn add_topic(
+        &mut self,
+        stream: &str,
+        topic: &BootstrapTopic,
+        existing_topic: Option<&Topic>,
+        options: ReconciliationOptions,
+    ) {
+        let message_expiry = topic
+            .message_expiry
+            .map(|message_expiry| message_expiry.as_secs())
+            .or(options.default_message_expiry)
+            .filter(|message_expiry| *message_expiry > 0);
+        let max_topic_size = topic
+            .max_topic_size
+            .filter(|max_topic_size| max_topic_size.as_bytes_u64() > 0);
+
+        let Some(existing_topic) = existing_topic else {
+            self.actions.push(ReconciliationAction::CreateTopic {
+                stream: stream.to_owned(),
+                topic_id: topic.id,
+                name: topic.name.clone(),
+                partitions_count: topic.partitions_count,
+                message_expiry,
+                max_topic_size,
+                replication_factor: topic.replication_factor,
+            });
+            return;
+        };
+
+        if !options.update_existing {
+            return;
+        }
+
+        // Message expiry which is neither declared nor defaulted is left as it is
+        let message_expiry =
+            match topic.message_expiry.is_none() && options.default_message_expiry.is_none() {
+                true => existing_topic.message_expiry,
+                false => message_expiry,
+            };
+        if existing_topic.message_expiry != message_expiry
+            || existing_topic.max_topic_size != max_topic_size
+            || existing_topic.replication_factor != topic.replication_factor
+        {
+            self.actions.push(ReconciliationAction::UpdateTopic {
+                stream: stream.to_owned(),
+                topic: existing_topic.name.clone(),
+                message_expiry: Change {
+                    from: existing_topic.message_expiry,
+                    to: message_expiry,
+                },
+                max_topic_size: Change {
+                    from: existing_topic.max_topic_size,
+                    to: max_topic_size,
+                },
+                replication_factor: Change {
+                    from: existing_topic.replication_factor,
+                    to: topic.replication_factor,
+                },
+            });
+        }
+
+        let partitions_count = existing_topic.partitions_count;
+        if topic.partitions_count > partitions_count {
+            self.actions.push(ReconciliationAction::CreatePartitions {
+                stream: stream.to_owned(),
+                topic: existing_topic.name.clone(),
+                partitions_count: topic.partitions_count - partitions_count,
+            });
+        } else if topic.partitions_count < partitions_count {
+            self.warnings.push(format!(
+                "Topic: {} has {partitions_count} partitions, more than {} declared in the definition, the partitions will not be deleted.",
+                topic.name, topic.partitions_count
+            ));
+        }
+    }
+
+    fn add_user(
+        &mut self,
+        user: &BootstrapUser,
+        existing: &ExistingResources,
+        options: ReconciliationOptions,
+    ) {
+        let existing_user = existing
+            .users
+            .iter()
+            .find(|existing_user| same_name(&existing_user.username, &user.username));
+        let Some(existing_user) = existing_user else {
+            self.actions.push(ReconciliationAction::CreateUser {
+                username: user.username.clone(),
+                password: user.password.clone(),
+                status: user.status,
+                permissions: user.permissions.clone(),
+            });
+            return;
+        };
+
+        if !options.update_existing {
+            return;
+        }
+
+        if existing_user.id == DEFAULT_ROOT_USER_ID {
+            self.warnings.push(format!(
+                "User: {} is the root user, it will not be updated from the definition.",
+                existing_user.username
+            ));
+            return;
+        }
+
+        if existing_user.status != user.status {
+            self.actions.push(ReconciliationAction::UpdateUserStatus {
+                username: existing_user.username.clone(),
+                status: Change {
+                    from: existing_user.status,
+                    to: user.status,
+                },
+            });
+        }
+
+        if existing_user.permissions != user.permissions {
+            self.actions.push(ReconciliationAction::UpdatePermissions {
+                username: existing_user.username.clone(),
+                permissions: user.permissions.clone(),
+            });
+        }
+    }
+}
+
+/// Returns the identifier of the resource declared with given name, the names
+/// are normalized in the same way as on creation of the resource.
+pub fn named_identifier(name: &str) -> Result<Identifier, IggyError> {
+    Identifier::named(&text::to_lowercase_non_whitespace(name))
+}
+
+/// Resources are matched by the names in the same way as the server matches the named identifiers.
+fn same_name(existing: &str, declared: &str) -> bool {
+    existing == text::to_lowercase_non_whitespace(declared)
+}
+
+fn format_message_expiry(message_expiry: Option<u32>) -> String {
+    match message_expiry {
+        Some(message_expiry) => format!("{message_expiry}s"),
+        None => String::from("unlimited"),
+    }
+}
+
+fn format_max_topic_size(max_topic_size: Option<IggyByteSize>) -> String {
+    match max_topic_size {
+        Some(max_topic_size) => max_topic_size.as_human_string(),
+        None => String::from("unlimited"),
+    }
+}
+
+impl<T: PartialEq> Change<T> {
+    fn is_changed(&self) -> bool {
+        self.from != self.to
+    }
+}
+
+impl Display for ReconciliationAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CreateStream { stream_id, name } => match stream_id {
+                Some(stream_id) => write!(f, "+ stream: {name} (ID: {stream_id})"),
+                None => write!(f, "+ stream: {name}"),
+            },
+            Self::CreateTopic {
+                stream,
+                topic_id,
+                name,
+                partitions_count,
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+            } => {
+                write!(f, "+ topic: {stream}/{name} (")?;
+                if let Some(topic_id) = topic_id {
+                    write!(f, "ID: {topic_id}, ")?;
+                }
+                write!(
+                    f,
+                    "partitions: {partitions_count}, message expiry: {}, max topic size: {}, replication factor: {replication_factor})",
+                    format_message_expiry(*message_expiry),
+                    format_max_topic_size(*max_topic_size)
+                )
+            }
+            Self::UpdateTopic {
+                stream,
+                topic,
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+            } => {
+                let mut changes = vec![];
+                if message_expiry.is_changed() {
+                    changes.push(format!(
+                        "message expiry: {} -> {}",
+                        format_message_expiry(message_expiry.from),
+                        format_message_expiry(message_expiry.to)
+                    ));
+                }
+                if max_topic_size.is_changed() {
+                    changes.push(format!(
+                        "max topic size: {} -> {}",
+                        format_max_topic_size(max_topic_size.from),
+                        format_max_topic_size(max_topic_size.to)
+                    ));
+                }
+                if replication_factor.is_changed() {
+                    changes.push(format!(
+                        "replication factor: {} -> {}",
+                        replication_factor.from, replication_factor.to
+                    ));
+                }
+                write!(f, "~ topic: {stream}/{topic} ({})", changes.join(", "))
+            }
+            Self::CreatePartitions {
+                stream,
+                topic,
+                partitions_count,
+            } => write!(
+                f,
+                "+ partitions: {stream}/{topic} ({partitions_count} added)"
+            ),
+            Self::CreateUser {
+                username, status, ..
+            } => write!(f, "+ user: {username} (status: {status})"),
+            Self::UpdateUserStatus { username, status } => {
+                write!(
+                    f,
+                    "~ user: {username} (status: {} -> {})",
+                    status.from, status.to
+                )
+            }
+            Self::UpdatePermissions { username, .. } => {
+                write!(f, "~ user: {username} (permissions)")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compression::compression_algorithm::CompressionAlgorithm;
+    use crate::models::consumer_limits::ConsumerLimits;
+    use crate::models::topic_defaults::TopicDefaults;
+    use uuid::Uuid;
+
+    const DEFINITION: &str = r#"
+        [[streams]]
+        id = 1
+        name = "orders"
+
+        [[streams.topics]]
+        name = "created"
+        partitions_count = 3
+        message_expiry = "1 day"
+
+        [[streams.topics]]
+        name = "cancelled"
+
+        [[users]]
+        username = "producer"
+        password = "secret"
+        status = "inactive"
+        "#;
+
+    fn existing() -> ExistingResources {
+        ExistingResources {
+            streams: vec![StreamDetails {
+                id: 1,
+                uuid: Uuid::new_v4(),
+                created_at: 0,
+                name: String::from("orders"),
+                size_bytes: IggyByteSize::default(),
+                messages_count: 0,
+                topics_count: 1,
+                topic_defaults: TopicDefaults::default(),
+                topics: vec![Topic {
+                    id: 1,
+                    uuid: Uuid::new_v4(),
+                    created_at: 0,
+                    name: String::from("created"),
+                    size: IggyByteSize::default(),
+                    message_expiry: None,
+                    max_topic_size: None,
+                    replication_factor: 1,
+                    messages_count: 0,
+                    partitions_count: 1,
+                    routing_epoch: 0,
+                    compression_algorithm: CompressionAlgorithm::None,
+                    frozen: false,
+                    consumer_limits: ConsumerLimits::default(),
+                    max_message_size: None,
+                }],
+            }],
+            users: vec![UserInfoDetails {
+                id: 2,
+                uuid: Uuid::new_v4(),
+                created_at: 0,
+                status: UserStatus::Active,
+                username: String::from("producer"),
+                permissions: None,
+                expires_at: None,
+                max_inactive_days: None,
+                last_active_at: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn should_create_all_resources_when_none_exist() {
+        let definition = BootstrapDefinition::from_toml(DEFINITION).unwrap();
+        let plan = ReconciliationPlan::new(
+            &definition,
+            &ExistingResources::default(),
+            ReconciliationOptions {
+                update_existing: true,
+                default_message_expiry: None,
+            },
+        );
+
+        let actions = plan
+            .actions
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            vec![
+                "+ stream: orders (ID: 1)",
+                "+ topic: orders/created (partitions: 3, message expiry: 86400s, max topic size: unlimited, replication factor: 1)",
+                "+ topic: orders/cancelled (partitions: 1, message expiry: unlimited, max topic size: unlimited, replication factor: 1)",
+                "+ user: producer (status: inactive)",
+            ]
+        );
+        assert!(plan.warnings.is_empty());
+    }
+
+    #[test]
+    fn should_only_create_missing_resources_when_existing_are_not_updated() {
+        let definition = BootstrapDefinition::from_toml(DEFINITION).unwrap();
+        let plan = ReconciliationPlan::new(
+            &definition,
+            &existing(),
+            ReconciliationOptions {
+                update_existing: false,
+                default_message_expiry: None,
+            },
+        );
+
+        assert_eq!(plan.actions.len(), 1);
+        assert!(matches!(
+            &plan.actions[0],
+            ReconciliationAction::CreateTopic { name, .. } if name == "cancelled"
+        ));
+    }
+
+    #[test]
+    fn should_update_existing_resources() {
+        let definition = BootstrapDefinition::from_toml(DEFINITION).unwrap();
+        let plan = ReconciliationPlan::new(
+            &definition,
+            &existing(),
+            ReconciliationOptions {
+                update_existing: true,
+                default_message_expiry: None,
+            },
+        );
+
+        let actions = plan
+            .actions
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            vec![
+                "~ topic: orders/created (message expiry: unlimited -> 86400s)",
+                "+ partitions: orders/created (2 added)",
+                "+ topic: orders/cancelled (partitions: 1, message expiry: unlimited, max topic size: unlimited, replication factor: 1)",
+                "~ user: producer (status: active -> inactive)",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_warn_about_partitions_which_cannot_be_deleted() {
+        let definition = BootstrapDefinition::from_toml(
+            r#"
+            [[streams]]
+            name = "orders"
+
+            [[streams.topics]]
+            name = "created"
+            partitions_count = 1
+            "#,
+        )
+        .unwrap();
+        let mut existing = existing();
+        existing.streams[0].topics[0].partitions_count = 5;
+
+        let plan = ReconciliationPlan::new(
+            &definition,
+            &existing,
+            ReconciliationOptions {
+                update_existing: true,
+                default_message_expiry: None,
+            },
+        );
+
+        assert!(plan.is_empty());
+        assert_eq!(plan.warnings.len(), 1);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::bootstrap::definition::BootstrapDefinition;
+use crate::bootstrap::reconciliation::{
+    named_identifier, ExistingResources, ReconciliationAction, ReconciliationOptions,
+    ReconciliationPlan,
+};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::models::consumer_limits::ConsumerLimits;
+use crate::models::timestamp_type::TimestampType;
+use crate::partitions::create_partitions::CreatePartitions;
+use crate::streams::create_stream::CreateStream;
+use crate::streams::get_stream::GetStream;
+use crate::topics::create_topic::CreateTopic;
+use crate::topics::update_topic::UpdateTopic;
+use crate::users::create_user::CreateUser;
+use crate::users::get_user::GetUser;
+use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_user::UpdateUser;
+use anyhow::Context;
+use async_trait::async_trait;
+use std::path::PathBuf;
+use tracing::{event, Level};
+
+pub struct ApplyDefinitionCmd {
+    path: PathBuf,
+    dry_run: bool,
+}
+
+impl ApplyDefinitionCmd {
+    pub fn new(path: PathBuf, dry_run: bool) -> Self {
+        Self { path, dry_run }
+    }
+
+    fn load_definition(&self) -> anyhow::Result<BootstrapDefinition, anyhow::Error> {
+        let content = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Problem reading definition file: {}", self.path.display()))?;
+        let extension = self
+            .path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+
+        BootstrapDefinition::from_extension(extension, &content)
+            .with_context(|| format!("Problem parsing definition file: {}", self.path.display()))
+    }
+
+    /// Fetches the streams and users declared in the definition, the ones which can't be fetched are considered missing.
+    async fn get_existing_resources(
+        &self,
+        client: &dyn Client,
+        definition: &BootstrapDefinition,
+    ) -> ExistingResources {
+        let mut existing = ExistingResources::default();
+        for stream in &definition.streams {
+            let Ok(stream_id) = named_identifier(&stream.name) else {
+                continue;
+            };
+            if let Ok(stream) = client.get_stream(&GetStream { stream_id }).await {
+                existing.streams.push(stream);
+            }
+        }
+        for user in &definition.users {
+            let Ok(user_id) = named_identifier(&user.username) else {
+                continue;
+            };
+            if let Ok(user) = client.get_user(&GetUser { user_id }).await {
+                existing.users.push(user);
+            }
+        }
+        existing
+    }
+}
+
+async fn apply_action(
+    client: &dyn Client,
+    action: &ReconciliationAction,
+) -> anyhow::Result<(), anyhow::Error> {
+    match action {
+        ReconciliationAction::CreateStream { stream_id, name } => {
+            client
+                .create_stream(&CreateStream {
+                    stream_id: *stream_id,
+                    name: name.clone(),
+                    ..CreateStream::default()
+                })
+                .await?
+        }
+        ReconciliationAction::CreateTopic {
+            stream,
+            topic_id,
+            name,
+            partitions_count,
+            message_expiry,
+            max_topic_size,
+            replication_factor,
+        } => {
+            client
+                .create_topic(&CreateTopic {
+                    stream_id: named_identifier(stream)?,
+                    topic_id: *topic_id,
+                    partitions_count: *partitions_count,
+                    message_expiry: *message_expiry,
+                    max_topic_size: *max_topic_size,
+                    replication_factor: *replication_factor,
+                    name: name.clone(),
+                    message_schema: None,
+                    ephemeral: false,
+                    timestamp_type: TimestampType::default(),
+                    consumer_limits: ConsumerLimits::default(),
+                    max_message_size: None,
+                })
+                .await?
+        }
+        ReconciliationAction::UpdateTopic {
+            stream,
+            topic,
+            message_expiry,
+            max_topic_size,
+            replication_factor,
+        } => {
+            client
+                .update_topic(&UpdateTopic {
+                    stream_id: named_identifier(stream)?,
+                    topic_id: named_identifier(topic)?,
+                    message_expiry: message_expiry.to,
+                    max_topic_size: max_topic_size.to,
+                    replication_factor: replication_factor.to,
+                    name: topic.clone(),
+                    message_schema: None,
+                    timestamp_type: TimestampType::default(),
+                    consumer_limits: ConsumerLimits::default(),
+                    max_message_size: None,
+                })
+                .await?
+        }
+        ReconciliationAction::CreatePartitions {
+            stream,
+            topic,
+            partitions_count,
+        } => {
+            client
+                .create_partitions(&CreatePartitions {
+                    stream_id: named_identifier(stream)?,
+                    topic_id: named_identifier(topic)?,
+                    partitions_count: *partitions_count,
+                    repartition: false,
+                })
+                .await?
+        }
+        ReconciliationAction::CreateUser {
+            username,
+            password,
+            status,
+            permissions,
+        } => {
+            client
+                .create_user(&CreateUser {
+                    username: username.clone(),
+                    password: password.clone(),
+                    status: *status,
+                    permissions: permissions.clone(),
+                })
+                .await?
+        }
+        ReconciliationAction::UpdateUserStatus { username, status } => {
+            client
+                .update_user(&UpdateUser {
+                    user_id: named_identifier(username)?,
+                    username: None,
+                    status: Some(status.to),
+                })
+                .await?
+        }
+        ReconciliationAction::UpdatePermissions {
+            username,
+            permissions,
+        } => {
+            client
+                .update_permissions(&UpdatePermissions {
+                    user_id: named_identifier(username)?,
+                    permissions: permissions.clone(),
+                })
+                .await?
+        }
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl CliCommand for ApplyDefinitionCmd {
+    fn explain(&self) -> String {
+        match self.dry_run {
+            true => format!(
+                "apply resources from file: {} in dry run mode",
+                self.path.display()
+            ),
+            false => format!("apply resources from file: {}", self.path.display()),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let definition = self.load_definition()?;
+        let existing = self.get_existing_resources(client, &definition).await;
+        let plan = ReconciliationPlan::new(
+            &definition,
+            &existing,
+            ReconciliationOptions {
+                update_existing: true,
+                default_message_expiry: None,
+            },
+        );
+
+        for warning in &plan.warnings {
+            event!(target: PRINT_TARGET, Level::INFO, "Warning: {warning}");
+        }
+
+        if plan.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO, "No changes, resources match the definition");
+            return Ok(());
+        }
+
+        for action in &plan.actions {
+            event!(target: PRINT_TARGET, Level::INFO, "{action}");
+            if !self.dry_run {
+                apply_action(client, action)
+                    .await
+                    .with_context(|| format!("Problem applying change: {action}"))?;
+            }
+        }
+
+        match self.dry_run {
+            true => {
+                event!(target: PRINT_TARGET, Level::INFO, "Dry run, {} change(s) not applied", plan.actions.len())
+            }
+            false => {
+                event!(target: PRINT_TARGET, Level::INFO, "{} change(s) applied", plan.actions.len())
+            }
+        }
+
+        Ok(())
+    }
+}
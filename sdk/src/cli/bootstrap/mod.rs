@@ -0,0 +1 @@
+pub mod apply_definition;
@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv_properties, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::system::get_client::GetClient;
@@ -8,12 +9,14 @@ use tracing::{event, Level};
 
 pub struct GetClientCmd {
     get_client: GetClient,
+    format: OutputFormat,
 }
 
 impl GetClientCmd {
-    pub fn new(client_id: u32) -> Self {
+    pub fn new(client_id: u32, format: OutputFormat) -> Self {
         Self {
             get_client: GetClient { client_id },
+            format,
         }
     }
 }
@@ -32,47 +35,56 @@ impl CliCommand for GetClientCmd {
             )
         })?;
 
-        let mut table = Table::new();
+        let properties = vec![
+            ("Client ID", format!("{}", client_details.client_id)),
+            (
+                "User ID",
+                match client_details.user_id {
+                    Some(user_id) => format!("{}", user_id),
+                    None => String::from("None"),
+                },
+            ),
+            ("Address", client_details.address.clone()),
+            ("Transport", client_details.transport.clone()),
+            (
+                "Consumer Groups Count",
+                format!("{}", client_details.consumer_groups_count),
+            ),
+        ];
 
-        table.set_header(vec!["Property", "Value"]);
-        table.add_row(vec![
-            "Client ID",
-            format!("{}", client_details.client_id).as_str(),
-        ]);
+        match self.format {
+            OutputFormat::Json => print_json(&client_details)?,
+            OutputFormat::Csv => print_csv_properties(&properties),
+            OutputFormat::Table => {
+                let mut table = Table::new();
 
-        let user = match client_details.user_id {
-            Some(user_id) => format!("{}", user_id),
-            None => String::from("None"),
-        };
-        table.add_row(vec!["User ID", user.as_str()]);
+                table.set_header(vec!["Property", "Value"]);
+                properties.iter().for_each(|(property, value)| {
+                    table.add_row(vec![*property, value.as_str()]);
+                });
 
-        table.add_row(vec!["Address", client_details.address.as_str()]);
-        table.add_row(vec!["Transport", client_details.transport.as_str()]);
-        table.add_row(vec![
-            "Consumer Groups Count",
-            format!("{}", client_details.consumer_groups_count).as_str(),
-        ]);
+                if client_details.consumer_groups_count > 0 {
+                    let mut consumer_groups = Table::new();
+                    consumer_groups.load_preset(ASCII_NO_BORDERS);
+                    consumer_groups.set_header(vec!["Stream ID", "Topic ID", "Consumer Group ID"]);
+                    for consumer_group in client_details.consumer_groups {
+                        consumer_groups.add_row(vec![
+                            format!("{}", consumer_group.stream_id).as_str(),
+                            format!("{}", consumer_group.topic_id).as_str(),
+                            format!("{}", consumer_group.consumer_group_id).as_str(),
+                        ]);
+                    }
 
-        if client_details.consumer_groups_count > 0 {
-            let mut consumer_groups = Table::new();
-            consumer_groups.load_preset(ASCII_NO_BORDERS);
-            consumer_groups.set_header(vec!["Stream ID", "Topic ID", "Consumer Group ID"]);
-            for consumer_group in client_details.consumer_groups {
-                consumer_groups.add_row(vec![
-                    format!("{}", consumer_group.stream_id).as_str(),
-                    format!("{}", consumer_group.topic_id).as_str(),
-                    format!("{}", consumer_group.consumer_group_id).as_str(),
-                ]);
-            }
+                    table.add_row(vec![
+                        "Consumer Groups Details",
+                        consumer_groups.to_string().as_str(),
+                    ]);
+                }
 
-            table.add_row(vec![
-                "Consumer Groups Details",
-                consumer_groups.to_string().as_str(),
-            ]);
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
         }
 
-        event!(target: PRINT_TARGET, Level::INFO, "{table}");
-
         Ok(())
     }
 }
@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::system::get_clients::GetClients;
@@ -14,13 +15,15 @@ pub enum GetClientsOutput {
 pub struct GetClientsCmd {
     get_clients: GetClients,
     output: GetClientsOutput,
+    format: OutputFormat,
 }
 
 impl GetClientsCmd {
-    pub fn new(output: GetClientsOutput) -> Self {
+    pub fn new(output: GetClientsOutput, format: OutputFormat) -> Self {
         GetClientsCmd {
             get_clients: GetClients {},
             output,
+            format,
         }
     }
 }
@@ -30,6 +33,7 @@ impl Default for GetClientsCmd {
         GetClientsCmd {
             get_clients: GetClients {},
             output: GetClientsOutput::Table,
+            format: OutputFormat::default(),
         }
     }
 }
@@ -50,51 +54,46 @@ impl CliCommand for GetClientsCmd {
             .await
             .with_context(|| String::from("Problem getting list of clients"))?;
 
-        if clients.is_empty() {
-            event!(target: PRINT_TARGET, Level::INFO, "No clients found!");
-            return Ok(());
-        }
+        let header = vec![
+            "Client ID",
+            "User ID",
+            "Address",
+            "Transport",
+            "Consumer Groups",
+        ];
+        let rows = clients
+            .iter()
+            .map(|client_info| {
+                vec![
+                    format!("{}", client_info.client_id),
+                    match client_info.user_id {
+                        Some(user_id) => format!("{}", user_id),
+                        None => String::from(""),
+                    },
+                    format!("{}", client_info.address),
+                    format!("{}", client_info.transport),
+                    format!("{}", client_info.consumer_groups_count),
+                ]
+            })
+            .collect::<Vec<_>>();
 
-        match self.output {
-            GetClientsOutput::Table => {
+        match (self.format, &self.output) {
+            (OutputFormat::Json, _) => print_json(&clients)?,
+            (OutputFormat::Csv, _) => print_csv(&header, &rows),
+            (OutputFormat::Table, _) if clients.is_empty() => {
+                event!(target: PRINT_TARGET, Level::INFO, "No clients found!");
+            }
+            (OutputFormat::Table, GetClientsOutput::Table) => {
                 let mut table = Table::new();
 
-                table.set_header(vec![
-                    "Client ID",
-                    "User ID",
-                    "Address",
-                    "Transport",
-                    "Consumer Groups",
-                ]);
-
-                clients.iter().for_each(|client_info| {
-                    table.add_row(vec![
-                        format!("{}", client_info.client_id),
-                        match client_info.user_id {
-                            Some(user_id) => format!("{}", user_id),
-                            None => String::from(""),
-                        },
-                        format!("{}", client_info.address),
-                        format!("{}", client_info.transport),
-                        format!("{}", client_info.consumer_groups_count),
-                    ]);
-                });
+                table.set_header(header);
+                table.add_rows(rows);
 
                 event!(target: PRINT_TARGET, Level::INFO, "{table}");
             }
-            GetClientsOutput::List => {
-                clients.iter().for_each(|client_info| {
-                    event!(target: PRINT_TARGET, Level::INFO,
-                        "{}|{}|{}|{}|{}",
-                        client_info.client_id,
-                        match client_info.user_id {
-                            Some(user_id) => format!("{}", user_id),
-                            None => String::from(""),
-                        },
-                        client_info.address,
-                        client_info.transport,
-                        client_info.consumer_groups_count
-                    );
+            (OutputFormat::Table, GetClientsOutput::List) => {
+                rows.iter().for_each(|row| {
+                    event!(target: PRINT_TARGET, Level::INFO, "{}", row.join("|"));
                 });
             }
         }
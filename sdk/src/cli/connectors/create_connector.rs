@@ -0,0 +1,69 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::connectors::create_connector::CreateConnector;
+use anyhow::Context;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tracing::{event, Level};
+
+pub struct CreateConnectorCmd {
+    create_connector: CreateConnector,
+}
+
+impl CreateConnectorCmd {
+    pub fn new(
+        name: String,
+        kind: String,
+        stream: String,
+        topic: String,
+        properties: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            create_connector: CreateConnector {
+                name,
+                kind,
+                stream,
+                topic,
+                properties,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CreateConnectorCmd {
+    fn explain(&self) -> String {
+        format!(
+            "create connector with name: {}, kind: {} for topic: {} and stream: {}",
+            self.create_connector.name,
+            self.create_connector.kind,
+            self.create_connector.topic,
+            self.create_connector.stream,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .create_connector(&self.create_connector)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem creating connector with name: {}, kind: {} for topic: {} and stream: {}",
+                    self.create_connector.name,
+                    self.create_connector.kind,
+                    self.create_connector.topic,
+                    self.create_connector.stream
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Connector with name: {}, kind: {} created for topic: {} and stream: {}",
+            self.create_connector.name,
+            self.create_connector.kind,
+            self.create_connector.topic,
+            self.create_connector.stream,
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::connectors::delete_connector::DeleteConnector;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct DeleteConnectorCmd {
+    delete_connector: DeleteConnector,
+}
+
+impl DeleteConnectorCmd {
+    pub fn new(name: String) -> Self {
+        Self {
+            delete_connector: DeleteConnector { name },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for DeleteConnectorCmd {
+    fn explain(&self) -> String {
+        format!("delete connector with name: {}", self.delete_connector.name)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .delete_connector(&self.delete_connector)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem deleting connector with name: {}",
+                    self.delete_connector.name
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Connector with name: {} deleted",
+            self.delete_connector.name,
+        );
+
+        Ok(())
+    }
+}
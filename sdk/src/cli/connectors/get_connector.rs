@@ -0,0 +1,79 @@
+use crate::cli::utils::output_format::{print_csv_properties, print_json, OutputFormat};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::connectors::get_connector::GetConnector;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetConnectorCmd {
+    get_connector: GetConnector,
+    format: OutputFormat,
+}
+
+impl GetConnectorCmd {
+    pub fn new(name: String, format: OutputFormat) -> Self {
+        Self {
+            get_connector: GetConnector { name },
+            format,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetConnectorCmd {
+    fn explain(&self) -> String {
+        format!("get connector with name: {}", self.get_connector.name)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let connector = client
+            .get_connector(&self.get_connector)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting connector with name: {}",
+                    self.get_connector.name
+                )
+            })?;
+
+        let mut properties = vec![
+            ("Connector name", connector.name.clone()),
+            ("Kind", connector.kind.clone()),
+            ("Stream", connector.stream.clone()),
+            ("Topic", connector.topic.clone()),
+            ("Status", format!("{}", connector.status)),
+            (
+                "Processed messages",
+                format!("{}", connector.processed_messages),
+            ),
+        ];
+        if let Some(error) = &connector.error {
+            properties.push(("Error", error.clone()));
+        }
+
+        match self.format {
+            OutputFormat::Json => print_json(&connector)?,
+            OutputFormat::Csv => print_csv_properties(&properties),
+            OutputFormat::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec!["Property", "Value"]);
+                properties.iter().for_each(|(property, value)| {
+                    table.add_row(vec![*property, value.as_str()]);
+                });
+
+                let mut connector_properties = connector.properties.iter().collect::<Vec<_>>();
+                connector_properties.sort();
+                for (key, value) in connector_properties {
+                    table.add_row(vec![format!("Property: {key}"), value.clone()]);
+                }
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::cli::utils::output_format::{print_csv, print_json, OutputFormat};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::connectors::get_connectors::GetConnectors;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use std::fmt::{self, Display, Formatter};
+use tracing::{event, Level};
+
+pub enum GetConnectorsOutput {
+    Table,
+    List,
+}
+
+impl Display for GetConnectorsOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GetConnectorsOutput::Table => write!(f, "table"),
+            GetConnectorsOutput::List => write!(f, "list"),
+        }?;
+
+        Ok(())
+    }
+}
+
+pub struct GetConnectorsCmd {
+    get_connectors: GetConnectors,
+    output: GetConnectorsOutput,
+    format: OutputFormat,
+}
+
+impl GetConnectorsCmd {
+    pub fn new(output: GetConnectorsOutput, format: OutputFormat) -> Self {
+        Self {
+            get_connectors: GetConnectors {},
+            output,
+            format,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetConnectorsCmd {
+    fn explain(&self) -> String {
+        format!("list connectors in {} mode", self.output)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let connectors = client
+            .get_connectors(&self.get_connectors)
+            .await
+            .with_context(|| String::from("Problem getting list of connectors"))?;
+
+        let header = vec![
+            "Name",
+            "Kind",
+            "Stream",
+            "Topic",
+            "Status",
+            "Processed Messages",
+        ];
+        let rows = connectors
+            .iter()
+            .map(|connector| {
+                vec![
+                    connector.name.clone(),
+                    connector.kind.clone(),
+                    connector.stream.clone(),
+                    connector.topic.clone(),
+                    format!("{}", connector.status),
+                    format!("{}", connector.processed_messages),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        match (self.format, &self.output) {
+            (OutputFormat::Json, _) => print_json(&connectors)?,
+            (OutputFormat::Csv, _) => print_csv(&header, &rows),
+            (OutputFormat::Table, GetConnectorsOutput::Table) => {
+                let mut table = Table::new();
+
+                table.set_header(header);
+                table.add_rows(rows);
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            (OutputFormat::Table, GetConnectorsOutput::List) => {
+                rows.iter().for_each(|row| {
+                    event!(target: PRINT_TARGET, Level::INFO, "{}", row.join("|"));
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod create_connector;
+pub mod delete_connector;
+pub mod get_connector;
+pub mod get_connectors;
+pub mod restart_connector;
@@ -0,0 +1,47 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::connectors::restart_connector::RestartConnector;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct RestartConnectorCmd {
+    restart_connector: RestartConnector,
+}
+
+impl RestartConnectorCmd {
+    pub fn new(name: String) -> Self {
+        Self {
+            restart_connector: RestartConnector { name },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RestartConnectorCmd {
+    fn explain(&self) -> String {
+        format!(
+            "restart connector with name: {}",
+            self.restart_connector.name
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .restart_connector(&self.restart_connector)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem restarting connector with name: {}",
+                    self.restart_connector.name
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Connector with name: {} restarted",
+            self.restart_connector.name,
+        );
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv_properties, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
@@ -9,16 +10,23 @@ use tracing::{event, Level};
 
 pub struct GetConsumerGroupCmd {
     get_consumer_group: GetConsumerGroup,
+    format: OutputFormat,
 }
 
 impl GetConsumerGroupCmd {
-    pub fn new(stream_id: Identifier, topic_id: Identifier, consumer_group_id: Identifier) -> Self {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        consumer_group_id: Identifier,
+        format: OutputFormat,
+    ) -> Self {
         Self {
             get_consumer_group: GetConsumerGroup {
                 stream_id,
                 topic_id,
                 consumer_group_id,
             },
+            format,
         }
     }
 }
@@ -45,45 +53,51 @@ impl CliCommand for GetConsumerGroupCmd {
                 )
             })?;
 
-        let mut table = Table::new();
+        let properties = vec![
+            ("Consumer group id", format!("{}", consumer_group.id)),
+            ("Consumer group name", consumer_group.name.clone()),
+            (
+                "Partitions count",
+                format!("{}", consumer_group.partitions_count),
+            ),
+            ("Members count", format!("{}", consumer_group.members_count)),
+        ];
 
-        table.set_header(vec!["Property", "Value"]);
-        table.add_row(vec![
-            "Consumer group id",
-            format!("{}", consumer_group.id).as_str(),
-        ]);
-        table.add_row(vec!["Consumer group name", consumer_group.name.as_str()]);
-        table.add_row(vec![
-            "Partitions count",
-            format!("{}", consumer_group.partitions_count).as_str(),
-        ]);
-        table.add_row(vec![
-            "Members count",
-            format!("{}", consumer_group.members_count).as_str(),
-        ]);
+        match self.format {
+            OutputFormat::Json => print_json(&consumer_group)?,
+            OutputFormat::Csv => print_csv_properties(&properties),
+            OutputFormat::Table => {
+                let mut table = Table::new();
 
-        if consumer_group.members_count > 0 {
-            let mut members_table = Table::new();
-            members_table.load_preset(ASCII_NO_BORDERS);
-            members_table.set_header(vec!["Member id", "Partitions count", "Partitions"]);
-            for member in consumer_group.members {
-                members_table.add_row(vec![
-                    format!("{}", member.id).as_str(),
-                    format!("{}", member.partitions_count).as_str(),
-                    member
-                        .partitions
-                        .iter()
-                        .map(|i| format!("{}", i))
-                        .collect::<Vec<String>>()
-                        .join(", ")
-                        .as_str(),
-                ]);
+                table.set_header(vec!["Property", "Value"]);
+                properties.iter().for_each(|(property, value)| {
+                    table.add_row(vec![*property, value.as_str()]);
+                });
+
+                if consumer_group.members_count > 0 {
+                    let mut members_table = Table::new();
+                    members_table.load_preset(ASCII_NO_BORDERS);
+                    members_table.set_header(vec!["Member id", "Partitions count", "Partitions"]);
+                    for member in consumer_group.members {
+                        members_table.add_row(vec![
+                            format!("{}", member.id).as_str(),
+                            format!("{}", member.partitions_count).as_str(),
+                            member
+                                .partitions
+                                .iter()
+                                .map(|i| format!("{}", i))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                                .as_str(),
+                        ]);
+                    }
+                    table.add_row(vec!["Members", members_table.to_string().as_str()]);
+                }
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
             }
-            table.add_row(vec!["Members", members_table.to_string().as_str()]);
         }
 
-        event!(target: PRINT_TARGET, Level::INFO,"{table}");
-
         Ok(())
     }
 }
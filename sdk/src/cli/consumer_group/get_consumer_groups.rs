@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
@@ -27,6 +28,7 @@ impl Display for GetConsumerGroupsOutput {
 pub struct GetConsumerGroupsCmd {
     get_consumer_groups: GetConsumerGroups,
     output: GetConsumerGroupsOutput,
+    format: OutputFormat,
 }
 
 impl GetConsumerGroupsCmd {
@@ -34,6 +36,7 @@ impl GetConsumerGroupsCmd {
         stream_id: Identifier,
         topic_id: Identifier,
         output: GetConsumerGroupsOutput,
+        format: OutputFormat,
     ) -> Self {
         Self {
             get_consumer_groups: GetConsumerGroups {
@@ -41,6 +44,7 @@ impl GetConsumerGroupsCmd {
                 topic_id,
             },
             output,
+            format,
         }
     }
 }
@@ -65,30 +69,33 @@ impl CliCommand for GetConsumerGroupsCmd {
                 )
             })?;
 
-        match self.output {
-            GetConsumerGroupsOutput::Table => {
+        let header = vec!["ID", "Name", "Partitions Count", "Members Count"];
+        let rows = consumer_groups
+            .iter()
+            .map(|group| {
+                vec![
+                    format!("{}", group.id),
+                    group.name.clone(),
+                    format!("{}", group.partitions_count),
+                    format!("{}", group.members_count),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        match (self.format, &self.output) {
+            (OutputFormat::Json, _) => print_json(&consumer_groups)?,
+            (OutputFormat::Csv, _) => print_csv(&header, &rows),
+            (OutputFormat::Table, GetConsumerGroupsOutput::Table) => {
                 let mut table = Table::new();
-                table.set_header(vec!["ID", "Name", "Partitions Count", "Members Count"]);
-                consumer_groups.iter().for_each(|group| {
-                    table.add_row(vec![
-                        format!("{}", group.id),
-                        group.name.clone(),
-                        format!("{}", group.partitions_count),
-                        format!("{}", group.members_count),
-                    ]);
-                });
+
+                table.set_header(header);
+                table.add_rows(rows);
 
                 event!(target: PRINT_TARGET, Level::INFO, "{table}");
             }
-            GetConsumerGroupsOutput::List => {
-                consumer_groups.iter().for_each(|group| {
-                    event!(target: PRINT_TARGET, Level::INFO,
-                        "{}|{}|{}|{}",
-                        group.id,
-                        group.name,
-                        group.partitions_count,
-                        group.members_count,
-                    );
+            (OutputFormat::Table, GetConsumerGroupsOutput::List) => {
+                rows.iter().for_each(|row| {
+                    event!(target: PRINT_TARGET, Level::INFO, "{}", row.join("|"));
                 });
             }
         }
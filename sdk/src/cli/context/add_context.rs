@@ -0,0 +1,46 @@
+use crate::cli::context::contexts::{Context, Contexts};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct AddContextCmd {
+    name: String,
+    context: Context,
+}
+
+impl AddContextCmd {
+    pub fn new(name: String, context: Context) -> Self {
+        Self { name, context }
+    }
+}
+
+#[async_trait]
+impl CliCommand for AddContextCmd {
+    fn explain(&self) -> String {
+        format!("add context: {}", self.name)
+    }
+
+    fn login_required(&self) -> bool {
+        false
+    }
+
+    fn connection_required(&self) -> bool {
+        false
+    }
+
+    async fn execute_cmd(&mut self, _client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let path = Contexts::default_path()?;
+        let mut contexts = Contexts::load(&path)?;
+        let updated = contexts
+            .contexts
+            .insert(self.name.clone(), self.context.clone())
+            .is_some();
+        contexts.save(&path)?;
+
+        let action = if updated { "updated" } else { "added" };
+        event!(target: PRINT_TARGET, Level::INFO, "Context: {} {action}", self.name);
+
+        Ok(())
+    }
+}
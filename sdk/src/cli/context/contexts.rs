@@ -0,0 +1,135 @@
+use anyhow::{bail, Context as _};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env::var;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+static ENV_XDG_CONFIG_HOME: &str = "XDG_CONFIG_HOME";
+static ENV_HOME: &str = "HOME";
+static CONTEXTS_FILE: &str = "iggy/contexts.toml";
+
+/// Named server profile used by the command line tool instead of passing
+/// the server address, transport and credentials in each invocation.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Context {
+    /// Transport used to connect to the server (`tcp`, `quic` or `http`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transport: Option<String>,
+    /// Server address for the TCP and QUIC transports or the API URL for the HTTP transport
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Password stored in plain text, the token name should be preferred
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// Name of the personal access token stored in the platform-specific secure storage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_name: Option<String>,
+    /// Enables TLS for the TCP transport and the certificate validation for the QUIC transport
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_enabled: Option<bool>,
+    /// TLS domain for the TCP transport and the server name for the QUIC transport
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_domain: Option<String>,
+}
+
+/// Contexts stored in the `iggy/contexts.toml` file in the user's configuration directory.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Contexts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<String>,
+    #[serde(default)]
+    pub contexts: BTreeMap<String, Context>,
+}
+
+impl Contexts {
+    /// Returns the path of the contexts file, `$XDG_CONFIG_HOME/iggy/contexts.toml`
+    /// or `~/.config/iggy/contexts.toml` when `XDG_CONFIG_HOME` is not set.
+    pub fn default_path() -> anyhow::Result<PathBuf> {
+        let config_dir = match (var(ENV_XDG_CONFIG_HOME), var(ENV_HOME)) {
+            (Ok(config_home), _) if !config_home.is_empty() => PathBuf::from(config_home),
+            (_, Ok(home)) => PathBuf::from(home).join(".config"),
+            _ => bail!("Cannot determine the user's configuration directory"),
+        };
+
+        Ok(config_dir.join(CONTEXTS_FILE))
+    }
+
+    /// Loads the contexts from given file, missing file means no contexts.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Problem reading contexts file: {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Problem parsing contexts file: {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Problem creating contexts directory: {}", parent.display())
+            })?;
+        }
+
+        let content = toml::to_string(self)?;
+        fs::write(path, content)
+            .with_context(|| format!("Problem writing contexts file: {}", path.display()))
+    }
+
+    /// Returns the context with given name or the active one when the name is not provided.
+    pub fn get(&self, name: Option<&str>) -> anyhow::Result<Option<&Context>> {
+        match name.or(self.active.as_deref()) {
+            Some(name) => match self.contexts.get(name) {
+                Some(context) => Ok(Some(context)),
+                None => bail!("Context: {name} does not exist"),
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_saved_and_loaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONTEXTS_FILE);
+        let mut contexts = Contexts {
+            active: Some("staging".into()),
+            ..Default::default()
+        };
+        contexts.contexts.insert(
+            "staging".into(),
+            Context {
+                transport: Some("tcp".into()),
+                server_address: Some("10.0.0.1:8090".into()),
+                token_name: Some("staging".into()),
+                tls_enabled: Some(true),
+                ..Default::default()
+            },
+        );
+
+        contexts.save(&path).unwrap();
+        let loaded = Contexts::load(&path).unwrap();
+
+        assert_eq!(loaded, contexts);
+        assert_eq!(loaded.get(None).unwrap(), contexts.contexts.get("staging"));
+    }
+
+    #[test]
+    fn missing_file_should_be_loaded_as_no_contexts() {
+        let dir = tempfile::tempdir().unwrap();
+        let contexts = Contexts::load(&dir.path().join(CONTEXTS_FILE)).unwrap();
+
+        assert_eq!(contexts, Contexts::default());
+        assert!(contexts.get(None).unwrap().is_none());
+        assert!(contexts.get(Some("missing")).is_err());
+    }
+}
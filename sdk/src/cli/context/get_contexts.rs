@@ -0,0 +1,98 @@
+use crate::cli::context::contexts::Contexts;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub enum GetContextsOutput {
+    Table,
+    List,
+}
+
+pub struct GetContextsCmd {
+    output: GetContextsOutput,
+}
+
+impl GetContextsCmd {
+    pub fn new(output: GetContextsOutput) -> Self {
+        Self { output }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetContextsCmd {
+    fn explain(&self) -> String {
+        let mode = match self.output {
+            GetContextsOutput::Table => "table",
+            GetContextsOutput::List => "list",
+        };
+        format!("list contexts in {mode} mode")
+    }
+
+    fn login_required(&self) -> bool {
+        false
+    }
+
+    fn connection_required(&self) -> bool {
+        false
+    }
+
+    async fn execute_cmd(&mut self, _client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let path = Contexts::default_path()?;
+        let contexts = Contexts::load(&path)?;
+
+        if contexts.contexts.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO, "No contexts found!");
+            return Ok(());
+        }
+
+        let rows = contexts
+            .contexts
+            .iter()
+            .map(|(name, context)| {
+                vec![
+                    match contexts.active.as_deref() == Some(name.as_str()) {
+                        true => String::from("*"),
+                        false => String::from(""),
+                    },
+                    name.clone(),
+                    context.transport.clone().unwrap_or_default(),
+                    context.server_address.clone().unwrap_or_default(),
+                    context.username.clone().unwrap_or_default(),
+                    context.token_name.clone().unwrap_or_default(),
+                    context
+                        .tls_enabled
+                        .map(|enabled| enabled.to_string())
+                        .unwrap_or_default(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        match self.output {
+            GetContextsOutput::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec![
+                    "Active",
+                    "Name",
+                    "Transport",
+                    "Server Address",
+                    "Username",
+                    "Token Name",
+                    "TLS",
+                ]);
+                table.add_rows(rows);
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetContextsOutput::List => {
+                rows.iter().for_each(|row| {
+                    event!(target: PRINT_TARGET, Level::INFO, "{}", row.join("|"));
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
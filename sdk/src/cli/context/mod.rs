@@ -0,0 +1,4 @@
+pub mod add_context;
+pub mod contexts;
+pub mod get_contexts;
+pub mod use_context;
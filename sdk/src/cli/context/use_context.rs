@@ -0,0 +1,46 @@
+use crate::cli::context::contexts::Contexts;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use anyhow::bail;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UseContextCmd {
+    name: String,
+}
+
+impl UseContextCmd {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UseContextCmd {
+    fn explain(&self) -> String {
+        format!("use context: {}", self.name)
+    }
+
+    fn login_required(&self) -> bool {
+        false
+    }
+
+    fn connection_required(&self) -> bool {
+        false
+    }
+
+    async fn execute_cmd(&mut self, _client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let path = Contexts::default_path()?;
+        let mut contexts = Contexts::load(&path)?;
+        if !contexts.contexts.contains_key(&self.name) {
+            bail!("Context: {} does not exist", self.name);
+        }
+
+        contexts.active = Some(self.name.clone());
+        contexts.save(&path)?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "Context: {} is active", self.name);
+
+        Ok(())
+    }
+}
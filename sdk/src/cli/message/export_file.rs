@@ -0,0 +1,113 @@
+use crate::models::messages::Message;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Identifies the files written by the export command.
+pub const EXPORT_FORMAT: &str = "iggy-export";
+/// The version of the export file format, bumped on the incompatible changes.
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// The first line of the export file, followed by the `ExportRecord` lines.
+/// Each line is a separate JSON object, so the file can be appended to and read back in chunks.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportHeader {
+    pub format: String,
+    pub version: u32,
+    /// The name of the exported stream.
+    pub stream: String,
+    /// The name of the exported topic.
+    pub topic: String,
+    /// The number of the partitions of the exported topic.
+    pub partitions_count: u32,
+}
+
+impl ExportHeader {
+    pub fn new(stream: &str, topic: &str, partitions_count: u32) -> Self {
+        Self {
+            format: EXPORT_FORMAT.to_string(),
+            version: EXPORT_FORMAT_VERSION,
+            stream: stream.to_string(),
+            topic: topic.to_string(),
+            partitions_count,
+        }
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.format != EXPORT_FORMAT {
+            bail!("Unsupported file format: {}", self.format);
+        }
+        if self.version != EXPORT_FORMAT_VERSION {
+            bail!(
+                "Unsupported file format version: {}, expected: {EXPORT_FORMAT_VERSION}",
+                self.version
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The exported message (including its offset, timestamp, ID and headers) along with the partition it was polled from.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportRecord {
+    pub partition_id: u32,
+    pub message: Message,
+}
+
+/// The progress of the export or import, stored next to the export file (with the `.progress` extension appended),
+/// so the interrupted command continues from where it stopped instead of starting over. Removed once the command completes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TransferProgress {
+    /// The number of bytes of the export file written (export) or processed (import) so far.
+    pub position: u64,
+    /// The number of messages exported or imported so far.
+    pub messages_count: u64,
+    /// The offset of the next message to be exported from each partition (export only).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub next_offsets: BTreeMap<u32, u64>,
+}
+
+impl TransferProgress {
+    pub fn path(file: &Path) -> PathBuf {
+        let mut path = file.as_os_str().to_owned();
+        path.push(".progress");
+        PathBuf::from(path)
+    }
+
+    /// Loads the progress of the previous, interrupted run, if there was any.
+    pub fn load(file: &Path) -> anyhow::Result<Option<Self>> {
+        let path = Self::path(file);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Problem reading progress file: {}", path.display()))?;
+        let progress = serde_json::from_str(&content)
+            .with_context(|| format!("Problem parsing progress file: {}", path.display()))?;
+        Ok(Some(progress))
+    }
+
+    /// Saves the progress to the temporary file first, so the progress file is never left partially written.
+    pub fn save(&self, file: &Path) -> anyhow::Result<()> {
+        let path = Self::path(file);
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        std::fs::write(&temp_path, serde_json::to_vec(self)?)
+            .with_context(|| format!("Problem writing progress file: {}", path.display()))?;
+        std::fs::rename(&temp_path, &path)
+            .with_context(|| format!("Problem writing progress file: {}", path.display()))?;
+        Ok(())
+    }
+
+    pub fn remove(file: &Path) -> anyhow::Result<()> {
+        let path = Self::path(file);
+        if !path.exists() {
+            return Ok(());
+        }
+
+        std::fs::remove_file(&path)
+            .with_context(|| format!("Problem removing progress file: {}", path.display()))
+    }
+}
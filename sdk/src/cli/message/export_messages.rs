@@ -0,0 +1,202 @@
+use crate::cli::message::export_file::{ExportHeader, ExportRecord, TransferProgress};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use crate::streams::get_stream::GetStream;
+use crate::topics::get_topic::GetTopic;
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use tracing::{event, Level};
+
+pub struct ExportMessagesCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_id: Option<u32>,
+    path: PathBuf,
+    batch_size: u32,
+}
+
+impl ExportMessagesCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: Option<u32>,
+        path: PathBuf,
+        batch_size: u32,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            partition_id,
+            path,
+            batch_size,
+        }
+    }
+
+    /// Creates the export file with the header or, if the previous export has been interrupted,
+    /// truncates the file to the last saved position, dropping the records written after the progress was saved.
+    fn open_file(
+        &self,
+        header: &ExportHeader,
+        progress: &mut Option<TransferProgress>,
+    ) -> anyhow::Result<BufWriter<File>> {
+        if let Some(resumed) = progress {
+            let mut file = OpenOptions::new()
+                .write(true)
+                .open(&self.path)
+                .with_context(|| format!("Problem opening file: {}", self.path.display()))?;
+            file.set_len(resumed.position)?;
+            file.seek(SeekFrom::End(0))?;
+            return Ok(BufWriter::new(file));
+        }
+
+        let file = File::create(&self.path)
+            .with_context(|| format!("Problem creating file: {}", self.path.display()))?;
+        let mut writer = BufWriter::new(file);
+        let mut line = serde_json::to_vec(header)?;
+        line.push(b'\n');
+        writer.write_all(&line)?;
+        *progress = Some(TransferProgress {
+            position: line.len() as u64,
+            ..TransferProgress::default()
+        });
+        Ok(writer)
+    }
+}
+
+#[async_trait]
+impl CliCommand for ExportMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "export messages from topic with ID: {} and stream with ID: {} to file: {}",
+            self.topic_id,
+            self.stream_id,
+            self.path.display()
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let stream = client
+            .get_stream(&GetStream {
+                stream_id: self.stream_id.clone(),
+            })
+            .await
+            .with_context(|| format!("Problem getting stream with ID: {}", self.stream_id))?;
+        let topic = client
+            .get_topic(&GetTopic {
+                stream_id: self.stream_id.clone(),
+                topic_id: self.topic_id.clone(),
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+
+        let mut partitions = topic
+            .partitions
+            .iter()
+            .filter(|partition| self.partition_id.map_or(true, |id| id == partition.id))
+            .collect::<Vec<_>>();
+        if partitions.is_empty() {
+            bail!(
+                "Partition with ID: {} does not exist in topic with ID: {} and stream with ID: {}",
+                self.partition_id.unwrap_or_default(),
+                self.topic_id,
+                self.stream_id
+            );
+        }
+        partitions.sort_by_key(|partition| partition.id);
+
+        let header = ExportHeader::new(&stream.name, &topic.name, topic.partitions_count);
+        let mut progress = TransferProgress::load(&self.path)?;
+        if progress.is_some() {
+            event!(target: PRINT_TARGET, Level::INFO, "Resuming interrupted export to file: {}", self.path.display());
+        }
+        let mut writer = self.open_file(&header, &mut progress)?;
+        let mut progress = progress.unwrap_or_default();
+
+        // Only the messages appended before the export has started are exported, so the export of the topic being written to completes.
+        for partition in partitions {
+            if partition.messages_count == 0 {
+                continue;
+            }
+
+            let end_offset = partition.current_offset;
+            let mut offset = progress
+                .next_offsets
+                .get(&partition.id)
+                .copied()
+                .unwrap_or_default();
+            while offset <= end_offset {
+                let polled_messages = client
+                    .poll_messages(&PollMessages {
+                        consumer: Consumer::default(),
+                        stream_id: self.stream_id.clone(),
+                        topic_id: self.topic_id.clone(),
+                        partition_id: Some(partition.id),
+                        strategy: PollingStrategy::offset(offset),
+                        count: self.batch_size,
+                        auto_commit: false,
+                        filter: None,
+                        mode: PollingMode::Peek,
+                        max_wait_ms: None,
+                        max_bytes: None,
+                    })
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem polling messages from partition with ID: {} at offset: {offset}",
+                            partition.id
+                        )
+                    })?;
+                let Some(last_offset) = polled_messages
+                    .messages
+                    .last()
+                    .map(|message| message.offset)
+                else {
+                    break;
+                };
+
+                for message in polled_messages.messages {
+                    if message.offset > end_offset {
+                        break;
+                    }
+
+                    let mut line = serde_json::to_vec(&ExportRecord {
+                        partition_id: partition.id,
+                        message,
+                    })?;
+                    line.push(b'\n');
+                    writer.write_all(&line)?;
+                    progress.position += line.len() as u64;
+                    progress.messages_count += 1;
+                }
+
+                offset = last_offset + 1;
+                writer.flush()?;
+                progress.next_offsets.insert(partition.id, offset);
+                progress.save(&self.path)?;
+            }
+        }
+
+        writer.flush()?;
+        TransferProgress::remove(&self.path)?;
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Exported {} messages from topic: {} and stream: {} to file: {}",
+            progress.messages_count,
+            topic.name,
+            stream.name,
+            self.path.display()
+        );
+
+        Ok(())
+    }
+}
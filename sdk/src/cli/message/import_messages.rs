@@ -0,0 +1,161 @@
+use crate::cli::message::export_file::{ExportHeader, ExportRecord, TransferProgress};
+use crate::cli::message::republish::republish_message;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::{Message, Partitioning, SendMessages};
+use anyhow::Context;
+use async_trait::async_trait;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::path::PathBuf;
+use tracing::{event, Level};
+
+pub struct ImportMessagesCmd {
+    stream_id: Option<Identifier>,
+    topic_id: Option<Identifier>,
+    path: PathBuf,
+    batch_size: u32,
+}
+
+/// The consecutive messages sent in a single request, all of them go to the same partition.
+struct ImportBatch {
+    partitioning: Partitioning,
+    messages: Vec<Message>,
+    /// The position in the file right after the last message of the batch.
+    end_position: u64,
+}
+
+impl ImportMessagesCmd {
+    pub fn new(
+        stream_id: Option<Identifier>,
+        topic_id: Option<Identifier>,
+        path: PathBuf,
+        batch_size: u32,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            path,
+            batch_size,
+        }
+    }
+
+    async fn send_batch(
+        &self,
+        client: &dyn Client,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        batch: ImportBatch,
+        progress: &mut TransferProgress,
+    ) -> anyhow::Result<()> {
+        let messages_count = batch.messages.len() as u64;
+        client
+            .send_messages(&mut SendMessages {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partitioning: batch.partitioning,
+                messages: batch.messages,
+                acks: None,
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem sending messages to topic with ID: {topic_id} and stream with ID: {stream_id}"
+                )
+            })?;
+        progress.position = batch.end_position;
+        progress.messages_count += messages_count;
+        progress.save(&self.path)
+    }
+}
+
+#[async_trait]
+impl CliCommand for ImportMessagesCmd {
+    fn explain(&self) -> String {
+        format!("import messages from file: {}", self.path.display())
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let file = File::open(&self.path)
+            .with_context(|| format!("Problem opening file: {}", self.path.display()))?;
+        let mut reader = BufReader::new(file);
+        let mut line = String::new();
+        let header_size = reader.read_line(&mut line)? as u64;
+        let header: ExportHeader = serde_json::from_str(&line)
+            .with_context(|| format!("Problem parsing file header: {}", self.path.display()))?;
+        header.validate()?;
+
+        let stream_id = match &self.stream_id {
+            Some(stream_id) => stream_id.clone(),
+            None => Identifier::named(&header.stream)?,
+        };
+        let topic_id = match &self.topic_id {
+            Some(topic_id) => topic_id.clone(),
+            None => Identifier::named(&header.topic)?,
+        };
+
+        let mut progress = match TransferProgress::load(&self.path)? {
+            Some(progress) => {
+                event!(target: PRINT_TARGET, Level::INFO, "Resuming interrupted import from file: {}, {} messages already imported", self.path.display(), progress.messages_count);
+                reader.seek(SeekFrom::Start(progress.position))?;
+                progress
+            }
+            None => TransferProgress {
+                position: header_size,
+                ..TransferProgress::default()
+            },
+        };
+
+        let mut position = progress.position;
+        let mut batch: Option<ImportBatch> = None;
+        loop {
+            line.clear();
+            let line_size = reader.read_line(&mut line)? as u64;
+            if line_size == 0 {
+                break;
+            }
+
+            let record: ExportRecord = serde_json::from_str(&line).with_context(|| {
+                format!(
+                    "Problem parsing message at position: {position} of file: {}",
+                    self.path.display()
+                )
+            })?;
+            position += line_size;
+            let id = record.message.id;
+            let (partitioning, message) =
+                republish_message(record.partition_id, record.message, Some(id))?;
+
+            // The messages are sent in the order they have been exported, the batch is sent once it's full or the partition changes.
+            if let Some(pending) = batch.take_if(|pending| {
+                pending.partitioning != partitioning
+                    || pending.messages.len() >= self.batch_size as usize
+            }) {
+                self.send_batch(client, &stream_id, &topic_id, pending, &mut progress)
+                    .await?;
+            }
+            let pending = batch.get_or_insert_with(|| ImportBatch {
+                partitioning,
+                messages: Vec::new(),
+                end_position: position,
+            });
+            pending.messages.push(message);
+            pending.end_position = position;
+        }
+
+        if let Some(pending) = batch {
+            self.send_batch(client, &stream_id, &topic_id, pending, &mut progress)
+                .await?;
+        }
+
+        TransferProgress::remove(&self.path)?;
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Imported {} messages to topic with ID: {topic_id} and stream with ID: {stream_id} from file: {}",
+            progress.messages_count,
+            self.path.display()
+        );
+
+        Ok(())
+    }
+}
@@ -1,2 +1,8 @@
+pub mod export_file;
+pub mod export_messages;
+pub mod import_messages;
 pub mod poll_messages;
+pub mod query_messages;
+pub mod replay_messages;
+mod republish;
 pub mod send_messages;
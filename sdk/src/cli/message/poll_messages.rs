@@ -2,16 +2,55 @@ use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer::Consumer;
 use crate::identifier::Identifier;
-use crate::messages::poll_messages::{PollMessages, PollingStrategy};
+use crate::messages::headers_filter::{self, HeadersFilter};
+use crate::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy, MAX_WAIT_MS};
+use crate::models::messages::{Message, PolledMessages};
+use crate::models::schema::SchemaFormat;
+use crate::schemas::decoding::PayloadDecoder;
+use crate::schemas::get_schema::GetSchema;
 use crate::utils::{byte_size::IggyByteSize, duration::IggyDuration, timestamp::IggyTimestamp};
-use anyhow::Context;
+use anyhow::{bail, Context};
 use async_trait::async_trait;
 use comfy_table::Table;
+use serde_json::{json, Map, Value};
 use std::mem::size_of_val;
+use std::path::PathBuf;
 use tracing::{event, Level};
 
+/// Schema used to decode the polled message payloads into the structured JSON.
+pub enum PayloadSchema {
+    /// Schema registered under the given ID.
+    Registered(u32),
+    /// Schema read from the file, the format is inferred from the file extension if not provided.
+    File(PathBuf, Option<SchemaFormat>),
+}
+
+pub struct PayloadDecoding {
+    pub schema: PayloadSchema,
+    /// Name of the Protobuf message type, by default the first message defined in the file is used.
+    pub message_type: Option<String>,
+}
+
+/// Format in which the polled messages are printed.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum MessagesFormat {
+    /// Table with the offset, timestamp, ID, length and payload of the messages.
+    #[default]
+    Table,
+    /// Payload of each message printed in a separate line.
+    Raw,
+    /// Payload of each message printed as the hexadecimal string in a separate line.
+    Hex,
+    /// Each message including its headers printed as the JSON object in a separate line.
+    Json,
+}
+
 pub struct PollMessagesCmd {
     poll_messages: PollMessages,
+    decoding: Option<PayloadDecoding>,
+    format: MessagesFormat,
+    /// Interval between the polls when following the new messages, the messages are polled only once if not set.
+    follow: Option<IggyDuration>,
 }
 
 impl PollMessagesCmd {
@@ -27,6 +66,11 @@ impl PollMessagesCmd {
         last: bool,
         next: bool,
         consumer: Identifier,
+        filter: Option<HeadersFilter>,
+        mode: PollingMode,
+        decoding: Option<PayloadDecoding>,
+        format: MessagesFormat,
+        follow: Option<IggyDuration>,
     ) -> Self {
         let strategy = match (offset, first, last, next) {
             (Some(offset), false, false, false) => PollingStrategy::offset(offset),
@@ -44,33 +88,65 @@ impl PollMessagesCmd {
                 strategy,
                 count: message_count,
                 auto_commit,
+                filter,
+                mode,
+                // In the follow mode the server holds the poll until the new messages arrive, instead of polling in a loop.
+                max_wait_ms: follow.map(|interval| {
+                    interval.get_duration().as_millis().min(MAX_WAIT_MS as u128) as u32
+                }),
+                max_bytes: None,
             },
+            decoding,
+            format,
+            follow,
         }
     }
-}
 
-#[async_trait]
-impl CliCommand for PollMessagesCmd {
-    fn explain(&self) -> String {
-        format!(
-            "poll messages from topic ID: {} and stream with ID: {}",
-            self.poll_messages.topic_id, self.poll_messages.stream_id
-        )
-    }
+    async fn get_decoder(&self, client: &dyn Client) -> anyhow::Result<Option<PayloadDecoder>> {
+        let Some(decoding) = &self.decoding else {
+            return Ok(None);
+        };
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        let start = std::time::Instant::now();
-        let messages = client
-            .poll_messages(&self.poll_messages)
-            .await
-            .with_context(|| {
-                format!(
-                    "Problem polling messages to topic with ID: {} and stream with ID: {}",
-                    self.poll_messages.topic_id, self.poll_messages.stream_id
-                )
-            })?;
-        let elapsed = IggyDuration::new(start.elapsed());
+        let message_type = decoding.message_type.as_deref();
+        let decoder = match &decoding.schema {
+            PayloadSchema::Registered(schema_id) => {
+                let schema = client
+                    .get_schema(&GetSchema {
+                        schema_id: *schema_id,
+                    })
+                    .await
+                    .with_context(|| format!("Problem getting schema with ID: {schema_id}"))?;
+                PayloadDecoder::new(schema.format, &schema.schema, message_type)?
+            }
+            PayloadSchema::File(path, format) => {
+                let format = match format {
+                    Some(format) => *format,
+                    None => match path.extension().and_then(|extension| extension.to_str()) {
+                        Some("avsc") => SchemaFormat::Avro,
+                        Some("proto") => SchemaFormat::Protobuf,
+                        Some("json") => SchemaFormat::Json,
+                        _ => bail!(
+                            "Cannot infer the schema format from the file: {}, please specify it explicitly",
+                            path.display()
+                        ),
+                    },
+                };
+                let schema = std::fs::read_to_string(path).with_context(|| {
+                    format!("Problem reading schema from the file: {}", path.display())
+                })?;
+                PayloadDecoder::new(format, &schema, message_type)?
+            }
+        };
+
+        Ok(Some(decoder))
+    }
 
+    fn print_messages(
+        &self,
+        messages: &PolledMessages,
+        elapsed: IggyDuration,
+        decoder: Option<&PayloadDecoder>,
+    ) {
         event!(target: PRINT_TARGET, Level::INFO,
             "Polled messages from topic with ID: {} and stream with ID: {} (from partition with ID: {})",
             self.poll_messages.topic_id,
@@ -88,21 +164,125 @@ impl CliCommand for PollMessagesCmd {
 
         event!(target: PRINT_TARGET, Level::INFO, "Polled {} messages of total size {polled_size}, it took {}", messages.messages.len(), elapsed.as_human_time_string());
 
-        let mut table = Table::new();
-        table.set_header(vec!["Offset", "Timestamp", "ID", "Length", "Payload"]);
+        // Messages in other formats than the table are printed also in the quiet mode, so they can be piped.
+        match self.format {
+            MessagesFormat::Table => {
+                let mut table = Table::new();
+                table.set_header(vec!["Offset", "Timestamp", "ID", "Length", "Payload"]);
+
+                messages.messages.iter().for_each(|message| {
+                    table.add_row(vec![
+                        format!("{}", message.offset),
+                        IggyTimestamp::from(message.timestamp).to_local("%Y-%m-%d %H:%M:%S%.6f"),
+                        format!("{}", message.id),
+                        format!("{}", message.payload.len()),
+                        get_payload(message, decoder),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            MessagesFormat::Raw => messages
+                .messages
+                .iter()
+                .for_each(|message| println!("{}", get_payload(message, decoder))),
+            MessagesFormat::Hex => messages
+                .messages
+                .iter()
+                .for_each(|message| println!("{}", to_hex(&message.payload))),
+            MessagesFormat::Json => messages
+                .messages
+                .iter()
+                .for_each(|message| println!("{}", to_json(message, decoder))),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for PollMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "poll messages from topic ID: {} and stream with ID: {}",
+            self.poll_messages.topic_id, self.poll_messages.stream_id
+        )
+    }
 
-        messages.messages.iter().for_each(|message| {
-            table.add_row(vec![
-                format!("{}", message.offset),
-                IggyTimestamp::from(message.timestamp).to_local("%Y-%m-%d %H:%M:%S%.6f"),
-                format!("{}", message.id),
-                format!("{}", message.payload.len()),
-                String::from_utf8_lossy(&message.payload).to_string(),
-            ]);
-        });
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let decoder = self.get_decoder(client).await?;
+        loop {
+            let start = std::time::Instant::now();
+            let messages = client
+                .poll_messages(&self.poll_messages)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem polling messages to topic with ID: {} and stream with ID: {}",
+                        self.poll_messages.topic_id, self.poll_messages.stream_id
+                    )
+                })?;
+            let elapsed = IggyDuration::new(start.elapsed());
+
+            let Some(interval) = self.follow else {
+                self.print_messages(&messages, elapsed, decoder.as_ref());
+                return Ok(());
+            };
 
-        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            // Keep polling from the message following the last polled one, regardless of the initial strategy.
+            // The server may not support the long polling, so the rest of the interval is waited on the client side.
+            let Some(last_message) = messages.messages.last() else {
+                let remaining = interval.get_duration().saturating_sub(start.elapsed());
+                tokio::time::sleep(remaining).await;
+                continue;
+            };
 
-        Ok(())
+            self.poll_messages.strategy = PollingStrategy::offset(last_message.offset + 1);
+            self.print_messages(&messages, elapsed, decoder.as_ref());
+        }
+    }
+}
+
+fn get_payload(message: &Message, decoder: Option<&PayloadDecoder>) -> String {
+    match decoder.map(|decoder| decoder.decode(&message.payload)) {
+        Some(Ok(payload)) => payload.to_string(),
+        Some(Err(error)) => {
+            format!("{} ({error})", String::from_utf8_lossy(&message.payload))
+        }
+        None => String::from_utf8_lossy(&message.payload).to_string(),
     }
 }
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn to_json(message: &Message, decoder: Option<&PayloadDecoder>) -> Value {
+    let headers = message.headers.as_ref().map(|headers| {
+        headers
+            .iter()
+            .map(|(key, value)| {
+                // The raw values which are not a valid UTF-8 are printed as the hexadecimal strings.
+                let text = match headers_filter::get_text(value) {
+                    Some(text) => text.to_string(),
+                    None => to_hex(&value.value),
+                };
+                (
+                    key.as_str().to_string(),
+                    json!({ "kind": value.kind.to_string(), "value": text }),
+                )
+            })
+            .collect::<Map<String, Value>>()
+    });
+    let payload = match decoder.map(|decoder| decoder.decode(&message.payload)) {
+        Some(Ok(payload)) => payload,
+        _ => Value::String(String::from_utf8_lossy(&message.payload).to_string()),
+    };
+
+    json!({
+        "offset": message.offset,
+        "timestamp": message.timestamp,
+        "id": message.id.to_string(),
+        "checksum": message.checksum,
+        "headers": headers,
+        "payload": payload,
+    })
+}
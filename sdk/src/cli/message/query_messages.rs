@@ -0,0 +1,62 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::messages::messages_query::MessagesQuery;
+use crate::messages::query_messages::QueryMessages;
+use crate::utils::duration::IggyDuration;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use serde_json::Value;
+use tracing::{event, Level};
+
+pub struct QueryMessagesCmd {
+    query_messages: QueryMessages,
+}
+
+impl QueryMessagesCmd {
+    pub fn new(query: MessagesQuery) -> Self {
+        Self {
+            query_messages: QueryMessages { query },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for QueryMessagesCmd {
+    fn explain(&self) -> String {
+        format!("query messages: {}", self.query_messages.query)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let start = std::time::Instant::now();
+        let result = client
+            .query_messages(&self.query_messages)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem querying messages with: {}",
+                    self.query_messages.query
+                )
+            })?;
+        let elapsed = IggyDuration::new(start.elapsed());
+
+        event!(target: PRINT_TARGET, Level::INFO, "Query returned {} rows, it took {}", result.rows.len(), elapsed.as_human_time_string());
+        if let Some(next_offset) = result.next_offset {
+            event!(target: PRINT_TARGET, Level::INFO, "Query reached the limit of the scanned messages, it can be continued from offset: {next_offset}");
+        }
+
+        let mut table = Table::new();
+        table.set_header(&result.columns);
+        result.rows.iter().for_each(|row| {
+            table.add_row(row.iter().map(|value| match value {
+                Value::String(value) => value.clone(),
+                Value::Null => String::new(),
+                value => value.to_string(),
+            }));
+        });
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+use crate::cli::message::republish::republish_message;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use crate::messages::send_messages::{Partitioning, SendMessages};
+use crate::models::header::{HeaderKey, HeaderValue};
+use anyhow::Context;
+use async_trait::async_trait;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tracing::{event, Level};
+
+/// The header stamped on the replayed messages (if enabled), with the source of the message in the `stream/topic/partition/offset` format.
+pub const REPLAYED_FROM_HEADER: &str = "replayed-from";
+
+/// The range of the offsets to replay, given as `START..END` (end exclusive), `START..=END` (end inclusive) or `START..`
+/// (up to the last message appended before the replay has started).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OffsetRange {
+    pub start: u64,
+    /// The first offset which is not replayed, unbounded if not set.
+    pub end: Option<u64>,
+}
+
+impl FromStr for OffsetRange {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let Some((start, end)) = value.split_once("..") else {
+            return Err(format!(
+                "Invalid offset range: {value}, expected format is: START..END, START..=END or START.."
+            ));
+        };
+        let parse = |offset: &str| {
+            offset
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid offset: {offset} in offset range: {value}"))
+        };
+
+        let start = parse(start)?;
+        let end = match end.strip_prefix('=') {
+            Some(end) => Some(parse(end)? + 1),
+            None if end.is_empty() => None,
+            None => Some(parse(end)?),
+        };
+        if end.is_some_and(|end| end <= start) {
+            return Err(format!("Empty offset range: {value}"));
+        }
+
+        Ok(Self { start, end })
+    }
+}
+
+impl Display for OffsetRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.end {
+            Some(end) => write!(f, "{}..{end}", self.start),
+            None => write!(f, "{}..", self.start),
+        }
+    }
+}
+
+pub struct ReplayMessagesCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_id: u32,
+    offsets: OffsetRange,
+    target_stream_id: Identifier,
+    target_topic_id: Identifier,
+    target_partition_id: Option<u32>,
+    batch_size: u32,
+    /// The maximum number of the replayed messages per second, unlimited if not set.
+    rate: Option<u32>,
+    stamp_replayed_from: bool,
+}
+
+impl ReplayMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        offsets: OffsetRange,
+        target_stream_id: Identifier,
+        target_topic_id: Identifier,
+        target_partition_id: Option<u32>,
+        batch_size: u32,
+        rate: Option<u32>,
+        stamp_replayed_from: bool,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            partition_id,
+            offsets,
+            target_stream_id,
+            target_topic_id,
+            target_partition_id,
+            // The batches are never bigger than the number of messages allowed per second, so the rate is kept evenly.
+            batch_size: rate.map_or(batch_size, |rate| batch_size.min(rate)),
+            rate,
+            stamp_replayed_from,
+        }
+    }
+
+    /// Waits until sending the given number of messages in total doesn't exceed the rate.
+    async fn throttle(&self, started_at: Instant, messages_count: u64) {
+        let Some(rate) = self.rate else {
+            return;
+        };
+
+        let expected = Duration::from_secs_f64(messages_count as f64 / rate as f64);
+        let elapsed = started_at.elapsed();
+        if expected > elapsed {
+            tokio::time::sleep(expected - elapsed).await;
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ReplayMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "replay messages with offsets: {} from partition with ID: {} of topic with ID: {} and stream with ID: {} to topic with ID: {} and stream with ID: {}",
+            self.offsets,
+            self.partition_id,
+            self.topic_id,
+            self.stream_id,
+            self.target_topic_id,
+            self.target_stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let started_at = Instant::now();
+        let mut offset = self.offsets.start;
+        let mut end = self.offsets.end;
+        let mut messages_count = 0u64;
+        while end.is_none_or(|end| offset < end) {
+            let count = end.map_or(self.batch_size as u64, |end| {
+                (end - offset).min(self.batch_size as u64)
+            });
+            let polled_messages = client
+                .poll_messages(&PollMessages {
+                    consumer: Consumer::default(),
+                    stream_id: self.stream_id.clone(),
+                    topic_id: self.topic_id.clone(),
+                    partition_id: Some(self.partition_id),
+                    strategy: PollingStrategy::offset(offset),
+                    count: count as u32,
+                    auto_commit: false,
+                    filter: None,
+                    mode: PollingMode::Peek,
+                    max_wait_ms: None,
+                    max_bytes: None,
+                })
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem polling messages from partition with ID: {} at offset: {offset}",
+                        self.partition_id
+                    )
+                })?;
+
+            // The open range ends with the last message appended before the replay has started,
+            // so replaying to the same topic doesn't replay the replayed messages over and over.
+            let end_offset = *end.get_or_insert(polled_messages.current_offset + 1);
+            let Some(last_offset) = polled_messages
+                .messages
+                .last()
+                .map(|message| message.offset)
+            else {
+                break;
+            };
+
+            let mut batches: Vec<(Partitioning, Vec<_>)> = Vec::new();
+            for message in polled_messages.messages {
+                if message.offset >= end_offset {
+                    break;
+                }
+
+                let source = format!(
+                    "{}/{}/{}/{}",
+                    self.stream_id, self.topic_id, self.partition_id, message.offset
+                );
+                let (mut partitioning, mut message) =
+                    republish_message(self.partition_id, message, None)?;
+                if let Some(partition_id) = self.target_partition_id {
+                    partitioning = Partitioning::partition_id(partition_id);
+                }
+                if self.stamp_replayed_from {
+                    message.headers.get_or_insert_with(Default::default).insert(
+                        HeaderKey::new(REPLAYED_FROM_HEADER)?,
+                        HeaderValue::from_str(&source)?,
+                    );
+                }
+
+                // The consecutive messages with the same partitioning are sent together, keeping their order.
+                match batches.last_mut() {
+                    Some((last, messages)) if *last == partitioning => messages.push(message),
+                    _ => batches.push((partitioning, vec![message])),
+                }
+            }
+
+            for (partitioning, messages) in batches {
+                let count = messages.len() as u64;
+                client
+                    .send_messages(&mut SendMessages {
+                        stream_id: self.target_stream_id.clone(),
+                        topic_id: self.target_topic_id.clone(),
+                        partitioning,
+                        messages,
+                        acks: None,
+                    })
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem sending messages to topic with ID: {} and stream with ID: {}",
+                            self.target_topic_id, self.target_stream_id
+                        )
+                    })?;
+                messages_count += count;
+                self.throttle(started_at, messages_count).await;
+            }
+
+            offset = last_offset + 1;
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Replayed {messages_count} messages to topic with ID: {} and stream with ID: {}",
+            self.target_topic_id,
+            self.target_stream_id
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_range_should_be_parsed() {
+        assert_eq!(
+            "1000..2000".parse::<OffsetRange>().unwrap(),
+            OffsetRange {
+                start: 1000,
+                end: Some(2000)
+            }
+        );
+        assert_eq!(
+            "1000..=2000".parse::<OffsetRange>().unwrap(),
+            OffsetRange {
+                start: 1000,
+                end: Some(2001)
+            }
+        );
+        assert_eq!(
+            "10..".parse::<OffsetRange>().unwrap(),
+            OffsetRange {
+                start: 10,
+                end: None
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_offset_range_should_be_rejected() {
+        assert!("1000".parse::<OffsetRange>().is_err());
+        assert!("..2000".parse::<OffsetRange>().is_err());
+        assert!("2000..1000".parse::<OffsetRange>().is_err());
+        assert!("1000..1000".parse::<OffsetRange>().is_err());
+        assert!("a..b".parse::<OffsetRange>().is_err());
+    }
+}
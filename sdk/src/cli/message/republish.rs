@@ -0,0 +1,32 @@
+use crate::messages::send_messages::{Message, Partitioning};
+use crate::models::header::{
+    HeaderKey, HeaderValue, CREATE_TIMESTAMP_HEADER, PARTITION_KEY_HEADER,
+};
+use crate::models::messages::Message as PolledMessage;
+
+/// Converts the polled message into the one sent again, e.g. to another topic or server.
+/// The messages appended with the key are sent with the same key, so it's stored again and the partition is calculated
+/// by the target server, otherwise the original partition is used. The system headers stamped by the source server
+/// are dropped, the original timestamp is sent as the create timestamp, so it's preserved by the topics
+/// with the `create_time` timestamp type. The original ID is kept only if provided, otherwise the server generates a new one.
+pub(crate) fn republish_message(
+    partition_id: u32,
+    message: PolledMessage,
+    id: Option<u128>,
+) -> anyhow::Result<(Partitioning, Message)> {
+    let mut partitioning = Partitioning::partition_id(partition_id);
+    let mut headers = message.headers.unwrap_or_default();
+    if let Some(key) = headers.get(&HeaderKey::new(PARTITION_KEY_HEADER)?) {
+        partitioning = Partitioning::messages_key(&key.value)?;
+    }
+    headers.retain(|key, _| !key.is_system());
+    headers.insert(
+        HeaderKey::new(CREATE_TIMESTAMP_HEADER)?,
+        HeaderValue::from_uint64(message.timestamp)?,
+    );
+
+    Ok((
+        partitioning,
+        Message::new(id, message.payload, Some(headers)),
+    ))
+}
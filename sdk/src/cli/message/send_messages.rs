@@ -83,6 +83,7 @@ impl CliCommand for SendMessagesCmd {
                 topic_id: self.topic_id.clone(),
                 partitioning: self.partitioning.clone(),
                 messages,
+                acks: None,
             })
             .await
             .with_context(|| {
@@ -11,12 +11,18 @@ pub struct CreatePartitionsCmd {
 }
 
 impl CreatePartitionsCmd {
-    pub fn new(stream_id: Identifier, topic_id: Identifier, partitions_count: u32) -> Self {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partitions_count: u32,
+        repartition: bool,
+    ) -> Self {
         Self {
             create_partition: CreatePartitions {
                 stream_id,
                 topic_id,
                 partitions_count,
+                repartition,
             },
         }
     }
@@ -1,25 +1,86 @@
+use crate::cli::utils::confirmation::Confirmation;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
-use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::delete_partitions::{DeletePartitions, DeletePartitionsMode};
+use crate::topics::get_topic::GetTopic;
+use crate::utils::byte_size::IggyByteSize;
 use anyhow::Context;
 use async_trait::async_trait;
 use tracing::{event, Level};
 
 pub struct DeletePartitionsCmd {
     delete_partitions: DeletePartitions,
+    confirmation: Confirmation,
 }
 
 impl DeletePartitionsCmd {
-    pub fn new(stream_id: Identifier, topic_id: Identifier, partitions_count: u32) -> Self {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partitions_count: u32,
+        mode: DeletePartitionsMode,
+        confirmation: Confirmation,
+    ) -> Self {
         Self {
             delete_partitions: DeletePartitions {
                 stream_id,
                 topic_id,
                 partitions_count,
+                mode,
             },
+            confirmation,
         }
     }
+
+    /// The partitions with the highest IDs are deleted, so the remaining ones keep their IDs.
+    async fn get_summary(&self, client: &dyn Client) -> anyhow::Result<String> {
+        let topic = client
+            .get_topic(&GetTopic {
+                stream_id: self.delete_partitions.stream_id.clone(),
+                topic_id: self.delete_partitions.topic_id.clone(),
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {} and stream with ID: {}",
+                    self.delete_partitions.topic_id, self.delete_partitions.stream_id
+                )
+            })?;
+
+        let mut partitions = topic.partitions.iter().collect::<Vec<_>>();
+        partitions.sort_by_key(|partition| partition.id);
+        let deleted = &partitions[partitions
+            .len()
+            .saturating_sub(self.delete_partitions.partitions_count as usize)..];
+        let messages_count = deleted
+            .iter()
+            .map(|partition| partition.messages_count)
+            .sum::<u64>();
+        let size = IggyByteSize::from(
+            deleted
+                .iter()
+                .map(|partition| partition.size_bytes.as_bytes_u64())
+                .sum::<u64>(),
+        );
+
+        Ok(format!(
+            "Partitions with IDs: {} of topic: {} (ID: {}) in stream with ID: {} with {messages_count} messages of size {size} will be deleted{}",
+            deleted
+                .iter()
+                .map(|partition| partition.id.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            topic.name,
+            topic.id,
+            self.delete_partitions.stream_id,
+            match self.delete_partitions.mode {
+                DeletePartitionsMode::Refuse => ", unless any of them contains messages",
+                DeletePartitionsMode::Discard => " along with their messages",
+                DeletePartitionsMode::Drain => ", their messages will be moved to the remaining partitions",
+            }
+        ))
+    }
 }
 
 #[async_trait]
@@ -39,6 +100,14 @@ impl CliCommand for DeletePartitionsCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        if self.confirmation.is_required()
+            && !self
+                .confirmation
+                .confirm(&self.get_summary(client).await?)?
+        {
+            return Ok(());
+        }
+
         let mut partitions = String::from("partition");
         if self.delete_partitions.partitions_count > 1 {
             partitions.push('s');
@@ -0,0 +1,128 @@
+use crate::cli::utils::output_format::{print_csv, print_json, OutputFormat};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::get_partition_details::GetPartitionDetails;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetPartitionCmd {
+    get_partition: GetPartitionDetails,
+    format: OutputFormat,
+}
+
+impl GetPartitionCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        format: OutputFormat,
+    ) -> Self {
+        Self {
+            get_partition: GetPartitionDetails {
+                stream_id,
+                topic_id,
+                partition_id,
+            },
+            format,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetPartitionCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get partition with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.get_partition.partition_id,
+            self.get_partition.topic_id,
+            self.get_partition.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let partition = client
+            .get_partition_details(&self.get_partition)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.get_partition.partition_id,
+                    self.get_partition.topic_id,
+                    self.get_partition.stream_id
+                )
+            })?;
+
+        let header = vec![
+            "Start offset",
+            "End offset",
+            "Size",
+            "Closed",
+            "Message expiry",
+            "Expired",
+        ];
+        let rows = partition
+            .segments
+            .iter()
+            .map(|segment| {
+                vec![
+                    format!("{}", segment.start_offset),
+                    format!("{}", segment.end_offset),
+                    format!("{}", segment.size_bytes),
+                    format!("{}", segment.is_closed),
+                    match segment.message_expiry {
+                        Some(value) => format!("{}", value),
+                        None => String::from("unlimited"),
+                    },
+                    format!("{}", segment.is_expired),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        match self.format {
+            OutputFormat::Json => print_json(&partition)?,
+            OutputFormat::Csv => print_csv(&header, &rows),
+            OutputFormat::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec!["Property", "Value"]);
+                table.add_row(vec!["Partition id", format!("{}", partition.id).as_str()]);
+                table.add_row(vec![
+                    "Created",
+                    IggyTimestamp::from(partition.created_at)
+                        .to_string("%Y-%m-%d %H:%M:%S")
+                        .as_str(),
+                ]);
+                table.add_row(vec![
+                    "Current offset",
+                    format!("{}", partition.current_offset).as_str(),
+                ]);
+                table.add_row(vec![
+                    "Messages count",
+                    format!("{}", partition.messages_count).as_str(),
+                ]);
+                table.add_row(vec!["Size", format!("{}", partition.size_bytes).as_str()]);
+                table.add_row(vec![
+                    "Segments count",
+                    format!("{}", partition.segments_count).as_str(),
+                ]);
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+                let mut table = Table::new();
+
+                table.set_header(header);
+                rows.into_iter().for_each(|row| {
+                    table.add_row(row);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+        }
+
+        Ok(())
+    }
+}
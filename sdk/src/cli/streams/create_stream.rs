@@ -1,5 +1,6 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::models::topic_defaults::TopicDefaults;
 use crate::streams::create_stream::CreateStream;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -10,9 +11,13 @@ pub struct CreateStreamCmd {
 }
 
 impl CreateStreamCmd {
-    pub fn new(stream_id: Option<u32>, name: String) -> Self {
+    pub fn new(stream_id: Option<u32>, name: String, topic_defaults: TopicDefaults) -> Self {
         Self {
-            create_stream: CreateStream { stream_id, name },
+            create_stream: CreateStream {
+                stream_id,
+                name,
+                topic_defaults,
+            },
         }
     }
 
@@ -1,30 +1,81 @@
+use crate::cli::utils::confirmation::Confirmation;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
 use crate::streams::delete_stream::DeleteStream;
+use crate::streams::get_stream::GetStream;
 use anyhow::Context;
 use async_trait::async_trait;
 use tracing::{event, Level};
 
 pub struct DeleteStreamCmd {
     delete_stream: DeleteStream,
+    confirmation: Confirmation,
 }
 
 impl DeleteStreamCmd {
-    pub fn new(stream_id: Identifier) -> Self {
+    pub fn new(stream_id: Identifier, soft: bool, confirmation: Confirmation) -> Self {
         Self {
-            delete_stream: DeleteStream { stream_id },
+            delete_stream: DeleteStream { stream_id, soft },
+            confirmation,
         }
     }
+
+    async fn get_summary(&self, client: &dyn Client) -> anyhow::Result<String> {
+        let stream = client
+            .get_stream(&GetStream {
+                stream_id: self.delete_stream.stream_id.clone(),
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting stream with ID: {}",
+                    self.delete_stream.stream_id
+                )
+            })?;
+
+        Ok(format!(
+            "Stream: {} (ID: {}) with {} topics, {} partitions and {} messages of size {} will be {}",
+            stream.name,
+            stream.id,
+            stream.topics_count,
+            stream
+                .topics
+                .iter()
+                .map(|topic| topic.partitions_count)
+                .sum::<u32>(),
+            stream.messages_count,
+            stream.size_bytes,
+            match self.delete_stream.soft {
+                true => "moved to trash",
+                false => "deleted",
+            }
+        ))
+    }
 }
 
 #[async_trait]
 impl CliCommand for DeleteStreamCmd {
     fn explain(&self) -> String {
-        format!("delete stream with ID: {}", self.delete_stream.stream_id)
+        format!(
+            "delete stream with ID: {}{}",
+            self.delete_stream.stream_id,
+            match self.delete_stream.soft {
+                true => " (move to trash)",
+                false => "",
+            }
+        )
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        if self.confirmation.is_required()
+            && !self
+                .confirmation
+                .confirm(&self.get_summary(client).await?)?
+        {
+            return Ok(());
+        }
+
         client
             .delete_stream(&self.delete_stream)
             .await
@@ -35,7 +86,14 @@ impl CliCommand for DeleteStreamCmd {
                 )
             })?;
 
-        event!(target: PRINT_TARGET, Level::INFO, "Stream with ID: {} deleted", self.delete_stream.stream_id);
+        match self.delete_stream.soft {
+            true => {
+                event!(target: PRINT_TARGET, Level::INFO, "Stream with ID: {} moved to trash", self.delete_stream.stream_id)
+            }
+            false => {
+                event!(target: PRINT_TARGET, Level::INFO, "Stream with ID: {} deleted", self.delete_stream.stream_id)
+            }
+        }
 
         Ok(())
     }
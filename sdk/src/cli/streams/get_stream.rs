@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv_properties, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
@@ -10,12 +11,14 @@ use tracing::{event, Level};
 
 pub struct GetStreamCmd {
     get_stream: GetStream,
+    format: OutputFormat,
 }
 
 impl GetStreamCmd {
-    pub fn new(stream_id: Identifier) -> Self {
+    pub fn new(stream_id: Identifier, format: OutputFormat) -> Self {
         Self {
             get_stream: GetStream { stream_id },
+            format,
         }
     }
 }
@@ -34,31 +37,58 @@ impl CliCommand for GetStreamCmd {
             )
         })?;
 
-        let mut table = Table::new();
+        let properties = vec![
+            ("Stream ID", format!("{}", stream.id)),
+            ("Stream UUID", format!("{}", stream.uuid)),
+            (
+                "Created",
+                IggyTimestamp::from(stream.created_at).to_string("%Y-%m-%d %H:%M:%S"),
+            ),
+            ("Stream name", stream.name.clone()),
+            ("Stream size", format!("{}", stream.size_bytes)),
+            ("Stream message count", format!("{}", stream.messages_count)),
+            ("Stream topics count", format!("{}", stream.topics_count)),
+            (
+                "Default topic expiry",
+                match stream.topic_defaults.message_expiry {
+                    Some(value) => format!("{}", value),
+                    None => String::from("unlimited"),
+                },
+            ),
+            (
+                "Default topic size",
+                match stream.topic_defaults.max_topic_size {
+                    Some(value) => format!("{}", value),
+                    None => String::from("unlimited"),
+                },
+            ),
+            (
+                "Default compression",
+                match &stream.topic_defaults.compression_algorithm {
+                    Some(value) => format!("{}", value),
+                    None => String::from("server default"),
+                },
+            ),
+            (
+                "Default replication",
+                format!("{}", stream.topic_defaults.replication_factor.unwrap_or(1)),
+            ),
+        ];
 
-        table.set_header(vec!["Property", "Value"]);
-        table.add_row(vec!["Stream ID", format!("{}", stream.id).as_str()]);
-        table.add_row(vec![
-            "Created",
-            IggyTimestamp::from(stream.created_at)
-                .to_string("%Y-%m-%d %H:%M:%S")
-                .as_str(),
-        ]);
-        table.add_row(vec!["Stream name", stream.name.as_str()]);
-        table.add_row(vec![
-            "Stream size",
-            format!("{}", stream.size_bytes).as_str(),
-        ]);
-        table.add_row(vec![
-            "Stream message count",
-            format!("{}", stream.messages_count).as_str(),
-        ]);
-        table.add_row(vec![
-            "Stream topics count",
-            format!("{}", stream.topics_count).as_str(),
-        ]);
+        match self.format {
+            OutputFormat::Json => print_json(&stream)?,
+            OutputFormat::Csv => print_csv_properties(&properties),
+            OutputFormat::Table => {
+                let mut table = Table::new();
 
-        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+                table.set_header(vec!["Property", "Value"]);
+                properties.iter().for_each(|(property, value)| {
+                    table.add_row(vec![*property, value.as_str()]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+        }
 
         Ok(())
     }
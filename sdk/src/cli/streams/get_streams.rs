@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::streams::get_streams::GetStreams;
@@ -15,13 +16,15 @@ pub enum GetStreamsOutput {
 pub struct GetStreamsCmd {
     get_streams: GetStreams,
     output: GetStreamsOutput,
+    format: OutputFormat,
 }
 
 impl GetStreamsCmd {
-    pub fn new(output: GetStreamsOutput) -> Self {
+    pub fn new(output: GetStreamsOutput, format: OutputFormat) -> Self {
         GetStreamsCmd {
             get_streams: GetStreams {},
             output,
+            format,
         }
     }
 }
@@ -31,6 +34,7 @@ impl Default for GetStreamsCmd {
         GetStreamsCmd {
             get_streams: GetStreams {},
             output: GetStreamsOutput::Table,
+            format: OutputFormat::default(),
         }
     }
 }
@@ -51,43 +55,38 @@ impl CliCommand for GetStreamsCmd {
             .await
             .with_context(|| String::from("Problem getting list of streams"))?;
 
-        if streams.is_empty() {
-            event!(target: PRINT_TARGET, Level::INFO, "No streams found!");
-            return Ok(());
-        }
+        let header = vec!["ID", "Created", "Name", "Size (B)", "Messages", "Topics"];
+        let rows = streams
+            .iter()
+            .map(|stream| {
+                vec![
+                    format!("{}", stream.id),
+                    IggyTimestamp::from(stream.created_at).to_string("%Y-%m-%d %H:%M:%S"),
+                    stream.name.clone(),
+                    format!("{}", stream.size_bytes),
+                    format!("{}", stream.messages_count),
+                    format!("{}", stream.topics_count),
+                ]
+            })
+            .collect::<Vec<_>>();
 
-        match self.output {
-            GetStreamsOutput::Table => {
+        match (self.format, &self.output) {
+            (OutputFormat::Json, _) => print_json(&streams)?,
+            (OutputFormat::Csv, _) => print_csv(&header, &rows),
+            (OutputFormat::Table, _) if streams.is_empty() => {
+                event!(target: PRINT_TARGET, Level::INFO, "No streams found!");
+            }
+            (OutputFormat::Table, GetStreamsOutput::Table) => {
                 let mut table = Table::new();
 
-                table.set_header(vec![
-                    "ID", "Created", "Name", "Size (B)", "Messages", "Topics",
-                ]);
-
-                streams.iter().for_each(|stream| {
-                    table.add_row(vec![
-                        format!("{}", stream.id),
-                        IggyTimestamp::from(stream.created_at).to_string("%Y-%m-%d %H:%M:%S"),
-                        stream.name.clone(),
-                        format!("{}", stream.size_bytes),
-                        format!("{}", stream.messages_count),
-                        format!("{}", stream.topics_count),
-                    ]);
-                });
+                table.set_header(header);
+                table.add_rows(rows);
 
                 event!(target: PRINT_TARGET, Level::INFO, "{table}");
             }
-            GetStreamsOutput::List => {
-                streams.iter().for_each(|stream| {
-                    event!(target: PRINT_TARGET, Level::INFO,
-                        "{}|{}|{}|{}|{}|{}",
-                        stream.id,
-                        IggyTimestamp::from(stream.created_at).to_string("%Y-%m-%d %H:%M:%S"),
-                        stream.name,
-                        stream.size_bytes,
-                        stream.messages_count,
-                        stream.topics_count
-                    );
+            (OutputFormat::Table, GetStreamsOutput::List) => {
+                rows.iter().for_each(|row| {
+                    event!(target: PRINT_TARGET, Level::INFO, "{}", row.join("|"));
                 });
             }
         }
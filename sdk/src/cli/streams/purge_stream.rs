@@ -1,6 +1,8 @@
+use crate::cli::utils::confirmation::Confirmation;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
+use crate::streams::get_stream::GetStream;
 use crate::streams::purge_stream::PurgeStream;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -8,14 +10,35 @@ use tracing::{event, Level};
 
 pub struct PurgeStreamCmd {
     purge_stream: PurgeStream,
+    confirmation: Confirmation,
 }
 
 impl PurgeStreamCmd {
-    pub fn new(stream_id: Identifier) -> Self {
+    pub fn new(stream_id: Identifier, confirmation: Confirmation) -> Self {
         Self {
             purge_stream: PurgeStream { stream_id },
+            confirmation,
         }
     }
+
+    async fn get_summary(&self, client: &dyn Client) -> anyhow::Result<String> {
+        let stream = client
+            .get_stream(&GetStream {
+                stream_id: self.purge_stream.stream_id.clone(),
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting stream with ID: {}",
+                    self.purge_stream.stream_id
+                )
+            })?;
+
+        Ok(format!(
+            "All {} messages of size {} in {} topics of stream: {} (ID: {}) will be removed",
+            stream.messages_count, stream.size_bytes, stream.topics_count, stream.name, stream.id
+        ))
+    }
 }
 
 #[async_trait]
@@ -25,6 +48,14 @@ impl CliCommand for PurgeStreamCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        if self.confirmation.is_required()
+            && !self
+                .confirmation
+                .confirm(&self.get_summary(client).await?)?
+        {
+            return Ok(());
+        }
+
         client
             .purge_stream(&self.purge_stream)
             .await
@@ -0,0 +1,45 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::streams::undelete_stream::UndeleteStream;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UndeleteStreamCmd {
+    undelete_stream: UndeleteStream,
+}
+
+impl UndeleteStreamCmd {
+    pub fn new(stream_id: Identifier) -> Self {
+        Self {
+            undelete_stream: UndeleteStream { stream_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UndeleteStreamCmd {
+    fn explain(&self) -> String {
+        format!(
+            "undelete stream with ID: {}",
+            self.undelete_stream.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .undelete_stream(&self.undelete_stream)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem undeleting stream with ID: {}",
+                    self.undelete_stream.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "Stream with ID: {} restored from trash", self.undelete_stream.stream_id);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
+use crate::models::topic_defaults::TopicDefaults;
 use crate::streams::update_stream::UpdateStream;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -11,9 +12,13 @@ pub struct UpdateStreamCmd {
 }
 
 impl UpdateStreamCmd {
-    pub fn new(stream_id: Identifier, name: String) -> Self {
+    pub fn new(stream_id: Identifier, name: String, topic_defaults: Option<TopicDefaults>) -> Self {
         UpdateStreamCmd {
-            update_stream: UpdateStream { stream_id, name },
+            update_stream: UpdateStream {
+                stream_id,
+                name,
+                topic_defaults,
+            },
         }
     }
 }
@@ -0,0 +1,87 @@
+use crate::cli::system::session::{ServerSession, SESSION_TOKEN_NAME};
+use crate::cli::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct LoginCmd {
+    server_session: ServerSession,
+    login_session_expiry: Option<PersonalAccessTokenExpiry>,
+}
+
+impl LoginCmd {
+    pub fn new(
+        server_address: String,
+        login_session_expiry: Option<PersonalAccessTokenExpiry>,
+    ) -> Self {
+        Self {
+            server_session: ServerSession::new(server_address),
+            login_session_expiry,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for LoginCmd {
+    fn explain(&self) -> String {
+        match &self.login_session_expiry {
+            Some(value) => format!(
+                "login to server: {} with session expire time: {}",
+                self.server_session.server_address(),
+                value
+            ),
+            None => format!(
+                "login to server: {} without session expire time",
+                self.server_session.server_address()
+            ),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        // Token of the previous session (e.g. stored on another machine) is replaced by the new one
+        let tokens = client
+            .get_personal_access_tokens(&GetPersonalAccessTokens {})
+            .await
+            .with_context(|| "Problem getting personal access tokens".to_owned())?;
+        if tokens.iter().any(|token| token.name == SESSION_TOKEN_NAME) {
+            client
+                .delete_personal_access_token(&DeletePersonalAccessToken {
+                    name: SESSION_TOKEN_NAME.to_owned(),
+                })
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem deleting personal access token with name: {SESSION_TOKEN_NAME}"
+                    )
+                })?;
+        }
+
+        let token = client
+            .create_personal_access_token(&CreatePersonalAccessToken {
+                name: SESSION_TOKEN_NAME.to_owned(),
+                expiry: match &self.login_session_expiry {
+                    None => None,
+                    Some(value) => value.into(),
+                },
+            })
+            .await
+            .with_context(|| {
+                format!("Problem creating personal access token with name: {SESSION_TOKEN_NAME}")
+            })?;
+
+        self.server_session
+            .store_token(&token.token)
+            .with_context(|| "Problem storing session token in the secure storage".to_owned())?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Successfully logged into server: {}, session is stored as personal access token: {SESSION_TOKEN_NAME}",
+            self.server_session.server_address());
+
+        Ok(())
+    }
+}
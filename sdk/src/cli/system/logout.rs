@@ -0,0 +1,79 @@
+use crate::cli::system::session::{ServerSession, SESSION_TOKEN_NAME};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::users::logout_user::LogoutUser;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct LogoutCmd {
+    server_session: ServerSession,
+}
+
+impl LogoutCmd {
+    pub fn new(server_address: String) -> Self {
+        Self {
+            server_session: ServerSession::new(server_address),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for LogoutCmd {
+    fn explain(&self) -> String {
+        format!(
+            "logout from server: {}",
+            self.server_session.server_address()
+        )
+    }
+
+    // Logout uses the stored session instead of the credentials provided in the command line
+    fn login_required(&self) -> bool {
+        false
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let Some(token) = self
+            .server_session
+            .get_token()
+            .with_context(|| "Problem reading session token from the secure storage".to_owned())?
+        else {
+            event!(target: PRINT_TARGET, Level::INFO,
+                "No session stored for server: {}", self.server_session.server_address());
+            return Ok(());
+        };
+
+        // Expired or deleted session token is only removed from the secure storage
+        if client
+            .login_with_personal_access_token(&LoginWithPersonalAccessToken { token })
+            .await
+            .is_ok()
+        {
+            client
+                .delete_personal_access_token(&DeletePersonalAccessToken {
+                    name: SESSION_TOKEN_NAME.to_owned(),
+                })
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem deleting personal access token with name: {SESSION_TOKEN_NAME}"
+                    )
+                })?;
+            client
+                .logout_user(&LogoutUser {})
+                .await
+                .with_context(|| "Problem with server logout".to_owned())?;
+        }
+
+        self.server_session
+            .delete_token()
+            .with_context(|| "Problem deleting session token from the secure storage".to_owned())?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Successfully logged out from server: {}", self.server_session.server_address());
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv_properties, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::system::get_me::GetMe;
@@ -8,17 +9,24 @@ use tracing::{event, Level};
 
 pub struct GetMeCmd {
     get_me: GetMe,
+    format: OutputFormat,
 }
 
 impl GetMeCmd {
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(format: OutputFormat) -> Self {
+        Self {
+            get_me: GetMe {},
+            format,
+        }
     }
 }
 
 impl Default for GetMeCmd {
     fn default() -> Self {
-        Self { get_me: GetMe {} }
+        Self {
+            get_me: GetMe {},
+            format: OutputFormat::default(),
+        }
     }
 }
 
@@ -34,20 +42,27 @@ impl CliCommand for GetMeCmd {
             .await
             .with_context(|| "Problem sending get_me command".to_owned())?;
 
-        let mut table = Table::new();
-
-        table.set_header(vec!["Property", "Value"]);
-        table.add_row(vec![
-            "Client ID",
-            format!("{}", client_info.client_id).as_str(),
-        ]);
+        let mut properties = vec![("Client ID", format!("{}", client_info.client_id))];
         if let Some(user_id) = client_info.user_id {
-            table.add_row(vec!["User ID", format!("{}", user_id).as_str()]);
+            properties.push(("User ID", format!("{}", user_id)));
         }
-        table.add_row(vec!["Address", client_info.address.as_str()]);
-        table.add_row(vec!["Transport", client_info.transport.as_str()]);
+        properties.push(("Address", client_info.address.clone()));
+        properties.push(("Transport", client_info.transport.clone()));
 
-        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+        match self.format {
+            OutputFormat::Json => print_json(&client_info)?,
+            OutputFormat::Csv => print_csv_properties(&properties),
+            OutputFormat::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec!["Property", "Value"]);
+                properties.iter().for_each(|(property, value)| {
+                    table.add_row(vec![*property, value.as_str()]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+        }
 
         Ok(())
     }
@@ -1,3 +1,6 @@
+pub mod login;
+pub mod logout;
 pub mod me;
 pub mod ping;
+pub mod session;
 pub mod stats;
@@ -1,8 +1,12 @@
+use crate::cli::utils::output_format::{print_csv, print_json, OutputFormat};
+use crate::cli::utils::target_client::TargetClient;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::system::ping::Ping;
 use anyhow::Context;
 use async_trait::async_trait;
+use comfy_table::Table;
+use serde::Serialize;
 use std::fmt::{Display, Formatter, Result};
 use std::time::Duration;
 use tokio::time::{sleep, Instant};
@@ -11,15 +15,55 @@ use tracing::{event, Level};
 pub struct PingCmd {
     ping: Ping,
     count: u32,
+    format: OutputFormat,
+    transport: String,
+    targets: Vec<(String, TargetClient)>,
 }
 
 impl PingCmd {
-    pub fn new(count: u32) -> Self {
+    /// Creates command pinging the server using the current connection (described by the transport name)
+    /// or, when the targets are provided, using each of the target connections sequentially.
+    pub fn new(
+        count: u32,
+        format: OutputFormat,
+        transport: String,
+        targets: Vec<(String, TargetClient)>,
+    ) -> Self {
         Self {
             ping: Ping {},
             count,
+            format,
+            transport,
+            targets,
         }
     }
+
+    async fn ping(
+        &self,
+        client: &dyn Client,
+        transport: &str,
+    ) -> anyhow::Result<PingStats, anyhow::Error> {
+        let print_width = (self.count.ilog10() + 1) as usize;
+        let mut ping_stats = PingStats::new();
+
+        for i in 1..=self.count {
+            let time_start = Instant::now();
+            client
+                .ping(&self.ping)
+                .await
+                .with_context(|| format!("Problem sending ping command using: {transport}"))?;
+            let ping_duration = time_start.elapsed();
+            ping_stats.add(&ping_duration);
+            if self.format == OutputFormat::Table {
+                event!(target: PRINT_TARGET, Level::INFO, "Ping sequence id: {:width$} time: {:.2} ms", i, nano_to_ms(ping_duration.as_nanos()), width = print_width);
+            }
+            if i < self.count {
+                sleep(Duration::from_secs(1)).await;
+            }
+        }
+
+        Ok(ping_stats)
+    }
 }
 
 struct PingStats {
@@ -68,6 +112,30 @@ impl PingStats {
 
         (min, avg, max, std_dev)
     }
+
+    /// Returns the percentile of the samples using the nearest-rank method.
+    fn get_percentile(&self, percentile: f64) -> u128 {
+        let mut samples = self.samples.clone();
+        samples.sort_unstable();
+        let rank = (percentile / 100.0 * samples.len() as f64).ceil() as usize;
+
+        samples[rank.clamp(1, samples.len()) - 1]
+    }
+
+    fn summary(&self, transport: &str) -> PingSummary {
+        let (min, avg, max, std_dev) = self.get_stats();
+
+        PingSummary {
+            transport: transport.to_owned(),
+            count: self.count(),
+            min_ms: nano_to_ms(min),
+            avg_ms: nano_to_ms(avg),
+            p95_ms: nano_to_ms(self.get_percentile(95.0)),
+            p99_ms: nano_to_ms(self.get_percentile(99.0)),
+            max_ms: nano_to_ms(max),
+            mdev_ms: nano_to_ms(std_dev),
+        }
+    }
 }
 
 fn nano_to_ms(nanoseconds: u128) -> f64 {
@@ -79,44 +147,129 @@ impl Display for PingStats {
         let (min, avg, max, std_dev) = self.get_stats();
         write!(
             f,
-            "min/avg/max/mdev = {:.3}/{:.3}/{:.3}/{:.3} ms",
+            "min/avg/p95/p99/max/mdev = {:.3}/{:.3}/{:.3}/{:.3}/{:.3}/{:.3} ms",
             nano_to_ms(min),
             nano_to_ms(avg),
+            nano_to_ms(self.get_percentile(95.0)),
+            nano_to_ms(self.get_percentile(99.0)),
             nano_to_ms(max),
             nano_to_ms(std_dev)
         )
     }
 }
 
+/// Latency statistics of the single transport in milliseconds, printed in the JSON and CSV formats.
+#[derive(Debug, Serialize)]
+struct PingSummary {
+    transport: String,
+    count: usize,
+    min_ms: f64,
+    avg_ms: f64,
+    p95_ms: f64,
+    p99_ms: f64,
+    max_ms: f64,
+    mdev_ms: f64,
+}
+
+impl PingSummary {
+    fn to_row(&self) -> Vec<String> {
+        vec![
+            self.transport.clone(),
+            format!("{}", self.count),
+            format!("{:.3}", self.min_ms),
+            format!("{:.3}", self.avg_ms),
+            format!("{:.3}", self.p95_ms),
+            format!("{:.3}", self.p99_ms),
+            format!("{:.3}", self.max_ms),
+            format!("{:.3}", self.mdev_ms),
+        ]
+    }
+}
+
+static SUMMARY_HEADER: [&str; 8] = [
+    "Transport",
+    "Count",
+    "Min (ms)",
+    "Avg (ms)",
+    "P95 (ms)",
+    "P99 (ms)",
+    "Max (ms)",
+    "Mdev (ms)",
+];
+
 #[async_trait]
 impl CliCommand for PingCmd {
     fn explain(&self) -> String {
-        "ping command".to_owned()
+        match self.targets.is_empty() {
+            true => "ping command".to_owned(),
+            false => format!(
+                "ping command using: {}",
+                self.targets
+                    .iter()
+                    .map(|(transport, _)| transport.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
     }
 
     fn login_required(&self) -> bool {
         false
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        let print_width = (self.count.ilog10() + 1) as usize;
-        let mut ping_stats = PingStats::new();
+    // Each of the target transports is connected separately
+    fn connection_required(&self) -> bool {
+        self.targets.is_empty()
+    }
 
-        for i in 1..=self.count {
-            let time_start = Instant::now();
-            client
-                .ping(&self.ping)
-                .await
-                .with_context(|| "Problem sending ping command".to_owned())?;
-            let ping_duration = time_start.elapsed();
-            ping_stats.add(&ping_duration);
-            event!(target: PRINT_TARGET, Level::INFO, "Ping sequence id: {:width$} time: {:.2} ms", i, nano_to_ms(ping_duration.as_nanos()), width = print_width);
-            sleep(Duration::from_secs(1)).await;
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let mut summaries = vec![];
+
+        if self.targets.is_empty() {
+            let ping_stats = self.ping(client, &self.transport).await?;
+            if self.format == OutputFormat::Table {
+                event!(target: PRINT_TARGET, Level::INFO, "");
+                event!(target: PRINT_TARGET, Level::INFO, "Ping statistics for {} ping commands", ping_stats.count());
+                event!(target: PRINT_TARGET, Level::INFO, "{ping_stats}");
+            }
+            summaries.push(ping_stats.summary(&self.transport));
+        } else {
+            for (transport, target) in std::mem::take(&mut self.targets) {
+                if self.format == OutputFormat::Table {
+                    event!(target: PRINT_TARGET, Level::INFO, "Transport: {transport}");
+                }
+                let target = target()
+                    .await
+                    .with_context(|| format!("Problem connecting using: {transport}"))?;
+                let ping_stats = self.ping(target.as_ref(), &transport).await?;
+                if self.format == OutputFormat::Table {
+                    event!(target: PRINT_TARGET, Level::INFO, "{ping_stats}");
+                    event!(target: PRINT_TARGET, Level::INFO, "");
+                }
+                summaries.push(ping_stats.summary(&transport));
+            }
         }
 
-        event!(target: PRINT_TARGET, Level::INFO, "");
-        event!(target: PRINT_TARGET, Level::INFO, "Ping statistics for {} ping commands", ping_stats.count());
-        event!(target: PRINT_TARGET, Level::INFO, "{ping_stats}");
+        match self.format {
+            OutputFormat::Json => print_json(&summaries)?,
+            OutputFormat::Csv => print_csv(
+                &SUMMARY_HEADER,
+                &summaries
+                    .iter()
+                    .map(PingSummary::to_row)
+                    .collect::<Vec<_>>(),
+            ),
+            OutputFormat::Table => {
+                if summaries.len() > 1 {
+                    let mut table = Table::new();
+                    table.set_header(SUMMARY_HEADER);
+                    summaries.iter().for_each(|summary| {
+                        table.add_row(summary.to_row());
+                    });
+                    event!(target: PRINT_TARGET, Level::INFO, "{table}");
+                }
+            }
+        }
 
         Ok(())
     }
@@ -176,7 +329,31 @@ mod tests {
 
         assert_eq!(
             format!("{ping_stats}"),
-            "min/avg/max/mdev = 1.322/4.490/7.838/2.400 ms"
+            "min/avg/p95/p99/max/mdev = 1.322/4.490/7.838/7.838/7.838/2.400 ms"
         );
     }
+
+    #[test]
+    fn should_return_percentiles() {
+        let mut ping_stats = PingStats::new();
+
+        (1..=100)
+            .rev()
+            .for_each(|value| ping_stats.add(&Duration::from_nanos(value)));
+
+        assert_eq!(ping_stats.get_percentile(50.0), 50);
+        assert_eq!(ping_stats.get_percentile(95.0), 95);
+        assert_eq!(ping_stats.get_percentile(99.0), 99);
+        assert_eq!(ping_stats.get_percentile(100.0), 100);
+    }
+
+    #[test]
+    fn should_return_percentile_of_single_sample() {
+        let mut ping_stats = PingStats::new();
+
+        ping_stats.add(&Duration::from_nanos(7));
+
+        assert_eq!(ping_stats.get_percentile(95.0), 7);
+        assert_eq!(ping_stats.get_percentile(99.0), 7);
+    }
 }
@@ -0,0 +1,47 @@
+use keyring::{Entry, Error};
+
+/// Name of the personal access token created by the login command and stored in the keyring.
+pub const SESSION_TOKEN_NAME: &str = "iggy-cli-session";
+
+/// Session of the command line tool stored in a platform-specific secure storage,
+/// there is at most one session for each server address.
+pub struct ServerSession {
+    server_address: String,
+}
+
+impl ServerSession {
+    pub fn new(server_address: String) -> Self {
+        Self { server_address }
+    }
+
+    pub fn server_address(&self) -> &str {
+        &self.server_address
+    }
+
+    fn entry(&self) -> anyhow::Result<Entry, anyhow::Error> {
+        Ok(Entry::new(
+            &format!("iggy:{}", self.server_address),
+            SESSION_TOKEN_NAME,
+        )?)
+    }
+
+    /// Returns the stored session token, `None` when there is no session for the server.
+    pub fn get_token(&self) -> anyhow::Result<Option<String>, anyhow::Error> {
+        match self.entry()?.get_password() {
+            Ok(token) => Ok(Some(token)),
+            Err(Error::NoEntry) => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn store_token(&self, token: &str) -> anyhow::Result<(), anyhow::Error> {
+        Ok(self.entry()?.set_password(token)?)
+    }
+
+    pub fn delete_token(&self) -> anyhow::Result<(), anyhow::Error> {
+        match self.entry()?.delete_password() {
+            Ok(()) | Err(Error::NoEntry) => Ok(()),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
@@ -1,134 +1,406 @@
+use crate::cli::utils::output_format::{print_csv_properties, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
+use crate::models::stats::Stats;
+use crate::models::stream::Stream;
+use crate::streams::get_streams::GetStreams;
 use crate::system::get_stats::GetStats;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
 use anyhow::Context;
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, Utc};
 use comfy_table::Table;
 use humantime::format_duration;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 use tracing::{event, Level};
 
+// Clears the terminal and moves the cursor to the top left corner
+static CLEAR_SCREEN: &str = "\x1B[2J\x1B[1;1H";
+
 pub struct GetStatsCmd {
     get_stats: GetStats,
+    format: OutputFormat,
+    /// Interval between the refreshes of the dashboard, the stats are fetched only once if not set.
+    watch: Option<IggyDuration>,
 }
 
 impl GetStatsCmd {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-
-impl Default for GetStatsCmd {
-    fn default() -> Self {
+    pub fn new(format: OutputFormat, watch: Option<IggyDuration>) -> Self {
         Self {
             get_stats: GetStats {},
+            format,
+            watch,
         }
     }
-}
-
-#[async_trait]
-impl CliCommand for GetStatsCmd {
-    fn explain(&self) -> String {
-        "stats command".to_owned()
-    }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        let stats = client
+    async fn get_stats(&self, client: &dyn Client) -> anyhow::Result<Stats, anyhow::Error> {
+        client
             .get_stats(&self.get_stats)
             .await
-            .with_context(|| "Problem sending get_stats command".to_owned())?;
+            .with_context(|| "Problem sending get_stats command".to_owned())
+    }
 
-        let mut table = Table::new();
+    async fn watch(
+        &self,
+        client: &dyn Client,
+        interval: IggyDuration,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let mut previous: Option<(Instant, Counters)> = None;
+        loop {
+            let stats = self.get_stats(client).await?;
+            let now = Instant::now();
+            let counters = Counters::from(&stats);
+            let throughput = previous.as_ref().map(|(time, previous)| {
+                Throughput::between(previous, &counters, now.duration_since(*time))
+            });
+            previous = Some((now, counters));
 
-        table.set_header(vec!["Server property", "Value"]);
-        table.add_row(vec![
-            "Iggy Server PID",
-            format!("{}", stats.process_id).as_str(),
-        ]);
-        table.add_row(vec![
-            "Iggy Server CPU Usage",
-            format!("{:.4} %", stats.cpu_usage).as_str(),
-        ]);
-        table.add_row(vec![
-            "Iggy Server Memory Usage",
-            stats.memory_usage.as_bytes_u64().to_string().as_str(),
-        ]);
+            match self.format {
+                // Each sample is printed in the machine-readable formats, so it can be piped into other tools
+                OutputFormat::Json => print_json(&stats)?,
+                OutputFormat::Csv => print_csv_properties(&get_properties(&stats)),
+                OutputFormat::Table => {
+                    let streams = client
+                        .get_streams(&GetStreams {})
+                        .await
+                        .with_context(|| "Problem sending get_streams command".to_owned())?;
+                    self.print_dashboard(&stats, throughput, &streams, interval);
+                }
+            }
 
-        table.add_row(vec![
-            "Total Memory (RAM)",
-            stats.total_memory.as_bytes_u64().to_string().as_str(),
+            tokio::time::sleep(interval.get_duration()).await;
+        }
+    }
+
+    fn print_dashboard(
+        &self,
+        stats: &Stats,
+        throughput: Option<Throughput>,
+        streams: &[Stream],
+        interval: IggyDuration,
+    ) {
+        let rate = |value: Option<String>| value.unwrap_or_else(|| String::from("-"));
+
+        let mut server = Table::new();
+        server.set_header(vec!["Server property", "Value"]);
+        server.add_row(vec![
+            String::from("CPU Usage"),
+            format!("{:.4} %", stats.cpu_usage),
         ]);
-        table.add_row(vec![
-            "Available Memory (RAM)",
-            stats.available_memory.as_bytes_u64().to_string().as_str(),
+        server.add_row(vec![
+            String::from("Memory Usage"),
+            format!(
+                "{} / {} (available: {})",
+                stats.memory_usage, stats.total_memory, stats.available_memory
+            ),
         ]);
-
-        table.add_row(vec![
-            "Iggy Server Run Time",
-            format!("{}", format_duration(Duration::from_secs(stats.run_time))).as_str(),
+        server.add_row(vec![
+            String::from("Memory Budget Usage"),
+            format!(
+                "{} / {} (unsaved: {}, messages cache: {}, indexes cache: {})",
+                stats.memory_budget_usage(),
+                stats.memory_budget.as_human_string_with_zero_as_unlimited(),
+                stats.unsaved_messages_size,
+                stats.cached_messages_size,
+                stats.cached_indexes_size
+            ),
         ]);
-
-        let start_time = SystemTime::UNIX_EPOCH + Duration::from_secs(stats.start_time);
-        let date_time_utc: DateTime<Utc> = start_time.into();
-
-        table.add_row(vec![
-            "Start Time (UTC)",
-            format!("{}", date_time_utc.format("%Y-%m-%d %H:%M:%S")).as_str(),
+        server.add_row(vec![
+            String::from("Disk Space"),
+            format!(
+                "{} free / {}",
+                stats.disk_free_space, stats.disk_total_space
+            ),
         ]);
-
-        table.add_row(vec![
-            "Read Bytes",
-            stats.read_bytes.as_bytes_u64().to_string().as_str(),
+        server.add_row(vec![
+            String::from("Read Throughput"),
+            rate(throughput.as_ref().map(|throughput| {
+                format!("{}/s", IggyByteSize::from(throughput.read_bytes_per_second))
+            })),
         ]);
-        table.add_row(vec![
-            "Written Bytes",
-            stats.written_bytes.as_bytes_u64().to_string().as_str(),
+        server.add_row(vec![
+            String::from("Write Throughput"),
+            rate(throughput.as_ref().map(|throughput| {
+                format!(
+                    "{}/s",
+                    IggyByteSize::from(throughput.written_bytes_per_second)
+                )
+            })),
         ]);
-        table.add_row(vec![
-            "Messages Size Bytes",
-            stats
-                .messages_size_bytes
-                .as_bytes_u64()
-                .to_string()
-                .as_str(),
+        server.add_row(vec![
+            String::from("Messages Rate"),
+            rate(
+                throughput
+                    .as_ref()
+                    .map(|throughput| format!("{} msg/s", throughput.messages_per_second)),
+            ),
         ]);
-
-        table.add_row(vec![
-            "Streams Count",
-            format!("{}", stats.streams_count).as_str(),
+        server.add_row(vec![
+            String::from("Clients Count"),
+            format!("{}", stats.clients_count),
         ]);
-        table.add_row(vec![
-            "Topics Count",
-            format!("{}", stats.topics_count).as_str(),
+        server.add_row(vec![
+            String::from("Messages Count"),
+            format!("{}", stats.messages_count),
         ]);
-        table.add_row(vec![
-            "Partitions Count",
-            format!("{}", stats.partitions_count).as_str(),
+        server.add_row(vec![
+            String::from("Messages Size"),
+            format!("{}", stats.messages_size_bytes),
         ]);
-        table.add_row(vec![
-            "Segments Count",
-            format!("{}", stats.segments_count).as_str(),
+        server.add_row(vec![
+            String::from("Streams / Topics / Partitions / Segments"),
+            format!(
+                "{} / {} / {} / {}",
+                stats.streams_count,
+                stats.topics_count,
+                stats.partitions_count,
+                stats.segments_count
+            ),
         ]);
-        table.add_row(vec![
-            "Message Count",
-            format!("{}", stats.messages_count).as_str(),
+        server.add_row(vec![
+            String::from("Run Time"),
+            format!("{}", format_duration(Duration::from_secs(stats.run_time))),
         ]);
-        table.add_row(vec![
-            "Clients Count",
-            format!("{}", stats.clients_count).as_str(),
+
+        let mut streams_table = Table::new();
+        streams_table.set_header(vec![
+            "Stream ID",
+            "Name",
+            "Size",
+            "Messages Count",
+            "Topics",
         ]);
-        table.add_row(vec![
+        streams.iter().for_each(|stream| {
+            streams_table.add_row(vec![
+                format!("{}", stream.id),
+                stream.name.clone(),
+                format!("{}", stream.size_bytes),
+                format!("{}", stream.messages_count),
+                format!("{}", stream.topics_count),
+            ]);
+        });
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "{CLEAR_SCREEN}Iggy server stats at {} (refreshed every {}, press Ctrl+C to exit)\n{server}\n{streams_table}",
+            Local::now().format("%Y-%m-%d %H:%M:%S"),
+            interval.as_human_time_string()
+        );
+    }
+}
+
+impl Default for GetStatsCmd {
+    fn default() -> Self {
+        Self {
+            get_stats: GetStats {},
+            format: OutputFormat::default(),
+            watch: None,
+        }
+    }
+}
+
+/// Server counters used to calculate the throughput between two samples of the stats.
+struct Counters {
+    read_bytes: u64,
+    written_bytes: u64,
+    messages_count: u64,
+}
+
+impl From<&Stats> for Counters {
+    fn from(stats: &Stats) -> Self {
+        Self {
+            read_bytes: stats.read_bytes.as_bytes_u64(),
+            written_bytes: stats.written_bytes.as_bytes_u64(),
+            messages_count: stats.messages_count,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Throughput {
+    read_bytes_per_second: u64,
+    written_bytes_per_second: u64,
+    messages_per_second: u64,
+}
+
+impl Throughput {
+    fn between(previous: &Counters, current: &Counters, elapsed: Duration) -> Self {
+        // Counters can decrease when the server is restarted or the data is purged
+        let per_second = |previous: u64, current: u64| match elapsed.as_secs_f64() {
+            seconds if seconds > 0.0 => (current.saturating_sub(previous) as f64 / seconds) as u64,
+            _ => 0,
+        };
+
+        Self {
+            read_bytes_per_second: per_second(previous.read_bytes, current.read_bytes),
+            written_bytes_per_second: per_second(previous.written_bytes, current.written_bytes),
+            messages_per_second: per_second(previous.messages_count, current.messages_count),
+        }
+    }
+}
+
+fn get_properties(stats: &Stats) -> Vec<(&'static str, String)> {
+    let start_time = SystemTime::UNIX_EPOCH + Duration::from_secs(stats.start_time);
+    let date_time_utc: DateTime<Utc> = start_time.into();
+
+    vec![
+        ("Iggy Server PID", format!("{}", stats.process_id)),
+        ("Iggy Server CPU Usage", format!("{:.4} %", stats.cpu_usage)),
+        (
+            "Iggy Server Memory Usage",
+            stats.memory_usage.as_bytes_u64().to_string(),
+        ),
+        (
+            "Total Memory (RAM)",
+            stats.total_memory.as_bytes_u64().to_string(),
+        ),
+        (
+            "Available Memory (RAM)",
+            stats.available_memory.as_bytes_u64().to_string(),
+        ),
+        (
+            "Iggy Server Run Time",
+            format!("{}", format_duration(Duration::from_secs(stats.run_time))),
+        ),
+        (
+            "Start Time (UTC)",
+            format!("{}", date_time_utc.format("%Y-%m-%d %H:%M:%S")),
+        ),
+        ("Read Bytes", stats.read_bytes.as_bytes_u64().to_string()),
+        (
+            "Written Bytes",
+            stats.written_bytes.as_bytes_u64().to_string(),
+        ),
+        (
+            "Messages Size Bytes",
+            stats.messages_size_bytes.as_bytes_u64().to_string(),
+        ),
+        ("Streams Count", format!("{}", stats.streams_count)),
+        ("Topics Count", format!("{}", stats.topics_count)),
+        ("Partitions Count", format!("{}", stats.partitions_count)),
+        ("Segments Count", format!("{}", stats.segments_count)),
+        ("Message Count", format!("{}", stats.messages_count)),
+        ("Clients Count", format!("{}", stats.clients_count)),
+        (
             "Consumer Groups Count",
-            format!("{}", stats.consumer_groups_count).as_str(),
-        ]);
+            format!("{}", stats.consumer_groups_count),
+        ),
+        ("OS Name", stats.os_name.clone()),
+        ("OS Version", stats.os_version.clone()),
+        ("Kernel Version", stats.kernel_version.clone()),
+        (
+            "Unsaved Messages Bytes",
+            stats.unsaved_messages_size.as_bytes_u64().to_string(),
+        ),
+        (
+            "Cached Messages Bytes",
+            stats.cached_messages_size.as_bytes_u64().to_string(),
+        ),
+        (
+            "Cached Indexes Bytes",
+            stats.cached_indexes_size.as_bytes_u64().to_string(),
+        ),
+        (
+            "Memory Budget Bytes",
+            stats.memory_budget.as_bytes_u64().to_string(),
+        ),
+        (
+            "Disk Free Space Bytes",
+            stats.disk_free_space.as_bytes_u64().to_string(),
+        ),
+        (
+            "Disk Total Space Bytes",
+            stats.disk_total_space.as_bytes_u64().to_string(),
+        ),
+    ]
+}
+
+#[async_trait]
+impl CliCommand for GetStatsCmd {
+    fn explain(&self) -> String {
+        match self.watch {
+            Some(interval) => format!(
+                "stats command refreshed every {}",
+                interval.as_human_time_string()
+            ),
+            None => "stats command".to_owned(),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        if let Some(interval) = self.watch {
+            return self.watch(client, interval).await;
+        }
 
-        table.add_row(vec!["OS Name", stats.os_name.as_str()]);
-        table.add_row(vec!["OS Version", stats.os_version.as_str()]);
-        table.add_row(vec!["Kernel Version", stats.kernel_version.as_str()]);
+        let stats = self.get_stats(client).await?;
+        let properties = get_properties(&stats);
 
-        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+        match self.format {
+            OutputFormat::Json => print_json(&stats)?,
+            OutputFormat::Csv => print_csv_properties(&properties),
+            OutputFormat::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec!["Server property", "Value"]);
+                properties.iter().for_each(|(property, value)| {
+                    table.add_row(vec![*property, value.as_str()]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+        }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_calculate_throughput_per_second() {
+        let previous = Counters {
+            read_bytes: 1000,
+            written_bytes: 2000,
+            messages_count: 10,
+        };
+        let current = Counters {
+            read_bytes: 5000,
+            written_bytes: 2000,
+            messages_count: 50,
+        };
+
+        assert_eq!(
+            Throughput::between(&previous, &current, Duration::from_secs(2)),
+            Throughput {
+                read_bytes_per_second: 2000,
+                written_bytes_per_second: 0,
+                messages_per_second: 20,
+            }
+        );
+    }
+
+    #[test]
+    fn should_not_calculate_negative_throughput() {
+        let previous = Counters {
+            read_bytes: 5000,
+            written_bytes: 5000,
+            messages_count: 50,
+        };
+        let current = Counters {
+            read_bytes: 0,
+            written_bytes: 0,
+            messages_count: 0,
+        };
+
+        assert_eq!(
+            Throughput::between(&previous, &current, Duration::from_secs(1)),
+            Throughput {
+                read_bytes_per_second: 0,
+                written_bytes_per_second: 0,
+                messages_per_second: 0,
+            }
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::cli::utils::target_client::TargetClient;
+use crate::cli::utils::topic_path::TopicPath;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::identifier::IdKind;
+use crate::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use crate::messages::send_messages::{Message, Partitioning, SendMessages};
+use crate::models::timestamp_type::TimestampType;
+use crate::models::topic::TopicDetails;
+use crate::streams::create_stream::CreateStream;
+use crate::streams::get_stream::GetStream;
+use crate::topics::create_topic::CreateTopic;
+use crate::topics::get_topic::GetTopic;
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+static MESSAGES_BATCH_SIZE: u32 = 1000;
+
+pub struct CloneTopicCmd {
+    from: TopicPath,
+    to: TopicPath,
+    with_data: bool,
+    target: Option<(String, TargetClient)>,
+}
+
+impl CloneTopicCmd {
+    /// Creates command cloning the topic on the same server or, when the target is provided,
+    /// on the server described by the target name (e.g. a context name).
+    pub fn new(
+        from: TopicPath,
+        to: TopicPath,
+        with_data: bool,
+        target: Option<(String, TargetClient)>,
+    ) -> Self {
+        Self {
+            from,
+            to,
+            with_data,
+            target,
+        }
+    }
+
+    async fn create_target_stream(
+        &self,
+        source: &dyn Client,
+        target: &dyn Client,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let stream_id = self.to.stream_id.clone();
+        if target.get_stream(&GetStream { stream_id }).await.is_ok() {
+            return Ok(());
+        }
+
+        let (stream_id, name) = match self.to.stream_id.kind {
+            IdKind::Numeric => {
+                let stream = source
+                    .get_stream(&GetStream {
+                        stream_id: self.from.stream_id.clone(),
+                    })
+                    .await
+                    .with_context(|| {
+                        format!("Problem getting stream with ID: {}", self.from.stream_id)
+                    })?;
+                (Some(self.to.stream_id.get_u32_value()?), stream.name)
+            }
+            IdKind::String => (None, self.to.stream_id.get_string_value()?),
+            IdKind::Uuid => bail!(
+                "Stream: {} doesn't exist and can't be created with the given UUID, as the UUIDs are assigned by the server",
+                self.to.stream_id
+            ),
+        };
+
+        target
+            .create_stream(&CreateStream {
+                stream_id,
+                name: name.clone(),
+                ..CreateStream::default()
+            })
+            .await
+            .with_context(|| format!("Problem creating stream: {name}"))?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "Stream: {name} created");
+
+        Ok(())
+    }
+
+    async fn create_target_topic(
+        &self,
+        target: &dyn Client,
+        topic: &TopicDetails,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let get_topic = GetTopic {
+            stream_id: self.to.stream_id.clone(),
+            topic_id: self.to.topic_id.clone(),
+        };
+        if target.get_topic(&get_topic).await.is_ok() {
+            bail!("Topic: {} already exists", self.to);
+        }
+
+        let (topic_id, name) = match self.to.topic_id.kind {
+            IdKind::Numeric => (Some(self.to.topic_id.get_u32_value()?), topic.name.clone()),
+            IdKind::String => (None, self.to.topic_id.get_string_value()?),
+            IdKind::Uuid => bail!(
+                "Topic: {} can't be created with the given UUID, as the UUIDs are assigned by the server",
+                self.to
+            ),
+        };
+
+        target
+            .create_topic(&CreateTopic {
+                stream_id: self.to.stream_id.clone(),
+                topic_id,
+                partitions_count: topic.partitions_count,
+                message_expiry: topic.message_expiry,
+                max_topic_size: topic.max_topic_size,
+                replication_factor: topic.replication_factor,
+                name,
+                message_schema: topic.message_schema.clone(),
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: topic.consumer_limits,
+                max_message_size: topic.max_message_size,
+            })
+            .await
+            .with_context(|| format!("Problem creating topic: {}", self.to))?;
+
+        Ok(())
+    }
+
+    /// Copies the messages partition by partition, so each message lands in the partition
+    /// with the same ID and the order of the messages is preserved.
+    async fn copy_messages(
+        &self,
+        source: &dyn Client,
+        target: &dyn Client,
+        topic: &TopicDetails,
+    ) -> anyhow::Result<u64, anyhow::Error> {
+        let mut copied = 0;
+
+        for partition in &topic.partitions {
+            let mut offset = 0;
+            let mut partition_copied = 0;
+
+            while partition.messages_count > 0 && offset <= partition.current_offset {
+                let polled = source
+                    .poll_messages(&PollMessages {
+                        consumer: Consumer::default(),
+                        stream_id: self.from.stream_id.clone(),
+                        topic_id: self.from.topic_id.clone(),
+                        partition_id: Some(partition.id),
+                        strategy: PollingStrategy::offset(offset),
+                        count: MESSAGES_BATCH_SIZE,
+                        auto_commit: false,
+                        filter: None,
+                        mode: PollingMode::Peek,
+                        max_wait_ms: None,
+                        max_bytes: None,
+                    })
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem polling messages from partition: {} of topic: {}",
+                            partition.id, self.from
+                        )
+                    })?;
+
+                let Some(last) = polled.messages.last() else {
+                    break;
+                };
+                offset = last.offset + 1;
+
+                let messages = polled
+                    .messages
+                    .into_iter()
+                    .map(|message| Message {
+                        id: message.id,
+                        length: message.length,
+                        payload: message.payload,
+                        headers: message.headers,
+                    })
+                    .collect::<Vec<_>>();
+                let count = messages.len() as u64;
+
+                target
+                    .send_messages(&mut SendMessages {
+                        stream_id: self.to.stream_id.clone(),
+                        topic_id: self.to.topic_id.clone(),
+                        partitioning: Partitioning::partition_id(partition.id),
+                        messages,
+                        acks: None,
+                    })
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem sending messages to partition: {} of topic: {}",
+                            partition.id, self.to
+                        )
+                    })?;
+
+                partition_copied += count;
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "Partition: {} copied {partition_copied}/{} messages",
+                    partition.id, partition.messages_count);
+            }
+
+            copied += partition_copied;
+        }
+
+        Ok(copied)
+    }
+}
+
+#[async_trait]
+impl CliCommand for CloneTopicCmd {
+    fn explain(&self) -> String {
+        let data = match self.with_data {
+            true => " with messages",
+            false => "",
+        };
+        match &self.target {
+            Some((target_name, _)) => format!(
+                "clone topic: {} to topic: {} on: {target_name}{data}",
+                self.from, self.to
+            ),
+            None => format!("clone topic: {} to topic: {}{data}", self.from, self.to),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let target = match self.target.take() {
+            Some((target_name, target)) => Some(
+                target()
+                    .await
+                    .with_context(|| format!("Problem connecting to: {target_name}"))?,
+            ),
+            None => None,
+        };
+        let target = target.as_deref().unwrap_or(client);
+
+        let topic = client
+            .get_topic(&GetTopic {
+                stream_id: self.from.stream_id.clone(),
+                topic_id: self.from.topic_id.clone(),
+            })
+            .await
+            .with_context(|| format!("Problem getting topic: {}", self.from))?;
+
+        self.create_target_stream(client, target).await?;
+        self.create_target_topic(target, &topic).await?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic: {} with {} partition(s) created", self.to, topic.partitions_count);
+
+        if self.with_data {
+            let copied = self.copy_messages(client, target, &topic).await?;
+            event!(target: PRINT_TARGET, Level::INFO,
+                "{copied} message(s) copied from topic: {} to topic: {}", self.from, self.to);
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,6 @@
 use crate::cli::utils::message_expiry::MessageExpiry;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
-use crate::identifier::Identifier;
 use crate::topics::create_topic::CreateTopic;
 use crate::utils::byte_size::IggyByteSize;
 use anyhow::Context;
@@ -17,25 +16,13 @@ pub struct CreateTopicCmd {
 }
 
 impl CreateTopicCmd {
-    pub fn new(
-        stream_id: Identifier,
-        topic_id: Option<u32>,
-        partitions_count: u32,
-        name: String,
-        message_expiry: MessageExpiry,
-        max_topic_size: IggyByteSize,
-        replication_factor: u8,
-    ) -> Self {
+    /// Creates the command from the prepared `CreateTopic`, the message expiry is set from the provided one.
+    pub fn new(mut create_topic: CreateTopic, message_expiry: MessageExpiry) -> Self {
+        create_topic.message_expiry = message_expiry.clone().into();
+        let max_topic_size = create_topic.max_topic_size.unwrap_or_default();
+        let replication_factor = create_topic.replication_factor;
         Self {
-            create_topic: CreateTopic {
-                stream_id,
-                topic_id,
-                partitions_count,
-                name,
-                message_expiry: message_expiry.clone().into(),
-                max_topic_size: Some(max_topic_size),
-                replication_factor,
-            },
+            create_topic,
             message_expiry,
             max_topic_size,
             replication_factor,
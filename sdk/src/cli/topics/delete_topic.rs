@@ -1,36 +1,88 @@
+use crate::cli::utils::confirmation::Confirmation;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
 use crate::topics::delete_topic::DeleteTopic;
+use crate::topics::get_topic::GetTopic;
 use anyhow::Context;
 use async_trait::async_trait;
 use tracing::{event, Level};
 
 pub struct DeleteTopicCmd {
     delete_topic: DeleteTopic,
+    confirmation: Confirmation,
 }
 
 impl DeleteTopicCmd {
-    pub fn new(stream_id: Identifier, topic_id: Identifier) -> Self {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        soft: bool,
+        confirmation: Confirmation,
+    ) -> Self {
         Self {
             delete_topic: DeleteTopic {
                 stream_id,
                 topic_id,
+                soft,
             },
+            confirmation,
         }
     }
+
+    async fn get_summary(&self, client: &dyn Client) -> anyhow::Result<String> {
+        let topic = client
+            .get_topic(&GetTopic {
+                stream_id: self.delete_topic.stream_id.clone(),
+                topic_id: self.delete_topic.topic_id.clone(),
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {} in stream {}",
+                    self.delete_topic.topic_id, self.delete_topic.stream_id
+                )
+            })?;
+
+        Ok(format!(
+            "Topic: {} (ID: {}) in stream with ID: {} with {} partitions and {} messages of size {} will be {}",
+            topic.name,
+            topic.id,
+            self.delete_topic.stream_id,
+            topic.partitions_count,
+            topic.messages_count,
+            topic.size,
+            match self.delete_topic.soft {
+                true => "moved to trash",
+                false => "deleted",
+            }
+        ))
+    }
 }
 
 #[async_trait]
 impl CliCommand for DeleteTopicCmd {
     fn explain(&self) -> String {
         format!(
-            "delete topic with ID: {} in stream with ID: {}",
-            self.delete_topic.topic_id, self.delete_topic.stream_id
+            "delete topic with ID: {} in stream with ID: {}{}",
+            self.delete_topic.topic_id,
+            self.delete_topic.stream_id,
+            match self.delete_topic.soft {
+                true => " (move to trash)",
+                false => "",
+            }
         )
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        if self.confirmation.is_required()
+            && !self
+                .confirmation
+                .confirm(&self.get_summary(client).await?)?
+        {
+            return Ok(());
+        }
+
         client
             .delete_topic(&self.delete_topic)
             .await
@@ -41,10 +93,16 @@ impl CliCommand for DeleteTopicCmd {
                 )
             })?;
 
-        event!(target: PRINT_TARGET, Level::INFO,
-            "Topic with ID: {} in stream with ID: {} deleted",
-            self.delete_topic.topic_id, self.delete_topic.stream_id
-        );
+        match self.delete_topic.soft {
+            true => event!(target: PRINT_TARGET, Level::INFO,
+                "Topic with ID: {} in stream with ID: {} moved to trash",
+                self.delete_topic.topic_id, self.delete_topic.stream_id
+            ),
+            false => event!(target: PRINT_TARGET, Level::INFO,
+                "Topic with ID: {} in stream with ID: {} deleted",
+                self.delete_topic.topic_id, self.delete_topic.stream_id
+            ),
+        }
 
         Ok(())
     }
@@ -0,0 +1,50 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::topics::freeze_topic::FreezeTopic;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct FreezeTopicCmd {
+    freeze_topic: FreezeTopic,
+}
+
+impl FreezeTopicCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier) -> Self {
+        Self {
+            freeze_topic: FreezeTopic {
+                stream_id,
+                topic_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for FreezeTopicCmd {
+    fn explain(&self) -> String {
+        format!(
+            "freeze topic with ID: {} in stream with ID: {}",
+            self.freeze_topic.topic_id, self.freeze_topic.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .freeze_topic(&self.freeze_topic)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem freezing topic with ID: {} in stream {}",
+                    self.freeze_topic.topic_id, self.freeze_topic.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic with ID: {} in stream with ID: {} frozen",
+            self.freeze_topic.topic_id, self.freeze_topic.stream_id);
+
+        Ok(())
+    }
+}
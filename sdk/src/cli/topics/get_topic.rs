@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv_properties, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
@@ -10,15 +11,17 @@ use tracing::{event, Level};
 
 pub struct GetTopicCmd {
     get_topic: GetTopic,
+    format: OutputFormat,
 }
 
 impl GetTopicCmd {
-    pub fn new(stream_id: Identifier, topic_id: Identifier) -> Self {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, format: OutputFormat) -> Self {
         Self {
             get_topic: GetTopic {
                 stream_id,
                 topic_id,
             },
+            format,
         }
     }
 }
@@ -40,44 +43,71 @@ impl CliCommand for GetTopicCmd {
             )
         })?;
 
-        let mut table = Table::new();
+        let properties = vec![
+            ("Topic id", format!("{}", topic.id)),
+            ("Topic UUID", format!("{}", topic.uuid)),
+            (
+                "Created",
+                IggyTimestamp::from(topic.created_at).to_string("%Y-%m-%d %H:%M:%S"),
+            ),
+            ("Topic name", topic.name.clone()),
+            ("Topic size", format!("{}", topic.size)),
+            (
+                "Message expiry",
+                match topic.message_expiry {
+                    Some(value) => format!("{}", value),
+                    None => String::from("unlimited"),
+                },
+            ),
+            (
+                "Max topic size",
+                match topic.max_topic_size {
+                    Some(value) => format!("{}", value),
+                    None => String::from("unlimited"),
+                },
+            ),
+            ("Topic message count", format!("{}", topic.messages_count)),
+            ("Partitions count", format!("{}", topic.partitions_count)),
+            ("Routing epoch", format!("{}", topic.routing_epoch)),
+            ("Compression", format!("{}", topic.compression_algorithm)),
+            ("Frozen", format!("{}", topic.frozen)),
+            (
+                "Max consumer groups",
+                match topic.consumer_limits.max_consumer_groups {
+                    Some(value) => format!("{}", value),
+                    None => String::from("unlimited"),
+                },
+            ),
+            (
+                "Max group members",
+                match topic.consumer_limits.max_group_members {
+                    Some(value) => format!("{}", value),
+                    None => String::from("unlimited"),
+                },
+            ),
+            (
+                "Max message size",
+                match topic.max_message_size {
+                    Some(value) => format!("{}", value),
+                    None => String::from("unlimited"),
+                },
+            ),
+        ];
 
-        table.set_header(vec!["Property", "Value"]);
-        table.add_row(vec!["Topic id", format!("{}", topic.id).as_str()]);
-        table.add_row(vec![
-            "Created",
-            IggyTimestamp::from(topic.created_at)
-                .to_string("%Y-%m-%d %H:%M:%S")
-                .as_str(),
-        ]);
-        table.add_row(vec!["Topic name", topic.name.as_str()]);
-        table.add_row(vec!["Topic size", format!("{}", topic.size).as_str()]);
-        table.add_row(vec![
-            "Message expiry",
-            match topic.message_expiry {
-                Some(value) => format!("{}", value),
-                None => String::from("unlimited"),
-            }
-            .as_str(),
-        ]);
-        table.add_row(vec![
-            "Max topic size",
-            match topic.max_topic_size {
-                Some(value) => format!("{}", value),
-                None => String::from("unlimited"),
-            }
-            .as_str(),
-        ]);
-        table.add_row(vec![
-            "Topic message count",
-            format!("{}", topic.messages_count).as_str(),
-        ]);
-        table.add_row(vec![
-            "Partitions count",
-            format!("{}", topic.partitions_count).as_str(),
-        ]);
+        match self.format {
+            OutputFormat::Json => print_json(&topic)?,
+            OutputFormat::Csv => print_csv_properties(&properties),
+            OutputFormat::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec!["Property", "Value"]);
+                properties.iter().for_each(|(property, value)| {
+                    table.add_row(vec![*property, value.as_str()]);
+                });
 
-        event!(target: PRINT_TARGET, Level::INFO,"{table}");
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+        }
 
         Ok(())
     }
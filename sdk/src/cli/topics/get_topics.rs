@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
@@ -28,13 +29,15 @@ impl Display for GetTopicsOutput {
 pub struct GetTopicsCmd {
     get_topics: GetTopics,
     output: GetTopicsOutput,
+    format: OutputFormat,
 }
 
 impl GetTopicsCmd {
-    pub fn new(stream_id: Identifier, output: GetTopicsOutput) -> Self {
+    pub fn new(stream_id: Identifier, output: GetTopicsOutput, format: OutputFormat) -> Self {
         Self {
             get_topics: GetTopics { stream_id },
             output,
+            format,
         }
     }
 }
@@ -56,61 +59,54 @@ impl CliCommand for GetTopicsCmd {
             )
         })?;
 
-        match self.output {
-            GetTopicsOutput::Table => {
-                let mut table = Table::new();
+        let header = vec![
+            "ID",
+            "Created",
+            "Name",
+            "Size (B)",
+            "Max Topic Size (B)",
+            "Message Expiry (s)",
+            "Messages Count",
+            "Partitions Count",
+            "Frozen",
+        ];
+        let rows = topics
+            .iter()
+            .map(|topic| {
+                vec![
+                    format!("{}", topic.id),
+                    IggyTimestamp::from(topic.created_at).to_string("%Y-%m-%d %H:%M:%S"),
+                    topic.name.clone(),
+                    format!("{}", topic.size),
+                    match topic.max_topic_size {
+                        Some(value) => format!("{}", value),
+                        None => String::from("unlimited"),
+                    },
+                    match topic.message_expiry {
+                        Some(value) => format!("{}", value),
+                        None => String::from("unlimited"),
+                    },
+                    format!("{}", topic.messages_count),
+                    format!("{}", topic.partitions_count),
+                    format!("{}", topic.frozen),
+                ]
+            })
+            .collect::<Vec<_>>();
 
-                table.set_header(vec![
-                    "ID",
-                    "Created",
-                    "Name",
-                    "Size (B)",
-                    "Max Topic Size (B)",
-                    "Message Expiry (s)",
-                    "Messages Count",
-                    "Partitions Count",
-                ]);
+        match (self.format, &self.output) {
+            (OutputFormat::Json, _) => print_json(&topics)?,
+            (OutputFormat::Csv, _) => print_csv(&header, &rows),
+            (OutputFormat::Table, GetTopicsOutput::Table) => {
+                let mut table = Table::new();
 
-                topics.iter().for_each(|topic| {
-                    table.add_row(vec![
-                        format!("{}", topic.id),
-                        IggyTimestamp::from(topic.created_at).to_string("%Y-%m-%d %H:%M:%S"),
-                        topic.name.clone(),
-                        format!("{}", topic.size),
-                        match topic.max_topic_size {
-                            Some(value) => format!("{}", value),
-                            None => String::from("unlimited"),
-                        },
-                        match topic.message_expiry {
-                            Some(value) => format!("{}", value),
-                            None => String::from("unlimited"),
-                        },
-                        format!("{}", topic.messages_count),
-                        format!("{}", topic.partitions_count),
-                    ]);
-                });
+                table.set_header(header);
+                table.add_rows(rows);
 
                 event!(target: PRINT_TARGET, Level::INFO, "{table}");
             }
-            GetTopicsOutput::List => {
-                topics.iter().for_each(|topic| {
-                    event!(target: PRINT_TARGET, Level::INFO,
-                        "{}|{}|{}|{}|{}|{}|{}|{}",
-                        topic.id,
-                        IggyTimestamp::from(topic.created_at).to_string("%Y-%m-%d %H:%M:%S"),
-                        topic.name,
-                        topic.size,
-                        match topic.max_topic_size {
-                            Some(value) => format!("{}", value),
-                            None => String::from("unlimited"),
-                        },
-                        match topic.message_expiry {
-                            Some(value) => format!("{}", value),
-                            None => String::from("unlimited"),
-                        },
-                        topic.messages_count,
-                        topic.partitions_count
-                    );
+            (OutputFormat::Table, GetTopicsOutput::List) => {
+                rows.iter().for_each(|row| {
+                    event!(target: PRINT_TARGET, Level::INFO, "{}", row.join("|"));
                 });
             }
         }
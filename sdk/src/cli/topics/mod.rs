@@ -1,6 +1,10 @@
+pub mod clone_topic;
 pub mod create_topic;
 pub mod delete_topic;
+pub mod freeze_topic;
 pub mod get_topic;
 pub mod get_topics;
 pub mod purge_topic;
+pub mod undelete_topic;
+pub mod unfreeze_topic;
 pub mod update_topic;
@@ -1,6 +1,8 @@
+use crate::cli::utils::confirmation::Confirmation;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
+use crate::topics::get_topic::GetTopic;
 use crate::topics::purge_topic::PurgeTopic;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -8,17 +10,44 @@ use tracing::{event, Level};
 
 pub struct PurgeTopicCmd {
     purge_topic: PurgeTopic,
+    confirmation: Confirmation,
 }
 
 impl PurgeTopicCmd {
-    pub fn new(stream_id: Identifier, topic_id: Identifier) -> Self {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, confirmation: Confirmation) -> Self {
         Self {
             purge_topic: PurgeTopic {
                 stream_id,
                 topic_id,
             },
+            confirmation,
         }
     }
+
+    async fn get_summary(&self, client: &dyn Client) -> anyhow::Result<String> {
+        let topic = client
+            .get_topic(&GetTopic {
+                stream_id: self.purge_topic.stream_id.clone(),
+                topic_id: self.purge_topic.topic_id.clone(),
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {} in stream {}",
+                    self.purge_topic.topic_id, self.purge_topic.stream_id
+                )
+            })?;
+
+        Ok(format!(
+            "All {} messages of size {} in {} partitions of topic: {} (ID: {}) in stream with ID: {} will be removed",
+            topic.messages_count,
+            topic.size,
+            topic.partitions_count,
+            topic.name,
+            topic.id,
+            self.purge_topic.stream_id
+        ))
+    }
 }
 
 #[async_trait]
@@ -31,6 +60,14 @@ impl CliCommand for PurgeTopicCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        if self.confirmation.is_required()
+            && !self
+                .confirmation
+                .confirm(&self.get_summary(client).await?)?
+        {
+            return Ok(());
+        }
+
         client
             .purge_topic(&self.purge_topic)
             .await
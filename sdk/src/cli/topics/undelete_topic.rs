@@ -0,0 +1,51 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::topics::undelete_topic::UndeleteTopic;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UndeleteTopicCmd {
+    undelete_topic: UndeleteTopic,
+}
+
+impl UndeleteTopicCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier) -> Self {
+        Self {
+            undelete_topic: UndeleteTopic {
+                stream_id,
+                topic_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UndeleteTopicCmd {
+    fn explain(&self) -> String {
+        format!(
+            "undelete topic with ID: {} in stream with ID: {}",
+            self.undelete_topic.topic_id, self.undelete_topic.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .undelete_topic(&self.undelete_topic)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem undeleting topic with ID: {} in stream {}",
+                    self.undelete_topic.topic_id, self.undelete_topic.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic with ID: {} in stream with ID: {} restored from trash",
+            self.undelete_topic.topic_id, self.undelete_topic.stream_id
+        );
+
+        Ok(())
+    }
+}
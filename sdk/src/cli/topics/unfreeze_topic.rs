@@ -0,0 +1,50 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::topics::unfreeze_topic::UnfreezeTopic;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UnfreezeTopicCmd {
+    unfreeze_topic: UnfreezeTopic,
+}
+
+impl UnfreezeTopicCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier) -> Self {
+        Self {
+            unfreeze_topic: UnfreezeTopic {
+                stream_id,
+                topic_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UnfreezeTopicCmd {
+    fn explain(&self) -> String {
+        format!(
+            "unfreeze topic with ID: {} in stream with ID: {}",
+            self.unfreeze_topic.topic_id, self.unfreeze_topic.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .unfreeze_topic(&self.unfreeze_topic)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem unfreezing topic with ID: {} in stream {}",
+                    self.unfreeze_topic.topic_id, self.unfreeze_topic.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic with ID: {} in stream with ID: {} unfrozen",
+            self.unfreeze_topic.topic_id, self.unfreeze_topic.stream_id);
+
+        Ok(())
+    }
+}
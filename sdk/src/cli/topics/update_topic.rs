@@ -1,7 +1,7 @@
 use crate::cli::utils::message_expiry::MessageExpiry;
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
-use crate::identifier::Identifier;
+use crate::topics::get_topic::GetTopic;
 use crate::topics::update_topic::UpdateTopic;
 use crate::utils::byte_size::IggyByteSize;
 use anyhow::Context;
@@ -14,29 +14,26 @@ pub struct UpdateTopicCmd {
     message_expiry: MessageExpiry,
     max_topic_size: IggyByteSize,
     replication_factor: u8,
+    keep_message_schema: bool,
 }
 
 impl UpdateTopicCmd {
+    /// Creates the command from the prepared `UpdateTopic`, the message expiry is set from the provided one.
+    /// If `keep_message_schema` is set, the current message schema of the topic is sent instead of the one in `UpdateTopic`.
     pub fn new(
-        stream_id: Identifier,
-        topic_id: Identifier,
-        name: String,
+        mut update_topic: UpdateTopic,
         message_expiry: MessageExpiry,
-        max_topic_size: IggyByteSize,
-        replication_factor: u8,
+        keep_message_schema: bool,
     ) -> Self {
+        update_topic.message_expiry = message_expiry.clone().into();
+        let max_topic_size = update_topic.max_topic_size.unwrap_or_default();
+        let replication_factor = update_topic.replication_factor;
         Self {
-            update_topic: UpdateTopic {
-                stream_id,
-                topic_id,
-                name,
-                message_expiry: message_expiry.clone().into(),
-                max_topic_size: Some(max_topic_size),
-                replication_factor,
-            },
+            update_topic,
             message_expiry,
             max_topic_size,
             replication_factor,
+            keep_message_schema,
         }
     }
 }
@@ -48,6 +45,22 @@ impl CliCommand for UpdateTopicCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        if self.keep_message_schema {
+            let topic = client
+                .get_topic(&GetTopic {
+                    stream_id: self.update_topic.stream_id.clone(),
+                    topic_id: self.update_topic.topic_id.clone(),
+                })
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem getting the message schema of topic with ID: {} in stream with ID: {}",
+                        self.update_topic.topic_id, self.update_topic.stream_id
+                    )
+                })?;
+            self.update_topic.message_schema = topic.message_schema;
+        }
+
         client
             .update_topic(&self.update_topic)
             .await
@@ -0,0 +1,110 @@
+use crate::cli::utils::output_format::{print_csv_properties, print_json, OutputFormat};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::models::permission_check::PermissionAction;
+use crate::users::check_permission::CheckPermission;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct CheckPermissionCmd {
+    check_permission: CheckPermission,
+    format: OutputFormat,
+}
+
+impl CheckPermissionCmd {
+    pub fn new(
+        user_id: Identifier,
+        action: PermissionAction,
+        stream_id: Option<u32>,
+        topic_id: Option<u32>,
+        format: OutputFormat,
+    ) -> Self {
+        Self {
+            check_permission: CheckPermission {
+                user_id,
+                action,
+                stream_id,
+                topic_id,
+            },
+            format,
+        }
+    }
+
+    fn resource(&self) -> String {
+        match (
+            self.check_permission.stream_id,
+            self.check_permission.topic_id,
+        ) {
+            (Some(stream_id), Some(topic_id)) => {
+                format!(" on stream with ID: {stream_id} and topic with ID: {topic_id}")
+            }
+            (Some(stream_id), None) => format!(" on stream with ID: {stream_id}"),
+            _ => String::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CheckPermissionCmd {
+    fn explain(&self) -> String {
+        format!(
+            "check permission: {} for user with ID: {}{}",
+            self.check_permission.action,
+            self.check_permission.user_id,
+            self.resource()
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let permission_check = client
+            .check_permission(&self.check_permission)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem checking permission: {} for user with ID: {}",
+                    self.check_permission.action, self.check_permission.user_id
+                )
+            })?;
+
+        let properties = vec![
+            ("User ID", format!("{}", self.check_permission.user_id)),
+            ("Action", format!("{}", self.check_permission.action)),
+            (
+                "Allowed",
+                match permission_check.allowed {
+                    true => "yes".to_string(),
+                    false => "no".to_string(),
+                },
+            ),
+            ("Rule", permission_check.rule.clone()),
+            (
+                "Granted by",
+                match permission_check.group_id {
+                    Some(group_id) => format!("user group with ID: {group_id}"),
+                    None if permission_check.allowed => "user".to_string(),
+                    None => "-".to_string(),
+                },
+            ),
+        ];
+
+        match self.format {
+            OutputFormat::Json => print_json(&permission_check)?,
+            OutputFormat::Csv => print_csv_properties(&properties),
+            OutputFormat::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec!["Property", "Value"]);
+                properties.iter().for_each(|(property, value)| {
+                    table.add_row(vec![*property, value.as_str()]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::models::permissions::Permissions;
+use crate::users::get_user::GetUser;
+use crate::users::update_permissions::UpdatePermissions;
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::env::var;
+use std::io::Write;
+use std::process::Command;
+use toml::{Table, Value};
+use tracing::{event, Level};
+
+static DEFAULT_EDITOR: &str = "vi";
+
+pub struct EditPermissionsCmd {
+    user_id: Identifier,
+}
+
+impl EditPermissionsCmd {
+    pub fn new(user_id: Identifier) -> Self {
+        Self { user_id }
+    }
+}
+
+/// Returns the editor command from the VISUAL or EDITOR environment variables,
+/// the value can contain arguments (e.g. "code --wait").
+fn get_editor() -> Vec<String> {
+    let editor = var("VISUAL")
+        .or_else(|_| var("EDITOR"))
+        .unwrap_or_else(|_| DEFAULT_EDITOR.to_owned());
+    editor.split_whitespace().map(String::from).collect()
+}
+
+fn edit(content: &str) -> anyhow::Result<String, anyhow::Error> {
+    let mut file = tempfile::Builder::new()
+        .prefix("iggy-permissions-")
+        .suffix(".toml")
+        .tempfile()
+        .with_context(|| "Problem creating temporary permissions file")?;
+    file.write_all(content.as_bytes())?;
+    file.flush()?;
+
+    let editor = get_editor();
+    let Some((program, args)) = editor.split_first() else {
+        bail!("Editor is not set, use VISUAL or EDITOR environment variable");
+    };
+    let status = Command::new(program)
+        .args(args)
+        .arg(file.path())
+        .status()
+        .with_context(|| format!("Problem starting editor: {program}"))?;
+    if !status.success() {
+        bail!("Editor: {program} exited with {status}, permissions not changed");
+    }
+
+    std::fs::read_to_string(file.path())
+        .with_context(|| "Problem reading edited permissions file".to_owned())
+}
+
+pub(crate) fn permissions_to_toml(
+    permissions: &Permissions,
+) -> anyhow::Result<String, anyhow::Error> {
+    Ok(toml::to_string_pretty(&to_table(permissions)?)?)
+}
+
+/// Parses the edited permissions, keys which do not match any permission are reported
+/// as errors instead of being silently ignored.
+pub(crate) fn permissions_from_toml(content: &str) -> anyhow::Result<Permissions, anyhow::Error> {
+    let edited = content.parse::<Table>()?;
+    let permissions: Permissions = serde_json::from_value(serde_json::to_value(&edited)?)?;
+    let known = flatten(&to_table(&permissions)?);
+
+    let unknown = flatten(&edited)
+        .into_keys()
+        .filter(|key| !known.contains_key(key))
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        bail!("Unknown permission(s): {}", unknown.join(", "));
+    }
+
+    Ok(permissions)
+}
+
+/// TOML tables require string keys and have no null values, so the permissions
+/// are converted through JSON, which turns the stream and topic IDs into strings.
+fn to_table(permissions: &Permissions) -> anyhow::Result<Table, anyhow::Error> {
+    let mut value = serde_json::to_value(permissions)?;
+    remove_nulls(&mut value);
+    Ok(Table::try_from(value)?)
+}
+
+fn remove_nulls(value: &mut serde_json::Value) {
+    if let serde_json::Value::Object(map) = value {
+        map.retain(|_, value| !value.is_null());
+        map.values_mut().for_each(remove_nulls);
+    }
+}
+
+fn flatten(table: &Table) -> BTreeMap<String, String> {
+    let mut values = BTreeMap::new();
+    flatten_into("", table, &mut values);
+    values
+}
+
+fn flatten_into(prefix: &str, table: &Table, values: &mut BTreeMap<String, String>) {
+    for (key, value) in table {
+        let path = match prefix.is_empty() {
+            true => key.clone(),
+            false => format!("{prefix}.{key}"),
+        };
+        match value {
+            Value::Table(table) => flatten_into(&path, table, values),
+            value => {
+                values.insert(path, value.to_string());
+            }
+        }
+    }
+}
+
+/// Lists the changed permissions, one line for each added, removed or changed value.
+pub(crate) fn permissions_diff(
+    current: &Permissions,
+    edited: &Permissions,
+) -> anyhow::Result<Vec<String>, anyhow::Error> {
+    let current = flatten(&to_table(current)?);
+    let edited = flatten(&to_table(edited)?);
+
+    let mut changes = Vec::new();
+    for (key, value) in &current {
+        match edited.get(key) {
+            None => changes.push(format!("- {key} = {value}")),
+            Some(edited_value) if edited_value != value => {
+                changes.push(format!("~ {key}: {value} -> {edited_value}"))
+            }
+            _ => {}
+        }
+    }
+    for (key, value) in &edited {
+        if !current.contains_key(key) {
+            changes.push(format!("+ {key} = {value}"));
+        }
+    }
+
+    Ok(changes)
+}
+
+#[async_trait]
+impl CliCommand for EditPermissionsCmd {
+    fn explain(&self) -> String {
+        format!("edit permissions for user with ID: {}", self.user_id)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let user = client
+            .get_user(&GetUser {
+                user_id: self.user_id.clone(),
+            })
+            .await
+            .with_context(|| format!("Problem getting user with ID: {}", self.user_id))?;
+        let current = user.permissions.unwrap_or_default();
+
+        let content = edit(&permissions_to_toml(&current)?)?;
+        let edited = permissions_from_toml(&content)
+            .with_context(|| "Problem parsing edited permissions, permissions not changed")?;
+
+        let changes = permissions_diff(&current, &edited)?;
+        if changes.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO,
+                "No changes, permissions for user with ID: {} not updated", self.user_id);
+            return Ok(());
+        }
+
+        for change in &changes {
+            event!(target: PRINT_TARGET, Level::INFO, "{change}");
+        }
+
+        client
+            .update_permissions(&UpdatePermissions {
+                user_id: self.user_id.clone(),
+                permissions: Some(edited),
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating permissions for user with ID: {}",
+                    self.user_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Permissions for user with ID: {} updated", self.user_id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::permissions::{StreamPermissions, TopicPermissions};
+    use std::collections::HashMap;
+
+    fn stream_permissions() -> Permissions {
+        Permissions {
+            global: Default::default(),
+            streams: Some(HashMap::from([(
+                1,
+                StreamPermissions {
+                    read_stream: true,
+                    topics: Some(HashMap::from([(
+                        2,
+                        TopicPermissions {
+                            send_messages: true,
+                            ..Default::default()
+                        },
+                    )])),
+                    ..Default::default()
+                },
+            )])),
+        }
+    }
+
+    #[test]
+    fn should_convert_permissions_to_toml_and_back() {
+        let permissions = stream_permissions();
+
+        let content = permissions_to_toml(&permissions).unwrap();
+        let parsed = permissions_from_toml(&content).unwrap();
+
+        assert_eq!(parsed, permissions);
+    }
+
+    #[test]
+    fn should_fail_for_unknown_permission() {
+        let content = permissions_to_toml(&Permissions::default())
+            .unwrap()
+            .replace("poll_messages", "pol_messages");
+
+        let result = permissions_from_toml(&content);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_list_changed_permissions() {
+        let current = Permissions::default();
+        let mut edited = stream_permissions();
+        edited.global.poll_messages = true;
+
+        let changes = permissions_diff(&current, &edited).unwrap();
+
+        assert!(changes.contains(&"~ global.poll_messages: false -> true".to_owned()));
+        assert!(changes.contains(&"+ streams.1.read_stream = true".to_owned()));
+        assert!(changes.contains(&"+ streams.1.topics.2.send_messages = true".to_owned()));
+        assert!(permissions_diff(&edited, &edited).unwrap().is_empty());
+    }
+}
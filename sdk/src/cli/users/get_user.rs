@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv_properties, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
@@ -103,6 +104,14 @@ impl From<&StreamPermissions> for Table {
             "Send Messages",
             value.send_messages.to_string().as_str(),
         ]);
+        table.add_row(vec![
+            "Manage Partitions",
+            value.manage_partitions.to_string().as_str(),
+        ]);
+        table.add_row(vec![
+            "Manage Consumer Groups",
+            value.manage_consumer_groups.to_string().as_str(),
+        ]);
 
         if let Some(topics) = &value.topics {
             topics.iter().for_each(|(topic_id, topic_permissions)| {
@@ -120,12 +129,14 @@ impl From<&StreamPermissions> for Table {
 
 pub struct GetUserCmd {
     get_user: GetUser,
+    format: OutputFormat,
 }
 
 impl GetUserCmd {
-    pub fn new(user_id: Identifier) -> Self {
+    pub fn new(user_id: Identifier, format: OutputFormat) -> Self {
         Self {
             get_user: GetUser { user_id },
+            format,
         }
     }
 }
@@ -142,35 +153,65 @@ impl CliCommand for GetUserCmd {
             .await
             .with_context(|| format!("Problem getting user with ID: {}", self.get_user.user_id))?;
 
-        let mut table = Table::new();
-
-        table.set_header(vec!["Property", "Value"]);
-        table.add_row(vec!["User ID", format!("{}", user.id).as_str()]);
-        table.add_row(vec![
-            "Created",
-            IggyTimestamp::from(user.created_at)
-                .to_local("%Y-%m-%d %H:%M:%S")
-                .as_str(),
-        ]);
-        table.add_row(vec!["Status", format!("{}", user.status).as_str()]);
-        table.add_row(vec!["Username", user.username.as_str()]);
-
-        if let Some(permissions) = user.permissions {
-            let global_permissions: Table = permissions.global.into();
-            table.add_row(vec!["Global", format!("{}", global_permissions).as_str()]);
-
-            if let Some(streams) = permissions.streams {
-                streams.iter().for_each(|(stream_id, stream_permissions)| {
-                    let stream_permissions: Table = stream_permissions.into();
-                    table.add_row(vec![
-                        format!("Stream: {}", stream_id).as_str(),
-                        format!("{}", stream_permissions).as_str(),
-                    ]);
+        // Permissions are nested, so they're printed only in the table and JSON formats
+        let properties = vec![
+            ("User ID", format!("{}", user.id)),
+            ("User UUID", format!("{}", user.uuid)),
+            (
+                "Created",
+                IggyTimestamp::from(user.created_at).to_local("%Y-%m-%d %H:%M:%S"),
+            ),
+            ("Status", format!("{}", user.status)),
+            ("Username", user.username.clone()),
+            (
+                "Expires",
+                user.expires_at.map_or("never".to_string(), |expires_at| {
+                    IggyTimestamp::from(expires_at).to_local("%Y-%m-%d %H:%M:%S")
+                }),
+            ),
+            (
+                "Max inactive days",
+                user.max_inactive_days
+                    .map_or("unlimited".to_string(), |days| days.to_string()),
+            ),
+            (
+                "Last active",
+                user.last_active_at
+                    .map_or("-".to_string(), |last_active_at| {
+                        IggyTimestamp::from(last_active_at).to_local("%Y-%m-%d %H:%M:%S")
+                    }),
+            ),
+        ];
+
+        match self.format {
+            OutputFormat::Json => print_json(&user)?,
+            OutputFormat::Csv => print_csv_properties(&properties),
+            OutputFormat::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec!["Property", "Value"]);
+                properties.iter().for_each(|(property, value)| {
+                    table.add_row(vec![*property, value.as_str()]);
                 });
-            }
-        };
 
-        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+                if let Some(permissions) = user.permissions {
+                    let global_permissions: Table = permissions.global.into();
+                    table.add_row(vec!["Global", format!("{}", global_permissions).as_str()]);
+
+                    if let Some(streams) = permissions.streams {
+                        streams.iter().for_each(|(stream_id, stream_permissions)| {
+                            let stream_permissions: Table = stream_permissions.into();
+                            table.add_row(vec![
+                                format!("Stream: {}", stream_id).as_str(),
+                                format!("{}", stream_permissions).as_str(),
+                            ]);
+                        });
+                    }
+                };
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+        }
 
         Ok(())
     }
@@ -1,3 +1,4 @@
+use crate::cli::utils::output_format::{print_csv, print_json, OutputFormat};
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::users::get_users::GetUsers;
@@ -15,13 +16,15 @@ pub enum GetUsersOutput {
 pub struct GetUsersCmd {
     get_users: GetUsers,
     output: GetUsersOutput,
+    format: OutputFormat,
 }
 
 impl GetUsersCmd {
-    pub fn new(output: GetUsersOutput) -> Self {
+    pub fn new(output: GetUsersOutput, format: OutputFormat) -> Self {
         GetUsersCmd {
             get_users: GetUsers {},
             output,
+            format,
         }
     }
 }
@@ -31,6 +34,7 @@ impl Default for GetUsersCmd {
         GetUsersCmd {
             get_users: GetUsers {},
             output: GetUsersOutput::Table,
+            format: OutputFormat::default(),
         }
     }
 }
@@ -51,37 +55,36 @@ impl CliCommand for GetUsersCmd {
             .await
             .with_context(|| String::from("Problem getting list of users"))?;
 
-        if users.is_empty() {
-            event!(target: PRINT_TARGET, Level::INFO, "No users found!");
-            return Ok(());
-        }
+        let header = vec!["ID", "Created", "Status", "Username"];
+        let rows = users
+            .iter()
+            .map(|user| {
+                vec![
+                    format!("{}", user.id),
+                    IggyTimestamp::from(user.created_at).to_local("%Y-%m-%d %H:%M:%S"),
+                    user.status.clone().to_string(),
+                    user.username.clone(),
+                ]
+            })
+            .collect::<Vec<_>>();
 
-        match self.output {
-            GetUsersOutput::Table => {
+        match (self.format, &self.output) {
+            (OutputFormat::Json, _) => print_json(&users)?,
+            (OutputFormat::Csv, _) => print_csv(&header, &rows),
+            (OutputFormat::Table, _) if users.is_empty() => {
+                event!(target: PRINT_TARGET, Level::INFO, "No users found!");
+            }
+            (OutputFormat::Table, GetUsersOutput::Table) => {
                 let mut table = Table::new();
 
-                table.set_header(vec!["ID", "Created", "Status", "Username"]);
-
-                users.iter().for_each(|user| {
-                    table.add_row(vec![
-                        format!("{}", user.id),
-                        IggyTimestamp::from(user.created_at).to_local("%Y-%m-%d %H:%M:%S"),
-                        user.status.clone().to_string(),
-                        user.username.clone(),
-                    ]);
-                });
+                table.set_header(header);
+                table.add_rows(rows);
 
                 event!(target: PRINT_TARGET, Level::INFO, "{table}");
             }
-            GetUsersOutput::List => {
-                users.iter().for_each(|user| {
-                    event!(target: PRINT_TARGET, Level::INFO,
-                        "{}|{}|{}|{}",
-                        user.id,
-                        IggyTimestamp::from(user.created_at).to_local("%Y-%m-%d %H:%M:%S"),
-                        user.status.clone().to_string(),
-                        user.username.clone(),
-                    );
+            (OutputFormat::Table, GetUsersOutput::List) => {
+                rows.iter().for_each(|row| {
+                    event!(target: PRINT_TARGET, Level::INFO, "{}", row.join("|"));
                 });
             }
         }
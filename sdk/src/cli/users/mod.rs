@@ -1,7 +1,10 @@
 pub mod change_password;
+pub mod check_permission;
 pub mod create_user;
 pub mod delete_user;
+pub mod edit_permissions;
 pub mod get_user;
 pub mod get_users;
 pub mod update_permissions;
 pub mod update_user;
+pub mod update_user_expiry;
@@ -0,0 +1,81 @@
+use crate::cli::utils::user_expiry::UserExpiry;
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::users::update_user_expiry::UpdateUserExpiry;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UpdateUserExpiryCmd {
+    update_user_expiry: UpdateUserExpiry,
+    user_expiry: Option<UserExpiry>,
+}
+
+impl UpdateUserExpiryCmd {
+    pub fn new(
+        user_id: Identifier,
+        user_expiry: Option<UserExpiry>,
+        max_inactive_days: Option<u32>,
+    ) -> Self {
+        let expires_at = match &user_expiry {
+            Some(UserExpiry::ExpireDuration(duration)) => {
+                Some(IggyTimestamp::now().to_micros() + duration.as_micros() as u64)
+            }
+            _ => None,
+        };
+
+        Self {
+            update_user_expiry: UpdateUserExpiry {
+                user_id,
+                expires_at,
+                max_inactive_days,
+            },
+            user_expiry,
+        }
+    }
+
+    fn get_message(&self) -> String {
+        let expiry = match &self.user_expiry {
+            Some(expiry @ UserExpiry::ExpireDuration(_)) => format!("expiry: {expiry}"),
+            _ => String::from("no expiry"),
+        };
+        let max_inactive_days = match self.update_user_expiry.max_inactive_days {
+            Some(days) => format!("max inactive days: {days}"),
+            None => String::from("no max inactive days"),
+        };
+        format!("{expiry} and {max_inactive_days}")
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdateUserExpiryCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update user with ID: {} with {}",
+            self.update_user_expiry.user_id,
+            self.get_message()
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_user_expiry(&self.update_user_expiry)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating user with ID: {} with {}",
+                    self.update_user_expiry.user_id,
+                    self.get_message()
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "User with ID: {} updated with {}",
+            self.update_user_expiry.user_id, self.get_message()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::cli_command::PRINT_TARGET;
+use passterm::{isatty, Stream};
+use std::io::{self, BufRead, Write};
+use tracing::{event, Level};
+
+/// How the destructive command (e.g. delete or purge) is confirmed before it's executed
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Confirmation {
+    /// Show what will be removed and ask for the confirmation, only when the command is run in the terminal,
+    /// so the scripts piping the input keep working without any prompts
+    #[default]
+    Prompt,
+    /// Execute the command without asking
+    Assumed,
+    /// Show what would be removed without executing the command
+    DryRun,
+}
+
+impl Confirmation {
+    pub fn new(dry_run: bool, yes: bool) -> Self {
+        match (dry_run, yes) {
+            (true, _) => Self::DryRun,
+            (false, true) => Self::Assumed,
+            (false, false) => Self::Prompt,
+        }
+    }
+
+    /// Returns `true` if the summary of what's removed has to be fetched and confirmed.
+    pub fn is_required(&self) -> bool {
+        match self {
+            Self::Prompt => isatty(Stream::Stdin),
+            Self::Assumed => false,
+            Self::DryRun => true,
+        }
+    }
+
+    /// Prints the summary of what's removed and returns `true` if the command should be executed.
+    pub fn confirm(&self, summary: &str) -> anyhow::Result<bool> {
+        event!(target: PRINT_TARGET, Level::INFO, "{summary}");
+        if *self == Self::DryRun {
+            event!(target: PRINT_TARGET, Level::INFO, "Dry run, nothing has been removed");
+            return Ok(false);
+        }
+
+        // The prompt is printed also in the quiet mode, otherwise the command would wait for the input silently.
+        print!("Do you want to continue? [y/N] ");
+        io::stdout().flush()?;
+        let mut answer = String::new();
+        io::stdin().lock().read_line(&mut answer)?;
+        if matches!(answer.trim().to_lowercase().as_str(), "y" | "yes") {
+            return Ok(true);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "Aborted, nothing has been removed");
+        Ok(false)
+    }
+}
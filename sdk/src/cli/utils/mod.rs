@@ -1,2 +1,7 @@
+pub mod confirmation;
 pub mod message_expiry;
+pub mod output_format;
 pub mod personal_access_token_expiry;
+pub mod target_client;
+pub mod topic_path;
+pub mod user_expiry;
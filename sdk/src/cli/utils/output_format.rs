@@ -0,0 +1,80 @@
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Format in which the commands print the fetched resources
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    /// Human readable table (or list, depending on the command mode)
+    #[default]
+    Table,
+    /// Pretty printed JSON of the resources returned by the server
+    Json,
+    /// Comma separated values with the header in the first line
+    Csv,
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Table => write!(f, "table"),
+            Self::Json => write!(f, "json"),
+            Self::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// Prints given value as the pretty JSON on the standard output.
+///
+/// JSON (and CSV) output is meant to be consumed by other tools, so it's
+/// printed regardless of the quiet mode.
+pub fn print_json<T: Serialize + ?Sized>(value: &T) -> anyhow::Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Prints given header and rows as the CSV on the standard output.
+pub fn print_csv<H: AsRef<str>>(header: &[H], rows: &[Vec<String>]) {
+    println!("{}", to_csv_line(header));
+    rows.iter().for_each(|row| println!("{}", to_csv_line(row)));
+}
+
+/// Prints the properties of the single resource as the CSV with a header line
+/// built from the property names and a single line of values.
+pub fn print_csv_properties(properties: &[(&str, String)]) {
+    let (header, values): (Vec<&str>, Vec<String>) = properties.iter().cloned().unzip();
+    print_csv(&header, &[values]);
+}
+
+fn to_csv_line<V: AsRef<str>>(values: &[V]) -> String {
+    values
+        .iter()
+        .map(|value| escape_csv(value.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_not_quote_plain_values() {
+        assert_eq!(to_csv_line(&["1", "stream", "0"]), "1,stream,0");
+    }
+
+    #[test]
+    fn should_quote_values_with_separators_quotes_and_new_lines() {
+        assert_eq!(
+            to_csv_line(&["a,b", "say \"hi\"", "line\nbreak"]),
+            "\"a,b\",\"say \"\"hi\"\"\",\"line\nbreak\""
+        );
+    }
+}
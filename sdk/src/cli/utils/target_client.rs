@@ -0,0 +1,11 @@
+use crate::client::Client;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Connects to the server used by the command in addition to (or instead of) the default one,
+/// called only when the command is executed.
+pub type TargetClient = Box<
+    dyn FnOnce() -> Pin<Box<dyn Future<Output = anyhow::Result<Box<dyn Client>>> + Send>>
+        + Send
+        + Sync,
+>;
@@ -0,0 +1,54 @@
+use crate::identifier::{Identifier, IdentifierPath};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Topic given as `stream/topic`, both stream and topic can be specified as a name or ID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicPath {
+    pub stream_id: Identifier,
+    pub topic_id: Identifier,
+}
+
+impl FromStr for TopicPath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (stream_id, topic_id) = IdentifierPath::from_str(s)
+            .and_then(|path| path.topic())
+            .map_err(|e| format!("{e}"))?;
+
+        Ok(Self {
+            stream_id,
+            topic_id,
+        })
+    }
+}
+
+impl Display for TopicPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.stream_id, self.topic_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_topic_path() {
+        let path = TopicPath::from_str("prod/orders").unwrap();
+        assert_eq!(path.stream_id, Identifier::named("prod").unwrap());
+        assert_eq!(path.topic_id, Identifier::named("orders").unwrap());
+        assert_eq!(path.to_string(), "prod/orders");
+
+        let path = TopicPath::from_str("1/2").unwrap();
+        assert_eq!(path.stream_id, Identifier::numeric(1).unwrap());
+        assert_eq!(path.topic_id, Identifier::numeric(2).unwrap());
+    }
+
+    #[test]
+    fn should_fail_parsing_topic_path() {
+        assert!(TopicPath::from_str("orders").is_err());
+        assert!(TopicPath::from_str("prod/").is_err());
+    }
+}
@@ -0,0 +1,3 @@
+use crate::cli::utils::message_expiry::MessageExpiry;
+
+pub type UserExpiry = MessageExpiry;
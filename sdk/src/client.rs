@@ -1,3 +1,9 @@
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::connectors::create_connector::CreateConnector;
+use crate::connectors::delete_connector::DeleteConnector;
+use crate::connectors::get_connector::GetConnector;
+use crate::connectors::get_connectors::GetConnectors;
+use crate::connectors::restart_connector::RestartConnector;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
@@ -7,42 +13,87 @@ use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::IggyError;
+use crate::messages::create_fetch_session::CreateFetchSession;
+use crate::messages::delete_fetch_session::DeleteFetchSession;
+use crate::messages::fetch_messages::FetchMessages;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::query_messages::QueryMessages;
 use crate::messages::send_messages::SendMessages;
+use crate::models::batch_result::BatchCommandResult;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::connector::ConnectorInfo;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::fetch_session::{FetchSession, FetchedMessages};
+use crate::models::hello_info::HelloInfo;
 use crate::models::identity_info::IdentityInfo;
-use crate::models::messages::PolledMessages;
+use crate::models::messages::{PolledMessages, SendMessagesReceipt};
+use crate::models::metadata::Metadata;
+use crate::models::partition::{PartitionDetails, PartitionEndOffset};
+use crate::models::permission_check::PermissionCheck;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::query_result::QueryResult;
+use crate::models::schema::SchemaInfo;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
+use crate::models::tenant::Tenant;
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::user_group::{UserGroup, UserGroupDetails};
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_partition_details::GetPartitionDetails;
+use crate::partitions::get_partition_end_offsets::GetPartitionEndOffsets;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schema_versions::GetSchemaVersions;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::undelete_stream::UndeleteStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::batch::Batch;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_metadata::GetMetadata;
 use crate::system::get_stats::GetStats;
+use crate::system::heartbeat::Heartbeat;
+use crate::system::hello::Hello;
+use crate::system::negotiate_compression::NegotiateCompression;
 use crate::system::ping::Ping;
+use crate::tenants::add_tenant_user::AddTenantUser;
+use crate::tenants::create_tenant::CreateTenant;
+use crate::tenants::delete_tenant::DeleteTenant;
+use crate::tenants::get_tenant::GetTenant;
+use crate::tenants::get_tenants::GetTenants;
+use crate::tenants::remove_tenant_user::RemoveTenantUser;
+use crate::tenants::update_tenant::UpdateTenant;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
+use crate::topics::freeze_topic::FreezeTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::undelete_topic::UndeleteTopic;
+use crate::topics::unfreeze_topic::UnfreezeTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::user_groups::add_user_group_member::AddUserGroupMember;
+use crate::user_groups::create_user_group::CreateUserGroup;
+use crate::user_groups::delete_user_group::DeleteUserGroup;
+use crate::user_groups::get_user_group::GetUserGroup;
+use crate::user_groups::get_user_groups::GetUserGroups;
+use crate::user_groups::remove_user_group_member::RemoveUserGroupMember;
+use crate::user_groups::update_user_group_permissions::UpdateUserGroupPermissions;
 use crate::users::change_password::ChangePassword;
+use crate::users::check_permission::CheckPermission;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
@@ -51,6 +102,7 @@ use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_expiry::UpdateUserExpiry;
 use async_trait::async_trait;
 use std::fmt::Debug;
 
@@ -68,6 +120,10 @@ pub trait Client:
     + MessageClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
+    + SchemaClient
+    + TenantClient
+    + UserGroupClient
+    + ConnectorClient
     + Sync
     + Send
     + Debug
@@ -87,6 +143,10 @@ pub trait SystemClient {
     ///
     /// Authentication is required, and the permission to read the server info.
     async fn get_stats(&self, command: &GetStats) -> Result<Stats, IggyError>;
+    /// Get the metadata of all the streams, topics and partitions in a single response, instead of fetching each stream and topic separately.
+    ///
+    /// Authentication is required, and only the streams the user is allowed to read are returned.
+    async fn get_metadata(&self, command: &GetMetadata) -> Result<Metadata, IggyError>;
     /// Get the info about the currently connected client (not to be confused with the user).
     ///
     /// Authentication is required.
@@ -101,6 +161,24 @@ pub trait SystemClient {
     async fn get_clients(&self, command: &GetClients) -> Result<Vec<ClientInfo>, IggyError>;
     /// Ping the server to check if it's alive.
     async fn ping(&self, command: &Ping) -> Result<(), IggyError>;
+    /// Send the heartbeat to let the server know that the connection is still alive.
+    /// It's sent periodically in the background by the TCP and QUIC clients, and it's not supported by the HTTP client.
+    async fn heartbeat(&self, command: &Heartbeat) -> Result<(), IggyError>;
+    /// Perform the handshake by exchanging the protocol versions and the supported features with the server.
+    /// It's performed by the `IggyClient` right after connecting to the server.
+    async fn hello(&self, command: &Hello) -> Result<HelloInfo, IggyError>;
+    /// Execute several commands in a single round trip and get the status (and the raw payload) of each command, in the same order as the commands were provided.
+    /// The commands are sent as they are, so the message payloads are neither compressed nor encrypted, and it's not supported by the HTTP client.
+    ///
+    /// Each command requires the same authentication and permissions as when it's sent on its own.
+    async fn batch(&self, command: &Batch) -> Result<Vec<BatchCommandResult>, IggyError>;
+    /// Advertise the compression algorithms supported by the client and get the one which should be used to compress the message payloads.
+    ///
+    /// Authentication is required.
+    async fn negotiate_compression(
+        &self,
+        command: &NegotiateCompression,
+    ) -> Result<CompressionAlgorithm, IggyError>;
 }
 
 /// This trait defines the methods to interact with the user module.
@@ -130,6 +208,10 @@ pub trait UserClient {
     ///
     /// Authentication is required, and the permission to manage the users.
     async fn update_permissions(&self, command: &UpdatePermissions) -> Result<(), IggyError>;
+    /// Update the expiry and the inactivity window of a user by unique ID or username, after which the user is disabled.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_user_expiry(&self, command: &UpdateUserExpiry) -> Result<(), IggyError>;
     /// Change the password of a user by unique ID or username.
     ///
     /// Authentication is required, and the permission to manage the users, unless the provided user ID is the same as the authenticated user.
@@ -138,6 +220,13 @@ pub trait UserClient {
     async fn login_user(&self, command: &LoginUser) -> Result<IdentityInfo, IggyError>;
     /// Logout the currently authenticated user.
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), IggyError>;
+    /// Check whether the action would be allowed for a user by unique ID or username, and which permission granted or denied it.
+    ///
+    /// Authentication is required, and the permission to read the users, unless the provided user ID is the same as the authenticated user.
+    async fn check_permission(
+        &self,
+        command: &CheckPermission,
+    ) -> Result<PermissionCheck, IggyError>;
 }
 
 /// This trait defines the methods to interact with the personal access token module.
@@ -184,10 +273,14 @@ pub trait StreamClient {
     ///
     /// Authentication is required, and the permission to manage the streams.
     async fn update_stream(&self, command: &UpdateStream) -> Result<(), IggyError>;
-    /// Delete a stream by unique ID or name.
+    /// Delete a stream by unique ID or name, the soft deleted stream is moved to the trash.
     ///
     /// Authentication is required, and the permission to manage the streams.
     async fn delete_stream(&self, command: &DeleteStream) -> Result<(), IggyError>;
+    /// Restore the soft deleted stream from the trash by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    async fn undelete_stream(&self, command: &UndeleteStream) -> Result<(), IggyError>;
     /// Purge a stream by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the streams.
@@ -213,19 +306,47 @@ pub trait TopicClient {
     ///
     /// Authentication is required, and the permission to manage the topics.
     async fn update_topic(&self, command: &UpdateTopic) -> Result<(), IggyError>;
-    /// Delete a topic by unique ID or name.
+    /// Delete a topic by unique ID or name, the soft deleted topic is moved to the trash.
     ///
     /// Authentication is required, and the permission to manage the topics.
     async fn delete_topic(&self, command: &DeleteTopic) -> Result<(), IggyError>;
+    /// Restore the soft deleted topic from the trash by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn undelete_topic(&self, command: &UndeleteTopic) -> Result<(), IggyError>;
     /// Purge a topic by unique ID or name.
     ///
     /// Authentication is required, and the permission to manage the topics.
     async fn purge_topic(&self, command: &PurgeTopic) -> Result<(), IggyError>;
+    /// Freeze a topic by unique ID or name, the messages can no longer be sent to it, but they can still be polled.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn freeze_topic(&self, command: &FreezeTopic) -> Result<(), IggyError>;
+    /// Unfreeze a topic by unique ID or name, the messages can be sent to it again.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn unfreeze_topic(&self, command: &UnfreezeTopic) -> Result<(), IggyError>;
 }
 
 /// This trait defines the methods to interact with the partition module.
 #[async_trait]
 pub trait PartitionClient {
+    /// Get the info about a specific partition of a topic by unique IDs or names, including its segments.
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_partition_details(
+        &self,
+        command: &GetPartitionDetails,
+    ) -> Result<PartitionDetails, IggyError>;
+    /// Get the end offsets (the offsets of the next messages to be appended) of all the partitions of a topic by unique IDs or names.
+    ///
+    /// It's a lightweight alternative to getting the topic details, e.g. for calculating the consumer lag.
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_partition_end_offsets(
+        &self,
+        command: &GetPartitionEndOffsets,
+    ) -> Result<Vec<PartitionEndOffset>, IggyError>;
     /// Create new N partitions for a topic by unique ID or name.
     ///
     /// For example, given a topic with 3 partitions, if you create 2 partitions, the topic will have 5 partitions (from 1 to 5).
@@ -251,6 +372,37 @@ pub trait MessageClient {
     ///
     /// Authentication is required, and the permission to send the messages.
     async fn send_messages(&self, command: &mut SendMessages) -> Result<(), IggyError>;
+    /// Send messages like `send_messages()`, but return the receipt with the partition, the offsets and the log-append timestamp assigned to the messages,
+    /// e.g. to keep track of the produced messages downstream. The messages are always sent immediately, rather than in the background.
+    /// The older server doesn't return the receipt, in which case the messages are appended, but `FeatureUnavailable` error is returned.
+    /// The receipt can't be returned for `Acks::None`, so such command is rejected.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn send_messages_with_receipt(
+        &self,
+        command: &mut SendMessages,
+    ) -> Result<SendMessagesReceipt, IggyError>;
+    /// Browse the messages stored in the partition with the read-only, SQL-like query, without affecting the consumer offsets.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn query_messages(&self, command: &QueryMessages) -> Result<QueryResult, IggyError>;
+    /// Register the set of partitions polled by the consumer once, so the subsequent fetches only send the session ID and epoch.
+    /// The session is bound to the connection, and it's not supported by the HTTP client.
+    ///
+    /// Authentication is required, and the permission to poll the messages from each of the partitions.
+    async fn create_fetch_session(
+        &self,
+        command: &CreateFetchSession,
+    ) -> Result<FetchSession, IggyError>;
+    /// Fetch the messages from the partitions of the fetch session, only the partitions with the new messages are returned.
+    /// The returned epoch has to be sent with the next fetch, and it's not supported by the HTTP client.
+    ///
+    /// Authentication is required, and the permission to poll the messages from each of the partitions.
+    async fn fetch_messages(&self, command: &FetchMessages) -> Result<FetchedMessages, IggyError>;
+    /// Delete the fetch session, it's not supported by the HTTP client.
+    ///
+    /// Authentication is required.
+    async fn delete_fetch_session(&self, command: &DeleteFetchSession) -> Result<(), IggyError>;
 }
 
 /// This trait defines the methods to interact with the consumer offset module.
@@ -303,3 +455,135 @@ pub trait ConsumerGroupClient {
     /// Authentication is required, and the permission to read the streams or topics.
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), IggyError>;
 }
+
+/// This trait defines the methods to interact with the schema registry module.
+#[async_trait]
+pub trait SchemaClient {
+    /// Get the schema by unique ID, e.g. the one the received message was tagged with.
+    ///
+    /// Authentication is required.
+    async fn get_schema(&self, command: &GetSchema) -> Result<SchemaInfo, IggyError>;
+    /// Get all the versions of the schema registered for the subject, ordered by version.
+    ///
+    /// Authentication is required.
+    async fn get_schema_versions(
+        &self,
+        command: &GetSchemaVersions,
+    ) -> Result<Vec<SchemaInfo>, IggyError>;
+    /// Register the new version of the schema for the subject. If the same schema is already registered for the subject, its existing version is returned.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    async fn register_schema(&self, command: &RegisterSchema) -> Result<SchemaInfo, IggyError>;
+    /// Update the compatibility checked when registering the new versions of the schema for the subject.
+    ///
+    /// Authentication is required, and the permission to manage the streams or topics.
+    async fn update_schema_compatibility(
+        &self,
+        command: &UpdateSchemaCompatibility,
+    ) -> Result<(), IggyError>;
+}
+
+/// This trait defines the methods to interact with the tenants module.
+/// The tenant is the namespace above the streams, with its own resource quotas and users, which can access only the streams owned by it.
+#[async_trait]
+pub trait TenantClient {
+    /// Get the info about a specific tenant by unique ID or name, including the current usage of its quotas.
+    ///
+    /// Authentication is required, and the permission to read the server info, unless the user is assigned to the tenant.
+    async fn get_tenant(&self, command: &GetTenant) -> Result<Tenant, IggyError>;
+    /// Get the info about all the tenants.
+    ///
+    /// Authentication is required, and the permission to read the server info. The user assigned to the tenant gets only its own tenant.
+    async fn get_tenants(&self, command: &GetTenants) -> Result<Vec<Tenant>, IggyError>;
+    /// Create a new tenant with the optional resource quotas.
+    ///
+    /// Authentication is required, and the permission to manage the server. The user assigned to the tenant can't manage the tenants.
+    async fn create_tenant(&self, command: &CreateTenant) -> Result<(), IggyError>;
+    /// Update the resource quotas of the tenant.
+    ///
+    /// Authentication is required, and the permission to manage the server. The user assigned to the tenant can't manage the tenants.
+    async fn update_tenant(&self, command: &UpdateTenant) -> Result<(), IggyError>;
+    /// Delete the tenant, which must not own any streams nor have any users assigned.
+    ///
+    /// Authentication is required, and the permission to manage the server. The user assigned to the tenant can't manage the tenants.
+    async fn delete_tenant(&self, command: &DeleteTenant) -> Result<(), IggyError>;
+    /// Assign the user to the tenant, so that it can access only the streams owned by the tenant.
+    ///
+    /// Authentication is required, and the permission to manage the server. The user assigned to the tenant can't manage the tenants.
+    async fn add_tenant_user(&self, command: &AddTenantUser) -> Result<(), IggyError>;
+    /// Unassign the user from the tenant.
+    ///
+    /// Authentication is required, and the permission to manage the server. The user assigned to the tenant can't manage the tenants.
+    async fn remove_tenant_user(&self, command: &RemoveTenantUser) -> Result<(), IggyError>;
+}
+
+/// This trait defines the methods to interact with the user groups module.
+/// The user group shares its permissions with all the members, which are merged with their own permissions.
+#[async_trait]
+pub trait UserGroupClient {
+    /// Get the info about a specific user group by unique ID or name, including its members and permissions.
+    ///
+    /// Authentication is required, and the permission to read the users.
+    async fn get_user_group(&self, command: &GetUserGroup) -> Result<UserGroupDetails, IggyError>;
+    /// Get the info about all the user groups.
+    ///
+    /// Authentication is required, and the permission to read the users.
+    async fn get_user_groups(&self, command: &GetUserGroups) -> Result<Vec<UserGroup>, IggyError>;
+    /// Create a new user group with the optional permissions.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn create_user_group(&self, command: &CreateUserGroup) -> Result<(), IggyError>;
+    /// Update the permissions of the user group, which affects all of its members.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_user_group_permissions(
+        &self,
+        command: &UpdateUserGroupPermissions,
+    ) -> Result<(), IggyError>;
+    /// Delete the user group, the members lose the permissions granted by the group.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn delete_user_group(&self, command: &DeleteUserGroup) -> Result<(), IggyError>;
+    /// Add the user to the user group, so that it gets the permissions granted by the group.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn add_user_group_member(&self, command: &AddUserGroupMember) -> Result<(), IggyError>;
+    /// Remove the user from the user group.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn remove_user_group_member(
+        &self,
+        command: &RemoveUserGroupMember,
+    ) -> Result<(), IggyError>;
+}
+
+/// This trait defines the methods to interact with the connectors module.
+/// The connector runs inside the server and either appends the records from the external system to the topic (source),
+/// or writes the messages consumed from the topic to the external system (sink).
+/// The connectors must be enabled in the server configuration.
+#[async_trait]
+pub trait ConnectorClient {
+    /// Get the info about a specific connector by unique name, including its status and the number of the processed messages.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_connector(&self, command: &GetConnector) -> Result<ConnectorInfo, IggyError>;
+    /// Get the info about all the connectors.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_connectors(
+        &self,
+        command: &GetConnectors,
+    ) -> Result<Vec<ConnectorInfo>, IggyError>;
+    /// Create a new connector, which is started immediately and restored after the server restart.
+    ///
+    /// Authentication is required, and the permission to manage the server.
+    async fn create_connector(&self, command: &CreateConnector) -> Result<(), IggyError>;
+    /// Restart the connector, e.g. the failed one which has exhausted the retries.
+    ///
+    /// Authentication is required, and the permission to manage the server.
+    async fn restart_connector(&self, command: &RestartConnector) -> Result<(), IggyError>;
+    /// Delete the connector, which is stopped and no longer restored after the server restart.
+    ///
+    /// Authentication is required, and the permission to manage the server.
+    async fn delete_connector(&self, command: &DeleteConnector) -> Result<(), IggyError>;
+}
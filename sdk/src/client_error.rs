@@ -12,6 +12,12 @@ pub enum ClientError {
     /// Transport is invalid and cannot be used.
     #[error("Invalid transport {0}")]
     InvalidTransport(String),
+    /// Connection string is invalid and cannot be parsed.
+    #[error("Invalid connection string: {0}")]
+    InvalidConnectionString(String),
+    /// None of the transports provided for the failover could be connected.
+    #[error("No available transport, tried: {0}")]
+    NoAvailableTransport(String),
     /// IO error.
     #[error("IO error")]
     IoError(#[from] io::Error),
@@ -0,0 +1,391 @@
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::headers_filter::HeadersFilter;
+use crate::messages::poll_messages::{PollMessages, PollingMode, PollingStrategy};
+use crate::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use crate::models::messages::PolledMessages;
+use crate::validatable::Validatable;
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::time::Duration;
+
+/// The fluent builder for the `PollMessages` command, created by `IggyClient::poll()` or `PollMessagesBuilder::new()`.
+/// The stream and topic have to be provided, the other fields have the same defaults as `PollMessages`
+/// (the default consumer, partition 1, 10 messages from offset 0 and no auto commit).
+/// Awaiting the builder polls the messages, `build()` returns the command without sending it.
+///
+/// ```no_run
+/// # use iggy::clients::client::IggyClient;
+/// # async fn poll(client: &IggyClient) -> Result<(), iggy::error::IggyError> {
+/// let polled_messages = client
+///     .poll()
+///     .stream("orders")
+///     .topic("created")
+///     .partition(1)
+///     .last(100)
+///     .auto_commit()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct PollMessagesBuilder<'a> {
+    client: &'a dyn Client,
+    stream_id: Option<Result<Identifier, IggyError>>,
+    topic_id: Option<Result<Identifier, IggyError>>,
+    command: PollMessages,
+}
+
+impl<'a> PollMessagesBuilder<'a> {
+    /// Creates a new `PollMessagesBuilder` polling the messages using the provided client.
+    pub fn new(client: &'a dyn Client) -> Self {
+        Self {
+            client,
+            stream_id: None,
+            topic_id: None,
+            command: PollMessages::default(),
+        }
+    }
+
+    /// Poll the messages from the stream with the provided ID or name.
+    pub fn stream(mut self, stream: &str) -> Self {
+        self.stream_id = Some(Identifier::from_str_value(stream));
+        self
+    }
+
+    /// Poll the messages from the topic with the provided ID or name.
+    pub fn topic(mut self, topic: &str) -> Self {
+        self.topic_id = Some(Identifier::from_str_value(topic));
+        self
+    }
+
+    /// Poll the messages from the partition with the provided ID.
+    pub fn partition(mut self, partition_id: u32) -> Self {
+        self.command.partition_id = Some(partition_id);
+        self
+    }
+
+    /// Poll the messages as the provided consumer.
+    pub fn consumer(mut self, consumer: Consumer) -> Self {
+        self.command.consumer = consumer;
+        self
+    }
+
+    /// Poll the messages as the member of the consumer group with the provided ID or name, the partition is assigned by the server.
+    pub fn consumer_group(mut self, group: &str) -> Self {
+        match Identifier::from_str_value(group) {
+            Ok(group_id) => {
+                self.command.consumer = Consumer::group(group_id);
+                self.command.partition_id = None;
+            }
+            Err(error) => self.stream_id = Some(Err(error)),
+        }
+        self
+    }
+
+    /// Poll the provided number of messages starting from the provided offset.
+    pub fn offset(self, offset: u64, count: u32) -> Self {
+        self.strategy(PollingStrategy::offset(offset), count)
+    }
+
+    /// Poll the provided number of messages starting from the provided timestamp (in microseconds).
+    pub fn timestamp(self, timestamp: u64, count: u32) -> Self {
+        self.strategy(PollingStrategy::timestamp(timestamp), count)
+    }
+
+    /// Poll the provided number of the first messages in the partition.
+    pub fn first(self, count: u32) -> Self {
+        self.strategy(PollingStrategy::first(), count)
+    }
+
+    /// Poll the provided number of the last messages in the partition.
+    pub fn last(self, count: u32) -> Self {
+        self.strategy(PollingStrategy::last(), count)
+    }
+
+    /// Poll the provided number of messages following the stored consumer offset.
+    pub fn next(self, count: u32) -> Self {
+        self.strategy(PollingStrategy::next(), count)
+    }
+
+    /// Poll the provided number of messages using the provided strategy.
+    pub fn strategy(mut self, strategy: PollingStrategy, count: u32) -> Self {
+        self.command.strategy = strategy;
+        self.command.count = count;
+        self
+    }
+
+    /// Commit the offset of the last polled message on the server.
+    pub fn auto_commit(mut self) -> Self {
+        self.command.auto_commit = true;
+        self
+    }
+
+    /// Return only the messages matching the provided filter over the message headers.
+    pub fn filter(mut self, filter: HeadersFilter) -> Self {
+        self.command.filter = Some(filter);
+        self
+    }
+
+    /// Browse the messages without storing the consumer offset.
+    pub fn peek(mut self) -> Self {
+        self.command.mode = PollingMode::Peek;
+        self
+    }
+
+    /// Wait up to the provided time for the new messages if there are none to poll, instead of returning immediately.
+    pub fn wait(mut self, max_wait: Duration) -> Self {
+        self.command.max_wait_ms = Some(max_wait.as_millis().min(u32::MAX as u128) as u32);
+        self
+    }
+
+    /// Limit the total size of the polled messages, the ones exceeding it are left out and polled from the returned next offset.
+    pub fn max_bytes(mut self, max_bytes: u32) -> Self {
+        self.command.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Build the validated `PollMessages` command.
+    pub fn build(self) -> Result<PollMessages, IggyError> {
+        let mut command = self.command;
+        command.stream_id = self.stream_id.ok_or(IggyError::InvalidStreamId)??;
+        command.topic_id = self.topic_id.ok_or(IggyError::InvalidTopicId)??;
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl<'a> IntoFuture for PollMessagesBuilder<'a> {
+    type Output = Result<PolledMessages, IggyError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let client = self.client;
+        Box::pin(async move { client.poll_messages(&self.build()?).await })
+    }
+}
+
+/// The fluent builder for the `SendMessages` command, created by `IggyClient::send()` or `SendMessagesBuilder::new()`.
+/// The stream, topic and at least one message have to be provided, the messages are balanced between the partitions by default.
+/// Awaiting the builder sends the messages, `build()` returns the command without sending it.
+///
+/// ```no_run
+/// # use iggy::clients::client::IggyClient;
+/// # use iggy::messages::send_messages::Message;
+/// # use std::str::FromStr;
+/// # async fn send(client: &IggyClient) -> Result<(), iggy::error::IggyError> {
+/// client
+///     .send()
+///     .stream("orders")
+///     .topic("created")
+///     .key("customer-1")
+///     .message(Message::from_str("order 1")?)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SendMessagesBuilder<'a> {
+    client: &'a dyn Client,
+    stream_id: Option<Result<Identifier, IggyError>>,
+    topic_id: Option<Result<Identifier, IggyError>>,
+    partitioning: Result<Partitioning, IggyError>,
+    messages: Vec<Message>,
+    acks: Option<Acks>,
+}
+
+impl<'a> SendMessagesBuilder<'a> {
+    /// Creates a new `SendMessagesBuilder` sending the messages using the provided client.
+    pub fn new(client: &'a dyn Client) -> Self {
+        Self {
+            client,
+            stream_id: None,
+            topic_id: None,
+            partitioning: Ok(Partitioning::balanced()),
+            messages: Vec::new(),
+            acks: None,
+        }
+    }
+
+    /// Send the messages to the stream with the provided ID or name.
+    pub fn stream(mut self, stream: &str) -> Self {
+        self.stream_id = Some(Identifier::from_str_value(stream));
+        self
+    }
+
+    /// Send the messages to the topic with the provided ID or name.
+    pub fn topic(mut self, topic: &str) -> Self {
+        self.topic_id = Some(Identifier::from_str_value(topic));
+        self
+    }
+
+    /// Send the messages to the partition with the provided ID.
+    pub fn partition(mut self, partition_id: u32) -> Self {
+        self.partitioning = Ok(Partitioning::partition_id(partition_id));
+        self
+    }
+
+    /// Send the messages to the partition calculated by the server from the provided key.
+    pub fn key(mut self, key: &str) -> Self {
+        self.partitioning = Partitioning::messages_key_str(key);
+        self
+    }
+
+    /// Send the messages using the provided partitioning.
+    pub fn partitioning(mut self, partitioning: Partitioning) -> Self {
+        self.partitioning = Ok(partitioning);
+        self
+    }
+
+    /// Send the messages with the provided acknowledgement level, rather than the client (or server) default.
+    pub fn acks(mut self, acks: Acks) -> Self {
+        self.acks = Some(acks);
+        self
+    }
+
+    /// Append the provided message.
+    pub fn message(mut self, message: Message) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    /// Append the provided messages.
+    pub fn messages(mut self, messages: impl IntoIterator<Item = Message>) -> Self {
+        self.messages.extend(messages);
+        self
+    }
+
+    /// Build the validated `SendMessages` command.
+    pub fn build(self) -> Result<SendMessages, IggyError> {
+        let command = SendMessages {
+            stream_id: self.stream_id.ok_or(IggyError::InvalidStreamId)??,
+            topic_id: self.topic_id.ok_or(IggyError::InvalidTopicId)??,
+            partitioning: self.partitioning?,
+            messages: self.messages,
+            acks: self.acks,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl<'a> IntoFuture for SendMessagesBuilder<'a> {
+    type Output = Result<(), IggyError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let client = self.client;
+        Box::pin(async move { client.send_messages(&mut self.build()?).await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::client::IggyClient;
+    use crate::messages::poll_messages::PollingKind;
+    use crate::messages::send_messages::PartitioningKind;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_build_poll_messages() {
+        let client = IggyClient::default();
+
+        let command = client
+            .poll()
+            .stream("orders")
+            .topic("2")
+            .partition(3)
+            .last(100)
+            .auto_commit()
+            .wait(Duration::from_secs(5))
+            .max_bytes(1024)
+            .build()
+            .unwrap();
+
+        assert_eq!(command.stream_id, Identifier::named("orders").unwrap());
+        assert_eq!(command.topic_id, Identifier::numeric(2).unwrap());
+        assert_eq!(command.partition_id, Some(3));
+        assert_eq!(command.strategy.kind, PollingKind::Last);
+        assert_eq!(command.count, 100);
+        assert!(command.auto_commit);
+        assert_eq!(command.max_wait_ms, Some(5000));
+        assert_eq!(command.max_bytes, Some(1024));
+        assert_eq!(command.consumer, Consumer::default());
+    }
+
+    #[test]
+    fn should_build_poll_messages_for_consumer_group() {
+        let client = IggyClient::default();
+
+        let command = client
+            .poll()
+            .stream("1")
+            .topic("1")
+            .consumer_group("workers")
+            .next(10)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            command.consumer,
+            Consumer::group(Identifier::named("workers").unwrap())
+        );
+        assert_eq!(command.partition_id, None);
+        assert_eq!(command.strategy.kind, PollingKind::Next);
+    }
+
+    #[test]
+    fn should_not_build_poll_messages_without_stream_or_topic() {
+        let client = IggyClient::default();
+
+        assert!(client.poll().topic("1").build().is_err());
+        assert!(client.poll().stream("1").build().is_err());
+    }
+
+    #[test]
+    fn should_not_build_poll_messages_with_auto_commit_in_peek_mode() {
+        let client = IggyClient::default();
+
+        let command = client
+            .poll()
+            .stream("1")
+            .topic("1")
+            .peek()
+            .auto_commit()
+            .build();
+
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_build_send_messages() {
+        let client = IggyClient::default();
+
+        let command = client
+            .send()
+            .stream("orders")
+            .topic("created")
+            .key("customer-1")
+            .message(Message::from_str("first").unwrap())
+            .messages(vec![Message::from_str("second").unwrap()])
+            .build()
+            .unwrap();
+
+        assert_eq!(command.stream_id, Identifier::named("orders").unwrap());
+        assert_eq!(command.topic_id, Identifier::named("created").unwrap());
+        assert_eq!(command.partitioning.kind, PartitioningKind::MessagesKey);
+        assert_eq!(command.messages.len(), 2);
+    }
+
+    #[test]
+    fn should_not_build_send_messages_without_messages() {
+        let client = IggyClient::default();
+
+        let command = client.send().stream("1").topic("1").build();
+
+        assert!(command.is_err());
+    }
+}
@@ -1,7 +1,15 @@
 use crate::client::{
-    Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient, UserClient,
+    Client, ConnectorClient, ConsumerGroupClient, ConsumerOffsetClient, MessageClient,
+    PartitionClient, PersonalAccessTokenClient, SchemaClient, StreamClient, SystemClient,
+    TenantClient, TopicClient, UserClient, UserGroupClient,
 };
+use crate::clients::builders::{PollMessagesBuilder, SendMessagesBuilder};
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::connectors::create_connector::CreateConnector;
+use crate::connectors::delete_connector::DeleteConnector;
+use crate::connectors::get_connector::GetConnector;
+use crate::connectors::get_connectors::GetConnectors;
+use crate::connectors::restart_connector::RestartConnector;
 use crate::consumer::Consumer;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
@@ -11,47 +19,94 @@ use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
-use crate::error::IggyError;
+use crate::error::{IggyError, IggyErrorDiscriminants};
 use crate::identifier::Identifier;
 use crate::message_handler::MessageHandler;
+use crate::messages::create_fetch_session::CreateFetchSession;
+use crate::messages::delete_fetch_session::DeleteFetchSession;
+use crate::messages::fetch_messages::FetchMessages;
 use crate::messages::poll_messages::{PollMessages, PollingKind};
-use crate::messages::send_messages::{Partitioning, PartitioningKind, SendMessages};
+use crate::messages::query_messages::QueryMessages;
+use crate::messages::send_messages::{Acks, Partitioning, PartitioningKind, SendMessages};
+use crate::metrics::{ClientMetrics, ClientOperation};
+use crate::models::batch_result::BatchCommandResult;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::connector::ConnectorInfo;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::fetch_session::{FetchSession, FetchedMessages};
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::hello_info::HelloInfo;
 use crate::models::identity_info::IdentityInfo;
-use crate::models::messages::{Message, PolledMessages};
+use crate::models::messages::{Message, PolledMessages, SendMessagesReceipt};
+use crate::models::metadata::Metadata;
+use crate::models::partition::{PartitionDetails, PartitionEndOffset};
+use crate::models::permission_check::PermissionCheck;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::query_result::QueryResult;
+use crate::models::schema::SchemaInfo;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
+use crate::models::tenant::Tenant;
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::user_group::{UserGroup, UserGroupDetails};
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitioner::Partitioner;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_partition_details::GetPartitionDetails;
+use crate::partitions::get_partition_end_offsets::GetPartitionEndOffsets;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schema_versions::GetSchemaVersions;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::undelete_stream::UndeleteStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::batch::Batch;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_metadata::GetMetadata;
 use crate::system::get_stats::GetStats;
+use crate::system::heartbeat::Heartbeat;
+use crate::system::hello::Hello;
+use crate::system::negotiate_compression::NegotiateCompression;
 use crate::system::ping::Ping;
 use crate::tcp::client::TcpClient;
+use crate::tenants::add_tenant_user::AddTenantUser;
+use crate::tenants::create_tenant::CreateTenant;
+use crate::tenants::delete_tenant::DeleteTenant;
+use crate::tenants::get_tenant::GetTenant;
+use crate::tenants::get_tenants::GetTenants;
+use crate::tenants::remove_tenant_user::RemoveTenantUser;
+use crate::tenants::update_tenant::UpdateTenant;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
+use crate::topics::freeze_topic::FreezeTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::undelete_topic::UndeleteTopic;
+use crate::topics::unfreeze_topic::UnfreezeTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::user_groups::add_user_group_member::AddUserGroupMember;
+use crate::user_groups::create_user_group::CreateUserGroup;
+use crate::user_groups::delete_user_group::DeleteUserGroup;
+use crate::user_groups::get_user_group::GetUserGroup;
+use crate::user_groups::get_user_groups::GetUserGroups;
+use crate::user_groups::remove_user_group_member::RemoveUserGroupMember;
+use crate::user_groups::update_user_group_permissions::UpdateUserGroupPermissions;
 use crate::users::change_password::ChangePassword;
+use crate::users::check_permission::CheckPermission;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
@@ -60,15 +115,18 @@ use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_expiry::UpdateUserExpiry;
+use crate::utils::checksum::ChecksumPolicy;
 use crate::utils::crypto::Encryptor;
 use async_dropper::AsyncDrop;
 use async_trait::async_trait;
 use bytes::Bytes;
 use flume::{Receiver, Sender};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
+use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::{Mutex, RwLock};
 use tokio::task::JoinHandle;
 use tokio::time::sleep;
@@ -85,8 +143,15 @@ pub struct IggyClient {
     encryptor: Option<Box<dyn Encryptor>>,
     message_handler: Option<Arc<Box<dyn MessageHandler>>>,
     message_channel_sender: Option<Arc<Sender<Message>>>,
+    pending_offsets: Arc<Mutex<Vec<StoreConsumerOffset>>>,
+    compression_algorithm: Arc<Mutex<Option<CompressionAlgorithm>>>,
+    hello_info: Arc<Mutex<Option<HelloInfo>>>,
+    metadata: Arc<Mutex<Option<CachedMetadata>>>,
 }
 
+/// The name of the message header containing the algorithm used to compress the payload by the `IggyClient`.
+pub const COMPRESSION_HEADER: &str = "iggy-compression";
+
 /// The builder for the `IggyClient` instance, which allows to configure and provide custom implementations for the partitioner, encryptor or message handler.
 #[derive(Debug)]
 pub struct IggyClientBuilder {
@@ -137,6 +202,12 @@ struct SendMessagesBatch {
     pub commands: VecDeque<SendMessages>,
 }
 
+#[derive(Debug)]
+struct CachedMetadata {
+    metadata: Metadata,
+    fetched_at: Instant,
+}
+
 /// The optional configuration for the `IggyClient` instance, consisting of the optional configuration for sending and polling the messages in the background.
 #[derive(Debug, Default)]
 pub struct IggyClientConfig {
@@ -144,6 +215,36 @@ pub struct IggyClientConfig {
     pub send_messages: SendMessagesConfig,
     /// The configuration for polling the messages in the background.
     pub poll_messages: PollMessagesConfig,
+    /// The policy for validating the checksum of the polled messages.
+    pub checksum_policy: ChecksumPolicy,
+    /// The optional callbacks recording the client-side metrics of sending and polling the messages, also in the background.
+    pub metrics: Option<Arc<dyn ClientMetrics>>,
+    /// The optional configuration for compressing the message payloads before sending them to the server.
+    pub compression: Option<CompressionConfig>,
+    /// The configuration for caching the metadata of the streams, topics and partitions.
+    pub metadata_cache: MetadataCacheConfig,
+}
+
+/// The configuration for caching the metadata returned by `get_metadata()`, so that it's not fetched from the server on each call.
+/// The cached metadata is invalidated once the TTL expires, when the streams, topics or partitions are created, updated or deleted by this client,
+/// and when any of the commands operating on them (including sending and polling the messages) fails, as the metadata might be stale.
+#[derive(Debug, Copy, Clone)]
+pub struct MetadataCacheConfig {
+    /// Whether the metadata is cached.
+    pub enabled: bool,
+    /// The time to live in milliseconds of the cached metadata.
+    pub ttl: u64,
+}
+
+/// The configuration for compressing the message payloads on the client side.
+/// The algorithm is negotiated with the server before sending the first messages, and the compressed messages are marked with the `iggy-compression` header,
+/// so they are decompressed when polled by the `IggyClient`, regardless of the server-side compression.
+#[derive(Debug, Clone)]
+pub struct CompressionConfig {
+    /// The supported algorithms in the order of preference, the first one is used if the server doesn't prefer any of them.
+    pub algorithms: Vec<CompressionAlgorithm>,
+    /// The minimum size of the payload in bytes to be compressed, smaller payloads are sent as they are.
+    pub min_payload_size: u32,
 }
 
 /// The configuration for sending the messages in the background. It allows to configure the interval between sending the messages as batches in the background and the maximum number of messages in the batch.
@@ -155,6 +256,8 @@ pub struct SendMessagesConfig {
     pub interval: u64,
     /// The maximum number of messages in the batch.
     pub max_messages: u32,
+    /// The acknowledgement level of the sent messages, unless it's set for the specific command, the server default is used if not set.
+    pub acks: Option<Acks>,
 }
 
 /// The configuration for polling the messages in the background. It allows to configure the interval between polling the messages and the offset storing strategy.
@@ -177,6 +280,15 @@ pub enum StoreOffsetKind {
     WhenMessagesAreProcessed,
     /// The offset is stored on the server after processing each message.
     AfterProcessingEachMessage,
+    /// The offset of the processed messages is stored on the server every `interval` milliseconds or after processing `messages` messages, whichever comes first.
+    /// The interval is checked on each poll, the pending offset can be also stored immediately by calling `IggyClient::commit_sync()`.
+    /// As the offset is stored with a delay, the offset polling strategy should be used instead of the next one, which would poll the already processed messages again.
+    AutoCommit {
+        /// The interval in milliseconds between storing the offset.
+        interval: u64,
+        /// The number of processed messages after which the offset is stored.
+        messages: u32,
+    },
 }
 
 impl Default for SendMessagesConfig {
@@ -185,6 +297,25 @@ impl Default for SendMessagesConfig {
             enabled: false,
             interval: 100,
             max_messages: 1000,
+            acks: None,
+        }
+    }
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        CompressionConfig {
+            algorithms: vec![CompressionAlgorithm::Zstd],
+            min_payload_size: 1024,
+        }
+    }
+}
+
+impl Default for MetadataCacheConfig {
+    fn default() -> Self {
+        MetadataCacheConfig {
+            enabled: true,
+            ttl: 30_000,
         }
     }
 }
@@ -220,6 +351,10 @@ impl IggyClient {
             encryptor: None,
             message_handler: None,
             message_channel_sender: None,
+            pending_offsets: Arc::new(Mutex::new(Vec::new())),
+            compression_algorithm: Arc::new(Mutex::new(None)),
+            hello_info: Arc::new(Mutex::new(None)),
+            metadata: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -250,6 +385,7 @@ impl IggyClient {
                 config.send_messages.max_messages,
                 client.clone(),
                 send_messages_batch.clone(),
+                config.metrics.clone(),
             );
         }
 
@@ -259,11 +395,25 @@ impl IggyClient {
             send_messages_batch: Some(send_messages_batch),
             message_handler: message_handler.map(Arc::new),
             message_channel_sender: None,
+            pending_offsets: Arc::new(Mutex::new(Vec::new())),
+            compression_algorithm: Arc::new(Mutex::new(None)),
+            hello_info: Arc::new(Mutex::new(None)),
+            metadata: Arc::new(Mutex::new(None)),
             partitioner,
             encryptor,
         }
     }
 
+    /// Returns the fluent builder for polling the messages, e.g. `client.poll().stream("s").topic("t").last(100).await`.
+    pub fn poll(&self) -> PollMessagesBuilder<'_> {
+        PollMessagesBuilder::new(self)
+    }
+
+    /// Returns the fluent builder for sending the messages, e.g. `client.send().stream("s").topic("t").message(message).await`.
+    pub fn send(&self) -> SendMessagesBuilder<'_> {
+        SendMessagesBuilder::new(self)
+    }
+
     /// Returns the channel receiver for the messages which are polled in the background. This will only work if the `start_polling_messages` method is called.
     pub fn subscribe_to_polled_messages(&mut self) -> Receiver<Message> {
         let (sender, receiver) = flume::unbounded();
@@ -287,6 +437,14 @@ impl IggyClient {
         let message_channel_sender = self.message_channel_sender.clone();
         let mut store_offset_after_processing_each_message = false;
         let mut store_offset_when_messages_are_processed = false;
+        let mut auto_commit = None;
+        let pending_offsets = self.pending_offsets.clone();
+        let metrics = self.metrics().cloned();
+        let checksum_policy = self
+            .config
+            .as_ref()
+            .map(|config| config.checksum_policy)
+            .unwrap_or_default();
 
         let config = match config_override {
             Some(config) => Some(config),
@@ -311,14 +469,43 @@ impl IggyClient {
                     poll_messages.auto_commit = false;
                     store_offset_after_processing_each_message = true;
                 }
+                StoreOffsetKind::AutoCommit { interval, messages } => {
+                    poll_messages.auto_commit = false;
+                    auto_commit = Some((Duration::from_millis(interval), messages.max(1)));
+                }
             }
         }
 
+        // With the long polling the server waits for the new messages, so there's no need to sleep between the polls.
+        let long_polling = poll_messages
+            .max_wait_ms
+            .is_some_and(|max_wait_ms| max_wait_ms > 0);
         tokio::spawn(async move {
+            let mut last_commit = Instant::now();
+            let mut uncommitted_messages = 0;
+            let mut failed = false;
             loop {
-                sleep(interval).await;
+                if !long_polling || failed {
+                    sleep(interval).await;
+                }
                 let client = client.read().await;
+                if let Some((commit_interval, _)) = auto_commit {
+                    if last_commit.elapsed() >= commit_interval {
+                        Self::commit_pending_offsets(
+                            client.as_ref(),
+                            &pending_offsets,
+                            metrics.as_deref(),
+                        )
+                        .await;
+                        last_commit = Instant::now();
+                        uncommitted_messages = 0;
+                    }
+                }
+
+                let started_at = Instant::now();
                 let polled_messages = client.poll_messages(&poll_messages).await;
+                Self::record_poll(metrics.as_deref(), started_at, &polled_messages);
+                failed = polled_messages.is_err();
                 if let Err(error) = polled_messages {
                     error!("There was an error while polling messages: {:?}", error);
                     continue;
@@ -330,8 +517,21 @@ impl IggyClient {
                 }
 
                 let mut current_offset = 0;
-                for message in messages {
+                for mut message in messages {
                     current_offset = message.offset;
+                    if let Err(error) =
+                        checksum_policy.validate(&message.payload, message.checksum, message.offset)
+                    {
+                        error!("Skipping the message with invalid checksum: {:?}", error);
+                        continue;
+                    }
+                    if let Err(error) = Self::decompress_message(&mut message) {
+                        error!(
+                            "Skipping the message which cannot be decompressed: {:?}",
+                            error
+                        );
+                        continue;
+                    }
                     // Send a message to the subscribed channel (if created), otherwise to the provided closure or message handler.
                     if let Some(sender) = &message_channel_sender {
                         if sender.send_async(message).await.is_err() {
@@ -345,12 +545,38 @@ impl IggyClient {
                         warn!("Received a message with ID: {} at offset: {} which won't be processed. Consider providing the custom `MessageHandler` trait implementation or `on_message` closure.", message.id, message.offset);
                     }
                     if store_offset_after_processing_each_message {
-                        Self::store_offset(client.as_ref(), &poll_messages, current_offset).await;
+                        Self::store_offset(
+                            client.as_ref(),
+                            &poll_messages,
+                            current_offset,
+                            metrics.as_deref(),
+                        )
+                        .await;
+                    }
+                    if let Some((_, commit_messages)) = auto_commit {
+                        Self::track_offset(&pending_offsets, &poll_messages, current_offset).await;
+                        uncommitted_messages += 1;
+                        if uncommitted_messages >= commit_messages {
+                            Self::commit_pending_offsets(
+                                client.as_ref(),
+                                &pending_offsets,
+                                metrics.as_deref(),
+                            )
+                            .await;
+                            last_commit = Instant::now();
+                            uncommitted_messages = 0;
+                        }
                     }
                 }
 
                 if store_offset_when_messages_are_processed {
-                    Self::store_offset(client.as_ref(), &poll_messages, current_offset).await;
+                    Self::store_offset(
+                        client.as_ref(),
+                        &poll_messages,
+                        current_offset,
+                        metrics.as_deref(),
+                    )
+                    .await;
                 }
 
                 if poll_messages.strategy.kind == PollingKind::Offset {
@@ -360,6 +586,35 @@ impl IggyClient {
         })
     }
 
+    /// Stores immediately the offsets of the messages processed by the background polling with `StoreOffsetKind::AutoCommit`, which were not stored yet.
+    /// The offsets which could not be stored are kept pending and the first error is returned.
+    pub async fn commit_sync(&self) -> Result<(), IggyError> {
+        let client = self.client.read().await;
+        Self::store_pending_offsets(client.as_ref(), &self.pending_offsets).await
+    }
+
+    /// Returns the end offset (the offset of the next message to be appended) of the partition with the provided ID, which can be used to implement the catch-up logic.
+    pub async fn get_partition_end_offset(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<u64, IggyError> {
+        self.get_partition_end_offsets(&GetPartitionEndOffsets {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+        })
+        .await?
+        .into_iter()
+        .find(|end_offset| end_offset.partition_id == partition_id)
+        .map(|end_offset| end_offset.end_offset)
+        .ok_or_else(|| {
+            IggyError::ResourceNotFound(format!(
+                "streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}"
+            ))
+        })
+    }
+
     /// Sends the provided messages in the background using the custom partitioner implementation.
     pub async fn send_messages_using_partitioner(
         &self,
@@ -376,7 +631,238 @@ impl IggyClient {
         self.send_messages(command).await
     }
 
-    async fn store_offset(client: &dyn Client, poll_messages: &PollMessages, offset: u64) {
+    /// Returns the result of the handshake performed when connecting to the server,
+    /// which is not available if the client hasn't connected yet or the server doesn't support the handshake.
+    pub async fn hello_info(&self) -> Option<HelloInfo> {
+        self.hello_info.lock().await.clone()
+    }
+
+    /// Invalidates the cached metadata, so that the next call to `get_metadata()` fetches it from the server.
+    pub async fn invalidate_metadata(&self) {
+        self.metadata.lock().await.take();
+    }
+
+    fn metadata_cache_ttl(&self) -> Option<Duration> {
+        let config = self.config.as_ref()?;
+        match config.metadata_cache.enabled && config.metadata_cache.ttl > 0 {
+            true => Some(Duration::from_millis(config.metadata_cache.ttl)),
+            false => None,
+        }
+    }
+
+    async fn invalidate_metadata_on_error<T>(
+        &self,
+        result: Result<T, IggyError>,
+    ) -> Result<T, IggyError> {
+        if result.is_err() {
+            self.invalidate_metadata().await;
+        }
+        result
+    }
+
+    async fn invalidate_metadata_after<T>(
+        &self,
+        result: Result<T, IggyError>,
+    ) -> Result<T, IggyError> {
+        self.invalidate_metadata().await;
+        result
+    }
+
+    async fn negotiate_compression_algorithm(
+        &self,
+        config: &CompressionConfig,
+    ) -> CompressionAlgorithm {
+        let mut compression_algorithm = self.compression_algorithm.lock().await;
+        if let Some(algorithm) = compression_algorithm.as_ref() {
+            return algorithm.clone();
+        }
+
+        let command = NegotiateCompression {
+            algorithms: config.algorithms.clone(),
+        };
+        let algorithm = match self
+            .client
+            .read()
+            .await
+            .negotiate_compression(&command)
+            .await
+        {
+            Ok(algorithm) => algorithm,
+            Err(error) => {
+                let algorithm = config
+                    .algorithms
+                    .first()
+                    .cloned()
+                    .unwrap_or(CompressionAlgorithm::None);
+                warn!(
+                    "Failed to negotiate the compression algorithm: {error}, using: {algorithm}."
+                );
+                algorithm
+            }
+        };
+        info!("Client-side compression is enabled using algorithm: {algorithm}.");
+        *compression_algorithm = Some(algorithm.clone());
+        algorithm
+    }
+
+    async fn compress_messages(&self, command: &mut SendMessages) -> Result<(), IggyError> {
+        let Some(config) = self
+            .config
+            .as_ref()
+            .and_then(|config| config.compression.as_ref())
+        else {
+            return Ok(());
+        };
+
+        let algorithm = self.negotiate_compression_algorithm(config).await;
+        if algorithm == CompressionAlgorithm::None {
+            return Ok(());
+        }
+
+        for message in &mut command.messages {
+            if message.payload.len() < config.min_payload_size as usize {
+                continue;
+            }
+
+            message.payload = Bytes::from(algorithm.compress(&message.payload)?);
+            message.length = message.payload.len() as u32;
+            message.headers.get_or_insert_with(HashMap::new).insert(
+                HeaderKey::new(COMPRESSION_HEADER)?,
+                HeaderValue::from_str(algorithm.as_str())?,
+            );
+        }
+        Ok(())
+    }
+
+    /// Validates the checksums, decrypts and decompresses the polled messages, as configured.
+    fn process_polled_messages(
+        &self,
+        polled_messages: &mut PolledMessages,
+    ) -> Result<(), IggyError> {
+        if let Some(config) = &self.config {
+            if config.checksum_policy != ChecksumPolicy::Off {
+                for message in &polled_messages.messages {
+                    config.checksum_policy.validate(
+                        &message.payload,
+                        message.checksum,
+                        message.offset,
+                    )?;
+                }
+            }
+        }
+
+        if let Some(ref encryptor) = self.encryptor {
+            for message in &mut polled_messages.messages {
+                let payload = encryptor.decrypt(&message.payload)?;
+                message.payload = Bytes::from(payload);
+            }
+        }
+
+        for message in &mut polled_messages.messages {
+            Self::decompress_message(message)?;
+        }
+        Ok(())
+    }
+
+    fn decompress_message(message: &mut Message) -> Result<(), IggyError> {
+        let Some(headers) = message.headers.as_mut() else {
+            return Ok(());
+        };
+
+        let Some(algorithm) = headers.remove(&HeaderKey::new(COMPRESSION_HEADER)?) else {
+            return Ok(());
+        };
+
+        let algorithm = CompressionAlgorithm::from_str(algorithm.as_str()?)
+            .map_err(IggyError::CannotDecompressData)?;
+        message.payload = Bytes::from(algorithm.decompress(&message.payload)?);
+        message.length = message.payload.len() as u32;
+        if headers.is_empty() {
+            message.headers = None;
+        }
+        Ok(())
+    }
+
+    fn metrics(&self) -> Option<&Arc<dyn ClientMetrics>> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.metrics.as_ref())
+    }
+
+    fn record_poll(
+        metrics: Option<&dyn ClientMetrics>,
+        started_at: Instant,
+        result: &Result<PolledMessages, IggyError>,
+    ) {
+        if let Some(metrics) = metrics {
+            match result {
+                Ok(polled_messages) => {
+                    metrics.record_poll(polled_messages.messages.len() as u32, started_at.elapsed())
+                }
+                Err(error) => metrics.record_error(ClientOperation::PollMessages, error),
+            }
+        }
+    }
+
+    fn record_send<T>(
+        metrics: Option<&dyn ClientMetrics>,
+        started_at: Instant,
+        messages_count: u32,
+        result: &Result<T, IggyError>,
+    ) {
+        if let Some(metrics) = metrics {
+            match result {
+                Ok(_) => metrics.record_send(messages_count, started_at.elapsed()),
+                Err(error) => metrics.record_error(ClientOperation::SendMessages, error),
+            }
+        }
+    }
+
+    /// Applies the default acks, the partitioner, the compression and the encryption to the messages before they're sent.
+    async fn prepare_messages_to_send(&self, command: &mut SendMessages) -> Result<(), IggyError> {
+        if command.acks.is_none() {
+            command.acks = self
+                .config
+                .as_ref()
+                .and_then(|config| config.send_messages.acks);
+        }
+
+        if let Some(partitioner) = &self.partitioner {
+            let partition_id = partitioner.calculate_partition_id(
+                &command.stream_id,
+                &command.topic_id,
+                &command.partitioning,
+                &command.messages,
+            )?;
+            command.partitioning = Partitioning::partition_id(partition_id);
+        }
+
+        self.compress_messages(command).await?;
+        if let Some(encryptor) = &self.encryptor {
+            for message in &mut command.messages {
+                message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
+                message.length = message.payload.len() as u32;
+            }
+        }
+        Ok(())
+    }
+
+    fn record_error(
+        metrics: Option<&dyn ClientMetrics>,
+        operation: ClientOperation,
+        error: &IggyError,
+    ) {
+        if let Some(metrics) = metrics {
+            metrics.record_error(operation, error);
+        }
+    }
+
+    async fn store_offset(
+        client: &dyn Client,
+        poll_messages: &PollMessages,
+        offset: u64,
+        metrics: Option<&dyn ClientMetrics>,
+    ) {
         let result = client
             .store_consumer_offset(&StoreConsumerOffset {
                 consumer: Consumer::from_consumer(&poll_messages.consumer),
@@ -388,6 +874,61 @@ impl IggyClient {
             .await;
         if let Err(error) = result {
             error!("There was an error while storing offset: {:?}", error);
+            Self::record_error(metrics, ClientOperation::StoreConsumerOffset, &error);
+        }
+    }
+
+    async fn track_offset(
+        pending_offsets: &Mutex<Vec<StoreConsumerOffset>>,
+        poll_messages: &PollMessages,
+        offset: u64,
+    ) {
+        let mut pending_offsets = pending_offsets.lock().await;
+        let pending_offset = pending_offsets.iter_mut().find(|pending_offset| {
+            pending_offset.consumer == poll_messages.consumer
+                && pending_offset.stream_id == poll_messages.stream_id
+                && pending_offset.topic_id == poll_messages.topic_id
+                && pending_offset.partition_id == poll_messages.partition_id
+        });
+        match pending_offset {
+            Some(pending_offset) => pending_offset.offset = offset,
+            None => pending_offsets.push(StoreConsumerOffset {
+                consumer: Consumer::from_consumer(&poll_messages.consumer),
+                stream_id: Identifier::from_identifier(&poll_messages.stream_id),
+                topic_id: Identifier::from_identifier(&poll_messages.topic_id),
+                partition_id: poll_messages.partition_id,
+                offset,
+            }),
+        }
+    }
+
+    async fn store_pending_offsets(
+        client: &dyn Client,
+        pending_offsets: &Mutex<Vec<StoreConsumerOffset>>,
+    ) -> Result<(), IggyError> {
+        let mut pending_offsets = pending_offsets.lock().await;
+        let mut result = Ok(());
+        let mut failed_offsets = Vec::new();
+        for pending_offset in pending_offsets.drain(..) {
+            if let Err(error) = client.store_consumer_offset(&pending_offset).await {
+                if result.is_ok() {
+                    result = Err(error);
+                }
+                failed_offsets.push(pending_offset);
+            }
+        }
+        *pending_offsets = failed_offsets;
+        result
+    }
+
+    async fn commit_pending_offsets(
+        client: &dyn Client,
+        pending_offsets: &Mutex<Vec<StoreConsumerOffset>>,
+        metrics: Option<&dyn ClientMetrics>,
+    ) {
+        if let Err(error) = Self::store_pending_offsets(client, pending_offsets).await {
+            error!("There was an error while committing offsets: {:?}", error);
+            Self::record_error(metrics, ClientOperation::StoreConsumerOffset, &error);
         }
     }
 
@@ -396,6 +937,7 @@ impl IggyClient {
         max_messages: u32,
         client: Arc<RwLock<Box<dyn Client>>>,
         send_messages_batch: Arc<Mutex<SendMessagesBatch>>,
+        metrics: Option<Arc<dyn ClientMetrics>>,
     ) {
         tokio::spawn(async move {
             let max_messages = max_messages as usize;
@@ -411,6 +953,7 @@ impl IggyClient {
                 let mut stream_id = Identifier::numeric(1).unwrap();
                 let mut topic_id = Identifier::numeric(1).unwrap();
                 let mut key = Partitioning::partition_id(1);
+                let mut acks = None;
                 let mut batch_messages = true;
 
                 for send_messages in &send_messages_batch.commands {
@@ -423,14 +966,16 @@ impl IggyClient {
                         stream_id = Identifier::from_identifier(&send_messages.stream_id);
                         topic_id = Identifier::from_identifier(&send_messages.topic_id);
                         key.value = send_messages.partitioning.value.clone();
+                        acks = send_messages.acks;
                         initialized = true;
                     }
 
-                    // Batching the messages is only possible for the same stream, topic and partition.
+                    // Batching the messages is only possible for the same stream, topic, partition and acks.
                     if send_messages.stream_id != stream_id
                         || send_messages.topic_id != topic_id
                         || send_messages.partitioning.kind != PartitioningKind::PartitionId
                         || send_messages.partitioning.value != key.value
+                        || send_messages.acks != acks
                     {
                         batch_messages = false;
                         break;
@@ -439,7 +984,11 @@ impl IggyClient {
 
                 if !batch_messages {
                     for send_messages in &mut send_messages_batch.commands {
-                        if let Err(error) = client.read().await.send_messages(send_messages).await {
+                        let started_at = Instant::now();
+                        let messages_count = send_messages.messages.len() as u32;
+                        let result = client.read().await.send_messages(send_messages).await;
+                        Self::record_send(metrics.as_deref(), started_at, messages_count, &result);
+                        if let Err(error) = result {
                             error!("There was an error when sending the messages: {:?}", error);
                         }
                     }
@@ -471,16 +1020,23 @@ impl IggyClient {
                             value: key.value.clone(),
                         },
                         messages,
+                        acks,
                     };
 
-                    if let Err(error) = client.read().await.send_messages(&mut send_messages).await
-                    {
+                    let started_at = Instant::now();
+                    let messages_count = send_messages.messages.len() as u32;
+                    let result = client.read().await.send_messages(&mut send_messages).await;
+                    Self::record_send(metrics.as_deref(), started_at, messages_count, &result);
+                    if let Err(error) = result {
                         error!(
                             "There was an error when sending the messages batch: {:?}",
                             error
                         );
 
                         if !send_messages.messages.is_empty() {
+                            if let Some(metrics) = &metrics {
+                                metrics.record_retry(ClientOperation::SendMessages);
+                            }
                             batches.push_back(send_messages.messages);
                         }
                     }
@@ -518,6 +1074,10 @@ impl UserClient for IggyClient {
         self.client.read().await.update_permissions(command).await
     }
 
+    async fn update_user_expiry(&self, command: &UpdateUserExpiry) -> Result<(), IggyError> {
+        self.client.read().await.update_user_expiry(command).await
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), IggyError> {
         self.client.read().await.change_password(command).await
     }
@@ -529,6 +1089,13 @@ impl UserClient for IggyClient {
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), IggyError> {
         self.client.read().await.logout_user(command).await
     }
+
+    async fn check_permission(
+        &self,
+        command: &CheckPermission,
+    ) -> Result<PermissionCheck, IggyError> {
+        self.client.read().await.check_permission(command).await
+    }
 }
 
 #[async_trait]
@@ -581,7 +1148,34 @@ impl PersonalAccessTokenClient for IggyClient {
 #[async_trait]
 impl Client for IggyClient {
     async fn connect(&self) -> Result<(), IggyError> {
-        self.client.read().await.connect().await
+        let client = self.client.read().await;
+        client.connect().await?;
+        match client.hello(&Hello::default()).await {
+            Ok(hello_info) => {
+                info!(
+                    "Connected to Iggy server: {} using protocol version: {}.",
+                    hello_info.server_version, hello_info.protocol_version
+                );
+                self.hello_info.lock().await.replace(hello_info);
+            }
+            Err(IggyError::InvalidResponse(status))
+                if status == IggyErrorDiscriminants::UnsupportedProtocolVersion as u32 =>
+            {
+                error!(
+                    "Protocol version: {} is not supported by the server.",
+                    Hello::default().protocol_version
+                );
+                client.disconnect().await?;
+                return Err(IggyError::UnsupportedProtocolVersion(
+                    Hello::default().protocol_version,
+                ));
+            }
+            Err(error) => {
+                warn!("Failed to perform the handshake, the server might not support it: {error}");
+            }
+        }
+
+        Ok(())
     }
 
     async fn disconnect(&self) -> Result<(), IggyError> {
@@ -595,6 +1189,26 @@ impl SystemClient for IggyClient {
         self.client.read().await.get_stats(command).await
     }
 
+    async fn get_metadata(&self, command: &GetMetadata) -> Result<Metadata, IggyError> {
+        let Some(ttl) = self.metadata_cache_ttl() else {
+            return self.client.read().await.get_metadata(command).await;
+        };
+
+        let mut cached_metadata = self.metadata.lock().await;
+        if let Some(cached_metadata) = cached_metadata.as_ref() {
+            if cached_metadata.fetched_at.elapsed() < ttl {
+                return Ok(cached_metadata.metadata.clone());
+            }
+        }
+
+        let metadata = self.client.read().await.get_metadata(command).await?;
+        cached_metadata.replace(CachedMetadata {
+            metadata: metadata.clone(),
+            fetched_at: Instant::now(),
+        });
+        Ok(metadata)
+    }
+
     async fn get_me(&self, command: &GetMe) -> Result<ClientInfoDetails, IggyError> {
         self.client.read().await.get_me(command).await
     }
@@ -610,83 +1224,169 @@ impl SystemClient for IggyClient {
     async fn ping(&self, command: &Ping) -> Result<(), IggyError> {
         self.client.read().await.ping(command).await
     }
+
+    async fn heartbeat(&self, command: &Heartbeat) -> Result<(), IggyError> {
+        self.client.read().await.heartbeat(command).await
+    }
+
+    async fn hello(&self, command: &Hello) -> Result<HelloInfo, IggyError> {
+        self.client.read().await.hello(command).await
+    }
+
+    async fn batch(&self, command: &Batch) -> Result<Vec<BatchCommandResult>, IggyError> {
+        self.client.read().await.batch(command).await
+    }
+
+    async fn negotiate_compression(
+        &self,
+        command: &NegotiateCompression,
+    ) -> Result<CompressionAlgorithm, IggyError> {
+        self.client
+            .read()
+            .await
+            .negotiate_compression(command)
+            .await
+    }
 }
 
 #[async_trait]
 impl StreamClient for IggyClient {
     async fn get_stream(&self, command: &GetStream) -> Result<StreamDetails, IggyError> {
-        self.client.read().await.get_stream(command).await
+        let result = self.client.read().await.get_stream(command).await;
+        self.invalidate_metadata_on_error(result).await
     }
 
     async fn get_streams(&self, command: &GetStreams) -> Result<Vec<Stream>, IggyError> {
-        self.client.read().await.get_streams(command).await
+        let result = self.client.read().await.get_streams(command).await;
+        self.invalidate_metadata_on_error(result).await
     }
 
     async fn create_stream(&self, command: &CreateStream) -> Result<(), IggyError> {
-        self.client.read().await.create_stream(command).await
+        let result = self.client.read().await.create_stream(command).await;
+        self.invalidate_metadata_after(result).await
     }
 
     async fn update_stream(&self, command: &UpdateStream) -> Result<(), IggyError> {
-        self.client.read().await.update_stream(command).await
+        let result = self.client.read().await.update_stream(command).await;
+        self.invalidate_metadata_after(result).await
     }
 
     async fn delete_stream(&self, command: &DeleteStream) -> Result<(), IggyError> {
-        self.client.read().await.delete_stream(command).await
+        let result = self.client.read().await.delete_stream(command).await;
+        self.invalidate_metadata_after(result).await
+    }
+
+    async fn undelete_stream(&self, command: &UndeleteStream) -> Result<(), IggyError> {
+        let result = self.client.read().await.undelete_stream(command).await;
+        self.invalidate_metadata_after(result).await
     }
 
     async fn purge_stream(&self, command: &PurgeStream) -> Result<(), IggyError> {
-        self.client.read().await.purge_stream(command).await
+        let result = self.client.read().await.purge_stream(command).await;
+        self.invalidate_metadata_after(result).await
     }
 }
 
 #[async_trait]
 impl TopicClient for IggyClient {
     async fn get_topic(&self, command: &GetTopic) -> Result<TopicDetails, IggyError> {
-        self.client.read().await.get_topic(command).await
+        let result = self.client.read().await.get_topic(command).await;
+        self.invalidate_metadata_on_error(result).await
     }
 
     async fn get_topics(&self, command: &GetTopics) -> Result<Vec<Topic>, IggyError> {
-        self.client.read().await.get_topics(command).await
+        let result = self.client.read().await.get_topics(command).await;
+        self.invalidate_metadata_on_error(result).await
     }
 
     async fn create_topic(&self, command: &CreateTopic) -> Result<(), IggyError> {
-        self.client.read().await.create_topic(command).await
+        let result = self.client.read().await.create_topic(command).await;
+        self.invalidate_metadata_after(result).await
     }
 
     async fn update_topic(&self, command: &UpdateTopic) -> Result<(), IggyError> {
-        self.client.read().await.update_topic(command).await
+        let result = self.client.read().await.update_topic(command).await;
+        self.invalidate_metadata_after(result).await
     }
 
     async fn delete_topic(&self, command: &DeleteTopic) -> Result<(), IggyError> {
-        self.client.read().await.delete_topic(command).await
+        let result = self.client.read().await.delete_topic(command).await;
+        self.invalidate_metadata_after(result).await
+    }
+
+    async fn undelete_topic(&self, command: &UndeleteTopic) -> Result<(), IggyError> {
+        let result = self.client.read().await.undelete_topic(command).await;
+        self.invalidate_metadata_after(result).await
     }
 
     async fn purge_topic(&self, command: &PurgeTopic) -> Result<(), IggyError> {
-        self.client.read().await.purge_topic(command).await
+        let result = self.client.read().await.purge_topic(command).await;
+        self.invalidate_metadata_after(result).await
+    }
+
+    async fn freeze_topic(&self, command: &FreezeTopic) -> Result<(), IggyError> {
+        let result = self.client.read().await.freeze_topic(command).await;
+        self.invalidate_metadata_after(result).await
+    }
+
+    async fn unfreeze_topic(&self, command: &UnfreezeTopic) -> Result<(), IggyError> {
+        let result = self.client.read().await.unfreeze_topic(command).await;
+        self.invalidate_metadata_after(result).await
     }
 }
 
 #[async_trait]
 impl PartitionClient for IggyClient {
+    async fn get_partition_details(
+        &self,
+        command: &GetPartitionDetails,
+    ) -> Result<PartitionDetails, IggyError> {
+        let result = self
+            .client
+            .read()
+            .await
+            .get_partition_details(command)
+            .await;
+        self.invalidate_metadata_on_error(result).await
+    }
+
+    async fn get_partition_end_offsets(
+        &self,
+        command: &GetPartitionEndOffsets,
+    ) -> Result<Vec<PartitionEndOffset>, IggyError> {
+        let result = self
+            .client
+            .read()
+            .await
+            .get_partition_end_offsets(command)
+            .await;
+        self.invalidate_metadata_on_error(result).await
+    }
+
     async fn create_partitions(&self, command: &CreatePartitions) -> Result<(), IggyError> {
-        self.client.read().await.create_partitions(command).await
+        let result = self.client.read().await.create_partitions(command).await;
+        self.invalidate_metadata_after(result).await
     }
 
     async fn delete_partitions(&self, command: &DeletePartitions) -> Result<(), IggyError> {
-        self.client.read().await.delete_partitions(command).await
+        let result = self.client.read().await.delete_partitions(command).await;
+        self.invalidate_metadata_after(result).await
     }
 }
 
 #[async_trait]
 impl MessageClient for IggyClient {
     async fn poll_messages(&self, command: &PollMessages) -> Result<PolledMessages, IggyError> {
-        let mut polled_messages = self.client.read().await.poll_messages(command).await?;
-        if let Some(ref encryptor) = self.encryptor {
-            for message in &mut polled_messages.messages {
-                let payload = encryptor.decrypt(&message.payload)?;
-                message.payload = Bytes::from(payload);
-            }
-        }
+        let started_at = Instant::now();
+        let polled_messages = self.client.read().await.poll_messages(command).await;
+        let polled_messages = self.invalidate_metadata_on_error(polled_messages).await;
+        Self::record_poll(
+            self.metrics().map(Arc::as_ref),
+            started_at,
+            &polled_messages,
+        );
+        let mut polled_messages = polled_messages?;
+        self.process_polled_messages(&mut polled_messages)?;
         Ok(polled_messages)
     }
 
@@ -695,23 +1395,7 @@ impl MessageClient for IggyClient {
             return Ok(());
         }
 
-        if let Some(partitioner) = &self.partitioner {
-            let partition_id = partitioner.calculate_partition_id(
-                &command.stream_id,
-                &command.topic_id,
-                &command.partitioning,
-                &command.messages,
-            )?;
-            command.partitioning = Partitioning::partition_id(partition_id);
-        }
-
-        if let Some(encryptor) = &self.encryptor {
-            for message in &mut command.messages {
-                message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
-                message.length = message.payload.len() as u32;
-            }
-        }
-
+        self.prepare_messages_to_send(command).await?;
         let send_messages_now = self.send_messages_batch.is_none()
             || match &self.config {
                 Some(config) => !config.send_messages.enabled || config.send_messages.interval == 0,
@@ -719,7 +1403,17 @@ impl MessageClient for IggyClient {
             };
 
         if send_messages_now {
-            return self.client.read().await.send_messages(command).await;
+            let started_at = Instant::now();
+            let messages_count = command.messages.len() as u32;
+            let result = self.client.read().await.send_messages(command).await;
+            let result = self.invalidate_metadata_on_error(result).await;
+            Self::record_send(
+                self.metrics().map(Arc::as_ref),
+                started_at,
+                messages_count,
+                &result,
+            );
+            return result;
         }
 
         let mut messages = Vec::with_capacity(command.messages.len());
@@ -737,12 +1431,59 @@ impl MessageClient for IggyClient {
             topic_id: Identifier::from_identifier(&command.topic_id),
             partitioning: Partitioning::from_partitioning(&command.partitioning),
             messages,
+            acks: command.acks,
         };
 
         let mut batch = self.send_messages_batch.as_ref().unwrap().lock().await;
         batch.commands.push_back(send_messages);
         Ok(())
     }
+
+    async fn send_messages_with_receipt(
+        &self,
+        command: &mut SendMessages,
+    ) -> Result<SendMessagesReceipt, IggyError> {
+        self.prepare_messages_to_send(command).await?;
+        let started_at = Instant::now();
+        let messages_count = command.messages.len() as u32;
+        let result = self
+            .client
+            .read()
+            .await
+            .send_messages_with_receipt(command)
+            .await;
+        let result = self.invalidate_metadata_on_error(result).await;
+        Self::record_send(
+            self.metrics().map(Arc::as_ref),
+            started_at,
+            messages_count,
+            &result,
+        );
+        result
+    }
+
+    async fn query_messages(&self, command: &QueryMessages) -> Result<QueryResult, IggyError> {
+        self.client.read().await.query_messages(command).await
+    }
+
+    async fn create_fetch_session(
+        &self,
+        command: &CreateFetchSession,
+    ) -> Result<FetchSession, IggyError> {
+        self.client.read().await.create_fetch_session(command).await
+    }
+
+    async fn fetch_messages(&self, command: &FetchMessages) -> Result<FetchedMessages, IggyError> {
+        let mut fetched_messages = self.client.read().await.fetch_messages(command).await?;
+        for partition in &mut fetched_messages.partitions {
+            self.process_polled_messages(&mut partition.messages)?;
+        }
+        Ok(fetched_messages)
+    }
+
+    async fn delete_fetch_session(&self, command: &DeleteFetchSession) -> Result<(), IggyError> {
+        self.client.read().await.delete_fetch_session(command).await
+    }
 }
 
 #[async_trait]
@@ -804,6 +1545,141 @@ impl ConsumerGroupClient for IggyClient {
     }
 }
 
+#[async_trait]
+impl SchemaClient for IggyClient {
+    async fn get_schema(&self, command: &GetSchema) -> Result<SchemaInfo, IggyError> {
+        self.client.read().await.get_schema(command).await
+    }
+
+    async fn get_schema_versions(
+        &self,
+        command: &GetSchemaVersions,
+    ) -> Result<Vec<SchemaInfo>, IggyError> {
+        self.client.read().await.get_schema_versions(command).await
+    }
+
+    async fn register_schema(&self, command: &RegisterSchema) -> Result<SchemaInfo, IggyError> {
+        self.client.read().await.register_schema(command).await
+    }
+
+    async fn update_schema_compatibility(
+        &self,
+        command: &UpdateSchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_schema_compatibility(command)
+            .await
+    }
+}
+
+#[async_trait]
+impl TenantClient for IggyClient {
+    async fn get_tenant(&self, command: &GetTenant) -> Result<Tenant, IggyError> {
+        self.client.read().await.get_tenant(command).await
+    }
+
+    async fn get_tenants(&self, command: &GetTenants) -> Result<Vec<Tenant>, IggyError> {
+        self.client.read().await.get_tenants(command).await
+    }
+
+    async fn create_tenant(&self, command: &CreateTenant) -> Result<(), IggyError> {
+        self.client.read().await.create_tenant(command).await
+    }
+
+    async fn update_tenant(&self, command: &UpdateTenant) -> Result<(), IggyError> {
+        self.client.read().await.update_tenant(command).await
+    }
+
+    async fn delete_tenant(&self, command: &DeleteTenant) -> Result<(), IggyError> {
+        self.client.read().await.delete_tenant(command).await
+    }
+
+    async fn add_tenant_user(&self, command: &AddTenantUser) -> Result<(), IggyError> {
+        self.client.read().await.add_tenant_user(command).await
+    }
+
+    async fn remove_tenant_user(&self, command: &RemoveTenantUser) -> Result<(), IggyError> {
+        self.client.read().await.remove_tenant_user(command).await
+    }
+}
+
+#[async_trait]
+impl UserGroupClient for IggyClient {
+    async fn get_user_group(&self, command: &GetUserGroup) -> Result<UserGroupDetails, IggyError> {
+        self.client.read().await.get_user_group(command).await
+    }
+
+    async fn get_user_groups(&self, command: &GetUserGroups) -> Result<Vec<UserGroup>, IggyError> {
+        self.client.read().await.get_user_groups(command).await
+    }
+
+    async fn create_user_group(&self, command: &CreateUserGroup) -> Result<(), IggyError> {
+        self.client.read().await.create_user_group(command).await
+    }
+
+    async fn update_user_group_permissions(
+        &self,
+        command: &UpdateUserGroupPermissions,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_user_group_permissions(command)
+            .await
+    }
+
+    async fn delete_user_group(&self, command: &DeleteUserGroup) -> Result<(), IggyError> {
+        self.client.read().await.delete_user_group(command).await
+    }
+
+    async fn add_user_group_member(&self, command: &AddUserGroupMember) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .add_user_group_member(command)
+            .await
+    }
+
+    async fn remove_user_group_member(
+        &self,
+        command: &RemoveUserGroupMember,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .remove_user_group_member(command)
+            .await
+    }
+}
+
+#[async_trait]
+impl ConnectorClient for IggyClient {
+    async fn get_connector(&self, command: &GetConnector) -> Result<ConnectorInfo, IggyError> {
+        self.client.read().await.get_connector(command).await
+    }
+
+    async fn get_connectors(
+        &self,
+        command: &GetConnectors,
+    ) -> Result<Vec<ConnectorInfo>, IggyError> {
+        self.client.read().await.get_connectors(command).await
+    }
+
+    async fn create_connector(&self, command: &CreateConnector) -> Result<(), IggyError> {
+        self.client.read().await.create_connector(command).await
+    }
+
+    async fn restart_connector(&self, command: &RestartConnector) -> Result<(), IggyError> {
+        self.client.read().await.restart_connector(command).await
+    }
+
+    async fn delete_connector(&self, command: &DeleteConnector) -> Result<(), IggyError> {
+        self.client.read().await.delete_connector(command).await
+    }
+}
+
 #[async_trait]
 impl AsyncDrop for IggyClient {
     async fn async_drop(&mut self) {
@@ -1,4 +1,9 @@
 use crate::bytes_serializable::BytesSerializable;
+use crate::connectors::create_connector::CreateConnector;
+use crate::connectors::delete_connector::DeleteConnector;
+use crate::connectors::get_connector::GetConnector;
+use crate::connectors::get_connectors::GetConnectors;
+use crate::connectors::restart_connector::RestartConnector;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
@@ -8,32 +13,66 @@ use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::IggyError;
+use crate::messages::create_fetch_session::CreateFetchSession;
+use crate::messages::delete_fetch_session::DeleteFetchSession;
+use crate::messages::fetch_messages::FetchMessages;
 use crate::messages::poll_messages::PollMessages;
+use crate::messages::query_messages::QueryMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_partition_details::GetPartitionDetails;
+use crate::partitions::get_partition_end_offsets::GetPartitionEndOffsets;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schema_versions::GetSchemaVersions;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::undelete_stream::UndeleteStream;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::batch::Batch;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_metadata::GetMetadata;
 use crate::system::get_stats::GetStats;
+use crate::system::heartbeat::Heartbeat;
+use crate::system::hello::Hello;
+use crate::system::negotiate_compression::NegotiateCompression;
 use crate::system::ping::Ping;
+use crate::tenants::add_tenant_user::AddTenantUser;
+use crate::tenants::create_tenant::CreateTenant;
+use crate::tenants::delete_tenant::DeleteTenant;
+use crate::tenants::get_tenant::GetTenant;
+use crate::tenants::get_tenants::GetTenants;
+use crate::tenants::remove_tenant_user::RemoveTenantUser;
+use crate::tenants::update_tenant::UpdateTenant;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
+use crate::topics::freeze_topic::FreezeTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::undelete_topic::UndeleteTopic;
+use crate::topics::unfreeze_topic::UnfreezeTopic;
 use crate::topics::update_topic::UpdateTopic;
+use crate::user_groups::add_user_group_member::AddUserGroupMember;
+use crate::user_groups::create_user_group::CreateUserGroup;
+use crate::user_groups::delete_user_group::DeleteUserGroup;
+use crate::user_groups::get_user_group::GetUserGroup;
+use crate::user_groups::get_user_groups::GetUserGroups;
+use crate::user_groups::remove_user_group_member::RemoveUserGroupMember;
+use crate::user_groups::update_user_group_permissions::UpdateUserGroupPermissions;
 use crate::users::change_password::ChangePassword;
+use crate::users::check_permission::CheckPermission;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
@@ -42,20 +81,33 @@ use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_expiry::UpdateUserExpiry;
 use bytes::{BufMut, Bytes, BytesMut};
 use std::fmt::{Display, Formatter};
 use strum::EnumString;
 
 pub const PING: &str = "ping";
 pub const PING_CODE: u32 = 1;
+pub const HEARTBEAT: &str = "heartbeat";
+pub const HEARTBEAT_CODE: u32 = 2;
+pub const HELLO: &str = "hello";
+pub const HELLO_CODE: u32 = 3;
+pub const BATCH: &str = "batch";
+pub const BATCH_CODE: u32 = 4;
 pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
+pub const GET_METADATA: &str = "metadata";
+pub const GET_METADATA_CODE: u32 = 11;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
 pub const GET_CLIENT_CODE: u32 = 21;
 pub const GET_CLIENTS: &str = "client.list";
 pub const GET_CLIENTS_CODE: u32 = 22;
+pub const NEGOTIATE_COMPRESSION: &str = "compression.negotiate";
+pub const NEGOTIATE_COMPRESSION_CODE: u32 = 23;
+pub const UPDATE_USER_EXPIRY: &str = "user.expiry";
+pub const UPDATE_USER_EXPIRY_CODE: u32 = 30;
 pub const GET_USER: &str = "user.get";
 pub const GET_USER_CODE: u32 = 31;
 pub const GET_USERS: &str = "user.list";
@@ -74,6 +126,8 @@ pub const LOGIN_USER: &str = "user.login";
 pub const LOGIN_USER_CODE: u32 = 38;
 pub const LOGOUT_USER: &str = "user.logout";
 pub const LOGOUT_USER_CODE: u32 = 39;
+pub const CHECK_PERMISSION: &str = "user.permission.check";
+pub const CHECK_PERMISSION_CODE: u32 = 40;
 pub const GET_PERSONAL_ACCESS_TOKENS: &str = "personal_access_token.list";
 pub const GET_PERSONAL_ACCESS_TOKENS_CODE: u32 = 41;
 pub const CREATE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.create";
@@ -86,6 +140,14 @@ pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES: &str = "message.send";
 pub const SEND_MESSAGES_CODE: u32 = 101;
+pub const QUERY_MESSAGES: &str = "message.query";
+pub const QUERY_MESSAGES_CODE: u32 = 102;
+pub const CREATE_FETCH_SESSION: &str = "message.fetch_session.create";
+pub const CREATE_FETCH_SESSION_CODE: u32 = 103;
+pub const FETCH_MESSAGES: &str = "message.fetch";
+pub const FETCH_MESSAGES_CODE: u32 = 104;
+pub const DELETE_FETCH_SESSION: &str = "message.fetch_session.delete";
+pub const DELETE_FETCH_SESSION_CODE: u32 = 105;
 pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
 pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
@@ -102,6 +164,8 @@ pub const UPDATE_STREAM: &str = "stream.update";
 pub const UPDATE_STREAM_CODE: u32 = 204;
 pub const PURGE_STREAM: &str = "stream.purge";
 pub const PURGE_STREAM_CODE: u32 = 205;
+pub const UNDELETE_STREAM: &str = "stream.undelete";
+pub const UNDELETE_STREAM_CODE: u32 = 206;
 pub const GET_TOPIC: &str = "topic.get";
 pub const GET_TOPIC_CODE: u32 = 300;
 pub const GET_TOPICS: &str = "topic.list";
@@ -114,6 +178,16 @@ pub const UPDATE_TOPIC: &str = "topic.update";
 pub const UPDATE_TOPIC_CODE: u32 = 304;
 pub const PURGE_TOPIC: &str = "topic.purge";
 pub const PURGE_TOPIC_CODE: u32 = 305;
+pub const UNDELETE_TOPIC: &str = "topic.undelete";
+pub const UNDELETE_TOPIC_CODE: u32 = 306;
+pub const FREEZE_TOPIC: &str = "topic.freeze";
+pub const FREEZE_TOPIC_CODE: u32 = 307;
+pub const UNFREEZE_TOPIC: &str = "topic.unfreeze";
+pub const UNFREEZE_TOPIC_CODE: u32 = 308;
+pub const GET_PARTITION_DETAILS: &str = "partition.get";
+pub const GET_PARTITION_DETAILS_CODE: u32 = 400;
+pub const GET_PARTITION_END_OFFSETS: &str = "partition.end_offsets";
+pub const GET_PARTITION_END_OFFSETS_CODE: u32 = 404;
 pub const CREATE_PARTITIONS: &str = "partition.create";
 pub const CREATE_PARTITIONS_CODE: u32 = 402;
 pub const DELETE_PARTITIONS: &str = "partition.delete";
@@ -130,14 +204,65 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const GET_SCHEMA: &str = "schema.get";
+pub const GET_SCHEMA_CODE: u32 = 700;
+pub const GET_SCHEMA_VERSIONS: &str = "schema.versions";
+pub const GET_SCHEMA_VERSIONS_CODE: u32 = 701;
+pub const REGISTER_SCHEMA: &str = "schema.register";
+pub const REGISTER_SCHEMA_CODE: u32 = 702;
+pub const UPDATE_SCHEMA_COMPATIBILITY: &str = "schema.compatibility";
+pub const UPDATE_SCHEMA_COMPATIBILITY_CODE: u32 = 703;
+pub const GET_TENANT: &str = "tenant.get";
+pub const GET_TENANT_CODE: u32 = 800;
+pub const GET_TENANTS: &str = "tenant.list";
+pub const GET_TENANTS_CODE: u32 = 801;
+pub const CREATE_TENANT: &str = "tenant.create";
+pub const CREATE_TENANT_CODE: u32 = 802;
+pub const UPDATE_TENANT: &str = "tenant.update";
+pub const UPDATE_TENANT_CODE: u32 = 803;
+pub const DELETE_TENANT: &str = "tenant.delete";
+pub const DELETE_TENANT_CODE: u32 = 804;
+pub const ADD_TENANT_USER: &str = "tenant.user.add";
+pub const ADD_TENANT_USER_CODE: u32 = 805;
+pub const REMOVE_TENANT_USER: &str = "tenant.user.remove";
+pub const REMOVE_TENANT_USER_CODE: u32 = 806;
+pub const GET_USER_GROUP: &str = "user_group.get";
+pub const GET_USER_GROUP_CODE: u32 = 900;
+pub const GET_USER_GROUPS: &str = "user_group.list";
+pub const GET_USER_GROUPS_CODE: u32 = 901;
+pub const CREATE_USER_GROUP: &str = "user_group.create";
+pub const CREATE_USER_GROUP_CODE: u32 = 902;
+pub const UPDATE_USER_GROUP_PERMISSIONS: &str = "user_group.permissions";
+pub const UPDATE_USER_GROUP_PERMISSIONS_CODE: u32 = 903;
+pub const DELETE_USER_GROUP: &str = "user_group.delete";
+pub const DELETE_USER_GROUP_CODE: u32 = 904;
+pub const ADD_USER_GROUP_MEMBER: &str = "user_group.member.add";
+pub const ADD_USER_GROUP_MEMBER_CODE: u32 = 905;
+pub const REMOVE_USER_GROUP_MEMBER: &str = "user_group.member.remove";
+pub const REMOVE_USER_GROUP_MEMBER_CODE: u32 = 906;
+pub const GET_CONNECTOR: &str = "connector.get";
+pub const GET_CONNECTOR_CODE: u32 = 1000;
+pub const GET_CONNECTORS: &str = "connector.list";
+pub const GET_CONNECTORS_CODE: u32 = 1001;
+pub const CREATE_CONNECTOR: &str = "connector.create";
+pub const CREATE_CONNECTOR_CODE: u32 = 1002;
+pub const RESTART_CONNECTOR: &str = "connector.restart";
+pub const RESTART_CONNECTOR_CODE: u32 = 1003;
+pub const DELETE_CONNECTOR: &str = "connector.delete";
+pub const DELETE_CONNECTOR_CODE: u32 = 1004;
 
 #[derive(Debug, PartialEq, EnumString)]
 pub enum Command {
     Ping(Ping),
+    Heartbeat(Heartbeat),
+    Hello(Hello),
+    Batch(Batch),
     GetStats(GetStats),
+    GetMetadata(GetMetadata),
     GetMe(GetMe),
     GetClient(GetClient),
     GetClients(GetClients),
+    NegotiateCompression(NegotiateCompression),
     GetUser(GetUser),
     GetUsers(GetUsers),
     CreateUser(CreateUser),
@@ -147,12 +272,18 @@ pub enum Command {
     ChangePassword(ChangePassword),
     LoginUser(LoginUser),
     LogoutUser(LogoutUser),
+    CheckPermission(CheckPermission),
+    UpdateUserExpiry(UpdateUserExpiry),
     GetPersonalAccessTokens(GetPersonalAccessTokens),
     CreatePersonalAccessToken(CreatePersonalAccessToken),
     DeletePersonalAccessToken(DeletePersonalAccessToken),
     LoginWithPersonalAccessToken(LoginWithPersonalAccessToken),
     SendMessages(SendMessages),
     PollMessages(PollMessages),
+    QueryMessages(QueryMessages),
+    CreateFetchSession(CreateFetchSession),
+    FetchMessages(FetchMessages),
+    DeleteFetchSession(DeleteFetchSession),
     GetConsumerOffset(GetConsumerOffset),
     StoreConsumerOffset(StoreConsumerOffset),
     GetStream(GetStream),
@@ -161,12 +292,18 @@ pub enum Command {
     DeleteStream(DeleteStream),
     UpdateStream(UpdateStream),
     PurgeStream(PurgeStream),
+    UndeleteStream(UndeleteStream),
     GetTopic(GetTopic),
     GetTopics(GetTopics),
     CreateTopic(CreateTopic),
     DeleteTopic(DeleteTopic),
     UpdateTopic(UpdateTopic),
     PurgeTopic(PurgeTopic),
+    UndeleteTopic(UndeleteTopic),
+    FreezeTopic(FreezeTopic),
+    UnfreezeTopic(UnfreezeTopic),
+    GetPartitionDetails(GetPartitionDetails),
+    GetPartitionEndOffsets(GetPartitionEndOffsets),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
     GetConsumerGroup(GetConsumerGroup),
@@ -175,6 +312,29 @@ pub enum Command {
     DeleteConsumerGroup(DeleteConsumerGroup),
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
+    GetSchema(GetSchema),
+    GetSchemaVersions(GetSchemaVersions),
+    RegisterSchema(RegisterSchema),
+    UpdateSchemaCompatibility(UpdateSchemaCompatibility),
+    GetTenant(GetTenant),
+    GetTenants(GetTenants),
+    CreateTenant(CreateTenant),
+    UpdateTenant(UpdateTenant),
+    DeleteTenant(DeleteTenant),
+    AddTenantUser(AddTenantUser),
+    RemoveTenantUser(RemoveTenantUser),
+    GetUserGroup(GetUserGroup),
+    GetUserGroups(GetUserGroups),
+    CreateUserGroup(CreateUserGroup),
+    UpdateUserGroupPermissions(UpdateUserGroupPermissions),
+    DeleteUserGroup(DeleteUserGroup),
+    AddUserGroupMember(AddUserGroupMember),
+    RemoveUserGroupMember(RemoveUserGroupMember),
+    GetConnector(GetConnector),
+    GetConnectors(GetConnectors),
+    CreateConnector(CreateConnector),
+    RestartConnector(RestartConnector),
+    DeleteConnector(DeleteConnector),
 }
 
 /// A trait for all command payloads.
@@ -184,10 +344,17 @@ impl BytesSerializable for Command {
     fn as_bytes(&self) -> Bytes {
         match self {
             Command::Ping(payload) => as_bytes(PING_CODE, payload.as_bytes()),
+            Command::Heartbeat(payload) => as_bytes(HEARTBEAT_CODE, payload.as_bytes()),
+            Command::Hello(payload) => as_bytes(HELLO_CODE, payload.as_bytes()),
+            Command::Batch(payload) => as_bytes(BATCH_CODE, payload.as_bytes()),
             Command::GetStats(payload) => as_bytes(GET_STATS_CODE, payload.as_bytes()),
+            Command::GetMetadata(payload) => as_bytes(GET_METADATA_CODE, payload.as_bytes()),
             Command::GetMe(payload) => as_bytes(GET_ME_CODE, payload.as_bytes()),
             Command::GetClient(payload) => as_bytes(GET_CLIENT_CODE, payload.as_bytes()),
             Command::GetClients(payload) => as_bytes(GET_CLIENTS_CODE, payload.as_bytes()),
+            Command::NegotiateCompression(payload) => {
+                as_bytes(NEGOTIATE_COMPRESSION_CODE, payload.as_bytes())
+            }
             Command::GetUser(payload) => as_bytes(GET_USER_CODE, payload.as_bytes()),
             Command::GetUsers(payload) => as_bytes(GET_USERS_CODE, payload.as_bytes()),
             Command::CreateUser(payload) => as_bytes(CREATE_USER_CODE, payload.as_bytes()),
@@ -199,6 +366,12 @@ impl BytesSerializable for Command {
             Command::ChangePassword(payload) => as_bytes(CHANGE_PASSWORD_CODE, payload.as_bytes()),
             Command::LoginUser(payload) => as_bytes(LOGIN_USER_CODE, payload.as_bytes()),
             Command::LogoutUser(payload) => as_bytes(LOGOUT_USER_CODE, payload.as_bytes()),
+            Command::CheckPermission(payload) => {
+                as_bytes(CHECK_PERMISSION_CODE, payload.as_bytes())
+            }
+            Command::UpdateUserExpiry(payload) => {
+                as_bytes(UPDATE_USER_EXPIRY_CODE, payload.as_bytes())
+            }
             Command::GetPersonalAccessTokens(payload) => {
                 as_bytes(GET_PERSONAL_ACCESS_TOKENS_CODE, payload.as_bytes())
             }
@@ -213,6 +386,14 @@ impl BytesSerializable for Command {
             }
             Command::SendMessages(payload) => as_bytes(SEND_MESSAGES_CODE, payload.as_bytes()),
             Command::PollMessages(payload) => as_bytes(POLL_MESSAGES_CODE, payload.as_bytes()),
+            Command::QueryMessages(payload) => as_bytes(QUERY_MESSAGES_CODE, payload.as_bytes()),
+            Command::CreateFetchSession(payload) => {
+                as_bytes(CREATE_FETCH_SESSION_CODE, payload.as_bytes())
+            }
+            Command::FetchMessages(payload) => as_bytes(FETCH_MESSAGES_CODE, payload.as_bytes()),
+            Command::DeleteFetchSession(payload) => {
+                as_bytes(DELETE_FETCH_SESSION_CODE, payload.as_bytes())
+            }
             Command::StoreConsumerOffset(payload) => {
                 as_bytes(STORE_CONSUMER_OFFSET_CODE, payload.as_bytes())
             }
@@ -225,12 +406,22 @@ impl BytesSerializable for Command {
             Command::DeleteStream(payload) => as_bytes(DELETE_STREAM_CODE, payload.as_bytes()),
             Command::UpdateStream(payload) => as_bytes(UPDATE_STREAM_CODE, payload.as_bytes()),
             Command::PurgeStream(payload) => as_bytes(PURGE_STREAM_CODE, payload.as_bytes()),
+            Command::UndeleteStream(payload) => as_bytes(UNDELETE_STREAM_CODE, payload.as_bytes()),
             Command::GetTopic(payload) => as_bytes(GET_TOPIC_CODE, payload.as_bytes()),
             Command::GetTopics(payload) => as_bytes(GET_TOPICS_CODE, payload.as_bytes()),
             Command::CreateTopic(payload) => as_bytes(CREATE_TOPIC_CODE, payload.as_bytes()),
             Command::DeleteTopic(payload) => as_bytes(DELETE_TOPIC_CODE, payload.as_bytes()),
             Command::UpdateTopic(payload) => as_bytes(UPDATE_TOPIC_CODE, payload.as_bytes()),
             Command::PurgeTopic(payload) => as_bytes(PURGE_TOPIC_CODE, payload.as_bytes()),
+            Command::UndeleteTopic(payload) => as_bytes(UNDELETE_TOPIC_CODE, payload.as_bytes()),
+            Command::FreezeTopic(payload) => as_bytes(FREEZE_TOPIC_CODE, payload.as_bytes()),
+            Command::UnfreezeTopic(payload) => as_bytes(UNFREEZE_TOPIC_CODE, payload.as_bytes()),
+            Command::GetPartitionDetails(payload) => {
+                as_bytes(GET_PARTITION_DETAILS_CODE, payload.as_bytes())
+            }
+            Command::GetPartitionEndOffsets(payload) => {
+                as_bytes(GET_PARTITION_END_OFFSETS_CODE, payload.as_bytes())
+            }
             Command::CreatePartitions(payload) => {
                 as_bytes(CREATE_PARTITIONS_CODE, payload.as_bytes())
             }
@@ -255,6 +446,51 @@ impl BytesSerializable for Command {
             Command::LeaveConsumerGroup(payload) => {
                 as_bytes(LEAVE_CONSUMER_GROUP_CODE, payload.as_bytes())
             }
+            Command::GetSchema(payload) => as_bytes(GET_SCHEMA_CODE, payload.as_bytes()),
+            Command::GetSchemaVersions(payload) => {
+                as_bytes(GET_SCHEMA_VERSIONS_CODE, payload.as_bytes())
+            }
+            Command::RegisterSchema(payload) => as_bytes(REGISTER_SCHEMA_CODE, payload.as_bytes()),
+            Command::UpdateSchemaCompatibility(payload) => {
+                as_bytes(UPDATE_SCHEMA_COMPATIBILITY_CODE, payload.as_bytes())
+            }
+            Command::GetTenant(payload) => as_bytes(GET_TENANT_CODE, payload.as_bytes()),
+            Command::GetTenants(payload) => as_bytes(GET_TENANTS_CODE, payload.as_bytes()),
+            Command::CreateTenant(payload) => as_bytes(CREATE_TENANT_CODE, payload.as_bytes()),
+            Command::UpdateTenant(payload) => as_bytes(UPDATE_TENANT_CODE, payload.as_bytes()),
+            Command::DeleteTenant(payload) => as_bytes(DELETE_TENANT_CODE, payload.as_bytes()),
+            Command::AddTenantUser(payload) => as_bytes(ADD_TENANT_USER_CODE, payload.as_bytes()),
+            Command::RemoveTenantUser(payload) => {
+                as_bytes(REMOVE_TENANT_USER_CODE, payload.as_bytes())
+            }
+            Command::GetUserGroup(payload) => as_bytes(GET_USER_GROUP_CODE, payload.as_bytes()),
+            Command::GetUserGroups(payload) => as_bytes(GET_USER_GROUPS_CODE, payload.as_bytes()),
+            Command::CreateUserGroup(payload) => {
+                as_bytes(CREATE_USER_GROUP_CODE, payload.as_bytes())
+            }
+            Command::UpdateUserGroupPermissions(payload) => {
+                as_bytes(UPDATE_USER_GROUP_PERMISSIONS_CODE, payload.as_bytes())
+            }
+            Command::DeleteUserGroup(payload) => {
+                as_bytes(DELETE_USER_GROUP_CODE, payload.as_bytes())
+            }
+            Command::AddUserGroupMember(payload) => {
+                as_bytes(ADD_USER_GROUP_MEMBER_CODE, payload.as_bytes())
+            }
+            Command::RemoveUserGroupMember(payload) => {
+                as_bytes(REMOVE_USER_GROUP_MEMBER_CODE, payload.as_bytes())
+            }
+            Command::GetConnector(payload) => as_bytes(GET_CONNECTOR_CODE, payload.as_bytes()),
+            Command::GetConnectors(payload) => as_bytes(GET_CONNECTORS_CODE, payload.as_bytes()),
+            Command::CreateConnector(payload) => {
+                as_bytes(CREATE_CONNECTOR_CODE, payload.as_bytes())
+            }
+            Command::RestartConnector(payload) => {
+                as_bytes(RESTART_CONNECTOR_CODE, payload.as_bytes())
+            }
+            Command::DeleteConnector(payload) => {
+                as_bytes(DELETE_CONNECTOR_CODE, payload.as_bytes())
+            }
         }
     }
 
@@ -263,10 +499,17 @@ impl BytesSerializable for Command {
         let payload = bytes.slice(4..);
         match command {
             PING_CODE => Ok(Command::Ping(Ping::from_bytes(payload)?)),
+            HEARTBEAT_CODE => Ok(Command::Heartbeat(Heartbeat::from_bytes(payload)?)),
+            HELLO_CODE => Ok(Command::Hello(Hello::from_bytes(payload)?)),
+            BATCH_CODE => Ok(Command::Batch(Batch::from_bytes(payload)?)),
             GET_STATS_CODE => Ok(Command::GetStats(GetStats::from_bytes(payload)?)),
+            GET_METADATA_CODE => Ok(Command::GetMetadata(GetMetadata::from_bytes(payload)?)),
             GET_ME_CODE => Ok(Command::GetMe(GetMe::from_bytes(payload)?)),
             GET_CLIENT_CODE => Ok(Command::GetClient(GetClient::from_bytes(payload)?)),
             GET_CLIENTS_CODE => Ok(Command::GetClients(GetClients::from_bytes(payload)?)),
+            NEGOTIATE_COMPRESSION_CODE => Ok(Command::NegotiateCompression(
+                NegotiateCompression::from_bytes(payload)?,
+            )),
             GET_USER_CODE => Ok(Command::GetUser(GetUser::from_bytes(payload)?)),
             GET_USERS_CODE => Ok(Command::GetUsers(GetUsers::from_bytes(payload)?)),
             CREATE_USER_CODE => Ok(Command::CreateUser(CreateUser::from_bytes(payload)?)),
@@ -280,6 +523,12 @@ impl BytesSerializable for Command {
             )?)),
             LOGIN_USER_CODE => Ok(Command::LoginUser(LoginUser::from_bytes(payload)?)),
             LOGOUT_USER_CODE => Ok(Command::LogoutUser(LogoutUser::from_bytes(payload)?)),
+            CHECK_PERMISSION_CODE => Ok(Command::CheckPermission(CheckPermission::from_bytes(
+                payload,
+            )?)),
+            UPDATE_USER_EXPIRY_CODE => Ok(Command::UpdateUserExpiry(UpdateUserExpiry::from_bytes(
+                payload,
+            )?)),
             GET_PERSONAL_ACCESS_TOKENS_CODE => Ok(Command::GetPersonalAccessTokens(
                 GetPersonalAccessTokens::from_bytes(payload)?,
             )),
@@ -294,6 +543,14 @@ impl BytesSerializable for Command {
             )),
             SEND_MESSAGES_CODE => Ok(Command::SendMessages(SendMessages::from_bytes(payload)?)),
             POLL_MESSAGES_CODE => Ok(Command::PollMessages(PollMessages::from_bytes(payload)?)),
+            QUERY_MESSAGES_CODE => Ok(Command::QueryMessages(QueryMessages::from_bytes(payload)?)),
+            CREATE_FETCH_SESSION_CODE => Ok(Command::CreateFetchSession(
+                CreateFetchSession::from_bytes(payload)?,
+            )),
+            FETCH_MESSAGES_CODE => Ok(Command::FetchMessages(FetchMessages::from_bytes(payload)?)),
+            DELETE_FETCH_SESSION_CODE => Ok(Command::DeleteFetchSession(
+                DeleteFetchSession::from_bytes(payload)?,
+            )),
             STORE_CONSUMER_OFFSET_CODE => Ok(Command::StoreConsumerOffset(
                 StoreConsumerOffset::from_bytes(payload)?,
             )),
@@ -306,12 +563,24 @@ impl BytesSerializable for Command {
             DELETE_STREAM_CODE => Ok(Command::DeleteStream(DeleteStream::from_bytes(payload)?)),
             UPDATE_STREAM_CODE => Ok(Command::UpdateStream(UpdateStream::from_bytes(payload)?)),
             PURGE_STREAM_CODE => Ok(Command::PurgeStream(PurgeStream::from_bytes(payload)?)),
+            UNDELETE_STREAM_CODE => Ok(Command::UndeleteStream(UndeleteStream::from_bytes(
+                payload,
+            )?)),
             GET_TOPIC_CODE => Ok(Command::GetTopic(GetTopic::from_bytes(payload)?)),
             GET_TOPICS_CODE => Ok(Command::GetTopics(GetTopics::from_bytes(payload)?)),
             CREATE_TOPIC_CODE => Ok(Command::CreateTopic(CreateTopic::from_bytes(payload)?)),
             DELETE_TOPIC_CODE => Ok(Command::DeleteTopic(DeleteTopic::from_bytes(payload)?)),
             UPDATE_TOPIC_CODE => Ok(Command::UpdateTopic(UpdateTopic::from_bytes(payload)?)),
             PURGE_TOPIC_CODE => Ok(Command::PurgeTopic(PurgeTopic::from_bytes(payload)?)),
+            UNDELETE_TOPIC_CODE => Ok(Command::UndeleteTopic(UndeleteTopic::from_bytes(payload)?)),
+            FREEZE_TOPIC_CODE => Ok(Command::FreezeTopic(FreezeTopic::from_bytes(payload)?)),
+            UNFREEZE_TOPIC_CODE => Ok(Command::UnfreezeTopic(UnfreezeTopic::from_bytes(payload)?)),
+            GET_PARTITION_DETAILS_CODE => Ok(Command::GetPartitionDetails(
+                GetPartitionDetails::from_bytes(payload)?,
+            )),
+            GET_PARTITION_END_OFFSETS_CODE => Ok(Command::GetPartitionEndOffsets(
+                GetPartitionEndOffsets::from_bytes(payload)?,
+            )),
             CREATE_PARTITIONS_CODE => Ok(Command::CreatePartitions(CreatePartitions::from_bytes(
                 payload,
             )?)),
@@ -336,6 +605,53 @@ impl BytesSerializable for Command {
             LEAVE_CONSUMER_GROUP_CODE => Ok(Command::LeaveConsumerGroup(
                 LeaveConsumerGroup::from_bytes(payload)?,
             )),
+            GET_SCHEMA_CODE => Ok(Command::GetSchema(GetSchema::from_bytes(payload)?)),
+            GET_SCHEMA_VERSIONS_CODE => Ok(Command::GetSchemaVersions(
+                GetSchemaVersions::from_bytes(payload)?,
+            )),
+            REGISTER_SCHEMA_CODE => Ok(Command::RegisterSchema(RegisterSchema::from_bytes(
+                payload,
+            )?)),
+            UPDATE_SCHEMA_COMPATIBILITY_CODE => Ok(Command::UpdateSchemaCompatibility(
+                UpdateSchemaCompatibility::from_bytes(payload)?,
+            )),
+            GET_TENANT_CODE => Ok(Command::GetTenant(GetTenant::from_bytes(payload)?)),
+            GET_TENANTS_CODE => Ok(Command::GetTenants(GetTenants::from_bytes(payload)?)),
+            CREATE_TENANT_CODE => Ok(Command::CreateTenant(CreateTenant::from_bytes(payload)?)),
+            UPDATE_TENANT_CODE => Ok(Command::UpdateTenant(UpdateTenant::from_bytes(payload)?)),
+            DELETE_TENANT_CODE => Ok(Command::DeleteTenant(DeleteTenant::from_bytes(payload)?)),
+            ADD_TENANT_USER_CODE => Ok(Command::AddTenantUser(AddTenantUser::from_bytes(payload)?)),
+            REMOVE_TENANT_USER_CODE => Ok(Command::RemoveTenantUser(RemoveTenantUser::from_bytes(
+                payload,
+            )?)),
+            GET_USER_GROUP_CODE => Ok(Command::GetUserGroup(GetUserGroup::from_bytes(payload)?)),
+            GET_USER_GROUPS_CODE => Ok(Command::GetUserGroups(GetUserGroups::from_bytes(payload)?)),
+            CREATE_USER_GROUP_CODE => Ok(Command::CreateUserGroup(CreateUserGroup::from_bytes(
+                payload,
+            )?)),
+            UPDATE_USER_GROUP_PERMISSIONS_CODE => Ok(Command::UpdateUserGroupPermissions(
+                UpdateUserGroupPermissions::from_bytes(payload)?,
+            )),
+            DELETE_USER_GROUP_CODE => Ok(Command::DeleteUserGroup(DeleteUserGroup::from_bytes(
+                payload,
+            )?)),
+            ADD_USER_GROUP_MEMBER_CODE => Ok(Command::AddUserGroupMember(
+                AddUserGroupMember::from_bytes(payload)?,
+            )),
+            REMOVE_USER_GROUP_MEMBER_CODE => Ok(Command::RemoveUserGroupMember(
+                RemoveUserGroupMember::from_bytes(payload)?,
+            )),
+            GET_CONNECTOR_CODE => Ok(Command::GetConnector(GetConnector::from_bytes(payload)?)),
+            GET_CONNECTORS_CODE => Ok(Command::GetConnectors(GetConnectors::from_bytes(payload)?)),
+            CREATE_CONNECTOR_CODE => Ok(Command::CreateConnector(CreateConnector::from_bytes(
+                payload,
+            )?)),
+            RESTART_CONNECTOR_CODE => Ok(Command::RestartConnector(RestartConnector::from_bytes(
+                payload,
+            )?)),
+            DELETE_CONNECTOR_CODE => Ok(Command::DeleteConnector(DeleteConnector::from_bytes(
+                payload,
+            )?)),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -348,14 +664,111 @@ fn as_bytes(command: u32, payload: Bytes) -> Bytes {
     bytes.freeze()
 }
 
+impl Command {
+    /// Returns the name of the command, e.g. `message.send`, without its payload.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::Ping(_) => PING,
+            Command::Heartbeat(_) => HEARTBEAT,
+            Command::Hello(_) => HELLO,
+            Command::Batch(_) => BATCH,
+            Command::GetStats(_) => GET_STATS,
+            Command::GetMetadata(_) => GET_METADATA,
+            Command::GetMe(_) => GET_ME,
+            Command::GetClient(_) => GET_CLIENT,
+            Command::GetClients(_) => GET_CLIENTS,
+            Command::NegotiateCompression(_) => NEGOTIATE_COMPRESSION,
+            Command::GetUser(_) => GET_USER,
+            Command::GetUsers(_) => GET_USERS,
+            Command::CreateUser(_) => CREATE_USER,
+            Command::DeleteUser(_) => DELETE_USER,
+            Command::UpdateUser(_) => UPDATE_USER,
+            Command::UpdatePermissions(_) => UPDATE_PERMISSIONS,
+            Command::ChangePassword(_) => CHANGE_PASSWORD,
+            Command::LoginUser(_) => LOGIN_USER,
+            Command::LogoutUser(_) => LOGOUT_USER,
+            Command::CheckPermission(_) => CHECK_PERMISSION,
+            Command::UpdateUserExpiry(_) => UPDATE_USER_EXPIRY,
+            Command::GetPersonalAccessTokens(_) => GET_PERSONAL_ACCESS_TOKENS,
+            Command::CreatePersonalAccessToken(_) => CREATE_PERSONAL_ACCESS_TOKEN,
+            Command::DeletePersonalAccessToken(_) => DELETE_PERSONAL_ACCESS_TOKEN,
+            Command::LoginWithPersonalAccessToken(_) => LOGIN_WITH_PERSONAL_ACCESS_TOKEN,
+            Command::GetStream(_) => GET_STREAM,
+            Command::GetStreams(_) => GET_STREAMS,
+            Command::CreateStream(_) => CREATE_STREAM,
+            Command::DeleteStream(_) => DELETE_STREAM,
+            Command::UpdateStream(_) => UPDATE_STREAM,
+            Command::PurgeStream(_) => PURGE_STREAM,
+            Command::UndeleteStream(_) => UNDELETE_STREAM,
+            Command::GetTopic(_) => GET_TOPIC,
+            Command::GetTopics(_) => GET_TOPICS,
+            Command::CreateTopic(_) => CREATE_TOPIC,
+            Command::DeleteTopic(_) => DELETE_TOPIC,
+            Command::UpdateTopic(_) => UPDATE_TOPIC,
+            Command::PurgeTopic(_) => PURGE_TOPIC,
+            Command::UndeleteTopic(_) => UNDELETE_TOPIC,
+            Command::FreezeTopic(_) => FREEZE_TOPIC,
+            Command::UnfreezeTopic(_) => UNFREEZE_TOPIC,
+            Command::GetPartitionDetails(_) => GET_PARTITION_DETAILS,
+            Command::GetPartitionEndOffsets(_) => GET_PARTITION_END_OFFSETS,
+            Command::CreatePartitions(_) => CREATE_PARTITIONS,
+            Command::DeletePartitions(_) => DELETE_PARTITIONS,
+            Command::PollMessages(_) => POLL_MESSAGES,
+            Command::QueryMessages(_) => QUERY_MESSAGES,
+            Command::CreateFetchSession(_) => CREATE_FETCH_SESSION,
+            Command::FetchMessages(_) => FETCH_MESSAGES,
+            Command::DeleteFetchSession(_) => DELETE_FETCH_SESSION,
+            Command::SendMessages(_) => SEND_MESSAGES,
+            Command::StoreConsumerOffset(_) => STORE_CONSUMER_OFFSET,
+            Command::GetConsumerOffset(_) => GET_CONSUMER_OFFSET,
+            Command::GetConsumerGroup(_) => GET_CONSUMER_GROUP,
+            Command::GetConsumerGroups(_) => GET_CONSUMER_GROUPS,
+            Command::CreateConsumerGroup(_) => CREATE_CONSUMER_GROUP,
+            Command::DeleteConsumerGroup(_) => DELETE_CONSUMER_GROUP,
+            Command::JoinConsumerGroup(_) => JOIN_CONSUMER_GROUP,
+            Command::LeaveConsumerGroup(_) => LEAVE_CONSUMER_GROUP,
+            Command::GetSchema(_) => GET_SCHEMA,
+            Command::GetSchemaVersions(_) => GET_SCHEMA_VERSIONS,
+            Command::RegisterSchema(_) => REGISTER_SCHEMA,
+            Command::UpdateSchemaCompatibility(_) => UPDATE_SCHEMA_COMPATIBILITY,
+            Command::GetTenant(_) => GET_TENANT,
+            Command::GetTenants(_) => GET_TENANTS,
+            Command::CreateTenant(_) => CREATE_TENANT,
+            Command::UpdateTenant(_) => UPDATE_TENANT,
+            Command::DeleteTenant(_) => DELETE_TENANT,
+            Command::AddTenantUser(_) => ADD_TENANT_USER,
+            Command::RemoveTenantUser(_) => REMOVE_TENANT_USER,
+            Command::GetUserGroup(_) => GET_USER_GROUP,
+            Command::GetUserGroups(_) => GET_USER_GROUPS,
+            Command::CreateUserGroup(_) => CREATE_USER_GROUP,
+            Command::UpdateUserGroupPermissions(_) => UPDATE_USER_GROUP_PERMISSIONS,
+            Command::DeleteUserGroup(_) => DELETE_USER_GROUP,
+            Command::AddUserGroupMember(_) => ADD_USER_GROUP_MEMBER,
+            Command::RemoveUserGroupMember(_) => REMOVE_USER_GROUP_MEMBER,
+            Command::GetConnector(_) => GET_CONNECTOR,
+            Command::GetConnectors(_) => GET_CONNECTORS,
+            Command::CreateConnector(_) => CREATE_CONNECTOR,
+            Command::RestartConnector(_) => RESTART_CONNECTOR,
+            Command::DeleteConnector(_) => DELETE_CONNECTOR,
+        }
+    }
+}
+
 impl Display for Command {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Command::Ping(_) => write!(formatter, "{PING}"),
+            Command::Heartbeat(_) => write!(formatter, "{HEARTBEAT}"),
+            Command::Hello(payload) => write!(formatter, "{HELLO}|{payload}"),
+            Command::Batch(payload) => write!(formatter, "{BATCH}|{payload}"),
             Command::GetStats(_) => write!(formatter, "{GET_STATS}"),
+            Command::GetMetadata(_) => write!(formatter, "{GET_METADATA}"),
             Command::GetMe(_) => write!(formatter, "{GET_ME}"),
             Command::GetClient(payload) => write!(formatter, "{GET_CLIENT}|{payload}"),
             Command::GetClients(_) => write!(formatter, "{GET_CLIENTS}"),
+            Command::NegotiateCompression(payload) => {
+                write!(formatter, "{NEGOTIATE_COMPRESSION}|{payload}")
+            }
             Command::GetUser(payload) => write!(formatter, "{GET_USER}|{payload}"),
             Command::GetUsers(_) => write!(formatter, "{GET_USERS}"),
             Command::CreateUser(payload) => write!(formatter, "{CREATE_USER}|{payload}"),
@@ -369,6 +782,12 @@ impl Display for Command {
             }
             Command::LoginUser(payload) => write!(formatter, "{LOGIN_USER}|{payload}"),
             Command::LogoutUser(_) => write!(formatter, "{LOGOUT_USER}"),
+            Command::CheckPermission(payload) => {
+                write!(formatter, "{CHECK_PERMISSION}|{payload}")
+            }
+            Command::UpdateUserExpiry(payload) => {
+                write!(formatter, "{UPDATE_USER_EXPIRY}|{payload}")
+            }
             Command::GetPersonalAccessTokens(_) => {
                 write!(formatter, "{GET_PERSONAL_ACCESS_TOKENS}")
             }
@@ -387,12 +806,22 @@ impl Display for Command {
             Command::DeleteStream(payload) => write!(formatter, "{DELETE_STREAM}|{payload}"),
             Command::UpdateStream(payload) => write!(formatter, "{UPDATE_STREAM}|{payload}"),
             Command::PurgeStream(payload) => write!(formatter, "{PURGE_STREAM}|{payload}"),
+            Command::UndeleteStream(payload) => write!(formatter, "{UNDELETE_STREAM}|{payload}"),
             Command::GetTopic(payload) => write!(formatter, "{GET_TOPIC}|{payload}"),
             Command::GetTopics(payload) => write!(formatter, "{GET_TOPICS}|{payload}"),
             Command::CreateTopic(payload) => write!(formatter, "{CREATE_TOPIC}|{payload}"),
             Command::DeleteTopic(payload) => write!(formatter, "{DELETE_TOPIC}|{payload}"),
             Command::UpdateTopic(payload) => write!(formatter, "{UPDATE_TOPIC}|{payload}"),
             Command::PurgeTopic(payload) => write!(formatter, "{PURGE_TOPIC}|{payload}"),
+            Command::UndeleteTopic(payload) => write!(formatter, "{UNDELETE_TOPIC}|{payload}"),
+            Command::FreezeTopic(payload) => write!(formatter, "{FREEZE_TOPIC}|{payload}"),
+            Command::UnfreezeTopic(payload) => write!(formatter, "{UNFREEZE_TOPIC}|{payload}"),
+            Command::GetPartitionDetails(payload) => {
+                write!(formatter, "{GET_PARTITION_DETAILS}|{payload}")
+            }
+            Command::GetPartitionEndOffsets(payload) => {
+                write!(formatter, "{GET_PARTITION_END_OFFSETS}|{payload}")
+            }
             Command::CreatePartitions(payload) => {
                 write!(formatter, "{CREATE_PARTITIONS}|{payload}")
             }
@@ -400,6 +829,14 @@ impl Display for Command {
                 write!(formatter, "{DELETE_PARTITIONS}|{payload}")
             }
             Command::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
+            Command::QueryMessages(payload) => write!(formatter, "{QUERY_MESSAGES}|{payload}"),
+            Command::CreateFetchSession(payload) => {
+                write!(formatter, "{CREATE_FETCH_SESSION}|{payload}")
+            }
+            Command::FetchMessages(payload) => write!(formatter, "{FETCH_MESSAGES}|{payload}"),
+            Command::DeleteFetchSession(payload) => {
+                write!(formatter, "{DELETE_FETCH_SESSION}|{payload}")
+            }
             Command::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
             Command::StoreConsumerOffset(payload) => {
                 write!(formatter, "{STORE_CONSUMER_OFFSET}|{payload}")
@@ -425,6 +862,51 @@ impl Display for Command {
             Command::LeaveConsumerGroup(payload) => {
                 write!(formatter, "{LEAVE_CONSUMER_GROUP}|{payload}")
             }
+            Command::GetSchema(payload) => write!(formatter, "{GET_SCHEMA}|{payload}"),
+            Command::GetSchemaVersions(payload) => {
+                write!(formatter, "{GET_SCHEMA_VERSIONS}|{payload}")
+            }
+            Command::RegisterSchema(payload) => write!(formatter, "{REGISTER_SCHEMA}|{payload}"),
+            Command::UpdateSchemaCompatibility(payload) => {
+                write!(formatter, "{UPDATE_SCHEMA_COMPATIBILITY}|{payload}")
+            }
+            Command::GetTenant(payload) => write!(formatter, "{GET_TENANT}|{payload}"),
+            Command::GetTenants(_) => write!(formatter, "{GET_TENANTS}"),
+            Command::CreateTenant(payload) => write!(formatter, "{CREATE_TENANT}|{payload}"),
+            Command::UpdateTenant(payload) => write!(formatter, "{UPDATE_TENANT}|{payload}"),
+            Command::DeleteTenant(payload) => write!(formatter, "{DELETE_TENANT}|{payload}"),
+            Command::AddTenantUser(payload) => write!(formatter, "{ADD_TENANT_USER}|{payload}"),
+            Command::RemoveTenantUser(payload) => {
+                write!(formatter, "{REMOVE_TENANT_USER}|{payload}")
+            }
+            Command::GetUserGroup(payload) => write!(formatter, "{GET_USER_GROUP}|{payload}"),
+            Command::GetUserGroups(_) => write!(formatter, "{GET_USER_GROUPS}"),
+            Command::CreateUserGroup(payload) => {
+                write!(formatter, "{CREATE_USER_GROUP}|{payload}")
+            }
+            Command::UpdateUserGroupPermissions(payload) => {
+                write!(formatter, "{UPDATE_USER_GROUP_PERMISSIONS}|{payload}")
+            }
+            Command::DeleteUserGroup(payload) => {
+                write!(formatter, "{DELETE_USER_GROUP}|{payload}")
+            }
+            Command::AddUserGroupMember(payload) => {
+                write!(formatter, "{ADD_USER_GROUP_MEMBER}|{payload}")
+            }
+            Command::RemoveUserGroupMember(payload) => {
+                write!(formatter, "{REMOVE_USER_GROUP_MEMBER}|{payload}")
+            }
+            Command::GetConnector(payload) => write!(formatter, "{GET_CONNECTOR}|{payload}"),
+            Command::GetConnectors(_) => write!(formatter, "{GET_CONNECTORS}"),
+            Command::CreateConnector(payload) => {
+                write!(formatter, "{CREATE_CONNECTOR}|{payload}")
+            }
+            Command::RestartConnector(payload) => {
+                write!(formatter, "{RESTART_CONNECTOR}|{payload}")
+            }
+            Command::DeleteConnector(payload) => {
+                write!(formatter, "{DELETE_CONNECTOR}|{payload}")
+            }
         }
     }
 }
@@ -433,6 +915,13 @@ impl Display for Command {
 mod tests {
     use super::*;
 
+    #[test]
+    fn name_should_not_include_payload() {
+        let command = Command::SendMessages(SendMessages::default());
+        assert_eq!(command.name(), SEND_MESSAGES);
+        assert_eq!(Command::Ping(Ping::default()).name(), PING);
+    }
+
     #[test]
     fn should_be_serialized_as_bytes_and_deserialized_from_bytes() {
         assert_serialized_as_bytes_and_deserialized_from_bytes(
@@ -440,11 +929,35 @@ mod tests {
             PING_CODE,
             &Ping::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::Heartbeat(Heartbeat::default()),
+            HEARTBEAT_CODE,
+            &Heartbeat::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::Hello(Hello::default()),
+            HELLO_CODE,
+            &Hello::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::Batch(Batch {
+                commands: vec![Command::Ping(Ping::default())],
+            }),
+            BATCH_CODE,
+            &Batch {
+                commands: vec![Command::Ping(Ping::default())],
+            },
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetStats(GetStats::default()),
             GET_STATS_CODE,
             &GetStats::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetMetadata(GetMetadata::default()),
+            GET_METADATA_CODE,
+            &GetMetadata::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetMe(GetMe::default()),
             GET_ME_CODE,
@@ -460,6 +973,11 @@ mod tests {
             GET_CLIENTS_CODE,
             &GetClients::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::NegotiateCompression(NegotiateCompression::default()),
+            NEGOTIATE_COMPRESSION_CODE,
+            &NegotiateCompression::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetUser(GetUser::default()),
             GET_USER_CODE,
@@ -505,6 +1023,16 @@ mod tests {
             LOGOUT_USER_CODE,
             &LogoutUser::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CheckPermission(CheckPermission::default()),
+            CHECK_PERMISSION_CODE,
+            &CheckPermission::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateUserExpiry(UpdateUserExpiry::default()),
+            UPDATE_USER_EXPIRY_CODE,
+            &UpdateUserExpiry::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetPersonalAccessTokens(GetPersonalAccessTokens::default()),
             GET_PERSONAL_ACCESS_TOKENS_CODE,
@@ -535,6 +1063,26 @@ mod tests {
             POLL_MESSAGES_CODE,
             &PollMessages::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::QueryMessages(QueryMessages::default()),
+            QUERY_MESSAGES_CODE,
+            &QueryMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreateFetchSession(CreateFetchSession::default()),
+            CREATE_FETCH_SESSION_CODE,
+            &CreateFetchSession::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::FetchMessages(FetchMessages::default()),
+            FETCH_MESSAGES_CODE,
+            &FetchMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeleteFetchSession(DeleteFetchSession::default()),
+            DELETE_FETCH_SESSION_CODE,
+            &DeleteFetchSession::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::StoreConsumerOffset(StoreConsumerOffset::default()),
             STORE_CONSUMER_OFFSET_CODE,
@@ -575,6 +1123,11 @@ mod tests {
             PURGE_STREAM_CODE,
             &PurgeStream::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UndeleteStream(UndeleteStream::default()),
+            UNDELETE_STREAM_CODE,
+            &UndeleteStream::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetTopic(GetTopic::default()),
             GET_TOPIC_CODE,
@@ -605,6 +1158,31 @@ mod tests {
             PURGE_TOPIC_CODE,
             &PurgeTopic::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UndeleteTopic(UndeleteTopic::default()),
+            UNDELETE_TOPIC_CODE,
+            &UndeleteTopic::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::FreezeTopic(FreezeTopic::default()),
+            FREEZE_TOPIC_CODE,
+            &FreezeTopic::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UnfreezeTopic(UnfreezeTopic::default()),
+            UNFREEZE_TOPIC_CODE,
+            &UnfreezeTopic::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetPartitionDetails(GetPartitionDetails::default()),
+            GET_PARTITION_DETAILS_CODE,
+            &GetPartitionDetails::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetPartitionEndOffsets(GetPartitionEndOffsets::default()),
+            GET_PARTITION_END_OFFSETS_CODE,
+            &GetPartitionEndOffsets::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::CreatePartitions(CreatePartitions::default()),
             CREATE_PARTITIONS_CODE,
@@ -645,6 +1223,121 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetSchema(GetSchema::default()),
+            GET_SCHEMA_CODE,
+            &GetSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetSchemaVersions(GetSchemaVersions::default()),
+            GET_SCHEMA_VERSIONS_CODE,
+            &GetSchemaVersions::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RegisterSchema(RegisterSchema::default()),
+            REGISTER_SCHEMA_CODE,
+            &RegisterSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateSchemaCompatibility(UpdateSchemaCompatibility::default()),
+            UPDATE_SCHEMA_COMPATIBILITY_CODE,
+            &UpdateSchemaCompatibility::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetTenant(GetTenant::default()),
+            GET_TENANT_CODE,
+            &GetTenant::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetTenants(GetTenants::default()),
+            GET_TENANTS_CODE,
+            &GetTenants::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreateTenant(CreateTenant::default()),
+            CREATE_TENANT_CODE,
+            &CreateTenant::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateTenant(UpdateTenant::default()),
+            UPDATE_TENANT_CODE,
+            &UpdateTenant::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeleteTenant(DeleteTenant::default()),
+            DELETE_TENANT_CODE,
+            &DeleteTenant::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::AddTenantUser(AddTenantUser::default()),
+            ADD_TENANT_USER_CODE,
+            &AddTenantUser::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RemoveTenantUser(RemoveTenantUser::default()),
+            REMOVE_TENANT_USER_CODE,
+            &RemoveTenantUser::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetUserGroup(GetUserGroup::default()),
+            GET_USER_GROUP_CODE,
+            &GetUserGroup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetUserGroups(GetUserGroups::default()),
+            GET_USER_GROUPS_CODE,
+            &GetUserGroups::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreateUserGroup(CreateUserGroup::default()),
+            CREATE_USER_GROUP_CODE,
+            &CreateUserGroup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateUserGroupPermissions(UpdateUserGroupPermissions::default()),
+            UPDATE_USER_GROUP_PERMISSIONS_CODE,
+            &UpdateUserGroupPermissions::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeleteUserGroup(DeleteUserGroup::default()),
+            DELETE_USER_GROUP_CODE,
+            &DeleteUserGroup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::AddUserGroupMember(AddUserGroupMember::default()),
+            ADD_USER_GROUP_MEMBER_CODE,
+            &AddUserGroupMember::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RemoveUserGroupMember(RemoveUserGroupMember::default()),
+            REMOVE_USER_GROUP_MEMBER_CODE,
+            &RemoveUserGroupMember::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetConnector(GetConnector::default()),
+            GET_CONNECTOR_CODE,
+            &GetConnector::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetConnectors(GetConnectors::default()),
+            GET_CONNECTORS_CODE,
+            &GetConnectors::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreateConnector(CreateConnector::default()),
+            CREATE_CONNECTOR_CODE,
+            &CreateConnector::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RestartConnector(RestartConnector::default()),
+            RESTART_CONNECTOR_CODE,
+            &RestartConnector::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeleteConnector(DeleteConnector::default()),
+            DELETE_CONNECTOR_CODE,
+            &DeleteConnector::default(),
+        );
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
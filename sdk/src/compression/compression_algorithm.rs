@@ -1,20 +1,24 @@
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use serde::{
     de::{self, Deserializer, Visitor},
     Deserialize, Serialize, Serializer,
 };
 use std::{
     fmt::{Display, Formatter},
+    io::{Read, Write},
     str::FromStr,
 };
 
 use crate::error::IggyError;
 
-// for now only those, in the future will add snappy, lz4, zstd (same as in confluent kafka) in addition to that
-// we should consider brotli as well.
-#[derive(Debug, PartialEq, Clone)]
+// in the future we might add snappy (same as in confluent kafka), we should consider brotli as well.
+#[derive(Debug, PartialEq, Clone, Default)]
 pub enum CompressionAlgorithm {
+    #[default]
     None,
     Gzip,
+    Zstd,
+    Lz4,
 }
 impl FromStr for CompressionAlgorithm {
     type Err = String;
@@ -22,6 +26,8 @@ impl FromStr for CompressionAlgorithm {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "gzip" => Ok(CompressionAlgorithm::Gzip),
+            "zstd" => Ok(CompressionAlgorithm::Zstd),
+            "lz4" => Ok(CompressionAlgorithm::Lz4),
             "none" => Ok(CompressionAlgorithm::None),
             _ => Err(format!("Unknown compression type: {}", s)),
         }
@@ -33,6 +39,8 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::None => 1,
             CompressionAlgorithm::Gzip => 2,
+            CompressionAlgorithm::Zstd => 3,
+            CompressionAlgorithm::Lz4 => 4,
         }
     }
 
@@ -40,17 +48,57 @@ impl CompressionAlgorithm {
         match code {
             1 => Ok(CompressionAlgorithm::None),
             2 => Ok(CompressionAlgorithm::Gzip),
+            3 => Ok(CompressionAlgorithm::Zstd),
+            4 => Ok(CompressionAlgorithm::Lz4),
             _ => Err(IggyError::InvalidCommand),
         }
     }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompressionAlgorithm::None => "none",
+            CompressionAlgorithm::Gzip => "gzip",
+            CompressionAlgorithm::Zstd => "zstd",
+            CompressionAlgorithm::Lz4 => "lz4",
+        }
+    }
+
+    pub fn compress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        let error = |_| IggyError::CannotCompressData(self.to_string());
+        match self {
+            CompressionAlgorithm::None => Ok(data.to_vec()),
+            CompressionAlgorithm::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(data).map_err(error)?;
+                encoder.finish().map_err(error)
+            }
+            CompressionAlgorithm::Zstd => zstd::encode_all(data, 0).map_err(error),
+            CompressionAlgorithm::Lz4 => Ok(lz4_flex::compress_prepend_size(data)),
+        }
+    }
+
+    pub fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        let error = || IggyError::CannotDecompressData(self.to_string());
+        match self {
+            CompressionAlgorithm::None => Ok(data.to_vec()),
+            CompressionAlgorithm::Gzip => {
+                let mut decompressed = Vec::new();
+                GzDecoder::new(data)
+                    .read_to_end(&mut decompressed)
+                    .map_err(|_| error())?;
+                Ok(decompressed)
+            }
+            CompressionAlgorithm::Zstd => zstd::decode_all(data).map_err(|_| error()),
+            CompressionAlgorithm::Lz4 => {
+                lz4_flex::decompress_size_prepended(data).map_err(|_| error())
+            }
+        }
+    }
 }
 
 impl Display for CompressionAlgorithm {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            CompressionAlgorithm::None => write!(f, "none"),
-            CompressionAlgorithm::Gzip => write!(f, "gzip"),
-        }
+        write!(f, "{}", self.as_str())
     }
 }
 
@@ -59,19 +107,13 @@ impl Serialize for CompressionAlgorithm {
     where
         S: Serializer,
     {
-        match self {
-            CompressionAlgorithm::None => serializer.serialize_str("none"),
-            CompressionAlgorithm::Gzip => serializer.serialize_str("gzip"),
-        }
+        serializer.serialize_str(self.as_str())
     }
 }
 
 impl From<CompressionAlgorithm> for String {
     fn from(value: CompressionAlgorithm) -> Self {
-        match value {
-            CompressionAlgorithm::None => "none".to_string(),
-            CompressionAlgorithm::Gzip => "gzip".to_string(),
-        }
+        value.as_str().to_string()
     }
 }
 struct CompressionKindVisitor;
@@ -169,10 +211,59 @@ mod tests {
         let invalid_compression_kind = CompressionAlgorithm::from_code(0);
         assert!(invalid_compression_kind.is_err());
 
+        let invalid_compression_kind = CompressionAlgorithm::from_code(5);
+        assert!(invalid_compression_kind.is_err());
+
         let invalid_compression_kind = CompressionAlgorithm::from_code(69);
         assert!(invalid_compression_kind.is_err());
 
         let invalid_compression_kind = CompressionAlgorithm::from_code(255);
         assert!(invalid_compression_kind.is_err());
     }
+
+    #[test]
+    fn test_zstd_and_lz4_from_str_and_code() {
+        for (name, code, algorithm) in [
+            ("zstd", 3, CompressionAlgorithm::Zstd),
+            ("lz4", 4, CompressionAlgorithm::Lz4),
+        ] {
+            assert_eq!(CompressionAlgorithm::from_str(name).unwrap(), algorithm);
+            assert_eq!(CompressionAlgorithm::from_code(code).unwrap(), algorithm);
+            assert_eq!(algorithm.as_code(), code);
+            assert_eq!(algorithm.to_string(), name);
+        }
+    }
+
+    #[test]
+    fn test_compress_and_decompress() {
+        let data = "{\"id\":1,\"name\":\"iggy\"}".repeat(100);
+        for algorithm in [
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Lz4,
+        ] {
+            let compressed = algorithm.compress(data.as_bytes()).unwrap();
+            if algorithm != CompressionAlgorithm::None {
+                assert!(compressed.len() < data.len());
+            }
+            let decompressed = algorithm.decompress(&compressed).unwrap();
+            assert_eq!(decompressed, data.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_decompress_invalid_data() {
+        for algorithm in [
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Lz4,
+        ] {
+            let error = algorithm.decompress(b"invalid").unwrap_err();
+            assert_eq!(
+                error.as_code(),
+                IggyError::CannotDecompressData(algorithm.to_string()).as_code()
+            );
+        }
+    }
 }
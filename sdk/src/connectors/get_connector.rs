@@ -0,0 +1,96 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::connectors::{put_short_string, read_short_string, validate_name};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetConnector` command is used to retrieve the information about the connector, including its status.
+/// It has additional payload:
+/// - `name` - unique connector name.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetConnector {
+    /// Unique connector name.
+    #[serde(skip)]
+    pub name: String,
+}
+
+impl CommandPayload for GetConnector {}
+
+impl Default for GetConnector {
+    fn default() -> Self {
+        GetConnector {
+            name: "connector".to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for GetConnector {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_name(&self.name)
+    }
+}
+
+impl BytesSerializable for GetConnector {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1 + self.name.len());
+        put_short_string(&mut bytes, &self.name);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<GetConnector, IggyError> {
+        let (name, read_bytes) = read_short_string(&bytes, 0)?;
+        if bytes.len() != read_bytes {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = GetConnector { name };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetConnector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetConnector {
+            name: "orders-cdc".to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let (name, read_bytes) = read_short_string(&bytes, 0).unwrap();
+
+        assert_eq!(read_bytes, bytes.len());
+        assert_eq!(name, command.name);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let mut bytes = BytesMut::new();
+        put_short_string(&mut bytes, "orders-cdc");
+        let command = GetConnector::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.name, "orders-cdc");
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_invalid_name() {
+        let mut bytes = BytesMut::new();
+        put_short_string(&mut bytes, ".hidden");
+        assert!(GetConnector::from_bytes(bytes.freeze()).is_err());
+        assert!(GetConnector::from_bytes(Bytes::new()).is_err());
+    }
+}
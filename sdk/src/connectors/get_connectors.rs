@@ -0,0 +1,66 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetConnectors` command is used to retrieve the information about all the connectors.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct GetConnectors {}
+
+impl CommandPayload for GetConnectors {}
+
+impl Validatable<IggyError> for GetConnectors {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetConnectors {
+    fn as_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<GetConnectors, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = GetConnectors {};
+        command.validate()?;
+        Ok(GetConnectors {})
+    }
+}
+
+impl Display for GetConnectors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = GetConnectors {};
+        let bytes = command.as_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let command = GetConnectors::from_bytes(Bytes::new());
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_empty_bytes() {
+        let command = GetConnectors::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,48 @@
+pub mod create_connector;
+pub mod delete_connector;
+pub mod get_connector;
+pub mod get_connectors;
+pub mod restart_connector;
+
+use crate::error::IggyError;
+use crate::utils::text;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::str::from_utf8;
+
+const MAX_NAME_LENGTH: usize = 255;
+
+/// Validates the connector name, which is used as the file name of its definition on the server,
+/// so it can't start with the dot.
+pub(crate) fn validate_name(name: &str) -> Result<(), IggyError> {
+    if name.is_empty() || name.len() > MAX_NAME_LENGTH || name.starts_with('.') {
+        return Err(IggyError::InvalidConnectorName);
+    }
+
+    if !text::is_resource_name_valid(name) {
+        return Err(IggyError::InvalidConnectorName);
+    }
+
+    Ok(())
+}
+
+/// Writes the string prefixed with its length (1 byte), the string must be at most 255 bytes long.
+pub(crate) fn put_short_string(bytes: &mut BytesMut, value: &str) {
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u8(value.len() as u8);
+    bytes.put_slice(value.as_bytes());
+}
+
+/// Reads the string prefixed with its length (1 byte), returns the string and the number of the read bytes.
+pub(crate) fn read_short_string(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<(String, usize), IggyError> {
+    let Some(length) = bytes.get(position).map(|length| *length as usize) else {
+        return Err(IggyError::InvalidCommand);
+    };
+    let Some(value) = bytes.get(position + 1..position + 1 + length) else {
+        return Err(IggyError::InvalidCommand);
+    };
+
+    Ok((from_utf8(value)?.to_string(), 1 + length))
+}
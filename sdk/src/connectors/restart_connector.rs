@@ -0,0 +1,96 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::connectors::{put_short_string, read_short_string, validate_name};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `RestartConnector` command is used to restart the connector, e.g. once it has failed, continuing from the last stored offset.
+/// It has additional payload:
+/// - `name` - unique connector name.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RestartConnector {
+    /// Unique connector name.
+    #[serde(skip)]
+    pub name: String,
+}
+
+impl CommandPayload for RestartConnector {}
+
+impl Default for RestartConnector {
+    fn default() -> Self {
+        RestartConnector {
+            name: "connector".to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for RestartConnector {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_name(&self.name)
+    }
+}
+
+impl BytesSerializable for RestartConnector {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1 + self.name.len());
+        put_short_string(&mut bytes, &self.name);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<RestartConnector, IggyError> {
+        let (name, read_bytes) = read_short_string(&bytes, 0)?;
+        if bytes.len() != read_bytes {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = RestartConnector { name };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for RestartConnector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RestartConnector {
+            name: "orders-cdc".to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let (name, read_bytes) = read_short_string(&bytes, 0).unwrap();
+
+        assert_eq!(read_bytes, bytes.len());
+        assert_eq!(name, command.name);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let mut bytes = BytesMut::new();
+        put_short_string(&mut bytes, "orders-cdc");
+        let command = RestartConnector::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.name, "orders-cdc");
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_invalid_name() {
+        let mut bytes = BytesMut::new();
+        put_short_string(&mut bytes, ".hidden");
+        assert!(RestartConnector::from_bytes(bytes.freeze()).is_err());
+        assert!(RestartConnector::from_bytes(Bytes::new()).is_err());
+    }
+}
@@ -1,6 +1,9 @@
 use crate::bytes_serializable::BytesSerializable;
+use crate::client::Client;
+use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::partitions::get_partition_end_offsets::GetPartitionEndOffsets;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
@@ -69,6 +72,37 @@ impl Consumer {
             id,
         }
     }
+
+    /// Returns the number of messages in the partition which were not yet processed by the consumer, based on its stored offset and the partition end offset.
+    /// For the consumer group, the partition is resolved by the server when `partition_id` is `None`.
+    /// The server reports the missing stored offset as 0, so the lag of the consumer which has not stored any offset yet doesn't include the first message.
+    pub async fn lag(
+        &self,
+        client: &dyn Client,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<u64, IggyError> {
+        let consumer_offset = client
+            .get_consumer_offset(&GetConsumerOffset {
+                consumer: Consumer::from_consumer(self),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+            })
+            .await?;
+        let end_offset = client
+            .get_partition_end_offsets(&GetPartitionEndOffsets {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            })
+            .await?
+            .into_iter()
+            .find(|end_offset| end_offset.partition_id == consumer_offset.partition_id)
+            .map(|end_offset| end_offset.end_offset)
+            .unwrap_or_default();
+        Ok(end_offset.saturating_sub(consumer_offset.stored_offset + 1))
+    }
 }
 
 impl BytesSerializable for Consumer {
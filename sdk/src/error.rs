@@ -1,5 +1,6 @@
 use strum::{EnumDiscriminants, FromRepr, IntoStaticStr};
 use thiserror::Error;
+use uuid::Uuid;
 
 #[derive(Debug, Error, EnumDiscriminants, IntoStaticStr)]
 #[repr(u32)]
@@ -20,12 +21,26 @@ pub enum IggyError {
     InvalidFormat = 4,
     #[error("Feature is unavailable")]
     FeatureUnavailable = 5,
+    #[error("Too many in-flight commands, the limit for the connection is: {0}")]
+    TooManyInFlightCommands(u32) = 6,
+    #[error("Request size: {0} bytes exceeds the available buffer of the connection: {1} bytes")]
+    BufferedSizeLimitExceeded(u32, u32) = 7,
+    #[error("Invalid identifier path: {0}. Reason: {1}")]
+    InvalidIdentifierPath(String, String) = 8,
     #[error("Cannot create base directory, Path: {0}")]
     CannotCreateBaseDirectory(String) = 10,
     #[error("Cannot create runtime directory, Path: {0}")]
     CannotCreateRuntimeDirectory(String) = 11,
     #[error("Cannot remove runtime directory, Path: {0}")]
     CannotRemoveRuntimeDirectory(String) = 12,
+    #[error("Unsupported storage format version: {0}, the latest supported version: {1}")]
+    UnsupportedStorageFormat(u32, u32) = 13,
+    #[error("Storage format version: {0} must be migrated to version: {1}")]
+    StorageMigrationRequired(u32, u32) = 14,
+    #[error("Cannot apply storage migration to version: {0}. Reason: {1}")]
+    CannotApplyStorageMigration(u32, String) = 15,
+    #[error("Invalid bootstrap definition: {0}")]
+    InvalidBootstrapDefinition(String) = 16,
     #[error("Resource with key: {0} was not found.")]
     ResourceNotFound(String) = 20,
     #[error("Cannot load resource. Reason: {0:#}")]
@@ -68,6 +83,16 @@ pub enum IggyError {
     InvalidPersonalAccessToken = 53,
     #[error("Personal access token: {0} for user with ID: {1} has expired.")]
     PersonalAccessTokenExpired(String, u32) = 54,
+    #[error("Address: {0} is not allowed")]
+    AddressNotAllowed(String) = 55,
+    #[error("Invalid permission action")]
+    InvalidPermissionAction = 56,
+    #[error("Permission action: {0} requires the resource: {1}")]
+    PermissionActionResourceMissing(String, String) = 57,
+    #[error("Invalid user max inactive days")]
+    InvalidUserMaxInactiveDays = 58,
+    #[error("Cannot set the expiry for user with ID: {0}")]
+    CannotSetUserExpiry(u32) = 59,
     #[error("Not connected")]
     NotConnected = 61,
     #[error("Request error")]
@@ -92,6 +117,12 @@ pub enum IggyError {
     InvalidRefreshToken = 78,
     #[error("Refresh token expired")]
     RefreshTokenExpired = 79,
+    #[error("Cannot compress data using algorithm: {0}")]
+    CannotCompressData(String) = 80,
+    #[error("Cannot decompress data using algorithm: {0}")]
+    CannotDecompressData(String) = 81,
+    #[error("Unsupported protocol version: {0}")]
+    UnsupportedProtocolVersion(u32) = 82,
     #[error("Client with ID: {0} was not found.")]
     ClientNotFound(u32) = 100,
     #[error("Invalid client ID")]
@@ -160,6 +191,14 @@ pub enum IggyError {
     InvalidStreamId = 1014,
     #[error("Cannot read streams")]
     CannotReadStreams = 1015,
+    #[error("Cannot create stream, the limit of {0} streams has been reached.")]
+    StreamsLimitReached(u32) = 1016,
+    #[error("Stream with UUID: {0} was not found.")]
+    StreamUuidNotFound(Uuid) = 1017,
+    #[error("Trashed stream with ID: {0} was not found.")]
+    TrashedStreamNotFound(String) = 1018,
+    #[error("Cannot move stream with ID: {0} to or from the trash, Path: {1}")]
+    CannotMoveStreamToTrash(u32, String) = 1019,
     #[error("Cannot create topics directory for stream with ID: {0}, Path: {1}")]
     CannotCreateTopicsDirectory(u32, String) = 2000,
     #[error(
@@ -200,6 +239,28 @@ pub enum IggyError {
     CannotReadTopics(u32) = 2017,
     #[error("Invalid replication factor")]
     InvalidReplicationFactor = 2018,
+    #[error("Invalid message schema. Reason: {0}")]
+    InvalidMessageSchema(String) = 2019,
+    #[error("Cannot create topic for stream with ID: {0}, the limit of {1} topics per stream has been reached.")]
+    TopicsLimitReached(u32, u32) = 2020,
+    #[error("Invalid timestamp type")]
+    InvalidTimestampType = 2021,
+    #[error("Invalid delete partitions mode")]
+    InvalidDeletePartitionsMode = 2022,
+    #[error("Topic with UUID: {0} for stream with ID: {1} was not found.")]
+    TopicUuidNotFound(Uuid, u32) = 2023,
+    #[error("Trashed topic with ID: {0} for stream with ID: {1} was not found.")]
+    TrashedTopicNotFound(String, u32) = 2024,
+    #[error(
+        "Cannot move topic with ID: {0} for stream with ID: {1} to or from the trash, Path: {2}"
+    )]
+    CannotMoveTopicToTrash(u32, u32, String) = 2025,
+    #[error(
+        "Topic with ID: {0} for stream with ID: {1} is frozen, the messages cannot be sent to it."
+    )]
+    TopicFrozen(u32, u32) = 2026,
+    #[error("Invalid consumer limits, the limit must be greater than 0 if set")]
+    InvalidConsumerLimits = 2027,
     #[error("Cannot create partition with ID: {0} for stream with ID: {1} and topic with ID: {2}")]
     CannotCreatePartition(u32, u32, u32) = 3000,
     #[error(
@@ -224,6 +285,10 @@ pub enum IggyError {
     PartitionNotFound(u32, u32, u32) = 3007,
     #[error("Topic with ID: {0} for stream with ID: {1} has no partitions.")]
     NoPartitions(u32, u32) = 3008,
+    #[error("Cannot create partitions, the limit of {0} partitions per topic has been reached.")]
+    PartitionsLimitReached(u32) = 3009,
+    #[error("Cannot delete partition with ID: {0}, it contains {1} messages.")]
+    PartitionNotEmpty(u32, u64) = 3010,
     #[error("Segment not found")]
     SegmentNotFound = 4000,
     #[error("Segment with start offset: {0} and partition with ID: {1} is closed")]
@@ -282,6 +347,42 @@ pub enum IggyError {
     InvalidMessageChecksum(u32, u32, u64) = 4027,
     #[error("Invalid key value length")]
     InvalidKeyValueLength = 4028,
+    #[error("Message checksum mismatch for offset: {0}, calculated: {1}, expected: {2}")]
+    MessageChecksumMismatch(u64, u32, u32) = 4029,
+    #[error("Message with ID: {0} doesn't match the topic message schema. Reason: {1}")]
+    MessageSchemaMismatch(u128, String) = 4030,
+    #[error("Cannot decode message payload. Reason: {0}")]
+    CannotDecodeMessagePayload(String) = 4031,
+    #[error("Invalid headers filter. Reason: {0}")]
+    InvalidHeadersFilter(String) = 4032,
+    #[error("Invalid messages query. Reason: {0}")]
+    InvalidMessagesQuery(String) = 4033,
+    #[error("Invalid polling mode. Reason: {0}")]
+    InvalidPollingMode(String) = 4034,
+    #[error("Cannot create segment for partition with ID: {0}, the limit of {1} segments per partition has been reached.")]
+    SegmentsLimitReached(u32, u32) = 4035,
+    #[error("Invalid max wait time for polling messages, max value is: {0} ms.")]
+    InvalidPollingMaxWait(u32) = 4036,
+    #[error("Cannot read messages batch")]
+    CannotReadMessagesBatch = 4037,
+    #[error("Unsupported messages batch format version: {0}")]
+    UnsupportedMessagesBatchVersion(u8) = 4038,
+    #[error("Invalid create timestamp header for message with ID: {0}")]
+    InvalidCreateTimestamp(u128) = 4039,
+    #[error("Invalid fetch session. Reason: {0}")]
+    InvalidFetchSession(String) = 4040,
+    #[error("Fetch session with ID: {0} was not found.")]
+    FetchSessionNotFound(u32) = 4041,
+    #[error("Invalid epoch: {0} for fetch session with ID: {1}, expected: {2}")]
+    InvalidFetchSessionEpoch(u32, u32, u32) = 4042,
+    #[error("Cannot create fetch session, the limit of {0} sessions per client has been reached.")]
+    FetchSessionsLimitReached(u32) = 4043,
+    #[error("Insufficient disk space: {0} bytes available, at least: {1} bytes are required")]
+    InsufficientDiskSpace(u64, u64) = 4044,
+    #[error("Invalid max bytes for polling messages, it must be greater than 0.")]
+    InvalidPollingMaxBytes = 4045,
+    #[error("Message at index: {0} has payload of size: {1} bytes, which exceeds the topic max message size: {2} bytes.")]
+    MessageTooLarge(u32, u64, u64) = 4046,
     #[error("Invalid offset: {0}")]
     InvalidOffset(u64) = 4100,
     #[error("Failed to read consumers offsets for partition with ID: {0}")]
@@ -304,6 +405,68 @@ pub enum IggyError {
     CannotCreateConsumerGroupInfo(u32, u32, u32) = 5007,
     #[error("Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}.")]
     CannotDeleteConsumerGroupInfo(u32, u32, u32) = 5008,
+    #[error("Cannot create consumer group for topic with ID: {0} for stream with ID: {1}, the limit of {2} consumer groups per topic has been reached.")]
+    ConsumerGroupsLimitReached(u32, u32, u32) = 5009,
+    #[error("Cannot join consumer group with ID: {0} for topic with ID: {1}, the limit of {2} members per consumer group has been reached.")]
+    ConsumerGroupMembersLimitReached(u32, u32, u32) = 5010,
+    #[error("Invalid schema subject")]
+    InvalidSchemaSubject = 6000,
+    #[error("Invalid schema. Reason: {0}")]
+    InvalidSchema(String) = 6001,
+    #[error("Invalid schema compatibility")]
+    InvalidSchemaCompatibility = 6002,
+    #[error("Schema with ID: {0} was not found.")]
+    SchemaIdNotFound(u32) = 6003,
+    #[error("Schema subject: {0} was not found.")]
+    SchemaSubjectNotFound(String) = 6004,
+    #[error("Schema is not compatible with the latest version for subject: {0}. Reason: {1}")]
+    IncompatibleSchema(String, String) = 6005,
+    #[error("Invalid schema format")]
+    InvalidSchemaFormat = 6006,
+    #[error("Invalid tenant ID")]
+    InvalidTenantId = 7000,
+    #[error("Invalid tenant name")]
+    InvalidTenantName = 7001,
+    #[error("Tenant with ID: {0} was not found.")]
+    TenantIdNotFound(u32) = 7002,
+    #[error("Tenant with name: {0} was not found.")]
+    TenantNameNotFound(String) = 7003,
+    #[error("Tenant with ID: {0} already exists.")]
+    TenantIdAlreadyExists(u32) = 7004,
+    #[error("Tenant with name: {0} already exists.")]
+    TenantNameAlreadyExists(String) = 7005,
+    #[error("Cannot delete tenant with ID: {0}, it still owns streams or has users assigned.")]
+    CannotDeleteTenant(u32) = 7006,
+    #[error("Quota of {1} for tenant with ID: {0} has been exceeded.")]
+    TenantQuotaExceeded(u32, String) = 7007,
+    #[error("Cannot assign user with ID: {0} to tenant with ID: {1}.")]
+    CannotAssignTenantUser(u32, u32) = 7008,
+    #[error("User with ID: {0} is not assigned to tenant with ID: {1}.")]
+    TenantUserNotFound(u32, u32) = 7009,
+    #[error("Invalid connector name")]
+    InvalidConnectorName = 8000,
+    #[error("Invalid connector configuration. Reason: {0}")]
+    InvalidConnectorConfig(String) = 8001,
+    #[error("Connector with name: {0} was not found.")]
+    ConnectorNotFound(String) = 8002,
+    #[error("Connector with name: {0} already exists.")]
+    ConnectorAlreadyExists(String) = 8003,
+    #[error("Invalid user group ID")]
+    InvalidUserGroupId = 9000,
+    #[error("Invalid user group name")]
+    InvalidUserGroupName = 9001,
+    #[error("User group with ID: {0} was not found.")]
+    UserGroupIdNotFound(u32) = 9002,
+    #[error("User group with name: {0} was not found.")]
+    UserGroupNameNotFound(String) = 9003,
+    #[error("User group with ID: {0} already exists.")]
+    UserGroupIdAlreadyExists(u32) = 9004,
+    #[error("User group with name: {0} already exists.")]
+    UserGroupNameAlreadyExists(String) = 9005,
+    #[error("User with ID: {0} is already a member of user group with ID: {1}.")]
+    UserGroupMemberAlreadyExists(u32, u32) = 9006,
+    #[error("User with ID: {0} is not a member of user group with ID: {1}.")]
+    UserGroupMemberNotFound(u32, u32) = 9007,
 }
 
 impl IggyError {
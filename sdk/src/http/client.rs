@@ -14,6 +14,7 @@ const UNAUTHORIZED_PATHS: &[&str] = &[
     "/",
     "/metrics",
     "/ping",
+    "/hello",
     "/users/login",
     "/users/refresh-token",
     "/personal-access-tokens/login",
@@ -64,7 +65,12 @@ impl HttpClient {
         if api_url.is_err() {
             return Err(IggyError::CannotParseUrl);
         }
-        let api_url = api_url.unwrap();
+        let mut api_url = api_url.unwrap();
+        // The API can be served under a path prefix (e.g. behind a reverse proxy), which is kept when the paths are joined.
+        if !api_url.path().ends_with('/') {
+            let path = format!("{}/", api_url.path());
+            api_url.set_path(&path);
+        }
         let retry_policy = ExponentialBackoff::builder().build_with_max_retries(config.retries);
         let client = ClientBuilder::new(reqwest::Client::new())
             .with(RetryTransientMiddleware::new_with_policy(retry_policy))
@@ -172,10 +178,10 @@ impl HttpClient {
         Self::handle_response(response).await
     }
 
-    /// Get full URL for the provided path.
+    /// Get full URL for the provided path, relative to the API URL.
     pub fn get_url(&self, path: &str) -> Result<Url, IggyError> {
         self.api_url
-            .join(path)
+            .join(path.trim_start_matches('/'))
             .map_err(|_| IggyError::CannotParseUrl)
     }
 
@@ -229,6 +235,16 @@ impl HttpClient {
         self.refresh_access_token(&refresh_token).await
     }
 
+    /// Revoke the current refresh token, if any.
+    pub async fn revoke_current_refresh_token(&self) -> Result<(), IggyError> {
+        let refresh_token = self.refresh_token.read().await.clone();
+        if refresh_token.is_empty() {
+            return Ok(());
+        }
+
+        self.revoke_refresh_token(&refresh_token).await
+    }
+
     async fn handle_response(response: Response) -> Result<Response, IggyError> {
         match response.status().is_success() {
             true => Ok(response),
@@ -249,3 +265,29 @@ impl HttpClient {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_should_be_relative_to_api_url() {
+        let client = HttpClient::new("http://localhost:3000").unwrap();
+        assert_eq!(
+            client.get_url("/streams").unwrap().as_str(),
+            "http://localhost:3000/streams"
+        );
+
+        let client = HttpClient::new("http://localhost/iggy").unwrap();
+        assert_eq!(
+            client.get_url("/streams/1").unwrap().as_str(),
+            "http://localhost/iggy/streams/1"
+        );
+
+        let client = HttpClient::new("http://localhost/iggy/").unwrap();
+        assert_eq!(
+            client.get_url("/users/login").unwrap().as_str(),
+            "http://localhost/iggy/users/login"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use crate::client::ConnectorClient;
+use crate::connectors::create_connector::CreateConnector;
+use crate::connectors::delete_connector::DeleteConnector;
+use crate::connectors::get_connector::GetConnector;
+use crate::connectors::get_connectors::GetConnectors;
+use crate::connectors::restart_connector::RestartConnector;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::models::connector::ConnectorInfo;
+use async_trait::async_trait;
+
+const PATH: &str = "/connectors";
+
+#[async_trait]
+impl ConnectorClient for HttpClient {
+    async fn get_connector(&self, command: &GetConnector) -> Result<ConnectorInfo, IggyError> {
+        let response = self.get(&get_details_path(&command.name)).await?;
+        let connector = response.json().await?;
+        Ok(connector)
+    }
+
+    async fn get_connectors(
+        &self,
+        _command: &GetConnectors,
+    ) -> Result<Vec<ConnectorInfo>, IggyError> {
+        let response = self.get(PATH).await?;
+        let connectors = response.json().await?;
+        Ok(connectors)
+    }
+
+    async fn create_connector(&self, command: &CreateConnector) -> Result<(), IggyError> {
+        self.post(PATH, &command).await?;
+        Ok(())
+    }
+
+    async fn restart_connector(&self, command: &RestartConnector) -> Result<(), IggyError> {
+        self.post(
+            &format!("{}/restart", get_details_path(&command.name)),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_connector(&self, command: &DeleteConnector) -> Result<(), IggyError> {
+        self.delete(&get_details_path(&command.name)).await?;
+        Ok(())
+    }
+}
+
+fn get_details_path(name: &str) -> String {
+    format!("{PATH}/{name}")
+}
@@ -1,11 +1,19 @@
 use crate::client::MessageClient;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
+use crate::messages::create_fetch_session::CreateFetchSession;
+use crate::messages::delete_fetch_session::DeleteFetchSession;
+use crate::messages::fetch_messages::FetchMessages;
 use crate::messages::poll_messages::PollMessages;
-use crate::messages::send_messages::SendMessages;
-use crate::models::messages::PolledMessages;
+use crate::messages::query_messages::QueryMessages;
+use crate::messages::send_messages::{Acks, SendMessages};
+use crate::models::fetch_session::{FetchSession, FetchedMessages};
+use crate::models::messages::{PolledMessages, SendMessagesReceipt};
+use crate::models::query_result::QueryResult;
 use async_trait::async_trait;
 
+const QUERY_PATH: &str = "/query";
+
 #[async_trait]
 impl MessageClient for HttpClient {
     async fn poll_messages(&self, command: &PollMessages) -> Result<PolledMessages, IggyError> {
@@ -33,6 +41,54 @@ impl MessageClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn send_messages_with_receipt(
+        &self,
+        command: &mut SendMessages,
+    ) -> Result<SendMessagesReceipt, IggyError> {
+        if command.acks == Some(Acks::None) {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let response = self
+            .post(
+                &get_path(
+                    &command.stream_id.as_cow_str(),
+                    &command.topic_id.as_cow_str(),
+                ),
+                &command,
+            )
+            .await?;
+        let body = response.bytes().await?;
+        if body.is_empty() {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let receipt = serde_json::from_slice(&body)
+            .map_err(|error| IggyError::CannotDeserializeResource(error.into()))?;
+        Ok(receipt)
+    }
+
+    async fn query_messages(&self, command: &QueryMessages) -> Result<QueryResult, IggyError> {
+        let response = self.post(QUERY_PATH, &command).await?;
+        let result = response.json().await?;
+        Ok(result)
+    }
+
+    async fn create_fetch_session(
+        &self,
+        _command: &CreateFetchSession,
+    ) -> Result<FetchSession, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn fetch_messages(&self, _command: &FetchMessages) -> Result<FetchedMessages, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn delete_fetch_session(&self, _command: &DeleteFetchSession) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
@@ -1,12 +1,50 @@
 use crate::client::PartitionClient;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
+use crate::models::partition::{PartitionDetails, PartitionEndOffset};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_partition_details::GetPartitionDetails;
+use crate::partitions::get_partition_end_offsets::GetPartitionEndOffsets;
 use async_trait::async_trait;
 
 #[async_trait]
 impl PartitionClient for HttpClient {
+    async fn get_partition_details(
+        &self,
+        command: &GetPartitionDetails,
+    ) -> Result<PartitionDetails, IggyError> {
+        let response = self
+            .get(&format!(
+                "{}/{}",
+                get_path(
+                    &command.stream_id.as_cow_str(),
+                    &command.topic_id.as_cow_str(),
+                ),
+                command.partition_id
+            ))
+            .await?;
+        let partition = response.json().await?;
+        Ok(partition)
+    }
+
+    async fn get_partition_end_offsets(
+        &self,
+        command: &GetPartitionEndOffsets,
+    ) -> Result<Vec<PartitionEndOffset>, IggyError> {
+        let response = self
+            .get(&format!(
+                "{}/end-offsets",
+                get_path(
+                    &command.stream_id.as_cow_str(),
+                    &command.topic_id.as_cow_str(),
+                )
+            ))
+            .await?;
+        let end_offsets = response.json().await?;
+        Ok(end_offsets)
+    }
+
     async fn create_partitions(&self, command: &CreatePartitions) -> Result<(), IggyError> {
         self.post(
             &get_path(
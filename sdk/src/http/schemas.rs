@@ -0,0 +1,53 @@
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::models::schema::SchemaInfo;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schema_versions::GetSchemaVersions;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use async_trait::async_trait;
+
+const PATH: &str = "/schemas";
+
+#[async_trait]
+impl SchemaClient for HttpClient {
+    async fn get_schema(&self, command: &GetSchema) -> Result<SchemaInfo, IggyError> {
+        let response = self.get(&format!("{PATH}/{}", command.schema_id)).await?;
+        let schema = response.json().await?;
+        Ok(schema)
+    }
+
+    async fn get_schema_versions(
+        &self,
+        command: &GetSchemaVersions,
+    ) -> Result<Vec<SchemaInfo>, IggyError> {
+        let response = self.get(&get_versions_path(&command.subject)).await?;
+        let schemas = response.json().await?;
+        Ok(schemas)
+    }
+
+    async fn register_schema(&self, command: &RegisterSchema) -> Result<SchemaInfo, IggyError> {
+        let response = self
+            .post(&get_versions_path(&command.subject), &command)
+            .await?;
+        let schema = response.json().await?;
+        Ok(schema)
+    }
+
+    async fn update_schema_compatibility(
+        &self,
+        command: &UpdateSchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!("{PATH}/subjects/{}/compatibility", command.subject),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+fn get_versions_path(subject: &str) -> String {
+    format!("{PATH}/subjects/{subject}/versions")
+}
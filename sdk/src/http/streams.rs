@@ -7,6 +7,7 @@ use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::purge_stream::PurgeStream;
+use crate::streams::undelete_stream::UndeleteStream;
 use crate::streams::update_stream::UpdateStream;
 use async_trait::async_trait;
 
@@ -41,7 +42,13 @@ impl StreamClient for HttpClient {
 
     async fn delete_stream(&self, command: &DeleteStream) -> Result<(), IggyError> {
         let path = format!("{}/{}", PATH, command.stream_id.as_cow_str());
-        self.delete(&path).await?;
+        self.delete_with_query(&path, &command).await?;
+        Ok(())
+    }
+
+    async fn undelete_stream(&self, command: &UndeleteStream) -> Result<(), IggyError> {
+        let path = format!("{}/{}/undelete", PATH, command.stream_id.as_cow_str());
+        self.post(&path, &command).await?;
         Ok(())
     }
 
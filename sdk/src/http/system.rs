@@ -1,18 +1,30 @@
 use crate::client::SystemClient;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
+use crate::models::batch_result::BatchCommandResult;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::hello_info::HelloInfo;
+use crate::models::metadata::Metadata;
 use crate::models::stats::Stats;
+use crate::system::batch::Batch;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_metadata::GetMetadata;
 use crate::system::get_stats::GetStats;
+use crate::system::heartbeat::Heartbeat;
+use crate::system::hello::Hello;
+use crate::system::negotiate_compression::NegotiateCompression;
 use crate::system::ping::Ping;
 use async_trait::async_trait;
 
 const PING: &str = "/ping";
+const COMPRESSION: &str = "/compression";
+const HELLO: &str = "/hello";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
+const METADATA: &str = "/metadata";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -22,6 +34,12 @@ impl SystemClient for HttpClient {
         Ok(stats)
     }
 
+    async fn get_metadata(&self, _command: &GetMetadata) -> Result<Metadata, IggyError> {
+        let response = self.get(METADATA).await?;
+        let metadata = response.json().await?;
+        Ok(metadata)
+    }
+
     async fn get_me(&self, _command: &GetMe) -> Result<ClientInfoDetails, IggyError> {
         Err(IggyError::FeatureUnavailable)
     }
@@ -43,4 +61,27 @@ impl SystemClient for HttpClient {
         self.get(PING).await?;
         Ok(())
     }
+
+    async fn heartbeat(&self, _command: &Heartbeat) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn hello(&self, command: &Hello) -> Result<HelloInfo, IggyError> {
+        let response = self.post(HELLO, &command).await?;
+        let hello_info = response.json().await?;
+        Ok(hello_info)
+    }
+
+    async fn batch(&self, _command: &Batch) -> Result<Vec<BatchCommandResult>, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn negotiate_compression(
+        &self,
+        command: &NegotiateCompression,
+    ) -> Result<CompressionAlgorithm, IggyError> {
+        let response = self.post(COMPRESSION, &command).await?;
+        let algorithm = response.json().await?;
+        Ok(algorithm)
+    }
 }
@@ -0,0 +1,77 @@
+use crate::client::TenantClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::models::tenant::Tenant;
+use crate::tenants::add_tenant_user::AddTenantUser;
+use crate::tenants::create_tenant::CreateTenant;
+use crate::tenants::delete_tenant::DeleteTenant;
+use crate::tenants::get_tenant::GetTenant;
+use crate::tenants::get_tenants::GetTenants;
+use crate::tenants::remove_tenant_user::RemoveTenantUser;
+use crate::tenants::update_tenant::UpdateTenant;
+use async_trait::async_trait;
+
+const PATH: &str = "/tenants";
+
+#[async_trait]
+impl TenantClient for HttpClient {
+    async fn get_tenant(&self, command: &GetTenant) -> Result<Tenant, IggyError> {
+        let response = self
+            .get(&get_details_path(&command.tenant_id.as_cow_str()))
+            .await?;
+        let tenant = response.json().await?;
+        Ok(tenant)
+    }
+
+    async fn get_tenants(&self, _command: &GetTenants) -> Result<Vec<Tenant>, IggyError> {
+        let response = self.get(PATH).await?;
+        let tenants = response.json().await?;
+        Ok(tenants)
+    }
+
+    async fn create_tenant(&self, command: &CreateTenant) -> Result<(), IggyError> {
+        self.post(PATH, &command).await?;
+        Ok(())
+    }
+
+    async fn update_tenant(&self, command: &UpdateTenant) -> Result<(), IggyError> {
+        self.put(&get_details_path(&command.tenant_id.as_cow_str()), command)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_tenant(&self, command: &DeleteTenant) -> Result<(), IggyError> {
+        self.delete(&get_details_path(&command.tenant_id.as_cow_str()))
+            .await?;
+        Ok(())
+    }
+
+    async fn add_tenant_user(&self, command: &AddTenantUser) -> Result<(), IggyError> {
+        self.post(
+            &get_user_path(
+                &command.tenant_id.as_cow_str(),
+                &command.user_id.as_cow_str(),
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_tenant_user(&self, command: &RemoveTenantUser) -> Result<(), IggyError> {
+        self.delete(&get_user_path(
+            &command.tenant_id.as_cow_str(),
+            &command.user_id.as_cow_str(),
+        ))
+        .await?;
+        Ok(())
+    }
+}
+
+fn get_details_path(tenant_id: &str) -> String {
+    format!("{PATH}/{tenant_id}")
+}
+
+fn get_user_path(tenant_id: &str, user_id: &str) -> String {
+    format!("{PATH}/{tenant_id}/users/{user_id}")
+}
@@ -4,9 +4,12 @@ use crate::http::client::HttpClient;
 use crate::models::topic::{Topic, TopicDetails};
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
+use crate::topics::freeze_topic::FreezeTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::undelete_topic::UndeleteTopic;
+use crate::topics::unfreeze_topic::UnfreezeTopic;
 use crate::topics::update_topic::UpdateTopic;
 use async_trait::async_trait;
 
@@ -49,10 +52,28 @@ impl TopicClient for HttpClient {
     }
 
     async fn delete_topic(&self, command: &DeleteTopic) -> Result<(), IggyError> {
-        self.delete(&get_details_path(
-            &command.stream_id.as_cow_str(),
-            &command.topic_id.as_cow_str(),
-        ))
+        self.delete_with_query(
+            &get_details_path(
+                &command.stream_id.as_cow_str(),
+                &command.topic_id.as_cow_str(),
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn undelete_topic(&self, command: &UndeleteTopic) -> Result<(), IggyError> {
+        self.post(
+            &format!(
+                "{}/undelete",
+                &get_details_path(
+                    &command.stream_id.as_cow_str(),
+                    &command.topic_id.as_cow_str(),
+                )
+            ),
+            &command,
+        )
         .await?;
         Ok(())
     }
@@ -68,6 +89,36 @@ impl TopicClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn freeze_topic(&self, command: &FreezeTopic) -> Result<(), IggyError> {
+        self.post(
+            &format!(
+                "{}/freeze",
+                &get_details_path(
+                    &command.stream_id.as_cow_str(),
+                    &command.topic_id.as_cow_str(),
+                )
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn unfreeze_topic(&self, command: &UnfreezeTopic) -> Result<(), IggyError> {
+        self.post(
+            &format!(
+                "{}/unfreeze",
+                &get_details_path(
+                    &command.stream_id.as_cow_str(),
+                    &command.topic_id.as_cow_str(),
+                )
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_path(stream_id: &str) -> String {
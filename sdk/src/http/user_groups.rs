@@ -0,0 +1,89 @@
+use crate::client::UserGroupClient;
+use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::models::user_group::{UserGroup, UserGroupDetails};
+use crate::user_groups::add_user_group_member::AddUserGroupMember;
+use crate::user_groups::create_user_group::CreateUserGroup;
+use crate::user_groups::delete_user_group::DeleteUserGroup;
+use crate::user_groups::get_user_group::GetUserGroup;
+use crate::user_groups::get_user_groups::GetUserGroups;
+use crate::user_groups::remove_user_group_member::RemoveUserGroupMember;
+use crate::user_groups::update_user_group_permissions::UpdateUserGroupPermissions;
+use async_trait::async_trait;
+
+const PATH: &str = "/user-groups";
+
+#[async_trait]
+impl UserGroupClient for HttpClient {
+    async fn get_user_group(&self, command: &GetUserGroup) -> Result<UserGroupDetails, IggyError> {
+        let response = self
+            .get(&get_details_path(&command.group_id.as_cow_str()))
+            .await?;
+        let group = response.json().await?;
+        Ok(group)
+    }
+
+    async fn get_user_groups(&self, _command: &GetUserGroups) -> Result<Vec<UserGroup>, IggyError> {
+        let response = self.get(PATH).await?;
+        let groups = response.json().await?;
+        Ok(groups)
+    }
+
+    async fn create_user_group(&self, command: &CreateUserGroup) -> Result<(), IggyError> {
+        self.post(PATH, &command).await?;
+        Ok(())
+    }
+
+    async fn update_user_group_permissions(
+        &self,
+        command: &UpdateUserGroupPermissions,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/permissions",
+                get_details_path(&command.group_id.as_cow_str())
+            ),
+            command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_user_group(&self, command: &DeleteUserGroup) -> Result<(), IggyError> {
+        self.delete(&get_details_path(&command.group_id.as_cow_str()))
+            .await?;
+        Ok(())
+    }
+
+    async fn add_user_group_member(&self, command: &AddUserGroupMember) -> Result<(), IggyError> {
+        self.post(
+            &get_member_path(
+                &command.group_id.as_cow_str(),
+                &command.user_id.as_cow_str(),
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_user_group_member(
+        &self,
+        command: &RemoveUserGroupMember,
+    ) -> Result<(), IggyError> {
+        self.delete(&get_member_path(
+            &command.group_id.as_cow_str(),
+            &command.user_id.as_cow_str(),
+        ))
+        .await?;
+        Ok(())
+    }
+}
+
+fn get_details_path(group_id: &str) -> String {
+    format!("{PATH}/{group_id}")
+}
+
+fn get_member_path(group_id: &str, user_id: &str) -> String {
+    format!("{PATH}/{group_id}/members/{user_id}")
+}
@@ -2,8 +2,10 @@ use crate::client::UserClient;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::models::identity_info::IdentityInfo;
+use crate::models::permission_check::PermissionCheck;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::users::change_password::ChangePassword;
+use crate::users::check_permission::CheckPermission;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
 use crate::users::get_user::GetUser;
@@ -12,6 +14,7 @@ use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
 use crate::users::update_permissions::UpdatePermissions;
 use crate::users::update_user::UpdateUser;
+use crate::users::update_user_expiry::UpdateUserExpiry;
 use async_trait::async_trait;
 use serde::Serialize;
 
@@ -53,6 +56,12 @@ impl UserClient for HttpClient {
         Ok(())
     }
 
+    async fn update_user_expiry(&self, command: &UpdateUserExpiry) -> Result<(), IggyError> {
+        self.put(&format!("{PATH}/{}/expiry", command.user_id), &command)
+            .await?;
+        Ok(())
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), IggyError> {
         self.put(&format!("{PATH}/{}/password", command.user_id), &command)
             .await?;
@@ -68,10 +77,25 @@ impl UserClient for HttpClient {
 
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), IggyError> {
         self.post(&format!("{PATH}/logout"), &command).await?;
+        self.revoke_current_refresh_token().await?;
         self.set_access_token(None).await;
         self.set_refresh_token(None).await;
         Ok(())
     }
+
+    async fn check_permission(
+        &self,
+        command: &CheckPermission,
+    ) -> Result<PermissionCheck, IggyError> {
+        let response = self
+            .get_with_query(
+                &format!("{PATH}/{}/permissions/check", command.user_id),
+                &command,
+            )
+            .await?;
+        let permission_check = response.json().await?;
+        Ok(permission_check)
+    }
 }
 
 impl HttpClient {
@@ -94,6 +118,20 @@ impl HttpClient {
         self.set_tokens_from_identity(&identity_info).await?;
         Ok(())
     }
+
+    /// Revokes the refresh token along with all the tokens issued by rotating it.
+    /// The access token of the token owner is still required, but it may have already expired or been logged out.
+    pub async fn revoke_refresh_token(&self, refresh_token: &str) -> Result<(), IggyError> {
+        if refresh_token.is_empty() {
+            return Err(IggyError::RefreshTokenMissing);
+        }
+
+        let command = RefreshToken {
+            refresh_token: refresh_token.to_string(),
+        };
+        self.post(&format!("{PATH}/revoke-token"), &command).await?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize)]
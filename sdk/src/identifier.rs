@@ -8,6 +8,7 @@ use serde_with::serde_as;
 use std::borrow::Cow;
 use std::fmt::Display;
 use std::str::FromStr;
+use uuid::Uuid;
 
 /// `Identifier` represents the unique identifier of the resources such as stream, topic, partition, user etc.
 /// It consists of the following fields:
@@ -36,6 +37,8 @@ pub enum IdKind {
     Numeric,
     /// The identifier is string.
     String,
+    /// The identifier is the immutable UUID of the resource, which is kept across renames.
+    Uuid,
 }
 
 impl Default for Identifier {
@@ -67,6 +70,10 @@ impl Validatable<IggyError> for Identifier {
             return Err(IggyError::InvalidCommand);
         }
 
+        if self.kind == IdKind::Uuid && self.length != 16 {
+            return Err(IggyError::InvalidCommand);
+        }
+
         Ok(())
     }
 }
@@ -99,6 +106,15 @@ impl Identifier {
         Ok(String::from_utf8_lossy(&self.value))
     }
 
+    /// Returns the UUID value of the identifier.
+    pub fn get_uuid_value(&self) -> Result<Uuid, IggyError> {
+        if self.kind != IdKind::Uuid {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Uuid::from_slice(&self.value).map_err(|_| IggyError::InvalidCommand)
+    }
+
     /// Returns the string representation of the identifier.
     pub fn as_string(&self) -> String {
         self.as_cow_str().to_string()
@@ -109,6 +125,7 @@ impl Identifier {
         match self.kind {
             IdKind::Numeric => Cow::Owned(self.get_u32_value().unwrap().to_string()),
             IdKind::String => self.get_cow_str_value().unwrap(),
+            IdKind::Uuid => Cow::Owned(self.get_uuid_value().unwrap().to_string()),
         }
     }
 
@@ -126,16 +143,20 @@ impl Identifier {
         }
     }
 
-    /// Creates a new identifier from the given string value, either numeric or string.
+    /// Creates a new identifier from the given string value, either numeric, UUID (in the hyphenated form) or string.
     pub fn from_str_value(value: &str) -> Result<Self, IggyError> {
         let length = value.len();
         if length == 0 || length > 255 {
             return Err(IggyError::InvalidCommand);
         }
 
-        match value.parse::<u32>() {
-            Ok(id) => Identifier::numeric(id),
-            Err(_) => Identifier::named(value),
+        if let Ok(id) = value.parse::<u32>() {
+            return Identifier::numeric(id);
+        }
+
+        match parse_hyphenated_uuid(value) {
+            Some(uuid) => Identifier::uuid(uuid),
+            None => Identifier::named(value),
         }
     }
 
@@ -152,6 +173,19 @@ impl Identifier {
         })
     }
 
+    /// Creates a new identifier from the given UUID value.
+    pub fn uuid(value: Uuid) -> Result<Self, IggyError> {
+        if value.is_nil() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(Self {
+            kind: IdKind::Uuid,
+            length: 16,
+            value: value.as_bytes().to_vec(),
+        })
+    }
+
     /// Creates a new identifier from the given string value. The name will be always converted to lowercase and all whitespaces will be replaced with dots.
     pub fn named(value: &str) -> Result<Self, IggyError> {
         let length = value.len();
@@ -208,6 +242,7 @@ impl IdKind {
         match self {
             IdKind::Numeric => 1,
             IdKind::String => 2,
+            IdKind::Uuid => 3,
         }
     }
 
@@ -216,6 +251,7 @@ impl IdKind {
         match code {
             1 => Ok(IdKind::Numeric),
             2 => Ok(IdKind::String),
+            3 => Ok(IdKind::Uuid),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -227,6 +263,7 @@ impl FromStr for IdKind {
         match input {
             "n" | "numeric" => Ok(IdKind::Numeric),
             "s" | "string" => Ok(IdKind::String),
+            "u" | "uuid" => Ok(IdKind::Uuid),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -239,6 +276,10 @@ impl FromStr for Identifier {
             return Identifier::numeric(value);
         }
 
+        if let Some(uuid) = parse_hyphenated_uuid(input) {
+            return Identifier::uuid(uuid);
+        }
+
         let identifier = Identifier::named(input)?;
         identifier.validate()?;
         Ok(identifier)
@@ -254,6 +295,7 @@ impl Display for Identifier {
                 u32::from_le_bytes(self.value.as_slice().try_into().unwrap())
             ),
             IdKind::String => write!(f, "{}", String::from_utf8_lossy(&self.value)),
+            IdKind::Uuid => write!(f, "{}", Uuid::from_slice(&self.value).unwrap()),
         }
     }
 }
@@ -263,6 +305,238 @@ impl Display for IdKind {
         match self {
             IdKind::Numeric => write!(f, "numeric"),
             IdKind::String => write!(f, "string"),
+            IdKind::Uuid => write!(f, "uuid"),
+        }
+    }
+}
+
+/// `IdentifierPath` represents the stream, topic and partition given as a single path, e.g. `orders/events/3`.
+/// It consists of the following fields:
+/// - `stream_id`: the stream identifier, either numeric, UUID or name.
+/// - `topic_id`: the optional topic identifier, either numeric, UUID or name.
+/// - `partition_id`: the optional partition ID, must be a positive number.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentifierPath {
+    /// The stream identifier.
+    pub stream_id: Identifier,
+    /// The optional topic identifier.
+    pub topic_id: Option<Identifier>,
+    /// The optional partition ID.
+    pub partition_id: Option<u32>,
+}
+
+impl IdentifierPath {
+    /// The maximum number of the path segments: stream, topic and partition.
+    pub const MAX_SEGMENTS: usize = 3;
+
+    /// Creates a new path from the given segments, the first one is the stream, then the topic and the partition.
+    pub fn from_segments<S: AsRef<str>>(segments: &[S]) -> Result<Self, IggyError> {
+        let path = segments
+            .iter()
+            .map(|segment| segment.as_ref())
+            .collect::<Vec<_>>()
+            .join("/");
+        if segments.is_empty() {
+            return Err(IggyError::InvalidIdentifierPath(
+                path,
+                "path is empty, expected format is: stream/topic/partition".to_string(),
+            ));
+        }
+
+        if segments.len() > Self::MAX_SEGMENTS {
+            return Err(IggyError::InvalidIdentifierPath(
+                path,
+                format!(
+                    "too many segments: {}, expected format is: stream/topic/partition",
+                    segments.len()
+                ),
+            ));
+        }
+
+        let segment_error = |name: &str, reason: String| {
+            IggyError::InvalidIdentifierPath(path.clone(), format!("invalid {name}: {reason}"))
+        };
+        let parse_identifier = |name: &str, segment: &str| {
+            if segment.is_empty() {
+                return Err(segment_error(name, "value is empty".to_string()));
+            }
+
+            Identifier::from_str(segment).map_err(|error| segment_error(name, error.to_string()))
+        };
+
+        let stream_id = parse_identifier("stream", segments[0].as_ref())?;
+        let topic_id = match segments.get(1) {
+            Some(segment) => Some(parse_identifier("topic", segment.as_ref())?),
+            None => None,
+        };
+        let partition_id = match segments.get(2) {
+            Some(segment) => match segment.as_ref().parse::<u32>() {
+                Ok(partition_id) if partition_id > 0 => Some(partition_id),
+                _ => {
+                    return Err(segment_error(
+                        "partition",
+                        format!("{} is not a positive number", segment.as_ref()),
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        Ok(Self {
+            stream_id,
+            topic_id,
+            partition_id,
+        })
+    }
+
+    /// Appends the given segments to the path, e.g. the topic and the partition given as the separate arguments.
+    pub fn join<S: AsRef<str>>(&self, segments: &[S]) -> Result<Self, IggyError> {
+        let mut path = self.segments();
+        path.extend(segments.iter().map(|segment| segment.as_ref().to_string()));
+        Self::from_segments(&path)
+    }
+
+    /// Returns the stream and topic identifiers, the path must be in the `stream/topic` format.
+    pub fn topic(&self) -> Result<(Identifier, Identifier), IggyError> {
+        match (&self.topic_id, self.partition_id) {
+            (Some(topic_id), None) => Ok((self.stream_id.clone(), topic_id.clone())),
+            _ => Err(IggyError::InvalidIdentifierPath(
+                self.to_string(),
+                "expected format is: stream/topic".to_string(),
+            )),
+        }
+    }
+
+    /// Returns the stream and topic identifiers and the partition ID, the path must be in the `stream/topic/partition` format.
+    pub fn partition(&self) -> Result<(Identifier, Identifier, u32), IggyError> {
+        match (&self.topic_id, self.partition_id) {
+            (Some(topic_id), Some(partition_id)) => {
+                Ok((self.stream_id.clone(), topic_id.clone(), partition_id))
+            }
+            _ => Err(IggyError::InvalidIdentifierPath(
+                self.to_string(),
+                "expected format is: stream/topic/partition".to_string(),
+            )),
         }
     }
+
+    fn segments(&self) -> Vec<String> {
+        let mut segments = vec![self.stream_id.to_string()];
+        if let Some(topic_id) = &self.topic_id {
+            segments.push(topic_id.to_string());
+        }
+        if let Some(partition_id) = self.partition_id {
+            segments.push(partition_id.to_string());
+        }
+        segments
+    }
+}
+
+impl FromStr for IdentifierPath {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::from_segments(&input.split('/').collect::<Vec<_>>())
+    }
+}
+
+impl Display for IdentifierPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.segments().join("/"))
+    }
+}
+
+/// Only the hyphenated form is treated as the UUID, so that the names which merely consist of 32 hex digits are kept as names.
+fn parse_hyphenated_uuid(value: &str) -> Option<Uuid> {
+    if value.len() != 36 {
+        return None;
+    }
+
+    Uuid::try_parse(value).ok().filter(|uuid| !uuid.is_nil())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_as_uuid_given_hyphenated_uuid() {
+        let uuid = Uuid::new_v4();
+        let identifier = Identifier::from_str(&uuid.to_string()).unwrap();
+        assert_eq!(identifier.kind, IdKind::Uuid);
+        assert_eq!(identifier.get_uuid_value().unwrap(), uuid);
+        assert_eq!(identifier.to_string(), uuid.to_string());
+    }
+
+    #[test]
+    fn should_be_parsed_as_name_given_simple_uuid() {
+        let uuid = Uuid::new_v4().simple().to_string();
+        let identifier = Identifier::from_str(&uuid).unwrap();
+        assert_eq!(identifier.kind, IdKind::String);
+        assert_eq!(identifier.get_string_value().unwrap(), uuid);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_given_uuid() {
+        let identifier = Identifier::uuid(Uuid::new_v4()).unwrap();
+        let deserialized = Identifier::from_bytes(identifier.as_bytes()).unwrap();
+        assert_eq!(deserialized, identifier);
+    }
+
+    #[test]
+    fn should_not_be_created_given_nil_uuid() {
+        assert!(Identifier::uuid(Uuid::nil()).is_err());
+    }
+
+    #[test]
+    fn should_parse_identifier_path() {
+        let path = IdentifierPath::from_str("orders/events/3").unwrap();
+        assert_eq!(path.stream_id, Identifier::named("orders").unwrap());
+        assert_eq!(path.topic_id, Some(Identifier::named("events").unwrap()));
+        assert_eq!(path.partition_id, Some(3));
+        assert_eq!(path.to_string(), "orders/events/3");
+
+        let path = IdentifierPath::from_str("1").unwrap();
+        assert_eq!(path.stream_id, Identifier::numeric(1).unwrap());
+        assert_eq!(path.topic_id, None);
+        assert_eq!(path.partition_id, None);
+    }
+
+    #[test]
+    fn should_join_identifier_path_with_separate_segments() {
+        let path = IdentifierPath::from_str("orders").unwrap();
+        let path = path.join(&["events", "3"]).unwrap();
+        assert_eq!(
+            path.partition().unwrap(),
+            (
+                Identifier::named("orders").unwrap(),
+                Identifier::named("events").unwrap(),
+                3
+            )
+        );
+
+        let path = IdentifierPath::from_str("orders/events").unwrap();
+        assert!(path.join(&["3"]).unwrap().topic().is_err());
+        assert!(path.join(&["3", "4"]).is_err());
+    }
+
+    #[test]
+    fn should_fail_parsing_invalid_identifier_path() {
+        assert!(IdentifierPath::from_str("").is_err());
+        assert!(IdentifierPath::from_str("orders/").is_err());
+        assert!(IdentifierPath::from_str("/events").is_err());
+        assert!(IdentifierPath::from_str("orders/events/0").is_err());
+        assert!(IdentifierPath::from_str("orders/events/first").is_err());
+        assert!(IdentifierPath::from_str("orders/events/3/4").is_err());
+    }
+
+    #[test]
+    fn should_fail_resolving_identifier_path_of_different_depth() {
+        let path = IdentifierPath::from_str("orders").unwrap();
+        assert!(path.topic().is_err());
+        assert!(path.partition().is_err());
+
+        let path = IdentifierPath::from_str("orders/events/3").unwrap();
+        assert!(path.topic().is_err());
+        assert!(path.partition().is_ok());
+    }
 }
@@ -1,5 +1,6 @@
 pub mod args;
 pub mod binary;
+pub mod bootstrap;
 pub mod bytes_serializable;
 #[cfg(feature = "iggy-cli")]
 pub mod cli;
@@ -10,6 +11,8 @@ pub mod client_provider;
 pub mod clients;
 pub mod command;
 pub mod compression;
+pub mod connection_string;
+pub mod connectors;
 pub mod consumer;
 pub mod consumer_groups;
 pub mod consumer_offsets;
@@ -18,16 +21,20 @@ pub mod http;
 pub mod identifier;
 pub mod message_handler;
 pub mod messages;
+pub mod metrics;
 pub mod models;
 pub mod partitioner;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod quic;
+pub mod schemas;
 pub mod sizeable;
 pub mod streams;
 pub mod system;
 pub mod tcp;
+pub mod tenants;
 pub mod topics;
+pub mod user_groups;
 pub mod users;
 pub mod utils;
 pub mod validatable;
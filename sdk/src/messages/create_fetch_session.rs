@@ -0,0 +1,251 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The maximum number of partitions which can be registered in a single fetch session.
+pub const MAX_FETCH_SESSION_PARTITIONS: usize = 10000;
+
+/// `CreateFetchSession` command is used to register the set of partitions polled by the consumer once,
+/// so the subsequent `FetchMessages` commands only carry the session ID and epoch, instead of the whole partition set.
+/// The session is bound to the connection, and it's deleted when the client disconnects.
+/// It has additional payload:
+/// - `consumer` - consumer which will fetch the messages, only the regular consumer is supported, as the consumer group assigns the partitions on its own.
+/// - `partitions` - the partitions to fetch the messages from, identified by the stream, topic and partition ID.
+/// - `count` - the maximum number of messages to fetch from each partition.
+/// - `auto_commit` - whether to commit the offset on the server automatically after fetching the messages.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateFetchSession {
+    /// Consumer which will fetch the messages, only the regular consumer is supported.
+    #[serde(flatten)]
+    pub consumer: Consumer,
+    /// The partitions to fetch the messages from.
+    pub partitions: Vec<FetchPartition>,
+    /// The maximum number of messages to fetch from each partition.
+    pub count: u32,
+    /// Whether to commit the offset on the server automatically after fetching the messages.
+    #[serde(default)]
+    pub auto_commit: bool,
+}
+
+/// `FetchPartition` identifies the partition registered in the fetch session.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct FetchPartition {
+    /// Unique stream ID (numeric or name).
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    pub topic_id: Identifier,
+    /// Partition ID.
+    pub partition_id: u32,
+}
+
+impl CommandPayload for CreateFetchSession {}
+
+impl Default for CreateFetchSession {
+    fn default() -> Self {
+        Self {
+            consumer: Consumer::default(),
+            partitions: vec![FetchPartition {
+                stream_id: Identifier::numeric(1).unwrap(),
+                topic_id: Identifier::numeric(1).unwrap(),
+                partition_id: 1,
+            }],
+            count: 10,
+            auto_commit: false,
+        }
+    }
+}
+
+impl Validatable<IggyError> for CreateFetchSession {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.consumer.kind != ConsumerKind::Consumer {
+            return Err(IggyError::InvalidFetchSession(
+                "only the regular consumer can create the fetch session".to_string(),
+            ));
+        }
+
+        if self.partitions.is_empty() || self.partitions.len() > MAX_FETCH_SESSION_PARTITIONS {
+            return Err(IggyError::InvalidFetchSession(format!(
+                "partitions count must be between 1 and {MAX_FETCH_SESSION_PARTITIONS}"
+            )));
+        }
+
+        if self.count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        for (index, partition) in self.partitions.iter().enumerate() {
+            if partition.partition_id == 0 {
+                return Err(IggyError::InvalidFetchSession(
+                    "partition ID must be greater than 0".to_string(),
+                ));
+            }
+
+            if self.partitions[..index].contains(partition) {
+                return Err(IggyError::InvalidFetchSession(format!(
+                    "partition: {partition} is registered more than once"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CreateFetchSession {
+    fn as_bytes(&self) -> Bytes {
+        let consumer_bytes = self.consumer.as_bytes();
+        let mut bytes = BytesMut::with_capacity(9 + consumer_bytes.len());
+        bytes.put_slice(&consumer_bytes);
+        bytes.put_u32_le(self.count);
+        if self.auto_commit {
+            bytes.put_u8(1);
+        } else {
+            bytes.put_u8(0);
+        }
+        bytes.put_u32_le(self.partitions.len() as u32);
+        for partition in &self.partitions {
+            bytes.put_slice(&partition.stream_id.as_bytes());
+            bytes.put_slice(&partition.topic_id.as_bytes());
+            bytes.put_u32_le(partition.partition_id);
+        }
+
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CreateFetchSession, IggyError> {
+        if bytes.len() < 13 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let consumer = Consumer::from_bytes(bytes.clone())?;
+        let mut position = 1 + consumer.id.get_size_bytes() as usize;
+        if bytes.len() < position + 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let count = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let auto_commit = matches!(bytes[position + 4], 1);
+        let partitions_count =
+            u32::from_le_bytes(bytes[position + 5..position + 9].try_into()?) as usize;
+        position += 9;
+        if partitions_count > MAX_FETCH_SESSION_PARTITIONS {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut partitions = Vec::with_capacity(partitions_count);
+        for _ in 0..partitions_count {
+            let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+            position += stream_id.get_size_bytes() as usize;
+            let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+            position += topic_id.get_size_bytes() as usize;
+            if bytes.len() < position + 4 {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+            position += 4;
+            partitions.push(FetchPartition {
+                stream_id,
+                topic_id,
+                partition_id,
+            });
+        }
+
+        let command = CreateFetchSession {
+            consumer,
+            partitions,
+            count,
+            auto_commit,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for FetchPartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{}/{}",
+            self.stream_id, self.topic_id, self.partition_id
+        )
+    }
+}
+
+impl Display for CreateFetchSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let partitions = self
+            .partitions
+            .iter()
+            .map(|partition| partition.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.consumer, partitions, self.count, self.auto_commit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes_and_deserialized_from_bytes() {
+        let command = CreateFetchSession {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            partitions: vec![
+                FetchPartition {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: Identifier::named("orders").unwrap(),
+                    partition_id: 1,
+                },
+                FetchPartition {
+                    stream_id: Identifier::named("prod").unwrap(),
+                    topic_id: Identifier::numeric(2).unwrap(),
+                    partition_id: 3,
+                },
+            ],
+            count: 100,
+            auto_commit: true,
+        };
+
+        let bytes = command.as_bytes();
+        let deserialized = CreateFetchSession::from_bytes(bytes).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_created_for_consumer_group() {
+        let command = CreateFetchSession {
+            consumer: Consumer::group(Identifier::numeric(1).unwrap()),
+            ..CreateFetchSession::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn should_not_contain_duplicated_partitions() {
+        let partition = FetchPartition {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(1).unwrap(),
+            partition_id: 1,
+        };
+        let command = CreateFetchSession {
+            partitions: vec![partition.clone(), partition],
+            ..CreateFetchSession::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
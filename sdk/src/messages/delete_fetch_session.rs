@@ -0,0 +1,76 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `DeleteFetchSession` command is used to delete the fetch session, once the consumer no longer fetches the messages from its partitions.
+/// It has additional payload:
+/// - `session_id` - unique ID of the fetch session.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct DeleteFetchSession {
+    /// Unique ID of the fetch session.
+    pub session_id: u32,
+}
+
+impl CommandPayload for DeleteFetchSession {}
+
+impl Validatable<IggyError> for DeleteFetchSession {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DeleteFetchSession {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.session_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DeleteFetchSession, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let session_id = u32::from_le_bytes(bytes[..4].try_into()?);
+        let command = DeleteFetchSession { session_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for DeleteFetchSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.session_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = DeleteFetchSession { session_id: 1 };
+
+        let bytes = command.as_bytes();
+        let session_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(session_id, command.session_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(1);
+        let command = DeleteFetchSession::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.session_id, 1);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `FetchMessages` command is used to fetch the messages from all the partitions registered in the fetch session.
+/// Only the partitions with the new messages are returned, each of them continuing from where the previous fetch ended.
+/// It has additional payload:
+/// - `session_id` - unique ID of the fetch session, returned by `CreateFetchSession`.
+/// - `epoch` - the epoch of the fetch session, returned by `CreateFetchSession` or the previous `FetchMessages`.
+///   The server rejects the stale epoch, so the lost or duplicated fetch is detected instead of silently skipping the messages.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct FetchMessages {
+    /// Unique ID of the fetch session.
+    pub session_id: u32,
+    /// The epoch of the fetch session.
+    pub epoch: u32,
+}
+
+impl CommandPayload for FetchMessages {}
+
+impl Validatable<IggyError> for FetchMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for FetchMessages {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u32_le(self.session_id);
+        bytes.put_u32_le(self.epoch);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<FetchMessages, IggyError> {
+        if bytes.len() != 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let session_id = u32::from_le_bytes(bytes[..4].try_into()?);
+        let epoch = u32::from_le_bytes(bytes[4..8].try_into()?);
+        let command = FetchMessages { session_id, epoch };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for FetchMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.session_id, self.epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = FetchMessages {
+            session_id: 1,
+            epoch: 2,
+        };
+
+        let bytes = command.as_bytes();
+        let session_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let epoch = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(session_id, command.session_id);
+        assert_eq!(epoch, command.epoch);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(1);
+        bytes.put_u32_le(2);
+        let command = FetchMessages::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.session_id, 1);
+        assert_eq!(command.epoch, 2);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const MAX_CONDITIONS: usize = 32;
+const CONDITIONS_SEPARATOR: &str = "&&";
+
+lazy_static! {
+    static ref IN_SET_REGEX: Regex = Regex::new(r"^\s*([^\s=^\[\]]+)\s+in\s*\[(.*)\]\s*$").unwrap();
+    static ref EQUALS_REGEX: Regex = Regex::new(r"^\s*([^\s=^]+)\s*==\s*(.*?)\s*$").unwrap();
+    static ref PREFIX_REGEX: Regex = Regex::new(r"^\s*([^\s=^]+)\s*\^=\s*(.*?)\s*$").unwrap();
+}
+
+/// `HeadersFilter` is a simple filter over the message headers, evaluated by the server when polling the messages.
+/// Only the messages meeting all the conditions are returned, so the consumers of the multiplexed topics
+/// don't have to download the messages they're not interested in.
+///
+/// The filter can be created with the builder methods or parsed from the expression, where the conditions are separated with `&&`:
+/// - `key == value` - the header value must be equal to the given value.
+/// - `key ^= prefix` - the header value must start with the given prefix.
+/// - `key in [value1, value2]` - the header value must be equal to one of the given values.
+///
+/// The header values are compared using their textual representation (e.g. `42` for the `uint32` header),
+/// and the messages without the header are never matched. The keys are case-insensitive.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HeadersFilter {
+    conditions: Vec<HeaderCondition>,
+}
+
+/// The single condition of the `HeadersFilter`, which must be met by the value of the header with the given key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeaderCondition {
+    /// The key of the header.
+    pub key: HeaderKey,
+    /// The operator used to compare the header value.
+    pub operator: HeaderOperator,
+}
+
+/// The operator used to compare the header value in the `HeaderCondition`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeaderOperator {
+    /// The header value must be equal to the given value.
+    Equals(String),
+    /// The header value must start with the given prefix.
+    Prefix(String),
+    /// The header value must be equal to one of the given values.
+    In(Vec<String>),
+}
+
+impl HeadersFilter {
+    /// Creates the empty filter, which must have at least one condition before being used.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the condition requiring the header value to be equal to the given value.
+    pub fn equals(self, key: &str, value: &str) -> Result<Self, IggyError> {
+        self.with_condition(key, HeaderOperator::Equals(value.to_string()))
+    }
+
+    /// Adds the condition requiring the header value to start with the given prefix.
+    pub fn prefix(self, key: &str, prefix: &str) -> Result<Self, IggyError> {
+        self.with_condition(key, HeaderOperator::Prefix(prefix.to_string()))
+    }
+
+    /// Adds the condition requiring the header value to be equal to one of the given values.
+    pub fn in_set(self, key: &str, values: &[&str]) -> Result<Self, IggyError> {
+        self.with_condition(
+            key,
+            HeaderOperator::In(values.iter().map(|value| value.to_string()).collect()),
+        )
+    }
+
+    /// Returns the conditions of the filter.
+    pub fn conditions(&self) -> &[HeaderCondition] {
+        &self.conditions
+    }
+
+    /// Returns true if all the conditions are met by the given message headers.
+    pub fn matches(&self, headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> bool {
+        let Some(headers) = headers else {
+            return self.conditions.is_empty();
+        };
+
+        self.conditions.iter().all(|condition| {
+            headers
+                .get(&condition.key)
+                .and_then(get_text)
+                .is_some_and(|text| condition.operator.matches(&text))
+        })
+    }
+
+    fn with_condition(mut self, key: &str, operator: HeaderOperator) -> Result<Self, IggyError> {
+        let condition = HeaderCondition {
+            key: HeaderKey::new(key)?,
+            operator,
+        };
+        condition.validate()?;
+        self.conditions.push(condition);
+        if self.conditions.len() > MAX_CONDITIONS {
+            return Err(invalid_filter(&format!(
+                "filter can have at most {MAX_CONDITIONS} conditions"
+            )));
+        }
+
+        Ok(self)
+    }
+}
+
+impl HeaderCondition {
+    fn validate(&self) -> Result<(), IggyError> {
+        let key = self.key.as_str();
+        if key.contains(char::is_whitespace) || key.contains(['=', '^', '[', ']']) {
+            return Err(invalid_filter(&format!("invalid header key: {key}")));
+        }
+
+        let values = match &self.operator {
+            HeaderOperator::Equals(value) | HeaderOperator::Prefix(value) => {
+                vec![value.as_str()]
+            }
+            HeaderOperator::In(values) => {
+                if values.is_empty() {
+                    return Err(invalid_filter(&format!(
+                        "set of values for header: {key} is empty"
+                    )));
+                }
+                values.iter().map(|value| value.as_str()).collect()
+            }
+        };
+
+        for value in values {
+            let is_in_set = matches!(self.operator, HeaderOperator::In(_));
+            if value.trim() != value
+                || value.contains(CONDITIONS_SEPARATOR)
+                || (is_in_set && value.contains([',', ']']))
+            {
+                return Err(invalid_filter(&format!(
+                    "invalid value: '{value}' for header: {key}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl HeaderOperator {
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            HeaderOperator::Equals(value) => text == value,
+            HeaderOperator::Prefix(prefix) => text.starts_with(prefix.as_str()),
+            HeaderOperator::In(values) => values.iter().any(|value| text == value),
+        }
+    }
+}
+
+impl FromStr for HeadersFilter {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut filter = HeadersFilter::new();
+        for condition in input.split(CONDITIONS_SEPARATOR) {
+            filter = if let Some(captures) = IN_SET_REGEX.captures(condition) {
+                let values = captures[2]
+                    .split(',')
+                    .map(|value| value.trim())
+                    .filter(|value| !value.is_empty())
+                    .collect::<Vec<_>>();
+                filter.in_set(&captures[1], &values)?
+            } else if let Some(captures) = EQUALS_REGEX.captures(condition) {
+                filter.equals(&captures[1], &captures[2])?
+            } else if let Some(captures) = PREFIX_REGEX.captures(condition) {
+                filter.prefix(&captures[1], &captures[2])?
+            } else {
+                return Err(invalid_filter(&format!(
+                    "invalid condition: '{}'",
+                    condition.trim()
+                )));
+            };
+        }
+
+        Ok(filter)
+    }
+}
+
+impl Display for HeadersFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let conditions = self
+            .conditions
+            .iter()
+            .map(|condition| condition.to_string())
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "{}",
+            conditions.join(&format!(" {CONDITIONS_SEPARATOR} "))
+        )
+    }
+}
+
+impl Display for HeaderCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let key = self.key.as_str();
+        match &self.operator {
+            HeaderOperator::Equals(value) => write!(f, "{key} == {value}"),
+            HeaderOperator::Prefix(prefix) => write!(f, "{key} ^= {prefix}"),
+            HeaderOperator::In(values) => write!(f, "{key} in [{}]", values.join(", ")),
+        }
+    }
+}
+
+/// Returns the textual representation of the header value, the raw value is compared only if it's a valid UTF-8.
+pub(crate) fn get_text(value: &HeaderValue) -> Option<Cow<'_, str>> {
+    let text = match value.kind {
+        HeaderKind::Raw | HeaderKind::String => {
+            return std::str::from_utf8(&value.value).ok().map(Cow::Borrowed)
+        }
+        HeaderKind::Bool => value.as_bool().ok()?.to_string(),
+        HeaderKind::Int8 => value.as_int8().ok()?.to_string(),
+        HeaderKind::Int16 => value.as_int16().ok()?.to_string(),
+        HeaderKind::Int32 => value.as_int32().ok()?.to_string(),
+        HeaderKind::Int64 => value.as_int64().ok()?.to_string(),
+        HeaderKind::Int128 => value.as_int128().ok()?.to_string(),
+        HeaderKind::Uint8 => value.as_uint8().ok()?.to_string(),
+        HeaderKind::Uint16 => value.as_uint16().ok()?.to_string(),
+        HeaderKind::Uint32 => value.as_uint32().ok()?.to_string(),
+        HeaderKind::Uint64 => value.as_uint64().ok()?.to_string(),
+        HeaderKind::Uint128 => value.as_uint128().ok()?.to_string(),
+        HeaderKind::Float32 => value.as_float32().ok()?.to_string(),
+        HeaderKind::Float64 => value.as_float64().ok()?.to_string(),
+    };
+
+    Some(Cow::Owned(text))
+}
+
+fn invalid_filter(reason: &str) -> IggyError {
+    IggyError::InvalidHeadersFilter(reason.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(entries: &[(&str, HeaderValue)]) -> Option<HashMap<HeaderKey, HeaderValue>> {
+        Some(
+            entries
+                .iter()
+                .map(|(key, value)| (HeaderKey::new(key).unwrap(), value.clone()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn filter_should_be_parsed_from_expression() {
+        let filter =
+            HeadersFilter::from_str("region == eu-west && type ^= order. && Tenant in [a, b ,c]")
+                .unwrap();
+
+        let expected_filter = HeadersFilter::new()
+            .equals("region", "eu-west")
+            .unwrap()
+            .prefix("type", "order.")
+            .unwrap()
+            .in_set("tenant", &["a", "b", "c"])
+            .unwrap();
+        assert_eq!(filter, expected_filter);
+        assert_eq!(
+            filter.to_string(),
+            "region == eu-west && type ^= order. && tenant in [a, b, c]"
+        );
+        assert_eq!(
+            HeadersFilter::from_str(&filter.to_string()).unwrap(),
+            filter
+        );
+    }
+
+    #[test]
+    fn invalid_expression_should_not_be_parsed() {
+        assert!(HeadersFilter::from_str("").is_err());
+        assert!(HeadersFilter::from_str("region").is_err());
+        assert!(HeadersFilter::from_str("region != eu").is_err());
+        assert!(HeadersFilter::from_str("region == eu &&").is_err());
+        assert!(HeadersFilter::from_str("region in []").is_err());
+    }
+
+    #[test]
+    fn messages_should_be_matched_by_all_conditions() {
+        let filter = HeadersFilter::from_str("region == eu && type ^= order.").unwrap();
+
+        assert!(filter.matches(&headers(&[
+            ("region", HeaderValue::from_str("eu").unwrap()),
+            ("type", HeaderValue::from_str("order.created").unwrap()),
+        ])));
+        assert!(!filter.matches(&headers(&[
+            ("region", HeaderValue::from_str("us").unwrap()),
+            ("type", HeaderValue::from_str("order.created").unwrap()),
+        ])));
+        assert!(!filter.matches(&headers(&[(
+            "region",
+            HeaderValue::from_str("eu").unwrap()
+        )])));
+        assert!(!filter.matches(&None));
+    }
+
+    #[test]
+    fn non_string_header_values_should_be_compared_as_text() {
+        let filter = HeadersFilter::from_str("priority in [1, 2] && urgent == true").unwrap();
+
+        assert!(filter.matches(&headers(&[
+            ("priority", HeaderValue::from_uint32(2).unwrap()),
+            ("urgent", HeaderValue::from_bool(true).unwrap()),
+        ])));
+        assert!(!filter.matches(&headers(&[
+            ("priority", HeaderValue::from_uint32(3).unwrap()),
+            ("urgent", HeaderValue::from_bool(true).unwrap()),
+        ])));
+    }
+}
@@ -0,0 +1,797 @@
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::headers_filter;
+use crate::models::header::HeaderKey;
+use crate::models::messages::Message;
+use serde_json::{Map, Value};
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub const DEFAULT_LIMIT: u32 = 100;
+pub const MAX_LIMIT: u32 = 1000;
+const MAX_CONDITIONS: usize = 32;
+
+/// `MessagesQuery` is a read-only, SQL-like query used to browse the messages stored in a single partition,
+/// e.g. for debugging or support tooling. The query has the following form (keywords are case-insensitive):
+///
+/// `SELECT <columns> FROM <stream>.<topic>.<partition> [WHERE <condition> [AND <condition>]...] [LIMIT <count>]`
+///
+/// - `columns` - either `*` or the comma separated list of: `offset`, `timestamp`, `id`, `state`, `checksum`,
+///   `length` (the payload length, after the decryption), `headers` (all of them) or `headers.<key>` (the single header).
+/// - `stream` and `topic` - unique IDs (numeric or name), the names containing the special characters must be double quoted.
+/// - `condition` - `<column> <operator> <value>`, where the operator is one of `=`, `!=`, `<`, `<=`, `>`, `>=`.
+///   The numeric columns are compared with the numbers, the timestamp can also be provided as the RFC 3339 date in single quotes.
+///   The `state` and `headers.<key>` columns can be compared only for (in)equality using their textual representation.
+/// - `count` - maximum number of the returned rows, by default 100, up to 1000.
+///
+/// Example: `SELECT offset, timestamp, headers.region FROM orders.events.1 WHERE timestamp > '2024-01-01T00:00:00Z' LIMIT 10`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessagesQuery {
+    /// Columns returned for each matching message.
+    pub columns: Vec<QueryColumn>,
+    /// Unique stream ID (numeric or name).
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    pub topic_id: Identifier,
+    /// Partition ID from which the messages are read.
+    pub partition_id: u32,
+    /// Conditions which must be met by all the returned messages.
+    pub conditions: Vec<QueryCondition>,
+    /// Maximum number of the returned rows.
+    pub limit: u32,
+}
+
+/// The column of the message which can be selected or used in the condition.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryColumn {
+    Offset,
+    Timestamp,
+    Id,
+    State,
+    Checksum,
+    Length,
+    Headers,
+    Header(HeaderKey),
+}
+
+/// The single condition of the `MessagesQuery`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryCondition {
+    pub column: QueryColumn,
+    pub operator: QueryOperator,
+    pub value: QueryValue,
+}
+
+/// The comparison operator used in the `QueryCondition`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+/// The value compared with the column in the `QueryCondition`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryValue {
+    Number(u128),
+    Text(String),
+}
+
+impl Default for MessagesQuery {
+    fn default() -> Self {
+        Self {
+            columns: QueryColumn::all(),
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(1).unwrap(),
+            partition_id: 1,
+            conditions: vec![],
+            limit: DEFAULT_LIMIT,
+        }
+    }
+}
+
+impl MessagesQuery {
+    /// Returns the names of the selected columns.
+    pub fn get_column_names(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|column| column.to_string())
+            .collect()
+    }
+
+    /// Returns true if the message meets all the conditions, the payload length is passed separately,
+    /// as the stored payload might be encrypted.
+    pub fn matches(&self, message: &Message, payload_length: u32) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.matches(message, payload_length))
+    }
+
+    /// Returns true if the column is either selected or used in any of the conditions.
+    pub fn uses_column(&self, column: &QueryColumn) -> bool {
+        self.columns.contains(column)
+            || self
+                .conditions
+                .iter()
+                .any(|condition| &condition.column == column)
+    }
+
+    /// Returns the values of the selected columns for the message.
+    pub fn project(&self, message: &Message, payload_length: u32) -> Vec<Value> {
+        self.columns
+            .iter()
+            .map(|column| column.get_value(message, payload_length))
+            .collect()
+    }
+
+    /// Returns the lowest offset which can meet the conditions, if it's restricted by any of them.
+    pub fn get_start_offset(&self) -> Option<u64> {
+        self.get_lower_bound(&QueryColumn::Offset)
+    }
+
+    /// Returns the highest offset which can meet the conditions, if it's restricted by any of them.
+    pub fn get_end_offset(&self) -> Option<u64> {
+        self.conditions
+            .iter()
+            .filter(|condition| condition.column == QueryColumn::Offset)
+            .filter_map(|condition| {
+                let QueryValue::Number(value) = condition.value else {
+                    return None;
+                };
+                let value = u64::try_from(value).unwrap_or(u64::MAX);
+                match condition.operator {
+                    QueryOperator::Equal | QueryOperator::LessOrEqual => Some(value),
+                    QueryOperator::Less => Some(value.saturating_sub(1)),
+                    _ => None,
+                }
+            })
+            .min()
+    }
+
+    /// Returns the lowest timestamp which can meet the conditions, if it's restricted by any of them.
+    pub fn get_start_timestamp(&self) -> Option<u64> {
+        self.get_lower_bound(&QueryColumn::Timestamp)
+    }
+
+    fn get_lower_bound(&self, column: &QueryColumn) -> Option<u64> {
+        self.conditions
+            .iter()
+            .filter(|condition| &condition.column == column)
+            .filter_map(|condition| {
+                let QueryValue::Number(value) = condition.value else {
+                    return None;
+                };
+                let value = u64::try_from(value).unwrap_or(u64::MAX);
+                match condition.operator {
+                    QueryOperator::Equal | QueryOperator::GreaterOrEqual => Some(value),
+                    QueryOperator::Greater => Some(value.saturating_add(1)),
+                    _ => None,
+                }
+            })
+            .max()
+    }
+}
+
+impl QueryColumn {
+    /// Returns the columns selected with `*`.
+    pub fn all() -> Vec<QueryColumn> {
+        vec![
+            QueryColumn::Offset,
+            QueryColumn::Timestamp,
+            QueryColumn::Id,
+            QueryColumn::State,
+            QueryColumn::Checksum,
+            QueryColumn::Length,
+            QueryColumn::Headers,
+        ]
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            QueryColumn::Offset
+                | QueryColumn::Timestamp
+                | QueryColumn::Id
+                | QueryColumn::Checksum
+                | QueryColumn::Length
+        )
+    }
+
+    fn get_number(&self, message: &Message, payload_length: u32) -> Option<u128> {
+        match self {
+            QueryColumn::Offset => Some(message.offset as u128),
+            QueryColumn::Timestamp => Some(message.timestamp as u128),
+            QueryColumn::Id => Some(message.id),
+            QueryColumn::Checksum => Some(message.checksum as u128),
+            QueryColumn::Length => Some(payload_length as u128),
+            _ => None,
+        }
+    }
+
+    fn get_text(&self, message: &Message) -> Option<String> {
+        match self {
+            QueryColumn::State => Some(message.state.to_string()),
+            QueryColumn::Header(key) => message
+                .headers
+                .as_ref()
+                .and_then(|headers| headers.get(key))
+                .and_then(headers_filter::get_text)
+                .map(|text| text.into_owned()),
+            _ => None,
+        }
+    }
+
+    fn get_value(&self, message: &Message, payload_length: u32) -> Value {
+        match self {
+            QueryColumn::Headers => match &message.headers {
+                Some(headers) => Value::Object(
+                    headers
+                        .iter()
+                        .map(|(key, value)| {
+                            let value = headers_filter::get_text(value)
+                                .map_or(Value::Null, |text| Value::String(text.into_owned()));
+                            (key.as_str().to_string(), value)
+                        })
+                        .collect::<Map<_, _>>(),
+                ),
+                None => Value::Null,
+            },
+            QueryColumn::State | QueryColumn::Header(_) => {
+                self.get_text(message).map_or(Value::Null, Value::String)
+            }
+            // The numbers which don't fit into u64 (e.g. message IDs) are returned as strings.
+            column => match column.get_number(message, payload_length) {
+                Some(value) => match u64::try_from(value) {
+                    Ok(value) => Value::from(value),
+                    Err(_) => Value::String(value.to_string()),
+                },
+                None => Value::Null,
+            },
+        }
+    }
+
+    fn parse(segments: &[String]) -> Result<Self, IggyError> {
+        let column = match segments {
+            [name] => match name.to_lowercase().as_str() {
+                "offset" => QueryColumn::Offset,
+                "timestamp" => QueryColumn::Timestamp,
+                "id" => QueryColumn::Id,
+                "state" => QueryColumn::State,
+                "checksum" => QueryColumn::Checksum,
+                "length" => QueryColumn::Length,
+                "headers" => QueryColumn::Headers,
+                _ => return Err(invalid_query(&format!("unknown column: {name}"))),
+            },
+            [headers, key] if headers.eq_ignore_ascii_case("headers") => {
+                QueryColumn::Header(HeaderKey::new(key)?)
+            }
+            _ => {
+                return Err(invalid_query(&format!(
+                    "unknown column: {}",
+                    segments.join(".")
+                )))
+            }
+        };
+
+        Ok(column)
+    }
+}
+
+impl QueryCondition {
+    fn matches(&self, message: &Message, payload_length: u32) -> bool {
+        match &self.value {
+            QueryValue::Number(value) => self
+                .column
+                .get_number(message, payload_length)
+                .is_some_and(|number| self.operator.compare(&number, value)),
+            QueryValue::Text(value) => self
+                .column
+                .get_text(message)
+                .is_some_and(|text| self.operator.compare(&text, value)),
+        }
+    }
+
+    fn new(
+        column: QueryColumn,
+        operator: QueryOperator,
+        value: QueryValue,
+    ) -> Result<Self, IggyError> {
+        let value = match (&column, value) {
+            (QueryColumn::Headers, _) => {
+                return Err(invalid_query(
+                    "headers can't be compared, use headers.<key> instead",
+                ))
+            }
+            (QueryColumn::Timestamp, QueryValue::Text(date)) => {
+                let timestamp = chrono::DateTime::parse_from_rfc3339(&date)
+                    .map_err(|_| invalid_query(&format!("invalid timestamp: '{date}'")))?
+                    .timestamp_micros();
+                QueryValue::Number(u128::try_from(timestamp).unwrap_or_default())
+            }
+            (column, QueryValue::Text(text)) if column.is_numeric() => {
+                return Err(invalid_query(&format!(
+                    "column: {column} can't be compared with the text: '{text}'"
+                )))
+            }
+            (column, value) if column.is_numeric() => value,
+            (column, value) => {
+                if !matches!(operator, QueryOperator::Equal | QueryOperator::NotEqual) {
+                    return Err(invalid_query(&format!(
+                        "column: {column} can be compared only with = or != operator"
+                    )));
+                }
+                match value {
+                    QueryValue::Number(number) => QueryValue::Text(number.to_string()),
+                    text => text,
+                }
+            }
+        };
+
+        Ok(Self {
+            column,
+            operator,
+            value,
+        })
+    }
+}
+
+impl QueryOperator {
+    fn compare<T: PartialOrd>(&self, left: &T, right: &T) -> bool {
+        match self {
+            QueryOperator::Equal => left == right,
+            QueryOperator::NotEqual => left != right,
+            QueryOperator::Less => left < right,
+            QueryOperator::LessOrEqual => left <= right,
+            QueryOperator::Greater => left > right,
+            QueryOperator::GreaterOrEqual => left >= right,
+        }
+    }
+}
+
+impl FromStr for MessagesQuery {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+        };
+        parser.parse_query()
+    }
+}
+
+impl Display for MessagesQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| column.to_string())
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "SELECT {} FROM {}.{}.{}",
+            columns.join(", "),
+            format_identifier(&self.stream_id),
+            format_identifier(&self.topic_id),
+            self.partition_id
+        )?;
+        if !self.conditions.is_empty() {
+            let conditions = self
+                .conditions
+                .iter()
+                .map(|condition| condition.to_string())
+                .collect::<Vec<_>>();
+            write!(f, " WHERE {}", conditions.join(" AND "))?;
+        }
+
+        write!(f, " LIMIT {}", self.limit)
+    }
+}
+
+impl Display for QueryColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryColumn::Offset => write!(f, "offset"),
+            QueryColumn::Timestamp => write!(f, "timestamp"),
+            QueryColumn::Id => write!(f, "id"),
+            QueryColumn::State => write!(f, "state"),
+            QueryColumn::Checksum => write!(f, "checksum"),
+            QueryColumn::Length => write!(f, "length"),
+            QueryColumn::Headers => write!(f, "headers"),
+            QueryColumn::Header(key) => write!(f, "headers.{}", quote_name(key.as_str())),
+        }
+    }
+}
+
+impl Display for QueryCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.column, self.operator, self.value)
+    }
+}
+
+impl Display for QueryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryOperator::Equal => write!(f, "="),
+            QueryOperator::NotEqual => write!(f, "!="),
+            QueryOperator::Less => write!(f, "<"),
+            QueryOperator::LessOrEqual => write!(f, "<="),
+            QueryOperator::Greater => write!(f, ">"),
+            QueryOperator::GreaterOrEqual => write!(f, ">="),
+        }
+    }
+}
+
+impl Display for QueryValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryValue::Number(value) => write!(f, "{value}"),
+            QueryValue::Text(value) => write!(f, "'{}'", value.replace('\'', "''")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    QuotedName(String),
+    Text(String),
+    Operator(QueryOperator),
+    Dot,
+    Comma,
+    Star,
+    Semicolon,
+}
+
+const SPECIAL_CHARACTERS: [char; 9] = ['.', ',', '*', ';', '\'', '"', '=', '<', '>'];
+
+fn tokenize(input: &str) -> Result<Vec<Token>, IggyError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(char) = chars.next() {
+        let token = match char {
+            char if char.is_whitespace() => continue,
+            '.' => Token::Dot,
+            ',' => Token::Comma,
+            '*' => Token::Star,
+            ';' => Token::Semicolon,
+            '=' => Token::Operator(QueryOperator::Equal),
+            '!' if chars.next_if_eq(&'=').is_some() => Token::Operator(QueryOperator::NotEqual),
+            '<' if chars.next_if_eq(&'=').is_some() => Token::Operator(QueryOperator::LessOrEqual),
+            '<' if chars.next_if_eq(&'>').is_some() => Token::Operator(QueryOperator::NotEqual),
+            '<' => Token::Operator(QueryOperator::Less),
+            '>' if chars.next_if_eq(&'=').is_some() => {
+                Token::Operator(QueryOperator::GreaterOrEqual)
+            }
+            '>' => Token::Operator(QueryOperator::Greater),
+            '\'' | '"' => {
+                // The quote character is escaped by doubling it, as in SQL.
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some(next) if next == char && chars.next_if_eq(&char).is_some() => {
+                            value.push(char)
+                        }
+                        Some(next) if next == char => break,
+                        Some(next) => value.push(next),
+                        None => return Err(invalid_query("unterminated quoted value")),
+                    }
+                }
+                if char == '\'' {
+                    Token::Text(value)
+                } else {
+                    Token::QuotedName(value)
+                }
+            }
+            char => {
+                let mut word = char.to_string();
+                while let Some(next) = chars.next_if(|next| {
+                    !next.is_whitespace() && !SPECIAL_CHARACTERS.contains(next) && *next != '!'
+                }) {
+                    word.push(next);
+                }
+                Token::Word(word)
+            }
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn parse_query(&mut self) -> Result<MessagesQuery, IggyError> {
+        self.expect_keyword("SELECT")?;
+        let columns = if self.next_if(&Token::Star) {
+            QueryColumn::all()
+        } else {
+            let mut columns = vec![QueryColumn::parse(&self.parse_path()?)?];
+            while self.next_if(&Token::Comma) {
+                columns.push(QueryColumn::parse(&self.parse_path()?)?);
+            }
+            columns
+        };
+
+        self.expect_keyword("FROM")?;
+        let (stream_id, topic_id, partition_id) = match &self.parse_path()?[..] {
+            [stream_id, topic_id, partition_id] => (
+                Identifier::from_str_value(stream_id)?,
+                Identifier::from_str_value(topic_id)?,
+                partition_id
+                    .parse::<u32>()
+                    .map_err(|_| invalid_query(&format!("invalid partition ID: {partition_id}")))?,
+            ),
+            _ => {
+                return Err(invalid_query(
+                    "source must be specified as <stream>.<topic>.<partition>",
+                ))
+            }
+        };
+
+        let mut conditions = Vec::new();
+        if self.next_if_keyword("WHERE") {
+            conditions.push(self.parse_condition()?);
+            while self.next_if_keyword("AND") {
+                conditions.push(self.parse_condition()?);
+            }
+        }
+        if conditions.len() > MAX_CONDITIONS {
+            return Err(invalid_query(&format!(
+                "query can have at most {MAX_CONDITIONS} conditions"
+            )));
+        }
+
+        let mut limit = DEFAULT_LIMIT;
+        if self.next_if_keyword("LIMIT") {
+            limit = match self.next() {
+                Some(Token::Word(word)) => word.parse::<u32>().ok(),
+                _ => None,
+            }
+            .filter(|limit| (1..=MAX_LIMIT).contains(limit))
+            .ok_or_else(|| invalid_query(&format!("limit must be between 1 and {MAX_LIMIT}")))?;
+        }
+
+        self.next_if(&Token::Semicolon);
+        if let Some(token) = self.next() {
+            return Err(invalid_query(&format!("unexpected token: {token:?}")));
+        }
+
+        Ok(MessagesQuery {
+            columns,
+            stream_id,
+            topic_id,
+            partition_id,
+            conditions,
+            limit,
+        })
+    }
+
+    fn parse_path(&mut self) -> Result<Vec<String>, IggyError> {
+        let mut segments = Vec::new();
+        loop {
+            match self.next() {
+                Some(Token::Word(segment)) | Some(Token::QuotedName(segment)) => {
+                    segments.push(segment)
+                }
+                token => {
+                    return Err(invalid_query(&format!(
+                        "expected name, found: {}",
+                        token.map_or("end of query".to_string(), |token| format!("{token:?}"))
+                    )))
+                }
+            }
+
+            if !self.next_if(&Token::Dot) {
+                return Ok(segments);
+            }
+        }
+    }
+
+    fn parse_condition(&mut self) -> Result<QueryCondition, IggyError> {
+        let column = QueryColumn::parse(&self.parse_path()?)?;
+        let Some(Token::Operator(operator)) = self.next() else {
+            return Err(invalid_query(&format!(
+                "expected comparison operator after: {column}"
+            )));
+        };
+        let value = match self.next() {
+            Some(Token::Text(text)) => QueryValue::Text(text),
+            Some(Token::Word(word)) => QueryValue::Number(
+                word.parse::<u128>()
+                    .map_err(|_| invalid_query(&format!("invalid number: {word}")))?,
+            ),
+            _ => {
+                return Err(invalid_query(&format!(
+                    "expected value after: {column} {operator}"
+                )))
+            }
+        };
+
+        QueryCondition::new(column, operator, value)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn next_if(&mut self, expected: &Token) -> bool {
+        if self.tokens.get(self.position) == Some(expected) {
+            self.position += 1;
+            return true;
+        }
+
+        false
+    }
+
+    fn next_if_keyword(&mut self, keyword: &str) -> bool {
+        if let Some(Token::Word(word)) = self.tokens.get(self.position) {
+            if word.eq_ignore_ascii_case(keyword) {
+                self.position += 1;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), IggyError> {
+        if self.next_if_keyword(keyword) {
+            return Ok(());
+        }
+
+        Err(invalid_query(&format!("expected keyword: {keyword}")))
+    }
+}
+
+fn format_identifier(identifier: &Identifier) -> String {
+    quote_name(&identifier.as_cow_str())
+}
+
+fn quote_name(name: &str) -> String {
+    let is_plain = name
+        .chars()
+        .all(|char| !char.is_whitespace() && !SPECIAL_CHARACTERS.contains(&char) && char != '!');
+    if is_plain {
+        return name.to_string();
+    }
+
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn invalid_query(reason: &str) -> IggyError {
+    IggyError::InvalidMessagesQuery(reason.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::header::HeaderValue;
+    use crate::models::messages::MessageState;
+    use bytes::Bytes;
+    use std::collections::HashMap;
+
+    fn message(offset: u64, region: &str) -> Message {
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderKey::new("region").unwrap(),
+            HeaderValue::from_str(region).unwrap(),
+        );
+        Message {
+            offset,
+            state: MessageState::Available,
+            timestamp: 1000 + offset,
+            id: offset as u128,
+            checksum: 0,
+            headers: Some(headers),
+            length: 5,
+            payload: Bytes::from("hello"),
+        }
+    }
+
+    #[test]
+    fn query_should_be_parsed() {
+        let query = MessagesQuery::from_str(
+            "select offset, headers.region, Headers.\"Key 2\" from \"my stream\".2.3 where offset >= 10 and headers.region = 'eu' and timestamp < '2024-01-01T00:00:00Z' limit 5;",
+        )
+        .unwrap();
+
+        assert_eq!(
+            query.columns,
+            vec![
+                QueryColumn::Offset,
+                QueryColumn::Header(HeaderKey::new("region").unwrap()),
+                QueryColumn::Header(HeaderKey::new("key 2").unwrap()),
+            ]
+        );
+        assert_eq!(query.stream_id, Identifier::named("my stream").unwrap());
+        assert_eq!(query.topic_id, Identifier::numeric(2).unwrap());
+        assert_eq!(query.partition_id, 3);
+        assert_eq!(query.conditions.len(), 3);
+        assert_eq!(
+            query.conditions[2].value,
+            QueryValue::Number(1_704_067_200_000_000)
+        );
+        assert_eq!(query.limit, 5);
+        assert_eq!(
+            query.to_string(),
+            "SELECT offset, headers.region, headers.\"key 2\" FROM \"my stream\".2.3 WHERE offset >= 10 AND headers.region = 'eu' AND timestamp < 1704067200000000 LIMIT 5"
+        );
+        assert_eq!(MessagesQuery::from_str(&query.to_string()).unwrap(), query);
+    }
+
+    #[test]
+    fn all_columns_should_be_selected_with_default_limit() {
+        let query = MessagesQuery::from_str("SELECT * FROM stream.topic.1").unwrap();
+
+        assert_eq!(query.columns, QueryColumn::all());
+        assert!(query.conditions.is_empty());
+        assert_eq!(query.limit, DEFAULT_LIMIT);
+    }
+
+    #[test]
+    fn invalid_query_should_not_be_parsed() {
+        let queries = [
+            "",
+            "SELECT",
+            "SELECT * FROM stream.topic",
+            "SELECT * FROM stream.topic.partition",
+            "SELECT unknown FROM stream.topic.1",
+            "SELECT * FROM stream.topic.1 WHERE offset = 'text'",
+            "SELECT * FROM stream.topic.1 WHERE headers = 'text'",
+            "SELECT * FROM stream.topic.1 WHERE headers.region > 'eu'",
+            "SELECT * FROM stream.topic.1 WHERE timestamp > 'yesterday'",
+            "SELECT * FROM stream.topic.1 LIMIT 0",
+            "SELECT * FROM stream.topic.1 LIMIT 1001",
+            "SELECT * FROM stream.topic.1 WHERE headers.region = 'eu",
+            "SELECT * FROM stream.topic.1 ORDER BY offset",
+        ];
+
+        for query in queries {
+            assert!(MessagesQuery::from_str(query).is_err(), "{query}");
+        }
+    }
+
+    #[test]
+    fn messages_should_be_matched_and_projected() {
+        let query = MessagesQuery::from_str(
+            "SELECT offset, length, headers.region, headers.missing, headers FROM 1.1.1 WHERE offset > 1 AND headers.region != 'us'",
+        )
+        .unwrap();
+
+        assert!(!query.matches(&message(1, "eu"), 5));
+        assert!(!query.matches(&message(2, "us"), 5));
+        assert!(query.matches(&message(2, "eu"), 5));
+        assert_eq!(
+            query.project(&message(2, "eu"), 5),
+            vec![
+                Value::from(2),
+                Value::from(5),
+                Value::from("eu"),
+                Value::Null,
+                serde_json::json!({"region": "eu"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn offset_and_timestamp_bounds_should_be_calculated() {
+        let query = MessagesQuery::from_str(
+            "SELECT * FROM 1.1.1 WHERE offset > 5 AND offset >= 3 AND offset < 20 AND offset <= 30 AND timestamp >= 100",
+        )
+        .unwrap();
+
+        assert_eq!(query.get_start_offset(), Some(6));
+        assert_eq!(query.get_end_offset(), Some(19));
+        assert_eq!(query.get_start_timestamp(), Some(100));
+    }
+}
@@ -1,4 +1,10 @@
+pub mod create_fetch_session;
+pub mod delete_fetch_session;
+pub mod fetch_messages;
+pub mod headers_filter;
+pub mod messages_query;
 pub mod poll_messages;
+pub mod query_messages;
 pub mod send_messages;
 
 const MAX_HEADERS_SIZE: u32 = 100 * 1000;
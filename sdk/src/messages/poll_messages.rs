@@ -3,6 +3,7 @@ use crate::command::CommandPayload;
 use crate::consumer::{Consumer, ConsumerKind};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::messages::headers_filter::HeadersFilter;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
@@ -10,6 +11,9 @@ use serde_with::{serde_as, DisplayFromStr};
 use std::fmt::Display;
 use std::str::FromStr;
 
+/// The maximum time in milliseconds for which the server can hold the poll until the messages arrive.
+pub const MAX_WAIT_MS: u32 = 60000;
+
 /// `PollMessages` command is used to poll messages from a topic in a stream.
 /// It has additional payload:
 /// - `consumer` - consumer which will poll messages. Either regular consumer or consumer group.
@@ -19,6 +23,11 @@ use std::str::FromStr;
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+/// - `filter` - optional filter over the message headers, only the matching messages are returned.
+/// - `mode` - polling mode which specifies whether the consumer offset can be stored and in which direction the messages are read.
+/// - `max_wait_ms` - optional time in milliseconds for which the server holds the request until the messages arrive, if there are none to poll, max value is 60000.
+/// - `max_bytes` - optional limit of the size of the polled messages, the messages exceeding it are left out and the offset to continue from is returned.
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     /// Consumer which will poll messages. Either regular consumer or consumer group.
@@ -42,6 +51,24 @@ pub struct PollMessages {
     #[serde(default)]
     /// Whether to commit offset on the server automatically after polling the messages.
     pub auto_commit: bool,
+    /// Optional filter over the message headers, evaluated by the server, only the matching messages are returned.
+    /// When the auto commit is enabled, the offset of the last scanned message is stored, even if it didn't match the filter.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<HeadersFilter>,
+    /// Polling mode, by default the messages are consumed. In the peek modes, the consumer offset is never stored,
+    /// so the messages can be browsed (e.g. by the dashboards) without affecting the consumers.
+    #[serde(default, skip_serializing_if = "PollingMode::is_consume")]
+    pub mode: PollingMode,
+    /// Optional time in milliseconds for which the server holds the request until the new messages are appended to the polled partition (or the time elapses),
+    /// if there are no messages to poll, so the consumers don't have to poll the empty partitions in a loop. Max value is 60000.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_wait_ms: Option<u32>,
+    /// Optional limit of the total size in bytes of the polled messages, so the large messages polled with the high count don't exhaust the memory.
+    /// The messages are never split, the first message is always returned (even if it exceeds the limit), and if any messages have been left out,
+    /// the offset of the first one is returned as the `next_offset` to continue polling from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_bytes: Option<u32>,
 }
 
 /// `PollingStrategy` specifies from where to start polling messages.
@@ -81,6 +108,24 @@ pub enum PollingKind {
     Next,
 }
 
+/// `PollingMode` specifies whether the polled messages are consumed or only browsed and is used by `PollMessages`.
+/// It has the following kinds:
+/// - `Consume` - the messages are read forward and the consumer offset can be stored (e.g. with the auto commit).
+/// - `Peek` - the messages are read forward and the consumer offset is never stored.
+/// - `PeekBackward` - the messages are read backward, starting from the specified offset (or the last one),
+///   returned in the descending order of their offsets, and the consumer offset is never stored.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PollingMode {
+    #[default]
+    /// Read the messages forward, the consumer offset can be stored.
+    Consume,
+    /// Read the messages forward, the consumer offset is never stored.
+    Peek,
+    /// Read the messages backward, the consumer offset is never stored.
+    PeekBackward,
+}
+
 impl Default for PollMessages {
     fn default() -> Self {
         Self {
@@ -91,6 +136,10 @@ impl Default for PollMessages {
             strategy: default_strategy(),
             count: default_count(),
             auto_commit: false,
+            filter: None,
+            mode: PollingMode::Consume,
+            max_wait_ms: None,
+            max_bytes: None,
         }
     }
 }
@@ -128,6 +177,45 @@ fn default_count() -> u32 {
 
 impl Validatable<IggyError> for PollMessages {
     fn validate(&self) -> Result<(), IggyError> {
+        if self
+            .max_wait_ms
+            .is_some_and(|max_wait_ms| max_wait_ms > MAX_WAIT_MS)
+        {
+            return Err(IggyError::InvalidPollingMaxWait(MAX_WAIT_MS));
+        }
+
+        if self.max_bytes == Some(0) {
+            return Err(IggyError::InvalidPollingMaxBytes);
+        }
+
+        if let Some(filter) = &self.filter {
+            if filter.conditions().is_empty() {
+                return Err(IggyError::InvalidHeadersFilter(
+                    "filter must have at least one condition".to_string(),
+                ));
+            }
+        }
+
+        if self.mode.is_consume() {
+            return Ok(());
+        }
+
+        if self.auto_commit {
+            return Err(IggyError::InvalidPollingMode(format!(
+                "auto commit is not allowed in the {} mode",
+                self.mode
+            )));
+        }
+
+        if self.mode == PollingMode::PeekBackward
+            && !matches!(self.strategy.kind, PollingKind::Offset | PollingKind::Last)
+        {
+            return Err(IggyError::InvalidPollingMode(format!(
+                "{} strategy is not supported in the {} mode, use offset or last",
+                self.strategy.kind, self.mode
+            )));
+        }
+
         Ok(())
     }
 }
@@ -225,17 +313,68 @@ impl Display for PollingKind {
     }
 }
 
+impl PollingMode {
+    /// Returns true if the messages are consumed, so the consumer offset can be stored.
+    pub fn is_consume(&self) -> bool {
+        *self == PollingMode::Consume
+    }
+
+    /// Returns code of the polling mode.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PollingMode::Consume => 1,
+            PollingMode::Peek => 2,
+            PollingMode::PeekBackward => 3,
+        }
+    }
+
+    /// Returns polling mode from the specified code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(PollingMode::Consume),
+            2 => Ok(PollingMode::Peek),
+            3 => Ok(PollingMode::PeekBackward),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for PollingMode {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "c" | "consume" => Ok(PollingMode::Consume),
+            "p" | "peek" => Ok(PollingMode::Peek),
+            "b" | "peek_backward" => Ok(PollingMode::PeekBackward),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for PollingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PollingMode::Consume => write!(f, "consume"),
+            PollingMode::Peek => write!(f, "peek"),
+            PollingMode::PeekBackward => write!(f, "peek_backward"),
+        }
+    }
+}
+
 impl BytesSerializable for PollMessages {
     fn as_bytes(&self) -> Bytes {
         let consumer_bytes = self.consumer.as_bytes();
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let strategy_bytes = self.strategy.as_bytes();
+        let filter = self.filter.as_ref().map(|filter| filter.to_string());
         let mut bytes = BytesMut::with_capacity(
             9 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
-                + strategy_bytes.len(),
+                + strategy_bytes.len()
+                + filter.as_ref().map_or(0, |filter| 4 + filter.len())
+                + 13,
         );
         bytes.put_slice(&consumer_bytes);
         bytes.put_slice(&stream_id_bytes);
@@ -252,6 +391,23 @@ impl BytesSerializable for PollMessages {
         } else {
             bytes.put_u8(0);
         }
+        // The optional trailer is omitted for the default values, the empty filter means no filter at all,
+        // and the zero max wait means no waiting, if it's only present because of the max bytes.
+        let has_max_wait = self.max_wait_ms.is_some() || self.max_bytes.is_some();
+        if filter.is_some() || !self.mode.is_consume() || has_max_wait {
+            let filter = filter.unwrap_or_default();
+            bytes.put_u32_le(filter.len() as u32);
+            bytes.put_slice(filter.as_bytes());
+        }
+        if !self.mode.is_consume() || has_max_wait {
+            bytes.put_u8(self.mode.as_code());
+        }
+        if has_max_wait {
+            bytes.put_u32_le(self.max_wait_ms.unwrap_or_default());
+        }
+        if let Some(max_bytes) = self.max_bytes {
+            bytes.put_u32_le(max_bytes);
+        }
 
         bytes.freeze()
     }
@@ -288,6 +444,49 @@ impl BytesSerializable for PollMessages {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into()?);
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        position += 13;
+        let mut filter = None;
+        let mut mode = PollingMode::Consume;
+        let mut max_wait_ms = None;
+        let mut max_bytes = None;
+        if bytes.len() > position {
+            if bytes.len() < position + 4 {
+                return Err(IggyError::InvalidCommand);
+            }
+            let filter_length =
+                u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+            position += 4;
+            if bytes.len() < position + filter_length {
+                return Err(IggyError::InvalidCommand);
+            }
+            if filter_length > 0 {
+                let value = std::str::from_utf8(&bytes[position..position + filter_length])?;
+                filter = Some(HeadersFilter::from_str(value)?);
+            }
+            position += filter_length;
+            match bytes.len() - position {
+                0 => {}
+                1 => mode = PollingMode::from_code(bytes[position])?,
+                5 => {
+                    mode = PollingMode::from_code(bytes[position])?;
+                    max_wait_ms = Some(u32::from_le_bytes(
+                        bytes[position + 1..position + 5].try_into()?,
+                    ));
+                }
+                9 => {
+                    mode = PollingMode::from_code(bytes[position])?;
+                    max_wait_ms =
+                        match u32::from_le_bytes(bytes[position + 1..position + 5].try_into()?) {
+                            0 => None,
+                            max_wait_ms => Some(max_wait_ms),
+                        };
+                    max_bytes = Some(u32::from_le_bytes(
+                        bytes[position + 5..position + 9].try_into()?,
+                    ));
+                }
+                _ => return Err(IggyError::InvalidCommand),
+            }
+        }
         let command = PollMessages {
             consumer,
             stream_id,
@@ -296,6 +495,10 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            filter,
+            mode,
+            max_wait_ms,
+            max_bytes,
         };
         command.validate()?;
         Ok(command)
@@ -314,7 +517,21 @@ impl Display for PollMessages {
             self.strategy,
             self.count,
             auto_commit_to_string(self.auto_commit)
-        )
+        )?;
+        if let Some(filter) = &self.filter {
+            write!(f, "|{filter}")?;
+        }
+        if !self.mode.is_consume() {
+            write!(f, "|{}", self.mode)?;
+        }
+        if let Some(max_wait_ms) = self.max_wait_ms {
+            write!(f, "|{max_wait_ms}ms")?;
+        }
+        if let Some(max_bytes) = self.max_bytes {
+            write!(f, "|{max_bytes}B")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -366,6 +583,10 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            filter: None,
+            mode: PollingMode::Consume,
+            max_wait_ms: None,
+            max_bytes: None,
         };
 
         let bytes = command.as_bytes();
@@ -445,4 +666,132 @@ mod tests {
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
     }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_filter() {
+        let command = PollMessages {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            strategy: PollingStrategy::next(),
+            count: 3,
+            auto_commit: true,
+            filter: Some(
+                HeadersFilter::new()
+                    .equals("region", "eu")
+                    .unwrap()
+                    .in_set("type", &["created", "paid"])
+                    .unwrap(),
+            ),
+            mode: PollingMode::Consume,
+            max_wait_ms: None,
+            max_bytes: None,
+        };
+
+        let deserialized_command = PollMessages::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_bytes_with_invalid_filter() {
+        let command = PollMessages::default();
+        let mut bytes = BytesMut::from(&command.as_bytes()[..]);
+        let filter = "region != eu";
+        bytes.put_u32_le(filter.len() as u32);
+        bytes.put_slice(filter.as_bytes());
+
+        let command = PollMessages::from_bytes(bytes.freeze());
+
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_mode() {
+        let mut command = PollMessages {
+            strategy: PollingStrategy::last(),
+            mode: PollingMode::PeekBackward,
+            ..PollMessages::default()
+        };
+
+        let deserialized_command = PollMessages::from_bytes(command.as_bytes()).unwrap();
+        assert_eq!(deserialized_command, command);
+
+        command.mode = PollingMode::Peek;
+        command.filter = Some(HeadersFilter::from_str("region == eu").unwrap());
+        let deserialized_command = PollMessages::from_bytes(command.as_bytes()).unwrap();
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_max_wait() {
+        let mut command = PollMessages {
+            max_wait_ms: Some(5000),
+            ..PollMessages::default()
+        };
+
+        let deserialized_command = PollMessages::from_bytes(command.as_bytes()).unwrap();
+        assert_eq!(deserialized_command, command);
+
+        command.mode = PollingMode::Peek;
+        command.filter = Some(HeadersFilter::from_str("region == eu").unwrap());
+        let deserialized_command = PollMessages::from_bytes(command.as_bytes()).unwrap();
+        assert_eq!(deserialized_command, command);
+
+        command.max_wait_ms = Some(MAX_WAIT_MS + 1);
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_max_bytes() {
+        let mut command = PollMessages {
+            max_bytes: Some(1024),
+            ..PollMessages::default()
+        };
+
+        let deserialized_command = PollMessages::from_bytes(command.as_bytes()).unwrap();
+        assert_eq!(deserialized_command, command);
+
+        command.max_wait_ms = Some(5000);
+        command.mode = PollingMode::Peek;
+        let deserialized_command = PollMessages::from_bytes(command.as_bytes()).unwrap();
+        assert_eq!(deserialized_command, command);
+
+        command.max_bytes = Some(0);
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn peek_mode_should_not_allow_auto_commit() {
+        let command = PollMessages {
+            auto_commit: true,
+            mode: PollingMode::Peek,
+            ..PollMessages::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn peek_backward_mode_should_allow_only_offset_or_last_strategy() {
+        let mut command = PollMessages {
+            mode: PollingMode::PeekBackward,
+            ..PollMessages::default()
+        };
+
+        for strategy in [PollingStrategy::offset(10), PollingStrategy::last()] {
+            command.strategy = strategy;
+            assert!(command.validate().is_ok());
+        }
+
+        for strategy in [
+            PollingStrategy::first(),
+            PollingStrategy::next(),
+            PollingStrategy::timestamp(10),
+        ] {
+            command.strategy = strategy;
+            assert!(command.validate().is_err());
+        }
+    }
 }
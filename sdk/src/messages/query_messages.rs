@@ -0,0 +1,114 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::messages::messages_query::MessagesQuery;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::fmt::Display;
+use std::str::FromStr;
+
+const MAX_QUERY_LENGTH: usize = 10000;
+
+/// `QueryMessages` command is used to browse the messages stored in the partition with the read-only, SQL-like query,
+/// e.g. `SELECT offset, timestamp, headers.region FROM stream.topic.1 WHERE offset >= 100 LIMIT 10`.
+/// The consumer offsets are not affected.
+/// It has additional payload:
+/// - `query` - the query to execute, see `MessagesQuery` for the supported syntax.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct QueryMessages {
+    /// The query to execute.
+    #[serde_as(as = "DisplayFromStr")]
+    pub query: MessagesQuery,
+}
+
+impl CommandPayload for QueryMessages {}
+
+impl Validatable<IggyError> for QueryMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for QueryMessages {
+    fn as_bytes(&self) -> Bytes {
+        let query = self.query.to_string();
+        let mut bytes = BytesMut::with_capacity(4 + query.len());
+        bytes.put_u32_le(query.len() as u32);
+        bytes.put_slice(query.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<QueryMessages, IggyError> {
+        if bytes.len() < 5 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let query_length = u32::from_le_bytes(bytes[..4].try_into()?) as usize;
+        if query_length > MAX_QUERY_LENGTH || bytes.len() != 4 + query_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let query = MessagesQuery::from_str(std::str::from_utf8(&bytes[4..])?)?;
+        let command = QueryMessages { query };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for QueryMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = QueryMessages {
+            query: MessagesQuery::from_str("SELECT offset FROM stream.topic.1 WHERE offset > 1")
+                .unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let query_length = u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize;
+        let query = std::str::from_utf8(&bytes[4..]).unwrap();
+
+        assert_eq!(query_length, query.len());
+        assert_eq!(
+            query,
+            "SELECT offset FROM stream.topic.1 WHERE offset > 1 LIMIT 100"
+        );
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let query = "select * from 1.2.3 limit 10";
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(query.len() as u32);
+        bytes.put_slice(query.as_bytes());
+
+        let command = QueryMessages::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.query, MessagesQuery::from_str(query).unwrap());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_bytes_with_invalid_query() {
+        let query = "DELETE FROM 1.2.3";
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(query.len() as u32);
+        bytes.put_slice(query.as_bytes());
+
+        let command = QueryMessages::from_bytes(bytes.freeze());
+
+        assert!(command.is_err());
+    }
+}
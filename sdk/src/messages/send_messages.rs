@@ -5,6 +5,7 @@ use crate::identifier::Identifier;
 use crate::messages::{MAX_HEADERS_SIZE, MAX_PAYLOAD_SIZE};
 use crate::models::header;
 use crate::models::header::{HeaderKey, HeaderValue};
+use crate::utils::buffer_pool::BufferPool;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
@@ -15,6 +16,9 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 const EMPTY_KEY_VALUE: Vec<u8> = vec![];
+/// The acks are encoded in the upper bits of the partitioning kind, so the command with the default acks is understood by the older server.
+const ACKS_SHIFT: u8 = 4;
+const PARTITIONING_KIND_MASK: u8 = (1 << ACKS_SHIFT) - 1;
 
 /// `SendMessages` command is used to send messages to a topic in a stream.
 /// It has additional payload:
@@ -22,6 +26,7 @@ const EMPTY_KEY_VALUE: Vec<u8> = vec![];
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitioning` - to which partition the messages should be sent - either provided by the client or calculated by the server.
 /// - `messages` - collection of messages to be sent.
+/// - `acks` - optional acknowledgement level, the client (or server) default is used if not set.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct SendMessages {
     /// Unique stream ID (numeric or name).
@@ -34,6 +39,27 @@ pub struct SendMessages {
     pub partitioning: Partitioning,
     /// Collection of messages to be sent.
     pub messages: Vec<Message>,
+    /// Optional acknowledgement level, the client (or server) default is used if not set.
+    #[serde(default)]
+    pub acks: Option<Acks>,
+}
+
+/// `Acks` is used to specify when the server acknowledges the sent messages, trading the latency for the durability.
+/// It has the following kinds:
+/// - `None` - the messages are acknowledged once they're queued for appending, without waiting for the append, so the errors are not returned.
+/// - `Leader` - the messages are acknowledged once they're appended to the partition (in memory), which is the default.
+/// - `All` - the messages are acknowledged once they're persisted on disk, and replicated to the quorum once the replication is available.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Acks {
+    /// The messages are acknowledged once they're queued for appending, without waiting for the append.
+    None,
+    /// The messages are acknowledged once they're appended to the partition.
+    #[default]
+    Leader,
+    /// The messages are acknowledged once they're persisted on disk (and replicated to the quorum).
+    #[serde(alias = "quorum")]
+    All,
 }
 
 /// `Partitioning` is used to specify to which partition the messages should be sent.
@@ -99,6 +125,7 @@ impl Default for SendMessages {
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
             messages: vec![Message::default()],
+            acks: None,
         }
     }
 }
@@ -251,6 +278,50 @@ impl PartitioningKind {
     }
 }
 
+impl Acks {
+    /// Get the code of the acks.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            Acks::None => 1,
+            Acks::Leader => 2,
+            Acks::All => 3,
+        }
+    }
+
+    /// Get the acks from the provided code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(Acks::None),
+            2 => Ok(Acks::Leader),
+            3 => Ok(Acks::All),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for Acks {
+    type Err = IggyError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(Acks::None),
+            "leader" => Ok(Acks::Leader),
+            "all" | "quorum" => Ok(Acks::All),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for Acks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Acks::None => write!(f, "none"),
+            Acks::Leader => write!(f, "leader"),
+            Acks::All => write!(f, "all"),
+        }
+    }
+}
+
 impl Message {
     /// Create a new message with the optional ID, payload and headers.
     pub fn new(
@@ -272,6 +343,20 @@ impl Message {
         // ID + Length + Payload + Headers
         16 + 4 + self.payload.len() as u32 + header::get_headers_size_bytes(&self.headers)
     }
+
+    /// Extends the provided bytes with the message, without allocating the intermediate buffer.
+    pub fn extend(&self, bytes: &mut BytesMut) {
+        bytes.put_u128_le(self.id);
+        if let Some(headers) = &self.headers {
+            let headers_bytes = headers.as_bytes();
+            bytes.put_u32_le(headers_bytes.len() as u32);
+            bytes.put_slice(&headers_bytes);
+        } else {
+            bytes.put_u32_le(0);
+        }
+        bytes.put_u32_le(self.length);
+        bytes.put_slice(&self.payload);
+    }
 }
 
 impl Default for Message {
@@ -327,16 +412,7 @@ impl BytesSerializable for Partitioning {
 impl BytesSerializable for Message {
     fn as_bytes(&self) -> Bytes {
         let mut bytes = BytesMut::with_capacity(self.get_size_bytes() as usize);
-        bytes.put_u128_le(self.id);
-        if let Some(headers) = &self.headers {
-            let headers_bytes = headers.as_bytes();
-            bytes.put_u32_le(headers_bytes.len() as u32);
-            bytes.put_slice(&headers_bytes);
-        } else {
-            bytes.put_u32_le(0);
-        }
-        bytes.put_u32_le(self.length);
-        bytes.put_slice(&self.payload);
+        self.extend(&mut bytes);
         bytes.freeze()
     }
 
@@ -408,17 +484,22 @@ impl BytesSerializable for SendMessages {
         let key_bytes = self.partitioning.as_bytes();
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
-        let mut bytes = BytesMut::with_capacity(
+        let buffer_pool = BufferPool::global();
+        let mut bytes = buffer_pool.acquire(
             stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len() + messages_size as usize,
         );
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
+        let kind_position = bytes.len();
         bytes.put_slice(&key_bytes);
+        if let Some(acks) = self.acks {
+            bytes[kind_position] |= acks.as_code() << ACKS_SHIFT;
+        }
         for message in &self.messages {
-            bytes.put_slice(&message.as_bytes());
+            message.extend(&mut bytes);
         }
 
-        bytes.freeze()
+        buffer_pool.freeze(bytes)
     }
 
     fn from_bytes(bytes: Bytes) -> Result<SendMessages, IggyError> {
@@ -431,7 +512,26 @@ impl BytesSerializable for SendMessages {
         position += stream_id.get_size_bytes() as usize;
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes() as usize;
-        let key = Partitioning::from_bytes(bytes.slice(position..))?;
+        let acks = match bytes[position] >> ACKS_SHIFT {
+            0 => None,
+            code => Some(Acks::from_code(code)?),
+        };
+        let key = match acks {
+            Some(_) => {
+                let kind = PartitioningKind::from_code(bytes[position] & PARTITIONING_KIND_MASK)?;
+                let length = bytes[position + 1];
+                let value_position = position + 2;
+                if bytes.len() < value_position + length as usize {
+                    return Err(IggyError::InvalidCommand);
+                }
+                Partitioning {
+                    kind,
+                    length,
+                    value: bytes[value_position..value_position + length as usize].to_vec(),
+                }
+            }
+            None => Partitioning::from_bytes(bytes.slice(position..))?,
+        };
         position += key.get_size_bytes() as usize;
         let messages_payloads = bytes.slice(position..);
         position = 0;
@@ -447,6 +547,7 @@ impl BytesSerializable for SendMessages {
             topic_id,
             partitioning: key,
             messages,
+            acks,
         };
         command.validate()?;
         Ok(command)
@@ -512,6 +613,7 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             partitioning: Partitioning::partition_id(4),
             messages,
+            acks: None,
         };
 
         let bytes = command.as_bytes();
@@ -641,4 +743,44 @@ mod tests {
         let key = Partitioning::messages_key_str(&messages_key);
         assert!(key.is_err());
     }
+
+    #[test]
+    fn acks_should_be_serialized_and_deserialized_with_partitioning() {
+        for partitioning in [
+            Partitioning::balanced(),
+            Partitioning::partition_id(4),
+            Partitioning::messages_key_str("key").unwrap(),
+        ] {
+            for acks in [None, Some(Acks::None), Some(Acks::Leader), Some(Acks::All)] {
+                let command = SendMessages {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: Identifier::numeric(2).unwrap(),
+                    partitioning: partitioning.clone(),
+                    messages: vec![Message::from_str("hello").unwrap()],
+                    acks,
+                };
+
+                let deserialized_command = SendMessages::from_bytes(command.as_bytes()).unwrap();
+
+                assert_eq!(deserialized_command, command);
+            }
+        }
+    }
+
+    #[test]
+    fn command_without_acks_should_be_serialized_as_before() {
+        let command = SendMessages {
+            partitioning: Partitioning::partition_id(4),
+            ..SendMessages::default()
+        };
+        let stream_id_size = command.stream_id.get_size_bytes() as usize;
+        let topic_id_size = command.topic_id.get_size_bytes() as usize;
+
+        let bytes = command.as_bytes();
+
+        assert_eq!(
+            bytes[stream_id_size + topic_id_size],
+            PartitioningKind::PartitionId.as_code()
+        );
+    }
 }
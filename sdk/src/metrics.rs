@@ -0,0 +1,80 @@
+use crate::error::IggyError;
+use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
+
+/// The operation performed by the `IggyClient` which is reported to the `ClientMetrics`.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ClientOperation {
+    /// Sending the messages, either directly or as batches in the background.
+    SendMessages,
+    /// Polling the messages, either directly or in the background.
+    PollMessages,
+    /// Storing the consumer offset during the background polling.
+    StoreConsumerOffset,
+}
+
+impl ClientOperation {
+    /// Returns the name of the operation, which is used as the metric label.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClientOperation::SendMessages => "send_messages",
+            ClientOperation::PollMessages => "poll_messages",
+            ClientOperation::StoreConsumerOffset => "store_consumer_offset",
+        }
+    }
+}
+
+impl Display for ClientOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The trait represent the callbacks invoked by the `IggyClient` to record the client-side metrics,
+/// such as the send and poll latencies, the batch sizes, the retries and the errors.
+/// All the methods have the empty default implementation, so only the relevant ones have to be implemented.
+pub trait ClientMetrics: Send + Sync + Debug {
+    /// Invoked after the messages have been successfully sent to the server.
+    fn record_send(&self, _messages_count: u32, _latency: Duration) {}
+
+    /// Invoked after the messages have been successfully polled from the server.
+    fn record_poll(&self, _messages_count: u32, _latency: Duration) {}
+
+    /// Invoked when the failed operation is going to be retried in the background.
+    fn record_retry(&self, _operation: ClientOperation) {}
+
+    /// Invoked when the operation has failed.
+    fn record_error(&self, _operation: ClientOperation, _error: &IggyError) {}
+}
+
+/// The `ClientMetrics` implementation which records the metrics using the `metrics` crate facade,
+/// so they can be exported by any installed recorder (e.g. Prometheus exporter):
+/// - `iggy_client_send_latency_seconds` and `iggy_client_poll_latency_seconds` histograms.
+/// - `iggy_client_send_batch_size` and `iggy_client_poll_batch_size` histograms.
+/// - `iggy_client_retries_total` and `iggy_client_errors_total` counters with the `operation` label.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Default)]
+pub struct MetricsRecorder;
+
+#[cfg(feature = "metrics")]
+impl ClientMetrics for MetricsRecorder {
+    fn record_send(&self, messages_count: u32, latency: Duration) {
+        metrics::histogram!("iggy_client_send_latency_seconds").record(latency.as_secs_f64());
+        metrics::histogram!("iggy_client_send_batch_size").record(messages_count as f64);
+    }
+
+    fn record_poll(&self, messages_count: u32, latency: Duration) {
+        metrics::histogram!("iggy_client_poll_latency_seconds").record(latency.as_secs_f64());
+        metrics::histogram!("iggy_client_poll_batch_size").record(messages_count as f64);
+    }
+
+    fn record_retry(&self, operation: ClientOperation) {
+        metrics::counter!("iggy_client_retries_total", "operation" => operation.as_str())
+            .increment(1);
+    }
+
+    fn record_error(&self, operation: ClientOperation, _error: &IggyError) {
+        metrics::counter!("iggy_client_errors_total", "operation" => operation.as_str())
+            .increment(1);
+    }
+}
@@ -0,0 +1,30 @@
+use crate::error::IggyError;
+use bytes::Bytes;
+
+/// `BatchCommandResult` represents the result of a single command executed as part of the batch.
+/// It consists of the following fields:
+/// - `status`: the status code of the command, `0` if the command succeeded, otherwise the code of the error.
+/// - `payload`: the payload returned by the command in the binary format, empty if the command failed or has no response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchCommandResult {
+    /// The status code of the command, `0` if the command succeeded, otherwise the code of the error.
+    pub status: u32,
+    /// The payload returned by the command in the binary format.
+    pub payload: Bytes,
+}
+
+impl BatchCommandResult {
+    /// Returns true if the command succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.status == 0
+    }
+
+    /// Returns the payload if the command succeeded, otherwise the `InvalidResponse` error with the status code, the same as for the single command.
+    pub fn into_result(self) -> Result<Bytes, IggyError> {
+        if self.is_ok() {
+            return Ok(self.payload);
+        }
+
+        Err(IggyError::InvalidResponse(self.status))
+    }
+}
@@ -0,0 +1,75 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// `ConnectorInfo` represents the connector running inside the server, which either reads the records
+/// from the external system and appends them to the topic (source), or consumes the topic and writes
+/// the messages to the external system (sink).
+/// It consists of the following fields:
+/// - `name`: the unique name of the connector.
+/// - `kind`: the kind of the connector, e.g. `file_source` or `http_sink`.
+/// - `stream`: the stream (ID or name) to which the source appends or from which the sink consumes.
+/// - `topic`: the topic (ID or name) to which the source appends or from which the sink consumes.
+/// - `properties`: the settings specific to the kind of the connector.
+/// - `status`: the current status of the connector.
+/// - `error`: the error of the failed connector.
+/// - `processed_messages`: the number of the messages processed since the connector has been started.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ConnectorInfo {
+    /// The unique name of the connector.
+    pub name: String,
+    /// The kind of the connector, e.g. `file_source` or `http_sink`.
+    pub kind: String,
+    /// The stream (ID or name) to which the source appends or from which the sink consumes.
+    pub stream: String,
+    /// The topic (ID or name) to which the source appends or from which the sink consumes.
+    pub topic: String,
+    /// The settings specific to the kind of the connector.
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+    /// The current status of the connector.
+    pub status: ConnectorStatus,
+    /// The error of the failed connector.
+    pub error: Option<String>,
+    /// The number of the messages processed since the connector has been started.
+    pub processed_messages: u64,
+}
+
+/// `ConnectorStatus` represents the status of the connector.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorStatus {
+    /// The connector is running, the failures are retried.
+    Running,
+    /// The connector has exhausted the retries, it can be restarted.
+    Failed,
+}
+
+impl Display for ConnectorStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConnectorStatus::Running => write!(f, "running"),
+            ConnectorStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl ConnectorStatus {
+    /// Returns the code of the connector status.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ConnectorStatus::Running => 1,
+            ConnectorStatus::Failed => 2,
+        }
+    }
+
+    /// Returns the connector status from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(ConnectorStatus::Running),
+            2 => Ok(ConnectorStatus::Failed),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `ConsumerLimits` are the settings of the topic, which cap the number of consumers attached to it.
+/// It consists of the following fields:
+/// - `max_consumer_groups`: the optional maximum number of consumer groups in the topic, unlimited if not set.
+/// - `max_group_members`: the optional maximum number of members in each consumer group of the topic, unlimited if not set.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+pub struct ConsumerLimits {
+    /// The optional maximum number of consumer groups in the topic.
+    pub max_consumer_groups: Option<u32>,
+    /// The optional maximum number of members in each consumer group of the topic.
+    pub max_group_members: Option<u32>,
+}
+
+impl ConsumerLimits {
+    /// Checks whether another consumer group can be created, given the current number of the consumer groups.
+    pub fn allows_consumer_group(&self, consumer_groups_count: u32) -> bool {
+        self.max_consumer_groups
+            .is_none_or(|max_consumer_groups| consumer_groups_count < max_consumer_groups)
+    }
+
+    /// Checks whether another member can join the consumer group, given the current number of its members.
+    pub fn allows_group_member(&self, members_count: u32) -> bool {
+        self.max_group_members
+            .is_none_or(|max_group_members| members_count < max_group_members)
+    }
+}
+
+impl Validatable<IggyError> for ConsumerLimits {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.max_consumer_groups == Some(0) || self.max_group_members == Some(0) {
+            return Err(IggyError::InvalidConsumerLimits);
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for ConsumerLimits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}",
+            self.max_consumer_groups.unwrap_or(0),
+            self.max_group_members.unwrap_or(0)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_allow_any_number_of_consumers_given_no_limits() {
+        let limits = ConsumerLimits::default();
+
+        assert!(limits.allows_consumer_group(u32::MAX - 1));
+        assert!(limits.allows_group_member(u32::MAX - 1));
+    }
+
+    #[test]
+    fn should_not_allow_consumers_above_limits() {
+        let limits = ConsumerLimits {
+            max_consumer_groups: Some(2),
+            max_group_members: Some(3),
+        };
+
+        assert!(limits.allows_consumer_group(1));
+        assert!(!limits.allows_consumer_group(2));
+        assert!(limits.allows_group_member(2));
+        assert!(!limits.allows_group_member(3));
+    }
+}
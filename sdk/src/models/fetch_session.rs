@@ -0,0 +1,40 @@
+use crate::models::messages::PolledMessages;
+use serde::{Deserialize, Serialize};
+
+/// `FetchSession` represents the fetch session created on the server.
+/// It consists of the following fields:
+/// - `session_id`: the unique ID of the fetch session, scoped to the client connection.
+/// - `epoch`: the epoch to be sent with the next `FetchMessages` command.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+pub struct FetchSession {
+    /// The unique ID of the fetch session, scoped to the client connection.
+    pub session_id: u32,
+    /// The epoch to be sent with the next `FetchMessages` command.
+    pub epoch: u32,
+}
+
+/// `FetchedMessages` represents the messages fetched from the partitions of the fetch session.
+/// It consists of the following fields:
+/// - `session_id`: the unique ID of the fetch session.
+/// - `epoch`: the epoch to be sent with the next `FetchMessages` command.
+/// - `partitions`: the partitions with the new messages, the ones without them are omitted.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct FetchedMessages {
+    /// The unique ID of the fetch session.
+    pub session_id: u32,
+    /// The epoch to be sent with the next `FetchMessages` command.
+    pub epoch: u32,
+    /// The partitions with the new messages, the ones without them are omitted.
+    pub partitions: Vec<FetchedPartition>,
+}
+
+/// `FetchedPartition` represents the messages fetched from a single partition of the fetch session.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FetchedPartition {
+    /// The unique ID of the stream.
+    pub stream_id: u32,
+    /// The unique ID of the topic.
+    pub topic_id: u32,
+    /// The messages fetched from the partition.
+    pub messages: PolledMessages,
+}
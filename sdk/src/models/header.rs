@@ -9,6 +9,30 @@ use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
+/// The prefix of the header keys reserved for the system headers stamped by the server on append.
+/// The headers with this prefix set by the producers are removed by the server, except for the create timestamp.
+pub const SYSTEM_HEADER_PREFIX: &str = "$iggy.";
+/// The ID of the user who appended the message (`uint32`).
+pub const USER_ID_HEADER: &str = "$iggy.user_id";
+/// The ID of the client who appended the message, 0 for the HTTP API and the server itself (`uint32`).
+pub const CLIENT_ID_HEADER: &str = "$iggy.client_id";
+/// The time in microseconds since Unix epoch when the message was received by the server (`uint64`).
+pub const RECEIVE_TIMESTAMP_HEADER: &str = "$iggy.receive_timestamp";
+/// The transport used to append the message, e.g. `TCP` or `HTTP`, missing for the messages appended by the server itself (`string`).
+pub const TRANSPORT_HEADER: &str = "$iggy.transport";
+/// The time in microseconds since Unix epoch when the message was created by the producer (`uint64`),
+/// stored as the message timestamp for the topics with the `create_time` timestamp type and removed on append.
+pub const CREATE_TIMESTAMP_HEADER: &str = "$iggy.create_timestamp";
+/// The key used to calculate the partition of the message appended with the messages key partitioning (`raw`).
+pub const PARTITION_KEY_HEADER: &str = "$iggy.partition_key";
+/// The routing epoch of the topic when the message was appended with the messages key partitioning (`uint32`),
+/// incremented whenever the number of partitions changes, so the consumers can detect the changed key to partition mapping.
+pub const ROUTING_EPOCH_HEADER: &str = "$iggy.routing_epoch";
+
+/// The content type of the payload (`string`), set by the HTTP API for the messages sent as the raw bytes or the multipart form,
+/// and used as the content type of the payloads polled in the binary format.
+pub const CONTENT_TYPE_HEADER: &str = "content-type";
+
 /// Represents a header key with a unique name. The name is case-insensitive and wraps a string.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct HeaderKey(String);
@@ -25,6 +49,11 @@ impl HeaderKey {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Returns `true` if the key belongs to the reserved namespace of the system headers.
+    pub fn is_system(&self) -> bool {
+        self.0.starts_with(SYSTEM_HEADER_PREFIX)
+    }
 }
 
 impl Hash for HeaderKey {
@@ -631,6 +660,14 @@ mod tests {
         assert_eq!(error.as_code(), IggyError::InvalidHeaderKey.as_code());
     }
 
+    #[test]
+    fn header_key_should_be_system_only_for_reserved_prefix() {
+        assert!(HeaderKey::new(USER_ID_HEADER).unwrap().is_system());
+        assert!(HeaderKey::new("$IGGY.custom").unwrap().is_system());
+        assert!(!HeaderKey::new("iggy.user_id").unwrap().is_system());
+        assert!(!HeaderKey::new("key-1").unwrap().is_system());
+    }
+
     #[test]
     fn header_value_should_not_be_created_for_empty_value() {
         let header_value = HeaderValue::from(HeaderKind::Raw, &[]);
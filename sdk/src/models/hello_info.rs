@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// `HelloInfo` represents the result of the handshake between the client and the server.
+/// It consists of the following fields:
+/// - `protocol_version`: the version of the protocol negotiated for the connection.
+/// - `server_version`: the version of the server.
+/// - `features`: the features supported by both the client and the server.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HelloInfo {
+    /// The version of the protocol negotiated for the connection.
+    pub protocol_version: u32,
+    /// The version of the server.
+    pub server_version: String,
+    /// The features supported by both the client and the server.
+    pub features: Vec<String>,
+}
+
+impl HelloInfo {
+    /// Returns true if the feature is supported by both the client and the server.
+    pub fn supports(&self, feature: &str) -> bool {
+        self.features.iter().any(|supported| supported == feature)
+    }
+}
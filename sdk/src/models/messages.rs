@@ -11,6 +11,7 @@ use serde_with::base64::Base64;
 use serde_with::serde_as;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::ops::Range;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -19,6 +20,7 @@ use std::sync::Arc;
 /// - `partition_id`: the identifier of the partition.
 /// - `current_offset`: the current offset of the partition.
 /// - `messages`: the collection of messages.
+/// - `next_offset`: the offset to continue polling from, if the messages have been truncated to the max bytes.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PolledMessages {
     /// The identifier of the partition.
@@ -27,6 +29,34 @@ pub struct PolledMessages {
     pub current_offset: u64,
     /// The collection of messages.
     pub messages: Vec<Message>,
+    /// The offset of the first message left out, once the polled messages have exceeded the max bytes of the poll.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u64>,
+}
+
+/// The receipt of the messages appended to the partition, returned to the producer.
+/// It consists of the following fields:
+/// - `partition_id`: the identifier of the partition the messages have been appended to.
+/// - `base_offset`: the offset of the first appended message, the subsequent messages have the consecutive offsets.
+/// - `messages_count`: the number of the appended messages, lower than the number of the sent messages if the duplicates have been skipped by the deduplication.
+/// - `timestamp`: the log-append timestamp in microseconds, shared by all the messages appended at once.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+pub struct SendMessagesReceipt {
+    /// The identifier of the partition the messages have been appended to.
+    pub partition_id: u32,
+    /// The offset of the first appended message, the subsequent messages have the consecutive offsets.
+    pub base_offset: u64,
+    /// The number of the appended messages, lower than the number of the sent messages if the duplicates have been skipped by the deduplication.
+    pub messages_count: u32,
+    /// The log-append timestamp in microseconds, shared by all the messages appended at once.
+    pub timestamp: u64,
+}
+
+impl SendMessagesReceipt {
+    /// Returns the offsets of the appended messages, in the order they were sent (excluding the skipped duplicates).
+    pub fn offsets(&self) -> Range<u64> {
+        self.base_offset..self.base_offset + self.messages_count as u64
+    }
 }
 
 /// The single message that is polled from the partition.
@@ -132,7 +162,11 @@ impl Sizeable for Arc<Message> {
 impl Message {
     /// Creates a new message from the `Message` struct being part of `SendMessages` command.
     pub fn from_message(message: &send_messages::Message) -> Self {
-        let timestamp = IggyTimestamp::now().to_micros();
+        Self::from_message_with_timestamp(message, IggyTimestamp::now().to_micros())
+    }
+
+    /// Creates a new message from the `Message` struct being part of `SendMessages` command, with the provided timestamp.
+    pub fn from_message_with_timestamp(message: &send_messages::Message, timestamp: u64) -> Self {
         let checksum = checksum::calculate(&message.payload);
         let headers = message.headers.as_ref().cloned();
 
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// `Metadata` represents the topology of the streams, topics and partitions available to the user,
+/// so that the client can resolve all of them at once instead of fetching each stream and topic separately.
+/// It consists of the following fields:
+/// - `streams`: the collection of the streams with their topics.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+pub struct Metadata {
+    /// The collection of the streams with their topics.
+    pub streams: Vec<StreamMetadata>,
+}
+
+/// `StreamMetadata` represents the stream and its topics.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the stream.
+/// - `name`: the unique name of the stream.
+/// - `topics`: the collection of the topics in the stream.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct StreamMetadata {
+    /// The unique identifier (numeric) of the stream.
+    pub id: u32,
+    /// The unique name of the stream.
+    pub name: String,
+    /// The collection of the topics in the stream.
+    pub topics: Vec<TopicMetadata>,
+}
+
+/// `TopicMetadata` represents the topic and its partitions.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the topic.
+/// - `name`: the unique name of the topic within the stream.
+/// - `partitions_count`: the number of the partitions in the topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct TopicMetadata {
+    /// The unique identifier (numeric) of the topic.
+    pub id: u32,
+    /// The unique name of the topic within the stream.
+    pub name: String,
+    /// The number of the partitions in the topic.
+    pub partitions_count: u32,
+}
+
+impl Metadata {
+    /// Returns the stream by its numeric ID or name.
+    pub fn get_stream(&self, id_or_name: &str) -> Option<&StreamMetadata> {
+        match id_or_name.parse::<u32>() {
+            Ok(id) => self.streams.iter().find(|stream| stream.id == id),
+            Err(_) => self.streams.iter().find(|stream| stream.name == id_or_name),
+        }
+    }
+}
+
+impl StreamMetadata {
+    /// Returns the topic by its numeric ID or name.
+    pub fn get_topic(&self, id_or_name: &str) -> Option<&TopicMetadata> {
+        match id_or_name.parse::<u32>() {
+            Ok(id) => self.topics.iter().find(|topic| topic.id == id),
+            Err(_) => self.topics.iter().find(|topic| topic.name == id_or_name),
+        }
+    }
+}
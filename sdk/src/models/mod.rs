@@ -1,14 +1,27 @@
+pub mod batch_result;
 pub mod client_info;
+pub mod connector;
 pub mod consumer_group;
+pub mod consumer_limits;
 pub mod consumer_offset_info;
+pub mod fetch_session;
 pub mod header;
+pub mod hello_info;
 pub mod identity_info;
 pub mod messages;
+pub mod metadata;
 pub mod partition;
+pub mod permission_check;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod query_result;
+pub mod schema;
 pub mod stats;
 pub mod stream;
+pub mod tenant;
+pub mod timestamp_type;
 pub mod topic;
+pub mod topic_defaults;
+pub mod user_group;
 pub mod user_info;
 pub mod user_status;
@@ -24,3 +24,67 @@ pub struct Partition {
     /// The number of messages in the partition.
     pub messages_count: u64,
 }
+
+/// `PartitionDetails` represents the detailed information about a partition.
+/// It consists of the following fields:
+/// - `id`: unique identifier of the partition.
+/// - `created_at`: the timestamp of the partition creation.
+/// - `segments_count`: the number of segments in the partition.
+/// - `current_offset`: the current offset of the partition.
+/// - `size_bytes`: the size of the partition in bytes.
+/// - `messages_count`: the number of messages in the partition.
+/// - `segments`: the collection of segments in the partition.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PartitionDetails {
+    /// Unique identifier of the partition.
+    pub id: u32,
+    /// The timestamp of the partition creation.
+    pub created_at: u64,
+    /// The number of segments in the partition.
+    pub segments_count: u32,
+    /// The current offset of the partition.
+    pub current_offset: u64,
+    /// The size of the partition in bytes.
+    pub size_bytes: IggyByteSize,
+    /// The number of messages in the partition.
+    pub messages_count: u64,
+    /// The collection of segments in the partition.
+    pub segments: Vec<Segment>,
+}
+
+/// `PartitionEndOffset` represents the end offset of a partition.
+/// It consists of the following fields:
+/// - `partition_id`: unique identifier of the partition.
+/// - `end_offset`: the offset of the next message to be appended to the partition (0 if the partition is empty).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Copy, Clone)]
+pub struct PartitionEndOffset {
+    /// Unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset of the next message to be appended to the partition (0 if the partition is empty).
+    pub end_offset: u64,
+}
+
+/// `Segment` represents the information about a segment of a partition.
+/// It consists of the following fields:
+/// - `start_offset`: the offset of the first message in the segment.
+/// - `end_offset`: the offset of the last message in the segment.
+/// - `size_bytes`: the size of the segment in bytes.
+/// - `is_closed`: whether the segment is closed (read-only) or still appended to.
+/// - `message_expiry`: the optional expiry of the messages in seconds.
+/// - `is_expired`: whether the last message in the segment has expired, so the segment can be deleted.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Segment {
+    /// The offset of the first message in the segment.
+    pub start_offset: u64,
+    /// The offset of the last message in the segment.
+    pub end_offset: u64,
+    /// The size of the segment in bytes.
+    pub size_bytes: IggyByteSize,
+    /// Whether the segment is closed (read-only) or still appended to.
+    pub is_closed: bool,
+    /// The optional expiry of the messages in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_expiry: Option<u32>,
+    /// Whether the last message in the segment has expired, so the segment can be deleted.
+    pub is_expired: bool,
+}
@@ -0,0 +1,176 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `PermissionAction` represents the action which can be checked against the user's effective permissions.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionAction {
+    /// Read the server info, stats and clients.
+    #[default]
+    ReadServers,
+    /// Manage the server.
+    ManageServers,
+    /// Read the users.
+    ReadUsers,
+    /// Manage the users.
+    ManageUsers,
+    /// Read all the streams.
+    ReadStreams,
+    /// Create a new stream.
+    CreateStream,
+    /// Read the specific stream, requires the stream ID.
+    ReadStream,
+    /// Update, delete or purge the specific stream, requires the stream ID.
+    ManageStream,
+    /// Read the specific topic, requires the stream ID and the topic ID.
+    ReadTopic,
+    /// Create a new topic in the specific stream, requires the stream ID.
+    CreateTopic,
+    /// Update, delete or purge the specific topic, requires the stream ID and the topic ID.
+    ManageTopic,
+    /// Poll the messages from the specific topic, requires the stream ID and the topic ID.
+    PollMessages,
+    /// Send the messages to the specific topic, requires the stream ID and the topic ID.
+    SendMessages,
+    /// Create or delete the partitions of the specific topic, requires the stream ID and the topic ID.
+    ManagePartitions,
+    /// Create or delete the consumer groups of the specific topic, requires the stream ID and the topic ID.
+    ManageConsumerGroups,
+}
+
+impl PermissionAction {
+    /// Returns the code of the permission action.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PermissionAction::ReadServers => 1,
+            PermissionAction::ManageServers => 2,
+            PermissionAction::ReadUsers => 3,
+            PermissionAction::ManageUsers => 4,
+            PermissionAction::ReadStreams => 5,
+            PermissionAction::CreateStream => 6,
+            PermissionAction::ReadStream => 7,
+            PermissionAction::ManageStream => 8,
+            PermissionAction::ReadTopic => 9,
+            PermissionAction::CreateTopic => 10,
+            PermissionAction::ManageTopic => 11,
+            PermissionAction::PollMessages => 12,
+            PermissionAction::SendMessages => 13,
+            PermissionAction::ManagePartitions => 14,
+            PermissionAction::ManageConsumerGroups => 15,
+        }
+    }
+
+    /// Returns the permission action from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(PermissionAction::ReadServers),
+            2 => Ok(PermissionAction::ManageServers),
+            3 => Ok(PermissionAction::ReadUsers),
+            4 => Ok(PermissionAction::ManageUsers),
+            5 => Ok(PermissionAction::ReadStreams),
+            6 => Ok(PermissionAction::CreateStream),
+            7 => Ok(PermissionAction::ReadStream),
+            8 => Ok(PermissionAction::ManageStream),
+            9 => Ok(PermissionAction::ReadTopic),
+            10 => Ok(PermissionAction::CreateTopic),
+            11 => Ok(PermissionAction::ManageTopic),
+            12 => Ok(PermissionAction::PollMessages),
+            13 => Ok(PermissionAction::SendMessages),
+            14 => Ok(PermissionAction::ManagePartitions),
+            15 => Ok(PermissionAction::ManageConsumerGroups),
+            _ => Err(IggyError::InvalidPermissionAction),
+        }
+    }
+
+    /// Returns `true` if the action is performed on the specific stream.
+    pub fn requires_stream(&self) -> bool {
+        matches!(
+            self,
+            PermissionAction::ReadStream
+                | PermissionAction::ManageStream
+                | PermissionAction::ReadTopic
+                | PermissionAction::CreateTopic
+                | PermissionAction::ManageTopic
+                | PermissionAction::PollMessages
+                | PermissionAction::SendMessages
+                | PermissionAction::ManagePartitions
+                | PermissionAction::ManageConsumerGroups
+        )
+    }
+
+    /// Returns `true` if the action is performed on the specific topic.
+    pub fn requires_topic(&self) -> bool {
+        matches!(
+            self,
+            PermissionAction::ReadTopic
+                | PermissionAction::ManageTopic
+                | PermissionAction::PollMessages
+                | PermissionAction::SendMessages
+                | PermissionAction::ManagePartitions
+                | PermissionAction::ManageConsumerGroups
+        )
+    }
+}
+
+impl FromStr for PermissionAction {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "read_servers" => Ok(PermissionAction::ReadServers),
+            "manage_servers" => Ok(PermissionAction::ManageServers),
+            "read_users" => Ok(PermissionAction::ReadUsers),
+            "manage_users" => Ok(PermissionAction::ManageUsers),
+            "read_streams" => Ok(PermissionAction::ReadStreams),
+            "create_stream" => Ok(PermissionAction::CreateStream),
+            "read_stream" => Ok(PermissionAction::ReadStream),
+            "manage_stream" => Ok(PermissionAction::ManageStream),
+            "read_topic" => Ok(PermissionAction::ReadTopic),
+            "create_topic" => Ok(PermissionAction::CreateTopic),
+            "manage_topic" => Ok(PermissionAction::ManageTopic),
+            "poll_messages" => Ok(PermissionAction::PollMessages),
+            "send_messages" => Ok(PermissionAction::SendMessages),
+            "manage_partitions" => Ok(PermissionAction::ManagePartitions),
+            "manage_consumer_groups" => Ok(PermissionAction::ManageConsumerGroups),
+            _ => Err(IggyError::InvalidPermissionAction),
+        }
+    }
+}
+
+impl Display for PermissionAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PermissionAction::ReadServers => write!(f, "read_servers"),
+            PermissionAction::ManageServers => write!(f, "manage_servers"),
+            PermissionAction::ReadUsers => write!(f, "read_users"),
+            PermissionAction::ManageUsers => write!(f, "manage_users"),
+            PermissionAction::ReadStreams => write!(f, "read_streams"),
+            PermissionAction::CreateStream => write!(f, "create_stream"),
+            PermissionAction::ReadStream => write!(f, "read_stream"),
+            PermissionAction::ManageStream => write!(f, "manage_stream"),
+            PermissionAction::ReadTopic => write!(f, "read_topic"),
+            PermissionAction::CreateTopic => write!(f, "create_topic"),
+            PermissionAction::ManageTopic => write!(f, "manage_topic"),
+            PermissionAction::PollMessages => write!(f, "poll_messages"),
+            PermissionAction::SendMessages => write!(f, "send_messages"),
+            PermissionAction::ManagePartitions => write!(f, "manage_partitions"),
+            PermissionAction::ManageConsumerGroups => write!(f, "manage_consumer_groups"),
+        }
+    }
+}
+
+/// `PermissionCheck` represents the result of checking the action against the user's effective permissions.
+/// It consists of the following fields:
+/// - `allowed`: whether the action would be allowed.
+/// - `rule`: the permission granting the action (e.g. `streams[1].poll_messages`) or the reason of denying it.
+/// - `group_id`: the ID of the user group the granting permission comes from, `None` if it's the user's own permission.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct PermissionCheck {
+    /// Whether the action would be allowed.
+    pub allowed: bool,
+    /// The permission granting the action or the reason of denying it.
+    pub rule: String,
+    /// The ID of the user group the granting permission comes from.
+    pub group_id: Option<u32>,
+}
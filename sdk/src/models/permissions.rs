@@ -77,6 +77,11 @@ pub struct GlobalPermissions {
 
     /// `send_messages` permission allows to send messages to all the streams and theirs topics.
     pub send_messages: bool,
+
+    /// `auto_create_topics` permission allows to create the missing topics in all the streams when sending the messages to them,
+    /// if the topic auto-creation is enabled on the server.
+    #[serde(default)]
+    pub auto_create_topics: bool,
 }
 
 /// `StreamPermissions` are applied to a specific stream and its all topics. If you want to define granular permissions for each topic, use the `topics` field.
@@ -117,6 +122,29 @@ pub struct StreamPermissions {
 
     /// The `topics` field allows to define the granular permissions for each topic of a stream.
     pub topics: Option<HashMap<u32, TopicPermissions>>,
+
+    /// `auto_create_topics` permission allows to create the missing topics in the stream when sending the messages to them,
+    /// if the topic auto-creation is enabled on the server.
+    #[serde(default)]
+    pub auto_create_topics: bool,
+
+    /// `manage_partitions` permission allows to manage the partitions of all the topics of a stream,
+    /// without the permission to manage the topics themselves. Also, it allows to read the topics.
+    /// Additionally, the following methods can be invoked:
+    /// - create_partitions
+    /// - delete_partitions
+    #[serde(default)]
+    pub manage_partitions: bool,
+
+    /// `manage_consumer_groups` permission allows to manage the consumer groups of all the topics of a stream,
+    /// without the permission to manage the topics themselves. Also, it allows to read the topics.
+    /// Additionally, the following methods can be invoked:
+    /// - create_consumer_group
+    /// - delete_consumer_group
+    /// - get_consumer_group
+    /// - get_consumer_groups
+    #[serde(default)]
+    pub manage_consumer_groups: bool,
 }
 
 /// `TopicPermissions` are applied to a specific topic of a stream. This is the lowest level of permissions.
@@ -149,6 +177,7 @@ impl Permissions {
                 read_topics: true,
                 poll_messages: true,
                 send_messages: true,
+                auto_create_topics: true,
             },
             streams: None,
         }
@@ -168,6 +197,10 @@ impl Display for Permissions {
         result.push_str(&format!("read_topics: {}\n", self.global.read_topics));
         result.push_str(&format!("poll_messages: {}\n", self.global.poll_messages));
         result.push_str(&format!("send_messages: {}\n", self.global.send_messages));
+        result.push_str(&format!(
+            "auto_create_topics: {}\n",
+            self.global.auto_create_topics
+        ));
         if let Some(streams) = &self.streams {
             for (stream_id, stream) in streams {
                 result.push_str(&format!("stream_id: {}\n", stream_id));
@@ -177,6 +210,18 @@ impl Display for Permissions {
                 result.push_str(&format!("read_topics: {}\n", stream.read_topics));
                 result.push_str(&format!("poll_messages: {}\n", stream.poll_messages));
                 result.push_str(&format!("send_messages: {}\n", stream.send_messages));
+                result.push_str(&format!(
+                    "auto_create_topics: {}\n",
+                    stream.auto_create_topics
+                ));
+                result.push_str(&format!(
+                    "manage_partitions: {}\n",
+                    stream.manage_partitions
+                ));
+                result.push_str(&format!(
+                    "manage_consumer_groups: {}\n",
+                    stream.manage_consumer_groups
+                ));
                 if let Some(topics) = &stream.topics {
                     for (topic_id, topic) in topics {
                         result.push_str(&format!("topic_id: {}\n", topic_id));
@@ -206,6 +251,7 @@ impl BytesSerializable for Permissions {
         bytes.put_u8(if self.global.read_topics { 1 } else { 0 });
         bytes.put_u8(if self.global.poll_messages { 1 } else { 0 });
         bytes.put_u8(if self.global.send_messages { 1 } else { 0 });
+        bytes.put_u8(if self.global.auto_create_topics { 1 } else { 0 });
         if let Some(streams) = &self.streams {
             bytes.put_u8(1);
             let streams_count = streams.len();
@@ -218,6 +264,9 @@ impl BytesSerializable for Permissions {
                 bytes.put_u8(if stream.read_topics { 1 } else { 0 });
                 bytes.put_u8(if stream.poll_messages { 1 } else { 0 });
                 bytes.put_u8(if stream.send_messages { 1 } else { 0 });
+                bytes.put_u8(if stream.auto_create_topics { 1 } else { 0 });
+                bytes.put_u8(if stream.manage_partitions { 1 } else { 0 });
+                bytes.put_u8(if stream.manage_consumer_groups { 1 } else { 0 });
                 if let Some(topics) = &stream.topics {
                     bytes.put_u8(1);
                     let topics_count = topics.len();
@@ -266,6 +315,7 @@ impl BytesSerializable for Permissions {
         let read_topics = bytes.get_u8() == 1;
         let poll_messages = bytes.get_u8() == 1;
         let send_messages = bytes.get_u8() == 1;
+        let auto_create_topics = bytes.get_u8() == 1;
         let mut streams = None;
         if bytes.get_u8() == 1 {
             let mut streams_map = HashMap::new();
@@ -277,6 +327,9 @@ impl BytesSerializable for Permissions {
                 let read_topics = bytes.get_u8() == 1;
                 let poll_messages = bytes.get_u8() == 1;
                 let send_messages = bytes.get_u8() == 1;
+                let auto_create_topics = bytes.get_u8() == 1;
+                let manage_partitions = bytes.get_u8() == 1;
+                let manage_consumer_groups = bytes.get_u8() == 1;
                 let mut topics = None;
                 if bytes.get_u8() == 1 {
                     let mut topics_map = HashMap::new();
@@ -311,6 +364,9 @@ impl BytesSerializable for Permissions {
                         poll_messages,
                         send_messages,
                         topics,
+                        auto_create_topics,
+                        manage_partitions,
+                        manage_consumer_groups,
                     },
                 );
                 if bytes.get_u8() == 0 {
@@ -331,6 +387,7 @@ impl BytesSerializable for Permissions {
                 read_topics,
                 poll_messages,
                 send_messages,
+                auto_create_topics,
             },
             streams,
         })
@@ -355,6 +412,7 @@ mod tests {
                 read_topics: true,
                 poll_messages: true,
                 send_messages: true,
+                auto_create_topics: false,
             },
             streams: Some(HashMap::from([
                 (
@@ -386,6 +444,9 @@ mod tests {
                                 },
                             ),
                         ])),
+                        auto_create_topics: true,
+                        manage_partitions: true,
+                        manage_consumer_groups: false,
                     },
                 ),
                 (
@@ -398,6 +459,9 @@ mod tests {
                         poll_messages: true,
                         send_messages: true,
                         topics: None,
+                        auto_create_topics: false,
+                        manage_partitions: false,
+                        manage_consumer_groups: true,
                     },
                 ),
             ])),
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// `QueryResult` represents the result of the messages query.
+/// It consists of the following fields:
+/// - `columns`: the names of the selected columns.
+/// - `rows`: the values of the selected columns for each matching message, in the order of the columns.
+/// - `next_offset`: the offset from which the query can be continued, if the scan was stopped by the server limit.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct QueryResult {
+    /// The names of the selected columns.
+    pub columns: Vec<String>,
+    /// The values of the selected columns for each matching message, in the order of the columns.
+    pub rows: Vec<Vec<Value>>,
+    /// The offset from which the query can be continued, if the scan was stopped by the limit of the messages
+    /// scanned by a single query, rather than by reaching the end offset or the limit of the rows.
+    #[serde(default)]
+    pub next_offset: Option<u64>,
+}
@@ -0,0 +1,148 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `SchemaInfo` represents the single version of the schema registered for the subject.
+/// It consists of the following fields:
+/// - `id`: the unique ID of the schema, which is used to tag the messages.
+/// - `subject`: the name of the subject the schema was registered for.
+/// - `version`: the version of the schema within the subject, starting from 1.
+/// - `format`: the format of the schema: JSON Schema, Avro or Protobuf.
+/// - `schema`: the schema definition.
+/// - `created_at`: the timestamp when the schema was registered.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct SchemaInfo {
+    /// The unique ID of the schema, which is used to tag the messages.
+    pub id: u32,
+    /// The name of the subject the schema was registered for.
+    pub subject: String,
+    /// The version of the schema within the subject, starting from 1.
+    pub version: u32,
+    /// The format of the schema: JSON Schema, Avro or Protobuf.
+    pub format: SchemaFormat,
+    /// The schema definition.
+    pub schema: String,
+    /// The timestamp when the schema was registered.
+    pub created_at: u64,
+}
+
+/// `SchemaFormat` defines how the schema definition is interpreted, and how the message payloads produced with it can be decoded.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaFormat {
+    /// The JSON Schema, the message payloads are JSON documents.
+    #[default]
+    Json,
+    /// The Avro schema in the JSON form, the message payloads are Avro binary encoded.
+    Avro,
+    /// The `.proto` file content, the message payloads are Protobuf binary encoded.
+    Protobuf,
+}
+
+impl FromStr for SchemaFormat {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "json" => Ok(SchemaFormat::Json),
+            "avro" => Ok(SchemaFormat::Avro),
+            "protobuf" | "proto" => Ok(SchemaFormat::Protobuf),
+            _ => Err(IggyError::InvalidSchemaFormat),
+        }
+    }
+}
+
+impl Display for SchemaFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaFormat::Json => write!(f, "json"),
+            SchemaFormat::Avro => write!(f, "avro"),
+            SchemaFormat::Protobuf => write!(f, "protobuf"),
+        }
+    }
+}
+
+impl SchemaFormat {
+    /// Returns the code of the schema format.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaFormat::Json => 1,
+            SchemaFormat::Avro => 2,
+            SchemaFormat::Protobuf => 3,
+        }
+    }
+
+    /// Returns the schema format from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaFormat::Json),
+            2 => Ok(SchemaFormat::Avro),
+            3 => Ok(SchemaFormat::Protobuf),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+/// `SchemaCompatibility` defines which changes are allowed when registering the new version of the schema for the subject.
+/// The new schema is always checked against the latest version registered for the subject.
+/// The compatibility is checked only for the JSON Schema, for the other formats only the format itself can't be changed.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaCompatibility {
+    /// No compatibility checks are performed.
+    None,
+    /// The consumers using the new schema can read the messages produced with the latest one.
+    #[default]
+    Backward,
+    /// The consumers using the latest schema can read the messages produced with the new one.
+    Forward,
+    /// The new schema is both backward and forward compatible with the latest one.
+    Full,
+}
+
+impl FromStr for SchemaCompatibility {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(SchemaCompatibility::None),
+            "backward" => Ok(SchemaCompatibility::Backward),
+            "forward" => Ok(SchemaCompatibility::Forward),
+            "full" => Ok(SchemaCompatibility::Full),
+            _ => Err(IggyError::InvalidSchemaCompatibility),
+        }
+    }
+}
+
+impl Display for SchemaCompatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaCompatibility::None => write!(f, "none"),
+            SchemaCompatibility::Backward => write!(f, "backward"),
+            SchemaCompatibility::Forward => write!(f, "forward"),
+            SchemaCompatibility::Full => write!(f, "full"),
+        }
+    }
+}
+
+impl SchemaCompatibility {
+    /// Returns the code of the schema compatibility.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaCompatibility::None => 1,
+            SchemaCompatibility::Backward => 2,
+            SchemaCompatibility::Forward => 3,
+            SchemaCompatibility::Full => 4,
+        }
+    }
+
+    /// Returns the schema compatibility from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaCompatibility::None),
+            2 => Ok(SchemaCompatibility::Backward),
+            3 => Ok(SchemaCompatibility::Forward),
+            4 => Ok(SchemaCompatibility::Full),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -46,4 +46,50 @@ pub struct Stats {
     pub os_version: String,
     /// The version of the kernel.
     pub kernel_version: String,
+    /// The total size of the messages which haven't been persisted on disk yet.
+    #[serde(default)]
+    pub unsaved_messages_size: IggyByteSize,
+    /// The total size of the cached messages.
+    #[serde(default)]
+    pub cached_messages_size: IggyByteSize,
+    /// The total size of the cached indexes.
+    #[serde(default)]
+    pub cached_indexes_size: IggyByteSize,
+    /// The memory budget for the unsaved messages and caches, `0` if the budget is disabled.
+    #[serde(default)]
+    pub memory_budget: IggyByteSize,
+    /// The runtimes of the server, the storage and background ones are listed only if they're dedicated.
+    #[serde(default)]
+    pub runtimes: Vec<RuntimeStats>,
+    /// The free space on the disk of the data path.
+    #[serde(default)]
+    pub disk_free_space: IggyByteSize,
+    /// The total space on the disk of the data path.
+    #[serde(default)]
+    pub disk_total_space: IggyByteSize,
+}
+
+/// `RuntimeStats` represents the statistics of the runtime, on which the server handles the requests, flushes the storage or runs the background jobs.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RuntimeStats {
+    /// The name of the runtime, e.g. `io`, `storage` or `background`.
+    pub name: String,
+    /// The number of the worker threads.
+    pub workers_count: u32,
+    /// The number of the tasks which are currently alive.
+    pub alive_tasks_count: u64,
+    /// The number of the tasks waiting in the global queue of the runtime.
+    pub global_queue_depth: u64,
+    /// The total time the worker threads have been busy, in microseconds.
+    pub busy_duration: u64,
+}
+
+impl Stats {
+    /// Returns the memory held by the unsaved messages and caches, which is limited by the memory budget.
+    pub fn memory_budget_usage(&self) -> IggyByteSize {
+        (self.unsaved_messages_size.as_bytes_u64()
+            + self.cached_messages_size.as_bytes_u64()
+            + self.cached_indexes_size.as_bytes_u64())
+        .into()
+    }
 }
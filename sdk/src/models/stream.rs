@@ -1,19 +1,26 @@
 use crate::models::topic::Topic;
+use crate::models::topic_defaults::TopicDefaults;
 use crate::utils::byte_size::IggyByteSize;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// `Stream` represents the highest level of logical separation of data.
 /// It consists of the following fields:
 /// - `id`: the unique identifier (numeric) of the stream.
+/// - `uuid`: the immutable UUID of the stream, which is kept across renames and ID reuse.
 /// - `created_at`: the timestamp when the stream was created.
 /// - `name`: the unique name of the stream.
 /// - `size_bytes`: the total size of the stream in bytes.
 /// - `messages_count`: the total number of messages in the stream.
 /// - `topics_count`: the total number of topics in the stream.
+/// - `topic_defaults`: the settings inherited by the topics created in the stream without the explicit values.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Stream {
     /// The unique identifier (numeric) of the stream.
     pub id: u32,
+    /// The immutable UUID of the stream, which is kept across renames and ID reuse.
+    #[serde(default)]
+    pub uuid: Uuid,
     /// The timestamp when the stream was created.
     pub created_at: u64,
     /// The unique name of the stream.
@@ -24,21 +31,29 @@ pub struct Stream {
     pub messages_count: u64,
     /// The total number of topics in the stream.
     pub topics_count: u32,
+    /// The settings inherited by the topics created in the stream without the explicit values.
+    #[serde(default)]
+    pub topic_defaults: TopicDefaults,
 }
 
 /// `StreamDetails` represents the detailed information about the stream.
 /// It consists of the following fields:
 /// - `id`: the unique identifier (numeric) of the stream.
+/// - `uuid`: the immutable UUID of the stream, which is kept across renames and ID reuse.
 /// - `created_at`: the timestamp when the stream was created.
 /// - `name`: the unique name of the stream.
 /// - `size_bytes`: the total size of the stream in bytes.
 /// - `messages_count`: the total number of messages in the stream.
 /// - `topics_count`: the total number of topics in the stream.
+/// - `topic_defaults`: the settings inherited by the topics created in the stream without the explicit values.
 /// - `topics`: the list of topics in the stream.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StreamDetails {
     /// The unique identifier (numeric) of the stream.
     pub id: u32,
+    /// The immutable UUID of the stream, which is kept across renames and ID reuse.
+    #[serde(default)]
+    pub uuid: Uuid,
     /// The timestamp when the stream was created.
     pub created_at: u64,
     /// The unique name of the stream.
@@ -49,6 +64,9 @@ pub struct StreamDetails {
     pub messages_count: u64,
     /// The total number of topics in the stream.
     pub topics_count: u32,
+    /// The settings inherited by the topics created in the stream without the explicit values.
+    #[serde(default)]
+    pub topic_defaults: TopicDefaults,
     /// The collection of topics in the stream.
     pub topics: Vec<Topic>,
 }
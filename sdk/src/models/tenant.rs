@@ -0,0 +1,143 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `Tenant` represents the namespace above the streams, which isolates the resources and users of a single team.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the tenant.
+/// - `created_at`: the timestamp when the tenant was created.
+/// - `name`: the unique name of the tenant, used as the prefix of its streams names.
+/// - `quotas`: the limits of the resources the tenant can use.
+/// - `streams_count`: the number of streams owned by the tenant.
+/// - `topics_count`: the number of topics in the streams owned by the tenant.
+/// - `partitions_count`: the number of partitions in the streams owned by the tenant.
+/// - `size`: the total size of the messages in the streams owned by the tenant.
+/// - `users_count`: the number of users assigned to the tenant.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Tenant {
+    /// The unique identifier (numeric) of the tenant.
+    pub id: u32,
+    /// The timestamp when the tenant was created.
+    pub created_at: u64,
+    /// The unique name of the tenant, used as the prefix of its streams names.
+    pub name: String,
+    /// The limits of the resources the tenant can use.
+    pub quotas: TenantQuotas,
+    /// The number of streams owned by the tenant.
+    pub streams_count: u32,
+    /// The number of topics in the streams owned by the tenant.
+    pub topics_count: u32,
+    /// The number of partitions in the streams owned by the tenant.
+    pub partitions_count: u32,
+    /// The total size of the messages in the streams owned by the tenant.
+    pub size: IggyByteSize,
+    /// The number of users assigned to the tenant.
+    pub users_count: u32,
+}
+
+/// `TenantQuotas` defines the limits of the resources the tenant can use, `None` means that the resource is unlimited.
+/// It consists of the following fields:
+/// - `max_streams`: the maximum number of streams.
+/// - `max_topics`: the maximum number of topics across all the streams.
+/// - `max_partitions`: the maximum number of partitions across all the topics.
+/// - `max_size`: the maximum total size of the messages across all the streams.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+pub struct TenantQuotas {
+    /// The maximum number of streams.
+    #[serde(default)]
+    pub max_streams: Option<u32>,
+    /// The maximum number of topics across all the streams.
+    #[serde(default)]
+    pub max_topics: Option<u32>,
+    /// The maximum number of partitions across all the topics.
+    #[serde(default)]
+    pub max_partitions: Option<u32>,
+    /// The maximum total size of the messages across all the streams.
+    #[serde(default)]
+    pub max_size: Option<IggyByteSize>,
+}
+
+impl TenantQuotas {
+    /// The size of the quotas in the binary format.
+    pub const SIZE_BYTES: usize = 20;
+}
+
+impl BytesSerializable for TenantQuotas {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(Self::SIZE_BYTES);
+        bytes.put_u32_le(self.max_streams.unwrap_or(0));
+        bytes.put_u32_le(self.max_topics.unwrap_or(0));
+        bytes.put_u32_le(self.max_partitions.unwrap_or(0));
+        match self.max_size {
+            Some(max_size) => bytes.put_u64_le(max_size.as_bytes_u64()),
+            None => bytes.put_u64_le(0),
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        if bytes.len() != Self::SIZE_BYTES {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let max_streams = u32::from_le_bytes(bytes[..4].try_into()?);
+        let max_topics = u32::from_le_bytes(bytes[4..8].try_into()?);
+        let max_partitions = u32::from_le_bytes(bytes[8..12].try_into()?);
+        let max_size = u64::from_le_bytes(bytes[12..20].try_into()?);
+        Ok(TenantQuotas {
+            max_streams: (max_streams > 0).then_some(max_streams),
+            max_topics: (max_topics > 0).then_some(max_topics),
+            max_partitions: (max_partitions > 0).then_some(max_partitions),
+            max_size: (max_size > 0).then_some(IggyByteSize::from(max_size)),
+        })
+    }
+}
+
+impl Display for TenantQuotas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let limit = |value: Option<u32>| match value {
+            Some(value) => value.to_string(),
+            None => "unlimited".to_string(),
+        };
+        let max_size = match self.max_size {
+            Some(max_size) => max_size.to_string(),
+            None => "unlimited".to_string(),
+        };
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            limit(self.max_streams),
+            limit(self.max_topics),
+            limit(self.max_partitions),
+            max_size
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotas_should_be_serialized_and_deserialized_with_zero_as_unlimited() {
+        let quotas = TenantQuotas {
+            max_streams: Some(10),
+            max_topics: None,
+            max_partitions: Some(100),
+            max_size: Some(IggyByteSize::from(1_000_000)),
+        };
+
+        let bytes = quotas.as_bytes();
+        assert_eq!(bytes.len(), TenantQuotas::SIZE_BYTES);
+        assert_eq!(u32::from_le_bytes(bytes[4..8].try_into().unwrap()), 0);
+
+        let deserialized_quotas = TenantQuotas::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized_quotas, quotas);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `TimestampType` defines which timestamp is stored with the messages appended to the topic,
+/// and therefore used by the time index queries and the message expiry.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampType {
+    /// The time when the message was appended by the server.
+    #[default]
+    LogAppendTime,
+    /// The time when the message was created by the producer, provided in the `$iggy.create_timestamp` header,
+    /// or the append time if the header is missing.
+    CreateTime,
+}
+
+impl FromStr for TimestampType {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "log_append_time" => Ok(TimestampType::LogAppendTime),
+            "create_time" => Ok(TimestampType::CreateTime),
+            _ => Err(IggyError::InvalidTimestampType),
+        }
+    }
+}
+
+impl Display for TimestampType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimestampType::LogAppendTime => write!(f, "log_append_time"),
+            TimestampType::CreateTime => write!(f, "create_time"),
+        }
+    }
+}
+
+impl TimestampType {
+    /// Returns the code of the timestamp type.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            TimestampType::LogAppendTime => 0,
+            TimestampType::CreateTime => 1,
+        }
+    }
+
+    /// Returns the timestamp type from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            0 => Ok(TimestampType::LogAppendTime),
+            1 => Ok(TimestampType::CreateTime),
+            _ => Err(IggyError::InvalidTimestampType),
+        }
+    }
+}
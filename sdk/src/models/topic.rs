@@ -1,9 +1,13 @@
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::models::consumer_limits::ConsumerLimits;
 use crate::{models::partition::Partition, utils::byte_size::IggyByteSize};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// `Topic` represents the medium level of logical separation of data as it's a part of the stream.
 /// It consists of the following fields:
 /// - `id`: the unique identifier (numeric) of the topic.
+/// - `uuid`: the immutable UUID of the topic, which is kept across renames and ID reuse.
 /// - `created_at`: the timestamp when the topic was created.
 /// - `name`: the unique name of the topic.
 /// - `size`: the total size of the topic in bytes.
@@ -12,10 +16,18 @@ use serde::{Deserialize, Serialize};
 /// - `replication_factor`: replication factor for the topic.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
+/// - `routing_epoch`: the number of times the partitions count has changed, i.e. the key to partition mapping.
+/// - `compression_algorithm`: the compression algorithm of the topic data, inherited from the stream or the server default.
+/// - `frozen`: whether the topic is read-only, i.e. the messages can't be sent to it, but can still be polled.
+/// - `consumer_limits`: the optional maximum number of consumer groups in the topic and members in each of them.
+/// - `max_message_size`: the optional maximum size of the message payload, either set for the topic or the server default.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Topic {
     /// The unique identifier (numeric) of the topic.
     pub id: u32,
+    /// The immutable UUID of the topic, which is kept across renames and ID reuse.
+    #[serde(default)]
+    pub uuid: Uuid,
     /// The timestamp when the topic was created.
     pub created_at: u64,
     /// The unique name of the topic.
@@ -33,11 +45,27 @@ pub struct Topic {
     pub messages_count: u64,
     /// The total number of partitions in the topic.
     pub partitions_count: u32,
+    /// The number of times the partitions count has changed, i.e. the key to partition mapping.
+    #[serde(default)]
+    pub routing_epoch: u32,
+    /// The compression algorithm of the topic data, inherited from the stream or the server default.
+    #[serde(default)]
+    pub compression_algorithm: CompressionAlgorithm,
+    /// Whether the topic is read-only, i.e. the messages can't be sent to it, but can still be polled.
+    #[serde(default)]
+    pub frozen: bool,
+    /// The optional maximum number of consumer groups in the topic and members in each of them.
+    #[serde(default)]
+    pub consumer_limits: ConsumerLimits,
+    /// The optional maximum size of the message payload, either set for the topic or the server default.
+    #[serde(default)]
+    pub max_message_size: Option<IggyByteSize>,
 }
 
 /// `TopicDetails` represents the detailed information about the topic.
 /// It consists of the following fields:
 /// - `id`: the unique identifier (numeric) of the topic.
+/// - `uuid`: the immutable UUID of the topic, which is kept across renames and ID reuse.
 /// - `created_at`: the timestamp when the topic was created.
 /// - `name`: the unique name of the topic.
 /// - `size`: the total size of the topic.
@@ -46,11 +74,20 @@ pub struct Topic {
 /// - `replication_factor`: replication factor for the topic.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
+/// - `routing_epoch`: the number of times the partitions count has changed, i.e. the key to partition mapping.
+/// - `compression_algorithm`: the compression algorithm of the topic data, inherited from the stream or the server default.
+/// - `frozen`: whether the topic is read-only, i.e. the messages can't be sent to it, but can still be polled.
+/// - `consumer_limits`: the optional maximum number of consumer groups in the topic and members in each of them.
+/// - `max_message_size`: the optional maximum size of the message payload, either set for the topic or the server default.
+/// - `message_schema`: the optional JSON Schema the payloads of the messages sent to the topic must conform to.
 /// - `partitions`: the collection of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TopicDetails {
     /// The unique identifier (numeric) of the topic.
     pub id: u32,
+    /// The immutable UUID of the topic, which is kept across renames and ID reuse.
+    #[serde(default)]
+    pub uuid: Uuid,
     /// The timestamp when the topic was created.
     pub created_at: u64,
     /// The unique name of the topic.
@@ -68,6 +105,24 @@ pub struct TopicDetails {
     pub messages_count: u64,
     /// The total number of partitions in the topic.
     pub partitions_count: u32,
+    /// The number of times the partitions count has changed, i.e. the key to partition mapping.
+    #[serde(default)]
+    pub routing_epoch: u32,
+    /// The compression algorithm of the topic data, inherited from the stream or the server default.
+    #[serde(default)]
+    pub compression_algorithm: CompressionAlgorithm,
+    /// Whether the topic is read-only, i.e. the messages can't be sent to it, but can still be polled.
+    #[serde(default)]
+    pub frozen: bool,
+    /// The optional maximum number of consumer groups in the topic and members in each of them.
+    #[serde(default)]
+    pub consumer_limits: ConsumerLimits,
+    /// The optional maximum size of the message payload, either set for the topic or the server default.
+    #[serde(default)]
+    pub max_message_size: Option<IggyByteSize>,
+    /// The optional JSON Schema the payloads of the messages sent to the topic must conform to.
+    #[serde(default)]
+    pub message_schema: Option<String>,
     /// The collection of partitions in the topic.
     pub partitions: Vec<Partition>,
 }
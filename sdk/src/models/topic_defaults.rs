@@ -0,0 +1,55 @@
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `TopicDefaults` are the settings set at the stream level, which are inherited by the topics created in the stream without the explicit values.
+/// It consists of the following fields:
+/// - `message_expiry`: the optional message expiry in seconds.
+/// - `max_topic_size`: the optional maximum size of the topic.
+/// - `compression_algorithm`: the optional compression algorithm of the topic data, the server default is used if not set.
+/// - `replication_factor`: the optional replication factor of the topic, 1 is used if not set.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+pub struct TopicDefaults {
+    /// The optional message expiry in seconds.
+    pub message_expiry: Option<u32>,
+    /// The optional maximum size of the topic.
+    pub max_topic_size: Option<IggyByteSize>,
+    /// The optional compression algorithm of the topic data.
+    pub compression_algorithm: Option<CompressionAlgorithm>,
+    /// The optional replication factor of the topic.
+    pub replication_factor: Option<u8>,
+}
+
+impl Validatable<IggyError> for TopicDefaults {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.replication_factor == Some(0) {
+            return Err(IggyError::InvalidReplicationFactor);
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for TopicDefaults {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let max_topic_size = match self.max_topic_size {
+            Some(max_topic_size) => max_topic_size.to_string(),
+            None => "unlimited".to_string(),
+        };
+        let compression_algorithm = match &self.compression_algorithm {
+            Some(compression_algorithm) => compression_algorithm.to_string(),
+            None => "default".to_string(),
+        };
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.message_expiry.unwrap_or(0),
+            max_topic_size,
+            compression_algorithm,
+            self.replication_factor.unwrap_or(0)
+        )
+    }
+}
@@ -0,0 +1,42 @@
+use crate::models::permissions::Permissions;
+use crate::models::user_info::UserId;
+use serde::{Deserialize, Serialize};
+
+/// `UserGroup` represents the group of users sharing the same permissions, e.g. a team.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the user group.
+/// - `created_at`: the timestamp when the user group was created.
+/// - `name`: the unique name of the user group.
+/// - `members_count`: the number of users belonging to the group.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct UserGroup {
+    /// The unique identifier (numeric) of the user group.
+    pub id: u32,
+    /// The timestamp when the user group was created.
+    pub created_at: u64,
+    /// The unique name of the user group.
+    pub name: String,
+    /// The number of users belonging to the group.
+    pub members_count: u32,
+}
+
+/// `UserGroupDetails` represents the detailed information about the user group.
+/// It consists of the following fields:
+/// - `id`: the unique identifier (numeric) of the user group.
+/// - `created_at`: the timestamp when the user group was created.
+/// - `name`: the unique name of the user group.
+/// - `members`: the IDs of the users belonging to the group.
+/// - `permissions`: the optional permissions granted to all the members, in addition to their own ones.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct UserGroupDetails {
+    /// The unique identifier (numeric) of the user group.
+    pub id: u32,
+    /// The timestamp when the user group was created.
+    pub created_at: u64,
+    /// The unique name of the user group.
+    pub name: String,
+    /// The IDs of the users belonging to the group.
+    pub members: Vec<UserId>,
+    /// The optional permissions granted to all the members, in addition to their own ones.
+    pub permissions: Option<Permissions>,
+}
@@ -2,6 +2,7 @@ use crate::models::permissions::Permissions;
 use crate::models::user_status::UserStatus;
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::AtomicU32;
+use uuid::Uuid;
 
 /// `UserId` represents the unique identifier (numeric) of the user.
 pub type UserId = u32;
@@ -12,6 +13,7 @@ pub type AtomicUserId = AtomicU32;
 /// `UserInfo` represents the basic information about the user.
 /// It consists of the following fields:
 /// - `id`: the unique identifier (numeric) of the user.
+/// - `uuid`: the immutable UUID of the user, which is kept across renames and ID reuse.
 /// - `created_at`: the timestamp when the user was created.
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
@@ -19,6 +21,9 @@ pub type AtomicUserId = AtomicU32;
 pub struct UserInfo {
     /// The unique identifier (numeric) of the user.
     pub id: UserId,
+    /// The immutable UUID of the user, which is kept across renames and ID reuse.
+    #[serde(default)]
+    pub uuid: Uuid,
     /// The timestamp when the user was created.
     pub created_at: u64,
     /// The status of the user.
@@ -30,14 +35,21 @@ pub struct UserInfo {
 /// `UserInfoDetails` represents the detailed information about the user.
 /// It consists of the following fields:
 /// - `id`: the unique identifier (numeric) of the user.
+/// - `uuid`: the immutable UUID of the user, which is kept across renames and ID reuse.
 /// - `created_at`: the timestamp when the user was created.
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
 /// - `permissions`: the optional permissions of the user.
+/// - `expires_at`: the optional timestamp after which the user is disabled.
+/// - `max_inactive_days`: the optional number of days without any login after which the user is disabled.
+/// - `last_active_at`: the optional timestamp when the user logged in or was reactivated for the last time.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserInfoDetails {
     /// The unique identifier (numeric) of the user.
     pub id: UserId,
+    /// The immutable UUID of the user, which is kept across renames and ID reuse.
+    #[serde(default)]
+    pub uuid: Uuid,
     /// The timestamp when the user was created.
     pub created_at: u64,
     /// The status of the user.
@@ -46,4 +58,13 @@ pub struct UserInfoDetails {
     pub username: String,
     /// The optional permissions of the user.
     pub permissions: Option<Permissions>,
+    /// The optional timestamp after which the user is disabled.
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+    /// The optional number of days without any login after which the user is disabled.
+    #[serde(default)]
+    pub max_inactive_days: Option<u32>,
+    /// The optional timestamp when the user logged in or was reactivated for the last time.
+    #[serde(default)]
+    pub last_active_at: Option<u64>,
 }
@@ -13,6 +13,7 @@ use std::fmt::Display;
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitions_count` - number of partitions in the topic to create, max value is 1000.
+/// - `repartition` - whether to copy the keyed messages to the partitions they're mapped to after the change, in the background.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreatePartitions {
     /// Unique stream ID (numeric or name).
@@ -23,6 +24,9 @@ pub struct CreatePartitions {
     pub topic_id: Identifier,
     /// Number of partitions in the topic to create, max value is 1000.
     pub partitions_count: u32,
+    /// Whether to copy the keyed messages to the partitions they're mapped to after the change, in the background.
+    #[serde(default)]
+    pub repartition: bool,
 }
 
 impl CommandPayload for CreatePartitions {}
@@ -33,6 +37,7 @@ impl Default for CreatePartitions {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             partitions_count: 1,
+            repartition: false,
         }
     }
 }
@@ -51,10 +56,11 @@ impl BytesSerializable for CreatePartitions {
     fn as_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
-        let mut bytes = BytesMut::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len());
+        let mut bytes = BytesMut::with_capacity(5 + stream_id_bytes.len() + topic_id_bytes.len());
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
         bytes.put_u32_le(self.partitions_count);
+        bytes.put_u8(self.repartition as u8);
         bytes.freeze()
     }
 
@@ -69,10 +75,12 @@ impl BytesSerializable for CreatePartitions {
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes() as usize;
         let partitions_count = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let repartition = bytes.get(position + 4).is_some_and(|value| *value == 1);
         let command = CreatePartitions {
             stream_id,
             topic_id,
             partitions_count,
+            repartition,
         };
         command.validate()?;
         Ok(command)
@@ -83,8 +91,8 @@ impl Display for CreatePartitions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}",
-            self.stream_id, self.topic_id, self.partitions_count
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partitions_count, self.repartition
         )
     }
 }
@@ -100,6 +108,7 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             partitions_count: 3,
+            repartition: true,
         };
 
         let bytes = command.as_bytes();
@@ -110,11 +119,13 @@ mod tests {
         position += topic_id.get_size_bytes() as usize;
         let partitions_count =
             u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let repartition = bytes[position + 4] == 1;
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(partitions_count, command.partitions_count);
+        assert_eq!(repartition, command.repartition);
     }
 
     #[test]
@@ -135,5 +146,20 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitions_count, partitions_count);
+        assert!(!command.repartition);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_repartition() {
+        let command = CreatePartitions {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partitions_count: 3,
+            repartition: true,
+        };
+
+        let deserialized_command = CreatePartitions::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
     }
 }
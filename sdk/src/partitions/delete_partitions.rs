@@ -7,12 +7,14 @@ use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// `DeletePartitions` command is used to delete partitions from a topic.
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitions_count` - number of partitions in the topic to delete, max value is 1000.
+/// - `mode` - what happens to the messages stored in the deleted partitions, refused by default.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct DeletePartitions {
     /// Unique stream ID (numeric or name).
@@ -23,6 +25,66 @@ pub struct DeletePartitions {
     pub topic_id: Identifier,
     /// Number of partitions in the topic to delete, max value is 1000.
     pub partitions_count: u32,
+    /// What happens to the messages stored in the deleted partitions.
+    #[serde(default)]
+    pub mode: DeletePartitionsMode,
+}
+
+/// `DeletePartitionsMode` defines what happens to the messages stored in the partitions being deleted.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DeletePartitionsMode {
+    /// The partitions are deleted only if none of them contains any messages.
+    #[default]
+    Refuse,
+    /// The partitions are deleted along with their messages.
+    Discard,
+    /// The messages are appended to the remaining partitions before the partitions are deleted,
+    /// and the consumer offsets are moved along with them.
+    Drain,
+}
+
+impl FromStr for DeletePartitionsMode {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "refuse" => Ok(DeletePartitionsMode::Refuse),
+            "discard" => Ok(DeletePartitionsMode::Discard),
+            "drain" => Ok(DeletePartitionsMode::Drain),
+            _ => Err(IggyError::InvalidDeletePartitionsMode),
+        }
+    }
+}
+
+impl Display for DeletePartitionsMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeletePartitionsMode::Refuse => write!(f, "refuse"),
+            DeletePartitionsMode::Discard => write!(f, "discard"),
+            DeletePartitionsMode::Drain => write!(f, "drain"),
+        }
+    }
+}
+
+impl DeletePartitionsMode {
+    /// Returns the code of the mode.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            DeletePartitionsMode::Refuse => 0,
+            DeletePartitionsMode::Discard => 1,
+            DeletePartitionsMode::Drain => 2,
+        }
+    }
+
+    /// Returns the mode from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            0 => Ok(DeletePartitionsMode::Refuse),
+            1 => Ok(DeletePartitionsMode::Discard),
+            2 => Ok(DeletePartitionsMode::Drain),
+            _ => Err(IggyError::InvalidDeletePartitionsMode),
+        }
+    }
 }
 
 impl CommandPayload for DeletePartitions {}
@@ -33,6 +95,7 @@ impl Default for DeletePartitions {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             partitions_count: 1,
+            mode: DeletePartitionsMode::default(),
         }
     }
 }
@@ -51,10 +114,11 @@ impl BytesSerializable for DeletePartitions {
     fn as_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
-        let mut bytes = BytesMut::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len());
+        let mut bytes = BytesMut::with_capacity(5 + stream_id_bytes.len() + topic_id_bytes.len());
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
         bytes.put_u32_le(self.partitions_count);
+        bytes.put_u8(self.mode.as_code());
         bytes.freeze()
     }
 
@@ -69,10 +133,16 @@ impl BytesSerializable for DeletePartitions {
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes() as usize;
         let partitions_count = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let mode = bytes
+            .get(position + 4)
+            .map_or(Ok(DeletePartitionsMode::default()), |code| {
+                DeletePartitionsMode::from_code(*code)
+            })?;
         let command = DeletePartitions {
             stream_id,
             topic_id,
             partitions_count,
+            mode,
         };
         command.validate()?;
         Ok(command)
@@ -83,8 +153,8 @@ impl Display for DeletePartitions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}",
-            self.stream_id, self.topic_id, self.partitions_count
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partitions_count, self.mode
         )
     }
 }
@@ -100,6 +170,7 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             partitions_count: 3,
+            mode: DeletePartitionsMode::Drain,
         };
 
         let bytes = command.as_bytes();
@@ -110,11 +181,13 @@ mod tests {
         position += topic_id.get_size_bytes() as usize;
         let partitions_count =
             u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let mode = DeletePartitionsMode::from_code(bytes[position + 4]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(partitions_count, command.partitions_count);
+        assert_eq!(mode, command.mode);
     }
 
     #[test]
@@ -135,5 +208,35 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitions_count, partitions_count);
+        assert_eq!(command.mode, DeletePartitionsMode::Refuse);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_mode() {
+        let command = DeletePartitions {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partitions_count: 3,
+            mode: DeletePartitionsMode::Discard,
+        };
+
+        let deserialized_command = DeletePartitions::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_bytes_with_invalid_mode() {
+        let command = DeletePartitions::default();
+        let mut bytes = BytesMut::from(&command.as_bytes()[..]);
+        let last = bytes.len() - 1;
+        bytes[last] = 3;
+
+        let command = DeletePartitions::from_bytes(bytes.freeze());
+
+        assert!(matches!(
+            command,
+            Err(IggyError::InvalidDeletePartitionsMode)
+        ));
     }
 }
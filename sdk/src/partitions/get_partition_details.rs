@@ -0,0 +1,136 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetPartitionDetails` command is used to retrieve a partition of a topic including its segments.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - unique partition ID.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetPartitionDetails {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique partition ID.
+    #[serde(skip)]
+    pub partition_id: u32,
+}
+
+impl CommandPayload for GetPartitionDetails {}
+
+impl Default for GetPartitionDetails {
+    fn default() -> Self {
+        GetPartitionDetails {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: 1,
+        }
+    }
+}
+
+impl Validatable<IggyError> for GetPartitionDetails {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.partition_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetPartitionDetails {
+    fn as_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<GetPartitionDetails, IggyError> {
+        if bytes.len() < 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes() as usize;
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let command = GetPartitionDetails {
+            stream_id,
+            topic_id,
+            partition_id,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetPartitionDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::BufMut;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetPartitionDetails {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: 3,
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let partition_id = 3u32;
+        let mut bytes = BytesMut::new();
+        bytes.put(stream_id.as_bytes());
+        bytes.put(topic_id.as_bytes());
+        bytes.put_u32_le(partition_id);
+        let command = GetPartitionDetails::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, partition_id);
+    }
+}
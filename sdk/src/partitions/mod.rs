@@ -1,4 +1,6 @@
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod get_partition_details;
+pub mod get_partition_end_offsets;
 
-const MAX_PARTITIONS_COUNT: u32 = 1000;
+pub const MAX_PARTITIONS_COUNT: u32 = 1000;
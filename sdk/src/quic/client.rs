@@ -1,7 +1,10 @@
 use crate::binary::binary_client::{BinaryClient, ClientState};
+use crate::bytes_serializable::BytesSerializable;
 use crate::client::Client;
+use crate::command::HEARTBEAT_CODE;
 use crate::error::IggyError;
 use crate::quic::config::QuicClientConfig;
+use crate::system::heartbeat::Heartbeat;
 use async_trait::async_trait;
 use bytes::Bytes;
 use quinn::{ClientConfig, Connection, Endpoint, IdleTimeout, RecvStream, VarInt};
@@ -11,6 +14,7 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tracing::{error, info, trace};
 
@@ -26,6 +30,7 @@ pub struct QuicClient {
     pub(crate) config: Arc<QuicClientConfig>,
     pub(crate) server_address: SocketAddr,
     pub(crate) state: Mutex<ClientState>,
+    heartbeat: std::sync::Mutex<Option<JoinHandle<()>>>,
 }
 
 unsafe impl Send for QuicClient {}
@@ -83,6 +88,7 @@ impl Client for QuicClient {
         }
 
         self.set_state(ClientState::Connected).await;
+        self.start_heartbeat(connection.clone());
         self.connection.lock().await.replace(connection);
 
         Ok(())
@@ -95,6 +101,7 @@ impl Client for QuicClient {
 
         info!("{} client is disconnecting from server...", NAME);
         self.set_state(ClientState::Disconnected).await;
+        self.stop_heartbeat();
         self.connection.lock().await.take();
         self.endpoint.wait_idle().await;
         info!("{} client has disconnected from server.", NAME);
@@ -119,17 +126,13 @@ impl BinaryClient for QuicClient {
 
         let connection = self.connection.lock().await;
         if let Some(connection) = connection.as_ref() {
-            let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
-
-            let (mut send, mut recv) = connection.open_bi().await?;
-            trace!("Sending a QUIC request...");
-            send.write_all(&(payload_length as u32).to_le_bytes())
-                .await?;
-            send.write_all(&command.to_le_bytes()).await?;
-            send.write_all(&payload).await?;
-            send.finish().await?;
-            trace!("Sent a QUIC request, waiting for a response...");
-            return self.handle_response(&mut recv).await;
+            return send_request(
+                connection,
+                command,
+                payload,
+                self.config.response_buffer_size as usize,
+            )
+            .await;
         }
 
         error!("Cannot send data. Client is not connected.");
@@ -182,40 +185,100 @@ impl QuicClient {
             server_address,
             connection: Mutex::new(None),
             state: Mutex::new(ClientState::Disconnected),
+            heartbeat: std::sync::Mutex::new(None),
         })
     }
 
-    async fn handle_response(&self, recv: &mut RecvStream) -> Result<Bytes, IggyError> {
-        let buffer = recv
-            .read_to_end(self.config.response_buffer_size as usize)
-            .await?;
-        if buffer.is_empty() {
-            return Err(IggyError::EmptyResponse);
+    /// Spawns the background task sending the heartbeats to the server, so that the server doesn't consider the idle connection dead.
+    fn start_heartbeat(&self, connection: Connection) {
+        self.stop_heartbeat();
+        if self.config.heartbeat_interval == 0 {
+            return;
         }
 
-        let status = u32::from_le_bytes(buffer[..4].try_into().unwrap());
-        if status != 0 {
-            error!(
-                "Received an invalid response with status: {} ({}).",
-                status,
-                IggyError::from_code_as_string(status)
-            );
-            return Err(IggyError::InvalidResponse(status));
-        }
+        let interval = Duration::from_millis(self.config.heartbeat_interval);
+        let response_buffer_size = self.config.response_buffer_size as usize;
+        let task = tokio::spawn(async move {
+            loop {
+                sleep(interval).await;
+                trace!("Sending a heartbeat...");
+                if let Err(error) = send_request(
+                    &connection,
+                    HEARTBEAT_CODE,
+                    Heartbeat {}.as_bytes(),
+                    response_buffer_size,
+                )
+                .await
+                {
+                    error!("Failed to send a heartbeat, error: {error}");
+                    break;
+                }
+            }
+        });
+        self.heartbeat.lock().unwrap().replace(task);
+    }
 
-        let length =
-            u32::from_le_bytes(buffer[4..RESPONSE_INITIAL_BYTES_LENGTH].try_into().unwrap());
-        trace!("Status: OK. Response length: {}", length);
-        if length <= 1 {
-            return Ok(Bytes::new());
+    fn stop_heartbeat(&self) {
+        if let Some(task) = self.heartbeat.lock().unwrap().take() {
+            task.abort();
         }
+    }
+}
 
-        Ok(Bytes::copy_from_slice(
-            &buffer[RESPONSE_INITIAL_BYTES_LENGTH..RESPONSE_INITIAL_BYTES_LENGTH + length as usize],
-        ))
+impl Drop for QuicClient {
+    fn drop(&mut self) {
+        self.stop_heartbeat();
     }
 }
 
+async fn send_request(
+    connection: &Connection,
+    command: u32,
+    payload: Bytes,
+    response_buffer_size: usize,
+) -> Result<Bytes, IggyError> {
+    let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
+    let (mut send, mut recv) = connection.open_bi().await?;
+    trace!("Sending a QUIC request...");
+    send.write_all(&(payload_length as u32).to_le_bytes())
+        .await?;
+    send.write_all(&command.to_le_bytes()).await?;
+    send.write_all(&payload).await?;
+    send.finish().await?;
+    trace!("Sent a QUIC request, waiting for a response...");
+    handle_response(&mut recv, response_buffer_size).await
+}
+
+async fn handle_response(
+    recv: &mut RecvStream,
+    response_buffer_size: usize,
+) -> Result<Bytes, IggyError> {
+    let buffer = recv.read_to_end(response_buffer_size).await?;
+    if buffer.is_empty() {
+        return Err(IggyError::EmptyResponse);
+    }
+
+    let status = u32::from_le_bytes(buffer[..4].try_into().unwrap());
+    if status != 0 {
+        error!(
+            "Received an invalid response with status: {} ({}).",
+            status,
+            IggyError::from_code_as_string(status)
+        );
+        return Err(IggyError::InvalidResponse(status));
+    }
+
+    let length = u32::from_le_bytes(buffer[4..RESPONSE_INITIAL_BYTES_LENGTH].try_into().unwrap());
+    trace!("Status: OK. Response length: {}", length);
+    if length <= 1 {
+        return Ok(Bytes::new());
+    }
+
+    Ok(Bytes::copy_from_slice(
+        &buffer[RESPONSE_INITIAL_BYTES_LENGTH..RESPONSE_INITIAL_BYTES_LENGTH + length as usize],
+    ))
+}
+
 fn configure(config: &QuicClientConfig) -> Result<ClientConfig, IggyError> {
     let max_concurrent_bidi_streams = VarInt::try_from(config.max_concurrent_bidi_streams);
     if max_concurrent_bidi_streams.is_err() {
@@ -27,6 +27,8 @@ pub struct QuicClientConfig {
     pub keep_alive_interval: u64,
     /// The maximum idle timeout.
     pub max_idle_timeout: u64,
+    /// The interval in milliseconds of the heartbeats sent to the server, 0 disables them.
+    pub heartbeat_interval: u64,
     /// Whether to validate the server certificate.
     pub validate_certificate: bool,
 }
@@ -47,6 +49,7 @@ impl Default for QuicClientConfig {
             receive_window: 100_000,
             keep_alive_interval: 5000,
             max_idle_timeout: 10000,
+            heartbeat_interval: 5000,
             validate_certificate: false,
         }
     }
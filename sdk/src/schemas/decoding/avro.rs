@@ -0,0 +1,364 @@
+use crate::error::IggyError;
+use crate::utils::text;
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+
+/// The parsed Avro schema, which is used to decode the Avro binary encoded payloads.
+/// The named types (records, enums and fixed) can be referenced by their full or simple names.
+#[derive(Debug, Clone)]
+pub struct AvroSchema {
+    root: AvroType,
+    named_types: HashMap<String, AvroType>,
+}
+
+#[derive(Debug, Clone)]
+enum AvroType {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record(Vec<(String, AvroType)>),
+    Enum(Vec<String>),
+    Array(Box<AvroType>),
+    Map(Box<AvroType>),
+    Union(Vec<AvroType>),
+    Fixed(usize),
+    Named(String),
+}
+
+impl AvroSchema {
+    pub fn parse(schema: &str) -> Result<Self, IggyError> {
+        let schema = serde_json::from_str::<Value>(schema)
+            .map_err(|error| IggyError::InvalidSchema(error.to_string()))?;
+        let mut named_types = HashMap::new();
+        let root = parse_type(&schema, None, &mut named_types)?;
+        Ok(Self { root, named_types })
+    }
+
+    pub fn decode(&self, payload: &[u8]) -> Result<Value, IggyError> {
+        let mut reader = Reader {
+            payload,
+            position: 0,
+        };
+        let value = self.decode_type(&self.root, &mut reader)?;
+        if reader.position != payload.len() {
+            return Err(decoding_error(format!(
+                "unexpected {} trailing byte(s)",
+                payload.len() - reader.position
+            )));
+        }
+
+        Ok(value)
+    }
+
+    fn decode_type(&self, avro_type: &AvroType, reader: &mut Reader) -> Result<Value, IggyError> {
+        let value = match avro_type {
+            AvroType::Null => Value::Null,
+            AvroType::Boolean => Value::Bool(reader.read_bytes(1)?[0] != 0),
+            AvroType::Int | AvroType::Long => Value::from(reader.read_long()?),
+            AvroType::Float => {
+                let bytes = reader.read_bytes(4)?;
+                number_or_null(f32::from_le_bytes(bytes.try_into()?) as f64)
+            }
+            AvroType::Double => {
+                let bytes = reader.read_bytes(8)?;
+                number_or_null(f64::from_le_bytes(bytes.try_into()?))
+            }
+            AvroType::Bytes => {
+                let length = reader.read_length()?;
+                Value::String(text::as_base64(reader.read_bytes(length)?))
+            }
+            AvroType::String => {
+                let length = reader.read_length()?;
+                Value::String(std::str::from_utf8(reader.read_bytes(length)?)?.to_string())
+            }
+            AvroType::Record(fields) => {
+                let mut record = Map::with_capacity(fields.len());
+                for (name, field_type) in fields {
+                    record.insert(name.clone(), self.decode_type(field_type, reader)?);
+                }
+                Value::Object(record)
+            }
+            AvroType::Enum(symbols) => {
+                let index = reader.read_long()?;
+                let symbol = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| symbols.get(index))
+                    .ok_or_else(|| decoding_error(format!("invalid enum index: {index}")))?;
+                Value::String(symbol.clone())
+            }
+            AvroType::Array(items_type) => {
+                let mut items = Vec::new();
+                while let Some(count) = reader.read_block_count()? {
+                    for _ in 0..count {
+                        items.push(self.decode_type(items_type, reader)?);
+                    }
+                }
+                Value::Array(items)
+            }
+            AvroType::Map(values_type) => {
+                let mut entries = Map::new();
+                while let Some(count) = reader.read_block_count()? {
+                    for _ in 0..count {
+                        let key = self.decode_type(&AvroType::String, reader)?;
+                        let value = self.decode_type(values_type, reader)?;
+                        if let Value::String(key) = key {
+                            entries.insert(key, value);
+                        }
+                    }
+                }
+                Value::Object(entries)
+            }
+            AvroType::Union(variants) => {
+                let index = reader.read_long()?;
+                let variant = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| variants.get(index))
+                    .ok_or_else(|| decoding_error(format!("invalid union index: {index}")))?;
+                self.decode_type(variant, reader)?
+            }
+            AvroType::Fixed(size) => Value::String(text::as_base64(reader.read_bytes(*size)?)),
+            AvroType::Named(name) => {
+                let named_type = self
+                    .named_types
+                    .get(name)
+                    .ok_or_else(|| decoding_error(format!("unknown type: {name}")))?;
+                self.decode_type(named_type, reader)?
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+fn parse_type(
+    schema: &Value,
+    namespace: Option<&str>,
+    named_types: &mut HashMap<String, AvroType>,
+) -> Result<AvroType, IggyError> {
+    match schema {
+        Value::String(name) => parse_type_name(name, namespace, named_types),
+        Value::Array(variants) => Ok(AvroType::Union(
+            variants
+                .iter()
+                .map(|variant| parse_type(variant, namespace, named_types))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        Value::Object(schema) => {
+            let Some(schema_type) = schema.get("type") else {
+                return Err(invalid_schema("type is missing"));
+            };
+            let Value::String(schema_type) = schema_type else {
+                return parse_type(schema_type, namespace, named_types);
+            };
+
+            match schema_type.as_str() {
+                "record" | "error" | "enum" | "fixed" => {
+                    parse_named_type(schema, schema_type, namespace, named_types)
+                }
+                "array" => {
+                    let items = schema
+                        .get("items")
+                        .ok_or_else(|| invalid_schema("array items are missing"))?;
+                    Ok(AvroType::Array(Box::new(parse_type(
+                        items,
+                        namespace,
+                        named_types,
+                    )?)))
+                }
+                "map" => {
+                    let values = schema
+                        .get("values")
+                        .ok_or_else(|| invalid_schema("map values are missing"))?;
+                    Ok(AvroType::Map(Box::new(parse_type(
+                        values,
+                        namespace,
+                        named_types,
+                    )?)))
+                }
+                // Logical types, such as date or decimal, are decoded as their underlying types.
+                name => parse_type_name(name, namespace, named_types),
+            }
+        }
+        _ => Err(invalid_schema("type must be a string, object or array")),
+    }
+}
+
+fn parse_named_type(
+    schema: &Map<String, Value>,
+    schema_type: &str,
+    namespace: Option<&str>,
+    named_types: &mut HashMap<String, AvroType>,
+) -> Result<AvroType, IggyError> {
+    let Some(Value::String(name)) = schema.get("name") else {
+        return Err(invalid_schema(&format!("{schema_type} name is missing")));
+    };
+
+    let namespace = match schema.get("namespace") {
+        Some(Value::String(namespace)) if !name.contains('.') => Some(namespace.as_str()),
+        _ => namespace,
+    };
+    let full_name = get_full_name(name, namespace);
+    let namespace = full_name.rsplit_once('.').map(|(namespace, _)| namespace);
+    // The type is registered before parsing its fields, so that it can reference itself.
+    named_types.insert(full_name.clone(), AvroType::Null);
+
+    let named_type = match schema_type {
+        "enum" => {
+            let Some(Value::Array(symbols)) = schema.get("symbols") else {
+                return Err(invalid_schema(&format!("enum: {name} symbols are missing")));
+            };
+            AvroType::Enum(
+                symbols
+                    .iter()
+                    .filter_map(|symbol| symbol.as_str().map(|symbol| symbol.to_string()))
+                    .collect(),
+            )
+        }
+        "fixed" => {
+            let size = schema
+                .get("size")
+                .and_then(Value::as_u64)
+                .ok_or_else(|| invalid_schema(&format!("fixed: {name} size is missing")))?;
+            AvroType::Fixed(size as usize)
+        }
+        _ => {
+            let Some(Value::Array(fields)) = schema.get("fields") else {
+                return Err(invalid_schema(&format!(
+                    "record: {name} fields are missing"
+                )));
+            };
+            let mut record_fields = Vec::with_capacity(fields.len());
+            for field in fields {
+                let (Some(Value::String(field_name)), Some(field_type)) =
+                    (field.get("name"), field.get("type"))
+                else {
+                    return Err(invalid_schema(&format!(
+                        "record: {name} field name or type is missing"
+                    )));
+                };
+                record_fields.push((
+                    field_name.clone(),
+                    parse_type(field_type, namespace, named_types)?,
+                ));
+            }
+            AvroType::Record(record_fields)
+        }
+    };
+
+    named_types.insert(full_name.clone(), named_type);
+    Ok(AvroType::Named(full_name))
+}
+
+fn parse_type_name(
+    name: &str,
+    namespace: Option<&str>,
+    named_types: &HashMap<String, AvroType>,
+) -> Result<AvroType, IggyError> {
+    let avro_type = match name {
+        "null" => AvroType::Null,
+        "boolean" => AvroType::Boolean,
+        "int" => AvroType::Int,
+        "long" => AvroType::Long,
+        "float" => AvroType::Float,
+        "double" => AvroType::Double,
+        "bytes" => AvroType::Bytes,
+        "string" => AvroType::String,
+        _ => {
+            let full_name = get_full_name(name, namespace);
+            if named_types.contains_key(&full_name) {
+                AvroType::Named(full_name)
+            } else if named_types.contains_key(name) {
+                AvroType::Named(name.to_string())
+            } else {
+                return Err(invalid_schema(&format!("unknown type: {name}")));
+            }
+        }
+    };
+
+    Ok(avro_type)
+}
+
+fn get_full_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(namespace) if !name.contains('.') && !namespace.is_empty() => {
+            format!("{namespace}.{name}")
+        }
+        _ => name.to_string(),
+    }
+}
+
+fn number_or_null(value: f64) -> Value {
+    Number::from_f64(value).map_or(Value::Null, Value::Number)
+}
+
+fn invalid_schema(reason: &str) -> IggyError {
+    IggyError::InvalidSchema(format!("invalid Avro schema, {reason}"))
+}
+
+fn decoding_error(reason: String) -> IggyError {
+    IggyError::CannotDecodeMessagePayload(reason)
+}
+
+struct Reader<'a> {
+    payload: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], IggyError> {
+        if self.payload.len() - self.position < length {
+            return Err(decoding_error("unexpected end of payload".to_string()));
+        }
+
+        let bytes = &self.payload[self.position..self.position + length];
+        self.position += length;
+        Ok(bytes)
+    }
+
+    /// Reads the zig-zag encoded variable length integer, used for both `int` and `long` types.
+    fn read_long(&mut self) -> Result<i64, IggyError> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            if shift > 63 {
+                return Err(decoding_error(
+                    "invalid variable length integer".to_string(),
+                ));
+            }
+
+            let byte = self.read_bytes(1)?[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    fn read_length(&mut self) -> Result<usize, IggyError> {
+        let length = self.read_long()?;
+        usize::try_from(length).map_err(|_| decoding_error(format!("invalid length: {length}")))
+    }
+
+    /// Reads the number of items in the next block of an array or map, returns `None` for the last, empty block.
+    /// The negative count is followed by the size of the block in bytes, which is not needed here.
+    fn read_block_count(&mut self) -> Result<Option<u64>, IggyError> {
+        let count = self.read_long()?;
+        if count == 0 {
+            return Ok(None);
+        }
+
+        if count < 0 {
+            self.read_long()?;
+        }
+
+        Ok(Some(count.unsigned_abs()))
+    }
+}
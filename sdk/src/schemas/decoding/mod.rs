@@ -0,0 +1,137 @@
+mod avro;
+mod protobuf;
+
+use crate::error::IggyError;
+use crate::models::schema::SchemaFormat;
+use crate::schemas::decoding::avro::AvroSchema;
+use ::protobuf::reflect::MessageDescriptor;
+use serde_json::Value;
+
+/// `PayloadDecoder` is used to decode the message payloads into the structured JSON, based on the schema they were produced with.
+/// It supports the following formats:
+/// - `Json` - the payload is a JSON document, it's parsed as is.
+/// - `Avro` - the payload is Avro binary encoded, the schema must be provided in the JSON form.
+/// - `Protobuf` - the payload is Protobuf binary encoded, the schema must be provided as the `.proto` file content.
+#[derive(Debug, Clone)]
+pub enum PayloadDecoder {
+    /// The payload is a JSON document.
+    Json,
+    /// The payload is Avro binary encoded.
+    Avro(AvroSchema),
+    /// The payload is Protobuf binary encoded message.
+    Protobuf(MessageDescriptor),
+}
+
+impl PayloadDecoder {
+    /// Creates the decoder for the schema in the given format.
+    /// The message name is used only for the Protobuf schema, by default the first message defined in the file is used.
+    pub fn new(
+        format: SchemaFormat,
+        schema: &str,
+        message_name: Option<&str>,
+    ) -> Result<Self, IggyError> {
+        match format {
+            SchemaFormat::Json => Ok(PayloadDecoder::Json),
+            SchemaFormat::Avro => Ok(PayloadDecoder::Avro(AvroSchema::parse(schema)?)),
+            SchemaFormat::Protobuf => Ok(PayloadDecoder::Protobuf(
+                protobuf::get_message_descriptor(schema, message_name)?,
+            )),
+        }
+    }
+
+    /// Decodes the message payload into the structured JSON.
+    pub fn decode(&self, payload: &[u8]) -> Result<Value, IggyError> {
+        match self {
+            PayloadDecoder::Json => serde_json::from_slice(payload)
+                .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string())),
+            PayloadDecoder::Avro(schema) => schema.decode(payload),
+            PayloadDecoder::Protobuf(descriptor) => protobuf::decode(descriptor, payload),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn json_payload_should_be_decoded() {
+        let decoder = PayloadDecoder::new(SchemaFormat::Json, "{}", None).unwrap();
+
+        let value = decoder.decode(br#"{"id":1}"#).unwrap();
+
+        assert_eq!(value, json!({"id": 1}));
+        assert!(decoder.decode(b"not json").is_err());
+    }
+
+    #[test]
+    fn avro_payload_should_be_decoded() {
+        let schema = r#"{
+            "type": "record",
+            "name": "Order",
+            "fields": [
+                {"name": "id", "type": "long"},
+                {"name": "note", "type": ["null", "string"]},
+                {"name": "status", "type": {"type": "enum", "name": "Status", "symbols": ["NEW", "PAID"]}},
+                {"name": "items", "type": {"type": "array", "items": "int"}}
+            ]
+        }"#;
+        let decoder = PayloadDecoder::new(SchemaFormat::Avro, schema, None).unwrap();
+        // id: 42 (zigzag: 84), note: union branch 1 + "hi", status: index 1, items: block of 2 ints [1, -1] + end.
+        let payload = [84, 2, 4, b'h', b'i', 2, 4, 2, 1, 0];
+
+        let value = decoder.decode(&payload).unwrap();
+
+        assert_eq!(
+            value,
+            json!({"id": 42, "note": "hi", "status": "PAID", "items": [1, -1]})
+        );
+    }
+
+    #[test]
+    fn protobuf_payload_should_be_decoded() {
+        let schema = r#"
+            syntax = "proto3";
+            package shop;
+            message Order {
+                uint64 id = 1;
+                string note = 2;
+                repeated Item items = 3;
+            }
+            message Item {
+                string name = 1;
+            }
+        "#;
+        let decoder = PayloadDecoder::new(SchemaFormat::Protobuf, schema, None).unwrap();
+        // id: 7, note: "hi", items: [{name: "a"}].
+        let payload = [8, 7, 18, 2, b'h', b'i', 26, 3, 10, 1, b'a'];
+
+        let value = decoder.decode(&payload).unwrap();
+
+        assert_eq!(
+            value,
+            json!({"id": 7, "note": "hi", "items": [{"name": "a"}]})
+        );
+    }
+
+    #[test]
+    fn protobuf_message_should_be_found_by_name() {
+        let schema = r#"
+            syntax = "proto3";
+            package shop;
+            message Order { uint64 id = 1; }
+            message Item { string name = 1; }
+        "#;
+
+        assert!(PayloadDecoder::new(SchemaFormat::Protobuf, schema, Some("Item")).is_ok());
+        assert!(PayloadDecoder::new(SchemaFormat::Protobuf, schema, Some("shop.Item")).is_ok());
+        assert!(PayloadDecoder::new(SchemaFormat::Protobuf, schema, Some("Unknown")).is_err());
+    }
+
+    #[test]
+    fn invalid_schema_should_be_rejected() {
+        assert!(PayloadDecoder::new(SchemaFormat::Avro, r#"{"type":"unknown"}"#, None).is_err());
+        assert!(PayloadDecoder::new(SchemaFormat::Protobuf, "message {", None).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::error::IggyError;
+use crate::utils::text;
+use ::protobuf::reflect::{FileDescriptor, MessageDescriptor, ReflectFieldRef, ReflectValueRef};
+use ::protobuf::MessageDyn;
+use serde_json::{Map, Number, Value};
+use std::fmt::Display;
+use std::fs;
+
+const SCHEMA_FILE_NAME: &str = "schema.proto";
+
+/// Parses the `.proto` file content and returns the descriptor of the message with the given name
+/// (relative to the package or fully qualified), or the first message defined in the file.
+/// The parser works with the files only, thus the content is stored in the temporary directory first.
+/// The well-known types (e.g. `google/protobuf/timestamp.proto`) can be imported.
+pub(crate) fn get_message_descriptor(
+    schema: &str,
+    message_name: Option<&str>,
+) -> Result<MessageDescriptor, IggyError> {
+    let directory = tempfile::tempdir().map_err(invalid_schema)?;
+    let schema_path = directory.path().join(SCHEMA_FILE_NAME);
+    fs::write(&schema_path, schema).map_err(invalid_schema)?;
+    let parsed_schema = protobuf_parse::Parser::new()
+        .pure()
+        .include(directory.path())
+        .input(&schema_path)
+        .parse_and_typecheck()
+        .map_err(invalid_schema)?;
+    let file_descriptors = FileDescriptor::new_dynamic_fds(parsed_schema.file_descriptors, &[])
+        .map_err(invalid_schema)?;
+    let file_descriptor = file_descriptors
+        .into_iter()
+        .find(|file_descriptor| file_descriptor.name() == SCHEMA_FILE_NAME)
+        .ok_or_else(|| IggyError::InvalidSchema("Protobuf schema is empty".to_string()))?;
+
+    let message_descriptor = match message_name {
+        Some(message_name) => file_descriptor
+            .message_by_package_relative_name(message_name)
+            .or_else(|| {
+                file_descriptor
+                    .message_by_full_name(&format!(".{}", message_name.trim_start_matches('.')))
+            }),
+        None => file_descriptor.messages().next(),
+    };
+    message_descriptor.ok_or_else(|| {
+        IggyError::InvalidSchema(format!(
+            "Protobuf message: {} was not found",
+            message_name.unwrap_or_default()
+        ))
+    })
+}
+
+pub(crate) fn decode(descriptor: &MessageDescriptor, payload: &[u8]) -> Result<Value, IggyError> {
+    let message = descriptor
+        .parse_from_bytes(payload)
+        .map_err(|error| IggyError::CannotDecodeMessagePayload(error.to_string()))?;
+    Ok(map_message(&*message))
+}
+
+/// Maps the message to JSON using the field JSON names, the fields which are not set are omitted.
+fn map_message(message: &dyn MessageDyn) -> Value {
+    let mut fields = Map::new();
+    for field in message.descriptor_dyn().fields() {
+        let value = match field.get_reflect(message) {
+            ReflectFieldRef::Optional(value) => match value.value() {
+                Some(value) => map_value(value),
+                None => continue,
+            },
+            ReflectFieldRef::Repeated(values) => {
+                if values.is_empty() {
+                    continue;
+                }
+                Value::Array(values.into_iter().map(map_value).collect())
+            }
+            ReflectFieldRef::Map(entries) => {
+                if entries.is_empty() {
+                    continue;
+                }
+                Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key.to_string(), map_value(value)))
+                        .collect(),
+                )
+            }
+        };
+        fields.insert(field.json_name().to_string(), value);
+    }
+
+    Value::Object(fields)
+}
+
+fn map_value(value: ReflectValueRef) -> Value {
+    match value {
+        ReflectValueRef::U32(value) => Value::from(value),
+        ReflectValueRef::U64(value) => Value::from(value),
+        ReflectValueRef::I32(value) => Value::from(value),
+        ReflectValueRef::I64(value) => Value::from(value),
+        ReflectValueRef::F32(value) => {
+            Number::from_f64(value as f64).map_or(Value::Null, Value::Number)
+        }
+        ReflectValueRef::F64(value) => Number::from_f64(value).map_or(Value::Null, Value::Number),
+        ReflectValueRef::Bool(value) => Value::Bool(value),
+        ReflectValueRef::String(value) => Value::String(value.to_string()),
+        ReflectValueRef::Bytes(value) => Value::String(text::as_base64(value)),
+        ReflectValueRef::Enum(descriptor, value) => match descriptor.value_by_number(value) {
+            Some(enum_value) => Value::String(enum_value.name().to_string()),
+            None => Value::from(value),
+        },
+        ReflectValueRef::Message(message) => map_message(&*message),
+    }
+}
+
+fn invalid_schema(error: impl Display) -> IggyError {
+    IggyError::InvalidSchema(format!("invalid Protobuf schema, {error}"))
+}
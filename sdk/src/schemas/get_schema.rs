@@ -0,0 +1,85 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetSchema` command is used to retrieve the schema by unique ID, e.g. the one the received message was tagged with.
+/// It has additional payload:
+/// - `schema_id` - unique schema ID.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetSchema {
+    /// Unique schema ID.
+    #[serde(skip)]
+    pub schema_id: u32,
+}
+
+impl CommandPayload for GetSchema {}
+
+impl Default for GetSchema {
+    fn default() -> Self {
+        GetSchema { schema_id: 1 }
+    }
+}
+
+impl Validatable<IggyError> for GetSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.schema_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetSchema {
+    fn as_bytes(&self) -> Bytes {
+        Bytes::copy_from_slice(&self.schema_id.to_le_bytes())
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetSchema, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let schema_id = u32::from_le_bytes(bytes[..4].try_into()?);
+        let command = GetSchema { schema_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.schema_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetSchema { schema_id: 3 };
+
+        let bytes = command.as_bytes();
+        let schema_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(schema_id, command.schema_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let bytes = Bytes::copy_from_slice(&3u32.to_le_bytes());
+
+        let command = GetSchema::from_bytes(bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.schema_id, 3);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::schemas::validate_subject;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `GetSchemaVersions` command is used to retrieve all the versions of the schema registered for the subject, ordered by version.
+/// It has additional payload:
+/// - `subject` - name of the subject, must be between 1 and 255 characters long.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetSchemaVersions {
+    /// Name of the subject, must be between 1 and 255 characters long.
+    #[serde(skip)]
+    pub subject: String,
+}
+
+impl CommandPayload for GetSchemaVersions {}
+
+impl Default for GetSchemaVersions {
+    fn default() -> Self {
+        GetSchemaVersions {
+            subject: "subject".to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for GetSchemaVersions {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_subject(&self.subject)
+    }
+}
+
+impl BytesSerializable for GetSchemaVersions {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1 + self.subject.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.subject.len() as u8);
+        bytes.put_slice(self.subject.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetSchemaVersions, IggyError> {
+        if bytes.len() < 2 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject_length = bytes[0] as usize;
+        if bytes.len() != 1 + subject_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject = from_utf8(&bytes[1..])?.to_string();
+        let command = GetSchemaVersions { subject };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetSchemaVersions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.subject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetSchemaVersions {
+            subject: "orders".to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let subject_length = bytes[0] as usize;
+        let subject = from_utf8(&bytes[1..1 + subject_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(subject, command.subject);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let subject = "orders";
+        let mut bytes = BytesMut::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(subject.len() as u8);
+        bytes.put_slice(subject.as_bytes());
+
+        let command = GetSchemaVersions::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subject, subject);
+    }
+}
@@ -0,0 +1,100 @@
+pub mod decoding;
+pub mod get_schema;
+pub mod get_schema_versions;
+pub mod register_schema;
+pub mod update_schema_compatibility;
+
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::schema::SchemaFormat;
+use crate::utils::text;
+use std::collections::HashMap;
+
+const MAX_SUBJECT_LENGTH: usize = 255;
+/// The maximum length of the schema registered for the subject.
+pub const MAX_SCHEMA_LENGTH: usize = 65_536;
+/// The header used to tag the message with the ID of the schema its payload was produced with.
+pub const SCHEMA_ID_HEADER: &str = "iggy-schema-id";
+
+pub(crate) fn validate_subject(subject: &str) -> Result<(), IggyError> {
+    if subject.is_empty() || subject.len() > MAX_SUBJECT_LENGTH {
+        return Err(IggyError::InvalidSchemaSubject);
+    }
+
+    if !text::is_resource_name_valid(subject) {
+        return Err(IggyError::InvalidSchemaSubject);
+    }
+
+    Ok(())
+}
+
+/// Validates the schema, which must not be longer than `MAX_SCHEMA_LENGTH`.
+/// The JSON Schema must be a JSON object (or boolean), and the Avro schema must be a valid JSON.
+/// Only the syntax is checked here, the schema itself is compiled by the server.
+pub(crate) fn validate_schema(format: SchemaFormat, schema: &str) -> Result<(), IggyError> {
+    if schema.is_empty() || schema.len() > MAX_SCHEMA_LENGTH {
+        return Err(IggyError::InvalidSchema(format!(
+            "schema length must be between 1 and {MAX_SCHEMA_LENGTH} bytes"
+        )));
+    }
+
+    if format == SchemaFormat::Protobuf {
+        return Ok(());
+    }
+
+    match serde_json::from_str::<serde_json::Value>(schema) {
+        Ok(serde_json::Value::Object(_)) | Ok(serde_json::Value::Bool(_)) => Ok(()),
+        Ok(_) if format == SchemaFormat::Avro => Ok(()),
+        Ok(_) => Err(IggyError::InvalidSchema(
+            "schema must be a JSON object or boolean".to_string(),
+        )),
+        Err(error) => Err(IggyError::InvalidSchema(error.to_string())),
+    }
+}
+
+/// Tags the message headers with the ID of the schema the payload was produced with.
+pub fn set_schema_id(
+    headers: &mut HashMap<HeaderKey, HeaderValue>,
+    schema_id: u32,
+) -> Result<(), IggyError> {
+    headers.insert(
+        HeaderKey::new(SCHEMA_ID_HEADER)?,
+        HeaderValue::from_uint32(schema_id)?,
+    );
+    Ok(())
+}
+
+/// Returns the ID of the schema the message payload was produced with, if the message was tagged with one.
+pub fn get_schema_id(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<u32>, IggyError> {
+    let Some(headers) = headers else {
+        return Ok(None);
+    };
+
+    match headers.get(&HeaderKey::new(SCHEMA_ID_HEADER)?) {
+        Some(value) => Ok(Some(value.as_uint32()?)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_id_should_be_set_and_read_from_headers() {
+        let mut headers = HashMap::new();
+        set_schema_id(&mut headers, 7).unwrap();
+
+        let schema_id = get_schema_id(&Some(headers)).unwrap();
+
+        assert_eq!(schema_id, Some(7));
+    }
+
+    #[test]
+    fn schema_id_should_be_none_for_message_without_headers() {
+        assert_eq!(get_schema_id(&None).unwrap(), None);
+        assert_eq!(get_schema_id(&Some(HashMap::new())).unwrap(), None);
+    }
+}
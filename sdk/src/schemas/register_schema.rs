@@ -0,0 +1,163 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::models::schema::SchemaFormat;
+use crate::schemas::{validate_schema, validate_subject};
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `RegisterSchema` command is used to register the new version of the schema for the subject.
+/// If the same schema is already registered for the subject, its existing version is returned.
+/// Otherwise, the schema must be compatible with the latest version, according to the compatibility of the subject.
+/// It has additional payload:
+/// - `subject` - name of the subject, must be between 1 and 255 characters long.
+/// - `format` - format of the schema: JSON Schema (default), Avro or Protobuf.
+/// - `schema` - schema definition, must not be longer than 65536 bytes. The JSON Schema must be a JSON object (or boolean).
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RegisterSchema {
+    /// Name of the subject, must be between 1 and 255 characters long.
+    #[serde(skip)]
+    pub subject: String,
+    /// Format of the schema: JSON Schema (default), Avro or Protobuf.
+    #[serde(default)]
+    pub format: SchemaFormat,
+    /// Schema definition, must not be longer than 65536 bytes. The JSON Schema must be a JSON object (or boolean).
+    pub schema: String,
+}
+
+impl CommandPayload for RegisterSchema {}
+
+impl Default for RegisterSchema {
+    fn default() -> Self {
+        RegisterSchema {
+            subject: "subject".to_string(),
+            format: SchemaFormat::default(),
+            schema: r#"{"type":"object"}"#.to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for RegisterSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_subject(&self.subject)?;
+        validate_schema(self.format, &self.schema)
+    }
+}
+
+impl BytesSerializable for RegisterSchema {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(6 + self.subject.len() + self.schema.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.subject.len() as u8);
+        bytes.put_slice(self.subject.as_bytes());
+        bytes.put_u8(self.format.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(self.schema.len() as u32);
+        bytes.put_slice(self.schema.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RegisterSchema, IggyError> {
+        if bytes.len() < 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject_length = bytes[0] as usize;
+        if bytes.len() < 6 + subject_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject = from_utf8(&bytes[1..1 + subject_length])?.to_string();
+        let format = SchemaFormat::from_code(bytes[1 + subject_length])?;
+        let position = 2 + subject_length;
+        let schema_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+        let position = position + 4;
+        if bytes.len() != position + schema_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let schema = from_utf8(&bytes[position..position + schema_length])?.to_string();
+        let command = RegisterSchema {
+            subject,
+            format,
+            schema,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for RegisterSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}|{}", self.subject, self.format, self.schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RegisterSchema {
+            subject: "orders".to_string(),
+            format: SchemaFormat::Avro,
+            schema: r#"{"type":"long"}"#.to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let subject_length = bytes[0] as usize;
+        let subject = from_utf8(&bytes[1..1 + subject_length]).unwrap();
+        let format = SchemaFormat::from_code(bytes[1 + subject_length]).unwrap();
+        let schema_length = u32::from_le_bytes(
+            bytes[2 + subject_length..6 + subject_length]
+                .try_into()
+                .unwrap(),
+        ) as usize;
+        let schema =
+            from_utf8(&bytes[6 + subject_length..6 + subject_length + schema_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(subject, command.subject);
+        assert_eq!(format, command.format);
+        assert_eq!(schema, command.schema);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let subject = "orders";
+        let schema = r#"{"type":"object"}"#;
+        let mut bytes = BytesMut::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(subject.len() as u8);
+        bytes.put_slice(subject.as_bytes());
+        bytes.put_u8(SchemaFormat::Json.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(schema.len() as u32);
+        bytes.put_slice(schema.as_bytes());
+
+        let command = RegisterSchema::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subject, subject);
+        assert_eq!(command.format, SchemaFormat::Json);
+        assert_eq!(command.schema, schema);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_with_schema_not_being_json_object() {
+        let command = RegisterSchema {
+            subject: "orders".to_string(),
+            format: SchemaFormat::Json,
+            schema: "[1, 2]".to_string(),
+        };
+
+        let result = RegisterSchema::from_bytes(command.as_bytes());
+
+        assert!(matches!(result, Err(IggyError::InvalidSchema(_))));
+    }
+}
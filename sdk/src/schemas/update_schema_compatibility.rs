@@ -0,0 +1,117 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::models::schema::SchemaCompatibility;
+use crate::schemas::validate_subject;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `UpdateSchemaCompatibility` command is used to change the compatibility checked when registering the new versions of the schema for the subject.
+/// It has additional payload:
+/// - `subject` - name of the subject, must be between 1 and 255 characters long.
+/// - `compatibility` - compatibility of the subject: none, backward, forward or full.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct UpdateSchemaCompatibility {
+    /// Name of the subject, must be between 1 and 255 characters long.
+    #[serde(skip)]
+    pub subject: String,
+    /// Compatibility of the subject: none, backward, forward or full.
+    pub compatibility: SchemaCompatibility,
+}
+
+impl CommandPayload for UpdateSchemaCompatibility {}
+
+impl Default for UpdateSchemaCompatibility {
+    fn default() -> Self {
+        UpdateSchemaCompatibility {
+            subject: "subject".to_string(),
+            compatibility: SchemaCompatibility::default(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for UpdateSchemaCompatibility {
+    fn validate(&self) -> Result<(), IggyError> {
+        validate_subject(&self.subject)
+    }
+}
+
+impl BytesSerializable for UpdateSchemaCompatibility {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(2 + self.subject.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.subject.len() as u8);
+        bytes.put_slice(self.subject.as_bytes());
+        bytes.put_u8(self.compatibility.as_code());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateSchemaCompatibility, IggyError> {
+        if bytes.len() < 3 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject_length = bytes[0] as usize;
+        if bytes.len() != 2 + subject_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let subject = from_utf8(&bytes[1..1 + subject_length])?.to_string();
+        let compatibility = SchemaCompatibility::from_code(bytes[1 + subject_length])?;
+        let command = UpdateSchemaCompatibility {
+            subject,
+            compatibility,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateSchemaCompatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.subject, self.compatibility)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateSchemaCompatibility {
+            subject: "orders".to_string(),
+            compatibility: SchemaCompatibility::Full,
+        };
+
+        let bytes = command.as_bytes();
+        let subject_length = bytes[0] as usize;
+        let subject = from_utf8(&bytes[1..1 + subject_length]).unwrap();
+        let compatibility = SchemaCompatibility::from_code(bytes[1 + subject_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(subject, command.subject);
+        assert_eq!(compatibility, command.compatibility);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let subject = "orders";
+        let compatibility = SchemaCompatibility::Forward;
+        let mut bytes = BytesMut::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(subject.len() as u8);
+        bytes.put_slice(subject.as_bytes());
+        bytes.put_u8(compatibility.as_code());
+
+        let command = UpdateSchemaCompatibility::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subject, subject);
+        assert_eq!(command.compatibility, compatibility);
+    }
+}
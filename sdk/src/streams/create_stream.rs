@@ -1,7 +1,10 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::IggyError;
-use crate::streams::MAX_NAME_LENGTH;
+use crate::models::topic_defaults::TopicDefaults;
+use crate::streams::{
+    put_topic_defaults, read_topic_defaults, MAX_NAME_LENGTH, TOPIC_DEFAULTS_SIZE,
+};
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -13,12 +16,16 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric)
 /// - `name` - unique stream name (string), max length is 255 characters. The name will be always converted to lowercase and all whitespaces will be replaced with dots.
+/// - `topic_defaults` - the settings inherited by the topics created in the stream without the explicit values.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateStream {
     /// Unique stream ID (numeric), if None is provided then the server will automatically assign it.
     pub stream_id: Option<u32>,
     /// Unique stream name (string), max length is 255 characters.
     pub name: String,
+    /// The settings inherited by the topics created in the stream without the explicit values.
+    #[serde(default)]
+    pub topic_defaults: TopicDefaults,
 }
 
 impl CommandPayload for CreateStream {}
@@ -28,6 +35,7 @@ impl Default for CreateStream {
         CreateStream {
             stream_id: Some(1),
             name: "stream".to_string(),
+            topic_defaults: TopicDefaults::default(),
         }
     }
 }
@@ -48,17 +56,18 @@ impl Validatable<IggyError> for CreateStream {
             return Err(IggyError::InvalidStreamName);
         }
 
-        Ok(())
+        self.topic_defaults.validate()
     }
 }
 
 impl BytesSerializable for CreateStream {
     fn as_bytes(&self) -> Bytes {
-        let mut bytes = BytesMut::with_capacity(5 + self.name.len());
+        let mut bytes = BytesMut::with_capacity(5 + self.name.len() + TOPIC_DEFAULTS_SIZE);
         bytes.put_u32_le(self.stream_id.unwrap_or(0));
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        put_topic_defaults(&mut bytes, &self.topic_defaults);
         bytes.freeze()
     }
 
@@ -79,7 +88,13 @@ impl BytesSerializable for CreateStream {
             return Err(IggyError::InvalidCommand);
         }
 
-        let command = CreateStream { stream_id, name };
+        let topic_defaults =
+            read_topic_defaults(&bytes, 5 + name_length as usize)?.unwrap_or_default();
+        let command = CreateStream {
+            stream_id,
+            name,
+            topic_defaults,
+        };
         command.validate()?;
         Ok(command)
     }
@@ -87,29 +102,47 @@ impl BytesSerializable for CreateStream {
 
 impl Display for CreateStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id.unwrap_or(0), self.name)
+        write!(
+            f,
+            "{}|{}|{}",
+            self.stream_id.unwrap_or(0),
+            self.name,
+            self.topic_defaults
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::compression::compression_algorithm::CompressionAlgorithm;
+    use crate::utils::byte_size::IggyByteSize;
 
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = CreateStream {
             stream_id: Some(1),
             name: "test".to_string(),
+            topic_defaults: TopicDefaults {
+                message_expiry: Some(10),
+                max_topic_size: Some(IggyByteSize::from(100)),
+                compression_algorithm: Some(CompressionAlgorithm::Gzip),
+                replication_factor: Some(2),
+            },
         };
 
         let bytes = command.as_bytes();
         let stream_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
         let name_length = bytes[4];
         let name = from_utf8(&bytes[5..5 + name_length as usize]).unwrap();
+        let topic_defaults = read_topic_defaults(&bytes, 5 + name_length as usize)
+            .unwrap()
+            .unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id.unwrap());
         assert_eq!(name, command.name);
+        assert_eq!(topic_defaults, command.topic_defaults);
     }
 
     #[test]
@@ -127,5 +160,38 @@ mod tests {
         let command = command.unwrap();
         assert_eq!(command.stream_id.unwrap(), stream_id);
         assert_eq!(command.name, name);
+        assert_eq!(command.topic_defaults, TopicDefaults::default());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_topic_defaults() {
+        let command = CreateStream {
+            stream_id: Some(1),
+            name: "test".to_string(),
+            topic_defaults: TopicDefaults {
+                message_expiry: None,
+                max_topic_size: Some(IggyByteSize::from(100)),
+                compression_algorithm: None,
+                replication_factor: Some(3),
+            },
+        };
+
+        let deserialized = CreateStream::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_zero_replication_factor_default() {
+        let command = CreateStream {
+            stream_id: Some(1),
+            name: "test".to_string(),
+            topic_defaults: TopicDefaults {
+                replication_factor: Some(0),
+                ..TopicDefaults::default()
+            },
+        };
+
+        assert!(command.validate().is_err());
     }
 }
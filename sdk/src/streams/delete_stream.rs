@@ -3,18 +3,22 @@ use crate::command::CommandPayload;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::validatable::Validatable;
-use bytes::Bytes;
+use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 /// `DeleteStream` command is used to delete an existing stream.
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
+/// - `soft` - whether the stream is moved to the trash, from which it can be restored until the retention period expires.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct DeleteStream {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
     pub stream_id: Identifier,
+    /// Whether the stream is moved to the trash instead of being deleted right away.
+    #[serde(default)]
+    pub soft: bool,
 }
 
 impl CommandPayload for DeleteStream {}
@@ -27,7 +31,11 @@ impl Validatable<IggyError> for DeleteStream {
 
 impl BytesSerializable for DeleteStream {
     fn as_bytes(&self) -> Bytes {
-        self.stream_id.as_bytes()
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(1 + stream_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_u8(self.soft as u8);
+        bytes.freeze()
     }
 
     fn from_bytes(bytes: Bytes) -> std::result::Result<DeleteStream, IggyError> {
@@ -35,8 +43,9 @@ impl BytesSerializable for DeleteStream {
             return Err(IggyError::InvalidCommand);
         }
 
-        let stream_id = Identifier::from_bytes(bytes)?;
-        let command = DeleteStream { stream_id };
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        let soft = bytes.get(stream_id.get_size_bytes() as usize) == Some(&1);
+        let command = DeleteStream { stream_id, soft };
         command.validate()?;
         Ok(command)
     }
@@ -44,7 +53,7 @@ impl BytesSerializable for DeleteStream {
 
 impl Display for DeleteStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.stream_id)
+        write!(f, "{}|{}", self.stream_id, self.soft)
     }
 }
 
@@ -56,13 +65,16 @@ mod tests {
     fn should_be_serialized_as_bytes() {
         let command = DeleteStream {
             stream_id: Identifier::numeric(1).unwrap(),
+            soft: true,
         };
 
         let bytes = command.as_bytes();
         let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        let soft = bytes[stream_id.get_size_bytes() as usize];
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
+        assert_eq!(soft, 1);
     }
 
     #[test]
@@ -74,5 +86,17 @@ mod tests {
 
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
+        assert!(!command.soft);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_soft_flag() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put(stream_id.as_bytes());
+        bytes.put_u8(1);
+        let command = DeleteStream::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert!(command.soft);
     }
 }
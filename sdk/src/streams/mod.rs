@@ -3,6 +3,66 @@ pub mod delete_stream;
 pub mod get_stream;
 pub mod get_streams;
 pub mod purge_stream;
+pub mod undelete_stream;
 pub mod update_stream;
 
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::error::IggyError;
+use crate::models::topic_defaults::TopicDefaults;
+use crate::utils::byte_size::IggyByteSize;
+use bytes::{BufMut, Bytes, BytesMut};
+
 const MAX_NAME_LENGTH: usize = 255;
+
+/// The size of the topic defaults in the binary format:
+/// message expiry (4), max topic size (8), compression algorithm code (1) and replication factor (1), where 0 means not set.
+pub(crate) const TOPIC_DEFAULTS_SIZE: usize = 14;
+
+pub(crate) fn put_topic_defaults(bytes: &mut BytesMut, topic_defaults: &TopicDefaults) {
+    bytes.put_u32_le(topic_defaults.message_expiry.unwrap_or(0));
+    bytes.put_u64_le(
+        topic_defaults
+            .max_topic_size
+            .map_or(0, |max_topic_size| max_topic_size.as_bytes_u64()),
+    );
+    bytes.put_u8(
+        topic_defaults
+            .compression_algorithm
+            .as_ref()
+            .map_or(0, |compression_algorithm| compression_algorithm.as_code()),
+    );
+    bytes.put_u8(topic_defaults.replication_factor.unwrap_or(0));
+}
+
+/// Reads the topic defaults at the given position, the missing defaults (e.g. sent by the older client or server) are treated as `None`.
+pub(crate) fn read_topic_defaults(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<Option<TopicDefaults>, IggyError> {
+    let Some(bytes) = bytes.get(position..position + TOPIC_DEFAULTS_SIZE) else {
+        return Ok(None);
+    };
+
+    let message_expiry = match u32::from_le_bytes(bytes[..4].try_into()?) {
+        0 => None,
+        message_expiry => Some(message_expiry),
+    };
+    let max_topic_size = match u64::from_le_bytes(bytes[4..12].try_into()?) {
+        0 => None,
+        max_topic_size => Some(IggyByteSize::from(max_topic_size)),
+    };
+    let compression_algorithm = match bytes[12] {
+        0 => None,
+        code => Some(CompressionAlgorithm::from_code(code)?),
+    };
+    let replication_factor = match bytes[13] {
+        0 => None,
+        replication_factor => Some(replication_factor),
+    };
+    Ok(Some(TopicDefaults {
+        message_expiry,
+        max_topic_size,
+        compression_algorithm,
+        replication_factor,
+    }))
+}
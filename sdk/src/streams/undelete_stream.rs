@@ -0,0 +1,78 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UndeleteStream` command is used to restore the stream moved to the trash by the soft delete.
+/// It has additional payload:
+/// - `stream_id` - unique ID (numeric or name) of the stream in the trash.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UndeleteStream {
+    /// Unique ID (numeric or name) of the stream in the trash.
+    #[serde(skip)]
+    pub stream_id: Identifier,
+}
+
+impl CommandPayload for UndeleteStream {}
+
+impl Validatable<IggyError> for UndeleteStream {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UndeleteStream {
+    fn as_bytes(&self) -> Bytes {
+        self.stream_id.as_bytes()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UndeleteStream, IggyError> {
+        if bytes.len() < 3 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_bytes(bytes)?;
+        let command = UndeleteStream { stream_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UndeleteStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.stream_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UndeleteStream {
+            stream_id: Identifier::numeric(1).unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::named("orders").unwrap();
+        let bytes = stream_id.as_bytes();
+        let command = UndeleteStream::from_bytes(bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+    }
+}
@@ -2,7 +2,10 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
-use crate::streams::MAX_NAME_LENGTH;
+use crate::models::topic_defaults::TopicDefaults;
+use crate::streams::{
+    put_topic_defaults, read_topic_defaults, MAX_NAME_LENGTH, TOPIC_DEFAULTS_SIZE,
+};
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -14,6 +17,7 @@ use std::str::from_utf8;
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `name` - unique stream name (string), max length is 255 characters.
+/// - `topic_defaults` - optional settings inherited by the topics created in the stream afterwards, if `None` then the current ones are kept.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct UpdateStream {
     /// Unique stream ID (numeric or name).
@@ -21,6 +25,9 @@ pub struct UpdateStream {
     pub stream_id: Identifier,
     /// Unique stream name (string), max length is 255 characters.
     pub name: String,
+    /// Optional settings inherited by the topics created in the stream afterwards, if `None` then the current ones are kept.
+    #[serde(default)]
+    pub topic_defaults: Option<TopicDefaults>,
 }
 
 impl CommandPayload for UpdateStream {}
@@ -30,6 +37,7 @@ impl Default for UpdateStream {
         UpdateStream {
             stream_id: Identifier::default(),
             name: "stream".to_string(),
+            topic_defaults: None,
         }
     }
 }
@@ -44,6 +52,10 @@ impl Validatable<IggyError> for UpdateStream {
             return Err(IggyError::InvalidStreamName);
         }
 
+        if let Some(topic_defaults) = &self.topic_defaults {
+            topic_defaults.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -51,11 +63,16 @@ impl Validatable<IggyError> for UpdateStream {
 impl BytesSerializable for UpdateStream {
     fn as_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.as_bytes();
-        let mut bytes = BytesMut::with_capacity(1 + stream_id_bytes.len() + self.name.len());
+        let mut bytes = BytesMut::with_capacity(
+            1 + stream_id_bytes.len() + self.name.len() + TOPIC_DEFAULTS_SIZE,
+        );
         bytes.put_slice(&stream_id_bytes);
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        if let Some(topic_defaults) = &self.topic_defaults {
+            put_topic_defaults(&mut bytes, topic_defaults);
+        }
         bytes.freeze()
     }
 
@@ -74,7 +91,12 @@ impl BytesSerializable for UpdateStream {
             return Err(IggyError::InvalidCommand);
         }
 
-        let command = UpdateStream { stream_id, name };
+        let topic_defaults = read_topic_defaults(&bytes, position + 1 + name_length as usize)?;
+        let command = UpdateStream {
+            stream_id,
+            name,
+            topic_defaults,
+        };
         command.validate()?;
         Ok(command)
     }
@@ -82,19 +104,26 @@ impl BytesSerializable for UpdateStream {
 
 impl Display for UpdateStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id, self.name)
+        match &self.topic_defaults {
+            Some(topic_defaults) => {
+                write!(f, "{}|{}|{}", self.stream_id, self.name, topic_defaults)
+            }
+            None => write!(f, "{}|{}", self.stream_id, self.name),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::compression::compression_algorithm::CompressionAlgorithm;
 
     #[test]
     fn should_be_serialized_as_bytes() {
         let command = UpdateStream {
             stream_id: Identifier::numeric(1).unwrap(),
             name: "test".to_string(),
+            topic_defaults: None,
         };
 
         let bytes = command.as_bytes();
@@ -128,5 +157,24 @@ mod tests {
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.name, name);
+        assert!(command.topic_defaults.is_none());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_topic_defaults() {
+        let command = UpdateStream {
+            stream_id: Identifier::numeric(1).unwrap(),
+            name: "test".to_string(),
+            topic_defaults: Some(TopicDefaults {
+                message_expiry: Some(10),
+                max_topic_size: None,
+                compression_algorithm: Some(CompressionAlgorithm::Zstd),
+                replication_factor: None,
+            }),
+        };
+
+        let deserialized = UpdateStream::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized, command);
     }
 }
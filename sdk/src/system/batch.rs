@@ -0,0 +1,167 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, CommandPayload};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::fmt::Display;
+
+const MAX_COMMANDS_COUNT: usize = 1000;
+
+/// `Batch` command is used to send several commands to the server in a single round trip, e.g. to create the topic along with its partitions or to send the messages to many different topics.
+/// The commands are executed by the server sequentially in the provided order, and the failure of one command doesn't prevent the execution of the following ones.
+/// The server responds with the status (and the optional payload) of each command, in the same order as the commands were sent.
+/// It has additional payload:
+/// - `commands` - the commands to execute, must contain at least one and at most 1000 commands, which cannot be batches themselves.
+#[derive(Debug, Default, PartialEq)]
+pub struct Batch {
+    /// The commands to execute.
+    pub commands: Vec<Command>,
+}
+
+impl CommandPayload for Batch {}
+
+impl Validatable<IggyError> for Batch {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.commands.is_empty() || self.commands.len() > MAX_COMMANDS_COUNT {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        if self
+            .commands
+            .iter()
+            .any(|command| matches!(command, Command::Batch(_)))
+        {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for Batch {
+    fn as_bytes(&self) -> Bytes {
+        let commands = self
+            .commands
+            .iter()
+            .map(|command| command.as_bytes())
+            .collect::<Vec<_>>();
+        let commands_length = commands
+            .iter()
+            .map(|command| 4 + command.len())
+            .sum::<usize>();
+        let mut bytes = BytesMut::with_capacity(4 + commands_length);
+        bytes.put_u32_le(commands.len() as u32);
+        for command in commands {
+            bytes.put_u32_le(command.len() as u32);
+            bytes.put_slice(&command);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Batch, IggyError> {
+        if bytes.len() < 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let count = u32::from_le_bytes(bytes[..4].try_into()?) as usize;
+        if count > MAX_COMMANDS_COUNT {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut commands = Vec::with_capacity(count);
+        let mut position = 4;
+        for _ in 0..count {
+            if position + 4 > bytes.len() {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            let length = u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+            position += 4;
+            if length < 4 || position + length > bytes.len() {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            commands.push(Command::from_bytes(
+                bytes.slice(position..position + length),
+            )?);
+            position += length;
+        }
+
+        if position != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = Batch { commands };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for Batch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let commands = self
+            .commands
+            .iter()
+            .map(|command| command.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "{}", commands.join(";"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifier::Identifier;
+    use crate::streams::create_stream::CreateStream;
+    use crate::system::ping::Ping;
+    use crate::topics::get_topics::GetTopics;
+
+    #[test]
+    fn should_be_serialized_as_bytes_and_deserialized_from_bytes() {
+        let command = Batch {
+            commands: vec![
+                Command::Ping(Ping::default()),
+                Command::CreateStream(CreateStream {
+                    stream_id: Some(1),
+                    name: "test".to_string(),
+                    ..CreateStream::default()
+                }),
+                Command::GetTopics(GetTopics {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                }),
+            ],
+        };
+
+        let bytes = command.as_bytes();
+        let deserialized_command = Batch::from_bytes(bytes.clone()).unwrap();
+
+        assert_eq!(u32::from_le_bytes(bytes[..4].try_into().unwrap()), 3);
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let command = Batch {
+            commands: vec![Command::Ping(Ping::default())],
+        };
+        let bytes = command.as_bytes();
+
+        assert!(Batch::from_bytes(bytes.slice(..bytes.len() - 1)).is_err());
+        assert!(Batch::from_bytes(Bytes::from_static(&[0, 0])).is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_when_empty_or_nested() {
+        let empty = Batch::default();
+        let nested = Batch {
+            commands: vec![Command::Batch(Batch {
+                commands: vec![Command::Ping(Ping::default())],
+            })],
+        };
+
+        assert!(empty.validate().is_err());
+        assert!(Batch::from_bytes(empty.as_bytes()).is_err());
+        assert!(nested.validate().is_err());
+        assert!(Batch::from_bytes(nested.as_bytes()).is_err());
+    }
+}
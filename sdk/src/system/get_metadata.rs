@@ -0,0 +1,66 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetMetadata` command is used to get the metadata of all the streams, topics and partitions available to the user in a single response.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct GetMetadata {}
+
+impl CommandPayload for GetMetadata {}
+
+impl Validatable<IggyError> for GetMetadata {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetMetadata {
+    fn as_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetMetadata, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = GetMetadata {};
+        command.validate()?;
+        Ok(GetMetadata {})
+    }
+}
+
+impl Display for GetMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = GetMetadata {};
+        let bytes = command.as_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let command = GetMetadata::from_bytes(Bytes::new());
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_empty_bytes() {
+        let command = GetMetadata::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
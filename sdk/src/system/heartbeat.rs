@@ -0,0 +1,66 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `Heartbeat` command is periodically sent by the TCP and QUIC clients on the idle connection, so that the server can detect the dead (half-open) connections.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Heartbeat {}
+
+impl CommandPayload for Heartbeat {}
+
+impl Validatable<IggyError> for Heartbeat {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for Heartbeat {
+    fn as_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Heartbeat, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = Heartbeat {};
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for Heartbeat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = Heartbeat {};
+        let bytes = command.as_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let command = Heartbeat::from_bytes(Bytes::new());
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_empty_bytes() {
+        let command = Heartbeat::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// The current version of the protocol, which is incremented on every incompatible change of the format.
+pub const PROTOCOL_VERSION: u32 = 1;
+/// The oldest version of the protocol which is still supported.
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+/// The feature of sending the heartbeats on the idle connection.
+pub const HEARTBEAT_FEATURE: &str = "heartbeat";
+/// The feature of sending and polling the messages in the current batch format.
+pub const BATCH_V1_FEATURE: &str = "batch.v1";
+/// The feature of executing several commands in a single round trip with the `Batch` command.
+pub const COMMAND_BATCH_FEATURE: &str = "command.batch";
+/// The feature of fetching the messages from many partitions at once within the fetch session.
+pub const FETCH_SESSION_FEATURE: &str = "fetch.session";
+/// The feature of authenticating with the username and password.
+pub const PASSWORD_AUTH_FEATURE: &str = "auth.password";
+/// The feature of authenticating with the personal access token.
+pub const PERSONAL_ACCESS_TOKEN_AUTH_FEATURE: &str = "auth.personal_access_token";
+const COMPRESSION_FEATURE_PREFIX: &str = "compression.";
+const MAX_FEATURES_COUNT: usize = 255;
+const MAX_FEATURE_LENGTH: usize = 255;
+
+/// Returns the name of the feature for the provided compression algorithm, e.g. `compression.zstd`.
+pub fn compression_feature(algorithm: &CompressionAlgorithm) -> String {
+    format!("{COMPRESSION_FEATURE_PREFIX}{algorithm}")
+}
+
+/// Returns all the features supported by this version of the SDK and the server.
+pub fn supported_features() -> Vec<String> {
+    let mut features = vec![
+        HEARTBEAT_FEATURE.to_string(),
+        BATCH_V1_FEATURE.to_string(),
+        COMMAND_BATCH_FEATURE.to_string(),
+        FETCH_SESSION_FEATURE.to_string(),
+        PASSWORD_AUTH_FEATURE.to_string(),
+        PERSONAL_ACCESS_TOKEN_AUTH_FEATURE.to_string(),
+    ];
+    features.extend(
+        [
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Zstd,
+            CompressionAlgorithm::Lz4,
+        ]
+        .iter()
+        .map(compression_feature),
+    );
+    features
+}
+
+/// `Hello` command is used to start the connection with the handshake, in which the client and the server exchange the protocol versions and the supported features.
+/// The server responds with the negotiated protocol version and the features supported by both sides, which allows to evolve the protocol without breaking the older clients.
+/// It has additional payload:
+/// - `protocol_version` - the version of the protocol used by the client.
+/// - `features` - the features supported by the client, e.g. `heartbeat`, `batch.v1`, `auth.password` or `compression.zstd`.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Hello {
+    /// The version of the protocol used by the client.
+    pub protocol_version: u32,
+    /// The features supported by the client.
+    pub features: Vec<String>,
+}
+
+impl CommandPayload for Hello {}
+
+impl Default for Hello {
+    fn default() -> Self {
+        Hello {
+            protocol_version: PROTOCOL_VERSION,
+            features: supported_features(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for Hello {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.protocol_version == 0 || self.features.len() > MAX_FEATURES_COUNT {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        if self
+            .features
+            .iter()
+            .any(|feature| feature.is_empty() || feature.len() > MAX_FEATURE_LENGTH)
+        {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for Hello {
+    fn as_bytes(&self) -> Bytes {
+        let features_length = self
+            .features
+            .iter()
+            .map(|feature| 1 + feature.len())
+            .sum::<usize>();
+        let mut bytes = BytesMut::with_capacity(4 + features_length);
+        bytes.put_u32_le(self.protocol_version);
+        for feature in &self.features {
+            bytes.put_u8(feature.len() as u8);
+            bytes.put_slice(feature.as_bytes());
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Hello, IggyError> {
+        if bytes.len() < 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let protocol_version = u32::from_le_bytes(bytes[..4].try_into()?);
+        let mut features = Vec::new();
+        let mut position = 4;
+        while position < bytes.len() {
+            let length = bytes[position] as usize;
+            position += 1;
+            if position + length > bytes.len() {
+                return Err(IggyError::InvalidCommand);
+            }
+
+            features.push(from_utf8(&bytes[position..position + length])?.to_string());
+            position += length;
+        }
+
+        let command = Hello {
+            protocol_version,
+            features,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for Hello {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.protocol_version, self.features.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = Hello {
+            protocol_version: 1,
+            features: vec!["heartbeat".to_string(), "compression.zstd".to_string()],
+        };
+
+        let bytes = command.as_bytes();
+
+        assert_eq!(u32::from_le_bytes(bytes[..4].try_into().unwrap()), 1);
+        assert_eq!(bytes[4], 9);
+        assert_eq!(&bytes[5..14], b"heartbeat");
+        assert_eq!(bytes[14], 16);
+        assert_eq!(&bytes[15..], b"compression.zstd");
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = Hello::default();
+
+        let deserialized = Hello::from_bytes(command.as_bytes());
+
+        assert!(deserialized.is_ok());
+        assert_eq!(deserialized.unwrap(), command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_bytes() {
+        assert!(Hello::from_bytes(Bytes::new()).is_err());
+        assert!(Hello::from_bytes(Bytes::from_static(&[0, 0, 0, 0])).is_err());
+        assert!(Hello::from_bytes(Bytes::from_static(&[1, 0, 0, 0, 5, b'a'])).is_err());
+    }
+}
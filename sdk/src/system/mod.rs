@@ -1,5 +1,10 @@
+pub mod batch;
 pub mod get_client;
 pub mod get_clients;
 pub mod get_me;
+pub mod get_metadata;
 pub mod get_stats;
+pub mod heartbeat;
+pub mod hello;
+pub mod negotiate_compression;
 pub mod ping;
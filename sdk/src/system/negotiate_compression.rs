@@ -0,0 +1,113 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `NegotiateCompression` command is used to advertise the compression algorithms supported by the client,
+/// the server responds with the algorithm which should be used to compress the message payloads.
+/// It has additional payload:
+/// - `algorithms` - the supported compression algorithms, in the order of preference.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct NegotiateCompression {
+    /// The supported compression algorithms, in the order of preference.
+    pub algorithms: Vec<CompressionAlgorithm>,
+}
+
+impl CommandPayload for NegotiateCompression {}
+
+impl Default for NegotiateCompression {
+    fn default() -> Self {
+        NegotiateCompression {
+            algorithms: vec![CompressionAlgorithm::None],
+        }
+    }
+}
+
+impl Validatable<IggyError> for NegotiateCompression {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.algorithms.is_empty() || self.algorithms.len() > 255 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for NegotiateCompression {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(self.algorithms.len());
+        for algorithm in &self.algorithms {
+            bytes.put_u8(algorithm.as_code());
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<NegotiateCompression, IggyError> {
+        if bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let algorithms = bytes
+            .iter()
+            .map(|code| CompressionAlgorithm::from_code(*code))
+            .collect::<Result<Vec<_>, _>>()?;
+        let command = NegotiateCompression { algorithms };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for NegotiateCompression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let algorithms = self
+            .algorithms
+            .iter()
+            .map(|algorithm| algorithm.as_str())
+            .collect::<Vec<_>>();
+        write!(f, "{}", algorithms.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = NegotiateCompression {
+            algorithms: vec![CompressionAlgorithm::Zstd, CompressionAlgorithm::Gzip],
+        };
+
+        let bytes = command.as_bytes();
+
+        assert_eq!(bytes.len(), 2);
+        assert_eq!(bytes[0], CompressionAlgorithm::Zstd.as_code());
+        assert_eq!(bytes[1], CompressionAlgorithm::Gzip.as_code());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let mut bytes = BytesMut::with_capacity(2);
+        bytes.put_u8(CompressionAlgorithm::Lz4.as_code());
+        bytes.put_u8(CompressionAlgorithm::None.as_code());
+
+        let command = NegotiateCompression::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(
+            command.algorithms,
+            vec![CompressionAlgorithm::Lz4, CompressionAlgorithm::None]
+        );
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_empty_bytes_or_unknown_algorithm() {
+        assert!(NegotiateCompression::from_bytes(Bytes::new()).is_err());
+        assert!(NegotiateCompression::from_bytes(Bytes::from_static(&[99])).is_err());
+    }
+}
@@ -1,9 +1,14 @@
 use crate::binary::binary_client::{BinaryClient, ClientState};
+use crate::bytes_serializable::BytesSerializable;
 use crate::client::Client;
+use crate::command::HEARTBEAT_CODE;
 use crate::error::{IggyError, IggyErrorDiscriminants};
+use crate::system::heartbeat::Heartbeat;
 use crate::tcp::config::TcpClientConfig;
+use crate::utils::buffer_pool::BufferPool;
 use async_trait::async_trait;
-use bytes::{BufMut, Bytes, BytesMut};
+use bytes::{BufMut, Bytes};
+use socket2::{SockRef, TcpKeepalive};
 use std::fmt::Debug;
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -12,6 +17,7 @@ use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tokio_native_tls::native_tls::TlsConnector;
 use tokio_native_tls::TlsStream;
@@ -27,9 +33,10 @@ const NAME: &str = "Iggy";
 #[derive(Debug)]
 pub struct TcpClient {
     pub(crate) server_address: SocketAddr,
-    pub(crate) stream: Mutex<Option<Box<dyn ConnectionStream>>>,
+    pub(crate) stream: Arc<Mutex<Option<Box<dyn ConnectionStream>>>>,
     pub(crate) config: Arc<TcpClientConfig>,
     pub(crate) state: Mutex<ClientState>,
+    heartbeat: std::sync::Mutex<Option<JoinHandle<()>>>,
 }
 
 unsafe impl Send for TcpClient {}
@@ -161,6 +168,14 @@ impl Client for TcpClient {
 
             let stream = connection.unwrap();
             remote_address = stream.peer_addr()?;
+            if self.config.heartbeat_interval > 0 {
+                // The keep alive time has the granularity of seconds, so it cannot be lower than 1 second.
+                let keepalive = TcpKeepalive::new().with_time(
+                    Duration::from_millis(self.config.heartbeat_interval)
+                        .max(Duration::from_secs(1)),
+                );
+                SockRef::from(&stream).set_tcp_keepalive(&keepalive)?;
+            }
 
             if !tls_enabled {
                 connection_stream = Box::new(TcpConnectionStream::new(stream));
@@ -182,6 +197,7 @@ impl Client for TcpClient {
 
         self.stream.lock().await.replace(connection_stream);
         self.set_state(ClientState::Connected).await;
+        self.start_heartbeat();
 
         info!(
             "{} client has connected to server: {}",
@@ -198,6 +214,7 @@ impl Client for TcpClient {
 
         info!("{} client is disconnecting from server...", NAME);
         self.set_state(ClientState::Disconnected).await;
+        self.stop_heartbeat();
         self.stream.lock().await.take();
         info!("{} client has disconnected from server.", NAME);
         Ok(())
@@ -221,24 +238,7 @@ impl BinaryClient for TcpClient {
 
         let mut stream = self.stream.lock().await;
         if let Some(stream) = stream.as_mut() {
-            let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
-            trace!("Sending a TCP request...");
-            stream.write(&(payload_length as u32).to_le_bytes()).await?;
-            stream.write(&command.to_le_bytes()).await?;
-            stream.write(&payload).await?;
-            stream.flush().await?;
-            trace!("Sent a TCP request, waiting for a response...");
-
-            let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
-            let read_bytes = stream.read(&mut response_buffer).await?;
-            if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
-                error!("Received an invalid or empty response.");
-                return Err(IggyError::EmptyResponse);
-            }
-
-            let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
-            let length = u32::from_le_bytes(response_buffer[4..].try_into().unwrap());
-            return self.handle_response(status, length, stream.as_mut()).await;
+            return send_request(stream.as_mut(), command, payload).await;
         }
 
         error!("Cannot send data. Client is not connected.");
@@ -272,52 +272,119 @@ impl TcpClient {
         Ok(Self {
             config,
             server_address,
-            stream: Mutex::new(None),
+            stream: Arc::new(Mutex::new(None)),
             state: Mutex::new(ClientState::Disconnected),
+            heartbeat: std::sync::Mutex::new(None),
         })
     }
 
-    async fn handle_response(
-        &self,
-        status: u32,
-        length: u32,
-        stream: &mut dyn ConnectionStream,
-    ) -> Result<Bytes, IggyError> {
-        if status != 0 {
-            // TEMP: See https://github.com/iggy-rs/iggy/pull/604 for context.
-            if status == IggyErrorDiscriminants::TopicIdAlreadyExists as u32
-                || status == IggyErrorDiscriminants::TopicNameAlreadyExists as u32
-                || status == IggyErrorDiscriminants::StreamIdAlreadyExists as u32
-                || status == IggyErrorDiscriminants::StreamNameAlreadyExists as u32
-                || status == IggyErrorDiscriminants::UserAlreadyExists as u32
-                || status == IggyErrorDiscriminants::PersonalAccessTokenAlreadyExists as u32
-                || status == IggyErrorDiscriminants::ConsumerGroupIdAlreadyExists as u32
-                || status == IggyErrorDiscriminants::ConsumerGroupNameAlreadyExists as u32
-            {
-                tracing::debug!(
-                    "Received a server resource already exists response: {} ({})",
-                    status,
-                    IggyError::from_code_as_string(status)
-                )
-            } else {
-                error!(
-                    "Received an invalid response with status: {} ({}).",
-                    status,
-                    IggyError::from_code_as_string(status)
-                );
+    /// Spawns the background task sending the heartbeats to the server, so that the server doesn't consider the idle connection dead.
+    fn start_heartbeat(&self) {
+        self.stop_heartbeat();
+        if self.config.heartbeat_interval == 0 {
+            return;
+        }
+
+        let stream = self.stream.clone();
+        let interval = Duration::from_millis(self.config.heartbeat_interval);
+        let task = tokio::spawn(async move {
+            loop {
+                sleep(interval).await;
+                let mut stream = stream.lock().await;
+                let Some(stream) = stream.as_mut() else {
+                    break;
+                };
+
+                trace!("Sending a heartbeat...");
+                if let Err(error) =
+                    send_request(stream.as_mut(), HEARTBEAT_CODE, Heartbeat {}.as_bytes()).await
+                {
+                    error!("Failed to send a heartbeat, error: {error}");
+                    break;
+                }
             }
+        });
+        self.heartbeat.lock().unwrap().replace(task);
+    }
 
-            return Err(IggyError::InvalidResponse(status));
+    fn stop_heartbeat(&self) {
+        if let Some(task) = self.heartbeat.lock().unwrap().take() {
+            task.abort();
         }
+    }
+}
 
-        trace!("Status: OK. Response length: {}", length);
-        if length <= 1 {
-            return Ok(Bytes::new());
+impl Drop for TcpClient {
+    fn drop(&mut self) {
+        self.stop_heartbeat();
+    }
+}
+
+async fn send_request(
+    stream: &mut dyn ConnectionStream,
+    command: u32,
+    payload: Bytes,
+) -> Result<Bytes, IggyError> {
+    let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
+    trace!("Sending a TCP request...");
+    stream.write(&(payload_length as u32).to_le_bytes()).await?;
+    stream.write(&command.to_le_bytes()).await?;
+    stream.write(&payload).await?;
+    stream.flush().await?;
+    trace!("Sent a TCP request, waiting for a response...");
+
+    let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
+    let read_bytes = stream.read(&mut response_buffer).await?;
+    if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
+        error!("Received an invalid or empty response.");
+        return Err(IggyError::EmptyResponse);
+    }
+
+    let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
+    let length = u32::from_le_bytes(response_buffer[4..].try_into().unwrap());
+    handle_response(status, length, stream).await
+}
+
+async fn handle_response(
+    status: u32,
+    length: u32,
+    stream: &mut dyn ConnectionStream,
+) -> Result<Bytes, IggyError> {
+    if status != 0 {
+        // TEMP: See https://github.com/iggy-rs/iggy/pull/604 for context.
+        if status == IggyErrorDiscriminants::TopicIdAlreadyExists as u32
+            || status == IggyErrorDiscriminants::TopicNameAlreadyExists as u32
+            || status == IggyErrorDiscriminants::StreamIdAlreadyExists as u32
+            || status == IggyErrorDiscriminants::StreamNameAlreadyExists as u32
+            || status == IggyErrorDiscriminants::UserAlreadyExists as u32
+            || status == IggyErrorDiscriminants::PersonalAccessTokenAlreadyExists as u32
+            || status == IggyErrorDiscriminants::ConsumerGroupIdAlreadyExists as u32
+            || status == IggyErrorDiscriminants::ConsumerGroupNameAlreadyExists as u32
+        {
+            tracing::debug!(
+                "Received a server resource already exists response: {} ({})",
+                status,
+                IggyError::from_code_as_string(status)
+            )
+        } else {
+            error!(
+                "Received an invalid response with status: {} ({}).",
+                status,
+                IggyError::from_code_as_string(status)
+            );
         }
 
-        let mut response_buffer = BytesMut::with_capacity(length as usize);
-        response_buffer.put_bytes(0, length as usize);
-        stream.read(&mut response_buffer).await?;
-        Ok(response_buffer.freeze())
+        return Err(IggyError::InvalidResponse(status));
     }
+
+    trace!("Status: OK. Response length: {}", length);
+    if length <= 1 {
+        return Ok(Bytes::new());
+    }
+
+    let buffer_pool = BufferPool::global();
+    let mut response_buffer = buffer_pool.acquire(length as usize);
+    response_buffer.put_bytes(0, length as usize);
+    stream.read(&mut response_buffer).await?;
+    Ok(buffer_pool.freeze(response_buffer))
 }
@@ -11,6 +11,8 @@ pub struct TcpClientConfig {
     pub tls_enabled: bool,
     /// The domain to use for TLS when connecting to the server.
     pub tls_domain: String,
+    /// The interval in milliseconds of the heartbeats (and TCP keep alive probes) sent to the server, 0 disables them.
+    pub heartbeat_interval: u64,
 }
 
 impl Default for TcpClientConfig {
@@ -21,6 +23,7 @@ impl Default for TcpClientConfig {
             reconnection_interval: 1000,
             tls_enabled: false,
             tls_domain: "localhost".to_string(),
+            heartbeat_interval: 5000,
         }
     }
 }
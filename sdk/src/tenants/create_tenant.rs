@@ -0,0 +1,162 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::models::tenant::TenantQuotas;
+use crate::tenants::validate_name;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `CreateTenant` command is used to create a new tenant.
+/// It has additional payload:
+/// - `tenant_id` - unique tenant ID (numeric), if None is provided then the server will automatically assign it.
+/// - `name` - unique tenant name (string), max length is 255 characters. The name will be always converted to lowercase,
+///   it can't contain the dots nor the whitespaces, as it's used as the prefix of the tenant streams names.
+/// - `quotas` - the limits of the resources the tenant can use.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateTenant {
+    /// Unique tenant ID (numeric), if None is provided then the server will automatically assign it.
+    pub tenant_id: Option<u32>,
+    /// Unique tenant name (string), max length is 255 characters.
+    pub name: String,
+    /// The limits of the resources the tenant can use.
+    #[serde(default)]
+    pub quotas: TenantQuotas,
+}
+
+impl CommandPayload for CreateTenant {}
+
+impl Default for CreateTenant {
+    fn default() -> Self {
+        CreateTenant {
+            tenant_id: Some(1),
+            name: "tenant".to_string(),
+            quotas: TenantQuotas::default(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for CreateTenant {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(tenant_id) = self.tenant_id {
+            if tenant_id == 0 {
+                return Err(IggyError::InvalidTenantId);
+            }
+        }
+
+        validate_name(&self.name)
+    }
+}
+
+impl BytesSerializable for CreateTenant {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(5 + TenantQuotas::SIZE_BYTES + self.name.len());
+        bytes.put_u32_le(self.tenant_id.unwrap_or(0));
+        bytes.put_slice(&self.quotas.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<CreateTenant, IggyError> {
+        let name_position = 5 + TenantQuotas::SIZE_BYTES;
+        if bytes.len() < name_position + 1 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let tenant_id = match u32::from_le_bytes(bytes[..4].try_into()?) {
+            0 => None,
+            tenant_id => Some(tenant_id),
+        };
+        let quotas = TenantQuotas::from_bytes(bytes.slice(4..name_position - 1))?;
+        let name_length = bytes[name_position - 1] as usize;
+        if bytes.len() != name_position + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[name_position..])?.to_string();
+        let command = CreateTenant {
+            tenant_id,
+            name,
+            quotas,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for CreateTenant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.tenant_id.unwrap_or(0),
+            self.name,
+            self.quotas
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::byte_size::IggyByteSize;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = CreateTenant {
+            tenant_id: Some(1),
+            name: "team".to_string(),
+            quotas: TenantQuotas {
+                max_streams: Some(5),
+                max_size: Some(IggyByteSize::from(1_000_000)),
+                ..Default::default()
+            },
+        };
+
+        let bytes = command.as_bytes();
+        let tenant_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let quotas = TenantQuotas::from_bytes(bytes.slice(4..24)).unwrap();
+        let name_length = bytes[24];
+        let name = from_utf8(&bytes[25..25 + name_length as usize]).unwrap();
+
+        assert_eq!(tenant_id, command.tenant_id.unwrap());
+        assert_eq!(quotas, command.quotas);
+        assert_eq!(name, command.name);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let name = "team";
+        let quotas = TenantQuotas {
+            max_topics: Some(10),
+            ..Default::default()
+        };
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(0);
+        bytes.put_slice(&quotas.as_bytes());
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+
+        let command = CreateTenant::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert!(command.tenant_id.is_none());
+        assert_eq!(command.name, name);
+        assert_eq!(command.quotas, quotas);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_name_with_separator() {
+        let command = CreateTenant {
+            name: "team.a".to_string(),
+            ..Default::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
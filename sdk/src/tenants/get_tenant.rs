@@ -0,0 +1,78 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetTenant` command is used to retrieve the information about a tenant by unique ID.
+/// It has additional payload:
+/// - `tenant_id` - unique tenant ID (numeric or name).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct GetTenant {
+    /// Unique tenant ID (numeric or name).
+    #[serde(skip)]
+    pub tenant_id: Identifier,
+}
+
+impl CommandPayload for GetTenant {}
+
+impl Validatable<IggyError> for GetTenant {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetTenant {
+    fn as_bytes(&self) -> Bytes {
+        self.tenant_id.as_bytes()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<GetTenant, IggyError> {
+        if bytes.len() < 3 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let tenant_id = Identifier::from_bytes(bytes)?;
+        let command = GetTenant { tenant_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetTenant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tenant_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetTenant {
+            tenant_id: Identifier::numeric(1).unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let tenant_id = Identifier::from_bytes(bytes.clone()).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(tenant_id, command.tenant_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let tenant_id = Identifier::named("team").unwrap();
+        let bytes = tenant_id.as_bytes();
+        let command = GetTenant::from_bytes(bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.tenant_id, tenant_id);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetTenants` command is used to retrieve the information about all tenants.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct GetTenants {}
+
+impl CommandPayload for GetTenants {}
+
+impl Validatable<IggyError> for GetTenants {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetTenants {
+    fn as_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<GetTenants, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let command = GetTenants {};
+        command.validate()?;
+        Ok(GetTenants {})
+    }
+}
+
+impl Display for GetTenants {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = GetTenants {};
+        let bytes = command.as_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let command = GetTenants::from_bytes(Bytes::new());
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_empty_bytes() {
+        let command = GetTenants::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
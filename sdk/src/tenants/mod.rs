@@ -0,0 +1,31 @@
+pub mod add_tenant_user;
+pub mod create_tenant;
+pub mod delete_tenant;
+pub mod get_tenant;
+pub mod get_tenants;
+pub mod remove_tenant_user;
+pub mod update_tenant;
+
+use crate::error::IggyError;
+use crate::utils::text;
+
+const MAX_NAME_LENGTH: usize = 255;
+/// The separator between the tenant name and the name of the stream owned by the tenant.
+pub const TENANT_NAME_SEPARATOR: char = '.';
+
+/// Validates the tenant name, which can't contain the separator used to scope the streams names,
+/// nor the whitespaces, as these are replaced with the same separator when the name is normalized.
+pub(crate) fn validate_name(name: &str) -> Result<(), IggyError> {
+    if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+        return Err(IggyError::InvalidTenantName);
+    }
+
+    if !text::is_resource_name_valid(name)
+        || name.contains(TENANT_NAME_SEPARATOR)
+        || name.contains(char::is_whitespace)
+    {
+        return Err(IggyError::InvalidTenantName);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `RemoveTenantUser` command is used to unassign the user from the tenant.
+/// The user is no longer restricted to the streams owned by the tenant, and its access is defined only by its permissions.
+/// It has additional payload:
+/// - `tenant_id` - unique tenant ID (numeric or name).
+/// - `user_id` - unique user ID (numeric or name).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct RemoveTenantUser {
+    /// Unique tenant ID (numeric or name).
+    #[serde(skip)]
+    pub tenant_id: Identifier,
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+}
+
+impl CommandPayload for RemoveTenantUser {}
+
+impl Validatable<IggyError> for RemoveTenantUser {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RemoveTenantUser {
+    fn as_bytes(&self) -> Bytes {
+        let tenant_id_bytes = self.tenant_id.as_bytes();
+        let user_id_bytes = self.user_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(tenant_id_bytes.len() + user_id_bytes.len());
+        bytes.put_slice(&tenant_id_bytes);
+        bytes.put_slice(&user_id_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<RemoveTenantUser, IggyError> {
+        if bytes.len() < 6 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let tenant_id = Identifier::from_bytes(bytes.clone())?;
+        let position = tenant_id.get_size_bytes() as usize;
+        let user_id = Identifier::from_bytes(bytes.slice(position..))?;
+        let command = RemoveTenantUser { tenant_id, user_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for RemoveTenantUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.tenant_id, self.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RemoveTenantUser {
+            tenant_id: Identifier::numeric(1).unwrap(),
+            user_id: Identifier::named("user").unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let tenant_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        let position = tenant_id.get_size_bytes() as usize;
+        let user_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+
+        assert_eq!(tenant_id, command.tenant_id);
+        assert_eq!(user_id, command.user_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let tenant_id = Identifier::named("team").unwrap();
+        let user_id = Identifier::numeric(2).unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&tenant_id.as_bytes());
+        bytes.put_slice(&user_id.as_bytes());
+
+        let command = RemoveTenantUser::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.tenant_id, tenant_id);
+        assert_eq!(command.user_id, user_id);
+    }
+}
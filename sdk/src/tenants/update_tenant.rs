@@ -0,0 +1,104 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::tenant::TenantQuotas;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateTenant` command is used to update the quotas of an existing tenant.
+/// The quotas lower than the current usage don't affect the existing resources, but prevent creating the new ones.
+/// It has additional payload:
+/// - `tenant_id` - unique tenant ID (numeric or name).
+/// - `quotas` - the limits of the resources the tenant can use.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateTenant {
+    /// Unique tenant ID (numeric or name).
+    #[serde(skip)]
+    pub tenant_id: Identifier,
+    /// The limits of the resources the tenant can use.
+    #[serde(default)]
+    pub quotas: TenantQuotas,
+}
+
+impl CommandPayload for UpdateTenant {}
+
+impl Validatable<IggyError> for UpdateTenant {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateTenant {
+    fn as_bytes(&self) -> Bytes {
+        let tenant_id_bytes = self.tenant_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(tenant_id_bytes.len() + TenantQuotas::SIZE_BYTES);
+        bytes.put_slice(&tenant_id_bytes);
+        bytes.put_slice(&self.quotas.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<UpdateTenant, IggyError> {
+        if bytes.len() < 3 + TenantQuotas::SIZE_BYTES {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let tenant_id = Identifier::from_bytes(bytes.clone())?;
+        let position = tenant_id.get_size_bytes() as usize;
+        let quotas = TenantQuotas::from_bytes(bytes.slice(position..))?;
+        let command = UpdateTenant { tenant_id, quotas };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateTenant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.tenant_id, self.quotas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateTenant {
+            tenant_id: Identifier::numeric(1).unwrap(),
+            quotas: TenantQuotas {
+                max_partitions: Some(10),
+                ..Default::default()
+            },
+        };
+
+        let bytes = command.as_bytes();
+        let tenant_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        let position = tenant_id.get_size_bytes() as usize;
+        let quotas = TenantQuotas::from_bytes(bytes.slice(position..)).unwrap();
+
+        assert_eq!(tenant_id, command.tenant_id);
+        assert_eq!(quotas, command.quotas);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let tenant_id = Identifier::named("team").unwrap();
+        let quotas = TenantQuotas {
+            max_streams: Some(3),
+            ..Default::default()
+        };
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&tenant_id.as_bytes());
+        bytes.put_slice(&quotas.as_bytes());
+
+        let command = UpdateTenant::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.tenant_id, tenant_id);
+        assert_eq!(command.quotas, quotas);
+    }
+}
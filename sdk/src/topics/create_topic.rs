@@ -2,7 +2,13 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
-use crate::topics::{MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT};
+use crate::models::consumer_limits::ConsumerLimits;
+use crate::models::timestamp_type::TimestampType;
+use crate::topics::{
+    put_consumer_limits, put_max_message_size, put_message_schema, read_consumer_limits,
+    read_max_message_size, read_message_schema, read_timestamp_type, validate_message_schema,
+    CONSUMER_LIMITS_SIZE, MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT,
+};
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::text;
 use crate::validatable::Validatable;
@@ -16,11 +22,16 @@ use std::str::from_utf8;
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric).
 /// - `partitions_count` - number of partitions in the topic, max value is 1000.
-/// - `message_expiry` - optional message expiry in seconds, if `None` then messages will never expire.
-/// - `max_topic_size` - optional maximum size of the topic, if `None` then topic size is unlimited.
+/// - `message_expiry` - optional message expiry in seconds, if `None` then the stream default is used, or messages will never expire.
+/// - `max_topic_size` - optional maximum size of the topic, if `None` then the stream default is used, or topic size is unlimited.
 ///                      Can't be lower than segment size in the config.
-/// - `replication_factor` - replication factor for the topic.
+/// - `replication_factor` - replication factor for the topic, if 0 then the stream default is used, or 1.
 /// - `name` - unique topic name, max length is 255 characters. The name will be always converted to lowercase and all whitespaces will be replaced with dots.
+/// - `message_schema` - optional JSON Schema, if set then the payloads of the messages sent to the topic must conform to it.
+/// - `ephemeral` - if `true` then the partitions and messages of the topic are kept in memory only and lost once the server stops.
+/// - `timestamp_type` - whether the messages are stored with the time they were appended by the server or created by the producer.
+/// - `consumer_limits` - the optional maximum number of consumer groups in the topic and members in each of them, unlimited if not set.
+/// - `max_message_size` - optional maximum size of the message payload, if `None` then the server default is used.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateTopic {
     /// Unique stream ID (numeric or name).
@@ -30,14 +41,29 @@ pub struct CreateTopic {
     pub topic_id: Option<u32>,
     /// Number of partitions in the topic, max value is 1000.
     pub partitions_count: u32,
-    /// Optional message expiry in seconds, if `None` then messages will never expire.
+    /// Optional message expiry in seconds, if `None` then the stream default is used, or messages will never expire.
     pub message_expiry: Option<u32>,
-    /// The optional maximum size of the topic.
+    /// The optional maximum size of the topic, if `None` then the stream default is used, or topic size is unlimited.
     pub max_topic_size: Option<IggyByteSize>,
-    /// Replication factor for the topic.
+    /// Replication factor for the topic, if 0 then the stream default is used, or 1.
     pub replication_factor: u8,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Optional JSON Schema, which the payloads of the messages sent to the topic must conform to.
+    #[serde(default)]
+    pub message_schema: Option<String>,
+    /// Whether the partitions and messages of the topic are kept in memory only, regardless of the server storage backend.
+    #[serde(default)]
+    pub ephemeral: bool,
+    /// The timestamp stored with the messages, the append time by default.
+    #[serde(default)]
+    pub timestamp_type: TimestampType,
+    /// The caps on the consumer groups in the topic and their members, unlimited by default.
+    #[serde(default)]
+    pub consumer_limits: ConsumerLimits,
+    /// The optional maximum size of the message payload, if `None` then the server default is used.
+    #[serde(default)]
+    pub max_message_size: Option<IggyByteSize>,
 }
 
 impl CommandPayload for CreateTopic {}
@@ -52,6 +78,11 @@ impl Default for CreateTopic {
             max_topic_size: None,
             replication_factor: 1,
             name: "topic".to_string(),
+            message_schema: None,
+            ephemeral: false,
+            timestamp_type: TimestampType::default(),
+            consumer_limits: ConsumerLimits::default(),
+            max_message_size: None,
         }
     }
 }
@@ -76,9 +107,8 @@ impl Validatable<IggyError> for CreateTopic {
             return Err(IggyError::TooManyPartitions);
         }
 
-        if self.replication_factor == 0 {
-            return Err(IggyError::InvalidReplicationFactor);
-        }
+        validate_message_schema(&self.message_schema)?;
+        self.consumer_limits.validate()?;
 
         Ok(())
     }
@@ -87,7 +117,16 @@ impl Validatable<IggyError> for CreateTopic {
 impl BytesSerializable for CreateTopic {
     fn as_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.as_bytes();
-        let mut bytes = BytesMut::with_capacity(22 + stream_id_bytes.len() + self.name.len());
+        let mut bytes = BytesMut::with_capacity(
+            28 + CONSUMER_LIMITS_SIZE
+                + 8
+                + stream_id_bytes.len()
+                + self.name.len()
+                + self
+                    .message_schema
+                    .as_ref()
+                    .map_or(0, |schema| schema.len()),
+        );
         bytes.put_slice(&stream_id_bytes);
         bytes.put_u32_le(self.topic_id.unwrap_or(0));
         bytes.put_u32_le(self.partitions_count);
@@ -103,6 +142,11 @@ impl BytesSerializable for CreateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        put_message_schema(&mut bytes, &self.message_schema);
+        bytes.put_u8(if self.ephemeral { 1 } else { 0 });
+        bytes.put_u8(self.timestamp_type.as_code());
+        put_consumer_limits(&mut bytes, &self.consumer_limits);
+        put_max_message_size(&mut bytes, self.max_message_size);
         bytes.freeze()
     }
 
@@ -133,6 +177,16 @@ impl BytesSerializable for CreateTopic {
         if name.len() != name_length as usize {
             return Err(IggyError::InvalidCommand);
         }
+        let message_schema_position = position + 22 + name_length as usize;
+        let message_schema = read_message_schema(&bytes, message_schema_position)?;
+        // The flag is missing when sent by the older client, so the topic isn't ephemeral.
+        let ephemeral_position =
+            message_schema_position + 4 + message_schema.as_ref().map_or(0, |schema| schema.len());
+        let ephemeral = bytes.get(ephemeral_position).is_some_and(|flag| *flag == 1);
+        let timestamp_type = read_timestamp_type(&bytes, ephemeral_position + 1)?;
+        let consumer_limits = read_consumer_limits(&bytes, ephemeral_position + 2)?;
+        let max_message_size =
+            read_max_message_size(&bytes, ephemeral_position + 2 + CONSUMER_LIMITS_SIZE)?;
         let command = CreateTopic {
             stream_id,
             topic_id,
@@ -141,6 +195,11 @@ impl BytesSerializable for CreateTopic {
             max_topic_size,
             replication_factor,
             name,
+            message_schema,
+            ephemeral,
+            timestamp_type,
+            consumer_limits,
+            max_message_size,
         };
         command.validate()?;
         Ok(command)
@@ -153,16 +212,23 @@ impl Display for CreateTopic {
             Some(max_topic_size) => max_topic_size.to_string(),
             None => "unlimited".to_string(),
         };
+        let max_message_size = match self.max_message_size {
+            Some(max_message_size) => max_message_size.to_string(),
+            None => "default".to_string(),
+        };
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id.unwrap_or(0),
             self.partitions_count,
             self.message_expiry.unwrap_or(0),
             max_topic_size,
             self.replication_factor,
-            self.name
+            self.name,
+            self.timestamp_type,
+            self.consumer_limits,
+            max_message_size
         )
     }
 }
@@ -182,6 +248,11 @@ mod tests {
             max_topic_size: Some(IggyByteSize::from(100)),
             replication_factor: 1,
             name: "test".to_string(),
+            message_schema: None,
+            ephemeral: false,
+            timestamp_type: TimestampType::CreateTime,
+            consumer_limits: ConsumerLimits::default(),
+            max_message_size: Some(IggyByteSize::from(1000)),
         };
         let bytes = command.as_bytes();
         let mut position = 0;
@@ -217,6 +288,105 @@ mod tests {
         assert_eq!(name, command.name);
     }
 
+    #[test]
+    fn should_be_serialized_and_deserialized_with_message_schema() {
+        let command = CreateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            message_schema: Some(r#"{"type": "object"}"#.to_string()),
+            ..CreateTopic::default()
+        };
+
+        let deserialized_command = CreateTopic::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_as_ephemeral() {
+        let command = CreateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            message_schema: Some(r#"{"type": "object"}"#.to_string()),
+            ephemeral: true,
+            ..CreateTopic::default()
+        };
+
+        let deserialized_command = CreateTopic::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_create_time_timestamp_type() {
+        let command = CreateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            timestamp_type: TimestampType::CreateTime,
+            ..CreateTopic::default()
+        };
+
+        let deserialized_command = CreateTopic::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_max_message_size() {
+        let command = CreateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            max_message_size: Some(IggyByteSize::from(1024)),
+            ..CreateTopic::default()
+        };
+
+        let deserialized_command = CreateTopic::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_consumer_limits() {
+        let command = CreateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            message_schema: Some(r#"{"type": "object"}"#.to_string()),
+            consumer_limits: ConsumerLimits {
+                max_consumer_groups: Some(2),
+                max_group_members: Some(5),
+            },
+            ..CreateTopic::default()
+        };
+
+        let deserialized_command = CreateTopic::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_zero_consumer_limit() {
+        let command = CreateTopic {
+            consumer_limits: ConsumerLimits {
+                max_consumer_groups: Some(0),
+                max_group_members: None,
+            },
+            ..CreateTopic::default()
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidConsumerLimits)
+        ));
+    }
+
+    #[test]
+    fn should_not_be_valid_given_message_schema_not_being_json_object() {
+        let command = CreateTopic {
+            message_schema: Some("[1, 2]".to_string()),
+            ..CreateTopic::default()
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidMessageSchema(_))
+        ));
+    }
+
     #[test]
     fn should_be_deserialized_from_bytes() {
         let stream_id = Identifier::numeric(1).unwrap();
@@ -250,5 +420,9 @@ mod tests {
         assert_eq!(command.max_topic_size, Some(max_topic_size));
         assert_eq!(command.replication_factor, replication_factor);
         assert_eq!(command.partitions_count, partitions_count);
+        assert!(!command.ephemeral);
+        assert_eq!(command.timestamp_type, TimestampType::LogAppendTime);
+        assert_eq!(command.consumer_limits, ConsumerLimits::default());
+        assert_eq!(command.max_message_size, None);
     }
 }
@@ -11,6 +11,7 @@ use std::fmt::Display;
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
+/// - `soft` - whether the topic is moved to the trash, from which it can be restored until the retention period expires.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct DeleteTopic {
     /// Unique stream ID (numeric or name).
@@ -19,6 +20,9 @@ pub struct DeleteTopic {
     /// Unique topic ID (numeric or name).
     #[serde(skip)]
     pub topic_id: Identifier,
+    /// Whether the topic is moved to the trash instead of being deleted right away.
+    #[serde(default)]
+    pub soft: bool,
 }
 
 impl CommandPayload for DeleteTopic {}
@@ -33,9 +37,10 @@ impl BytesSerializable for DeleteTopic {
     fn as_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
-        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + topic_id_bytes.len());
+        let mut bytes = BytesMut::with_capacity(1 + stream_id_bytes.len() + topic_id_bytes.len());
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.soft as u8);
         bytes.freeze()
     }
 
@@ -48,9 +53,12 @@ impl BytesSerializable for DeleteTopic {
         let stream_id = Identifier::from_bytes(bytes.clone())?;
         position += stream_id.get_size_bytes() as usize;
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes() as usize;
+        let soft = bytes.get(position) == Some(&1);
         let command = DeleteTopic {
             stream_id,
             topic_id,
+            soft,
         };
         command.validate()?;
         Ok(command)
@@ -59,7 +67,7 @@ impl BytesSerializable for DeleteTopic {
 
 impl Display for DeleteTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id, self.topic_id)
+        write!(f, "{}|{}|{}", self.stream_id, self.topic_id, self.soft)
     }
 }
 
@@ -74,6 +82,7 @@ mod tests {
         let command = DeleteTopic {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
+            soft: true,
         };
 
         let bytes = command.as_bytes();
@@ -81,10 +90,13 @@ mod tests {
         let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
         position += stream_id.get_size_bytes() as usize;
         let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let soft = bytes[position];
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
+        assert_eq!(soft, 1);
     }
 
     #[test]
@@ -100,5 +112,6 @@ mod tests {
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
+        assert!(!command.soft);
     }
 }
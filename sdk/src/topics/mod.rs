@@ -1,9 +1,142 @@
 pub mod create_topic;
 pub mod delete_topic;
+pub mod freeze_topic;
 pub mod get_topic;
 pub mod get_topics;
 pub mod purge_topic;
+pub mod undelete_topic;
+pub mod unfreeze_topic;
 pub mod update_topic;
 
+use crate::error::IggyError;
+use crate::models::consumer_limits::ConsumerLimits;
+use crate::models::timestamp_type::TimestampType;
+use crate::utils::byte_size::IggyByteSize;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::str::from_utf8;
+
 const MAX_NAME_LENGTH: usize = 255;
 const MAX_PARTITIONS_COUNT: u32 = 1000;
+/// The maximum length of the JSON Schema for the messages sent to the topic.
+pub const MAX_MESSAGE_SCHEMA_LENGTH: usize = 65_536;
+
+/// Validates the optional message schema, which must be a JSON object (or boolean) not longer than `MAX_MESSAGE_SCHEMA_LENGTH`.
+/// Only the JSON syntax is checked here, the schema itself is compiled by the server.
+pub(crate) fn validate_message_schema(message_schema: &Option<String>) -> Result<(), IggyError> {
+    let Some(message_schema) = message_schema else {
+        return Ok(());
+    };
+
+    if message_schema.is_empty() || message_schema.len() > MAX_MESSAGE_SCHEMA_LENGTH {
+        return Err(IggyError::InvalidMessageSchema(format!(
+            "schema length must be between 1 and {MAX_MESSAGE_SCHEMA_LENGTH} bytes"
+        )));
+    }
+
+    match serde_json::from_str::<serde_json::Value>(message_schema) {
+        Ok(serde_json::Value::Object(_)) | Ok(serde_json::Value::Bool(_)) => Ok(()),
+        Ok(_) => Err(IggyError::InvalidMessageSchema(
+            "schema must be a JSON object or boolean".to_string(),
+        )),
+        Err(error) => Err(IggyError::InvalidMessageSchema(error.to_string())),
+    }
+}
+
+/// Writes the optional message schema as its length (0 if `None`) followed by the schema bytes.
+pub(crate) fn put_message_schema(bytes: &mut BytesMut, message_schema: &Option<String>) {
+    match message_schema {
+        Some(message_schema) => {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(message_schema.len() as u32);
+            bytes.put_slice(message_schema.as_bytes());
+        }
+        None => bytes.put_u32_le(0),
+    }
+}
+
+/// Reads the optional message schema at the given position, the missing schema (e.g. sent by the older client) is treated as `None`.
+pub(crate) fn read_message_schema(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<Option<String>, IggyError> {
+    if bytes.len() < position + 4 {
+        return Ok(None);
+    }
+
+    let length = u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+    if length == 0 {
+        return Ok(None);
+    }
+
+    let position = position + 4;
+    if bytes.len() < position + length {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(Some(
+        from_utf8(&bytes[position..position + length])?.to_string(),
+    ))
+}
+
+/// Reads the timestamp type at the given position, the missing one (e.g. sent by the older client) is treated as the default.
+pub(crate) fn read_timestamp_type(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<TimestampType, IggyError> {
+    bytes
+        .get(position)
+        .map_or(Ok(TimestampType::default()), |code| {
+            TimestampType::from_code(*code)
+        })
+}
+
+/// The size of the consumer limits in the binary format:
+/// max consumer groups (4) and max group members (4), where 0 means unlimited.
+pub(crate) const CONSUMER_LIMITS_SIZE: usize = 8;
+
+pub(crate) fn put_consumer_limits(bytes: &mut BytesMut, consumer_limits: &ConsumerLimits) {
+    bytes.put_u32_le(consumer_limits.max_consumer_groups.unwrap_or(0));
+    bytes.put_u32_le(consumer_limits.max_group_members.unwrap_or(0));
+}
+
+/// Reads the consumer limits at the given position, the missing ones (e.g. sent by the older client or server) are treated as unlimited.
+pub(crate) fn read_consumer_limits(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<ConsumerLimits, IggyError> {
+    let Some(bytes) = bytes.get(position..position + CONSUMER_LIMITS_SIZE) else {
+        return Ok(ConsumerLimits::default());
+    };
+
+    let max_consumer_groups = match u32::from_le_bytes(bytes[..4].try_into()?) {
+        0 => None,
+        max_consumer_groups => Some(max_consumer_groups),
+    };
+    let max_group_members = match u32::from_le_bytes(bytes[4..8].try_into()?) {
+        0 => None,
+        max_group_members => Some(max_group_members),
+    };
+    Ok(ConsumerLimits {
+        max_consumer_groups,
+        max_group_members,
+    })
+}
+
+pub(crate) fn put_max_message_size(bytes: &mut BytesMut, max_message_size: Option<IggyByteSize>) {
+    bytes.put_u64_le(max_message_size.map_or(0, |size| size.as_bytes_u64()));
+}
+
+/// Reads the max message size at the given position, the missing one (e.g. sent by the older client or server) means the server default.
+pub(crate) fn read_max_message_size(
+    bytes: &Bytes,
+    position: usize,
+) -> Result<Option<IggyByteSize>, IggyError> {
+    let Some(bytes) = bytes.get(position..position + 8) else {
+        return Ok(None);
+    };
+
+    match u64::from_le_bytes(bytes.try_into()?) {
+        0 => Ok(None),
+        size => Ok(Some(IggyByteSize::from(size))),
+    }
+}
@@ -0,0 +1,97 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UndeleteTopic` command is used to restore the topic moved to the trash by the soft delete.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name), the stream must exist.
+/// - `topic_id` - unique ID (numeric or name) of the topic in the trash.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UndeleteTopic {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique ID (numeric or name) of the topic in the trash.
+    #[serde(skip)]
+    pub topic_id: Identifier,
+}
+
+impl CommandPayload for UndeleteTopic {}
+
+impl Validatable<IggyError> for UndeleteTopic {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UndeleteTopic {
+    fn as_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UndeleteTopic, IggyError> {
+        if bytes.len() < 6 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        let position = stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        let command = UndeleteTopic {
+            stream_id,
+            topic_id,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UndeleteTopic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.stream_id, self.topic_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UndeleteTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("events").unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        let position = stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put(stream_id.as_bytes());
+        bytes.put(topic_id.as_bytes());
+        let command = UndeleteTopic::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+    }
+}
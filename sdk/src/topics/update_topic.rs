@@ -2,7 +2,13 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
-use crate::topics::MAX_NAME_LENGTH;
+use crate::models::consumer_limits::ConsumerLimits;
+use crate::models::timestamp_type::TimestampType;
+use crate::topics::{
+    put_consumer_limits, put_max_message_size, put_message_schema, read_consumer_limits,
+    read_max_message_size, read_message_schema, read_timestamp_type, validate_message_schema,
+    CONSUMER_LIMITS_SIZE, MAX_NAME_LENGTH,
+};
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::text;
 use crate::validatable::Validatable;
@@ -20,6 +26,10 @@ use std::str::from_utf8;
 ///                      Can't be lower than segment size in the config.
 /// - `replication_factor` - replication factor for the topic.
 /// - `name` - unique topic name, max length is 255 characters.
+/// - `message_schema` - optional JSON Schema, if `None` then the messages sent to the topic are not validated.
+/// - `timestamp_type` - whether the messages are stored with the time they were appended by the server or created by the producer.
+/// - `consumer_limits` - the optional maximum number of consumer groups in the topic and members in each of them, unlimited if not set.
+/// - `max_message_size` - optional maximum size of the message payload, if `None` then the server default is used.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct UpdateTopic {
     /// Unique stream ID (numeric or name).
@@ -37,6 +47,18 @@ pub struct UpdateTopic {
     pub replication_factor: u8,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
+    /// Optional JSON Schema, which the payloads of the messages sent to the topic must conform to.
+    #[serde(default)]
+    pub message_schema: Option<String>,
+    /// The timestamp stored with the messages appended from now on, the append time by default.
+    #[serde(default)]
+    pub timestamp_type: TimestampType,
+    /// The caps on the consumer groups in the topic and their members, unlimited by default.
+    #[serde(default)]
+    pub consumer_limits: ConsumerLimits,
+    /// The optional maximum size of the message payload, if `None` then the server default is used.
+    #[serde(default)]
+    pub max_message_size: Option<IggyByteSize>,
 }
 
 impl CommandPayload for UpdateTopic {}
@@ -50,6 +72,10 @@ impl Default for UpdateTopic {
             max_topic_size: None,
             replication_factor: 1,
             name: "topic".to_string(),
+            message_schema: None,
+            timestamp_type: TimestampType::default(),
+            consumer_limits: ConsumerLimits::default(),
+            max_message_size: None,
         }
     }
 }
@@ -68,6 +94,9 @@ impl Validatable<IggyError> for UpdateTopic {
             return Err(IggyError::InvalidReplicationFactor);
         }
 
+        validate_message_schema(&self.message_schema)?;
+        self.consumer_limits.validate()?;
+
         Ok(())
     }
 }
@@ -77,7 +106,15 @@ impl BytesSerializable for UpdateTopic {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes = BytesMut::with_capacity(
-            13 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
+            18 + CONSUMER_LIMITS_SIZE
+                + 8
+                + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + self.name.len()
+                + self
+                    .message_schema
+                    .as_ref()
+                    .map_or(0, |schema| schema.len()),
         );
         bytes.put_slice(&stream_id_bytes.clone());
         bytes.put_slice(&topic_id_bytes.clone());
@@ -93,6 +130,10 @@ impl BytesSerializable for UpdateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        put_message_schema(&mut bytes, &self.message_schema);
+        bytes.put_u8(self.timestamp_type.as_code());
+        put_consumer_limits(&mut bytes, &self.consumer_limits);
+        put_max_message_size(&mut bytes, self.max_message_size);
         bytes.freeze()
     }
 
@@ -122,6 +163,14 @@ impl BytesSerializable for UpdateTopic {
         if name.len() != name_length as usize {
             return Err(IggyError::InvalidCommand);
         }
+        let message_schema_position = position + 14 + name_length as usize;
+        let message_schema = read_message_schema(&bytes, message_schema_position)?;
+        let timestamp_type_position =
+            message_schema_position + 4 + message_schema.as_ref().map_or(0, |schema| schema.len());
+        let timestamp_type = read_timestamp_type(&bytes, timestamp_type_position)?;
+        let consumer_limits = read_consumer_limits(&bytes, timestamp_type_position + 1)?;
+        let max_message_size =
+            read_max_message_size(&bytes, timestamp_type_position + 1 + CONSUMER_LIMITS_SIZE)?;
         let command = UpdateTopic {
             stream_id,
             topic_id,
@@ -129,6 +178,10 @@ impl BytesSerializable for UpdateTopic {
             max_topic_size,
             replication_factor,
             name,
+            message_schema,
+            timestamp_type,
+            consumer_limits,
+            max_message_size,
         };
         command.validate()?;
         Ok(command)
@@ -141,15 +194,22 @@ impl Display for UpdateTopic {
             Some(max_topic_size) => max_topic_size.to_string(),
             None => String::from("unlimited"),
         };
+        let max_message_size = match self.max_message_size {
+            Some(max_message_size) => max_message_size.to_string(),
+            None => String::from("default"),
+        };
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.message_expiry.unwrap_or(0),
             max_topic_size,
             self.replication_factor,
             self.name,
+            self.timestamp_type,
+            self.consumer_limits,
+            max_message_size
         )
     }
 }
@@ -168,6 +228,10 @@ mod tests {
             max_topic_size: Some(IggyByteSize::from(100)),
             replication_factor: 1,
             name: "test".to_string(),
+            message_schema: None,
+            timestamp_type: TimestampType::CreateTime,
+            consumer_limits: ConsumerLimits::default(),
+            max_message_size: Some(IggyByteSize::from(1000)),
         };
 
         let bytes = command.as_bytes();
@@ -202,6 +266,51 @@ mod tests {
         assert_eq!(name, command.name);
     }
 
+    #[test]
+    fn should_be_serialized_and_deserialized_with_message_schema_and_timestamp_type() {
+        let command = UpdateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            message_schema: Some(r#"{"type": "object"}"#.to_string()),
+            timestamp_type: TimestampType::CreateTime,
+            ..UpdateTopic::default()
+        };
+
+        let deserialized_command = UpdateTopic::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_max_message_size() {
+        let command = UpdateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            max_message_size: Some(IggyByteSize::from(1024)),
+            ..UpdateTopic::default()
+        };
+
+        let deserialized_command = UpdateTopic::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_with_consumer_limits() {
+        let command = UpdateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            consumer_limits: ConsumerLimits {
+                max_consumer_groups: None,
+                max_group_members: Some(3),
+            },
+            ..UpdateTopic::default()
+        };
+
+        let deserialized_command = UpdateTopic::from_bytes(command.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_command, command);
+    }
+
     #[test]
     fn should_be_deserialized_from_bytes() {
         let stream_id = Identifier::numeric(1).unwrap();
@@ -234,5 +343,8 @@ mod tests {
         assert_eq!(command.message_expiry, Some(message_expiry));
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.timestamp_type, TimestampType::LogAppendTime);
+        assert_eq!(command.consumer_limits, ConsumerLimits::default());
+        assert_eq!(command.max_message_size, None);
     }
 }
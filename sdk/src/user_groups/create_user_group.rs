@@ -0,0 +1,203 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::models::permissions::Permissions;
+use crate::user_groups::validate_name;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `CreateUserGroup` command is used to create a new user group.
+/// It has additional payload:
+/// - `group_id` - unique user group ID (numeric), if None is provided then the server will automatically assign it.
+/// - `name` - unique user group name (string), max length is 255 characters. The name will be always converted to lowercase.
+/// - `permissions` - optional permissions granted to all the members of the group, in addition to their own ones.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateUserGroup {
+    /// Unique user group ID (numeric), if None is provided then the server will automatically assign it.
+    pub group_id: Option<u32>,
+    /// Unique user group name (string), max length is 255 characters.
+    pub name: String,
+    /// Optional permissions granted to all the members of the group, in addition to their own ones.
+    pub permissions: Option<Permissions>,
+}
+
+impl CommandPayload for CreateUserGroup {}
+
+impl Default for CreateUserGroup {
+    fn default() -> Self {
+        CreateUserGroup {
+            group_id: Some(1),
+            name: "group".to_string(),
+            permissions: None,
+        }
+    }
+}
+
+impl Validatable<IggyError> for CreateUserGroup {
+    fn validate(&self) -> Result<(), IggyError> {
+        if let Some(group_id) = self.group_id {
+            if group_id == 0 {
+                return Err(IggyError::InvalidUserGroupId);
+            }
+        }
+
+        validate_name(&self.name)
+    }
+}
+
+impl BytesSerializable for CreateUserGroup {
+    fn as_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(6 + self.name.len());
+        bytes.put_u32_le(self.group_id.unwrap_or(0));
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        if let Some(permissions) = &self.permissions {
+            let permissions = permissions.as_bytes();
+            bytes.put_u8(1);
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u32_le(permissions.len() as u32);
+            bytes.put_slice(&permissions);
+        } else {
+            bytes.put_u8(0);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<CreateUserGroup, IggyError> {
+        if bytes.len() < 7 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let group_id = match u32::from_le_bytes(bytes[..4].try_into()?) {
+            0 => None,
+            group_id => Some(group_id),
+        };
+        let name_length = bytes[4] as usize;
+        let mut position = 5 + name_length;
+        if bytes.len() < position + 1 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[5..position])?.to_string();
+        let has_permissions = bytes[position];
+        position += 1;
+        let permissions = match has_permissions {
+            0 => None,
+            1 => {
+                if bytes.len() < position + 4 {
+                    return Err(IggyError::InvalidCommand);
+                }
+
+                let permissions_length =
+                    u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+                position += 4;
+                if bytes.len() != position + permissions_length {
+                    return Err(IggyError::InvalidCommand);
+                }
+
+                Some(Permissions::from_bytes(bytes.slice(position..))?)
+            }
+            _ => return Err(IggyError::InvalidCommand),
+        };
+
+        let command = CreateUserGroup {
+            group_id,
+            name,
+            permissions,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for CreateUserGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let permissions = if let Some(permissions) = &self.permissions {
+            permissions.to_string()
+        } else {
+            "no_permissions".to_string()
+        };
+        write!(
+            f,
+            "{}|{}|{}",
+            self.group_id.unwrap_or(0),
+            self.name,
+            permissions
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::permissions::GlobalPermissions;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = CreateUserGroup {
+            group_id: Some(1),
+            name: "developers".to_string(),
+            permissions: Some(get_permissions()),
+        };
+
+        let bytes = command.as_bytes();
+        let group_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let name_length = bytes[4] as usize;
+        let name = from_utf8(&bytes[5..5 + name_length]).unwrap();
+        let position = 5 + name_length;
+        let has_permissions = bytes[position];
+        let permissions_length =
+            u32::from_le_bytes(bytes[position + 1..position + 5].try_into().unwrap()) as usize;
+        let permissions =
+            Permissions::from_bytes(bytes.slice(position + 5..position + 5 + permissions_length))
+                .unwrap();
+
+        assert_eq!(group_id, command.group_id.unwrap());
+        assert_eq!(name, command.name);
+        assert_eq!(has_permissions, 1);
+        assert_eq!(permissions, command.permissions.unwrap());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let name = "developers";
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(0);
+        bytes.put_u8(name.len() as u8);
+        bytes.put_slice(name.as_bytes());
+        bytes.put_u8(0);
+
+        let command = CreateUserGroup::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert!(command.group_id.is_none());
+        assert_eq!(command.name, name);
+        assert!(command.permissions.is_none());
+    }
+
+    #[test]
+    fn should_not_be_valid_given_empty_name() {
+        let command = CreateUserGroup {
+            name: "".to_string(),
+            ..Default::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+
+    fn get_permissions() -> Permissions {
+        Permissions {
+            global: GlobalPermissions {
+                read_streams: true,
+                poll_messages: true,
+                ..Default::default()
+            },
+            streams: None,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `DeleteUserGroup` command is used to delete an existing user group, its members lose the permissions granted by the group.
+/// It has additional payload:
+/// - `group_id` - unique user group ID (numeric or name).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct DeleteUserGroup {
+    /// Unique user group ID (numeric or name).
+    #[serde(skip)]
+    pub group_id: Identifier,
+}
+
+impl CommandPayload for DeleteUserGroup {}
+
+impl Validatable<IggyError> for DeleteUserGroup {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DeleteUserGroup {
+    fn as_bytes(&self) -> Bytes {
+        self.group_id.as_bytes()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<DeleteUserGroup, IggyError> {
+        if bytes.len() < 3 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let group_id = Identifier::from_bytes(bytes)?;
+        let command = DeleteUserGroup { group_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for DeleteUserGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.group_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = DeleteUserGroup {
+            group_id: Identifier::numeric(1).unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let group_id = Identifier::from_bytes(bytes.clone()).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(group_id, command.group_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let group_id = Identifier::named("developers").unwrap();
+        let bytes = group_id.as_bytes();
+        let command = DeleteUserGroup::from_bytes(bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.group_id, group_id);
+    }
+}
@@ -0,0 +1,24 @@
+pub mod add_user_group_member;
+pub mod create_user_group;
+pub mod delete_user_group;
+pub mod get_user_group;
+pub mod get_user_groups;
+pub mod remove_user_group_member;
+pub mod update_user_group_permissions;
+
+use crate::error::IggyError;
+use crate::utils::text;
+
+const MAX_NAME_LENGTH: usize = 255;
+
+pub(crate) fn validate_name(name: &str) -> Result<(), IggyError> {
+    if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+        return Err(IggyError::InvalidUserGroupName);
+    }
+
+    if !text::is_resource_name_valid(name) {
+        return Err(IggyError::InvalidUserGroupName);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `RemoveUserGroupMember` command is used to remove the user from the user group, so that it's no longer granted the permissions of the group.
+/// It has additional payload:
+/// - `group_id` - unique user group ID (numeric or name).
+/// - `user_id` - unique user ID (numeric or name).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct RemoveUserGroupMember {
+    /// Unique user group ID (numeric or name).
+    #[serde(skip)]
+    pub group_id: Identifier,
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+}
+
+impl CommandPayload for RemoveUserGroupMember {}
+
+impl Validatable<IggyError> for RemoveUserGroupMember {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RemoveUserGroupMember {
+    fn as_bytes(&self) -> Bytes {
+        let group_id_bytes = self.group_id.as_bytes();
+        let user_id_bytes = self.user_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(group_id_bytes.len() + user_id_bytes.len());
+        bytes.put_slice(&group_id_bytes);
+        bytes.put_slice(&user_id_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<RemoveUserGroupMember, IggyError> {
+        if bytes.len() < 6 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let group_id = Identifier::from_bytes(bytes.clone())?;
+        let position = group_id.get_size_bytes() as usize;
+        let user_id = Identifier::from_bytes(bytes.slice(position..))?;
+        let command = RemoveUserGroupMember { group_id, user_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for RemoveUserGroupMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.group_id, self.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RemoveUserGroupMember {
+            group_id: Identifier::numeric(1).unwrap(),
+            user_id: Identifier::named("user").unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let group_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        let position = group_id.get_size_bytes() as usize;
+        let user_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+
+        assert_eq!(group_id, command.group_id);
+        assert_eq!(user_id, command.user_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let group_id = Identifier::named("developers").unwrap();
+        let user_id = Identifier::numeric(2).unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&group_id.as_bytes());
+        bytes.put_slice(&user_id.as_bytes());
+
+        let command = RemoveUserGroupMember::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.group_id, group_id);
+        assert_eq!(command.user_id, user_id);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::permissions::Permissions;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateUserGroupPermissions` command is used to update the permissions granted to all the members of the user group.
+/// It has additional payload:
+/// - `group_id` - unique user group ID (numeric or name).
+/// - `permissions` - new permissions (optional)
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateUserGroupPermissions {
+    /// Unique user group ID (numeric or name).
+    #[serde(skip)]
+    pub group_id: Identifier,
+    /// New permissions if `None` is provided, then the existing group's permissions will be removed.
+    pub permissions: Option<Permissions>,
+}
+
+impl CommandPayload for UpdateUserGroupPermissions {}
+
+impl Validatable<IggyError> for UpdateUserGroupPermissions {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateUserGroupPermissions {
+    fn as_bytes(&self) -> Bytes {
+        let group_id_bytes = self.group_id.as_bytes();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&group_id_bytes);
+        if let Some(permissions) = &self.permissions {
+            bytes.put_u8(1);
+            bytes.put_u32_le(permissions.as_bytes().len() as u32);
+            bytes.put_slice(&permissions.as_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
+
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateUserGroupPermissions, IggyError> {
+        if bytes.len() < 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let group_id = Identifier::from_bytes(bytes.clone())?;
+        let mut position = group_id.get_size_bytes() as usize;
+        let has_permissions = bytes[position];
+        if has_permissions > 1 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        position += 1;
+        let permissions = if has_permissions == 1 {
+            let permissions_length =
+                u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+            position += 4;
+            let permissions = Permissions::from_bytes(
+                bytes.slice(position..position + permissions_length as usize),
+            )?;
+            Some(permissions)
+        } else {
+            None
+        };
+
+        let command = UpdateUserGroupPermissions {
+            group_id,
+            permissions,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateUserGroupPermissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let permissions = if let Some(permissions) = &self.permissions {
+            permissions.to_string()
+        } else {
+            "no_permissions".to_string()
+        };
+        write!(f, "{}|{}", self.group_id, permissions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::permissions::GlobalPermissions;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateUserGroupPermissions {
+            group_id: Identifier::numeric(1).unwrap(),
+            permissions: Some(get_permissions()),
+        };
+        let bytes = command.as_bytes();
+        let group_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        let mut position = group_id.get_size_bytes() as usize;
+        let has_permissions = bytes[position];
+        position += 1;
+        let permissions_length =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        position += 4;
+        let permissions =
+            Permissions::from_bytes(bytes.slice(position..position + permissions_length as usize))
+                .unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(group_id, command.group_id);
+        assert_eq!(has_permissions, 1);
+        assert_eq!(permissions, command.permissions.unwrap());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let group_id = Identifier::numeric(1).unwrap();
+        let permissions = get_permissions();
+        let permissions_bytes = permissions.as_bytes();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&group_id.as_bytes());
+        bytes.put_u8(1);
+        bytes.put_u32_le(permissions_bytes.len() as u32);
+        bytes.put_slice(&permissions_bytes);
+
+        let command = UpdateUserGroupPermissions::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.group_id, group_id);
+        assert_eq!(command.permissions.unwrap(), permissions);
+    }
+
+    fn get_permissions() -> Permissions {
+        Permissions {
+            global: GlobalPermissions {
+                manage_servers: true,
+                read_servers: true,
+                manage_users: true,
+                read_users: true,
+                manage_streams: false,
+                read_streams: true,
+                manage_topics: false,
+                read_topics: true,
+                poll_messages: true,
+                send_messages: false,
+                auto_create_topics: false,
+            },
+            streams: None,
+        }
+    }
+}
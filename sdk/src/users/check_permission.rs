@@ -0,0 +1,175 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::permission_check::PermissionAction;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `CheckPermission` command is used to check whether the action would be allowed for a user,
+/// and which permission granted or denied it, based on the user's effective permissions.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name).
+/// - `action` - the action to check.
+/// - `stream_id` - the optional stream ID, required by the stream and topic level actions.
+/// - `topic_id` - the optional topic ID, required by the topic level actions.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct CheckPermission {
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+    /// The action to check.
+    pub action: PermissionAction,
+    /// The optional stream ID, required by the stream and topic level actions.
+    pub stream_id: Option<u32>,
+    /// The optional topic ID, required by the topic level actions.
+    pub topic_id: Option<u32>,
+}
+
+impl CommandPayload for CheckPermission {}
+
+impl Validatable<IggyError> for CheckPermission {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.stream_id == Some(0) {
+            return Err(IggyError::InvalidStreamId);
+        }
+
+        if self.topic_id == Some(0) {
+            return Err(IggyError::InvalidTopicId);
+        }
+
+        if self.action.requires_stream() && self.stream_id.is_none() {
+            return Err(IggyError::PermissionActionResourceMissing(
+                self.action.to_string(),
+                "stream_id".to_string(),
+            ));
+        }
+
+        if self.action.requires_topic() && self.topic_id.is_none() {
+            return Err(IggyError::PermissionActionResourceMissing(
+                self.action.to_string(),
+                "topic_id".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for CheckPermission {
+    fn as_bytes(&self) -> Bytes {
+        let user_id_bytes = self.user_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(user_id_bytes.len() + 9);
+        bytes.put_slice(&user_id_bytes);
+        bytes.put_u8(self.action.as_code());
+        bytes.put_u32_le(self.stream_id.unwrap_or(0));
+        bytes.put_u32_le(self.topic_id.unwrap_or(0));
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<CheckPermission, IggyError> {
+        if bytes.len() < 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes.clone())?;
+        let position = user_id.get_size_bytes() as usize;
+        if bytes.len() != position + 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let action = PermissionAction::from_code(bytes[position])?;
+        let stream_id = u32::from_le_bytes(bytes[position + 1..position + 5].try_into()?);
+        let topic_id = u32::from_le_bytes(bytes[position + 5..position + 9].try_into()?);
+        let command = CheckPermission {
+            user_id,
+            action,
+            stream_id: if stream_id == 0 {
+                None
+            } else {
+                Some(stream_id)
+            },
+            topic_id: if topic_id == 0 { None } else { Some(topic_id) },
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for CheckPermission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.user_id,
+            self.action,
+            self.stream_id.unwrap_or(0),
+            self.topic_id.unwrap_or(0)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = CheckPermission {
+            user_id: Identifier::numeric(1).unwrap(),
+            action: PermissionAction::PollMessages,
+            stream_id: Some(2),
+            topic_id: Some(3),
+        };
+
+        let bytes = command.as_bytes();
+        let user_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        let position = user_id.get_size_bytes() as usize;
+        let action = PermissionAction::from_code(bytes[position]).unwrap();
+        let stream_id = u32::from_le_bytes(bytes[position + 1..position + 5].try_into().unwrap());
+        let topic_id = u32::from_le_bytes(bytes[position + 5..position + 9].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(user_id, command.user_id);
+        assert_eq!(action, command.action);
+        assert_eq!(stream_id, 2);
+        assert_eq!(topic_id, 3);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let user_id = Identifier::numeric(1).unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&user_id.as_bytes());
+        bytes.put_u8(PermissionAction::CreateTopic.as_code());
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(0);
+
+        let command = CheckPermission::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.user_id, user_id);
+        assert_eq!(command.action, PermissionAction::CreateTopic);
+        assert_eq!(command.stream_id, Some(2));
+        assert_eq!(command.topic_id, None);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_without_required_resource() {
+        let user_id = Identifier::numeric(1).unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&user_id.as_bytes());
+        bytes.put_u8(PermissionAction::PollMessages.as_code());
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(0);
+
+        let command = CheckPermission::from_bytes(bytes.freeze());
+        assert!(matches!(
+            command,
+            Err(IggyError::PermissionActionResourceMissing(_, _))
+        ));
+    }
+}
@@ -176,6 +176,7 @@ mod tests {
                     read_topics: true,
                     poll_messages: true,
                     send_messages: true,
+                    auto_create_topics: false,
                 },
                 streams: None,
             }),
@@ -227,6 +228,7 @@ mod tests {
                 read_topics: true,
                 poll_messages: true,
                 send_messages: true,
+                auto_create_topics: false,
             },
             streams: None,
         };
@@ -1,4 +1,5 @@
 pub mod change_password;
+pub mod check_permission;
 pub mod create_user;
 pub mod defaults;
 pub mod delete_user;
@@ -8,3 +9,4 @@ pub mod login_user;
 pub mod logout_user;
 pub mod update_permissions;
 pub mod update_user;
+pub mod update_user_expiry;
@@ -151,6 +151,7 @@ mod tests {
                 read_topics: true,
                 poll_messages: true,
                 send_messages: false,
+                auto_create_topics: false,
             },
             streams: None,
         }
@@ -0,0 +1,145 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateUserExpiry` command is used to set when the user is automatically disabled.
+/// Both settings are replaced, thus the one which is not provided is removed.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name).
+/// - `expires_at` - the optional timestamp (in microseconds) after which the user is disabled.
+/// - `max_inactive_days` - the optional number of days without any activity after which the user is disabled.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateUserExpiry {
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+    /// The optional timestamp (in microseconds) after which the user is disabled.
+    pub expires_at: Option<u64>,
+    /// The optional number of days without any login after which the user is disabled, must be greater than 0.
+    pub max_inactive_days: Option<u32>,
+}
+
+impl CommandPayload for UpdateUserExpiry {}
+
+impl Validatable<IggyError> for UpdateUserExpiry {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.max_inactive_days == Some(0) {
+            return Err(IggyError::InvalidUserMaxInactiveDays);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateUserExpiry {
+    fn as_bytes(&self) -> Bytes {
+        let user_id_bytes = self.user_id.as_bytes();
+        let mut bytes = BytesMut::with_capacity(user_id_bytes.len() + 12);
+        bytes.put_slice(&user_id_bytes);
+        bytes.put_u64_le(self.expires_at.unwrap_or(0));
+        bytes.put_u32_le(self.max_inactive_days.unwrap_or(0));
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateUserExpiry, IggyError> {
+        if bytes.len() < 15 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes.clone())?;
+        let position = user_id.get_size_bytes() as usize;
+        if bytes.len() != position + 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let expires_at = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+        let max_inactive_days = u32::from_le_bytes(bytes[position + 8..position + 12].try_into()?);
+        let command = UpdateUserExpiry {
+            user_id,
+            expires_at: match expires_at {
+                0 => None,
+                expires_at => Some(expires_at),
+            },
+            max_inactive_days: match max_inactive_days {
+                0 => None,
+                max_inactive_days => Some(max_inactive_days),
+            },
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateUserExpiry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.user_id,
+            self.expires_at.unwrap_or(0),
+            self.max_inactive_days.unwrap_or(0)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateUserExpiry {
+            user_id: Identifier::numeric(1).unwrap(),
+            expires_at: Some(1_700_000_000_000_000),
+            max_inactive_days: Some(90),
+        };
+
+        let bytes = command.as_bytes();
+        let user_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        let position = user_id.get_size_bytes() as usize;
+        let expires_at = u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+        let max_inactive_days =
+            u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(user_id, command.user_id);
+        assert_eq!(expires_at, 1_700_000_000_000_000);
+        assert_eq!(max_inactive_days, 90);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let user_id = Identifier::numeric(1).unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&user_id.as_bytes());
+        bytes.put_u64_le(0);
+        bytes.put_u32_le(30);
+
+        let command = UpdateUserExpiry::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.user_id, user_id);
+        assert_eq!(command.expires_at, None);
+        assert_eq!(command.max_inactive_days, Some(30));
+    }
+
+    #[test]
+    fn should_not_be_valid_given_zero_max_inactive_days() {
+        let command = UpdateUserExpiry {
+            user_id: Identifier::numeric(1).unwrap(),
+            expires_at: None,
+            max_inactive_days: Some(0),
+        };
+
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidUserMaxInactiveDays)
+        ));
+    }
+}
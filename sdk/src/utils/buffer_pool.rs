@@ -0,0 +1,186 @@
+use bytes::{Bytes, BytesMut};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// The maximum number of the buffers kept by the global pool.
+pub const MAX_POOLED_BUFFERS: usize = 32;
+/// The maximum capacity of the buffer kept by the global pool, the larger buffers are always allocated.
+pub const MAX_POOLED_BUFFER_SIZE: usize = 1024 * 1024;
+
+static GLOBAL: BufferPool = BufferPool::new(MAX_POOLED_BUFFERS, MAX_POOLED_BUFFER_SIZE);
+
+/// A pool of the reusable byte buffers, used to avoid the allocation churn when serializing the batches of messages
+/// on the hot paths (sending, appending and polling the messages).
+///
+/// The buffers frozen with `freeze()` are returned to the pool right away and their memory is reclaimed
+/// by the next `acquire()`, as soon as the previously returned `Bytes` have been dropped.
+/// If the `Bytes` are still in use (e.g. the payloads kept in the cache), the new memory is allocated instead.
+#[derive(Debug)]
+pub struct BufferPool {
+    buffers: Mutex<Vec<BytesMut>>,
+    max_buffers: usize,
+    max_buffer_size: usize,
+    acquired: AtomicU64,
+    reused: AtomicU64,
+    released: AtomicU64,
+    discarded: AtomicU64,
+}
+
+/// The statistics of the buffer pool, useful for benchmarking.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct BufferPoolStats {
+    /// The number of the acquired buffers.
+    pub acquired: u64,
+    /// The number of the acquired buffers which have been taken from the pool instead of being allocated.
+    pub reused: u64,
+    /// The number of the buffers returned to the pool.
+    pub released: u64,
+    /// The number of the buffers dropped instead of being returned to the pool, because the pool was full or the buffer was too large.
+    pub discarded: u64,
+}
+
+impl BufferPool {
+    /// Creates a new pool keeping at most `max_buffers` buffers of at most `max_buffer_size` bytes.
+    pub const fn new(max_buffers: usize, max_buffer_size: usize) -> Self {
+        Self {
+            buffers: Mutex::new(Vec::new()),
+            max_buffers,
+            max_buffer_size,
+            acquired: AtomicU64::new(0),
+            reused: AtomicU64::new(0),
+            released: AtomicU64::new(0),
+            discarded: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the global pool shared by the SDK and the server.
+    pub fn global() -> &'static BufferPool {
+        &GLOBAL
+    }
+
+    /// Returns an empty buffer with at least the provided capacity, taken from the pool if possible.
+    pub fn acquire(&self, capacity: usize) -> BytesMut {
+        self.acquired.fetch_add(1, Ordering::Relaxed);
+        if capacity > self.max_buffer_size {
+            return BytesMut::with_capacity(capacity);
+        }
+
+        let buffer = self.buffers.lock().unwrap().pop();
+        match buffer {
+            Some(mut buffer) => {
+                self.reused.fetch_add(1, Ordering::Relaxed);
+                buffer.clear();
+                buffer.reserve(capacity);
+                buffer
+            }
+            None => BytesMut::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the buffer to the pool, unless the pool is full or the buffer is too large.
+    pub fn release(&self, buffer: BytesMut) {
+        if buffer.capacity() > self.max_buffer_size {
+            self.discarded.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        let mut buffers = self.buffers.lock().unwrap();
+        if buffers.len() >= self.max_buffers {
+            self.discarded.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        buffers.push(buffer);
+        self.released.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Converts the content of the buffer into `Bytes` and returns the buffer to the pool,
+    /// so that its memory can be reused once the returned `Bytes` are dropped.
+    pub fn freeze(&self, mut buffer: BytesMut) -> Bytes {
+        let capacity = buffer.capacity();
+        let bytes = buffer.split().freeze();
+        if capacity > self.max_buffer_size {
+            self.discarded.fetch_add(1, Ordering::Relaxed);
+            return bytes;
+        }
+
+        self.release(buffer);
+        bytes
+    }
+
+    pub fn stats(&self) -> BufferPoolStats {
+        BufferPoolStats {
+            acquired: self.acquired.load(Ordering::Relaxed),
+            reused: self.reused.load(Ordering::Relaxed),
+            released: self.released.load(Ordering::Relaxed),
+            discarded: self.discarded.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Display for BufferPoolStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "acquired: {}, reused: {}, released: {}, discarded: {}",
+            self.acquired, self.reused, self.released, self.discarded
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::BufMut;
+
+    #[test]
+    fn released_buffer_should_be_reused() {
+        let pool = BufferPool::new(1, 1024);
+        let mut buffer = pool.acquire(100);
+        buffer.put_slice(&[1, 2, 3]);
+        pool.release(buffer);
+
+        let buffer = pool.acquire(200);
+        assert!(buffer.is_empty());
+        assert!(buffer.capacity() >= 200);
+        assert_eq!(
+            pool.stats(),
+            BufferPoolStats {
+                acquired: 2,
+                reused: 1,
+                released: 1,
+                discarded: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn frozen_buffer_memory_should_be_reclaimed_after_bytes_are_dropped() {
+        let pool = BufferPool::new(1, 1024);
+        let mut buffer = pool.acquire(512);
+        buffer.put_bytes(7, 512);
+        let bytes = pool.freeze(buffer);
+        assert_eq!(bytes.len(), 512);
+        let pointer = bytes.as_ptr();
+        drop(bytes);
+
+        let buffer = pool.acquire(512);
+        assert_eq!(buffer.as_ptr(), pointer);
+    }
+
+    #[test]
+    fn buffers_should_be_discarded_when_pool_is_full_or_buffer_is_too_large() {
+        let pool = BufferPool::new(1, 1024);
+        let large = pool.acquire(2048);
+        let first = pool.acquire(10);
+        let second = pool.acquire(10);
+        pool.release(large);
+        pool.release(first);
+        pool.release(second);
+
+        let stats = pool.stats();
+        assert_eq!(stats.released, 1);
+        assert_eq!(stats.discarded, 2);
+    }
+}
@@ -1,3 +1,125 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
 pub fn calculate(data: &[u8]) -> u32 {
     crc32fast::hash(data)
 }
+
+/// The policy describing what should happen when the message checksum doesn't match its payload.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChecksumPolicy {
+    /// The checksum is not validated.
+    #[default]
+    Off,
+    /// The checksum is validated and the mismatch is logged, but the message is still returned.
+    Log,
+    /// The checksum is validated and the mismatch results in an error.
+    Reject,
+}
+
+impl ChecksumPolicy {
+    /// Validates the checksum of the provided payload according to the policy.
+    /// Returns `Ok(true)` if the checksum is valid (or validation is disabled), `Ok(false)` if the mismatch was only logged.
+    pub fn validate(&self, payload: &[u8], checksum: u32, offset: u64) -> Result<bool, IggyError> {
+        if *self == ChecksumPolicy::Off {
+            return Ok(true);
+        }
+
+        let calculated_checksum = calculate(payload);
+        if calculated_checksum == checksum {
+            return Ok(true);
+        }
+
+        match self {
+            ChecksumPolicy::Reject => Err(IggyError::MessageChecksumMismatch(
+                offset,
+                calculated_checksum,
+                checksum,
+            )),
+            _ => {
+                tracing::warn!(
+                    "Message checksum mismatch for offset: {}, calculated: {}, expected: {}.",
+                    offset,
+                    calculated_checksum,
+                    checksum
+                );
+                Ok(false)
+            }
+        }
+    }
+}
+
+impl FromStr for ChecksumPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "off" => Ok(ChecksumPolicy::Off),
+            "log" => Ok(ChecksumPolicy::Log),
+            "reject" => Ok(ChecksumPolicy::Reject),
+            _ => Err(format!("Unknown checksum policy: {}", s)),
+        }
+    }
+}
+
+impl Display for ChecksumPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChecksumPolicy::Off => write!(f, "off"),
+            ChecksumPolicy::Log => write!(f, "log"),
+            ChecksumPolicy::Reject => write!(f, "reject"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_checksum_policy() {
+        assert_eq!(
+            ChecksumPolicy::from_str("off").unwrap(),
+            ChecksumPolicy::Off
+        );
+        assert_eq!(
+            ChecksumPolicy::from_str("Log").unwrap(),
+            ChecksumPolicy::Log
+        );
+        assert_eq!(
+            ChecksumPolicy::from_str("reject").unwrap(),
+            ChecksumPolicy::Reject
+        );
+        assert!(ChecksumPolicy::from_str("invalid").is_err());
+    }
+
+    #[test]
+    fn should_validate_checksum_according_to_policy() {
+        let payload = b"test";
+        let valid_checksum = calculate(payload);
+        let invalid_checksum = valid_checksum + 1;
+
+        assert!(ChecksumPolicy::Off
+            .validate(payload, invalid_checksum, 0)
+            .unwrap());
+        assert!(ChecksumPolicy::Log
+            .validate(payload, valid_checksum, 0)
+            .unwrap());
+        assert!(!ChecksumPolicy::Log
+            .validate(payload, invalid_checksum, 0)
+            .unwrap());
+        assert!(ChecksumPolicy::Reject
+            .validate(payload, valid_checksum, 0)
+            .unwrap());
+        let error = ChecksumPolicy::Reject
+            .validate(payload, invalid_checksum, 1)
+            .unwrap_err();
+        assert_eq!(
+            error.as_code(),
+            IggyError::MessageChecksumMismatch(1, valid_checksum, invalid_checksum).as_code()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod buffer_pool;
 pub mod byte_size;
 pub mod checksum;
 pub mod crypto;
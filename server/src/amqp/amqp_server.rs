@@ -0,0 +1,67 @@
+use crate::amqp::connection_handler::{handle_connection, handle_error, AmqpSettings};
+use crate::configs::amqp::AmqpConfig;
+use crate::streaming::systems::system::SharedSystem;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tracing::{error, info};
+
+/// Starts the AMQP 0.9.1 server, which appends the published messages to the topics and delivers the topics as the queues.
+/// Returns the address the server is listening on.
+pub async fn start(config: AmqpConfig, system: SharedSystem) -> SocketAddr {
+    info!("Initializing Iggy AMQP server...");
+    let settings = AmqpSettings {
+        frame_max: config.frame_max,
+        heartbeat: config.heartbeat.as_secs() as u16,
+        poll_interval: config.poll_interval.get_duration(),
+        max_message_size: config.max_message_size.as_bytes_u64(),
+    };
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let listener = TcpListener::bind(&config.address)
+            .await
+            .expect("Unable to start AMQP server.");
+
+        let local_addr = listener
+            .local_addr()
+            .expect("Failed to get local address for AMQP listener");
+
+        tx.send(local_addr).unwrap_or_else(|_| {
+            panic!(
+                "Failed to send the local address {:?} for AMQP listener",
+                local_addr
+            )
+        });
+
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    if system.read().is_draining() {
+                        info!(
+                            "Rejected new AMQP connection: {address}, the server is shutting down."
+                        );
+                        continue;
+                    }
+
+                    info!("Accepted new AMQP connection: {address}");
+                    let system = system.clone();
+                    tokio::spawn(async move {
+                        if let Err(error) =
+                            handle_connection(address, stream, settings, system.clone()).await
+                        {
+                            handle_error(error);
+                        }
+                        system.read().delete_client(&address).await;
+                    });
+                }
+                Err(error) => error!("Unable to accept AMQP socket, error: {error}"),
+            }
+        }
+    });
+    let addr = match rx.await {
+        Ok(addr) => addr,
+        Err(_) => panic!("Failed to get the local address for AMQP listener"),
+    };
+    info!("Iggy AMQP server has started on: {addr:?}");
+    addr
+}
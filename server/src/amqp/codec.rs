@@ -0,0 +1,295 @@
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::str::from_utf8;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CodecError {
+    #[error("Malformed frame: {0}")]
+    Malformed(&'static str),
+    #[error("Unsupported field type: {0}")]
+    UnsupportedFieldType(char),
+}
+
+/// The value of the field table entry, only the strings, booleans and nested tables are decoded, the remaining types are skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Bool(bool),
+    LongString(Bytes),
+    Table(FieldTable),
+    Other,
+}
+
+pub type FieldTable = Vec<(String, FieldValue)>;
+
+/// Reads the AMQP domain types from the method arguments and the content header.
+pub struct Decoder {
+    bytes: Bytes,
+    bits: u8,
+    bits_position: u8,
+}
+
+impl Decoder {
+    pub fn new(bytes: Bytes) -> Self {
+        Self {
+            bytes,
+            bits: 0,
+            bits_position: 8,
+        }
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, CodecError> {
+        self.bits_position = 8;
+        self.ensure_remaining(1)?;
+        Ok(self.bytes.get_u8())
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, CodecError> {
+        self.bits_position = 8;
+        self.ensure_remaining(2)?;
+        Ok(self.bytes.get_u16())
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, CodecError> {
+        self.bits_position = 8;
+        self.ensure_remaining(4)?;
+        Ok(self.bytes.get_u32())
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, CodecError> {
+        self.bits_position = 8;
+        self.ensure_remaining(8)?;
+        Ok(self.bytes.get_u64())
+    }
+
+    /// Reads the next bit, the consecutive bits are packed into the single octet.
+    pub fn read_bit(&mut self) -> Result<bool, CodecError> {
+        if self.bits_position == 8 {
+            self.ensure_remaining(1)?;
+            self.bits = self.bytes.get_u8();
+            self.bits_position = 0;
+        }
+        let bit = self.bits & (1 << self.bits_position) != 0;
+        self.bits_position += 1;
+        Ok(bit)
+    }
+
+    pub fn read_short_string(&mut self) -> Result<String, CodecError> {
+        let length = self.read_u8()? as usize;
+        self.read_string(length)
+    }
+
+    pub fn read_long_string(&mut self) -> Result<Bytes, CodecError> {
+        let length = self.read_u32()? as usize;
+        self.ensure_remaining(length)?;
+        Ok(self.bytes.split_to(length))
+    }
+
+    pub fn read_table(&mut self) -> Result<FieldTable, CodecError> {
+        let length = self.read_u32()? as usize;
+        self.ensure_remaining(length)?;
+        let mut decoder = Decoder::new(self.bytes.split_to(length));
+        let mut table = Vec::new();
+        while decoder.bytes.has_remaining() {
+            let name = decoder.read_short_string()?;
+            let value = decoder.read_field_value()?;
+            table.push((name, value));
+        }
+        Ok(table)
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bytes.remaining()
+    }
+
+    fn read_field_value(&mut self) -> Result<FieldValue, CodecError> {
+        let kind = self.read_u8()? as char;
+        let skipped = match kind {
+            't' => return Ok(FieldValue::Bool(self.read_u8()? != 0)),
+            'S' => return Ok(FieldValue::LongString(self.read_long_string()?)),
+            'F' => return Ok(FieldValue::Table(self.read_table()?)),
+            'V' => 0,
+            'b' | 'B' => 1,
+            's' | 'u' => 2,
+            'I' | 'i' | 'f' => 4,
+            'D' => 5,
+            'l' | 'L' | 'd' | 'T' => 8,
+            'A' | 'x' => self.read_u32()? as usize,
+            kind => return Err(CodecError::UnsupportedFieldType(kind)),
+        };
+        self.ensure_remaining(skipped)?;
+        self.bytes.advance(skipped);
+        Ok(FieldValue::Other)
+    }
+
+    fn read_string(&mut self, length: usize) -> Result<String, CodecError> {
+        self.ensure_remaining(length)?;
+        let value = self.bytes.split_to(length);
+        from_utf8(&value)
+            .map(|value| value.to_string())
+            .map_err(|_| CodecError::Malformed("invalid UTF-8 string"))
+    }
+
+    fn ensure_remaining(&self, length: usize) -> Result<(), CodecError> {
+        if self.bytes.remaining() < length {
+            return Err(CodecError::Malformed("unexpected end of frame"));
+        }
+        Ok(())
+    }
+}
+
+/// Writes the AMQP domain types of the methods and the content headers sent by the server.
+#[derive(Default)]
+pub struct Encoder {
+    bytes: BytesMut,
+    bits_index: Option<usize>,
+    bits_position: u8,
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn write_u8(&mut self, value: u8) -> &mut Self {
+        self.bits_index = None;
+        self.bytes.put_u8(value);
+        self
+    }
+
+    pub fn write_u16(&mut self, value: u16) -> &mut Self {
+        self.bits_index = None;
+        self.bytes.put_u16(value);
+        self
+    }
+
+    pub fn write_u32(&mut self, value: u32) -> &mut Self {
+        self.bits_index = None;
+        self.bytes.put_u32(value);
+        self
+    }
+
+    pub fn write_u64(&mut self, value: u64) -> &mut Self {
+        self.bits_index = None;
+        self.bytes.put_u64(value);
+        self
+    }
+
+    /// Writes the next bit, the consecutive bits are packed into the single octet.
+    pub fn write_bit(&mut self, value: bool) -> &mut Self {
+        let index = match self.bits_index {
+            Some(index) if self.bits_position < 8 => index,
+            _ => {
+                self.bytes.put_u8(0);
+                self.bits_position = 0;
+                self.bytes.len() - 1
+            }
+        };
+        if value {
+            self.bytes[index] |= 1 << self.bits_position;
+        }
+        self.bits_index = Some(index);
+        self.bits_position += 1;
+        self
+    }
+
+    /// Writes the short string, truncated to 255 bytes (on the character boundary) if it's longer.
+    pub fn write_short_string(&mut self, value: &str) -> &mut Self {
+        let mut length = value.len().min(u8::MAX as usize);
+        while !value.is_char_boundary(length) {
+            length -= 1;
+        }
+        self.write_u8(length as u8);
+        self.bytes.put_slice(&value.as_bytes()[..length]);
+        self
+    }
+
+    pub fn write_long_string(&mut self, value: &[u8]) -> &mut Self {
+        self.write_u32(value.len() as u32);
+        self.bytes.put_slice(value);
+        self
+    }
+
+    pub fn write_table(&mut self, table: &FieldTable) -> &mut Self {
+        let mut encoder = Encoder::new();
+        for (name, value) in table {
+            encoder.write_short_string(name);
+            match value {
+                FieldValue::Bool(value) => encoder.write_u8(b't').write_u8(*value as u8),
+                FieldValue::LongString(value) => encoder.write_u8(b'S').write_long_string(value),
+                FieldValue::Table(value) => encoder.write_u8(b'F').write_table(value),
+                FieldValue::Other => encoder.write_u8(b'V'),
+            };
+        }
+        let table = encoder.finish();
+        self.write_u32(table.len() as u32);
+        self.bytes.put_slice(&table);
+        self
+    }
+
+    pub fn finish(&mut self) -> Bytes {
+        self.bits_index = None;
+        self.bytes.split().freeze()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_encode_and_decode_packed_bits_and_strings() {
+        let bytes = Encoder::new()
+            .write_u16(7)
+            .write_short_string("orders")
+            .write_bit(true)
+            .write_bit(false)
+            .write_bit(true)
+            .write_u64(42)
+            .write_long_string(b"payload")
+            .finish();
+
+        let mut decoder = Decoder::new(bytes);
+        assert_eq!(decoder.read_u16().unwrap(), 7);
+        assert_eq!(decoder.read_short_string().unwrap(), "orders");
+        assert!(decoder.read_bit().unwrap());
+        assert!(!decoder.read_bit().unwrap());
+        assert!(decoder.read_bit().unwrap());
+        assert_eq!(decoder.read_u64().unwrap(), 42);
+        assert_eq!(decoder.read_long_string().unwrap(), Bytes::from("payload"));
+        assert_eq!(decoder.remaining(), 0);
+    }
+
+    #[test]
+    fn should_decode_table_skipping_unsupported_values() {
+        let mut table = BytesMut::new();
+        table.put_u8(4);
+        table.put_slice(b"kind");
+        table.put_u8(b'S');
+        table.put_u32(5);
+        table.put_slice(b"order");
+        table.put_u8(5);
+        table.put_slice(b"count");
+        table.put_u8(b'I');
+        table.put_i32(10);
+        table.put_u8(7);
+        table.put_slice(b"durable");
+        table.put_u8(b't');
+        table.put_u8(1);
+        let mut bytes = BytesMut::new();
+        bytes.put_u32(table.len() as u32);
+        bytes.put_slice(&table);
+
+        let table = Decoder::new(bytes.freeze()).read_table().unwrap();
+        assert_eq!(
+            table,
+            vec![
+                (
+                    "kind".to_string(),
+                    FieldValue::LongString(Bytes::from("order"))
+                ),
+                ("count".to_string(), FieldValue::Other),
+                ("durable".to_string(), FieldValue::Bool(true)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1092 @@
+use crate::amqp::codec::{FieldTable, FieldValue};
+use crate::amqp::consumer::{PolledMessage, Queue, QueueConsumer, QUEUE_SEPARATOR};
+use crate::amqp::content::ContentHeader;
+use crate::amqp::error::AmqpError;
+use crate::amqp::frame::{
+    self, Frame, FRAME_BODY, FRAME_HEADER, FRAME_HEARTBEAT, FRAME_METHOD, FRAME_MIN_SIZE,
+    FRAME_OVERHEAD, PROTOCOL_HEADER,
+};
+use crate::amqp::method::{self, *};
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use bytes::{Bytes, BytesMut};
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Acks, Message, Partitioning};
+use std::collections::{BTreeMap, HashMap};
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::time::{interval, timeout, MissedTickBehavior};
+use tracing::{debug, error, info, warn};
+
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+const CHANNEL_MAX: u16 = 2047;
+const MECHANISM: &str = "PLAIN";
+const CONSUMER_TAG_PREFIX: &str = "amq.ctag-";
+const GET_CONSUMER_TAG: &str = "amq.get";
+/// The maximum number of messages delivered to the channel at once, when the prefetch count is not set.
+const MAX_DELIVERIES: u32 = 100;
+const CONTENT_TOO_LARGE: u16 = 311;
+
+/// The settings of the AMQP connections, the frame size and the heartbeat are negotiated with the client.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AmqpSettings {
+    pub frame_max: u32,
+    pub heartbeat: u16,
+    pub poll_interval: Duration,
+    pub max_message_size: u64,
+}
+
+/// Handles the AMQP 0.9.1 connection, the client must authenticate with the iggy user credentials (PLAIN mechanism),
+/// then it can publish the messages to the exchange named after the stream with the topic as the routing key
+/// (or to the default exchange with the `<stream>/<topic>` routing key), and consume the `<stream>/<topic>` queues.
+pub(crate) async fn handle_connection(
+    address: SocketAddr,
+    stream: TcpStream,
+    settings: AmqpSettings,
+    system: SharedSystem,
+) -> Result<(), AmqpError> {
+    let (reader, writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    let mut connection = Connection {
+        address,
+        session: Session::from_client_id(0, address),
+        system,
+        settings,
+        writer,
+        buffer: BytesMut::new(),
+        frame_max: settings.frame_max,
+        heartbeat: None,
+        last_sent: Instant::now(),
+        channels: HashMap::new(),
+    };
+    timeout(HANDSHAKE_TIMEOUT, connection.handshake(&mut reader))
+        .await
+        .map_err(|_| {
+            AmqpError::ConnectionRejected(format!(
+                "handshake with client: {address} has not been completed in time"
+            ))
+        })??;
+
+    let (sender, receiver) = mpsc::channel(16);
+    let frame_max = connection.frame_max;
+    let reader_task = tokio::spawn(read_frames(reader, frame_max, sender));
+    let result = connection.run(receiver).await;
+    reader_task.abort();
+    result
+}
+
+async fn read_frames(
+    mut reader: BufReader<OwnedReadHalf>,
+    frame_max: u32,
+    sender: mpsc::Sender<Result<Frame, AmqpError>>,
+) {
+    loop {
+        let frame = frame::read(&mut reader, frame_max).await;
+        let failed = frame.is_err();
+        if sender.send(frame).await.is_err() || failed {
+            return;
+        }
+    }
+}
+
+struct Connection {
+    address: SocketAddr,
+    session: Session,
+    system: SharedSystem,
+    settings: AmqpSettings,
+    writer: OwnedWriteHalf,
+    buffer: BytesMut,
+    frame_max: u32,
+    heartbeat: Option<Duration>,
+    last_sent: Instant,
+    channels: HashMap<u16, Channel>,
+}
+
+#[derive(Debug, Default)]
+struct Channel {
+    closing: bool,
+    paused: bool,
+    confirm: bool,
+    publish_sequence: u64,
+    publish: Option<PendingPublish>,
+    prefetch_count: u16,
+    next_delivery_tag: u64,
+    consumers: BTreeMap<String, QueueConsumer>,
+    /// The consumers used by `Basic.Get`, by the queue name.
+    getters: HashMap<String, QueueConsumer>,
+    unacked: BTreeMap<u64, Delivery>,
+}
+
+#[derive(Debug)]
+struct PendingPublish {
+    exchange: String,
+    routing_key: String,
+    header: Option<ContentHeader>,
+    body: BytesMut,
+}
+
+#[derive(Debug, Clone)]
+struct Delivery {
+    consumer: ConsumerKey,
+    partition_id: u32,
+    offset: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ConsumerKey {
+    Consume(String),
+    Get(String),
+}
+
+/// The error closing the channel (or the whole connection), with the reply code and text sent to the client.
+struct Exception {
+    connection: bool,
+    code: u16,
+    text: String,
+}
+
+impl Exception {
+    fn channel(code: u16, text: impl Into<String>) -> Self {
+        Self {
+            connection: false,
+            code,
+            text: text.into(),
+        }
+    }
+
+    fn connection(code: u16, text: impl Into<String>) -> Self {
+        Self {
+            connection: true,
+            code,
+            text: text.into(),
+        }
+    }
+}
+
+impl From<IggyError> for Exception {
+    fn from(error: IggyError) -> Self {
+        let code = match error {
+            IggyError::InvalidTopicName
+            | IggyError::StreamIdNotFound(_)
+            | IggyError::StreamNameNotFound(_)
+            | IggyError::TopicIdNotFound(_, _)
+            | IggyError::TopicNameNotFound(_, _)
+            | IggyError::PartitionNotFound(_, _, _) => NOT_FOUND,
+            IggyError::Unauthorized | IggyError::Unauthenticated => ACCESS_REFUSED,
+            _ => PRECONDITION_FAILED,
+        };
+        Exception::channel(code, error.to_string())
+    }
+}
+
+impl Channel {
+    fn get_consumer(&mut self, key: &ConsumerKey) -> Option<&mut QueueConsumer> {
+        match key {
+            ConsumerKey::Consume(tag) => self.consumers.get_mut(tag),
+            ConsumerKey::Get(queue) => self.getters.get_mut(queue),
+        }
+    }
+
+    /// Removes the unacknowledged deliveries with the tag (or up to the tag, if `multiple` is set, where 0 means all of them).
+    fn remove_unacked(&mut self, delivery_tag: u64, multiple: bool) -> Vec<Delivery> {
+        let tags = match multiple {
+            true => self
+                .unacked
+                .keys()
+                .filter(|tag| delivery_tag == 0 || **tag <= delivery_tag)
+                .copied()
+                .collect::<Vec<_>>(),
+            false => vec![delivery_tag],
+        };
+        tags.iter()
+            .filter_map(|tag| self.unacked.remove(tag))
+            .collect()
+    }
+
+    /// Moves the consumers back to the rejected deliveries, the following deliveries from the same partitions are delivered again too.
+    fn requeue(&mut self, deliveries: &[Delivery]) {
+        for delivery in deliveries {
+            self.unacked.retain(|_, unacked| {
+                unacked.consumer != delivery.consumer
+                    || unacked.partition_id != delivery.partition_id
+                    || unacked.offset < delivery.offset
+            });
+            if let Some(consumer) = self.get_consumer(&delivery.consumer) {
+                consumer.rewind(delivery.partition_id, delivery.offset);
+            }
+        }
+    }
+
+    /// Returns the offset which can be stored for the consumer partition, all the messages up to it have been acknowledged.
+    fn get_acknowledged_offset(&mut self, key: &ConsumerKey, partition_id: u32) -> Option<u64> {
+        let first_unacked_offset = self
+            .unacked
+            .values()
+            .filter(|delivery| delivery.consumer == *key && delivery.partition_id == partition_id)
+            .map(|delivery| delivery.offset)
+            .min();
+        match first_unacked_offset {
+            Some(offset) => offset.checked_sub(1),
+            None => self.get_consumer(key)?.get_delivered_offset(partition_id),
+        }
+    }
+}
+
+impl Connection {
+    async fn handshake(&mut self, reader: &mut BufReader<OwnedReadHalf>) -> Result<(), AmqpError> {
+        let mut protocol_header = [0u8; 8];
+        reader.read_exact(&mut protocol_header).await?;
+        if &protocol_header != PROTOCOL_HEADER {
+            self.writer.write_all(PROTOCOL_HEADER).await?;
+            return Err(AmqpError::ConnectionRejected(format!(
+                "unsupported protocol header: {protocol_header:?}"
+            )));
+        }
+
+        let client_id = self
+            .system
+            .read()
+            .add_client(&self.address, Transport::Amqp)
+            .await;
+        self.session =
+            Session::from_client_id(client_id, self.address).with_transport(Transport::Amqp);
+        self.send_method(0, method::connection_start(&server_properties(), MECHANISM));
+        self.flush().await?;
+
+        let Method::ConnectionStartOk {
+            mechanism,
+            response,
+        } = self.read_handshake_method(reader).await?
+        else {
+            return self
+                .close_handshake(COMMAND_INVALID, "expected Connection.StartOk")
+                .await;
+        };
+        if mechanism != MECHANISM {
+            return self
+                .close_handshake(
+                    ACCESS_REFUSED,
+                    &format!("unsupported mechanism: {mechanism}"),
+                )
+                .await;
+        }
+        if let Err(error) = self.login(&response).await {
+            return self
+                .close_handshake(
+                    ACCESS_REFUSED,
+                    &format!("ACCESS_REFUSED - login was refused: {error}"),
+                )
+                .await;
+        }
+
+        self.send_method(
+            0,
+            method::connection_tune(
+                CHANNEL_MAX,
+                self.settings.frame_max,
+                self.settings.heartbeat,
+            ),
+        );
+        self.flush().await?;
+        let Method::ConnectionTuneOk {
+            frame_max,
+            heartbeat,
+            ..
+        } = self.read_handshake_method(reader).await?
+        else {
+            return self
+                .close_handshake(COMMAND_INVALID, "expected Connection.TuneOk")
+                .await;
+        };
+        if frame_max != 0 && frame_max < FRAME_MIN_SIZE {
+            return self
+                .close_handshake(
+                    SYNTAX_ERROR,
+                    &format!("frame max: {frame_max} is too small"),
+                )
+                .await;
+        }
+        if frame_max != 0 {
+            self.frame_max = frame_max.min(self.settings.frame_max);
+        }
+        if heartbeat > 0 {
+            self.heartbeat = Some(Duration::from_secs(heartbeat as u64));
+        }
+
+        let Method::ConnectionOpen { virtual_host } = self.read_handshake_method(reader).await?
+        else {
+            return self
+                .close_handshake(COMMAND_INVALID, "expected Connection.Open")
+                .await;
+        };
+        self.send_method(0, method::connection_open_ok());
+        self.flush().await?;
+        info!(
+            "AMQP client: {} has connected, virtual host: {virtual_host}, frame max: {}, heartbeat: {:?}.",
+            self.address, self.frame_max, self.heartbeat
+        );
+        Ok(())
+    }
+
+    async fn read_handshake_method(
+        &mut self,
+        reader: &mut BufReader<OwnedReadHalf>,
+    ) -> Result<Method, AmqpError> {
+        let frame = frame::read(reader, self.settings.frame_max).await?;
+        if frame.kind != FRAME_METHOD || frame.channel != 0 {
+            self.close_handshake(UNEXPECTED_FRAME, "expected method frame on channel 0")
+                .await?;
+        }
+        let (_, _, method) = Method::decode(frame.payload)?;
+        Ok(method)
+    }
+
+    async fn close_handshake<T>(&mut self, code: u16, text: &str) -> Result<T, AmqpError> {
+        self.send_method(0, method::connection_close(code, text, 0, 0));
+        self.flush().await?;
+        Err(AmqpError::ConnectionClosed(code, text.to_string()))
+    }
+
+    /// Authenticates the user with the PLAIN mechanism response: `authzid\0username\0password`.
+    async fn login(&self, response: &[u8]) -> Result<(), IggyError> {
+        let credentials =
+            std::str::from_utf8(response).map_err(|_| IggyError::InvalidCredentials)?;
+        let mut parts = credentials.split('\0').skip(1);
+        let (Some(username), Some(password)) = (parts.next(), parts.next()) else {
+            return Err(IggyError::InvalidCredentials);
+        };
+
+        self.system
+            .read()
+            .login_user(username, password, Some(&self.session))
+            .await?;
+        Ok(())
+    }
+
+    async fn run(
+        &mut self,
+        mut receiver: mpsc::Receiver<Result<Frame, AmqpError>>,
+    ) -> Result<(), AmqpError> {
+        let mut ticker = interval(self.settings.poll_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut last_received = Instant::now();
+        loop {
+            tokio::select! {
+                frame = receiver.recv() => {
+                    let frame = match frame {
+                        Some(Ok(frame)) => frame,
+                        Some(Err(AmqpError::Codec(error))) => {
+                            return self.close(0, Exception::connection(FRAME_ERROR, error.to_string()), 0, 0).await;
+                        }
+                        Some(Err(error)) => return Err(error),
+                        None => return Ok(()),
+                    };
+                    last_received = Instant::now();
+                    if !self.handle_frame(frame).await? {
+                        return Ok(());
+                    }
+                }
+                _ = ticker.tick() => {
+                    if let Some(heartbeat) = self.heartbeat {
+                        if last_received.elapsed() > heartbeat * 2 {
+                            return Err(AmqpError::HeartbeatTimeout(format!(
+                                "no frame received from client: {} within: {} s",
+                                self.address,
+                                heartbeat.as_secs() * 2
+                            )));
+                        }
+                        if self.last_sent.elapsed() >= heartbeat / 2 {
+                            Frame::heartbeat().encode(&mut self.buffer);
+                        }
+                    }
+                    self.deliver().await;
+                }
+            }
+            self.flush().await?;
+        }
+    }
+
+    /// Handles the frame, returns `false` once the connection has been closed by the client.
+    async fn handle_frame(&mut self, frame: Frame) -> Result<bool, AmqpError> {
+        let channel_id = frame.channel;
+        match frame.kind {
+            FRAME_HEARTBEAT => Ok(true),
+            FRAME_METHOD => {
+                let (class_id, method_id, method) = match Method::decode(frame.payload) {
+                    Ok(method) => method,
+                    Err(error) => {
+                        return self
+                            .close(
+                                0,
+                                Exception::connection(SYNTAX_ERROR, error.to_string()),
+                                0,
+                                0,
+                            )
+                            .await
+                            .map(|_| false)
+                    }
+                };
+                debug!("Received AMQP method: {method:?} on channel: {channel_id}");
+                if channel_id == 0 {
+                    return match method {
+                        Method::ConnectionClose {
+                            reply_code,
+                            reply_text,
+                        } => {
+                            info!(
+                                "AMQP client: {} has closed the connection, code: {reply_code}, reason: {reply_text}",
+                                self.address
+                            );
+                            self.send_method(0, method::connection_close_ok());
+                            self.flush().await?;
+                            Ok(false)
+                        }
+                        _ => self
+                            .close(
+                                0,
+                                Exception::connection(
+                                    COMMAND_INVALID,
+                                    "unexpected method on channel 0",
+                                ),
+                                class_id,
+                                method_id,
+                            )
+                            .await
+                            .map(|_| false),
+                    };
+                }
+
+                match self.handle_method(channel_id, method).await {
+                    Ok(()) => Ok(true),
+                    Err(exception) => self
+                        .close(channel_id, exception, class_id, method_id)
+                        .await
+                        .map(|_| true),
+                }
+            }
+            FRAME_HEADER | FRAME_BODY => match self.handle_content(channel_id, frame).await {
+                Ok(()) => Ok(true),
+                Err(exception) => self
+                    .close(channel_id, exception, BASIC, 40)
+                    .await
+                    .map(|_| true),
+            },
+            kind => self
+                .close(
+                    0,
+                    Exception::connection(FRAME_ERROR, format!("unknown frame type: {kind}")),
+                    0,
+                    0,
+                )
+                .await
+                .map(|_| false),
+        }
+    }
+
+    /// Closes the channel or the connection, the latter is returned as the error, so the connection is dropped.
+    /// The closed channel ignores all the frames until the client confirms the closing.
+    async fn close(
+        &mut self,
+        channel_id: u16,
+        exception: Exception,
+        class_id: u16,
+        method_id: u16,
+    ) -> Result<(), AmqpError> {
+        if exception.connection {
+            self.send_method(
+                0,
+                method::connection_close(exception.code, &exception.text, class_id, method_id),
+            );
+            self.flush().await?;
+            return Err(AmqpError::ConnectionClosed(exception.code, exception.text));
+        }
+
+        warn!(
+            "Closing AMQP channel: {channel_id} for client: {}, code: {}, reason: {}",
+            self.address, exception.code, exception.text
+        );
+        if let Some(channel) = self.channels.get_mut(&channel_id) {
+            *channel = Channel {
+                closing: true,
+                ..Default::default()
+            };
+        }
+        self.send_method(
+            channel_id,
+            method::channel_close(exception.code, &exception.text, class_id, method_id),
+        );
+        Ok(())
+    }
+
+    async fn handle_method(&mut self, channel_id: u16, method: Method) -> Result<(), Exception> {
+        if let Method::ChannelOpen = method {
+            if channel_id > CHANNEL_MAX || self.channels.contains_key(&channel_id) {
+                return Err(Exception::connection(
+                    CHANNEL_ERROR,
+                    format!("channel: {channel_id} cannot be opened"),
+                ));
+            }
+            self.channels.insert(channel_id, Channel::default());
+            self.send_method(channel_id, method::channel_open_ok());
+            return Ok(());
+        }
+
+        let Some(channel) = self.channels.get_mut(&channel_id) else {
+            return Err(Exception::connection(
+                CHANNEL_ERROR,
+                format!("channel: {channel_id} is not open"),
+            ));
+        };
+
+        if channel.closing {
+            if let Method::ChannelCloseOk | Method::ChannelClose { .. } = method {
+                self.channels.remove(&channel_id);
+                if let Method::ChannelClose { .. } = method {
+                    self.send_method(channel_id, method::channel_close_ok());
+                }
+            }
+            return Ok(());
+        }
+
+        if channel.publish.is_some() {
+            return Err(Exception::connection(
+                UNEXPECTED_FRAME,
+                "expected content header or body frame",
+            ));
+        }
+
+        match method {
+            Method::ChannelFlow { active } => {
+                channel.paused = !active;
+                self.send_method(channel_id, method::channel_flow_ok(active));
+            }
+            Method::ChannelClose {
+                reply_code,
+                reply_text,
+            } => {
+                debug!("AMQP channel: {channel_id} has been closed by the client, code: {reply_code}, reason: {reply_text}");
+                self.channels.remove(&channel_id);
+                self.send_method(channel_id, method::channel_close_ok());
+            }
+            Method::ChannelCloseOk => {}
+            Method::ExchangeDeclare { exchange, no_wait } => {
+                if !exchange.is_empty() {
+                    let stream_id = Identifier::from_str_value(&exchange)?;
+                    let system = self.system.read();
+                    let stream = system.find_stream(&self.session, &stream_id).await?;
+                    debug!(
+                        "AMQP exchange: {exchange} is mapped to stream with ID: {}",
+                        stream.stream_id
+                    );
+                }
+                if !no_wait {
+                    self.send_method(channel_id, method::exchange_declare_ok());
+                }
+            }
+            Method::QueueDeclare { queue, no_wait } => {
+                let parsed_queue = parse_queue(&queue)?;
+                let consumers_count = channel
+                    .consumers
+                    .values()
+                    .filter(|consumer| consumer.queue.name == queue)
+                    .count();
+                let messages_count = self.get_messages_count(&parsed_queue).await?;
+                if !no_wait {
+                    self.send_method(
+                        channel_id,
+                        method::queue_declare_ok(
+                            &queue,
+                            messages_count.min(u32::MAX as u64) as u32,
+                            consumers_count as u32,
+                        ),
+                    );
+                }
+            }
+            Method::QueueBind { no_wait } => {
+                if !no_wait {
+                    self.send_method(channel_id, method::queue_bind_ok());
+                }
+            }
+            Method::BasicQos { prefetch_count } => {
+                channel.prefetch_count = prefetch_count;
+                self.send_method(channel_id, method::basic_qos_ok());
+            }
+            Method::BasicConsume {
+                queue,
+                consumer_tag,
+                no_ack,
+                no_wait,
+            } => {
+                let parsed_queue = parse_queue(&queue)?;
+                self.get_messages_count(&parsed_queue).await?;
+                let consumer_tag = match consumer_tag.is_empty() {
+                    true => format!("{CONSUMER_TAG_PREFIX}{queue}"),
+                    false => consumer_tag,
+                };
+                let channel = self.channels.get_mut(&channel_id).unwrap();
+                if channel.consumers.contains_key(&consumer_tag) {
+                    return Err(Exception::connection(
+                        NOT_ALLOWED,
+                        format!("consumer tag: {consumer_tag} is already used"),
+                    ));
+                }
+                channel.consumers.insert(
+                    consumer_tag.clone(),
+                    QueueConsumer::new(&consumer_tag, parsed_queue, no_ack),
+                );
+                info!(
+                    "AMQP client: {} has started consuming queue: {queue} with consumer tag: {consumer_tag}",
+                    self.address
+                );
+                if !no_wait {
+                    self.send_method(channel_id, method::basic_consume_ok(&consumer_tag));
+                }
+            }
+            Method::BasicCancel {
+                consumer_tag,
+                no_wait,
+            } => {
+                channel.consumers.remove(&consumer_tag);
+                let key = ConsumerKey::Consume(consumer_tag.clone());
+                channel
+                    .unacked
+                    .retain(|_, delivery| delivery.consumer != key);
+                if !no_wait {
+                    self.send_method(channel_id, method::basic_cancel_ok(&consumer_tag));
+                }
+            }
+            Method::BasicPublish {
+                exchange,
+                routing_key,
+            } => {
+                channel.publish = Some(PendingPublish {
+                    exchange,
+                    routing_key,
+                    header: None,
+                    body: BytesMut::new(),
+                });
+            }
+            Method::BasicGet { queue, no_ack } => self.get(channel_id, queue, no_ack).await?,
+            Method::BasicAck {
+                delivery_tag,
+                multiple,
+            } => {
+                let deliveries = channel.remove_unacked(delivery_tag, multiple);
+                self.store_offsets(channel_id, &deliveries).await;
+            }
+            Method::BasicReject {
+                delivery_tag,
+                requeue,
+            } => self.reject(channel_id, delivery_tag, false, requeue).await,
+            Method::BasicNack {
+                delivery_tag,
+                multiple,
+                requeue,
+            } => {
+                self.reject(channel_id, delivery_tag, multiple, requeue)
+                    .await
+            }
+            Method::BasicRecover { .. } => {
+                let deliveries = channel.remove_unacked(0, true);
+                channel.requeue(&deliveries);
+                self.send_method(channel_id, method::basic_recover_ok());
+            }
+            Method::ConfirmSelect { no_wait } => {
+                channel.confirm = true;
+                if !no_wait {
+                    self.send_method(channel_id, method::confirm_select_ok());
+                }
+            }
+            Method::ChannelOpen => {}
+            Method::ConnectionStartOk { .. }
+            | Method::ConnectionTuneOk { .. }
+            | Method::ConnectionOpen { .. }
+            | Method::ConnectionClose { .. }
+            | Method::ConnectionCloseOk => {
+                return Err(Exception::connection(
+                    COMMAND_INVALID,
+                    "connection method on non-zero channel",
+                ));
+            }
+            Method::Unsupported {
+                class_id,
+                method_id,
+            } => {
+                return Err(Exception::channel(
+                    NOT_IMPLEMENTED,
+                    format!("method: {class_id}.{method_id} is not supported"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the number of messages in the topic behind the queue, which fails if the topic doesn't exist.
+    async fn get_messages_count(&self, queue: &Queue) -> Result<u64, IggyError> {
+        let system = self.system.read();
+        let topic = system
+            .find_topic(&self.session, &queue.stream_id, &queue.topic_id)
+            .await?;
+        Ok(topic.get_messages_count())
+    }
+
+    async fn handle_content(&mut self, channel_id: u16, frame: Frame) -> Result<(), Exception> {
+        let max_message_size = self.settings.max_message_size;
+        let Some(channel) = self.channels.get_mut(&channel_id) else {
+            return Err(Exception::connection(
+                CHANNEL_ERROR,
+                format!("channel: {channel_id} is not open"),
+            ));
+        };
+        if channel.closing {
+            return Ok(());
+        }
+
+        let Some(publish) = channel.publish.as_mut() else {
+            return Err(Exception::connection(
+                UNEXPECTED_FRAME,
+                "content frame without Basic.Publish",
+            ));
+        };
+        match (frame.kind, &publish.header) {
+            (FRAME_HEADER, None) => {
+                let header = ContentHeader::decode(frame.payload)
+                    .map_err(|error| Exception::connection(FRAME_ERROR, error.to_string()))?;
+                if header.body_size > max_message_size {
+                    channel.publish = None;
+                    return Err(Exception::channel(
+                        CONTENT_TOO_LARGE,
+                        format!(
+                            "message of {} bytes exceeds the maximum size of {max_message_size} bytes",
+                            header.body_size
+                        ),
+                    ));
+                }
+                publish.header = Some(header);
+            }
+            (FRAME_BODY, Some(header)) => {
+                if publish.body.len() + frame.payload.len() > header.body_size as usize {
+                    return Err(Exception::connection(
+                        FRAME_ERROR,
+                        "body exceeds the declared size",
+                    ));
+                }
+                publish.body.extend_from_slice(&frame.payload);
+            }
+            _ => {
+                return Err(Exception::connection(
+                    UNEXPECTED_FRAME,
+                    "unexpected content frame",
+                ))
+            }
+        }
+
+        let header = publish.header.as_ref().unwrap();
+        if publish.body.len() as u64 == header.body_size {
+            let publish = channel.publish.take().unwrap();
+            channel.publish_sequence += 1;
+            self.publish(channel_id, publish).await?;
+        }
+        Ok(())
+    }
+
+    async fn publish(&mut self, channel_id: u16, publish: PendingPublish) -> Result<(), Exception> {
+        let result = self.append_message(&publish).await;
+        let channel = self.channels.get_mut(&channel_id).unwrap();
+        let sequence = channel.publish_sequence;
+        let confirm = channel.confirm;
+        match result {
+            Ok(()) => {
+                if confirm {
+                    self.send_method(channel_id, method::basic_ack(sequence, false));
+                }
+                Ok(())
+            }
+            Err(error) => {
+                warn!(
+                    "Cannot append AMQP message published to exchange: {}, routing key: {}, error: {error}",
+                    publish.exchange, publish.routing_key
+                );
+                let exception = Exception::from(error);
+                if confirm && exception.code == PRECONDITION_FAILED {
+                    self.send_method(channel_id, method::basic_nack(sequence, false, false));
+                    return Ok(());
+                }
+                Err(exception)
+            }
+        }
+    }
+
+    async fn append_message(&self, publish: &PendingPublish) -> Result<(), IggyError> {
+        let (stream, topic) = match publish.exchange.is_empty() {
+            true => publish
+                .routing_key
+                .split_once(QUEUE_SEPARATOR)
+                .ok_or(IggyError::InvalidTopicName)?,
+            false => (publish.exchange.as_str(), publish.routing_key.as_str()),
+        };
+        let stream_id = Identifier::from_str_value(stream)?;
+        let topic_id = Identifier::from_str_value(topic)?;
+        let headers = publish
+            .header
+            .as_ref()
+            .map(|header| header.to_message_headers())
+            .transpose()?
+            .filter(|headers| !headers.is_empty());
+        let messages = vec![Message::new(
+            None,
+            Bytes::copy_from_slice(&publish.body),
+            headers,
+        )];
+        self.system
+            .read()
+            .append_messages(
+                &self.session,
+                &stream_id,
+                &topic_id,
+                &Partitioning::balanced(),
+                &messages,
+                Acks::Leader,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn get(&mut self, channel_id: u16, queue: String, no_ack: bool) -> Result<(), Exception> {
+        let channel = self.channels.get_mut(&channel_id).unwrap();
+        if !channel.getters.contains_key(&queue) {
+            let parsed_queue = parse_queue(&queue)?;
+            channel.getters.insert(
+                queue.clone(),
+                QueueConsumer::new(GET_CONSUMER_TAG, parsed_queue, false),
+            );
+        }
+
+        let getter = channel.getters.get_mut(&queue).unwrap();
+        let mut messages = getter.poll(&self.session, &self.system, 1).await?;
+        let Some(PolledMessage {
+            partition_id,
+            message,
+        }) = messages.pop()
+        else {
+            self.send_method(channel_id, method::basic_get_empty());
+            return Ok(());
+        };
+
+        let (exchange, routing_key) = getter.queue.get_exchange_and_routing_key();
+        let get_ok = method::basic_get_ok(
+            channel.next_delivery_tag + 1,
+            false,
+            exchange,
+            routing_key,
+            0,
+        );
+        channel.next_delivery_tag += 1;
+        let delivery = Delivery {
+            consumer: ConsumerKey::Get(queue),
+            partition_id,
+            offset: message.offset,
+        };
+        match no_ack {
+            true => {
+                self.store_offsets(channel_id, &[delivery]).await;
+            }
+            false => {
+                channel.unacked.insert(channel.next_delivery_tag, delivery);
+            }
+        }
+        self.send_method(channel_id, get_ok);
+        self.send_content(channel_id, &message);
+        Ok(())
+    }
+
+    async fn reject(&mut self, channel_id: u16, delivery_tag: u64, multiple: bool, requeue: bool) {
+        let channel = self.channels.get_mut(&channel_id).unwrap();
+        let deliveries = channel.remove_unacked(delivery_tag, multiple);
+        match requeue {
+            true => channel.requeue(&deliveries),
+            false => self.store_offsets(channel_id, &deliveries).await,
+        }
+    }
+
+    /// Stores the consumer offsets of the partitions, once all the messages up to the offset have been acknowledged.
+    async fn store_offsets(&mut self, channel_id: u16, deliveries: &[Delivery]) {
+        let Some(channel) = self.channels.get_mut(&channel_id) else {
+            return;
+        };
+
+        let mut partitions = deliveries
+            .iter()
+            .map(|delivery| (delivery.consumer.clone(), delivery.partition_id))
+            .collect::<Vec<_>>();
+        partitions.dedup();
+        for (key, partition_id) in partitions {
+            let Some(offset) = channel.get_acknowledged_offset(&key, partition_id) else {
+                continue;
+            };
+            let Some(consumer) = channel.get_consumer(&key) else {
+                continue;
+            };
+            if let Err(error) = consumer
+                .store_offset(&self.session, &self.system, partition_id, offset)
+                .await
+            {
+                warn!(
+                    "Cannot store offset: {offset} for AMQP consumer: {}, partition ID: {partition_id}, error: {error}",
+                    consumer.tag
+                );
+            }
+        }
+    }
+
+    /// Delivers the new messages to the consumers, as long as the prefetch count allows.
+    async fn deliver(&mut self) {
+        let channel_ids = self.channels.keys().copied().collect::<Vec<_>>();
+        for channel_id in channel_ids {
+            if let Err(exception) = self.deliver_to_channel(channel_id).await {
+                if let Err(error) = self.close(channel_id, exception, BASIC, 60).await {
+                    warn!("Cannot close AMQP channel: {channel_id}, error: {error}");
+                }
+            }
+        }
+    }
+
+    async fn deliver_to_channel(&mut self, channel_id: u16) -> Result<(), Exception> {
+        let channel = self.channels.get_mut(&channel_id).unwrap();
+        if channel.closing || channel.paused || channel.consumers.is_empty() {
+            return Ok(());
+        }
+
+        let mut capacity = match channel.prefetch_count {
+            0 => MAX_DELIVERIES,
+            prefetch_count => (prefetch_count as u32).saturating_sub(channel.unacked.len() as u32),
+        };
+        let tags = channel.consumers.keys().cloned().collect::<Vec<_>>();
+        for tag in tags {
+            if capacity == 0 {
+                break;
+            }
+
+            let channel = self.channels.get_mut(&channel_id).unwrap();
+            let consumer = channel.consumers.get_mut(&tag).unwrap();
+            let messages = consumer.poll(&self.session, &self.system, capacity).await?;
+            if messages.is_empty() {
+                continue;
+            }
+
+            capacity -= messages.len() as u32;
+            let no_ack = consumer.no_ack;
+            let (exchange, routing_key) = consumer.queue.get_exchange_and_routing_key();
+            let (exchange, routing_key) = (exchange.to_string(), routing_key.to_string());
+            let mut deliveries = Vec::with_capacity(messages.len());
+            for PolledMessage {
+                partition_id,
+                message,
+            } in messages
+            {
+                let channel = self.channels.get_mut(&channel_id).unwrap();
+                channel.next_delivery_tag += 1;
+                let delivery_tag = channel.next_delivery_tag;
+                let delivery = Delivery {
+                    consumer: ConsumerKey::Consume(tag.clone()),
+                    partition_id,
+                    offset: message.offset,
+                };
+                match no_ack {
+                    true => deliveries.push(delivery),
+                    false => {
+                        channel.unacked.insert(delivery_tag, delivery);
+                    }
+                }
+                self.send_method(
+                    channel_id,
+                    method::basic_deliver(&tag, delivery_tag, false, &exchange, &routing_key),
+                );
+                self.send_content(channel_id, &message);
+            }
+            if no_ack {
+                self.store_offsets(channel_id, &deliveries).await;
+            }
+        }
+        Ok(())
+    }
+
+    fn send_method(&mut self, channel_id: u16, payload: Bytes) {
+        Frame::new(FRAME_METHOD, channel_id, payload).encode(&mut self.buffer);
+    }
+
+    fn send_content(&mut self, channel_id: u16, message: &iggy::models::messages::Message) {
+        let header = ContentHeader::from_message_headers(
+            message.payload.len() as u64,
+            message.headers.as_ref(),
+        );
+        Frame::new(FRAME_HEADER, channel_id, header.encode()).encode(&mut self.buffer);
+        let chunk_size = self.frame_max as usize - FRAME_OVERHEAD;
+        for chunk in message.payload.chunks(chunk_size) {
+            Frame::new(FRAME_BODY, channel_id, Bytes::copy_from_slice(chunk))
+                .encode(&mut self.buffer);
+        }
+    }
+
+    async fn flush(&mut self) -> Result<(), AmqpError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        self.writer.write_all(&self.buffer).await?;
+        self.buffer.clear();
+        self.last_sent = Instant::now();
+        Ok(())
+    }
+}
+
+fn parse_queue(queue: &str) -> Result<Queue, Exception> {
+    Queue::parse(queue).map_err(|_| {
+        Exception::channel(
+            NOT_FOUND,
+            format!("queue: {queue} must be named as <stream>{QUEUE_SEPARATOR}<topic>"),
+        )
+    })
+}
+
+fn server_properties() -> FieldTable {
+    let string = |value: &str| FieldValue::LongString(Bytes::copy_from_slice(value.as_bytes()));
+    vec![
+        ("product".to_string(), string("Iggy")),
+        ("version".to_string(), string(env!("CARGO_PKG_VERSION"))),
+        ("platform".to_string(), string("Rust")),
+        (
+            "capabilities".to_string(),
+            FieldValue::Table(vec![
+                ("publisher_confirms".to_string(), FieldValue::Bool(true)),
+                ("basic.nack".to_string(), FieldValue::Bool(true)),
+                (
+                    "consumer_cancel_notify".to_string(),
+                    FieldValue::Bool(false),
+                ),
+            ]),
+        ),
+    ]
+}
+
+pub(crate) fn handle_error(error: AmqpError) {
+    match error {
+        AmqpError::Io(error) => match error.kind() {
+            ErrorKind::UnexpectedEof => info!("AMQP connection has been closed."),
+            ErrorKind::ConnectionAborted => info!("AMQP connection has been aborted."),
+            ErrorKind::ConnectionReset => info!("AMQP connection has been reset."),
+            _ => error!("AMQP connection has failed: {error}"),
+        },
+        AmqpError::HeartbeatTimeout(reason) => {
+            warn!("AMQP connection has been closed, heartbeat timeout: {reason}.")
+        }
+        error => warn!("AMQP connection has been closed: {error}"),
+    }
+}
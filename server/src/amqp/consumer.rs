@@ -0,0 +1,166 @@
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::hash;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollingMode, PollingStrategy};
+use iggy::models::messages::Message;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The queue is the topic, named as `<stream>/<topic>`, where both the stream and the topic can be specified by ID or name.
+pub const QUEUE_SEPARATOR: char = '/';
+
+#[derive(Debug)]
+pub struct Queue {
+    pub name: String,
+    pub stream_id: Identifier,
+    pub topic_id: Identifier,
+}
+
+impl Queue {
+    pub fn parse(name: &str) -> Result<Self, IggyError> {
+        let Some((stream, topic)) = name.split_once(QUEUE_SEPARATOR) else {
+            return Err(IggyError::InvalidTopicName);
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            stream_id: Identifier::from_str_value(stream)?,
+            topic_id: Identifier::from_str_value(topic)?,
+        })
+    }
+
+    /// Returns the exchange (the stream) and the routing key (the topic) set for the delivered messages.
+    pub fn get_exchange_and_routing_key(&self) -> (&str, &str) {
+        self.name.split_once(QUEUE_SEPARATOR).unwrap()
+    }
+}
+
+/// Consumes the topic behind the queue as the iggy consumer identified by the consumer tag, so the acknowledged offsets
+/// are stored as the regular consumer offsets and the consumption is resumed by the next consumer using the same tag.
+#[derive(Debug)]
+pub struct QueueConsumer {
+    pub tag: String,
+    pub queue: Queue,
+    pub no_ack: bool,
+    consumer_id: u32,
+    /// The next offset to deliver for each partition, unknown until the first poll, which starts after the stored offset.
+    cursors: HashMap<u32, u64>,
+    next_partition_id: u32,
+}
+
+#[derive(Debug)]
+pub struct PolledMessage {
+    pub partition_id: u32,
+    pub message: Arc<Message>,
+}
+
+impl QueueConsumer {
+    pub fn new(tag: &str, queue: Queue, no_ack: bool) -> Self {
+        Self {
+            tag: tag.to_string(),
+            queue,
+            no_ack,
+            consumer_id: hash::calculate_32(tag.as_bytes()),
+            cursors: HashMap::new(),
+            next_partition_id: 1,
+        }
+    }
+
+    /// Polls up to `count` messages, starting from the partition following the last polled one, so that all partitions are consumed fairly.
+    pub async fn poll(
+        &mut self,
+        session: &Session,
+        system: &SharedSystem,
+        count: u32,
+    ) -> Result<Vec<PolledMessage>, IggyError> {
+        let partitions_count = {
+            let system = system.read_async().await;
+            let topic = system
+                .find_topic(session, &self.queue.stream_id, &self.queue.topic_id)
+                .await?;
+            topic.get_partitions_count()
+        };
+
+        let mut polled_messages = Vec::new();
+        for _ in 0..partitions_count {
+            if polled_messages.len() as u32 >= count {
+                break;
+            }
+
+            if self.next_partition_id > partitions_count {
+                self.next_partition_id = 1;
+            }
+            let partition_id = self.next_partition_id;
+            self.next_partition_id += 1;
+            let strategy = match self.cursors.get(&partition_id) {
+                Some(offset) => PollingStrategy::offset(*offset),
+                None => PollingStrategy::next(),
+            };
+            let args = PollingArgs::new(
+                strategy,
+                count - polled_messages.len() as u32,
+                false,
+                None,
+                PollingMode::Consume,
+            );
+            let messages = system
+                .read_async()
+                .await
+                .poll_messages(
+                    session,
+                    PollingConsumer::Consumer(self.consumer_id, partition_id),
+                    &self.queue.stream_id,
+                    &self.queue.topic_id,
+                    args,
+                )
+                .await?
+                .messages;
+            if let Some(message) = messages.last() {
+                self.cursors.insert(partition_id, message.offset + 1);
+            }
+            polled_messages.extend(messages.into_iter().map(|message| PolledMessage {
+                partition_id,
+                message,
+            }));
+        }
+        Ok(polled_messages)
+    }
+
+    /// Moves the cursor back, so that the rejected message (and all the following ones) are delivered again.
+    pub fn rewind(&mut self, partition_id: u32, offset: u64) {
+        if let Some(cursor) = self.cursors.get_mut(&partition_id) {
+            *cursor = (*cursor).min(offset);
+        }
+    }
+
+    /// Returns the offset of the last delivered message in the partition.
+    pub fn get_delivered_offset(&self, partition_id: u32) -> Option<u64> {
+        self.cursors
+            .get(&partition_id)
+            .and_then(|cursor| cursor.checked_sub(1))
+    }
+
+    pub async fn store_offset(
+        &self,
+        session: &Session,
+        system: &SharedSystem,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        system
+            .read_async()
+            .await
+            .store_consumer_offset(
+                session,
+                PollingConsumer::Consumer(self.consumer_id, partition_id),
+                &self.queue.stream_id,
+                &self.queue.topic_id,
+                offset,
+            )
+            .await
+    }
+}
@@ -0,0 +1,266 @@
+use crate::amqp::codec::{CodecError, Decoder, Encoder, FieldTable, FieldValue};
+use crate::amqp::method::BASIC;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const CONTENT_TYPE: u16 = 1 << 15;
+const CONTENT_ENCODING: u16 = 1 << 14;
+const HEADERS: u16 = 1 << 13;
+const DELIVERY_MODE: u16 = 1 << 12;
+const PRIORITY: u16 = 1 << 11;
+const CORRELATION_ID: u16 = 1 << 10;
+const REPLY_TO: u16 = 1 << 9;
+const EXPIRATION: u16 = 1 << 8;
+const MESSAGE_ID: u16 = 1 << 7;
+const TIMESTAMP: u16 = 1 << 6;
+const TYPE: u16 = 1 << 5;
+const USER_ID: u16 = 1 << 4;
+const APP_ID: u16 = 1 << 3;
+const CLUSTER_ID: u16 = 1 << 2;
+
+/// The basic properties kept as the message headers, under the same names.
+const STRING_PROPERTIES: [(u16, &str); 7] = [
+    (CONTENT_TYPE, "content_type"),
+    (CONTENT_ENCODING, "content_encoding"),
+    (CORRELATION_ID, "correlation_id"),
+    (REPLY_TO, "reply_to"),
+    (MESSAGE_ID, "message_id"),
+    (TYPE, "type"),
+    (APP_ID, "app_id"),
+];
+
+/// The content header of the published or delivered message, with the basic properties.
+#[derive(Debug, Default)]
+pub struct ContentHeader {
+    pub body_size: u64,
+    pub properties: Vec<(u16, String)>,
+    pub headers: FieldTable,
+}
+
+impl ContentHeader {
+    pub fn decode(payload: Bytes) -> Result<Self, CodecError> {
+        let mut decoder = Decoder::new(payload);
+        if decoder.read_u16()? != BASIC {
+            return Err(CodecError::Malformed("content header class"));
+        }
+
+        decoder.read_u16()?;
+        let body_size = decoder.read_u64()?;
+        let flags = decoder.read_u16()?;
+        let mut header = ContentHeader {
+            body_size,
+            ..Default::default()
+        };
+        for flag in [
+            CONTENT_TYPE,
+            CONTENT_ENCODING,
+            HEADERS,
+            DELIVERY_MODE,
+            PRIORITY,
+            CORRELATION_ID,
+            REPLY_TO,
+            EXPIRATION,
+            MESSAGE_ID,
+            TIMESTAMP,
+            TYPE,
+            USER_ID,
+            APP_ID,
+            CLUSTER_ID,
+        ] {
+            if flags & flag == 0 {
+                continue;
+            }
+
+            match flag {
+                HEADERS => header.headers = decoder.read_table()?,
+                DELIVERY_MODE | PRIORITY => {
+                    decoder.read_u8()?;
+                }
+                TIMESTAMP => {
+                    decoder.read_u64()?;
+                }
+                flag => {
+                    let value = decoder.read_short_string()?;
+                    if STRING_PROPERTIES
+                        .iter()
+                        .any(|(property, _)| *property == flag)
+                    {
+                        header.properties.push((flag, value));
+                    }
+                }
+            }
+        }
+        Ok(header)
+    }
+
+    pub fn encode(&self) -> Bytes {
+        let mut flags = 0;
+        for (flag, _) in &self.properties {
+            flags |= flag;
+        }
+        if !self.headers.is_empty() {
+            flags |= HEADERS;
+        }
+
+        let mut encoder = Encoder::new();
+        encoder
+            .write_u16(BASIC)
+            .write_u16(0)
+            .write_u64(self.body_size)
+            .write_u16(flags);
+        for flag in [
+            CONTENT_TYPE,
+            CONTENT_ENCODING,
+            HEADERS,
+            CORRELATION_ID,
+            REPLY_TO,
+            MESSAGE_ID,
+            TYPE,
+            APP_ID,
+        ] {
+            if flags & flag == 0 {
+                continue;
+            }
+
+            match flag {
+                HEADERS => encoder.write_table(&self.headers),
+                flag => {
+                    let (_, value) = self
+                        .properties
+                        .iter()
+                        .find(|(property, _)| *property == flag)
+                        .unwrap();
+                    encoder.write_short_string(value)
+                }
+            };
+        }
+        encoder.finish()
+    }
+
+    /// Maps the string properties and the string or boolean headers to the message headers, the other values are skipped.
+    pub fn to_message_headers(&self) -> Result<HashMap<HeaderKey, HeaderValue>, IggyError> {
+        let mut message_headers = HashMap::new();
+        for (flag, value) in &self.properties {
+            if let Some((_, name)) = STRING_PROPERTIES
+                .iter()
+                .find(|(property, _)| property == flag)
+            {
+                message_headers.insert(HeaderKey::new(name)?, HeaderValue::from_str(value)?);
+            }
+        }
+        for (name, value) in &self.headers {
+            let value = match value {
+                FieldValue::LongString(value) => match std::str::from_utf8(value) {
+                    Ok(value) => HeaderValue::from_str(value)?,
+                    Err(_) => HeaderValue::from_raw(value)?,
+                },
+                FieldValue::Bool(value) => HeaderValue::from_bool(*value)?,
+                _ => continue,
+            };
+            message_headers.insert(HeaderKey::new(name)?, value);
+        }
+        Ok(message_headers)
+    }
+
+    /// Creates the content header of the delivered message, the reverse of `to_message_headers` (the numeric headers are skipped).
+    pub fn from_message_headers(
+        body_size: u64,
+        message_headers: Option<&HashMap<HeaderKey, HeaderValue>>,
+    ) -> Self {
+        let mut header = ContentHeader {
+            body_size,
+            ..Default::default()
+        };
+        let Some(message_headers) = message_headers else {
+            return header;
+        };
+
+        for (key, value) in message_headers {
+            let value = match value.kind {
+                HeaderKind::String => match value.as_str() {
+                    Ok(value) => value,
+                    Err(_) => continue,
+                },
+                HeaderKind::Bool => {
+                    if let Ok(value) = value.as_bool() {
+                        header
+                            .headers
+                            .push((key.as_str().to_string(), FieldValue::Bool(value)));
+                    }
+                    continue;
+                }
+                HeaderKind::Raw => {
+                    if let Ok(value) = value.as_raw() {
+                        header.headers.push((
+                            key.as_str().to_string(),
+                            FieldValue::LongString(Bytes::copy_from_slice(value)),
+                        ));
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            match STRING_PROPERTIES
+                .iter()
+                .find(|(_, name)| *name == key.as_str())
+            {
+                Some((flag, _)) => header.properties.push((*flag, value.to_string())),
+                None => header.headers.push((
+                    key.as_str().to_string(),
+                    FieldValue::LongString(Bytes::copy_from_slice(value.as_bytes())),
+                )),
+            }
+        }
+        header.headers.sort_by(|a, b| a.0.cmp(&b.0));
+        header
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_map_properties_and_headers_to_message_headers_and_back() {
+        let header = ContentHeader {
+            body_size: 5,
+            properties: vec![(CONTENT_TYPE, "application/json".to_string())],
+            headers: vec![
+                (
+                    "tenant".to_string(),
+                    FieldValue::LongString(Bytes::from("acme")),
+                ),
+                ("retried".to_string(), FieldValue::Bool(true)),
+            ],
+        };
+        let header = ContentHeader::decode(header.encode()).unwrap();
+        let message_headers = header.to_message_headers().unwrap();
+        assert_eq!(message_headers.len(), 3);
+        assert_eq!(
+            message_headers[&HeaderKey::new("content_type").unwrap()]
+                .as_str()
+                .unwrap(),
+            "application/json"
+        );
+
+        let delivered = ContentHeader::from_message_headers(5, Some(&message_headers));
+        assert_eq!(delivered.body_size, 5);
+        assert_eq!(
+            delivered.properties,
+            vec![(CONTENT_TYPE, "application/json".to_string())]
+        );
+        assert_eq!(
+            delivered.headers,
+            vec![
+                ("retried".to_string(), FieldValue::Bool(true)),
+                (
+                    "tenant".to_string(),
+                    FieldValue::LongString(Bytes::from("acme"))
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use crate::amqp::codec::CodecError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AmqpError {
+    #[error(transparent)]
+    Codec(#[from] CodecError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Connection rejected: {0}")]
+    ConnectionRejected(String),
+    #[error("Connection closed by the server, code: {0}, reason: {1}")]
+    ConnectionClosed(u16, String),
+    #[error("Heartbeat timeout: {0}")]
+    HeartbeatTimeout(String),
+}
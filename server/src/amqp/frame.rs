@@ -0,0 +1,68 @@
+use crate::amqp::codec::CodecError;
+use crate::amqp::error::AmqpError;
+use bytes::{BufMut, Bytes, BytesMut};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+pub const PROTOCOL_HEADER: &[u8; 8] = b"AMQP\x00\x00\x09\x01";
+pub const FRAME_METHOD: u8 = 1;
+pub const FRAME_HEADER: u8 = 2;
+pub const FRAME_BODY: u8 = 3;
+pub const FRAME_HEARTBEAT: u8 = 8;
+pub const FRAME_END: u8 = 0xCE;
+/// The size of the frame type, channel, payload size and the frame end octet.
+pub const FRAME_OVERHEAD: usize = 8;
+/// The minimum frame size, which must be accepted by both the client and the server.
+pub const FRAME_MIN_SIZE: u32 = 4096;
+
+#[derive(Debug)]
+pub struct Frame {
+    pub kind: u8,
+    pub channel: u16,
+    pub payload: Bytes,
+}
+
+impl Frame {
+    pub fn new(kind: u8, channel: u16, payload: Bytes) -> Self {
+        Self {
+            kind,
+            channel,
+            payload,
+        }
+    }
+
+    pub fn heartbeat() -> Self {
+        Self::new(FRAME_HEARTBEAT, 0, Bytes::new())
+    }
+
+    pub fn encode(&self, bytes: &mut BytesMut) {
+        bytes.reserve(self.payload.len() + FRAME_OVERHEAD);
+        bytes.put_u8(self.kind);
+        bytes.put_u16(self.channel);
+        bytes.put_u32(self.payload.len() as u32);
+        bytes.put_slice(&self.payload);
+        bytes.put_u8(FRAME_END);
+    }
+}
+
+/// Reads the next frame, the frames larger than the negotiated maximum size are rejected.
+pub async fn read<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    max_frame_size: u32,
+) -> Result<Frame, AmqpError> {
+    let kind = reader.read_u8().await?;
+    let channel = reader.read_u16().await?;
+    let size = reader.read_u32().await?;
+    if size as usize + FRAME_OVERHEAD > max_frame_size as usize {
+        return Err(AmqpError::Codec(CodecError::Malformed(
+            "frame exceeds the maximum size",
+        )));
+    }
+
+    let mut payload = vec![0; size as usize];
+    reader.read_exact(&mut payload).await?;
+    if reader.read_u8().await? != FRAME_END {
+        return Err(AmqpError::Codec(CodecError::Malformed("invalid frame end")));
+    }
+
+    Ok(Frame::new(kind, channel, Bytes::from(payload)))
+}
@@ -0,0 +1,398 @@
+use crate::amqp::codec::{CodecError, Decoder, Encoder, FieldTable};
+use bytes::Bytes;
+
+pub const CONNECTION: u16 = 10;
+pub const CHANNEL: u16 = 20;
+pub const EXCHANGE: u16 = 40;
+pub const QUEUE: u16 = 50;
+pub const BASIC: u16 = 60;
+pub const CONFIRM: u16 = 85;
+
+pub const REPLY_SUCCESS: u16 = 200;
+pub const ACCESS_REFUSED: u16 = 403;
+pub const NOT_FOUND: u16 = 404;
+pub const PRECONDITION_FAILED: u16 = 406;
+pub const FRAME_ERROR: u16 = 501;
+pub const SYNTAX_ERROR: u16 = 502;
+pub const COMMAND_INVALID: u16 = 503;
+pub const CHANNEL_ERROR: u16 = 504;
+pub const UNEXPECTED_FRAME: u16 = 505;
+pub const NOT_ALLOWED: u16 = 530;
+pub const NOT_IMPLEMENTED: u16 = 540;
+pub const INTERNAL_ERROR: u16 = 541;
+
+/// The methods sent by the clients, the remaining ones are rejected with `NOT_IMPLEMENTED`.
+#[derive(Debug)]
+pub enum Method {
+    ConnectionStartOk {
+        mechanism: String,
+        response: Bytes,
+    },
+    ConnectionTuneOk {
+        channel_max: u16,
+        frame_max: u32,
+        heartbeat: u16,
+    },
+    ConnectionOpen {
+        virtual_host: String,
+    },
+    ConnectionClose {
+        reply_code: u16,
+        reply_text: String,
+    },
+    ConnectionCloseOk,
+    ChannelOpen,
+    ChannelFlow {
+        active: bool,
+    },
+    ChannelClose {
+        reply_code: u16,
+        reply_text: String,
+    },
+    ChannelCloseOk,
+    ExchangeDeclare {
+        exchange: String,
+        no_wait: bool,
+    },
+    QueueDeclare {
+        queue: String,
+        no_wait: bool,
+    },
+    QueueBind {
+        no_wait: bool,
+    },
+    BasicQos {
+        prefetch_count: u16,
+    },
+    BasicConsume {
+        queue: String,
+        consumer_tag: String,
+        no_ack: bool,
+        no_wait: bool,
+    },
+    BasicCancel {
+        consumer_tag: String,
+        no_wait: bool,
+    },
+    BasicPublish {
+        exchange: String,
+        routing_key: String,
+    },
+    BasicGet {
+        queue: String,
+        no_ack: bool,
+    },
+    BasicAck {
+        delivery_tag: u64,
+        multiple: bool,
+    },
+    BasicReject {
+        delivery_tag: u64,
+        requeue: bool,
+    },
+    BasicNack {
+        delivery_tag: u64,
+        multiple: bool,
+        requeue: bool,
+    },
+    BasicRecover {
+        requeue: bool,
+    },
+    ConfirmSelect {
+        no_wait: bool,
+    },
+    Unsupported {
+        class_id: u16,
+        method_id: u16,
+    },
+}
+
+impl Method {
+    pub fn decode(payload: Bytes) -> Result<(u16, u16, Method), CodecError> {
+        let mut decoder = Decoder::new(payload);
+        let class_id = decoder.read_u16()?;
+        let method_id = decoder.read_u16()?;
+        let method = match (class_id, method_id) {
+            (CONNECTION, 11) => {
+                decoder.read_table()?;
+                let mechanism = decoder.read_short_string()?;
+                let response = decoder.read_long_string()?;
+                decoder.read_short_string()?;
+                Method::ConnectionStartOk {
+                    mechanism,
+                    response,
+                }
+            }
+            (CONNECTION, 31) => Method::ConnectionTuneOk {
+                channel_max: decoder.read_u16()?,
+                frame_max: decoder.read_u32()?,
+                heartbeat: decoder.read_u16()?,
+            },
+            (CONNECTION, 40) => Method::ConnectionOpen {
+                virtual_host: decoder.read_short_string()?,
+            },
+            (CONNECTION, 50) => Method::ConnectionClose {
+                reply_code: decoder.read_u16()?,
+                reply_text: decoder.read_short_string()?,
+            },
+            (CONNECTION, 51) => Method::ConnectionCloseOk,
+            (CHANNEL, 10) => Method::ChannelOpen,
+            (CHANNEL, 20) => Method::ChannelFlow {
+                active: decoder.read_bit()?,
+            },
+            (CHANNEL, 40) => Method::ChannelClose {
+                reply_code: decoder.read_u16()?,
+                reply_text: decoder.read_short_string()?,
+            },
+            (CHANNEL, 41) => Method::ChannelCloseOk,
+            (EXCHANGE, 10) => {
+                decoder.read_u16()?;
+                let exchange = decoder.read_short_string()?;
+                decoder.read_short_string()?;
+                for _ in 0..4 {
+                    decoder.read_bit()?;
+                }
+                Method::ExchangeDeclare {
+                    exchange,
+                    no_wait: decoder.read_bit()?,
+                }
+            }
+            (QUEUE, 10) => {
+                decoder.read_u16()?;
+                let queue = decoder.read_short_string()?;
+                for _ in 0..4 {
+                    decoder.read_bit()?;
+                }
+                Method::QueueDeclare {
+                    queue,
+                    no_wait: decoder.read_bit()?,
+                }
+            }
+            (QUEUE, 20) => {
+                decoder.read_u16()?;
+                for _ in 0..3 {
+                    decoder.read_short_string()?;
+                }
+                Method::QueueBind {
+                    no_wait: decoder.read_bit()?,
+                }
+            }
+            (BASIC, 10) => {
+                decoder.read_u32()?;
+                Method::BasicQos {
+                    prefetch_count: decoder.read_u16()?,
+                }
+            }
+            (BASIC, 20) => {
+                decoder.read_u16()?;
+                let queue = decoder.read_short_string()?;
+                let consumer_tag = decoder.read_short_string()?;
+                decoder.read_bit()?;
+                let no_ack = decoder.read_bit()?;
+                decoder.read_bit()?;
+                Method::BasicConsume {
+                    queue,
+                    consumer_tag,
+                    no_ack,
+                    no_wait: decoder.read_bit()?,
+                }
+            }
+            (BASIC, 30) => Method::BasicCancel {
+                consumer_tag: decoder.read_short_string()?,
+                no_wait: decoder.read_bit()?,
+            },
+            (BASIC, 40) => {
+                decoder.read_u16()?;
+                Method::BasicPublish {
+                    exchange: decoder.read_short_string()?,
+                    routing_key: decoder.read_short_string()?,
+                }
+            }
+            (BASIC, 70) => {
+                decoder.read_u16()?;
+                Method::BasicGet {
+                    queue: decoder.read_short_string()?,
+                    no_ack: decoder.read_bit()?,
+                }
+            }
+            (BASIC, 80) => Method::BasicAck {
+                delivery_tag: decoder.read_u64()?,
+                multiple: decoder.read_bit()?,
+            },
+            (BASIC, 90) => Method::BasicReject {
+                delivery_tag: decoder.read_u64()?,
+                requeue: decoder.read_bit()?,
+            },
+            (BASIC, 110) => Method::BasicRecover {
+                requeue: decoder.read_bit()?,
+            },
+            (BASIC, 120) => Method::BasicNack {
+                delivery_tag: decoder.read_u64()?,
+                multiple: decoder.read_bit()?,
+                requeue: decoder.read_bit()?,
+            },
+            (CONFIRM, 10) => Method::ConfirmSelect {
+                no_wait: decoder.read_bit()?,
+            },
+            (class_id, method_id) => Method::Unsupported {
+                class_id,
+                method_id,
+            },
+        };
+        Ok((class_id, method_id, method))
+    }
+
+    /// Returns `true` if the method is followed by the content header and body frames.
+    pub fn has_content(&self) -> bool {
+        matches!(self, Method::BasicPublish { .. })
+    }
+}
+
+fn method(class_id: u16, method_id: u16) -> Encoder {
+    let mut encoder = Encoder::new();
+    encoder.write_u16(class_id).write_u16(method_id);
+    encoder
+}
+
+pub fn connection_start(server_properties: &FieldTable, mechanisms: &str) -> Bytes {
+    method(CONNECTION, 10)
+        .write_u8(0)
+        .write_u8(9)
+        .write_table(server_properties)
+        .write_long_string(mechanisms.as_bytes())
+        .write_long_string(b"en_US")
+        .finish()
+}
+
+pub fn connection_tune(channel_max: u16, frame_max: u32, heartbeat: u16) -> Bytes {
+    method(CONNECTION, 30)
+        .write_u16(channel_max)
+        .write_u32(frame_max)
+        .write_u16(heartbeat)
+        .finish()
+}
+
+pub fn connection_open_ok() -> Bytes {
+    method(CONNECTION, 41).write_short_string("").finish()
+}
+
+pub fn connection_close(reply_code: u16, reply_text: &str, class_id: u16, method_id: u16) -> Bytes {
+    method(CONNECTION, 50)
+        .write_u16(reply_code)
+        .write_short_string(reply_text)
+        .write_u16(class_id)
+        .write_u16(method_id)
+        .finish()
+}
+
+pub fn connection_close_ok() -> Bytes {
+    method(CONNECTION, 51).finish()
+}
+
+pub fn channel_open_ok() -> Bytes {
+    method(CHANNEL, 11).write_long_string(b"").finish()
+}
+
+pub fn channel_flow_ok(active: bool) -> Bytes {
+    method(CHANNEL, 21).write_bit(active).finish()
+}
+
+pub fn channel_close(reply_code: u16, reply_text: &str, class_id: u16, method_id: u16) -> Bytes {
+    method(CHANNEL, 40)
+        .write_u16(reply_code)
+        .write_short_string(reply_text)
+        .write_u16(class_id)
+        .write_u16(method_id)
+        .finish()
+}
+
+pub fn channel_close_ok() -> Bytes {
+    method(CHANNEL, 41).finish()
+}
+
+pub fn exchange_declare_ok() -> Bytes {
+    method(EXCHANGE, 11).finish()
+}
+
+pub fn queue_declare_ok(queue: &str, message_count: u32, consumer_count: u32) -> Bytes {
+    method(QUEUE, 11)
+        .write_short_string(queue)
+        .write_u32(message_count)
+        .write_u32(consumer_count)
+        .finish()
+}
+
+pub fn queue_bind_ok() -> Bytes {
+    method(QUEUE, 21).finish()
+}
+
+pub fn basic_qos_ok() -> Bytes {
+    method(BASIC, 11).finish()
+}
+
+pub fn basic_consume_ok(consumer_tag: &str) -> Bytes {
+    method(BASIC, 21).write_short_string(consumer_tag).finish()
+}
+
+pub fn basic_cancel_ok(consumer_tag: &str) -> Bytes {
+    method(BASIC, 31).write_short_string(consumer_tag).finish()
+}
+
+pub fn basic_deliver(
+    consumer_tag: &str,
+    delivery_tag: u64,
+    redelivered: bool,
+    exchange: &str,
+    routing_key: &str,
+) -> Bytes {
+    method(BASIC, 60)
+        .write_short_string(consumer_tag)
+        .write_u64(delivery_tag)
+        .write_bit(redelivered)
+        .write_short_string(exchange)
+        .write_short_string(routing_key)
+        .finish()
+}
+
+pub fn basic_get_ok(
+    delivery_tag: u64,
+    redelivered: bool,
+    exchange: &str,
+    routing_key: &str,
+    message_count: u32,
+) -> Bytes {
+    method(BASIC, 71)
+        .write_u64(delivery_tag)
+        .write_bit(redelivered)
+        .write_short_string(exchange)
+        .write_short_string(routing_key)
+        .write_u32(message_count)
+        .finish()
+}
+
+pub fn basic_get_empty() -> Bytes {
+    method(BASIC, 72).write_short_string("").finish()
+}
+
+pub fn basic_ack(delivery_tag: u64, multiple: bool) -> Bytes {
+    method(BASIC, 80)
+        .write_u64(delivery_tag)
+        .write_bit(multiple)
+        .finish()
+}
+
+pub fn basic_nack(delivery_tag: u64, multiple: bool, requeue: bool) -> Bytes {
+    method(BASIC, 120)
+        .write_u64(delivery_tag)
+        .write_bit(multiple)
+        .write_bit(requeue)
+        .finish()
+}
+
+pub fn basic_recover_ok() -> Bytes {
+    method(BASIC, 111).finish()
+}
+
+pub fn confirm_select_ok() -> Bytes {
+    method(CONFIRM, 11).finish()
+}
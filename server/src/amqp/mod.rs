@@ -0,0 +1,8 @@
+pub mod amqp_server;
+pub mod codec;
+pub mod connection_handler;
+pub mod consumer;
+pub mod content;
+pub mod error;
+pub mod frame;
+pub mod method;
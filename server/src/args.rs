@@ -1,8 +1,18 @@
 use clap::Parser;
+use iggy::utils::duration::IggyDuration;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
     #[arg(short, long, default_value = "file")]
     pub config_provider: String,
+
+    /// Applies the pending storage format migrations and exits without starting the server.
+    #[arg(long, default_value_t = false)]
+    pub migrate: bool,
+
+    /// On shutdown, stops accepting the new connections and waits up to this duration (e.g. "30s")
+    /// for the connected clients to disconnect before flushing the data, "0" shuts down immediately.
+    #[arg(long, default_value = "0")]
+    pub drain_timeout: IggyDuration,
 }
@@ -1,3 +1,7 @@
+use crate::binary::handlers::connectors::{
+    create_connector_handler, delete_connector_handler, get_connector_handler,
+    get_connectors_handler, restart_connector_handler,
+};
 use crate::binary::handlers::consumer_groups::{
     create_consumer_group_handler, delete_consumer_group_handler, get_consumer_group_handler,
     get_consumer_groups_handler, join_consumer_group_handler, leave_consumer_group_handler,
@@ -9,20 +13,37 @@ use crate::binary::handlers::personal_access_tokens::{
     create_personal_access_token_handler, delete_personal_access_token_handler,
     get_personal_access_tokens_handler, login_with_personal_access_token_handler,
 };
+use crate::binary::handlers::schemas::{
+    get_schema_handler, get_schema_versions_handler, register_schema_handler,
+    update_schema_compatibility_handler,
+};
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
+use crate::binary::handlers::tenants::{
+    add_tenant_user_handler, create_tenant_handler, delete_tenant_handler, get_tenant_handler,
+    get_tenants_handler, remove_tenant_user_handler, update_tenant_handler,
+};
 use crate::binary::handlers::topics::*;
+use crate::binary::handlers::user_groups::{
+    add_user_group_member_handler, create_user_group_handler, delete_user_group_handler,
+    get_user_group_handler, get_user_groups_handler, remove_user_group_member_handler,
+    update_user_group_permissions_handler,
+};
 use crate::binary::handlers::users::{
-    change_password_handler, create_user_handler, delete_user_handler, get_user_handler,
-    get_users_handler, login_user_handler, logout_user_handler, update_permissions_handler,
-    update_user_handler,
+    change_password_handler, check_permission_handler, create_user_handler, delete_user_handler,
+    get_user_handler, get_users_handler, login_user_handler, logout_user_handler,
+    update_permissions_handler, update_user_expiry_handler, update_user_handler,
 };
 use crate::binary::sender::Sender;
+use crate::streaming::events::event::ServerEvent;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::systems::system_topics::AuditRecord;
 use iggy::command::Command;
 use iggy::error::IggyError;
-use tracing::{debug, error};
+use iggy::utils::timestamp::IggyTimestamp;
+use std::time::Instant;
+use tracing::{debug, error, warn};
 
 pub async fn handle(
     command: &Command,
@@ -30,19 +51,67 @@ pub async fn handle(
     session: &Session,
     system: SharedSystem,
 ) -> Result<(), IggyError> {
-    match try_handle(command, sender, session, &system).await {
+    let started_at = Instant::now();
+    let user_id = session.get_user_id();
+    let result = try_handle(command, sender, session, &system).await;
+    let elapsed = started_at.elapsed();
+    if result.is_ok() && session.is_authenticated() {
+        if let Err(error) = system.read().record_user_activity(user_id).await {
+            warn!("Cannot record the activity of the user, session: {session}, error: {error}");
+        }
+    }
+
+    {
+        let system = system.read();
+        system
+            .metrics
+            .record_command_latency(command.name(), elapsed);
+        let threshold = system.config.logging.slow_request_threshold;
+        if !threshold.is_zero() && elapsed >= threshold.get_duration() {
+            warn!(
+                "Slow command '{}' took {} ms, user ID: {}, resources: {}, session: {session}.",
+                command.name(),
+                elapsed.as_millis(),
+                session.get_user_id(),
+                describe_resources(command)
+            );
+        }
+    }
+
+    if is_audited(command) {
+        system.read().audit(AuditRecord {
+            timestamp: IggyTimestamp::now().to_micros(),
+            // The user is known only after the login, and no longer after the logout.
+            user_id: match session.get_user_id() {
+                0 => user_id,
+                user_id => user_id,
+            },
+            client_id: session.client_id,
+            address: session.ip_address.to_string(),
+            command: command.name().to_string(),
+            succeeded: result.is_ok(),
+            error_code: result.as_ref().err().map(|error| error.as_code()),
+        });
+    }
+
+    match result {
         Ok(_) => {
             debug!("Command was handled successfully, session: {session}.");
             Ok(())
         }
         Err(error) => {
             error!("Command was not handled successfully, session: {session}, error: {error}");
+            system.read().publish_event(ServerEvent::Error {
+                source: command.name().to_string(),
+                code: error.as_code(),
+                reason: error.to_string(),
+            });
             sender.send_error_response(error).await
         }
     }
 }
 
-async fn try_handle(
+pub(crate) async fn try_handle(
     command: &Command,
     sender: &mut dyn Sender,
     session: &Session,
@@ -51,9 +120,15 @@ async fn try_handle(
     debug!("Handling command '{command}', session: {session}...");
     match command {
         Command::Ping(command) => ping_handler::handle(command, sender, session).await,
+        Command::Heartbeat(command) => heartbeat_handler::handle(command, sender, session).await,
+        Command::Hello(command) => hello_handler::handle(command, sender, session, system).await,
+        Command::Batch(command) => batch_handler::handle(command, sender, session, system).await,
         Command::GetStats(command) => {
             get_stats_handler::handle(command, sender, session, system).await
         }
+        Command::GetMetadata(command) => {
+            get_metadata_handler::handle(command, sender, session, system).await
+        }
         Command::GetMe(command) => get_me_handler::handle(command, sender, session, system).await,
         Command::GetClient(command) => {
             get_client_handler::handle(command, sender, session, system).await
@@ -61,6 +136,9 @@ async fn try_handle(
         Command::GetClients(command) => {
             get_clients_handler::handle(command, sender, session, system).await
         }
+        Command::NegotiateCompression(command) => {
+            negotiate_compression_handler::handle(command, sender, session, system).await
+        }
         Command::GetUser(command) => {
             get_user_handler::handle(command, sender, session, system).await
         }
@@ -79,6 +157,9 @@ async fn try_handle(
         Command::UpdatePermissions(command) => {
             update_permissions_handler::handle(command, sender, session, system).await
         }
+        Command::UpdateUserExpiry(command) => {
+            update_user_expiry_handler::handle(command, sender, session, system).await
+        }
         Command::ChangePassword(command) => {
             change_password_handler::handle(command, sender, session, system).await
         }
@@ -88,6 +169,9 @@ async fn try_handle(
         Command::LogoutUser(command) => {
             logout_user_handler::handle(command, sender, session, system).await
         }
+        Command::CheckPermission(command) => {
+            check_permission_handler::handle(command, sender, session, system).await
+        }
         Command::GetPersonalAccessTokens(command) => {
             get_personal_access_tokens_handler::handle(command, sender, session, system).await
         }
@@ -106,6 +190,18 @@ async fn try_handle(
         Command::PollMessages(command) => {
             poll_messages_handler::handle(command, sender, session, system).await
         }
+        Command::QueryMessages(command) => {
+            query_messages_handler::handle(command, sender, session, system).await
+        }
+        Command::CreateFetchSession(command) => {
+            create_fetch_session_handler::handle(command, sender, session, system).await
+        }
+        Command::FetchMessages(command) => {
+            fetch_messages_handler::handle(command, sender, session, system).await
+        }
+        Command::DeleteFetchSession(command) => {
+            delete_fetch_session_handler::handle(command, sender, session, system).await
+        }
         Command::GetConsumerOffset(command) => {
             get_consumer_offset_handler::handle(command, sender, session, system).await
         }
@@ -130,6 +226,9 @@ async fn try_handle(
         Command::PurgeStream(command) => {
             purge_stream_handler::handle(command, sender, session, system).await
         }
+        Command::UndeleteStream(command) => {
+            undelete_stream_handler::handle(command, sender, session, system).await
+        }
         Command::GetTopic(command) => {
             get_topic_handler::handle(command, sender, session, system).await
         }
@@ -148,6 +247,21 @@ async fn try_handle(
         Command::PurgeTopic(command) => {
             purge_topic_handler::handle(command, sender, session, system).await
         }
+        Command::UndeleteTopic(command) => {
+            undelete_topic_handler::handle(command, sender, session, system).await
+        }
+        Command::FreezeTopic(command) => {
+            freeze_topic_handler::handle(command, sender, session, system).await
+        }
+        Command::UnfreezeTopic(command) => {
+            unfreeze_topic_handler::handle(command, sender, session, system).await
+        }
+        Command::GetPartitionDetails(command) => {
+            get_partition_details_handler::handle(command, sender, session, system).await
+        }
+        Command::GetPartitionEndOffsets(command) => {
+            get_partition_end_offsets_handler::handle(command, sender, session, system).await
+        }
         Command::CreatePartitions(command) => {
             create_partitions_handler::handle(command, sender, session, system).await
         }
@@ -172,5 +286,151 @@ async fn try_handle(
         Command::LeaveConsumerGroup(command) => {
             leave_consumer_group_handler::handle(command, sender, session, system).await
         }
+        Command::GetSchema(command) => {
+            get_schema_handler::handle(command, sender, session, system).await
+        }
+        Command::GetSchemaVersions(command) => {
+            get_schema_versions_handler::handle(command, sender, session, system).await
+        }
+        Command::RegisterSchema(command) => {
+            register_schema_handler::handle(command, sender, session, system).await
+        }
+        Command::UpdateSchemaCompatibility(command) => {
+            update_schema_compatibility_handler::handle(command, sender, session, system).await
+        }
+        Command::GetTenant(command) => {
+            get_tenant_handler::handle(command, sender, session, system).await
+        }
+        Command::GetTenants(command) => {
+            get_tenants_handler::handle(command, sender, session, system).await
+        }
+        Command::CreateTenant(command) => {
+            create_tenant_handler::handle(command, sender, session, system).await
+        }
+        Command::UpdateTenant(command) => {
+            update_tenant_handler::handle(command, sender, session, system).await
+        }
+        Command::DeleteTenant(command) => {
+            delete_tenant_handler::handle(command, sender, session, system).await
+        }
+        Command::AddTenantUser(command) => {
+            add_tenant_user_handler::handle(command, sender, session, system).await
+        }
+        Command::RemoveTenantUser(command) => {
+            remove_tenant_user_handler::handle(command, sender, session, system).await
+        }
+        Command::GetUserGroup(command) => {
+            get_user_group_handler::handle(command, sender, session, system).await
+        }
+        Command::GetUserGroups(command) => {
+            get_user_groups_handler::handle(command, sender, session, system).await
+        }
+        Command::CreateUserGroup(command) => {
+            create_user_group_handler::handle(command, sender, session, system).await
+        }
+        Command::UpdateUserGroupPermissions(command) => {
+            update_user_group_permissions_handler::handle(command, sender, session, system).await
+        }
+        Command::DeleteUserGroup(command) => {
+            delete_user_group_handler::handle(command, sender, session, system).await
+        }
+        Command::AddUserGroupMember(command) => {
+            add_user_group_member_handler::handle(command, sender, session, system).await
+        }
+        Command::RemoveUserGroupMember(command) => {
+            remove_user_group_member_handler::handle(command, sender, session, system).await
+        }
+        Command::GetConnector(command) => {
+            get_connector_handler::handle(command, sender, session, system).await
+        }
+        Command::GetConnectors(command) => {
+            get_connectors_handler::handle(command, sender, session, system).await
+        }
+        Command::CreateConnector(command) => {
+            create_connector_handler::handle(command, sender, session, system).await
+        }
+        Command::RestartConnector(command) => {
+            restart_connector_handler::handle(command, sender, session, system).await
+        }
+        Command::DeleteConnector(command) => {
+            delete_connector_handler::handle(command, sender, session, system).await
+        }
     }
 }
+
+/// Describes the resources targeted by the command, without its payload, which could contain the messages or credentials.
+fn describe_resources(command: &Command) -> String {
+    match command {
+        Command::SendMessages(command) => format!(
+            "stream ID: {}, topic ID: {}, messages: {}",
+            command.stream_id,
+            command.topic_id,
+            command.messages.len()
+        ),
+        Command::PollMessages(command) => format!(
+            "stream ID: {}, topic ID: {}, partition ID: {}, count: {}",
+            command.stream_id,
+            command.topic_id,
+            command.partition_id.unwrap_or_default(),
+            command.count
+        ),
+        Command::StoreConsumerOffset(command) => format!(
+            "stream ID: {}, topic ID: {}, partition ID: {}",
+            command.stream_id,
+            command.topic_id,
+            command.partition_id.unwrap_or_default()
+        ),
+        Command::GetConsumerOffset(command) => format!(
+            "stream ID: {}, topic ID: {}, partition ID: {}",
+            command.stream_id,
+            command.topic_id,
+            command.partition_id.unwrap_or_default()
+        ),
+        _ => "none".to_string(),
+    }
+}
+
+/// Returns `true` if the command changes the server state (or the session), the reads and the data plane commands are skipped.
+fn is_audited(command: &Command) -> bool {
+    !matches!(
+        command,
+        Command::Ping(_)
+            | Command::Heartbeat(_)
+            | Command::Hello(_)
+            | Command::Batch(_)
+            | Command::GetStats(_)
+            | Command::GetMetadata(_)
+            | Command::GetMe(_)
+            | Command::GetClient(_)
+            | Command::GetClients(_)
+            | Command::NegotiateCompression(_)
+            | Command::GetUser(_)
+            | Command::GetUsers(_)
+            | Command::CheckPermission(_)
+            | Command::GetPersonalAccessTokens(_)
+            | Command::GetStream(_)
+            | Command::GetStreams(_)
+            | Command::GetTopic(_)
+            | Command::GetTopics(_)
+            | Command::GetPartitionDetails(_)
+            | Command::GetPartitionEndOffsets(_)
+            | Command::PollMessages(_)
+            | Command::QueryMessages(_)
+            | Command::FetchMessages(_)
+            | Command::SendMessages(_)
+            | Command::StoreConsumerOffset(_)
+            | Command::GetConsumerOffset(_)
+            | Command::GetConsumerGroup(_)
+            | Command::GetConsumerGroups(_)
+            | Command::JoinConsumerGroup(_)
+            | Command::LeaveConsumerGroup(_)
+            | Command::GetSchema(_)
+            | Command::GetSchemaVersions(_)
+            | Command::GetTenant(_)
+            | Command::GetTenants(_)
+            | Command::GetUserGroup(_)
+            | Command::GetUserGroups(_)
+            | Command::GetConnector(_)
+            | Command::GetConnectors(_)
+    )
+}
@@ -0,0 +1,139 @@
+use crate::configs::server::BackpressureConfig;
+use iggy::error::IggyError;
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Limits the number of the commands processed concurrently and the total size of the requests buffered in memory for a single connection,
+/// so that one client sending huge batches cannot starve the other connections of memory and executor time.
+/// The commands exceeding the limits are rejected immediately with the throttle error instead of waiting for the resources to be released.
+#[derive(Debug, Clone)]
+pub(crate) struct ConnectionLimiter {
+    limits: Option<Limits>,
+}
+
+#[derive(Debug, Clone)]
+struct Limits {
+    max_in_flight_commands: u32,
+    in_flight_commands: Arc<Semaphore>,
+    buffered_bytes: Arc<Semaphore>,
+}
+
+/// The resources acquired for a single command, released when dropped.
+#[derive(Debug)]
+pub(crate) struct CommandPermit {
+    _in_flight_command: Option<OwnedSemaphorePermit>,
+    _buffered_bytes: Option<OwnedSemaphorePermit>,
+}
+
+impl ConnectionLimiter {
+    pub fn new(config: &BackpressureConfig) -> Self {
+        if !config.enabled {
+            return Self { limits: None };
+        }
+
+        let max_buffered_size = config.max_buffered_size.as_bytes_u64() as u32;
+        Self {
+            limits: Some(Limits {
+                max_in_flight_commands: config.max_in_flight_commands,
+                in_flight_commands: Arc::new(Semaphore::new(
+                    config.max_in_flight_commands as usize,
+                )),
+                buffered_bytes: Arc::new(Semaphore::new(max_buffered_size as usize)),
+            }),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.limits.is_some()
+    }
+
+    /// Acquires the in-flight command slot and the buffer for the request of the provided size,
+    /// or returns the throttle error if any of the limits has been reached.
+    pub fn try_acquire(&self, request_size: u32) -> Result<CommandPermit, IggyError> {
+        let Some(limits) = &self.limits else {
+            return Ok(CommandPermit {
+                _in_flight_command: None,
+                _buffered_bytes: None,
+            });
+        };
+
+        let in_flight_command = limits
+            .in_flight_commands
+            .clone()
+            .try_acquire_owned()
+            .map_err(|_| IggyError::TooManyInFlightCommands(limits.max_in_flight_commands))?;
+        let buffered_bytes = limits
+            .buffered_bytes
+            .clone()
+            .try_acquire_many_owned(request_size)
+            .map_err(|_| {
+                IggyError::BufferedSizeLimitExceeded(
+                    request_size,
+                    limits.buffered_bytes.available_permits() as u32,
+                )
+            })?;
+        Ok(CommandPermit {
+            _in_flight_command: Some(in_flight_command),
+            _buffered_bytes: Some(buffered_bytes),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(max_in_flight_commands: u32, max_buffered_size: u64) -> ConnectionLimiter {
+        ConnectionLimiter::new(&BackpressureConfig {
+            enabled: true,
+            max_in_flight_commands,
+            max_buffered_size: max_buffered_size.into(),
+        })
+    }
+
+    #[test]
+    fn in_flight_commands_should_be_limited_until_permit_is_released() {
+        let limiter = limiter(2, 1000);
+        let first = limiter.try_acquire(10).unwrap();
+        let _second = limiter.try_acquire(10).unwrap();
+
+        assert!(matches!(
+            limiter.try_acquire(10),
+            Err(IggyError::TooManyInFlightCommands(2))
+        ));
+
+        drop(first);
+        assert!(limiter.try_acquire(10).is_ok());
+    }
+
+    #[test]
+    fn buffered_size_should_be_limited_across_in_flight_commands() {
+        let limiter = limiter(10, 100);
+        let first = limiter.try_acquire(60).unwrap();
+
+        assert!(matches!(
+            limiter.try_acquire(50),
+            Err(IggyError::BufferedSizeLimitExceeded(50, 40))
+        ));
+        assert!(matches!(
+            limiter.try_acquire(101),
+            Err(IggyError::BufferedSizeLimitExceeded(101, 40))
+        ));
+
+        drop(first);
+        assert!(limiter.try_acquire(100).is_ok());
+    }
+
+    #[test]
+    fn disabled_limiter_should_accept_any_request() {
+        let limiter = ConnectionLimiter::new(&BackpressureConfig {
+            enabled: false,
+            max_in_flight_commands: 1,
+            max_buffered_size: 1u64.into(),
+        });
+        let _first = limiter.try_acquire(u32::MAX).unwrap();
+
+        assert!(limiter.try_acquire(u32::MAX).is_ok());
+        assert!(!limiter.is_enabled());
+    }
+}
@@ -0,0 +1,19 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::connectors::create_connector::CreateConnector;
+use iggy::error::IggyError;
+use tracing::debug;
+
+pub async fn handle(
+    command: &CreateConnector,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let runtime = system.read().manage_connectors_runtime(session)?;
+    runtime.create_connector(system, command.into()).await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::connectors::get_connector::GetConnector;
+use iggy::error::IggyError;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetConnector,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let runtime = system.read().get_connectors_runtime(session)?;
+    let connector = runtime.get_connector(&command.name).await?;
+    let bytes = mapper::map_connector(&connector);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::connectors::get_connectors::GetConnectors;
+use iggy::error::IggyError;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetConnectors,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let runtime = system.read().get_connectors_runtime(session)?;
+    let connectors = runtime.get_connectors().await;
+    let bytes = mapper::map_connectors(&connectors);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
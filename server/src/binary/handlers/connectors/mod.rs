@@ -0,0 +1,5 @@
+pub mod create_connector_handler;
+pub mod delete_connector_handler;
+pub mod get_connector_handler;
+pub mod get_connectors_handler;
+pub mod restart_connector_handler;
@@ -0,0 +1,19 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::connectors::restart_connector::RestartConnector;
+use iggy::error::IggyError;
+use tracing::debug;
+
+pub async fn handle(
+    command: &RestartConnector,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let runtime = system.read().manage_connectors_runtime(session)?;
+    runtime.restart_connector(system, &command.name).await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -15,12 +15,14 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let system = system.read();
-    let consumer_group = system.get_consumer_group(
-        session,
-        &command.stream_id,
-        &command.topic_id,
-        &command.consumer_group_id,
-    )?;
+    let consumer_group = system
+        .get_consumer_group(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            &command.consumer_group_id,
+        )
+        .await?;
     let consumer_group = consumer_group.read().await;
     let consumer_group = mapper::map_consumer_group(&consumer_group).await;
     sender.send_ok_response(&consumer_group).await?;
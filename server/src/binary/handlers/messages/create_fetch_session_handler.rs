@@ -0,0 +1,30 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::messages::create_fetch_session::CreateFetchSession;
+use tracing::debug;
+
+pub async fn handle(
+    command: &CreateFetchSession,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let fetch_session = system
+        .create_fetch_session(
+            session,
+            &command.consumer,
+            &command.partitions,
+            command.count,
+            command.auto_commit,
+        )
+        .await?;
+    let fetch_session = mapper::map_fetch_session(&fetch_session);
+    sender.send_ok_response(&fetch_session).await?;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::messages::delete_fetch_session::DeleteFetchSession;
+use tracing::debug;
+
+pub async fn handle(
+    command: &DeleteFetchSession,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    system
+        .delete_fetch_session(session, command.session_id)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
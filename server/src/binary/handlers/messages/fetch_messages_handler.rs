@@ -0,0 +1,24 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::messages::fetch_messages::FetchMessages;
+use tracing::debug;
+
+pub async fn handle(
+    command: &FetchMessages,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let messages = system
+        .fetch_messages(session, command.session_id, command.epoch)
+        .await?;
+    let messages = mapper::map_fetched_messages(&messages);
+    sender.send_ok_response(&messages).await?;
+    Ok(())
+}
@@ -1,2 +1,6 @@
+pub mod create_fetch_session_handler;
+pub mod delete_fetch_session_handler;
+pub mod fetch_messages_handler;
 pub mod poll_messages_handler;
+pub mod query_messages_handler;
 pub mod send_messages_handler;
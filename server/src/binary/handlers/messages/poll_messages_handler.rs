@@ -7,6 +7,8 @@ use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use iggy::error::IggyError;
 use iggy::messages::poll_messages::PollMessages;
+use iggy::utils::duration::IggyDuration;
+use std::time::Duration;
 use tracing::debug;
 
 pub async fn handle(
@@ -18,14 +20,23 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let consumer =
         PollingConsumer::from_consumer(&command.consumer, session.client_id, command.partition_id);
-    let system = system.read();
     let messages = system
         .poll_messages(
             session,
             consumer,
             &command.stream_id,
             &command.topic_id,
-            PollingArgs::new(command.strategy, command.count, command.auto_commit),
+            PollingArgs::new(
+                command.strategy,
+                command.count,
+                command.auto_commit,
+                command.filter.clone(),
+                command.mode,
+            )
+            .with_max_bytes(command.max_bytes),
+            command
+                .max_wait_ms
+                .map(|max_wait_ms| IggyDuration::new(Duration::from_millis(max_wait_ms as u64))),
         )
         .await?;
     let messages = mapper::map_polled_messages(&messages);
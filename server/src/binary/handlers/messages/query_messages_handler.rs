@@ -0,0 +1,22 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::messages::query_messages::QueryMessages;
+use tracing::debug;
+
+pub async fn handle(
+    command: &QueryMessages,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let result = system.query_messages(session, &command.query).await?;
+    let result = mapper::map_query_result(&result);
+    sender.send_ok_response(&result).await?;
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::binary::mapper;
 use crate::binary::sender::Sender;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
@@ -13,16 +14,27 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
-    let system = system.read();
     system
+        .auto_create_topic(session, &command.stream_id, &command.topic_id)
+        .await?;
+    let system = system.read();
+    let receipt = system
         .append_messages(
             session,
             &command.stream_id,
             &command.topic_id,
             &command.partitioning,
             &command.messages,
+            command.acks.unwrap_or_default(),
         )
         .await?;
-    sender.send_empty_ok_response().await?;
+    // There's no receipt for the messages sent without acks, as the response is sent before they're appended.
+    match receipt {
+        Some(receipt) => {
+            let bytes = mapper::map_send_messages_receipt(&receipt);
+            sender.send_ok_response(&bytes).await?;
+        }
+        None => sender.send_empty_ok_response().await?,
+    }
     Ok(())
 }
@@ -1,9 +1,13 @@
+pub mod connectors;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod streams;
 pub mod system;
+pub mod tenants;
 pub mod topics;
+pub mod user_groups;
 pub mod users;
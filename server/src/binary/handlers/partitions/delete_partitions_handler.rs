@@ -13,13 +13,14 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
-    let mut system = system.write();
+    let system = system.read();
     system
         .delete_partitions(
             session,
             &command.stream_id,
             &command.topic_id,
             command.partitions_count,
+            command.mode,
         )
         .await?;
     sender.send_empty_ok_response().await?;
@@ -0,0 +1,30 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::partitions::get_partition_details::GetPartitionDetails;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetPartitionDetails,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let partition = system
+        .find_partition(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+        )
+        .await?;
+    let partition = partition.read().await;
+    let partition = mapper::map_partition(&partition).await;
+    sender.send_ok_response(&partition).await?;
+    Ok(())
+}
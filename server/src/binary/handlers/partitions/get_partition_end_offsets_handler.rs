@@ -0,0 +1,24 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::partitions::get_partition_end_offsets::GetPartitionEndOffsets;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetPartitionEndOffsets,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let end_offsets = system
+        .get_partition_end_offsets(session, &command.stream_id, &command.topic_id)
+        .await?;
+    let end_offsets = mapper::map_partition_end_offsets(&end_offsets);
+    sender.send_ok_response(&end_offsets).await?;
+    Ok(())
+}
@@ -1,2 +1,4 @@
 pub mod create_partitions_handler;
 pub mod delete_partitions_handler;
+pub mod get_partition_details_handler;
+pub mod get_partition_end_offsets_handler;
@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::schemas::get_schema_versions::GetSchemaVersions;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetSchemaVersions,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let schemas = system.get_schema_versions(session, &command.subject)?;
+    let bytes = mapper::map_schemas(&schemas);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
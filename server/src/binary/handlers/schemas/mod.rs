@@ -0,0 +1,4 @@
+pub mod get_schema_handler;
+pub mod get_schema_versions_handler;
+pub mod register_schema_handler;
+pub mod update_schema_compatibility_handler;
@@ -0,0 +1,23 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::schemas::register_schema::RegisterSchema;
+use tracing::debug;
+
+pub async fn handle(
+    command: &RegisterSchema,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    let schema = system
+        .register_schema(session, &command.subject, command.format, &command.schema)
+        .await?;
+    let bytes = mapper::map_schema(&schema);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use tracing::debug;
+
+pub async fn handle(
+    command: &UpdateSchemaCompatibility,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .update_schema_compatibility(session, &command.subject, command.compatibility)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
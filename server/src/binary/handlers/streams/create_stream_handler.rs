@@ -15,7 +15,12 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let mut system = system.write();
     system
-        .create_stream(session, command.stream_id, &command.name)
+        .create_stream(
+            session,
+            command.stream_id,
+            &command.name,
+            command.topic_defaults.clone(),
+        )
         .await?;
     sender.send_empty_ok_response().await?;
     Ok(())
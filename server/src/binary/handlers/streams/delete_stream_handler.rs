@@ -14,7 +14,9 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let mut system = system.write();
-    system.delete_stream(session, &command.stream_id).await?;
+    system
+        .delete_stream(session, &command.stream_id, command.soft)
+        .await?;
     sender.send_empty_ok_response().await?;
     Ok(())
 }
@@ -15,8 +15,8 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let system = system.read();
-    let stream = system.find_stream(session, &command.stream_id)?;
-    let stream = mapper::map_stream(stream).await;
+    let stream = system.find_stream(session, &command.stream_id).await?;
+    let stream = mapper::map_stream(&stream).await;
     sender.send_ok_response(&stream).await?;
     Ok(())
 }
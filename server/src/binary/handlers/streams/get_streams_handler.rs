@@ -15,7 +15,7 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let system = system.read();
-    let streams = system.find_streams(session)?;
+    let streams = system.find_streams(session).await?;
     let streams = mapper::map_streams(&streams).await;
     sender.send_ok_response(&streams).await?;
     Ok(())
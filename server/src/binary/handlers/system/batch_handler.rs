@@ -0,0 +1,82 @@
+use crate::binary::command;
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::models::batch_result::BatchCommandResult;
+use iggy::system::batch::Batch;
+use tracing::debug;
+
+pub async fn handle(
+    command: &Batch,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut results = Vec::with_capacity(command.commands.len());
+    for command in &command.commands {
+        let mut batch_sender = BatchSender::default();
+        let result = match Box::pin(command::try_handle(
+            command,
+            &mut batch_sender,
+            session,
+            system,
+        ))
+        .await
+        {
+            Ok(_) => batch_sender.result.unwrap_or(BatchCommandResult {
+                status: 0,
+                payload: Bytes::new(),
+            }),
+            Err(error) => {
+                debug!("Batched command '{command}' failed, session: {session}, error: {error}");
+                BatchCommandResult {
+                    status: error.as_code(),
+                    payload: Bytes::new(),
+                }
+            }
+        };
+        results.push(result);
+    }
+
+    let bytes = mapper::map_batch_results(&results);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
+
+/// Captures the response of a single command executed as part of the batch, instead of writing it to the connection.
+#[derive(Default)]
+struct BatchSender {
+    result: Option<BatchCommandResult>,
+}
+
+#[async_trait]
+impl Sender for BatchSender {
+    async fn read(&mut self, _buffer: &mut [u8]) -> Result<usize, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        self.send_ok_response(&[]).await
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.result = Some(BatchCommandResult {
+            status: 0,
+            payload: Bytes::copy_from_slice(payload),
+        });
+        Ok(())
+    }
+
+    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
+        self.result = Some(BatchCommandResult {
+            status: error.as_code(),
+            payload: Bytes::new(),
+        });
+        Ok(())
+    }
+}
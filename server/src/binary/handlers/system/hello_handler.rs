@@ -0,0 +1,23 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::system::hello::Hello;
+use tracing::debug;
+
+pub async fn handle(
+    command: &Hello,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let hello_info = system
+        .hello(session, command.protocol_version, &command.features)
+        .await?;
+    let bytes = mapper::map_hello_info(&hello_info);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
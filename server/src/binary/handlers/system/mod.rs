@@ -1,5 +1,10 @@
+pub mod batch_handler;
 pub mod get_client_handler;
 pub mod get_clients_handler;
 pub mod get_me_handler;
+pub mod get_metadata_handler;
 pub mod get_stats_handler;
+pub mod heartbeat_handler;
+pub mod hello_handler;
+pub mod negotiate_compression_handler;
 pub mod ping_handler;
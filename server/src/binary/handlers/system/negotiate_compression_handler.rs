@@ -0,0 +1,22 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::system::negotiate_compression::NegotiateCompression;
+use tracing::debug;
+
+pub async fn handle(
+    command: &NegotiateCompression,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let algorithm = system
+        .negotiate_compression(session, &command.algorithms)
+        .await?;
+    let bytes = (algorithm.as_code() as u32).to_le_bytes();
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
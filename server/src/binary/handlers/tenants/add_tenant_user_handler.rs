@@ -0,0 +1,21 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::tenants::add_tenant_user::AddTenantUser;
+use tracing::debug;
+
+pub async fn handle(
+    command: &AddTenantUser,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .add_tenant_user(session, &command.tenant_id, &command.user_id)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::tenants::create_tenant::CreateTenant;
+use tracing::debug;
+
+pub async fn handle(
+    command: &CreateTenant,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .create_tenant(session, command.tenant_id, &command.name, command.quotas)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
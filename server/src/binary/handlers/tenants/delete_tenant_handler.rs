@@ -0,0 +1,19 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::tenants::delete_tenant::DeleteTenant;
+use tracing::debug;
+
+pub async fn handle(
+    command: &DeleteTenant,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system.delete_tenant(session, &command.tenant_id).await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::tenants::get_tenant::GetTenant;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetTenant,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let tenant = system.find_tenant(session, &command.tenant_id)?;
+    let usage = system.get_tenant_usage(tenant.id).await;
+    let bytes = mapper::map_tenant(tenant, &usage);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::tenants::get_tenants::GetTenants;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetTenants,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let mut tenants = Vec::new();
+    for tenant in system.find_tenants(session)? {
+        tenants.push((tenant, system.get_tenant_usage(tenant.id).await));
+    }
+    let bytes = mapper::map_tenants(&tenants);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
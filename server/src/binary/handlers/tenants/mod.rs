@@ -0,0 +1,7 @@
+pub mod add_tenant_user_handler;
+pub mod create_tenant_handler;
+pub mod delete_tenant_handler;
+pub mod get_tenant_handler;
+pub mod get_tenants_handler;
+pub mod remove_tenant_user_handler;
+pub mod update_tenant_handler;
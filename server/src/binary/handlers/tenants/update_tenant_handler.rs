@@ -0,0 +1,21 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::tenants::update_tenant::UpdateTenant;
+use tracing::debug;
+
+pub async fn handle(
+    command: &UpdateTenant,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .update_tenant(session, &command.tenant_id, command.quotas)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -13,9 +13,9 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
-    let mut system = system.write();
+    let system = system.read();
     system
-        .delete_topic(session, &command.stream_id, &command.topic_id)
+        .delete_topic(session, &command.stream_id, &command.topic_id, command.soft)
         .await?;
     sender.send_empty_ok_response().await?;
     Ok(())
@@ -0,0 +1,22 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::topics::freeze_topic::FreezeTopic;
+use tracing::debug;
+
+pub async fn handle(
+    command: &FreezeTopic,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    system
+        .freeze_topic(session, &command.stream_id, &command.topic_id)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -15,8 +15,10 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let system = system.read();
-    let topic = system.find_topic(session, &command.stream_id, &command.topic_id)?;
-    let topic = mapper::map_topic(topic).await;
+    let topic = system
+        .find_topic(session, &command.stream_id, &command.topic_id)
+        .await?;
+    let topic = mapper::map_topic(&topic).await;
     sender.send_ok_response(&topic).await?;
     Ok(())
 }
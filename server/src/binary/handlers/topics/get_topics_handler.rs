@@ -15,8 +15,8 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let system = system.read();
-    let topics = system.find_topics(session, &command.stream_id)?;
-    let topics = mapper::map_topics(&topics).await;
+    let stream = system.find_topics(session, &command.stream_id).await?;
+    let topics = mapper::map_topics(&stream.get_topics()).await;
     sender.send_ok_response(&topics).await?;
     Ok(())
 }
@@ -1,6 +1,9 @@
 pub mod create_topic_handler;
 pub mod delete_topic_handler;
+pub mod freeze_topic_handler;
 pub mod get_topic_handler;
 pub mod get_topics_handler;
 pub mod purge_topic_handler;
+pub mod undelete_topic_handler;
+pub mod unfreeze_topic_handler;
 pub mod update_topic_handler;
@@ -13,7 +13,7 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
-    let mut system = system.write();
+    let system = system.read();
     system
         .update_topic(
             session,
@@ -23,6 +23,10 @@ pub async fn handle(
             command.message_expiry,
             command.max_topic_size,
             command.replication_factor,
+            command.message_schema.as_deref(),
+            command.timestamp_type,
+            command.consumer_limits,
+            command.max_message_size,
         )
         .await?;
     sender.send_empty_ok_response().await?;
@@ -0,0 +1,26 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::user_groups::create_user_group::CreateUserGroup;
+use tracing::debug;
+
+pub async fn handle(
+    command: &CreateUserGroup,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .create_user_group(
+            session,
+            command.group_id,
+            &command.name,
+            command.permissions.clone(),
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
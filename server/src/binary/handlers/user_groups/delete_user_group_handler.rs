@@ -0,0 +1,19 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::user_groups::delete_user_group::DeleteUserGroup;
+use tracing::debug;
+
+pub async fn handle(
+    command: &DeleteUserGroup,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system.delete_user_group(session, &command.group_id).await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::user_groups::get_user_group::GetUserGroup;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetUserGroup,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let group = system.find_user_group(session, &command.group_id)?;
+    let bytes = mapper::map_user_group(group);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::user_groups::get_user_groups::GetUserGroups;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetUserGroups,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let groups = system.find_user_groups(session)?;
+    let bytes = mapper::map_user_groups(&groups);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
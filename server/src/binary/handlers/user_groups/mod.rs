@@ -0,0 +1,7 @@
+pub mod add_user_group_member_handler;
+pub mod create_user_group_handler;
+pub mod delete_user_group_handler;
+pub mod get_user_group_handler;
+pub mod get_user_groups_handler;
+pub mod remove_user_group_member_handler;
+pub mod update_user_group_permissions_handler;
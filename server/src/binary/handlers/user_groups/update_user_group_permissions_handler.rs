@@ -0,0 +1,21 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::user_groups::update_user_group_permissions::UpdateUserGroupPermissions;
+use tracing::debug;
+
+pub async fn handle(
+    command: &UpdateUserGroupPermissions,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .update_user_group_permissions(session, &command.group_id, command.permissions.clone())
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
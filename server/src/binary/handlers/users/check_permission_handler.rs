@@ -0,0 +1,29 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::users::check_permission::CheckPermission;
+use tracing::log::debug;
+
+pub async fn handle(
+    command: &CheckPermission,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read();
+    let permission_check = system
+        .check_permission(
+            session,
+            &command.user_id,
+            command.action,
+            command.stream_id,
+            command.topic_id,
+        )
+        .await?;
+    let bytes = mapper::map_permission_check(&permission_check);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
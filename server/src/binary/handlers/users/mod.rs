@@ -1,4 +1,5 @@
 pub mod change_password_handler;
+pub mod check_permission_handler;
 pub mod create_user_handler;
 pub mod delete_user_handler;
 pub mod get_user_handler;
@@ -6,4 +7,5 @@ pub mod get_users_handler;
 pub mod login_user_handler;
 pub mod logout_user_handler;
 pub mod update_permissions_handler;
+pub mod update_user_expiry_handler;
 pub mod update_user_handler;
@@ -0,0 +1,27 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::users::update_user_expiry::UpdateUserExpiry;
+use tracing::debug;
+
+pub async fn handle(
+    command: &UpdateUserExpiry,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write();
+    system
+        .update_user_expiry(
+            session,
+            &command.user_id,
+            command.expires_at,
+            command.max_inactive_days,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -1,18 +1,59 @@
 use crate::streaming::clients::client_manager::{Client, Transport};
-use crate::streaming::models::messages::PolledMessages;
+use crate::streaming::models::messages::{FetchedMessages, PolledMessages};
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::schemas::schema::Schema;
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::tenants::tenant::{Tenant, TenantUsage};
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::user_groups::user_group::UserGroup;
 use crate::streaming::users::user::User;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::models::batch_result::BatchCommandResult;
+use iggy::models::connector::ConnectorInfo;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::fetch_session::FetchSession;
+use iggy::models::hello_info::HelloInfo;
+use iggy::models::messages::SendMessagesReceipt;
+use iggy::models::metadata::Metadata;
+use iggy::models::partition::PartitionEndOffset;
+use iggy::models::permission_check::PermissionCheck;
+use iggy::models::query_result::QueryResult;
 use iggy::models::stats::Stats;
+use iggy::models::topic_defaults::TopicDefaults;
 use iggy::models::user_info::UserId;
+use iggy::utils::buffer_pool::BufferPool;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, RwLockReadGuard};
+
+pub fn map_send_messages_receipt(receipt: &SendMessagesReceipt) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(24);
+    bytes.put_u32_le(receipt.partition_id);
+    bytes.put_u64_le(receipt.base_offset);
+    bytes.put_u32_le(receipt.messages_count);
+    bytes.put_u64_le(receipt.timestamp);
+    bytes.freeze()
+}
+
+pub fn map_metadata(metadata: &Metadata) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for stream in &metadata.streams {
+        bytes.put_u32_le(stream.id);
+        bytes.put_u32_le(stream.topics.len() as u32);
+        bytes.put_u8(stream.name.len() as u8);
+        bytes.put_slice(stream.name.as_bytes());
+        for topic in &stream.topics {
+            bytes.put_u32_le(topic.id);
+            bytes.put_u32_le(topic.partitions_count);
+            bytes.put_u8(topic.name.len() as u8);
+            bytes.put_slice(topic.name.as_bytes());
+        }
+    }
+    bytes.freeze()
+}
 
 pub fn map_stats(stats: &Stats) -> Bytes {
     let mut bytes = BytesMut::with_capacity(104);
@@ -41,6 +82,21 @@ pub fn map_stats(stats: &Stats) -> Bytes {
     bytes.put_slice(stats.os_version.as_bytes());
     bytes.put_u32_le(stats.kernel_version.len() as u32);
     bytes.put_slice(stats.kernel_version.as_bytes());
+    bytes.put_u64_le(stats.unsaved_messages_size.as_bytes_u64());
+    bytes.put_u64_le(stats.cached_messages_size.as_bytes_u64());
+    bytes.put_u64_le(stats.cached_indexes_size.as_bytes_u64());
+    bytes.put_u64_le(stats.memory_budget.as_bytes_u64());
+    bytes.put_u32_le(stats.runtimes.len() as u32);
+    for runtime in &stats.runtimes {
+        bytes.put_u8(runtime.name.len() as u8);
+        bytes.put_slice(runtime.name.as_bytes());
+        bytes.put_u32_le(runtime.workers_count);
+        bytes.put_u64_le(runtime.alive_tasks_count);
+        bytes.put_u64_le(runtime.global_queue_depth);
+        bytes.put_u64_le(runtime.busy_duration);
+    }
+    bytes.put_u64_le(stats.disk_free_space.as_bytes_u64());
+    bytes.put_u64_le(stats.disk_total_space.as_bytes_u64());
     bytes.freeze()
 }
 
@@ -52,6 +108,15 @@ pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_partition_end_offsets(end_offsets: &[PartitionEndOffset]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(12 * end_offsets.len());
+    for end_offset in end_offsets {
+        bytes.put_u32_le(end_offset.partition_id);
+        bytes.put_u64_le(end_offset.end_offset);
+    }
+    bytes.freeze()
+}
+
 pub async fn map_client(client: &Client) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_client(client, &mut bytes);
@@ -82,8 +147,11 @@ pub fn map_user(user: &User) -> Bytes {
         bytes.put_u32_le(permissions.len() as u32);
         bytes.put_slice(&permissions);
     } else {
-        bytes.put_u32_le(0);
+        bytes.put_u8(0);
     }
+    bytes.put_u64_le(user.expires_at.unwrap_or(0));
+    bytes.put_u32_le(user.max_inactive_days.unwrap_or(0));
+    bytes.put_u64_le(user.last_active_at.unwrap_or(0));
     bytes.freeze()
 }
 
@@ -101,6 +169,44 @@ pub fn map_identity_info(user_id: UserId) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_permission_check(permission_check: &PermissionCheck) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(10 + permission_check.rule.len());
+    bytes.put_u8(permission_check.allowed as u8);
+    bytes.put_u8(permission_check.group_id.is_some() as u8);
+    bytes.put_u32_le(permission_check.group_id.unwrap_or(0));
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u32_le(permission_check.rule.len() as u32);
+    bytes.put_slice(permission_check.rule.as_bytes());
+    bytes.freeze()
+}
+
+pub fn map_hello_info(hello_info: &HelloInfo) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(5 + hello_info.server_version.len());
+    bytes.put_u32_le(hello_info.protocol_version);
+    bytes.put_u8(hello_info.server_version.len() as u8);
+    bytes.put_slice(hello_info.server_version.as_bytes());
+    for feature in &hello_info.features {
+        bytes.put_u8(feature.len() as u8);
+        bytes.put_slice(feature.as_bytes());
+    }
+    bytes.freeze()
+}
+
+pub fn map_batch_results(results: &[BatchCommandResult]) -> Bytes {
+    let payloads_length = results
+        .iter()
+        .map(|result| 8 + result.payload.len())
+        .sum::<usize>();
+    let mut bytes = BytesMut::with_capacity(4 + payloads_length);
+    bytes.put_u32_le(results.len() as u32);
+    for result in results {
+        bytes.put_u32_le(result.status);
+        bytes.put_u32_le(result.payload.len() as u32);
+        bytes.put_slice(&result.payload);
+    }
+    bytes.freeze()
+}
+
 pub fn map_raw_pat(token: &str) -> Bytes {
     let mut bytes = BytesMut::with_capacity(1 + token.len());
     bytes.put_u8(token.len() as u8);
@@ -116,6 +222,93 @@ pub fn map_personal_access_tokens(personal_access_tokens: &[PersonalAccessToken]
     bytes.freeze()
 }
 
+pub fn map_schema(schema: &Schema) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_schema(schema, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_schemas(schemas: &[&Schema]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for schema in schemas {
+        extend_schema(schema, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_tenant(tenant: &Tenant, usage: &TenantUsage) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_tenant(tenant, usage, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_tenants(tenants: &[(&Tenant, TenantUsage)]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for (tenant, usage) in tenants {
+        extend_tenant(tenant, usage, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_user_group(group: &UserGroup) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_user_group(group, &mut bytes);
+    for user_id in &group.members {
+        bytes.put_u32_le(*user_id);
+    }
+    if let Some(permissions) = &group.permissions {
+        bytes.put_u8(1);
+        let permissions = permissions.as_bytes();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(permissions.len() as u32);
+        bytes.put_slice(&permissions);
+    } else {
+        bytes.put_u8(0);
+    }
+    bytes.freeze()
+}
+
+pub fn map_user_groups(groups: &[&UserGroup]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for group in groups {
+        extend_user_group(group, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_connector(connector: &ConnectorInfo) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_connector(connector, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_connectors(connectors: &[ConnectorInfo]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for connector in connectors {
+        extend_connector(connector, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+pub fn map_query_result(result: &QueryResult) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u32_le(result.columns.len() as u32);
+    for column in &result.columns {
+        bytes.put_u32_le(column.len() as u32);
+        bytes.put_slice(column.as_bytes());
+    }
+    bytes.put_u32_le(result.rows.len() as u32);
+    for row in &result.rows {
+        for value in row {
+            let value = value.to_string();
+            bytes.put_u32_le(value.len() as u32);
+            bytes.put_slice(value.as_bytes());
+        }
+    }
+    bytes.put_u64_le(result.next_offset.unwrap_or(0));
+    bytes.freeze()
+}
+
 pub fn map_polled_messages(polled_messages: &PolledMessages) -> Bytes {
     let messages_count = polled_messages.messages.len() as u32;
     let messages_size = polled_messages
@@ -124,14 +317,40 @@ pub fn map_polled_messages(polled_messages: &PolledMessages) -> Bytes {
         .map(|message| message.get_size_bytes())
         .sum::<u32>();
 
-    let mut bytes = BytesMut::with_capacity(20 + messages_size as usize);
+    let buffer_pool = BufferPool::global();
+    let mut bytes = buffer_pool.acquire(24 + messages_size as usize);
     bytes.put_u32_le(polled_messages.partition_id);
     bytes.put_u64_le(polled_messages.current_offset);
     bytes.put_u32_le(messages_count);
     for message in polled_messages.messages.iter() {
         message.extend(&mut bytes);
     }
+    // The next offset is only present if the client has set the max bytes, so the older clients never receive it.
+    if let Some(next_offset) = polled_messages.next_offset {
+        bytes.put_u64_le(next_offset);
+    }
+
+    buffer_pool.freeze(bytes)
+}
+
+pub fn map_fetch_session(fetch_session: &FetchSession) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(8);
+    bytes.put_u32_le(fetch_session.session_id);
+    bytes.put_u32_le(fetch_session.epoch);
+    bytes.freeze()
+}
 
+pub fn map_fetched_messages(fetched_messages: &FetchedMessages) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u32_le(fetched_messages.session_id);
+    bytes.put_u32_le(fetched_messages.epoch);
+    for partition in &fetched_messages.partitions {
+        let messages = map_polled_messages(&partition.messages);
+        bytes.put_u32_le(partition.stream_id);
+        bytes.put_u32_le(partition.topic_id);
+        bytes.put_u32_le(messages.len() as u32);
+        bytes.put_slice(&messages);
+    }
     bytes.freeze()
 }
 
@@ -144,7 +363,7 @@ pub async fn map_stream(stream: &Stream) -> Bytes {
     bytes.freeze()
 }
 
-pub async fn map_streams(streams: &[&Stream]) -> Bytes {
+pub async fn map_streams(streams: &[RwLockReadGuard<'_, Stream>]) -> Bytes {
     let mut bytes = BytesMut::new();
     for stream in streams {
         extend_stream(stream, &mut bytes).await;
@@ -163,6 +382,13 @@ pub async fn map_topics(topics: &[&Topic]) -> Bytes {
 pub async fn map_topic(topic: &Topic) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_topic(topic, &mut bytes).await;
+    match &topic.message_schema {
+        Some(message_schema) => {
+            bytes.put_u32_le(message_schema.as_str().len() as u32);
+            bytes.put_slice(message_schema.as_str().as_bytes());
+        }
+        None => bytes.put_u32_le(0),
+    }
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
         extend_partition(&partition, &mut bytes);
@@ -170,6 +396,24 @@ pub async fn map_topic(topic: &Topic) -> Bytes {
     bytes.freeze()
 }
 
+pub async fn map_partition(partition: &Partition) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_partition(partition, &mut bytes);
+    let now = IggyTimestamp::now().to_micros();
+    for segment in partition.get_segments() {
+        bytes.put_u64_le(segment.start_offset);
+        bytes.put_u64_le(match segment.is_closed {
+            true => segment.end_offset,
+            false => segment.current_offset,
+        });
+        bytes.put_u64_le(segment.size_bytes as u64);
+        bytes.put_u8(segment.is_closed as u8);
+        bytes.put_u32_le(segment.message_expiry.unwrap_or(0));
+        bytes.put_u8(segment.is_expired(now).await as u8);
+    }
+    bytes.freeze()
+}
+
 pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_consumer_group(consumer_group, &mut bytes);
@@ -186,7 +430,7 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Bytes {
     bytes.freeze()
 }
 
-pub async fn map_consumer_groups(consumer_groups: &[&RwLock<ConsumerGroup>]) -> Bytes {
+pub async fn map_consumer_groups(consumer_groups: &[Arc<RwLock<ConsumerGroup>>]) -> Bytes {
     let mut bytes = BytesMut::new();
     for consumer_group in consumer_groups {
         let consumer_group = consumer_group.read().await;
@@ -201,10 +445,25 @@ async fn extend_stream(stream: &Stream, bytes: &mut BytesMut) {
     bytes.put_u32_le(stream.get_topics().len() as u32);
     bytes.put_u64_le(stream.get_size().as_bytes_u64());
     bytes.put_u64_le(stream.get_messages_count());
+    extend_topic_defaults(&stream.topic_defaults, bytes);
+    bytes.put_slice(stream.uuid.as_bytes());
     bytes.put_u8(stream.name.len() as u8);
     bytes.put_slice(stream.name.as_bytes());
 }
 
+fn extend_topic_defaults(topic_defaults: &TopicDefaults, bytes: &mut BytesMut) {
+    bytes.put_u32_le(topic_defaults.message_expiry.unwrap_or(0));
+    match topic_defaults.max_topic_size {
+        Some(max_topic_size) => bytes.put_u64_le(max_topic_size.as_bytes_u64()),
+        None => bytes.put_u64_le(0),
+    };
+    match &topic_defaults.compression_algorithm {
+        Some(compression_algorithm) => bytes.put_u8(compression_algorithm.as_code()),
+        None => bytes.put_u8(0),
+    };
+    bytes.put_u8(topic_defaults.replication_factor.unwrap_or(0));
+}
+
 async fn extend_topic(topic: &Topic, bytes: &mut BytesMut) {
     bytes.put_u32_le(topic.topic_id);
     bytes.put_u64_le(topic.created_at);
@@ -220,6 +479,17 @@ async fn extend_topic(topic: &Topic, bytes: &mut BytesMut) {
     bytes.put_u8(topic.replication_factor);
     bytes.put_u64_le(topic.get_size().as_bytes_u64());
     bytes.put_u64_le(topic.get_messages_count());
+    bytes.put_u32_le(topic.routing_epoch);
+    bytes.put_u8(topic.compression_algorithm.as_code());
+    bytes.put_u8(topic.frozen as u8);
+    bytes.put_u32_le(topic.consumer_limits.max_consumer_groups.unwrap_or(0));
+    bytes.put_u32_le(topic.consumer_limits.max_group_members.unwrap_or(0));
+    bytes.put_u64_le(
+        topic
+            .get_max_message_size()
+            .map_or(0, |size| size.as_bytes_u64()),
+    );
+    bytes.put_slice(topic.uuid.as_bytes());
     bytes.put_u8(topic.name.len() as u8);
     bytes.put_slice(topic.name.as_bytes());
 }
@@ -247,6 +517,9 @@ fn extend_client(client: &Client, bytes: &mut BytesMut) {
     let transport: u8 = match client.transport {
         Transport::Tcp => 1,
         Transport::Quic => 2,
+        Transport::Mqtt => 3,
+        Transport::Amqp => 4,
+        Transport::Http => 5,
     };
     bytes.put_u8(transport);
     let address = client.address.to_string();
@@ -259,6 +532,7 @@ fn extend_user(user: &User, bytes: &mut BytesMut) {
     bytes.put_u32_le(user.id);
     bytes.put_u64_le(user.created_at);
     bytes.put_u8(user.status.as_code());
+    bytes.put_slice(user.uuid.as_bytes());
     bytes.put_u8(user.username.len() as u8);
     bytes.put_slice(user.username.as_bytes());
 }
@@ -268,3 +542,59 @@ fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut BytesMut)
     bytes.put_slice(personal_access_token.name.as_bytes());
     bytes.put_u64_le(personal_access_token.expiry.unwrap_or(0));
 }
+
+fn extend_schema(schema: &Schema, bytes: &mut BytesMut) {
+    bytes.put_u32_le(schema.id);
+    bytes.put_u32_le(schema.version);
+    bytes.put_u64_le(schema.created_at);
+    bytes.put_u8(schema.format.as_code());
+    bytes.put_u8(schema.subject.len() as u8);
+    bytes.put_slice(schema.subject.as_bytes());
+    bytes.put_u32_le(schema.schema.len() as u32);
+    bytes.put_slice(schema.schema.as_bytes());
+}
+
+fn extend_user_group(group: &UserGroup, bytes: &mut BytesMut) {
+    bytes.put_u32_le(group.id);
+    bytes.put_u64_le(group.created_at);
+    bytes.put_u32_le(group.members.len() as u32);
+    bytes.put_u8(group.name.len() as u8);
+    bytes.put_slice(group.name.as_bytes());
+}
+
+fn extend_connector(connector: &ConnectorInfo, bytes: &mut BytesMut) {
+    for value in [
+        &connector.name,
+        &connector.kind,
+        &connector.stream,
+        &connector.topic,
+    ] {
+        bytes.put_u8(value.len() as u8);
+        bytes.put_slice(value.as_bytes());
+    }
+    bytes.put_u8(connector.status.as_code());
+    bytes.put_u64_le(connector.processed_messages);
+    let error = connector.error.as_deref().unwrap_or_default();
+    bytes.put_u32_le(error.len() as u32);
+    bytes.put_slice(error.as_bytes());
+    bytes.put_u32_le(connector.properties.len() as u32);
+    for (key, value) in &connector.properties {
+        bytes.put_u8(key.len() as u8);
+        bytes.put_slice(key.as_bytes());
+        bytes.put_u32_le(value.len() as u32);
+        bytes.put_slice(value.as_bytes());
+    }
+}
+
+fn extend_tenant(tenant: &Tenant, usage: &TenantUsage, bytes: &mut BytesMut) {
+    bytes.put_u32_le(tenant.id);
+    bytes.put_u64_le(tenant.created_at);
+    bytes.put_u32_le(usage.streams_count);
+    bytes.put_u32_le(usage.topics_count);
+    bytes.put_u32_le(usage.partitions_count);
+    bytes.put_u64_le(usage.size_bytes);
+    bytes.put_u32_le(usage.users_count);
+    bytes.put_slice(&tenant.quotas.as_bytes());
+    bytes.put_u8(tenant.name.len() as u8);
+    bytes.put_slice(tenant.name.as_bytes());
+}
@@ -1,4 +1,5 @@
 pub mod command;
+pub(crate) mod connection_limiter;
 mod handlers;
 mod mapper;
 pub mod sender;
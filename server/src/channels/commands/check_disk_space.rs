@@ -0,0 +1,135 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ServerConfig;
+use crate::configs::system::DiskSpaceConfig;
+use crate::streaming::diagnostics::disk_space::get_disk_usage;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::{Receiver, Sender};
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info, warn};
+
+pub struct DiskSpaceChecker {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<CheckDiskSpaceCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckDiskSpaceCommand;
+
+/// Updates the free space on the disk of the system path, logging once the thresholds are crossed in either direction.
+#[derive(Debug)]
+pub struct CheckDiskSpaceExecutor {
+    min_free_space_for_partitions: u64,
+    min_free_space_for_sends: u64,
+    partitions_blocked: bool,
+    sends_blocked: bool,
+}
+
+impl DiskSpaceChecker {
+    pub fn new(config: &DiskSpaceConfig, sender: Sender<CheckDiskSpaceCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.check_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Disk space checker is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Disk space checker is enabled, free disk space will be checked every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(CheckDiskSpaceCommand).unwrap_or_else(|error| {
+                    error!("Failed to send CheckDiskSpaceCommand. Error: {}", error);
+                });
+            }
+        });
+    }
+}
+
+impl CheckDiskSpaceExecutor {
+    pub fn new(config: &DiskSpaceConfig) -> Self {
+        Self {
+            min_free_space_for_partitions: config.min_free_space_for_partitions.as_bytes_u64(),
+            min_free_space_for_sends: config.min_free_space_for_sends.as_bytes_u64(),
+            partitions_blocked: false,
+            sends_blocked: false,
+        }
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CheckDiskSpaceCommand> for CheckDiskSpaceExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: CheckDiskSpaceCommand) {
+        let system = system.read();
+        let path = system.config.get_system_path();
+        let Some(usage) = get_disk_usage(&path) else {
+            debug!("Cannot find the disk for path: {path}.");
+            return;
+        };
+
+        system.disk_space.update(usage);
+        system
+            .metrics
+            .set_disk_space(usage.available_bytes, usage.total_bytes);
+
+        let free_space = IggyByteSize::from(usage.available_bytes);
+        let partitions_blocked = usage.available_bytes < self.min_free_space_for_partitions;
+        if partitions_blocked != self.partitions_blocked {
+            self.partitions_blocked = partitions_blocked;
+            match partitions_blocked {
+                true => warn!("Free disk space: {free_space} for path: {path} is running out, new partitions will be rejected."),
+                false => info!("Free disk space: {free_space} for path: {path} is sufficient, new partitions are allowed again."),
+            }
+        }
+
+        let sends_blocked = usage.available_bytes < self.min_free_space_for_sends;
+        if sends_blocked != self.sends_blocked {
+            self.sends_blocked = sends_blocked;
+            match sends_blocked {
+                true => warn!("Free disk space: {free_space} for path: {path} is running out, messages will be rejected."),
+                false => info!("Free disk space: {free_space} for path: {path} is sufficient, messages are allowed again."),
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<CheckDiskSpaceCommand>,
+    ) {
+        let disk_space_checker = DiskSpaceChecker::new(&config.system.disk_space, sender);
+        disk_space_checker.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<CheckDiskSpaceCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Disk space checker receiver stopped.");
+        });
+    }
+}
@@ -0,0 +1,206 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::{EventsConfig, ServerConfig};
+use crate::streaming::diagnostics::disk_space::{canonicalize, get_disk_usage};
+use crate::streaming::events::event::ServerEvent;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::{Receiver, Sender};
+use iggy::utils::duration::IggyDuration;
+use std::collections::HashSet;
+use tokio::time;
+use tracing::{debug, error, info, warn};
+
+pub struct ThresholdsChecker {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<CheckThresholdsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckThresholdsCommand;
+
+/// Checks the consumer lags and the disk usage against the configured thresholds,
+/// the event is published once the threshold is crossed, and re-armed when the usage drops below it.
+#[derive(Debug)]
+pub struct CheckThresholdsExecutor {
+    consumer_lag_threshold: u64,
+    disk_usage_threshold: u8,
+    exceeded_consumer_lags: HashSet<(u32, u32, u32, u32, bool)>,
+    disk_usage_exceeded: bool,
+}
+
+impl ThresholdsChecker {
+    pub fn new(config: &EventsConfig, sender: Sender<CheckThresholdsCommand>) -> Self {
+        Self {
+            enabled: config.is_enabled(),
+            interval: config.check_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Thresholds checker is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Thresholds checker is enabled, consumer lags and disk usage will be checked every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(CheckThresholdsCommand).unwrap_or_else(|error| {
+                    error!("Failed to send CheckThresholdsCommand. Error: {}", error);
+                });
+            }
+        });
+    }
+}
+
+impl CheckThresholdsExecutor {
+    pub fn new(config: &EventsConfig) -> Self {
+        Self {
+            consumer_lag_threshold: config.consumer_lag_threshold,
+            disk_usage_threshold: config.disk_usage_threshold,
+            exceeded_consumer_lags: HashSet::new(),
+            disk_usage_exceeded: false,
+        }
+    }
+
+    async fn check_consumer_lags(&mut self, system: &SharedSystem) {
+        let threshold = self.consumer_lag_threshold;
+        let mut lags = Vec::new();
+        {
+            let system = system.read();
+            for stream in system.streams.values() {
+                let stream = stream.read().await;
+                for topic in stream.topics.values() {
+                    for partition in topic.partitions.values() {
+                        let partition = partition.read().await;
+                        let offsets = partition
+                            .consumer_offsets
+                            .iter()
+                            .map(|offset| (offset.consumer_id, offset.offset, false))
+                            .chain(
+                                partition
+                                    .consumer_group_offsets
+                                    .iter()
+                                    .map(|offset| (offset.consumer_id, offset.offset, true)),
+                            );
+                        for (consumer_id, offset, consumer_group) in offsets {
+                            lags.push((
+                                (
+                                    topic.stream_id,
+                                    topic.topic_id,
+                                    partition.partition_id,
+                                    consumer_id,
+                                    consumer_group,
+                                ),
+                                partition.current_offset.saturating_sub(offset),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (key, lag) in lags {
+            if lag < threshold {
+                self.exceeded_consumer_lags.remove(&key);
+                continue;
+            }
+
+            if !self.exceeded_consumer_lags.insert(key) {
+                continue;
+            }
+
+            let (stream_id, topic_id, partition_id, consumer_id, consumer_group) = key;
+            warn!("Consumer with ID: {consumer_id} for partition with ID: {partition_id}, topic with ID: {topic_id} and stream with ID: {stream_id} is lagging by {lag} messages.");
+            system.read().publish_event(ServerEvent::ConsumerLag {
+                stream_id,
+                topic_id,
+                partition_id,
+                consumer_id,
+                consumer_group,
+                lag,
+                threshold,
+            });
+        }
+    }
+
+    fn check_disk_usage(&mut self, system: &SharedSystem) {
+        let path = canonicalize(&system.read().config.get_system_path())
+            .to_string_lossy()
+            .to_string();
+        let Some(usage) = get_disk_usage(&path) else {
+            debug!("Cannot find the disk for path: {path}.");
+            return;
+        };
+
+        let total_bytes = usage.total_bytes;
+        if total_bytes == 0 {
+            return;
+        }
+
+        let used_bytes = total_bytes.saturating_sub(usage.available_bytes);
+        let usage_percent = (used_bytes * 100 / total_bytes) as u8;
+        let threshold_percent = self.disk_usage_threshold;
+        if usage_percent < threshold_percent {
+            self.disk_usage_exceeded = false;
+            return;
+        }
+
+        if self.disk_usage_exceeded {
+            return;
+        }
+
+        self.disk_usage_exceeded = true;
+        warn!("Disk usage for path: {path} has reached {usage_percent}%.");
+        system.read().publish_event(ServerEvent::DiskUsage {
+            path,
+            used_bytes,
+            total_bytes,
+            usage_percent,
+            threshold_percent,
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CheckThresholdsCommand> for CheckThresholdsExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: CheckThresholdsCommand) {
+        self.check_consumer_lags(system).await;
+        self.check_disk_usage(system);
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<CheckThresholdsCommand>,
+    ) {
+        let thresholds_checker = ThresholdsChecker::new(&config.events, sender);
+        thresholds_checker.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<CheckThresholdsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            warn!("Server command handler stopped receiving commands.");
+        });
+    }
+}
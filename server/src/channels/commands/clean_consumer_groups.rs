@@ -0,0 +1,103 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::ConsumerGroupCleanerConfig;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info};
+
+pub struct ConsumerGroupsCleaner {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<CleanConsumerGroupsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanConsumerGroupsCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanConsumerGroupsExecutor;
+
+impl ConsumerGroupsCleaner {
+    pub fn new(
+        config: &ConsumerGroupCleanerConfig,
+        sender: Sender<CleanConsumerGroupsCommand>,
+    ) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Consumer groups cleaner is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Consumer groups cleaner is enabled, inactive consumer groups will be expired every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(CleanConsumerGroupsCommand)
+                    .unwrap_or_else(|error| {
+                        error!(
+                            "Failed to send CleanConsumerGroupsCommand. Error: {}",
+                            error
+                        );
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CleanConsumerGroupsCommand> for CleanConsumerGroupsExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: CleanConsumerGroupsCommand) {
+        // The exclusive access ensures that no member joins the group, while it's being expired.
+        let mut system = system.write();
+        match system.expire_consumer_groups().await {
+            Ok((0, 0)) => debug!("No inactive consumer groups to expire."),
+            Ok((offsets_count, groups_count)) => info!(
+                "Expired offsets of {offsets_count} and deleted {groups_count} inactive consumer group(s)."
+            ),
+            Err(error) => error!("Failed to expire the inactive consumer groups. Error: {error}"),
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CleanConsumerGroupsCommand>,
+    ) {
+        let consumer_groups_cleaner =
+            ConsumerGroupsCleaner::new(&config.system.consumer_group_expiry.cleaner, sender);
+        consumer_groups_cleaner.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CleanConsumerGroupsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Consumer groups cleaner receiver stopped.");
+        });
+    }
+}
@@ -64,9 +64,10 @@ impl MessagesCleaner {
 impl ServerCommand<CleanMessagesCommand> for CleanMessagesExecutor {
     async fn execute(&mut self, system: &SharedSystem, _command: CleanMessagesCommand) {
         let now = IggyTimestamp::now().to_micros();
-        let system_read = system.read();
-        let streams = system_read.get_streams();
-        for stream in streams {
+        let system = system.read();
+        // The streams are locked one at a time, so the cleanup blocks only the changes of the currently cleaned stream.
+        for stream in system.streams.values() {
+            let stream = stream.read().await;
             let topics = stream.get_topics();
             for topic in topics {
                 let deleted_segments = delete_expired_segments(topic, now).await;
@@ -80,11 +81,9 @@ impl ServerCommand<CleanMessagesCommand> for CleanMessagesExecutor {
                     );
 
                     system
-                        .write()
                         .metrics
                         .decrement_segments(deleted_segments.segments_count);
                     system
-                        .write()
                         .metrics
                         .decrement_messages(deleted_segments.messages_count);
                 }
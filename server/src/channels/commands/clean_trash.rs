@@ -0,0 +1,99 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::TrashCleanerConfig;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info};
+
+pub struct TrashCleaner {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<CleanTrashCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanTrashCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanTrashExecutor;
+
+impl TrashCleaner {
+    pub fn new(config: &TrashCleanerConfig, sender: Sender<CleanTrashCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Trash cleaner is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Trash cleaner is enabled, expired streams and topics will be purged every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(CleanTrashCommand).unwrap_or_else(|error| {
+                    error!("Failed to send CleanTrashCommand. Error: {}", error);
+                });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CleanTrashCommand> for CleanTrashExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: CleanTrashCommand) {
+        let mut system = system.write();
+        match system.purge_expired_trash().await {
+            Ok(0) => debug!("No expired streams or topics to purge from the trash."),
+            Ok(purged_count) => {
+                info!("Purged {purged_count} expired stream(s) and topic(s) from the trash.")
+            }
+            Err(error) => error!("Failed to purge the expired trash. Error: {error}"),
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CleanTrashCommand>,
+    ) {
+        // There is nothing moved to the trash with the memory storage backend.
+        if config.system.storage.backend == crate::configs::system::StorageBackend::Memory {
+            info!("Trash cleaner is disabled for the memory storage backend.");
+            return;
+        }
+
+        let trash_cleaner = TrashCleaner::new(&config.system.trash.cleaner, sender);
+        trash_cleaner.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CleanTrashCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Trash cleaner receiver stopped.");
+        });
+    }
+}
@@ -0,0 +1,98 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::UserExpiryConfig;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info};
+
+pub struct UserExpiryChecker {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<DisableExpiredUsersCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DisableExpiredUsersCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct DisableExpiredUsersExecutor;
+
+impl UserExpiryChecker {
+    pub fn new(config: &UserExpiryConfig, sender: Sender<DisableExpiredUsersCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.check_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("User expiry checker is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "User expiry checker is enabled, expired and inactive users will be disabled every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(DisableExpiredUsersCommand)
+                    .unwrap_or_else(|error| {
+                        error!(
+                            "Failed to send DisableExpiredUsersCommand. Error: {}",
+                            error
+                        );
+                    });
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<DisableExpiredUsersCommand> for DisableExpiredUsersExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: DisableExpiredUsersCommand) {
+        let mut system = system.write();
+        match system.disable_expired_users().await {
+            Ok(0) => debug!("No expired or inactive users to disable."),
+            Ok(disabled_count) => {
+                info!("Disabled {disabled_count} expired or inactive user(s).")
+            }
+            Err(error) => error!("Failed to disable the expired users. Error: {error}"),
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<DisableExpiredUsersCommand>,
+    ) {
+        let user_expiry_checker = UserExpiryChecker::new(&config.system.user_expiry, sender);
+        user_expiry_checker.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<DisableExpiredUsersCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("User expiry checker receiver stopped.");
+        });
+    }
+}
@@ -0,0 +1,229 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::{ExportConfig, ServerConfig};
+use crate::export::error::ExportError;
+use crate::export::parquet;
+use crate::export::storage::ExportStorage;
+use crate::export::topic::ExportedTopic;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::hash;
+use async_trait::async_trait;
+use flume::{Receiver, Sender};
+use iggy::messages::poll_messages::{PollingMode, PollingStrategy};
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::utils::duration::IggyDuration;
+use std::net::{Ipv4Addr, SocketAddr};
+use tokio::time;
+use tracing::{error, info, warn};
+
+/// The name of the consumer storing the offsets of the exported messages, hashed like the named consumers.
+const EXPORT_CONSUMER: &str = "iggy-export";
+
+pub struct MessagesExporter {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<ExportMessagesCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ExportMessagesCommand;
+
+/// Exports the new messages of the configured topics to the Parquet files in the S3 bucket.
+/// The offsets of the exported messages are stored as the consumer offsets, so each export continues
+/// where the previous one has finished, and the files are uploaded before the offsets are stored,
+/// hence the messages are exported at least once.
+#[derive(Debug)]
+pub struct ExportMessagesExecutor {
+    topics: Vec<ExportedTopic>,
+    batch_size: u32,
+    prefix: String,
+    storage: Option<ExportStorage>,
+    consumer_id: u32,
+    session: Session,
+}
+
+impl MessagesExporter {
+    pub fn new(config: &ExportConfig, sender: Sender<ExportMessagesCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Messages export is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Messages export is enabled, new messages will be exported every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(ExportMessagesCommand).unwrap_or_else(|error| {
+                    error!("Failed to send ExportMessagesCommand. Error: {}", error);
+                });
+            }
+        });
+    }
+}
+
+impl ExportMessagesExecutor {
+    pub fn new(config: &ExportConfig) -> Self {
+        let storage = match config.enabled {
+            true => ExportStorage::new(config)
+                .map_err(|error| {
+                    error!(
+                        "Cannot create export storage, messages won't be exported. Error: {error}"
+                    );
+                })
+                .ok(),
+            false => None,
+        };
+        Self {
+            topics: config
+                .topics
+                .iter()
+                .filter_map(|topic| ExportedTopic::parse(topic).ok())
+                .collect(),
+            batch_size: config.batch_size,
+            prefix: config.prefix.clone(),
+            storage,
+            consumer_id: hash::calculate_32(EXPORT_CONSUMER.as_bytes()),
+            session: Session::stateless(
+                DEFAULT_ROOT_USER_ID,
+                SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+            ),
+        }
+    }
+
+    /// Exports the messages of each partition in the batches, until all the messages have been exported.
+    /// Returns the number of the exported messages.
+    async fn export_topic(
+        &self,
+        system: &SharedSystem,
+        storage: &ExportStorage,
+        topic: &ExportedTopic,
+    ) -> Result<usize, ExportError> {
+        let partitions_count = {
+            let system = system.read_async().await;
+            let stream = system.get_stream(&topic.stream_id).await?;
+            stream.get_topic(&topic.topic_id)?.get_partitions_count()
+        };
+
+        let mut exported_messages = 0;
+        for partition_id in 1..=partitions_count {
+            let consumer = PollingConsumer::Consumer(self.consumer_id, partition_id);
+            loop {
+                let args = PollingArgs::new(
+                    PollingStrategy::next(),
+                    self.batch_size,
+                    false,
+                    None,
+                    PollingMode::Consume,
+                );
+                let messages = system
+                    .read_async()
+                    .await
+                    .poll_messages(
+                        &self.session,
+                        consumer,
+                        &topic.stream_id,
+                        &topic.topic_id,
+                        args,
+                    )
+                    .await?
+                    .messages;
+                let Some(last_offset) = messages.last().map(|message| message.offset) else {
+                    break;
+                };
+
+                for (date, messages) in parquet::group_by_date(&messages) {
+                    let key = topic.get_object_key(
+                        &self.prefix,
+                        &date,
+                        partition_id,
+                        messages[0].offset,
+                        messages[messages.len() - 1].offset,
+                    );
+                    storage.put(&key, parquet::write(messages)?).await?;
+                }
+                system
+                    .read_async()
+                    .await
+                    .store_consumer_offset(
+                        &self.session,
+                        consumer,
+                        &topic.stream_id,
+                        &topic.topic_id,
+                        last_offset,
+                    )
+                    .await?;
+                exported_messages += messages.len();
+                if messages.len() < self.batch_size as usize {
+                    break;
+                }
+            }
+        }
+
+        Ok(exported_messages)
+    }
+}
+
+#[async_trait]
+impl ServerCommand<ExportMessagesCommand> for ExportMessagesExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: ExportMessagesCommand) {
+        let Some(storage) = &self.storage else {
+            return;
+        };
+
+        for topic in &self.topics {
+            match self.export_topic(system, storage, topic).await {
+                Ok(0) => {}
+                Ok(exported_messages) => info!(
+                    "Exported {exported_messages} messages of topic: {} in stream: {}.",
+                    topic.topic, topic.stream
+                ),
+                Err(error) => error!(
+                    "Failed to export messages of topic: {} in stream: {}. Error: {error}",
+                    topic.topic, topic.stream
+                ),
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<ExportMessagesCommand>,
+    ) {
+        let exporter = MessagesExporter::new(&config.export, sender);
+        exporter.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<ExportMessagesCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            warn!("Server command handler stopped receiving commands.");
+        });
+    }
+}
@@ -1,3 +1,11 @@
+pub mod check_disk_space;
+pub mod check_thresholds;
+pub mod clean_consumer_groups;
 pub mod clean_messages;
 pub mod clean_personal_access_tokens;
+pub mod clean_trash;
+pub mod disable_expired_users;
+pub mod export_messages;
+pub mod publish_events;
+pub mod publish_system_topics;
 pub mod save_messages;
@@ -0,0 +1,78 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::{EventsConfig, ServerConfig};
+use crate::streaming::events::event::EventEnvelope;
+use crate::streaming::events::log_sink::LogSink;
+use crate::streaming::events::sink::EventSink;
+use crate::streaming::events::topic_sink::TopicSink;
+use crate::streaming::events::webhook_sink::WebhookSink;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::{Receiver, Sender};
+use tracing::{info, warn};
+
+/// Dispatches the events raised by the system to the enabled sinks.
+pub struct PublishEventsExecutor {
+    sinks: Vec<Box<dyn EventSink>>,
+}
+
+impl PublishEventsExecutor {
+    pub fn new(config: &EventsConfig) -> Self {
+        let mut sinks: Vec<Box<dyn EventSink>> = Vec::new();
+        if config.log.enabled {
+            sinks.push(Box::new(LogSink::new(&config.log)));
+        }
+        if config.topic.enabled {
+            sinks.push(Box::new(TopicSink::new(&config.topic)));
+        }
+        if config.webhook.enabled {
+            sinks.push(Box::new(WebhookSink::new(&config.webhook)));
+        }
+        Self { sinks }
+    }
+}
+
+#[async_trait]
+impl ServerCommand<EventEnvelope> for PublishEventsExecutor {
+    async fn execute(&mut self, system: &SharedSystem, envelope: EventEnvelope) {
+        for sink in self.sinks.iter_mut() {
+            if sink.accepts(&envelope.event) {
+                sink.publish(system, &envelope).await;
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        sender: Sender<EventEnvelope>,
+    ) {
+        if self.sinks.is_empty() {
+            info!("Event sinks are disabled.");
+            return;
+        }
+
+        let sinks = self
+            .sinks
+            .iter()
+            .map(|sink| sink.name())
+            .collect::<Vec<_>>();
+        info!("Events will be published to the sinks: {sinks:?}.");
+        system.write().events = Some(sender);
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<EventEnvelope>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(envelope) = receiver.recv_async().await {
+                self.execute(&system, envelope).await;
+            }
+            warn!("Server command handler stopped receiving commands.");
+        });
+    }
+}
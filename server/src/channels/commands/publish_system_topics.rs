@@ -0,0 +1,180 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::{ServerConfig, SystemTopicsConfig};
+use crate::streaming::diagnostics::metrics::MetricsSnapshot;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::systems::system_topics::AuditRecord;
+use async_trait::async_trait;
+use bytes::Bytes;
+use flume::{Receiver, Sender};
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Acks, Message, Partitioning};
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::Serialize;
+use std::net::{Ipv4Addr, SocketAddr};
+use tokio::time;
+use tracing::{error, info, warn};
+
+pub struct SystemMetricsPublisher {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<SystemTopicsCommand>,
+}
+
+#[derive(Debug, Clone)]
+pub enum SystemTopicsCommand {
+    /// Appends the snapshot of the server metrics to the metrics topic.
+    PublishMetrics,
+    /// Appends the record of the handled command to the audit topic.
+    Audit(AuditRecord),
+}
+
+#[derive(Debug, Serialize)]
+struct MetricsRecord {
+    timestamp: u64,
+    #[serde(flatten)]
+    metrics: MetricsSnapshot,
+}
+
+/// Produces the server metrics and the audit records into the system topics, using the root session.
+#[derive(Debug)]
+pub struct PublishSystemTopicsExecutor {
+    enabled: bool,
+    stream: String,
+    metrics_topic: String,
+    audit_topic: String,
+    session: Session,
+}
+
+impl SystemMetricsPublisher {
+    pub fn new(config: &SystemTopicsConfig, sender: Sender<SystemTopicsCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.metrics_interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("System topics are disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "System topics are enabled, server metrics will be published every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(SystemTopicsCommand::PublishMetrics)
+                    .unwrap_or_else(|error| {
+                        error!("Failed to send PublishMetrics command. Error: {}", error);
+                    });
+            }
+        });
+    }
+}
+
+impl PublishSystemTopicsExecutor {
+    pub fn new(config: &SystemTopicsConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            stream: config.stream.clone(),
+            metrics_topic: config.metrics_topic.clone(),
+            audit_topic: config.audit_topic.clone(),
+            session: Session::stateless(
+                DEFAULT_ROOT_USER_ID,
+                SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+            ),
+        }
+    }
+
+    async fn append<T: Serialize>(
+        &self,
+        system: &SharedSystem,
+        topic: &str,
+        record: &T,
+    ) -> Result<(), IggyError> {
+        let stream_id = Identifier::named(&self.stream)?;
+        let topic_id = Identifier::named(topic)?;
+        let payload = serde_json::to_vec(record).map_err(|_| IggyError::InvalidFormat)?;
+        let message = Message::new(None, Bytes::from(payload), None);
+        system
+            .read()
+            .append_messages(
+                &self.session,
+                &stream_id,
+                &topic_id,
+                &Partitioning::balanced(),
+                &vec![message],
+                Acks::Leader,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ServerCommand<SystemTopicsCommand> for PublishSystemTopicsExecutor {
+    async fn execute(&mut self, system: &SharedSystem, command: SystemTopicsCommand) {
+        let (topic, result) = match command {
+            SystemTopicsCommand::PublishMetrics => {
+                let record = MetricsRecord {
+                    timestamp: IggyTimestamp::now().to_micros(),
+                    metrics: system.read().metrics.snapshot(),
+                };
+                let topic = &self.metrics_topic;
+                (topic, self.append(system, topic, &record).await)
+            }
+            SystemTopicsCommand::Audit(record) => {
+                let topic = &self.audit_topic;
+                (topic, self.append(system, topic, &record).await)
+            }
+        };
+
+        if let Err(error) = result {
+            error!(
+                "Failed to append message to system topic: {topic} in stream: {}. Error: {error}",
+                self.stream
+            );
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<SystemTopicsCommand>,
+    ) {
+        if self.enabled {
+            system.write().system_topics = Some(sender.clone());
+        }
+        let metrics_publisher = SystemMetricsPublisher::new(&config.system_topics, sender);
+        metrics_publisher.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<SystemTopicsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            warn!("Server command handler stopped receiving commands.");
+        });
+    }
+}
@@ -1,10 +1,12 @@
 use crate::channels::server_command::ServerCommand;
 use crate::configs::server::MessageSaverConfig;
 use crate::configs::server::ServerConfig;
+use crate::streaming::events::event::ServerEvent;
 use crate::streaming::systems::system::SharedSystem;
 use async_trait::async_trait;
 use flume::{Receiver, Sender};
 use iggy::utils::duration::IggyDuration;
+use std::time::Instant;
 use tokio::time;
 use tracing::{error, info, warn};
 
@@ -61,14 +63,24 @@ impl MessagesSaver {
 #[async_trait]
 impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
     async fn execute(&mut self, system: &SharedSystem, _command: SaveMessagesCommand) {
-        system
-            .read()
-            .persist_messages()
-            .await
-            .unwrap_or_else(|error| {
+        let started_at = Instant::now();
+        let system = system.read();
+        match system.persist_messages().await {
+            Ok(()) => {
+                info!("Buffered messages saved on disk.");
+                system.publish_event(ServerEvent::MessagesFlushed {
+                    duration_ms: started_at.elapsed().as_millis() as u64,
+                });
+            }
+            Err(error) => {
                 error!("Couldn't save buffered messages on disk. Error: {}", error);
-            });
-        info!("Buffered messages saved on disk.");
+                system.publish_event(ServerEvent::Error {
+                    source: "message_saver".to_string(),
+                    code: error.as_code(),
+                    reason: error.to_string(),
+                });
+            }
+        }
     }
 
     fn start_command_sender(
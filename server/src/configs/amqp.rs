@@ -0,0 +1,18 @@
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AmqpConfig {
+    pub enabled: bool,
+    pub address: String,
+    pub frame_max: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub heartbeat: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub poll_interval: IggyDuration,
+    pub max_message_size: IggyByteSize,
+}
@@ -0,0 +1,23 @@
+use crate::server_error::ServerError;
+pub use iggy::bootstrap::definition::{
+    BootstrapDefinition, BootstrapStream, BootstrapTopic, BootstrapUser,
+};
+use std::path::Path;
+
+/// Loads the bootstrap definition from the TOML, JSON or YAML file.
+pub async fn load_definition(path: &str) -> Result<BootstrapDefinition, ServerError> {
+    let content = tokio::fs::read_to_string(path).await.map_err(|error| {
+        ServerError::CannotLoadConfiguration(format!(
+            "Cannot read the bootstrap definition: {path}, error: {error}"
+        ))
+    })?;
+    let extension = Path::new(path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default();
+    BootstrapDefinition::from_extension(extension, &content).map_err(|error| {
+        ServerError::CannotLoadConfiguration(format!(
+            "Cannot load the bootstrap definition: {path}, {error}"
+        ))
+    })
+}
@@ -1,17 +1,34 @@
+use crate::configs::amqp::AmqpConfig;
 use crate::configs::http::{
     HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
 };
+use crate::configs::ip_filter::IpFilterConfig;
+use crate::configs::mqtt::MqttConfig;
+use crate::configs::otlp::OtlpConfig;
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
+use crate::configs::runtimes::{
+    DedicatedRuntimeConfig, IoRuntimeConfig, PartitionShardsConfig, RuntimesConfig,
+};
 use crate::configs::server::{
-    MessageCleanerConfig, MessageSaverConfig, PersonalAccessTokenCleanerConfig,
-    PersonalAccessTokenConfig, ServerConfig,
+    BackpressureConfig, ConnectorsConfig, EventsConfig, EventsLogSinkConfig, EventsTopicSinkConfig,
+    EventsWebhookSinkConfig, ExportConfig, HeartbeatConfig, MessageCleanerConfig,
+    MessageSaverConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig, ServerConfig,
+    SystemTopicsConfig,
 };
 use crate::configs::system::{
-    CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LoggingConfig,
-    MessageDeduplicationConfig, PartitionConfig, RetentionPolicyConfig, RuntimeConfig,
-    SegmentConfig, StreamConfig, SystemConfig, TopicConfig,
+    AnonymousAccessConfig, BootstrapConfig, CacheConfig, CompressionConfig,
+    ConsumerGroupCleanerConfig, ConsumerGroupExpiryConfig, DatabaseConfig, DiskSpaceConfig,
+    EncryptionConfig, LimitsConfig, LoggingConfig, MemoryBudgetConfig, MessageDeduplicationConfig,
+    MetadataLogConfig, MigrationConfig, PartitionConfig, RetentionPolicyConfig, RuntimeConfig,
+    SchemaRegistryConfig, SegmentConfig, StartupConfig, StateConfig, StorageBackend, StorageConfig,
+    StreamConfig, SystemConfig, SystemHeadersConfig, TopicAutoCreateConfig, TopicConfig,
+    TrashCleanerConfig, TrashConfig, UserExpiryConfig,
 };
-use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use crate::configs::tcp::{TcpConfig, TcpResponseCoalescingConfig, TcpSocketConfig, TcpTlsConfig};
+use crate::streaming::events::event::{CONSUMER_LAG, DISK_USAGE, TOPIC_CREATED};
+use iggy::models::schema::SchemaCompatibility;
+use iggy::utils::checksum::ChecksumPolicy;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 impl Default for ServerConfig {
@@ -20,10 +37,143 @@ impl Default for ServerConfig {
             message_cleaner: MessageCleanerConfig::default(),
             message_saver: MessageSaverConfig::default(),
             personal_access_token: PersonalAccessTokenConfig::default(),
+            heartbeat: HeartbeatConfig::default(),
+            backpressure: BackpressureConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
             http: HttpConfig::default(),
+            events: EventsConfig::default(),
+            system_topics: SystemTopicsConfig::default(),
+            connectors: ConnectorsConfig::default(),
+            mqtt: MqttConfig::default(),
+            amqp: AmqpConfig::default(),
+            otlp: OtlpConfig::default(),
+            export: ExportConfig::default(),
+            runtimes: RuntimesConfig::default(),
+        }
+    }
+}
+
+impl Default for EventsConfig {
+    fn default() -> EventsConfig {
+        EventsConfig {
+            check_interval: "1 m".parse().unwrap(),
+            consumer_lag_threshold: 10000,
+            disk_usage_threshold: 90,
+            log: EventsLogSinkConfig::default(),
+            topic: EventsTopicSinkConfig::default(),
+            webhook: EventsWebhookSinkConfig::default(),
+        }
+    }
+}
+
+impl Default for EventsTopicSinkConfig {
+    fn default() -> EventsTopicSinkConfig {
+        EventsTopicSinkConfig {
+            enabled: false,
+            stream: "_system".to_string(),
+            topic: "_events".to_string(),
+            partitions_count: 1,
+            events: vec![],
+        }
+    }
+}
+
+impl Default for EventsWebhookSinkConfig {
+    fn default() -> EventsWebhookSinkConfig {
+        EventsWebhookSinkConfig {
+            enabled: false,
+            urls: vec![],
+            secret: "".to_string(),
+            events: vec![
+                TOPIC_CREATED.to_string(),
+                CONSUMER_LAG.to_string(),
+                DISK_USAGE.to_string(),
+            ],
+            timeout: "5 s".parse().unwrap(),
+            max_retries: 3,
+            retry_interval: "1 s".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for SystemTopicsConfig {
+    fn default() -> SystemTopicsConfig {
+        SystemTopicsConfig {
+            enabled: false,
+            stream: "_system".to_string(),
+            metrics_topic: "metrics".to_string(),
+            metrics_interval: "10 s".parse().unwrap(),
+            audit_topic: "audit".to_string(),
+            message_expiry: "7 days".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for ConnectorsConfig {
+    fn default() -> ConnectorsConfig {
+        ConnectorsConfig {
+            enabled: false,
+            path: "connectors".to_string(),
+            poll_interval: "1 s".parse().unwrap(),
+            batch_size: 100,
+            max_retries: 3,
+            retry_interval: "1 s".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for ExportConfig {
+    fn default() -> ExportConfig {
+        ExportConfig {
+            enabled: false,
+            interval: "1 h".parse().unwrap(),
+            topics: Vec::new(),
+            batch_size: 100_000,
+            bucket: "".to_string(),
+            prefix: "iggy".to_string(),
+            region: "us-east-1".to_string(),
+            endpoint: "".to_string(),
+            access_key_id: "".to_string(),
+            secret_access_key: "".to_string(),
+        }
+    }
+}
+
+impl Default for MqttConfig {
+    fn default() -> MqttConfig {
+        MqttConfig {
+            enabled: false,
+            address: "0.0.0.0:1883".to_string(),
+            topic_template: "{stream}/{topic}".to_string(),
+            max_packet_size: "1 MB".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for AmqpConfig {
+    fn default() -> AmqpConfig {
+        AmqpConfig {
+            enabled: false,
+            address: "0.0.0.0:5672".to_string(),
+            frame_max: 131072,
+            heartbeat: "60 s".parse().unwrap(),
+            poll_interval: "100 ms".parse().unwrap(),
+            max_message_size: "10 MB".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for OtlpConfig {
+    fn default() -> OtlpConfig {
+        OtlpConfig {
+            enabled: false,
+            address: "0.0.0.0:4318".to_string(),
+            traces_topic: "otel/traces".to_string(),
+            logs_topic: "otel/logs".to_string(),
+            payload_format: "json".to_string(),
+            max_request_size: "4 MB".parse().unwrap(),
         }
     }
 }
@@ -41,6 +191,7 @@ impl Default for QuicConfig {
             keep_alive_interval: "5s".parse().unwrap(),
             max_idle_timeout: "10s".parse().unwrap(),
             certificate: QuicCertificateConfig::default(),
+            ip_filter: IpFilterConfig::default(),
         }
     }
 }
@@ -61,6 +212,29 @@ impl Default for TcpConfig {
             enabled: true,
             address: "127.0.0.1:8090".to_string(),
             tls: TcpTlsConfig::default(),
+            socket: TcpSocketConfig::default(),
+            response_coalescing: TcpResponseCoalescingConfig::default(),
+            ip_filter: IpFilterConfig::default(),
+        }
+    }
+}
+
+impl Default for TcpSocketConfig {
+    fn default() -> TcpSocketConfig {
+        TcpSocketConfig {
+            override_defaults: false,
+            nodelay: true,
+            recv_buffer_size: "100 KB".parse().unwrap(),
+            send_buffer_size: "100 KB".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for TcpResponseCoalescingConfig {
+    fn default() -> TcpResponseCoalescingConfig {
+        TcpResponseCoalescingConfig {
+            enabled: false,
+            max_size: "64 KB".parse().unwrap(),
         }
     }
 }
@@ -70,10 +244,12 @@ impl Default for HttpConfig {
         HttpConfig {
             enabled: true,
             address: "127.0.0.1:3000".to_string(),
+            base_path: "".to_string(),
             cors: HttpCorsConfig::default(),
             jwt: HttpJwtConfig::default(),
             metrics: HttpMetricsConfig::default(),
             tls: HttpTlsConfig::default(),
+            ip_filter: IpFilterConfig::default(),
         }
     }
 }
@@ -88,6 +264,7 @@ impl Default for HttpJwtConfig {
             valid_audiences: vec!["iggy".to_string()],
             access_token_expiry: "1h".parse().unwrap(),
             refresh_token_expiry: "1d".parse().unwrap(),
+            refresh_token_max_lifetime: "30d".parse().unwrap(),
             clock_skew: "5s".parse().unwrap(),
             not_before: "0s".parse().unwrap(),
             encoding_secret: "top_secret$iggy.rs$_jwt_HS256_key#!".to_string(),
@@ -125,6 +302,25 @@ impl Default for PersonalAccessTokenConfig {
     }
 }
 
+impl Default for HeartbeatConfig {
+    fn default() -> HeartbeatConfig {
+        HeartbeatConfig {
+            enabled: true,
+            interval: "30s".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for BackpressureConfig {
+    fn default() -> BackpressureConfig {
+        BackpressureConfig {
+            enabled: true,
+            max_in_flight_commands: 100,
+            max_buffered_size: "100 MB".parse().unwrap(),
+        }
+    }
+}
+
 impl Default for PersonalAccessTokenCleanerConfig {
     fn default() -> PersonalAccessTokenCleanerConfig {
         PersonalAccessTokenCleanerConfig {
@@ -138,11 +334,18 @@ impl Default for SystemConfig {
     fn default() -> SystemConfig {
         SystemConfig {
             path: "local_data".to_string(),
+            storage: StorageConfig::default(),
+            state: StateConfig::default(),
             database: DatabaseConfig::default(),
             runtime: RuntimeConfig::default(),
+            metadata_log: MetadataLogConfig::default(),
+            migration: MigrationConfig::default(),
+            bootstrap: BootstrapConfig::default(),
             logging: LoggingConfig::default(),
             cache: CacheConfig::default(),
+            memory_budget: MemoryBudgetConfig::default(),
             retention_policy: RetentionPolicyConfig::default(),
+            limits: LimitsConfig::default(),
             stream: StreamConfig::default(),
             encryption: EncryptionConfig::default(),
             topic: TopicConfig::default(),
@@ -150,6 +353,15 @@ impl Default for SystemConfig {
             segment: SegmentConfig::default(),
             compression: CompressionConfig::default(),
             message_deduplication: MessageDeduplicationConfig::default(),
+            system_headers: SystemHeadersConfig::default(),
+            schema_registry: SchemaRegistryConfig::default(),
+            trash: TrashConfig::default(),
+            consumer_group_expiry: ConsumerGroupExpiryConfig::default(),
+            disk_space: DiskSpaceConfig::default(),
+            startup: StartupConfig::default(),
+            user_ip_filters: HashMap::new(),
+            anonymous_access: AnonymousAccessConfig::default(),
+            user_expiry: UserExpiryConfig::default(),
         }
     }
 }
@@ -162,6 +374,23 @@ impl Default for DatabaseConfig {
     }
 }
 
+impl Default for StorageConfig {
+    fn default() -> StorageConfig {
+        StorageConfig {
+            backend: StorageBackend::File,
+            fault_scenario_path: "".to_string(),
+        }
+    }
+}
+
+impl Default for StateConfig {
+    fn default() -> StateConfig {
+        StateConfig {
+            path: "".to_string(),
+        }
+    }
+}
+
 impl Default for RuntimeConfig {
     fn default() -> RuntimeConfig {
         RuntimeConfig {
@@ -170,6 +399,35 @@ impl Default for RuntimeConfig {
     }
 }
 
+impl Default for MetadataLogConfig {
+    fn default() -> MetadataLogConfig {
+        MetadataLogConfig {
+            enabled: true,
+            path: "metadata.log".to_string(),
+        }
+    }
+}
+
+impl Default for MigrationConfig {
+    fn default() -> MigrationConfig {
+        MigrationConfig {
+            auto_migrate: true,
+            backup: true,
+            backup_path: "backup".to_string(),
+        }
+    }
+}
+
+impl Default for BootstrapConfig {
+    fn default() -> BootstrapConfig {
+        BootstrapConfig {
+            enabled: false,
+            path: "configs/bootstrap.toml".to_string(),
+            update_existing: false,
+        }
+    }
+}
+
 impl Default for CompressionConfig {
     fn default() -> Self {
         CompressionConfig {
@@ -184,8 +442,12 @@ impl Default for LoggingConfig {
         LoggingConfig {
             path: "logs".to_string(),
             level: "info".to_string(),
+            format: "text".to_string(),
+            rotation: "hourly".to_string(),
             max_size: "200 MB".parse().unwrap(),
+            max_files: 10,
             retention: "7 days".parse().unwrap(),
+            slow_request_threshold: "1 s".parse().unwrap(),
         }
     }
 }
@@ -199,6 +461,15 @@ impl Default for CacheConfig {
     }
 }
 
+impl Default for MemoryBudgetConfig {
+    fn default() -> MemoryBudgetConfig {
+        MemoryBudgetConfig {
+            enabled: true,
+            size: "75%".parse().unwrap(),
+        }
+    }
+}
+
 impl Default for RetentionPolicyConfig {
     fn default() -> RetentionPolicyConfig {
         RetentionPolicyConfig {
@@ -220,6 +491,20 @@ impl Default for TopicConfig {
     fn default() -> TopicConfig {
         TopicConfig {
             path: "topics".to_string(),
+            max_message_size: "0".parse().unwrap(),
+            auto_create: TopicAutoCreateConfig::default(),
+        }
+    }
+}
+
+impl Default for TopicAutoCreateConfig {
+    fn default() -> TopicAutoCreateConfig {
+        TopicAutoCreateConfig {
+            enabled: false,
+            partitions_count: 1,
+            message_expiry: "0".parse().unwrap(),
+            max_topic_size: "0".parse().unwrap(),
+            replication_factor: 1,
         }
     }
 }
@@ -231,6 +516,7 @@ impl Default for PartitionConfig {
             messages_required_to_save: 1000,
             enforce_fsync: false,
             validate_checksum: false,
+            checksum_policy: ChecksumPolicy::Off,
         }
     }
 }
@@ -245,6 +531,88 @@ impl Default for SegmentConfig {
     }
 }
 
+impl Default for SystemHeadersConfig {
+    fn default() -> SystemHeadersConfig {
+        SystemHeadersConfig { enabled: true }
+    }
+}
+
+impl Default for SchemaRegistryConfig {
+    fn default() -> SchemaRegistryConfig {
+        SchemaRegistryConfig {
+            default_compatibility: SchemaCompatibility::Backward,
+        }
+    }
+}
+
+impl Default for TrashConfig {
+    fn default() -> TrashConfig {
+        TrashConfig {
+            path: "trash".to_string(),
+            retention: "1 d".parse().unwrap(),
+            cleaner: TrashCleanerConfig::default(),
+        }
+    }
+}
+
+impl Default for TrashCleanerConfig {
+    fn default() -> TrashCleanerConfig {
+        TrashCleanerConfig {
+            enabled: true,
+            interval: "1 m".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for ConsumerGroupExpiryConfig {
+    fn default() -> ConsumerGroupExpiryConfig {
+        ConsumerGroupExpiryConfig {
+            offsets_retention: "7 d".parse().unwrap(),
+            group_retention: "30 d".parse().unwrap(),
+            cleaner: ConsumerGroupCleanerConfig::default(),
+        }
+    }
+}
+
+impl Default for StartupConfig {
+    fn default() -> StartupConfig {
+        StartupConfig {
+            max_concurrent_partition_loads: 64,
+            lazy_segment_indexes: false,
+            progress_interval: "5 s".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for UserExpiryConfig {
+    fn default() -> UserExpiryConfig {
+        UserExpiryConfig {
+            enabled: true,
+            check_interval: "1 m".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for DiskSpaceConfig {
+    fn default() -> DiskSpaceConfig {
+        DiskSpaceConfig {
+            enabled: false,
+            check_interval: "5 s".parse().unwrap(),
+            min_free_space_for_partitions: "5 GB".parse().unwrap(),
+            min_free_space_for_sends: "1 GB".parse().unwrap(),
+        }
+    }
+}
+
+impl Default for ConsumerGroupCleanerConfig {
+    fn default() -> ConsumerGroupCleanerConfig {
+        ConsumerGroupCleanerConfig {
+            enabled: false,
+            interval: "1 h".parse().unwrap(),
+        }
+    }
+}
+
 impl Default for MessageDeduplicationConfig {
     fn default() -> MessageDeduplicationConfig {
         MessageDeduplicationConfig {
@@ -254,3 +622,33 @@ impl Default for MessageDeduplicationConfig {
         }
     }
 }
+
+impl Default for RuntimesConfig {
+    fn default() -> RuntimesConfig {
+        RuntimesConfig {
+            io: IoRuntimeConfig::default(),
+            storage: DedicatedRuntimeConfig {
+                dedicated: false,
+                worker_threads: 2,
+                cores: vec![],
+            },
+            background: DedicatedRuntimeConfig {
+                dedicated: false,
+                worker_threads: 1,
+                cores: vec![],
+            },
+            partition_shards: PartitionShardsConfig::default(),
+        }
+    }
+}
+
+impl Default for PartitionShardsConfig {
+    fn default() -> PartitionShardsConfig {
+        PartitionShardsConfig {
+            enabled: false,
+            count: 0,
+            pin_cores: true,
+            cores: vec![],
+        }
+    }
+}
@@ -1,15 +1,28 @@
+use crate::configs::amqp::AmqpConfig;
+use crate::configs::ip_filter::IpFilterConfig;
+use crate::configs::mqtt::MqttConfig;
+use crate::configs::otlp::OtlpConfig;
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
-use crate::configs::system::MessageDeduplicationConfig;
+use crate::configs::system::{
+    AnonymousAccessConfig, BootstrapConfig, ConsumerGroupExpiryConfig, DiskSpaceConfig,
+    MessageDeduplicationConfig, MetadataLogConfig, MigrationConfig, SchemaRegistryConfig,
+    StartupConfig, SystemHeadersConfig, TopicAutoCreateConfig, TrashConfig, UserExpiryConfig,
+};
 use crate::configs::{
     http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
     resource_quota::MemoryResourceQuota,
-    server::{MessageCleanerConfig, MessageSaverConfig, ServerConfig},
+    runtimes::{DedicatedRuntimeConfig, IoRuntimeConfig, PartitionShardsConfig, RuntimesConfig},
+    server::{
+        BackpressureConfig, ConnectorsConfig, EventsConfig, EventsLogSinkConfig,
+        EventsTopicSinkConfig, EventsWebhookSinkConfig, ExportConfig, HeartbeatConfig,
+        MessageCleanerConfig, MessageSaverConfig, ServerConfig, SystemTopicsConfig,
+    },
     system::{
-        CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LoggingConfig,
-        PartitionConfig, RetentionPolicyConfig, SegmentConfig, StreamConfig, SystemConfig,
-        TopicConfig,
+        CacheConfig, CompressionConfig, DatabaseConfig, EncryptionConfig, LimitsConfig,
+        LoggingConfig, MemoryBudgetConfig, PartitionConfig, RetentionPolicyConfig, SegmentConfig,
+        StateConfig, StorageBackend, StorageConfig, StreamConfig, SystemConfig, TopicConfig,
     },
-    tcp::{TcpConfig, TcpTlsConfig},
+    tcp::{TcpConfig, TcpResponseCoalescingConfig, TcpSocketConfig, TcpTlsConfig},
 };
 use std::fmt::{Display, Formatter};
 
@@ -17,8 +30,15 @@ impl Display for HttpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, cors: {}, jwt: {}, metrics: {}, tls: {} }}",
-            self.enabled, self.address, self.cors, self.jwt, self.metrics, self.tls
+            "{{ enabled: {}, address: {}, base_path: {}, cors: {}, jwt: {}, metrics: {}, tls: {}, ip_filter: {} }}",
+            self.enabled,
+            self.address,
+            self.base_path,
+            self.cors,
+            self.jwt,
+            self.metrics,
+            self.tls,
+            self.ip_filter
         )
     }
 }
@@ -37,8 +57,13 @@ impl Display for HttpJwtConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ algorithm: {}, audience: {}, expiry: {}, use_base64_secret: {} }}",
-            self.algorithm, self.audience, self.access_token_expiry, self.use_base64_secret
+            "{{ algorithm: {}, audience: {}, expiry: {}, refresh_token_expiry: {}, refresh_token_max_lifetime: {}, use_base64_secret: {} }}",
+            self.algorithm,
+            self.audience,
+            self.access_token_expiry,
+            self.refresh_token_expiry,
+            self.refresh_token_max_lifetime,
+            self.use_base64_secret
         )
     }
 }
@@ -67,7 +92,7 @@ impl Display for QuicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {} }}",
+          "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {}, ip_filter: {} }}",
           self.enabled,
           self.address,
           self.max_concurrent_bidi_streams,
@@ -77,7 +102,8 @@ impl Display for QuicConfig {
           self.receive_window,
           self.keep_alive_interval,
           self.max_idle_timeout,
-          self.certificate
+          self.certificate,
+          self.ip_filter
       )
     }
 }
@@ -115,8 +141,145 @@ impl Display for ServerConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ message_cleaner: {}, message_saver: {}, system: {}, quic: {}, tcp: {}, http: {} }}",
-            self.message_cleaner, self.message_saver, self.system, self.quic, self.tcp, self.http
+            "{{ message_cleaner: {}, message_saver: {}, heartbeat: {}, backpressure: {}, system: {}, quic: {}, tcp: {}, http: {}, events: {}, system_topics: {}, connectors: {}, mqtt: {}, amqp: {}, otlp: {}, export: {}, runtimes: {} }}",
+            self.message_cleaner, self.message_saver, self.heartbeat, self.backpressure, self.system, self.quic, self.tcp, self.http, self.events, self.system_topics, self.connectors, self.mqtt, self.amqp, self.otlp, self.export, self.runtimes
+        )
+    }
+}
+
+impl Display for EventsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ check_interval: {}, consumer_lag_threshold: {}, disk_usage_threshold: {}%, log: {}, topic: {}, webhook: {} }}",
+            self.check_interval,
+            self.consumer_lag_threshold,
+            self.disk_usage_threshold,
+            self.log,
+            self.topic,
+            self.webhook
+        )
+    }
+}
+
+impl Display for EventsLogSinkConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, events: {:?} }}",
+            self.enabled, self.events
+        )
+    }
+}
+
+impl Display for EventsTopicSinkConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, stream: {}, topic: {}, partitions_count: {}, events: {:?} }}",
+            self.enabled, self.stream, self.topic, self.partitions_count, self.events
+        )
+    }
+}
+
+impl Display for EventsWebhookSinkConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, urls: {:?}, events: {:?}, timeout: {}, max_retries: {}, retry_interval: {} }}",
+            self.enabled,
+            self.urls,
+            self.events,
+            self.timeout,
+            self.max_retries,
+            self.retry_interval
+        )
+    }
+}
+
+impl Display for SystemTopicsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, stream: {}, metrics_topic: {}, metrics_interval: {}, audit_topic: {}, message_expiry: {} }}",
+            self.enabled,
+            self.stream,
+            self.metrics_topic,
+            self.metrics_interval,
+            self.audit_topic,
+            self.message_expiry
+        )
+    }
+}
+
+impl Display for ConnectorsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, path: {}, poll_interval: {}, batch_size: {}, max_retries: {}, retry_interval: {} }}",
+            self.enabled,
+            self.path,
+            self.poll_interval,
+            self.batch_size,
+            self.max_retries,
+            self.retry_interval
+        )
+    }
+}
+
+impl Display for ExportConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, topics: {:?}, batch_size: {}, bucket: {}, prefix: {}, region: {}, endpoint: {} }}",
+            self.enabled,
+            self.interval,
+            self.topics,
+            self.batch_size,
+            self.bucket,
+            self.prefix,
+            self.region,
+            self.endpoint
+        )
+    }
+}
+
+impl Display for MqttConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, topic_template: {}, max_packet_size: {} }}",
+            self.enabled, self.address, self.topic_template, self.max_packet_size
+        )
+    }
+}
+
+impl Display for AmqpConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, frame_max: {}, heartbeat: {}, poll_interval: {}, max_message_size: {} }}",
+            self.enabled,
+            self.address,
+            self.frame_max,
+            self.heartbeat,
+            self.poll_interval,
+            self.max_message_size
+        )
+    }
+}
+
+impl Display for OtlpConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, traces_topic: {}, logs_topic: {}, payload_format: {}, max_request_size: {} }}",
+            self.enabled,
+            self.address,
+            self.traces_topic,
+            self.logs_topic,
+            self.payload_format,
+            self.max_request_size
         )
     }
 }
@@ -131,6 +294,26 @@ impl Display for MessageCleanerConfig {
     }
 }
 
+impl Display for HeartbeatConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {} }}",
+            self.enabled, self.interval
+        )
+    }
+}
+
+impl Display for BackpressureConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, max_in_flight_commands: {}, max_buffered_size: {} }}",
+            self.enabled, self.max_in_flight_commands, self.max_buffered_size
+        )
+    }
+}
+
 impl Display for MessageSaverConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -141,6 +324,31 @@ impl Display for MessageSaverConfig {
     }
 }
 
+impl Display for StorageConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ backend: {}, fault_scenario_path: {} }}",
+            self.backend, self.fault_scenario_path
+        )
+    }
+}
+
+impl Display for StorageBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageBackend::File => write!(f, "file"),
+            StorageBackend::Memory => write!(f, "memory"),
+        }
+    }
+}
+
+impl Display for StateConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ path: {} }}", self.path)
+    }
+}
+
 impl Display for DatabaseConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ path: {} }}", self.path)
@@ -153,6 +361,12 @@ impl Display for CacheConfig {
     }
 }
 
+impl Display for MemoryBudgetConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ enabled: {}, size: {} }}", self.enabled, self.size)
+    }
+}
+
 impl Display for RetentionPolicyConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -164,6 +378,20 @@ impl Display for RetentionPolicyConfig {
     }
 }
 
+impl Display for LimitsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ max_streams: {}, max_topics_per_stream: {}, max_partitions_per_topic: {}, max_segments_per_partition: {}, max_scanned_messages_per_query: {} }}",
+            self.max_streams,
+            self.max_topics_per_stream,
+            self.max_partitions_per_topic,
+            self.max_segments_per_partition,
+            self.max_scanned_messages_per_query
+        )
+    }
+}
+
 impl Display for EncryptionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ enabled: {} }}", self.enabled)
@@ -178,7 +406,28 @@ impl Display for StreamConfig {
 
 impl Display for TopicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ path: {} }}", self.path)
+        write!(
+            f,
+            "{{ path: {}, max_message_size: {}, auto_create: {} }}",
+            self.path,
+            self.max_message_size
+                .as_human_string_with_zero_as_unlimited(),
+            self.auto_create
+        )
+    }
+}
+
+impl Display for TopicAutoCreateConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, partitions_count: {}, message_expiry: {}, max_topic_size: {}, replication_factor: {} }}",
+            self.enabled,
+            self.partitions_count,
+            self.message_expiry.as_secs(),
+            self.max_topic_size.as_human_string_with_zero_as_unlimited(),
+            self.replication_factor
+        )
     }
 }
 
@@ -186,11 +435,12 @@ impl Display for PartitionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {} }}",
+          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {}, checksum_policy: {} }}",
           self.path,
           self.messages_required_to_save,
           self.enforce_fsync,
-          self.validate_checksum
+          self.validate_checksum,
+          self.checksum_policy
       )
     }
 }
@@ -219,11 +469,15 @@ impl Display for LoggingConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, level: {}, max_size: {}, retention: {} }}",
+            "{{ path: {}, level: {}, format: {}, rotation: {}, max_size: {}, max_files: {}, retention: {}, slow_request_threshold: {} }}",
             self.path,
             self.level,
+            self.format,
+            self.rotation,
             self.max_size.as_human_string_with_zero_as_unlimited(),
-            self.retention
+            self.max_files,
+            self.retention,
+            self.slow_request_threshold
         )
     }
 }
@@ -232,8 +486,33 @@ impl Display for TcpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, tls: {} }}",
-            self.enabled, self.address, self.tls
+            "{{ enabled: {}, address: {}, tls: {}, socket: {}, response_coalescing: {}, ip_filter: {} }}",
+            self.enabled,
+            self.address,
+            self.tls,
+            self.socket,
+            self.response_coalescing,
+            self.ip_filter
+        )
+    }
+}
+
+impl Display for TcpSocketConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ override_defaults: {}, nodelay: {}, recv_buffer_size: {}, send_buffer_size: {} }}",
+            self.override_defaults, self.nodelay, self.recv_buffer_size, self.send_buffer_size
+        )
+    }
+}
+
+impl Display for TcpResponseCoalescingConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, max_size: {} }}",
+            self.enabled, self.max_size
         )
     }
 }
@@ -248,20 +527,209 @@ impl Display for TcpTlsConfig {
     }
 }
 
+impl Display for MetadataLogConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ enabled: {}, path: {} }}", self.enabled, self.path)
+    }
+}
+
+impl Display for MigrationConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ auto_migrate: {}, backup: {}, backup_path: {} }}",
+            self.auto_migrate, self.backup, self.backup_path
+        )
+    }
+}
+
+impl Display for BootstrapConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, path: {}, update_existing: {} }}",
+            self.enabled, self.path, self.update_existing
+        )
+    }
+}
+
+impl Display for SystemHeadersConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ enabled: {} }}", self.enabled)
+    }
+}
+
+impl Display for SchemaRegistryConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ default_compatibility: {} }}",
+            self.default_compatibility
+        )
+    }
+}
+
+impl Display for TrashConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ path: {}, retention: {}, cleaner: {{ enabled: {}, interval: {} }} }}",
+            self.path, self.retention, self.cleaner.enabled, self.cleaner.interval
+        )
+    }
+}
+
+impl Display for ConsumerGroupExpiryConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ offsets_retention: {}, group_retention: {}, cleaner: {{ enabled: {}, interval: {} }} }}",
+            self.offsets_retention,
+            self.group_retention,
+            self.cleaner.enabled,
+            self.cleaner.interval
+        )
+    }
+}
+
+impl Display for AnonymousAccessConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let streams = self
+            .streams
+            .iter()
+            .map(|stream| {
+                format!(
+                    "{{ stream_id: {}, topic_ids: {:?} }}",
+                    stream.stream_id, stream.topic_ids
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "{{ enabled: {}, streams: [{}] }}", self.enabled, streams)
+    }
+}
+
+impl Display for StartupConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ max_concurrent_partition_loads: {}, lazy_segment_indexes: {}, progress_interval: {} }}",
+            self.max_concurrent_partition_loads, self.lazy_segment_indexes, self.progress_interval
+        )
+    }
+}
+
+impl Display for DiskSpaceConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, check_interval: {}, min_free_space_for_partitions: {}, min_free_space_for_sends: {} }}",
+            self.enabled,
+            self.check_interval,
+            self.min_free_space_for_partitions,
+            self.min_free_space_for_sends
+        )
+    }
+}
+
+impl Display for UserExpiryConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, check_interval: {} }}",
+            self.enabled, self.check_interval
+        )
+    }
+}
+
 impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, database: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {} }}",
+          "{{ path: {}, storage: {}, state: {}, database: {}, metadata_log: {}, migration: {}, bootstrap: {}, logging: {}, cache: {}, memory_budget: {}, limits: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, system_headers: {}, schema_registry: {}, trash: {}, consumer_group_expiry: {}, disk_space: {}, startup: {}, user_ip_filters: {:?}, anonymous_access: {}, user_expiry: {} }}",
           self.path,
+          self.storage,
+          self.state,
           self.database,
+          self.metadata_log,
+          self.migration,
+          self.bootstrap,
           self.logging,
           self.cache,
+          self.memory_budget,
+          self.limits,
           self.stream,
           self.topic,
           self.partition,
           self.segment,
-          self.encryption
+          self.encryption,
+          self.system_headers,
+          self.schema_registry,
+          self.trash,
+          self.consumer_group_expiry,
+          self.disk_space,
+          self.startup,
+          self.user_ip_filters.keys().collect::<Vec<_>>(),
+          self.anonymous_access,
+          self.user_expiry
       )
     }
 }
+
+impl Display for IpFilterConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let join = |networks: &[ipnet::IpNet]| {
+            networks
+                .iter()
+                .map(|network| network.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        write!(
+            f,
+            "{{ allow: [{}], deny: [{}] }}",
+            join(&self.allow),
+            join(&self.deny)
+        )
+    }
+}
+
+impl Display for RuntimesConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ io: {}, storage: {}, background: {}, partition_shards: {} }}",
+            self.io, self.storage, self.background, self.partition_shards
+        )
+    }
+}
+
+impl Display for IoRuntimeConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ worker_threads: {}, cores: {:?} }}",
+            self.worker_threads, self.cores
+        )
+    }
+}
+
+impl Display for DedicatedRuntimeConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ dedicated: {}, worker_threads: {}, cores: {:?} }}",
+            self.dedicated, self.worker_threads, self.cores
+        )
+    }
+}
+
+impl Display for PartitionShardsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, count: {}, pin_cores: {}, cores: {:?} }}",
+            self.enabled, self.count, self.pin_cores, self.cores
+        )
+    }
+}
@@ -1,3 +1,4 @@
+use crate::configs::ip_filter::IpFilterConfig;
 use iggy::error::IggyError;
 use iggy::utils::duration::IggyDuration;
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
@@ -9,10 +10,12 @@ use serde_with::DisplayFromStr;
 pub struct HttpConfig {
     pub enabled: bool,
     pub address: String,
+    pub base_path: String,
     pub cors: HttpCorsConfig,
     pub jwt: HttpJwtConfig,
     pub metrics: HttpMetricsConfig,
     pub tls: HttpTlsConfig,
+    pub ip_filter: IpFilterConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
@@ -39,6 +42,8 @@ pub struct HttpJwtConfig {
     #[serde_as(as = "DisplayFromStr")]
     pub refresh_token_expiry: IggyDuration,
     #[serde_as(as = "DisplayFromStr")]
+    pub refresh_token_max_lifetime: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
     pub clock_skew: IggyDuration,
     #[serde_as(as = "DisplayFromStr")]
     pub not_before: IggyDuration,
@@ -0,0 +1,74 @@
+use ipnet::IpNet;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+/// The CIDR-based rules for the client addresses, the deny rules take precedence over the allow ones.
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct IpFilterConfig {
+    /// If not empty, only the addresses within these networks are allowed.
+    pub allow: Vec<IpNet>,
+    pub deny: Vec<IpNet>,
+}
+
+impl IpFilterConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.allow.is_empty() || !self.deny.is_empty()
+    }
+
+    pub fn is_allowed(&self, address: &IpAddr) -> bool {
+        // The IPv4 clients connected to the dual-stack listener have the IPv4-mapped IPv6 addresses.
+        let address = address.to_canonical();
+        if self.deny.iter().any(|network| network.contains(&address)) {
+            return false;
+        }
+
+        self.allow.is_empty() || self.allow.iter().any(|network| network.contains(&address))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(allow: &[&str], deny: &[&str]) -> IpFilterConfig {
+        IpFilterConfig {
+            allow: allow
+                .iter()
+                .map(|network| network.parse().unwrap())
+                .collect(),
+            deny: deny
+                .iter()
+                .map(|network| network.parse().unwrap())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn any_address_should_be_allowed_given_no_rules() {
+        let filter = filter(&[], &[]);
+        assert!(!filter.is_enabled());
+        assert!(filter.is_allowed(&"192.168.1.10".parse().unwrap()));
+        assert!(filter.is_allowed(&"::1".parse().unwrap()));
+    }
+
+    #[test]
+    fn only_addresses_within_allowed_networks_should_be_allowed() {
+        let filter = filter(&["10.0.0.0/8", "fd00::/8"], &[]);
+        assert!(filter.is_allowed(&"10.1.2.3".parse().unwrap()));
+        assert!(filter.is_allowed(&"fd00::1".parse().unwrap()));
+        assert!(!filter.is_allowed(&"192.168.1.10".parse().unwrap()));
+    }
+
+    #[test]
+    fn denied_network_should_take_precedence_over_allowed_one() {
+        let filter = filter(&["10.0.0.0/8"], &["10.0.1.0/24"]);
+        assert!(filter.is_allowed(&"10.0.2.1".parse().unwrap()));
+        assert!(!filter.is_allowed(&"10.0.1.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn ipv4_mapped_address_should_be_matched_as_ipv4() {
+        let filter = filter(&[], &["203.0.113.0/24"]);
+        assert!(!filter.is_allowed(&"::ffff:203.0.113.7".parse().unwrap()));
+    }
+}
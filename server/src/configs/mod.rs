@@ -1,12 +1,18 @@
 pub mod server;
 pub mod system;
 
+pub mod amqp;
 pub mod http;
+pub mod mqtt;
+pub mod otlp;
 pub mod quic;
 pub mod tcp;
 
+pub mod bootstrap;
 pub mod config_provider;
 pub mod defaults;
 pub mod displays;
+pub mod ip_filter;
 pub mod resource_quota;
+pub mod runtimes;
 pub mod validators;
@@ -0,0 +1,10 @@
+use iggy::utils::byte_size::IggyByteSize;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MqttConfig {
+    pub enabled: bool,
+    pub address: String,
+    pub topic_template: String,
+    pub max_packet_size: IggyByteSize,
+}
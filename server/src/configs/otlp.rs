@@ -0,0 +1,12 @@
+use iggy::utils::byte_size::IggyByteSize;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OtlpConfig {
+    pub enabled: bool,
+    pub address: String,
+    pub traces_topic: String,
+    pub logs_topic: String,
+    pub payload_format: String,
+    pub max_request_size: IggyByteSize,
+}
@@ -1,3 +1,4 @@
+use crate::configs::ip_filter::IpFilterConfig;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use serde::{Deserialize, Serialize};
@@ -19,6 +20,7 @@ pub struct QuicConfig {
     #[serde_as(as = "DisplayFromStr")]
     pub max_idle_timeout: IggyDuration,
     pub certificate: QuicCertificateConfig,
+    pub ip_filter: IpFilterConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RuntimesConfig {
+    pub io: IoRuntimeConfig,
+    pub storage: DedicatedRuntimeConfig,
+    pub background: DedicatedRuntimeConfig,
+    pub partition_shards: PartitionShardsConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct IoRuntimeConfig {
+    pub worker_threads: usize,
+    pub cores: Vec<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DedicatedRuntimeConfig {
+    pub dedicated: bool,
+    pub worker_threads: usize,
+    pub cores: Vec<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PartitionShardsConfig {
+    pub enabled: bool,
+    pub count: usize,
+    pub pin_cores: bool,
+    pub cores: Vec<usize>,
+}
@@ -1,9 +1,14 @@
+use crate::configs::amqp::AmqpConfig;
 use crate::configs::config_provider::ConfigProvider;
 use crate::configs::http::HttpConfig;
+use crate::configs::mqtt::MqttConfig;
+use crate::configs::otlp::OtlpConfig;
 use crate::configs::quic::QuicConfig;
+use crate::configs::runtimes::RuntimesConfig;
 use crate::configs::system::SystemConfig;
 use crate::configs::tcp::TcpConfig;
 use crate::server_error::ServerError;
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
 use serde::{Deserialize, Serialize};
@@ -16,10 +21,20 @@ pub struct ServerConfig {
     pub message_cleaner: MessageCleanerConfig,
     pub message_saver: MessageSaverConfig,
     pub personal_access_token: PersonalAccessTokenConfig,
+    pub heartbeat: HeartbeatConfig,
+    pub backpressure: BackpressureConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
     pub http: HttpConfig,
+    pub events: EventsConfig,
+    pub system_topics: SystemTopicsConfig,
+    pub connectors: ConnectorsConfig,
+    pub mqtt: MqttConfig,
+    pub amqp: AmqpConfig,
+    pub otlp: OtlpConfig,
+    pub export: ExportConfig,
+    pub runtimes: RuntimesConfig,
 }
 
 #[serde_as]
@@ -53,6 +68,111 @@ pub struct PersonalAccessTokenCleanerConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct HeartbeatConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EventsConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub check_interval: IggyDuration,
+    pub consumer_lag_threshold: u64,
+    pub disk_usage_threshold: u8,
+    pub log: EventsLogSinkConfig,
+    pub topic: EventsTopicSinkConfig,
+    pub webhook: EventsWebhookSinkConfig,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct EventsLogSinkConfig {
+    pub enabled: bool,
+    pub events: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EventsTopicSinkConfig {
+    pub enabled: bool,
+    pub stream: String,
+    pub topic: String,
+    pub partitions_count: u32,
+    pub events: Vec<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EventsWebhookSinkConfig {
+    pub enabled: bool,
+    pub urls: Vec<String>,
+    pub secret: String,
+    pub events: Vec<String>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+    pub max_retries: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retry_interval: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SystemTopicsConfig {
+    pub enabled: bool,
+    pub stream: String,
+    pub metrics_topic: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub metrics_interval: IggyDuration,
+    pub audit_topic: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub message_expiry: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ConnectorsConfig {
+    pub enabled: bool,
+    pub path: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub poll_interval: IggyDuration,
+    pub batch_size: u32,
+    pub max_retries: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retry_interval: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ExportConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    pub topics: Vec<String>,
+    pub batch_size: u32,
+    pub bucket: String,
+    pub prefix: String,
+    pub region: String,
+    pub endpoint: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct BackpressureConfig {
+    pub enabled: bool,
+    pub max_in_flight_commands: u32,
+    pub max_buffered_size: IggyByteSize,
+}
+
+impl EventsConfig {
+    /// Returns `true` if at least one of the sinks is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.log.enabled || self.topic.enabled || self.webhook.enabled
+    }
+}
+
 impl ServerConfig {
     pub async fn load(config_provider: &dyn ConfigProvider) -> Result<ServerConfig, ServerError> {
         let server_config = config_provider.load_config().await?;
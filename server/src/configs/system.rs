@@ -1,20 +1,35 @@
+use crate::configs::ip_filter::IpFilterConfig;
 use crate::configs::resource_quota::MemoryResourceQuota;
+use iggy::models::schema::SchemaCompatibility;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::checksum::ChecksumPolicy;
 use iggy::{
     compression::compression_algorithm::CompressionAlgorithm, utils::duration::IggyDuration,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// The name of the file written to the state directory once the server has shut down gracefully.
+const CLEAN_SHUTDOWN_MARKER: &str = "clean_shutdown";
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SystemConfig {
     pub path: String,
+    pub storage: StorageConfig,
+    pub state: StateConfig,
     pub database: DatabaseConfig,
     pub runtime: RuntimeConfig,
+    pub metadata_log: MetadataLogConfig,
+    pub migration: MigrationConfig,
+    pub bootstrap: BootstrapConfig,
     pub logging: LoggingConfig,
     pub cache: CacheConfig,
+    pub memory_budget: MemoryBudgetConfig,
     pub retention_policy: RetentionPolicyConfig,
+    pub limits: LimitsConfig,
     pub stream: StreamConfig,
     pub topic: TopicConfig,
     pub partition: PartitionConfig,
@@ -22,6 +37,41 @@ pub struct SystemConfig {
     pub encryption: EncryptionConfig,
     pub compression: CompressionConfig,
     pub message_deduplication: MessageDeduplicationConfig,
+    pub system_headers: SystemHeadersConfig,
+    pub schema_registry: SchemaRegistryConfig,
+    pub trash: TrashConfig,
+    pub consumer_group_expiry: ConsumerGroupExpiryConfig,
+    pub disk_space: DiskSpaceConfig,
+    pub startup: StartupConfig,
+    /// The address rules applied on login, by username.
+    pub user_ip_filters: HashMap<String, IpFilterConfig>,
+    pub anonymous_access: AnonymousAccessConfig,
+    pub user_expiry: UserExpiryConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    /// The path of the TOML file describing the faults injected into the segment storage, for testing only.
+    /// Used only if the server is built with the `fault-injection` feature.
+    #[serde(default)]
+    pub fault_scenario_path: String,
+}
+
+/// The storage of the streams, topics, partitions and messages.
+/// The users, permissions and the other system state are always kept in the database.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// The segments are persisted on disk and loaded once the server restarts.
+    File,
+    /// The segments are kept in memory only and lost once the server stops, e.g. for the tests or the caching use cases.
+    Memory,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StateConfig {
+    pub path: String,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -34,6 +84,26 @@ pub struct RuntimeConfig {
     pub path: String,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MetadataLogConfig {
+    pub enabled: bool,
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MigrationConfig {
+    pub auto_migrate: bool,
+    pub backup: bool,
+    pub backup_path: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BootstrapConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub update_existing: bool,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CompressionConfig {
     pub allow_override: bool,
@@ -45,9 +115,14 @@ pub struct CompressionConfig {
 pub struct LoggingConfig {
     pub path: String,
     pub level: String,
+    pub format: String,
+    pub rotation: String,
     pub max_size: IggyByteSize,
+    pub max_files: u32,
     #[serde_as(as = "DisplayFromStr")]
     pub retention: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub slow_request_threshold: IggyDuration,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -56,6 +131,12 @@ pub struct CacheConfig {
     pub size: MemoryResourceQuota,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MemoryBudgetConfig {
+    pub enabled: bool,
+    pub size: MemoryResourceQuota,
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize, Copy, Clone)]
 pub struct RetentionPolicyConfig {
@@ -64,6 +145,17 @@ pub struct RetentionPolicyConfig {
     pub max_topic_size: IggyByteSize,
 }
 
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct LimitsConfig {
+    pub max_streams: u32,
+    pub max_topics_per_stream: u32,
+    pub max_partitions_per_topic: u32,
+    pub max_segments_per_partition: u32,
+    /// The maximum number of messages scanned by a single messages query, "0" means the scan is not limited.
+    #[serde(default)]
+    pub max_scanned_messages_per_query: u64,
+}
+
 #[derive(Debug, Deserialize, Serialize, Default)]
 pub struct EncryptionConfig {
     pub enabled: bool,
@@ -78,6 +170,19 @@ pub struct StreamConfig {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct TopicConfig {
     pub path: String,
+    pub max_message_size: IggyByteSize,
+    pub auto_create: TopicAutoCreateConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TopicAutoCreateConfig {
+    pub enabled: bool,
+    pub partitions_count: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub message_expiry: IggyDuration,
+    pub max_topic_size: IggyByteSize,
+    pub replication_factor: u8,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -86,6 +191,7 @@ pub struct PartitionConfig {
     pub messages_required_to_save: u32,
     pub enforce_fsync: bool,
     pub validate_checksum: bool,
+    pub checksum_policy: ChecksumPolicy,
 }
 
 #[serde_as]
@@ -97,6 +203,102 @@ pub struct MessageDeduplicationConfig {
     pub expiry: IggyDuration,
 }
 
+/// The headers in the reserved `$iggy.` namespace stamped by the server on every appended message.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SystemHeadersConfig {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SchemaRegistryConfig {
+    pub default_compatibility: SchemaCompatibility,
+}
+
+/// The read-only access of the unauthenticated clients to the allowed streams and topics, e.g. for the public data feeds.
+#[derive(Debug, Deserialize, Serialize, Default, Clone)]
+pub struct AnonymousAccessConfig {
+    pub enabled: bool,
+    pub streams: Vec<AnonymousStreamConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AnonymousStreamConfig {
+    pub stream_id: u32,
+    /// If empty, all the topics of the stream can be read.
+    #[serde(default)]
+    pub topic_ids: Vec<u32>,
+}
+
+/// The loading of the streams, topics and partitions on startup.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct StartupConfig {
+    pub max_concurrent_partition_loads: usize,
+    pub lazy_segment_indexes: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub progress_interval: IggyDuration,
+}
+
+/// The free space on the disk of the system path, checked periodically, so that the server rejects the risky operations
+/// while the space is running out, rather than failing in the middle of writing the segment.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct DiskSpaceConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub check_interval: IggyDuration,
+    pub min_free_space_for_partitions: IggyByteSize,
+    pub min_free_space_for_sends: IggyByteSize,
+}
+
+/// The users past their expiry or inactivity window, checked periodically, get disabled.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct UserExpiryConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub check_interval: IggyDuration,
+}
+
+/// The soft deleted streams and topics kept in the trash until the retention expires.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TrashConfig {
+    pub path: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retention: IggyDuration,
+    pub cleaner: TrashCleanerConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct TrashCleanerConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
+/// The consumer groups without members and inactive for the retention period get their offsets deleted,
+/// and eventually the groups themselves.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ConsumerGroupExpiryConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub offsets_retention: IggyDuration,
+    /// The zero value keeps the groups, once their offsets are deleted.
+    #[serde_as(as = "DisplayFromStr")]
+    pub group_retention: IggyDuration,
+    pub cleaner: ConsumerGroupCleanerConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+pub struct ConsumerGroupCleanerConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SegmentConfig {
     pub size: IggyByteSize,
@@ -109,12 +311,61 @@ impl SystemConfig {
         self.path.to_string()
     }
 
+    /// Returns the base path of the server state (database, metadata log and runtime files),
+    /// which is the system path, unless the state path is configured.
+    pub fn get_state_path(&self) -> String {
+        if self.state.path.is_empty() {
+            return self.get_system_path();
+        }
+
+        if Path::new(&self.state.path).is_absolute() {
+            return self.state.path.to_string();
+        }
+
+        format!("{}/{}", self.get_system_path(), self.state.path)
+    }
+
+    pub fn get_clean_shutdown_marker_path(&self) -> String {
+        format!("{}/{}", self.get_state_path(), CLEAN_SHUTDOWN_MARKER)
+    }
+
     pub fn get_database_path(&self) -> String {
-        format!("{}/{}", self.get_system_path(), self.database.path)
+        format!("{}/{}", self.get_state_path(), self.database.path)
     }
 
     pub fn get_runtime_path(&self) -> String {
-        format!("{}/{}", self.get_system_path(), self.runtime.path)
+        format!("{}/{}", self.get_state_path(), self.runtime.path)
+    }
+
+    pub fn get_metadata_log_path(&self) -> String {
+        format!("{}/{}", self.get_state_path(), self.metadata_log.path)
+    }
+
+    pub fn get_migration_backup_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.migration.backup_path)
+    }
+
+    pub fn get_trash_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.trash.path)
+    }
+
+    pub fn get_trashed_stream_path(&self, stream_id: u32) -> String {
+        format!(
+            "{}/{}/{}",
+            self.get_trash_path(),
+            self.stream.path,
+            stream_id
+        )
+    }
+
+    pub fn get_trashed_topic_path(&self, stream_id: u32, topic_id: u32) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            self.get_trash_path(),
+            self.topic.path,
+            stream_id,
+            topic_id
+        )
     }
 
     pub fn get_streams_path(&self) -> String {
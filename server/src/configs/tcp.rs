@@ -1,3 +1,5 @@
+use crate::configs::ip_filter::IpFilterConfig;
+use iggy::utils::byte_size::IggyByteSize;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -5,6 +7,9 @@ pub struct TcpConfig {
     pub enabled: bool,
     pub address: String,
     pub tls: TcpTlsConfig,
+    pub socket: TcpSocketConfig,
+    pub response_coalescing: TcpResponseCoalescingConfig,
+    pub ip_filter: IpFilterConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
@@ -13,3 +18,17 @@ pub struct TcpTlsConfig {
     pub certificate: String,
     pub password: String,
 }
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TcpSocketConfig {
+    pub override_defaults: bool,
+    pub nodelay: bool,
+    pub recv_buffer_size: IggyByteSize,
+    pub send_buffer_size: IggyByteSize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct TcpResponseCoalescingConfig {
+    pub enabled: bool,
+    pub max_size: IggyByteSize,
+}
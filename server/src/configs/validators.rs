@@ -1,24 +1,204 @@
 extern crate sysinfo;
 
-use super::server::{MessageCleanerConfig, MessageSaverConfig};
+use super::amqp::AmqpConfig;
+use super::http::{HttpConfig, HttpCorsConfig, HttpJwtConfig};
+use super::mqtt::MqttConfig;
+use super::otlp::OtlpConfig;
+use super::runtimes::RuntimesConfig;
+use super::server::{
+    BackpressureConfig, ConnectorsConfig, EventsConfig, ExportConfig, HeartbeatConfig,
+    MessageCleanerConfig, MessageSaverConfig, SystemTopicsConfig,
+};
 use super::system::CompressionConfig;
+use super::tcp::TcpConfig;
+use crate::amqp::frame::FRAME_MIN_SIZE as AMQP_FRAME_MIN_SIZE;
 use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
-use crate::configs::system::{CacheConfig, RetentionPolicyConfig, SegmentConfig};
+use crate::configs::system::{
+    AnonymousAccessConfig, CacheConfig, ConsumerGroupExpiryConfig, DiskSpaceConfig, LimitsConfig,
+    LoggingConfig, MemoryBudgetConfig, RetentionPolicyConfig, SegmentConfig, StartupConfig,
+    TopicAutoCreateConfig, UserExpiryConfig,
+};
+use crate::export::topic::ExportedTopic;
+use crate::log::rotation::RotationPeriod;
+use crate::mqtt::mapping::TopicMapping;
+use crate::mqtt::packet::MAX_PACKET_SIZE as MQTT_MAX_PACKET_SIZE;
+use crate::otlp::encoding::Encoding;
+use crate::otlp::mapping::OtlpTopic;
 use crate::server_error::ServerError;
+use crate::streaming::events::event::EVENTS;
 use crate::streaming::segments::segment;
+use axum::http::{HeaderName, HeaderValue, Method};
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::partitions::MAX_PARTITIONS_COUNT;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::text;
 use iggy::validatable::Validatable;
+use std::str::FromStr;
 use sysinfo::System;
 use tracing::{error, info, warn};
+use tracing_subscriber::filter::Targets;
 
 impl Validatable<ServerError> for ServerConfig {
     fn validate(&self) -> Result<(), ServerError> {
+        self.system.logging.validate()?;
         self.system.segment.validate()?;
         self.system.cache.validate()?;
+        self.system.memory_budget.validate()?;
         self.system.retention_policy.validate()?;
+        self.system.limits.validate()?;
+        self.system.topic.auto_create.validate()?;
         self.system.compression.validate()?;
+        self.system.consumer_group_expiry.validate()?;
+        self.system.disk_space.validate()?;
+        self.system.startup.validate()?;
+        self.system.anonymous_access.validate()?;
+        self.system.user_expiry.validate()?;
         self.personal_access_token.validate()?;
+        self.tcp.validate()?;
+        self.http.validate()?;
+        self.heartbeat.validate()?;
+        self.backpressure.validate()?;
+        self.events.validate()?;
+        self.system_topics.validate()?;
+        self.connectors.validate()?;
+        self.mqtt.validate()?;
+        self.amqp.validate()?;
+        self.otlp.validate()?;
+        self.export.validate()?;
+        self.runtimes.validate()?;
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for TcpConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.socket.override_defaults {
+            for (name, size) in [
+                ("recv_buffer_size", self.socket.recv_buffer_size),
+                ("send_buffer_size", self.socket.send_buffer_size),
+            ] {
+                let size = size.as_bytes_u64();
+                if size == 0 || size > u32::MAX as u64 {
+                    error!(
+                        "TCP configuration -> socket {name} must be greater than 0 and fit in 4 GB."
+                    );
+                    return Err(ServerError::InvalidConfiguration);
+                }
+            }
+        }
+
+        if self.response_coalescing.enabled && self.response_coalescing.max_size.as_bytes_u64() == 0
+        {
+            error!("TCP configuration -> response coalescing max size must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for HttpConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if !self.base_path.is_empty()
+            && (!self.base_path.starts_with('/')
+                || self.base_path.ends_with('/')
+                || self.base_path.contains(['?', '#', ':', '*', '{', '}']))
+        {
+            error!(
+                "HTTP configuration -> invalid base path: {}, it must start with \"/\", must not end with \"/\" and must not contain any of: ?#:*{{}}.",
+                self.base_path
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        self.jwt.validate()?;
+        self.cors.validate()
+    }
+}
+
+impl Validatable<ServerError> for HttpJwtConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.access_token_expiry.is_zero() {
+            error!("HTTP JWT configuration -> access token expiry cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.refresh_token_expiry.as_secs() <= self.access_token_expiry.as_secs() {
+            error!(
+                "HTTP JWT configuration -> refresh token expiry: {} must be greater than access token expiry: {}.",
+                self.refresh_token_expiry, self.access_token_expiry
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if !self.refresh_token_max_lifetime.is_zero()
+            && self.refresh_token_max_lifetime.as_secs() < self.refresh_token_expiry.as_secs()
+        {
+            error!(
+                "HTTP JWT configuration -> refresh token max lifetime: {} cannot be lower than refresh token expiry: {}.",
+                self.refresh_token_max_lifetime, self.refresh_token_expiry
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for HttpCorsConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let is_any = |value: &String| value == "*";
+        if let Some(origin) = self
+            .allowed_origins
+            .iter()
+            .find(|origin| !is_any(origin) && origin.parse::<HeaderValue>().is_err())
+        {
+            error!("HTTP CORS configuration -> invalid allowed origin: {origin}.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if let Some(method) = self
+            .allowed_methods
+            .iter()
+            .find(|method| !is_any(method) && Method::from_str(&method.to_uppercase()).is_err())
+        {
+            error!("HTTP CORS configuration -> invalid allowed method: {method}.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if let Some(header) = self
+            .allowed_headers
+            .iter()
+            .chain(&self.exposed_headers)
+            .find(|header| !is_any(header) && header.parse::<HeaderName>().is_err())
+        {
+            error!("HTTP CORS configuration -> invalid header: {header}.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        // The browsers reject the wildcard responses to the requests with credentials.
+        if self.allow_credentials
+            && [
+                &self.allowed_origins,
+                &self.allowed_methods,
+                &self.allowed_headers,
+                &self.exposed_headers,
+            ]
+            .iter()
+            .any(|values| values.iter().any(is_any))
+        {
+            error!("HTTP CORS configuration -> credentials cannot be allowed along with \"*\" for the origins, methods or headers.");
+            return Err(ServerError::InvalidConfiguration);
+        }
 
         Ok(())
     }
@@ -76,6 +256,26 @@ impl Validatable<ServerError> for CacheConfig {
     }
 }
 
+impl Validatable<ServerError> for MemoryBudgetConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let limit_bytes = self.size.clone().into();
+        if limit_bytes == 0 {
+            error!("Memory budget size cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        info!(
+            "Memory budget configuration -> unsaved messages and caches are limited to {}.",
+            IggyByteSize::from(limit_bytes).as_human_string()
+        );
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for RetentionPolicyConfig {
     fn validate(&self) -> Result<(), ServerError> {
         // TODO(hubcio): Change this message once topic size based retention policy is fully developed.
@@ -87,6 +287,70 @@ impl Validatable<ServerError> for RetentionPolicyConfig {
     }
 }
 
+impl Validatable<ServerError> for LimitsConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.max_partitions_per_topic > MAX_PARTITIONS_COUNT {
+            error!(
+                "Limits configuration -> max partitions per topic cannot be greater than {}.",
+                MAX_PARTITIONS_COUNT
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for TopicAutoCreateConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if !(1..=MAX_PARTITIONS_COUNT).contains(&self.partitions_count) {
+            error!(
+                "Topic auto-create configuration -> partitions count must be between 1 and {}.",
+                MAX_PARTITIONS_COUNT
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.replication_factor == 0 {
+            error!("Topic auto-create configuration -> replication factor cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for LoggingConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if Targets::from_str(&self.level).is_err() {
+            error!("Logging configuration -> invalid level: {}.", self.level);
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if !self.format.eq_ignore_ascii_case("text") && !self.format.eq_ignore_ascii_case("json") {
+            error!(
+                "Logging configuration -> invalid format: {}, expected \"text\" or \"json\".",
+                self.format
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if RotationPeriod::from_str(&self.rotation).is_err() {
+            error!(
+                "Logging configuration -> invalid rotation: {}, expected \"never\", \"minutely\", \"hourly\" or \"daily\".",
+                self.rotation
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for SegmentConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.size.as_bytes_u64() as u32 > segment::MAX_SIZE_BYTES {
@@ -123,6 +387,133 @@ impl Validatable<ServerError> for MessageCleanerConfig {
     }
 }
 
+impl Validatable<ServerError> for AnonymousAccessConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.streams.is_empty() {
+            error!("Anonymous access configuration -> at least one stream must be allowed.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.streams.iter().any(|stream| stream.stream_id == 0) {
+            error!("Anonymous access configuration -> stream ID cannot be zero.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for StartupConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.progress_interval.is_zero() {
+            error!("Startup progress interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for UserExpiryConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.enabled && self.check_interval.is_zero() {
+            error!("User expiry check interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for DiskSpaceConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.check_interval.is_zero() {
+            error!("Disk space check interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        // The partitions are blocked first, so the space left for the sends isn't consumed by the new segments.
+        if self.min_free_space_for_sends.as_bytes_u64()
+            > self.min_free_space_for_partitions.as_bytes_u64()
+        {
+            error!("Disk space configuration -> min free space for sends cannot be greater than the min free space for partitions.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for ConsumerGroupExpiryConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.cleaner.enabled {
+            return Ok(());
+        }
+
+        if self.cleaner.interval.is_zero() {
+            error!("Consumer group cleaner interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.offsets_retention.is_zero() {
+            error!("Consumer group offsets retention cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if !self.group_retention.is_zero()
+            && self.group_retention.as_micros() < self.offsets_retention.as_micros()
+        {
+            error!("Consumer group retention cannot be shorter than the offsets retention.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for HeartbeatConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.enabled && self.interval.is_zero() {
+            error!("Heartbeat interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for BackpressureConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.max_in_flight_commands == 0 {
+            error!("Max in-flight commands cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        let max_buffered_size = self.max_buffered_size.as_bytes_u64();
+        if max_buffered_size == 0 || max_buffered_size > u32::MAX as u64 {
+            error!(
+                "Max buffered size must be greater than 0 and cannot exceed {} bytes.",
+                u32::MAX
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for PersonalAccessTokenConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.max_tokens_per_user == 0 {
@@ -140,3 +531,269 @@ impl Validatable<ServerError> for PersonalAccessTokenConfig {
         Ok(())
     }
 }
+
+impl Validatable<ServerError> for EventsConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        let sinks_events = [
+            ("log", &self.log.events),
+            ("topic", &self.topic.events),
+            ("webhook", &self.webhook.events),
+        ];
+        for (sink, events) in sinks_events {
+            if let Some(event) = events
+                .iter()
+                .find(|event| !EVENTS.contains(&event.as_str()))
+            {
+                error!("Events configuration -> unknown event: {event} for {sink} sink.");
+                return Err(ServerError::InvalidConfiguration);
+            }
+        }
+
+        if self.topic.enabled
+            && (self.topic.stream.is_empty()
+                || self.topic.topic.is_empty()
+                || self.topic.partitions_count == 0)
+        {
+            error!("Events configuration -> topic sink requires the stream name, the topic name and at least one partition.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.webhook.enabled && self.webhook.urls.is_empty() {
+            error!("Events configuration -> at least one URL is required when webhook sink is enabled.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.check_interval.is_zero() {
+            error!(
+                "Events configuration -> check interval cannot be zero, it must be greater than 0."
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.disk_usage_threshold > 100 {
+            error!("Events configuration -> disk usage threshold cannot be greater than 100%.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for SystemTopicsConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if [&self.stream, &self.metrics_topic, &self.audit_topic]
+            .iter()
+            .any(|name| name.is_empty() || !text::is_resource_name_valid(name))
+        {
+            error!("System topics configuration -> stream and topic names must be valid resource names.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.metrics_topic == self.audit_topic {
+            error!("System topics configuration -> metrics and audit topics must be different.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.metrics_interval.is_zero() {
+            error!("System topics configuration -> metrics interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for ConnectorsConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.path.is_empty() {
+            error!("Connectors configuration -> path cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.batch_size == 0 {
+            error!(
+                "Connectors configuration -> batch size cannot be zero, it must be greater than 0."
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.poll_interval.is_zero() {
+            error!("Connectors configuration -> poll interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for ExportConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.interval.as_micros() == 0 {
+            error!("Export configuration -> interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.topics.is_empty() {
+            error!("Export configuration -> topics cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        for topic in &self.topics {
+            if ExportedTopic::parse(topic).is_err() {
+                error!("Export configuration -> topic: {topic} is invalid, expected: <stream>/<topic>.");
+                return Err(ServerError::InvalidConfiguration);
+            }
+        }
+
+        if self.batch_size == 0 {
+            error!("Export configuration -> batch size cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.bucket.is_empty() {
+            error!("Export configuration -> bucket cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for MqttConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if let Err(reason) = TopicMapping::parse(&self.topic_template) {
+            error!("MQTT configuration -> invalid topic template: {reason}.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        let max_packet_size = self.max_packet_size.as_bytes_u64();
+        if max_packet_size == 0 || max_packet_size > MQTT_MAX_PACKET_SIZE {
+            error!("MQTT configuration -> max packet size must be greater than 0 and not greater than {MQTT_MAX_PACKET_SIZE} bytes.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for AmqpConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.frame_max < AMQP_FRAME_MIN_SIZE {
+            error!(
+                "AMQP configuration -> frame max cannot be less than {AMQP_FRAME_MIN_SIZE} bytes."
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.heartbeat.as_secs() > u16::MAX as u32 {
+            error!(
+                "AMQP configuration -> heartbeat cannot be greater than {} seconds.",
+                u16::MAX
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.poll_interval.as_micros() == 0 {
+            error!(
+                "AMQP configuration -> poll interval cannot be zero, it must be greater than 0."
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.max_message_size.as_bytes_u64() == 0 {
+            error!(
+                "AMQP configuration -> max message size cannot be zero, it must be greater than 0."
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for OtlpConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        for topic in [&self.traces_topic, &self.logs_topic] {
+            if OtlpTopic::parse(topic).is_err() {
+                error!("OTLP configuration -> invalid topic: {topic}, it must be specified as <stream>/<topic>.");
+                return Err(ServerError::InvalidConfiguration);
+            }
+        }
+
+        if self.payload_format.parse::<Encoding>().is_err() {
+            error!(
+                "OTLP configuration -> invalid payload format: {}, it must be either json or protobuf.",
+                self.payload_format
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.max_request_size.as_bytes_u64() == 0 {
+            error!(
+                "OTLP configuration -> max request size cannot be zero, it must be greater than 0."
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for RuntimesConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        for (name, dedicated) in [("storage", &self.storage), ("background", &self.background)] {
+            if dedicated.dedicated && dedicated.worker_threads == 0 {
+                error!("Runtimes configuration -> {name} worker threads must be greater than 0 for the dedicated runtime.");
+                return Err(ServerError::InvalidConfiguration);
+            }
+        }
+
+        // The cores are validated against the ones available to the process, so the misconfigured pinning fails the startup, rather than being silently ignored.
+        let available_cores = core_affinity::get_core_ids()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|core| core.id)
+            .collect::<Vec<_>>();
+        for (name, cores) in [
+            ("io", &self.io.cores),
+            ("storage", &self.storage.cores),
+            ("background", &self.background.cores),
+            ("partition shards", &self.partition_shards.cores),
+        ] {
+            if let Some(core) = cores.iter().find(|core| !available_cores.contains(core)) {
+                error!("Runtimes configuration -> {name} core: {core} is not available, the available cores are: {available_cores:?}.");
+                return Err(ServerError::InvalidConfiguration);
+            }
+        }
+
+        Ok(())
+    }
+}
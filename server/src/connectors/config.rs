@@ -0,0 +1,96 @@
+use iggy::connectors::create_connector::CreateConnector;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::utils::text;
+use iggy::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const FILE_SOURCE: &str = "file_source";
+pub const POSTGRES_CDC_SOURCE: &str = "postgres_cdc_source";
+pub const HTTP_SINK: &str = "http_sink";
+pub const DATABASE_SINK: &str = "database_sink";
+pub const S3_SINK: &str = "s3_sink";
+pub const KINDS: &[&str] = &[
+    FILE_SOURCE,
+    POSTGRES_CDC_SOURCE,
+    HTTP_SINK,
+    DATABASE_SINK,
+    S3_SINK,
+];
+
+/// The definition of the connector, persisted in the state directory and started on the server startup.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConnectorConfig {
+    pub name: String,
+    pub kind: String,
+    /// The stream (ID or name) to which the source appends or from which the sink consumes.
+    pub stream: String,
+    /// The topic (ID or name) to which the source appends or from which the sink consumes.
+    pub topic: String,
+    /// The settings specific to the kind of the connector, e.g. `path` of the file source.
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+impl From<&CreateConnector> for ConnectorConfig {
+    fn from(command: &CreateConnector) -> Self {
+        Self {
+            name: command.name.clone(),
+            kind: command.kind.clone(),
+            stream: command.stream.clone(),
+            topic: command.topic.clone(),
+            properties: command.properties.clone(),
+        }
+    }
+}
+
+impl ConnectorConfig {
+    pub fn is_source(&self) -> bool {
+        self.kind == FILE_SOURCE || self.kind == POSTGRES_CDC_SOURCE
+    }
+
+    pub fn get_property(&self, key: &str) -> Result<&str, IggyError> {
+        self.properties
+            .get(key)
+            .map(|value| value.as_str())
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                IggyError::InvalidConnectorConfig(format!(
+                    "property: {key} is required for connector kind: {}",
+                    self.kind
+                ))
+            })
+    }
+
+    pub fn get_stream_id(&self) -> Result<Identifier, IggyError> {
+        Identifier::from_str_value(&self.stream)
+    }
+
+    pub fn get_topic_id(&self) -> Result<Identifier, IggyError> {
+        Identifier::from_str_value(&self.topic)
+    }
+}
+
+impl Validatable<IggyError> for ConnectorConfig {
+    fn validate(&self) -> Result<(), IggyError> {
+        // The name is used as the file name of the definition, so it cannot point outside the directory.
+        if self.name.is_empty()
+            || self.name.starts_with('.')
+            || !text::is_resource_name_valid(&self.name)
+        {
+            return Err(IggyError::InvalidConnectorName);
+        }
+
+        if !KINDS.contains(&self.kind.as_str()) {
+            return Err(IggyError::InvalidConnectorConfig(format!(
+                "unknown kind: {}, available kinds: {KINDS:?}",
+                self.kind
+            )));
+        }
+
+        self.get_stream_id()?;
+        self.get_topic_id()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,552 @@
+use crate::connectors::config::ConnectorConfig;
+use crate::connectors::error::ConnectorError;
+use crate::connectors::sink::Sink;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use iggy::models::header::HeaderKey;
+use iggy::models::messages::Message;
+use serde_json::{Map, Value};
+use sqlx::mysql::{MySqlPool, MySqlPoolOptions};
+use sqlx::postgres::{PgPool, PgPoolOptions};
+use std::sync::Arc;
+
+const URL_PROPERTY: &str = "url";
+const TABLE_PROPERTY: &str = "table";
+const KEY_PROPERTY: &str = "key";
+const KEY_COLUMN_PROPERTY: &str = "key_column";
+const COLUMNS_PROPERTY: &str = "columns";
+const OFFSETS_TABLE_PROPERTY: &str = "offsets_table";
+const DEFAULT_KEY: &str = "id";
+const DEFAULT_KEY_COLUMN: &str = "id";
+const DEFAULT_OFFSETS_TABLE: &str = "iggy_sink_offsets";
+const HEADER_KEY_PREFIX: &str = "header:";
+const PAYLOAD_KEY_PREFIX: &str = "payload:";
+
+/// The source of the primary key of the row.
+#[derive(Debug, PartialEq)]
+enum KeySource {
+    /// The message ID, as the decimal number.
+    Id,
+    /// The string header of the message.
+    Header(HeaderKey),
+    /// The top-level field of the JSON payload.
+    Payload(String),
+}
+
+/// Maps the messages to the table rows: the key to the primary key column and the top-level fields
+/// of the JSON object payload to the columns (the missing fields are written as `NULL`).
+#[derive(Debug, PartialEq)]
+struct RowMapping {
+    table: String,
+    key: KeySource,
+    key_column: String,
+    /// The pairs of the payload field and the column.
+    columns: Vec<(String, String)>,
+}
+
+#[derive(Debug)]
+enum Database {
+    Postgres(PgPool),
+    MySql(MySqlPool),
+}
+
+/// Upserts the JSON messages into the PostgreSQL or MySQL table, depending on the `url` scheme.
+/// The last written offset of each partition is stored in the offsets table within the same transaction
+/// as the rows, so the messages delivered again (e.g. once the server has crashed before storing
+/// the consumer offset) are skipped and each message is applied exactly once.
+#[derive(Debug)]
+pub struct DatabaseSink {
+    connector: String,
+    database: Database,
+    mapping: RowMapping,
+    offsets_table: String,
+    offsets_table_created: bool,
+}
+
+impl DatabaseSink {
+    pub fn new(config: &ConnectorConfig) -> Result<Self, IggyError> {
+        let url = config.get_property(URL_PROPERTY)?;
+        let mapping = RowMapping::new(config)?;
+        let offsets_table = config
+            .properties
+            .get(OFFSETS_TABLE_PROPERTY)
+            .map(|table| table.as_str())
+            .unwrap_or(DEFAULT_OFFSETS_TABLE);
+        validate_identifier(offsets_table)?;
+
+        // The pool connects on the first write, so the unavailable database is retried like any other failure.
+        let database = if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+            Database::Postgres(
+                PgPoolOptions::new()
+                    .max_connections(1)
+                    .connect_lazy(url)
+                    .map_err(|error| IggyError::InvalidConnectorConfig(error.to_string()))?,
+            )
+        } else if url.starts_with("mysql://") {
+            Database::MySql(
+                MySqlPoolOptions::new()
+                    .max_connections(1)
+                    .connect_lazy(url)
+                    .map_err(|error| IggyError::InvalidConnectorConfig(error.to_string()))?,
+            )
+        } else {
+            return Err(IggyError::InvalidConnectorConfig(
+                "url must start with postgres://, postgresql:// or mysql://".to_string(),
+            ));
+        };
+
+        Ok(Self {
+            connector: config.name.clone(),
+            database,
+            mapping,
+            offsets_table: offsets_table.to_string(),
+            offsets_table_created: false,
+        })
+    }
+
+    async fn create_offsets_table(&mut self) -> Result<(), ConnectorError> {
+        if self.offsets_table_created {
+            return Ok(());
+        }
+
+        let table = self.database.quote(&self.offsets_table);
+        let query = format!(
+            "CREATE TABLE IF NOT EXISTS {table} (connector VARCHAR(255) NOT NULL, partition_id INTEGER NOT NULL, \
+            partition_offset BIGINT NOT NULL, PRIMARY KEY (connector, partition_id))"
+        );
+        match &self.database {
+            Database::Postgres(pool) => sqlx::query(&query).execute(pool).await.map(|_| ()),
+            Database::MySql(pool) => sqlx::query(&query).execute(pool).await.map(|_| ()),
+        }?;
+        self.offsets_table_created = true;
+        Ok(())
+    }
+}
+
+impl Database {
+    fn quote(&self, identifier: &str) -> String {
+        let quote = match self {
+            Database::Postgres(_) => '"',
+            Database::MySql(_) => '`',
+        };
+        identifier
+            .split('.')
+            .map(|part| format!("{quote}{part}{quote}"))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Returns the upsert query of the row, the Postgres one populates the record from the JSON object
+    /// (so the values are cast to the column types), while the MySQL one binds each column value.
+    fn upsert_query(&self, mapping: &RowMapping) -> String {
+        let table = self.quote(&mapping.table);
+        let key_column = self.quote(&mapping.key_column);
+        let columns = mapping
+            .columns
+            .iter()
+            .map(|(_, column)| self.quote(column))
+            .collect::<Vec<_>>();
+        let all_columns = std::iter::once(key_column.clone())
+            .chain(columns.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(", ");
+        match self {
+            Database::Postgres(_) => {
+                let update = match columns.is_empty() {
+                    true => "NOTHING".to_string(),
+                    false => format!(
+                        "UPDATE SET {}",
+                        columns
+                            .iter()
+                            .map(|column| format!("{column} = EXCLUDED.{column}"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+                format!(
+                    "INSERT INTO {table} ({all_columns}) SELECT {all_columns} FROM json_populate_record(NULL::{table}, $1::json) \
+                    ON CONFLICT ({key_column}) DO {update}"
+                )
+            }
+            Database::MySql(_) => {
+                let update = match columns.is_empty() {
+                    true => format!("{key_column} = {key_column}"),
+                    false => columns
+                        .iter()
+                        .map(|column| format!("{column} = VALUES({column})"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                };
+                let placeholders = vec!["?"; columns.len() + 1].join(", ");
+                format!(
+                    "INSERT INTO {table} ({all_columns}) VALUES ({placeholders}) ON DUPLICATE KEY UPDATE {update}"
+                )
+            }
+        }
+    }
+}
+
+impl RowMapping {
+    fn new(config: &ConnectorConfig) -> Result<Self, IggyError> {
+        let table = config.get_property(TABLE_PROPERTY)?;
+        validate_identifier(table)?;
+        let key = config
+            .properties
+            .get(KEY_PROPERTY)
+            .map(|key| key.as_str())
+            .unwrap_or(DEFAULT_KEY);
+        let key = if key == DEFAULT_KEY {
+            KeySource::Id
+        } else if let Some(header) = key.strip_prefix(HEADER_KEY_PREFIX) {
+            KeySource::Header(HeaderKey::new(header)?)
+        } else if let Some(field) = key.strip_prefix(PAYLOAD_KEY_PREFIX) {
+            KeySource::Payload(field.to_string())
+        } else {
+            return Err(IggyError::InvalidConnectorConfig(format!(
+                "invalid key: {key}, expected: {DEFAULT_KEY}, {HEADER_KEY_PREFIX}<name> or {PAYLOAD_KEY_PREFIX}<field>"
+            )));
+        };
+        let key_column = config
+            .properties
+            .get(KEY_COLUMN_PROPERTY)
+            .map(|column| column.as_str())
+            .unwrap_or(DEFAULT_KEY_COLUMN);
+        validate_identifier(key_column)?;
+
+        let mut columns = Vec::new();
+        for mapping in config
+            .properties
+            .get(COLUMNS_PROPERTY)
+            .map(|columns| columns.as_str())
+            .unwrap_or_default()
+            .split(',')
+            .map(|mapping| mapping.trim())
+            .filter(|mapping| !mapping.is_empty())
+        {
+            let (field, column) = mapping.split_once(':').unwrap_or((mapping, mapping));
+            let (field, column) = (field.trim(), column.trim());
+            validate_identifier(column)?;
+            if field.is_empty() || column == key_column {
+                return Err(IggyError::InvalidConnectorConfig(format!(
+                    "invalid column mapping: {mapping}"
+                )));
+            }
+            columns.push((field.to_string(), column.to_string()));
+        }
+
+        Ok(Self {
+            table: table.to_string(),
+            key,
+            key_column: key_column.to_string(),
+            columns,
+        })
+    }
+
+    /// Returns the key and the column values of the message, in the order of the mapped columns.
+    fn map(&self, message: &Message) -> Result<(Value, Vec<Value>), ConnectorError> {
+        let payload = match serde_json::from_slice::<Value>(&message.payload) {
+            Ok(Value::Object(payload)) => payload,
+            _ => {
+                return Err(ConnectorError::InvalidMessage(format!(
+                    "payload of message at offset: {} is not a JSON object",
+                    message.offset
+                )))
+            }
+        };
+
+        let key = match &self.key {
+            KeySource::Id => Value::String(message.id.to_string()),
+            KeySource::Header(header) => message
+                .headers
+                .as_ref()
+                .and_then(|headers| headers.get(header))
+                .and_then(|value| value.as_str().ok())
+                .map(|value| Value::String(value.to_string()))
+                .ok_or_else(|| {
+                    ConnectorError::InvalidMessage(format!(
+                        "message at offset: {} has no string header: {}",
+                        message.offset,
+                        header.as_str()
+                    ))
+                })?,
+            KeySource::Payload(field) => match payload.get(field) {
+                Some(Value::Null) | None => {
+                    return Err(ConnectorError::InvalidMessage(format!(
+                        "payload of message at offset: {} has no field: {field}",
+                        message.offset
+                    )))
+                }
+                Some(value) => value.clone(),
+            },
+        };
+        let values = self
+            .columns
+            .iter()
+            .map(|(field, _)| payload.get(field).cloned().unwrap_or(Value::Null))
+            .collect();
+        Ok((key, values))
+    }
+
+    /// Returns the JSON object with the column names, populated into the record by Postgres.
+    fn to_record(&self, key: Value, values: Vec<Value>) -> Value {
+        let mut record = Map::new();
+        record.insert(self.key_column.clone(), key);
+        for ((_, column), value) in self.columns.iter().zip(values) {
+            record.insert(column.clone(), value);
+        }
+        Value::Object(record)
+    }
+}
+
+/// The names are quoted, so only the letters, digits and underscores (with the optional schema) are allowed.
+fn validate_identifier(identifier: &str) -> Result<(), IggyError> {
+    let is_valid = identifier.split('.').count() <= 2
+        && identifier.split('.').all(|part| {
+            !part.is_empty()
+                && part.len() <= 63
+                && part
+                    .chars()
+                    .all(|char| char.is_ascii_alphanumeric() || char == '_')
+        });
+    if !is_valid {
+        return Err(IggyError::InvalidConnectorConfig(format!(
+            "invalid table or column name: {identifier}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Binds the JSON value to the MySQL query, the nested arrays and objects are bound as the JSON text.
+fn bind_mysql<'q>(
+    query: sqlx::query::Query<'q, sqlx::MySql, sqlx::mysql::MySqlArguments>,
+    value: Value,
+) -> sqlx::query::Query<'q, sqlx::MySql, sqlx::mysql::MySqlArguments> {
+    match value {
+        Value::Null => query.bind(None::<String>),
+        Value::Bool(value) => query.bind(value),
+        Value::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(value), _) => query.bind(value),
+            (None, Some(value)) => query.bind(value),
+            _ => query.bind(number.as_f64()),
+        },
+        Value::String(value) => query.bind(value),
+        value => query.bind(value.to_string()),
+    }
+}
+
+#[async_trait]
+impl Sink for DatabaseSink {
+    async fn write(
+        &mut self,
+        partition_id: u32,
+        messages: &[Arc<Message>],
+    ) -> Result<(), ConnectorError> {
+        let Some(last_offset) = messages.last().map(|message| message.offset as i64) else {
+            return Ok(());
+        };
+
+        self.create_offsets_table().await?;
+        let offsets_table = self.database.quote(&self.offsets_table);
+        let upsert_query = self.database.upsert_query(&self.mapping);
+        match &self.database {
+            Database::Postgres(pool) => {
+                let mut transaction = pool.begin().await?;
+                let stored_offset = sqlx::query_scalar::<_, i64>(&format!(
+                    "SELECT partition_offset FROM {offsets_table} WHERE connector = $1 AND partition_id = $2 FOR UPDATE"
+                ))
+                .bind(&self.connector)
+                .bind(partition_id as i32)
+                .fetch_optional(&mut *transaction)
+                .await?;
+                for message in skip_written(messages, stored_offset) {
+                    let (key, values) = self.mapping.map(message)?;
+                    sqlx::query(&upsert_query)
+                        .bind(self.mapping.to_record(key, values).to_string())
+                        .execute(&mut *transaction)
+                        .await?;
+                }
+                sqlx::query(&format!(
+                    "INSERT INTO {offsets_table} (connector, partition_id, partition_offset) VALUES ($1, $2, $3) \
+                    ON CONFLICT (connector, partition_id) DO UPDATE SET partition_offset = EXCLUDED.partition_offset"
+                ))
+                .bind(&self.connector)
+                .bind(partition_id as i32)
+                .bind(last_offset)
+                .execute(&mut *transaction)
+                .await?;
+                transaction.commit().await?;
+            }
+            Database::MySql(pool) => {
+                let mut transaction = pool.begin().await?;
+                let stored_offset = sqlx::query_scalar::<_, i64>(&format!(
+                    "SELECT partition_offset FROM {offsets_table} WHERE connector = ? AND partition_id = ? FOR UPDATE"
+                ))
+                .bind(&self.connector)
+                .bind(partition_id as i32)
+                .fetch_optional(&mut *transaction)
+                .await?;
+                for message in skip_written(messages, stored_offset) {
+                    let (key, values) = self.mapping.map(message)?;
+                    let mut query = bind_mysql(sqlx::query(&upsert_query), key);
+                    for value in values {
+                        query = bind_mysql(query, value);
+                    }
+                    query.execute(&mut *transaction).await?;
+                }
+                sqlx::query(&format!(
+                    "INSERT INTO {offsets_table} (connector, partition_id, partition_offset) VALUES (?, ?, ?) \
+                    ON DUPLICATE KEY UPDATE partition_offset = VALUES(partition_offset)"
+                ))
+                .bind(&self.connector)
+                .bind(partition_id as i32)
+                .bind(last_offset)
+                .execute(&mut *transaction)
+                .await?;
+                transaction.commit().await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the messages following the offset already written to the database.
+fn skip_written(
+    messages: &[Arc<Message>],
+    stored_offset: Option<i64>,
+) -> impl Iterator<Item = &Message> {
+    messages
+        .iter()
+        .map(|message| message.as_ref())
+        .filter(move |message| stored_offset.is_none_or(|offset| message.offset as i64 > offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::models::header::HeaderValue;
+    use iggy::models::messages::MessageState;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    fn config(properties: &[(&str, &str)]) -> ConnectorConfig {
+        ConnectorConfig {
+            name: "orders".to_string(),
+            kind: crate::connectors::config::DATABASE_SINK.to_string(),
+            stream: "stream".to_string(),
+            topic: "topic".to_string(),
+            properties: properties
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    fn message(
+        offset: u64,
+        payload: &str,
+        headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Message {
+        Message::create(
+            offset,
+            MessageState::Available,
+            1,
+            7,
+            Bytes::from(payload.to_string()),
+            0,
+            headers,
+        )
+    }
+
+    #[test]
+    fn should_map_key_and_payload_fields_to_columns() {
+        let mapping = RowMapping::new(&config(&[
+            (TABLE_PROPERTY, "public.orders"),
+            (KEY_PROPERTY, "header:order_id"),
+            (KEY_COLUMN_PROPERTY, "order_id"),
+            (COLUMNS_PROPERTY, "amount, customer:customer_name, note"),
+        ]))
+        .unwrap();
+        let headers = HashMap::from([(
+            HeaderKey::new("order_id").unwrap(),
+            HeaderValue::from_str("o-1").unwrap(),
+        )]);
+        let (key, values) = mapping
+            .map(&message(
+                0,
+                r#"{"amount":10.5,"customer":"acme"}"#,
+                Some(headers),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            mapping.to_record(key, values),
+            serde_json::json!({"order_id": "o-1", "amount": 10.5, "customer_name": "acme", "note": null})
+        );
+        assert!(mapping.map(&message(1, "[1, 2]", None)).is_err());
+        assert!(mapping.map(&message(2, "{}", None)).is_err());
+    }
+
+    #[test]
+    fn should_reject_invalid_identifiers_and_mappings() {
+        assert!(RowMapping::new(&config(&[(TABLE_PROPERTY, "orders; DROP TABLE users")])).is_err());
+        assert!(RowMapping::new(&config(&[
+            (TABLE_PROPERTY, "orders"),
+            (KEY_PROPERTY, "offset")
+        ]))
+        .is_err());
+        assert!(RowMapping::new(&config(&[
+            (TABLE_PROPERTY, "orders"),
+            (COLUMNS_PROPERTY, "id")
+        ]))
+        .is_err());
+        assert!(RowMapping::new(&config(&[
+            (TABLE_PROPERTY, "orders"),
+            (COLUMNS_PROPERTY, "a:b\"c")
+        ]))
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn should_build_upsert_queries() {
+        let properties = [
+            (URL_PROPERTY, "postgres://localhost/iggy"),
+            (TABLE_PROPERTY, "orders"),
+            (COLUMNS_PROPERTY, "amount"),
+        ];
+        let sink = DatabaseSink::new(&config(&properties)).unwrap();
+        assert_eq!(
+            sink.database.upsert_query(&sink.mapping),
+            "INSERT INTO \"orders\" (\"id\", \"amount\") SELECT \"id\", \"amount\" FROM json_populate_record(NULL::\"orders\", $1::json) \
+            ON CONFLICT (\"id\") DO UPDATE SET \"amount\" = EXCLUDED.\"amount\""
+        );
+
+        let sink = DatabaseSink::new(&config(&[
+            (URL_PROPERTY, "mysql://localhost/iggy"),
+            properties[1],
+            properties[2],
+        ]))
+        .unwrap();
+        assert_eq!(
+            sink.database.upsert_query(&sink.mapping),
+            "INSERT INTO `orders` (`id`, `amount`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `amount` = VALUES(`amount`)"
+        );
+    }
+
+    #[test]
+    fn should_skip_messages_already_written() {
+        let messages = (0..4)
+            .map(|offset| Arc::new(message(offset, "{}", None)))
+            .collect::<Vec<_>>();
+        let offsets = |stored_offset| {
+            skip_written(&messages, stored_offset)
+                .map(|message| message.offset)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(offsets(None), vec![0, 1, 2, 3]);
+        assert_eq!(offsets(Some(1)), vec![2, 3]);
+        assert_eq!(offsets(Some(3)), Vec::<u64>::new());
+    }
+}
@@ -0,0 +1,20 @@
+use iggy::error::IggyError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConnectorError {
+    #[error("Invalid source offset: {0}")]
+    InvalidOffset(String),
+    #[error("HTTP request failed. Reason: {0}")]
+    Http(String),
+    #[error("Database query failed. Reason: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("Object storage request failed. Reason: {0}")]
+    Storage(#[from] object_store::Error),
+    #[error("Invalid message: {0}")]
+    InvalidMessage(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Iggy(#[from] IggyError),
+}
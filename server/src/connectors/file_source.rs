@@ -0,0 +1,145 @@
+use crate::connectors::config::ConnectorConfig;
+use crate::connectors::error::ConnectorError;
+use crate::connectors::source::{Source, SourceBatch};
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use std::io::SeekFrom;
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncSeekExt, BufReader};
+use tracing::warn;
+
+const PATH_PROPERTY: &str = "path";
+
+/// Tails the file, each line is appended as the separate message and the offset is the byte position in the file.
+/// The last line is read only once it's terminated, and the file is read from the beginning if it has been truncated.
+#[derive(Debug)]
+pub struct FileSource {
+    path: String,
+}
+
+impl FileSource {
+    pub fn new(config: &ConnectorConfig) -> Result<Self, IggyError> {
+        Ok(Self {
+            path: config.get_property(PATH_PROPERTY)?.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl Source for FileSource {
+    async fn read(
+        &mut self,
+        offset: Option<&str>,
+        count: u32,
+    ) -> Result<Option<SourceBatch>, ConnectorError> {
+        let mut position = match offset {
+            Some(offset) => offset
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| ConnectorError::InvalidOffset(offset.to_string()))?,
+            None => 0,
+        };
+
+        let start_position = position;
+        let file = File::open(&self.path).await?;
+        let length = file.metadata().await?.len();
+        if length < position {
+            warn!(
+                "File: {} has been truncated to {length} bytes, reading from the beginning.",
+                self.path
+            );
+            position = 0;
+        }
+
+        if length == start_position {
+            return Ok(None);
+        }
+
+        let mut reader = BufReader::new(file);
+        reader.seek(SeekFrom::Start(position)).await?;
+        let mut payloads = Vec::new();
+        let mut line = Vec::new();
+        while payloads.len() < count as usize {
+            line.clear();
+            let read_bytes = reader.read_until(b'\n', &mut line).await?;
+            if read_bytes == 0 || line.last() != Some(&b'\n') {
+                break;
+            }
+
+            position += read_bytes as u64;
+            let payload = line.trim_ascii_end();
+            if !payload.is_empty() {
+                payloads.push(Bytes::copy_from_slice(payload));
+            }
+        }
+
+        if position == start_position {
+            return Ok(None);
+        }
+
+        Ok(Some(SourceBatch {
+            payloads,
+            offset: position.to_string(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn file_source(path: &str) -> FileSource {
+        let config = ConnectorConfig {
+            name: "test".to_string(),
+            kind: crate::connectors::config::FILE_SOURCE.to_string(),
+            stream: "stream".to_string(),
+            topic: "topic".to_string(),
+            properties: HashMap::from([(PATH_PROPERTY.to_string(), path.to_string())]),
+        };
+        FileSource::new(&config).unwrap()
+    }
+
+    fn temporary_path() -> String {
+        std::env::temp_dir()
+            .join(format!("iggy-file-source-{}.log", uuid::Uuid::new_v4()))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn should_read_only_terminated_lines_and_resume_from_offset() {
+        let path = temporary_path();
+        tokio::fs::write(&path, "first\n\nsecond\nthird\npartial")
+            .await
+            .unwrap();
+        let mut source = file_source(&path);
+
+        let batch = source.read(None, 1).await.unwrap().unwrap();
+        assert_eq!(batch.payloads, vec![Bytes::from("first")]);
+        assert_eq!(batch.offset, "6");
+
+        let batch = source.read(Some(&batch.offset), 10).await.unwrap().unwrap();
+        assert_eq!(
+            batch.payloads,
+            vec![Bytes::from("second"), Bytes::from("third")]
+        );
+        assert_eq!(batch.offset, "20");
+
+        assert!(source.read(Some("20"), 10).await.unwrap().is_none());
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_read_from_beginning_when_file_was_truncated() {
+        let path = temporary_path();
+        tokio::fs::write(&path, "line\n").await.unwrap();
+        let mut source = file_source(&path);
+
+        let batch = source.read(Some("100"), 10).await.unwrap().unwrap();
+        assert_eq!(batch.payloads, vec![Bytes::from("line")]);
+        assert_eq!(batch.offset, "5");
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+}
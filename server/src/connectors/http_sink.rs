@@ -0,0 +1,99 @@
+use crate::connectors::config::ConnectorConfig;
+use crate::connectors::error::ConnectorError;
+use crate::connectors::sink::Sink;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use iggy::models::messages::Message;
+use iggy::utils::duration::IggyDuration;
+use serde::Serialize;
+use std::str::FromStr;
+use std::sync::Arc;
+
+const URL_PROPERTY: &str = "url";
+const TIMEOUT_PROPERTY: &str = "timeout";
+const DEFAULT_TIMEOUT: &str = "10 s";
+
+/// Posts the batch of messages as JSON to the configured URL, the messages have the same format
+/// as the ones polled via HTTP API (base64 encoded payloads). Any non-success status fails the batch.
+#[derive(Debug)]
+pub struct HttpSink {
+    connector: String,
+    stream: String,
+    topic: String,
+    url: String,
+    client: reqwest::Client,
+}
+
+#[derive(Debug, Serialize)]
+struct HttpSinkBatch<'a> {
+    connector: &'a str,
+    stream: &'a str,
+    topic: &'a str,
+    partition_id: u32,
+    messages: &'a [Arc<Message>],
+}
+
+impl HttpSink {
+    pub fn new(config: &ConnectorConfig) -> Result<Self, IggyError> {
+        let url = config.get_property(URL_PROPERTY)?;
+        if reqwest::Url::parse(url).is_err() {
+            return Err(IggyError::InvalidConnectorConfig(format!(
+                "invalid url: {url}"
+            )));
+        }
+
+        let timeout = config
+            .properties
+            .get(TIMEOUT_PROPERTY)
+            .map(|timeout| timeout.as_str())
+            .unwrap_or(DEFAULT_TIMEOUT);
+        let timeout = IggyDuration::from_str(timeout).map_err(|_| {
+            IggyError::InvalidConnectorConfig(format!("invalid timeout: {timeout}"))
+        })?;
+        let client = reqwest::Client::builder()
+            .timeout(timeout.get_duration())
+            .build()
+            .map_err(|error| IggyError::InvalidConnectorConfig(error.to_string()))?;
+        Ok(Self {
+            connector: config.name.clone(),
+            stream: config.stream.clone(),
+            topic: config.topic.clone(),
+            url: url.to_string(),
+            client,
+        })
+    }
+}
+
+#[async_trait]
+impl Sink for HttpSink {
+    async fn write(
+        &mut self,
+        partition_id: u32,
+        messages: &[Arc<Message>],
+    ) -> Result<(), ConnectorError> {
+        let batch = HttpSinkBatch {
+            connector: &self.connector,
+            stream: &self.stream,
+            topic: &self.topic,
+            partition_id,
+            messages,
+        };
+        let payload = serde_json::to_vec(&batch).map_err(|_| IggyError::InvalidFormat)?;
+        let response = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(payload)
+            .send()
+            .await
+            .map_err(|error| ConnectorError::Http(error.to_string()))?;
+        if !response.status().is_success() {
+            return Err(ConnectorError::Http(format!(
+                "unexpected status: {}",
+                response.status()
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+pub mod config;
+pub mod database_sink;
+pub mod error;
+pub mod file_source;
+pub mod http_sink;
+pub mod postgres_cdc_source;
+pub mod runtime;
+pub mod s3_sink;
+pub mod sink;
+pub mod source;
+pub mod storage;
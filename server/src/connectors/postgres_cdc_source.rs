@@ -0,0 +1,362 @@
+use crate::connectors::config::ConnectorConfig;
+use crate::connectors::error::ConnectorError;
+use crate::connectors::source::{Source, SourceBatch};
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use serde_json::Value;
+use sqlx::postgres::{PgPool, PgPoolOptions};
+
+const URL_PROPERTY: &str = "url";
+const SLOT_PROPERTY: &str = "slot";
+const PLUGIN_PROPERTY: &str = "plugin";
+const TABLES_PROPERTY: &str = "tables";
+const SLOT_PREFIX: &str = "iggy_";
+const MAX_SLOT_LENGTH: usize = 63;
+
+/// The logical decoding output plugin of the replication slot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputPlugin {
+    /// Each change is decoded as the JSON object (format version 2), the transaction boundaries have the `B` and `C` actions.
+    Wal2Json,
+    /// Each change is decoded as the text line (the plugin shipped with PostgreSQL), e.g. for the testing.
+    TestDecoding,
+}
+
+/// Captures the changes of the PostgreSQL tables via the logical replication slot, by default decoded by the `wal2json` plugin,
+/// each inserted, updated or deleted row is appended as the separate message (the JSON object for `wal2json`).
+/// The offset is the LSN of the last commit in the batch: the changes are only peeked from the slot,
+/// and consumed up to the offset once it has been persisted, so the batch is read again after the restart.
+#[derive(Debug)]
+pub struct PostgresCdcSource {
+    pool: PgPool,
+    slot: String,
+    plugin: OutputPlugin,
+    options: Vec<String>,
+    slot_created: bool,
+}
+
+impl PostgresCdcSource {
+    pub fn new(config: &ConnectorConfig) -> Result<Self, IggyError> {
+        let url = config.get_property(URL_PROPERTY)?;
+        if !url.starts_with("postgres://") && !url.starts_with("postgresql://") {
+            return Err(IggyError::InvalidConnectorConfig(
+                "url must start with postgres:// or postgresql://".to_string(),
+            ));
+        }
+
+        let slot = match config.properties.get(SLOT_PROPERTY) {
+            Some(slot) => slot.to_string(),
+            None => get_default_slot(&config.name),
+        };
+        validate_slot(&slot)?;
+        let plugin = match config
+            .properties
+            .get(PLUGIN_PROPERTY)
+            .map(|plugin| plugin.as_str())
+        {
+            None | Some("wal2json") => OutputPlugin::Wal2Json,
+            Some("test_decoding") => OutputPlugin::TestDecoding,
+            Some(plugin) => {
+                return Err(IggyError::InvalidConnectorConfig(format!(
+                    "unsupported plugin: {plugin}, expected: wal2json or test_decoding"
+                )))
+            }
+        };
+        let options = plugin.get_options(
+            config
+                .properties
+                .get(TABLES_PROPERTY)
+                .map(|tables| tables.as_str()),
+        )?;
+
+        // The pool connects on the first read, so the unavailable database is retried like any other failure.
+        let pool = PgPoolOptions::new()
+            .max_connections(1)
+            .connect_lazy(url)
+            .map_err(|error| IggyError::InvalidConnectorConfig(error.to_string()))?;
+        Ok(Self {
+            pool,
+            slot,
+            plugin,
+            options,
+            slot_created: false,
+        })
+    }
+
+    async fn create_slot(&mut self) -> Result<(), ConnectorError> {
+        if self.slot_created {
+            return Ok(());
+        }
+
+        let slot_exists =
+            sqlx::query_scalar::<_, i32>("SELECT 1 FROM pg_replication_slots WHERE slot_name = $1")
+                .bind(&self.slot)
+                .fetch_optional(&self.pool)
+                .await?
+                .is_some();
+        if !slot_exists {
+            sqlx::query("SELECT pg_create_logical_replication_slot($1, $2)")
+                .bind(&self.slot)
+                .bind(self.plugin.as_str())
+                .execute(&self.pool)
+                .await?;
+        }
+
+        self.slot_created = true;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Source for PostgresCdcSource {
+    async fn read(
+        &mut self,
+        offset: Option<&str>,
+        count: u32,
+    ) -> Result<Option<SourceBatch>, ConnectorError> {
+        self.create_slot().await?;
+        // The changes committed up to the persisted offset are consumed, so the slot doesn't retain the WAL anymore.
+        if let Some(offset) = offset {
+            sqlx::query(
+                "SELECT count(*) FROM pg_logical_slot_get_changes($1, $2::text::pg_lsn, NULL, VARIADIC $3::text[])",
+            )
+            .bind(&self.slot)
+            .bind(offset)
+            .bind(&self.options)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT lsn::text, data FROM pg_logical_slot_peek_changes($1, NULL, $2, VARIADIC $3::text[])",
+        )
+        .bind(&self.slot)
+        .bind(count.min(i32::MAX as u32) as i32)
+        .bind(&self.options)
+        .fetch_all(&self.pool)
+        .await?;
+        self.plugin.map_changes(rows)
+    }
+}
+
+/// The kind of the decoded row.
+#[derive(Debug, PartialEq)]
+enum Change {
+    Begin,
+    Commit,
+    Row,
+}
+
+impl OutputPlugin {
+    fn as_str(&self) -> &'static str {
+        match self {
+            OutputPlugin::Wal2Json => "wal2json",
+            OutputPlugin::TestDecoding => "test_decoding",
+        }
+    }
+
+    /// Returns the plugin options, the tables are the comma separated `schema.table` names (all the tables by default),
+    /// which can be filtered only by `wal2json`.
+    fn get_options(&self, tables: Option<&str>) -> Result<Vec<String>, IggyError> {
+        let tables = tables
+            .unwrap_or_default()
+            .split(',')
+            .map(|table| table.trim())
+            .filter(|table| !table.is_empty())
+            .collect::<Vec<_>>();
+        match self {
+            OutputPlugin::Wal2Json => {
+                let mut options = vec![
+                    "format-version".to_string(),
+                    "2".to_string(),
+                    "include-transaction".to_string(),
+                    "true".to_string(),
+                    "include-timestamp".to_string(),
+                    "true".to_string(),
+                ];
+                if !tables.is_empty() {
+                    options.push("add-tables".to_string());
+                    options.push(tables.join(","));
+                }
+                Ok(options)
+            }
+            OutputPlugin::TestDecoding if tables.is_empty() => Ok(vec![
+                "include-xids".to_string(),
+                "0".to_string(),
+                "skip-empty-xacts".to_string(),
+                "1".to_string(),
+            ]),
+            OutputPlugin::TestDecoding => Err(IggyError::InvalidConnectorConfig(
+                "tables can be filtered only by the wal2json plugin".to_string(),
+            )),
+        }
+    }
+
+    fn get_change(&self, lsn: &str, data: &str) -> Result<Change, ConnectorError> {
+        match self {
+            OutputPlugin::Wal2Json => {
+                let change = serde_json::from_str::<Value>(data).map_err(|error| {
+                    ConnectorError::InvalidMessage(format!("invalid change at LSN: {lsn}: {error}"))
+                })?;
+                Ok(
+                    match change.get("action").and_then(|action| action.as_str()) {
+                        Some("B") => Change::Begin,
+                        Some("C") => Change::Commit,
+                        _ => Change::Row,
+                    },
+                )
+            }
+            OutputPlugin::TestDecoding => Ok(match data {
+                "BEGIN" => Change::Begin,
+                "COMMIT" => Change::Commit,
+                _ => Change::Row,
+            }),
+        }
+    }
+
+    /// Returns the batch of the changes, skipping the transaction boundaries, with the LSN of the last commit as the offset.
+    /// The changes of the transaction are always decoded together, so the batch ends with the commit.
+    fn map_changes(
+        &self,
+        rows: Vec<(String, String)>,
+    ) -> Result<Option<SourceBatch>, ConnectorError> {
+        let mut payloads = Vec::new();
+        let mut offset = None;
+        for (lsn, data) in rows {
+            match self.get_change(&lsn, &data)? {
+                Change::Begin => {}
+                Change::Commit => offset = Some(lsn),
+                Change::Row => payloads.push(Bytes::from(data)),
+            }
+        }
+
+        Ok(offset.map(|offset| SourceBatch { payloads, offset }))
+    }
+}
+
+/// The slot names can contain only the lowercase letters, digits and underscores.
+fn get_default_slot(connector: &str) -> String {
+    let slot = format!("{SLOT_PREFIX}{connector}")
+        .to_lowercase()
+        .chars()
+        .map(|char| match char.is_ascii_alphanumeric() {
+            true => char,
+            false => '_',
+        })
+        .collect::<String>();
+    slot.chars().take(MAX_SLOT_LENGTH).collect()
+}
+
+fn validate_slot(slot: &str) -> Result<(), IggyError> {
+    let is_valid = !slot.is_empty()
+        && slot.len() <= MAX_SLOT_LENGTH
+        && slot
+            .chars()
+            .all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_');
+    if !is_valid {
+        return Err(IggyError::InvalidConnectorConfig(format!(
+            "invalid slot: {slot}, only lowercase letters, digits and underscores are allowed"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn config(properties: &[(&str, &str)]) -> ConnectorConfig {
+        ConnectorConfig {
+            name: "Orders-CDC".to_string(),
+            kind: crate::connectors::config::POSTGRES_CDC_SOURCE.to_string(),
+            stream: "stream".to_string(),
+            topic: "topic".to_string(),
+            properties: properties
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_use_default_or_configured_slot() {
+        let source =
+            PostgresCdcSource::new(&config(&[(URL_PROPERTY, "postgres://localhost/iggy")]))
+                .unwrap();
+        assert_eq!(source.slot, "iggy_orders_cdc");
+
+        let source = PostgresCdcSource::new(&config(&[
+            (URL_PROPERTY, "postgres://localhost/iggy"),
+            (SLOT_PROPERTY, "orders"),
+            (TABLES_PROPERTY, "public.orders, public.customers"),
+        ]))
+        .unwrap();
+        assert_eq!(source.slot, "orders");
+        assert_eq!(
+            &source.options[source.options.len() - 2..],
+            ["add-tables", "public.orders,public.customers"]
+        );
+
+        assert!(PostgresCdcSource::new(&config(&[
+            (URL_PROPERTY, "postgres://localhost/iggy"),
+            (SLOT_PROPERTY, "Orders; DROP")
+        ]))
+        .is_err());
+        assert!(
+            PostgresCdcSource::new(&config(&[(URL_PROPERTY, "mysql://localhost/iggy")])).is_err()
+        );
+        assert!(PostgresCdcSource::new(&config(&[
+            (URL_PROPERTY, "postgres://localhost/iggy"),
+            (PLUGIN_PROPERTY, "pgoutput")
+        ]))
+        .is_err());
+        assert!(PostgresCdcSource::new(&config(&[
+            (URL_PROPERTY, "postgres://localhost/iggy"),
+            (PLUGIN_PROPERTY, "test_decoding"),
+            (TABLES_PROPERTY, "public.orders")
+        ]))
+        .is_err());
+    }
+
+    #[test]
+    fn should_map_changes_and_use_last_commit_as_offset() {
+        let insert = r#"{"action":"I","schema":"public","table":"orders","columns":[{"name":"id","type":"integer","value":1}]}"#;
+        let delete = r#"{"action":"D","schema":"public","table":"orders","identity":[{"name":"id","type":"integer","value":1}]}"#;
+        let rows = vec![
+            ("0/1000".to_string(), r#"{"action":"B"}"#.to_string()),
+            ("0/1010".to_string(), insert.to_string()),
+            ("0/1020".to_string(), r#"{"action":"C"}"#.to_string()),
+            ("0/2000".to_string(), r#"{"action":"B"}"#.to_string()),
+            ("0/2010".to_string(), delete.to_string()),
+            ("0/2020".to_string(), r#"{"action":"C"}"#.to_string()),
+        ];
+
+        let plugin = OutputPlugin::Wal2Json;
+        let batch = plugin.map_changes(rows).unwrap().unwrap();
+        assert_eq!(
+            batch.payloads,
+            vec![Bytes::from(insert), Bytes::from(delete)]
+        );
+        assert_eq!(batch.offset, "0/2020");
+        assert!(plugin.map_changes(Vec::new()).unwrap().is_none());
+        assert!(plugin
+            .map_changes(vec![("0/1".to_string(), "invalid".to_string())])
+            .is_err());
+
+        let update = "table public.orders: UPDATE: id[integer]:1 amount[numeric]:10.5";
+        let rows = vec![
+            ("0/3000".to_string(), "BEGIN".to_string()),
+            ("0/3010".to_string(), update.to_string()),
+            ("0/3020".to_string(), "COMMIT".to_string()),
+        ];
+        let batch = OutputPlugin::TestDecoding
+            .map_changes(rows)
+            .unwrap()
+            .unwrap();
+        assert_eq!(batch.payloads, vec![Bytes::from(update)]);
+        assert_eq!(batch.offset, "0/3020");
+    }
+}
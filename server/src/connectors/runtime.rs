@@ -0,0 +1,458 @@
+use crate::configs::server::ConnectorsConfig;
+use crate::connectors::config::{
+    ConnectorConfig, DATABASE_SINK, FILE_SOURCE, HTTP_SINK, POSTGRES_CDC_SOURCE, S3_SINK,
+};
+use crate::connectors::database_sink::DatabaseSink;
+use crate::connectors::error::ConnectorError;
+use crate::connectors::file_source::FileSource;
+use crate::connectors::http_sink::HttpSink;
+use crate::connectors::postgres_cdc_source::PostgresCdcSource;
+use crate::connectors::s3_sink::S3Sink;
+use crate::connectors::sink::Sink;
+use crate::connectors::source::Source;
+use crate::connectors::storage::ConnectorStorage;
+use crate::server_error::ServerError;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollingMode, PollingStrategy};
+use iggy::messages::send_messages::{Acks, Message, Partitioning};
+use iggy::models::connector::{ConnectorInfo, ConnectorStatus};
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::utils::checksum;
+use iggy::validatable::Validatable;
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time;
+use tracing::{error, info, warn};
+
+enum Connector {
+    Source(Box<dyn Source>),
+    Sink(Box<dyn Sink>),
+}
+
+#[derive(Debug)]
+struct ConnectorState {
+    status: ConnectorStatus,
+    error: Option<String>,
+    processed_messages: u64,
+}
+
+#[derive(Debug)]
+struct ConnectorHandle {
+    config: ConnectorConfig,
+    state: Arc<std::sync::Mutex<ConnectorState>>,
+    task: JoinHandle<()>,
+}
+
+/// Runs each connector as the background task, using the root session to append to or consume from the topics.
+/// The source offsets are persisted in the connectors directory, while the sinks store the consumer offsets
+/// (the consumer ID is the checksum of the connector name), so both resume where they left off after the restart.
+/// The messages are delivered at least once, as the offset is stored only after the batch has been processed.
+#[derive(Debug)]
+pub struct ConnectorRuntime {
+    config: ConnectorsConfig,
+    storage: Arc<ConnectorStorage>,
+    connectors: Mutex<HashMap<String, ConnectorHandle>>,
+}
+
+/// The state of the single connector task, the consecutive failures are retried until `max_retries` is exceeded.
+struct ConnectorTask {
+    name: String,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    session: Session,
+    config: ConnectorsConfig,
+    storage: Arc<ConnectorStorage>,
+    state: Arc<std::sync::Mutex<ConnectorState>>,
+}
+
+impl ConnectorRuntime {
+    /// Starts the persisted connectors, the runtime is available to the API only if the connectors are enabled.
+    pub async fn start(
+        config: &ConnectorsConfig,
+        system: &SharedSystem,
+    ) -> Result<(), ServerError> {
+        if !config.enabled {
+            info!("Connectors are disabled.");
+            return Ok(());
+        }
+
+        let path = format!("{}/{}", system.read().config.get_state_path(), config.path);
+        let runtime = ConnectorRuntime {
+            config: config.clone(),
+            storage: Arc::new(ConnectorStorage::new(&path)),
+            connectors: Mutex::new(HashMap::new()),
+        };
+        runtime.storage.init().await?;
+        let definitions = runtime.storage.load_definitions().await?;
+        {
+            let mut connectors = runtime.connectors.lock().await;
+            for definition in definitions {
+                let name = definition.name.clone();
+                match runtime.spawn(system, definition) {
+                    Ok(handle) => {
+                        connectors.insert(name, handle);
+                    }
+                    Err(error) => {
+                        error!("Cannot start connector: {name}. Error: {error}");
+                    }
+                }
+            }
+            info!(
+                "Connectors are enabled, started {} connector(s).",
+                connectors.len()
+            );
+        }
+
+        system.write().connectors = Some(Arc::new(runtime));
+        Ok(())
+    }
+
+    /// Stops all the connectors, the unprocessed batches are processed again after the restart.
+    pub async fn shutdown(system: &SharedSystem) {
+        let runtime = system.read().connectors.clone();
+        let Some(runtime) = runtime else {
+            return;
+        };
+
+        let mut connectors = runtime.connectors.lock().await;
+        for (_, connector) in connectors.drain() {
+            connector.task.abort();
+        }
+        info!("Connectors have been stopped.");
+    }
+
+    pub async fn get_connector(&self, name: &str) -> Result<ConnectorInfo, IggyError> {
+        let connectors = self.connectors.lock().await;
+        connectors
+            .get(name)
+            .map(|connector| connector.info())
+            .ok_or_else(|| IggyError::ConnectorNotFound(name.to_string()))
+    }
+
+    pub async fn get_connectors(&self) -> Vec<ConnectorInfo> {
+        let connectors = self.connectors.lock().await;
+        let mut connectors = connectors
+            .values()
+            .map(|connector| connector.info())
+            .collect::<Vec<_>>();
+        connectors.sort_by(|a, b| a.name.cmp(&b.name));
+        connectors
+    }
+
+    pub async fn create_connector(
+        &self,
+        system: &SharedSystem,
+        config: ConnectorConfig,
+    ) -> Result<ConnectorInfo, IggyError> {
+        config.validate()?;
+        let mut connectors = self.connectors.lock().await;
+        if connectors.contains_key(&config.name) {
+            return Err(IggyError::ConnectorAlreadyExists(config.name));
+        }
+
+        {
+            let system = system.read();
+            let stream = system.get_stream(&config.get_stream_id()?).await?;
+            stream.get_topic(&config.get_topic_id()?)?;
+        }
+
+        let handle = self.spawn(system, config)?;
+        if let Err(error) = self.storage.save_definition(&handle.config).await {
+            handle.task.abort();
+            return Err(error);
+        }
+
+        let info = handle.info();
+        info!("Created connector: {} of kind: {}.", info.name, info.kind);
+        connectors.insert(info.name.clone(), handle);
+        Ok(info)
+    }
+
+    /// Restarts the connector, e.g. once it has failed, continuing from the last stored offset.
+    pub async fn restart_connector(
+        &self,
+        system: &SharedSystem,
+        name: &str,
+    ) -> Result<ConnectorInfo, IggyError> {
+        let mut connectors = self.connectors.lock().await;
+        let Some(connector) = connectors.remove(name) else {
+            return Err(IggyError::ConnectorNotFound(name.to_string()));
+        };
+
+        connector.task.abort();
+        let handle = self.spawn(system, connector.config)?;
+        let info = handle.info();
+        info!("Restarted connector: {name}.");
+        connectors.insert(name.to_string(), handle);
+        Ok(info)
+    }
+
+    /// Deletes the connector along with its source offset, the consumer offsets of the sink remain stored in the topic.
+    pub async fn delete_connector(&self, name: &str) -> Result<(), IggyError> {
+        let mut connectors = self.connectors.lock().await;
+        let Some(connector) = connectors.remove(name) else {
+            return Err(IggyError::ConnectorNotFound(name.to_string()));
+        };
+
+        connector.task.abort();
+        self.storage.delete(name).await?;
+        info!("Deleted connector: {name}.");
+        Ok(())
+    }
+
+    fn spawn(
+        &self,
+        system: &SharedSystem,
+        config: ConnectorConfig,
+    ) -> Result<ConnectorHandle, IggyError> {
+        config.validate()?;
+        let connector = match config.kind.as_str() {
+            FILE_SOURCE => Connector::Source(Box::new(FileSource::new(&config)?)),
+            POSTGRES_CDC_SOURCE => Connector::Source(Box::new(PostgresCdcSource::new(&config)?)),
+            HTTP_SINK => Connector::Sink(Box::new(HttpSink::new(&config)?)),
+            DATABASE_SINK => Connector::Sink(Box::new(DatabaseSink::new(&config)?)),
+            S3_SINK => Connector::Sink(Box::new(S3Sink::new(&config)?)),
+            kind => {
+                return Err(IggyError::InvalidConnectorConfig(format!(
+                    "unknown kind: {kind}"
+                )))
+            }
+        };
+
+        let state = Arc::new(std::sync::Mutex::new(ConnectorState {
+            status: ConnectorStatus::Running,
+            error: None,
+            processed_messages: 0,
+        }));
+        let task = ConnectorTask {
+            name: config.name.clone(),
+            stream_id: config.get_stream_id()?,
+            topic_id: config.get_topic_id()?,
+            session: Session::stateless(
+                DEFAULT_ROOT_USER_ID,
+                SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+            ),
+            config: self.config.clone(),
+            storage: self.storage.clone(),
+            state: state.clone(),
+        };
+        let system = system.clone();
+        let task = tokio::spawn(async move {
+            match connector {
+                Connector::Source(source) => task.run_source(&system, source).await,
+                Connector::Sink(sink) => task.run_sink(&system, sink).await,
+            }
+        });
+
+        Ok(ConnectorHandle {
+            config,
+            state,
+            task,
+        })
+    }
+}
+
+impl ConnectorHandle {
+    fn info(&self) -> ConnectorInfo {
+        let state = self.state.lock().unwrap();
+        ConnectorInfo {
+            name: self.config.name.clone(),
+            kind: self.config.kind.clone(),
+            stream: self.config.stream.clone(),
+            topic: self.config.topic.clone(),
+            properties: self.config.properties.clone(),
+            status: state.status,
+            error: state.error.clone(),
+            processed_messages: state.processed_messages,
+        }
+    }
+}
+
+impl ConnectorTask {
+    async fn run_source(self, system: &SharedSystem, mut source: Box<dyn Source>) {
+        let mut offset = match self.storage.load_offset(&self.name).await {
+            Ok(offset) => offset,
+            Err(error) => {
+                self.fail(error.into());
+                return;
+            }
+        };
+
+        let mut attempt = 0;
+        loop {
+            match self
+                .process_source_batch(system, source.as_mut(), offset.as_deref())
+                .await
+            {
+                Ok(Some(next_offset)) => {
+                    attempt = 0;
+                    offset = Some(next_offset);
+                }
+                Ok(None) => {
+                    attempt = 0;
+                    time::sleep(self.config.poll_interval.get_duration()).await;
+                }
+                Err(error) => {
+                    if !self.retry(&mut attempt, error).await {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reads the batch from the source, appends it to the topic and persists the offset, which is returned.
+    async fn process_source_batch(
+        &self,
+        system: &SharedSystem,
+        source: &mut dyn Source,
+        offset: Option<&str>,
+    ) -> Result<Option<String>, ConnectorError> {
+        let Some(batch) = source.read(offset, self.config.batch_size).await? else {
+            return Ok(None);
+        };
+
+        if !batch.payloads.is_empty() {
+            let messages = batch
+                .payloads
+                .into_iter()
+                .map(|payload| Message::new(None, payload, None))
+                .collect::<Vec<_>>();
+            system
+                .read_async()
+                .await
+                .append_messages(
+                    &self.session,
+                    &self.stream_id,
+                    &self.topic_id,
+                    &Partitioning::balanced(),
+                    &messages,
+                    Acks::Leader,
+                )
+                .await?;
+            self.add_processed_messages(messages.len());
+        }
+
+        self.storage.save_offset(&self.name, &batch.offset).await?;
+        Ok(Some(batch.offset))
+    }
+
+    async fn run_sink(self, system: &SharedSystem, mut sink: Box<dyn Sink>) {
+        let consumer_id = checksum::calculate(self.name.as_bytes());
+        let mut attempt = 0;
+        loop {
+            match self
+                .process_sink_batch(system, sink.as_mut(), consumer_id)
+                .await
+            {
+                Ok(0) => {
+                    attempt = 0;
+                    time::sleep(self.config.poll_interval.get_duration()).await;
+                }
+                Ok(_) => attempt = 0,
+                Err(error) => {
+                    if !self.retry(&mut attempt, error).await {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Consumes the next batch from each partition, writes it to the sink and stores the consumer offset.
+    /// Returns the number of the processed messages.
+    async fn process_sink_batch(
+        &self,
+        system: &SharedSystem,
+        sink: &mut dyn Sink,
+        consumer_id: u32,
+    ) -> Result<usize, ConnectorError> {
+        let partitions_count = {
+            let system = system.read_async().await;
+            let stream = system.get_stream(&self.stream_id).await?;
+            stream.get_topic(&self.topic_id)?.get_partitions_count()
+        };
+
+        let mut processed_messages = 0;
+        for partition_id in 1..=partitions_count {
+            let consumer = PollingConsumer::Consumer(consumer_id, partition_id);
+            let args = PollingArgs::new(
+                PollingStrategy::next(),
+                self.config.batch_size,
+                false,
+                None,
+                PollingMode::Consume,
+            );
+            let polled_messages = system
+                .read_async()
+                .await
+                .poll_messages(
+                    &self.session,
+                    consumer,
+                    &self.stream_id,
+                    &self.topic_id,
+                    args,
+                )
+                .await?;
+            let Some(offset) = polled_messages
+                .messages
+                .last()
+                .map(|message| message.offset)
+            else {
+                continue;
+            };
+
+            sink.write(partition_id, &polled_messages.messages).await?;
+            system
+                .read_async()
+                .await
+                .store_consumer_offset(
+                    &self.session,
+                    consumer,
+                    &self.stream_id,
+                    &self.topic_id,
+                    offset,
+                )
+                .await?;
+            processed_messages += polled_messages.messages.len();
+            self.add_processed_messages(polled_messages.messages.len());
+        }
+
+        Ok(processed_messages)
+    }
+
+    /// Waits before the next attempt, or marks the connector as failed once the retries are exhausted.
+    async fn retry(&self, attempt: &mut u32, error: ConnectorError) -> bool {
+        *attempt += 1;
+        if *attempt > self.config.max_retries {
+            self.fail(error);
+            return false;
+        }
+
+        warn!(
+            "Connector: {} has failed, retrying ({}/{}) in {}. Error: {error}",
+            self.name, attempt, self.config.max_retries, self.config.retry_interval
+        );
+        time::sleep(self.config.retry_interval.get_duration()).await;
+        true
+    }
+
+    fn fail(&self, error: ConnectorError) {
+        error!("Connector: {} has failed. Error: {error}", self.name);
+        let mut state = self.state.lock().unwrap();
+        state.status = ConnectorStatus::Failed;
+        state.error = Some(error.to_string());
+    }
+
+    fn add_processed_messages(&self, count: usize) {
+        self.state.lock().unwrap().processed_messages += count as u64;
+    }
+}
@@ -0,0 +1,173 @@
+use crate::connectors::config::ConnectorConfig;
+use crate::connectors::error::ConnectorError;
+use crate::connectors::sink::Sink;
+use crate::export::storage::build_s3_store;
+use async_trait::async_trait;
+use bytes::{BufMut, Bytes, BytesMut};
+use iggy::error::IggyError;
+use iggy::models::messages::Message;
+use object_store::aws::AmazonS3;
+use object_store::path::Path;
+use object_store::{ObjectStore, PutPayload};
+use std::sync::Arc;
+
+const BUCKET_PROPERTY: &str = "bucket";
+const REGION_PROPERTY: &str = "region";
+const ENDPOINT_PROPERTY: &str = "endpoint";
+const ACCESS_KEY_ID_PROPERTY: &str = "access_key_id";
+const SECRET_ACCESS_KEY_PROPERTY: &str = "secret_access_key";
+const PREFIX_PROPERTY: &str = "prefix";
+const DEFAULT_REGION: &str = "us-east-1";
+
+/// Uploads each batch of messages to the S3 (or S3 compatible, e.g. MinIO) bucket as the JSON lines file,
+/// the messages have the same format as the ones polled via HTTP API (base64 encoded payloads).
+/// The object key is derived from the offsets range, so the batch delivered again overwrites the same object.
+#[derive(Debug)]
+pub struct S3Sink {
+    stream: String,
+    topic: String,
+    prefix: String,
+    store: AmazonS3,
+}
+
+impl S3Sink {
+    pub fn new(config: &ConnectorConfig) -> Result<Self, IggyError> {
+        let property = |key| {
+            config
+                .properties
+                .get(key)
+                .map(|value| value.as_str())
+                .unwrap_or_default()
+        };
+        let region = match property(REGION_PROPERTY) {
+            "" => DEFAULT_REGION,
+            region => region,
+        };
+        let store = build_s3_store(
+            config.get_property(BUCKET_PROPERTY)?,
+            region,
+            property(ENDPOINT_PROPERTY),
+            property(ACCESS_KEY_ID_PROPERTY),
+            property(SECRET_ACCESS_KEY_PROPERTY),
+        )
+        .map_err(|error| IggyError::InvalidConnectorConfig(error.to_string()))?;
+        Ok(Self {
+            stream: config.stream.clone(),
+            topic: config.topic.clone(),
+            prefix: property(PREFIX_PROPERTY).trim_matches('/').to_string(),
+            store,
+        })
+    }
+
+    /// Returns the key of the object with the messages from the offsets range,
+    /// e.g. `iggy/orders/created/partition=1/00000000000000000000-00000000000000000099.jsonl`.
+    fn get_object_key(&self, partition_id: u32, first_offset: u64, last_offset: u64) -> String {
+        let key = format!(
+            "{}/{}/partition={partition_id}/{first_offset:020}-{last_offset:020}.jsonl",
+            self.stream, self.topic
+        );
+        match self.prefix.as_str() {
+            "" => key,
+            prefix => format!("{prefix}/{key}"),
+        }
+    }
+}
+
+/// Returns the messages as the JSON lines, each message is serialized on the separate line.
+fn to_json_lines(messages: &[Arc<Message>]) -> Result<Bytes, IggyError> {
+    let mut bytes = BytesMut::new().writer();
+    for message in messages {
+        serde_json::to_writer(&mut bytes, message).map_err(|_| IggyError::InvalidFormat)?;
+        bytes.get_mut().put_u8(b'\n');
+    }
+    Ok(bytes.into_inner().freeze())
+}
+
+#[async_trait]
+impl Sink for S3Sink {
+    async fn write(
+        &mut self,
+        partition_id: u32,
+        messages: &[Arc<Message>],
+    ) -> Result<(), ConnectorError> {
+        let (Some(first_message), Some(last_message)) = (messages.first(), messages.last()) else {
+            return Ok(());
+        };
+
+        let key = self.get_object_key(partition_id, first_message.offset, last_message.offset);
+        self.store
+            .put(
+                &Path::from(key),
+                PutPayload::from_bytes(to_json_lines(messages)?),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::messages::MessageState;
+    use std::collections::HashMap;
+
+    fn config(properties: &[(&str, &str)]) -> ConnectorConfig {
+        ConnectorConfig {
+            name: "archive".to_string(),
+            kind: crate::connectors::config::S3_SINK.to_string(),
+            stream: "orders".to_string(),
+            topic: "created".to_string(),
+            properties: properties
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn should_build_object_key_with_optional_prefix() {
+        let sink = S3Sink::new(&config(&[
+            (BUCKET_PROPERTY, "iggy"),
+            (PREFIX_PROPERTY, "/archive/"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            sink.get_object_key(2, 100, 199),
+            "archive/orders/created/partition=2/00000000000000000100-00000000000000000199.jsonl"
+        );
+
+        let sink = S3Sink::new(&config(&[(BUCKET_PROPERTY, "iggy")])).unwrap();
+        assert_eq!(
+            sink.get_object_key(1, 0, 0),
+            "orders/created/partition=1/00000000000000000000-00000000000000000000.jsonl"
+        );
+        assert!(S3Sink::new(&config(&[])).is_err());
+    }
+
+    #[test]
+    fn should_write_messages_as_json_lines() {
+        let messages = (0..2)
+            .map(|offset| {
+                Arc::new(Message::create(
+                    offset,
+                    MessageState::Available,
+                    1,
+                    offset as u128 + 1,
+                    Bytes::from("payload"),
+                    0,
+                    None,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let bytes = to_json_lines(&messages).unwrap();
+        let lines = std::str::from_utf8(&bytes)
+            .unwrap()
+            .lines()
+            .collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        let message = serde_json::from_str::<serde_json::Value>(lines[1]).unwrap();
+        assert_eq!(message["offset"], 1);
+        assert_eq!(message["payload"], "cGF5bG9hZA==");
+    }
+}
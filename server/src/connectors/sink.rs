@@ -0,0 +1,15 @@
+use crate::connectors::error::ConnectorError;
+use async_trait::async_trait;
+use iggy::models::messages::Message;
+use std::sync::Arc;
+
+/// Writes the messages consumed from the topic to the external system.
+/// The consumer offset is stored by the runtime only once the write has succeeded.
+#[async_trait]
+pub trait Sink: Send {
+    async fn write(
+        &mut self,
+        partition_id: u32,
+        messages: &[Arc<Message>],
+    ) -> Result<(), ConnectorError>;
+}
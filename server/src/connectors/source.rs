@@ -0,0 +1,22 @@
+use crate::connectors::error::ConnectorError;
+use async_trait::async_trait;
+use bytes::Bytes;
+
+/// The batch of records read from the external system, along with the offset to continue from.
+#[derive(Debug)]
+pub struct SourceBatch {
+    pub payloads: Vec<Bytes>,
+    pub offset: String,
+}
+
+/// Reads the records from the external system, which are appended to the topic by the runtime.
+/// The offset is opaque to the runtime, it's persisted only once the batch has been appended.
+#[async_trait]
+pub trait Source: Send {
+    /// Returns the batch of at most `count` records after the given offset, or `None` if there are no new records.
+    async fn read(
+        &mut self,
+        offset: Option<&str>,
+        count: u32,
+    ) -> Result<Option<SourceBatch>, ConnectorError>;
+}
@@ -0,0 +1,95 @@
+use crate::connectors::config::ConnectorConfig;
+use iggy::error::IggyError;
+use std::path::Path;
+use tokio::fs;
+use tracing::{error, info};
+
+const DEFINITION_EXTENSION: &str = "json";
+const OFFSET_EXTENSION: &str = "offset";
+
+/// Stores the connector definitions and the source offsets as the files in the connectors directory.
+/// The sink offsets are stored as the regular consumer offsets of the consumed topic.
+#[derive(Debug)]
+pub struct ConnectorStorage {
+    path: String,
+}
+
+impl ConnectorStorage {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+        }
+    }
+
+    pub async fn init(&self) -> Result<(), IggyError> {
+        if !Path::new(&self.path).exists() {
+            fs::create_dir_all(&self.path).await?;
+            info!("Created connectors directory: {}", self.path);
+        }
+        Ok(())
+    }
+
+    pub async fn load_definitions(&self) -> Result<Vec<ConnectorConfig>, IggyError> {
+        let mut definitions = Vec::new();
+        let mut entries = fs::read_dir(&self.path).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|extension| extension.to_str())
+                != Some(DEFINITION_EXTENSION)
+            {
+                continue;
+            }
+
+            let content = fs::read(&path).await?;
+            match serde_json::from_slice::<ConnectorConfig>(&content) {
+                Ok(definition) => definitions.push(definition),
+                Err(err) => {
+                    error!("Cannot parse connector definition: {path:?}. Error: {err}");
+                    return Err(IggyError::CannotLoadResource(err.into()));
+                }
+            }
+        }
+        definitions.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(definitions)
+    }
+
+    pub async fn save_definition(&self, definition: &ConnectorConfig) -> Result<(), IggyError> {
+        let content =
+            serde_json::to_vec_pretty(definition).map_err(|_| IggyError::InvalidFormat)?;
+        let path = self.get_path(&definition.name, DEFINITION_EXTENSION);
+        fs::write(&path, content).await?;
+        Ok(())
+    }
+
+    pub async fn delete(&self, name: &str) -> Result<(), IggyError> {
+        for extension in [DEFINITION_EXTENSION, OFFSET_EXTENSION] {
+            let path = self.get_path(name, extension);
+            if Path::new(&path).exists() {
+                fs::remove_file(&path).await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn load_offset(&self, name: &str) -> Result<Option<String>, IggyError> {
+        let path = self.get_path(name, OFFSET_EXTENSION);
+        if !Path::new(&path).exists() {
+            return Ok(None);
+        }
+
+        Ok(Some(fs::read_to_string(&path).await?))
+    }
+
+    /// Writes the offset to the temporary file first, so that the crash cannot leave the partially written offset.
+    pub async fn save_offset(&self, name: &str, offset: &str) -> Result<(), IggyError> {
+        let path = self.get_path(name, OFFSET_EXTENSION);
+        let temporary_path = format!("{path}.tmp");
+        fs::write(&temporary_path, offset).await?;
+        fs::rename(&temporary_path, &path).await?;
+        Ok(())
+    }
+
+    fn get_path(&self, name: &str, extension: &str) -> String {
+        format!("{}/{name}.{extension}", self.path)
+    }
+}
@@ -0,0 +1,104 @@
+use clap::Parser;
+use server::streaming::segments::inspection::{inspect_segment, InspectedEntry, SegmentFiles};
+use std::process::ExitCode;
+
+/// Inspects the segment files directly from disk, without the running server, e.g. for the post-mortem of the corrupted data.
+/// Prints the entries of the log, validates the checksums of the messages and the consistency of the index and time index files.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Path to the segment log, index or time index file, or the segment path without the extension
+    path: String,
+
+    /// Prints the metadata of each message, not only the entries of the log
+    #[arg(short, long, default_value_t = false)]
+    messages: bool,
+
+    /// Prints the payload of each message (as UTF-8, with the invalid sequences replaced), implies `--messages`
+    #[arg(short, long, default_value_t = false)]
+    payload: bool,
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    let args = Args::parse();
+    let files = SegmentFiles::from_path(&args.path);
+    println!(
+        "Inspecting segment log: {}, index: {}, time index: {}",
+        files.log_path, files.index_path, files.time_index_path
+    );
+
+    let report = match inspect_segment(&files, |entry| print_entry(entry, &args)).await {
+        Ok(report) => report,
+        Err(error) => {
+            eprintln!("Cannot inspect segment: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!(
+        "Log size: {} bytes, entries: {}, messages: {}, offsets: {}..{}, indexes: {}, time indexes: {}",
+        report.log_size,
+        report.entries_count,
+        report.messages_count,
+        report
+            .first_offset
+            .map_or("-".to_string(), |offset| offset.to_string()),
+        report
+            .last_offset
+            .map_or("-".to_string(), |offset| offset.to_string()),
+        report.indexes_count,
+        report.time_indexes_count
+    );
+
+    if report.is_valid() {
+        println!("No issues found.");
+        return ExitCode::SUCCESS;
+    }
+
+    println!("Found {} issue(s):", report.issues.len());
+    for issue in &report.issues {
+        println!("- {issue}");
+    }
+    ExitCode::FAILURE
+}
+
+fn print_entry(entry: &InspectedEntry, args: &Args) {
+    match entry.batch {
+        Some(batch) => println!(
+            "Batch at position: {}, size: {} bytes, messages: {}, base offset: {}, base timestamp: {}, max timestamp: {}, producer ID: {}, producer epoch: {}",
+            entry.position,
+            entry.size,
+            entry.messages.len(),
+            batch.base_offset,
+            batch.base_timestamp,
+            batch.max_timestamp,
+            batch.producer_id,
+            batch.producer_epoch
+        ),
+        None => println!(
+            "Message (format v1) at position: {}, size: {} bytes",
+            entry.position, entry.size
+        ),
+    }
+
+    if !args.messages && !args.payload {
+        return;
+    }
+
+    for message in &entry.messages {
+        println!(
+            "  Offset: {}, ID: {}, timestamp: {}, state: {}, checksum: {}, headers: {}, payload: {} bytes",
+            message.offset,
+            message.id,
+            message.timestamp,
+            message.state,
+            message.checksum,
+            message.headers.as_ref().map_or(0, |headers| headers.len()),
+            message.payload.len()
+        );
+        if args.payload {
+            println!("    {}", String::from_utf8_lossy(&message.payload));
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use iggy::error::IggyError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ExportError {
+    #[error("Cannot write Parquet file. Reason: {0}")]
+    Parquet(#[from] parquet::errors::ParquetError),
+    #[error("Cannot create record batch. Reason: {0}")]
+    Arrow(#[from] arrow_schema::ArrowError),
+    #[error("Cannot upload file. Reason: {0}")]
+    Storage(#[from] object_store::Error),
+    #[error(transparent)]
+    Iggy(#[from] IggyError),
+}
@@ -0,0 +1,4 @@
+pub mod error;
+pub mod parquet;
+pub mod storage;
+pub mod topic;
@@ -0,0 +1,165 @@
+use crate::export::error::ExportError;
+use arrow_array::{
+    ArrayRef, BinaryArray, RecordBatch, StringArray, TimestampMicrosecondArray, UInt64Array,
+};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use bytes::Bytes;
+use chrono::DateTime;
+use iggy::models::messages::Message;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use std::sync::Arc;
+
+/// Returns the schema of the exported messages, the key is the message ID and the headers are serialized
+/// as JSON, in the same format as returned by the HTTP API.
+fn get_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("offset", DataType::UInt64, false),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
+        Field::new("key", DataType::Utf8, false),
+        Field::new("headers", DataType::Utf8, true),
+        Field::new("payload", DataType::Binary, false),
+    ])
+}
+
+/// Writes the messages as the single row group of the Snappy compressed Parquet file.
+pub fn write(messages: &[Arc<Message>]) -> Result<Bytes, ExportError> {
+    let schema = Arc::new(get_schema());
+    let headers = messages
+        .iter()
+        .map(|message| {
+            message
+                .headers
+                .as_ref()
+                .map(serde_json::to_string)
+                .transpose()
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| iggy::error::IggyError::InvalidFormat)?;
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt64Array::from_iter_values(
+            messages.iter().map(|message| message.offset),
+        )),
+        Arc::new(
+            TimestampMicrosecondArray::from_iter_values(
+                messages.iter().map(|message| message.timestamp as i64),
+            )
+            .with_timezone("UTC"),
+        ),
+        Arc::new(StringArray::from_iter_values(
+            messages.iter().map(|message| message.id.to_string()),
+        )),
+        Arc::new(StringArray::from(headers)),
+        Arc::new(BinaryArray::from_iter_values(
+            messages.iter().map(|message| message.payload.as_ref()),
+        )),
+    ];
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer = ArrowWriter::try_new(Vec::new(), schema, Some(properties))?;
+    writer.write(&batch)?;
+    Ok(Bytes::from(writer.into_inner()?))
+}
+
+/// Splits the messages into the consecutive runs with the same date (UTC) of the timestamp,
+/// so each file belongs to the single date partition.
+pub fn group_by_date(messages: &[Arc<Message>]) -> Vec<(String, &[Arc<Message>])> {
+    let mut groups = Vec::new();
+    let mut start = 0;
+    let mut current_date: Option<String> = None;
+    for (index, message) in messages.iter().enumerate() {
+        let date = get_date(message.timestamp);
+        match &current_date {
+            Some(current) if *current == date => {}
+            Some(current) => {
+                groups.push((current.clone(), &messages[start..index]));
+                start = index;
+                current_date = Some(date);
+            }
+            None => current_date = Some(date),
+        }
+    }
+    if let Some(date) = current_date {
+        groups.push((date, &messages[start..]));
+    }
+    groups
+}
+
+fn get_date(timestamp: u64) -> String {
+    DateTime::from_timestamp_micros(timestamp as i64)
+        .unwrap_or_default()
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::header::{HeaderKey, HeaderValue};
+    use iggy::models::messages::MessageState;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    const DAY: u64 = 24 * 60 * 60 * 1_000_000;
+
+    fn message(offset: u64, timestamp: u64) -> Arc<Message> {
+        let headers = HashMap::from([(
+            HeaderKey::new("source").unwrap(),
+            HeaderValue::from_str("sensor").unwrap(),
+        )]);
+        Arc::new(Message::create(
+            offset,
+            MessageState::Available,
+            timestamp,
+            offset as u128 + 1,
+            Bytes::from(format!("payload-{offset}")),
+            0,
+            (offset == 0).then_some(headers),
+        ))
+    }
+
+    #[test]
+    fn should_group_consecutive_messages_by_date() {
+        let messages = vec![message(0, DAY), message(1, DAY + 1), message(2, 2 * DAY)];
+        let groups = group_by_date(&messages);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, "1970-01-02");
+        assert_eq!(groups[0].1.len(), 2);
+        assert_eq!(groups[1].0, "1970-01-03");
+        assert_eq!(groups[1].1[0].offset, 2);
+        assert!(group_by_date(&[]).is_empty());
+    }
+
+    #[test]
+    fn should_write_messages_to_parquet_file() {
+        let messages = vec![message(0, DAY), message(1, DAY)];
+        let file = write(&messages).unwrap();
+
+        let reader = SerializedFileReader::new(file).unwrap();
+        let metadata = reader.metadata();
+        assert_eq!(metadata.file_metadata().num_rows(), 2);
+        let columns = metadata
+            .file_metadata()
+            .schema_descr()
+            .columns()
+            .iter()
+            .map(|column| column.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            columns,
+            vec!["offset", "timestamp", "key", "headers", "payload"]
+        );
+        assert_eq!(
+            metadata.row_group(0).column(0).compression(),
+            Compression::SNAPPY
+        );
+    }
+}
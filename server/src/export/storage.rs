@@ -0,0 +1,59 @@
+use crate::configs::server::ExportConfig;
+use crate::export::error::ExportError;
+use bytes::Bytes;
+use object_store::aws::{AmazonS3, AmazonS3Builder};
+use object_store::path::Path;
+use object_store::{ObjectStore, PutPayload};
+
+/// Uploads the exported files to the S3 (or S3 compatible, e.g. MinIO) bucket.
+/// The credentials missing from the configuration are read from the standard AWS environment variables.
+#[derive(Debug)]
+pub struct ExportStorage {
+    store: AmazonS3,
+}
+
+impl ExportStorage {
+    pub fn new(config: &ExportConfig) -> Result<Self, ExportError> {
+        Ok(Self {
+            store: build_s3_store(
+                &config.bucket,
+                &config.region,
+                &config.endpoint,
+                &config.access_key_id,
+                &config.secret_access_key,
+            )?,
+        })
+    }
+
+    pub async fn put(&self, key: &str, file: Bytes) -> Result<(), ExportError> {
+        self.store
+            .put(&Path::from(key), PutPayload::from_bytes(file))
+            .await?;
+        Ok(())
+    }
+}
+
+/// Builds the client of the bucket, the empty endpoint and credentials are read from the AWS environment variables.
+pub fn build_s3_store(
+    bucket: &str,
+    region: &str,
+    endpoint: &str,
+    access_key_id: &str,
+    secret_access_key: &str,
+) -> Result<AmazonS3, object_store::Error> {
+    let mut builder = AmazonS3Builder::from_env()
+        .with_bucket_name(bucket)
+        .with_region(region);
+    if !endpoint.is_empty() {
+        builder = builder
+            .with_endpoint(endpoint)
+            .with_allow_http(endpoint.starts_with("http://"));
+    }
+    if !access_key_id.is_empty() {
+        builder = builder
+            .with_access_key_id(access_key_id)
+            .with_secret_access_key(secret_access_key);
+    }
+
+    builder.build()
+}
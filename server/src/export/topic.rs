@@ -0,0 +1,67 @@
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+
+/// The topic exported to the object storage, configured as `<stream>/<topic>`, where both the stream
+/// and the topic can be specified by ID or name.
+#[derive(Debug)]
+pub struct ExportedTopic {
+    pub stream: String,
+    pub topic: String,
+    pub stream_id: Identifier,
+    pub topic_id: Identifier,
+}
+
+impl ExportedTopic {
+    pub fn parse(value: &str) -> Result<Self, IggyError> {
+        let Some((stream, topic)) = value.split_once('/') else {
+            return Err(IggyError::InvalidTopicName);
+        };
+
+        Ok(Self {
+            stream: stream.to_string(),
+            topic: topic.to_string(),
+            stream_id: Identifier::from_str_value(stream)?,
+            topic_id: Identifier::from_str_value(topic)?,
+        })
+    }
+
+    /// Returns the key of the file with the messages from the offsets range, partitioned by the date,
+    /// e.g. `iggy/orders/created/date=2024-03-01/partition=1/00000000000000000000-00000000000000000099.parquet`.
+    pub fn get_object_key(
+        &self,
+        prefix: &str,
+        date: &str,
+        partition_id: u32,
+        first_offset: u64,
+        last_offset: u64,
+    ) -> String {
+        let key = format!(
+            "{}/{}/date={date}/partition={partition_id}/{first_offset:020}-{last_offset:020}.parquet",
+            self.stream, self.topic
+        );
+        match prefix.trim_matches('/') {
+            "" => key,
+            prefix => format!("{prefix}/{key}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_topic_and_build_object_key() {
+        let topic = ExportedTopic::parse("orders/created").unwrap();
+        assert_eq!(
+            topic.get_object_key("iggy/", "2024-03-01", 2, 100, 199),
+            "iggy/orders/created/date=2024-03-01/partition=2/00000000000000000100-00000000000000000199.parquet"
+        );
+        assert_eq!(
+            topic.get_object_key("", "2024-03-01", 1, 0, 0),
+            "orders/created/date=2024-03-01/partition=1/00000000000000000000-00000000000000000000.parquet"
+        );
+        assert!(ExportedTopic::parse("orders").is_err());
+        assert!(ExportedTopic::parse("orders/").is_err());
+    }
+}
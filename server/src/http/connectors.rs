@@ -0,0 +1,86 @@
+use crate::connectors::config::ConnectorConfig;
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::shared::AppState;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use iggy::models::connector::ConnectorInfo;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/connectors", get(get_connectors).post(create_connector))
+        .route(
+            "/connectors/:name",
+            get(get_connector).delete(delete_connector),
+        )
+        .route("/connectors/:name/restart", post(restart_connector))
+        .with_state(state)
+}
+
+async fn get_connector(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(name): Path<String>,
+) -> Result<Json<ConnectorInfo>, CustomError> {
+    let runtime = state
+        .system
+        .read()
+        .get_connectors_runtime(&Session::stateless(identity.user_id, identity.ip_address))?;
+    let connector = runtime.get_connector(&name).await?;
+    Ok(Json(connector))
+}
+
+async fn get_connectors(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<ConnectorInfo>>, CustomError> {
+    let runtime = state
+        .system
+        .read()
+        .get_connectors_runtime(&Session::stateless(identity.user_id, identity.ip_address))?;
+    let connectors = runtime.get_connectors().await;
+    Ok(Json(connectors))
+}
+
+async fn create_connector(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(config): Json<ConnectorConfig>,
+) -> Result<(StatusCode, Json<ConnectorInfo>), CustomError> {
+    let runtime = state
+        .system
+        .read()
+        .manage_connectors_runtime(&Session::stateless(identity.user_id, identity.ip_address))?;
+    let connector = runtime.create_connector(&state.system, config).await?;
+    Ok((StatusCode::CREATED, Json(connector)))
+}
+
+async fn restart_connector(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(name): Path<String>,
+) -> Result<Json<ConnectorInfo>, CustomError> {
+    let runtime = state
+        .system
+        .read()
+        .manage_connectors_runtime(&Session::stateless(identity.user_id, identity.ip_address))?;
+    let connector = runtime.restart_connector(&state.system, &name).await?;
+    Ok(Json(connector))
+}
+
+async fn delete_connector(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let runtime = state
+        .system
+        .read()
+        .manage_connectors_runtime(&Session::stateless(identity.user_id, identity.ip_address))?;
+    runtime.delete_connector(&name).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -35,12 +35,14 @@ async fn get_consumer_group(
     let topic_id = Identifier::from_str_value(&topic_id)?;
     let consumer_group_id = Identifier::from_str_value(&consumer_group_id)?;
     let system = state.system.read();
-    let consumer_group = system.get_consumer_group(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-        &topic_id,
-        &consumer_group_id,
-    )?;
+    let consumer_group = system
+        .get_consumer_group(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+            &consumer_group_id,
+        )
+        .await?;
     let consumer_group = consumer_group.read().await;
     let consumer_group = mapper::map_consumer_group(&consumer_group).await;
     Ok(Json(consumer_group))
@@ -54,11 +56,13 @@ async fn get_consumer_groups(
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
     let system = state.system.read();
-    let consumer_groups = system.get_consumer_groups(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-        &topic_id,
-    )?;
+    let consumer_groups = system
+        .get_consumer_groups(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+        )
+        .await?;
     let consumer_groups = mapper::map_consumer_groups(&consumer_groups).await;
     Ok(Json(consumer_groups))
 }
@@ -72,7 +76,7 @@ async fn create_consumer_group(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
-    let mut system = state.system.write();
+    let system = state.system.read();
     system
         .create_consumer_group(
             &Session::stateless(identity.user_id, identity.ip_address),
@@ -93,7 +97,7 @@ async fn delete_consumer_group(
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
     let consumer_group_id = Identifier::from_str_value(&consumer_group_id)?;
-    let mut system = state.system.write();
+    let system = state.system.read();
     system
         .delete_consumer_group(
             &Session::stateless(identity.user_id, identity.ip_address),
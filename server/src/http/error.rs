@@ -28,6 +28,10 @@ impl IntoResponse for CustomError {
                 let status_code = match error {
                     IggyError::StreamIdNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::TopicIdNotFound(_, _) => StatusCode::NOT_FOUND,
+                    IggyError::StreamUuidNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::TopicUuidNotFound(_, _) => StatusCode::NOT_FOUND,
+                    IggyError::TrashedStreamNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::TrashedTopicNotFound(_, _) => StatusCode::NOT_FOUND,
                     IggyError::PartitionNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::SegmentNotFound => StatusCode::NOT_FOUND,
                     IggyError::ClientNotFound(_) => StatusCode::NOT_FOUND,
@@ -36,6 +40,26 @@ impl IntoResponse for CustomError {
                     IggyError::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::CannotLoadResource(_) => StatusCode::NOT_FOUND,
                     IggyError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::SchemaIdNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::SchemaSubjectNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::IncompatibleSchema(_, _) => StatusCode::CONFLICT,
+                    IggyError::TenantIdNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::TenantNameNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::TenantUserNotFound(_, _) => StatusCode::NOT_FOUND,
+                    IggyError::TenantQuotaExceeded(_, _) => StatusCode::CONFLICT,
+                    IggyError::UserGroupIdNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::UserGroupNameNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::UserGroupMemberNotFound(_, _) => StatusCode::NOT_FOUND,
+                    IggyError::ConnectorNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::ConnectorAlreadyExists(_) => StatusCode::CONFLICT,
+                    IggyError::StreamsLimitReached(_) => StatusCode::CONFLICT,
+                    IggyError::TopicsLimitReached(_, _) => StatusCode::CONFLICT,
+                    IggyError::TopicFrozen(_, _) => StatusCode::CONFLICT,
+                    IggyError::ConsumerGroupsLimitReached(_, _, _) => StatusCode::CONFLICT,
+                    IggyError::ConsumerGroupMembersLimitReached(_, _, _) => StatusCode::CONFLICT,
+                    IggyError::PartitionsLimitReached(_) => StatusCode::CONFLICT,
+                    IggyError::SegmentsLimitReached(_, _) => StatusCode::CONFLICT,
+                    IggyError::MessageTooLarge(_, _, _) => StatusCode::PAYLOAD_TOO_LARGE,
                     IggyError::IoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::WriteError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::CannotParseInt(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -43,6 +67,7 @@ impl IntoResponse for CustomError {
                     IggyError::CannotParseUtf8(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::Unauthenticated => StatusCode::UNAUTHORIZED,
                     IggyError::Unauthorized => StatusCode::FORBIDDEN,
+                    IggyError::AddressNotAllowed(_) => StatusCode::FORBIDDEN,
                     _ => StatusCode::BAD_REQUEST,
                 };
                 (status_code, Json(ErrorResponse::from_error(error)))
@@ -61,6 +86,10 @@ impl ErrorResponse {
             field: match error {
                 IggyError::StreamIdNotFound(_) => Some("stream_id".to_string()),
                 IggyError::TopicIdNotFound(_, _) => Some("topic_id".to_string()),
+                IggyError::StreamUuidNotFound(_) => Some("stream_id".to_string()),
+                IggyError::TopicUuidNotFound(_, _) => Some("topic_id".to_string()),
+                IggyError::TrashedStreamNotFound(_) => Some("stream_id".to_string()),
+                IggyError::TrashedTopicNotFound(_, _) => Some("topic_id".to_string()),
                 IggyError::PartitionNotFound(_, _, _) => Some("partition_id".to_string()),
                 IggyError::SegmentNotFound => Some("segment_id".to_string()),
                 IggyError::ClientNotFound(_) => Some("client_id".to_string()),
@@ -80,6 +109,22 @@ impl ErrorResponse {
                 IggyError::ConsumerGroupNameAlreadyExists(_, _) => Some("name".to_string()),
                 IggyError::UserAlreadyExists => Some("username".to_string()),
                 IggyError::PersonalAccessTokenAlreadyExists(_, _) => Some("name".to_string()),
+                IggyError::InvalidSchemaSubject => Some("subject".to_string()),
+                IggyError::InvalidSchema(_) => Some("schema".to_string()),
+                IggyError::InvalidMessagesQuery(_) => Some("query".to_string()),
+                IggyError::InvalidTenantId => Some("tenant_id".to_string()),
+                IggyError::TenantIdNotFound(_) => Some("tenant_id".to_string()),
+                IggyError::TenantIdAlreadyExists(_) => Some("tenant_id".to_string()),
+                IggyError::InvalidTenantName => Some("name".to_string()),
+                IggyError::TenantNameAlreadyExists(_) => Some("name".to_string()),
+                IggyError::InvalidPermissionAction => Some("action".to_string()),
+                IggyError::InvalidUserMaxInactiveDays => Some("max_inactive_days".to_string()),
+                IggyError::InvalidUserGroupId => Some("group_id".to_string()),
+                IggyError::UserGroupIdNotFound(_) => Some("group_id".to_string()),
+                IggyError::UserGroupIdAlreadyExists(_) => Some("group_id".to_string()),
+                IggyError::InvalidUserGroupName => Some("name".to_string()),
+                IggyError::UserGroupNameAlreadyExists(_) => Some("name".to_string()),
+                IggyError::InvalidPollingMode(_) => Some("mode".to_string()),
                 _ => None,
             },
         }
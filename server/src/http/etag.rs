@@ -0,0 +1,83 @@
+use axum::body::{to_bytes, Body};
+use axum::http::header::{ETAG, IF_NONE_MATCH};
+use axum::http::{HeaderMap, HeaderValue, Method, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use tracing::error;
+
+/// Adds the `ETag` header (the hash of the response body) to the successful GET responses, and returns
+/// `304 Not Modified` without the body if it matches the `If-None-Match` header of the request,
+/// so the clients refreshing the resources periodically don't transfer them again if nothing has changed.
+pub async fn conditional_get(request: Request<Body>, next: Next) -> Response {
+    if request.method() != Method::GET {
+        return next.run(request).await;
+    }
+
+    let if_none_match = request.headers().get(IF_NONE_MATCH).cloned();
+    let response = next.run(request).await;
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(error) => {
+            error!("Failed to read the response body to calculate the ETag: {error}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let etag = format!("\"{}\"", &blake3::hash(&body).to_hex()[..32]);
+    let etag = HeaderValue::from_str(&etag).expect("ETag is a valid header value");
+    if if_none_match.is_some_and(|if_none_match| matches_etag(&if_none_match, &etag)) {
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, etag);
+        return (StatusCode::NOT_MODIFIED, headers).into_response();
+    }
+
+    parts.headers.insert(ETAG, etag);
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// Returns `true` if any of the ETags listed in the `If-None-Match` header (or `*`) matches the given one.
+/// The weak comparison is used, as required for `If-None-Match`, so the `W/` prefix is ignored.
+fn matches_etag(if_none_match: &HeaderValue, etag: &HeaderValue) -> bool {
+    let Ok(if_none_match) = if_none_match.to_str() else {
+        return false;
+    };
+    let Ok(etag) = etag.to_str() else {
+        return false;
+    };
+
+    let etag = etag.trim_start_matches("W/");
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn etag_should_match_if_none_match_header() {
+        let etag = HeaderValue::from_static("\"abc\"");
+        assert!(matches_etag(&HeaderValue::from_static("\"abc\""), &etag));
+        assert!(matches_etag(&HeaderValue::from_static("W/\"abc\""), &etag));
+        assert!(matches_etag(
+            &HeaderValue::from_static("\"xyz\", \"abc\""),
+            &etag
+        ));
+        assert!(matches_etag(&HeaderValue::from_static("*"), &etag));
+    }
+
+    #[test]
+    fn etag_should_not_match_other_etags() {
+        let etag = HeaderValue::from_static("\"abc\"");
+        assert!(!matches_etag(&HeaderValue::from_static("\"xyz\""), &etag));
+        assert!(!matches_etag(&HeaderValue::from_static("abc"), &etag));
+        assert!(!matches_etag(&HeaderValue::from_static(""), &etag));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::configs::http::{HttpConfig, HttpCorsConfig};
 use crate::http::diagnostics::request_diagnostics;
+use crate::http::ip_filter::{ip_filter, IpFilterState};
 use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
 use crate::http::jwt::middleware::jwt_auth;
@@ -12,8 +13,9 @@ use axum::{middleware, Router};
 use axum_server::tls_rustls::RustlsConfig;
 use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Arc;
-use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, CorsLayer, ExposeHeaders};
 use tracing::{error, info};
 
 /// Starts the HTTP API server.
@@ -25,7 +27,7 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         "HTTP API"
     };
 
-    let app_state = build_app_state(&config, system).await;
+    let app_state = build_app_state(&config, system.clone()).await;
     let mut app = Router::new()
         .merge(system::router(app_state.clone(), &config.metrics))
         .merge(personal_access_tokens::router(app_state.clone()))
@@ -35,9 +37,19 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(consumer_groups::router(app_state.clone()))
         .merge(consumer_offsets::router(app_state.clone()))
         .merge(partitions::router(app_state.clone()))
+        .merge(schemas::router(app_state.clone()))
+        .merge(tenants::router(app_state.clone()))
+        .merge(user_groups::router(app_state.clone()))
+        .merge(connectors::router(app_state.clone()))
         .merge(messages::router(app_state.clone()))
         .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
 
+    // Behind a reverse proxy the API is served under the configured path prefix, which is stripped
+    // before the request is routed, so the paths checked by the endpoints and the JWT middleware stay the same.
+    if !config.base_path.is_empty() {
+        app = Router::new().nest(&config.base_path, app);
+    }
+
     if config.cors.enabled {
         app = app.layer(configure_cors(config.cors));
     }
@@ -46,6 +58,17 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         app = app.layer(middleware::from_fn_with_state(app_state.clone(), metrics));
     }
 
+    // The address is checked before anything else, so the rejected clients don't reach the endpoints.
+    if config.ip_filter.is_enabled() {
+        app = app.layer(middleware::from_fn_with_state(
+            IpFilterState {
+                ip_filter: config.ip_filter.clone(),
+                system,
+            },
+            ip_filter,
+        ));
+    }
+
     start_expired_tokens_cleaner(app_state.clone());
     app = app.layer(middleware::from_fn(request_diagnostics));
 
@@ -123,41 +146,34 @@ async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppSt
         system,
     })
 }
+/// Creates the CORS layer from the validated configuration, an asterisk "*" allows any origin, method or header.
 fn configure_cors(config: HttpCorsConfig) -> CorsLayer {
+    let is_any = |values: &[String]| values.iter().any(|value| value == "*");
+
     let allowed_origins = match config.allowed_origins {
         origins if origins.is_empty() => AllowOrigin::default(),
-        origins if origins.first().unwrap() == "*" => AllowOrigin::any(),
+        origins if is_any(&origins) => AllowOrigin::any(),
         origins => AllowOrigin::list(origins.iter().map(|s| s.parse().unwrap())),
     };
 
-    let allowed_headers = config
-        .allowed_headers
-        .iter()
-        .map(|s| s.parse().unwrap())
-        .collect::<Vec<_>>();
-
-    let exposed_headers = config
-        .exposed_headers
-        .iter()
-        .map(|s| s.parse().unwrap())
-        .collect::<Vec<_>>();
-
-    let allowed_methods = config
-        .allowed_methods
-        .iter()
-        .map(|s| match s.to_uppercase().as_str() {
-            "GET" => Method::GET,
-            "POST" => Method::POST,
-            "PUT" => Method::PUT,
-            "DELETE" => Method::DELETE,
-            "HEAD" => Method::HEAD,
-            "OPTIONS" => Method::OPTIONS,
-            "CONNECT" => Method::CONNECT,
-            "PATCH" => Method::PATCH,
-            "TRACE" => Method::TRACE,
-            _ => panic!("Invalid HTTP method: {}", s),
-        })
-        .collect::<Vec<_>>();
+    let allowed_headers = match config.allowed_headers {
+        headers if is_any(&headers) => AllowHeaders::any(),
+        headers => AllowHeaders::list(headers.iter().map(|s| s.parse().unwrap())),
+    };
+
+    let exposed_headers = match config.exposed_headers {
+        headers if is_any(&headers) => ExposeHeaders::any(),
+        headers => ExposeHeaders::list(headers.iter().map(|s| s.parse().unwrap())),
+    };
+
+    let allowed_methods = match config.allowed_methods {
+        methods if is_any(&methods) => AllowMethods::any(),
+        methods => AllowMethods::list(
+            methods
+                .iter()
+                .map(|s| Method::from_str(&s.to_uppercase()).unwrap()),
+        ),
+    };
 
     CorsLayer::new()
         .allow_methods(allowed_methods)
@@ -0,0 +1,36 @@
+use crate::configs::ip_filter::IpFilterConfig;
+use crate::http::error::CustomError;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::systems::system::SharedSystem;
+use axum::body::Body;
+use axum::{
+    extract::{ConnectInfo, State},
+    http::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use iggy::error::IggyError;
+use std::net::SocketAddr;
+
+#[derive(Clone)]
+pub struct IpFilterState {
+    pub ip_filter: IpFilterConfig,
+    pub system: SharedSystem,
+}
+
+pub async fn ip_filter(
+    State(state): State<IpFilterState>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    if state.ip_filter.is_allowed(&address.ip()) {
+        return next.run(request).await;
+    }
+
+    state
+        .system
+        .read()
+        .reject_connection(&address, Transport::Http);
+    CustomError::Error(IggyError::AddressNotAllowed(address.ip().to_string())).into_response()
+}
@@ -19,6 +19,9 @@ pub struct JwtClaims {
     pub iat: u64,
     pub exp: u64,
     pub nbf: u64,
+    /// The session epoch of the user at the time of issuing, the token is revoked once the user's epoch changes.
+    #[serde(default)]
+    pub session_epoch: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -11,13 +11,14 @@ use sled::Db;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 pub struct IssuerOptions {
     pub issuer: String,
     pub audience: String,
     pub access_token_expiry: IggyDuration,
     pub refresh_token_expiry: IggyDuration,
+    pub refresh_token_max_lifetime: IggyDuration,
     pub not_before: IggyDuration,
     pub key: EncodingKey,
     pub algorithm: Algorithm,
@@ -67,6 +68,7 @@ impl JwtManager {
             audience: config.audience.clone(),
             access_token_expiry: config.access_token_expiry,
             refresh_token_expiry: config.refresh_token_expiry,
+            refresh_token_max_lifetime: config.refresh_token_max_lifetime,
             not_before: config.not_before,
             key: config.get_encoding_key()?,
             algorithm,
@@ -163,20 +165,48 @@ impl JwtManager {
         Ok(())
     }
 
-    pub fn generate(&self, user_id: UserId) -> Result<GeneratedTokens, IggyError> {
-        let header = Header::new(self.issuer.algorithm);
+    pub fn generate(
+        &self,
+        user_id: UserId,
+        session_epoch: u32,
+    ) -> Result<GeneratedTokens, IggyError> {
         let now = IggyTimestamp::now().to_secs();
+        let family_expiry = if self.issuer.refresh_token_max_lifetime.is_zero() {
+            0
+        } else {
+            now + self.issuer.refresh_token_max_lifetime.as_secs() as u64
+        };
+        self.generate_in_family(
+            user_id,
+            session_epoch,
+            now,
+            &uuid::Uuid::new_v4().to_string(),
+            family_expiry,
+        )
+    }
+
+    fn generate_in_family(
+        &self,
+        user_id: UserId,
+        session_epoch: u32,
+        now: u64,
+        family_id: &str,
+        family_expiry: u64,
+    ) -> Result<GeneratedTokens, IggyError> {
+        let header = Header::new(self.issuer.algorithm);
         let iat = now;
         let exp = iat + self.issuer.access_token_expiry.as_secs() as u64;
         let nbf = iat + self.issuer.not_before.as_secs() as u64;
+        let jti = uuid::Uuid::new_v4().to_string();
         let claims = JwtClaims {
-            jti: uuid::Uuid::new_v4().to_string(),
+            jti: jti.clone(),
             sub: user_id,
             aud: self.issuer.audience.to_string(),
             iss: self.issuer.issuer.to_string(),
             iat,
             exp,
             nbf,
+            session_epoch,
         };
 
         let access_token = encode::<JwtClaims>(&header, &claims, &self.issuer.key);
@@ -185,11 +215,16 @@ impl JwtManager {
             return Err(IggyError::CannotGenerateJwt);
         }
 
-        let (refresh_token, raw_refresh_token) = RefreshToken::new(
+        let (mut refresh_token, raw_refresh_token) = RefreshToken::new_in_family(
             user_id,
             now,
             self.issuer.refresh_token_expiry.as_secs() as u64,
+            family_id,
+            family_expiry,
         );
+        refresh_token.access_token_id = jti;
+        refresh_token.access_token_expiry = exp;
+        refresh_token.session_epoch = session_epoch;
         self.tokens_storage.save_refresh_token(&refresh_token)?;
 
         Ok(GeneratedTokens {
@@ -201,7 +236,13 @@ impl JwtManager {
         })
     }
 
-    pub fn refresh_token(&self, refresh_token: &str) -> Result<GeneratedTokens, IggyError> {
+    /// Rotates the refresh token, which is rejected if the user's current session epoch
+    /// (as resolved by the provided function) differs from the one it was issued with.
+    pub async fn refresh_token(
+        &self,
+        refresh_token: &str,
+        get_session_epoch: impl Fn(UserId) -> Option<u32>,
+    ) -> Result<GeneratedTokens, IggyError> {
         let now = IggyTimestamp::now().to_secs();
         if refresh_token.is_empty() {
             return Err(IggyError::InvalidRefreshToken);
@@ -214,18 +255,133 @@ impl JwtManager {
         }
 
         let refresh_token = refresh_token.unwrap();
-        self.tokens_storage.delete_refresh_token(&token_hash)?;
-        if refresh_token.expiry < now {
+        if refresh_token.used {
+            warn!(
+                "Detected reuse of the rotated refresh token for user with ID: {}, revoking the tokens family: {}.",
+                refresh_token.user_id, refresh_token.family_id
+            );
+            self.revoke_tokens_family(&refresh_token.family_id, now)
+                .await?;
+            return Err(IggyError::InvalidRefreshToken);
+        }
+
+        if refresh_token.is_expired(now) {
+            self.tokens_storage.delete_refresh_token(&token_hash)?;
             return Err(IggyError::RefreshTokenExpired);
         }
 
-        self.generate(refresh_token.user_id)
+        let session_epoch = get_session_epoch(refresh_token.user_id);
+        if session_epoch != Some(refresh_token.session_epoch) {
+            info!(
+                "Refresh token for user with ID: {} has been revoked by the change of the session epoch.",
+                refresh_token.user_id
+            );
+            if refresh_token.family_id.is_empty() {
+                self.tokens_storage.delete_refresh_token(&token_hash)?;
+            } else {
+                self.revoke_tokens_family(&refresh_token.family_id, now)
+                    .await?;
+            }
+            return Err(IggyError::InvalidRefreshToken);
+        }
+
+        // The tokens issued before the rotation tracking have no family, so they're simply exchanged.
+        if refresh_token.family_id.is_empty() {
+            self.tokens_storage.delete_refresh_token(&token_hash)?;
+            return self.generate(refresh_token.user_id, refresh_token.session_epoch);
+        }
+
+        // The concurrent rotation of the same token is treated as its reuse.
+        if !self.tokens_storage.mark_refresh_token_used(&token_hash)? {
+            warn!(
+                "Detected concurrent reuse of the refresh token for user with ID: {}, revoking the tokens family: {}.",
+                refresh_token.user_id, refresh_token.family_id
+            );
+            self.revoke_tokens_family(&refresh_token.family_id, now)
+                .await?;
+            return Err(IggyError::InvalidRefreshToken);
+        }
+
+        self.generate_in_family(
+            refresh_token.user_id,
+            refresh_token.session_epoch,
+            now,
+            &refresh_token.family_id,
+            refresh_token.family_expiry,
+        )
+    }
+
+    /// Revokes the whole family of the refresh token owned by the user, including the issued access tokens.
+    /// Possessing the refresh token is enough to revoke it, no matter which access token of the user is used.
+    pub async fn revoke_refresh_token(
+        &self,
+        refresh_token: &str,
+        user_id: UserId,
+    ) -> Result<(), IggyError> {
+        if refresh_token.is_empty() {
+            return Err(IggyError::InvalidRefreshToken);
+        }
+
+        let token_hash = RefreshToken::hash_token(refresh_token);
+        let refresh_token = self.tokens_storage.load_refresh_token(&token_hash);
+        if refresh_token.is_err() {
+            return Err(IggyError::InvalidRefreshToken);
+        }
+
+        let refresh_token = refresh_token.unwrap();
+        if refresh_token.user_id != user_id {
+            return Err(IggyError::InvalidRefreshToken);
+        }
+
+        if refresh_token.family_id.is_empty() {
+            return self.tokens_storage.delete_refresh_token(&token_hash);
+        }
+
+        self.revoke_tokens_family(&refresh_token.family_id, IggyTimestamp::now().to_secs())
+            .await
+    }
+
+    async fn revoke_tokens_family(&self, family_id: &str, now: u64) -> Result<(), IggyError> {
+        let refresh_tokens = self.tokens_storage.load_all_refresh_tokens()?;
+        for token in refresh_tokens {
+            if token.family_id != family_id {
+                continue;
+            }
+
+            self.tokens_storage
+                .delete_refresh_token(&token.token_hash)?;
+            if !token.access_token_id.is_empty() && token.access_token_expiry >= now {
+                self.revoke_token(&token.access_token_id, token.access_token_expiry)
+                    .await?;
+            }
+        }
+
+        info!("Revoked refresh tokens family: {family_id}");
+        Ok(())
     }
 
     pub fn decode(
         &self,
         token: &str,
         algorithm: Algorithm,
+    ) -> Result<TokenData<JwtClaims>, IggyError> {
+        self.decode_with_expiry(token, algorithm, true)
+    }
+
+    /// Decodes the access token, which may have already expired, e.g. to revoke the refresh token after the logout.
+    pub fn decode_ignoring_expiry(
+        &self,
+        token: &str,
+        algorithm: Algorithm,
+    ) -> Result<TokenData<JwtClaims>, IggyError> {
+        self.decode_with_expiry(token, algorithm, false)
+    }
+
+    fn decode_with_expiry(
+        &self,
+        token: &str,
+        algorithm: Algorithm,
+        validate_expiry: bool,
     ) -> Result<TokenData<JwtClaims>, IggyError> {
         let validation = self.validations.get(&algorithm);
         if validation.is_none() {
@@ -234,8 +390,9 @@ impl JwtManager {
             ));
         }
 
-        let validation = validation.unwrap();
-        match jsonwebtoken::decode::<JwtClaims>(token, &self.validator.key, validation) {
+        let mut validation = validation.unwrap().clone();
+        validation.validate_exp = validate_expiry;
+        match jsonwebtoken::decode::<JwtClaims>(token, &self.validator.key, &validation) {
             Ok(claims) => Ok(claims),
             _ => Err(IggyError::Unauthenticated),
         }
@@ -271,3 +428,115 @@ impl JwtManager {
         revoked_tokens.contains_key(token_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const CONCURRENT_REFRESHES: usize = 8;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_refresh_of_the_same_token_should_succeed_only_once() {
+        let jwt_manager = Arc::new(create_jwt_manager());
+        let session_epoch = 1;
+        let tokens = jwt_manager.generate(1, session_epoch).unwrap();
+
+        let barrier = Arc::new(tokio::sync::Barrier::new(CONCURRENT_REFRESHES));
+        let mut tasks = Vec::with_capacity(CONCURRENT_REFRESHES);
+        for _ in 0..CONCURRENT_REFRESHES {
+            let jwt_manager = jwt_manager.clone();
+            let barrier = barrier.clone();
+            let refresh_token = tokens.refresh_token.clone();
+            tasks.push(tokio::spawn(async move {
+                barrier.wait().await;
+                jwt_manager
+                    .refresh_token(&refresh_token, |_| Some(session_epoch))
+                    .await
+            }));
+        }
+
+        let mut succeeded = 0;
+        for task in tasks {
+            match task.await.unwrap() {
+                Ok(_) => succeeded += 1,
+                Err(error) => assert_eq!(error.as_code(), IggyError::InvalidRefreshToken.as_code()),
+            }
+        }
+        assert_eq!(succeeded, 1);
+    }
+
+    #[tokio::test]
+    async fn refresh_token_should_be_revoked_only_by_its_owner() {
+        let jwt_manager = create_jwt_manager();
+        let session_epoch = 1;
+        let tokens = jwt_manager.generate(1, session_epoch).unwrap();
+
+        let result = jwt_manager
+            .revoke_refresh_token(&tokens.refresh_token, 2)
+            .await;
+        assert_eq!(
+            result.unwrap_err().as_code(),
+            IggyError::InvalidRefreshToken.as_code()
+        );
+
+        jwt_manager
+            .revoke_refresh_token(&tokens.refresh_token, 1)
+            .await
+            .unwrap();
+        let result = jwt_manager
+            .refresh_token(&tokens.refresh_token, |_| Some(session_epoch))
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn expired_access_token_should_be_decoded_only_when_ignoring_expiry() {
+        let jwt_manager = create_jwt_manager();
+        let now = IggyTimestamp::now().to_secs();
+        let claims = JwtClaims {
+            jti: "expired".to_string(),
+            iss: "iggy.rs".to_string(),
+            aud: "iggy.rs".to_string(),
+            sub: 1,
+            iat: now - 3600,
+            exp: now - 60,
+            nbf: now - 3600,
+            session_epoch: 1,
+        };
+        let token = encode(
+            &Header::new(Algorithm::HS256),
+            &claims,
+            &jwt_manager.issuer.key,
+        )
+        .unwrap();
+
+        assert!(jwt_manager.decode(&token, Algorithm::HS256).is_err());
+        let decoded = jwt_manager
+            .decode_ignoring_expiry(&token, Algorithm::HS256)
+            .unwrap();
+        assert_eq!(decoded.claims.sub, 1);
+    }
+
+    fn create_jwt_manager() -> JwtManager {
+        let secret = b"top_secret$iggy.rs$_jwt_$3cr3t!";
+        let issuer = IssuerOptions {
+            issuer: "iggy.rs".to_string(),
+            audience: "iggy.rs".to_string(),
+            access_token_expiry: IggyDuration::new(Duration::from_secs(3600)),
+            refresh_token_expiry: IggyDuration::new(Duration::from_secs(86400)),
+            refresh_token_max_lifetime: IggyDuration::new(Duration::from_secs(0)),
+            not_before: IggyDuration::new(Duration::from_secs(0)),
+            key: EncodingKey::from_secret(secret),
+            algorithm: Algorithm::HS256,
+        };
+        let validator = ValidatorOptions {
+            valid_audiences: vec!["iggy.rs".to_string()],
+            valid_issuers: vec!["iggy.rs".to_string()],
+            clock_skew: IggyDuration::new(Duration::from_secs(5)),
+            key: DecodingKey::from_secret(secret),
+        };
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        JwtManager::new(issuer, validator, Arc::new(db)).unwrap()
+    }
+}
@@ -1,5 +1,6 @@
-use crate::http::jwt::json_web_token::Identity;
+use crate::http::jwt::json_web_token::{Identity, JwtClaims};
 use crate::http::shared::{AppState, RequestDetails};
+use crate::streaming::users::user::ANONYMOUS_USER_ID;
 use axum::body::Body;
 use axum::{
     extract::State,
@@ -8,15 +9,20 @@ use axum::{
     response::Response,
 };
 use std::sync::Arc;
+use tracing::warn;
 
 const AUTHORIZATION: &str = "authorization";
 const BEARER: &str = "Bearer ";
 const UNAUTHORIZED: StatusCode = StatusCode::UNAUTHORIZED;
+/// The refresh token can be revoked with the expired or logged out access token of its owner.
+const REVOKE_TOKEN_PATH: &str = "/users/revoke-token";
 
 const UNAUTHORIZED_PATHS: &[&str] = &[
     "/",
     "/metrics",
     "/ping",
+    "/ready",
+    "/hello",
     "/users/login",
     "/users/refresh-token",
     "/personal-access-tokens/login",
@@ -31,12 +37,16 @@ pub async fn jwt_auth(
         return Ok(next.run(request).await);
     }
 
-    let bearer = request
-        .headers()
-        .get(AUTHORIZATION)
-        .ok_or(UNAUTHORIZED)?
-        .to_str()
-        .map_err(|_| UNAUTHORIZED)?;
+    let revoking_token = request.uri().path() == REVOKE_TOKEN_PATH;
+    let Some(bearer) = request.headers().get(AUTHORIZATION) else {
+        if revoking_token {
+            return Err(UNAUTHORIZED);
+        }
+
+        return authenticate_anonymous(&state, request, next).await;
+    };
+
+    let bearer = bearer.to_str().map_err(|_| UNAUTHORIZED)?;
 
     if !bearer.starts_with(BEARER) {
         return Err(StatusCode::UNAUTHORIZED);
@@ -44,6 +54,15 @@ pub async fn jwt_auth(
 
     let jwt_token = &bearer[BEARER.len()..];
     let token_header = jsonwebtoken::decode_header(jwt_token).map_err(|_| UNAUTHORIZED)?;
+    if revoking_token {
+        let jwt_claims = state
+            .jwt_manager
+            .decode_ignoring_expiry(jwt_token, token_header.alg)
+            .map_err(|_| UNAUTHORIZED)?;
+        insert_identity(&mut request, jwt_claims.claims);
+        return Ok(next.run(request).await);
+    }
+
     let jwt_claims = state
         .jwt_manager
         .decode(jwt_token, token_header.alg)
@@ -56,11 +75,57 @@ pub async fn jwt_auth(
         return Err(StatusCode::UNAUTHORIZED);
     }
 
+    let session_epoch = state
+        .system
+        .read()
+        .permissioner
+        .get_session_epoch(jwt_claims.claims.sub);
+    if session_epoch != Some(jwt_claims.claims.session_epoch) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    if let Err(error) = state
+        .system
+        .read()
+        .record_user_activity(jwt_claims.claims.sub)
+        .await
+    {
+        warn!(
+            "Cannot record the activity of the user with ID: {}, error: {error}",
+            jwt_claims.claims.sub
+        );
+    }
+
+    insert_identity(&mut request, jwt_claims.claims);
+    Ok(next.run(request).await)
+}
+
+fn insert_identity(request: &mut Request<Body>, claims: JwtClaims) {
+    let request_details = request.extensions().get::<RequestDetails>().unwrap();
+    let identity = Identity {
+        token_id: claims.jti,
+        token_expiry: claims.exp,
+        user_id: claims.sub,
+        ip_address: request_details.ip_address,
+    };
+    request.extensions_mut().insert(identity);
+}
+
+/// Maps the request without the access token to the anonymous pseudo-user, if the anonymous access is enabled.
+async fn authenticate_anonymous(
+    state: &AppState,
+    mut request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if !state.system.read().config.anonymous_access.enabled {
+        return Err(UNAUTHORIZED);
+    }
+
     let request_details = request.extensions().get::<RequestDetails>().unwrap();
     let identity = Identity {
-        token_id: jwt_claims.claims.jti,
-        token_expiry: jwt_claims.claims.exp,
-        user_id: jwt_claims.claims.sub,
+        token_id: String::new(),
+        token_expiry: 0,
+        user_id: ANONYMOUS_USER_ID,
         ip_address: request_details.ip_address,
     };
     request.extensions_mut().insert(identity);
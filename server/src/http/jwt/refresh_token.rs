@@ -6,27 +6,61 @@ use serde::{Deserialize, Serialize};
 
 const REFRESH_TOKEN_SIZE: usize = 50;
 
+/// The refresh token is rotated on every use, the rotated tokens share the family ID
+/// and are kept as used until they expire, so that a reuse of the stolen token can be detected.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RefreshToken {
     #[serde(skip)]
     pub token_hash: String,
     pub user_id: u32,
     pub expiry: u64,
+    #[serde(default)]
+    pub family_id: String,
+    #[serde(default)]
+    pub family_expiry: u64,
+    #[serde(default)]
+    pub access_token_id: String,
+    #[serde(default)]
+    pub access_token_expiry: u64,
+    #[serde(default)]
+    pub used: bool,
+    #[serde(default)]
+    pub session_epoch: u32,
 }
 
 impl RefreshToken {
     pub fn new(user_id: UserId, now: u64, expiry: u64) -> (Self, String) {
+        Self::new_in_family(user_id, now, expiry, &uuid::Uuid::new_v4().to_string(), 0)
+    }
+
+    /// Creates the next token in the family, its expiry can't exceed the family expiry (if set).
+    pub fn new_in_family(
+        user_id: UserId,
+        now: u64,
+        expiry: u64,
+        family_id: &str,
+        family_expiry: u64,
+    ) -> (Self, String) {
         let mut buffer: [u8; REFRESH_TOKEN_SIZE] = [0; REFRESH_TOKEN_SIZE];
         let system_random = ring::rand::SystemRandom::new();
         system_random.fill(&mut buffer).unwrap();
         let token = as_base64(&buffer);
         let hash = Self::hash_token(&token);
-        let expiry = now + expiry;
+        let mut expiry = now + expiry;
+        if family_expiry > 0 && expiry > family_expiry {
+            expiry = family_expiry;
+        }
         (
             Self {
                 token_hash: hash,
                 user_id,
                 expiry,
+                family_id: family_id.to_string(),
+                family_expiry,
+                access_token_id: String::new(),
+                access_token_expiry: 0,
+                used: false,
+                session_epoch: 0,
             },
             token,
         )
@@ -70,4 +104,16 @@ mod tests {
         let (refresh_token, _) = RefreshToken::new(user_id, now, expiry);
         assert!(refresh_token.is_expired(now + expiry + 1));
     }
+
+    #[test]
+    fn refresh_token_in_family_should_not_outlive_family_expiry() {
+        let user_id = 1;
+        let now = IggyTimestamp::now().to_secs();
+        let family_expiry = now + 5;
+        let (refresh_token, _) =
+            RefreshToken::new_in_family(user_id, now, 10, "family", family_expiry);
+        assert_eq!(refresh_token.family_id, "family");
+        assert_eq!(refresh_token.expiry, family_expiry);
+        assert!(!refresh_token.used);
+    }
 }
@@ -71,10 +71,10 @@ impl TokenStorage {
                     })
                     .map_err(IggyError::CannotDeserializeResource)?;
 
-                token.token_hash = from_utf8(&hash)
+                let hash = from_utf8(&hash)
                     .with_context(|| "Failed to convert hash to UTF-8 string")
-                    .map_err(IggyError::CannotDeserializeResource)?
-                    .to_string();
+                    .map_err(IggyError::CannotDeserializeResource)?;
+                token.token_hash = hash.strip_prefix(&key).unwrap_or(hash).to_string();
                 Ok(token)
             })
             .collect();
@@ -158,6 +158,52 @@ impl TokenStorage {
         Ok(())
     }
 
+    /// Atomically marks the refresh token as used, returns `false` if it was already used
+    /// (or changed in the meantime) by the concurrent rotation.
+    pub fn mark_refresh_token_used(&self, token_hash: &str) -> Result<bool, IggyError> {
+        let key = Self::get_refresh_token_key(token_hash);
+        let token_data = self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load refresh token, key: {}", key));
+        if let Err(err) = token_data {
+            return Err(IggyError::CannotLoadResource(err));
+        }
+
+        let token_data = token_data.unwrap();
+        if token_data.is_none() {
+            return Ok(false);
+        }
+
+        let token_data = token_data.unwrap();
+        let token = rmp_serde::from_slice::<RefreshToken>(&token_data)
+            .with_context(|| format!("Failed to deserialize refresh token, key: {}", key));
+        if let Err(err) = token {
+            return Err(IggyError::CannotDeserializeResource(err));
+        }
+
+        let mut token = token.unwrap();
+        if token.used {
+            return Ok(false);
+        }
+
+        token.used = true;
+        let data = rmp_serde::to_vec(&token)
+            .with_context(|| format!("Failed to serialize refresh token, key: {}", key));
+        if let Err(err) = data {
+            return Err(IggyError::CannotSerializeResource(err));
+        }
+
+        match self
+            .db
+            .compare_and_swap(&key, Some(token_data), Some(data.unwrap()))
+            .with_context(|| format!("Failed to save refresh token, key: {}", key))
+        {
+            Ok(result) => Ok(result.is_ok()),
+            Err(err) => Err(IggyError::CannotSaveResource(err)),
+        }
+    }
+
     pub fn delete_revoked_access_token(&self, id: &str) -> Result<(), IggyError> {
         let key = Self::get_revoked_token_key(id);
         if let Err(err) = self
@@ -1,45 +1,62 @@
 use crate::http::jwt::json_web_token::GeneratedTokens;
 use crate::streaming::clients::client_manager::Client;
+use crate::streaming::models::messages::{DecodedMessage, DecodedPolledMessages, PolledMessages};
+use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::schemas::schema::Schema;
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::tenants::tenant::{Tenant, TenantUsage};
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::user_groups::user_group::UserGroup;
 use crate::streaming::users::user::User;
 use iggy::models::client_info::ConsumerGroupInfo;
 use iggy::models::consumer_group::{ConsumerGroupDetails, ConsumerGroupMember};
 use iggy::models::identity_info::{IdentityInfo, IdentityTokens, TokenInfo};
+use iggy::models::partition::{PartitionDetails, Segment};
 use iggy::models::personal_access_token::PersonalAccessTokenInfo;
+use iggy::models::schema::SchemaInfo;
 use iggy::models::stream::StreamDetails;
 use iggy::models::topic::TopicDetails;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
+use iggy::schemas::decoding::PayloadDecoder;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::text;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, RwLockReadGuard};
 
 pub async fn map_stream(stream: &Stream) -> StreamDetails {
     let topics = map_topics(&stream.get_topics()).await;
     let mut stream_details = StreamDetails {
         id: stream.stream_id,
+        uuid: stream.uuid,
         created_at: stream.created_at,
         name: stream.name.clone(),
         topics_count: topics.len() as u32,
         size_bytes: stream.get_size(),
         messages_count: stream.get_messages_count(),
+        topic_defaults: stream.topic_defaults.clone(),
         topics,
     };
     stream_details.topics.sort_by(|a, b| a.id.cmp(&b.id));
     stream_details
 }
 
-pub async fn map_streams(streams: &[&Stream]) -> Vec<iggy::models::stream::Stream> {
+pub async fn map_streams(
+    streams: &[RwLockReadGuard<'_, Stream>],
+) -> Vec<iggy::models::stream::Stream> {
     let mut streams_data = Vec::with_capacity(streams.len());
     for stream in streams {
         let stream = iggy::models::stream::Stream {
             id: stream.stream_id,
+            uuid: stream.uuid,
             created_at: stream.created_at,
             name: stream.name.clone(),
             size_bytes: stream.get_size(),
             topics_count: stream.get_topics().len() as u32,
             messages_count: stream.get_messages_count(),
+            topic_defaults: stream.topic_defaults.clone(),
         };
         streams_data.push(stream);
     }
@@ -53,6 +70,7 @@ pub async fn map_topics(topics: &[&Topic]) -> Vec<iggy::models::topic::Topic> {
     for topic in topics {
         let topic = iggy::models::topic::Topic {
             id: topic.topic_id,
+            uuid: topic.uuid,
             created_at: topic.created_at,
             name: topic.name.clone(),
             size: topic.get_size(),
@@ -61,6 +79,11 @@ pub async fn map_topics(topics: &[&Topic]) -> Vec<iggy::models::topic::Topic> {
             message_expiry: topic.message_expiry,
             max_topic_size: topic.max_topic_size,
             replication_factor: topic.replication_factor,
+            routing_epoch: topic.routing_epoch,
+            compression_algorithm: topic.compression_algorithm.clone(),
+            frozen: topic.frozen,
+            consumer_limits: topic.consumer_limits,
+            max_message_size: topic.get_max_message_size(),
         };
         topics_data.push(topic);
     }
@@ -71,6 +94,7 @@ pub async fn map_topics(topics: &[&Topic]) -> Vec<iggy::models::topic::Topic> {
 pub async fn map_topic(topic: &Topic) -> TopicDetails {
     let mut topic_details = TopicDetails {
         id: topic.topic_id,
+        uuid: topic.uuid,
         created_at: topic.created_at,
         name: topic.name.clone(),
         size: topic.get_size(),
@@ -80,6 +104,15 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         message_expiry: topic.message_expiry,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
+        routing_epoch: topic.routing_epoch,
+        compression_algorithm: topic.compression_algorithm.clone(),
+        frozen: topic.frozen,
+        consumer_limits: topic.consumer_limits,
+        max_message_size: topic.get_max_message_size(),
+        message_schema: topic
+            .message_schema
+            .as_ref()
+            .map(|message_schema| message_schema.as_str().to_string()),
     };
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
@@ -98,13 +131,45 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
     topic_details
 }
 
+pub async fn map_partition(partition: &Partition) -> PartitionDetails {
+    let mut partition_details = PartitionDetails {
+        id: partition.partition_id,
+        created_at: partition.created_at,
+        segments_count: partition.get_segments().len() as u32,
+        current_offset: partition.current_offset,
+        size_bytes: partition.get_size_bytes().into(),
+        messages_count: partition.get_messages_count(),
+        segments: Vec::new(),
+    };
+    let now = IggyTimestamp::now().to_micros();
+    for segment in partition.get_segments() {
+        partition_details.segments.push(Segment {
+            start_offset: segment.start_offset,
+            end_offset: match segment.is_closed {
+                true => segment.end_offset,
+                false => segment.current_offset,
+            },
+            size_bytes: (segment.size_bytes as u64).into(),
+            is_closed: segment.is_closed,
+            message_expiry: segment.message_expiry,
+            is_expired: segment.is_expired(now).await,
+        });
+    }
+    partition_details.segments.sort_by_key(|x| x.start_offset);
+    partition_details
+}
+
 pub fn map_user(user: &User) -> UserInfoDetails {
     UserInfoDetails {
         id: user.id,
+        uuid: user.uuid,
         username: user.username.clone(),
         created_at: user.created_at,
         status: user.status,
         permissions: user.permissions.clone(),
+        expires_at: user.expires_at,
+        max_inactive_days: user.max_inactive_days,
+        last_active_at: user.last_active_at,
     }
 }
 
@@ -113,6 +178,7 @@ pub fn map_users(users: &[User]) -> Vec<UserInfo> {
     for user in users {
         let user = UserInfo {
             id: user.id,
+            uuid: user.uuid,
             username: user.username.clone(),
             created_at: user.created_at,
             status: user.status,
@@ -138,6 +204,103 @@ pub fn map_personal_access_tokens(
     personal_access_tokens_data
 }
 
+pub fn map_schema(schema: &Schema) -> SchemaInfo {
+    SchemaInfo {
+        id: schema.id,
+        subject: schema.subject.clone(),
+        version: schema.version,
+        format: schema.format,
+        schema: schema.schema.clone(),
+        created_at: schema.created_at,
+    }
+}
+
+pub fn map_schemas(schemas: &[&Schema]) -> Vec<SchemaInfo> {
+    schemas.iter().map(|schema| map_schema(schema)).collect()
+}
+
+pub fn map_tenant(tenant: &Tenant, usage: &TenantUsage) -> iggy::models::tenant::Tenant {
+    iggy::models::tenant::Tenant {
+        id: tenant.id,
+        created_at: tenant.created_at,
+        name: tenant.name.clone(),
+        quotas: tenant.quotas,
+        streams_count: usage.streams_count,
+        topics_count: usage.topics_count,
+        partitions_count: usage.partitions_count,
+        size: IggyByteSize::from(usage.size_bytes),
+        users_count: usage.users_count,
+    }
+}
+
+pub fn map_user_group(group: &UserGroup) -> iggy::models::user_group::UserGroupDetails {
+    iggy::models::user_group::UserGroupDetails {
+        id: group.id,
+        created_at: group.created_at,
+        name: group.name.clone(),
+        members: group.members.iter().copied().collect(),
+        permissions: group.permissions.clone(),
+    }
+}
+
+pub fn map_user_groups(groups: &[&UserGroup]) -> Vec<iggy::models::user_group::UserGroup> {
+    let mut groups_data = groups
+        .iter()
+        .map(|group| iggy::models::user_group::UserGroup {
+            id: group.id,
+            created_at: group.created_at,
+            name: group.name.clone(),
+            members_count: group.members.len() as u32,
+        })
+        .collect::<Vec<_>>();
+    groups_data.sort_by_key(|group| group.id);
+    groups_data
+}
+
+pub fn map_tenants(tenants: &[(&Tenant, TenantUsage)]) -> Vec<iggy::models::tenant::Tenant> {
+    let mut tenants_data = tenants
+        .iter()
+        .map(|(tenant, usage)| map_tenant(tenant, usage))
+        .collect::<Vec<_>>();
+    tenants_data.sort_by_key(|tenant| tenant.id);
+    tenants_data
+}
+
+pub fn map_decoded_messages(
+    polled_messages: PolledMessages,
+    decoder: &PayloadDecoder,
+) -> DecodedPolledMessages {
+    let messages = polled_messages
+        .messages
+        .iter()
+        .map(|message| {
+            let (payload, decoding_error) = match decoder.decode(&message.payload) {
+                Ok(payload) => (payload, None),
+                Err(error) => (
+                    serde_json::Value::String(text::as_base64(&message.payload)),
+                    Some(error.to_string()),
+                ),
+            };
+            DecodedMessage {
+                offset: message.offset,
+                state: message.state,
+                timestamp: message.timestamp,
+                id: message.id,
+                checksum: message.checksum,
+                headers: message.headers.clone(),
+                payload,
+                decoding_error,
+            }
+        })
+        .collect();
+    DecodedPolledMessages {
+        partition_id: polled_messages.partition_id,
+        current_offset: polled_messages.current_offset,
+        messages,
+        next_offset: polled_messages.next_offset,
+    }
+}
+
 pub async fn map_client(client: &Client) -> iggy::models::client_info::ClientInfoDetails {
     let client = iggy::models::client_info::ClientInfoDetails {
         client_id: client.client_id,
@@ -179,7 +342,7 @@ pub async fn map_clients(
 }
 
 pub async fn map_consumer_groups(
-    consumer_groups: &[&RwLock<ConsumerGroup>],
+    consumer_groups: &[Arc<RwLock<ConsumerGroup>>],
 ) -> Vec<iggy::models::consumer_group::ConsumerGroup> {
     let mut groups = Vec::new();
     for consumer_group in consumer_groups {
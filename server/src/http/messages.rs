@@ -1,19 +1,166 @@
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
 use crate::http::shared::AppState;
-use crate::streaming;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::models::messages::PolledMessages;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
-use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
-use axum::routing::get;
+use crate::streaming::systems::system::System;
+use axum::body::Bytes;
+use axum::extract::{FromRequest, Multipart, Path, Query, Request, State};
+use axum::http::header::{ACCEPT, CONTENT_TYPE};
+use axum::http::{HeaderMap, HeaderName, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
+use bytes::{BufMut, BytesMut};
+use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollMessages;
-use iggy::messages::send_messages::SendMessages;
+use iggy::messages::query_messages::QueryMessages;
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::models::header::{HeaderKey, HeaderValue, CONTENT_TYPE_HEADER};
+use iggy::models::messages::Message as PolledMessage;
+use iggy::models::query_result::QueryResult;
+use iggy::models::schema::SchemaFormat;
+use iggy::schemas::decoding::PayloadDecoder;
+use iggy::utils::duration::IggyDuration;
 use iggy::validatable::Validatable;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+const BINARY_CONTENT_TYPE: &str = "application/octet-stream";
+const JSON_CONTENT_TYPE: &str = "application/json";
+const MULTIPART_FORM_CONTENT_TYPE: &str = "multipart/form-data";
+const MULTIPART_MIXED_CONTENT_TYPE: &str = "multipart/mixed";
+const PARTITION_ID_HEADER: HeaderName = HeaderName::from_static("iggy-partition-id");
+const CURRENT_OFFSET_HEADER: HeaderName = HeaderName::from_static("iggy-current-offset");
+const OFFSET_HEADER: HeaderName = HeaderName::from_static("iggy-offset");
+const MESSAGE_ID_HEADER: HeaderName = HeaderName::from_static("iggy-message-id");
+const TIMESTAMP_HEADER: HeaderName = HeaderName::from_static("iggy-timestamp");
+
+/// The optional parameters used to decode the polled message payloads into the structured JSON,
+/// either with the schema registered under the given ID, or with the one provided inline in the given format.
+/// The message type is used only for the Protobuf schema, by default the first message defined in the file is used.
+#[derive(Debug, Deserialize)]
+struct PayloadDecoding {
+    schema_id: Option<u32>,
+    schema_format: Option<SchemaFormat>,
+    schema: Option<String>,
+    message_type: Option<String>,
+}
+
+impl PayloadDecoding {
+    fn get_decoder(
+        &self,
+        system: &System,
+        session: &Session,
+    ) -> Result<Option<PayloadDecoder>, IggyError> {
+        let message_type = self.message_type.as_deref();
+        if let Some(schema_id) = self.schema_id {
+            let schema = system.get_schema(session, schema_id)?;
+            return PayloadDecoder::new(schema.format, &schema.schema, message_type).map(Some);
+        }
+
+        match (self.schema_format, &self.schema) {
+            (None, None) => Ok(None),
+            (Some(SchemaFormat::Json), _) => Ok(Some(PayloadDecoder::Json)),
+            (Some(format), Some(schema)) => {
+                PayloadDecoder::new(format, schema, message_type).map(Some)
+            }
+            (Some(format), None) => Err(IggyError::InvalidSchema(format!(
+                "schema is required to decode the {format} payloads"
+            ))),
+            (None, Some(_)) => Err(IggyError::InvalidSchemaFormat),
+        }
+    }
+}
+
+/// The parameters of the messages sent as the raw bytes (a single message) or the multipart form (a message per part)
+/// instead of the JSON with the base64 encoded payloads. The messages are balanced across the partitions,
+/// unless the partition ID or the messages key is given. The content type of the payload is stored in the message header.
+#[derive(Debug, Deserialize)]
+struct BinaryMessagesParams {
+    partition_id: Option<u32>,
+    messages_key: Option<String>,
+    acks: Option<Acks>,
+}
+
+impl BinaryMessagesParams {
+    fn to_command(&self, messages: Vec<Message>) -> Result<SendMessages, IggyError> {
+        let partitioning = match (self.partition_id, &self.messages_key) {
+            (None, None) => Partitioning::balanced(),
+            (Some(partition_id), None) => Partitioning::partition_id(partition_id),
+            (None, Some(messages_key)) => Partitioning::messages_key_str(messages_key)?,
+            (Some(_), Some(_)) => return Err(IggyError::InvalidCommand),
+        };
+
+        Ok(SendMessages {
+            partitioning,
+            messages,
+            acks: self.acks,
+            ..SendMessages::default()
+        })
+    }
+}
+
+/// The format of the polled messages, negotiated with the `Accept` header. The raw bytes are the payload
+/// of the first polled message, and the multipart response has a part per message, both with the message
+/// offset, ID and timestamp in the headers and with the content type stored in the message header.
+#[derive(Debug, PartialEq)]
+enum PolledMessagesFormat {
+    Json,
+    Binary,
+    Multipart,
+}
+
+impl PolledMessagesFormat {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let accepted = headers
+            .get_all(ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(get_media_type)
+            .collect::<Vec<_>>();
+        // JSON is preferred if accepted along with the binary formats, so the existing clients are not affected.
+        if accepted
+            .iter()
+            .any(|media_type| media_type == JSON_CONTENT_TYPE)
+        {
+            return Self::Json;
+        }
+        if accepted
+            .iter()
+            .any(|media_type| media_type == MULTIPART_MIXED_CONTENT_TYPE)
+        {
+            return Self::Multipart;
+        }
+        if accepted
+            .iter()
+            .any(|media_type| media_type == BINARY_CONTENT_TYPE)
+        {
+            return Self::Binary;
+        }
+        Self::Json
+    }
+}
+
+/// Returns the media type without the parameters, e.g. `text/plain` for `text/plain; charset=utf-8`.
+fn get_media_type(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -21,6 +168,7 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/messages",
             get(poll_messages).post(send_messages),
         )
+        .route("/query", post(query_messages))
         .with_state(state)
 }
 
@@ -29,7 +177,9 @@ async fn poll_messages(
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     mut query: Query<PollMessages>,
-) -> Result<Json<streaming::models::messages::PolledMessages>, CustomError> {
+    Query(decoding): Query<PayloadDecoding>,
+    headers: HeaderMap,
+) -> Result<Response, CustomError> {
     query.stream_id = Identifier::from_str_value(&stream_id)?;
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
@@ -37,39 +187,232 @@ async fn poll_messages(
     let partition_id = query.partition_id.unwrap_or(0);
     let consumer_id = PollingConsumer::resolve_consumer_id(&query.consumer.id);
     let consumer = PollingConsumer::Consumer(consumer_id, partition_id);
-    let system = state.system.read();
-    let polled_messages = system
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    let decoder = decoding.get_decoder(&state.system.read(), &session)?;
+    let filter = query.filter.take();
+    let polled_messages = state
+        .system
         .poll_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             consumer,
             &query.stream_id,
             &query.topic_id,
-            PollingArgs::new(query.strategy, query.count, query.auto_commit),
+            PollingArgs::new(
+                query.strategy,
+                query.count,
+                query.auto_commit,
+                filter,
+                query.mode,
+            )
+            .with_max_bytes(query.max_bytes),
+            query
+                .max_wait_ms
+                .map(|max_wait_ms| IggyDuration::new(Duration::from_millis(max_wait_ms as u64))),
         )
         .await?;
-    Ok(Json(polled_messages))
+    match PolledMessagesFormat::from_headers(&headers) {
+        PolledMessagesFormat::Binary => return Ok(map_binary_message(polled_messages)),
+        PolledMessagesFormat::Multipart => return Ok(map_multipart_messages(polled_messages)),
+        PolledMessagesFormat::Json => {}
+    }
+    match decoder {
+        Some(decoder) => {
+            Ok(Json(mapper::map_decoded_messages(polled_messages, &decoder)).into_response())
+        }
+        None => Ok(Json(polled_messages).into_response()),
+    }
+}
+
+/// Returns the payload of the first polled message, or no content if there are no messages.
+fn map_binary_message(polled_messages: PolledMessages) -> Response {
+    let mut headers = get_polled_messages_headers(&polled_messages);
+    let Some(message) = polled_messages.messages.first() else {
+        return (StatusCode::NO_CONTENT, headers).into_response();
+    };
+
+    headers.extend(get_message_headers(message));
+    (headers, message.payload.clone()).into_response()
+}
+
+/// Returns the polled messages as the `multipart/mixed` response with a part per message.
+fn map_multipart_messages(polled_messages: PolledMessages) -> Response {
+    let boundary = Uuid::new_v4().simple().to_string();
+    let mut body = BytesMut::new();
+    for message in &polled_messages.messages {
+        body.put_slice(format!("--{boundary}\r\n").as_bytes());
+        for (name, value) in get_message_headers(message).iter() {
+            body.put_slice(name.as_str().as_bytes());
+            body.put_slice(b": ");
+            body.put_slice(value.as_bytes());
+            body.put_slice(b"\r\n");
+        }
+        body.put_slice(b"\r\n");
+        body.put_slice(&message.payload);
+        body.put_slice(b"\r\n");
+    }
+    body.put_slice(format!("--{boundary}--\r\n").as_bytes());
+
+    let mut headers = get_polled_messages_headers(&polled_messages);
+    headers.insert(
+        CONTENT_TYPE,
+        format!("{MULTIPART_MIXED_CONTENT_TYPE}; boundary={boundary}")
+            .parse()
+            .expect("Multipart content type is valid"),
+    );
+    (headers, body.freeze()).into_response()
+}
+
+fn get_polled_messages_headers(polled_messages: &PolledMessages) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(PARTITION_ID_HEADER, polled_messages.partition_id.into());
+    headers.insert(CURRENT_OFFSET_HEADER, polled_messages.current_offset.into());
+    headers
+}
+
+fn get_message_headers(message: &PolledMessage) -> HeaderMap {
+    let content_type = message
+        .headers
+        .as_ref()
+        .and_then(|headers| headers.get(&HeaderKey::new(CONTENT_TYPE_HEADER).ok()?))
+        .and_then(|value| value.as_str().ok()?.parse().ok())
+        .unwrap_or_else(|| BINARY_CONTENT_TYPE.parse().expect("Content type is valid"));
+
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, content_type);
+    headers.insert(OFFSET_HEADER, message.offset.into());
+    headers.insert(
+        MESSAGE_ID_HEADER,
+        message.id.to_string().parse().expect("Message ID is valid"),
+    );
+    headers.insert(TIMESTAMP_HEADER, message.timestamp.into());
+    headers
 }
 
 async fn send_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
-    Json(mut command): Json<SendMessages>,
-) -> Result<StatusCode, CustomError> {
+    Query(params): Query<BinaryMessagesParams>,
+    request: Request,
+) -> Response {
+    let command = match read_send_messages(&state, &params, request).await {
+        Ok(command) => command,
+        Err(response) => return response,
+    };
+
+    append_messages(state, identity, stream_id, topic_id, command)
+        .await
+        .into_response()
+}
+
+/// Reads the messages sent as the JSON, the multipart form or the raw bytes, depending on the content type.
+async fn read_send_messages(
+    state: &Arc<AppState>,
+    params: &BinaryMessagesParams,
+    request: Request,
+) -> Result<SendMessages, Response> {
+    let content_type = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned);
+    let into_response = |error: IggyError| CustomError::from(error).into_response();
+
+    match content_type.as_deref().map(get_media_type).as_deref() {
+        Some(JSON_CONTENT_TYPE) => {
+            let Json(command) = Json::<SendMessages>::from_request(request, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            Ok(command)
+        }
+        Some(MULTIPART_FORM_CONTENT_TYPE) => {
+            let mut multipart = Multipart::from_request(request, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            let mut messages = Vec::new();
+            while let Some(field) = multipart
+                .next_field()
+                .await
+                .map_err(IntoResponse::into_response)?
+            {
+                let content_type = field.content_type().map(str::to_owned);
+                let payload = field.bytes().await.map_err(IntoResponse::into_response)?;
+                messages.push(create_binary_message(payload, content_type).map_err(into_response)?);
+            }
+            params.to_command(messages).map_err(into_response)
+        }
+        _ => {
+            let payload = Bytes::from_request(request, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            let message = create_binary_message(payload, content_type).map_err(into_response)?;
+            params.to_command(vec![message]).map_err(into_response)
+        }
+    }
+}
+
+fn create_binary_message(
+    payload: Bytes,
+    content_type: Option<String>,
+) -> Result<Message, IggyError> {
+    let headers = match content_type {
+        Some(content_type) => Some(HashMap::from([(
+            HeaderKey::new(CONTENT_TYPE_HEADER)?,
+            HeaderValue::from_str(&content_type)?,
+        )])),
+        None => None,
+    };
+    Ok(Message::new(None, payload, headers))
+}
+
+async fn append_messages(
+    state: Arc<AppState>,
+    identity: Identity,
+    stream_id: String,
+    topic_id: String,
+    mut command: SendMessages,
+) -> Result<Response, CustomError> {
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.partitioning.length = command.partitioning.value.len() as u8;
     command.validate()?;
 
+    let session =
+        Session::stateless(identity.user_id, identity.ip_address).with_transport(Transport::Http);
+    state
+        .system
+        .auto_create_topic(&session, &command.stream_id, &command.topic_id)
+        .await?;
     let system = state.system.read();
-    system
+    let receipt = system
         .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             &command.stream_id,
             &command.topic_id,
             &command.partitioning,
             &command.messages,
+            command.acks.unwrap_or_default(),
+        )
+        .await?;
+    // There's no receipt for the messages sent without acks, as they're only accepted for appending.
+    match receipt {
+        Some(receipt) => Ok((StatusCode::CREATED, Json(receipt)).into_response()),
+        None => Ok(StatusCode::ACCEPTED.into_response()),
+    }
+}
+
+async fn query_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<QueryMessages>,
+) -> Result<Json<QueryResult>, CustomError> {
+    command.validate()?;
+    let system = state.system.read();
+    let result = system
+        .query_messages(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.query,
         )
         .await?;
-    Ok(StatusCode::CREATED)
+    Ok(Json(result))
 }
@@ -1,16 +1,22 @@
+pub mod connectors;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod diagnostics;
 pub mod error;
+pub mod etag;
 pub mod http_server;
+pub mod ip_filter;
 pub mod jwt;
-mod mapper;
+pub(crate) mod mapper;
 pub mod messages;
 pub mod metrics;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 mod shared;
 pub mod streams;
 pub mod system;
+pub mod tenants;
 pub mod topics;
+pub mod user_groups;
 pub mod users;
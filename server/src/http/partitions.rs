@@ -1,12 +1,14 @@
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::post;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
+use iggy::models::partition::{PartitionDetails, PartitionEndOffset};
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
 use iggy::validatable::Validatable;
@@ -18,9 +20,56 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/partitions",
             post(create_partitions).delete(delete_partitions),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/end-offsets",
+            get(get_partition_end_offsets),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/:partition_id",
+            get(get_partition_details),
+        )
         .with_state(state)
 }
 
+async fn get_partition_details(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+) -> Result<Json<PartitionDetails>, CustomError> {
+    let system = state.system.read();
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let partition = system
+        .find_partition(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+            partition_id,
+        )
+        .await?;
+    let partition = partition.read().await;
+    let partition = mapper::map_partition(&partition).await;
+    Ok(Json(partition))
+}
+
+async fn get_partition_end_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<Vec<PartitionEndOffset>>, CustomError> {
+    let system = state.system.read();
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let end_offsets = system
+        .get_partition_end_offsets(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+        )
+        .await?;
+    Ok(Json(end_offsets))
+}
+
 async fn create_partitions(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -30,13 +79,14 @@ async fn create_partitions(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
-    let mut system = state.system.write();
+    let system = state.system.read();
     system
         .create_partitions(
             &Session::stateless(identity.user_id, identity.ip_address),
             &command.stream_id,
             &command.topic_id,
             command.partitions_count,
+            command.repartition,
         )
         .await?;
     Ok(StatusCode::CREATED)
@@ -51,13 +101,14 @@ async fn delete_partitions(
     query.stream_id = Identifier::from_str_value(&stream_id)?;
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
-    let mut system = state.system.write();
+    let system = state.system.read();
     system
         .delete_partitions(
             &Session::stateless(identity.user_id, identity.ip_address),
             &query.stream_id,
             &query.topic_id,
             query.partitions_count,
+            query.mode,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
@@ -2,7 +2,7 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_tokens_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -78,13 +78,17 @@ async fn delete_personal_access_token(
 
 async fn login_with_personal_access_token(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginWithPersonalAccessToken>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
     let system = state.system.read();
     let user = system
-        .login_with_personal_access_token(&command.token, None)
+        .login_with_personal_access_token(
+            &command.token,
+            Some(&Session::stateless(0, request_details.ip_address)),
+        )
         .await?;
-    let tokens = state.jwt_manager.generate(user.id)?;
+    let tokens = state.jwt_manager.generate(user.id, user.session_epoch)?;
     Ok(Json(map_generated_tokens_to_identity_info(tokens)))
 }
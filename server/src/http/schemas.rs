@@ -0,0 +1,96 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::shared::AppState;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, put};
+use axum::{Extension, Json, Router};
+use iggy::models::schema::SchemaInfo;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::schemas::update_schema_compatibility::UpdateSchemaCompatibility;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/schemas/:schema_id", get(get_schema))
+        .route(
+            "/schemas/subjects/:subject/versions",
+            get(get_schema_versions).post(register_schema),
+        )
+        .route(
+            "/schemas/subjects/:subject/compatibility",
+            put(update_schema_compatibility),
+        )
+        .with_state(state)
+}
+
+async fn get_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(schema_id): Path<u32>,
+) -> Result<Json<SchemaInfo>, CustomError> {
+    let system = state.system.read();
+    let schema = system.get_schema(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        schema_id,
+    )?;
+    let schema = mapper::map_schema(schema);
+    Ok(Json(schema))
+}
+
+async fn get_schema_versions(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(subject): Path<String>,
+) -> Result<Json<Vec<SchemaInfo>>, CustomError> {
+    let system = state.system.read();
+    let schemas = system.get_schema_versions(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        &subject,
+    )?;
+    let schemas = mapper::map_schemas(&schemas);
+    Ok(Json(schemas))
+}
+
+async fn register_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(subject): Path<String>,
+    Json(mut command): Json<RegisterSchema>,
+) -> Result<Json<SchemaInfo>, CustomError> {
+    command.subject = subject;
+    command.validate()?;
+    let mut system = state.system.write();
+    let schema = system
+        .register_schema(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.subject,
+            command.format,
+            &command.schema,
+        )
+        .await?;
+    let schema = mapper::map_schema(&schema);
+    Ok(Json(schema))
+}
+
+async fn update_schema_compatibility(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(subject): Path<String>,
+    Json(mut command): Json<UpdateSchemaCompatibility>,
+) -> Result<StatusCode, CustomError> {
+    command.subject = subject;
+    command.validate()?;
+    let mut system = state.system.write();
+    system
+        .update_schema_compatibility(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.subject,
+            command.compatibility,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
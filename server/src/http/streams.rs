@@ -1,15 +1,17 @@
 use crate::http::error::CustomError;
+use crate::http::etag::conditional_get;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
-use axum::{Extension, Json, Router};
+use axum::routing::{delete, get, post};
+use axum::{middleware, Extension, Json, Router};
 use iggy::identifier::Identifier;
 use iggy::models::stream::{Stream, StreamDetails};
 use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::update_stream::UpdateStream;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
@@ -22,6 +24,8 @@ pub fn router(state: Arc<AppState>) -> Router {
             get(get_stream).put(update_stream).delete(delete_stream),
         )
         .route("/streams/:stream_id/purge", delete(purge_stream))
+        .route("/streams/:stream_id/undelete", post(undelete_stream))
+        .layer(middleware::from_fn(conditional_get))
         .with_state(state)
 }
 
@@ -32,11 +36,13 @@ async fn get_stream(
 ) -> Result<Json<StreamDetails>, CustomError> {
     let system = state.system.read();
     let stream_id = Identifier::from_str_value(&stream_id)?;
-    let stream = system.find_stream(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-    )?;
-    let stream = mapper::map_stream(stream).await;
+    let stream = system
+        .find_stream(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+        )
+        .await?;
+    let stream = mapper::map_stream(&stream).await;
     Ok(Json(stream))
 }
 
@@ -45,8 +51,9 @@ async fn get_streams(
     Extension(identity): Extension<Identity>,
 ) -> Result<Json<Vec<Stream>>, CustomError> {
     let system = state.system.read();
-    let streams =
-        system.find_streams(&Session::stateless(identity.user_id, identity.ip_address))?;
+    let streams = system
+        .find_streams(&Session::stateless(identity.user_id, identity.ip_address))
+        .await?;
     let streams = mapper::map_streams(&streams).await;
     Ok(Json(streams))
 }
@@ -63,6 +70,7 @@ async fn create_stream(
             &Session::stateless(identity.user_id, identity.ip_address),
             command.stream_id,
             &command.name,
+            command.topic_defaults,
         )
         .await?;
     Ok(StatusCode::CREATED)
@@ -82,6 +90,7 @@ async fn update_stream(
             &Session::stateless(identity.user_id, identity.ip_address),
             &command.stream_id,
             &command.name,
+            command.topic_defaults,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
@@ -91,6 +100,7 @@ async fn delete_stream(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path(stream_id): Path<String>,
+    query: Query<DeleteStream>,
 ) -> Result<StatusCode, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let mut system = state.system.write();
@@ -98,6 +108,23 @@ async fn delete_stream(
         .delete_stream(
             &Session::stateless(identity.user_id, identity.ip_address),
             &stream_id,
+            query.soft,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn undelete_stream(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(stream_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let mut system = state.system.write();
+    system
+        .undelete_stream(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
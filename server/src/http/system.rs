@@ -2,25 +2,38 @@ use crate::configs::http::HttpMetricsConfig;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
-use axum::routing::get;
+use axum::http::StatusCode;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::models::client_info::{ClientInfo, ClientInfoDetails};
+use iggy::models::hello_info::HelloInfo;
+use iggy::models::metadata::Metadata;
 use iggy::models::stats::Stats;
+use iggy::system::hello::Hello;
+use iggy::system::negotiate_compression::NegotiateCompression;
+use iggy::validatable::Validatable;
 use std::sync::Arc;
 
 const NAME: &str = "Iggy HTTP";
 const PONG: &str = "pong";
+const READY: &str = "ready";
+const NOT_READY: &str = "not ready";
 
 pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Router {
     let mut router = Router::new()
         .route("/", get(|| async { NAME }))
         .route("/ping", get(|| async { PONG }))
+        .route("/ready", get(ready))
         .route("/stats", get(get_stats))
+        .route("/metadata", get(get_metadata))
         .route("/clients", get(get_clients))
-        .route("/clients/:client_id", get(get_client));
+        .route("/clients/:client_id", get(get_client))
+        .route("/compression", post(negotiate_compression))
+        .route("/hello", post(hello));
     if metrics_config.enabled {
         router = router.route(&metrics_config.endpoint, get(get_metrics));
     }
@@ -28,6 +41,14 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
     router.with_state(state)
 }
 
+/// Returns `503 Service Unavailable` until the system has been initialized and once the server starts draining, e.g. for the readiness probes.
+async fn ready(State(state): State<Arc<AppState>>) -> (StatusCode, &'static str) {
+    match state.system.read().is_ready() {
+        true => (StatusCode::OK, READY),
+        false => (StatusCode::SERVICE_UNAVAILABLE, NOT_READY),
+    }
+}
+
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read();
     Ok(system.metrics.get_formatted_output())
@@ -44,6 +65,50 @@ async fn get_stats(
     Ok(Json(stats))
 }
 
+async fn get_metadata(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Metadata>, CustomError> {
+    let system = state.system.read();
+    let metadata = system
+        .get_metadata(&Session::stateless(identity.user_id, identity.ip_address))
+        .await?;
+    Ok(Json(metadata))
+}
+
+async fn hello(
+    State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
+    Json(command): Json<Hello>,
+) -> Result<Json<HelloInfo>, CustomError> {
+    command.validate()?;
+    let system = state.system.read();
+    let hello_info = system
+        .hello(
+            &Session::stateless(0, request_details.ip_address),
+            command.protocol_version,
+            &command.features,
+        )
+        .await?;
+    Ok(Json(hello_info))
+}
+
+async fn negotiate_compression(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<NegotiateCompression>,
+) -> Result<Json<CompressionAlgorithm>, CustomError> {
+    command.validate()?;
+    let system = state.system.read();
+    let algorithm = system
+        .negotiate_compression(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.algorithms,
+        )
+        .await?;
+    Ok(Json(algorithm))
+}
+
 async fn get_client(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -0,0 +1,146 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::shared::AppState;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use iggy::identifier::Identifier;
+use iggy::models::tenant::Tenant;
+use iggy::tenants::create_tenant::CreateTenant;
+use iggy::tenants::update_tenant::UpdateTenant;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/tenants", get(get_tenants).post(create_tenant))
+        .route(
+            "/tenants/:tenant_id",
+            get(get_tenant).put(update_tenant).delete(delete_tenant),
+        )
+        .route(
+            "/tenants/:tenant_id/users/:user_id",
+            post(add_tenant_user).delete(remove_tenant_user),
+        )
+        .with_state(state)
+}
+
+async fn get_tenant(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(tenant_id): Path<String>,
+) -> Result<Json<Tenant>, CustomError> {
+    let tenant_id = Identifier::from_str_value(&tenant_id)?;
+    let system = state.system.read();
+    let tenant = system.find_tenant(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        &tenant_id,
+    )?;
+    let tenant = mapper::map_tenant(tenant, &system.get_tenant_usage(tenant.id).await);
+    Ok(Json(tenant))
+}
+
+async fn get_tenants(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<Tenant>>, CustomError> {
+    let system = state.system.read();
+    let mut tenants = Vec::new();
+    for tenant in system.find_tenants(&Session::stateless(identity.user_id, identity.ip_address))? {
+        tenants.push((tenant, system.get_tenant_usage(tenant.id).await));
+    }
+    let tenants = mapper::map_tenants(&tenants);
+    Ok(Json(tenants))
+}
+
+async fn create_tenant(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateTenant>,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+    let mut system = state.system.write();
+    system
+        .create_tenant(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            command.tenant_id,
+            &command.name,
+            command.quotas,
+        )
+        .await?;
+    Ok(StatusCode::CREATED)
+}
+
+async fn update_tenant(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(tenant_id): Path<String>,
+    Json(mut command): Json<UpdateTenant>,
+) -> Result<StatusCode, CustomError> {
+    command.tenant_id = Identifier::from_str_value(&tenant_id)?;
+    command.validate()?;
+    let mut system = state.system.write();
+    system
+        .update_tenant(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.tenant_id,
+            command.quotas,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn delete_tenant(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(tenant_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let tenant_id = Identifier::from_str_value(&tenant_id)?;
+    let mut system = state.system.write();
+    system
+        .delete_tenant(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &tenant_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn add_tenant_user(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((tenant_id, user_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let tenant_id = Identifier::from_str_value(&tenant_id)?;
+    let user_id = Identifier::from_str_value(&user_id)?;
+    let mut system = state.system.write();
+    system
+        .add_tenant_user(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &tenant_id,
+            &user_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn remove_tenant_user(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((tenant_id, user_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let tenant_id = Identifier::from_str_value(&tenant_id)?;
+    let user_id = Identifier::from_str_value(&user_id)?;
+    let mut system = state.system.write();
+    system
+        .remove_tenant_user(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &tenant_id,
+            &user_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,15 +1,17 @@
 use crate::http::error::CustomError;
+use crate::http::etag::conditional_get;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
-use axum::{Extension, Json, Router};
+use axum::routing::{delete, get, post};
+use axum::{middleware, Extension, Json, Router};
 use iggy::identifier::Identifier;
 use iggy::models::topic::{Topic, TopicDetails};
 use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::update_topic::UpdateTopic;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
@@ -28,6 +30,19 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/purge",
             delete(purge_topic),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/undelete",
+            post(undelete_topic),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/freeze",
+            post(freeze_topic),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/unfreeze",
+            post(unfreeze_topic),
+        )
+        .layer(middleware::from_fn(conditional_get))
         .with_state(state)
 }
 
@@ -39,12 +54,14 @@ async fn get_topic(
     let system = state.system.read();
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
-    let topic = system.find_topic(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-        &topic_id,
-    )?;
-    let topic = mapper::map_topic(topic).await;
+    let topic = system
+        .find_topic(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+        )
+        .await?;
+    let topic = mapper::map_topic(&topic).await;
     Ok(Json(topic))
 }
 
@@ -55,11 +72,13 @@ async fn get_topics(
 ) -> Result<Json<Vec<Topic>>, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let system = state.system.read();
-    let topics = system.find_topics(
-        &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
-    )?;
-    let topics = mapper::map_topics(&topics).await;
+    let stream = system
+        .find_topics(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+        )
+        .await?;
+    let topics = mapper::map_topics(&stream.get_topics()).await;
     Ok(Json(topics))
 }
 
@@ -71,7 +90,7 @@ async fn create_topic(
 ) -> Result<StatusCode, CustomError> {
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.validate()?;
-    let mut system = state.system.write();
+    let system = state.system.read();
     system
         .create_topic(
             &Session::stateless(identity.user_id, identity.ip_address),
@@ -82,6 +101,11 @@ async fn create_topic(
             command.message_expiry,
             command.max_topic_size,
             command.replication_factor,
+            command.message_schema.as_deref(),
+            command.ephemeral,
+            command.timestamp_type,
+            command.consumer_limits,
+            command.max_message_size,
         )
         .await?;
     Ok(StatusCode::CREATED)
@@ -96,7 +120,7 @@ async fn update_topic(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
-    let mut system = state.system.write();
+    let system = state.system.read();
     system
         .update_topic(
             &Session::stateless(identity.user_id, identity.ip_address),
@@ -106,6 +130,10 @@ async fn update_topic(
             command.message_expiry,
             command.max_topic_size,
             command.replication_factor,
+            command.message_schema.as_deref(),
+            command.timestamp_type,
+            command.consumer_limits,
+            command.max_message_size,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
@@ -115,15 +143,35 @@ async fn delete_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
+    query: Query<DeleteTopic>,
 ) -> Result<StatusCode, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
-    let mut system = state.system.write();
+    let system = state.system.read();
     system
         .delete_topic(
             &Session::stateless(identity.user_id, identity.ip_address),
             &stream_id,
             &topic_id,
+            query.soft,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn undelete_topic(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read();
+    system
+        .undelete_topic(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
@@ -146,3 +194,39 @@ async fn purge_topic(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn freeze_topic(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read();
+    system
+        .freeze_topic(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn unfreeze_topic(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read();
+    system
+        .unfreeze_topic(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
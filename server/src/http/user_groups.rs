@@ -0,0 +1,148 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::shared::AppState;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post, put};
+use axum::{Extension, Json, Router};
+use iggy::identifier::Identifier;
+use iggy::models::user_group::{UserGroup, UserGroupDetails};
+use iggy::user_groups::create_user_group::CreateUserGroup;
+use iggy::user_groups::update_user_group_permissions::UpdateUserGroupPermissions;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/user-groups", get(get_user_groups).post(create_user_group))
+        .route(
+            "/user-groups/:group_id",
+            get(get_user_group).delete(delete_user_group),
+        )
+        .route(
+            "/user-groups/:group_id/permissions",
+            put(update_user_group_permissions),
+        )
+        .route(
+            "/user-groups/:group_id/members/:user_id",
+            post(add_user_group_member).delete(remove_user_group_member),
+        )
+        .with_state(state)
+}
+
+async fn get_user_group(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(group_id): Path<String>,
+) -> Result<Json<UserGroupDetails>, CustomError> {
+    let group_id = Identifier::from_str_value(&group_id)?;
+    let system = state.system.read();
+    let group = system.find_user_group(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        &group_id,
+    )?;
+    let group = mapper::map_user_group(group);
+    Ok(Json(group))
+}
+
+async fn get_user_groups(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<UserGroup>>, CustomError> {
+    let system = state.system.read();
+    let groups =
+        system.find_user_groups(&Session::stateless(identity.user_id, identity.ip_address))?;
+    let groups = mapper::map_user_groups(&groups);
+    Ok(Json(groups))
+}
+
+async fn create_user_group(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateUserGroup>,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+    let mut system = state.system.write();
+    system
+        .create_user_group(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            command.group_id,
+            &command.name,
+            command.permissions,
+        )
+        .await?;
+    Ok(StatusCode::CREATED)
+}
+
+async fn update_user_group_permissions(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(group_id): Path<String>,
+    Json(mut command): Json<UpdateUserGroupPermissions>,
+) -> Result<StatusCode, CustomError> {
+    command.group_id = Identifier::from_str_value(&group_id)?;
+    command.validate()?;
+    let mut system = state.system.write();
+    system
+        .update_user_group_permissions(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.group_id,
+            command.permissions,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn delete_user_group(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(group_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let group_id = Identifier::from_str_value(&group_id)?;
+    let mut system = state.system.write();
+    system
+        .delete_user_group(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &group_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn add_user_group_member(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((group_id, user_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let group_id = Identifier::from_str_value(&group_id)?;
+    let user_id = Identifier::from_str_value(&user_id)?;
+    let mut system = state.system.write();
+    system
+        .add_user_group_member(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &group_id,
+            &user_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn remove_user_group_member(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((group_id, user_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let group_id = Identifier::from_str_value(&group_id)?;
+    let user_id = Identifier::from_str_value(&user_id)?;
+    let mut system = state.system.write();
+    system
+        .remove_user_group_member(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &group_id,
+            &user_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,25 +1,30 @@
 use crate::http::error::CustomError;
+use crate::http::etag::conditional_get;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_tokens_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::routing::{get, post, put};
-use axum::{Extension, Json, Router};
+use axum::{middleware, Extension, Json, Router};
 use iggy::identifier::Identifier;
 use iggy::models::identity_info::IdentityInfo;
+use iggy::models::permission_check::PermissionCheck;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
 use iggy::users::change_password::ChangePassword;
+use iggy::users::check_permission::CheckPermission;
 use iggy::users::create_user::CreateUser;
 use iggy::users::login_user::LoginUser;
 use iggy::users::logout_user::LogoutUser;
 use iggy::users::update_permissions::UpdatePermissions;
 use iggy::users::update_user::UpdateUser;
+use iggy::users::update_user_expiry::UpdateUserExpiry;
 use iggy::validatable::Validatable;
 use serde::Deserialize;
 use std::sync::Arc;
+use tracing::warn;
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -29,10 +34,14 @@ pub fn router(state: Arc<AppState>) -> Router {
             get(get_user).put(update_user).delete(delete_user),
         )
         .route("/users/:user_id/permissions", put(update_permissions))
+        .route("/users/:user_id/permissions/check", get(check_permission))
+        .route("/users/:user_id/expiry", put(update_user_expiry))
         .route("/users/:user_id/password", put(change_password))
         .route("/users/login", post(login_user))
         .route("/users/logout", post(logout_user))
         .route("/users/refresh-token", post(refresh_token))
+        .route("/users/revoke-token", post(revoke_token))
+        .layer(middleware::from_fn(conditional_get))
         .with_state(state)
 }
 
@@ -53,6 +62,27 @@ async fn get_user(
     Ok(Json(user))
 }
 
+async fn check_permission(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    mut query: Query<CheckPermission>,
+) -> Result<Json<PermissionCheck>, CustomError> {
+    query.user_id = Identifier::from_str_value(&user_id)?;
+    query.validate()?;
+    let system = state.system.read();
+    let permission_check = system
+        .check_permission(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.user_id,
+            query.action,
+            query.stream_id,
+            query.topic_id,
+        )
+        .await?;
+    Ok(Json(permission_check))
+}
+
 async fn get_users(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -123,6 +153,26 @@ async fn update_permissions(
     Ok(StatusCode::NO_CONTENT)
 }
 
+async fn update_user_expiry(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    Json(mut command): Json<UpdateUserExpiry>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.validate()?;
+    let mut system = state.system.write();
+    system
+        .update_user_expiry(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.user_id,
+            command.expires_at,
+            command.max_inactive_days,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 async fn change_password(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -131,7 +181,7 @@ async fn change_password(
 ) -> Result<StatusCode, CustomError> {
     command.user_id = Identifier::from_str_value(&user_id)?;
     command.validate()?;
-    let system = state.system.read();
+    let mut system = state.system.write();
     system
         .change_password(
             &Session::stateless(identity.user_id, identity.ip_address),
@@ -161,14 +211,19 @@ async fn delete_user(
 
 async fn login_user(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginUser>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
     let system = state.system.read();
     let user = system
-        .login_user(&command.username, &command.password, None)
+        .login_user(
+            &command.username,
+            &command.password,
+            Some(&Session::stateless(0, request_details.ip_address)),
+        )
         .await?;
-    let tokens = state.jwt_manager.generate(user.id)?;
+    let tokens = state.jwt_manager.generate(user.id, user.session_epoch)?;
     Ok(Json(map_generated_tokens_to_identity_info(tokens)))
 }
 
@@ -193,10 +248,41 @@ async fn refresh_token(
     State(state): State<Arc<AppState>>,
     Json(command): Json<RefreshToken>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
-    let tokens = state.jwt_manager.refresh_token(&command.refresh_token)?;
+    let tokens = state
+        .jwt_manager
+        .refresh_token(&command.refresh_token, |user_id| {
+            state.system.read().permissioner.get_session_epoch(user_id)
+        })
+        .await?;
+    // The rotated token can't be used again, so the refreshed tokens are returned even if the activity isn't recorded.
+    if let Err(error) = state
+        .system
+        .read()
+        .record_user_activity(tokens.user_id)
+        .await
+    {
+        warn!(
+            "Cannot record the activity of the user with ID: {}, error: {error}",
+            tokens.user_id
+        );
+    }
     Ok(Json(map_generated_tokens_to_identity_info(tokens)))
 }
 
+/// Revokes the refresh token of the authenticated user, the access token may have already expired or been logged out.
+/// Possessing the refresh token (and any access token of its owner) is enough to revoke it, along with its whole family.
+async fn revoke_token(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<RefreshToken>,
+) -> Result<StatusCode, CustomError> {
+    state
+        .jwt_manager
+        .revoke_refresh_token(&command.refresh_token, identity.user_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[derive(Debug, Deserialize)]
 struct RefreshToken {
     refresh_token: String,
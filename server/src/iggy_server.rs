@@ -0,0 +1,301 @@
+use crate::amqp::amqp_server;
+use crate::channels::commands::check_disk_space::CheckDiskSpaceExecutor;
+use crate::channels::commands::check_thresholds::CheckThresholdsExecutor;
+use crate::channels::commands::clean_consumer_groups::CleanConsumerGroupsExecutor;
+use crate::channels::commands::clean_messages::CleanMessagesExecutor;
+use crate::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use crate::channels::commands::clean_trash::CleanTrashExecutor;
+use crate::channels::commands::disable_expired_users::DisableExpiredUsersExecutor;
+use crate::channels::commands::export_messages::ExportMessagesExecutor;
+use crate::channels::commands::publish_events::PublishEventsExecutor;
+use crate::channels::commands::publish_system_topics::PublishSystemTopicsExecutor;
+use crate::channels::commands::save_messages::SaveMessagesExecutor;
+use crate::channels::handler::ServerCommandHandler;
+use crate::configs::bootstrap;
+use crate::configs::server::ServerConfig;
+use crate::connectors::runtime::ConnectorRuntime;
+use crate::http::http_server;
+use crate::mqtt::mqtt_server;
+use crate::otlp::otlp_server;
+use crate::quic::quic_server;
+use crate::runtimes::{RuntimeHandles, ServerRuntimes};
+use crate::server_error::ServerError;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::tcp::tcp_server;
+use iggy::validatable::Validatable;
+use std::net::SocketAddr;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tracing::info;
+
+const RUNTIME_THREAD_NAME: &str = "iggy-server";
+
+/// The server running in-process, e.g. in the tests or the edge deployments, on its own runtimes,
+/// so all of its listeners and background tasks are stopped on shutdown.
+///
+/// The logging is not initialized, it's up to the embedding application to install the tracing subscriber.
+pub struct IggyServer {
+    config: ServerConfig,
+    shutdown_sender: Option<oneshot::Sender<Duration>>,
+    runtime_thread: Option<JoinHandle<Result<(), ServerError>>>,
+}
+
+impl IggyServer {
+    /// Starts the server with the provided configuration and waits until it's ready to accept the connections.
+    /// The addresses with the port `0` are bound to the random free ports, see `config()` for the actual ones.
+    pub async fn start(config: ServerConfig) -> Result<Self, ServerError> {
+        config.validate()?;
+        let (started_sender, started_receiver) = oneshot::channel();
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+        let runtime_thread = std::thread::Builder::new()
+            .name(RUNTIME_THREAD_NAME.to_string())
+            .spawn(move || {
+                let runtimes = ServerRuntimes::build(&config.runtimes)?;
+                let handles = runtimes.handles();
+                runtimes
+                    .io()
+                    .block_on(run(config, handles, started_sender, shutdown_receiver))
+            })?;
+
+        match started_receiver.await {
+            Ok(Ok(config)) => Ok(Self {
+                config,
+                shutdown_sender: Some(shutdown_sender),
+                runtime_thread: Some(runtime_thread),
+            }),
+            Ok(Err(error)) => {
+                join(runtime_thread).await?;
+                Err(error)
+            }
+            // The runtime thread has failed before the server has been initialized.
+            Err(_) => {
+                join(runtime_thread).await?;
+                Err(ServerError::InvalidConfiguration)
+            }
+        }
+    }
+
+    /// Returns the configuration of the running server, including the addresses the listeners are bound to.
+    pub fn config(&self) -> &ServerConfig {
+        &self.config
+    }
+
+    pub fn tcp_address(&self) -> Option<SocketAddr> {
+        parse_address(self.config.tcp.enabled, &self.config.tcp.address)
+    }
+
+    pub fn quic_address(&self) -> Option<SocketAddr> {
+        parse_address(self.config.quic.enabled, &self.config.quic.address)
+    }
+
+    pub fn http_address(&self) -> Option<SocketAddr> {
+        parse_address(self.config.http.enabled, &self.config.http.address)
+    }
+
+    /// Shuts down the server immediately, persisting the unsaved messages.
+    pub async fn shutdown(self) -> Result<(), ServerError> {
+        self.shutdown_with_drain(Duration::ZERO).await
+    }
+
+    /// Stops accepting the new connections and waits up to the drain timeout for the connected clients
+    /// to disconnect, before shutting down the server.
+    pub async fn shutdown_with_drain(mut self, drain_timeout: Duration) -> Result<(), ServerError> {
+        if let Some(shutdown_sender) = self.shutdown_sender.take() {
+            let _ = shutdown_sender.send(drain_timeout);
+        }
+        match self.runtime_thread.take() {
+            Some(runtime_thread) => join(runtime_thread).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for IggyServer {
+    fn drop(&mut self) {
+        if let Some(shutdown_sender) = self.shutdown_sender.take() {
+            let _ = shutdown_sender.send(Duration::ZERO);
+        }
+    }
+}
+
+async fn run(
+    config: ServerConfig,
+    runtimes: RuntimeHandles,
+    started_sender: oneshot::Sender<Result<ServerConfig, ServerError>>,
+    shutdown_receiver: oneshot::Receiver<Duration>,
+) -> Result<(), ServerError> {
+    let system = match start_server(&config, &runtimes).await {
+        Ok(system) => system,
+        Err(error) => {
+            let _ = started_sender.send(Err(error));
+            return Ok(());
+        }
+    };
+
+    let current_config = start_listeners(config, &system).await;
+    if let Err(error) = save_current_config(&current_config).await {
+        let _ = started_sender.send(Err(error));
+        return shutdown(system, Duration::ZERO).await;
+    }
+
+    info!("Embedded Iggy server has started.");
+    let _ = started_sender.send(Ok(current_config));
+    let drain_timeout = shutdown_receiver.await.unwrap_or(Duration::ZERO);
+    info!("Shutting down embedded Iggy server...");
+    shutdown(system, drain_timeout).await
+}
+
+async fn start_server(
+    config: &ServerConfig,
+    runtimes: &RuntimeHandles,
+) -> Result<SharedSystem, ServerError> {
+    let system = init_system(config, runtimes).await?;
+    start_background_tasks(config, &system, runtimes).await?;
+    Ok(system)
+}
+
+async fn join(runtime_thread: JoinHandle<Result<(), ServerError>>) -> Result<(), ServerError> {
+    match tokio::task::spawn_blocking(move || runtime_thread.join()).await {
+        Ok(Ok(result)) => result,
+        _ => panic!("Embedded Iggy server runtime thread has panicked."),
+    }
+}
+
+fn parse_address(enabled: bool, address: &str) -> Option<SocketAddr> {
+    match enabled {
+        true => address.parse().ok(),
+        false => None,
+    }
+}
+
+/// Initializes the system from the storage, applies the bootstrap definition and creates the internal topics.
+pub async fn init_system(
+    config: &ServerConfig,
+    runtimes: &RuntimeHandles,
+) -> Result<SharedSystem, ServerError> {
+    let mut system = System::new(config.system.clone(), None, config.personal_access_token);
+    system.runtimes = Some(runtimes.clone());
+    system.init().await?;
+    if config.system.bootstrap.enabled {
+        let definition = bootstrap::load_definition(&config.system.bootstrap.path).await?;
+        system.bootstrap(&definition).await?;
+    }
+
+    if config.events.topic.enabled {
+        let topic = &config.events.topic;
+        system
+            .create_internal_topic(&topic.stream, &topic.topic, topic.partitions_count, None)
+            .await?;
+    }
+
+    if config.system_topics.enabled {
+        system.create_system_topics(&config.system_topics).await?;
+    }
+
+    Ok(SharedSystem::new(system))
+}
+
+/// Starts the periodic server commands (saving and cleaning the messages, publishing the events etc.) and the connectors.
+/// The commands are spawned on the storage and background runtimes, which are the IO one unless they're dedicated.
+pub async fn start_background_tasks(
+    config: &ServerConfig,
+    system: &SharedSystem,
+    runtimes: &RuntimeHandles,
+) -> Result<(), ServerError> {
+    {
+        let _storage = runtimes.storage().enter();
+        ServerCommandHandler::new(system.clone(), config).install_handler(SaveMessagesExecutor);
+    }
+    {
+        let _background = runtimes.background().enter();
+        install_background_handlers(config, system);
+    }
+    ConnectorRuntime::start(&config.connectors, system).await?;
+    Ok(())
+}
+
+fn install_background_handlers(config: &ServerConfig, system: &SharedSystem) {
+    ServerCommandHandler::new(system.clone(), config)
+        .install_handler(CleanMessagesExecutor)
+        .install_handler(CleanPersonalAccessTokensExecutor)
+        .install_handler(CleanTrashExecutor)
+        .install_handler(CleanConsumerGroupsExecutor)
+        .install_handler(DisableExpiredUsersExecutor)
+        .install_handler(PublishEventsExecutor::new(&config.events))
+        .install_handler(CheckThresholdsExecutor::new(&config.events))
+        .install_handler(CheckDiskSpaceExecutor::new(&config.system.disk_space))
+        .install_handler(PublishSystemTopicsExecutor::new(&config.system_topics))
+        .install_handler(ExportMessagesExecutor::new(&config.export));
+}
+
+/// Starts the enabled listeners.
+/// Returns the configuration with the addresses the listeners are bound to.
+pub async fn start_listeners(config: ServerConfig, system: &SharedSystem) -> ServerConfig {
+    let mut current_config = config.clone();
+
+    if config.http.enabled {
+        let http_addr = http_server::start(config.http, system.clone()).await;
+        current_config.http.address = http_addr.to_string();
+    }
+
+    if config.quic.enabled {
+        let quic_addr = quic_server::start(
+            config.quic,
+            config.heartbeat,
+            config.backpressure,
+            system.clone(),
+        );
+        current_config.quic.address = quic_addr.to_string();
+    }
+
+    if config.tcp.enabled {
+        let tcp_addr = tcp_server::start(
+            config.tcp,
+            config.heartbeat,
+            config.backpressure,
+            system.clone(),
+        )
+        .await;
+        current_config.tcp.address = tcp_addr.to_string();
+    }
+
+    if config.mqtt.enabled {
+        let mqtt_addr = mqtt_server::start(config.mqtt, system.clone()).await;
+        current_config.mqtt.address = mqtt_addr.to_string();
+    }
+
+    if config.amqp.enabled {
+        let amqp_addr = amqp_server::start(config.amqp, system.clone()).await;
+        current_config.amqp.address = amqp_addr.to_string();
+    }
+
+    if config.otlp.enabled {
+        let otlp_addr = otlp_server::start(config.otlp, system.clone()).await;
+        current_config.otlp.address = otlp_addr.to_string();
+    }
+
+    current_config
+}
+
+/// Saves the configuration of the running server (with the actual listeners addresses) in the runtime directory.
+pub async fn save_current_config(current_config: &ServerConfig) -> Result<(), ServerError> {
+    let runtime_path = current_config.system.get_runtime_path();
+    let current_config_path = format!("{}/current_config.toml", runtime_path);
+    let current_config_content =
+        toml::to_string(current_config).expect("Cannot serialize current_config");
+    tokio::fs::write(current_config_path, current_config_content).await?;
+    Ok(())
+}
+
+/// Stops the connectors, drains the connected clients (if the timeout is not zero) and shuts down the system.
+pub async fn shutdown(system: SharedSystem, drain_timeout: Duration) -> Result<(), ServerError> {
+    ConnectorRuntime::shutdown(&system).await;
+    if !drain_timeout.is_zero() {
+        System::drain(&system, drain_timeout).await;
+    }
+
+    let mut system = system.write();
+    system.shutdown().await?;
+    Ok(())
+}
@@ -8,13 +8,20 @@ use tikv_jemallocator::Jemalloc;
 #[global_allocator]
 static GLOBAL: Jemalloc = Jemalloc;
 
+pub mod amqp;
 pub mod args;
 pub mod binary;
 pub mod channels;
 pub mod configs;
+pub mod connectors;
+pub mod export;
 pub mod http;
+pub mod iggy_server;
 pub mod log;
+pub mod mqtt;
+pub mod otlp;
 pub mod quic;
+pub mod runtimes;
 pub mod server_error;
 pub mod streaming;
 pub mod tcp;
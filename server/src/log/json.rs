@@ -0,0 +1,162 @@
+use chrono::{SecondsFormat, Utc};
+use serde_json::{Map, Value};
+use std::fmt;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Formats each event as a single line JSON object, suited for the ingestion by the log aggregators (e.g. Loki or ELK):
+/// `{"timestamp":"...","level":"INFO","target":"server::...","message":"...","fields":{...},"spans":[...]}`.
+pub struct JsonFormat;
+
+impl<S, N> FormatEvent<S, N> for JsonFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        let mut visitor = JsonVisitor::default();
+        event.record(&mut visitor);
+
+        let mut entry = Map::new();
+        entry.insert(
+            "timestamp".to_string(),
+            Utc::now()
+                .to_rfc3339_opts(SecondsFormat::Micros, true)
+                .into(),
+        );
+        entry.insert("level".to_string(), metadata.level().as_str().into());
+        entry.insert("target".to_string(), metadata.target().into());
+        entry.insert(
+            "message".to_string(),
+            visitor.message.unwrap_or_default().into(),
+        );
+        if !visitor.fields.is_empty() {
+            entry.insert("fields".to_string(), Value::Object(visitor.fields));
+        }
+        if let Some(scope) = ctx.event_scope() {
+            let spans = scope
+                .from_root()
+                .map(|span| Value::from(span.name()))
+                .collect::<Vec<_>>();
+            entry.insert("spans".to_string(), Value::Array(spans));
+        }
+
+        writeln!(writer, "{}", Value::Object(entry))
+    }
+}
+
+/// Wraps the line logged before the format was configured, so that the log file contains only the JSON objects.
+pub fn format_early_log(line: &str) -> String {
+    let mut entry = Map::new();
+    entry.insert("message".to_string(), line.trim_end().into());
+    entry.insert("early".to_string(), true.into());
+    format!("{}\n", Value::Object(entry))
+}
+
+#[derive(Default)]
+struct JsonVisitor {
+    message: Option<String>,
+    fields: Map<String, Value>,
+}
+
+impl JsonVisitor {
+    fn insert(&mut self, field: &Field, value: Value) {
+        if field.name() == "message" {
+            self.message = Some(match value {
+                Value::String(message) => message,
+                value => value.to_string(),
+            });
+            return;
+        }
+        self.fields.insert(field.name().to_string(), value);
+    }
+}
+
+impl Visit for JsonVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, value.into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, format!("{value:?}").into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::fmt::MakeWriter;
+
+    #[derive(Clone, Default)]
+    struct BufferWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for BufferWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for BufferWriter {
+        type Writer = BufferWriter;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn event_should_be_formatted_as_json_line() {
+        let writer = BufferWriter::default();
+        let subscriber = tracing_subscriber::fmt()
+            .event_format(JsonFormat)
+            .with_writer(writer.clone())
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("request");
+            let _guard = span.enter();
+            tracing::warn!(user_id = 1, "Slow command \"{}\".", "poll");
+        });
+
+        let output = String::from_utf8(writer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output.lines().count(), 1);
+        let entry: Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(entry["level"], "WARN");
+        assert_eq!(entry["message"], "Slow command \"poll\".");
+        assert_eq!(entry["fields"]["user_id"], 1);
+        assert_eq!(entry["spans"][0], "request");
+        assert!(entry["timestamp"].is_string());
+    }
+}
@@ -1,4 +1,6 @@
 use crate::configs::system::LoggingConfig;
+use crate::log::json::{self, JsonFormat};
+use crate::log::rotation::RotatingFileWriter;
 use crate::server_error::ServerError;
 use std::io::{self, Write};
 use std::path::PathBuf;
@@ -7,7 +9,8 @@ use std::sync::{Arc, Mutex};
 use tracing::{event, info, trace, Level};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{
-    filter::LevelFilter, fmt, fmt::MakeWriter, prelude::*, reload, reload::Handle, Layer, Registry,
+    filter::LevelFilter, filter::Targets, fmt, fmt::MakeWriter, prelude::*, reload, reload::Handle,
+    Layer, Registry,
 };
 
 const IGGY_LOG_FILE_PREFIX: &str = "iggy-server.log";
@@ -50,16 +53,21 @@ impl<'a> MakeWriter<'a> for VecStringMakeWriter {
 }
 
 pub trait EarlyLogDumper {
-    fn dump_to_file<W: Write>(&self, writer: &mut W);
+    fn dump_to_file<W: Write>(&self, writer: &mut W, json_format: bool);
     fn dump_to_stdout(&self);
 }
 
 impl EarlyLogDumper for Logging {
-    fn dump_to_file<W: Write>(&self, writer: &mut W) {
+    fn dump_to_file<W: Write>(&self, writer: &mut W, json_format: bool) {
         let early_logs_buffer = self.early_logs_buffer.lock().unwrap();
         for log in early_logs_buffer.iter() {
             let log = strip_ansi_escapes::strip(log);
-            writer.write_all(&log).unwrap();
+            if json_format {
+                let log = json::format_early_log(&String::from_utf8_lossy(&log));
+                writer.write_all(log.as_bytes()).unwrap();
+            } else {
+                writer.write_all(&log).unwrap();
+            }
         }
     }
 
@@ -110,13 +118,13 @@ impl Logging {
         // All layers will be replaced during late_init
         let mut layers = vec![];
 
-        let filtering_level = Self::get_filtering_level(None);
+        let filter = Self::get_filter(None);
         let (filtering_stdout_layer, filtering_stdout_reload_handle) =
-            reload::Layer::new(filtering_level.boxed());
+            reload::Layer::new(filter.clone().boxed());
         self.filtering_stdout_reload_handle = Some(filtering_stdout_reload_handle);
 
         let (filtering_file_layer, filtering_file_reload_handle) =
-            reload::Layer::new(filtering_level.boxed());
+            reload::Layer::new(filter.boxed());
         self.filtering_file_reload_handle = Some(filtering_file_reload_handle);
 
         let stdout_layer = fmt::Layer::default().with_writer(|| NullWriter);
@@ -161,28 +169,32 @@ impl Logging {
     ) -> Result<(), ServerError> {
         // Write to stdout and file at the same time.
         // Use the non_blocking appender to avoid blocking the threads.
-        // Use the rolling appender to avoid having a huge log file.
+        // Use the rotating writer to avoid having a huge log file and to keep only the recent ones.
         // Make sure logs are dumped to the file during graceful shutdown.
 
         trace!("Logging config: {}", config);
 
-        let filtering_level = Self::get_filtering_level(Some(config));
+        let filter = Self::get_filter(Some(config));
+        let json_format = config.format.eq_ignore_ascii_case("json");
 
         self.filtering_stdout_reload_handle
             .as_ref()
             .ok_or(ServerError::FilterReloadFailure)?
-            .modify(|layer| *layer = filtering_level.boxed())
+            .modify(|layer| *layer = filter.clone().boxed())
             .expect("Failed to modify stdout filtering layer");
 
         self.filtering_file_reload_handle
             .as_ref()
             .ok_or(ServerError::FilterReloadFailure)?
-            .modify(|layer| *layer = filtering_level.boxed())
+            .modify(|layer| *layer = filter.clone().boxed())
             .expect("Failed to modify file filtering layer");
 
         // Initialize non-blocking stdout layer
         let (_, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
-        let stdout_layer = fmt::Layer::default().with_ansi(true).boxed();
+        let stdout_layer = match json_format {
+            true => fmt::Layer::default().event_format(JsonFormat).boxed(),
+            false => fmt::Layer::default().with_ansi(true).boxed(),
+        };
         self.stdout_guard = Some(stdout_guard);
 
         self.stdout_reload_handle
@@ -197,17 +209,33 @@ impl Logging {
         let base_directory = PathBuf::from(base_directory);
         let logs_subdirectory = PathBuf::from(config.path.clone());
         let logs_path = base_directory.join(logs_subdirectory.clone());
-        let file_appender =
-            tracing_appender::rolling::hourly(logs_path.clone(), IGGY_LOG_FILE_PREFIX);
+        let rotation = config
+            .rotation
+            .parse()
+            .map_err(|_| ServerError::InvalidConfiguration)?;
+        let file_appender = RotatingFileWriter::new(
+            &logs_path,
+            IGGY_LOG_FILE_PREFIX,
+            rotation,
+            config.max_size.as_bytes_u64(),
+            config.max_files as usize,
+            config.retention.get_duration(),
+        )?;
         let (mut non_blocking_file, file_guard) = tracing_appender::non_blocking(file_appender);
 
-        self.dump_to_file(&mut non_blocking_file);
+        self.dump_to_file(&mut non_blocking_file, json_format);
 
-        let file_layer = fmt::layer()
-            .with_target(true)
-            .with_writer(non_blocking_file)
-            .with_ansi(false)
-            .boxed();
+        let file_layer = match json_format {
+            true => fmt::layer()
+                .event_format(JsonFormat)
+                .with_writer(non_blocking_file)
+                .boxed(),
+            false => fmt::layer()
+                .with_target(true)
+                .with_writer(non_blocking_file)
+                .with_ansi(false)
+                .boxed(),
+        };
 
         self.file_guard = Some(file_guard);
         self.file_reload_handle
@@ -215,14 +243,18 @@ impl Logging {
             .ok_or(ServerError::FileReloadFailure)?
             .modify(|layer| *layer = file_layer)
             .expect("Failed to modify file layer");
-        let level = filtering_level.to_string();
 
         let print = format!(
-            "Logging initialized, logs will be stored at: {:?}. Logs will be rotated hourly. Log level is: {}.",
-            logs_path, level
+            "Logging initialized, logs will be stored at: {:?}. Logs will be rotated {} or after reaching {}, keeping {} files. Log level is: {}, format: {}.",
+            logs_path,
+            config.rotation,
+            config.max_size.as_human_string_with_zero_as_unlimited(),
+            config.max_files,
+            filter,
+            config.format
         );
 
-        match filtering_level {
+        match filter.default_level().unwrap_or(LevelFilter::OFF) {
             LevelFilter::OFF => (),
             LevelFilter::ERROR => event!(Level::ERROR, "{}", print),
             LevelFilter::WARN => event!(Level::WARN, "{}", print),
@@ -234,38 +266,35 @@ impl Logging {
         Ok(())
     }
 
-    // RUST_LOG always takes precedence over config
-    fn get_filtering_level(config: Option<&LoggingConfig>) -> LevelFilter {
+    // RUST_LOG always takes precedence over config.
+    // Both accept the per-module directives, e.g. "info,server::binary=debug".
+    fn get_filter(config: Option<&LoggingConfig>) -> Targets {
         if let Ok(rust_log) = std::env::var("RUST_LOG") {
             // Parse log level from RUST_LOG env variable
-            if let Ok(level) = LevelFilter::from_str(&rust_log.to_uppercase()) {
-                level
+            if let Ok(filter) = Targets::from_str(&rust_log) {
+                filter
             } else {
                 println!("Invalid RUST_LOG value: {}, falling back to info", rust_log);
-                LevelFilter::INFO
+                Targets::new().with_default(LevelFilter::INFO)
             }
         } else {
             // Parse log level from config
             if let Some(config) = config {
-                if let Ok(level) = LevelFilter::from_str(&config.level.to_uppercase()) {
-                    level
+                if let Ok(filter) = Targets::from_str(&config.level) {
+                    filter
                 } else {
                     println!(
                         "Invalid log level in config: {}, falling back to info",
                         config.level
                     );
-                    LevelFilter::INFO
+                    Targets::new().with_default(LevelFilter::INFO)
                 }
             } else {
                 // config not provided
-                LevelFilter::INFO
+                Targets::new().with_default(LevelFilter::INFO)
             }
         }
     }
-
-    fn _install_log_rotation_handler(&self) {
-        todo!("Implement log rotation handler based on size and retention time");
-    }
 }
 
 impl Default for Logging {
@@ -3,3 +3,6 @@ pub mod logger;
 
 #[cfg(feature = "tokio-console")]
 pub mod tokio_console;
+
+pub mod json;
+pub mod rotation;
@@ -0,0 +1,209 @@
+use chrono::{DateTime, Duration as TimeDelta, DurationRound, Utc};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+/// The period after which the log file is rotated regardless of its size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotationPeriod {
+    Never,
+    Minutely,
+    Hourly,
+    Daily,
+}
+
+impl RotationPeriod {
+    fn get_next_rotation_at(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let period = match self {
+            RotationPeriod::Never => return None,
+            RotationPeriod::Minutely => TimeDelta::minutes(1),
+            RotationPeriod::Hourly => TimeDelta::hours(1),
+            RotationPeriod::Daily => TimeDelta::days(1),
+        };
+        now.duration_trunc(period).ok().map(|start| start + period)
+    }
+}
+
+impl FromStr for RotationPeriod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "never" => Ok(RotationPeriod::Never),
+            "minutely" => Ok(RotationPeriod::Minutely),
+            "hourly" => Ok(RotationPeriod::Hourly),
+            "daily" => Ok(RotationPeriod::Daily),
+            _ => Err(format!("Unknown log rotation: {s}")),
+        }
+    }
+}
+
+/// Writes the logs to the active file (named after the prefix) and rotates it once it exceeds the maximum size
+/// or the rotation period elapses. The rotated files are suffixed with the time of rotation,
+/// only the `max_files` most recent ones not older than `retention` are kept.
+#[derive(Debug)]
+pub struct RotatingFileWriter {
+    directory: PathBuf,
+    prefix: String,
+    period: RotationPeriod,
+    max_size: u64,
+    max_files: usize,
+    retention: Duration,
+    file: File,
+    size: u64,
+    next_rotation_at: Option<DateTime<Utc>>,
+}
+
+impl RotatingFileWriter {
+    /// Creates the writer, the zero `max_size`, `max_files` or `retention` means no limit.
+    pub fn new(
+        directory: &Path,
+        prefix: &str,
+        period: RotationPeriod,
+        max_size: u64,
+        max_files: usize,
+        retention: Duration,
+    ) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+        let file = open(&directory.join(prefix))?;
+        let size = file.metadata()?.len();
+        let writer = Self {
+            directory: directory.to_path_buf(),
+            prefix: prefix.to_string(),
+            period,
+            max_size,
+            max_files,
+            retention,
+            file,
+            size,
+            next_rotation_at: period.get_next_rotation_at(Utc::now()),
+        };
+        writer.remove_expired_files()?;
+        Ok(writer)
+    }
+
+    fn rotate(&mut self, now: DateTime<Utc>) -> io::Result<()> {
+        self.file.flush()?;
+        let active_path = self.directory.join(&self.prefix);
+        let suffix = now.format("%Y-%m-%d-%H-%M-%S-%3f");
+        let mut rotated_path = self.directory.join(format!("{}.{suffix}", self.prefix));
+        let mut attempt = 1;
+        while rotated_path.exists() {
+            rotated_path = self
+                .directory
+                .join(format!("{}.{suffix}-{attempt}", self.prefix));
+            attempt += 1;
+        }
+
+        fs::rename(&active_path, rotated_path)?;
+        self.file = open(&active_path)?;
+        self.size = 0;
+        self.next_rotation_at = self.period.get_next_rotation_at(now);
+        self.remove_expired_files()
+    }
+
+    fn remove_expired_files(&self) -> io::Result<()> {
+        let rotated_prefix = format!("{}.", self.prefix);
+        let mut rotated_files = fs::read_dir(&self.directory)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| name.starts_with(&rotated_prefix))
+            })
+            .filter_map(|entry| {
+                let modified_at = entry.metadata().and_then(|m| m.modified()).ok()?;
+                Some((modified_at, entry.path()))
+            })
+            .collect::<Vec<_>>();
+        rotated_files.sort_by(|a, b| b.cmp(a));
+
+        let now = SystemTime::now();
+        for (index, (modified_at, path)) in rotated_files.into_iter().enumerate() {
+            let exceeds_count = self.max_files > 0 && index >= self.max_files;
+            let expired = !self.retention.is_zero()
+                && now
+                    .duration_since(modified_at)
+                    .is_ok_and(|age| age > self.retention);
+            if exceeds_count || expired {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let now = Utc::now();
+        let period_elapsed = self.next_rotation_at.is_some_and(|at| now >= at);
+        let size_exceeded =
+            self.max_size > 0 && self.size > 0 && self.size + buf.len() as u64 > self.max_size;
+        if period_elapsed || size_exceeded {
+            self.rotate(now)?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn open(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_should_be_rotated_once_max_size_is_exceeded_and_only_max_files_kept() {
+        let directory = std::env::temp_dir().join(format!("iggy-logs-{}", uuid::Uuid::new_v4()));
+        let mut writer = RotatingFileWriter::new(
+            &directory,
+            "test.log",
+            RotationPeriod::Never,
+            10,
+            2,
+            Duration::ZERO,
+        )
+        .unwrap();
+
+        for _ in 0..5 {
+            writer.write_all(b"0123456789").unwrap();
+        }
+        writer.flush().unwrap();
+
+        let files_count = fs::read_dir(&directory).unwrap().count();
+        let active_size = fs::metadata(directory.join("test.log")).unwrap().len();
+        fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(files_count, 3);
+        assert_eq!(active_size, 10);
+    }
+
+    #[test]
+    fn next_rotation_should_start_with_next_period() {
+        let now = DateTime::parse_from_rfc3339("2024-02-10T10:15:30Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(RotationPeriod::Never.get_next_rotation_at(now), None);
+        assert_eq!(
+            RotationPeriod::Hourly.get_next_rotation_at(now).unwrap(),
+            DateTime::parse_from_rfc3339("2024-02-10T11:00:00Z").unwrap()
+        );
+        assert_eq!(
+            RotationPeriod::Daily.get_next_rotation_at(now).unwrap(),
+            DateTime::parse_from_rfc3339("2024-02-11T00:00:00Z").unwrap()
+        );
+        assert!("weekly".parse::<RotationPeriod>().is_err());
+    }
+}
@@ -2,27 +2,21 @@ use anyhow::Result;
 use clap::Parser;
 use figlet_rs::FIGfont;
 use server::args::Args;
-use server::channels::commands::clean_messages::CleanMessagesExecutor;
-use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
-use server::channels::commands::save_messages::SaveMessagesExecutor;
-use server::channels::handler::ServerCommandHandler;
 use server::configs::config_provider;
 use server::configs::server::ServerConfig;
-use server::http::http_server;
+use server::iggy_server;
 #[cfg(not(feature = "tokio-console"))]
 use server::log::logger::Logging;
 #[cfg(feature = "tokio-console")]
 use server::log::tokio_console::Logging;
-use server::quic::quic_server;
+use server::runtimes::{RuntimeHandles, ServerRuntimes};
 use server::server_error::ServerError;
-
-use server::streaming::systems::system::{SharedSystem, System};
-use server::tcp::tcp_server;
-use tokio::time::Instant;
+use server::streaming::systems::system::System;
+use std::time::Instant;
+use tokio::runtime::Builder;
 use tracing::info;
 
-#[tokio::main]
-async fn main() -> Result<(), ServerError> {
+fn main() -> Result<(), ServerError> {
     let startup_timestamp = Instant::now();
     let standard_font = FIGfont::standard().unwrap();
     let figure = standard_font.convert("Iggy Server");
@@ -35,19 +29,39 @@ async fn main() -> Result<(), ServerError> {
 
     let args = Args::parse();
 
-    let config_provider = config_provider::resolve(&args.config_provider)?;
-    let config = ServerConfig::load(config_provider.as_ref()).await?;
+    // The configuration is loaded before the server runtimes are built, as it defines their topology.
+    let config = Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(async {
+            let config_provider = config_provider::resolve(&args.config_provider)?;
+            ServerConfig::load(config_provider.as_ref()).await
+        })?;
 
     logging.late_init(config.system.get_system_path(), &config.system.logging)?;
 
-    let mut system = System::new(config.system.clone(), None, config.personal_access_token);
+    let runtimes = ServerRuntimes::build(&config.runtimes)?;
+    let handles = runtimes.handles();
+    runtimes
+        .io()
+        .block_on(run(args, config, handles, startup_timestamp))
+}
+
+async fn run(
+    args: Args,
+    config: ServerConfig,
+    runtimes: RuntimeHandles,
+    startup_timestamp: Instant,
+) -> Result<(), ServerError> {
+    if args.migrate {
+        let mut system = System::new(config.system.clone(), None, config.personal_access_token);
+        system.migrate().await?;
+        info!("Storage migration has completed.");
+        return Ok(());
+    }
 
-    system.init().await?;
-    let system = SharedSystem::new(system);
-    let _command_handler = ServerCommandHandler::new(system.clone(), &config)
-        .install_handler(SaveMessagesExecutor)
-        .install_handler(CleanMessagesExecutor)
-        .install_handler(CleanPersonalAccessTokensExecutor);
+    let system = iggy_server::init_system(&config, &runtimes).await?;
+    iggy_server::start_background_tasks(&config, &system, &runtimes).await?;
 
     #[cfg(unix)]
     let (mut ctrl_c, mut sigterm) = {
@@ -58,29 +72,8 @@ async fn main() -> Result<(), ServerError> {
         )
     };
 
-    let mut current_config = config.clone();
-
-    if config.http.enabled {
-        let http_addr = http_server::start(config.http, system.clone()).await;
-        current_config.http.address = http_addr.to_string();
-    }
-
-    if config.quic.enabled {
-        let quic_addr = quic_server::start(config.quic, system.clone());
-        current_config.quic.address = quic_addr.to_string();
-    }
-
-    if config.tcp.enabled {
-        let tcp_addr = tcp_server::start(config.tcp, system.clone()).await;
-        current_config.tcp.address = tcp_addr.to_string();
-    }
-
-    let runtime_path = current_config.system.get_runtime_path();
-    let current_config_path = format!("{}/current_config.toml", runtime_path);
-    let current_config_content =
-        toml::to_string(&current_config).expect("Cannot serialize current_config");
-
-    tokio::fs::write(current_config_path, current_config_content).await?;
+    let current_config = iggy_server::start_listeners(config, &system).await;
+    iggy_server::save_current_config(&current_config).await?;
 
     let elapsed_time = startup_timestamp.elapsed();
     info!(
@@ -109,8 +102,7 @@ async fn main() -> Result<(), ServerError> {
     }
 
     let shutdown_timestamp = Instant::now();
-    let mut system = system.write();
-    system.shutdown().await?;
+    iggy_server::shutdown(system, args.drain_timeout.get_duration()).await?;
     let elapsed_time = shutdown_timestamp.elapsed();
 
     info!(
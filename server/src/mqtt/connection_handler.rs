@@ -0,0 +1,311 @@
+use crate::mqtt::error::MqttError;
+use crate::mqtt::mapping::TopicMapping;
+use crate::mqtt::packet::{
+    self, Connect, Packet, PacketError, Publish, PROTOCOL_VERSION_3_1, PROTOCOL_VERSION_3_1_1,
+    PROTOCOL_VERSION_5,
+};
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::{Acks, Message};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::io::{AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tracing::{debug, error, info, warn};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const TOPIC_HEADER: &str = "mqtt_topic";
+
+const CONNACK_ACCEPTED: u8 = 0x00;
+const CONNACK_UNACCEPTABLE_PROTOCOL_VERSION: u8 = 0x01;
+const CONNACK_BAD_USERNAME_OR_PASSWORD: u8 = 0x04;
+const CONNACK_NOT_AUTHORIZED: u8 = 0x05;
+
+const REASON_SUCCESS: u8 = 0x00;
+const REASON_UNSPECIFIED_ERROR: u8 = 0x80;
+const REASON_UNSUPPORTED_PROTOCOL_VERSION: u8 = 0x84;
+const REASON_BAD_USERNAME_OR_PASSWORD: u8 = 0x86;
+const REASON_NOT_AUTHORIZED: u8 = 0x87;
+const REASON_TOPIC_NAME_INVALID: u8 = 0x90;
+const REASON_SUBSCRIPTIONS_NOT_SUPPORTED: u8 = 0x80;
+
+/// Handles the MQTT connection, the client must authenticate with the iggy user credentials in CONNECT,
+/// and then it can publish the messages (with QoS 0, 1 or 2), which are appended to the topics resolved by the mapping.
+/// The bridge is ingress only, so the subscriptions are rejected.
+pub(crate) async fn handle_connection(
+    address: SocketAddr,
+    stream: TcpStream,
+    mapping: &TopicMapping,
+    max_packet_size: usize,
+    system: SharedSystem,
+) -> Result<(), MqttError> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    let packet = timeout(
+        CONNECT_TIMEOUT,
+        packet::read(&mut reader, 0, max_packet_size),
+    )
+    .await
+    .map_err(|_| {
+        MqttError::ConnectionRejected(format!("no CONNECT packet received from client: {address}"))
+    })??;
+    let Packet::Connect(connect) = packet else {
+        return Err(MqttError::ProtocolViolation(
+            "the first packet must be CONNECT",
+        ));
+    };
+
+    let protocol_version = connect.protocol_version;
+    if !matches!(
+        protocol_version,
+        PROTOCOL_VERSION_3_1 | PROTOCOL_VERSION_3_1_1 | PROTOCOL_VERSION_5
+    ) {
+        // The clients newer than MQTT 5 understand its CONNACK, the older ones expect MQTT 3.1.1 return code.
+        let connack = match protocol_version > PROTOCOL_VERSION_5 {
+            true => packet::connack(PROTOCOL_VERSION_5, REASON_UNSUPPORTED_PROTOCOL_VERSION),
+            false => packet::connack(
+                PROTOCOL_VERSION_3_1_1,
+                CONNACK_UNACCEPTABLE_PROTOCOL_VERSION,
+            ),
+        };
+        writer.write_all(&connack).await?;
+        return Err(MqttError::ConnectionRejected(format!(
+            "unsupported protocol version: {protocol_version}"
+        )));
+    }
+
+    let client_id = system.read().add_client(&address, Transport::Mqtt).await;
+    let session = Session::from_client_id(client_id, address).with_transport(Transport::Mqtt);
+    if let Err(error) = login(&connect, &session, &system).await {
+        let code = match (protocol_version, &error) {
+            (PROTOCOL_VERSION_5, IggyError::InvalidCredentials) => REASON_BAD_USERNAME_OR_PASSWORD,
+            (PROTOCOL_VERSION_5, _) => REASON_NOT_AUTHORIZED,
+            (_, IggyError::InvalidCredentials) => CONNACK_BAD_USERNAME_OR_PASSWORD,
+            _ => CONNACK_NOT_AUTHORIZED,
+        };
+        writer
+            .write_all(&packet::connack(protocol_version, code))
+            .await?;
+        return Err(MqttError::ConnectionRejected(format!(
+            "cannot authenticate MQTT client: {}, error: {error}",
+            connect.client_id
+        )));
+    }
+
+    let accepted = match protocol_version {
+        PROTOCOL_VERSION_5 => REASON_SUCCESS,
+        _ => CONNACK_ACCEPTED,
+    };
+    writer
+        .write_all(&packet::connack(protocol_version, accepted))
+        .await?;
+    info!(
+        "MQTT client: {} has connected from: {address}, protocol version: {protocol_version}, keep alive: {} s.",
+        connect.client_id, connect.keep_alive
+    );
+
+    // The client is disconnected when no packet is received within one and a half of the keep alive interval.
+    let keep_alive = match connect.keep_alive {
+        0 => None,
+        keep_alive => Some(Duration::from_millis(keep_alive as u64 * 1500)),
+    };
+    loop {
+        let read = packet::read(&mut reader, protocol_version, max_packet_size);
+        let packet = match keep_alive {
+            Some(keep_alive) => timeout(keep_alive, read).await.map_err(|_| {
+                MqttError::KeepAliveTimeout(format!(
+                    "no packet received from MQTT client: {} within: {} ms",
+                    connect.client_id,
+                    keep_alive.as_millis()
+                ))
+            })??,
+            None => read.await?,
+        };
+
+        match packet {
+            Packet::Publish(publish) => {
+                handle_publish(
+                    &publish,
+                    protocol_version,
+                    &session,
+                    mapping,
+                    &system,
+                    &mut writer,
+                )
+                .await?
+            }
+            Packet::PubRel { packet_id } => writer.write_all(&packet::pubcomp(packet_id)).await?,
+            Packet::Subscribe {
+                packet_id,
+                filters_count,
+            } => {
+                warn!(
+                    "MQTT client: {} tried to subscribe, the subscriptions are not supported.",
+                    connect.client_id
+                );
+                writer
+                    .write_all(&packet::suback(
+                        protocol_version,
+                        packet_id,
+                        filters_count,
+                        REASON_SUBSCRIPTIONS_NOT_SUPPORTED,
+                    ))
+                    .await?
+            }
+            Packet::Unsubscribe {
+                packet_id,
+                filters_count,
+            } => {
+                writer
+                    .write_all(&packet::unsuback(
+                        protocol_version,
+                        packet_id,
+                        filters_count,
+                        REASON_SUCCESS,
+                    ))
+                    .await?
+            }
+            Packet::PingReq => writer.write_all(&packet::pingresp()).await?,
+            Packet::Disconnect => {
+                info!("MQTT client: {} has disconnected.", connect.client_id);
+                return Ok(());
+            }
+            Packet::Ignored => {}
+            Packet::Connect(_) => {
+                return Err(MqttError::ProtocolViolation(
+                    "CONNECT packet can be sent only once",
+                ))
+            }
+        }
+    }
+}
+
+async fn login(
+    connect: &Connect,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    let (Some(username), Some(password)) = (&connect.username, &connect.password) else {
+        return Err(IggyError::InvalidCredentials);
+    };
+
+    let password = std::str::from_utf8(password).map_err(|_| IggyError::InvalidCredentials)?;
+    system
+        .read()
+        .login_user(username, password, Some(session))
+        .await?;
+    Ok(())
+}
+
+async fn handle_publish<W: AsyncWrite + Unpin>(
+    publish: &Publish,
+    protocol_version: u8,
+    session: &Session,
+    mapping: &TopicMapping,
+    system: &SharedSystem,
+    writer: &mut W,
+) -> Result<(), MqttError> {
+    let reason_code = match append_message(publish, session, mapping, system).await {
+        Ok(()) => REASON_SUCCESS,
+        Err(error) => {
+            warn!(
+                "Cannot append MQTT message published to topic: {}, error: {error}",
+                publish.topic
+            );
+            // MQTT 3.1.1 has no way to reject the single message, so the connection must be closed.
+            if protocol_version != PROTOCOL_VERSION_5 {
+                return Err(MqttError::PublishFailed(format!(
+                    "topic: {}, error: {error}",
+                    publish.topic
+                )));
+            }
+
+            match error {
+                IggyError::InvalidTopicName
+                | IggyError::StreamIdNotFound(_)
+                | IggyError::StreamNameNotFound(_)
+                | IggyError::TopicIdNotFound(_, _)
+                | IggyError::TopicNameNotFound(_, _)
+                | IggyError::PartitionNotFound(_, _, _) => REASON_TOPIC_NAME_INVALID,
+                IggyError::Unauthorized | IggyError::Unauthenticated => REASON_NOT_AUTHORIZED,
+                _ => REASON_UNSPECIFIED_ERROR,
+            }
+        }
+    };
+
+    match (publish.qos, publish.packet_id) {
+        (1, Some(packet_id)) => {
+            writer
+                .write_all(&packet::puback(protocol_version, packet_id, reason_code))
+                .await?
+        }
+        // The duplicated QoS 2 messages (resent before PUBREC was received) are not deduplicated.
+        (2, Some(packet_id)) => {
+            writer
+                .write_all(&packet::pubrec(protocol_version, packet_id, reason_code))
+                .await?
+        }
+        _ => {}
+    }
+    debug!(
+        "Handled MQTT message published to topic: {}, QoS: {}, reason code: {reason_code}",
+        publish.topic, publish.qos
+    );
+    Ok(())
+}
+
+async fn append_message(
+    publish: &Publish,
+    session: &Session,
+    mapping: &TopicMapping,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    let Some(mapped) = mapping.resolve(&publish.topic)? else {
+        return Err(IggyError::InvalidTopicName);
+    };
+
+    let headers = HashMap::from([(
+        HeaderKey::new(TOPIC_HEADER)?,
+        HeaderValue::from_str(&publish.topic)?,
+    )]);
+    let messages = vec![Message::new(
+        None,
+        Bytes::clone(&publish.payload),
+        Some(headers),
+    )];
+    system
+        .read()
+        .append_messages(
+            session,
+            &mapped.stream_id,
+            &mapped.topic_id,
+            &mapped.partitioning,
+            &messages,
+            Acks::Leader,
+        )
+        .await?;
+    Ok(())
+}
+
+pub(crate) fn handle_error(error: MqttError) {
+    match error {
+        MqttError::Packet(PacketError::Io(error)) => match error.kind() {
+            ErrorKind::UnexpectedEof => info!("MQTT connection has been closed."),
+            ErrorKind::ConnectionAborted => info!("MQTT connection has been aborted."),
+            ErrorKind::ConnectionReset => info!("MQTT connection has been reset."),
+            _ => error!("MQTT connection has failed: {error}"),
+        },
+        MqttError::KeepAliveTimeout(reason) => {
+            warn!("MQTT connection has been closed, keep alive timeout: {reason}.")
+        }
+        error => warn!("MQTT connection has been closed: {error}"),
+    }
+}
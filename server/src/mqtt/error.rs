@@ -0,0 +1,22 @@
+use crate::mqtt::packet::PacketError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum MqttError {
+    #[error(transparent)]
+    Packet(#[from] PacketError),
+    #[error("Protocol violation: {0}")]
+    ProtocolViolation(&'static str),
+    #[error("Connection rejected: {0}")]
+    ConnectionRejected(String),
+    #[error("Keep alive timeout: {0}")]
+    KeepAliveTimeout(String),
+    #[error("Cannot publish message: {0}")]
+    PublishFailed(String),
+}
+
+impl From<std::io::Error> for MqttError {
+    fn from(error: std::io::Error) -> Self {
+        MqttError::Packet(PacketError::Io(error))
+    }
+}
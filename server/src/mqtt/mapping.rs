@@ -0,0 +1,190 @@
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::Partitioning;
+
+const STREAM_PLACEHOLDER: &str = "{stream}";
+const TOPIC_PLACEHOLDER: &str = "{topic}";
+const PARTITION_PLACEHOLDER: &str = "{partition}";
+const KEY_PLACEHOLDER: &str = "{key}";
+const SINGLE_LEVEL_WILDCARD: &str = "+";
+const MULTI_LEVEL_WILDCARD: &str = "#";
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Literal(String),
+    Stream,
+    Topic,
+    Partition,
+    Key,
+    SingleLevelWildcard,
+    MultiLevelWildcard,
+}
+
+/// Maps the MQTT topic names to the stream, topic and partitioning, based on the template such as `devices/{stream}/{topic}/{key}`.
+/// Each level of the template is either the literal, one of the placeholders (`{stream}` and `{topic}` are required,
+/// `{partition}` selects the partition by ID, `{key}` selects it by the messages key), or the `+` and `#` wildcards,
+/// matching any single level and all the remaining levels respectively. Without `{partition}` or `{key}`, the messages are balanced.
+#[derive(Debug, Clone)]
+pub struct TopicMapping {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug)]
+pub struct MappedTopic {
+    pub stream_id: Identifier,
+    pub topic_id: Identifier,
+    pub partitioning: Partitioning,
+}
+
+impl TopicMapping {
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let levels = template.split('/').collect::<Vec<_>>();
+        let mut segments = Vec::with_capacity(levels.len());
+        for (index, level) in levels.iter().enumerate() {
+            let segment = match *level {
+                "" => return Err(format!("empty level in template: {template}")),
+                STREAM_PLACEHOLDER => Segment::Stream,
+                TOPIC_PLACEHOLDER => Segment::Topic,
+                PARTITION_PLACEHOLDER => Segment::Partition,
+                KEY_PLACEHOLDER => Segment::Key,
+                SINGLE_LEVEL_WILDCARD => Segment::SingleLevelWildcard,
+                MULTI_LEVEL_WILDCARD if index == levels.len() - 1 => Segment::MultiLevelWildcard,
+                MULTI_LEVEL_WILDCARD => {
+                    return Err(format!(
+                        "'#' must be the last level in template: {template}"
+                    ))
+                }
+                level if level.contains(['{', '}', '+', '#']) => {
+                    return Err(format!("invalid level: {level} in template: {template}"))
+                }
+                level => Segment::Literal(level.to_string()),
+            };
+            if segment != Segment::SingleLevelWildcard
+                && !matches!(segment, Segment::Literal(_))
+                && segments.contains(&segment)
+            {
+                return Err(format!("duplicated level: {level} in template: {template}"));
+            }
+            segments.push(segment);
+        }
+
+        if !segments.contains(&Segment::Stream) || !segments.contains(&Segment::Topic) {
+            return Err(format!(
+                "template: {template} must contain {STREAM_PLACEHOLDER} and {TOPIC_PLACEHOLDER}"
+            ));
+        }
+
+        if segments.contains(&Segment::Partition) && segments.contains(&Segment::Key) {
+            return Err(format!(
+                "template: {template} cannot contain both {PARTITION_PLACEHOLDER} and {KEY_PLACEHOLDER}"
+            ));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Returns the stream, topic and partitioning for the MQTT topic name, or `None` if it doesn't match the template.
+    pub fn resolve(&self, topic_name: &str) -> Result<Option<MappedTopic>, IggyError> {
+        let levels = topic_name.split('/').collect::<Vec<_>>();
+        let mut stream = None;
+        let mut topic = None;
+        let mut partitioning = Partitioning::balanced();
+        let mut matched_all_levels = false;
+        for (index, segment) in self.segments.iter().enumerate() {
+            if *segment == Segment::MultiLevelWildcard {
+                matched_all_levels = true;
+                break;
+            }
+
+            let Some(level) = levels.get(index) else {
+                return Ok(None);
+            };
+
+            match segment {
+                Segment::Literal(literal) if literal != level => return Ok(None),
+                Segment::Stream | Segment::Topic | Segment::Key if level.is_empty() => {
+                    return Ok(None)
+                }
+                Segment::Stream => stream = Some(Identifier::from_str_value(level)?),
+                Segment::Topic => topic = Some(Identifier::from_str_value(level)?),
+                Segment::Partition => match level.parse::<u32>() {
+                    Ok(partition_id) if partition_id > 0 => {
+                        partitioning = Partitioning::partition_id(partition_id)
+                    }
+                    _ => return Ok(None),
+                },
+                Segment::Key => partitioning = Partitioning::messages_key_str(level)?,
+                _ => {}
+            }
+        }
+
+        if !matched_all_levels && levels.len() != self.segments.len() {
+            return Ok(None);
+        }
+
+        match (stream, topic) {
+            (Some(stream_id), Some(topic_id)) => Ok(Some(MappedTopic {
+                stream_id,
+                topic_id,
+                partitioning,
+            })),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_map_stream_and_topic() {
+        let mapping = TopicMapping::parse("{stream}/{topic}").unwrap();
+        let mapped = mapping.resolve("sensors/temperature").unwrap().unwrap();
+        assert_eq!(mapped.stream_id, Identifier::named("sensors").unwrap());
+        assert_eq!(mapped.topic_id, Identifier::named("temperature").unwrap());
+        assert_eq!(mapped.partitioning, Partitioning::balanced());
+        assert!(mapping.resolve("sensors").unwrap().is_none());
+        assert!(mapping.resolve("sensors/temperature/1").unwrap().is_none());
+    }
+
+    #[test]
+    fn should_map_partition_and_wildcards() {
+        let mapping = TopicMapping::parse("devices/+/{stream}/{topic}/{partition}/#").unwrap();
+        let mapped = mapping
+            .resolve("devices/eu/1/readings/3/room/42")
+            .unwrap()
+            .unwrap();
+        assert_eq!(mapped.stream_id, Identifier::numeric(1).unwrap());
+        assert_eq!(mapped.topic_id, Identifier::named("readings").unwrap());
+        assert_eq!(mapped.partitioning, Partitioning::partition_id(3));
+        assert!(mapping
+            .resolve("gateways/eu/1/readings/3")
+            .unwrap()
+            .is_none());
+        assert!(mapping
+            .resolve("devices/eu/1/readings/0")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn should_map_messages_key() {
+        let mapping = TopicMapping::parse("{stream}/{topic}/{key}").unwrap();
+        let mapped = mapping.resolve("iot/readings/device-1").unwrap().unwrap();
+        assert_eq!(
+            mapped.partitioning,
+            Partitioning::messages_key_str("device-1").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_templates() {
+        assert!(TopicMapping::parse("{stream}").is_err());
+        assert!(TopicMapping::parse("{stream}/{topic}/{stream}").is_err());
+        assert!(TopicMapping::parse("{stream}/#/{topic}").is_err());
+        assert!(TopicMapping::parse("{stream}//{topic}").is_err());
+        assert!(TopicMapping::parse("{stream}/{topic}/{partition}/{key}").is_err());
+        assert!(TopicMapping::parse("{stream}/{topic}/x{key}").is_err());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod connection_handler;
+pub mod error;
+pub mod mapping;
+pub mod mqtt_server;
+pub mod packet;
@@ -0,0 +1,74 @@
+use crate::configs::mqtt::MqttConfig;
+use crate::mqtt::connection_handler::{handle_connection, handle_error};
+use crate::mqtt::mapping::TopicMapping;
+use crate::streaming::systems::system::SharedSystem;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tracing::{error, info};
+
+/// Starts the MQTT server, which accepts the messages published by the MQTT clients and appends them to the mapped topics.
+/// Returns the address the server is listening on.
+pub async fn start(config: MqttConfig, system: SharedSystem) -> SocketAddr {
+    info!("Initializing Iggy MQTT server...");
+    let mapping = Arc::new(
+        TopicMapping::parse(&config.topic_template).expect("Invalid MQTT topic template."),
+    );
+    let max_packet_size = config.max_packet_size.as_bytes_u64() as usize;
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let listener = TcpListener::bind(&config.address)
+            .await
+            .expect("Unable to start MQTT server.");
+
+        let local_addr = listener
+            .local_addr()
+            .expect("Failed to get local address for MQTT listener");
+
+        tx.send(local_addr).unwrap_or_else(|_| {
+            panic!(
+                "Failed to send the local address {:?} for MQTT listener",
+                local_addr
+            )
+        });
+
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    if system.read().is_draining() {
+                        info!(
+                            "Rejected new MQTT connection: {address}, the server is shutting down."
+                        );
+                        continue;
+                    }
+
+                    info!("Accepted new MQTT connection: {address}");
+                    let system = system.clone();
+                    let mapping = mapping.clone();
+                    tokio::spawn(async move {
+                        if let Err(error) = handle_connection(
+                            address,
+                            stream,
+                            &mapping,
+                            max_packet_size,
+                            system.clone(),
+                        )
+                        .await
+                        {
+                            handle_error(error);
+                        }
+                        system.read().delete_client(&address).await;
+                    });
+                }
+                Err(error) => error!("Unable to accept MQTT socket, error: {error}"),
+            }
+        }
+    });
+    let addr = match rx.await {
+        Ok(addr) => addr,
+        Err(_) => panic!("Failed to get the local address for MQTT listener"),
+    };
+    info!("Iggy MQTT server has started on: {addr:?}");
+    addr
+}
@@ -0,0 +1,413 @@
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::str::from_utf8;
+use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+pub const PROTOCOL_VERSION_3_1: u8 = 3;
+pub const PROTOCOL_VERSION_3_1_1: u8 = 4;
+pub const PROTOCOL_VERSION_5: u8 = 5;
+
+const CONNECT: u8 = 1;
+const CONNACK: u8 = 2;
+const PUBLISH: u8 = 3;
+const PUBACK: u8 = 4;
+const PUBREC: u8 = 5;
+const PUBREL: u8 = 6;
+const PUBCOMP: u8 = 7;
+const SUBSCRIBE: u8 = 8;
+const SUBACK: u8 = 9;
+const UNSUBSCRIBE: u8 = 10;
+const UNSUBACK: u8 = 11;
+const PINGREQ: u8 = 12;
+const PINGRESP: u8 = 13;
+const DISCONNECT: u8 = 14;
+
+/// The maximum remaining length which can be encoded by the MQTT fixed header.
+pub const MAX_PACKET_SIZE: u64 = 268_435_455;
+
+#[derive(Debug, Error)]
+pub enum PacketError {
+    #[error("Malformed packet: {0}")]
+    Malformed(&'static str),
+    #[error("Packet of {0} bytes exceeds the maximum size of {1} bytes")]
+    TooLarge(usize, usize),
+    #[error("Unsupported packet type: {0}")]
+    UnsupportedType(u8),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// The packets sent by the clients, the bridge is ingress only, so the subscriptions are always rejected.
+#[derive(Debug)]
+pub enum Packet {
+    Connect(Connect),
+    Publish(Publish),
+    PubRel {
+        packet_id: u16,
+    },
+    Subscribe {
+        packet_id: u16,
+        filters_count: usize,
+    },
+    Unsubscribe {
+        packet_id: u16,
+        filters_count: usize,
+    },
+    PingReq,
+    Disconnect,
+    /// The acknowledgements of the messages sent by the server, which never happens.
+    Ignored,
+}
+
+#[derive(Debug)]
+pub struct Connect {
+    pub protocol_version: u8,
+    pub client_id: String,
+    pub keep_alive: u16,
+    pub username: Option<String>,
+    pub password: Option<Bytes>,
+}
+
+#[derive(Debug)]
+pub struct Publish {
+    pub qos: u8,
+    pub packet_id: Option<u16>,
+    pub topic: String,
+    pub payload: Bytes,
+}
+
+/// Reads the next packet, the protocol version is known once the connection has been established.
+pub async fn read<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    protocol_version: u8,
+    max_packet_size: usize,
+) -> Result<Packet, PacketError> {
+    let first_byte = reader.read_u8().await?;
+    let mut remaining_length = 0usize;
+    let mut multiplier = 1usize;
+    loop {
+        let byte = reader.read_u8().await?;
+        remaining_length += (byte & 0x7F) as usize * multiplier;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        multiplier *= 128;
+        if multiplier > 128 * 128 * 128 {
+            return Err(PacketError::Malformed("remaining length"));
+        }
+    }
+
+    if remaining_length > max_packet_size {
+        return Err(PacketError::TooLarge(remaining_length, max_packet_size));
+    }
+
+    let mut body = vec![0; remaining_length];
+    reader.read_exact(&mut body).await?;
+    decode(first_byte, Bytes::from(body), protocol_version)
+}
+
+fn decode(first_byte: u8, mut body: Bytes, protocol_version: u8) -> Result<Packet, PacketError> {
+    let packet_type = first_byte >> 4;
+    let flags = first_byte & 0x0F;
+    match packet_type {
+        CONNECT => decode_connect(&mut body).map(Packet::Connect),
+        PUBLISH => decode_publish(flags, &mut body, protocol_version).map(Packet::Publish),
+        PUBREL => Ok(Packet::PubRel {
+            packet_id: read_u16(&mut body)?,
+        }),
+        SUBSCRIBE | UNSUBSCRIBE => {
+            let packet_id = read_u16(&mut body)?;
+            skip_properties(&mut body, protocol_version)?;
+            let mut filters_count = 0;
+            while body.has_remaining() {
+                read_string(&mut body)?;
+                if packet_type == SUBSCRIBE {
+                    read_u8(&mut body)?;
+                }
+                filters_count += 1;
+            }
+            if filters_count == 0 {
+                return Err(PacketError::Malformed("no topic filters"));
+            }
+            match packet_type {
+                SUBSCRIBE => Ok(Packet::Subscribe {
+                    packet_id,
+                    filters_count,
+                }),
+                _ => Ok(Packet::Unsubscribe {
+                    packet_id,
+                    filters_count,
+                }),
+            }
+        }
+        PINGREQ => Ok(Packet::PingReq),
+        DISCONNECT => Ok(Packet::Disconnect),
+        PUBACK | PUBREC | PUBCOMP => Ok(Packet::Ignored),
+        packet_type => Err(PacketError::UnsupportedType(packet_type)),
+    }
+}
+
+fn decode_connect(body: &mut Bytes) -> Result<Connect, PacketError> {
+    let protocol_name = read_string(body)?;
+    if protocol_name != "MQTT" && protocol_name != "MQIsdp" {
+        return Err(PacketError::Malformed("protocol name"));
+    }
+
+    let protocol_version = read_u8(body)?;
+    let connect_flags = read_u8(body)?;
+    let keep_alive = read_u16(body)?;
+    skip_properties(body, protocol_version)?;
+    let client_id = read_string(body)?;
+    if connect_flags & 0x04 != 0 {
+        skip_properties(body, protocol_version)?;
+        read_string(body)?;
+        read_binary(body)?;
+    }
+
+    let username = match connect_flags & 0x80 != 0 {
+        true => Some(read_string(body)?),
+        false => None,
+    };
+    let password = match connect_flags & 0x40 != 0 {
+        true => Some(read_binary(body)?),
+        false => None,
+    };
+
+    Ok(Connect {
+        protocol_version,
+        client_id,
+        keep_alive,
+        username,
+        password,
+    })
+}
+
+fn decode_publish(
+    flags: u8,
+    body: &mut Bytes,
+    protocol_version: u8,
+) -> Result<Publish, PacketError> {
+    let qos = (flags >> 1) & 0x03;
+    if qos > 2 {
+        return Err(PacketError::Malformed("QoS"));
+    }
+
+    let topic = read_string(body)?;
+    let packet_id = match qos {
+        0 => None,
+        _ => Some(read_u16(body)?),
+    };
+    skip_properties(body, protocol_version)?;
+    Ok(Publish {
+        qos,
+        packet_id,
+        topic,
+        payload: body.split_off(0),
+    })
+}
+
+/// The properties (MQTT 5 only) are not used by the bridge, so they're skipped.
+fn skip_properties(body: &mut Bytes, protocol_version: u8) -> Result<(), PacketError> {
+    if protocol_version != PROTOCOL_VERSION_5 {
+        return Ok(());
+    }
+
+    let length = read_variable_length(body)?;
+    if body.remaining() < length {
+        return Err(PacketError::Malformed("properties length"));
+    }
+    body.advance(length);
+    Ok(())
+}
+
+fn read_variable_length(body: &mut Bytes) -> Result<usize, PacketError> {
+    let mut value = 0usize;
+    let mut multiplier = 1usize;
+    loop {
+        let byte = read_u8(body)?;
+        value += (byte & 0x7F) as usize * multiplier;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        multiplier *= 128;
+        if multiplier > 128 * 128 * 128 {
+            return Err(PacketError::Malformed("variable length"));
+        }
+    }
+}
+
+fn read_u8(body: &mut Bytes) -> Result<u8, PacketError> {
+    if !body.has_remaining() {
+        return Err(PacketError::Malformed("unexpected end of packet"));
+    }
+    Ok(body.get_u8())
+}
+
+fn read_u16(body: &mut Bytes) -> Result<u16, PacketError> {
+    if body.remaining() < 2 {
+        return Err(PacketError::Malformed("unexpected end of packet"));
+    }
+    Ok(body.get_u16())
+}
+
+fn read_binary(body: &mut Bytes) -> Result<Bytes, PacketError> {
+    let length = read_u16(body)? as usize;
+    if body.remaining() < length {
+        return Err(PacketError::Malformed("unexpected end of packet"));
+    }
+    Ok(body.split_to(length))
+}
+
+fn read_string(body: &mut Bytes) -> Result<String, PacketError> {
+    let value = read_binary(body)?;
+    from_utf8(&value)
+        .map(|value| value.to_string())
+        .map_err(|_| PacketError::Malformed("invalid UTF-8 string"))
+}
+
+/// Encodes CONNACK with the return code (MQTT 3.1.1) or the reason code (MQTT 5), the session is never present.
+pub fn connack(protocol_version: u8, code: u8) -> Bytes {
+    match protocol_version {
+        PROTOCOL_VERSION_5 => encode(CONNACK << 4, &[0, code, 0]),
+        _ => encode(CONNACK << 4, &[0, code]),
+    }
+}
+
+/// Encodes PUBACK, the reason code is sent only by MQTT 5 and only when it's not a success.
+pub fn puback(protocol_version: u8, packet_id: u16, reason_code: u8) -> Bytes {
+    encode_acknowledgement(PUBACK << 4, protocol_version, packet_id, reason_code)
+}
+
+pub fn pubrec(protocol_version: u8, packet_id: u16, reason_code: u8) -> Bytes {
+    encode_acknowledgement(PUBREC << 4, protocol_version, packet_id, reason_code)
+}
+
+pub fn pubcomp(packet_id: u16) -> Bytes {
+    encode(PUBCOMP << 4, &packet_id.to_be_bytes())
+}
+
+pub fn suback(protocol_version: u8, packet_id: u16, filters_count: usize, code: u8) -> Bytes {
+    let mut body = packet_id.to_be_bytes().to_vec();
+    if protocol_version == PROTOCOL_VERSION_5 {
+        body.push(0);
+    }
+    body.extend(std::iter::repeat_n(code, filters_count));
+    encode(SUBACK << 4, &body)
+}
+
+pub fn unsuback(protocol_version: u8, packet_id: u16, filters_count: usize, code: u8) -> Bytes {
+    let mut body = packet_id.to_be_bytes().to_vec();
+    if protocol_version == PROTOCOL_VERSION_5 {
+        body.push(0);
+        body.extend(std::iter::repeat_n(code, filters_count));
+    }
+    encode(UNSUBACK << 4, &body)
+}
+
+pub fn pingresp() -> Bytes {
+    encode(PINGRESP << 4, &[])
+}
+
+fn encode_acknowledgement(
+    first_byte: u8,
+    protocol_version: u8,
+    packet_id: u16,
+    reason_code: u8,
+) -> Bytes {
+    let mut body = packet_id.to_be_bytes().to_vec();
+    if protocol_version == PROTOCOL_VERSION_5 && reason_code != 0 {
+        body.push(reason_code);
+    }
+    encode(first_byte, &body)
+}
+
+fn encode(first_byte: u8, body: &[u8]) -> Bytes {
+    debug_assert!(body.len() as u64 <= MAX_PACKET_SIZE);
+    let mut bytes = BytesMut::with_capacity(body.len() + 5);
+    bytes.put_u8(first_byte);
+    let mut length = body.len();
+    loop {
+        let mut byte = (length % 128) as u8;
+        length /= 128;
+        if length > 0 {
+            byte |= 0x80;
+        }
+        bytes.put_u8(byte);
+        if length == 0 {
+            break;
+        }
+    }
+    bytes.put_slice(body);
+    bytes.freeze()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(value: &str) -> Vec<u8> {
+        let mut bytes = (value.len() as u16).to_be_bytes().to_vec();
+        bytes.extend_from_slice(value.as_bytes());
+        bytes
+    }
+
+    #[tokio::test]
+    async fn should_read_connect_with_credentials() {
+        let mut body = string("MQTT");
+        body.extend([PROTOCOL_VERSION_3_1_1, 0xC2, 0, 60]);
+        body.extend(string("device-1"));
+        body.extend(string("iggy"));
+        body.extend(string("secret"));
+        let packet = encode(CONNECT << 4, &body);
+
+        let packet = read(&mut packet.as_ref(), 0, 1024).await.unwrap();
+        let Packet::Connect(connect) = packet else {
+            panic!("Expected CONNECT packet, got: {packet:?}");
+        };
+        assert_eq!(connect.protocol_version, PROTOCOL_VERSION_3_1_1);
+        assert_eq!(connect.client_id, "device-1");
+        assert_eq!(connect.keep_alive, 60);
+        assert_eq!(connect.username.as_deref(), Some("iggy"));
+        assert_eq!(connect.password, Some(Bytes::from("secret")));
+    }
+
+    #[tokio::test]
+    async fn should_read_publish_with_properties() {
+        let mut body = string("sensors/temperature");
+        body.extend([0, 7]);
+        body.extend([2, 0x01, 0x01]);
+        body.extend(b"21.5");
+        let packet = encode((PUBLISH << 4) | 0x02, &body);
+
+        let packet = read(&mut packet.as_ref(), PROTOCOL_VERSION_5, 1024)
+            .await
+            .unwrap();
+        let Packet::Publish(publish) = packet else {
+            panic!("Expected PUBLISH packet, got: {packet:?}");
+        };
+        assert_eq!(publish.qos, 1);
+        assert_eq!(publish.packet_id, Some(7));
+        assert_eq!(publish.topic, "sensors/temperature");
+        assert_eq!(publish.payload, Bytes::from("21.5"));
+    }
+
+    #[tokio::test]
+    async fn should_reject_packet_exceeding_maximum_size() {
+        let packet = encode(PUBLISH << 4, &[0; 200]);
+        let result = read(&mut packet.as_ref(), PROTOCOL_VERSION_3_1_1, 128).await;
+        assert!(matches!(result, Err(PacketError::TooLarge(200, 128))));
+    }
+
+    #[test]
+    fn should_encode_puback_reason_code_only_for_mqtt_5_failures() {
+        assert_eq!(
+            puback(PROTOCOL_VERSION_3_1_1, 7, 0x80).as_ref(),
+            [0x40, 2, 0, 7]
+        );
+        assert_eq!(puback(PROTOCOL_VERSION_5, 7, 0).as_ref(), [0x40, 2, 0, 7]);
+        assert_eq!(
+            puback(PROTOCOL_VERSION_5, 7, 0x80).as_ref(),
+            [0x40, 3, 0, 7, 0x80]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::otlp::error::OtlpError;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::str::FromStr;
+
+const PROTOBUF_CONTENT_TYPE: &str = "application/x-protobuf";
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Encoding of the OTLP/HTTP requests and responses, also used for the payloads of the appended messages.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Encoding {
+    Protobuf,
+    Json,
+}
+
+impl Encoding {
+    pub fn from_content_type(content_type: &str) -> Result<Self, OtlpError> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+        match media_type {
+            PROTOBUF_CONTENT_TYPE => Ok(Encoding::Protobuf),
+            JSON_CONTENT_TYPE => Ok(Encoding::Json),
+            _ => Err(OtlpError::UnsupportedContentType(content_type.to_string())),
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Encoding::Protobuf => PROTOBUF_CONTENT_TYPE,
+            Encoding::Json => JSON_CONTENT_TYPE,
+        }
+    }
+
+    pub fn decode<T>(&self, data: &[u8]) -> Result<T, OtlpError>
+    where
+        T: prost::Message + Default + DeserializeOwned,
+    {
+        match self {
+            Encoding::Protobuf => {
+                T::decode(data).map_err(|error| OtlpError::InvalidRequest(error.to_string()))
+            }
+            Encoding::Json => serde_json::from_slice(data)
+                .map_err(|error| OtlpError::InvalidRequest(error.to_string())),
+        }
+    }
+
+    pub fn encode<T>(&self, value: &T) -> Result<Vec<u8>, OtlpError>
+    where
+        T: prost::Message + Serialize,
+    {
+        match self {
+            Encoding::Protobuf => Ok(value.encode_to_vec()),
+            Encoding::Json => {
+                serde_json::to_vec(value).map_err(|error| OtlpError::Encoding(error.to_string()))
+            }
+        }
+    }
+
+    pub fn respond<T>(&self, value: &T) -> Result<Response, OtlpError>
+    where
+        T: prost::Message + Serialize,
+    {
+        Ok(([(CONTENT_TYPE, self.content_type())], self.encode(value)?).into_response())
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "protobuf" => Ok(Encoding::Protobuf),
+            "json" => Ok(Encoding::Json),
+            _ => Err(format!("unsupported encoding: {value}")),
+        }
+    }
+}
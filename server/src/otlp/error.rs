@@ -0,0 +1,38 @@
+use crate::http::error::CustomError;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use iggy::error::IggyError;
+use thiserror::Error;
+use tracing::warn;
+
+#[derive(Debug, Error)]
+pub enum OtlpError {
+    #[error("Unsupported content type: {0}")]
+    UnsupportedContentType(String),
+    #[error("Unsupported content encoding: {0}")]
+    UnsupportedContentEncoding(String),
+    #[error("Request body exceeds the limit of {0} bytes")]
+    RequestTooLarge(usize),
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("Cannot encode the message payload: {0}")]
+    Encoding(String),
+    #[error(transparent)]
+    Iggy(#[from] IggyError),
+}
+
+impl IntoResponse for OtlpError {
+    fn into_response(self) -> Response {
+        let status_code = match self {
+            OtlpError::UnsupportedContentType(_) | OtlpError::UnsupportedContentEncoding(_) => {
+                StatusCode::UNSUPPORTED_MEDIA_TYPE
+            }
+            OtlpError::RequestTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            OtlpError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+            OtlpError::Encoding(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            OtlpError::Iggy(error) => return CustomError::Error(error).into_response(),
+        };
+        warn!("OTLP request has failed: {self}");
+        (status_code, self.to_string()).into_response()
+    }
+}
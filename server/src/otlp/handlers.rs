@@ -0,0 +1,155 @@
+use crate::otlp::encoding::Encoding;
+use crate::otlp::error::OtlpError;
+use crate::otlp::mapping::{self, MessagesBatch, OtlpTopic};
+use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use axum::extract::{ConnectInfo, State};
+use axum::http::header::{AUTHORIZATION, CONTENT_ENCODING, CONTENT_TYPE};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use bytes::Bytes;
+use flate2::read::GzDecoder;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Acks;
+use iggy::models::user_info::UserId;
+use moka::future::Cache;
+use opentelemetry_proto::tonic::collector::logs::v1::{
+    ExportLogsServiceRequest, ExportLogsServiceResponse,
+};
+use opentelemetry_proto::tonic::collector::trace::v1::{
+    ExportTraceServiceRequest, ExportTraceServiceResponse,
+};
+use std::io::Read;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+pub struct OtlpState {
+    pub system: SharedSystem,
+    pub traces_topic: OtlpTopic,
+    pub logs_topic: OtlpTopic,
+    pub payload_format: Encoding,
+    pub max_request_size: usize,
+    /// The recently authenticated personal access tokens (by hash), so the exporters sending
+    /// the requests every few seconds don't log in each time.
+    pub tokens: Cache<String, UserId>,
+}
+
+pub async fn export_traces(
+    State(state): State<Arc<OtlpState>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, OtlpError> {
+    let user_id = state.authenticate(&headers).await?;
+    let encoding = get_encoding(&headers)?;
+    let body = decompress(&headers, body, state.max_request_size)?;
+    let request: ExportTraceServiceRequest = encoding.decode(&body)?;
+    let batches = mapping::map_spans(request, state.payload_format)?;
+    state
+        .append(user_id, address, &state.traces_topic, batches)
+        .await?;
+    encoding.respond(&ExportTraceServiceResponse::default())
+}
+
+pub async fn export_logs(
+    State(state): State<Arc<OtlpState>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, OtlpError> {
+    let user_id = state.authenticate(&headers).await?;
+    let encoding = get_encoding(&headers)?;
+    let body = decompress(&headers, body, state.max_request_size)?;
+    let request: ExportLogsServiceRequest = encoding.decode(&body)?;
+    let batches = mapping::map_logs(request, state.payload_format)?;
+    state
+        .append(user_id, address, &state.logs_topic, batches)
+        .await?;
+    encoding.respond(&ExportLogsServiceResponse::default())
+}
+
+impl OtlpState {
+    async fn authenticate(&self, headers: &HeaderMap) -> Result<UserId, OtlpError> {
+        let Some(token) = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+        else {
+            return Err(IggyError::Unauthenticated.into());
+        };
+
+        let token_hash = PersonalAccessToken::hash_token(token);
+        if let Some(user_id) = self.tokens.get(&token_hash).await {
+            return Ok(user_id);
+        }
+
+        let user = self
+            .system
+            .read()
+            .login_with_personal_access_token(token, None)
+            .await
+            .map_err(|_| IggyError::Unauthenticated)?;
+        self.tokens.insert(token_hash, user.id).await;
+        Ok(user.id)
+    }
+
+    async fn append(
+        &self,
+        user_id: UserId,
+        address: SocketAddr,
+        topic: &OtlpTopic,
+        batches: Vec<MessagesBatch>,
+    ) -> Result<(), OtlpError> {
+        let session = Session::stateless(user_id, address);
+        let system = self.system.read();
+        for batch in batches {
+            system
+                .append_messages(
+                    &session,
+                    &topic.stream_id,
+                    &topic.topic_id,
+                    &batch.partitioning,
+                    &batch.messages,
+                    Acks::Leader,
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+fn get_encoding(headers: &HeaderMap) -> Result<Encoding, OtlpError> {
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    Encoding::from_content_type(content_type)
+}
+
+/// Decompresses the gzip encoded body, up to the max request size, as the body limit applies to the compressed body only.
+fn decompress(headers: &HeaderMap, body: Bytes, max_size: usize) -> Result<Bytes, OtlpError> {
+    let content_encoding = headers
+        .get(CONTENT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    match content_encoding {
+        "" | "identity" => Ok(body),
+        "gzip" => {
+            let mut decompressed = Vec::new();
+            GzDecoder::new(body.as_ref())
+                .take(max_size as u64 + 1)
+                .read_to_end(&mut decompressed)
+                .map_err(|error| OtlpError::InvalidRequest(error.to_string()))?;
+            if decompressed.len() > max_size {
+                return Err(OtlpError::RequestTooLarge(max_size));
+            }
+            Ok(Bytes::from(decompressed))
+        }
+        _ => Err(OtlpError::UnsupportedContentEncoding(
+            content_encoding.to_string(),
+        )),
+    }
+}
@@ -0,0 +1,261 @@
+use crate::otlp::encoding::Encoding;
+use crate::otlp::error::OtlpError;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceRequest;
+use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+use opentelemetry_proto::tonic::common::v1::any_value::Value;
+use opentelemetry_proto::tonic::logs::v1::{ResourceLogs, ScopeLogs};
+use opentelemetry_proto::tonic::resource::v1::Resource;
+use opentelemetry_proto::tonic::trace::v1::{ResourceSpans, ScopeSpans};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+const SERVICE_NAME_ATTRIBUTE: &str = "service.name";
+const SERVICE_NAME_HEADER: &str = "service_name";
+
+/// The topic receiving the spans or log records, configured as `<stream>/<topic>`, where both the stream
+/// and the topic can be specified by ID or name.
+#[derive(Debug)]
+pub struct OtlpTopic {
+    pub stream_id: Identifier,
+    pub topic_id: Identifier,
+}
+
+/// The messages appended to the topic with the same partitioning.
+#[derive(Debug)]
+pub struct MessagesBatch {
+    pub partitioning: Partitioning,
+    pub messages: Vec<Message>,
+}
+
+impl OtlpTopic {
+    pub fn parse(value: &str) -> Result<Self, IggyError> {
+        let Some((stream, topic)) = value.split_once('/') else {
+            return Err(IggyError::InvalidTopicName);
+        };
+
+        Ok(Self {
+            stream_id: Identifier::from_str_value(stream)?,
+            topic_id: Identifier::from_str_value(topic)?,
+        })
+    }
+}
+
+/// Maps each span to the message with the `ResourceSpans` payload, containing just that span along with its scope and resource.
+pub fn map_spans(
+    request: ExportTraceServiceRequest,
+    format: Encoding,
+) -> Result<Vec<MessagesBatch>, OtlpError> {
+    let mut messages = Vec::new();
+    for resource_spans in request.resource_spans {
+        let headers = get_headers(resource_spans.resource.as_ref())?;
+        for scope_spans in resource_spans.scope_spans {
+            for span in scope_spans.spans {
+                let trace_id = span.trace_id.clone();
+                let payload = format.encode(&ResourceSpans {
+                    resource: resource_spans.resource.clone(),
+                    scope_spans: vec![ScopeSpans {
+                        scope: scope_spans.scope.clone(),
+                        spans: vec![span],
+                        schema_url: scope_spans.schema_url.clone(),
+                    }],
+                    schema_url: resource_spans.schema_url.clone(),
+                })?;
+                messages.push((
+                    trace_id,
+                    Message::new(None, Bytes::from(payload), headers.clone()),
+                ));
+            }
+        }
+    }
+    group_by_trace(messages)
+}
+
+/// Maps each log record to the message with the `ResourceLogs` payload, containing just that record along with its scope and resource.
+pub fn map_logs(
+    request: ExportLogsServiceRequest,
+    format: Encoding,
+) -> Result<Vec<MessagesBatch>, OtlpError> {
+    let mut messages = Vec::new();
+    for resource_logs in request.resource_logs {
+        let headers = get_headers(resource_logs.resource.as_ref())?;
+        for scope_logs in resource_logs.scope_logs {
+            for log_record in scope_logs.log_records {
+                let trace_id = log_record.trace_id.clone();
+                let payload = format.encode(&ResourceLogs {
+                    resource: resource_logs.resource.clone(),
+                    scope_logs: vec![ScopeLogs {
+                        scope: scope_logs.scope.clone(),
+                        log_records: vec![log_record],
+                        schema_url: scope_logs.schema_url.clone(),
+                    }],
+                    schema_url: resource_logs.schema_url.clone(),
+                })?;
+                messages.push((
+                    trace_id,
+                    Message::new(None, Bytes::from(payload), headers.clone()),
+                ));
+            }
+        }
+    }
+    group_by_trace(messages)
+}
+
+/// Groups the messages by the trace ID used as the messages key, so the whole trace ends up in the same partition.
+/// The messages without the (valid) trace ID are balanced.
+fn group_by_trace(messages: Vec<(Vec<u8>, Message)>) -> Result<Vec<MessagesBatch>, OtlpError> {
+    let mut traces = BTreeMap::<Vec<u8>, Vec<Message>>::new();
+    for (trace_id, message) in messages {
+        let trace_id = match trace_id.iter().all(|byte| *byte == 0) {
+            true => Vec::new(),
+            false => trace_id,
+        };
+        traces.entry(trace_id).or_default().push(message);
+    }
+
+    let mut batches = Vec::with_capacity(traces.len());
+    for (trace_id, messages) in traces {
+        let partitioning = match trace_id.is_empty() {
+            true => Partitioning::balanced(),
+            false => Partitioning::messages_key(&trace_id)?,
+        };
+        batches.push(MessagesBatch {
+            partitioning,
+            messages,
+        });
+    }
+    Ok(batches)
+}
+
+fn get_headers(
+    resource: Option<&Resource>,
+) -> Result<Option<HashMap<HeaderKey, HeaderValue>>, IggyError> {
+    let Some(service_name) = resource.and_then(get_service_name) else {
+        return Ok(None);
+    };
+
+    Ok(Some(HashMap::from([(
+        HeaderKey::new(SERVICE_NAME_HEADER)?,
+        HeaderValue::from_str(service_name)?,
+    )])))
+}
+
+fn get_service_name(resource: &Resource) -> Option<&str> {
+    let attribute = resource
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == SERVICE_NAME_ATTRIBUTE)?;
+    match attribute.value.as_ref()?.value.as_ref()? {
+        Value::StringValue(service_name) if !service_name.is_empty() => Some(service_name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry_proto::tonic::common::v1::{AnyValue, KeyValue};
+    use opentelemetry_proto::tonic::logs::v1::LogRecord;
+    use opentelemetry_proto::tonic::trace::v1::Span;
+
+    fn resource() -> Option<Resource> {
+        Some(Resource {
+            attributes: vec![KeyValue {
+                key: SERVICE_NAME_ATTRIBUTE.to_string(),
+                value: Some(AnyValue {
+                    value: Some(Value::StringValue("checkout".to_string())),
+                }),
+            }],
+            dropped_attributes_count: 0,
+        })
+    }
+
+    fn span(trace_id: u8, name: &str) -> Span {
+        Span {
+            trace_id: vec![trace_id; 16],
+            span_id: vec![1; 8],
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_map_each_span_to_message_grouped_by_trace() {
+        let request = ExportTraceServiceRequest {
+            resource_spans: vec![ResourceSpans {
+                resource: resource(),
+                scope_spans: vec![ScopeSpans {
+                    spans: vec![span(1, "first"), span(2, "second"), span(1, "third")],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        };
+
+        let batches = map_spans(request, Encoding::Protobuf).unwrap();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(
+            batches[0].partitioning,
+            Partitioning::messages_key(&[1; 16]).unwrap()
+        );
+        assert_eq!(batches[0].messages.len(), 2);
+        assert_eq!(batches[1].messages.len(), 1);
+
+        let message = &batches[0].messages[1];
+        let payload = <ResourceSpans as prost::Message>::decode(message.payload.as_ref()).unwrap();
+        assert_eq!(payload.resource, resource());
+        assert_eq!(payload.scope_spans[0].spans, vec![span(1, "third")]);
+        let headers = message.headers.as_ref().unwrap();
+        assert_eq!(
+            headers
+                .get(&HeaderKey::new(SERVICE_NAME_HEADER).unwrap())
+                .unwrap()
+                .as_str()
+                .unwrap(),
+            "checkout"
+        );
+    }
+
+    #[test]
+    fn should_balance_log_records_without_trace_id() {
+        let request = ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                scope_logs: vec![ScopeLogs {
+                    log_records: vec![
+                        LogRecord {
+                            severity_text: "INFO".to_string(),
+                            ..Default::default()
+                        },
+                        LogRecord {
+                            trace_id: vec![0; 16],
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        };
+
+        let batches = map_logs(request, Encoding::Json).unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].partitioning, Partitioning::balanced());
+        assert_eq!(batches[0].messages.len(), 2);
+        assert!(batches[0].messages[0].headers.is_none());
+        let payload: ResourceLogs =
+            serde_json::from_slice(&batches[0].messages[0].payload).unwrap();
+        assert_eq!(payload.scope_logs[0].log_records[0].severity_text, "INFO");
+    }
+
+    #[test]
+    fn should_parse_topic() {
+        let topic = OtlpTopic::parse("otel/1").unwrap();
+        assert_eq!(topic.stream_id, Identifier::named("otel").unwrap());
+        assert_eq!(topic.topic_id, Identifier::numeric(1).unwrap());
+        assert!(OtlpTopic::parse("otel").is_err());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod encoding;
+pub mod error;
+pub mod handlers;
+pub mod mapping;
+pub mod otlp_server;
@@ -0,0 +1,56 @@
+use crate::configs::otlp::OtlpConfig;
+use crate::otlp::handlers::{export_logs, export_traces, OtlpState};
+use crate::otlp::mapping::OtlpTopic;
+use crate::streaming::systems::system::SharedSystem;
+use axum::extract::DefaultBodyLimit;
+use axum::routing::post;
+use axum::Router;
+use moka::future::Cache;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info};
+
+const TOKENS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Starts the OTLP/HTTP receiver, which appends the exported spans and log records to the configured topics.
+/// Returns the address the receiver is listening on.
+pub async fn start(config: OtlpConfig, system: SharedSystem) -> SocketAddr {
+    info!("Initializing Iggy OTLP receiver...");
+    let max_request_size = config.max_request_size.as_bytes_u64() as usize;
+    let state = Arc::new(OtlpState {
+        system,
+        traces_topic: OtlpTopic::parse(&config.traces_topic).expect("Invalid OTLP traces topic."),
+        logs_topic: OtlpTopic::parse(&config.logs_topic).expect("Invalid OTLP logs topic."),
+        payload_format: config
+            .payload_format
+            .parse()
+            .expect("Invalid OTLP payload format."),
+        max_request_size,
+        tokens: Cache::builder().time_to_live(TOKENS_CACHE_TTL).build(),
+    });
+    let app = Router::new()
+        .route("/v1/traces", post(export_traces))
+        .route("/v1/logs", post(export_logs))
+        .layer(DefaultBodyLimit::max(max_request_size))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(&config.address)
+        .await
+        .expect("Unable to start OTLP receiver.");
+    let address = listener
+        .local_addr()
+        .expect("Failed to get local address for OTLP receiver");
+    tokio::spawn(async move {
+        if let Err(error) = axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        {
+            error!("Failed to start OTLP receiver, error: {error}");
+        }
+    });
+    info!("Iggy OTLP receiver has started on: {address:?}");
+    address
+}
@@ -2,34 +2,63 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use crate::binary::command;
+use crate::binary::connection_limiter::ConnectionLimiter;
+use crate::binary::sender::Sender;
+use crate::configs::ip_filter::IpFilterConfig;
+use crate::configs::server::{BackpressureConfig, HeartbeatConfig};
 use crate::quic::quic_sender::QuicSender;
 use crate::server_error::ServerError;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
-use anyhow::{anyhow, Context};
+use anyhow::Context;
 use bytes::Bytes;
 use iggy::command::Command;
 use iggy::{bytes_serializable::BytesSerializable, messages::MAX_PAYLOAD_SIZE};
-use quinn::{Connection, Endpoint, RecvStream, SendStream};
-use tracing::{debug, error, info};
+use quinn::{Connection, Endpoint, RecvStream, SendStream, VarInt};
+use tokio::time::timeout;
+use tracing::{debug, error, info, warn};
 
 const LISTENERS_COUNT: u32 = 10;
 const INITIAL_BYTES_LENGTH: usize = 4;
 
-pub fn start(endpoint: Endpoint, system: SharedSystem) {
+pub fn start(
+    endpoint: Endpoint,
+    heartbeat: HeartbeatConfig,
+    backpressure: BackpressureConfig,
+    ip_filter: IpFilterConfig,
+    system: SharedSystem,
+) {
     for _ in 0..LISTENERS_COUNT {
         let endpoint = endpoint.clone();
+        let ip_filter = ip_filter.clone();
         let system = system.clone();
         tokio::spawn(async move {
             while let Some(incoming_connection) = endpoint.accept().await {
+                if system.read().is_draining() {
+                    info!(
+                        "Rejected incoming connection from client: {}, the server is shutting down.",
+                        incoming_connection.remote_address()
+                    );
+                    continue;
+                }
+
+                let address = incoming_connection.remote_address();
+                if !ip_filter.is_allowed(&address.ip()) {
+                    system.read().reject_connection(&address, Transport::Quic);
+                    continue;
+                }
+
                 info!(
                     "Incoming connection from client: {}",
                     incoming_connection.remote_address()
                 );
                 let system = system.clone();
                 tokio::spawn(async move {
-                    if let Err(error) = handle_connection(incoming_connection, system).await {
+                    if let Err(error) =
+                        handle_connection(incoming_connection, heartbeat, backpressure, system)
+                            .await
+                    {
                         error!("Connection has failed: {error}");
                     }
                 });
@@ -40,20 +69,25 @@ pub fn start(endpoint: Endpoint, system: SharedSystem) {
 
 async fn handle_connection(
     incoming_connection: quinn::Connecting,
+    heartbeat: HeartbeatConfig,
+    backpressure: BackpressureConfig,
     system: SharedSystem,
 ) -> Result<(), ServerError> {
     let connection = incoming_connection.await?;
     let address = connection.remote_address();
     info!("Client has connected: {address}");
     let client_id = system.read().add_client(&address, Transport::Quic).await;
-    let session = Arc::new(Session::from_client_id(client_id, address));
+    let session =
+        Arc::new(Session::from_client_id(client_id, address).with_transport(Transport::Quic));
+    let limiter = ConnectionLimiter::new(&backpressure);
 
-    while let Some(stream) = accept_stream(&connection, &system, &address).await? {
+    while let Some(stream) = accept_stream(&connection, heartbeat, &system, &address).await? {
         let system = system.clone();
         let session = session.clone();
+        let limiter = limiter.clone();
 
         let handle_stream_task = async move {
-            if let Err(err) = handle_stream(stream, system, session).await {
+            if let Err(err) = handle_stream(stream, system, session, limiter).await {
                 error!("Error when handling QUIC stream: {:?}", err)
             }
         };
@@ -66,10 +100,27 @@ type BiStream = (SendStream, RecvStream);
 
 async fn accept_stream(
     connection: &Connection,
+    heartbeat: HeartbeatConfig,
     system: &SharedSystem,
     address: &SocketAddr,
 ) -> Result<Option<BiStream>, ServerError> {
-    match connection.accept_bi().await {
+    let stream = match heartbeat.enabled {
+        true => match timeout(heartbeat.interval.get_duration(), connection.accept_bi()).await {
+            Ok(stream) => stream,
+            Err(_) => {
+                warn!(
+                    "Connection has been closed, heartbeat timeout: no command received from client: {address} within: {}.",
+                    heartbeat.interval
+                );
+                connection.close(VarInt::from_u32(0), b"heartbeat timeout");
+                system.read().delete_client(address).await;
+                return Ok(None);
+            }
+        },
+        false => connection.accept_bi().await,
+    };
+
+    match stream {
         Err(quinn::ConnectionError::ApplicationClosed { .. }) => {
             info!("Connection closed");
             system.read().delete_client(address).await;
@@ -88,35 +139,56 @@ async fn handle_stream(
     stream: BiStream,
     system: SharedSystem,
     session: impl AsRef<Session>,
+    limiter: ConnectionLimiter,
 ) -> anyhow::Result<()> {
     let (send_stream, mut recv_stream) = stream;
+    let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
+    recv_stream
+        .read_exact(&mut initial_buffer)
+        .await
+        .with_context(|| {
+            format!(
+                "Unable to read the QUIC request length, expected: {INITIAL_BYTES_LENGTH} bytes."
+            )
+        })?;
+
+    let length = u32::from_le_bytes(initial_buffer);
+    let mut sender = QuicSender {
+        send: send_stream,
+        recv: recv_stream,
+    };
+    let _permit = match limiter.try_acquire(length) {
+        Ok(permit) => permit,
+        Err(error) => {
+            warn!("Throttling the QUIC request, length: {length}, reason: {error}");
+            // Drain the rejected request, so that the client receives the throttle error instead of the stopped stream.
+            while sender
+                .recv
+                .read_chunk(MAX_PAYLOAD_SIZE as usize, false)
+                .await
+                .with_context(|| "Error when reading the QUIC request.")?
+                .is_some()
+            {}
+            return sender
+                .send_error_response(error)
+                .await
+                .with_context(|| "Error when sending the QUIC throttle response.");
+        }
+    };
+
     // TODO: read to BytesMut instead of Vec<u8>
-    let request = recv_stream
+    let request = sender
+        .recv
         .read_to_end(MAX_PAYLOAD_SIZE as usize)
         .await
         .with_context(|| "Error when reading the QUIC request.")?;
 
-    if request.len() < INITIAL_BYTES_LENGTH {
-        return Err(anyhow!(
-            "Unable to read the QUIC request length, expected: {INITIAL_BYTES_LENGTH} bytes, received: {} bytes.",
-            request.len()
-        ));
-    }
-
     debug!("Trying to read command...");
-    let length = request[..INITIAL_BYTES_LENGTH]
-        .try_into()
-        .map(u32::from_le_bytes)
-        .unwrap_or_default();
-    let command = Command::from_bytes(Bytes::copy_from_slice(&request[INITIAL_BYTES_LENGTH..]))
+    let command = Command::from_bytes(Bytes::from(request))
         .with_context(|| "Error when reading the QUIC request command.")?;
 
     debug!("Received a QUIC command: {command}, payload size: {length}");
 
-    let mut sender = QuicSender {
-        send: send_stream,
-        recv: recv_stream,
-    };
     command::handle(&command, &mut sender, session.as_ref(), system.clone())
         .await
         .with_context(|| "Error when handling the QUIC request.")
@@ -1,6 +1,8 @@
 use crate::binary::sender::Sender;
 use async_trait::async_trait;
+use bytes::BufMut;
 use iggy::error::IggyError;
+use iggy::utils::buffer_pool::BufferPool;
 use quinn::{RecvStream, SendStream};
 use tracing::debug;
 
@@ -43,10 +45,14 @@ impl Sender for QuicSender {
 impl QuicSender {
     async fn send_response(&mut self, status: &[u8], payload: &[u8]) -> Result<(), IggyError> {
         debug!("Sending response with status: {:?}...", status);
-        let length = (payload.len() as u32).to_le_bytes();
-        self.send
-            .write_all(&[status, &length, payload].as_slice().concat())
-            .await?;
+        let buffer_pool = BufferPool::global();
+        let mut response = buffer_pool.acquire(status.len() + 4 + payload.len());
+        response.put_slice(status);
+        response.put_u32_le(payload.len() as u32);
+        response.put_slice(payload);
+        let result = self.send.write_all(&response).await;
+        buffer_pool.release(response);
+        result?;
         self.send.finish().await?;
         debug!("Sent response with status: {:?}", status);
         Ok(())
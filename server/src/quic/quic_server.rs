@@ -1,4 +1,5 @@
 use crate::configs::quic::QuicConfig;
+use crate::configs::server::{BackpressureConfig, HeartbeatConfig};
 use crate::quic::listener;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
@@ -12,7 +13,12 @@ use tracing::info;
 
 /// Starts the QUIC server.
 /// Returns the address the server is listening on.
-pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
+pub fn start(
+    config: QuicConfig,
+    heartbeat: HeartbeatConfig,
+    backpressure: BackpressureConfig,
+    system: SharedSystem,
+) -> SocketAddr {
     info!("Initializing Iggy QUIC server...");
     let quic_config = configure_quic(&config);
     if let Err(error) = quic_config {
@@ -21,7 +27,7 @@ pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
 
     let endpoint = Endpoint::server(quic_config.unwrap(), config.address.parse().unwrap()).unwrap();
     let addr = endpoint.local_addr().unwrap();
-    listener::start(endpoint, system);
+    listener::start(endpoint, heartbeat, backpressure, config.ip_filter, system);
     info!("Iggy QUIC server has started on: {:?}", addr);
     addr
 }
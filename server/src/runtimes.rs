@@ -0,0 +1,337 @@
+use crate::configs::runtimes::{PartitionShardsConfig, RuntimesConfig};
+use crate::streaming::utils::hash;
+use core_affinity::CoreId;
+use iggy::models::stats::RuntimeStats;
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tokio::runtime::{Builder, Handle, Runtime};
+use tokio::sync::oneshot;
+use tracing::{info, warn};
+
+const IO_THREAD_NAME: &str = "iggy-server";
+const STORAGE_THREAD_NAME: &str = "iggy-storage";
+const BACKGROUND_THREAD_NAME: &str = "iggy-background";
+const SHARD_THREAD_NAME: &str = "iggy-shard";
+
+/// The runtimes the server is running on. The requests are always handled on the IO runtime,
+/// while the storage flushes and the background jobs can run on the dedicated runtimes,
+/// so e.g. the fsync storm doesn't take the worker threads away from handling the requests.
+///
+/// The runtimes must be dropped outside of the async context, once the server has been shut down.
+pub struct ServerRuntimes {
+    io: Runtime,
+    storage: Option<Runtime>,
+    background: Option<Runtime>,
+    partition_shards: Vec<PartitionShard>,
+}
+
+/// The single-threaded runtime running the writers of the partitions assigned to the shard, on its own thread,
+/// which is stopped and joined once the shard is dropped.
+struct PartitionShard {
+    handle: Handle,
+    shutdown_sender: Option<oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// The handles of the server runtimes, the storage and background ones fall back to the IO runtime unless they're dedicated.
+#[derive(Debug, Clone)]
+pub struct RuntimeHandles {
+    io: Handle,
+    storage: Option<Handle>,
+    background: Option<Handle>,
+    partition_shards: Option<PartitionShards>,
+}
+
+/// The handles of the partition shards. Each partition is assigned to the shard by the consistent hashing,
+/// so its writer always runs on the same thread (and core, if pinned), improving the cache locality
+/// and removing the cross-core contention for the hot partitions.
+#[derive(Debug, Clone)]
+pub struct PartitionShards {
+    handles: Arc<[Handle]>,
+}
+
+impl ServerRuntimes {
+    pub fn build(config: &RuntimesConfig) -> io::Result<Self> {
+        let io = build_runtime(IO_THREAD_NAME, config.io.worker_threads, &config.io.cores)?;
+        let storage = match config.storage.dedicated {
+            true => Some(build_runtime(
+                STORAGE_THREAD_NAME,
+                config.storage.worker_threads,
+                &config.storage.cores,
+            )?),
+            false => None,
+        };
+        let background = match config.background.dedicated {
+            true => Some(build_runtime(
+                BACKGROUND_THREAD_NAME,
+                config.background.worker_threads,
+                &config.background.cores,
+            )?),
+            false => None,
+        };
+
+        let partition_shards = match config.partition_shards.enabled {
+            true => build_partition_shards(&config.partition_shards)?,
+            false => Vec::new(),
+        };
+
+        info!(
+            "Server runtimes built, IO workers: {}, dedicated storage runtime: {}, dedicated background runtime: {}, partition shards: {}.",
+            io.metrics().num_workers(),
+            storage.is_some(),
+            background.is_some(),
+            partition_shards.len()
+        );
+        Ok(Self {
+            io,
+            storage,
+            background,
+            partition_shards,
+        })
+    }
+
+    pub fn io(&self) -> &Runtime {
+        &self.io
+    }
+
+    pub fn handles(&self) -> RuntimeHandles {
+        RuntimeHandles {
+            io: self.io.handle().clone(),
+            storage: self
+                .storage
+                .as_ref()
+                .map(|runtime| runtime.handle().clone()),
+            background: self
+                .background
+                .as_ref()
+                .map(|runtime| runtime.handle().clone()),
+            partition_shards: match self.partition_shards.is_empty() {
+                true => None,
+                false => Some(PartitionShards {
+                    handles: self
+                        .partition_shards
+                        .iter()
+                        .map(|shard| shard.handle.clone())
+                        .collect(),
+                }),
+            },
+        }
+    }
+}
+
+impl Drop for PartitionShard {
+    fn drop(&mut self) {
+        if let Some(shutdown_sender) = self.shutdown_sender.take() {
+            let _ = shutdown_sender.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl RuntimeHandles {
+    pub fn io(&self) -> &Handle {
+        &self.io
+    }
+
+    pub fn storage(&self) -> &Handle {
+        self.storage.as_ref().unwrap_or(&self.io)
+    }
+
+    pub fn background(&self) -> &Handle {
+        self.background.as_ref().unwrap_or(&self.io)
+    }
+
+    pub fn partition_shards(&self) -> Option<&PartitionShards> {
+        self.partition_shards.as_ref()
+    }
+
+    /// Returns the stats of the IO runtime and the dedicated ones, the partition shards are summed up as a single runtime.
+    pub fn stats(&self) -> Vec<RuntimeStats> {
+        let mut stats = vec![get_runtime_stats("io", std::slice::from_ref(&self.io))];
+        if let Some(storage) = &self.storage {
+            stats.push(get_runtime_stats("storage", std::slice::from_ref(storage)));
+        }
+        if let Some(background) = &self.background {
+            stats.push(get_runtime_stats(
+                "background",
+                std::slice::from_ref(background),
+            ));
+        }
+        if let Some(partition_shards) = &self.partition_shards {
+            stats.push(get_runtime_stats(
+                "partition_shards",
+                &partition_shards.handles,
+            ));
+        }
+        stats
+    }
+}
+
+impl PartitionShards {
+    /// Returns the handle of the shard the partition is assigned to.
+    pub fn get(&self, stream_id: u32, topic_id: u32, partition_id: u32) -> &Handle {
+        let key = [
+            stream_id.to_le_bytes(),
+            topic_id.to_le_bytes(),
+            partition_id.to_le_bytes(),
+        ]
+        .concat();
+        let shard = jump_consistent_hash(hash::calculate_32(&key) as u64, self.handles.len());
+        &self.handles[shard]
+    }
+}
+
+/// The jump consistent hash (Lamping and Veach), which moves only the 1/n of the keys to the new bucket, once the n-th bucket is added,
+/// so changing the number of the shards keeps most of the partitions on the same threads.
+fn jump_consistent_hash(mut key: u64, buckets: usize) -> usize {
+    let mut bucket = -1i64;
+    let mut next_bucket = 0i64;
+    while next_bucket < buckets as i64 {
+        bucket = next_bucket;
+        key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+        next_bucket =
+            ((bucket + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+    }
+    bucket as usize
+}
+
+fn build_partition_shards(config: &PartitionShardsConfig) -> io::Result<Vec<PartitionShard>> {
+    let available_cores = core_affinity::get_core_ids()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|core| core.id)
+        .collect::<Vec<_>>();
+    let cores = match config.cores.is_empty() {
+        true => available_cores,
+        false => config.cores.clone(),
+    };
+    let count = match config.count {
+        0 => cores.len().max(1),
+        count => count,
+    };
+
+    let mut shards = Vec::with_capacity(count);
+    for shard_id in 0..count {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        let handle = runtime.handle().clone();
+        let core = match config.pin_cores && !cores.is_empty() {
+            true => Some(cores[shard_id % cores.len()]),
+            false => None,
+        };
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+        let thread = std::thread::Builder::new()
+            .name(format!("{SHARD_THREAD_NAME}-{shard_id}"))
+            .spawn(move || {
+                if let Some(core) = core {
+                    if !core_affinity::set_for_current(CoreId { id: core }) {
+                        warn!("Failed to pin the partition shard: {shard_id} to core: {core}.");
+                    }
+                }
+                runtime.block_on(async {
+                    let _ = shutdown_receiver.await;
+                });
+            })?;
+        shards.push(PartitionShard {
+            handle,
+            shutdown_sender: Some(shutdown_sender),
+            thread: Some(thread),
+        });
+    }
+    Ok(shards)
+}
+
+fn build_runtime(name: &str, worker_threads: usize, cores: &[usize]) -> io::Result<Runtime> {
+    let mut builder = Builder::new_multi_thread();
+    builder.thread_name(name).enable_all();
+    if worker_threads > 0 {
+        builder.worker_threads(worker_threads);
+    }
+
+    if !cores.is_empty() {
+        // The threads (including the blocking ones) are pinned to the configured cores in the round-robin fashion.
+        let name = name.to_string();
+        let cores = cores.to_vec();
+        let next_core = AtomicUsize::new(0);
+        builder.on_thread_start(move || {
+            let core = cores[next_core.fetch_add(1, Ordering::Relaxed) % cores.len()];
+            if !core_affinity::set_for_current(CoreId { id: core }) {
+                warn!("Failed to pin the thread of runtime: {name} to core: {core}.");
+            }
+        });
+    }
+
+    builder.build()
+}
+
+fn get_runtime_stats(name: &str, handles: &[Handle]) -> RuntimeStats {
+    let mut stats = RuntimeStats {
+        name: name.to_string(),
+        workers_count: 0,
+        alive_tasks_count: 0,
+        global_queue_depth: 0,
+        busy_duration: 0,
+    };
+    for handle in handles {
+        let metrics = handle.metrics();
+        let workers_count = metrics.num_workers();
+        let busy_duration = (0..workers_count)
+            .map(|worker| metrics.worker_total_busy_duration(worker))
+            .sum::<Duration>();
+        stats.workers_count += workers_count as u32;
+        stats.alive_tasks_count += metrics.num_alive_tasks() as u64;
+        stats.global_queue_depth += metrics.global_queue_depth() as u64;
+        stats.busy_duration += busy_duration.as_micros() as u64;
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jump_consistent_hash_should_assign_keys_to_all_buckets() {
+        let mut counts = [0; 8];
+        for key in 0..8000u32 {
+            counts[jump_consistent_hash(hash::calculate_32(&key.to_le_bytes()) as u64, 8)] += 1;
+        }
+
+        assert!(counts.iter().all(|count| *count > 800 && *count < 1200));
+    }
+
+    #[test]
+    fn jump_consistent_hash_should_move_only_keys_assigned_to_new_bucket() {
+        for key in 0..1000u32 {
+            let key = hash::calculate_32(&key.to_le_bytes()) as u64;
+            let bucket = jump_consistent_hash(key, 4);
+            let new_bucket = jump_consistent_hash(key, 5);
+            assert!(new_bucket == bucket || new_bucket == 4);
+        }
+    }
+
+    #[test]
+    fn partition_should_be_always_assigned_to_same_shard() {
+        let runtimes = ServerRuntimes::build(&RuntimesConfig {
+            partition_shards: PartitionShardsConfig {
+                enabled: true,
+                count: 4,
+                pin_cores: false,
+                cores: vec![],
+            },
+            ..RuntimesConfig::default()
+        })
+        .unwrap();
+        let handles = runtimes.handles();
+        let shards = handles.partition_shards().unwrap();
+
+        let shard = shards.get(1, 2, 3).id();
+        assert!((0..10).all(|_| shards.get(1, 2, 3).id() == shard));
+        assert_eq!(handles.stats()[1].name, "partition_shards");
+        assert_eq!(handles.stats()[1].workers_count, 4);
+    }
+}
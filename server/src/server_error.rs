@@ -33,4 +33,6 @@ pub enum ServerError {
     CacheConfigValidationFailure(String),
     #[error("Command length error: {0}")]
     CommandLengthError(String),
+    #[error("Heartbeat timeout: {0}")]
+    HeartbeatTimeout(String),
 }
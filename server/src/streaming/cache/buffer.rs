@@ -59,10 +59,10 @@ where
     pub fn evict_by_size(&mut self, size_to_remove: u64) {
         let mut removed_size = 0;
 
-        while let Some(element) = self.buffer.pop_front() {
-            if removed_size >= size_to_remove {
+        while removed_size < size_to_remove {
+            let Some(element) = self.buffer.pop_front() else {
                 break;
-            }
+            };
             let elem_size = element.get_size_bytes() as u64;
             self.memory_tracker.decrement_used_memory(elem_size);
             self.current_size -= elem_size;
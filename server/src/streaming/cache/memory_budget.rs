@@ -0,0 +1,135 @@
+use crate::configs::system::MemoryBudgetConfig;
+use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use iggy::utils::byte_size::IggyByteSize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::info;
+
+static INSTANCE: MemoryBudget = MemoryBudget::new();
+
+/// Tracks the memory held by the unsaved messages, the messages cache and the indexes cache across all partitions.
+/// Once the configured budget is exceeded, the system flushes the unsaved messages and evicts the caches early,
+/// so that having many active partitions cannot exhaust the memory of the server.
+/// The usage of the messages cache is tracked by `CacheMemoryTracker` and included in the total usage.
+#[derive(Debug)]
+pub struct MemoryBudget {
+    limit_bytes: AtomicU64,
+    unsaved_messages_bytes: AtomicU64,
+    cached_indexes_bytes: AtomicU64,
+}
+
+impl MemoryBudget {
+    const fn new() -> Self {
+        MemoryBudget {
+            limit_bytes: AtomicU64::new(0),
+            unsaved_messages_bytes: AtomicU64::new(0),
+            cached_indexes_bytes: AtomicU64::new(0),
+        }
+    }
+
+    /// Sets the limit of the global memory budget, the usage is tracked regardless of the configuration.
+    pub fn initialize(config: &MemoryBudgetConfig) {
+        let limit_bytes = match config.enabled {
+            true => config.size.clone().into(),
+            false => 0,
+        };
+        INSTANCE.limit_bytes.store(limit_bytes, Ordering::SeqCst);
+        match config.enabled {
+            true => info!(
+                "Memory budget initialized, limit: {}",
+                IggyByteSize::from(limit_bytes).as_human_string()
+            ),
+            false => info!("Memory budget disabled"),
+        }
+    }
+
+    pub fn get_instance() -> &'static MemoryBudget {
+        &INSTANCE
+    }
+
+    /// Returns the limit in bytes, `0` if the budget is disabled.
+    pub fn limit_bytes(&self) -> u64 {
+        self.limit_bytes.load(Ordering::SeqCst)
+    }
+
+    pub fn increment_unsaved_messages(&self, size: u64) {
+        self.unsaved_messages_bytes
+            .fetch_add(size, Ordering::SeqCst);
+    }
+
+    pub fn decrement_unsaved_messages(&self, size: u64) {
+        Self::decrement(&self.unsaved_messages_bytes, size);
+    }
+
+    pub fn increment_cached_indexes(&self, size: u64) {
+        self.cached_indexes_bytes.fetch_add(size, Ordering::SeqCst);
+    }
+
+    pub fn decrement_cached_indexes(&self, size: u64) {
+        Self::decrement(&self.cached_indexes_bytes, size);
+    }
+
+    pub fn unsaved_messages_bytes(&self) -> u64 {
+        self.unsaved_messages_bytes.load(Ordering::SeqCst)
+    }
+
+    pub fn cached_indexes_bytes(&self) -> u64 {
+        self.cached_indexes_bytes.load(Ordering::SeqCst)
+    }
+
+    pub fn cached_messages_bytes(&self) -> u64 {
+        CacheMemoryTracker::get_instance()
+            .map(|memory_tracker| memory_tracker.usage_bytes())
+            .unwrap_or_default()
+    }
+
+    pub fn usage_bytes(&self) -> u64 {
+        self.unsaved_messages_bytes() + self.cached_indexes_bytes() + self.cached_messages_bytes()
+    }
+
+    /// Returns the number of bytes exceeding the budget, `0` if the usage is within the budget or the budget is disabled.
+    pub fn excess_bytes(&self) -> u64 {
+        let limit_bytes = self.limit_bytes();
+        if limit_bytes == 0 {
+            return 0;
+        }
+
+        self.usage_bytes().saturating_sub(limit_bytes)
+    }
+
+    fn decrement(bytes: &AtomicU64, size: u64) {
+        let _ = bytes.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+            Some(current.saturating_sub(size))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usage_should_be_tracked_and_never_go_below_zero() {
+        let budget = MemoryBudget::new();
+        budget.increment_unsaved_messages(100);
+        budget.increment_cached_indexes(40);
+        budget.decrement_unsaved_messages(30);
+        budget.decrement_cached_indexes(50);
+
+        assert_eq!(budget.unsaved_messages_bytes(), 70);
+        assert_eq!(budget.cached_indexes_bytes(), 0);
+    }
+
+    #[test]
+    fn excess_should_be_reported_only_when_budget_is_enabled_and_exceeded() {
+        let budget = MemoryBudget::new();
+        budget.increment_unsaved_messages(150);
+        assert_eq!(budget.excess_bytes(), 0);
+
+        budget.limit_bytes.store(100, Ordering::SeqCst);
+        assert!(budget.excess_bytes() >= 50);
+
+        budget.decrement_unsaved_messages(150);
+        budget.limit_bytes.store(u64::MAX, Ordering::SeqCst);
+        assert_eq!(budget.excess_bytes(), 0);
+    }
+}
@@ -1,2 +1,3 @@
 pub mod buffer;
+pub mod memory_budget;
 pub mod memory_tracker;
@@ -1,4 +1,6 @@
+use crate::streaming::clients::fetch_session::FetchSession;
 use crate::streaming::utils::hash;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::models::user_info::UserId;
 use std::collections::HashMap;
@@ -19,6 +21,11 @@ pub struct Client {
     pub address: SocketAddr,
     pub transport: Transport,
     pub consumer_groups: Vec<ConsumerGroup>,
+    pub compression_algorithms: Vec<CompressionAlgorithm>,
+    pub protocol_version: u32,
+    pub features: Vec<String>,
+    pub fetch_sessions: HashMap<u32, FetchSession>,
+    pub last_fetch_session_id: u32,
 }
 
 #[derive(Debug)]
@@ -32,6 +39,10 @@ pub struct ConsumerGroup {
 pub enum Transport {
     Tcp,
     Quic,
+    Mqtt,
+    Amqp,
+    /// The HTTP API is stateless, so it's never the transport of the client, only of the session.
+    Http,
 }
 
 impl Display for Transport {
@@ -39,6 +50,9 @@ impl Display for Transport {
         match self {
             Transport::Tcp => write!(f, "TCP"),
             Transport::Quic => write!(f, "QUIC"),
+            Transport::Mqtt => write!(f, "MQTT"),
+            Transport::Amqp => write!(f, "AMQP"),
+            Transport::Http => write!(f, "HTTP"),
         }
     }
 }
@@ -52,6 +66,11 @@ impl ClientManager {
             address: *address,
             transport,
             consumer_groups: Vec::new(),
+            compression_algorithms: vec![CompressionAlgorithm::None],
+            protocol_version: 0,
+            features: Vec::new(),
+            fetch_sessions: HashMap::new(),
+            last_fetch_session_id: 0,
         };
         self.clients
             .insert(client.client_id, Arc::new(RwLock::new(client)));
@@ -69,6 +88,38 @@ impl ClientManager {
         Ok(())
     }
 
+    pub async fn set_compression_algorithms(
+        &mut self,
+        client_id: u32,
+        algorithms: Vec<CompressionAlgorithm>,
+    ) -> Result<(), IggyError> {
+        let client = self.clients.get(&client_id);
+        if client.is_none() {
+            return Err(IggyError::ClientNotFound(client_id));
+        }
+
+        let mut client = client.unwrap().write().await;
+        client.compression_algorithms = algorithms;
+        Ok(())
+    }
+
+    pub async fn set_protocol(
+        &mut self,
+        client_id: u32,
+        protocol_version: u32,
+        features: Vec<String>,
+    ) -> Result<(), IggyError> {
+        let client = self.clients.get(&client_id);
+        if client.is_none() {
+            return Err(IggyError::ClientNotFound(client_id));
+        }
+
+        let mut client = client.unwrap().write().await;
+        client.protocol_version = protocol_version;
+        client.features = features;
+        Ok(())
+    }
+
     pub async fn clear_user_id(&mut self, client_id: u32) -> Result<(), IggyError> {
         let client = self.clients.get(&client_id);
         if client.is_none() {
@@ -80,6 +131,17 @@ impl ClientManager {
         Ok(())
     }
 
+    pub async fn clear_user_id_for_user(&mut self, user_id: UserId) -> Result<(), IggyError> {
+        for client in self.clients.values() {
+            let mut client = client.write().await;
+            if client.user_id == Some(user_id) {
+                client.user_id = None;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn get_client_by_address(
         &self,
         address: &SocketAddr,
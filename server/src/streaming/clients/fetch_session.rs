@@ -0,0 +1,41 @@
+/// The maximum number of the fetch sessions, which can be held by a single client at once.
+pub const MAX_FETCH_SESSIONS_PER_CLIENT: u32 = 100;
+
+/// The fetch session keeps the set of partitions registered by the consumer along with the offset of the next message to fetch from each of them,
+/// so the consumer doesn't have to send the whole partition set with every poll, and the partitions without the new messages are skipped cheaply.
+#[derive(Debug)]
+pub struct FetchSession {
+    pub session_id: u32,
+    pub epoch: u32,
+    pub consumer_id: u32,
+    pub count: u32,
+    pub auto_commit: bool,
+    pub partitions: Vec<FetchSessionPartition>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct FetchSessionPartition {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub next_offset: u64,
+}
+
+impl FetchSession {
+    pub fn new(
+        session_id: u32,
+        consumer_id: u32,
+        count: u32,
+        auto_commit: bool,
+        partitions: Vec<FetchSessionPartition>,
+    ) -> Self {
+        Self {
+            session_id,
+            epoch: 0,
+            consumer_id,
+            count,
+            auto_commit,
+            partitions,
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::configs::system::DiskSpaceConfig;
+use iggy::error::IggyError;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use sysinfo::Disks;
+use tracing::error;
+
+const UNKNOWN: u64 = u64::MAX;
+
+/// The space of the disk the path is located on.
+#[derive(Debug, Copy, Clone)]
+pub struct DiskUsage {
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+}
+
+/// The last known free space on the disk of the system path, updated by the disk space checker.
+/// Until the first check, the space is unknown and none of the operations is rejected.
+#[derive(Debug)]
+pub struct DiskSpace {
+    config: DiskSpaceConfig,
+    free_bytes: AtomicU64,
+}
+
+impl DiskSpace {
+    pub fn new(config: DiskSpaceConfig) -> Self {
+        Self {
+            config,
+            free_bytes: AtomicU64::new(UNKNOWN),
+        }
+    }
+
+    pub fn update(&self, usage: DiskUsage) {
+        self.free_bytes
+            .store(usage.available_bytes, Ordering::Relaxed);
+    }
+
+    pub fn free_bytes(&self) -> Option<u64> {
+        match self.free_bytes.load(Ordering::Relaxed) {
+            UNKNOWN => None,
+            free_bytes => Some(free_bytes),
+        }
+    }
+
+    /// Rejects the sends once the free space drops below the configured minimum.
+    pub fn ensure_space_for_sends(&self) -> Result<(), IggyError> {
+        self.ensure_space(self.config.min_free_space_for_sends.as_bytes_u64(), "sends")
+    }
+
+    /// Rejects the new partitions (and the topics with partitions) once the free space drops below the configured minimum.
+    pub fn ensure_space_for_partitions(&self) -> Result<(), IggyError> {
+        self.ensure_space(
+            self.config.min_free_space_for_partitions.as_bytes_u64(),
+            "partitions",
+        )
+    }
+
+    fn ensure_space(&self, min_free_bytes: u64, operation: &str) -> Result<(), IggyError> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let Some(free_bytes) = self.free_bytes() else {
+            return Ok(());
+        };
+
+        if free_bytes >= min_free_bytes {
+            return Ok(());
+        }
+
+        error!(
+            "Cannot allow {operation}, free disk space: {free_bytes} bytes is below the minimum: {min_free_bytes} bytes."
+        );
+        Err(IggyError::InsufficientDiskSpace(free_bytes, min_free_bytes))
+    }
+}
+
+/// Returns the space of the disk the path is located on, by finding the disk with the longest matching mount point.
+pub fn get_disk_usage(path: &str) -> Option<DiskUsage> {
+    let path = canonicalize(path);
+    let disks = Disks::new_with_refreshed_list();
+    let disk = disks
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())?;
+    Some(DiskUsage {
+        total_bytes: disk.total_space(),
+        available_bytes: disk.available_space(),
+    })
+}
+
+pub fn canonicalize(path: &str) -> PathBuf {
+    Path::new(path)
+        .canonicalize()
+        .unwrap_or_else(|_| Path::new(path).to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disk_space(enabled: bool) -> DiskSpace {
+        DiskSpace::new(DiskSpaceConfig {
+            enabled,
+            check_interval: "1 s".parse().unwrap(),
+            min_free_space_for_partitions: "2 KB".parse().unwrap(),
+            min_free_space_for_sends: "1 KB".parse().unwrap(),
+        })
+    }
+
+    #[test]
+    fn operations_should_be_allowed_until_free_space_is_known() {
+        let disk_space = disk_space(true);
+
+        assert!(disk_space.free_bytes().is_none());
+        assert!(disk_space.ensure_space_for_sends().is_ok());
+        assert!(disk_space.ensure_space_for_partitions().is_ok());
+    }
+
+    #[test]
+    fn partitions_should_be_rejected_before_sends() {
+        let disk_space = disk_space(true);
+        disk_space.update(DiskUsage {
+            total_bytes: 10_000,
+            available_bytes: 1500,
+        });
+
+        assert!(disk_space.ensure_space_for_sends().is_ok());
+        assert!(matches!(
+            disk_space.ensure_space_for_partitions(),
+            Err(IggyError::InsufficientDiskSpace(1500, 2000))
+        ));
+
+        disk_space.update(DiskUsage {
+            total_bytes: 10_000,
+            available_bytes: 500,
+        });
+
+        assert!(matches!(
+            disk_space.ensure_space_for_sends(),
+            Err(IggyError::InsufficientDiskSpace(500, 1000))
+        ));
+    }
+
+    #[test]
+    fn operations_should_be_allowed_when_disabled() {
+        let disk_space = disk_space(false);
+        disk_space.update(DiskUsage {
+            total_bytes: 10_000,
+            available_bytes: 0,
+        });
+
+        assert!(disk_space.ensure_space_for_sends().is_ok());
+        assert!(disk_space.ensure_space_for_partitions().is_ok());
+    }
+}
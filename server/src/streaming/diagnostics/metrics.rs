@@ -1,13 +1,44 @@
+use crate::streaming::cache::memory_budget::MemoryBudget;
+use iggy::utils::buffer_pool::BufferPool;
 use prometheus_client::encoding::text::encode;
+use prometheus_client::encoding::EncodeLabelSet;
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
 use prometheus_client::registry::Registry;
+use serde::Serialize;
+use std::time::Duration;
 use tracing::error;
 
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct CommandLabels {
+    command: &'static str,
+}
+
+/// The current values of the metrics, appended to the metrics system topic.
+#[derive(Debug, Serialize)]
+pub(crate) struct MetricsSnapshot {
+    pub http_requests: u64,
+    pub checksum_mismatches: u64,
+    pub streams: i64,
+    pub topics: i64,
+    pub partitions: i64,
+    pub segments: i64,
+    pub messages: i64,
+    pub users: i64,
+    pub clients: i64,
+    pub unsaved_messages_bytes: u64,
+    pub cached_messages_bytes: u64,
+    pub cached_indexes_bytes: u64,
+    pub memory_budget_bytes: u64,
+}
+
 #[derive(Debug)]
 pub(crate) struct Metrics {
     registry: Registry,
     http_requests: Counter,
+    checksum_mismatches: Counter,
     streams: Gauge,
     topics: Gauge,
     partitions: Gauge,
@@ -15,6 +46,13 @@ pub(crate) struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    buffer_pool_acquired: Gauge,
+    buffer_pool_reused: Gauge,
+    buffer_pool_released: Gauge,
+    buffer_pool_discarded: Gauge,
+    disk_free_bytes: Gauge,
+    disk_total_bytes: Gauge,
+    command_latency: Family<CommandLabels, Histogram, fn() -> Histogram>,
 }
 
 impl Metrics {
@@ -22,6 +60,7 @@ impl Metrics {
         let mut metrics = Metrics {
             registry: <Registry>::default(),
             http_requests: Counter::default(),
+            checksum_mismatches: Counter::default(),
             streams: Gauge::default(),
             topics: Gauge::default(),
             partitions: Gauge::default(),
@@ -29,15 +68,40 @@ impl Metrics {
             messages: Gauge::default(),
             users: Gauge::default(),
             clients: Gauge::default(),
+            buffer_pool_acquired: Gauge::default(),
+            buffer_pool_reused: Gauge::default(),
+            buffer_pool_released: Gauge::default(),
+            buffer_pool_discarded: Gauge::default(),
+            disk_free_bytes: Gauge::default(),
+            disk_total_bytes: Gauge::default(),
+            // The buckets from 100 µs up to ~13 s.
+            command_latency: Family::new_with_constructor(|| {
+                Histogram::new(exponential_buckets(0.0001, 2.0, 18))
+            }),
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
+        metrics.register_counter("checksum_mismatches", metrics.checksum_mismatches.clone());
         metrics.register_gauge("streams", metrics.streams.clone());
         metrics.register_gauge("partitions", metrics.partitions.clone());
         metrics.register_gauge("segments", metrics.segments.clone());
         metrics.register_gauge("messages", metrics.messages.clone());
         metrics.register_gauge("users", metrics.users.clone());
         metrics.register_gauge("clients", metrics.clients.clone());
+        metrics.register_gauge("buffer_pool_acquired", metrics.buffer_pool_acquired.clone());
+        metrics.register_gauge("buffer_pool_reused", metrics.buffer_pool_reused.clone());
+        metrics.register_gauge("buffer_pool_released", metrics.buffer_pool_released.clone());
+        metrics.register_gauge(
+            "buffer_pool_discarded",
+            metrics.buffer_pool_discarded.clone(),
+        );
+        metrics.register_gauge("disk_free_bytes", metrics.disk_free_bytes.clone());
+        metrics.register_gauge("disk_total_bytes", metrics.disk_total_bytes.clone());
+        metrics.registry.register(
+            "command_latency_seconds",
+            "latency of the handled commands",
+            metrics.command_latency.clone(),
+        );
 
         metrics
     }
@@ -53,6 +117,11 @@ impl Metrics {
     }
 
     pub fn get_formatted_output(&self) -> String {
+        let buffer_pool = BufferPool::global().stats();
+        self.buffer_pool_acquired.set(buffer_pool.acquired as i64);
+        self.buffer_pool_reused.set(buffer_pool.reused as i64);
+        self.buffer_pool_released.set(buffer_pool.released as i64);
+        self.buffer_pool_discarded.set(buffer_pool.discarded as i64);
         let mut buffer = String::new();
         if let Err(err) = encode(&mut buffer, &self.registry) {
             error!("Failed to encode metrics: {}", err);
@@ -60,10 +129,39 @@ impl Metrics {
         buffer
     }
 
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let memory_budget = MemoryBudget::get_instance();
+        MetricsSnapshot {
+            http_requests: self.http_requests.get(),
+            checksum_mismatches: self.checksum_mismatches.get(),
+            streams: self.streams.get(),
+            topics: self.topics.get(),
+            partitions: self.partitions.get(),
+            segments: self.segments.get(),
+            messages: self.messages.get(),
+            users: self.users.get(),
+            clients: self.clients.get(),
+            unsaved_messages_bytes: memory_budget.unsaved_messages_bytes(),
+            cached_messages_bytes: memory_budget.cached_messages_bytes(),
+            cached_indexes_bytes: memory_budget.cached_indexes_bytes(),
+            memory_budget_bytes: memory_budget.limit_bytes(),
+        }
+    }
+
     pub fn increment_http_requests(&self) {
         self.http_requests.inc();
     }
 
+    pub fn record_command_latency(&self, command: &'static str, duration: Duration) {
+        self.command_latency
+            .get_or_create(&CommandLabels { command })
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn increment_checksum_mismatches(&self) {
+        self.checksum_mismatches.inc();
+    }
+
     pub fn increment_streams(&self, count: u32) {
         self.streams.inc_by(count as i64);
     }
@@ -100,10 +198,20 @@ impl Metrics {
         self.messages.inc_by(count as i64);
     }
 
+    /// Returns the handle of the messages gauge, for the background jobs updating it once done.
+    pub fn messages_gauge(&self) -> Gauge {
+        self.messages.clone()
+    }
+
     pub fn decrement_messages(&self, count: u64) {
         self.messages.dec_by(count as i64);
     }
 
+    pub fn set_disk_space(&self, free_bytes: u64, total_bytes: u64) {
+        self.disk_free_bytes.set(free_bytes as i64);
+        self.disk_total_bytes.set(total_bytes as i64);
+    }
+
     pub fn increment_users(&self, count: u32) {
         self.users.inc_by(count as i64);
     }
@@ -1 +1,2 @@
+pub mod disk_space;
 pub mod metrics;
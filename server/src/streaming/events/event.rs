@@ -0,0 +1,271 @@
+use serde::Serialize;
+
+/// The names of all the events, used to validate the events configured for the sinks.
+pub const EVENTS: &[&str] = &[
+    STREAM_CREATED,
+    STREAM_UPDATED,
+    STREAM_DELETED,
+    STREAM_PURGED,
+    TOPIC_CREATED,
+    TOPIC_UPDATED,
+    TOPIC_DELETED,
+    TOPIC_PURGED,
+    TOPIC_FROZEN,
+    TOPIC_UNFROZEN,
+    PARTITIONS_CREATED,
+    PARTITIONS_DELETED,
+    PARTITION_DRAINED,
+    TOPIC_REPARTITIONED,
+    CONSUMER_GROUP_CREATED,
+    CONSUMER_GROUP_DELETED,
+    CONSUMER_GROUP_OFFSETS_EXPIRED,
+    CONSUMER_GROUP_EXPIRED,
+    USER_CREATED,
+    USER_DELETED,
+    USER_DISABLED,
+    CLIENT_CONNECTED,
+    CLIENT_DISCONNECTED,
+    MESSAGES_FLUSHED,
+    ERROR,
+    CONSUMER_LAG,
+    DISK_USAGE,
+];
+
+pub const STREAM_CREATED: &str = "stream_created";
+pub const STREAM_UPDATED: &str = "stream_updated";
+pub const STREAM_DELETED: &str = "stream_deleted";
+pub const STREAM_PURGED: &str = "stream_purged";
+pub const TOPIC_CREATED: &str = "topic_created";
+pub const TOPIC_UPDATED: &str = "topic_updated";
+pub const TOPIC_DELETED: &str = "topic_deleted";
+pub const TOPIC_PURGED: &str = "topic_purged";
+pub const TOPIC_FROZEN: &str = "topic_frozen";
+pub const TOPIC_UNFROZEN: &str = "topic_unfrozen";
+pub const PARTITIONS_CREATED: &str = "partitions_created";
+pub const PARTITIONS_DELETED: &str = "partitions_deleted";
+pub const PARTITION_DRAINED: &str = "partition_drained";
+pub const TOPIC_REPARTITIONED: &str = "topic_repartitioned";
+pub const CONSUMER_GROUP_CREATED: &str = "consumer_group_created";
+pub const CONSUMER_GROUP_DELETED: &str = "consumer_group_deleted";
+pub const CONSUMER_GROUP_OFFSETS_EXPIRED: &str = "consumer_group_offsets_expired";
+pub const CONSUMER_GROUP_EXPIRED: &str = "consumer_group_expired";
+pub const USER_CREATED: &str = "user_created";
+pub const USER_DELETED: &str = "user_deleted";
+pub const USER_DISABLED: &str = "user_disabled";
+pub const CLIENT_CONNECTED: &str = "client_connected";
+pub const CLIENT_DISCONNECTED: &str = "client_disconnected";
+pub const MESSAGES_FLUSHED: &str = "messages_flushed";
+pub const ERROR: &str = "error";
+pub const CONSUMER_LAG: &str = "consumer_lag";
+pub const DISK_USAGE: &str = "disk_usage";
+
+/// The event raised by the server, published to the configured sinks as JSON.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ServerEvent {
+    StreamCreated {
+        stream_id: u32,
+        name: String,
+    },
+    StreamUpdated {
+        stream_id: u32,
+        name: String,
+    },
+    StreamDeleted {
+        stream_id: u32,
+    },
+    StreamPurged {
+        stream_id: u32,
+    },
+    TopicCreated {
+        stream_id: u32,
+        topic_id: Option<u32>,
+        name: String,
+        partitions_count: u32,
+    },
+    TopicUpdated {
+        stream_id: u32,
+        topic_id: u32,
+        name: String,
+    },
+    TopicDeleted {
+        stream_id: u32,
+        topic_id: u32,
+    },
+    TopicPurged {
+        stream_id: u32,
+        topic_id: u32,
+    },
+    TopicFrozen {
+        stream_id: u32,
+        topic_id: u32,
+    },
+    TopicUnfrozen {
+        stream_id: u32,
+        topic_id: u32,
+    },
+    PartitionsCreated {
+        stream_id: u32,
+        topic_id: u32,
+        partitions_count: u32,
+    },
+    PartitionsDeleted {
+        stream_id: u32,
+        topic_id: u32,
+        partitions_count: u32,
+    },
+    PartitionDrained {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        target_partition_id: u32,
+        messages_count: u64,
+    },
+    TopicRepartitioned {
+        stream_id: u32,
+        topic_id: u32,
+        routing_epoch: u32,
+        messages_count: u64,
+    },
+    ConsumerGroupCreated {
+        stream_id: u32,
+        topic_id: u32,
+        consumer_group_id: u32,
+        name: String,
+    },
+    ConsumerGroupDeleted {
+        stream_id: u32,
+        topic_id: u32,
+        consumer_group_id: u32,
+    },
+    ConsumerGroupOffsetsExpired {
+        stream_id: u32,
+        topic_id: u32,
+        consumer_group_id: u32,
+        name: String,
+        offsets_count: u32,
+        last_activity_at: u64,
+    },
+    ConsumerGroupExpired {
+        stream_id: u32,
+        topic_id: u32,
+        consumer_group_id: u32,
+        name: String,
+        last_activity_at: u64,
+    },
+    UserCreated {
+        user_id: u32,
+        username: String,
+    },
+    UserDeleted {
+        user_id: u32,
+        username: String,
+    },
+    UserDisabled {
+        user_id: u32,
+        username: String,
+        reason: String,
+    },
+    ClientConnected {
+        client_id: u32,
+        address: String,
+        transport: String,
+    },
+    ClientDisconnected {
+        client_id: u32,
+        address: String,
+        transport: String,
+    },
+    MessagesFlushed {
+        duration_ms: u64,
+    },
+    Error {
+        source: String,
+        code: u32,
+        reason: String,
+    },
+    ConsumerLag {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        consumer_id: u32,
+        consumer_group: bool,
+        lag: u64,
+        threshold: u64,
+    },
+    DiskUsage {
+        path: String,
+        used_bytes: u64,
+        total_bytes: u64,
+        usage_percent: u8,
+        threshold_percent: u8,
+    },
+}
+
+impl ServerEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServerEvent::StreamCreated { .. } => STREAM_CREATED,
+            ServerEvent::StreamUpdated { .. } => STREAM_UPDATED,
+            ServerEvent::StreamDeleted { .. } => STREAM_DELETED,
+            ServerEvent::StreamPurged { .. } => STREAM_PURGED,
+            ServerEvent::TopicCreated { .. } => TOPIC_CREATED,
+            ServerEvent::TopicUpdated { .. } => TOPIC_UPDATED,
+            ServerEvent::TopicDeleted { .. } => TOPIC_DELETED,
+            ServerEvent::TopicPurged { .. } => TOPIC_PURGED,
+            ServerEvent::TopicFrozen { .. } => TOPIC_FROZEN,
+            ServerEvent::TopicUnfrozen { .. } => TOPIC_UNFROZEN,
+            ServerEvent::PartitionsCreated { .. } => PARTITIONS_CREATED,
+            ServerEvent::PartitionsDeleted { .. } => PARTITIONS_DELETED,
+            ServerEvent::PartitionDrained { .. } => PARTITION_DRAINED,
+            ServerEvent::TopicRepartitioned { .. } => TOPIC_REPARTITIONED,
+            ServerEvent::ConsumerGroupCreated { .. } => CONSUMER_GROUP_CREATED,
+            ServerEvent::ConsumerGroupDeleted { .. } => CONSUMER_GROUP_DELETED,
+            ServerEvent::ConsumerGroupOffsetsExpired { .. } => CONSUMER_GROUP_OFFSETS_EXPIRED,
+            ServerEvent::ConsumerGroupExpired { .. } => CONSUMER_GROUP_EXPIRED,
+            ServerEvent::UserCreated { .. } => USER_CREATED,
+            ServerEvent::UserDeleted { .. } => USER_DELETED,
+            ServerEvent::UserDisabled { .. } => USER_DISABLED,
+            ServerEvent::ClientConnected { .. } => CLIENT_CONNECTED,
+            ServerEvent::ClientDisconnected { .. } => CLIENT_DISCONNECTED,
+            ServerEvent::MessagesFlushed { .. } => MESSAGES_FLUSHED,
+            ServerEvent::Error { .. } => ERROR,
+            ServerEvent::ConsumerLag { .. } => CONSUMER_LAG,
+            ServerEvent::DiskUsage { .. } => DISK_USAGE,
+        }
+    }
+}
+
+/// The event along with the time it was raised at.
+#[derive(Debug, Clone, Serialize)]
+pub struct EventEnvelope {
+    pub timestamp: u64,
+    #[serde(flatten)]
+    pub event: ServerEvent,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_should_be_serialized_with_its_name_and_timestamp() {
+        let envelope = EventEnvelope {
+            timestamp: 100,
+            event: ServerEvent::TopicCreated {
+                stream_id: 1,
+                topic_id: Some(2),
+                name: "orders".to_string(),
+                partitions_count: 3,
+            },
+        };
+
+        let json = serde_json::to_value(&envelope).unwrap();
+
+        assert_eq!(json["event"], TOPIC_CREATED);
+        assert_eq!(json["event"], envelope.event.as_str());
+        assert_eq!(json["timestamp"], 100);
+        assert_eq!(json["topic_id"], 2);
+        assert_eq!(json["partitions_count"], 3);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::configs::server::EventsLogSinkConfig;
+use crate::streaming::events::event::{EventEnvelope, ServerEvent};
+use crate::streaming::events::sink::{is_event_configured, EventSink};
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use tracing::{error, info};
+
+/// Writes the events to the server log as JSON.
+pub struct LogSink {
+    events: Vec<String>,
+}
+
+impl LogSink {
+    pub fn new(config: &EventsLogSinkConfig) -> Self {
+        Self {
+            events: config.events.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl EventSink for LogSink {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    fn accepts(&self, event: &ServerEvent) -> bool {
+        is_event_configured(&self.events, event)
+    }
+
+    async fn publish(&mut self, _system: &SharedSystem, envelope: &EventEnvelope) {
+        match serde_json::to_string(envelope) {
+            Ok(event) => info!("Server event: {event}"),
+            Err(error) => error!("Failed to serialize server event: {envelope:?}. Error: {error}"),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod event;
+pub mod log_sink;
+pub mod sink;
+pub mod topic_sink;
+pub mod webhook_sink;
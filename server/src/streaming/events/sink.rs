@@ -0,0 +1,16 @@
+use crate::streaming::events::event::{EventEnvelope, ServerEvent};
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+
+/// The destination of the server events, each sink receives the events in the order they were raised.
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn accepts(&self, event: &ServerEvent) -> bool;
+    async fn publish(&mut self, system: &SharedSystem, envelope: &EventEnvelope);
+}
+
+/// Returns `true` if the event is one of the configured ones, the empty list accepts all the events.
+pub fn is_event_configured(events: &[String], event: &ServerEvent) -> bool {
+    events.is_empty() || events.iter().any(|name| name == event.as_str())
+}
@@ -0,0 +1,92 @@
+use crate::configs::server::EventsTopicSinkConfig;
+use crate::streaming::events::event::{EventEnvelope, ServerEvent};
+use crate::streaming::events::sink::{is_event_configured, EventSink};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Acks, Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use tracing::error;
+
+/// The header containing the name of the event, so that the consumers can filter the events without parsing the payloads.
+const EVENT_HEADER: &str = "event";
+
+/// Appends the events to the built-in topic (created on startup), so that the tooling can subscribe to them with the regular consumers.
+/// The flushes are never appended, as each appended event would have to be flushed again.
+pub struct TopicSink {
+    config: EventsTopicSinkConfig,
+    session: Session,
+}
+
+impl TopicSink {
+    pub fn new(config: &EventsTopicSinkConfig) -> Self {
+        Self {
+            config: config.clone(),
+            session: root_session(),
+        }
+    }
+
+    async fn append(
+        &self,
+        system: &SharedSystem,
+        envelope: &EventEnvelope,
+    ) -> Result<(), IggyError> {
+        let stream_id = Identifier::named(&self.config.stream)?;
+        let topic_id = Identifier::named(&self.config.topic)?;
+        let payload = serde_json::to_vec(envelope).map_err(|_| IggyError::InvalidFormat)?;
+        let headers = HashMap::from([(
+            HeaderKey::new(EVENT_HEADER)?,
+            HeaderValue::from_str(envelope.event.as_str())?,
+        )]);
+        let message = Message::new(None, Bytes::from(payload), Some(headers));
+        system
+            .read()
+            .append_messages(
+                &self.session,
+                &stream_id,
+                &topic_id,
+                &Partitioning::balanced(),
+                &vec![message],
+                Acks::Leader,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+fn root_session() -> Session {
+    Session::stateless(
+        DEFAULT_ROOT_USER_ID,
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+    )
+}
+
+#[async_trait]
+impl EventSink for TopicSink {
+    fn name(&self) -> &'static str {
+        "topic"
+    }
+
+    fn accepts(&self, event: &ServerEvent) -> bool {
+        !matches!(event, ServerEvent::MessagesFlushed { .. })
+            && is_event_configured(&self.config.events, event)
+    }
+
+    async fn publish(&mut self, system: &SharedSystem, envelope: &EventEnvelope) {
+        if let Err(error) = self.append(system, envelope).await {
+            error!(
+                "Failed to append event: {} to topic: {} in stream: {}. Error: {error}",
+                envelope.event.as_str(),
+                self.config.topic,
+                self.config.stream
+            );
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::configs::server::EventsWebhookSinkConfig;
+use crate::streaming::events::event::{EventEnvelope, ServerEvent};
+use crate::streaming::events::sink::{is_event_configured, EventSink};
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use ring::hmac;
+use std::sync::Arc;
+use tokio::time;
+use tracing::{debug, error, warn};
+
+/// The header containing the hex encoded HMAC-SHA256 of the payload, computed with the configured secret.
+const SIGNATURE_HEADER: &str = "X-Iggy-Signature";
+const EVENT_HEADER: &str = "X-Iggy-Event";
+
+/// Posts the events as JSON payloads to the configured webhook URLs, each delivery is retried independently.
+pub struct WebhookSink {
+    config: Arc<EventsWebhookSinkConfig>,
+    client: reqwest::Client,
+}
+
+impl WebhookSink {
+    pub fn new(config: &EventsWebhookSinkConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout.get_duration())
+            .build()
+            .expect("Failed to build the webhooks HTTP client");
+        Self {
+            config: Arc::new(config.clone()),
+            client,
+        }
+    }
+}
+
+#[async_trait]
+impl EventSink for WebhookSink {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn accepts(&self, event: &ServerEvent) -> bool {
+        is_event_configured(&self.config.events, event)
+    }
+
+    async fn publish(&mut self, _system: &SharedSystem, envelope: &EventEnvelope) {
+        let payload = match serde_json::to_vec(envelope) {
+            Ok(payload) => payload,
+            Err(error) => {
+                error!("Failed to serialize webhook event: {envelope:?}. Error: {error}");
+                return;
+            }
+        };
+        let signature = sign(&self.config.secret, &payload);
+        for url in &self.config.urls {
+            let client = self.client.clone();
+            let config = self.config.clone();
+            let url = url.clone();
+            let payload = payload.clone();
+            let signature = signature.clone();
+            let event = envelope.event.as_str();
+            tokio::spawn(async move {
+                post(&client, &config, &url, event, payload, signature).await;
+            });
+        }
+    }
+}
+
+/// Posts the payload, retrying with the doubled interval until it's accepted or the retries are exhausted.
+async fn post(
+    client: &reqwest::Client,
+    config: &EventsWebhookSinkConfig,
+    url: &str,
+    event: &str,
+    payload: Vec<u8>,
+    signature: Option<String>,
+) {
+    let mut retry_interval = config.retry_interval.get_duration();
+    for attempt in 0..=config.max_retries {
+        let mut request = client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, event)
+            .body(payload.clone());
+        if let Some(signature) = &signature {
+            request = request.header(SIGNATURE_HEADER, signature);
+        }
+
+        match request.send().await {
+            Ok(response) if response.status().is_success() => {
+                debug!("Webhook event: {event} has been posted to: {url}.");
+                return;
+            }
+            Ok(response) => warn!(
+                "Webhook event: {event} was rejected by: {url} with status: {}, attempt: {}.",
+                response.status(),
+                attempt + 1
+            ),
+            Err(error) => warn!(
+                "Failed to post webhook event: {event} to: {url}, attempt: {}. Error: {error}",
+                attempt + 1
+            ),
+        }
+
+        if attempt < config.max_retries {
+            time::sleep(retry_interval).await;
+            retry_interval *= 2;
+        }
+    }
+
+    error!("Webhook event: {event} could not be posted to: {url}, giving up.");
+}
+
+/// Returns the `sha256=<hex>` signature of the payload, or `None` if the secret isn't configured.
+fn sign(secret: &str, payload: &[u8]) -> Option<String> {
+    if secret.is_empty() {
+        return None;
+    }
+
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, payload);
+    let hex = tag
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    Some(format!("sha256={hex}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_should_be_signed_with_hmac_sha256() {
+        // RFC 4231, test case 2.
+        let signature = sign("Jefe", b"what do ya want for nothing?").unwrap();
+
+        assert_eq!(
+            signature,
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert!(sign("", b"payload").is_none());
+    }
+}
@@ -0,0 +1,442 @@
+use crate::streaming::metadata_log::entry::MetadataRecord;
+use crate::streaming::models::messages_batch::MessagesBatch;
+use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::segments::index::{Index, IndexRange};
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::storage::remove_segment_stats;
+use crate::streaming::segments::time_index::TimeIndex;
+use crate::streaming::storage::{
+    MetadataLogStorage, PartitionStorage, SegmentStorage, Storage, StreamStorage, TopicStorage,
+};
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::topic::Topic;
+use async_trait::async_trait;
+use dashmap::DashMap;
+use iggy::consumer::ConsumerKind;
+use iggy::error::IggyError;
+use iggy::models::messages::Message;
+use iggy::sizeable::Sizeable;
+use iggy::utils::checksum;
+use std::sync::Arc;
+use tracing::{info, trace};
+
+// The streams, topics and partitions kept in memory don't outlive the server, so there's nothing to load
+// and their state is held by the components themselves, while the messages are held by the segment storage.
+
+#[derive(Debug, Default)]
+pub struct MemoryStreamStorage {}
+
+impl StreamStorage for MemoryStreamStorage {}
+
+#[async_trait]
+impl Storage<Stream> for MemoryStreamStorage {
+    async fn load(&self, _stream: &mut Stream) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn save(&self, stream: &Stream) -> Result<(), IggyError> {
+        info!("Saved stream with ID: {} in memory.", stream.stream_id);
+        Ok(())
+    }
+
+    async fn delete(&self, stream: &Stream) -> Result<(), IggyError> {
+        info!("Deleted stream with ID: {} from memory.", stream.stream_id);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MemoryTopicStorage {}
+
+#[async_trait]
+impl TopicStorage for MemoryTopicStorage {
+    async fn save_consumer_group(
+        &self,
+        _topic: &Topic,
+        _consumer_group: &ConsumerGroup,
+    ) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn load_consumer_groups(&self, _topic: &Topic) -> Result<Vec<ConsumerGroup>, IggyError> {
+        Ok(vec![])
+    }
+
+    async fn delete_consumer_group(
+        &self,
+        _topic: &Topic,
+        _consumer_group: &ConsumerGroup,
+    ) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage<Topic> for MemoryTopicStorage {
+    async fn load(&self, _topic: &mut Topic) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn save(&self, topic: &Topic) -> Result<(), IggyError> {
+        for partition in topic.get_partitions() {
+            partition.read().await.persist().await?;
+        }
+        info!("Saved topic {} in memory.", topic);
+        Ok(())
+    }
+
+    async fn delete(&self, topic: &Topic) -> Result<(), IggyError> {
+        info!("Deleted topic {} from memory.", topic);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MemoryPartitionStorage {}
+
+#[async_trait]
+impl PartitionStorage for MemoryPartitionStorage {
+    async fn save_consumer_offset(&self, _offset: &ConsumerOffset) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn load_consumer_offsets(
+        &self,
+        _kind: ConsumerKind,
+        _stream_id: u32,
+        _topic_id: u32,
+        _partition_id: u32,
+    ) -> Result<Vec<ConsumerOffset>, IggyError> {
+        Ok(vec![])
+    }
+
+    async fn delete_consumer_offset(&self, _offset: &ConsumerOffset) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn delete_consumer_offsets(
+        &self,
+        _kind: ConsumerKind,
+        _stream_id: u32,
+        _topic_id: u32,
+        _partition_id: u32,
+    ) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage<Partition> for MemoryPartitionStorage {
+    async fn load(&self, _partition: &mut Partition) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn save(&self, partition: &Partition) -> Result<(), IggyError> {
+        for segment in partition.get_segments() {
+            segment.persist().await?;
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, _partition: &Partition) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+/// The segments kept in memory, by the path of their log, in the same layout as the files: the batches
+/// at their positions in the log, the positions of the messages (indexes) and their timestamps (time indexes).
+#[derive(Debug, Default)]
+pub struct MemorySegmentStorage {
+    segments: DashMap<String, MemorySegment>,
+}
+
+#[derive(Debug, Default)]
+struct MemorySegment {
+    batches: Vec<StoredBatch>,
+    size_bytes: u32,
+    indexes: Vec<u32>,
+    time_indexes: Vec<u64>,
+}
+
+#[derive(Debug)]
+struct StoredBatch {
+    position: u32,
+    messages: Vec<Arc<Message>>,
+}
+
+impl MemorySegment {
+    /// Returns the batches starting at the position or later, as the index of the message points to its batch.
+    fn batches_from(&self, position: u32) -> &[StoredBatch] {
+        let start = self
+            .batches
+            .partition_point(|batch| batch.position < position);
+        &self.batches[start..]
+    }
+
+    fn messages(&self) -> impl Iterator<Item = &Arc<Message>> {
+        self.batches.iter().flat_map(|batch| &batch.messages)
+    }
+}
+
+#[async_trait]
+impl Storage<Segment> for MemorySegmentStorage {
+    async fn load(&self, _segment: &mut Segment) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn save(&self, segment: &Segment) -> Result<(), IggyError> {
+        self.segments.entry(segment.log_path.clone()).or_default();
+        trace!(
+            "Saved segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {} in memory.",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, segment: &Segment) -> Result<(), IggyError> {
+        self.segments.remove(&segment.log_path);
+        remove_segment_stats(segment);
+        info!(
+            "Deleted segment with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {} from memory.",
+            segment.start_offset, segment.partition_id, segment.stream_id, segment.topic_id,
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SegmentStorage for MemorySegmentStorage {
+    async fn load_messages(
+        &self,
+        segment: &Segment,
+        index_range: &IndexRange,
+    ) -> Result<Vec<Arc<Message>>, IggyError> {
+        let Some(memory_segment) = self.segments.get(&segment.log_path) else {
+            return Ok(vec![]);
+        };
+
+        let start_offset = segment.start_offset + index_range.start.relative_offset as u64;
+        let messages_count =
+            (1 + index_range.end.relative_offset - index_range.start.relative_offset) as usize;
+        let messages = memory_segment
+            .batches_from(index_range.start.position)
+            .iter()
+            .flat_map(|batch| &batch.messages)
+            .filter(|message| message.offset >= start_offset)
+            .take(messages_count)
+            .cloned()
+            .collect::<Vec<_>>();
+        trace!("Loaded {} messages from memory.", messages.len());
+        Ok(messages)
+    }
+
+    async fn load_newest_messages_by_size(
+        &self,
+        segment: &Segment,
+        size_bytes: u64,
+    ) -> Result<Vec<Arc<Message>>, IggyError> {
+        let Some(memory_segment) = self.segments.get(&segment.log_path) else {
+            return Ok(vec![]);
+        };
+
+        let threshold = (memory_segment.size_bytes as u64).saturating_sub(size_bytes);
+        Ok(memory_segment
+            .batches
+            .iter()
+            .filter(|batch| batch.position as u64 >= threshold)
+            .flat_map(|batch| batch.messages.iter().cloned())
+            .collect())
+    }
+
+    async fn save_batches(
+        &self,
+        segment: &Segment,
+        batches: &[MessagesBatch],
+    ) -> Result<u32, IggyError> {
+        let mut memory_segment = self.segments.entry(segment.log_path.clone()).or_default();
+        let mut batches_size = 0;
+        for batch in batches {
+            let batch_size = batch.get_size_bytes();
+            let position = memory_segment.size_bytes;
+            memory_segment.batches.push(StoredBatch {
+                position,
+                messages: batch.messages.clone(),
+            });
+            memory_segment.size_bytes += batch_size;
+            batches_size += batch_size;
+        }
+        Ok(batches_size)
+    }
+
+    async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, IggyError> {
+        let Some(memory_segment) = self.segments.get(&segment.log_path) else {
+            return Ok(vec![]);
+        };
+
+        Ok(memory_segment
+            .messages()
+            .map(|message| message.id)
+            .collect())
+    }
+
+    async fn load_checksums(&self, segment: &Segment) -> Result<(), IggyError> {
+        let Some(memory_segment) = self.segments.get(&segment.log_path) else {
+            return Ok(());
+        };
+
+        for message in memory_segment.messages() {
+            let calculated_checksum = checksum::calculate(&message.payload);
+            if calculated_checksum != message.checksum {
+                return Err(IggyError::InvalidMessageChecksum(
+                    calculated_checksum,
+                    message.checksum,
+                    message.offset,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, IggyError> {
+        let Some(memory_segment) = self.segments.get(&segment.log_path) else {
+            return Ok(vec![]);
+        };
+
+        Ok(memory_segment
+            .indexes
+            .iter()
+            .enumerate()
+            .map(|(offset, position)| Index {
+                relative_offset: offset as u32,
+                position: *position,
+            })
+            .collect())
+    }
+
+    async fn load_index_range(
+        &self,
+        segment: &Segment,
+        segment_start_offset: u64,
+        index_start_offset: u64,
+        index_end_offset: u64,
+    ) -> Result<Option<IndexRange>, IggyError> {
+        if index_start_offset > index_end_offset {
+            return Ok(None);
+        }
+
+        let Some(memory_segment) = self.segments.get(&segment.log_path) else {
+            return Ok(None);
+        };
+
+        let indexes = &memory_segment.indexes;
+        if indexes.is_empty() {
+            return Ok(None);
+        }
+
+        let relative_start_offset = index_start_offset.saturating_sub(segment_start_offset) as u32;
+        let relative_end_offset = (index_end_offset - segment_start_offset) as u32;
+        let end_index = (relative_end_offset as usize).min(indexes.len() - 1);
+        if relative_start_offset as usize >= end_index {
+            return Ok(None);
+        }
+
+        let end_position = match indexes[end_index] {
+            0 => memory_segment.size_bytes,
+            position => position,
+        };
+        Ok(Some(IndexRange {
+            start: Index {
+                relative_offset: relative_start_offset,
+                position: indexes[relative_start_offset as usize],
+            },
+            end: Index {
+                relative_offset: relative_end_offset,
+                position: end_position,
+            },
+        }))
+    }
+
+    async fn save_index(
+        &self,
+        segment: &Segment,
+        mut current_position: u32,
+        batches: &[MessagesBatch],
+    ) -> Result<(), IggyError> {
+        let mut memory_segment = self.segments.entry(segment.log_path.clone()).or_default();
+        for batch in batches {
+            for _ in &batch.messages {
+                memory_segment.indexes.push(current_position);
+            }
+            current_position += batch.get_size_bytes();
+        }
+        Ok(())
+    }
+
+    async fn load_all_time_indexes(&self, segment: &Segment) -> Result<Vec<TimeIndex>, IggyError> {
+        let Some(memory_segment) = self.segments.get(&segment.log_path) else {
+            return Ok(vec![]);
+        };
+
+        Ok(memory_segment
+            .time_indexes
+            .iter()
+            .enumerate()
+            .map(|(offset, timestamp)| TimeIndex {
+                relative_offset: offset as u32,
+                timestamp: *timestamp,
+            })
+            .collect())
+    }
+
+    async fn load_last_time_index(
+        &self,
+        segment: &Segment,
+    ) -> Result<Option<TimeIndex>, IggyError> {
+        let Some(memory_segment) = self.segments.get(&segment.log_path) else {
+            return Ok(None);
+        };
+
+        let count = memory_segment.time_indexes.len();
+        Ok(memory_segment
+            .time_indexes
+            .last()
+            .map(|timestamp| TimeIndex {
+                relative_offset: count as u32 - 1,
+                timestamp: *timestamp,
+            }))
+    }
+
+    async fn save_time_index(
+        &self,
+        segment: &Segment,
+        batches: &[MessagesBatch],
+    ) -> Result<(), IggyError> {
+        let mut memory_segment = self.segments.entry(segment.log_path.clone()).or_default();
+        for message in batches.iter().flat_map(|batch| &batch.messages) {
+            memory_segment.time_indexes.push(message.timestamp);
+        }
+        Ok(())
+    }
+
+    fn set_clean_shutdown(&self, _clean_shutdown: bool) {}
+}
+
+/// The metadata log isn't needed, as there's nothing to recover once the server restarts.
+#[derive(Debug, Default)]
+pub struct MemoryMetadataLogStorage {}
+
+#[async_trait]
+impl MetadataLogStorage for MemoryMetadataLogStorage {
+    async fn append(&self, _path: &str, _record: &MetadataRecord) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn load_all(&self, _path: &str) -> Result<Vec<MetadataRecord>, IggyError> {
+        Ok(vec![])
+    }
+
+    async fn truncate(&self, _path: &str) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,269 @@
+use crate::streaming::users::user::User;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::permissions::Permissions;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::models::topic_defaults::TopicDefaults;
+use iggy::models::user_info::UserId;
+use iggy::partitions::delete_partitions::DeletePartitionsMode;
+use iggy::utils::byte_size::IggyByteSize;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The single metadata mutation, which is appended to the metadata log before being applied.
+/// All the identifiers are already resolved, so that the entry can be replayed without the original request.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum MetadataEntry {
+    CreateStream {
+        stream_id: u32,
+        name: String,
+        #[serde(default)]
+        tenant_id: Option<u32>,
+        #[serde(default)]
+        topic_defaults: TopicDefaults,
+    },
+    UpdateStream {
+        stream_id: u32,
+        name: String,
+        #[serde(default)]
+        topic_defaults: Option<TopicDefaults>,
+    },
+    DeleteStream {
+        stream_id: u32,
+        #[serde(default)]
+        soft: bool,
+    },
+    UndeleteStream {
+        stream_id: u32,
+    },
+    CreateTopic {
+        stream_id: u32,
+        topic_id: Option<u32>,
+        name: String,
+        partitions_count: u32,
+        message_expiry: Option<u32>,
+        max_topic_size: Option<IggyByteSize>,
+        replication_factor: u8,
+        #[serde(default)]
+        message_schema: Option<String>,
+        #[serde(default)]
+        ephemeral: bool,
+        #[serde(default)]
+        timestamp_type: TimestampType,
+        #[serde(default)]
+        consumer_limits: ConsumerLimits,
+        #[serde(default)]
+        max_message_size: Option<IggyByteSize>,
+    },
+    UpdateTopic {
+        stream_id: u32,
+        topic_id: u32,
+        name: String,
+        message_expiry: Option<u32>,
+        max_topic_size: Option<IggyByteSize>,
+        replication_factor: u8,
+        #[serde(default)]
+        message_schema: Option<String>,
+        #[serde(default)]
+        timestamp_type: TimestampType,
+        #[serde(default)]
+        consumer_limits: ConsumerLimits,
+        #[serde(default)]
+        max_message_size: Option<IggyByteSize>,
+    },
+    DeleteTopic {
+        stream_id: u32,
+        topic_id: u32,
+        #[serde(default)]
+        soft: bool,
+    },
+    UndeleteTopic {
+        stream_id: u32,
+        topic_id: u32,
+    },
+    FreezeTopic {
+        stream_id: u32,
+        topic_id: u32,
+    },
+    UnfreezeTopic {
+        stream_id: u32,
+        topic_id: u32,
+    },
+    CreatePartitions {
+        stream_id: u32,
+        topic_id: u32,
+        current_partitions_count: u32,
+        partitions_count: u32,
+        #[serde(default)]
+        repartition: bool,
+    },
+    DeletePartitions {
+        stream_id: u32,
+        topic_id: u32,
+        current_partitions_count: u32,
+        partitions_count: u32,
+        #[serde(default)]
+        mode: DeletePartitionsMode,
+    },
+    CreateUser {
+        user: Box<User>,
+    },
+    UpdateUser {
+        user: Box<User>,
+    },
+    DeleteUser {
+        user_id: UserId,
+    },
+    UpdatePermissions {
+        user_id: UserId,
+        permissions: Option<Permissions>,
+    },
+    ChangePassword {
+        user_id: UserId,
+        password: String,
+    },
+    CreateUserGroup {
+        group_id: u32,
+        name: String,
+        permissions: Option<Permissions>,
+    },
+    UpdateUserGroupPermissions {
+        group_id: u32,
+        permissions: Option<Permissions>,
+    },
+    DeleteUserGroup {
+        group_id: u32,
+    },
+    AddUserGroupMember {
+        group_id: u32,
+        user_id: UserId,
+    },
+    RemoveUserGroupMember {
+        group_id: u32,
+        user_id: UserId,
+    },
+}
+
+/// The record stored in the metadata log, either the mutation itself or the marker of its completion.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum MetadataRecord {
+    Entry { id: u64, entry: MetadataEntry },
+    Completed { id: u64 },
+}
+
+impl Display for MetadataEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetadataEntry::CreateStream {
+                stream_id, name, ..
+            } => {
+                write!(f, "create stream with ID: {stream_id}, name: {name}")
+            }
+            MetadataEntry::UpdateStream {
+                stream_id, name, ..
+            } => {
+                write!(f, "update stream with ID: {stream_id}, name: {name}")
+            }
+            MetadataEntry::DeleteStream { stream_id, soft } => match soft {
+                true => write!(f, "soft delete stream with ID: {stream_id}"),
+                false => write!(f, "delete stream with ID: {stream_id}"),
+            },
+            MetadataEntry::UndeleteStream { stream_id } => {
+                write!(f, "undelete stream with ID: {stream_id}")
+            }
+            MetadataEntry::CreateTopic {
+                stream_id, name, ..
+            } => write!(f, "create topic: {name} for stream with ID: {stream_id}"),
+            MetadataEntry::UpdateTopic {
+                stream_id,
+                topic_id,
+                ..
+            } => write!(
+                f,
+                "update topic with ID: {topic_id} for stream with ID: {stream_id}"
+            ),
+            MetadataEntry::DeleteTopic {
+                stream_id,
+                topic_id,
+                soft,
+            } => match soft {
+                true => write!(
+                    f,
+                    "soft delete topic with ID: {topic_id} for stream with ID: {stream_id}"
+                ),
+                false => write!(
+                    f,
+                    "delete topic with ID: {topic_id} for stream with ID: {stream_id}"
+                ),
+            },
+            MetadataEntry::UndeleteTopic {
+                stream_id,
+                topic_id,
+            } => write!(
+                f,
+                "undelete topic with ID: {topic_id} for stream with ID: {stream_id}"
+            ),
+            MetadataEntry::FreezeTopic {
+                stream_id,
+                topic_id,
+            } => write!(
+                f,
+                "freeze topic with ID: {topic_id} for stream with ID: {stream_id}"
+            ),
+            MetadataEntry::UnfreezeTopic {
+                stream_id,
+                topic_id,
+            } => write!(
+                f,
+                "unfreeze topic with ID: {topic_id} for stream with ID: {stream_id}"
+            ),
+            MetadataEntry::CreatePartitions {
+                stream_id,
+                topic_id,
+                partitions_count,
+                ..
+            } => write!(
+                f,
+                "create {partitions_count} partition(s) for topic with ID: {topic_id} for stream with ID: {stream_id}"
+            ),
+            MetadataEntry::DeletePartitions {
+                stream_id,
+                topic_id,
+                partitions_count,
+                ..
+            } => write!(
+                f,
+                "delete {partitions_count} partition(s) for topic with ID: {topic_id} for stream with ID: {stream_id}"
+            ),
+            MetadataEntry::CreateUser { user } => {
+                write!(f, "create user with ID: {}", user.id)
+            }
+            MetadataEntry::UpdateUser { user } => {
+                write!(f, "update user with ID: {}", user.id)
+            }
+            MetadataEntry::DeleteUser { user_id } => write!(f, "delete user with ID: {user_id}"),
+            MetadataEntry::UpdatePermissions { user_id, .. } => {
+                write!(f, "update permissions for user with ID: {user_id}")
+            }
+            MetadataEntry::ChangePassword { user_id, .. } => {
+                write!(f, "change password for user with ID: {user_id}")
+            }
+            MetadataEntry::CreateUserGroup { group_id, name, .. } => {
+                write!(f, "create user group with ID: {group_id}, name: {name}")
+            }
+            MetadataEntry::UpdateUserGroupPermissions { group_id, .. } => {
+                write!(f, "update permissions for user group with ID: {group_id}")
+            }
+            MetadataEntry::DeleteUserGroup { group_id } => {
+                write!(f, "delete user group with ID: {group_id}")
+            }
+            MetadataEntry::AddUserGroupMember { group_id, user_id } => write!(
+                f,
+                "add user with ID: {user_id} to user group with ID: {group_id}"
+            ),
+            MetadataEntry::RemoveUserGroupMember { group_id, user_id } => write!(
+                f,
+                "remove user with ID: {user_id} from user group with ID: {group_id}"
+            ),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::configs::system::SystemConfig;
+use crate::streaming::metadata_log::entry::{MetadataEntry, MetadataRecord};
+use crate::streaming::storage::MetadataLogStorage;
+use iggy::error::IggyError;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tracing::trace;
+
+/// The append-only log of the metadata mutations (streams, topics, partitions, users and permissions).
+/// Each mutation is appended before it's applied and marked as completed afterwards,
+/// thus the entries which weren't completed (e.g. due to the crash) can be replayed on startup.
+#[derive(Debug)]
+pub struct MetadataLog {
+    enabled: bool,
+    path: String,
+    current_id: AtomicU64,
+    storage: Arc<dyn MetadataLogStorage>,
+}
+
+impl MetadataLog {
+    pub fn new(config: &SystemConfig, storage: Arc<dyn MetadataLogStorage>) -> Self {
+        Self {
+            enabled: config.metadata_log.enabled,
+            path: config.get_metadata_log_path(),
+            current_id: AtomicU64::new(0),
+            storage,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Appends the entry to the log and returns its ID, which should be used to complete the entry once it's applied.
+    pub async fn append(&self, entry: MetadataEntry) -> Result<u64, IggyError> {
+        if !self.enabled {
+            return Ok(0);
+        }
+
+        let id = self.current_id.fetch_add(1, Ordering::SeqCst) + 1;
+        trace!("Appending metadata log entry with ID: {id}, {entry}");
+        self.storage
+            .append(&self.path, &MetadataRecord::Entry { id, entry })
+            .await?;
+        Ok(id)
+    }
+
+    /// Marks the entry as completed, regardless of whether it was applied successfully or rejected.
+    pub async fn complete(&self, id: u64) -> Result<(), IggyError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        trace!("Completing metadata log entry with ID: {id}");
+        self.storage
+            .append(&self.path, &MetadataRecord::Completed { id })
+            .await
+    }
+
+    /// Loads the entries which were appended, but never completed, in the original order.
+    pub async fn load_unfinished(&self) -> Result<Vec<MetadataEntry>, IggyError> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+
+        let records = self.storage.load_all(&self.path).await?;
+        let last_id = records
+            .iter()
+            .map(|record| match record {
+                MetadataRecord::Entry { id, .. } => *id,
+                MetadataRecord::Completed { id } => *id,
+            })
+            .max()
+            .unwrap_or(0);
+        // The replayed entries are appended to the same log, so their IDs must not collide with the loaded ones.
+        self.current_id.store(last_id, Ordering::SeqCst);
+        Ok(get_unfinished_entries(records))
+    }
+
+    /// Removes all the entries from the log, should be called only once the unfinished entries are replayed.
+    pub async fn truncate(&self) -> Result<(), IggyError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        self.storage.truncate(&self.path).await?;
+        self.current_id.store(0, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+fn get_unfinished_entries(records: Vec<MetadataRecord>) -> Vec<MetadataEntry> {
+    let completed_ids = records
+        .iter()
+        .filter_map(|record| match record {
+            MetadataRecord::Completed { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<HashSet<u64>>();
+
+    records
+        .into_iter()
+        .filter_map(|record| match record {
+            MetadataRecord::Entry { id, entry } if !completed_ids.contains(&id) => Some(entry),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::topic_defaults::TopicDefaults;
+
+    #[test]
+    fn should_return_only_unfinished_entries_in_order() {
+        let records = vec![
+            MetadataRecord::Entry {
+                id: 1,
+                entry: MetadataEntry::CreateStream {
+                    stream_id: 1,
+                    name: "test-1".to_string(),
+                    tenant_id: None,
+                    topic_defaults: TopicDefaults::default(),
+                },
+            },
+            MetadataRecord::Completed { id: 1 },
+            MetadataRecord::Entry {
+                id: 2,
+                entry: MetadataEntry::DeleteStream {
+                    stream_id: 2,
+                    soft: false,
+                },
+            },
+            MetadataRecord::Entry {
+                id: 3,
+                entry: MetadataEntry::UpdateStream {
+                    stream_id: 3,
+                    name: "test-3".to_string(),
+                    topic_defaults: None,
+                },
+            },
+        ];
+
+        let entries = get_unfinished_entries(records);
+
+        assert_eq!(entries.len(), 2);
+        assert!(matches!(
+            entries[0],
+            MetadataEntry::DeleteStream { stream_id: 2, .. }
+        ));
+        assert!(matches!(
+            entries[1],
+            MetadataEntry::UpdateStream { stream_id: 3, .. }
+        ));
+    }
+}
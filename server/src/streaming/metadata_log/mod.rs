@@ -0,0 +1,3 @@
+pub mod entry;
+pub mod log;
+pub mod storage;
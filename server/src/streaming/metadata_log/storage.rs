@@ -0,0 +1,168 @@
+use crate::streaming::metadata_log::entry::MetadataRecord;
+use crate::streaming::persistence::persister::Persister;
+use crate::streaming::storage::MetadataLogStorage;
+use anyhow::Context;
+use async_trait::async_trait;
+use bytes::{BufMut, BytesMut};
+use iggy::error::IggyError;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::fs;
+use tracing::warn;
+
+const LENGTH_SIZE: usize = 4;
+const HEADER_MAGIC: &[u8; 4] = b"IGML";
+pub const HEADER_SIZE: usize = 8;
+pub const METADATA_LOG_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug)]
+pub struct FileMetadataLogStorage {
+    persister: Arc<dyn Persister>,
+}
+
+impl FileMetadataLogStorage {
+    pub fn new(persister: Arc<dyn Persister>) -> Self {
+        Self { persister }
+    }
+}
+
+unsafe impl Send for FileMetadataLogStorage {}
+unsafe impl Sync for FileMetadataLogStorage {}
+
+#[async_trait]
+impl MetadataLogStorage for FileMetadataLogStorage {
+    async fn append(&self, path: &str, record: &MetadataRecord) -> Result<(), IggyError> {
+        let data = match rmp_serde::to_vec(record)
+            .with_context(|| format!("Failed to serialize metadata log record: {:?}", record))
+        {
+            Ok(data) => data,
+            Err(err) => {
+                return Err(IggyError::CannotSerializeResource(err));
+            }
+        };
+
+        let mut bytes = BytesMut::with_capacity(LENGTH_SIZE + data.len());
+        bytes.put_u32_le(data.len() as u32);
+        bytes.put_slice(&data);
+        if !Path::new(path).exists() {
+            self.persister.overwrite(path, &header()).await?;
+        }
+
+        self.persister.append(path, &bytes).await
+    }
+
+    async fn load_all(&self, path: &str) -> Result<Vec<MetadataRecord>, IggyError> {
+        if !Path::new(path).exists() {
+            return Ok(Vec::new());
+        }
+
+        let bytes = match fs::read(path)
+            .await
+            .with_context(|| format!("Failed to read metadata log: {}", path))
+        {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                return Err(IggyError::CannotLoadResource(err));
+            }
+        };
+
+        if bytes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        match read_format_version(&bytes) {
+            Some(METADATA_LOG_FORMAT_VERSION) => {}
+            Some(version) if version > METADATA_LOG_FORMAT_VERSION => {
+                return Err(IggyError::UnsupportedStorageFormat(
+                    version,
+                    METADATA_LOG_FORMAT_VERSION,
+                ));
+            }
+            Some(version) => {
+                return Err(IggyError::StorageMigrationRequired(
+                    version,
+                    METADATA_LOG_FORMAT_VERSION,
+                ));
+            }
+            None => {
+                return Err(IggyError::StorageMigrationRequired(
+                    0,
+                    METADATA_LOG_FORMAT_VERSION,
+                ));
+            }
+        }
+
+        let mut records = Vec::new();
+        let mut position = HEADER_SIZE;
+        while position + LENGTH_SIZE <= bytes.len() {
+            let length =
+                u32::from_le_bytes(bytes[position..position + LENGTH_SIZE].try_into()?) as usize;
+            position += LENGTH_SIZE;
+            if position + length > bytes.len() {
+                // The last record might have been only partially written, e.g. due to the crash.
+                warn!("Skipping the incomplete metadata log record at position: {position}.");
+                break;
+            }
+
+            let record =
+                match rmp_serde::from_slice::<MetadataRecord>(&bytes[position..position + length])
+                    .with_context(|| {
+                        format!("Failed to deserialize metadata log record at position: {position}")
+                    }) {
+                    Ok(record) => record,
+                    Err(err) => {
+                        return Err(IggyError::CannotDeserializeResource(err));
+                    }
+                };
+            records.push(record);
+            position += length;
+        }
+
+        Ok(records)
+    }
+
+    async fn truncate(&self, path: &str) -> Result<(), IggyError> {
+        if !Path::new(path).exists() {
+            return Ok(());
+        }
+
+        self.persister.delete(path).await?;
+        self.persister.overwrite(path, &header()).await
+    }
+}
+
+/// Returns the header written at the beginning of the metadata log file, the magic bytes followed by the format version.
+pub fn header() -> [u8; HEADER_SIZE] {
+    let mut header = [0; HEADER_SIZE];
+    header[..4].copy_from_slice(HEADER_MAGIC);
+    header[4..].copy_from_slice(&METADATA_LOG_FORMAT_VERSION.to_le_bytes());
+    header
+}
+
+/// Reads the format version from the header, returns `None` if the file doesn't start with the header.
+pub fn read_format_version(bytes: &[u8]) -> Option<u32> {
+    if bytes.len() < HEADER_SIZE || &bytes[..4] != HEADER_MAGIC {
+        return None;
+    }
+
+    Some(u32::from_le_bytes(bytes[4..HEADER_SIZE].try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_should_contain_current_format_version() {
+        assert_eq!(
+            read_format_version(&header()),
+            Some(METADATA_LOG_FORMAT_VERSION)
+        );
+    }
+
+    #[test]
+    fn format_version_should_not_be_read_without_header() {
+        assert_eq!(read_format_version(&[]), None);
+        assert_eq!(read_format_version(&[1, 0, 0, 0, 2, 3, 4, 5]), None);
+    }
+}
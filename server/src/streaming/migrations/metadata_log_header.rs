@@ -0,0 +1,66 @@
+use crate::configs::system::SystemConfig;
+use crate::streaming::metadata_log::storage::{header, read_format_version};
+use crate::streaming::migrations::migration::StorageMigration;
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use std::path::Path;
+use tokio::fs;
+use tracing::info;
+
+/// Prepends the format version header to the metadata log written without one.
+#[derive(Debug)]
+pub struct MetadataLogHeaderMigration;
+
+#[async_trait]
+impl StorageMigration for MetadataLogHeaderMigration {
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn name(&self) -> &'static str {
+        "metadata_log_header"
+    }
+
+    async fn get_modified_files(&self, config: &SystemConfig) -> Result<Vec<String>, IggyError> {
+        let path = config.get_metadata_log_path();
+        if !Path::new(&path).exists() {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![path])
+    }
+
+    async fn migrate(&self, config: &SystemConfig) -> Result<(), IggyError> {
+        let path = config.get_metadata_log_path();
+        if !Path::new(&path).exists() {
+            info!("Metadata log: {path} doesn't exist, nothing to migrate.");
+            return Ok(());
+        }
+
+        let bytes = match fs::read(&path)
+            .await
+            .with_context(|| format!("Failed to read metadata log: {path}"))
+        {
+            Ok(bytes) => bytes,
+            Err(err) => return Err(IggyError::CannotLoadResource(err)),
+        };
+
+        if read_format_version(&bytes).is_some() {
+            info!("Metadata log: {path} already contains the header.");
+            return Ok(());
+        }
+
+        let mut migrated_bytes = header().to_vec();
+        migrated_bytes.extend_from_slice(&bytes);
+        if let Err(err) = fs::write(&path, migrated_bytes)
+            .await
+            .with_context(|| format!("Failed to write metadata log: {path}"))
+        {
+            return Err(IggyError::CannotSaveResource(err));
+        }
+
+        info!("Added the header to metadata log: {path}.");
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::configs::system::SystemConfig;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use std::fmt::Debug;
+
+/// The single step upgrading the on-disk data to the next storage format version.
+/// The migrations are applied in the order of their versions, each one only once.
+#[async_trait]
+pub trait StorageMigration: Send + Sync + Debug {
+    /// The storage format version, which the data is upgraded to.
+    fn version(&self) -> u32;
+
+    fn name(&self) -> &'static str;
+
+    /// Returns the paths of the files, which are modified by the migration, so that only these are backed up before applying it.
+    async fn get_modified_files(&self, config: &SystemConfig) -> Result<Vec<String>, IggyError>;
+
+    async fn migrate(&self, config: &SystemConfig) -> Result<(), IggyError>;
+}
@@ -0,0 +1,282 @@
+use crate::configs::system::SystemConfig;
+use crate::streaming::migrations::metadata_log_header::MetadataLogHeaderMigration;
+use crate::streaming::migrations::migration::StorageMigration;
+use crate::streaming::migrations::segment_headers::SegmentHeadersMigration;
+use crate::streaming::storage::SystemInfoStorage;
+use crate::streaming::systems::info::{Migration, SystemInfo};
+use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::fs;
+use tracing::{error, info, warn};
+
+/// The storage format version of the data written by the current server version.
+pub const CURRENT_FORMAT_VERSION: u32 = 2;
+
+const INCOMPLETE_BACKUP_SUFFIX: &str = ".incomplete";
+const BACKUP_MANIFEST_FILE: &str = "manifest";
+
+/// Applies the pending storage migrations in order. Before each migration, the files it modifies
+/// are backed up (if enabled) and restored in case the migration fails or the server stops in the middle of it.
+#[derive(Debug)]
+pub struct Migrator {
+    config: Arc<SystemConfig>,
+    migrations: Vec<Box<dyn StorageMigration>>,
+}
+
+impl Migrator {
+    pub fn new(config: Arc<SystemConfig>) -> Self {
+        Self::with_migrations(
+            config,
+            vec![
+                Box::new(MetadataLogHeaderMigration),
+                Box::new(SegmentHeadersMigration),
+            ],
+        )
+    }
+
+    pub fn with_migrations(
+        config: Arc<SystemConfig>,
+        mut migrations: Vec<Box<dyn StorageMigration>>,
+    ) -> Self {
+        migrations.sort_by_key(|migration| migration.version());
+        Self { config, migrations }
+    }
+
+    pub fn get_pending_migrations(&self, format_version: u32) -> Vec<&dyn StorageMigration> {
+        self.migrations
+            .iter()
+            .filter(|migration| migration.version() > format_version)
+            .map(|migration| migration.as_ref())
+            .collect()
+    }
+
+    /// Applies the pending migrations and saves the system info after each one, returns the number of applied migrations.
+    pub async fn migrate(
+        &self,
+        system_info: &mut SystemInfo,
+        storage: &dyn SystemInfoStorage,
+    ) -> Result<usize, IggyError> {
+        self.remove_stale_backups(system_info.format_version).await;
+        let pending_migrations = self.get_pending_migrations(system_info.format_version);
+        let migrations_count = pending_migrations.len();
+        for (index, migration) in pending_migrations.into_iter().enumerate() {
+            let version = migration.version();
+            info!(
+                "Applying storage migration {}/{migrations_count}: {} to format version: {version}...",
+                index + 1,
+                migration.name()
+            );
+            let backup_path = self.get_backup_path(version);
+            if Path::new(&backup_path).exists() {
+                warn!("Found the backup for format version: {version} left by the interrupted migration, restoring...");
+                self.restore_backup(version, &backup_path).await?;
+            }
+
+            if self.config.migration.backup {
+                self.create_backup(migration, &backup_path).await?;
+            }
+
+            if let Err(err) = migration.migrate(&self.config).await {
+                error!("Storage migration to format version: {version} has failed. Error: {err}");
+                if self.config.migration.backup {
+                    self.restore_backup(version, &backup_path).await?;
+                    self.remove_backup(&backup_path).await;
+                }
+                return Err(IggyError::CannotApplyStorageMigration(
+                    version,
+                    err.to_string(),
+                ));
+            }
+
+            system_info.format_version = version;
+            system_info.migrations.push(Migration {
+                id: version,
+                name: migration.name().to_string(),
+                hash: hash_name(migration.name()),
+                applied_at: IggyTimestamp::now().to_micros(),
+            });
+            storage.save(system_info).await?;
+            if self.config.migration.backup {
+                self.remove_backup(&backup_path).await;
+            }
+            info!(
+                "Applied storage migration {}/{migrations_count}: {} to format version: {version}.",
+                index + 1,
+                migration.name()
+            );
+        }
+
+        Ok(migrations_count)
+    }
+
+    fn get_backup_path(&self, version: u32) -> String {
+        format!("{}/{version}", self.config.get_migration_backup_path())
+    }
+
+    /// Copies the files modified by the migration to the backup directory, along with the manifest of their original paths.
+    async fn create_backup(
+        &self,
+        migration: &dyn StorageMigration,
+        backup_path: &str,
+    ) -> Result<(), IggyError> {
+        let version = migration.version();
+        info!("Creating the backup for format version: {version} at: {backup_path}...");
+        let files = migration.get_modified_files(&self.config).await?;
+        // The backup is written under the temporary name first, so that the partially created one is never restored.
+        let incomplete_backup_path = format!("{backup_path}{INCOMPLETE_BACKUP_SUFFIX}");
+        let result = async {
+            if Path::new(&incomplete_backup_path).exists() {
+                fs::remove_dir_all(&incomplete_backup_path).await?;
+            }
+
+            fs::create_dir_all(&incomplete_backup_path).await?;
+            for (index, path) in files.iter().enumerate() {
+                fs::copy(path, format!("{incomplete_backup_path}/{index}")).await?;
+            }
+
+            fs::write(
+                format!("{incomplete_backup_path}/{BACKUP_MANIFEST_FILE}"),
+                files.join("\n"),
+            )
+            .await?;
+            fs::rename(&incomplete_backup_path, backup_path).await
+        }
+        .await;
+
+        if let Err(err) = result {
+            error!("Cannot create the backup for format version: {version}. Error: {err}");
+            return Err(IggyError::CannotApplyStorageMigration(
+                version,
+                err.to_string(),
+            ));
+        }
+
+        info!(
+            "Created the backup of {} file(s) for format version: {version}.",
+            files.len()
+        );
+        Ok(())
+    }
+
+    /// Copies the backed up files back to their original paths listed in the manifest.
+    async fn restore_backup(&self, version: u32, backup_path: &str) -> Result<(), IggyError> {
+        info!("Restoring the backup for format version: {version} from: {backup_path}...");
+        let result = async {
+            let manifest =
+                fs::read_to_string(format!("{backup_path}/{BACKUP_MANIFEST_FILE}")).await?;
+            for (index, path) in manifest.lines().enumerate() {
+                fs::copy(format!("{backup_path}/{index}"), path).await?;
+            }
+
+            Ok::<(), std::io::Error>(())
+        }
+        .await;
+
+        if let Err(err) = result {
+            error!("Cannot restore the backup for format version: {version}. Error: {err}");
+            return Err(IggyError::CannotApplyStorageMigration(
+                version,
+                err.to_string(),
+            ));
+        }
+
+        info!("Restored the backup for format version: {version}.");
+        Ok(())
+    }
+
+    async fn remove_backup(&self, backup_path: &str) {
+        if let Err(err) = fs::remove_dir_all(backup_path).await {
+            warn!("Cannot remove the migration backup at: {backup_path}. Error: {err}");
+        }
+    }
+
+    /// Removes the incomplete backups and the ones left after the already applied migrations.
+    async fn remove_stale_backups(&self, format_version: u32) {
+        let backups_path = self.config.get_migration_backup_path();
+        let Ok(mut dir_entries) = fs::read_dir(&backups_path).await else {
+            return;
+        };
+
+        while let Ok(Some(dir_entry)) = dir_entries.next_entry().await {
+            let name = dir_entry.file_name().into_string().unwrap_or_default();
+            let is_stale = name.ends_with(INCOMPLETE_BACKUP_SUFFIX)
+                || name
+                    .parse::<u32>()
+                    .is_ok_and(|version| version <= format_version);
+            if is_stale {
+                self.remove_backup(&dir_entry.path().to_string_lossy())
+                    .await;
+            }
+        }
+    }
+}
+
+fn hash_name(name: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    name.hash(&mut hasher);
+    hasher.finish().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    #[derive(Debug)]
+    struct TestMigration(u32);
+
+    #[async_trait]
+    impl StorageMigration for TestMigration {
+        fn version(&self) -> u32 {
+            self.0
+        }
+
+        fn name(&self) -> &'static str {
+            "test"
+        }
+
+        async fn get_modified_files(
+            &self,
+            _config: &SystemConfig,
+        ) -> Result<Vec<String>, IggyError> {
+            Ok(Vec::new())
+        }
+
+        async fn migrate(&self, _config: &SystemConfig) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn should_return_pending_migrations_in_order() {
+        let migrator = Migrator::with_migrations(
+            Arc::new(SystemConfig::default()),
+            vec![
+                Box::new(TestMigration(3)),
+                Box::new(TestMigration(1)),
+                Box::new(TestMigration(2)),
+            ],
+        );
+
+        let pending_versions = migrator
+            .get_pending_migrations(1)
+            .iter()
+            .map(|migration| migration.version())
+            .collect::<Vec<_>>();
+
+        assert_eq!(pending_versions, vec![2, 3]);
+    }
+
+    #[test]
+    fn should_not_return_any_pending_migrations_for_current_format_version() {
+        let migrator = Migrator::new(Arc::new(SystemConfig::default()));
+
+        assert!(migrator
+            .get_pending_migrations(CURRENT_FORMAT_VERSION)
+            .is_empty());
+    }
+}
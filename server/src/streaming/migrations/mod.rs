@@ -0,0 +1,4 @@
+pub mod metadata_log_header;
+pub mod migration;
+pub mod migrator;
+pub mod segment_headers;
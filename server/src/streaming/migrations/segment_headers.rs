@@ -0,0 +1,111 @@
+use crate::configs::system::SystemConfig;
+use crate::streaming::migrations::migration::StorageMigration;
+use crate::streaming::segments::storage::{read_segment_format_version, SegmentFileKind};
+use crate::streaming::utils::file;
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use std::path::PathBuf;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tracing::info;
+
+const MIGRATED_FILE_SUFFIX: &str = ".migrated";
+
+/// Prepends the format version header to the segment log, index and time index files written without one,
+/// including the ones of the soft deleted streams and topics kept in the trash.
+#[derive(Debug)]
+pub struct SegmentHeadersMigration;
+
+impl SegmentHeadersMigration {
+    async fn find_files_without_header(
+        config: &SystemConfig,
+    ) -> Result<Vec<(String, SegmentFileKind)>, IggyError> {
+        let mut files = Vec::new();
+        let mut directories = vec![
+            PathBuf::from(config.get_streams_path()),
+            PathBuf::from(config.get_trash_path()),
+        ];
+        while let Some(directory) = directories.pop() {
+            if !directory.exists() {
+                continue;
+            }
+
+            let mut dir_entries = fs::read_dir(&directory).await?;
+            while let Some(dir_entry) = dir_entries.next_entry().await? {
+                let path = dir_entry.path();
+                if dir_entry.metadata().await?.is_dir() {
+                    directories.push(path);
+                    continue;
+                }
+
+                let Some(kind) = path
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .and_then(SegmentFileKind::from_extension)
+                else {
+                    continue;
+                };
+
+                let path = path.to_string_lossy().to_string();
+                if read_segment_format_version(&path, kind).await?.is_none() {
+                    files.push((path, kind));
+                }
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Writes the header followed by the content of the file to the temporary one, which then replaces the original file,
+    /// so that the interrupted migration never leaves the partially written file behind (the temporary file is skipped,
+    /// as its extension doesn't match any of the segment files).
+    async fn prepend_header(path: &str, kind: SegmentFileKind) -> Result<(), std::io::Error> {
+        let migrated_path = format!("{path}{MIGRATED_FILE_SUFFIX}");
+        let mut migrated_file = file::overwrite(&migrated_path).await?;
+        migrated_file.write_all(&kind.header()).await?;
+        let mut original_file = file::open(path).await?;
+        tokio::io::copy(&mut original_file, &mut migrated_file).await?;
+        migrated_file.sync_all().await?;
+        fs::rename(&migrated_path, path).await
+    }
+}
+
+#[async_trait]
+impl StorageMigration for SegmentHeadersMigration {
+    fn version(&self) -> u32 {
+        2
+    }
+
+    fn name(&self) -> &'static str {
+        "segment_headers"
+    }
+
+    async fn get_modified_files(&self, config: &SystemConfig) -> Result<Vec<String>, IggyError> {
+        Ok(Self::find_files_without_header(config)
+            .await?
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect())
+    }
+
+    async fn migrate(&self, config: &SystemConfig) -> Result<(), IggyError> {
+        let files = Self::find_files_without_header(config).await?;
+        if files.is_empty() {
+            info!("All the segment files already contain the header.");
+            return Ok(());
+        }
+
+        for (path, kind) in &files {
+            if let Err(err) = Self::prepend_header(path, *kind)
+                .await
+                .with_context(|| format!("Failed to add the header to segment file: {path}"))
+            {
+                return Err(IggyError::CannotSaveResource(err));
+            }
+        }
+
+        info!("Added the header to {} segment file(s).", files.len());
+        Ok(())
+    }
+}
@@ -2,16 +2,25 @@ pub mod cache;
 pub mod clients;
 mod deduplication;
 pub mod diagnostics;
+pub mod events;
+pub mod memory_storage;
+pub mod metadata_log;
+pub mod migrations;
 pub mod models;
 pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod schemas;
 pub mod segments;
 pub mod session;
 pub mod storage;
 pub mod streams;
+pub mod system_headers;
 pub mod systems;
+pub mod tenants;
 pub mod topics;
+pub mod trash;
+pub mod user_groups;
 pub mod users;
 pub mod utils;
@@ -1,5 +1,7 @@
-use iggy::models::messages::Message;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::{Message, MessageState};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 // It's the same as PolledMessages from Iggy models, but with the Arc<Message> instead of Message.
@@ -8,4 +10,129 @@ pub struct PolledMessages {
     pub partition_id: u32,
     pub current_offset: u64,
     pub messages: Vec<Arc<Message>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u64>,
+}
+
+impl PolledMessages {
+    /// Leaves out the messages exceeding the max bytes, never splitting the message, and always keeping the first one,
+    /// so the consumer can make progress even if a single message is larger than the limit.
+    /// Returns true if any messages have been left out, the offset of the first one is then set as the next offset.
+    pub fn truncate_to_max_bytes(&mut self, max_bytes: u32) -> bool {
+        let mut size_bytes = 0u64;
+        let Some(count) = self.messages.iter().position(|message| {
+            size_bytes += message.get_size_bytes() as u64;
+            size_bytes > max_bytes as u64
+        }) else {
+            return false;
+        };
+
+        let count = count.max(1);
+        if count == self.messages.len() {
+            return false;
+        }
+
+        self.next_offset = Some(self.messages[count].offset);
+        self.messages.truncate(count);
+        true
+    }
+}
+
+// It's the same as FetchedMessages from Iggy models, but with the Arc<Message> instead of Message.
+#[derive(Debug)]
+pub struct FetchedMessages {
+    pub session_id: u32,
+    pub epoch: u32,
+    pub partitions: Vec<FetchedPartition>,
+}
+
+#[derive(Debug)]
+pub struct FetchedPartition {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub messages: PolledMessages,
+}
+
+// It's the same as PolledMessages, but with the payloads decoded into the structured JSON.
+#[derive(Debug, Serialize)]
+pub struct DecodedPolledMessages {
+    pub partition_id: u32,
+    pub current_offset: u64,
+    pub messages: Vec<DecodedMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DecodedMessage {
+    pub offset: u64,
+    pub state: MessageState,
+    pub timestamp: u64,
+    pub id: u128,
+    pub checksum: u32,
+    pub headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    /// The decoded payload, or the raw one encoded in Base64 if it couldn't be decoded.
+    pub payload: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decoding_error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+
+    #[test]
+    fn messages_exceeding_max_bytes_should_be_left_out() {
+        // Each message takes 52 bytes, 45 bytes of the metadata and 7 bytes of the payload.
+        let mut polled_messages = create_polled_messages(5);
+
+        assert!(polled_messages.truncate_to_max_bytes(110));
+
+        assert_eq!(polled_messages.messages.len(), 2);
+        assert_eq!(polled_messages.next_offset, Some(2));
+    }
+
+    #[test]
+    fn first_message_should_be_kept_even_if_it_exceeds_max_bytes() {
+        let mut polled_messages = create_polled_messages(5);
+
+        assert!(polled_messages.truncate_to_max_bytes(10));
+
+        assert_eq!(polled_messages.messages.len(), 1);
+        assert_eq!(polled_messages.next_offset, Some(1));
+    }
+
+    #[test]
+    fn messages_within_max_bytes_should_not_be_truncated() {
+        let mut polled_messages = create_polled_messages(5);
+
+        assert!(!polled_messages.truncate_to_max_bytes(260));
+
+        assert_eq!(polled_messages.messages.len(), 5);
+        assert!(polled_messages.next_offset.is_none());
+    }
+
+    fn create_polled_messages(count: u64) -> PolledMessages {
+        let messages = (0..count)
+            .map(|offset| {
+                let payload = Bytes::from("message");
+                Arc::new(Message::create(
+                    offset,
+                    MessageState::Available,
+                    1,
+                    offset as u128,
+                    payload.clone(),
+                    iggy::utils::checksum::calculate(&payload),
+                    None,
+                ))
+            })
+            .collect();
+        PolledMessages {
+            partition_id: 1,
+            current_offset: count - 1,
+            messages,
+            next_offset: None,
+        }
+    }
 }
@@ -0,0 +1,294 @@
+use crate::streaming::utils::varint;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::IggyError;
+use iggy::models::header;
+use iggy::models::messages::{Message, MessageState};
+use iggy::sizeable::Sizeable;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The value written in place of the message offset at the beginning of the batch. As the offset of the message
+/// never reaches `u64::MAX`, it allows to tell the batches apart from the messages stored one by one (format v1),
+/// thus the segments written by the previous server versions can still be read.
+pub const BATCH_MARKER: u64 = u64::MAX;
+/// The format version of the batches written by the current server version.
+pub const BATCH_FORMAT_VERSION: u8 = 2;
+/// Marker + Version + Length
+pub const BATCH_PREFIX_SIZE: u32 = 8 + 1 + 4;
+/// Base offset + Base timestamp + Max timestamp + Producer ID + Producer epoch + Messages count
+const BATCH_HEADER_SIZE: u32 = 8 + 8 + 8 + 8 + 2 + 4;
+/// Offset delta + Timestamp delta + State + ID + Checksum + Headers length + Payload length, with the single byte varints.
+const MIN_ENCODED_MESSAGE_SIZE: usize = 1 + 1 + 1 + 16 + 4 + 1 + 1;
+
+/// The messages appended to the segment at once, stored on disk (format v2) as a single header followed by the messages,
+/// whose offsets and timestamps are varint-encoded deltas to the base ones, as well as the headers and payload lengths.
+/// The batch format:
+/// - `marker` - 8 bytes, always `BATCH_MARKER`
+/// - `version` - 1 byte
+/// - `length` - 4 bytes, the length of the remaining part of the batch
+/// - `base_offset` - 8 bytes
+/// - `base_timestamp` - 8 bytes
+/// - `max_timestamp` - 8 bytes
+/// - `producer_id` - 8 bytes, reserved, always 0 as the messages aren't tracked per producer yet
+/// - `producer_epoch` - 2 bytes, reserved, always 0 as the messages aren't tracked per producer yet
+/// - `messages_count` - 4 bytes
+/// - `messages` - for each message: offset delta (varint), timestamp delta (zigzag varint), state (1 byte), ID (16 bytes),
+///   checksum (4 bytes), headers length (varint), headers, payload length (varint), payload.
+#[derive(Debug)]
+pub struct MessagesBatch {
+    pub base_offset: u64,
+    pub base_timestamp: u64,
+    pub max_timestamp: u64,
+    /// Reserved for the identifier of the producer which has sent the messages, always 0 for now.
+    pub producer_id: u64,
+    /// Reserved for the epoch of the producer which has sent the messages, always 0 for now.
+    pub producer_epoch: u16,
+    pub messages: Vec<Arc<Message>>,
+}
+
+impl MessagesBatch {
+    pub fn new(messages: Vec<Arc<Message>>) -> Self {
+        let base_offset = messages.first().map_or(0, |message| message.offset);
+        let base_timestamp = messages.first().map_or(0, |message| message.timestamp);
+        let max_timestamp = messages
+            .iter()
+            .map(|message| message.timestamp)
+            .max()
+            .unwrap_or(0);
+        Self {
+            base_offset,
+            base_timestamp,
+            max_timestamp,
+            producer_id: 0,
+            producer_epoch: 0,
+            messages,
+        }
+    }
+
+    pub fn get_last_offset(&self) -> u64 {
+        self.messages
+            .last()
+            .map_or(self.base_offset, |message| message.offset)
+    }
+
+    /// Extends the provided bytes with the batch.
+    pub fn extend(&self, bytes: &mut BytesMut) {
+        bytes.put_u64_le(BATCH_MARKER);
+        bytes.put_u8(BATCH_FORMAT_VERSION);
+        bytes.put_u32_le(self.get_size_bytes() - BATCH_PREFIX_SIZE);
+        bytes.put_u64_le(self.base_offset);
+        bytes.put_u64_le(self.base_timestamp);
+        bytes.put_u64_le(self.max_timestamp);
+        bytes.put_u64_le(self.producer_id);
+        bytes.put_u16_le(self.producer_epoch);
+        bytes.put_u32_le(self.messages.len() as u32);
+        for message in &self.messages {
+            varint::put_u64(bytes, message.offset - self.base_offset);
+            varint::put_i64(bytes, message.timestamp as i64 - self.base_timestamp as i64);
+            bytes.put_u8(message.state.as_code());
+            bytes.put_u128_le(message.id);
+            bytes.put_u32_le(message.checksum);
+            match &message.headers {
+                Some(headers) => {
+                    let headers_bytes = headers.as_bytes();
+                    varint::put_u64(bytes, headers_bytes.len() as u64);
+                    bytes.put_slice(&headers_bytes);
+                }
+                None => varint::put_u64(bytes, 0),
+            }
+            varint::put_u64(bytes, message.payload.len() as u64);
+            bytes.put_slice(&message.payload);
+        }
+    }
+
+    /// Decodes the batch from the bytes following its length, the payloads share the memory with the provided bytes.
+    pub fn from_bytes(mut bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.remaining() < BATCH_HEADER_SIZE as usize {
+            return Err(IggyError::CannotReadMessagesBatch);
+        }
+
+        let base_offset = bytes.get_u64_le();
+        let base_timestamp = bytes.get_u64_le();
+        let max_timestamp = bytes.get_u64_le();
+        let producer_id = bytes.get_u64_le();
+        let producer_epoch = bytes.get_u16_le();
+        let messages_count = bytes.get_u32_le();
+        // The count isn't trusted for the allocation, as the corrupted batch could claim billions of messages.
+        let mut messages = Vec::with_capacity(
+            (messages_count as usize).min(bytes.remaining() / MIN_ENCODED_MESSAGE_SIZE),
+        );
+        for _ in 0..messages_count {
+            let offset_delta = varint::get_u64(&mut bytes).ok_or(IggyError::CannotReadMessage)?;
+            let timestamp_delta =
+                varint::get_i64(&mut bytes).ok_or(IggyError::CannotReadMessageTimestamp)?;
+            if bytes.remaining() < 1 + 16 + 4 {
+                return Err(IggyError::CannotReadMessage);
+            }
+
+            let state = MessageState::from_code(bytes.get_u8())?;
+            let id = bytes.get_u128_le();
+            let checksum = bytes.get_u32_le();
+            let headers_length =
+                varint::get_u64(&mut bytes).ok_or(IggyError::CannotReadHeadersLength)? as usize;
+            let headers = match headers_length {
+                0 => None,
+                _ => {
+                    if bytes.remaining() < headers_length {
+                        return Err(IggyError::CannotReadHeadersPayload);
+                    }
+                    Some(HashMap::from_bytes(bytes.split_to(headers_length))?)
+                }
+            };
+            let payload_length =
+                varint::get_u64(&mut bytes).ok_or(IggyError::CannotReadMessageLength)? as usize;
+            if bytes.remaining() < payload_length {
+                return Err(IggyError::CannotReadMessagePayload);
+            }
+
+            let payload = bytes.split_to(payload_length);
+            messages.push(Arc::new(Message::create(
+                base_offset + offset_delta,
+                state,
+                (base_timestamp as i64 + timestamp_delta) as u64,
+                id,
+                payload,
+                checksum,
+                headers,
+            )));
+        }
+
+        Ok(Self {
+            base_offset,
+            base_timestamp,
+            max_timestamp,
+            producer_id,
+            producer_epoch,
+            messages,
+        })
+    }
+}
+
+impl Sizeable for MessagesBatch {
+    /// Returns the size of the batch stored on disk.
+    fn get_size_bytes(&self) -> u32 {
+        let messages_size = self
+            .messages
+            .iter()
+            .map(|message| {
+                // Headers size includes its 4 bytes length field, which is replaced by the varint.
+                let headers_size = header::get_headers_size_bytes(&message.headers) - 4;
+                varint::get_u64_size(message.offset - self.base_offset)
+                    + varint::get_i64_size(message.timestamp as i64 - self.base_timestamp as i64)
+                    + 1
+                    + 16
+                    + 4
+                    + varint::get_u64_size(headers_size as u64)
+                    + headers_size
+                    + varint::get_u64_size(message.payload.len() as u64)
+                    + message.payload.len() as u32
+            })
+            .sum::<u32>();
+        BATCH_PREFIX_SIZE + BATCH_HEADER_SIZE + messages_size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::header::{HeaderKey, HeaderValue};
+    use std::str::FromStr;
+
+    #[test]
+    fn batch_should_be_encoded_and_decoded() {
+        let headers = HashMap::from([(
+            HeaderKey::new("key").unwrap(),
+            HeaderValue::from_str("value").unwrap(),
+        )]);
+        let batch = MessagesBatch::new(vec![
+            create_message(10, 1000, None),
+            create_message(11, 990, Some(headers)),
+            create_message(12, 1200, None),
+        ]);
+        let mut bytes = BytesMut::new();
+        batch.extend(&mut bytes);
+        assert_eq!(bytes.len() as u32, batch.get_size_bytes());
+
+        let mut bytes = bytes.freeze();
+        assert_eq!(bytes.get_u64_le(), BATCH_MARKER);
+        assert_eq!(bytes.get_u8(), BATCH_FORMAT_VERSION);
+        assert_eq!(bytes.get_u32_le() as usize, bytes.remaining());
+        let decoded_batch = MessagesBatch::from_bytes(bytes).unwrap();
+
+        assert_eq!(decoded_batch.base_offset, 10);
+        assert_eq!(decoded_batch.base_timestamp, 1000);
+        assert_eq!(decoded_batch.max_timestamp, 1200);
+        assert_eq!(decoded_batch.get_last_offset(), 12);
+        assert_eq!(decoded_batch.messages.len(), batch.messages.len());
+        for (decoded_message, message) in decoded_batch.messages.iter().zip(&batch.messages) {
+            assert_eq!(decoded_message.offset, message.offset);
+            assert_eq!(decoded_message.timestamp, message.timestamp);
+            assert_eq!(decoded_message.id, message.id);
+            assert_eq!(decoded_message.checksum, message.checksum);
+            assert_eq!(decoded_message.headers, message.headers);
+            assert_eq!(decoded_message.payload, message.payload);
+        }
+    }
+
+    #[test]
+    fn batch_should_take_less_space_than_messages_stored_one_by_one() {
+        let batch = MessagesBatch::new(
+            (0..100)
+                .map(|offset| create_message(offset, 1000, None))
+                .collect(),
+        );
+        let messages_size = batch
+            .messages
+            .iter()
+            .map(|message| message.get_size_bytes())
+            .sum::<u32>();
+
+        assert!(batch.get_size_bytes() * 10 < messages_size * 7);
+    }
+
+    #[test]
+    fn truncated_batch_should_not_be_decoded() {
+        let batch = MessagesBatch::new(vec![create_message(0, 1000, None)]);
+        let mut bytes = BytesMut::new();
+        batch.extend(&mut bytes);
+        let length = bytes.len();
+        let bytes = bytes.freeze().slice(BATCH_PREFIX_SIZE as usize..length - 1);
+
+        assert!(MessagesBatch::from_bytes(bytes).is_err());
+    }
+
+    #[test]
+    fn batch_with_corrupted_messages_count_should_not_be_decoded() {
+        let batch = MessagesBatch::new(vec![create_message(0, 1000, None)]);
+        let mut bytes = BytesMut::new();
+        batch.extend(&mut bytes);
+        let count_position = (BATCH_PREFIX_SIZE + BATCH_HEADER_SIZE - 4) as usize;
+        bytes[count_position..count_position + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let length = bytes.len();
+        let bytes = bytes.freeze().slice(BATCH_PREFIX_SIZE as usize..length);
+
+        assert!(MessagesBatch::from_bytes(bytes).is_err());
+    }
+
+    fn create_message(
+        offset: u64,
+        timestamp: u64,
+        headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Arc<Message> {
+        let payload = Bytes::from(format!("message-{offset}"));
+        Arc::new(Message::create(
+            offset,
+            MessageState::Available,
+            timestamp,
+            offset as u128 + 1,
+            payload.clone(),
+            iggy::utils::checksum::calculate(&payload),
+            headers,
+        ))
+    }
+}
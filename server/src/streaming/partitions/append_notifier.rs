@@ -0,0 +1,93 @@
+use futures::future::select_all;
+use tokio::sync::watch;
+
+/// The notification bus of the partition, which is fired whenever the messages are appended to it,
+/// so that the consumers waiting for the new messages (e.g. long polling) are woken up instead of polling the partition in a loop.
+/// Only the offset of the last appended message is kept, so the slow subscribers never lag behind, they just observe the latest append.
+#[derive(Debug)]
+pub struct AppendNotifier {
+    sender: watch::Sender<u64>,
+}
+
+/// The subscription to the appends of a single partition, created by `AppendNotifier::subscribe()`.
+#[derive(Debug)]
+pub struct AppendSubscription {
+    receiver: watch::Receiver<u64>,
+}
+
+impl AppendNotifier {
+    pub fn new(current_offset: u64) -> Self {
+        let (sender, _) = watch::channel(current_offset);
+        Self { sender }
+    }
+
+    /// Notifies all the subscribers that the messages up to the provided offset have been appended.
+    pub fn notify(&self, offset: u64) {
+        self.sender.send_replace(offset);
+    }
+
+    /// Subscribes to the appends, only the appends made after subscribing are observed.
+    pub fn subscribe(&self) -> AppendSubscription {
+        AppendSubscription {
+            receiver: self.sender.subscribe(),
+        }
+    }
+}
+
+impl AppendSubscription {
+    /// Waits for the next append and returns the offset of the last appended message, or `None` if the partition has been deleted.
+    pub async fn next(&mut self) -> Option<u64> {
+        self.receiver.changed().await.ok()?;
+        Some(*self.receiver.borrow_and_update())
+    }
+
+    /// Waits for the next append to any of the provided partitions, returns `None` if there are no subscriptions or the partition has been deleted.
+    pub async fn next_any(subscriptions: &mut [AppendSubscription]) -> Option<u64> {
+        if subscriptions.is_empty() {
+            return None;
+        }
+
+        let appends = subscriptions
+            .iter_mut()
+            .map(|subscription| Box::pin(subscription.next()));
+        select_all(appends).await.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    async fn subscription_should_observe_only_appends_made_after_subscribing() {
+        let notifier = AppendNotifier::new(0);
+        notifier.notify(5);
+        let mut subscription = notifier.subscribe();
+
+        let next = timeout(Duration::from_millis(50), subscription.next()).await;
+        assert!(next.is_err());
+
+        notifier.notify(7);
+        notifier.notify(9);
+        assert_eq!(subscription.next().await, Some(9));
+    }
+
+    #[tokio::test]
+    async fn any_subscription_should_be_woken_up_by_append_to_any_partition() {
+        let first_notifier = AppendNotifier::new(0);
+        let second_notifier = AppendNotifier::new(0);
+        let mut subscriptions = vec![first_notifier.subscribe(), second_notifier.subscribe()];
+
+        second_notifier.notify(3);
+        assert_eq!(
+            AppendSubscription::next_any(&mut subscriptions).await,
+            Some(3)
+        );
+
+        drop(first_notifier);
+        assert_eq!(AppendSubscription::next_any(&mut subscriptions).await, None);
+        assert_eq!(AppendSubscription::next_any(&mut []).await, None);
+    }
+}
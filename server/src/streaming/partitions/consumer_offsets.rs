@@ -3,6 +3,7 @@ use crate::streaming::polling_consumer::PollingConsumer;
 use dashmap::DashMap;
 use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
 use tracing::trace;
 
 impl Partition {
@@ -73,6 +74,7 @@ impl Partition {
             .get_mut(&consumer_id)
             .map(|mut consumer_offset| {
                 consumer_offset.offset = offset;
+                consumer_offset.committed_at = IggyTimestamp::now().to_micros();
                 consumer_offset.clone()
             });
         if let Some(consumer_offset) = consumer_offset {
@@ -99,6 +101,71 @@ impl Partition {
         Ok(())
     }
 
+    /// Moves the offsets of the consumers and consumer groups from the drained partition, whose messages starting at
+    /// `source_first_offset` were appended to this partition starting at `base_offset`.
+    /// The offset is moved only if the consumer has already consumed all the messages of this partition,
+    /// otherwise it's kept as it is and the drained messages are delivered again (at least once).
+    pub(crate) async fn move_consumer_offsets(
+        &self,
+        source: &Partition,
+        source_first_offset: u64,
+        base_offset: u64,
+    ) -> Result<(), IggyError> {
+        for kind in [ConsumerKind::Consumer, ConsumerKind::ConsumerGroup] {
+            let source_offsets = source
+                .get_consumer_offsets(kind)
+                .iter()
+                .map(|consumer_offset| (consumer_offset.consumer_id, consumer_offset.offset))
+                .collect::<Vec<_>>();
+            for (consumer_id, offset) in source_offsets {
+                if offset < source_first_offset {
+                    continue;
+                }
+
+                let is_caught_up = match self.get_consumer_offsets(kind).get(&consumer_id) {
+                    Some(consumer_offset) => consumer_offset.offset + 1 >= base_offset,
+                    None => base_offset == 0,
+                };
+                if !is_caught_up {
+                    continue;
+                }
+
+                self.store_offset(
+                    kind,
+                    consumer_id,
+                    base_offset + offset - source_first_offset,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the timestamp of the latest offset commit of the consumer group, if it has committed any offset.
+    pub fn get_consumer_group_committed_at(&self, consumer_group_id: u32) -> Option<u64> {
+        self.consumer_group_offsets
+            .get(&consumer_group_id)
+            .map(|consumer_offset| consumer_offset.committed_at)
+    }
+
+    /// Deletes the offset committed by the consumer group, returns `true` if it existed.
+    pub async fn delete_consumer_group_offset(
+        &self,
+        consumer_group_id: u32,
+    ) -> Result<bool, IggyError> {
+        let Some((_, consumer_offset)) = self.consumer_group_offsets.remove(&consumer_group_id)
+        else {
+            return Ok(false);
+        };
+
+        self.storage
+            .partition
+            .delete_consumer_offset(&consumer_offset)
+            .await?;
+        Ok(true)
+    }
+
     pub async fn load_consumer_offsets(&mut self) -> Result<(), IggyError> {
         trace!(
                 "Loading consumer offsets for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
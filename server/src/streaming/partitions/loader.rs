@@ -0,0 +1,101 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// Limits the number of the partitions loaded concurrently on startup, as all the topics and their partitions are loaded at once,
+/// and keeps track of the progress, so that it can be reported while loading tens of thousands of partitions.
+#[derive(Debug)]
+pub struct PartitionsLoader {
+    permits: Semaphore,
+    total_count: AtomicU64,
+    loaded_count: AtomicU64,
+}
+
+/// The number of the partitions found and loaded (or failed to load) so far.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LoadingProgress {
+    pub loaded_count: u64,
+    pub total_count: u64,
+}
+
+impl Default for PartitionsLoader {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl PartitionsLoader {
+    /// Creates the loader allowing the given number of the concurrent loads, `0` means unlimited.
+    pub fn new(max_concurrent_loads: usize) -> Self {
+        let permits = match max_concurrent_loads {
+            0 => Semaphore::MAX_PERMITS,
+            max_concurrent_loads => max_concurrent_loads,
+        };
+        Self {
+            permits: Semaphore::new(permits),
+            total_count: AtomicU64::new(0),
+            loaded_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Registers the partitions found on disk, which are about to be loaded.
+    pub fn add(&self, count: usize) {
+        self.total_count.fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    /// Waits until the partition can be loaded, the permit must be held until it's done.
+    pub async fn acquire(&self) -> SemaphorePermit<'_> {
+        self.permits
+            .acquire()
+            .await
+            .expect("Partitions loader semaphore is never closed")
+    }
+
+    /// Marks the partition as processed, regardless of whether it has been loaded successfully.
+    pub fn complete(&self) {
+        self.loaded_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn progress(&self) -> LoadingProgress {
+        LoadingProgress {
+            loaded_count: self.loaded_count.load(Ordering::Relaxed),
+            total_count: self.total_count.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn concurrent_loads_should_be_limited() {
+        let loader = Arc::new(PartitionsLoader::new(2));
+        loader.add(3);
+
+        let first = loader.acquire().await;
+        let _second = loader.acquire().await;
+        assert!(loader.permits.try_acquire().is_err());
+
+        drop(first);
+        loader.complete();
+        assert!(loader.permits.try_acquire().is_ok());
+        assert_eq!(
+            loader.progress(),
+            LoadingProgress {
+                loaded_count: 1,
+                total_count: 3
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn loads_should_be_unlimited_by_default() {
+        let loader = PartitionsLoader::default();
+
+        let permits = (0..1000)
+            .map(|_| loader.permits.try_acquire().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(permits.len(), 1000);
+    }
+}
@@ -5,10 +5,18 @@ use crate::streaming::utils::random_id;
 use iggy::error::IggyError;
 use iggy::models::messages::Message;
 use std::sync::{atomic::Ordering, Arc};
-use tracing::{trace, warn};
+use tracing::{error, trace, warn};
 
 const EMPTY_MESSAGES: Vec<Arc<Message>> = vec![];
 
+/// The offsets assigned to the messages appended at once, the duplicates skipped by the deduplication don't get any offset.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct AppendedMessages {
+    pub partition_id: u32,
+    pub base_offset: u64,
+    pub messages_count: u32,
+}
+
 impl Partition {
     pub fn get_messages_count(&self) -> u64 {
         self.messages_count.load(Ordering::SeqCst)
@@ -30,27 +38,20 @@ impl Partition {
 
         let mut maybe_start_offset = None;
         for segment in self.segments.iter() {
-            if segment.time_indexes.is_none() {
-                continue;
-            }
-
-            let time_indexes = segment.time_indexes.as_ref().unwrap();
-            if time_indexes.is_empty() {
+            let Some(time_indexes) = segment.time_indexes.as_ref() else {
                 continue;
-            }
+            };
 
-            let first_timestamp = time_indexes.first().unwrap().timestamp;
-            let last_timestamp = time_indexes.last().unwrap().timestamp;
-            if timestamp < first_timestamp || timestamp > last_timestamp {
-                continue;
-            }
-
-            let relative_start_offset = time_indexes
+            // The timestamps aren't monotonic for the topics storing the create time provided by the producers,
+            // so the first message at or after the timestamp is searched for, instead of checking the range of the segment.
+            let Some(time_index) = time_indexes
                 .iter()
                 .find(|time_index| time_index.timestamp >= timestamp)
-                .map(|time_index| time_index.relative_offset)
-                .unwrap_or(0);
+            else {
+                continue;
+            };
 
+            let relative_start_offset = time_index.relative_offset;
             let start_offset = segment.start_offset + relative_start_offset as u64;
             maybe_start_offset = Some(start_offset);
             trace!(
@@ -306,11 +307,37 @@ impl Partition {
         messages
     }
 
-    pub async fn append_messages(&mut self, messages: Vec<Message>) -> Result<(), IggyError> {
+    pub async fn append_messages(
+        &mut self,
+        messages: Vec<Message>,
+    ) -> Result<AppendedMessages, IggyError> {
+        let appended_messages = self.append_messages_without_persisting(messages).await?;
+        self.persist_unsaved_messages_if_required().await?;
+        Ok(appended_messages)
+    }
+
+    /// Appends the messages to the current segment (and the cache), but doesn't persist them even if the threshold has been reached,
+    /// so that several batches can be appended before `persist_unsaved_messages_if_required()` is called once for all of them.
+    pub async fn append_messages_without_persisting(
+        &mut self,
+        messages: Vec<Message>,
+    ) -> Result<AppendedMessages, IggyError> {
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
             if last_segment.is_closed {
                 let start_offset = last_segment.end_offset + 1;
+                let max_segments = self.config.limits.max_segments_per_partition;
+                if max_segments > 0 && self.get_segments_count() >= max_segments {
+                    error!(
+                        "Cannot create segment for partition with ID: {}, stream with ID: {}, topic with ID: {}, the limit of {max_segments} segments per partition has been reached.",
+                        self.partition_id, self.stream_id, self.topic_id
+                    );
+                    return Err(IggyError::SegmentsLimitReached(
+                        self.partition_id,
+                        max_segments,
+                    ));
+                }
+
                 trace!(
                     "Current segment is closed, creating new segment with start offset: {} for partition with ID: {}...",
                     start_offset, self.partition_id
@@ -319,6 +346,10 @@ impl Partition {
             }
         }
 
+        let base_offset = match self.should_increment_offset {
+            true => self.current_offset + 1,
+            false => self.current_offset,
+        };
         let mut appendable_messages = Vec::with_capacity(messages.len());
         if let Some(message_deduplicator) = &self.message_deduplicator {
             for mut message in messages {
@@ -371,21 +402,46 @@ impl Partition {
         }
 
         self.unsaved_messages_count += messages_count;
+
+        // Wakes up the consumers waiting for the new messages (e.g. long polling).
+        if messages_count > 0 {
+            self.append_notifier.notify(self.current_offset);
+        }
+
+        Ok(AppendedMessages {
+            partition_id: self.partition_id,
+            base_offset,
+            messages_count,
+        })
+    }
+
+    /// Persists the unsaved messages of the current segment once enough of them have been appended or the segment is full.
+    pub async fn persist_unsaved_messages_if_required(&mut self) -> Result<(), IggyError> {
+        let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+        if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
+            || last_segment.is_full().await
         {
-            let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
-            if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
-                || last_segment.is_full().await
-            {
-                trace!(
-                    "Segment with start offset: {} for partition with ID: {} will be persisted on disk...",
-                    last_segment.start_offset,
-                    self.partition_id
-                );
-                last_segment.persist_messages().await?;
-                self.unsaved_messages_count = 0;
-            }
+            trace!(
+                "Segment with start offset: {} for partition with ID: {} will be persisted on disk...",
+                last_segment.start_offset,
+                self.partition_id
+            );
+            last_segment.persist_messages().await?;
+            self.unsaved_messages_count = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Persists the unsaved messages of the current segment regardless of the threshold, e.g. once the producer waits for them to be stored.
+    pub async fn persist_unsaved_messages(&mut self) -> Result<(), IggyError> {
+        if self.unsaved_messages_count == 0 {
+            return Ok(());
         }
 
+        let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+        last_segment.persist_messages().await?;
+        self.unsaved_messages_count = 0;
         Ok(())
     }
 }
@@ -395,7 +451,7 @@ mod tests {
     use std::sync::atomic::AtomicU64;
 
     use super::*;
-    use crate::configs::system::{MessageDeduplicationConfig, SystemConfig};
+    use crate::configs::system::{LimitsConfig, MessageDeduplicationConfig, SystemConfig};
     use crate::streaming::partitions::create_messages;
     use crate::streaming::storage::tests::get_test_system_storage;
 
@@ -405,12 +461,15 @@ mod tests {
         let messages = create_messages();
         let messages_count = messages.len() as u32;
         partition.append_messages(messages).await.unwrap();
+        let appended_messages = partition.append_messages(create_messages()).await.unwrap();
+        assert_eq!(appended_messages.base_offset, messages_count as u64);
+        assert_eq!(appended_messages.messages_count, messages_count);
 
         let loaded_messages = partition
-            .get_messages_by_offset(0, messages_count)
+            .get_messages_by_offset(0, 2 * messages_count)
             .await
             .unwrap();
-        assert_eq!(loaded_messages.len(), messages_count as usize);
+        assert_eq!(loaded_messages.len(), 2 * messages_count as usize);
     }
 
     #[tokio::test]
@@ -419,7 +478,12 @@ mod tests {
         let messages = create_messages();
         let messages_count = messages.len() as u32;
         let unique_messages_count = 3;
-        partition.append_messages(messages).await.unwrap();
+        let appended_messages = partition.append_messages(messages).await.unwrap();
+        assert_eq!(appended_messages.base_offset, 0);
+        assert_eq!(
+            appended_messages.messages_count,
+            unique_messages_count as u32
+        );
 
         let loaded_messages = partition
             .get_messages_by_offset(0, messages_count)
@@ -428,19 +492,40 @@ mod tests {
         assert_eq!(loaded_messages.len(), unique_messages_count);
     }
 
+    #[tokio::test]
+    async fn given_reached_segments_limit_messages_should_not_be_appended_to_new_segment() {
+        let mut partition = create_partition_with_config(SystemConfig {
+            limits: LimitsConfig {
+                max_segments_per_partition: 1,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        partition.get_segments_mut()[0].is_closed = true;
+
+        let result = partition.append_messages(create_messages()).await;
+
+        assert!(matches!(result, Err(IggyError::SegmentsLimitReached(3, 1))));
+        assert_eq!(partition.get_segments_count(), 1);
+    }
+
     fn create_partition(deduplication_enabled: bool) -> Partition {
-        let storage = Arc::new(get_test_system_storage());
-        let stream_id = 1;
-        let topic_id = 2;
-        let partition_id = 3;
-        let with_segment = true;
-        let config = Arc::new(SystemConfig {
+        create_partition_with_config(SystemConfig {
             message_deduplication: MessageDeduplicationConfig {
                 enabled: deduplication_enabled,
                 ..Default::default()
             },
             ..Default::default()
-        });
+        })
+    }
+
+    fn create_partition_with_config(config: SystemConfig) -> Partition {
+        let storage = Arc::new(get_test_system_storage());
+        let stream_id = 1;
+        let topic_id = 2;
+        let partition_id = 3;
+        let with_segment = true;
+        let config = Arc::new(config);
         Partition::create(
             stream_id,
             topic_id,
@@ -2,12 +2,15 @@ use bytes::Bytes;
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::checksum;
 
+pub mod append_notifier;
 pub mod consumer_offsets;
+pub mod loader;
 pub mod messages;
 pub mod partition;
 pub mod persistence;
 pub mod segments;
 pub mod storage;
+pub mod writer;
 
 #[allow(dead_code)]
 fn create_messages() -> Vec<Message> {
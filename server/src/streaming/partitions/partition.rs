@@ -2,6 +2,7 @@ use crate::configs::system::SystemConfig;
 use crate::streaming::cache::buffer::SmartCache;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::deduplication::message_deduplicator::MessageDeduplicator;
+use crate::streaming::partitions::append_notifier::AppendNotifier;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::storage::SystemStorage;
 use dashmap::DashMap;
@@ -36,6 +37,7 @@ pub struct Partition {
     pub(crate) segments: Vec<Segment>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
+    pub(crate) append_notifier: AppendNotifier,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -43,6 +45,8 @@ pub struct ConsumerOffset {
     pub kind: ConsumerKind,
     pub consumer_id: u32,
     pub offset: u64,
+    /// The timestamp (in microseconds) of the latest commit of the offset.
+    pub committed_at: u64,
     pub key: String,
 }
 
@@ -63,6 +67,7 @@ impl ConsumerOffset {
             kind,
             consumer_id,
             offset,
+            committed_at: IggyTimestamp::now().to_micros(),
         }
     }
 
@@ -140,6 +145,7 @@ impl Partition {
             messages_count_of_parent_stream,
             messages_count_of_parent_topic,
             messages_count: Arc::new(AtomicU64::new(0)),
+            append_notifier: AppendNotifier::new(0),
         };
 
         if with_segment {
@@ -167,6 +173,14 @@ impl Partition {
     pub fn get_size_bytes(&self) -> u64 {
         self.size_bytes.load(std::sync::atomic::Ordering::SeqCst)
     }
+
+    /// Returns the offset which will be assigned to the next appended message.
+    pub fn get_next_offset(&self) -> u64 {
+        match self.should_increment_offset {
+            true => self.current_offset + 1,
+            false => self.current_offset,
+        }
+    }
 }
 
 #[cfg(test)]
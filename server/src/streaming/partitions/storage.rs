@@ -5,6 +5,7 @@ use anyhow::Context;
 use async_trait::async_trait;
 use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
 use sled::Db;
 use std::path::Path;
@@ -30,19 +31,18 @@ unsafe impl Sync for FilePartitionStorage {}
 #[async_trait]
 impl PartitionStorage for FilePartitionStorage {
     async fn save_consumer_offset(&self, offset: &ConsumerOffset) -> Result<(), IggyError> {
-        // The stored value is just the offset, so we don't need to serialize the whole struct.
+        // The stored value is just the offset and the commit timestamp, so we don't need to serialize the whole struct.
         // It should be as fast and lightweight as possible.
         // As described in the docs, sled works better with big-endian byte order.
-        if let Err(err) = self
-            .db
-            .insert(&offset.key, &offset.offset.to_be_bytes())
-            .with_context(|| {
-                format!(
-                    "Failed to save consumer offset: {}, key: {}",
-                    offset.offset, offset.key
-                )
-            })
-        {
+        let mut value = [0u8; 16];
+        value[..8].copy_from_slice(&offset.offset.to_be_bytes());
+        value[8..].copy_from_slice(&offset.committed_at.to_be_bytes());
+        if let Err(err) = self.db.insert(&offset.key, &value).with_context(|| {
+            format!(
+                "Failed to save consumer offset: {}, key: {}",
+                offset.offset, offset.key
+            )
+        }) {
             return Err(IggyError::CannotSaveResource(err));
         }
 
@@ -76,13 +76,19 @@ impl PartitionStorage for FilePartitionStorage {
             }) {
                 Ok((key, value)) => {
                     let key = String::from_utf8(key.to_vec()).unwrap();
-                    let offset = u64::from_be_bytes(value.as_ref().try_into().unwrap());
+                    let offset = u64::from_be_bytes(value[..8].try_into().unwrap());
+                    // The offsets saved before the commit timestamp was stored are treated as just committed.
+                    let committed_at = match value.get(8..16) {
+                        Some(committed_at) => u64::from_be_bytes(committed_at.try_into().unwrap()),
+                        None => IggyTimestamp::now().to_micros(),
+                    };
                     let consumer_id = key.split(':').last().unwrap().parse::<u32>().unwrap();
                     ConsumerOffset {
                         key,
                         kind,
                         consumer_id,
                         offset,
+                        committed_at,
                     }
                 }
                 Err(err) => {
@@ -96,6 +102,24 @@ impl PartitionStorage for FilePartitionStorage {
         Ok(consumer_offsets)
     }
 
+    async fn delete_consumer_offset(&self, offset: &ConsumerOffset) -> Result<(), IggyError> {
+        if let Err(err) = self
+            .db
+            .remove(&offset.key)
+            .with_context(|| format!("Failed to delete consumer offset, key: {}", offset.key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        trace!(
+            "Deleted consumer offset value: {} for {} with ID: {}",
+            offset.offset,
+            offset.kind,
+            offset.consumer_id
+        );
+        Ok(())
+    }
+
     async fn delete_consumer_offsets(
         &self,
         kind: ConsumerKind,
@@ -221,7 +245,7 @@ impl Storage<Partition> for FilePartitionStorage {
             );
             segment.load().await?;
             if !segment.is_closed {
-                segment.unsaved_messages = Some(Vec::new())
+                segment.unsaved_batches = Some(Vec::new())
             }
 
             // If the first segment has at least a single message, we should increment the offset.
@@ -253,9 +277,7 @@ impl Storage<Partition> for FilePartitionStorage {
             partition.segments.push(segment);
         }
 
-        partition
-            .segments
-            .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
+        partition.segments.sort_by_key(|x| x.start_offset);
 
         let end_offsets = partition
             .segments
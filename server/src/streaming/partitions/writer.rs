@@ -0,0 +1,249 @@
+use crate::runtimes::PartitionShards;
+use crate::streaming::partitions::messages::AppendedMessages;
+use crate::streaming::partitions::partition::Partition;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Acks;
+use iggy::models::messages::Message;
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot, RwLock};
+use tracing::{error, trace};
+
+/// The maximum number of the appends waiting in the queue of the partition, once it's full the producers wait for the writer.
+const QUEUE_SIZE: usize = 1024;
+/// The maximum number of the queued appends which are applied under a single acquisition of the partition lock.
+const MAX_GROUPED_APPENDS: usize = 128;
+
+/// The single writer of the partition, which appends the messages on behalf of all the producers.
+/// The batches are sent over the channel to the dedicated task, so the producers don't contend for the partition lock,
+/// and the batches queued in the meantime are appended together, thus the unsaved messages are persisted (and fsynced) once per group.
+/// If any of the grouped batches has been sent with `Acks::All`, the unsaved messages are persisted regardless of the threshold.
+#[derive(Debug, Clone)]
+pub struct PartitionWriter {
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    sender: mpsc::Sender<AppendRequest>,
+}
+
+#[derive(Debug)]
+struct AppendRequest {
+    messages: Vec<Message>,
+    persist: bool,
+    result: oneshot::Sender<Result<AppendedMessages, IggyError>>,
+}
+
+impl PartitionWriter {
+    /// Spawns the writer task, which runs until all the writer handles are dropped.
+    /// If the partition shards are enabled, the task is spawned on the shard the partition is assigned to.
+    pub fn spawn(
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        partition: Arc<RwLock<Partition>>,
+        partition_shards: Option<&PartitionShards>,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel(QUEUE_SIZE);
+        match partition_shards {
+            Some(partition_shards) => {
+                partition_shards
+                    .get(stream_id, topic_id, partition_id)
+                    .spawn(run(partition, receiver));
+            }
+            None => {
+                tokio::spawn(run(partition, receiver));
+            }
+        }
+        trace!("Spawned writer for partition with ID: {partition_id}, topic with ID: {topic_id}, stream with ID: {stream_id}.");
+        Self {
+            stream_id,
+            topic_id,
+            partition_id,
+            sender,
+        }
+    }
+
+    /// Appends the messages to the partition and waits until they are appended (and persisted, for `Acks::All`).
+    /// For `Acks::None`, returns as soon as the messages are queued, so there are no appended messages to return,
+    /// while the queue still preserves the order of the batches sent by the producer.
+    pub async fn append(
+        &self,
+        messages: Vec<Message>,
+        acks: Acks,
+    ) -> Result<Option<AppendedMessages>, IggyError> {
+        let (result_sender, result_receiver) = oneshot::channel();
+        let request = AppendRequest {
+            messages,
+            persist: acks == Acks::All,
+            result: result_sender,
+        };
+        if self.sender.send(request).await.is_err() {
+            return Err(self.partition_not_found());
+        }
+
+        if acks == Acks::None {
+            return Ok(None);
+        }
+
+        result_receiver
+            .await
+            .unwrap_or_else(|_| Err(self.partition_not_found()))
+            .map(Some)
+    }
+
+    fn partition_not_found(&self) -> IggyError {
+        IggyError::PartitionNotFound(self.partition_id, self.topic_id, self.stream_id)
+    }
+}
+
+async fn run(partition: Arc<RwLock<Partition>>, mut receiver: mpsc::Receiver<AppendRequest>) {
+    let mut requests = Vec::with_capacity(MAX_GROUPED_APPENDS);
+    while let Some(request) = receiver.recv().await {
+        requests.push(request);
+        while requests.len() < MAX_GROUPED_APPENDS {
+            match receiver.try_recv() {
+                Ok(request) => requests.push(request),
+                Err(_) => break,
+            }
+        }
+
+        let mut partition = partition.write().await;
+        let mut appended = Vec::with_capacity(requests.len());
+        let mut persist = false;
+        for request in requests.drain(..) {
+            persist |= request.persist;
+            match partition
+                .append_messages_without_persisting(request.messages)
+                .await
+            {
+                Ok(appended_messages) => appended.push((request.result, appended_messages)),
+                Err(error) => {
+                    if let Err(Err(error)) = request.result.send(Err(error)) {
+                        // The producer didn't wait for the result (no acks), so the error can only be logged.
+                        error!(
+                            "Cannot append the messages sent without acks to partition with ID: {}, topic with ID: {}, stream with ID: {}. Error: {error}",
+                            partition.partition_id, partition.topic_id, partition.stream_id
+                        );
+                    }
+                }
+            }
+        }
+
+        let persisted = match persist {
+            true => partition.persist_unsaved_messages().await,
+            false => partition.persist_unsaved_messages_if_required().await,
+        };
+        if let Err(error) = persisted {
+            error!(
+                "Cannot persist the appended messages for partition with ID: {}, topic with ID: {}, stream with ID: {}. Error: {error}",
+                partition.partition_id, partition.topic_id, partition.stream_id
+            );
+            let mut error = Some(error);
+            for (result, _) in appended {
+                let _ = result.send(Err(error.take().unwrap_or(IggyError::CannotAppendMessage)));
+            }
+            continue;
+        }
+
+        for (result, appended_messages) in appended {
+            let _ = result.send(Ok(appended_messages));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use bytes::Bytes;
+    use iggy::models::messages::MessageState;
+    use std::sync::atomic::AtomicU64;
+
+    #[tokio::test]
+    async fn should_append_messages_sent_concurrently_with_sequential_offsets() {
+        let partition = Arc::new(RwLock::new(create_partition()));
+        let writer = PartitionWriter::spawn(1, 1, 1, partition.clone(), None);
+        let appends = (1..=10)
+            .map(|id| {
+                let writer = writer.clone();
+                tokio::spawn(
+                    async move { writer.append(vec![create_message(id)], Acks::Leader).await },
+                )
+            })
+            .collect::<Vec<_>>();
+        for append in appends {
+            append.await.unwrap().unwrap();
+        }
+
+        let partition = partition.read().await;
+        assert_eq!(partition.current_offset, 9);
+        assert_eq!(partition.unsaved_messages_count, 10);
+    }
+
+    #[tokio::test]
+    async fn should_persist_messages_appended_with_all_acks() {
+        let partition = Arc::new(RwLock::new(create_partition()));
+        let writer = PartitionWriter::spawn(1, 1, 1, partition.clone(), None);
+
+        let queued = writer
+            .append(vec![create_message(1)], Acks::None)
+            .await
+            .unwrap();
+        let appended = writer
+            .append(vec![create_message(2)], Acks::All)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(queued.is_none());
+        assert_eq!(appended.base_offset, 1);
+        let partition = partition.read().await;
+        assert_eq!(partition.current_offset, 1);
+        assert_eq!(partition.unsaved_messages_count, 0);
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_append_once_writer_task_has_stopped() {
+        let (sender, receiver) = mpsc::channel(1);
+        drop(receiver);
+        let writer = PartitionWriter {
+            stream_id: 1,
+            topic_id: 2,
+            partition_id: 3,
+            sender,
+        };
+
+        let result = writer.append(vec![create_message(1)], Acks::Leader).await;
+
+        assert!(matches!(result, Err(IggyError::PartitionNotFound(3, 2, 1))));
+    }
+
+    fn create_partition() -> Partition {
+        Partition::create(
+            1,
+            1,
+            1,
+            true,
+            Arc::new(SystemConfig::default()),
+            Arc::new(get_test_system_storage()),
+            None,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+        )
+    }
+
+    fn create_message(id: u128) -> Message {
+        let payload = Bytes::from("message");
+        Message::create(
+            0,
+            MessageState::Available,
+            1,
+            id,
+            payload.clone(),
+            iggy::utils::checksum::calculate(&payload),
+            None,
+        )
+    }
+}
@@ -0,0 +1,236 @@
+use iggy::models::schema::SchemaCompatibility;
+use serde_json::{Map, Value};
+
+/// Checks whether the new schema can be registered after the latest one for the given compatibility.
+/// The check is structural and covers the most common changes of the JSON Schema: types, required and known properties,
+/// enums and array items. Returns the reason of the first found incompatibility.
+pub fn check(
+    latest_schema: &Value,
+    new_schema: &Value,
+    compatibility: SchemaCompatibility,
+) -> Result<(), String> {
+    match compatibility {
+        SchemaCompatibility::None => Ok(()),
+        SchemaCompatibility::Backward => check_readable(new_schema, latest_schema, "#"),
+        SchemaCompatibility::Forward => check_readable(latest_schema, new_schema, "#"),
+        SchemaCompatibility::Full => {
+            check_readable(new_schema, latest_schema, "#")?;
+            check_readable(latest_schema, new_schema, "#")
+        }
+    }
+}
+
+/// Checks whether every value valid for the writer schema is also valid for the reader schema.
+fn check_readable(reader: &Value, writer: &Value, path: &str) -> Result<(), String> {
+    let (reader, writer) = match (reader, writer) {
+        (Value::Bool(true), _) => return Ok(()),
+        (_, Value::Bool(false)) => return Ok(()),
+        (Value::Object(reader), Value::Object(writer)) => (reader, writer),
+        _ => return Err(format!("schema at path: '{path}' is more restrictive")),
+    };
+
+    check_types(reader, writer, path)?;
+    check_enum(reader, writer, path)?;
+    check_required(reader, writer, path)?;
+    check_properties(reader, writer, path)?;
+    if let (Some(reader_items), Some(writer_items)) = (reader.get("items"), writer.get("items")) {
+        check_readable(reader_items, writer_items, &format!("{path}/items"))?;
+    }
+
+    Ok(())
+}
+
+fn check_types(
+    reader: &Map<String, Value>,
+    writer: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    let Some(reader_types) = get_types(reader) else {
+        return Ok(());
+    };
+
+    let Some(writer_types) = get_types(writer) else {
+        return Err(format!("type at path: '{path}' was restricted"));
+    };
+
+    for writer_type in writer_types {
+        let is_accepted = reader_types.contains(&writer_type)
+            || (writer_type == "integer" && reader_types.contains(&"number"));
+        if !is_accepted {
+            return Err(format!(
+                "type: '{writer_type}' at path: '{path}' is no longer accepted"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn check_enum(
+    reader: &Map<String, Value>,
+    writer: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    let Some(Value::Array(reader_values)) = reader.get("enum") else {
+        return Ok(());
+    };
+
+    let Some(Value::Array(writer_values)) = writer.get("enum") else {
+        return Err(format!("enum at path: '{path}' was added"));
+    };
+
+    match writer_values
+        .iter()
+        .find(|value| !reader_values.contains(value))
+    {
+        Some(value) => Err(format!(
+            "enum value: {value} at path: '{path}' is no longer accepted"
+        )),
+        None => Ok(()),
+    }
+}
+
+fn check_required(
+    reader: &Map<String, Value>,
+    writer: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    let writer_required = get_required(writer);
+    match get_required(reader)
+        .into_iter()
+        .find(|property| !writer_required.contains(property))
+    {
+        Some(property) => Err(format!(
+            "property: '{property}' at path: '{path}' is required, but might be missing"
+        )),
+        None => Ok(()),
+    }
+}
+
+fn check_properties(
+    reader: &Map<String, Value>,
+    writer: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    let Some(Value::Object(writer_properties)) = writer.get("properties") else {
+        return Ok(());
+    };
+
+    let reader_properties = match reader.get("properties") {
+        Some(Value::Object(reader_properties)) => Some(reader_properties),
+        _ => None,
+    };
+    let allows_additional_properties =
+        reader.get("additionalProperties") != Some(&Value::Bool(false));
+    for (name, writer_property) in writer_properties {
+        match reader_properties.and_then(|properties| properties.get(name)) {
+            Some(reader_property) => check_readable(
+                reader_property,
+                writer_property,
+                &format!("{path}/properties/{name}"),
+            )?,
+            None if !allows_additional_properties => {
+                return Err(format!(
+                    "property: '{name}' at path: '{path}' is no longer allowed"
+                ));
+            }
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn get_types(schema: &Map<String, Value>) -> Option<Vec<&str>> {
+    match schema.get("type") {
+        Some(Value::String(schema_type)) => Some(vec![schema_type.as_str()]),
+        Some(Value::Array(schema_types)) => {
+            Some(schema_types.iter().filter_map(Value::as_str).collect())
+        }
+        _ => None,
+    }
+}
+
+fn get_required(schema: &Map<String, Value>) -> Vec<&str> {
+    match schema.get("required") {
+        Some(Value::Array(required)) => required.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn latest_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "status": { "type": "string", "enum": ["new", "paid"] }
+            },
+            "required": ["id"]
+        })
+    }
+
+    #[test]
+    fn adding_optional_property_should_be_backward_and_forward_compatible() {
+        let new_schema = json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "status": { "type": "string", "enum": ["new", "paid"] },
+                "note": { "type": "string" }
+            },
+            "required": ["id"]
+        });
+
+        assert!(check(&latest_schema(), &new_schema, SchemaCompatibility::Full).is_ok());
+    }
+
+    #[test]
+    fn adding_required_property_should_not_be_backward_compatible() {
+        let new_schema = json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "amount": { "type": "number" }
+            },
+            "required": ["id", "amount"]
+        });
+
+        assert!(check(&latest_schema(), &new_schema, SchemaCompatibility::Backward).is_err());
+        assert!(check(&latest_schema(), &new_schema, SchemaCompatibility::Forward).is_ok());
+    }
+
+    #[test]
+    fn extending_enum_should_be_backward_but_not_forward_compatible() {
+        let new_schema = json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "status": { "type": "string", "enum": ["new", "paid", "refunded"] }
+            },
+            "required": ["id"]
+        });
+
+        assert!(check(&latest_schema(), &new_schema, SchemaCompatibility::Backward).is_ok());
+        assert!(check(&latest_schema(), &new_schema, SchemaCompatibility::Forward).is_err());
+    }
+
+    #[test]
+    fn changing_property_type_should_not_be_compatible() {
+        let new_schema = json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" }
+            },
+            "required": ["id"]
+        });
+
+        assert!(check(&latest_schema(), &new_schema, SchemaCompatibility::Backward).is_err());
+        assert!(check(&latest_schema(), &new_schema, SchemaCompatibility::Forward).is_err());
+        assert!(check(&latest_schema(), &new_schema, SchemaCompatibility::None).is_ok());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod compatibility;
+pub mod registry;
+pub mod schema;
+pub mod storage;
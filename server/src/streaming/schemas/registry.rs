@@ -0,0 +1,99 @@
+use crate::streaming::schemas::schema::{Schema, SchemaSubject};
+use iggy::models::schema::SchemaCompatibility;
+use std::collections::HashMap;
+
+/// Keeps all the registered schemas in memory, grouped by subject and ordered by version.
+#[derive(Debug, Default)]
+pub struct SchemaRegistry {
+    schemas: HashMap<u32, Schema>,
+    subjects_schemas: HashMap<String, Vec<u32>>,
+    subjects_compatibility: HashMap<String, SchemaCompatibility>,
+    current_schema_id: u32,
+}
+
+impl SchemaRegistry {
+    pub fn init(&mut self, mut schemas: Vec<Schema>, subjects: Vec<SchemaSubject>) {
+        schemas.sort_by_key(|schema| (schema.version, schema.id));
+        for schema in schemas {
+            self.add_schema(schema);
+        }
+
+        for subject in subjects {
+            self.subjects_compatibility
+                .insert(subject.name, subject.compatibility);
+        }
+    }
+
+    pub fn get_schema(&self, id: u32) -> Option<&Schema> {
+        self.schemas.get(&id)
+    }
+
+    pub fn get_schemas(&self, subject: &str) -> Vec<&Schema> {
+        self.subjects_schemas
+            .get(subject)
+            .map(|ids| ids.iter().filter_map(|id| self.schemas.get(id)).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn get_latest_schema(&self, subject: &str) -> Option<&Schema> {
+        self.subjects_schemas
+            .get(subject)
+            .and_then(|ids| ids.last())
+            .and_then(|id| self.schemas.get(id))
+    }
+
+    pub fn get_compatibility(&self, subject: &str) -> Option<SchemaCompatibility> {
+        self.subjects_compatibility.get(subject).copied()
+    }
+
+    pub fn set_compatibility(&mut self, subject: &str, compatibility: SchemaCompatibility) {
+        self.subjects_compatibility
+            .insert(subject.to_string(), compatibility);
+    }
+
+    pub fn get_next_schema_id(&self) -> u32 {
+        self.current_schema_id + 1
+    }
+
+    pub fn add_schema(&mut self, schema: Schema) {
+        if schema.id > self.current_schema_id {
+            self.current_schema_id = schema.id;
+        }
+
+        self.subjects_schemas
+            .entry(schema.subject.clone())
+            .or_default()
+            .push(schema.id);
+        self.schemas.insert(schema.id, schema);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::schema::SchemaFormat;
+
+    #[test]
+    fn schemas_should_be_grouped_by_subject_and_ordered_by_version() {
+        let mut registry = SchemaRegistry::default();
+        registry.init(
+            vec![
+                Schema::new(3, "orders", 2, SchemaFormat::Json, "{}", 0),
+                Schema::new(2, "payments", 1, SchemaFormat::Json, "{}", 0),
+                Schema::new(1, "orders", 1, SchemaFormat::Json, "{}", 0),
+            ],
+            vec![],
+        );
+
+        let versions = registry
+            .get_schemas("orders")
+            .iter()
+            .map(|schema| schema.version)
+            .collect::<Vec<_>>();
+
+        assert_eq!(versions, vec![1, 2]);
+        assert_eq!(registry.get_latest_schema("orders").unwrap().id, 3);
+        assert_eq!(registry.get_next_schema_id(), 4);
+        assert!(registry.get_schemas("unknown").is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+use iggy::models::schema::{SchemaCompatibility, SchemaFormat};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Schema {
+    pub id: u32,
+    pub subject: String,
+    pub version: u32,
+    pub schema: String,
+    pub created_at: u64,
+    #[serde(default)]
+    pub format: SchemaFormat,
+}
+
+/// The settings of the subject, which are stored only if they differ from the defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SchemaSubject {
+    pub name: String,
+    pub compatibility: SchemaCompatibility,
+}
+
+impl Schema {
+    pub fn new(
+        id: u32,
+        subject: &str,
+        version: u32,
+        format: SchemaFormat,
+        schema: &str,
+        created_at: u64,
+    ) -> Self {
+        Self {
+            id,
+            subject: subject.to_string(),
+            version,
+            schema: schema.to_string(),
+            created_at,
+            format,
+        }
+    }
+}
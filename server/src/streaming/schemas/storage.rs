@@ -0,0 +1,169 @@
+use crate::streaming::schemas::schema::{Schema, SchemaSubject};
+use crate::streaming::storage::{SchemaStorage, Storage};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use serde::de::DeserializeOwned;
+use sled::Db;
+use std::sync::Arc;
+use tracing::info;
+
+const KEY_PREFIX: &str = "schemas";
+const SUBJECT_KEY_PREFIX: &str = "schema_subjects";
+
+#[derive(Debug)]
+pub struct FileSchemaStorage {
+    db: Arc<Db>,
+}
+
+impl FileSchemaStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+
+    fn load_all_by_prefix<T: DeserializeOwned>(&self, prefix: &str) -> Result<Vec<T>, IggyError> {
+        let mut items = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", prefix)) {
+            let item = match data
+                .with_context(|| format!("Failed to load data, when searching for key: {}", prefix))
+            {
+                Ok((_, value)) => match rmp_serde::from_slice::<T>(&value).with_context(|| {
+                    format!(
+                        "Failed to deserialize data, when searching for key: {}",
+                        prefix
+                    )
+                }) {
+                    Ok(item) => item,
+                    Err(err) => {
+                        return Err(IggyError::CannotDeserializeResource(err));
+                    }
+                },
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            };
+            items.push(item);
+        }
+
+        Ok(items)
+    }
+}
+
+unsafe impl Send for FileSchemaStorage {}
+unsafe impl Sync for FileSchemaStorage {}
+
+#[async_trait]
+impl SchemaStorage for FileSchemaStorage {
+    async fn load_all(&self) -> Result<Vec<Schema>, IggyError> {
+        self.load_all_by_prefix(KEY_PREFIX)
+    }
+
+    async fn load_all_subjects(&self) -> Result<Vec<SchemaSubject>, IggyError> {
+        self.load_all_by_prefix(SUBJECT_KEY_PREFIX)
+    }
+
+    async fn save_subject(&self, subject: &SchemaSubject) -> Result<(), IggyError> {
+        let key = get_subject_key(&subject.name);
+        match rmp_serde::to_vec(&subject)
+            .with_context(|| format!("Failed to serialize schema subject with key: {}", key))
+        {
+            Ok(data) => {
+                if let Err(err) = self
+                    .db
+                    .insert(&key, data)
+                    .with_context(|| format!("Failed to insert schema subject with key: {}", key))
+                {
+                    return Err(IggyError::CannotSaveResource(err));
+                }
+            }
+            Err(err) => {
+                return Err(IggyError::CannotSerializeResource(err));
+            }
+        }
+
+        info!("Saved schema subject: {}.", subject.name);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage<Schema> for FileSchemaStorage {
+    async fn load(&self, schema: &mut Schema) -> Result<(), IggyError> {
+        let key = get_key(schema.id);
+        let schema_data = match self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load schema with key: {}", key))
+        {
+            Ok(data) => {
+                if let Some(schema_data) = data {
+                    schema_data
+                } else {
+                    return Err(IggyError::ResourceNotFound(key));
+                }
+            }
+            Err(err) => {
+                return Err(IggyError::CannotLoadResource(err));
+            }
+        };
+
+        match rmp_serde::from_slice::<Schema>(&schema_data)
+            .with_context(|| format!("Failed to deserialize schema with key: {}", key))
+        {
+            Ok(schema_data) => {
+                *schema = schema_data;
+                Ok(())
+            }
+            Err(err) => Err(IggyError::CannotDeserializeResource(err)),
+        }
+    }
+
+    async fn save(&self, schema: &Schema) -> Result<(), IggyError> {
+        let key = get_key(schema.id);
+        match rmp_serde::to_vec(&schema)
+            .with_context(|| format!("Failed to serialize schema with key: {}", key))
+        {
+            Ok(data) => {
+                if let Err(err) = self
+                    .db
+                    .insert(&key, data)
+                    .with_context(|| format!("Failed to insert schema with key: {}", key))
+                {
+                    return Err(IggyError::CannotSaveResource(err));
+                }
+            }
+            Err(err) => {
+                return Err(IggyError::CannotSerializeResource(err));
+            }
+        }
+
+        info!(
+            "Saved schema with ID: {} for subject: {}.",
+            schema.id, schema.subject
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, schema: &Schema) -> Result<(), IggyError> {
+        info!("Deleting schema with ID: {}...", schema.id);
+        let key = get_key(schema.id);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete schema with key: {}", key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        info!("Deleted schema with ID: {}.", schema.id);
+        Ok(())
+    }
+}
+
+fn get_key(schema_id: u32) -> String {
+    format!("{}:{}", KEY_PREFIX, schema_id)
+}
+
+fn get_subject_key(subject: &str) -> String {
+    format!("{}:{}", SUBJECT_KEY_PREFIX, subject)
+}
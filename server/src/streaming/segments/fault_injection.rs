@@ -0,0 +1,400 @@
+use crate::streaming::models::messages_batch::MessagesBatch;
+use crate::streaming::segments::index::{Index, IndexRange};
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::time_index::TimeIndex;
+use crate::streaming::storage::{SegmentStorage, Storage};
+use crate::streaming::utils::file;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::BytesMut;
+use iggy::error::IggyError;
+use iggy::models::messages::Message;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+
+/// The scenario of the faults injected into the segment storage, loaded from the TOML file, e.g.:
+///
+/// ```toml
+/// [[faults]]
+/// operation = "save_batches"
+/// partition_id = 1
+/// skip = 2
+/// times = 1
+/// fault = { type = "partial_write", bytes = 10 }
+/// ```
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct FaultScenario {
+    #[serde(default)]
+    pub faults: Vec<FaultRule>,
+}
+
+/// The fault injected into the given operation. The rule matches the calls for the given partition (or all of them),
+/// skips the first `skip` of them and then fires `times` times (or forever), so the scenario is deterministic.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FaultRule {
+    pub operation: SegmentOperation,
+    pub fault: Fault,
+    #[serde(default)]
+    pub partition_id: Option<u32>,
+    #[serde(default)]
+    pub skip: u32,
+    #[serde(default)]
+    pub times: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentOperation {
+    SaveBatches,
+    SaveIndex,
+    SaveTimeIndex,
+    LoadMessages,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Fault {
+    /// Delays the operation by the given number of milliseconds and then performs it.
+    Delay { ms: u64 },
+    /// Fails the operation without performing it.
+    IoError,
+    /// Appends only the first bytes of the batches to the log and fails, leaving the torn entry at its end.
+    /// Supported by the `save_batches` operation only.
+    PartialWrite { bytes: u32 },
+    /// Performs the operation and fails afterwards, as if the data was written but the fsync has failed.
+    FsyncError,
+}
+
+impl FaultScenario {
+    pub fn load(path: &str) -> Result<Self, IggyError> {
+        let content = std::fs::read_to_string(path)?;
+        let scenario = Self::parse(&content)?;
+        Ok(scenario)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, IggyError> {
+        let scenario: FaultScenario = toml::from_str(content)
+            .map_err(|error| IggyError::CannotLoadResource(anyhow!(error)))?;
+        for rule in &scenario.faults {
+            if matches!(rule.fault, Fault::PartialWrite { .. })
+                && rule.operation != SegmentOperation::SaveBatches
+            {
+                return Err(IggyError::CannotLoadResource(anyhow!(
+                    "Partial write fault is supported by the save_batches operation only, found: {:?}",
+                    rule.operation
+                )));
+            }
+        }
+        Ok(scenario)
+    }
+}
+
+/// The test-only wrapper of the segment storage injecting the faults described by the scenario,
+/// to deterministically test the recovery paths, e.g. the torn writes or the fsync failures.
+pub struct FaultInjectingSegmentStorage {
+    inner: Arc<dyn SegmentStorage>,
+    rules: Vec<(FaultRule, AtomicU32)>,
+}
+
+impl Debug for FaultInjectingSegmentStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FaultInjectingSegmentStorage")
+            .field("inner", &self.inner)
+            .field("faults", &self.rules.len())
+            .finish()
+    }
+}
+
+impl FaultInjectingSegmentStorage {
+    pub fn new(inner: Arc<dyn SegmentStorage>, scenario: FaultScenario) -> Self {
+        Self {
+            inner,
+            rules: scenario
+                .faults
+                .into_iter()
+                .map(|rule| (rule, AtomicU32::new(0)))
+                .collect(),
+        }
+    }
+
+    /// Returns the fault of the first matching rule, which should fire for the current call.
+    /// The calls are counted per rule, so the rule skips and fires for the consecutive matching calls only.
+    fn next_fault(&self, operation: SegmentOperation, segment: &Segment) -> Option<Fault> {
+        for (rule, calls) in &self.rules {
+            if rule.operation != operation {
+                continue;
+            }
+            if rule
+                .partition_id
+                .is_some_and(|partition_id| partition_id != segment.partition_id)
+            {
+                continue;
+            }
+
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            if call < rule.skip {
+                continue;
+            }
+            if rule.times.is_some_and(|times| call - rule.skip >= times) {
+                continue;
+            }
+
+            warn!(
+                "Injecting fault: {:?} into operation: {:?} for segment: {}",
+                rule.fault, operation, segment.log_path
+            );
+            return Some(rule.fault.clone());
+        }
+        None
+    }
+
+    /// Applies the fault before the operation, returns the error if the operation should not be performed.
+    async fn before(
+        &self,
+        fault: &Option<Fault>,
+        operation: SegmentOperation,
+        segment: &Segment,
+    ) -> Result<(), IggyError> {
+        match fault {
+            None | Some(Fault::FsyncError) => Ok(()),
+            Some(Fault::Delay { ms }) => {
+                tokio::time::sleep(Duration::from_millis(*ms)).await;
+                Ok(())
+            }
+            Some(Fault::IoError) | Some(Fault::PartialWrite { .. }) => {
+                Err(injected_error(operation, segment))
+            }
+        }
+    }
+
+    /// Applies the fault after the operation has been performed.
+    fn after(
+        &self,
+        fault: &Option<Fault>,
+        operation: SegmentOperation,
+        segment: &Segment,
+    ) -> Result<(), IggyError> {
+        match fault {
+            Some(Fault::FsyncError) => Err(injected_error(operation, segment)),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn injected_error(operation: SegmentOperation, segment: &Segment) -> IggyError {
+    let error = anyhow!(
+        "Injected fault into operation: {:?} for segment: {}",
+        operation,
+        segment.log_path
+    );
+    match operation {
+        SegmentOperation::SaveBatches => IggyError::CannotSaveMessagesToSegment(error),
+        SegmentOperation::SaveIndex => IggyError::CannotSaveIndexToSegment(error),
+        SegmentOperation::SaveTimeIndex => IggyError::CannotSaveTimeIndexToSegment(error),
+        SegmentOperation::LoadMessages | SegmentOperation::Delete => {
+            IggyError::IoError(std::io::Error::other(error))
+        }
+    }
+}
+
+#[async_trait]
+impl Storage<Segment> for FaultInjectingSegmentStorage {
+    async fn load(&self, segment: &mut Segment) -> Result<(), IggyError> {
+        self.inner.load(segment).await
+    }
+
+    async fn save(&self, segment: &Segment) -> Result<(), IggyError> {
+        self.inner.save(segment).await
+    }
+
+    async fn delete(&self, segment: &Segment) -> Result<(), IggyError> {
+        let operation = SegmentOperation::Delete;
+        let fault = self.next_fault(operation, segment);
+        self.before(&fault, operation, segment).await?;
+        self.inner.delete(segment).await?;
+        self.after(&fault, operation, segment)
+    }
+}
+
+#[async_trait]
+impl SegmentStorage for FaultInjectingSegmentStorage {
+    async fn load_messages(
+        &self,
+        segment: &Segment,
+        index_range: &IndexRange,
+    ) -> Result<Vec<Arc<Message>>, IggyError> {
+        let operation = SegmentOperation::LoadMessages;
+        let fault = self.next_fault(operation, segment);
+        self.before(&fault, operation, segment).await?;
+        let messages = self.inner.load_messages(segment, index_range).await?;
+        self.after(&fault, operation, segment)?;
+        Ok(messages)
+    }
+
+    async fn load_newest_messages_by_size(
+        &self,
+        segment: &Segment,
+        size_bytes: u64,
+    ) -> Result<Vec<Arc<Message>>, IggyError> {
+        self.inner
+            .load_newest_messages_by_size(segment, size_bytes)
+            .await
+    }
+
+    async fn save_batches(
+        &self,
+        segment: &Segment,
+        batches: &[MessagesBatch],
+    ) -> Result<u32, IggyError> {
+        let operation = SegmentOperation::SaveBatches;
+        let fault = self.next_fault(operation, segment);
+        if let Some(Fault::PartialWrite { bytes }) = fault {
+            let mut serialized = BytesMut::new();
+            for batch in batches {
+                batch.extend(&mut serialized);
+            }
+            let length = serialized.len().min(bytes as usize);
+            let mut log = file::append(&segment.log_path).await?;
+            log.write_all(&serialized[..length]).await?;
+            log.sync_all().await?;
+            return Err(injected_error(operation, segment));
+        }
+
+        self.before(&fault, operation, segment).await?;
+        let size = self.inner.save_batches(segment, batches).await?;
+        self.after(&fault, operation, segment)?;
+        Ok(size)
+    }
+
+    async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, IggyError> {
+        self.inner.load_message_ids(segment).await
+    }
+
+    async fn load_checksums(&self, segment: &Segment) -> Result<(), IggyError> {
+        self.inner.load_checksums(segment).await
+    }
+
+    async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, IggyError> {
+        self.inner.load_all_indexes(segment).await
+    }
+
+    async fn load_index_range(
+        &self,
+        segment: &Segment,
+        segment_start_offset: u64,
+        index_start_offset: u64,
+        index_end_offset: u64,
+    ) -> Result<Option<IndexRange>, IggyError> {
+        self.inner
+            .load_index_range(
+                segment,
+                segment_start_offset,
+                index_start_offset,
+                index_end_offset,
+            )
+            .await
+    }
+
+    async fn save_index(
+        &self,
+        segment: &Segment,
+        current_position: u32,
+        batches: &[MessagesBatch],
+    ) -> Result<(), IggyError> {
+        let operation = SegmentOperation::SaveIndex;
+        let fault = self.next_fault(operation, segment);
+        self.before(&fault, operation, segment).await?;
+        self.inner
+            .save_index(segment, current_position, batches)
+            .await?;
+        self.after(&fault, operation, segment)
+    }
+
+    async fn load_all_time_indexes(&self, segment: &Segment) -> Result<Vec<TimeIndex>, IggyError> {
+        self.inner.load_all_time_indexes(segment).await
+    }
+
+    async fn load_last_time_index(
+        &self,
+        segment: &Segment,
+    ) -> Result<Option<TimeIndex>, IggyError> {
+        self.inner.load_last_time_index(segment).await
+    }
+
+    async fn save_time_index(
+        &self,
+        segment: &Segment,
+        batches: &[MessagesBatch],
+    ) -> Result<(), IggyError> {
+        let operation = SegmentOperation::SaveTimeIndex;
+        let fault = self.next_fault(operation, segment);
+        self.before(&fault, operation, segment).await?;
+        self.inner.save_time_index(segment, batches).await?;
+        self.after(&fault, operation, segment)
+    }
+
+    fn set_clean_shutdown(&self, clean_shutdown: bool) {
+        self.inner.set_clean_shutdown(clean_shutdown);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scenario_should_be_parsed() {
+        let scenario = FaultScenario::parse(
+            r#"
+            [[faults]]
+            operation = "save_batches"
+            partition_id = 1
+            skip = 2
+            times = 1
+            fault = { type = "partial_write", bytes = 10 }
+
+            [[faults]]
+            operation = "save_index"
+            fault = { type = "fsync_error" }
+
+            [[faults]]
+            operation = "load_messages"
+            fault = { type = "delay", ms = 100 }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(scenario.faults.len(), 3);
+        let rule = &scenario.faults[0];
+        assert_eq!(rule.operation, SegmentOperation::SaveBatches);
+        assert_eq!(rule.fault, Fault::PartialWrite { bytes: 10 });
+        assert_eq!(rule.partition_id, Some(1));
+        assert_eq!(rule.skip, 2);
+        assert_eq!(rule.times, Some(1));
+        let rule = &scenario.faults[1];
+        assert_eq!(rule.fault, Fault::FsyncError);
+        assert_eq!(rule.partition_id, None);
+        assert_eq!(rule.skip, 0);
+        assert_eq!(rule.times, None);
+        assert_eq!(scenario.faults[2].fault, Fault::Delay { ms: 100 });
+    }
+
+    #[test]
+    fn partial_write_should_be_rejected_for_operation_other_than_save_batches() {
+        let scenario = FaultScenario::parse(
+            r#"
+            [[faults]]
+            operation = "save_index"
+            fault = { type = "partial_write", bytes = 10 }
+            "#,
+        );
+
+        assert!(scenario.is_err());
+    }
+}
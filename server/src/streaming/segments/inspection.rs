@@ -0,0 +1,566 @@
+use crate::streaming::models::messages_batch::MessagesBatch;
+use crate::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
+use crate::streaming::segments::storage::{
+    read_log_entry, read_segment_format_version, LogEntry, SegmentFileKind,
+    BUF_READER_CAPACITY_BYTES, INDEX_SIZE, SEGMENT_FORMAT_VERSION, SEGMENT_HEADER_SIZE,
+    TIME_INDEX_SIZE,
+};
+use crate::streaming::utils::file;
+use iggy::error::IggyError;
+use iggy::models::messages::Message;
+use iggy::utils::checksum;
+use std::fmt::{Display, Formatter};
+use std::io::SeekFrom;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::fs;
+use tokio::io::{AsyncSeekExt, BufReader};
+
+/// The log, index and time index files of the segment, inspected directly from disk, without the running server.
+#[derive(Debug, Clone)]
+pub struct SegmentFiles {
+    pub log_path: String,
+    pub index_path: String,
+    pub time_index_path: String,
+    /// The start offset of the segment, parsed from the file name, if it follows the server naming.
+    pub start_offset: Option<u64>,
+}
+
+/// The header of the batch (format v2) the messages have been stored in.
+#[derive(Debug, Clone, Copy)]
+pub struct BatchHeader {
+    pub base_offset: u64,
+    pub base_timestamp: u64,
+    pub max_timestamp: u64,
+    pub producer_id: u64,
+    pub producer_epoch: u16,
+}
+
+/// The entry read from the segment log, either the batch of messages or the single message (format v1).
+/// The position doesn't include the header of the log, same as the positions stored in the index file.
+#[derive(Debug)]
+pub struct InspectedEntry {
+    pub position: u64,
+    pub size: u64,
+    /// The header of the batch, `None` for the single message stored by the older server versions.
+    pub batch: Option<BatchHeader>,
+    pub messages: Vec<Arc<Message>>,
+}
+
+/// The inconsistency found in the segment files.
+#[derive(Debug)]
+pub enum SegmentIssue {
+    /// The checksum of the message doesn't match its payload.
+    InvalidChecksum {
+        offset: u64,
+        checksum: u32,
+        calculated_checksum: u32,
+    },
+    /// The offset of the message doesn't follow the previous one.
+    UnexpectedOffset { offset: u64, expected_offset: u64 },
+    /// The index of the message doesn't point to the log entry containing it.
+    InvalidIndex {
+        offset: u64,
+        position: u64,
+        expected_position: u64,
+    },
+    /// The time index of the message doesn't match its timestamp.
+    InvalidTimeIndex {
+        offset: u64,
+        timestamp: u64,
+        expected_timestamp: u64,
+    },
+    /// The number of the entries in the index file doesn't match the number of the messages in the log.
+    IndexesCountMismatch {
+        indexes_count: u64,
+        messages_count: u64,
+    },
+    /// The number of the entries in the time index file doesn't match the number of the messages in the log.
+    TimeIndexesCountMismatch {
+        time_indexes_count: u64,
+        messages_count: u64,
+    },
+    /// The index file ends with an incomplete entry.
+    IncompleteIndexFile { path: String, trailing_bytes: u64 },
+    /// The header of the file contains the format version, which is not supported, so the file is not inspected.
+    UnsupportedFormatVersion { path: String, version: u32 },
+    /// The entry at the position can't be read, so the rest of the log is not inspected.
+    CorruptedEntry {
+        position: u64,
+        unread_bytes: u64,
+        error: IggyError,
+    },
+    /// The log ends with an incomplete entry, e.g. when the server has stopped in the middle of persisting it.
+    IncompleteEntry { position: u64, unread_bytes: u64 },
+}
+
+/// The summary of the inspected segment along with the issues found.
+#[derive(Debug, Default)]
+pub struct SegmentReport {
+    pub log_size: u64,
+    pub entries_count: u64,
+    pub messages_count: u64,
+    pub first_offset: Option<u64>,
+    pub last_offset: Option<u64>,
+    pub indexes_count: u64,
+    pub time_indexes_count: u64,
+    pub issues: Vec<SegmentIssue>,
+}
+
+impl SegmentFiles {
+    /// Resolves the segment files from the path of any of them, or the path without the extension.
+    pub fn from_path(path: &str) -> Self {
+        let path = Path::new(path);
+        let base_path = match path.extension().and_then(|extension| extension.to_str()) {
+            Some(LOG_EXTENSION | INDEX_EXTENSION | TIME_INDEX_EXTENSION) => path.with_extension(""),
+            _ => path.to_path_buf(),
+        };
+        let start_offset = base_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<u64>().ok());
+        let base_path = base_path.to_string_lossy();
+        Self {
+            log_path: format!("{base_path}.{LOG_EXTENSION}"),
+            index_path: format!("{base_path}.{INDEX_EXTENSION}"),
+            time_index_path: format!("{base_path}.{TIME_INDEX_EXTENSION}"),
+            start_offset,
+        }
+    }
+}
+
+impl SegmentReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Reads all the entries of the segment log, passing each of them to the callback, and validates the checksums of the messages,
+/// the continuity of their offsets and whether the index and time index files are consistent with the log.
+/// The missing index files are treated as empty, as the server recreates them from the log on startup.
+/// The files written before the segment format versioning (without the header) are inspected as well.
+pub async fn inspect_segment(
+    files: &SegmentFiles,
+    mut on_entry: impl FnMut(&InspectedEntry),
+) -> Result<SegmentReport, IggyError> {
+    let mut report = SegmentReport::default();
+    let indexes = read_index_file(
+        &files.index_path,
+        SegmentFileKind::Index,
+        INDEX_SIZE,
+        &mut report,
+    )
+    .await?;
+    let time_indexes = read_index_file(
+        &files.time_index_path,
+        SegmentFileKind::TimeIndex,
+        TIME_INDEX_SIZE,
+        &mut report,
+    )
+    .await?;
+    report.indexes_count = indexes.len() as u64;
+    report.time_indexes_count = time_indexes.len() as u64;
+
+    let header_size =
+        match read_segment_format_version(&files.log_path, SegmentFileKind::Log).await? {
+            Some(version) if version > SEGMENT_FORMAT_VERSION => {
+                report.issues.push(SegmentIssue::UnsupportedFormatVersion {
+                    path: files.log_path.clone(),
+                    version,
+                });
+                return Ok(report);
+            }
+            Some(_) => SEGMENT_HEADER_SIZE,
+            None => 0,
+        };
+    let mut log_file = file::open(&files.log_path).await?;
+    report.log_size = log_file.metadata().await?.len() - header_size;
+    log_file.seek(SeekFrom::Start(header_size)).await?;
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, log_file);
+    let mut position = 0;
+    let mut expected_offset = files.start_offset;
+    loop {
+        let (entry, size) = match read_log_entry(&mut reader).await {
+            Ok(Some(entry)) => entry,
+            Ok(None) => {
+                if position < report.log_size {
+                    report.issues.push(SegmentIssue::IncompleteEntry {
+                        position,
+                        unread_bytes: report.log_size - position,
+                    });
+                }
+                break;
+            }
+            Err(error) => {
+                report.issues.push(SegmentIssue::CorruptedEntry {
+                    position,
+                    unread_bytes: report.log_size - position,
+                    error,
+                });
+                break;
+            }
+        };
+
+        let batch = match &entry {
+            LogEntry::Batch(batch) => Some(BatchHeader::from(batch)),
+            LogEntry::Message(_) => None,
+        };
+        let entry = InspectedEntry {
+            position,
+            size,
+            batch,
+            messages: entry.into_messages(),
+        };
+
+        for message in &entry.messages {
+            if let Some(expected_offset) = expected_offset {
+                if message.offset != expected_offset {
+                    report.issues.push(SegmentIssue::UnexpectedOffset {
+                        offset: message.offset,
+                        expected_offset,
+                    });
+                }
+            }
+            expected_offset = Some(message.offset + 1);
+
+            let calculated_checksum = checksum::calculate(&message.payload);
+            if calculated_checksum != message.checksum {
+                report.issues.push(SegmentIssue::InvalidChecksum {
+                    offset: message.offset,
+                    checksum: message.checksum,
+                    calculated_checksum,
+                });
+            }
+
+            // The index of each message points to the entry it's stored in.
+            let message_index = report.messages_count as usize;
+            if let Some(index_position) = indexes.get(message_index) {
+                if *index_position != position {
+                    report.issues.push(SegmentIssue::InvalidIndex {
+                        offset: message.offset,
+                        position: *index_position,
+                        expected_position: position,
+                    });
+                }
+            }
+
+            if let Some(timestamp) = time_indexes.get(message_index) {
+                if *timestamp != message.timestamp {
+                    report.issues.push(SegmentIssue::InvalidTimeIndex {
+                        offset: message.offset,
+                        timestamp: *timestamp,
+                        expected_timestamp: message.timestamp,
+                    });
+                }
+            }
+
+            report.first_offset.get_or_insert(message.offset);
+            report.last_offset = Some(message.offset);
+            report.messages_count += 1;
+        }
+
+        on_entry(&entry);
+        report.entries_count += 1;
+        position += size;
+    }
+
+    if report.indexes_count != report.messages_count {
+        report.issues.push(SegmentIssue::IndexesCountMismatch {
+            indexes_count: report.indexes_count,
+            messages_count: report.messages_count,
+        });
+    }
+
+    if report.time_indexes_count != report.messages_count {
+        report.issues.push(SegmentIssue::TimeIndexesCountMismatch {
+            time_indexes_count: report.time_indexes_count,
+            messages_count: report.messages_count,
+        });
+    }
+
+    Ok(report)
+}
+
+/// Reads the little-endian entries of the given size following the header (if any),
+/// the incomplete entry at the end of the file is reported and skipped.
+async fn read_index_file(
+    path: &str,
+    kind: SegmentFileKind,
+    entry_size: u32,
+    report: &mut SegmentReport,
+) -> Result<Vec<u64>, IggyError> {
+    if !Path::new(path).exists() {
+        return Ok(Vec::new());
+    }
+
+    let bytes = fs::read(path).await?;
+    let bytes = match kind.read_format_version(&bytes) {
+        Some(version) if version > SEGMENT_FORMAT_VERSION => {
+            report.issues.push(SegmentIssue::UnsupportedFormatVersion {
+                path: path.to_string(),
+                version,
+            });
+            return Ok(Vec::new());
+        }
+        Some(_) => &bytes[SEGMENT_HEADER_SIZE as usize..],
+        None => &bytes[..],
+    };
+    let entry_size = entry_size as usize;
+    let trailing_bytes = bytes.len() % entry_size;
+    if trailing_bytes > 0 {
+        report.issues.push(SegmentIssue::IncompleteIndexFile {
+            path: path.to_string(),
+            trailing_bytes: trailing_bytes as u64,
+        });
+    }
+
+    Ok(bytes
+        .chunks_exact(entry_size)
+        .map(|entry| {
+            let mut value = [0; 8];
+            value[..entry_size].copy_from_slice(entry);
+            u64::from_le_bytes(value)
+        })
+        .collect())
+}
+
+impl From<&MessagesBatch> for BatchHeader {
+    fn from(batch: &MessagesBatch) -> Self {
+        Self {
+            base_offset: batch.base_offset,
+            base_timestamp: batch.base_timestamp,
+            max_timestamp: batch.max_timestamp,
+            producer_id: batch.producer_id,
+            producer_epoch: batch.producer_epoch,
+        }
+    }
+}
+
+impl Display for SegmentIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SegmentIssue::InvalidChecksum {
+                offset,
+                checksum,
+                calculated_checksum,
+            } => write!(
+                f,
+                "Invalid checksum: {checksum} of message with offset: {offset}, calculated: {calculated_checksum}."
+            ),
+            SegmentIssue::UnexpectedOffset {
+                offset,
+                expected_offset,
+            } => write!(
+                f,
+                "Unexpected offset: {offset} of message, expected: {expected_offset}."
+            ),
+            SegmentIssue::InvalidIndex {
+                offset,
+                position,
+                expected_position,
+            } => write!(
+                f,
+                "Invalid index position: {position} of message with offset: {offset}, expected: {expected_position}."
+            ),
+            SegmentIssue::InvalidTimeIndex {
+                offset,
+                timestamp,
+                expected_timestamp,
+            } => write!(
+                f,
+                "Invalid time index timestamp: {timestamp} of message with offset: {offset}, expected: {expected_timestamp}."
+            ),
+            SegmentIssue::IndexesCountMismatch {
+                indexes_count,
+                messages_count,
+            } => write!(
+                f,
+                "Index file contains: {indexes_count} entries, expected: {messages_count}."
+            ),
+            SegmentIssue::TimeIndexesCountMismatch {
+                time_indexes_count,
+                messages_count,
+            } => write!(
+                f,
+                "Time index file contains: {time_indexes_count} entries, expected: {messages_count}."
+            ),
+            SegmentIssue::IncompleteIndexFile {
+                path,
+                trailing_bytes,
+            } => write!(
+                f,
+                "Index file: {path} ends with an incomplete entry of {trailing_bytes} bytes."
+            ),
+            SegmentIssue::UnsupportedFormatVersion { path, version } => write!(
+                f,
+                "File: {path} has unsupported format version: {version}, expected at most: {SEGMENT_FORMAT_VERSION}."
+            ),
+            SegmentIssue::CorruptedEntry {
+                position,
+                unread_bytes,
+                error,
+            } => write!(
+                f,
+                "Cannot read log entry at position: {position}, {unread_bytes} bytes left unread. Error: {error}"
+            ),
+            SegmentIssue::IncompleteEntry {
+                position,
+                unread_bytes,
+            } => write!(
+                f,
+                "Log ends with an incomplete entry of {unread_bytes} bytes at position: {position}."
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{BufMut, Bytes, BytesMut};
+    use iggy::models::messages::MessageState;
+    use iggy::sizeable::Sizeable;
+    use std::path::PathBuf;
+
+    fn create_batch(base_offset: u64, count: u64) -> MessagesBatch {
+        let messages = (base_offset..base_offset + count)
+            .map(|offset| {
+                let payload = Bytes::from(format!("message {offset}"));
+                Arc::new(Message::create(
+                    offset,
+                    MessageState::Available,
+                    1000 + offset,
+                    offset as u128,
+                    payload.clone(),
+                    checksum::calculate(&payload),
+                    None,
+                ))
+            })
+            .collect();
+        MessagesBatch::new(messages)
+    }
+
+    async fn write_segment(
+        directory: &Path,
+        batches: &[MessagesBatch],
+        with_headers: bool,
+    ) -> SegmentFiles {
+        fs::create_dir_all(directory).await.unwrap();
+        let files = SegmentFiles::from_path(
+            &directory
+                .join(format!("{:0>20}.log", batches[0].base_offset))
+                .to_string_lossy(),
+        );
+        let mut log = BytesMut::new();
+        let mut indexes = BytesMut::new();
+        let mut time_indexes = BytesMut::new();
+        for batch in batches {
+            for message in &batch.messages {
+                indexes.put_u32_le(log.len() as u32);
+                time_indexes.put_u64_le(message.timestamp);
+            }
+            batch.extend(&mut log);
+        }
+        if with_headers {
+            log = prepend_header(SegmentFileKind::Log, log);
+            indexes = prepend_header(SegmentFileKind::Index, indexes);
+            time_indexes = prepend_header(SegmentFileKind::TimeIndex, time_indexes);
+        }
+        fs::write(&files.log_path, &log).await.unwrap();
+        fs::write(&files.index_path, &indexes).await.unwrap();
+        fs::write(&files.time_index_path, &time_indexes)
+            .await
+            .unwrap();
+        files
+    }
+
+    fn prepend_header(kind: SegmentFileKind, bytes: BytesMut) -> BytesMut {
+        let mut file = BytesMut::from(&kind.header()[..]);
+        file.extend_from_slice(&bytes);
+        file
+    }
+
+    fn get_test_directory() -> PathBuf {
+        std::env::temp_dir().join(format!("iggy-inspection-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn valid_segment_should_have_no_issues() {
+        for with_headers in [true, false] {
+            let directory = get_test_directory();
+            let batches = [create_batch(10, 3), create_batch(13, 2)];
+            let files = write_segment(&directory, &batches, with_headers).await;
+
+            let mut entries = Vec::new();
+            let report = inspect_segment(&files, |entry| {
+                entries.push((entry.position, entry.messages.len()))
+            })
+            .await
+            .unwrap();
+            fs::remove_dir_all(&directory).await.unwrap();
+
+            assert!(report.is_valid(), "{:?}", report.issues);
+            assert_eq!(files.start_offset, Some(10));
+            assert_eq!(report.entries_count, 2);
+            assert_eq!(report.messages_count, 5);
+            assert_eq!(report.first_offset, Some(10));
+            assert_eq!(report.last_offset, Some(14));
+            assert_eq!(
+                report.log_size,
+                batches
+                    .iter()
+                    .map(|batch| batch.get_size_bytes() as u64)
+                    .sum::<u64>()
+            );
+            assert_eq!(
+                entries,
+                vec![(0, 3), (batches[0].get_size_bytes() as u64, 2)]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn corrupted_segment_should_be_reported() {
+        let directory = get_test_directory();
+        let files =
+            write_segment(&directory, &[create_batch(0, 2), create_batch(2, 2)], true).await;
+
+        // Flip the last byte of the payload of the last message and append the incomplete entry.
+        let mut log = fs::read(&files.log_path).await.unwrap();
+        *log.last_mut().unwrap() ^= 0xFF;
+        log.extend_from_slice(&[1, 2, 3]);
+        fs::write(&files.log_path, &log).await.unwrap();
+        // Drop the last time index.
+        let time_indexes = fs::read(&files.time_index_path).await.unwrap();
+        fs::write(
+            &files.time_index_path,
+            &time_indexes[..time_indexes.len() - TIME_INDEX_SIZE as usize],
+        )
+        .await
+        .unwrap();
+
+        let report = inspect_segment(&files, |_| {}).await.unwrap();
+        fs::remove_dir_all(&directory).await.unwrap();
+
+        assert_eq!(report.messages_count, 4);
+        assert_eq!(report.issues.len(), 3, "{:?}", report.issues);
+        assert!(matches!(
+            report.issues[0],
+            SegmentIssue::InvalidChecksum { offset: 3, .. }
+        ));
+        assert!(matches!(
+            report.issues[1],
+            SegmentIssue::IncompleteEntry {
+                unread_bytes: 3,
+                ..
+            }
+        ));
+        assert!(matches!(
+            report.issues[2],
+            SegmentIssue::TimeIndexesCountMismatch {
+                time_indexes_count: 3,
+                messages_count: 4
+            }
+        ));
+    }
+}
@@ -1,8 +1,11 @@
+use crate::streaming::cache::memory_budget::MemoryBudget;
+use crate::streaming::models::messages_batch::MessagesBatch;
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::time_index::TimeIndex;
 use iggy::error::IggyError;
 use iggy::models::messages::Message;
+use iggy::sizeable::Sizeable;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tracing::trace;
@@ -37,21 +40,21 @@ impl Segment {
         }
 
         // In case that the partition messages buffer is disabled, we need to check the unsaved messages buffer
-        if self.unsaved_messages.is_none() {
+        if self.unsaved_batches.is_none() {
             return self.load_messages_from_disk(offset, end_offset).await;
         }
 
-        let unsaved_messages = self.unsaved_messages.as_ref().unwrap();
-        if unsaved_messages.is_empty() {
+        let unsaved_batches = self.unsaved_batches.as_ref().unwrap();
+        if unsaved_batches.is_empty() {
             return self.load_messages_from_disk(offset, end_offset).await;
         }
 
-        let first_offset = unsaved_messages[0].offset;
+        let first_offset = unsaved_batches[0].base_offset;
         if end_offset < first_offset {
             return self.load_messages_from_disk(offset, end_offset).await;
         }
 
-        let last_offset = unsaved_messages[unsaved_messages.len() - 1].offset;
+        let last_offset = unsaved_batches[unsaved_batches.len() - 1].get_last_offset();
         if end_offset <= last_offset {
             return Ok(self.load_messages_from_unsaved_buffer(offset, end_offset));
         }
@@ -82,10 +85,11 @@ impl Segment {
     }
 
     fn load_messages_from_unsaved_buffer(&self, offset: u64, end_offset: u64) -> Vec<Arc<Message>> {
-        self.unsaved_messages
+        self.unsaved_batches
             .as_ref()
             .unwrap()
             .iter()
+            .flat_map(|batch| &batch.messages)
             .filter(|message| message.offset >= offset && message.offset <= end_offset)
             .cloned()
             .collect::<Vec<Arc<Message>>>()
@@ -185,85 +189,58 @@ impl Segment {
             ));
         }
 
+        if messages.is_empty() {
+            return Ok(());
+        }
+
         let messages_count = messages.len();
+        let cached_indexes_size = self.get_cached_indexes_size_bytes();
+        if self.lazy_indexes {
+            self.indexes = Some(self.storage.segment.load_all_indexes(self).await?);
+            self.lazy_indexes = false;
+        }
 
-        let unsaved_messages = self.unsaved_messages.get_or_insert_with(Vec::new);
-        unsaved_messages.reserve(messages_count);
+        // All the appended messages are stored on disk in a single batch, so their indexes point to the position of the batch.
+        let batch = MessagesBatch::new(messages.to_vec());
+        let batch_size = batch.get_size_bytes();
+        let position = self.size_bytes;
 
         if let Some(indexes) = &mut self.indexes {
             indexes.reserve(messages_count);
-        }
-
-        if let Some(time_indexes) = &mut self.time_indexes {
-            time_indexes.reserve(messages_count);
-        }
-
-        // Not the prettiest code. It's done this way to avoid repeatably
-        // checking if indexes and time_indexes are Some or None.
-        let mut messages_size = 0;
-        if self.indexes.is_some() && self.time_indexes.is_some() {
             for message in messages {
-                let relative_offset = (message.offset - self.start_offset) as u32;
-
-                self.indexes.as_mut().unwrap().push(Index {
-                    relative_offset,
-                    position: self.size_bytes,
-                });
-
-                self.time_indexes.as_mut().unwrap().push(TimeIndex {
-                    relative_offset,
-                    timestamp: message.timestamp,
+                indexes.push(Index {
+                    relative_offset: (message.offset - self.start_offset) as u32,
+                    position,
                 });
-                let message_size = message.get_size_bytes();
-                self.size_bytes += message_size;
-                messages_size += message_size;
-                self.current_offset = message.offset;
-                unsaved_messages.push(message.clone());
             }
-        } else if self.indexes.is_some() {
-            for message in messages {
-                let relative_offset = (message.offset - self.start_offset) as u32;
+        }
 
-                self.indexes.as_mut().unwrap().push(Index {
-                    relative_offset,
-                    position: self.size_bytes,
-                });
-                let message_size = message.get_size_bytes();
-                self.size_bytes += message_size;
-                messages_size += message_size;
-                self.current_offset = message.offset;
-                unsaved_messages.push(message.clone());
-            }
-        } else if self.time_indexes.is_some() {
+        if let Some(time_indexes) = &mut self.time_indexes {
+            time_indexes.reserve(messages_count);
             for message in messages {
-                let relative_offset = (message.offset - self.start_offset) as u32;
-
-                self.time_indexes.as_mut().unwrap().push(TimeIndex {
-                    relative_offset,
+                time_indexes.push(TimeIndex {
+                    relative_offset: (message.offset - self.start_offset) as u32,
                     timestamp: message.timestamp,
                 });
-                let message_size = message.get_size_bytes();
-                self.size_bytes += message_size;
-                messages_size += message_size;
-                self.current_offset = message.offset;
-                unsaved_messages.push(message.clone());
-            }
-        } else {
-            for message in messages {
-                let message_size = message.get_size_bytes();
-                self.size_bytes += message_size;
-                messages_size += message_size;
-                self.current_offset = message.offset;
-                unsaved_messages.push(message.clone());
             }
         }
 
+        let unsaved_messages_size = messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum::<u64>();
+        self.size_bytes += batch_size;
+        self.current_offset = batch.get_last_offset();
+        self.unsaved_batches
+            .get_or_insert_with(Vec::new)
+            .push(batch);
+
         self.size_of_parent_stream
-            .fetch_add(messages_size as u64, Ordering::SeqCst);
+            .fetch_add(batch_size as u64, Ordering::SeqCst);
         self.size_of_parent_topic
-            .fetch_add(messages_size as u64, Ordering::SeqCst);
+            .fetch_add(batch_size as u64, Ordering::SeqCst);
         self.size_of_parent_partition
-            .fetch_add(messages_size as u64, Ordering::SeqCst);
+            .fetch_add(batch_size as u64, Ordering::SeqCst);
         self.messages_count_of_parent_stream
             .fetch_add(messages_count as u64, Ordering::SeqCst);
         self.messages_count_of_parent_topic
@@ -271,48 +248,60 @@ impl Segment {
         self.messages_count_of_parent_partition
             .fetch_add(messages_count as u64, Ordering::SeqCst);
 
+        let memory_budget = MemoryBudget::get_instance();
+        memory_budget.increment_unsaved_messages(unsaved_messages_size);
+        memory_budget
+            .increment_cached_indexes(self.get_cached_indexes_size_bytes() - cached_indexes_size);
+
         Ok(())
     }
 
     pub async fn persist_messages(&mut self) -> Result<(), IggyError> {
         let storage = self.storage.segment.clone();
-        if self.unsaved_messages.is_none() {
+        if self.unsaved_batches.is_none() {
             return Ok(());
         }
 
-        let unsaved_messages = self.unsaved_messages.as_ref().unwrap();
-        if unsaved_messages.is_empty() {
+        let unsaved_batches = self.unsaved_batches.as_ref().unwrap();
+        if unsaved_batches.is_empty() {
             return Ok(());
         }
 
+        let unsaved_messages_count = unsaved_batches
+            .iter()
+            .map(|batch| batch.messages.len())
+            .sum::<usize>();
         trace!(
-            "Saving {} messages on disk in segment with start offset: {} for partition with ID: {}...",
-            unsaved_messages.len(),
+            "Saving {} messages in {} batches on disk in segment with start offset: {} for partition with ID: {}...",
+            unsaved_messages_count,
+            unsaved_batches.len(),
             self.start_offset,
             self.partition_id
         );
 
-        let saved_bytes = storage.save_messages(self, unsaved_messages).await?;
+        let saved_bytes = storage.save_batches(self, unsaved_batches).await?;
         let current_position = self.size_bytes - saved_bytes;
         storage
-            .save_index(self, current_position, unsaved_messages)
+            .save_index(self, current_position, unsaved_batches)
             .await?;
-        storage.save_time_index(self, unsaved_messages).await?;
+        storage.save_time_index(self, unsaved_batches).await?;
 
         trace!(
             "Saved {} messages on disk in segment with start offset: {} for partition with ID: {}, total bytes written: {}.",
-            unsaved_messages.len(),
+            unsaved_messages_count,
             self.start_offset,
             self.partition_id,
             saved_bytes
         );
 
+        MemoryBudget::get_instance()
+            .decrement_unsaved_messages(self.get_unsaved_messages_size_bytes());
         if self.is_full().await {
             self.end_offset = self.current_offset;
             self.is_closed = true;
-            self.unsaved_messages = None;
+            self.unsaved_batches = None;
         } else {
-            self.unsaved_messages.as_mut().unwrap().clear();
+            self.unsaved_batches.as_mut().unwrap().clear();
         }
 
         Ok(())
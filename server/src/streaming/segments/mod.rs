@@ -1,4 +1,7 @@
+#[cfg(feature = "fault-injection")]
+pub mod fault_injection;
 pub mod index;
+pub mod inspection;
 pub mod messages;
 pub mod persistence;
 pub mod segment;
@@ -1,9 +1,12 @@
 use crate::configs::system::SystemConfig;
+use crate::streaming::cache::memory_budget::MemoryBudget;
+use crate::streaming::models::messages_batch::MessagesBatch;
 use crate::streaming::segments::index::Index;
 use crate::streaming::segments::time_index::TimeIndex;
 use crate::streaming::storage::SystemStorage;
-use iggy::models::messages::Message;
+use iggy::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
+use std::mem::size_of;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
@@ -32,9 +35,12 @@ pub struct Segment {
     pub messages_count_of_parent_partition: Arc<AtomicU64>,
     pub is_closed: bool,
     pub(crate) message_expiry: Option<u32>,
-    pub(crate) unsaved_messages: Option<Vec<Arc<Message>>>,
+    pub(crate) unsaved_batches: Option<Vec<MessagesBatch>>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) indexes: Option<Vec<Index>>,
+    /// Set when the indexes are cached, but loading them from disk has been deferred on startup until the first append,
+    /// while the messages are read using the indexes stored on disk in the meantime.
+    pub(crate) lazy_indexes: bool,
     pub(crate) time_indexes: Option<Vec<TimeIndex>>,
     pub(crate) storage: Arc<SystemStorage>,
 }
@@ -74,11 +80,12 @@ impl Segment {
                 true => Some(Vec::new()),
                 false => None,
             },
+            lazy_indexes: false,
             time_indexes: match config.segment.cache_time_indexes {
                 true => Some(Vec::new()),
                 false => None,
             },
-            unsaved_messages: None,
+            unsaved_batches: None,
             is_closed: false,
             size_of_parent_stream,
             size_of_parent_partition,
@@ -104,19 +111,73 @@ impl Segment {
             return false;
         }
 
-        let last_messages = self.get_messages(self.end_offset, 1).await;
-        if last_messages.is_err() {
-            return false;
-        }
+        // The last message isn't necessarily the newest one if the create time provided by the producers is stored,
+        // so the newest timestamp is taken from the time indexes, if they are cached.
+        let newest_timestamp = self.time_indexes.as_ref().and_then(|time_indexes| {
+            time_indexes
+                .iter()
+                .map(|time_index| time_index.timestamp)
+                .max()
+        });
+        let newest_timestamp = match newest_timestamp {
+            Some(timestamp) => timestamp,
+            None => {
+                let last_messages = self.get_messages(self.end_offset, 1).await;
+                if last_messages.is_err() {
+                    return false;
+                }
 
-        let last_messages = last_messages.unwrap();
-        if last_messages.is_empty() {
-            return false;
-        }
+                let last_messages = last_messages.unwrap();
+                if last_messages.is_empty() {
+                    return false;
+                }
+
+                last_messages[0].timestamp
+            }
+        };
 
-        let last_message = last_messages[0].as_ref();
         let message_expiry = (self.message_expiry.unwrap() * 1000) as u64;
-        (last_message.timestamp + message_expiry) <= now
+        (newest_timestamp + message_expiry) <= now
+    }
+
+    /// Returns the size of the indexes and time indexes cached in memory.
+    pub fn get_cached_indexes_size_bytes(&self) -> u64 {
+        let indexes_size = self
+            .indexes
+            .as_ref()
+            .map_or(0, |indexes| indexes.len() * size_of::<Index>());
+        let time_indexes_size = self.time_indexes.as_ref().map_or(0, |time_indexes| {
+            time_indexes.len() * size_of::<TimeIndex>()
+        });
+        (indexes_size + time_indexes_size) as u64
+    }
+
+    /// Returns the size of the messages which haven't been persisted on disk yet.
+    pub fn get_unsaved_messages_size_bytes(&self) -> u64 {
+        self.unsaved_batches.as_ref().map_or(0, |batches| {
+            batches
+                .iter()
+                .flat_map(|batch| &batch.messages)
+                .map(|message| message.get_size_bytes() as u64)
+                .sum()
+        })
+    }
+
+    /// Drops the cached indexes of the closed segment to release the memory, the messages are then loaded using the indexes stored on disk.
+    /// The time indexes are kept, as they are required to find the messages by timestamp.
+    /// Returns the number of released bytes.
+    pub fn evict_cached_indexes(&mut self) -> u64 {
+        if !self.is_closed {
+            return 0;
+        }
+
+        let Some(indexes) = self.indexes.take() else {
+            return 0;
+        };
+
+        let released_bytes = (indexes.len() * size_of::<Index>()) as u64;
+        MemoryBudget::get_instance().decrement_cached_indexes(released_bytes);
+        released_bytes
     }
 
     fn get_log_path(path: &str) -> String {
@@ -132,6 +193,14 @@ impl Segment {
     }
 }
 
+impl Drop for Segment {
+    fn drop(&mut self) {
+        let memory_budget = MemoryBudget::get_instance();
+        memory_budget.decrement_unsaved_messages(self.get_unsaved_messages_size_bytes());
+        memory_budget.decrement_cached_indexes(self.get_cached_indexes_size_bytes());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,7 +254,7 @@ mod tests {
         assert_eq!(segment.index_path, index_path);
         assert_eq!(segment.time_index_path, time_index_path);
         assert_eq!(segment.message_expiry, message_expiry);
-        assert!(segment.unsaved_messages.is_none());
+        assert!(segment.unsaved_batches.is_none());
         assert!(segment.indexes.is_some());
         assert!(segment.time_indexes.is_some());
         assert!(!segment.is_closed);
@@ -272,4 +341,37 @@ mod tests {
         );
         assert!(segment.time_indexes.is_none());
     }
+
+    #[test]
+    fn should_evict_cached_indexes_only_when_closed() {
+        let storage = Arc::new(get_test_system_storage());
+        let config = Arc::new(SystemConfig::default());
+        let mut segment = Segment::create(
+            1,
+            2,
+            3,
+            0,
+            config,
+            storage,
+            None,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+        );
+        segment.indexes.as_mut().unwrap().push(Index {
+            relative_offset: 0,
+            position: 0,
+        });
+
+        assert_eq!(segment.evict_cached_indexes(), 0);
+        assert!(segment.indexes.is_some());
+
+        segment.is_closed = true;
+        assert_eq!(segment.evict_cached_indexes(), size_of::<Index>() as u64);
+        assert!(segment.indexes.is_none());
+        assert!(segment.time_indexes.is_some());
+    }
 }
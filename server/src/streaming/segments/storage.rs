@@ -1,6 +1,12 @@
+use crate::streaming::cache::memory_budget::MemoryBudget;
+use crate::streaming::models::messages_batch::{
+    MessagesBatch, BATCH_FORMAT_VERSION, BATCH_MARKER, BATCH_PREFIX_SIZE,
+};
 use crate::streaming::persistence::persister::Persister;
 use crate::streaming::segments::index::{Index, IndexRange};
-use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::segment::{
+    Segment, INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION,
+};
 use crate::streaming::segments::time_index::TimeIndex;
 use crate::streaming::storage::{SegmentStorage, Storage};
 use crate::streaming::utils::file;
@@ -10,29 +16,277 @@ use bytes::{BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::error::IggyError;
 use iggy::models::messages::{Message, MessageState};
+use iggy::sizeable::Sizeable;
 use iggy::utils::checksum;
 use std::collections::HashMap;
 use std::io::SeekFrom;
 use std::path::Path;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use tokio::fs;
+use tokio::fs::File;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, BufReader};
 use tracing::log::{trace, warn};
 use tracing::{error, info};
 
 const EMPTY_INDEXES: Vec<Index> = vec![];
 const EMPTY_TIME_INDEXES: Vec<TimeIndex> = vec![];
-const INDEX_SIZE: u32 = 4;
-const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
+pub(crate) const INDEX_SIZE: u32 = 4;
+pub(crate) const TIME_INDEX_SIZE: u32 = 8;
+pub(crate) const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
+pub const SEGMENT_HEADER_SIZE: u64 = 8;
+pub const SEGMENT_FORMAT_VERSION: u32 = 1;
+
+/// The kind of the segment file, each of them starts with the header containing its magic and the format version.
+/// The positions stored in the indexes (as well as the size of the segment) don't include the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentFileKind {
+    Log,
+    Index,
+    TimeIndex,
+}
+
+impl SegmentFileKind {
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            LOG_EXTENSION => Some(SegmentFileKind::Log),
+            INDEX_EXTENSION => Some(SegmentFileKind::Index),
+            TIME_INDEX_EXTENSION => Some(SegmentFileKind::TimeIndex),
+            _ => None,
+        }
+    }
+
+    fn magic(&self) -> &'static [u8; 4] {
+        match self {
+            SegmentFileKind::Log => b"IGSL",
+            SegmentFileKind::Index => b"IGSI",
+            SegmentFileKind::TimeIndex => b"IGST",
+        }
+    }
+
+    pub fn header(&self) -> [u8; SEGMENT_HEADER_SIZE as usize] {
+        let mut header = [0; SEGMENT_HEADER_SIZE as usize];
+        header[..4].copy_from_slice(self.magic());
+        header[4..].copy_from_slice(&SEGMENT_FORMAT_VERSION.to_le_bytes());
+        header
+    }
+
+    /// Reads the format version from the header, returns `None` if the bytes don't start with the header.
+    pub fn read_format_version(&self, bytes: &[u8]) -> Option<u32> {
+        if bytes.len() < SEGMENT_HEADER_SIZE as usize || &bytes[..4] != self.magic() {
+            return None;
+        }
+
+        Some(u32::from_le_bytes(
+            bytes[4..SEGMENT_HEADER_SIZE as usize].try_into().ok()?,
+        ))
+    }
+}
+
+/// Reads the format version from the header of the segment file, returns `None` if the file doesn't start with the header.
+pub async fn read_segment_format_version(
+    path: &str,
+    kind: SegmentFileKind,
+) -> Result<Option<u32>, IggyError> {
+    let mut file = file::open(path).await?;
+    let mut header = [0; SEGMENT_HEADER_SIZE as usize];
+    if file.read_exact(&mut header).await.is_err() {
+        return Ok(None);
+    }
+
+    Ok(kind.read_format_version(&header))
+}
+
+/// Returns the size of the data stored in the segment file of the given size, excluding its header.
+fn get_data_size(file_size: u64) -> u64 {
+    file_size.saturating_sub(SEGMENT_HEADER_SIZE)
+}
 
 #[derive(Debug)]
 pub struct FileSegmentStorage {
     persister: Arc<dyn Persister>,
+    clean_shutdown: AtomicBool,
 }
 
 impl FileSegmentStorage {
     pub fn new(persister: Arc<dyn Persister>) -> Self {
-        Self { persister }
+        Self {
+            persister,
+            clean_shutdown: AtomicBool::new(false),
+        }
+    }
+
+    /// Ensures that the existing segment files have been written in the current format version.
+    async fn validate_format_version(&self, segment: &Segment) -> Result<(), IggyError> {
+        for (path, kind) in [
+            (&segment.log_path, SegmentFileKind::Log),
+            (&segment.index_path, SegmentFileKind::Index),
+            (&segment.time_index_path, SegmentFileKind::TimeIndex),
+        ] {
+            if !Path::new(path).exists() {
+                continue;
+            }
+
+            match read_segment_format_version(path, kind).await? {
+                Some(SEGMENT_FORMAT_VERSION) => {}
+                Some(version) if version > SEGMENT_FORMAT_VERSION => {
+                    return Err(IggyError::UnsupportedStorageFormat(
+                        version,
+                        SEGMENT_FORMAT_VERSION,
+                    ));
+                }
+                Some(version) => {
+                    return Err(IggyError::StorageMigrationRequired(
+                        version,
+                        SEGMENT_FORMAT_VERSION,
+                    ));
+                }
+                None => {
+                    return Err(IggyError::StorageMigrationRequired(
+                        0,
+                        SEGMENT_FORMAT_VERSION,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Truncates the index file to the given size of its entries, the missing file is created along with its header.
+    async fn truncate_index_file(
+        &self,
+        path: &str,
+        kind: SegmentFileKind,
+        size: u64,
+    ) -> Result<(), IggyError> {
+        let file_size = fs::metadata(path)
+            .await
+            .map_or(0, |metadata| metadata.len());
+        if file_size < SEGMENT_HEADER_SIZE {
+            return self.persister.overwrite(path, &kind.header()).await;
+        }
+
+        file::write(path)
+            .await?
+            .set_len(SEGMENT_HEADER_SIZE + size)
+            .await?;
+        Ok(())
+    }
+
+    /// Restores the indexes and time indexes of the messages, which have been saved in the log but not in the index files,
+    /// e.g. when the server has stopped in the middle of persisting them, and truncates the incomplete entry at the end of the log.
+    /// Only the entries following the last one present in both index files are read from the log.
+    async fn recover_indexes(&self, segment: &Segment) -> Result<(), IggyError> {
+        // The missing index files are recreated from the log as well.
+        let indexes_count = fs::metadata(&segment.index_path)
+            .await
+            .map_or(0, |metadata| {
+                get_data_size(metadata.len()) / INDEX_SIZE as u64
+            });
+        let time_indexes_count = fs::metadata(&segment.time_index_path)
+            .await
+            .map_or(0, |metadata| {
+                get_data_size(metadata.len()) / TIME_INDEX_SIZE as u64
+            });
+        let recovered_count = indexes_count.min(time_indexes_count);
+        let mut position = 0;
+        if recovered_count > 0 {
+            let mut index_file = file::open(&segment.index_path).await?;
+            index_file
+                .seek(SeekFrom::Start(
+                    SEGMENT_HEADER_SIZE + (recovered_count - 1) * INDEX_SIZE as u64,
+                ))
+                .await?;
+            position = index_file.read_u32_le().await? as u64;
+        }
+
+        let log_file = file::open(&segment.log_path).await?;
+        let log_size = get_data_size(log_file.metadata().await?.len());
+        let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, log_file);
+        reader
+            .seek(SeekFrom::Start(SEGMENT_HEADER_SIZE + position))
+            .await?;
+        let mut missing_indexes = Vec::new();
+        let mut missing_time_indexes = Vec::new();
+        loop {
+            // The incomplete entry at the end of the log can't be read, so it's truncated below.
+            let Ok(Some((messages, size))) = read_messages_from_log(&mut reader).await else {
+                break;
+            };
+
+            for message in messages {
+                if message.offset - segment.start_offset < recovered_count {
+                    continue;
+                }
+
+                missing_indexes.put_u32_le(position as u32);
+                missing_time_indexes.put_u64_le(message.timestamp);
+            }
+            position += size;
+        }
+
+        if position < log_size {
+            warn!(
+                "Truncating the incomplete log entry of {} bytes at position: {position} of segment with start offset: {} for partition with ID: {}.",
+                log_size - position,
+                segment.start_offset,
+                segment.partition_id
+            );
+            file::write(&segment.log_path)
+                .await?
+                .set_len(SEGMENT_HEADER_SIZE + position)
+                .await?;
+        }
+
+        if missing_indexes.is_empty() && indexes_count == time_indexes_count {
+            return Ok(());
+        }
+
+        self.truncate_index_file(
+            &segment.index_path,
+            SegmentFileKind::Index,
+            recovered_count * INDEX_SIZE as u64,
+        )
+        .await?;
+        self.truncate_index_file(
+            &segment.time_index_path,
+            SegmentFileKind::TimeIndex,
+            recovered_count * TIME_INDEX_SIZE as u64,
+        )
+        .await?;
+        if let Err(err) = self
+            .persister
+            .append(&segment.index_path, &missing_indexes)
+            .await
+            .with_context(|| format!("Failed to save index to segment: {}", segment.index_path))
+        {
+            return Err(IggyError::CannotSaveIndexToSegment(err));
+        }
+
+        if let Err(err) = self
+            .persister
+            .append(&segment.time_index_path, &missing_time_indexes)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to save TimeIndex to segment: {}",
+                    segment.time_index_path
+                )
+            })
+        {
+            return Err(IggyError::CannotSaveTimeIndexToSegment(err));
+        }
+
+        warn!(
+            "Recovered {} indexes and time indexes of segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            missing_indexes.len() / INDEX_SIZE as usize,
+            segment.start_offset,
+            segment.partition_id,
+            segment.topic_id,
+            segment.stream_id
+        );
+        Ok(())
     }
 }
 
@@ -47,17 +301,25 @@ impl Storage<Segment> for FileSegmentStorage {
             "Loading segment from disk for start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {} ...",
             segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
         );
+        self.validate_format_version(segment).await?;
+        // All the batches and their indexes have been persisted before the graceful shutdown.
+        if !self.clean_shutdown.load(Ordering::SeqCst) {
+            self.recover_indexes(segment).await?;
+        }
         let log_file = file::open(&segment.log_path).await?;
-        let file_size = log_file.metadata().await.unwrap().len() as u64;
+        let file_size = get_data_size(log_file.metadata().await.unwrap().len());
         segment.size_bytes = file_size as u32;
-        let messages_count = segment.get_messages_count();
 
         info!(
             "Segment log file of size {} for start offset {}, current offset: {}, and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
             segment.size_bytes, segment.start_offset, segment.current_offset, segment.partition_id, segment.topic_id, segment.stream_id
         );
 
-        if segment.config.segment.cache_indexes {
+        let cached_indexes_size = segment.get_cached_indexes_size_bytes();
+        if segment.config.segment.cache_indexes && segment.config.startup.lazy_segment_indexes {
+            segment.indexes = None;
+            segment.lazy_indexes = true;
+        } else if segment.config.segment.cache_indexes {
             segment.indexes = Some(segment.storage.segment.load_all_indexes(segment).await?);
             info!(
                 "Loaded {} indexes for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
@@ -103,6 +365,11 @@ impl Storage<Segment> for FileSegmentStorage {
             segment.is_closed = true;
         }
 
+        // The messages are counted once the current offset has been restored from the time indexes.
+        let messages_count = segment.get_messages_count();
+        MemoryBudget::get_instance().increment_cached_indexes(
+            segment.get_cached_indexes_size_bytes() - cached_indexes_size,
+        );
         segment
             .size_of_parent_stream
             .fetch_add(file_size, Ordering::SeqCst);
@@ -131,7 +398,7 @@ impl Storage<Segment> for FileSegmentStorage {
         if !Path::new(&segment.log_path).exists()
             && self
                 .persister
-                .overwrite(&segment.log_path, &[])
+                .overwrite(&segment.log_path, &SegmentFileKind::Log.header())
                 .await
                 .is_err()
         {
@@ -143,7 +410,10 @@ impl Storage<Segment> for FileSegmentStorage {
         if !Path::new(&segment.time_index_path).exists()
             && self
                 .persister
-                .overwrite(&segment.time_index_path, &[])
+                .overwrite(
+                    &segment.time_index_path,
+                    &SegmentFileKind::TimeIndex.header(),
+                )
                 .await
                 .is_err()
         {
@@ -155,7 +425,7 @@ impl Storage<Segment> for FileSegmentStorage {
         if !Path::new(&segment.index_path).exists()
             && self
                 .persister
-                .overwrite(&segment.index_path, &[])
+                .overwrite(&segment.index_path, &SegmentFileKind::Index.header())
                 .await
                 .is_err()
         {
@@ -172,7 +442,6 @@ impl Storage<Segment> for FileSegmentStorage {
 
     async fn delete(&self, segment: &Segment) -> Result<(), IggyError> {
         let segment_size = segment.size_bytes;
-        let segment_count_of_messages = segment.get_messages_count();
         info!(
             "Deleting segment of size {segment_size} with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}...",
             segment.start_offset, segment.partition_id, segment.stream_id, segment.topic_id,
@@ -180,24 +449,7 @@ impl Storage<Segment> for FileSegmentStorage {
         self.persister.delete(&segment.log_path).await?;
         self.persister.delete(&segment.index_path).await?;
         self.persister.delete(&segment.time_index_path).await?;
-        segment
-            .size_of_parent_stream
-            .fetch_sub(segment.size_bytes as u64, Ordering::SeqCst);
-        segment
-            .size_of_parent_topic
-            .fetch_sub(segment.size_bytes as u64, Ordering::SeqCst);
-        segment
-            .size_of_parent_partition
-            .fetch_sub(segment.size_bytes as u64, Ordering::SeqCst);
-        segment
-            .messages_count_of_parent_stream
-            .fetch_sub(segment_count_of_messages, Ordering::SeqCst);
-        segment
-            .messages_count_of_parent_topic
-            .fetch_sub(segment_count_of_messages, Ordering::SeqCst);
-        segment
-            .messages_count_of_parent_partition
-            .fetch_sub(segment_count_of_messages, Ordering::SeqCst);
+        remove_segment_stats(segment);
         info!(
             "Deleted segment of size {segment_size} with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
             segment.start_offset, segment.partition_id, segment.stream_id, segment.topic_id,
@@ -216,8 +468,8 @@ impl SegmentStorage for FileSegmentStorage {
         let mut messages = Vec::with_capacity(
             1 + (index_range.end.relative_offset - index_range.start.relative_offset) as usize,
         );
-        load_messages_by_range(segment, index_range, |message: Message| {
-            messages.push(Arc::new(message));
+        load_messages_by_range(segment, index_range, |message: Arc<Message>| {
+            messages.push(message);
             Ok(())
         })
         .await?;
@@ -232,9 +484,9 @@ impl SegmentStorage for FileSegmentStorage {
     ) -> Result<Vec<Arc<Message>>, IggyError> {
         let mut messages = Vec::new();
         let mut total_size_bytes = 0;
-        load_messages_by_size(segment, size_bytes, |message: Message| {
+        load_messages_by_size(segment, size_bytes, |message: Arc<Message>| {
             total_size_bytes += message.get_size_bytes() as u64;
-            messages.push(Arc::new(message));
+            messages.push(message);
             Ok(())
         })
         .await?;
@@ -246,19 +498,19 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(messages)
     }
 
-    async fn save_messages(
+    async fn save_batches(
         &self,
         segment: &Segment,
-        messages: &[Arc<Message>],
+        batches: &[MessagesBatch],
     ) -> Result<u32, IggyError> {
-        let messages_size = messages
+        let batches_size = batches
             .iter()
-            .map(|message| message.get_size_bytes())
+            .map(|batch| batch.get_size_bytes())
             .sum::<u32>();
 
-        let mut bytes = BytesMut::with_capacity(messages_size as usize);
-        for message in messages {
-            message.extend(&mut bytes);
+        let mut bytes = BytesMut::with_capacity(batches_size as usize);
+        for batch in batches {
+            batch.extend(&mut bytes);
         }
 
         if let Err(err) = self
@@ -270,46 +522,54 @@ impl SegmentStorage for FileSegmentStorage {
             return Err(IggyError::CannotSaveMessagesToSegment(err));
         }
 
-        Ok(messages_size)
+        Ok(batches_size)
     }
 
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, IggyError> {
         let mut message_ids = Vec::new();
-        load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
-            message_ids.push(message.id);
-            Ok(())
-        })
+        load_messages_by_range(
+            segment,
+            &IndexRange::max_range(),
+            |message: Arc<Message>| {
+                message_ids.push(message.id);
+                Ok(())
+            },
+        )
         .await?;
         trace!("Loaded {} message IDs from disk.", message_ids.len());
         Ok(message_ids)
     }
 
     async fn load_checksums(&self, segment: &Segment) -> Result<(), IggyError> {
-        load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
-            let calculated_checksum = checksum::calculate(&message.payload);
-            trace!(
-                "Loaded message for offset: {}, checksum: {}, expected: {}",
-                message.offset,
-                calculated_checksum,
-                message.checksum
-            );
-            if calculated_checksum != message.checksum {
-                return Err(IggyError::InvalidMessageChecksum(
-                    calculated_checksum,
-                    message.checksum,
+        load_messages_by_range(
+            segment,
+            &IndexRange::max_range(),
+            |message: Arc<Message>| {
+                let calculated_checksum = checksum::calculate(&message.payload);
+                trace!(
+                    "Loaded message for offset: {}, checksum: {}, expected: {}",
                     message.offset,
-                ));
-            }
-            Ok(())
-        })
+                    calculated_checksum,
+                    message.checksum
+                );
+                if calculated_checksum != message.checksum {
+                    return Err(IggyError::InvalidMessageChecksum(
+                        calculated_checksum,
+                        message.checksum,
+                        message.offset,
+                    ));
+                }
+                Ok(())
+            },
+        )
         .await?;
         Ok(())
     }
 
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, IggyError> {
         trace!("Loading indexes from file...");
-        let file = file::open(&segment.index_path).await?;
-        let file_size = file.metadata().await?.len() as usize;
+        let mut file = file::open(&segment.index_path).await?;
+        let file_size = get_data_size(file.metadata().await?.len()) as usize;
         if file_size == 0 {
             trace!("Index file is empty.");
             return Ok(EMPTY_INDEXES);
@@ -317,6 +577,7 @@ impl SegmentStorage for FileSegmentStorage {
 
         let indexes_count = file_size / 4;
         let mut indexes = Vec::with_capacity(indexes_count);
+        file.seek(SeekFrom::Start(SEGMENT_HEADER_SIZE)).await?;
         let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
         for offset in 0..indexes_count {
             match reader.read_u32_le().await {
@@ -372,7 +633,7 @@ impl SegmentStorage for FileSegmentStorage {
         }
 
         let mut file = file::open(&segment.index_path).await?;
-        let file_length = file.metadata().await?.len() as u32;
+        let file_length = get_data_size(file.metadata().await?.len()) as u32;
         if file_length == 0 {
             trace!("Index file is empty.");
             return Ok(None);
@@ -407,10 +668,15 @@ impl SegmentStorage for FileSegmentStorage {
             start_seek_position,
             end_seek_position
         );
-        file.seek(SeekFrom::Start(start_seek_position as u64))
-            .await?;
+        file.seek(SeekFrom::Start(
+            SEGMENT_HEADER_SIZE + start_seek_position as u64,
+        ))
+        .await?;
         let start_position = file.read_u32_le().await?;
-        file.seek(SeekFrom::Start(end_seek_position as u64)).await?;
+        file.seek(SeekFrom::Start(
+            SEGMENT_HEADER_SIZE + end_seek_position as u64,
+        ))
+        .await?;
         let mut end_position = file.read_u32_le().await?;
         if end_position == 0 {
             end_position = file_length;
@@ -440,13 +706,15 @@ impl SegmentStorage for FileSegmentStorage {
         &self,
         segment: &Segment,
         mut current_position: u32,
-        messages: &[Arc<Message>],
+        batches: &[MessagesBatch],
     ) -> Result<(), IggyError> {
-        let mut bytes = Vec::with_capacity(messages.len() * 4);
-        for message in messages {
+        let mut bytes = Vec::with_capacity(get_messages_count(batches) * 4);
+        for batch in batches {
             trace!("Persisting index for position: {}", current_position);
-            bytes.put_u32_le(current_position);
-            current_position += message.get_size_bytes();
+            for _ in &batch.messages {
+                bytes.put_u32_le(current_position);
+            }
+            current_position += batch.get_size_bytes();
         }
 
         if let Err(err) = self
@@ -463,8 +731,8 @@ impl SegmentStorage for FileSegmentStorage {
 
     async fn load_all_time_indexes(&self, segment: &Segment) -> Result<Vec<TimeIndex>, IggyError> {
         trace!("Loading time indexes from file...");
-        let file = file::open(&segment.time_index_path).await?;
-        let file_size = file.metadata().await?.len() as usize;
+        let mut file = file::open(&segment.time_index_path).await?;
+        let file_size = get_data_size(file.metadata().await?.len()) as usize;
         if file_size == 0 {
             trace!("Time index file is empty.");
             return Ok(EMPTY_TIME_INDEXES);
@@ -472,6 +740,7 @@ impl SegmentStorage for FileSegmentStorage {
 
         let indexes_count = file_size / 8;
         let mut indexes = Vec::with_capacity(indexes_count);
+        file.seek(SeekFrom::Start(SEGMENT_HEADER_SIZE)).await?;
         let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
         for offset in 0..indexes_count {
             match reader.read_u64_le().await {
@@ -510,7 +779,7 @@ impl SegmentStorage for FileSegmentStorage {
     ) -> Result<Option<TimeIndex>, IggyError> {
         trace!("Loading last time index from file...");
         let mut file = file::open(&segment.time_index_path).await?;
-        let file_size = file.metadata().await?.len() as usize;
+        let file_size = get_data_size(file.metadata().await?.len()) as usize;
         if file_size == 0 {
             trace!("Time index file is empty.");
             return Ok(None);
@@ -518,8 +787,10 @@ impl SegmentStorage for FileSegmentStorage {
 
         let indexes_count = file_size / 8;
         let last_index_position = file_size - 8;
-        file.seek(SeekFrom::Start(last_index_position as u64))
-            .await?;
+        file.seek(SeekFrom::Start(
+            SEGMENT_HEADER_SIZE + last_index_position as u64,
+        ))
+        .await?;
         let timestamp = file.read_u64_le().await?;
         let index = TimeIndex {
             relative_offset: indexes_count as u32 - 1,
@@ -533,10 +804,10 @@ impl SegmentStorage for FileSegmentStorage {
     async fn save_time_index(
         &self,
         segment: &Segment,
-        messages: &[Arc<Message>],
+        batches: &[MessagesBatch],
     ) -> Result<(), IggyError> {
-        let mut bytes = Vec::with_capacity(messages.len() * 8);
-        for message in messages {
+        let mut bytes = Vec::with_capacity(get_messages_count(batches) * 8);
+        for message in batches.iter().flat_map(|batch| &batch.messages) {
             bytes.put_u64_le(message.timestamp);
         }
 
@@ -556,103 +827,80 @@ impl SegmentStorage for FileSegmentStorage {
 
         Ok(())
     }
+
+    fn set_clean_shutdown(&self, clean_shutdown: bool) {
+        self.clean_shutdown.store(clean_shutdown, Ordering::SeqCst);
+    }
+}
+
+/// Subtracts the size and the messages count of the deleted segment from its parent partition, topic and stream.
+pub(crate) fn remove_segment_stats(segment: &Segment) {
+    let segment_count_of_messages = segment.get_messages_count();
+    segment
+        .size_of_parent_stream
+        .fetch_sub(segment.size_bytes as u64, Ordering::SeqCst);
+    segment
+        .size_of_parent_topic
+        .fetch_sub(segment.size_bytes as u64, Ordering::SeqCst);
+    segment
+        .size_of_parent_partition
+        .fetch_sub(segment.size_bytes as u64, Ordering::SeqCst);
+    segment
+        .messages_count_of_parent_stream
+        .fetch_sub(segment_count_of_messages, Ordering::SeqCst);
+    segment
+        .messages_count_of_parent_topic
+        .fetch_sub(segment_count_of_messages, Ordering::SeqCst);
+    segment
+        .messages_count_of_parent_partition
+        .fetch_sub(segment_count_of_messages, Ordering::SeqCst);
+}
+
+fn get_messages_count(batches: &[MessagesBatch]) -> usize {
+    batches.iter().map(|batch| batch.messages.len()).sum()
 }
 
 async fn load_messages_by_range(
     segment: &Segment,
     index_range: &IndexRange,
-    mut on_message: impl FnMut(Message) -> Result<(), IggyError>,
+    mut on_message: impl FnMut(Arc<Message>) -> Result<(), IggyError>,
 ) -> Result<(), IggyError> {
     let file = file::open(&segment.log_path).await?;
-    let file_size = file.metadata().await?.len();
+    let file_size = get_data_size(file.metadata().await?.len());
     if file_size == 0 {
         return Ok(());
     }
 
-    if index_range.end.position == 0 {
-        return Ok(());
-    }
-
     let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
     reader
-        .seek(SeekFrom::Start(index_range.start.position as u64))
+        .seek(SeekFrom::Start(
+            SEGMENT_HEADER_SIZE + index_range.start.position as u64,
+        ))
         .await?;
 
+    // The index of the message stored in a batch points to the batch, so the preceding messages of the batch are skipped.
+    let start_offset = segment.start_offset + index_range.start.relative_offset as u64;
     let mut read_messages = 0;
     let messages_count =
         (1 + index_range.end.relative_offset - index_range.start.relative_offset) as usize;
 
     while read_messages < messages_count {
-        let offset = reader.read_u64_le().await;
-        if offset.is_err() {
+        let Some((messages, _)) = read_messages_from_log(&mut reader).await? else {
             break;
-        }
-
-        let state = reader.read_u8().await;
-        if state.is_err() {
-            return Err(IggyError::CannotReadMessageState);
-        }
-
-        let state = MessageState::from_code(state.unwrap())?;
-        let timestamp = reader.read_u64_le().await;
-        if timestamp.is_err() {
-            return Err(IggyError::CannotReadMessageTimestamp);
-        }
-
-        let id = reader.read_u128_le().await;
-        if id.is_err() {
-            return Err(IggyError::CannotReadMessageId);
-        }
-
-        let checksum = reader.read_u32_le().await;
-        if checksum.is_err() {
-            return Err(IggyError::CannotReadMessageChecksum);
-        }
-
-        let headers_length = reader.read_u32_le().await;
-        if headers_length.is_err() {
-            return Err(IggyError::CannotReadHeadersLength);
-        }
-
-        let headers_length = headers_length.unwrap();
-        let headers = match headers_length {
-            0 => None,
-            _ => {
-                let mut headers_payload = BytesMut::with_capacity(headers_length as usize);
-                headers_payload.put_bytes(0, headers_length as usize);
-                if reader.read_exact(&mut headers_payload).await.is_err() {
-                    return Err(IggyError::CannotReadHeadersPayload);
-                }
+        };
 
-                let headers = HashMap::from_bytes(headers_payload.freeze())?;
-                Some(headers)
+        for message in messages {
+            if message.offset < start_offset {
+                continue;
             }
-        };
 
-        let payload_length = reader.read_u32_le().await?;
+            if read_messages == messages_count {
+                break;
+            }
 
-        let mut payload = BytesMut::with_capacity(payload_length as usize);
-        payload.put_bytes(0, payload_length as usize);
-        if reader.read_exact(&mut payload).await.is_err() {
-            return Err(IggyError::CannotReadMessagePayload);
+            read_messages += 1;
+            on_message(message)?;
         }
-
-        let offset = offset.unwrap();
-        let timestamp = timestamp.unwrap();
-        let id = id.unwrap();
-        let checksum = checksum.unwrap();
-
-        let message = Message::create(
-            offset,
-            state,
-            timestamp,
-            id,
-            payload.freeze(),
-            checksum,
-            headers,
-        );
-        read_messages += 1;
-        on_message(message)?;
     }
     Ok(())
 }
@@ -660,100 +908,158 @@ async fn load_messages_by_range(
 async fn load_messages_by_size(
     segment: &Segment,
     size_bytes: u64,
-    mut on_message: impl FnMut(Message) -> Result<(), IggyError>,
+    mut on_message: impl FnMut(Arc<Message>) -> Result<(), IggyError>,
 ) -> Result<(), IggyError> {
     let file = file::open(&segment.log_path).await?;
-    let file_size = file.metadata().await?.len();
+    let file_size = get_data_size(file.metadata().await?.len());
     if file_size == 0 {
         return Ok(());
     }
     let threshold = file_size.saturating_sub(size_bytes);
 
     let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+    reader.seek(SeekFrom::Start(SEGMENT_HEADER_SIZE)).await?;
     let mut accumulated_size: u64 = 0;
 
-    loop {
-        let offset = reader.read_u64_le().await;
-        if offset.is_err() {
-            break;
+    while let Some((messages, size)) = read_messages_from_log(&mut reader).await? {
+        if accumulated_size >= threshold {
+            for message in messages {
+                on_message(message)?;
+            }
         }
 
-        let state = reader.read_u8().await;
-        if state.is_err() {
-            return Err(IggyError::CannotReadMessageState);
-        }
+        accumulated_size += size;
 
-        let state = MessageState::from_code(state.unwrap())?;
-        let timestamp = reader.read_u64_le().await;
-        if timestamp.is_err() {
-            return Err(IggyError::CannotReadMessageTimestamp);
+        if accumulated_size >= file_size {
+            break;
         }
+    }
 
-        let id = reader.read_u128_le().await;
-        if id.is_err() {
-            return Err(IggyError::CannotReadMessageId);
-        }
+    Ok(())
+}
 
-        let checksum = reader.read_u32_le().await;
-        if checksum.is_err() {
-            return Err(IggyError::CannotReadMessageChecksum);
-        }
+/// The entry of the segment log, either the batch of messages (format v2) or the single message (format v1).
+#[derive(Debug)]
+pub(crate) enum LogEntry {
+    Batch(MessagesBatch),
+    Message(Message),
+}
 
-        let headers_length = reader.read_u32_le().await;
-        if headers_length.is_err() {
-            return Err(IggyError::CannotReadHeadersLength);
+impl LogEntry {
+    pub(crate) fn into_messages(self) -> Vec<Arc<Message>> {
+        match self {
+            LogEntry::Batch(batch) => batch.messages,
+            LogEntry::Message(message) => vec![Arc::new(message)],
         }
+    }
+}
 
-        let headers_length = headers_length.unwrap();
-        let headers = match headers_length {
-            0 => None,
-            _ => {
-                let mut headers_payload = BytesMut::with_capacity(headers_length as usize);
-                if reader.read_exact(&mut headers_payload).await.is_err() {
-                    return Err(IggyError::CannotReadHeadersPayload);
-                }
+/// Reads the messages of the next entry of the segment log, along with its size on disk.
+/// Returns `None` once the end of the log has been reached.
+async fn read_messages_from_log(
+    reader: &mut BufReader<File>,
+) -> Result<Option<(Vec<Arc<Message>>, u64)>, IggyError> {
+    Ok(read_log_entry(reader)
+        .await?
+        .map(|(entry, size)| (entry.into_messages(), size)))
+}
 
-                let headers = HashMap::from_bytes(headers_payload.freeze())?;
-                Some(headers)
-            }
-        };
+/// Reads the next entry of the segment log along with its size on disk. Returns `None` once the end of the log has been reached.
+pub(crate) async fn read_log_entry(
+    reader: &mut BufReader<File>,
+) -> Result<Option<(LogEntry, u64)>, IggyError> {
+    let offset = reader.read_u64_le().await;
+    if offset.is_err() {
+        return Ok(None);
+    }
 
-        let payload_length = reader.read_u32_le().await;
-        if payload_length.is_err() {
-            return Err(IggyError::CannotReadMessageLength);
+    let offset = offset.unwrap();
+    if offset == BATCH_MARKER {
+        let version = reader
+            .read_u8()
+            .await
+            .map_err(|_| IggyError::CannotReadMessagesBatch)?;
+        if version != BATCH_FORMAT_VERSION {
+            return Err(IggyError::UnsupportedMessagesBatchVersion(version));
         }
 
-        let mut payload = vec![0; payload_length.unwrap() as usize];
-        if reader.read_exact(&mut payload).await.is_err() {
-            return Err(IggyError::CannotReadMessagePayload);
+        let length = reader
+            .read_u32_le()
+            .await
+            .map_err(|_| IggyError::CannotReadMessagesBatch)?;
+        let mut batch = BytesMut::with_capacity(length as usize);
+        batch.put_bytes(0, length as usize);
+        if reader.read_exact(&mut batch).await.is_err() {
+            return Err(IggyError::CannotReadMessagesBatch);
         }
 
-        let offset = offset.unwrap();
-        let timestamp = timestamp.unwrap();
-        let id = id.unwrap();
-        let checksum = checksum.unwrap();
+        let batch = MessagesBatch::from_bytes(batch.freeze())?;
+        return Ok(Some((
+            LogEntry::Batch(batch),
+            (BATCH_PREFIX_SIZE + length) as u64,
+        )));
+    }
 
-        let message = Message::create(
-            offset,
-            state,
-            timestamp,
-            id,
-            Bytes::from(payload),
-            checksum,
-            headers,
-        );
-        let message_size = message.get_size_bytes() as u64;
+    let state = reader.read_u8().await;
+    if state.is_err() {
+        return Err(IggyError::CannotReadMessageState);
+    }
 
-        if accumulated_size >= threshold {
-            on_message(message)?;
-        }
+    let state = MessageState::from_code(state.unwrap())?;
+    let timestamp = reader.read_u64_le().await;
+    if timestamp.is_err() {
+        return Err(IggyError::CannotReadMessageTimestamp);
+    }
 
-        accumulated_size += message_size;
+    let id = reader.read_u128_le().await;
+    if id.is_err() {
+        return Err(IggyError::CannotReadMessageId);
+    }
 
-        if accumulated_size >= file_size {
-            break;
+    let checksum = reader.read_u32_le().await;
+    if checksum.is_err() {
+        return Err(IggyError::CannotReadMessageChecksum);
+    }
+
+    let headers_length = reader.read_u32_le().await;
+    if headers_length.is_err() {
+        return Err(IggyError::CannotReadHeadersLength);
+    }
+
+    let headers_length = headers_length.unwrap();
+    let headers = match headers_length {
+        0 => None,
+        _ => {
+            let mut headers_payload = BytesMut::with_capacity(headers_length as usize);
+            headers_payload.put_bytes(0, headers_length as usize);
+            if reader.read_exact(&mut headers_payload).await.is_err() {
+                return Err(IggyError::CannotReadHeadersPayload);
+            }
+
+            let headers = HashMap::from_bytes(headers_payload.freeze())?;
+            Some(headers)
         }
+    };
+
+    let payload_length = reader.read_u32_le().await;
+    if payload_length.is_err() {
+        return Err(IggyError::CannotReadMessageLength);
     }
 
-    Ok(())
+    let mut payload = vec![0; payload_length.unwrap() as usize];
+    if reader.read_exact(&mut payload).await.is_err() {
+        return Err(IggyError::CannotReadMessagePayload);
+    }
+
+    let message = Message::create(
+        offset,
+        state,
+        timestamp.unwrap(),
+        id.unwrap(),
+        Bytes::from(payload),
+        checksum.unwrap(),
+        headers,
+    );
+    let size = message.get_size_bytes() as u64;
+    Ok(Some((LogEntry::Message(message), size)))
 }
@@ -1,14 +1,20 @@
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::users::user::ANONYMOUS_USER_ID;
 use iggy::models::user_info::{AtomicUserId, UserId};
 use std::fmt::Display;
 use std::net::SocketAddr;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 // This might be extended with more fields in the future e.g. custom name, permissions etc.
 #[derive(Debug)]
 pub struct Session {
     user_id: AtomicUserId,
+    /// The session epoch of the user at login, the session is revoked once the user's epoch changes.
+    session_epoch: AtomicU32,
     pub client_id: u32,
     pub ip_address: SocketAddr,
+    /// The transport of the session, missing for the server itself.
+    pub transport: Option<Transport>,
 }
 
 impl Session {
@@ -16,10 +22,17 @@ impl Session {
         Self {
             client_id,
             user_id: AtomicUserId::new(user_id),
+            session_epoch: AtomicU32::new(0),
             ip_address,
+            transport: None,
         }
     }
 
+    pub fn with_transport(mut self, transport: Transport) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
     pub fn stateless(user_id: UserId, ip_address: SocketAddr) -> Self {
         Self::new(0, user_id, ip_address)
     }
@@ -36,6 +49,14 @@ impl Session {
         self.user_id.store(user_id, Ordering::Release)
     }
 
+    pub fn get_session_epoch(&self) -> u32 {
+        self.session_epoch.load(Ordering::Acquire)
+    }
+
+    pub fn set_session_epoch(&self, session_epoch: u32) {
+        self.session_epoch.store(session_epoch, Ordering::Release)
+    }
+
     pub fn clear_user_id(&self) {
         self.set_user_id(0)
     }
@@ -43,6 +64,10 @@ impl Session {
     pub fn is_authenticated(&self) -> bool {
         self.get_user_id() > 0
     }
+
+    pub fn is_anonymous(&self) -> bool {
+        self.get_user_id() == ANONYMOUS_USER_ID
+    }
 }
 
 impl Display for Session {
@@ -1,8 +1,18 @@
+use crate::streaming::memory_storage::{
+    MemoryMetadataLogStorage, MemoryPartitionStorage, MemorySegmentStorage, MemoryStreamStorage,
+    MemoryTopicStorage,
+};
+use crate::streaming::metadata_log::entry::MetadataRecord;
+use crate::streaming::metadata_log::storage::FileMetadataLogStorage;
+use crate::streaming::models::messages_batch::MessagesBatch;
+use crate::streaming::partitions::loader::PartitionsLoader;
 use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
 use crate::streaming::partitions::storage::FilePartitionStorage;
 use crate::streaming::persistence::persister::Persister;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::personal_access_tokens::storage::FilePersonalAccessTokenStorage;
+use crate::streaming::schemas::schema::{Schema, SchemaSubject};
+use crate::streaming::schemas::storage::FileSchemaStorage;
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::storage::FileSegmentStorage;
@@ -11,9 +21,15 @@ use crate::streaming::streams::storage::FileStreamStorage;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::info::SystemInfo;
 use crate::streaming::systems::storage::FileSystemInfoStorage;
+use crate::streaming::tenants::storage::FileTenantStorage;
+use crate::streaming::tenants::tenant::Tenant;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::storage::FileTopicStorage;
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::trash::entry::TrashEntry;
+use crate::streaming::trash::storage::FileTrashStorage;
+use crate::streaming::user_groups::storage::FileUserGroupStorage;
+use crate::streaming::user_groups::user_group::UserGroup;
 use crate::streaming::users::storage::FileUserStorage;
 use crate::streaming::users::user::User;
 use async_trait::async_trait;
@@ -55,6 +71,28 @@ pub trait PersonalAccessTokenStorage: Storage<PersonalAccessToken> {
     async fn delete_for_user(&self, user_id: UserId, name: &str) -> Result<(), IggyError>;
 }
 
+#[async_trait]
+pub trait SchemaStorage: Storage<Schema> {
+    async fn load_all(&self) -> Result<Vec<Schema>, IggyError>;
+    async fn load_all_subjects(&self) -> Result<Vec<SchemaSubject>, IggyError>;
+    async fn save_subject(&self, subject: &SchemaSubject) -> Result<(), IggyError>;
+}
+
+#[async_trait]
+pub trait TenantStorage: Storage<Tenant> {
+    async fn load_all(&self) -> Result<Vec<Tenant>, IggyError>;
+}
+
+#[async_trait]
+pub trait UserGroupStorage: Storage<UserGroup> {
+    async fn load_all(&self) -> Result<Vec<UserGroup>, IggyError>;
+}
+
+#[async_trait]
+pub trait TrashStorage: Storage<TrashEntry> {
+    async fn load_all(&self) -> Result<Vec<TrashEntry>, IggyError>;
+}
+
 #[async_trait]
 pub trait StreamStorage: Storage<Stream> {}
 
@@ -83,6 +121,7 @@ pub trait PartitionStorage: Storage<Partition> {
         topic_id: u32,
         partition_id: u32,
     ) -> Result<Vec<ConsumerOffset>, IggyError>;
+    async fn delete_consumer_offset(&self, offset: &ConsumerOffset) -> Result<(), IggyError>;
     async fn delete_consumer_offsets(
         &self,
         kind: ConsumerKind,
@@ -104,10 +143,10 @@ pub trait SegmentStorage: Storage<Segment> {
         segment: &Segment,
         size_bytes: u64,
     ) -> Result<Vec<Arc<Message>>, IggyError>;
-    async fn save_messages(
+    async fn save_batches(
         &self,
         segment: &Segment,
-        messages: &[Arc<Message>],
+        batches: &[MessagesBatch],
     ) -> Result<u32, IggyError>;
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, IggyError>;
     async fn load_checksums(&self, segment: &Segment) -> Result<(), IggyError>;
@@ -123,7 +162,7 @@ pub trait SegmentStorage: Storage<Segment> {
         &self,
         segment: &Segment,
         current_position: u32,
-        messages: &[Arc<Message>],
+        batches: &[MessagesBatch],
     ) -> Result<(), IggyError>;
     async fn load_all_time_indexes(&self, segment: &Segment) -> Result<Vec<TimeIndex>, IggyError>;
     async fn load_last_time_index(&self, segment: &Segment)
@@ -131,8 +170,17 @@ pub trait SegmentStorage: Storage<Segment> {
     async fn save_time_index(
         &self,
         segment: &Segment,
-        messages: &[Arc<Message>],
+        batches: &[MessagesBatch],
     ) -> Result<(), IggyError>;
+    /// Marks whether the server has previously shut down gracefully, so the recovery of the segments can be skipped on load.
+    fn set_clean_shutdown(&self, clean_shutdown: bool);
+}
+
+#[async_trait]
+pub trait MetadataLogStorage: Sync + Send {
+    async fn append(&self, path: &str, record: &MetadataRecord) -> Result<(), IggyError>;
+    async fn load_all(&self, path: &str) -> Result<Vec<MetadataRecord>, IggyError>;
+    async fn truncate(&self, path: &str) -> Result<(), IggyError>;
 }
 
 #[derive(Debug)]
@@ -140,10 +188,17 @@ pub struct SystemStorage {
     pub info: Arc<dyn SystemInfoStorage>,
     pub user: Arc<dyn UserStorage>,
     pub personal_access_token: Arc<dyn PersonalAccessTokenStorage>,
+    pub schema: Arc<dyn SchemaStorage>,
+    pub tenant: Arc<dyn TenantStorage>,
+    pub user_group: Arc<dyn UserGroupStorage>,
+    pub trash: Arc<dyn TrashStorage>,
     pub stream: Arc<dyn StreamStorage>,
     pub topic: Arc<dyn TopicStorage>,
     pub partition: Arc<dyn PartitionStorage>,
     pub segment: Arc<dyn SegmentStorage>,
+    pub metadata_log: Arc<dyn MetadataLogStorage>,
+    /// Limits the number of the partitions loaded concurrently on startup, shared by all the topics.
+    pub partitions_loader: Arc<PartitionsLoader>,
 }
 
 impl SystemStorage {
@@ -152,10 +207,56 @@ impl SystemStorage {
             info: Arc::new(FileSystemInfoStorage::new(db.clone())),
             user: Arc::new(FileUserStorage::new(db.clone())),
             personal_access_token: Arc::new(FilePersonalAccessTokenStorage::new(db.clone())),
+            schema: Arc::new(FileSchemaStorage::new(db.clone())),
+            tenant: Arc::new(FileTenantStorage::new(db.clone())),
+            user_group: Arc::new(FileUserGroupStorage::new(db.clone())),
+            trash: Arc::new(FileTrashStorage::new(db.clone())),
             stream: Arc::new(FileStreamStorage::new(db.clone())),
             topic: Arc::new(FileTopicStorage::new(db.clone())),
             partition: Arc::new(FilePartitionStorage::new(db.clone())),
             segment: Arc::new(FileSegmentStorage::new(persister.clone())),
+            metadata_log: Arc::new(FileMetadataLogStorage::new(persister.clone())),
+            partitions_loader: Arc::new(PartitionsLoader::default()),
+        }
+    }
+
+    /// Keeps the streams, topics, partitions and messages in memory, while the rest of the state
+    /// is kept in the provided (usually temporary) database.
+    pub fn in_memory(db: Arc<Db>) -> Self {
+        Self {
+            info: Arc::new(FileSystemInfoStorage::new(db.clone())),
+            user: Arc::new(FileUserStorage::new(db.clone())),
+            personal_access_token: Arc::new(FilePersonalAccessTokenStorage::new(db.clone())),
+            schema: Arc::new(FileSchemaStorage::new(db.clone())),
+            tenant: Arc::new(FileTenantStorage::new(db.clone())),
+            user_group: Arc::new(FileUserGroupStorage::new(db.clone())),
+            trash: Arc::new(FileTrashStorage::new(db.clone())),
+            stream: Arc::new(MemoryStreamStorage::default()),
+            topic: Arc::new(MemoryTopicStorage::default()),
+            partition: Arc::new(MemoryPartitionStorage::default()),
+            segment: Arc::new(MemorySegmentStorage::default()),
+            metadata_log: Arc::new(MemoryMetadataLogStorage::default()),
+            partitions_loader: Arc::new(PartitionsLoader::default()),
+        }
+    }
+
+    /// Returns the storage for the ephemeral topic, keeping its partitions and messages in memory,
+    /// regardless of the storage backend of the rest of the system.
+    pub fn to_ephemeral(&self) -> Self {
+        Self {
+            info: self.info.clone(),
+            user: self.user.clone(),
+            personal_access_token: self.personal_access_token.clone(),
+            schema: self.schema.clone(),
+            tenant: self.tenant.clone(),
+            user_group: self.user_group.clone(),
+            trash: self.trash.clone(),
+            stream: self.stream.clone(),
+            topic: Arc::new(MemoryTopicStorage::default()),
+            partition: Arc::new(MemoryPartitionStorage::default()),
+            segment: Arc::new(MemorySegmentStorage::default()),
+            metadata_log: self.metadata_log.clone(),
+            partitions_loader: self.partitions_loader.clone(),
         }
     }
 }
@@ -178,6 +279,30 @@ impl Debug for dyn PersonalAccessTokenStorage {
     }
 }
 
+impl Debug for dyn SchemaStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SchemaStorage")
+    }
+}
+
+impl Debug for dyn TenantStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TenantStorage")
+    }
+}
+
+impl Debug for dyn UserGroupStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UserGroupStorage")
+    }
+}
+
+impl Debug for dyn TrashStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TrashStorage")
+    }
+}
+
 impl Debug for dyn StreamStorage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "StreamStorage")
@@ -202,6 +327,12 @@ impl Debug for dyn SegmentStorage {
     }
 }
 
+impl Debug for dyn MetadataLogStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MetadataLogStorage")
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use crate::streaming::partitions::partition::Partition;
@@ -218,10 +349,15 @@ pub(crate) mod tests {
     struct TestSystemInfoStorage {}
     struct TestUserStorage {}
     struct TestPersonalAccessTokenStorage {}
+    struct TestSchemaStorage {}
+    struct TestTenantStorage {}
+    struct TestUserGroupStorage {}
+    struct TestTrashStorage {}
     struct TestStreamStorage {}
     struct TestTopicStorage {}
     struct TestPartitionStorage {}
     struct TestSegmentStorage {}
+    struct TestMetadataLogStorage {}
 
     #[async_trait]
     impl Storage<SystemInfo> for TestSystemInfoStorage {
@@ -325,6 +461,102 @@ pub(crate) mod tests {
         }
     }
 
+    #[async_trait]
+    impl Storage<Schema> for TestSchemaStorage {
+        async fn load(&self, _schema: &mut Schema) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn save(&self, _schema: &Schema) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn delete(&self, _schema: &Schema) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl SchemaStorage for TestSchemaStorage {
+        async fn load_all(&self) -> Result<Vec<Schema>, IggyError> {
+            Ok(vec![])
+        }
+
+        async fn load_all_subjects(&self) -> Result<Vec<SchemaSubject>, IggyError> {
+            Ok(vec![])
+        }
+
+        async fn save_subject(&self, _subject: &SchemaSubject) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Storage<Tenant> for TestTenantStorage {
+        async fn load(&self, _tenant: &mut Tenant) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn save(&self, _tenant: &Tenant) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn delete(&self, _tenant: &Tenant) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl TenantStorage for TestTenantStorage {
+        async fn load_all(&self) -> Result<Vec<Tenant>, IggyError> {
+            Ok(vec![])
+        }
+    }
+
+    #[async_trait]
+    impl Storage<UserGroup> for TestUserGroupStorage {
+        async fn load(&self, _group: &mut UserGroup) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn save(&self, _group: &UserGroup) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn delete(&self, _group: &UserGroup) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl UserGroupStorage for TestUserGroupStorage {
+        async fn load_all(&self) -> Result<Vec<UserGroup>, IggyError> {
+            Ok(vec![])
+        }
+    }
+
+    #[async_trait]
+    impl Storage<TrashEntry> for TestTrashStorage {
+        async fn load(&self, _entry: &mut TrashEntry) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn save(&self, _entry: &TrashEntry) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn delete(&self, _entry: &TrashEntry) -> Result<(), IggyError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl TrashStorage for TestTrashStorage {
+        async fn load_all(&self) -> Result<Vec<TrashEntry>, IggyError> {
+            Ok(vec![])
+        }
+    }
+
     #[async_trait]
     impl Storage<Stream> for TestStreamStorage {
         async fn load(&self, _stream: &mut Stream) -> Result<(), IggyError> {
@@ -414,6 +646,10 @@ pub(crate) mod tests {
             Ok(vec![])
         }
 
+        async fn delete_consumer_offset(&self, _offset: &ConsumerOffset) -> Result<(), IggyError> {
+            Ok(())
+        }
+
         async fn delete_consumer_offsets(
             &self,
             _kind: ConsumerKind,
@@ -458,10 +694,10 @@ pub(crate) mod tests {
             Ok(vec![])
         }
 
-        async fn save_messages(
+        async fn save_batches(
             &self,
             _segment: &Segment,
-            _messages: &[Arc<Message>],
+            _batches: &[MessagesBatch],
         ) -> Result<u32, IggyError> {
             Ok(0)
         }
@@ -492,7 +728,7 @@ pub(crate) mod tests {
             &self,
             _segment: &Segment,
             _current_position: u32,
-            _messages: &[Arc<Message>],
+            _batches: &[MessagesBatch],
         ) -> Result<(), IggyError> {
             Ok(())
         }
@@ -514,10 +750,27 @@ pub(crate) mod tests {
         async fn save_time_index(
             &self,
             _segment: &Segment,
-            _messages: &[Arc<Message>],
+            _batches: &[MessagesBatch],
         ) -> Result<(), IggyError> {
             Ok(())
         }
+
+        fn set_clean_shutdown(&self, _clean_shutdown: bool) {}
+    }
+
+    #[async_trait]
+    impl MetadataLogStorage for TestMetadataLogStorage {
+        async fn append(&self, _path: &str, _record: &MetadataRecord) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn load_all(&self, _path: &str) -> Result<Vec<MetadataRecord>, IggyError> {
+            Ok(vec![])
+        }
+
+        async fn truncate(&self, _path: &str) -> Result<(), IggyError> {
+            Ok(())
+        }
     }
 
     pub fn get_test_system_storage() -> SystemStorage {
@@ -525,10 +778,16 @@ pub(crate) mod tests {
             info: Arc::new(TestSystemInfoStorage {}),
             user: Arc::new(TestUserStorage {}),
             personal_access_token: Arc::new(TestPersonalAccessTokenStorage {}),
+            schema: Arc::new(TestSchemaStorage {}),
+            tenant: Arc::new(TestTenantStorage {}),
+            user_group: Arc::new(TestUserGroupStorage {}),
+            trash: Arc::new(TestTrashStorage {}),
             stream: Arc::new(TestStreamStorage {}),
             topic: Arc::new(TestTopicStorage {}),
             partition: Arc::new(TestPartitionStorage {}),
             segment: Arc::new(TestSegmentStorage {}),
+            metadata_log: Arc::new(TestMetadataLogStorage {}),
+            partitions_loader: Arc::new(PartitionsLoader::default()),
         }
     }
 }
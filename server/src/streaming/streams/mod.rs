@@ -5,3 +5,4 @@ pub mod segments;
 pub mod storage;
 pub mod stream;
 pub mod topics;
+pub mod trash;
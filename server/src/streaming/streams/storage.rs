@@ -5,6 +5,7 @@ use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::join_all;
 use iggy::error::IggyError;
+use iggy::models::topic_defaults::TopicDefaults;
 use serde::{Deserialize, Serialize};
 use sled::Db;
 use std::path::Path;
@@ -13,6 +14,7 @@ use tokio::fs;
 use tokio::fs::create_dir;
 use tokio::sync::Mutex;
 use tracing::{error, info};
+use uuid::Uuid;
 
 #[derive(Debug)]
 pub struct FileStreamStorage {
@@ -34,6 +36,12 @@ impl StreamStorage for FileStreamStorage {}
 struct StreamData {
     name: String,
     created_at: u64,
+    #[serde(default)]
+    tenant_id: Option<u32>,
+    #[serde(default)]
+    topic_defaults: TopicDefaults,
+    #[serde(default)]
+    uuid: Uuid,
 }
 
 #[async_trait]
@@ -77,6 +85,14 @@ impl Storage<Stream> for FileStreamStorage {
 
         stream.name = stream_data.name;
         stream.created_at = stream_data.created_at;
+        stream.tenant_id = stream_data.tenant_id;
+        stream.topic_defaults = stream_data.topic_defaults;
+        // The stream saved without the UUID keeps the one generated on creation, which is saved right away to stay stable.
+        if stream_data.uuid.is_nil() {
+            self.save(stream).await?;
+        } else {
+            stream.uuid = stream_data.uuid;
+        }
         let mut unloaded_topics = Vec::new();
         let dir_entries = fs::read_dir(&stream.topics_path).await;
         if dir_entries.is_err() {
@@ -169,6 +185,9 @@ impl Storage<Stream> for FileStreamStorage {
         match rmp_serde::to_vec(&StreamData {
             name: stream.name.clone(),
             created_at: stream.created_at,
+            tenant_id: stream.tenant_id,
+            topic_defaults: stream.topic_defaults.clone(),
+            uuid: stream.uuid,
         })
         .with_context(|| format!("Failed to serialize stream with key: {}", key))
         {
@@ -1,24 +1,34 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::trash::entry::TrashEntry;
+use iggy::models::topic_defaults::TopicDefaults;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
+use uuid::Uuid;
 
 #[derive(Debug)]
 pub struct Stream {
     pub stream_id: u32,
+    /// The immutable UUID of the stream, which is kept across renames and ID reuse.
+    pub uuid: Uuid,
     pub name: String,
     pub path: String,
     pub topics_path: String,
     pub created_at: u64,
+    pub tenant_id: Option<u32>,
+    /// The settings inherited by the topics created in the stream without the explicit values.
+    pub topic_defaults: TopicDefaults,
     pub current_topic_id: AtomicU32,
     pub size_bytes: Arc<AtomicU64>,
     pub messages_count: Arc<AtomicU64>,
     pub(crate) topics: HashMap<u32, Topic>,
     pub(crate) topics_ids: HashMap<String, u32>,
+    /// The soft deleted topics, their IDs stay reserved until they're restored or purged.
+    pub(crate) trashed_topics: HashMap<u32, TrashEntry>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
 }
@@ -39,6 +49,7 @@ impl Stream {
 
         Stream {
             stream_id: id,
+            uuid: Uuid::new_v4(),
             name: name.to_string(),
             path,
             topics_path,
@@ -48,8 +59,11 @@ impl Stream {
             messages_count: Arc::new(AtomicU64::new(0)),
             topics: HashMap::new(),
             topics_ids: HashMap::new(),
+            trashed_topics: HashMap::new(),
             storage,
             created_at: IggyTimestamp::now().to_micros(),
+            tenant_id: None,
+            topic_defaults: TopicDefaults::default(),
         }
     }
 
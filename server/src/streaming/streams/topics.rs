@@ -1,17 +1,23 @@
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::topics::message_schema::MessageSchema;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::text;
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use tracing::{debug, info};
+use uuid::Uuid;
 
 impl Stream {
     pub fn get_topics_count(&self) -> u32 {
         self.topics.len() as u32
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_topic(
         &mut self,
         topic_id: Option<u32>,
@@ -20,6 +26,11 @@ impl Stream {
         message_expiry: Option<u32>,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        message_schema: Option<&str>,
+        ephemeral: bool,
+        timestamp_type: TimestampType,
+        consumer_limits: ConsumerLimits,
+        max_message_size: Option<IggyByteSize>,
     ) -> Result<(), IggyError> {
         let name = text::to_lowercase_non_whitespace(name);
         if self.topics_ids.contains_key(&name) {
@@ -30,7 +41,7 @@ impl Stream {
         if topic_id.is_none() {
             id = self.current_topic_id.fetch_add(1, Ordering::SeqCst);
             loop {
-                if self.topics.contains_key(&id) {
+                if self.topics.contains_key(&id) || self.trashed_topics.contains_key(&id) {
                     if id == u32::MAX {
                         return Err(IggyError::TopicIdAlreadyExists(id, self.stream_id));
                     }
@@ -43,24 +54,45 @@ impl Stream {
             id = topic_id.unwrap();
         }
 
-        if self.topics.contains_key(&id) {
+        if self.topics.contains_key(&id) || self.trashed_topics.contains_key(&id) {
             return Err(IggyError::TopicIdAlreadyExists(id, self.stream_id));
         }
 
+        let message_schema = message_schema.map(MessageSchema::compile).transpose()?;
+        let storage = match ephemeral {
+            true => Arc::new(self.storage.to_ephemeral()),
+            false => self.storage.clone(),
+        };
+        // The settings not provided explicitly are inherited from the stream defaults.
+        let topic_defaults = &self.topic_defaults;
+        let message_expiry = message_expiry.or(topic_defaults.message_expiry);
+        let max_topic_size = max_topic_size.or(topic_defaults.max_topic_size);
+        let replication_factor = match replication_factor {
+            0 => topic_defaults.replication_factor.unwrap_or(1),
+            replication_factor => replication_factor,
+        };
         // TODO: check if max_topic_size is not lower than system.segment.size
-        let topic = Topic::create(
+        let mut topic = Topic::create(
             self.stream_id,
             id,
             &name,
             partitions_count,
             self.config.clone(),
-            self.storage.clone(),
+            storage,
             self.size_bytes.clone(),
             self.messages_count.clone(),
             message_expiry,
             max_topic_size,
             replication_factor,
         )?;
+        topic.message_schema = message_schema;
+        topic.ephemeral = ephemeral;
+        topic.timestamp_type = timestamp_type;
+        topic.consumer_limits = consumer_limits;
+        topic.max_message_size = max_message_size;
+        if let Some(compression_algorithm) = &topic_defaults.compression_algorithm {
+            topic.compression_algorithm = compression_algorithm.clone();
+        }
         topic.persist().await?;
         info!("Created topic {}", topic);
         self.topics_ids.insert(name, id);
@@ -69,6 +101,7 @@ impl Stream {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_topic(
         &mut self,
         id: &Identifier,
@@ -76,7 +109,12 @@ impl Stream {
         message_expiry: Option<u32>,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        message_schema: Option<&str>,
+        timestamp_type: TimestampType,
+        consumer_limits: ConsumerLimits,
+        max_message_size: Option<IggyByteSize>,
     ) -> Result<(), IggyError> {
+        let message_schema = message_schema.map(MessageSchema::compile).transpose()?;
         let topic_id;
         {
             let topic = self.get_topic(id)?;
@@ -116,6 +154,11 @@ impl Stream {
             }
             topic.max_topic_size = max_topic_size;
             topic.replication_factor = replication_factor;
+            topic.message_schema = message_schema;
+            topic.timestamp_type = timestamp_type;
+            topic.consumer_limits = consumer_limits;
+            topic.max_message_size = max_message_size;
+            topic.max_message_size = max_message_size;
 
             topic.persist().await?;
             info!("Updated topic: {topic}");
@@ -124,10 +167,29 @@ impl Stream {
         Ok(())
     }
 
+    /// Freezes or unfreezes the topic, the frozen topic rejects the appended messages, but can still be polled.
+    pub async fn set_topic_frozen(
+        &mut self,
+        id: &Identifier,
+        frozen: bool,
+    ) -> Result<(), IggyError> {
+        let topic = self.get_topic_mut(id)?;
+        topic.frozen = frozen;
+        topic.persist().await?;
+        info!("Set topic: {topic} frozen: {frozen}");
+        Ok(())
+    }
+
     pub fn remove_topic(&mut self, identifier: &Identifier) -> Result<Topic, IggyError> {
         match identifier.kind {
             IdKind::Numeric => self.remove_topic_by_id(identifier.get_u32_value()?),
             IdKind::String => self.remove_topic_by_name(&identifier.get_cow_str_value()?),
+            IdKind::Uuid => {
+                let topic_id = self
+                    .get_topic_by_uuid(identifier.get_uuid_value()?)?
+                    .topic_id;
+                self.remove_topic_by_id(topic_id)
+            }
         }
     }
 
@@ -139,6 +201,7 @@ impl Stream {
         match identifier.kind {
             IdKind::Numeric => self.get_topic_by_id(identifier.get_u32_value()?),
             IdKind::String => self.get_topic_by_name(&identifier.get_cow_str_value()?),
+            IdKind::Uuid => self.get_topic_by_uuid(identifier.get_uuid_value()?),
         }
     }
 
@@ -146,6 +209,12 @@ impl Stream {
         match identifier.kind {
             IdKind::Numeric => self.get_topic_by_id_mut(identifier.get_u32_value()?),
             IdKind::String => self.get_topic_by_name_mut(&identifier.get_cow_str_value()?),
+            IdKind::Uuid => {
+                let topic_id = self
+                    .get_topic_by_uuid(identifier.get_uuid_value()?)?
+                    .topic_id;
+                self.get_topic_by_id_mut(topic_id)
+            }
         }
     }
 
@@ -162,6 +231,13 @@ impl Stream {
             .ok_or_else(|| IggyError::TopicNameNotFound(name.to_string(), self.stream_id))?
     }
 
+    fn get_topic_by_uuid(&self, uuid: Uuid) -> Result<&Topic, IggyError> {
+        self.topics
+            .values()
+            .find(|topic| topic.uuid == uuid)
+            .ok_or(IggyError::TopicUuidNotFound(uuid, self.stream_id))
+    }
+
     fn get_topic_by_id_mut(&mut self, id: u32) -> Result<&mut Topic, IggyError> {
         self.topics
             .get_mut(&id)
@@ -219,6 +295,8 @@ mod tests {
     use super::*;
     use crate::configs::system::SystemConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::models::topic_defaults::TopicDefaults;
     use std::sync::Arc;
 
     #[tokio::test]
@@ -240,6 +318,11 @@ mod tests {
                 message_expiry,
                 max_topic_size,
                 1,
+                None,
+                false,
+                TimestampType::default(),
+                ConsumerLimits::default(),
+                None,
             )
             .await
             .unwrap();
@@ -256,4 +339,61 @@ mod tests {
         assert_eq!(topic.topic_id, topic_id);
         assert_eq!(topic.name, topic_name);
     }
+
+    #[tokio::test]
+    async fn should_create_topic_inheriting_stream_topic_defaults() {
+        let config = Arc::new(SystemConfig::default());
+        let storage = Arc::new(get_test_system_storage());
+        let mut stream = Stream::create(1, "test_stream", config, storage);
+        stream.topic_defaults = TopicDefaults {
+            message_expiry: Some(10),
+            max_topic_size: Some(IggyByteSize::from(100)),
+            compression_algorithm: Some(CompressionAlgorithm::Lz4),
+            replication_factor: Some(3),
+        };
+        stream
+            .create_topic(
+                Some(1),
+                "inheriting",
+                1,
+                None,
+                None,
+                0,
+                None,
+                false,
+                TimestampType::default(),
+                ConsumerLimits::default(),
+                None,
+            )
+            .await
+            .unwrap();
+        stream
+            .create_topic(
+                Some(2),
+                "overriding",
+                1,
+                Some(20),
+                Some(IggyByteSize::from(200)),
+                2,
+                None,
+                false,
+                TimestampType::default(),
+                ConsumerLimits::default(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let topic = stream.get_topic(&Identifier::numeric(1).unwrap()).unwrap();
+        assert_eq!(topic.message_expiry, Some(10));
+        assert_eq!(topic.max_topic_size, Some(IggyByteSize::from(100)));
+        assert_eq!(topic.compression_algorithm, CompressionAlgorithm::Lz4);
+        assert_eq!(topic.replication_factor, 3);
+
+        let topic = stream.get_topic(&Identifier::numeric(2).unwrap()).unwrap();
+        assert_eq!(topic.message_expiry, Some(20));
+        assert_eq!(topic.max_topic_size, Some(IggyByteSize::from(200)));
+        assert_eq!(topic.compression_algorithm, CompressionAlgorithm::Lz4);
+        assert_eq!(topic.replication_factor, 2);
+    }
 }
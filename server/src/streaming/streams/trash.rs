@@ -0,0 +1,159 @@
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::trash::entry::TrashEntry;
+use crate::streaming::utils::file;
+use iggy::error::IggyError;
+use iggy::identifier::{IdKind, Identifier};
+use std::sync::atomic::Ordering;
+use tracing::{error, info};
+
+impl Stream {
+    pub fn get_trashed_topic(&self, identifier: &Identifier) -> Result<&TrashEntry, IggyError> {
+        let entry = match identifier.kind {
+            IdKind::Numeric => self.trashed_topics.get(&identifier.get_u32_value()?),
+            // The same name could have been trashed more than once, the latest one is restored first.
+            IdKind::String => {
+                let name = identifier.get_cow_str_value()?;
+                self.trashed_topics
+                    .values()
+                    .filter(|entry| entry.name == name)
+                    .max_by_key(|entry| entry.deleted_at)
+            }
+            IdKind::Uuid => None,
+        };
+        entry.ok_or_else(|| IggyError::TrashedTopicNotFound(identifier.to_string(), self.stream_id))
+    }
+
+    /// Moves the topic to the trash, its data and state are kept until it's restored or purged.
+    pub async fn trash_topic(&mut self, id: &Identifier) -> Result<Topic, IggyError> {
+        if self.get_topic(id)?.ephemeral {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let topic = self.remove_topic(id)?;
+        if let Err(error) = self.move_topic_to_trash(&topic).await {
+            self.topics_ids.insert(topic.name.clone(), topic.topic_id);
+            self.topics.insert(topic.topic_id, topic);
+            return Err(error);
+        }
+
+        self.size_bytes
+            .fetch_sub(topic.size_bytes.load(Ordering::SeqCst), Ordering::SeqCst);
+        self.messages_count.fetch_sub(
+            topic.messages_count.load(Ordering::SeqCst),
+            Ordering::SeqCst,
+        );
+        info!(
+            "Moved topic with ID: {} for stream with ID: {} to the trash.",
+            topic.topic_id, self.stream_id
+        );
+        Ok(topic)
+    }
+
+    async fn move_topic_to_trash(&mut self, topic: &Topic) -> Result<(), IggyError> {
+        topic.persist_messages().await?;
+        // The entry is saved first, so that the topic moved to the trash is never left without it.
+        let entry = TrashEntry::topic(self.stream_id, topic.topic_id, &topic.name);
+        self.storage.trash.save(&entry).await?;
+        let trash_path = self
+            .config
+            .get_trashed_topic_path(self.stream_id, topic.topic_id);
+        if let Err(error) = file::move_dir(&topic.path, &trash_path).await {
+            error!(
+                "Cannot move topic with ID: {} for stream with ID: {} to the trash. Error: {error}",
+                topic.topic_id, self.stream_id
+            );
+            self.storage.trash.delete(&entry).await?;
+            return Err(IggyError::CannotMoveTopicToTrash(
+                topic.topic_id,
+                self.stream_id,
+                trash_path,
+            ));
+        }
+
+        self.trashed_topics.insert(topic.topic_id, entry);
+        Ok(())
+    }
+
+    /// Restores the topic from the trash and returns its ID, the name must not be taken by another topic in the meantime.
+    pub async fn restore_topic(&mut self, id: &Identifier) -> Result<u32, IggyError> {
+        let entry = self.get_trashed_topic(id)?.clone();
+        if self.topics_ids.contains_key(&entry.name) {
+            return Err(IggyError::TopicNameAlreadyExists(
+                entry.name,
+                self.stream_id,
+            ));
+        }
+
+        let topic_id = entry.topic_id.unwrap_or_default();
+        let topic = self.load_trashed_topic(topic_id).await?;
+        self.storage.trash.delete(&entry).await?;
+        self.trashed_topics.remove(&topic_id);
+        info!(
+            "Restored topic with ID: {topic_id} for stream with ID: {} from the trash.",
+            self.stream_id
+        );
+        self.topics_ids.insert(topic.name.clone(), topic_id);
+        self.topics.insert(topic_id, topic);
+        Ok(topic_id)
+    }
+
+    /// Deletes the topic from the trash along with all its data.
+    pub async fn purge_trashed_topic(&mut self, topic_id: u32) -> Result<(), IggyError> {
+        let Some(entry) = self.trashed_topics.get(&topic_id).cloned() else {
+            return Err(IggyError::TrashedTopicNotFound(
+                topic_id.to_string(),
+                self.stream_id,
+            ));
+        };
+
+        let mut topic = Topic::empty(
+            self.stream_id,
+            topic_id,
+            self.config.clone(),
+            self.storage.clone(),
+        );
+        let topic_path = topic.path.clone();
+        let trash_path = self.config.get_trashed_topic_path(self.stream_id, topic_id);
+        file::move_dir(&trash_path, &topic_path)
+            .await
+            .map_err(|_| IggyError::CannotMoveTopicToTrash(topic_id, self.stream_id, trash_path))?;
+        topic.load().await?;
+        topic.delete().await?;
+        self.storage.trash.delete(&entry).await?;
+        self.trashed_topics.remove(&topic_id);
+        info!(
+            "Purged topic with ID: {topic_id} for stream with ID: {} from the trash.",
+            self.stream_id
+        );
+        Ok(())
+    }
+
+    /// Moves the topic back from the trash and loads it, the messages are counted by the stream again.
+    async fn load_trashed_topic(&self, topic_id: u32) -> Result<Topic, IggyError> {
+        let mut topic = Topic::empty(
+            self.stream_id,
+            topic_id,
+            self.config.clone(),
+            self.storage.clone(),
+        );
+        topic.size_of_parent_stream = self.size_bytes.clone();
+        topic.messages_count_of_parent_stream = self.messages_count.clone();
+        let trash_path = self.config.get_trashed_topic_path(self.stream_id, topic_id);
+        file::move_dir(&trash_path, &topic.path)
+            .await
+            .map_err(|_| {
+                IggyError::CannotMoveTopicToTrash(topic_id, self.stream_id, trash_path.clone())
+            })?;
+        if let Err(error) = topic.load().await {
+            error!(
+                "Cannot load topic with ID: {topic_id} for stream with ID: {} restored from the trash. Error: {error}",
+                self.stream_id
+            );
+            file::move_dir(&topic.path, &trash_path).await?;
+            return Err(error);
+        }
+
+        Ok(topic)
+    }
+}
@@ -0,0 +1,225 @@
+use crate::streaming::session::Session;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::{self, Partitioning, PartitioningKind};
+use iggy::models::header::{
+    HeaderKey, HeaderValue, CLIENT_ID_HEADER, CREATE_TIMESTAMP_HEADER, PARTITION_KEY_HEADER,
+    RECEIVE_TIMESTAMP_HEADER, ROUTING_EPOCH_HEADER, TRANSPORT_HEADER, USER_ID_HEADER,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// The headers in the reserved `$iggy.` namespace stamped on the messages appended within the session,
+/// so the consumers can tell who produced the message and measure the latency since it was received.
+#[derive(Debug)]
+pub struct SystemHeaders {
+    headers: Vec<(HeaderKey, HeaderValue)>,
+}
+
+impl SystemHeaders {
+    pub fn new(session: &Session, receive_timestamp: u64) -> Result<Self, IggyError> {
+        let mut headers = vec![
+            (
+                HeaderKey::new(USER_ID_HEADER)?,
+                HeaderValue::from_uint32(session.get_user_id())?,
+            ),
+            (
+                HeaderKey::new(CLIENT_ID_HEADER)?,
+                HeaderValue::from_uint32(session.client_id)?,
+            ),
+            (
+                HeaderKey::new(RECEIVE_TIMESTAMP_HEADER)?,
+                HeaderValue::from_uint64(receive_timestamp)?,
+            ),
+        ];
+        if let Some(transport) = session.transport {
+            headers.push((
+                HeaderKey::new(TRANSPORT_HEADER)?,
+                HeaderValue::from_str(&transport.to_string())?,
+            ));
+        }
+
+        Ok(Self { headers })
+    }
+
+    /// Adds the messages key and the routing epoch of the topic if the messages are partitioned by the key,
+    /// so the consumers can detect the changed key to partition mapping and the messages can be repartitioned.
+    pub fn with_routing(
+        mut self,
+        partitioning: &Partitioning,
+        routing_epoch: u32,
+    ) -> Result<Self, IggyError> {
+        if partitioning.kind != PartitioningKind::MessagesKey {
+            return Ok(self);
+        }
+
+        self.headers.push((
+            HeaderKey::new(PARTITION_KEY_HEADER)?,
+            HeaderValue::from_raw(&partitioning.value[..partitioning.length as usize])?,
+        ));
+        self.headers.push((
+            HeaderKey::new(ROUTING_EPOCH_HEADER)?,
+            HeaderValue::from_uint32(routing_epoch)?,
+        ));
+        Ok(self)
+    }
+
+    /// Replaces the reserved headers set by the producer with the system ones.
+    pub fn stamp(
+        &self,
+        headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Option<HashMap<HeaderKey, HeaderValue>> {
+        let mut headers = remove_reserved(headers).unwrap_or_default();
+        headers.extend(self.headers.iter().cloned());
+        Some(headers)
+    }
+}
+
+/// Returns the create timestamp provided by the producer in the reserved header, which has to be `uint64`.
+pub fn get_create_timestamp(message: &send_messages::Message) -> Result<Option<u64>, IggyError> {
+    let Some(headers) = &message.headers else {
+        return Ok(None);
+    };
+
+    headers
+        .iter()
+        .find(|(key, _)| key.as_str() == CREATE_TIMESTAMP_HEADER)
+        .map(|(_, value)| {
+            value
+                .as_uint64()
+                .map_err(|_| IggyError::InvalidCreateTimestamp(message.id))
+        })
+        .transpose()
+}
+
+/// Removes the headers in the reserved namespace, so the producers can't impersonate the server.
+pub fn remove_reserved(
+    headers: Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Option<HashMap<HeaderKey, HeaderValue>> {
+    let mut headers = headers?;
+    headers.retain(|key, _| !key.is_system());
+    if headers.is_empty() {
+        return None;
+    }
+
+    Some(headers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::clients::client_manager::Transport;
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    #[test]
+    fn system_headers_should_replace_reserved_ones_and_keep_others() {
+        let session = Session::new(7, 3, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234))
+            .with_transport(Transport::Tcp);
+        let system_headers = SystemHeaders::new(&session, 1000).unwrap();
+        let headers = HashMap::from([
+            (
+                HeaderKey::new("region").unwrap(),
+                HeaderValue::from_str("eu").unwrap(),
+            ),
+            (
+                HeaderKey::new(USER_ID_HEADER).unwrap(),
+                HeaderValue::from_uint32(1).unwrap(),
+            ),
+            (
+                HeaderKey::new("$iggy.custom").unwrap(),
+                HeaderValue::from_str("value").unwrap(),
+            ),
+        ]);
+
+        let headers = system_headers.stamp(Some(headers)).unwrap();
+
+        assert_eq!(headers.len(), 5);
+        assert_eq!(header(&headers, "region").as_str().unwrap(), "eu");
+        assert_eq!(header(&headers, USER_ID_HEADER).as_uint32().unwrap(), 3);
+        assert_eq!(header(&headers, CLIENT_ID_HEADER).as_uint32().unwrap(), 7);
+        assert_eq!(
+            header(&headers, RECEIVE_TIMESTAMP_HEADER)
+                .as_uint64()
+                .unwrap(),
+            1000
+        );
+        assert_eq!(header(&headers, TRANSPORT_HEADER).as_str().unwrap(), "TCP");
+    }
+
+    #[test]
+    fn system_headers_should_contain_partition_key_and_routing_epoch_only_for_messages_key() {
+        let session = Session::stateless(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0));
+        let system_headers = SystemHeaders::new(&session, 1000)
+            .unwrap()
+            .with_routing(&Partitioning::messages_key_str("user-1").unwrap(), 2)
+            .unwrap();
+
+        let headers = system_headers.stamp(None).unwrap();
+
+        assert_eq!(headers.len(), 5);
+        assert_eq!(
+            header(&headers, PARTITION_KEY_HEADER).as_raw().unwrap(),
+            b"user-1"
+        );
+        assert_eq!(
+            header(&headers, ROUTING_EPOCH_HEADER).as_uint32().unwrap(),
+            2
+        );
+
+        let system_headers = SystemHeaders::new(&session, 1000)
+            .unwrap()
+            .with_routing(&Partitioning::partition_id(1), 2)
+            .unwrap();
+
+        let headers = system_headers.stamp(None).unwrap();
+
+        assert_eq!(headers.len(), 3);
+        assert!(!headers.contains_key(&HeaderKey::new(PARTITION_KEY_HEADER).unwrap()));
+    }
+
+    #[test]
+    fn system_headers_should_not_contain_transport_for_server_session() {
+        let session = Session::stateless(1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0));
+        let system_headers = SystemHeaders::new(&session, 1000).unwrap();
+
+        let headers = system_headers.stamp(None).unwrap();
+
+        assert_eq!(headers.len(), 3);
+        assert!(!headers.contains_key(&HeaderKey::new(TRANSPORT_HEADER).unwrap()));
+    }
+
+    #[test]
+    fn remove_reserved_should_return_none_if_only_reserved_headers_were_set() {
+        let headers = HashMap::from([(
+            HeaderKey::new(TRANSPORT_HEADER).unwrap(),
+            HeaderValue::from_str("TCP").unwrap(),
+        )]);
+
+        assert!(remove_reserved(Some(headers)).is_none());
+        assert!(remove_reserved(None).is_none());
+    }
+
+    #[test]
+    fn create_timestamp_should_be_read_from_header_and_validated() {
+        let mut message = send_messages::Message::new(Some(1), "test".into(), None);
+        assert_eq!(get_create_timestamp(&message).unwrap(), None);
+
+        message.headers = Some(HashMap::from([(
+            HeaderKey::new(CREATE_TIMESTAMP_HEADER).unwrap(),
+            HeaderValue::from_uint64(1000).unwrap(),
+        )]));
+        assert_eq!(get_create_timestamp(&message).unwrap(), Some(1000));
+
+        message.headers = Some(HashMap::from([(
+            HeaderKey::new(CREATE_TIMESTAMP_HEADER).unwrap(),
+            HeaderValue::from_str("1000").unwrap(),
+        )]));
+        assert!(matches!(
+            get_create_timestamp(&message),
+            Err(IggyError::InvalidCreateTimestamp(1))
+        ));
+    }
+
+    fn header<'a>(headers: &'a HashMap<HeaderKey, HeaderValue>, key: &str) -> &'a HeaderValue {
+        headers.get(&HeaderKey::new(key).unwrap()).unwrap()
+    }
+}
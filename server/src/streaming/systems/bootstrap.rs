@@ -0,0 +1,177 @@
+use crate::configs::bootstrap::BootstrapDefinition;
+use crate::http::mapper;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::bootstrap::reconciliation::{
+    named_identifier, ExistingResources, ReconciliationAction, ReconciliationOptions,
+    ReconciliationPlan,
+};
+use iggy::error::IggyError;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::models::topic_defaults::TopicDefaults;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use std::net::{Ipv4Addr, SocketAddr};
+use tracing::{info, warn};
+
+impl System {
+    /// Reconciles the streams, topics and users with the declarative definition.
+    /// The missing resources are always created, the existing ones are updated only if `system.bootstrap.update_existing` is enabled.
+    pub async fn bootstrap(&mut self, definition: &BootstrapDefinition) -> Result<(), IggyError> {
+        info!("Bootstrapping the system from the declarative definition...");
+        let session = Session::stateless(
+            DEFAULT_ROOT_USER_ID,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+        );
+        let existing = self.get_existing_resources(definition).await;
+        let plan = ReconciliationPlan::new(
+            definition,
+            &existing,
+            ReconciliationOptions {
+                update_existing: self.config.bootstrap.update_existing,
+                default_message_expiry: Some(self.config.retention_policy.message_expiry.as_secs()),
+            },
+        );
+        for warning in &plan.warnings {
+            warn!("{warning}");
+        }
+        for action in &plan.actions {
+            info!("Bootstrap: {action}");
+            self.apply_bootstrap_action(&session, action).await?;
+        }
+        info!("Bootstrapped the system from the declarative definition.");
+        Ok(())
+    }
+
+    async fn get_existing_resources(&self, definition: &BootstrapDefinition) -> ExistingResources {
+        let mut existing = ExistingResources::default();
+        for stream in &definition.streams {
+            let Ok(stream_id) = named_identifier(&stream.name) else {
+                continue;
+            };
+            if let Ok(stream) = self.get_stream(&stream_id).await {
+                existing.streams.push(mapper::map_stream(&stream).await);
+            }
+        }
+        for user in &definition.users {
+            let Ok(user_id) = named_identifier(&user.username) else {
+                continue;
+            };
+            if let Ok(user) = self.get_user(&user_id).await {
+                existing.users.push(mapper::map_user(&user));
+            }
+        }
+        existing
+    }
+
+    async fn apply_bootstrap_action(
+        &mut self,
+        session: &Session,
+        action: &ReconciliationAction,
+    ) -> Result<(), IggyError> {
+        match action {
+            ReconciliationAction::CreateStream { stream_id, name } => {
+                self.create_stream(session, *stream_id, name, TopicDefaults::default())
+                    .await
+            }
+            ReconciliationAction::CreateTopic {
+                stream,
+                topic_id,
+                name,
+                partitions_count,
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+            } => {
+                self.create_topic(
+                    session,
+                    &named_identifier(stream)?,
+                    *topic_id,
+                    name,
+                    *partitions_count,
+                    *message_expiry,
+                    *max_topic_size,
+                    *replication_factor,
+                    None,
+                    false,
+                    TimestampType::default(),
+                    ConsumerLimits::default(),
+                    None,
+                )
+                .await
+            }
+            ReconciliationAction::UpdateTopic {
+                stream,
+                topic,
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+            } => {
+                let stream_id = named_identifier(stream)?;
+                let topic_id = named_identifier(topic)?;
+                let (message_schema, timestamp_type, consumer_limits, max_message_size) = {
+                    let stream = self.get_stream(&stream_id).await?;
+                    let topic = stream.get_topic(&topic_id)?;
+                    let message_schema = topic
+                        .message_schema
+                        .as_ref()
+                        .map(|message_schema| message_schema.as_str().to_string());
+                    (
+                        message_schema,
+                        topic.timestamp_type,
+                        topic.consumer_limits,
+                        topic.max_message_size,
+                    )
+                };
+                self.update_topic(
+                    session,
+                    &stream_id,
+                    &topic_id,
+                    topic,
+                    message_expiry.to,
+                    max_topic_size.to,
+                    replication_factor.to,
+                    message_schema.as_deref(),
+                    timestamp_type,
+                    consumer_limits,
+                    max_message_size,
+                )
+                .await
+            }
+            ReconciliationAction::CreatePartitions {
+                stream,
+                topic,
+                partitions_count,
+            } => {
+                self.create_partitions(
+                    session,
+                    &named_identifier(stream)?,
+                    &named_identifier(topic)?,
+                    *partitions_count,
+                    false,
+                )
+                .await
+            }
+            ReconciliationAction::CreateUser {
+                username,
+                password,
+                status,
+                permissions,
+            } => {
+                self.create_user(session, username, password, *status, permissions.clone())
+                    .await
+            }
+            ReconciliationAction::UpdateUserStatus { username, status } => self
+                .update_user(session, &named_identifier(username)?, None, Some(status.to))
+                .await
+                .map(|_| ()),
+            ReconciliationAction::UpdatePermissions {
+                username,
+                permissions,
+            } => {
+                self.update_permissions(session, &named_identifier(username)?, permissions.clone())
+                    .await
+            }
+        }
+    }
+}
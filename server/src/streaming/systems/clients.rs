@@ -1,19 +1,34 @@
 use crate::streaming::clients::client_manager::{Client, Transport};
+use crate::streaming::events::event::ServerEvent;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::models::hello_info::HelloInfo;
+use iggy::system::hello::{supported_features, MIN_PROTOCOL_VERSION, PROTOCOL_VERSION};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{error, info};
+use tracing::{debug, error, info, warn};
 
 impl System {
+    /// Logs and audits the connection rejected by the address rules of the listener.
+    pub fn reject_connection(&self, address: &SocketAddr, transport: Transport) {
+        warn!("Rejected {transport} connection from: {address}, the address is not allowed.");
+        self.audit_rejected_address(0, 0, address, "connection_rejected");
+    }
+
     pub async fn add_client(&self, address: &SocketAddr, transport: Transport) -> u32 {
         let mut client_manager = self.client_manager.write().await;
         let client_id = client_manager.add_client(address, transport);
         info!("Added {transport} client with ID: {client_id} for IP address: {address}");
         self.metrics.increment_clients(1);
+        self.publish_event(ServerEvent::ClientConnected {
+            client_id,
+            address: address.to_string(),
+            transport: transport.to_string(),
+        });
         client_id
     }
 
@@ -71,6 +86,11 @@ impl System {
                 "Deleted {} client with ID: {} for IP address: {}",
                 client.transport, client.client_id, client.address
             );
+            self.publish_event(ServerEvent::ClientDisconnected {
+                client_id: client.client_id,
+                address: client.address.to_string(),
+                transport: client.transport.to_string(),
+            });
         }
     }
 
@@ -85,6 +105,78 @@ impl System {
         client_manager.get_client_by_id(client_id)
     }
 
+    pub async fn hello(
+        &self,
+        session: &Session,
+        protocol_version: u32,
+        features: &[String],
+    ) -> Result<HelloInfo, IggyError> {
+        if protocol_version < MIN_PROTOCOL_VERSION {
+            error!(
+                "Client with ID: {} uses unsupported protocol version: {protocol_version}.",
+                session.client_id
+            );
+            return Err(IggyError::UnsupportedProtocolVersion(protocol_version));
+        }
+
+        let supported_features = supported_features();
+        let features = features
+            .iter()
+            .filter(|feature| supported_features.contains(feature))
+            .cloned()
+            .collect::<Vec<_>>();
+        let protocol_version = protocol_version.min(PROTOCOL_VERSION);
+        let mut client_manager = self.client_manager.write().await;
+        if client_manager
+            .set_protocol(session.client_id, protocol_version, features.clone())
+            .await
+            .is_ok()
+        {
+            debug!(
+                "Client with ID: {} negotiated protocol version: {protocol_version}, features: {}",
+                session.client_id,
+                features.join(",")
+            );
+        }
+
+        Ok(HelloInfo {
+            protocol_version,
+            server_version: env!("CARGO_PKG_VERSION").to_string(),
+            features,
+        })
+    }
+
+    pub async fn negotiate_compression(
+        &self,
+        session: &Session,
+        algorithms: &[CompressionAlgorithm],
+    ) -> Result<CompressionAlgorithm, IggyError> {
+        self.ensure_authenticated(session)?;
+        let default_algorithm = &self.config.compression.default_algorithm;
+        let algorithm = if algorithms.contains(default_algorithm) {
+            default_algorithm.clone()
+        } else {
+            algorithms
+                .first()
+                .cloned()
+                .unwrap_or(CompressionAlgorithm::None)
+        };
+
+        let mut client_manager = self.client_manager.write().await;
+        if client_manager
+            .set_compression_algorithms(session.client_id, algorithms.to_vec())
+            .await
+            .is_ok()
+        {
+            debug!(
+                "Client with ID: {} negotiated compression algorithm: {algorithm}",
+                session.client_id
+            );
+        }
+
+        Ok(algorithm)
+    }
+
     pub async fn get_clients(
         &self,
         session: &Session,
@@ -0,0 +1,25 @@
+use crate::connectors::runtime::ConnectorRuntime;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use std::sync::Arc;
+
+impl System {
+    pub fn get_connectors_runtime(
+        &self,
+        session: &Session,
+    ) -> Result<Arc<ConnectorRuntime>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_connectors(session.get_user_id())?;
+        self.connectors.clone().ok_or(IggyError::FeatureUnavailable)
+    }
+
+    pub fn manage_connectors_runtime(
+        &self,
+        session: &Session,
+    ) -> Result<Arc<ConnectorRuntime>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.manage_connectors(session.get_user_id())?;
+        self.connectors.clone().ok_or(IggyError::FeatureUnavailable)
+    }
+}
@@ -1,20 +1,25 @@
+use crate::streaming::events::event::ServerEvent;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::utils::text;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::Arc;
 use tokio::sync::RwLock;
+use tracing::{error, info};
 
 impl System {
-    pub fn get_consumer_group(
+    pub async fn get_consumer_group(
         &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
         consumer_group_id: &Identifier,
-    ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
+    ) -> Result<Arc<RwLock<ConsumerGroup>>, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(stream_id)?;
+        let stream = self.get_stream(stream_id).await?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner.get_consumer_group(
             session.get_user_id(),
@@ -25,14 +30,14 @@ impl System {
         topic.get_consumer_group(consumer_group_id)
     }
 
-    pub fn get_consumer_groups(
+    pub async fn get_consumer_groups(
         &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
-    ) -> Result<Vec<&RwLock<ConsumerGroup>>, IggyError> {
+    ) -> Result<Vec<Arc<RwLock<ConsumerGroup>>>, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(stream_id)?;
+        let stream = self.get_stream(stream_id).await?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner.get_consumer_groups(
             session.get_user_id(),
@@ -44,7 +49,7 @@ impl System {
     }
 
     pub async fn create_consumer_group(
-        &mut self,
+        &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
@@ -52,8 +57,9 @@ impl System {
         name: &str,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let _metadata_lock = self.metadata_lock.lock().await;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.create_consumer_group(
                 session.get_user_id(),
@@ -62,23 +68,31 @@ impl System {
             )?;
         }
 
-        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        let mut stream = self.get_stream_mut(stream_id).await?;
+        let topic = stream.get_topic_mut(topic_id)?;
         topic.create_consumer_group(consumer_group_id, name).await?;
+        self.publish_event(ServerEvent::ConsumerGroupCreated {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            consumer_group_id,
+            name: text::to_lowercase_non_whitespace(name),
+        });
         Ok(())
     }
 
     pub async fn delete_consumer_group(
-        &mut self,
+        &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
         consumer_group_id: &Identifier,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let _metadata_lock = self.metadata_lock.lock().await;
         let stream_id_value;
         let topic_id_value;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.delete_consumer_group(
                 session.get_user_id(),
@@ -91,7 +105,7 @@ impl System {
 
         let consumer_group;
         {
-            let stream = self.get_stream_mut(stream_id)?;
+            let mut stream = self.get_stream_mut(stream_id).await?;
             let topic = stream.get_topic_mut(topic_id)?;
             consumer_group = topic.delete_consumer_group(consumer_group_id).await?;
         }
@@ -110,9 +124,120 @@ impl System {
                 .await?;
         }
 
+        self.publish_event(ServerEvent::ConsumerGroupDeleted {
+            stream_id: stream_id_value,
+            topic_id: topic_id_value,
+            consumer_group_id: consumer_group.consumer_group_id,
+        });
         Ok(())
     }
 
+    /// Deletes the offsets of the consumer groups without members, which have been inactive (no member joined or left
+    /// and no offset was committed) for the offsets retention, and then the groups themselves, once they have been
+    /// inactive for the group retention. Returns the number of the groups whose offsets expired and of the expired groups.
+    pub async fn expire_consumer_groups(&mut self) -> Result<(u32, u32), IggyError> {
+        let now = IggyTimestamp::now().to_micros();
+        let offsets_retention = self
+            .config
+            .consumer_group_expiry
+            .offsets_retention
+            .as_micros();
+        let group_retention = self
+            .config
+            .consumer_group_expiry
+            .group_retention
+            .as_micros();
+        let mut expired_offsets_count = 0;
+        let mut expired_groups = Vec::new();
+        for stream in self.streams.values() {
+            let stream = stream.read().await;
+            for topic in stream.get_topics() {
+                for consumer_group in topic.get_consumer_groups() {
+                    let mut consumer_group = consumer_group.write().await;
+                    if !consumer_group.get_members().is_empty() {
+                        continue;
+                    }
+
+                    let last_commit = topic
+                        .get_consumer_group_last_commit(consumer_group.consumer_group_id)
+                        .await;
+                    let last_activity_at = consumer_group
+                        .last_activity_at
+                        .max(last_commit.unwrap_or_default());
+                    if last_commit.is_none() {
+                        if group_retention > 0 && now >= last_activity_at + group_retention {
+                            expired_groups.push((
+                                topic.stream_id,
+                                topic.topic_id,
+                                consumer_group.consumer_group_id,
+                                consumer_group.name.clone(),
+                                last_activity_at,
+                            ));
+                        }
+                        continue;
+                    }
+
+                    if now < last_activity_at + offsets_retention {
+                        continue;
+                    }
+
+                    match topic
+                        .expire_consumer_group_offsets(&mut consumer_group, last_activity_at)
+                        .await
+                    {
+                        Ok(offsets_count) => {
+                            expired_offsets_count += 1;
+                            self.publish_event(ServerEvent::ConsumerGroupOffsetsExpired {
+                                stream_id: topic.stream_id,
+                                topic_id: topic.topic_id,
+                                consumer_group_id: consumer_group.consumer_group_id,
+                                name: consumer_group.name.clone(),
+                                offsets_count,
+                                last_activity_at,
+                            });
+                        }
+                        Err(error) => error!(
+                            "Cannot expire offsets of consumer group with ID: {} for topic with ID: {} and stream with ID: {}. Error: {error}",
+                            consumer_group.consumer_group_id, topic.topic_id, topic.stream_id
+                        ),
+                    }
+                }
+            }
+        }
+
+        let mut expired_groups_count = 0;
+        for (stream_id, topic_id, consumer_group_id, name, last_activity_at) in expired_groups {
+            let result = async {
+                let mut stream = self
+                    .get_stream_mut(&Identifier::numeric(stream_id)?)
+                    .await?;
+                let topic = stream.get_topic_mut(&Identifier::numeric(topic_id)?)?;
+                topic
+                    .delete_consumer_group(&Identifier::numeric(consumer_group_id)?)
+                    .await
+            }
+            .await;
+            match result {
+                Ok(_) => {
+                    expired_groups_count += 1;
+                    info!("Expired consumer group with ID: {consumer_group_id} for topic with ID: {topic_id} and stream with ID: {stream_id}.");
+                    self.publish_event(ServerEvent::ConsumerGroupExpired {
+                        stream_id,
+                        topic_id,
+                        consumer_group_id,
+                        name,
+                        last_activity_at,
+                    });
+                }
+                Err(error) => error!(
+                    "Cannot expire consumer group with ID: {consumer_group_id} for topic with ID: {topic_id} and stream with ID: {stream_id}. Error: {error}"
+                ),
+            }
+        }
+
+        Ok((expired_offsets_count, expired_groups_count))
+    }
+
     pub async fn join_consumer_group(
         &self,
         session: &Session,
@@ -124,7 +249,7 @@ impl System {
         let stream_id_value;
         let topic_id_value;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.join_consumer_group(
                 session.get_user_id(),
@@ -137,7 +262,7 @@ impl System {
 
         let group_id;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             let topic = stream.get_topic(topic_id)?;
 
             {
@@ -167,7 +292,7 @@ impl System {
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.leave_consumer_group(
                 session.get_user_id(),
@@ -196,7 +321,7 @@ impl System {
         let topic_id_value;
         let group_id;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             let topic = stream.get_topic(topic_id)?;
 
             {
@@ -15,7 +15,7 @@ impl System {
         offset: u64,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(stream_id)?;
+        let stream = self.get_stream(stream_id).await?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner.store_consumer_offset(
             session.get_user_id(),
@@ -34,7 +34,7 @@ impl System {
         topic_id: &Identifier,
     ) -> Result<ConsumerOffsetInfo, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(stream_id)?;
+        let stream = self.get_stream(stream_id).await?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner.get_consumer_offset(
             session.get_user_id(),
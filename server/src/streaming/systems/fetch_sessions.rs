@@ -0,0 +1,236 @@
+use crate::streaming::clients::fetch_session::{
+    FetchSession, FetchSessionPartition, MAX_FETCH_SESSIONS_PER_CLIENT,
+};
+use crate::streaming::models::messages::{FetchedMessages, FetchedPartition};
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::System;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::create_fetch_session::FetchPartition;
+use iggy::messages::poll_messages::{PollingMode, PollingStrategy};
+use iggy::models::fetch_session::FetchSession as FetchSessionInfo;
+use tracing::{debug, info};
+
+impl System {
+    pub async fn create_fetch_session(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        partitions: &[FetchPartition],
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<FetchSessionInfo, IggyError> {
+        self.ensure_authenticated(session)?;
+        if consumer.kind != ConsumerKind::Consumer {
+            return Err(IggyError::InvalidFetchSession(
+                "only the regular consumer can create the fetch session".to_string(),
+            ));
+        }
+
+        let consumer_id = PollingConsumer::resolve_consumer_id(&consumer.id);
+        let mut session_partitions: Vec<FetchSessionPartition> =
+            Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            let stream = self.get_stream(&partition.stream_id).await?;
+            let topic = stream.get_topic(&partition.topic_id)?;
+            self.permissioner.poll_messages(
+                session.get_user_id(),
+                stream.stream_id,
+                topic.topic_id,
+            )?;
+            // The fetch continues from the offset stored by the consumer, or from the beginning of the partition.
+            let next_offset = {
+                let partition = topic.get_partition(partition.partition_id)?;
+                let partition = partition.read().await;
+                partition
+                    .consumer_offsets
+                    .get(&consumer_id)
+                    .map_or(0, |consumer_offset| consumer_offset.offset + 1)
+            };
+            let session_partition = FetchSessionPartition {
+                stream_id: stream.stream_id,
+                topic_id: topic.topic_id,
+                partition_id: partition.partition_id,
+                next_offset,
+            };
+            if session_partitions.iter().any(|existing| {
+                existing.stream_id == session_partition.stream_id
+                    && existing.topic_id == session_partition.topic_id
+                    && existing.partition_id == session_partition.partition_id
+            }) {
+                return Err(IggyError::InvalidFetchSession(format!(
+                    "partition: {partition} is registered more than once"
+                )));
+            }
+
+            session_partitions.push(session_partition);
+        }
+
+        let client = self
+            .client_manager
+            .read()
+            .await
+            .get_client_by_id(session.client_id)?;
+        let mut client = client.write().await;
+        if client.fetch_sessions.len() as u32 >= MAX_FETCH_SESSIONS_PER_CLIENT {
+            return Err(IggyError::FetchSessionsLimitReached(
+                MAX_FETCH_SESSIONS_PER_CLIENT,
+            ));
+        }
+
+        client.last_fetch_session_id += 1;
+        let session_id = client.last_fetch_session_id;
+        let partitions_count = session_partitions.len();
+        let fetch_session = FetchSession::new(
+            session_id,
+            consumer_id,
+            count,
+            auto_commit,
+            session_partitions,
+        );
+        let epoch = fetch_session.epoch;
+        client.fetch_sessions.insert(session_id, fetch_session);
+        info!(
+            "Created fetch session with ID: {session_id} for {partitions_count} partition(s) by client with ID: {}.",
+            session.client_id
+        );
+        Ok(FetchSessionInfo { session_id, epoch })
+    }
+
+    pub async fn fetch_messages(
+        &self,
+        session: &Session,
+        session_id: u32,
+        epoch: u32,
+    ) -> Result<FetchedMessages, IggyError> {
+        self.ensure_authenticated(session)?;
+        let client = self
+            .client_manager
+            .read()
+            .await
+            .get_client_by_id(session.client_id)?;
+        // The client stays locked until the fetch completes, so the concurrent fetches of the same session can't interleave.
+        let mut client = client.write().await;
+        let Some(fetch_session) = client.fetch_sessions.get_mut(&session_id) else {
+            return Err(IggyError::FetchSessionNotFound(session_id));
+        };
+
+        if fetch_session.epoch != epoch {
+            return Err(IggyError::InvalidFetchSessionEpoch(
+                epoch,
+                session_id,
+                fetch_session.epoch,
+            ));
+        }
+
+        // The next offsets are updated only once all the partitions have been fetched,
+        // so the failed fetch can be retried with the same epoch without skipping any messages.
+        let mut next_offsets = Vec::new();
+        let mut partitions = Vec::new();
+        for (index, partition) in fetch_session.partitions.iter().enumerate() {
+            let stream_id = Identifier::numeric(partition.stream_id)?;
+            let topic_id = Identifier::numeric(partition.topic_id)?;
+            let Some(offset) = self
+                .get_fetch_offset(&stream_id, &topic_id, partition)
+                .await?
+            else {
+                continue;
+            };
+
+            let consumer =
+                PollingConsumer::Consumer(fetch_session.consumer_id, partition.partition_id);
+            let messages = self
+                .poll_messages(
+                    session,
+                    consumer,
+                    &stream_id,
+                    &topic_id,
+                    PollingArgs::new(
+                        PollingStrategy::offset(offset),
+                        fetch_session.count,
+                        fetch_session.auto_commit,
+                        None,
+                        PollingMode::Consume,
+                    ),
+                )
+                .await?;
+            let Some(last_message) = messages.messages.last() else {
+                continue;
+            };
+
+            next_offsets.push((index, last_message.offset + 1));
+            partitions.push(FetchedPartition {
+                stream_id: partition.stream_id,
+                topic_id: partition.topic_id,
+                messages,
+            });
+        }
+
+        for (index, next_offset) in next_offsets {
+            fetch_session.partitions[index].next_offset = next_offset;
+        }
+        fetch_session.epoch = fetch_session.epoch.wrapping_add(1);
+        debug!(
+            "Fetched messages from {} partition(s) for fetch session with ID: {session_id}, next epoch: {}.",
+            partitions.len(),
+            fetch_session.epoch
+        );
+        Ok(FetchedMessages {
+            session_id,
+            epoch: fetch_session.epoch,
+            partitions,
+        })
+    }
+
+    pub async fn delete_fetch_session(
+        &self,
+        session: &Session,
+        session_id: u32,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let client = self
+            .client_manager
+            .read()
+            .await
+            .get_client_by_id(session.client_id)?;
+        let mut client = client.write().await;
+        if client.fetch_sessions.remove(&session_id).is_none() {
+            return Err(IggyError::FetchSessionNotFound(session_id));
+        }
+
+        info!(
+            "Deleted fetch session with ID: {session_id} by client with ID: {}.",
+            session.client_id
+        );
+        Ok(())
+    }
+
+    /// Returns the offset from which the messages should be fetched, or `None` if the partition has no new messages,
+    /// which is checked without reading any segment, so the idle partitions of the session are cheap to skip.
+    async fn get_fetch_offset(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition: &FetchSessionPartition,
+    ) -> Result<Option<u64>, IggyError> {
+        let stream = self.get_stream(stream_id).await?;
+        let topic = stream.get_topic(topic_id)?;
+        let partition_lock = topic.get_partition(partition.partition_id)?;
+        let partition_state = partition_lock.read().await;
+        if partition_state.get_messages_count() == 0
+            || partition.next_offset > partition_state.current_offset
+        {
+            return Ok(None);
+        }
+
+        // The messages older than the next offset might have already expired, so the fetch starts from the oldest remaining one.
+        let first_offset = partition_state
+            .segments
+            .first()
+            .map_or(0, |segment| segment.start_offset);
+        Ok(Some(partition.next_offset.max(first_offset)))
+    }
+}
@@ -1,3 +1,4 @@
+use crate::streaming::migrations::migrator::{Migrator, CURRENT_FORMAT_VERSION};
 use crate::streaming::systems::system::System;
 use iggy::error::IggyError;
 use serde::{Deserialize, Serialize};
@@ -5,7 +6,7 @@ use std::collections::hash_map::DefaultHasher;
 use std::fmt::Display;
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
-use tracing::info;
+use tracing::{error, info};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -13,6 +14,9 @@ const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub struct SystemInfo {
     pub version: Version,
     pub migrations: Vec<Migration>,
+    /// The version of the storage format, the data written before the versioning was introduced has version 0.
+    #[serde(default)]
+    pub format_version: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -44,6 +48,7 @@ impl System {
             match err {
                 IggyError::ResourceNotFound(_) => {
                     info!("System info not found, creating...");
+                    system_info.format_version = CURRENT_FORMAT_VERSION;
                     self.update_system_info(&mut system_info).await?;
                 }
                 _ => return Err(err),
@@ -63,6 +68,63 @@ impl System {
             self.update_system_info(&mut system_info).await?;
         }
 
+        self.apply_storage_migrations(&mut system_info, self.config.migration.auto_migrate)
+            .await
+    }
+
+    /// Applies the pending storage migrations without loading the streams, used by the `--migrate` argument.
+    pub async fn migrate(&mut self) -> Result<(), IggyError> {
+        let mut system_info = SystemInfo::default();
+        if let Err(err) = self.storage.info.load(&mut system_info).await {
+            return match err {
+                IggyError::ResourceNotFound(_) => {
+                    info!("System info not found, there is nothing to migrate.");
+                    Ok(())
+                }
+                _ => Err(err),
+            };
+        }
+
+        self.apply_storage_migrations(&mut system_info, true).await
+    }
+
+    async fn apply_storage_migrations(
+        &self,
+        system_info: &mut SystemInfo,
+        allowed: bool,
+    ) -> Result<(), IggyError> {
+        let format_version = system_info.format_version;
+        if format_version > CURRENT_FORMAT_VERSION {
+            error!("Storage format version {format_version} is not supported, the latest supported version is {CURRENT_FORMAT_VERSION}.");
+            return Err(IggyError::UnsupportedStorageFormat(
+                format_version,
+                CURRENT_FORMAT_VERSION,
+            ));
+        }
+
+        let migrator = Migrator::new(self.config.clone());
+        let pending_migrations = migrator.get_pending_migrations(format_version).len();
+        if pending_migrations == 0 {
+            info!("Storage format version {format_version} is up to date.");
+            return Ok(());
+        }
+
+        if !allowed {
+            error!("Storage format version {format_version} requires {pending_migrations} migration(s), run the server with the `--migrate` argument to apply them.");
+            return Err(IggyError::StorageMigrationRequired(
+                format_version,
+                CURRENT_FORMAT_VERSION,
+            ));
+        }
+
+        info!("Storage format version {format_version} requires {pending_migrations} migration(s), applying...");
+        let applied_migrations = migrator
+            .migrate(system_info, self.storage.info.as_ref())
+            .await?;
+        info!(
+            "Applied {applied_migrations} storage migration(s), format version is {}.",
+            system_info.format_version
+        );
         Ok(())
     }
 
@@ -85,6 +147,7 @@ impl SystemInfo {
 impl Hash for SystemInfo {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.version.version.hash(state);
+        self.format_version.hash(state);
         for migration in &self.migrations {
             migration.hash(state);
         }
@@ -0,0 +1,48 @@
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use tracing::error;
+
+impl System {
+    /// Ensures that creating the new stream won't exceed the configured limit of streams, `0` means unlimited.
+    pub(crate) fn ensure_streams_limit(&self) -> Result<(), IggyError> {
+        let max_streams = self.config.limits.max_streams;
+        if max_streams == 0 || (self.streams.len() as u32) < max_streams {
+            return Ok(());
+        }
+
+        error!("Cannot create stream, the limit of {max_streams} streams has been reached.");
+        Err(IggyError::StreamsLimitReached(max_streams))
+    }
+
+    /// Ensures that creating the new topic won't exceed the configured limit of topics per stream, `0` means unlimited.
+    pub(crate) fn ensure_topics_limit(&self, stream: &Stream) -> Result<(), IggyError> {
+        let max_topics = self.config.limits.max_topics_per_stream;
+        if max_topics == 0 || stream.get_topics_count() < max_topics {
+            return Ok(());
+        }
+
+        error!(
+            "Cannot create topic for stream with ID: {}, the limit of {max_topics} topics per stream has been reached.",
+            stream.stream_id
+        );
+        Err(IggyError::TopicsLimitReached(stream.stream_id, max_topics))
+    }
+
+    /// Ensures that adding the partitions to the topic won't exceed the configured limit of partitions per topic, `0` means unlimited.
+    pub(crate) fn ensure_partitions_limit(
+        &self,
+        current_partitions_count: u32,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        let max_partitions = self.config.limits.max_partitions_per_topic;
+        if max_partitions == 0 || current_partitions_count + partitions_count <= max_partitions {
+            return Ok(());
+        }
+
+        error!(
+            "Cannot create {partitions_count} partition(s) in addition to {current_partitions_count}, the limit of {max_partitions} partitions per topic has been reached."
+        );
+        Err(IggyError::PartitionsLimitReached(max_partitions))
+    }
+}
@@ -1,18 +1,32 @@
+use crate::runtimes::RuntimeHandles;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::models::messages::PolledMessages;
+use crate::streaming::partitions::append_notifier::AppendSubscription;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
-use crate::streaming::systems::system::System;
+use crate::streaming::system_headers::{self, SystemHeaders};
+use crate::streaming::systems::system::{SharedSystem, System};
 use bytes::Bytes;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::headers_filter::HeadersFilter;
+use iggy::messages::messages_query::{MessagesQuery, QueryColumn};
+use iggy::messages::poll_messages::{PollingMode, PollingStrategy};
 use iggy::messages::send_messages;
-use iggy::messages::send_messages::Partitioning;
-use iggy::models::messages::Message;
+use iggy::messages::send_messages::{Acks, Partitioning};
+use iggy::models::messages::{Message, SendMessagesReceipt};
+use iggy::models::query_result::QueryResult;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::utils::checksum;
+use iggy::utils::checksum::ChecksumPolicy;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
+use tokio::time::{timeout_at, Instant};
 use tracing::{error, trace};
 
+const QUERY_MESSAGES_BATCH_SIZE: u32 = 1000;
+
 impl System {
     pub async fn poll_messages(
         &self,
@@ -27,7 +41,7 @@ impl System {
             return Err(IggyError::InvalidMessagesCount);
         }
 
-        let stream = self.get_stream(stream_id)?;
+        let stream = self.get_stream(stream_id).await?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner
             .poll_messages(session.get_user_id(), stream.stream_id, topic.topic_id)?;
@@ -39,28 +53,74 @@ impl System {
         let partition_id = match consumer {
             PollingConsumer::Consumer(_, partition_id) => partition_id,
             PollingConsumer::ConsumerGroup(consumer_group_id, member_id) => {
-                let consumer_group = topic
-                    .get_consumer_group_by_id(consumer_group_id)?
-                    .read()
-                    .await;
+                let consumer_group = topic.get_consumer_group_by_id(consumer_group_id)?;
+                let consumer_group = consumer_group.read().await;
                 consumer_group.calculate_partition_id(member_id).await?
             }
         };
 
-        let mut polled_messages = topic
-            .get_messages(consumer, partition_id, args.strategy, args.count)
-            .await?;
+        // The headers are not encrypted, so the messages can be filtered before the decryption.
+        // The offset of the last scanned message is committed, so the non-matching messages are not scanned again.
+        let (mut polled_messages, mut last_offset) = match (&args.filter, args.mode) {
+            (filter, PollingMode::PeekBackward) => {
+                let polled_messages = topic
+                    .get_messages_backward(partition_id, args.strategy, args.count, filter.as_ref())
+                    .await?;
+                let last_offset = polled_messages
+                    .messages
+                    .last()
+                    .map(|message| message.offset);
+                (polled_messages, last_offset)
+            }
+            (Some(filter), _) => {
+                topic
+                    .get_filtered_messages(
+                        consumer,
+                        partition_id,
+                        args.strategy,
+                        args.count,
+                        filter,
+                    )
+                    .await?
+            }
+            (None, _) => {
+                let polled_messages = topic
+                    .get_messages(consumer, partition_id, args.strategy, args.count)
+                    .await?;
+                let last_offset = polled_messages
+                    .messages
+                    .last()
+                    .map(|message| message.offset);
+                (polled_messages, last_offset)
+            }
+        };
 
-        if polled_messages.messages.is_empty() {
-            return Ok(polled_messages);
+        // The messages left out are polled again from the next offset, so the offset of the last returned one is committed instead.
+        if let Some(max_bytes) = args.max_bytes {
+            if polled_messages.truncate_to_max_bytes(max_bytes) {
+                last_offset = polled_messages
+                    .messages
+                    .last()
+                    .map(|message| message.offset);
+            }
         }
 
-        let offset = polled_messages.messages.last().unwrap().offset;
-        if args.auto_commit {
+        let Some(offset) = last_offset else {
+            return Ok(polled_messages);
+        };
+
+        self.validate_checksums(&polled_messages)?;
+
+        // In the peek modes the messages are only browsed, so the consumer offset is never stored.
+        if args.auto_commit && args.mode.is_consume() {
             trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
             topic.store_consumer_offset(consumer, offset).await?;
         }
 
+        if polled_messages.messages.is_empty() {
+            return Ok(polled_messages);
+        }
+
         if self.encryptor.is_none() {
             return Ok(polled_messages);
         }
@@ -76,7 +136,7 @@ impl System {
                         state: message.state,
                         offset: message.offset,
                         timestamp: message.timestamp,
-                        checksum: message.checksum,
+                        checksum: checksum::calculate(&payload),
                         length: payload.len() as u32,
                         payload: Bytes::from(payload),
                         headers: message.headers.clone(),
@@ -94,6 +154,145 @@ impl System {
         Ok(polled_messages)
     }
 
+    /// Subscribes to the appends to the partitions from which the consumer polls the messages.
+    /// The consumer group member can be assigned any partition of the topic, so all of them are subscribed to.
+    async fn subscribe_to_appends(
+        &self,
+        consumer: PollingConsumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<AppendSubscription>, IggyError> {
+        let stream = self.get_stream(stream_id).await?;
+        let topic = stream.get_topic(topic_id)?;
+        let partitions = match consumer {
+            PollingConsumer::Consumer(_, partition_id) => vec![topic.get_partition(partition_id)?],
+            PollingConsumer::ConsumerGroup(_, _) => topic.partitions.values().cloned().collect(),
+        };
+        let mut subscriptions = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            subscriptions.push(partition.read().await.append_notifier.subscribe());
+        }
+        Ok(subscriptions)
+    }
+
+    /// Scans the partition from the lowest offset (or timestamp) allowed by the query conditions,
+    /// until the limit of the matching messages is reached or there are no more messages to scan.
+    /// Once the configured limit of the scanned messages is reached, the rows found so far are returned
+    /// together with the offset from which the query can be continued.
+    pub async fn query_messages(
+        &self,
+        session: &Session,
+        query: &MessagesQuery,
+    ) -> Result<QueryResult, IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(&query.stream_id).await?;
+        let topic = stream.get_topic(&query.topic_id)?;
+        self.permissioner
+            .poll_messages(session.get_user_id(), stream.stream_id, topic.topic_id)?;
+
+        let partition = topic.get_partition(query.partition_id)?;
+        let (end_offset, mut batch) = {
+            let partition = partition.read().await;
+            let end_offset = query
+                .get_end_offset()
+                .map_or(partition.current_offset, |offset| {
+                    offset.min(partition.current_offset)
+                });
+            let mut batch = match (query.get_start_offset(), query.get_start_timestamp()) {
+                (None, Some(timestamp)) => {
+                    partition
+                        .get_messages_by_timestamp(timestamp, QUERY_MESSAGES_BATCH_SIZE)
+                        .await?
+                }
+                _ => vec![],
+            };
+            // The timestamp lookup finds nothing if no message has the timestamp at or after the given one (or the time indexes are not cached),
+            // in such a case the partition is scanned from the start, and the timestamp condition is evaluated for each message.
+            if batch.is_empty() {
+                batch = partition
+                    .get_messages_by_offset(
+                        query.get_start_offset().unwrap_or(0),
+                        QUERY_MESSAGES_BATCH_SIZE,
+                    )
+                    .await?;
+            }
+            (end_offset, batch)
+        };
+
+        let max_scanned_messages = self.config.limits.max_scanned_messages_per_query;
+        let decrypt_payload = self.encryptor.is_some() && query.uses_column(&QueryColumn::Length);
+        let mut rows = Vec::new();
+        let mut scanned_messages = 0;
+        let mut next_offset = None;
+        'scan: while let Some(last_message) = batch.last() {
+            let last_offset = last_message.offset;
+            for message in batch.iter() {
+                if message.offset > end_offset || rows.len() == query.limit as usize {
+                    break 'scan;
+                }
+
+                if max_scanned_messages > 0 && scanned_messages == max_scanned_messages {
+                    next_offset = Some(message.offset);
+                    break 'scan;
+                }
+
+                scanned_messages += 1;
+
+                let payload_length = match self.encryptor {
+                    Some(ref encryptor) if decrypt_payload => {
+                        encryptor.decrypt(&message.payload)?.len() as u32
+                    }
+                    _ => message.payload.len() as u32,
+                };
+                if query.matches(message, payload_length) {
+                    rows.push(query.project(message, payload_length));
+                }
+            }
+
+            if last_offset >= end_offset {
+                break;
+            }
+
+            // The partition is locked only for reading the next batch, so that the long scan doesn't block the appends.
+            batch = partition
+                .read()
+                .await
+                .get_messages_by_offset(last_offset + 1, QUERY_MESSAGES_BATCH_SIZE)
+                .await?;
+        }
+
+        trace!(
+            "Query: {} returned {} row(s) for partition with ID: {}, scanned messages: {}",
+            query,
+            rows.len(),
+            query.partition_id,
+            scanned_messages
+        );
+        Ok(QueryResult {
+            columns: query.get_column_names(),
+            rows,
+            next_offset,
+        })
+    }
+
+    fn validate_checksums(&self, polled_messages: &PolledMessages) -> Result<(), IggyError> {
+        let checksum_policy = self.config.partition.checksum_policy;
+        if checksum_policy == ChecksumPolicy::Off {
+            return Ok(());
+        }
+
+        for message in polled_messages.messages.iter() {
+            let result =
+                checksum_policy.validate(&message.payload, message.checksum, message.offset);
+            if !matches!(result, Ok(true)) {
+                self.metrics.increment_checksum_mismatches();
+            }
+            result?;
+        }
+
+        Ok(())
+    }
+
     pub async fn append_messages(
         &self,
         session: &Session,
@@ -101,18 +300,44 @@ impl System {
         topic_id: &Identifier,
         partitioning: &Partitioning,
         messages: &Vec<send_messages::Message>,
-    ) -> Result<(), IggyError> {
+        acks: Acks,
+    ) -> Result<Option<SendMessagesReceipt>, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(stream_id)?;
-        let topic = stream.get_topic(topic_id)?;
-        self.permissioner.append_messages(
-            session.get_user_id(),
-            stream.stream_id,
-            topic.topic_id,
-        )?;
+        let tenant_id;
+        let timestamp_type;
+        let routing_epoch;
+        {
+            let stream = self.get_stream(stream_id).await?;
+            let topic = stream.get_topic(topic_id)?;
+            self.permissioner.append_messages(
+                session.get_user_id(),
+                stream.stream_id,
+                topic.topic_id,
+            )?;
+            if let Some(message_schema) = &topic.message_schema {
+                message_schema.validate(messages)?;
+            }
+            topic.validate_message_sizes(messages)?;
+            tenant_id = stream.tenant_id;
+            timestamp_type = topic.timestamp_type;
+            routing_epoch = topic.routing_epoch;
+        }
+
+        self.disk_space.ensure_space_for_sends()?;
+        // The usage of the tenant is calculated across its streams, so the stream lock must be released in the meantime.
+        self.ensure_tenant_quotas(tenant_id, 0, 0, 0).await?;
 
         let mut received_messages = Vec::with_capacity(messages.len());
         let mut batch_size_bytes = 0u64;
+        // The messages sent at once share the append timestamp, so their timestamp deltas in the stored batch take a single byte.
+        let timestamp = IggyTimestamp::now().to_micros();
+        let system_headers = match self.config.system_headers.enabled {
+            true => Some(
+                SystemHeaders::new(session, timestamp)?
+                    .with_routing(partitioning, routing_epoch)?,
+            ),
+            false => None,
+        };
 
         // For large batches it would be better to use par_iter() from rayon.
         for message in messages {
@@ -131,7 +356,19 @@ impl System {
                 None => message,
             };
             batch_size_bytes += message.get_size_bytes() as u64;
-            received_messages.push(Message::from_message(message));
+            let message_timestamp = match timestamp_type {
+                TimestampType::LogAppendTime => timestamp,
+                TimestampType::CreateTime => {
+                    system_headers::get_create_timestamp(message)?.unwrap_or(timestamp)
+                }
+            };
+            let mut received_message =
+                Message::from_message_with_timestamp(message, message_timestamp);
+            received_message.headers = match system_headers {
+                Some(ref system_headers) => system_headers.stamp(received_message.headers),
+                None => system_headers::remove_reserved(received_message.headers),
+            };
+            received_messages.push(received_message);
         }
 
         // If there's enough space in cache, do nothing.
@@ -141,27 +378,102 @@ impl System {
                 self.clean_cache(batch_size_bytes).await;
             }
         }
-        topic
-            .append_messages(partitioning, received_messages)
-            .await?;
+        let appended_messages = {
+            let stream = self.get_stream(stream_id).await?;
+            let topic = stream.get_topic(topic_id)?;
+            let partition_shards = self
+                .runtimes
+                .as_ref()
+                .and_then(RuntimeHandles::partition_shards);
+            topic
+                .append_messages(partitioning, received_messages, acks, partition_shards)
+                .await?
+        };
         self.metrics.increment_messages(messages.len() as u64);
-        Ok(())
+        self.release_memory().await?;
+        Ok(
+            appended_messages.map(|appended_messages| SendMessagesReceipt {
+                partition_id: appended_messages.partition_id,
+                base_offset: appended_messages.base_offset,
+                messages_count: appended_messages.messages_count,
+                timestamp,
+            }),
+        )
+    }
+}
+
+impl SharedSystem {
+    /// Polls the messages, and if there are none, waits until the new messages are appended to the polled partition or the max wait time elapses,
+    /// so that the consumers don't have to poll the empty partitions in a loop. The lock is not held while waiting, so the messages can be appended in the meantime.
+    pub async fn poll_messages(
+        &self,
+        session: &Session,
+        consumer: PollingConsumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        args: PollingArgs,
+        max_wait: Option<IggyDuration>,
+    ) -> Result<PolledMessages, IggyError> {
+        let Some(max_wait) = max_wait.filter(|max_wait| !max_wait.get_duration().is_zero()) else {
+            let system = self.read();
+            return system
+                .poll_messages(session, consumer, stream_id, topic_id, args)
+                .await;
+        };
+
+        let deadline = Instant::now() + max_wait.get_duration();
+        loop {
+            let system = self.read();
+            // The subscriptions are made before polling, so the messages appended in the meantime are not missed.
+            let mut subscriptions = system
+                .subscribe_to_appends(consumer, stream_id, topic_id)
+                .await?;
+            let polled_messages = system
+                .poll_messages(session, consumer, stream_id, topic_id, args.clone())
+                .await?;
+            if !polled_messages.messages.is_empty() || Instant::now() >= deadline {
+                return Ok(polled_messages);
+            }
+
+            drop(system);
+            let next_append = AppendSubscription::next_any(&mut subscriptions);
+            if timeout_at(deadline, next_append).await.is_err() {
+                return Ok(polled_messages);
+            }
+        }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
     pub auto_commit: bool,
+    pub filter: Option<HeadersFilter>,
+    pub mode: PollingMode,
+    pub max_bytes: Option<u32>,
 }
 
 impl PollingArgs {
-    pub fn new(strategy: PollingStrategy, count: u32, auto_commit: bool) -> Self {
+    pub fn new(
+        strategy: PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        filter: Option<HeadersFilter>,
+        mode: PollingMode,
+    ) -> Self {
         Self {
             strategy,
             count,
             auto_commit,
+            filter,
+            mode,
+            max_bytes: None,
         }
     }
+
+    pub fn with_max_bytes(mut self, max_bytes: Option<u32>) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
 }
@@ -0,0 +1,45 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::models::metadata::{Metadata, StreamMetadata, TopicMetadata};
+
+impl System {
+    /// Returns the streams and topics the user is allowed to read. The stream is also returned if the user can read only some of its topics.
+    pub async fn get_metadata(&self, session: &Session) -> Result<Metadata, IggyError> {
+        self.ensure_authenticated(session)?;
+        let user_id = session.get_user_id();
+        let mut streams = Vec::new();
+        for stream in self.get_streams().await {
+            let topics = stream
+                .get_topics()
+                .into_iter()
+                .filter(|topic| {
+                    self.permissioner
+                        .get_topic(user_id, stream.stream_id, topic.topic_id)
+                        .is_ok()
+                })
+                .map(|topic| TopicMetadata {
+                    id: topic.topic_id,
+                    name: topic.name.clone(),
+                    partitions_count: topic.get_partitions_count(),
+                })
+                .collect::<Vec<_>>();
+            if topics.is_empty()
+                && self
+                    .permissioner
+                    .get_stream(user_id, stream.stream_id)
+                    .is_err()
+            {
+                continue;
+            }
+
+            streams.push(StreamMetadata {
+                id: stream.stream_id,
+                name: stream.name.clone(),
+                topics,
+            });
+        }
+
+        Ok(Metadata { streams })
+    }
+}
@@ -0,0 +1,420 @@
+use crate::streaming::metadata_log::entry::MetadataEntry;
+use crate::streaming::session::Session;
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::system::System;
+use crate::streaming::systems::users::USER_ID;
+use crate::streaming::user_groups::user_group::UserGroup;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::utils::text;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use tracing::{error, info, warn};
+
+impl System {
+    /// Brings the streams directories touched by the unfinished entries to a loadable state,
+    /// as the partially created or deleted stream would otherwise fail the whole startup.
+    pub(crate) async fn prepare_metadata_log_replay(&self, entries: &[MetadataEntry]) {
+        for entry in entries {
+            let stream_id = match entry {
+                MetadataEntry::CreateStream { stream_id, .. } => *stream_id,
+                // The soft deleted stream is moved to the trash, so its data must be kept.
+                MetadataEntry::DeleteStream {
+                    stream_id,
+                    soft: false,
+                } => *stream_id,
+                _ => continue,
+            };
+
+            if !Path::new(&self.config.get_stream_path(stream_id)).exists() {
+                continue;
+            }
+
+            info!("Removing the stream with ID: {stream_id} left by the unfinished metadata log entry: {entry}...");
+            let stream = Stream::empty(stream_id, self.config.clone(), self.storage.clone());
+            if let Err(error) = self.storage.stream.delete(&stream).await {
+                warn!("Cannot remove the stream with ID: {stream_id}. Error: {error}");
+            }
+        }
+    }
+
+    /// Replays the unfinished entries against the loaded state and truncates the metadata log.
+    /// The entries which turn out to be already applied are skipped.
+    pub(crate) async fn replay_metadata_log(
+        &mut self,
+        entries: Vec<MetadataEntry>,
+    ) -> Result<(), IggyError> {
+        if !self.metadata_log.is_enabled() {
+            return Ok(());
+        }
+
+        if !entries.is_empty() {
+            info!(
+                "Replaying {} unfinished metadata log entries...",
+                entries.len()
+            );
+        }
+
+        let session = Session::stateless(
+            DEFAULT_ROOT_USER_ID,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+        );
+        for entry in entries {
+            let description = entry.to_string();
+            match self.replay_metadata_entry(&session, entry).await {
+                Ok(true) => info!("Replayed metadata log entry: {description}."),
+                Ok(false) => info!("Metadata log entry: {description} was already applied."),
+                Err(error) => {
+                    error!("Cannot replay metadata log entry: {description}. Error: {error}")
+                }
+            }
+        }
+
+        self.metadata_log.truncate().await
+    }
+
+    async fn replay_metadata_entry(
+        &mut self,
+        session: &Session,
+        entry: MetadataEntry,
+    ) -> Result<bool, IggyError> {
+        match entry {
+            MetadataEntry::CreateStream {
+                stream_id,
+                name,
+                tenant_id,
+                topic_defaults,
+            } => {
+                if self.streams.contains_key(&stream_id) {
+                    return Ok(false);
+                }
+
+                self.add_stream(Some(stream_id), &name, tenant_id, topic_defaults)
+                    .await?;
+            }
+            MetadataEntry::UpdateStream {
+                stream_id,
+                name,
+                topic_defaults,
+            } => {
+                self.update_stream(
+                    session,
+                    &Identifier::numeric(stream_id)?,
+                    &name,
+                    topic_defaults,
+                )
+                .await?;
+            }
+            MetadataEntry::DeleteStream { stream_id, soft } => {
+                if !self.streams.contains_key(&stream_id) {
+                    return Ok(false);
+                }
+
+                self.delete_stream(session, &Identifier::numeric(stream_id)?, soft)
+                    .await?;
+            }
+            MetadataEntry::UndeleteStream { stream_id } => {
+                if self.streams.contains_key(&stream_id) {
+                    return Ok(false);
+                }
+
+                self.undelete_stream(session, &Identifier::numeric(stream_id)?)
+                    .await?;
+            }
+            MetadataEntry::CreateTopic {
+                stream_id,
+                topic_id,
+                name,
+                partitions_count,
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+                message_schema,
+                ephemeral,
+                timestamp_type,
+                consumer_limits,
+                max_message_size,
+            } => {
+                let stream_id = Identifier::numeric(stream_id)?;
+                let topic_name = text::to_lowercase_non_whitespace(&name);
+                if self
+                    .get_stream(&stream_id)
+                    .await?
+                    .get_topic(&Identifier::named(&topic_name)?)
+                    .is_ok()
+                {
+                    return Ok(false);
+                }
+
+                self.create_topic(
+                    session,
+                    &stream_id,
+                    topic_id,
+                    &name,
+                    partitions_count,
+                    message_expiry,
+                    max_topic_size,
+                    replication_factor,
+                    message_schema.as_deref(),
+                    ephemeral,
+                    timestamp_type,
+                    consumer_limits,
+                    max_message_size,
+                )
+                .await?;
+            }
+            MetadataEntry::UpdateTopic {
+                stream_id,
+                topic_id,
+                name,
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+                message_schema,
+                timestamp_type,
+                consumer_limits,
+                max_message_size,
+            } => {
+                self.update_topic(
+                    session,
+                    &Identifier::numeric(stream_id)?,
+                    &Identifier::numeric(topic_id)?,
+                    &name,
+                    message_expiry,
+                    max_topic_size,
+                    replication_factor,
+                    message_schema.as_deref(),
+                    timestamp_type,
+                    consumer_limits,
+                    max_message_size,
+                )
+                .await?;
+            }
+            MetadataEntry::DeleteTopic {
+                stream_id,
+                topic_id,
+                soft,
+            } => {
+                let stream_id = Identifier::numeric(stream_id)?;
+                let topic_id = Identifier::numeric(topic_id)?;
+                if self
+                    .get_stream(&stream_id)
+                    .await?
+                    .get_topic(&topic_id)
+                    .is_err()
+                {
+                    return Ok(false);
+                }
+
+                self.delete_topic(session, &stream_id, &topic_id, soft)
+                    .await?;
+            }
+            MetadataEntry::UndeleteTopic {
+                stream_id,
+                topic_id,
+            } => {
+                let stream_id = Identifier::numeric(stream_id)?;
+                let topic_id = Identifier::numeric(topic_id)?;
+                if self
+                    .get_stream(&stream_id)
+                    .await?
+                    .get_topic(&topic_id)
+                    .is_ok()
+                {
+                    return Ok(false);
+                }
+
+                self.undelete_topic(session, &stream_id, &topic_id).await?;
+            }
+            MetadataEntry::FreezeTopic {
+                stream_id,
+                topic_id,
+            } => {
+                self.freeze_topic(
+                    session,
+                    &Identifier::numeric(stream_id)?,
+                    &Identifier::numeric(topic_id)?,
+                )
+                .await?;
+            }
+            MetadataEntry::UnfreezeTopic {
+                stream_id,
+                topic_id,
+            } => {
+                self.unfreeze_topic(
+                    session,
+                    &Identifier::numeric(stream_id)?,
+                    &Identifier::numeric(topic_id)?,
+                )
+                .await?;
+            }
+            MetadataEntry::CreatePartitions {
+                stream_id,
+                topic_id,
+                current_partitions_count,
+                partitions_count,
+                repartition,
+            } => {
+                let stream_id = Identifier::numeric(stream_id)?;
+                let topic_id = Identifier::numeric(topic_id)?;
+                let expected_partitions_count = current_partitions_count + partitions_count;
+                let partitions_count = self
+                    .get_stream(&stream_id)
+                    .await?
+                    .get_topic(&topic_id)?
+                    .get_partitions_count();
+                if partitions_count >= expected_partitions_count {
+                    return Ok(false);
+                }
+
+                self.create_partitions(
+                    session,
+                    &stream_id,
+                    &topic_id,
+                    expected_partitions_count - partitions_count,
+                    repartition,
+                )
+                .await?;
+            }
+            MetadataEntry::DeletePartitions {
+                stream_id,
+                topic_id,
+                current_partitions_count,
+                partitions_count,
+                mode,
+            } => {
+                let stream_id = Identifier::numeric(stream_id)?;
+                let topic_id = Identifier::numeric(topic_id)?;
+                let expected_partitions_count =
+                    current_partitions_count.saturating_sub(partitions_count);
+                let partitions_count = self
+                    .get_stream(&stream_id)
+                    .await?
+                    .get_topic(&topic_id)?
+                    .get_partitions_count();
+                if partitions_count <= expected_partitions_count {
+                    return Ok(false);
+                }
+
+                self.delete_partitions(
+                    session,
+                    &stream_id,
+                    &topic_id,
+                    partitions_count - expected_partitions_count,
+                    mode,
+                )
+                .await?;
+            }
+            MetadataEntry::CreateUser { user } => {
+                if self.storage.user.load_by_id(user.id).await.is_ok() {
+                    return Ok(false);
+                }
+
+                self.storage.user.save(&user).await?;
+                USER_ID.fetch_max(user.id + 1, Ordering::SeqCst);
+                self.permissioner.init_permissions_for_user(*user);
+                self.metrics.increment_users(1);
+            }
+            MetadataEntry::UpdateUser { user } => {
+                let current_user = self.storage.user.load_by_id(user.id).await?;
+                if current_user.username != user.username {
+                    self.storage.user.delete(&current_user).await?;
+                }
+                self.storage.user.save(&user).await?;
+                self.permissioner.update_permissions_for_user(*user);
+            }
+            MetadataEntry::DeleteUser { user_id } => {
+                let user = match self.storage.user.load_by_id(user_id).await {
+                    Ok(user) => user,
+                    Err(_) => return Ok(false),
+                };
+
+                self.storage.user.delete(&user).await?;
+                self.permissioner.delete_permissions_for_user(user_id);
+                self.remove_user_from_groups(user_id).await?;
+                self.metrics.decrement_users(1);
+            }
+            MetadataEntry::UpdatePermissions {
+                user_id,
+                permissions,
+            } => {
+                let mut user = self.storage.user.load_by_id(user_id).await?;
+                user.permissions = permissions;
+                user.revoke_sessions();
+                self.storage.user.save(&user).await?;
+                self.permissioner.update_permissions_for_user(user);
+            }
+            MetadataEntry::ChangePassword { user_id, password } => {
+                let mut user = self.storage.user.load_by_id(user_id).await?;
+                user.password = password;
+                user.revoke_sessions();
+                self.storage.user.save(&user).await?;
+                self.permissioner.update_permissions_for_user(user);
+            }
+            MetadataEntry::CreateUserGroup {
+                group_id,
+                name,
+                permissions,
+            } => {
+                if self.user_groups.contains_key(&group_id) {
+                    return Ok(false);
+                }
+
+                self.user_groups_ids.insert(name.clone(), group_id);
+                self.save_user_group(UserGroup::new(group_id, &name, permissions))
+                    .await?;
+            }
+            MetadataEntry::UpdateUserGroupPermissions {
+                group_id,
+                permissions,
+            } => {
+                let mut group = self
+                    .get_user_group(&Identifier::numeric(group_id)?)?
+                    .clone();
+                group.permissions = permissions;
+                let members = group.members.clone();
+                self.save_user_group(group).await?;
+                self.refresh_members_permissions(&members).await?;
+            }
+            MetadataEntry::DeleteUserGroup { group_id } => {
+                let group = match self.user_groups.remove(&group_id) {
+                    Some(group) => group,
+                    None => return Ok(false),
+                };
+
+                self.user_groups_ids.remove(&group.name);
+                self.storage.user_group.delete(&group).await?;
+                self.permissioner.remove_user_group(group_id);
+                self.refresh_members_permissions(&group.members).await?;
+            }
+            MetadataEntry::AddUserGroupMember { group_id, user_id } => {
+                let mut group = self
+                    .get_user_group(&Identifier::numeric(group_id)?)?
+                    .clone();
+                if !group.members.insert(user_id) {
+                    return Ok(false);
+                }
+
+                self.save_user_group(group).await?;
+                let user = self.storage.user.load_by_id(user_id).await?;
+                self.permissioner.update_permissions_for_user(user);
+            }
+            MetadataEntry::RemoveUserGroupMember { group_id, user_id } => {
+                let mut group = self
+                    .get_user_group(&Identifier::numeric(group_id)?)?
+                    .clone();
+                if !group.members.remove(&user_id) {
+                    return Ok(false);
+                }
+
+                self.save_user_group(group).await?;
+                let user = self.storage.user.load_by_id(user_id).await?;
+                self.permissioner.update_permissions_for_user(user);
+            }
+        }
+
+        Ok(true)
+    }
+}
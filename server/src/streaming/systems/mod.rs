@@ -1,13 +1,24 @@
+pub mod bootstrap;
 pub mod clients;
+pub mod connectors;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod fetch_sessions;
 pub mod info;
+pub mod limits;
 pub mod messages;
+pub mod metadata;
+pub mod metadata_log;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod stats;
 pub mod storage;
 pub mod streams;
 pub mod system;
+pub mod system_topics;
+pub mod tenants;
 pub mod topics;
+pub mod trash;
+pub mod user_groups;
 pub mod users;
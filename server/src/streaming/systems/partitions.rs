@@ -1,61 +1,200 @@
+use crate::runtimes::RuntimeHandles;
+use crate::streaming::events::event::{EventEnvelope, ServerEvent};
+use crate::streaming::metadata_log::entry::MetadataEntry;
+use crate::streaming::partitions::partition::Partition;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::models::partition::PartitionEndOffset;
+use iggy::partitions::delete_partitions::DeletePartitionsMode;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::Arc;
+use tokio::sync::RwLock;
 
 impl System {
+    pub async fn find_partition(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<Arc<RwLock<Partition>>, IggyError> {
+        let topic = self.find_topic(session, stream_id, topic_id).await?;
+        topic.get_partition(partition_id)
+    }
+
+    pub async fn get_partition_end_offsets(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<PartitionEndOffset>, IggyError> {
+        let topic = self.find_topic(session, stream_id, topic_id).await?;
+        let mut end_offsets = Vec::new();
+        for partition in topic.get_partitions() {
+            let partition = partition.read().await;
+            end_offsets.push(PartitionEndOffset {
+                partition_id: partition.partition_id,
+                end_offset: partition.get_next_offset(),
+            });
+        }
+        end_offsets.sort_by_key(|end_offset| end_offset.partition_id);
+        Ok(end_offsets)
+    }
+
     pub async fn create_partitions(
-        &mut self,
+        &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
+        repartition: bool,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let _metadata_lock = self.metadata_lock.lock().await;
+        let tenant_id;
+        let entry;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.create_partitons(
                 session.get_user_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
+            self.ensure_partitions_limit(topic.get_partitions_count(), partitions_count)?;
+            self.disk_space.ensure_space_for_partitions()?;
+            tenant_id = stream.tenant_id;
+            entry = MetadataEntry::CreatePartitions {
+                stream_id: stream.stream_id,
+                topic_id: topic.topic_id,
+                current_partitions_count: topic.get_partitions_count(),
+                partitions_count,
+                repartition,
+            };
         }
 
-        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
-        topic.add_persisted_partitions(partitions_count).await?;
-        topic.reassign_consumer_groups().await;
-        self.metrics.increment_partitions(partitions_count);
-        self.metrics.increment_segments(partitions_count);
-        Ok(())
+        self.ensure_tenant_quotas(tenant_id, 0, 0, partitions_count)
+            .await?;
+        let entry_id = self.metadata_log.append(entry).await?;
+
+        let result = async {
+            let mut stream = self.get_stream_mut(stream_id).await?;
+            let topic = stream.get_topic_mut(topic_id)?;
+            let previous_partitions_count = topic.get_partitions_count();
+            topic.add_persisted_partitions(partitions_count).await?;
+            topic.reassign_consumer_groups().await;
+            topic.routing_epoch += 1;
+            topic.persist().await?;
+            if repartition {
+                let events = self.events.clone();
+                let messages = self.metrics.messages_gauge();
+                let (stream_id, topic_id, routing_epoch) =
+                    (topic.stream_id, topic.topic_id, topic.routing_epoch);
+                topic.start_repartitioning(
+                    previous_partitions_count,
+                    move |messages_count| {
+                        messages.inc_by(messages_count as i64);
+                    },
+                    move |messages_count| {
+                        if let Some(events) = events {
+                            let _ = events.send(EventEnvelope {
+                                timestamp: IggyTimestamp::now().to_micros(),
+                                event: ServerEvent::TopicRepartitioned {
+                                    stream_id,
+                                    topic_id,
+                                    routing_epoch,
+                                    messages_count,
+                                },
+                            });
+                        }
+                    },
+                );
+            } else {
+                topic.repartitioning_job = None;
+            }
+            self.metrics.increment_partitions(partitions_count);
+            self.metrics.increment_segments(partitions_count);
+            self.publish_event(ServerEvent::PartitionsCreated {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                partitions_count,
+            });
+            Ok(())
+        }
+        .await;
+        self.metadata_log.complete(entry_id).await?;
+        result
     }
 
     pub async fn delete_partitions(
-        &mut self,
+        &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
+        mode: DeletePartitionsMode,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let _metadata_lock = self.metadata_lock.lock().await;
+        let entry;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.delete_partitions(
                 session.get_user_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
+            entry = MetadataEntry::DeletePartitions {
+                stream_id: stream.stream_id,
+                topic_id: topic.topic_id,
+                current_partitions_count: topic.get_partitions_count(),
+                partitions_count,
+                mode,
+            };
         }
 
-        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
-        let partitions = topic.delete_persisted_partitions(partitions_count).await?;
-        topic.reassign_consumer_groups().await;
-        if let Some(partitions) = partitions {
-            self.metrics.decrement_partitions(partitions_count);
-            self.metrics.decrement_segments(partitions.segments_count);
-            self.metrics.decrement_messages(partitions.messages_count);
+        let entry_id = self.metadata_log.append(entry).await?;
+
+        let result = async {
+            let mut stream = self.get_stream_mut(stream_id).await?;
+            let topic = stream.get_topic_mut(topic_id)?;
+            topic.repartitioning_job = None;
+            let partition_shards = self
+                .runtimes
+                .as_ref()
+                .and_then(RuntimeHandles::partition_shards);
+            let partitions = topic
+                .delete_persisted_partitions(partitions_count, mode, partition_shards)
+                .await?;
+            topic.reassign_consumer_groups().await;
+            topic.routing_epoch += 1;
+            topic.persist().await?;
+            if let Some(partitions) = partitions {
+                self.metrics.decrement_partitions(partitions_count);
+                self.metrics.decrement_segments(partitions.segments_count);
+                self.metrics.decrement_messages(partitions.messages_count);
+                for drained_partition in partitions.drained_partitions {
+                    self.publish_event(ServerEvent::PartitionDrained {
+                        stream_id: topic.stream_id,
+                        topic_id: topic.topic_id,
+                        partition_id: drained_partition.partition_id,
+                        target_partition_id: drained_partition.target_partition_id,
+                        messages_count: drained_partition.messages_count,
+                    });
+                }
+                self.publish_event(ServerEvent::PartitionsDeleted {
+                    stream_id: topic.stream_id,
+                    topic_id: topic.topic_id,
+                    partitions_count,
+                });
+            }
+            Ok(())
         }
-        Ok(())
+        .await;
+        self.metadata_log.complete(entry_id).await?;
+        result
     }
 }
@@ -12,7 +12,7 @@ impl System {
         &self,
         session: &Session,
     ) -> Result<Vec<PersonalAccessToken>, IggyError> {
-        self.ensure_authenticated(session)?;
+        self.ensure_authenticated_user(session)?;
         let user_id = session.get_user_id();
         info!("Loading personal access tokens for user with ID: {user_id}...",);
         let personal_access_tokens = self
@@ -33,7 +33,7 @@ impl System {
         name: &str,
         expiry: Option<u32>,
     ) -> Result<String, IggyError> {
-        self.ensure_authenticated(session)?;
+        self.ensure_authenticated_user(session)?;
         let user_id = session.get_user_id();
         let max_token_per_user = self.personal_access_token.max_tokens_per_user;
         let name = text::to_lowercase_non_whitespace(name);
@@ -77,7 +77,7 @@ impl System {
         session: &Session,
         name: &str,
     ) -> Result<(), IggyError> {
-        self.ensure_authenticated(session)?;
+        self.ensure_authenticated_user(session)?;
         let user_id = session.get_user_id();
         let name = text::to_lowercase_non_whitespace(name);
         info!("Deleting personal access token: {name} for user with ID: {user_id}...");
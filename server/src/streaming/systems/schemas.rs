@@ -0,0 +1,168 @@
+use crate::streaming::schemas::compatibility;
+use crate::streaming::schemas::schema::{Schema, SchemaSubject};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::models::schema::{SchemaCompatibility, SchemaFormat};
+use iggy::schemas::decoding::PayloadDecoder;
+use iggy::utils::timestamp::IggyTimestamp;
+use jsonschema::JSONSchema;
+use tracing::{error, info};
+
+impl System {
+    pub(crate) async fn load_schemas(&mut self) -> Result<(), IggyError> {
+        info!("Loading schemas...");
+        let schemas = self.storage.schema.load_all().await?;
+        let subjects = self.storage.schema.load_all_subjects().await?;
+        let schemas_count = schemas.len();
+        self.schema_registry.init(schemas, subjects);
+        info!("Initialized {} schema(s).", schemas_count);
+        Ok(())
+    }
+
+    pub fn get_schema(&self, session: &Session, schema_id: u32) -> Result<&Schema, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_schema(session.get_user_id())?;
+        self.schema_registry
+            .get_schema(schema_id)
+            .ok_or(IggyError::SchemaIdNotFound(schema_id))
+    }
+
+    pub fn get_schema_versions(
+        &self,
+        session: &Session,
+        subject: &str,
+    ) -> Result<Vec<&Schema>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_schema_versions(session.get_user_id())?;
+        let schemas = self.schema_registry.get_schemas(subject);
+        if schemas.is_empty() {
+            return Err(IggyError::SchemaSubjectNotFound(subject.to_string()));
+        }
+
+        Ok(schemas)
+    }
+
+    pub async fn register_schema(
+        &mut self,
+        session: &Session,
+        subject: &str,
+        format: SchemaFormat,
+        schema: &str,
+    ) -> Result<Schema, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.register_schema(session.get_user_id())?;
+        // The JSON Schema is compared structurally and checked for compatibility, other formats are only parsed.
+        let schema_value = match format {
+            SchemaFormat::Json => {
+                let schema_value = serde_json::from_str::<serde_json::Value>(schema)
+                    .map_err(|error| IggyError::InvalidSchema(error.to_string()))?;
+                JSONSchema::compile(&schema_value)
+                    .map_err(|error| IggyError::InvalidSchema(error.to_string()))?;
+                Some(schema_value)
+            }
+            SchemaFormat::Avro | SchemaFormat::Protobuf => {
+                PayloadDecoder::new(format, schema, None)?;
+                None
+            }
+        };
+
+        let versions = self.schema_registry.get_schemas(subject);
+        if let Some(existing_schema) = versions.iter().find(|version| {
+            version.format == format
+                && match &schema_value {
+                    Some(schema_value) => {
+                        serde_json::from_str::<serde_json::Value>(&version.schema)
+                            .ok()
+                            .as_ref()
+                            == Some(schema_value)
+                    }
+                    None => version.schema == schema,
+                }
+        }) {
+            info!(
+                "Schema is already registered for subject: {subject} with version: {}.",
+                existing_schema.version
+            );
+            return Ok((*existing_schema).clone());
+        }
+
+        if let Some(latest_schema) = self.schema_registry.get_latest_schema(subject) {
+            if latest_schema.format != format {
+                let reason = format!(
+                    "format cannot be changed from: {} to: {format}",
+                    latest_schema.format
+                );
+                error!("Schema is not compatible for subject: {subject}. Reason: {reason}");
+                return Err(IggyError::IncompatibleSchema(subject.to_string(), reason));
+            }
+        }
+
+        if let (Some(latest_schema), Some(schema_value)) = (
+            self.schema_registry.get_latest_schema(subject),
+            &schema_value,
+        ) {
+            let compatibility = self
+                .schema_registry
+                .get_compatibility(subject)
+                .unwrap_or(self.config.schema_registry.default_compatibility);
+            let latest_schema_value =
+                serde_json::from_str::<serde_json::Value>(&latest_schema.schema)
+                    .map_err(|error| IggyError::InvalidSchema(error.to_string()))?;
+            if let Err(reason) =
+                compatibility::check(&latest_schema_value, schema_value, compatibility)
+            {
+                error!("Schema is not {compatibility} compatible for subject: {subject}. Reason: {reason}");
+                return Err(IggyError::IncompatibleSchema(subject.to_string(), reason));
+            }
+        }
+
+        let version = self
+            .schema_registry
+            .get_latest_schema(subject)
+            .map(|schema| schema.version + 1)
+            .unwrap_or(1);
+        let schema = Schema::new(
+            self.schema_registry.get_next_schema_id(),
+            subject,
+            version,
+            format,
+            schema,
+            IggyTimestamp::now().to_micros(),
+        );
+        info!(
+            "Registering {format} schema with ID: {} for subject: {subject} with version: {version}...",
+            schema.id
+        );
+        self.storage.schema.save(&schema).await?;
+        self.schema_registry.add_schema(schema.clone());
+        info!(
+            "Registered schema with ID: {} for subject: {subject} with version: {version}.",
+            schema.id
+        );
+        Ok(schema)
+    }
+
+    pub async fn update_schema_compatibility(
+        &mut self,
+        session: &Session,
+        subject: &str,
+        compatibility: SchemaCompatibility,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_schema_compatibility(session.get_user_id())?;
+        self.storage
+            .schema
+            .save_subject(&SchemaSubject {
+                name: subject.to_string(),
+                compatibility,
+            })
+            .await?;
+        self.schema_registry
+            .set_compatibility(subject, compatibility);
+        info!("Updated compatibility for schema subject: {subject} to: {compatibility}.");
+        Ok(())
+    }
+}
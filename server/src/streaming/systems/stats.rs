@@ -1,3 +1,6 @@
+use crate::runtimes::RuntimeHandles;
+use crate::streaming::cache::memory_budget::MemoryBudget;
+use crate::streaming::diagnostics::disk_space::get_disk_usage;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use iggy::error::IggyError;
@@ -11,6 +14,7 @@ impl System {
         self.permissioner.get_stats(session.get_user_id())?;
         let mut sys = sysinfo::System::new_all();
         sys.refresh_all();
+        let memory_budget = MemoryBudget::get_instance();
 
         let mut stats = Stats {
             process_id: 0,
@@ -21,34 +25,12 @@ impl System {
             run_time: 0,
             start_time: 0,
             streams_count: self.streams.len() as u32,
-            topics_count: self
-                .streams
-                .values()
-                .map(|s| s.topics.len() as u32)
-                .sum::<u32>(),
-            partitions_count: self
-                .streams
-                .values()
-                .map(|s| {
-                    s.topics
-                        .values()
-                        .map(|t| t.partitions.len() as u32)
-                        .sum::<u32>()
-                })
-                .sum::<u32>(),
+            topics_count: 0,
+            partitions_count: 0,
             segments_count: 0,
             messages_count: 0,
             clients_count: self.client_manager.read().await.get_clients().len() as u32,
-            consumer_groups_count: self
-                .streams
-                .values()
-                .map(|s| {
-                    s.topics
-                        .values()
-                        .map(|t| t.consumer_groups.len() as u32)
-                        .sum::<u32>()
-                })
-                .sum::<u32>(),
+            consumer_groups_count: 0,
             read_bytes: 0.into(),
             written_bytes: 0.into(),
             messages_size_bytes: 0.into(),
@@ -58,8 +40,24 @@ impl System {
                 .unwrap_or("unknown_os_version".to_string()),
             kernel_version: sysinfo::System::kernel_version()
                 .unwrap_or("unknown_kernel_version".to_string()),
+            unsaved_messages_size: memory_budget.unsaved_messages_bytes().into(),
+            cached_messages_size: memory_budget.cached_messages_bytes().into(),
+            cached_indexes_size: memory_budget.cached_indexes_bytes().into(),
+            memory_budget: memory_budget.limit_bytes().into(),
+            runtimes: self
+                .runtimes
+                .as_ref()
+                .map(RuntimeHandles::stats)
+                .unwrap_or_default(),
+            disk_free_space: 0.into(),
+            disk_total_space: 0.into(),
         };
 
+        if let Some(usage) = get_disk_usage(&self.config.get_system_path()) {
+            stats.disk_free_space = usage.available_bytes.into();
+            stats.disk_total_space = usage.total_bytes.into();
+        }
+
         for (pid, process) in sys.processes() {
             if process.name() != PROCESS_NAME {
                 continue;
@@ -80,7 +78,11 @@ impl System {
 
         let mut messages_size_bytes = 0u64;
         for stream in self.streams.values() {
+            let stream = stream.read().await;
+            stats.topics_count += stream.topics.len() as u32;
             for topic in stream.topics.values() {
+                stats.partitions_count += topic.partitions.len() as u32;
+                stats.consumer_groups_count += topic.consumer_groups.len() as u32;
                 for partition in topic.partitions.values() {
                     let partition = partition.read().await;
                     stats.messages_count += partition.get_messages_count();
@@ -53,6 +53,7 @@ impl Storage<SystemInfo> for FileSystemInfoStorage {
 
         system_info.version = data.version;
         system_info.migrations = data.migrations;
+        system_info.format_version = data.format_version;
         Ok(())
     }
 
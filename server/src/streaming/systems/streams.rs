@@ -1,20 +1,26 @@
+use crate::streaming::events::event::ServerEvent;
+use crate::streaming::metadata_log::entry::MetadataEntry;
 use crate::streaming::session::Session;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::system::System;
 use futures::future::try_join_all;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
+use iggy::models::topic_defaults::TopicDefaults;
 use iggy::utils::text;
 use std::cell::RefCell;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::Ordering;
 use tokio::fs::read_dir;
+use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use tokio::task::JoinHandle;
+use tokio::time::{self, Instant};
 use tracing::{error, info};
-
-static CURRENT_STREAM_ID: AtomicU32 = AtomicU32::new(1);
+use uuid::Uuid;
 
 impl System {
     pub(crate) async fn load_streams(&mut self) -> Result<(), IggyError> {
         info!("Loading streams from disk...");
+        let now = Instant::now();
         let mut unloaded_streams = Vec::new();
         let dir_entries = read_dir(&self.config.get_streams_path()).await;
         if let Err(error) = dir_entries {
@@ -45,7 +51,16 @@ impl System {
             };
             load_stream_task
         });
-        try_join_all(load_stream_tasks).await?;
+        let progress_reporter = self.start_loading_progress_reporter();
+        let result = try_join_all(load_stream_tasks).await;
+        progress_reporter.abort();
+        result?;
+        let progress = self.storage.partitions_loader.progress();
+        info!(
+            "Loaded {} partition(s) from disk in {} ms.",
+            progress.loaded_count,
+            now.elapsed().as_millis()
+        );
 
         for stream in loaded_streams.take() {
             if self.streams.contains_key(&stream.stream_id) {
@@ -66,52 +81,108 @@ impl System {
                 .increment_segments(stream.get_segments_count().await);
             self.metrics.increment_messages(stream.get_messages_count());
 
+            self.permissioner
+                .set_stream_tenant(stream.stream_id, stream.tenant_id);
             self.streams_ids
                 .insert(stream.name.clone(), stream.stream_id);
-            self.streams.insert(stream.stream_id, stream);
+            self.streams_uuids.insert(stream.uuid, stream.stream_id);
+            self.streams.insert(stream.stream_id, RwLock::new(stream));
         }
 
         info!("Loaded {} stream(s) from disk.", self.streams.len());
         Ok(())
     }
 
-    pub fn get_streams(&self) -> Vec<&Stream> {
-        self.streams.values().collect()
+    /// Logs the number of the loaded partitions periodically, until the returned task is aborted.
+    fn start_loading_progress_reporter(&self) -> JoinHandle<()> {
+        let partitions_loader = self.storage.partitions_loader.clone();
+        let interval = self.config.startup.progress_interval.get_duration();
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval_at(time::Instant::now() + interval, interval);
+            loop {
+                interval_timer.tick().await;
+                let progress = partitions_loader.progress();
+                info!(
+                    "Loading partitions from disk, loaded: {}/{}.",
+                    progress.loaded_count, progress.total_count
+                );
+            }
+        })
+    }
+
+    /// Returns all the streams locked for reading, the locks are always acquired in the same order.
+    pub async fn get_streams(&self) -> Vec<RwLockReadGuard<'_, Stream>> {
+        let mut streams = Vec::with_capacity(self.streams.len());
+        for stream in self.streams.values() {
+            streams.push(stream.read().await);
+        }
+        streams
     }
 
-    pub fn find_streams(&self, session: &Session) -> Result<Vec<&Stream>, IggyError> {
+    pub async fn find_streams(
+        &self,
+        session: &Session,
+    ) -> Result<Vec<RwLockReadGuard<'_, Stream>>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner.get_streams(session.get_user_id())?;
-        Ok(self.get_streams())
+        let streams = self.get_streams().await;
+        let Some(tenant_id) = self.permissioner.get_user_tenant(session.get_user_id()) else {
+            return Ok(streams);
+        };
+
+        Ok(streams
+            .into_iter()
+            .filter(|stream| stream.tenant_id == Some(tenant_id))
+            .collect())
     }
 
-    pub fn find_stream(
+    pub async fn find_stream(
         &self,
         session: &Session,
         identifier: &Identifier,
-    ) -> Result<&Stream, IggyError> {
+    ) -> Result<RwLockReadGuard<'_, Stream>, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(identifier)?;
+        let stream = self.get_stream(identifier).await?;
         self.permissioner
             .get_stream(session.get_user_id(), stream.stream_id)?;
         Ok(stream)
     }
 
-    pub fn get_stream(&self, identifier: &Identifier) -> Result<&Stream, IggyError> {
+    /// Returns the stream locked for reading, the lock must be released before any other stream is locked by the same task.
+    pub async fn get_stream(
+        &self,
+        identifier: &Identifier,
+    ) -> Result<RwLockReadGuard<'_, Stream>, IggyError> {
+        Ok(self.get_stream_lock(identifier)?.read().await)
+    }
+
+    /// Returns the stream locked for writing, which blocks only the operations on this stream.
+    /// Unless the system itself is borrowed mutably, the changes must be made under the metadata lock.
+    pub async fn get_stream_mut(
+        &self,
+        identifier: &Identifier,
+    ) -> Result<RwLockWriteGuard<'_, Stream>, IggyError> {
+        Ok(self.get_stream_lock(identifier)?.write().await)
+    }
+
+    fn get_stream_lock(&self, identifier: &Identifier) -> Result<&RwLock<Stream>, IggyError> {
         match identifier.kind {
             IdKind::Numeric => self.get_stream_by_id(identifier.get_u32_value()?),
             IdKind::String => self.get_stream_by_name(&identifier.get_cow_str_value()?),
+            IdKind::Uuid => self.get_stream_by_uuid(identifier.get_uuid_value()?),
         }
     }
 
-    pub fn get_stream_mut(&mut self, identifier: &Identifier) -> Result<&mut Stream, IggyError> {
-        match identifier.kind {
-            IdKind::Numeric => self.get_stream_by_id_mut(identifier.get_u32_value()?),
-            IdKind::String => self.get_stream_by_name_mut(&identifier.get_cow_str_value()?),
+    fn get_stream_by_uuid(&self, uuid: Uuid) -> Result<&RwLock<Stream>, IggyError> {
+        let stream_id = self.streams_uuids.get(&uuid);
+        if stream_id.is_none() {
+            return Err(IggyError::StreamUuidNotFound(uuid));
         }
+
+        self.get_stream_by_id(*stream_id.unwrap())
     }
 
-    fn get_stream_by_name(&self, name: &str) -> Result<&Stream, IggyError> {
+    fn get_stream_by_name(&self, name: &str) -> Result<&RwLock<Stream>, IggyError> {
         let stream_id = self.streams_ids.get(name);
         if stream_id.is_none() {
             return Err(IggyError::StreamNameNotFound(name.to_string()));
@@ -120,7 +191,7 @@ impl System {
         self.get_stream_by_id(*stream_id.unwrap())
     }
 
-    fn get_stream_by_id(&self, stream_id: u32) -> Result<&Stream, IggyError> {
+    fn get_stream_by_id(&self, stream_id: u32) -> Result<&RwLock<Stream>, IggyError> {
         let stream = self.streams.get(&stream_id);
         if stream.is_none() {
             return Err(IggyError::StreamIdNotFound(stream_id));
@@ -129,37 +200,31 @@ impl System {
         Ok(stream.unwrap())
     }
 
-    fn get_stream_by_name_mut(&mut self, name: &str) -> Result<&mut Stream, IggyError> {
-        let stream_id;
-        {
-            let id = self.streams_ids.get_mut(name);
-            if id.is_none() {
-                return Err(IggyError::StreamNameNotFound(name.to_string()));
-            }
-
-            stream_id = *id.unwrap();
-        }
-
-        self.get_stream_by_id_mut(stream_id)
-    }
-
-    fn get_stream_by_id_mut(&mut self, stream_id: u32) -> Result<&mut Stream, IggyError> {
-        let stream = self.streams.get_mut(&stream_id);
-        if stream.is_none() {
-            return Err(IggyError::StreamIdNotFound(stream_id));
-        }
-
-        Ok(stream.unwrap())
-    }
-
     pub async fn create_stream(
         &mut self,
         session: &Session,
         stream_id: Option<u32>,
         name: &str,
+        topic_defaults: TopicDefaults,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner.create_stream(session.get_user_id())?;
+        self.ensure_streams_limit()?;
+        let tenant_id = self.permissioner.get_user_tenant(session.get_user_id());
+        self.ensure_tenant_quotas(tenant_id, 1, 0, 0).await?;
+        let name = self.get_tenant_stream_name(tenant_id, name);
+        self.add_stream(stream_id, &name, tenant_id, topic_defaults)
+            .await
+    }
+
+    /// Creates the stream owned by the tenant (if any), the name must be already scoped by the tenant name.
+    pub(crate) async fn add_stream(
+        &mut self,
+        stream_id: Option<u32>,
+        name: &str,
+        tenant_id: Option<u32>,
+        topic_defaults: TopicDefaults,
+    ) -> Result<(), IggyError> {
         let name = text::to_lowercase_non_whitespace(name);
         if self.streams_ids.contains_key(&name) {
             return Err(IggyError::StreamNameAlreadyExists(name.to_string()));
@@ -167,13 +232,13 @@ impl System {
 
         let mut id;
         if stream_id.is_none() {
-            id = CURRENT_STREAM_ID.fetch_add(1, Ordering::SeqCst);
+            id = self.current_stream_id.fetch_add(1, Ordering::SeqCst);
             loop {
-                if self.streams.contains_key(&id) {
+                if self.streams.contains_key(&id) || self.trashed_streams.contains_key(&id) {
                     if id == u32::MAX {
                         return Err(IggyError::StreamIdAlreadyExists(id));
                     }
-                    id = CURRENT_STREAM_ID.fetch_add(1, Ordering::SeqCst);
+                    id = self.current_stream_id.fetch_add(1, Ordering::SeqCst);
                 } else {
                     break;
                 }
@@ -182,17 +247,48 @@ impl System {
             id = stream_id.unwrap();
         }
 
-        if self.streams.contains_key(&id) {
+        if self.streams.contains_key(&id) || self.trashed_streams.contains_key(&id) {
             return Err(IggyError::StreamIdAlreadyExists(id));
         }
 
-        let stream = Stream::create(id, &name, self.config.clone(), self.storage.clone());
-        stream.persist().await?;
-        info!("Created stream with ID: {id}, name: '{name}'.");
-        self.streams_ids.insert(name, stream.stream_id);
-        self.streams.insert(stream.stream_id, stream);
-        self.metrics.increment_streams(1);
-        Ok(())
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::CreateStream {
+                stream_id: id,
+                name: name.clone(),
+                tenant_id,
+                topic_defaults: topic_defaults.clone(),
+            })
+            .await?;
+        let result = async {
+            let mut stream = Stream::create(id, &name, self.config.clone(), self.storage.clone());
+            stream.tenant_id = tenant_id;
+            stream.topic_defaults = topic_defaults;
+            stream.persist().await?;
+            info!("Created stream with ID: {id}, name: '{name}'.");
+            self.streams_ids.insert(name.clone(), stream.stream_id);
+            self.streams_uuids.insert(stream.uuid, stream.stream_id);
+            self.streams.insert(stream.stream_id, RwLock::new(stream));
+            self.permissioner.set_stream_tenant(id, tenant_id);
+            self.metrics.increment_streams(1);
+            self.publish_event(ServerEvent::StreamCreated {
+                stream_id: id,
+                name,
+            });
+            Ok(())
+        }
+        .await;
+        self.metadata_log.complete(entry_id).await?;
+        result
+    }
+
+    /// Returns the name of the stream scoped by the name of the tenant owning it, if any.
+    fn get_tenant_stream_name(&self, tenant_id: Option<u32>, name: &str) -> String {
+        let name = text::to_lowercase_non_whitespace(name);
+        match tenant_id.and_then(|tenant_id| self.tenants.get(&tenant_id)) {
+            Some(tenant) => tenant.get_stream_name(&name),
+            None => name,
+        }
     }
 
     pub async fn update_stream(
@@ -200,17 +296,20 @@ impl System {
         session: &Session,
         id: &Identifier,
         name: &str,
+        topic_defaults: Option<TopicDefaults>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let stream_id;
+        let tenant_id;
         {
-            let stream = self.get_stream(id)?;
+            let stream = self.get_stream(id).await?;
             stream_id = stream.stream_id;
+            tenant_id = stream.tenant_id;
         }
 
         self.permissioner
             .update_stream(session.get_user_id(), stream_id)?;
-        let updated_name = text::to_lowercase_non_whitespace(name);
+        let updated_name = self.get_tenant_stream_name(tenant_id, name);
 
         {
             if let Some(stream_id_by_name) = self.streams_ids.get(&updated_name) {
@@ -220,61 +319,117 @@ impl System {
             }
         }
 
-        let old_name;
-        {
-            let stream = self.get_stream_mut(id)?;
-            old_name = stream.name.clone();
-            stream.name = updated_name.clone();
-            stream.persist().await?;
-        }
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::UpdateStream {
+                stream_id,
+                name: updated_name.clone(),
+                topic_defaults: topic_defaults.clone(),
+            })
+            .await?;
+        let result = async {
+            let old_name;
+            {
+                let mut stream = self.get_stream_mut(id).await?;
+                old_name = stream.name.clone();
+                stream.name = updated_name.clone();
+                if let Some(topic_defaults) = topic_defaults {
+                    stream.topic_defaults = topic_defaults;
+                }
+                stream.persist().await?;
+            }
 
-        {
-            self.streams_ids.remove(&old_name);
-            self.streams_ids.insert(updated_name.clone(), stream_id);
-        }
+            {
+                self.streams_ids.remove(&old_name);
+                self.streams_ids.insert(updated_name.clone(), stream_id);
+            }
 
-        info!(
-            "Stream with ID '{}' updated. Old name: '{}' changed to: '{}'.",
-            id, old_name, updated_name
-        );
-        Ok(())
+            info!(
+                "Stream with ID '{}' updated. Old name: '{}' changed to: '{}'.",
+                id, old_name, updated_name
+            );
+            self.publish_event(ServerEvent::StreamUpdated {
+                stream_id,
+                name: updated_name.clone(),
+            });
+            Ok(())
+        }
+        .await;
+        self.metadata_log.complete(entry_id).await?;
+        result
     }
 
+    /// Deletes the stream, or moves it to the trash if it's soft deleted, so that it can be restored until the trash retention expires.
     pub async fn delete_stream(
         &mut self,
         session: &Session,
         id: &Identifier,
+        soft: bool,
     ) -> Result<u32, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(id)?;
-        let stream_id = stream.stream_id;
+        let stream_id = self.get_stream(id).await?.stream_id;
         self.permissioner
             .delete_stream(session.get_user_id(), stream_id)?;
-        let stream_name = stream.name.clone();
-        if stream.delete().await.is_err() {
-            return Err(IggyError::CannotDeleteStream(stream_id));
+        if soft {
+            self.ensure_trash_available()?;
         }
 
-        self.metrics.decrement_streams(1);
-        self.metrics.decrement_topics(stream.get_topics_count());
-        self.metrics
-            .decrement_partitions(stream.get_partitions_count());
-        self.metrics.decrement_messages(stream.get_messages_count());
-        self.metrics
-            .decrement_segments(stream.get_segments_count().await);
-
-        self.streams.remove(&stream_id);
-        self.streams_ids.remove(&stream_name);
-        let current_stream_id = CURRENT_STREAM_ID.load(Ordering::SeqCst);
-        if current_stream_id > stream_id {
-            CURRENT_STREAM_ID.store(stream_id, Ordering::SeqCst);
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::DeleteStream { stream_id, soft })
+            .await?;
+        let result = async {
+            let Some(stream) = self.streams.remove(&stream_id) else {
+                return Err(IggyError::StreamIdNotFound(stream_id));
+            };
+            let mut stream = stream.into_inner();
+            let stream_name = stream.name.clone();
+            if soft {
+                if let Err(error) = self.move_stream_to_trash(&stream).await {
+                    self.streams.insert(stream_id, RwLock::new(stream));
+                    return Err(error);
+                }
+            } else if Self::delete_stream_with_trash(&mut stream).await.is_err() {
+                self.streams.insert(stream_id, RwLock::new(stream));
+                return Err(IggyError::CannotDeleteStream(stream_id));
+            }
+
+            self.metrics.decrement_streams(1);
+            self.metrics.decrement_topics(stream.get_topics_count());
+            self.metrics
+                .decrement_partitions(stream.get_partitions_count());
+            self.metrics.decrement_messages(stream.get_messages_count());
+            self.metrics
+                .decrement_segments(stream.get_segments_count().await);
+
+            self.streams_ids.remove(&stream_name);
+            self.streams_uuids.remove(&stream.uuid);
+            self.permissioner.set_stream_tenant(stream_id, None);
+            let current_stream_id = self.current_stream_id.load(Ordering::SeqCst);
+            if !soft && current_stream_id > stream_id {
+                self.current_stream_id.store(stream_id, Ordering::SeqCst);
+            }
+
+            let client_manager = self.client_manager.read().await;
+            client_manager
+                .delete_consumer_groups_for_stream(stream_id)
+                .await;
+            self.publish_event(ServerEvent::StreamDeleted { stream_id });
+            Ok(stream_id)
         }
+        .await;
+        self.metadata_log.complete(entry_id).await?;
+        result
+    }
 
-        let client_manager = self.client_manager.read().await;
-        client_manager
-            .delete_consumer_groups_for_stream(stream_id)
-            .await;
-        Ok(stream_id)
+    /// Deletes the stream along with its trashed topics, as they can't be restored without the stream.
+    async fn delete_stream_with_trash(stream: &mut Stream) -> Result<(), IggyError> {
+        let trashed_topics_ids = stream.trashed_topics.keys().copied().collect::<Vec<_>>();
+        for topic_id in trashed_topics_ids {
+            stream.purge_trashed_topic(topic_id).await?;
+        }
+
+        stream.delete().await
     }
 
     pub async fn purge_stream(
@@ -282,10 +437,14 @@ impl System {
         session: &Session,
         stream_id: &Identifier,
     ) -> Result<(), IggyError> {
-        let stream = self.get_stream(stream_id)?;
+        let stream = self.get_stream(stream_id).await?;
         self.permissioner
             .purge_stream(session.get_user_id(), stream.stream_id)?;
-        stream.purge().await
+        stream.purge().await?;
+        self.publish_event(ServerEvent::StreamPurged {
+            stream_id: stream.stream_id,
+        });
+        Ok(())
     }
 }
 
@@ -296,9 +455,12 @@ mod tests {
     use crate::configs::system::SystemConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
     use crate::streaming::users::user::User;
+    use iggy::models::consumer_limits::ConsumerLimits;
+    use iggy::models::timestamp_type::TimestampType;
     use std::{
         net::{Ipv4Addr, SocketAddr},
         sync::Arc,
+        time::Duration,
     };
 
     #[tokio::test]
@@ -317,20 +479,97 @@ mod tests {
         );
         system.permissioner.init_permissions_for_user(root);
         system
-            .create_stream(&session, Some(stream_id), stream_name)
+            .create_stream(
+                &session,
+                Some(stream_id),
+                stream_name,
+                TopicDefaults::default(),
+            )
             .await
             .unwrap();
 
-        let stream = system.get_stream(&Identifier::numeric(stream_id).unwrap());
+        let stream = system
+            .get_stream(&Identifier::numeric(stream_id).unwrap())
+            .await;
         assert!(stream.is_ok());
         let stream = stream.unwrap();
         assert_eq!(stream.stream_id, stream_id);
         assert_eq!(stream.name, stream_name);
 
-        let stream = system.get_stream(&Identifier::named(stream_name).unwrap());
+        let stream = system
+            .get_stream(&Identifier::named(stream_name).unwrap())
+            .await;
         assert!(stream.is_ok());
         let stream = stream.unwrap();
         assert_eq!(stream.stream_id, stream_id);
         assert_eq!(stream.name, stream_name);
     }
+
+    #[tokio::test]
+    async fn should_change_stream_while_other_stream_is_locked() {
+        let config = Arc::new(SystemConfig::default());
+        let storage = get_test_system_storage();
+        let mut system =
+            System::create(config, storage, None, PersonalAccessTokenConfig::default());
+        let root = User::root();
+        let session = Session::new(
+            1,
+            root.id,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234),
+        );
+        system.permissioner.init_permissions_for_user(root);
+        system
+            .create_stream(&session, Some(1), "locked", TopicDefaults::default())
+            .await
+            .unwrap();
+        system
+            .create_stream(&session, Some(2), "unlocked", TopicDefaults::default())
+            .await
+            .unwrap();
+        let locked_stream_id = Identifier::numeric(1).unwrap();
+        let unlocked_stream_id = Identifier::numeric(2).unwrap();
+        let locked_stream = system.get_stream(&locked_stream_id).await.unwrap();
+        let timeout = Duration::from_millis(100);
+
+        let create_topic = system.create_topic(
+            &session,
+            &unlocked_stream_id,
+            Some(1),
+            "test",
+            1,
+            None,
+            None,
+            1,
+            None,
+            false,
+            TimestampType::default(),
+            ConsumerLimits::default(),
+            None,
+        );
+        assert!(tokio::time::timeout(timeout, create_topic)
+            .await
+            .unwrap()
+            .is_ok());
+
+        let create_topic = system.create_topic(
+            &session,
+            &locked_stream_id,
+            Some(1),
+            "test",
+            1,
+            None,
+            None,
+            1,
+            None,
+            false,
+            TimestampType::default(),
+            ConsumerLimits::default(),
+            None,
+        );
+        assert!(tokio::time::timeout(timeout, create_topic).await.is_err());
+
+        drop(locked_stream);
+        let stream = system.get_stream(&unlocked_stream_id).await.unwrap();
+        assert_eq!(stream.get_topics_count(), 1);
+    }
 }
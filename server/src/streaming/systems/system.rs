@@ -1,23 +1,43 @@
+use crate::channels::commands::publish_system_topics::SystemTopicsCommand;
 use crate::configs::server::PersonalAccessTokenConfig;
-use crate::configs::system::SystemConfig;
+use crate::configs::system::{StorageBackend, SystemConfig};
+use crate::connectors::runtime::ConnectorRuntime;
+use crate::runtimes::RuntimeHandles;
+use crate::streaming::cache::memory_budget::MemoryBudget;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::clients::client_manager::ClientManager;
+use crate::streaming::diagnostics::disk_space::DiskSpace;
 use crate::streaming::diagnostics::metrics::Metrics;
+use crate::streaming::events::event::{EventEnvelope, ServerEvent};
+use crate::streaming::metadata_log::log::MetadataLog;
+use crate::streaming::partitions::loader::PartitionsLoader;
 use crate::streaming::persistence::persister::*;
+use crate::streaming::schemas::registry::SchemaRegistry;
 use crate::streaming::session::Session;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::tenants::tenant::Tenant;
+use crate::streaming::trash::entry::TrashEntry;
+use crate::streaming::user_groups::user_group::UserGroup;
 use crate::streaming::users::permissioner::Permissioner;
+use crate::streaming::users::user::ANONYMOUS_USER_ID;
+use dashmap::DashMap;
 use iggy::error::IggyError;
+use iggy::models::user_info::UserId;
+use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use iggy::utils::timestamp::IggyTimestamp;
 use sled::Db;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
-use tokio::fs::{create_dir, remove_dir_all};
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::fs::{create_dir, create_dir_all, remove_dir_all, remove_file, write};
+use tokio::sync::{Mutex, RwLock};
 use tokio::time::Instant;
-use tracing::{info, trace};
+use tracing::{info, trace, warn};
+use uuid::Uuid;
 
 use keepcalm::{SharedMut, SharedReadLock, SharedWriteLock};
 
@@ -37,6 +57,17 @@ impl SharedSystem {
         self.system.read()
     }
 
+    /// Acquires the shared lock without blocking the worker thread, meant for the long-running background tasks,
+    /// as the exclusive lock might be held by the task suspended in the middle of the change.
+    pub async fn read_async(&self) -> SharedReadLock<'_, System> {
+        loop {
+            if let Some(system) = self.system.try_read() {
+                return system;
+            }
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+
     pub fn write(&self) -> SharedWriteLock<System> {
         self.system.write()
     }
@@ -54,13 +85,43 @@ impl Clone for SharedSystem {
 pub struct System {
     pub permissioner: Permissioner,
     pub(crate) storage: Arc<SystemStorage>,
-    pub(crate) streams: HashMap<u32, Stream>,
+    pub(crate) streams: HashMap<u32, RwLock<Stream>>,
     pub(crate) streams_ids: HashMap<String, u32>,
+    pub(crate) streams_uuids: HashMap<Uuid, u32>,
+    /// The soft deleted streams, their IDs stay reserved until they're restored or purged.
+    pub(crate) trashed_streams: HashMap<u32, TrashEntry>,
+    pub(crate) tenants: HashMap<u32, Tenant>,
+    pub(crate) tenants_ids: HashMap<String, u32>,
+    pub(crate) user_groups: HashMap<u32, UserGroup>,
+    pub(crate) user_groups_ids: HashMap<String, u32>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) client_manager: Arc<RwLock<ClientManager>>,
     pub(crate) encryptor: Option<Box<dyn Encryptor>>,
     pub(crate) metrics: Metrics,
+    pub(crate) metadata_log: MetadataLog,
+    pub(crate) schema_registry: SchemaRegistry,
     pub(crate) db: Option<Arc<Db>>,
+    /// Serializes the changes of the topics, partitions and consumer groups, which are made under the shared system lock
+    /// and the exclusive lock of the affected stream only, so that the other streams remain available in the meantime.
+    pub(crate) metadata_lock: Mutex<()>,
+    /// Set once the shutdown has started, the new connections are rejected while the connected clients are drained.
+    pub(crate) draining: AtomicBool,
+    /// Set once the system has been initialized, the server is ready to handle the requests until it starts draining.
+    pub(crate) ready: AtomicBool,
+    /// The next ID assigned to the stream created without the ID, kept per system, as many servers can run in the same process.
+    pub(crate) current_stream_id: AtomicU32,
+    /// The sender of the events to be published to the sinks, set only if at least one sink is enabled.
+    pub(crate) events: Option<flume::Sender<EventEnvelope>>,
+    /// The sender of the commands producing into the system topics, set only if the system topics are enabled.
+    pub(crate) system_topics: Option<flume::Sender<SystemTopicsCommand>>,
+    /// The runtime of the source and sink connectors, set only if the connectors are enabled.
+    pub(crate) connectors: Option<Arc<ConnectorRuntime>>,
+    /// The handles of the runtimes the server is running on, set only once the server has been started.
+    pub(crate) runtimes: Option<RuntimeHandles>,
+    /// The last known free space on the disk of the system path, rejecting the sends and the new partitions once it's running out.
+    pub(crate) disk_space: DiskSpace,
+    /// The time (in microseconds) the activity of each user has been last saved at, so that it's saved only once in a while.
+    pub(crate) users_activity: DashMap<UserId, u64>,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
@@ -74,6 +135,25 @@ impl System {
         db: Option<Arc<Db>>,
         pat_config: PersonalAccessTokenConfig,
     ) -> System {
+        if config.storage.backend == StorageBackend::Memory {
+            info!("Storage backend is memory, the data will be lost once the server stops.");
+            let db = match db {
+                Some(db) => db,
+                None => Arc::new(
+                    sled::Config::new()
+                        .temporary(true)
+                        .open()
+                        .expect("Cannot open temporary database"),
+                ),
+            };
+            return Self::create(
+                config,
+                SystemStorage::in_memory(db.clone()),
+                Some(db),
+                pat_config,
+            );
+        }
+
         let db = match db {
             Some(db) => db,
             None => {
@@ -88,24 +168,62 @@ impl System {
             true => Arc::new(FileWithSyncPersister {}),
             false => Arc::new(FilePersister {}),
         };
-        Self::create(
-            config,
-            SystemStorage::new(db.clone(), persister),
-            Some(db),
-            pat_config,
-        )
+        let storage = Self::inject_faults(&config, SystemStorage::new(db.clone(), persister));
+        Self::create(config, storage, Some(db), pat_config)
+    }
+
+    /// Wraps the segment storage to inject the faults described by the configured scenario, for testing only.
+    #[cfg(feature = "fault-injection")]
+    fn inject_faults(config: &SystemConfig, mut storage: SystemStorage) -> SystemStorage {
+        use crate::streaming::segments::fault_injection::{
+            FaultInjectingSegmentStorage, FaultScenario,
+        };
+
+        let path = &config.storage.fault_scenario_path;
+        if path.is_empty() {
+            return storage;
+        }
+
+        let scenario = FaultScenario::load(path)
+            .unwrap_or_else(|error| panic!("Cannot load fault scenario from: {path}. {error}"));
+        warn!(
+            "Injecting {} fault(s) into the segment storage from scenario: {path}, never use it in production.",
+            scenario.faults.len()
+        );
+        storage.segment = Arc::new(FaultInjectingSegmentStorage::new(storage.segment, scenario));
+        storage
+    }
+
+    #[cfg(not(feature = "fault-injection"))]
+    fn inject_faults(config: &SystemConfig, storage: SystemStorage) -> SystemStorage {
+        if !config.storage.fault_scenario_path.is_empty() {
+            warn!(
+                "Fault scenario: {} is ignored, as the server is built without the fault-injection feature.",
+                config.storage.fault_scenario_path
+            );
+        }
+        storage
     }
 
     pub fn create(
         config: Arc<SystemConfig>,
-        storage: SystemStorage,
+        mut storage: SystemStorage,
         db: Option<Arc<Db>>,
         pat_config: PersonalAccessTokenConfig,
     ) -> System {
+        storage.partitions_loader = Arc::new(PartitionsLoader::new(
+            config.startup.max_concurrent_partition_loads,
+        ));
         info!(
             "Server-side encryption is {}.",
             Self::map_toggle_str(config.encryption.enabled)
         );
+        info!(
+            "Metadata log is {}.",
+            Self::map_toggle_str(config.metadata_log.enabled)
+        );
+        let metadata_log = MetadataLog::new(&config, storage.metadata_log.clone());
+        let disk_space = DiskSpace::new(config.disk_space);
         System {
             encryptor: match config.encryption.enabled {
                 true => Some(Box::new(
@@ -116,11 +234,29 @@ impl System {
             config,
             streams: HashMap::new(),
             streams_ids: HashMap::new(),
+            streams_uuids: HashMap::new(),
+            trashed_streams: HashMap::new(),
+            tenants: HashMap::new(),
+            tenants_ids: HashMap::new(),
+            user_groups: HashMap::new(),
+            user_groups_ids: HashMap::new(),
             storage: Arc::new(storage),
             client_manager: Arc::new(RwLock::new(ClientManager::default())),
             permissioner: Permissioner::default(),
             metrics: Metrics::init(),
+            metadata_log,
+            schema_registry: SchemaRegistry::default(),
             db,
+            metadata_lock: Mutex::new(()),
+            draining: AtomicBool::new(false),
+            ready: AtomicBool::new(false),
+            current_stream_id: AtomicU32::new(1),
+            events: None,
+            system_topics: None,
+            connectors: None,
+            runtimes: None,
+            disk_space,
+            users_activity: DashMap::new(),
             personal_access_token: pat_config,
         }
     }
@@ -137,6 +273,20 @@ impl System {
             return Err(IggyError::CannotCreateStreamsDirectory(streams_path));
         }
 
+        let state_path = self.config.get_state_path();
+        if !Path::new(&state_path).exists() && create_dir_all(&state_path).await.is_err() {
+            return Err(IggyError::CannotCreateBaseDirectory(state_path));
+        }
+
+        // The marker is removed right away, so that the crash after the startup isn't mistaken for the clean shutdown.
+        let clean_shutdown_marker_path = self.config.get_clean_shutdown_marker_path();
+        let clean_shutdown = Path::new(&clean_shutdown_marker_path).exists();
+        if clean_shutdown {
+            info!("The server has previously shut down gracefully, skipping the recovery of segments.");
+            remove_file(&clean_shutdown_marker_path).await?;
+        }
+        self.storage.segment.set_clean_shutdown(clean_shutdown);
+
         let runtime_path = self.config.get_runtime_path();
         if Path::new(&runtime_path).exists() && remove_dir_all(&runtime_path).await.is_err() {
             return Err(IggyError::CannotRemoveRuntimeDirectory(runtime_path));
@@ -151,32 +301,131 @@ impl System {
             self.config.get_system_path()
         );
         let now = Instant::now();
+        MemoryBudget::initialize(&self.config.memory_budget);
         self.load_version().await?;
+        let unfinished_metadata_entries = self.metadata_log.load_unfinished().await?;
+        self.prepare_metadata_log_replay(&unfinished_metadata_entries)
+            .await;
+        self.load_tenants().await?;
+        self.load_user_groups().await?;
         self.load_users().await?;
-        self.load_streams().await?;
+        // There are no streams to load from the memory storage, and the ones possibly left
+        // in the system path by the file storage must not be mixed with the in-memory ones.
+        if self.config.storage.backend == StorageBackend::File {
+            self.load_streams().await?;
+            self.load_trash().await?;
+        }
+        self.load_schemas().await?;
+        self.replay_metadata_log(unfinished_metadata_entries)
+            .await?;
         info!("Initialized system in {} ms.", now.elapsed().as_millis());
+        self.ready.store(true, Ordering::SeqCst);
         Ok(())
     }
 
+    /// Persists the buffered messages and writes the clean shutdown marker, so that the next startup can skip the recovery.
     pub async fn shutdown(&mut self) -> Result<(), IggyError> {
         self.persist_messages().await?;
+        let clean_shutdown_marker_path = self.config.get_clean_shutdown_marker_path();
+        write(
+            &clean_shutdown_marker_path,
+            IggyTimestamp::now().to_micros().to_string(),
+        )
+        .await?;
+        info!("Written clean shutdown marker at: {clean_shutdown_marker_path}");
         Ok(())
     }
 
+    /// Stops accepting the new connections and waits until the connected clients disconnect or the timeout elapses.
+    pub async fn drain(system: &SharedSystem, timeout: Duration) {
+        let client_manager = {
+            let system = system.read();
+            system.draining.store(true, Ordering::SeqCst);
+            system.client_manager.clone()
+        };
+        info!(
+            "Draining the connected clients for up to {} ms...",
+            timeout.as_millis()
+        );
+        let started_at = Instant::now();
+        loop {
+            let clients_count = client_manager.read().await.get_clients().len();
+            if clients_count == 0 {
+                info!("All the clients have disconnected.");
+                return;
+            }
+
+            if started_at.elapsed() >= timeout {
+                warn!("Drain timeout has elapsed with {clients_count} clients still connected.");
+                return;
+            }
+
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    pub(crate) fn publish_event(&self, event: ServerEvent) {
+        if let Some(events) = &self.events {
+            let envelope = EventEnvelope {
+                timestamp: IggyTimestamp::now().to_micros(),
+                event,
+            };
+            if let Err(error) = events.send(envelope) {
+                warn!("Failed to send server event. Error: {error}");
+            }
+        }
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::SeqCst) && !self.is_draining()
+    }
+
     pub async fn persist_messages(&self) -> Result<(), IggyError> {
         trace!("Saving buffered messages on disk...");
         for stream in self.streams.values() {
-            stream.persist_messages().await?;
+            stream.read().await.persist_messages().await?;
         }
 
         Ok(())
     }
 
     pub fn ensure_authenticated(&self, session: &Session) -> Result<(), IggyError> {
-        match session.is_authenticated() {
-            true => Ok(()),
-            false => Err(IggyError::Unauthenticated),
+        if !session.is_authenticated() {
+            if !self.config.anonymous_access.enabled {
+                return Err(IggyError::Unauthenticated);
+            }
+
+            // The permissions of the pseudo-user are checked by the permissioner as for any other user.
+            session.set_user_id(ANONYMOUS_USER_ID);
         }
+
+        // The stateless sessions are created for the already verified identity (e.g. JWT) or the server itself.
+        if session.client_id == 0 {
+            return Ok(());
+        }
+
+        let user_id = session.get_user_id();
+        if self.permissioner.get_session_epoch(user_id) != Some(session.get_session_epoch()) {
+            warn!("Session for {session} has been revoked, the user must log in again.");
+            session.clear_user_id();
+            return Err(IggyError::Unauthenticated);
+        }
+
+        Ok(())
+    }
+
+    /// Ensures the session belongs to the actual user, e.g. to manage its own credentials, which the anonymous one doesn't have.
+    pub fn ensure_authenticated_user(&self, session: &Session) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        if session.is_anonymous() {
+            return Err(IggyError::Unauthenticated);
+        }
+
+        Ok(())
     }
 
     fn map_toggle_str<'a>(enabled: bool) -> &'a str {
@@ -188,6 +437,7 @@ impl System {
 
     pub async fn clean_cache(&self, size_to_clean: u64) {
         for stream in self.streams.values() {
+            let stream = stream.read().await;
             for topic in stream.get_topics() {
                 for partition in topic.get_partitions().into_iter() {
                     tokio::task::spawn(async move {
@@ -204,4 +454,52 @@ impl System {
             }
         }
     }
+
+    /// Releases the memory once the memory budget is exceeded: flushes the unsaved messages of all partitions first,
+    /// then evicts the messages cache and, if that's not enough, the cached indexes of the closed segments.
+    pub async fn release_memory(&self) -> Result<(), IggyError> {
+        let memory_budget = MemoryBudget::get_instance();
+        if memory_budget.excess_bytes() == 0 {
+            return Ok(());
+        }
+
+        warn!(
+            "Memory budget: {} exceeded, usage: {}, flushing unsaved messages and evicting caches...",
+            IggyByteSize::from(memory_budget.limit_bytes()),
+            IggyByteSize::from(memory_budget.usage_bytes())
+        );
+        self.persist_messages().await?;
+        let excess_bytes = memory_budget.excess_bytes();
+        if excess_bytes == 0 {
+            return Ok(());
+        }
+
+        let cached_messages_bytes = memory_budget.cached_messages_bytes();
+        if cached_messages_bytes > 0 {
+            self.clean_cache(excess_bytes.min(cached_messages_bytes))
+                .await;
+        }
+
+        if excess_bytes <= cached_messages_bytes {
+            return Ok(());
+        }
+
+        let mut released_bytes = 0;
+        for stream in self.streams.values() {
+            let stream = stream.read().await;
+            for topic in stream.get_topics() {
+                for partition in topic.get_partitions() {
+                    let mut partition = partition.write().await;
+                    for segment in partition.get_segments_mut() {
+                        released_bytes += segment.evict_cached_indexes();
+                    }
+                }
+            }
+        }
+        info!(
+            "Released {} of cached indexes.",
+            IggyByteSize::from(released_bytes)
+        );
+        Ok(())
+    }
 }
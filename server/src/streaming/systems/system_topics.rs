@@ -0,0 +1,126 @@
+use crate::channels::commands::publish_system_topics::SystemTopicsCommand;
+use crate::configs::server::SystemTopicsConfig;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
+use iggy::models::topic_defaults::TopicDefaults;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::Serialize;
+use std::net::{Ipv4Addr, SocketAddr};
+use tracing::{info, warn};
+
+/// The record of the command changing the server state, appended to the audit system topic.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditRecord {
+    pub timestamp: u64,
+    pub user_id: u32,
+    pub client_id: u32,
+    pub address: String,
+    pub command: String,
+    pub succeeded: bool,
+    pub error_code: Option<u32>,
+}
+
+impl System {
+    /// Creates the metrics and the audit topics in the system stream (if they don't exist yet),
+    /// and restricts the access to the stream to the server administrators.
+    pub async fn create_system_topics(
+        &mut self,
+        config: &SystemTopicsConfig,
+    ) -> Result<(), IggyError> {
+        let message_expiry = match config.message_expiry.as_secs() {
+            0 => None,
+            expiry => Some(expiry),
+        };
+        let stream_id = self
+            .create_internal_topic(&config.stream, &config.metrics_topic, 1, message_expiry)
+            .await?;
+        self.create_internal_topic(&config.stream, &config.audit_topic, 1, message_expiry)
+            .await?;
+        self.permissioner.set_system_stream(stream_id);
+        Ok(())
+    }
+
+    /// Creates the stream and the topic produced into by the server itself, if they don't exist yet.
+    /// Returns the ID of the stream.
+    pub async fn create_internal_topic(
+        &mut self,
+        stream: &str,
+        topic: &str,
+        partitions_count: u32,
+        message_expiry: Option<u32>,
+    ) -> Result<u32, IggyError> {
+        let session = Session::stateless(
+            DEFAULT_ROOT_USER_ID,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+        );
+        let stream_id = Identifier::named(stream)?;
+        let topic_id = Identifier::named(topic)?;
+        if self.get_stream(&stream_id).await.is_err() {
+            self.create_stream(&session, None, stream, TopicDefaults::default())
+                .await?;
+            info!("Created internal stream: {stream}.");
+        }
+
+        let stream_id_value;
+        let topic_exists;
+        {
+            let stream = self.get_stream(&stream_id).await?;
+            stream_id_value = stream.stream_id;
+            topic_exists = stream.get_topic(&topic_id).is_ok();
+        }
+
+        if !topic_exists {
+            self.create_topic(
+                &session,
+                &stream_id,
+                None,
+                topic,
+                partitions_count,
+                message_expiry,
+                None,
+                1,
+                None,
+                false,
+                TimestampType::default(),
+                ConsumerLimits::default(),
+                None,
+            )
+            .await?;
+            info!("Created internal topic: {topic} in stream: {stream}.");
+        }
+
+        Ok(stream_id_value)
+    }
+
+    /// Audits the connection or login attempt rejected by the client address rules.
+    pub(crate) fn audit_rejected_address(
+        &self,
+        user_id: u32,
+        client_id: u32,
+        address: &SocketAddr,
+        command: &str,
+    ) {
+        self.audit(AuditRecord {
+            timestamp: IggyTimestamp::now().to_micros(),
+            user_id,
+            client_id,
+            address: address.to_string(),
+            command: command.to_string(),
+            succeeded: false,
+            error_code: Some(IggyError::AddressNotAllowed(address.ip().to_string()).as_code()),
+        });
+    }
+
+    pub(crate) fn audit(&self, record: AuditRecord) {
+        if let Some(system_topics) = &self.system_topics {
+            if let Err(error) = system_topics.send(SystemTopicsCommand::Audit(record)) {
+                warn!("Failed to send audit record. Error: {error}");
+            }
+        }
+    }
+}
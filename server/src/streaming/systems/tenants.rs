@@ -0,0 +1,279 @@
+use crate::streaming::metadata_log::entry::MetadataEntry;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::tenants::tenant::{Tenant, TenantUsage};
+use crate::streaming::users::user::User;
+use iggy::error::IggyError;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::models::tenant::TenantQuotas;
+use iggy::utils::text;
+use tracing::{error, info};
+
+impl System {
+    pub(crate) async fn load_tenants(&mut self) -> Result<(), IggyError> {
+        info!("Loading tenants...");
+        let tenants = self.storage.tenant.load_all().await?;
+        for tenant in tenants {
+            self.tenants_ids.insert(tenant.name.clone(), tenant.id);
+            self.tenants.insert(tenant.id, tenant);
+        }
+        info!("Initialized {} tenant(s).", self.tenants.len());
+        Ok(())
+    }
+
+    pub fn get_tenant(&self, identifier: &Identifier) -> Result<&Tenant, IggyError> {
+        match identifier.kind {
+            IdKind::Numeric => {
+                let tenant_id = identifier.get_u32_value()?;
+                self.tenants
+                    .get(&tenant_id)
+                    .ok_or(IggyError::TenantIdNotFound(tenant_id))
+            }
+            IdKind::String => {
+                let name = identifier.get_cow_str_value()?;
+                self.tenants_ids
+                    .get(name.as_ref())
+                    .and_then(|tenant_id| self.tenants.get(tenant_id))
+                    .ok_or_else(|| IggyError::TenantNameNotFound(name.to_string()))
+            }
+            // Only the streams, topics and users have the UUIDs.
+            IdKind::Uuid => Err(IggyError::InvalidTenantId),
+        }
+    }
+
+    pub fn find_tenant(
+        &self,
+        session: &Session,
+        identifier: &Identifier,
+    ) -> Result<&Tenant, IggyError> {
+        self.ensure_authenticated(session)?;
+        let tenant = self.get_tenant(identifier)?;
+        self.permissioner
+            .get_tenant(session.get_user_id(), tenant.id)?;
+        Ok(tenant)
+    }
+
+    pub fn find_tenants(&self, session: &Session) -> Result<Vec<&Tenant>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let user_id = session.get_user_id();
+        self.permissioner.get_tenants(user_id)?;
+        let user_tenant_id = self.permissioner.get_user_tenant(user_id);
+        Ok(self
+            .tenants
+            .values()
+            .filter(|tenant| user_tenant_id.is_none() || user_tenant_id == Some(tenant.id))
+            .collect())
+    }
+
+    /// Calculates the usage of the tenant, the streams are locked for reading one at a time,
+    /// thus it must not be called by the task holding the lock of any stream.
+    pub async fn get_tenant_usage(&self, tenant_id: u32) -> TenantUsage {
+        let mut usage = TenantUsage {
+            users_count: self.permissioner.get_tenant_users_count(tenant_id),
+            ..Default::default()
+        };
+        for stream in self.streams.values() {
+            let stream = stream.read().await;
+            if stream.tenant_id != Some(tenant_id) {
+                continue;
+            }
+
+            usage.streams_count += 1;
+            usage.topics_count += stream.get_topics_count();
+            usage.partitions_count += stream.get_partitions_count();
+            usage.size_bytes += stream.get_size().as_bytes_u64();
+        }
+        usage
+    }
+
+    /// Ensures that the tenant owning the stream has not exceeded its quotas after adding the requested resources.
+    /// The resources not owned by any tenant are not limited.
+    pub(crate) async fn ensure_tenant_quotas(
+        &self,
+        tenant_id: Option<u32>,
+        streams_count: u32,
+        topics_count: u32,
+        partitions_count: u32,
+    ) -> Result<(), IggyError> {
+        let Some(tenant) = tenant_id.and_then(|tenant_id| self.tenants.get(&tenant_id)) else {
+            return Ok(());
+        };
+
+        let quotas = &tenant.quotas;
+        if quotas == &TenantQuotas::default() {
+            return Ok(());
+        }
+
+        let usage = self.get_tenant_usage(tenant.id).await;
+        let exceeds = |max: Option<u32>, current: u32, requested: u32| {
+            requested > 0 && max.is_some_and(|max| current + requested > max)
+        };
+        let resource = if exceeds(quotas.max_streams, usage.streams_count, streams_count) {
+            "streams"
+        } else if exceeds(quotas.max_topics, usage.topics_count, topics_count) {
+            "topics"
+        } else if exceeds(
+            quotas.max_partitions,
+            usage.partitions_count,
+            partitions_count,
+        ) {
+            "partitions"
+        } else if quotas
+            .max_size
+            .is_some_and(|max_size| usage.size_bytes >= max_size.as_bytes_u64())
+        {
+            "size"
+        } else {
+            return Ok(());
+        };
+
+        error!(
+            "Quota of {resource} for tenant: {} with ID: {} has been exceeded.",
+            tenant.name, tenant.id
+        );
+        Err(IggyError::TenantQuotaExceeded(
+            tenant.id,
+            resource.to_string(),
+        ))
+    }
+
+    pub async fn create_tenant(
+        &mut self,
+        session: &Session,
+        tenant_id: Option<u32>,
+        name: &str,
+        quotas: TenantQuotas,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.create_tenant(session.get_user_id())?;
+        let name = text::to_lowercase_non_whitespace(name);
+        if self.tenants_ids.contains_key(&name) {
+            return Err(IggyError::TenantNameAlreadyExists(name));
+        }
+
+        let id = match tenant_id {
+            Some(tenant_id) => tenant_id,
+            None => self
+                .tenants
+                .keys()
+                .max()
+                .map_or(1, |tenant_id| tenant_id + 1),
+        };
+        if self.tenants.contains_key(&id) {
+            return Err(IggyError::TenantIdAlreadyExists(id));
+        }
+
+        let tenant = Tenant::new(id, &name, quotas);
+        self.storage.tenant.save(&tenant).await?;
+        info!("Created tenant with ID: {id}, name: '{name}', quotas: {quotas}.");
+        self.tenants_ids.insert(name, id);
+        self.tenants.insert(id, tenant);
+        Ok(())
+    }
+
+    pub async fn update_tenant(
+        &mut self,
+        session: &Session,
+        identifier: &Identifier,
+        quotas: TenantQuotas,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.update_tenant(session.get_user_id())?;
+        let tenant_id = self.get_tenant(identifier)?.id;
+        let mut tenant = self.tenants[&tenant_id].clone();
+        tenant.quotas = quotas;
+        self.storage.tenant.save(&tenant).await?;
+        info!("Updated tenant with ID: {tenant_id}, quotas: {quotas}.");
+        self.tenants.insert(tenant_id, tenant);
+        Ok(())
+    }
+
+    pub async fn delete_tenant(
+        &mut self,
+        session: &Session,
+        identifier: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.delete_tenant(session.get_user_id())?;
+        let tenant = self.get_tenant(identifier)?;
+        let usage = self.get_tenant_usage(tenant.id).await;
+        if usage.streams_count > 0 || usage.users_count > 0 {
+            error!(
+                "Cannot delete tenant with ID: {}, it owns {} stream(s) and has {} user(s) assigned.",
+                tenant.id, usage.streams_count, usage.users_count
+            );
+            return Err(IggyError::CannotDeleteTenant(tenant.id));
+        }
+
+        let tenant_id = tenant.id;
+        self.storage.tenant.delete(tenant).await?;
+        if let Some(tenant) = self.tenants.remove(&tenant_id) {
+            self.tenants_ids.remove(&tenant.name);
+        }
+        info!("Deleted tenant with ID: {tenant_id}.");
+        Ok(())
+    }
+
+    pub async fn add_tenant_user(
+        &mut self,
+        session: &Session,
+        tenant_id: &Identifier,
+        user_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.add_tenant_user(session.get_user_id())?;
+        let tenant_id = self.get_tenant(tenant_id)?.id;
+        let mut user = self.get_user(user_id).await?;
+        if user.is_root() || user.tenant_id.is_some_and(|id| id != tenant_id) {
+            error!(
+                "Cannot assign user with ID: {} to tenant with ID: {tenant_id}.",
+                user.id
+            );
+            return Err(IggyError::CannotAssignTenantUser(user.id, tenant_id));
+        }
+
+        if user.tenant_id == Some(tenant_id) {
+            return Ok(());
+        }
+
+        user.tenant_id = Some(tenant_id);
+        self.save_tenant_user(user).await?;
+        info!("Assigned user with ID: {user_id} to tenant with ID: {tenant_id}.");
+        Ok(())
+    }
+
+    pub async fn remove_tenant_user(
+        &mut self,
+        session: &Session,
+        tenant_id: &Identifier,
+        user_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .remove_tenant_user(session.get_user_id())?;
+        let tenant_id = self.get_tenant(tenant_id)?.id;
+        let mut user = self.get_user(user_id).await?;
+        if user.tenant_id != Some(tenant_id) {
+            return Err(IggyError::TenantUserNotFound(user.id, tenant_id));
+        }
+
+        user.tenant_id = None;
+        self.save_tenant_user(user).await?;
+        info!("Unassigned user with ID: {user_id} from tenant with ID: {tenant_id}.");
+        Ok(())
+    }
+
+    async fn save_tenant_user(&mut self, user: User) -> Result<(), IggyError> {
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::UpdateUser {
+                user: Box::new(user.clone()),
+            })
+            .await?;
+        let result = self.storage.user.save(&user).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        self.permissioner.update_permissions_for_user(user);
+        Ok(())
+    }
+}
@@ -1,40 +1,53 @@
+use crate::streaming::events::event::ServerEvent;
+use crate::streaming::metadata_log::entry::MetadataEntry;
 use crate::streaming::session::Session;
-use crate::streaming::systems::system::System;
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::system::{SharedSystem, System};
 use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
-use iggy::identifier::Identifier;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::text;
+use tokio::sync::RwLockReadGuard;
+use tracing::info;
 
 impl System {
-    pub fn find_topic(
+    /// Returns the topic, its stream stays locked for reading as long as the topic is borrowed.
+    pub async fn find_topic(
         &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
-    ) -> Result<&Topic, IggyError> {
+    ) -> Result<RwLockReadGuard<'_, Topic>, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(stream_id)?;
+        let stream = self.get_stream(stream_id).await?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner
             .get_topic(session.get_user_id(), stream.stream_id, topic.topic_id)?;
-        Ok(topic)
+        let topic_id = topic.topic_id;
+        Ok(RwLockReadGuard::map(stream, |stream| {
+            stream.topics.get(&topic_id).unwrap()
+        }))
     }
 
-    pub fn find_topics(
+    /// Returns the stream owning the topics locked for reading, once the topics can be accessed by the session.
+    pub async fn find_topics(
         &self,
         session: &Session,
         stream_id: &Identifier,
-    ) -> Result<Vec<&Topic>, IggyError> {
+    ) -> Result<RwLockReadGuard<'_, Stream>, IggyError> {
         self.ensure_authenticated(session)?;
-        let stream = self.get_stream(stream_id)?;
+        let stream = self.get_stream(stream_id).await?;
         self.permissioner
             .get_topics(session.get_user_id(), stream.stream_id)?;
-        Ok(stream.get_topics())
+        Ok(stream)
     }
 
     #[allow(clippy::too_many_arguments)]
     pub async fn create_topic(
-        &mut self,
+        &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: Option<u32>,
@@ -43,33 +56,189 @@ impl System {
         message_expiry: Option<u32>,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        message_schema: Option<&str>,
+        ephemeral: bool,
+        timestamp_type: TimestampType,
+        consumer_limits: ConsumerLimits,
+        max_message_size: Option<IggyByteSize>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let _metadata_lock = self.metadata_lock.lock().await;
+        let stream_id_value;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             self.permissioner
                 .create_topic(session.get_user_id(), stream.stream_id)?;
+            stream_id_value = stream.stream_id;
         }
 
-        self.get_stream_mut(stream_id)?
-            .create_topic(
+        self.add_topic(
+            stream_id_value,
+            topic_id,
+            name,
+            partitions_count,
+            message_expiry,
+            max_topic_size,
+            replication_factor,
+            message_schema,
+            ephemeral,
+            timestamp_type,
+            consumer_limits,
+            max_message_size,
+        )
+        .await
+    }
+
+    /// Creates the missing topic referenced by its name with the defaults from `system.topic.auto_create`,
+    /// so that the messages sent to it can be appended. Does nothing if the topic already exists.
+    pub async fn auto_create_topic(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let _metadata_lock = self.metadata_lock.lock().await;
+        let stream_id_value;
+        let name;
+        {
+            let stream = self.get_stream(stream_id).await?;
+            let error = match stream.get_topic(topic_id) {
+                Ok(_) => return Ok(()),
+                Err(error) => error,
+            };
+            if !self.config.topic.auto_create.enabled || topic_id.kind != IdKind::String {
+                return Err(error);
+            }
+
+            // The topic name is normalized on creation, it must stay addressable by the same identifier.
+            name = topic_id.get_cow_str_value()?.to_string();
+            if text::to_lowercase_non_whitespace(&name) != name {
+                return Err(error);
+            }
+
+            self.permissioner
+                .auto_create_topic(session.get_user_id(), stream.stream_id)?;
+            stream_id_value = stream.stream_id;
+        }
+
+        let config = &self.config.topic.auto_create;
+        let partitions_count = config.partitions_count;
+        let replication_factor = config.replication_factor;
+        let message_expiry = match config.message_expiry.as_secs() {
+            0 => None,
+            expiry => Some(expiry),
+        };
+        let max_topic_size = match config.max_topic_size.as_bytes_u64() {
+            0 => None,
+            _ => Some(config.max_topic_size),
+        };
+        self.add_topic(
+            stream_id_value,
+            None,
+            &name,
+            partitions_count,
+            message_expiry,
+            max_topic_size,
+            replication_factor,
+            None,
+            false,
+            TimestampType::default(),
+            ConsumerLimits::default(),
+            None,
+        )
+        .await?;
+        info!("Auto-created topic: {name} in stream with ID: {stream_id_value}.");
+        Ok(())
+    }
+
+    /// Creates the topic in the stream, the caller must hold the metadata lock or borrow the system mutably.
+    #[allow(clippy::too_many_arguments)]
+    async fn add_topic(
+        &self,
+        stream_id: u32,
+        topic_id: Option<u32>,
+        name: &str,
+        partitions_count: u32,
+        message_expiry: Option<u32>,
+        max_topic_size: Option<IggyByteSize>,
+        replication_factor: u8,
+        message_schema: Option<&str>,
+        ephemeral: bool,
+        timestamp_type: TimestampType,
+        consumer_limits: ConsumerLimits,
+        max_message_size: Option<IggyByteSize>,
+    ) -> Result<(), IggyError> {
+        let tenant_id;
+        {
+            let stream = self.get_stream(&Identifier::numeric(stream_id)?).await?;
+            self.ensure_topics_limit(&stream)?;
+            tenant_id = stream.tenant_id;
+        }
+        self.ensure_partitions_limit(0, partitions_count)?;
+        if partitions_count > 0 {
+            self.disk_space.ensure_space_for_partitions()?;
+        }
+        self.ensure_tenant_quotas(tenant_id, 0, 1, partitions_count)
+            .await?;
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::CreateTopic {
+                stream_id,
                 topic_id,
-                name,
+                name: name.to_string(),
                 partitions_count,
                 message_expiry,
                 max_topic_size,
                 replication_factor,
-            )
+                message_schema: message_schema.map(|message_schema| message_schema.to_string()),
+                ephemeral,
+                timestamp_type,
+                consumer_limits,
+                max_message_size,
+            })
             .await?;
-        self.metrics.increment_topics(1);
-        self.metrics.increment_partitions(partitions_count);
-        self.metrics.increment_segments(partitions_count);
-        Ok(())
+        let result = async {
+            let mut stream = self
+                .get_stream_mut(&Identifier::numeric(stream_id)?)
+                .await?;
+            stream
+                .create_topic(
+                    topic_id,
+                    name,
+                    partitions_count,
+                    message_expiry,
+                    max_topic_size,
+                    replication_factor,
+                    message_schema,
+                    ephemeral,
+                    timestamp_type,
+                    consumer_limits,
+                    max_message_size,
+                )
+                .await?;
+            let name = text::to_lowercase_non_whitespace(name);
+            let topic_id = stream.topics_ids.get(&name).copied();
+            drop(stream);
+            self.metrics.increment_topics(1);
+            self.metrics.increment_partitions(partitions_count);
+            self.metrics.increment_segments(partitions_count);
+            self.publish_event(ServerEvent::TopicCreated {
+                stream_id,
+                topic_id,
+                name,
+                partitions_count,
+            });
+            Ok(())
+        }
+        .await;
+        self.metadata_log.complete(entry_id).await?;
+        result
     }
 
     #[allow(clippy::too_many_arguments)]
     pub async fn update_topic(
-        &mut self,
+        &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
@@ -77,27 +246,63 @@ impl System {
         message_expiry: Option<u32>,
         max_topic_size: Option<IggyByteSize>,
         replication_factor: u8,
+        message_schema: Option<&str>,
+        timestamp_type: TimestampType,
+        consumer_limits: ConsumerLimits,
+        max_message_size: Option<IggyByteSize>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        let _metadata_lock = self.metadata_lock.lock().await;
+        let entry;
+        let stream_id_value;
+        let topic_id_value;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.update_topic(
                 session.get_user_id(),
                 stream.stream_id,
                 topic.topic_id,
             )?;
+            stream_id_value = stream.stream_id;
+            topic_id_value = topic.topic_id;
+            entry = MetadataEntry::UpdateTopic {
+                stream_id: stream.stream_id,
+                topic_id: topic.topic_id,
+                name: name.to_string(),
+                message_expiry,
+                max_topic_size,
+                replication_factor,
+                message_schema: message_schema.map(|message_schema| message_schema.to_string()),
+                timestamp_type,
+                consumer_limits,
+                max_message_size,
+            };
         }
 
-        self.get_stream_mut(stream_id)?
+        let entry_id = self.metadata_log.append(entry).await?;
+        let result = self
+            .get_stream_mut(stream_id)
+            .await?
             .update_topic(
                 topic_id,
                 name,
                 message_expiry,
                 max_topic_size,
                 replication_factor,
+                message_schema,
+                timestamp_type,
+                consumer_limits,
+                max_message_size,
             )
-            .await?;
+            .await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        self.publish_event(ServerEvent::TopicUpdated {
+            stream_id: stream_id_value,
+            topic_id: topic_id_value,
+            name: text::to_lowercase_non_whitespace(name),
+        });
 
         // TODO: if message_expiry is changed, we need to check if we need to purge messages based on the new expiry
         // TODO: if max_size_bytes is changed, we need to check if we need to purge messages based on the new size
@@ -105,16 +310,24 @@ impl System {
         Ok(())
     }
 
+    /// Deletes the topic, or moves it to the trash if it's soft deleted, so that it can be restored until the trash retention expires.
     pub async fn delete_topic(
-        &mut self,
+        &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
+        soft: bool,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
+        if soft {
+            self.ensure_trash_available()?;
+        }
+
+        let _metadata_lock = self.metadata_lock.lock().await;
         let stream_id_value;
+        let topic_id_value;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = self.get_stream(stream_id).await?;
             let topic = stream.get_topic(topic_id)?;
             self.permissioner.delete_topic(
                 session.get_user_id(),
@@ -122,23 +335,43 @@ impl System {
                 topic.topic_id,
             )?;
             stream_id_value = stream.stream_id;
+            topic_id_value = topic.topic_id;
         }
 
-        let topic = self
-            .get_stream_mut(stream_id)?
-            .delete_topic(topic_id)
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::DeleteTopic {
+                stream_id: stream_id_value,
+                topic_id: topic_id_value,
+                soft,
+            })
             .await?;
-        self.metrics.decrement_topics(1);
-        self.metrics
-            .decrement_partitions(topic.get_partitions_count());
-        self.metrics.decrement_messages(topic.get_messages_count());
-        self.metrics
-            .decrement_segments(topic.get_segments_count().await);
-        let client_manager = self.client_manager.read().await;
-        client_manager
-            .delete_consumer_groups_for_topic(stream_id_value, topic.topic_id)
-            .await;
-        Ok(())
+        let result = async {
+            let mut stream = self.get_stream_mut(stream_id).await?;
+            let topic = match soft {
+                true => stream.trash_topic(topic_id).await?,
+                false => stream.delete_topic(topic_id).await?,
+            };
+            drop(stream);
+            self.metrics.decrement_topics(1);
+            self.metrics
+                .decrement_partitions(topic.get_partitions_count());
+            self.metrics.decrement_messages(topic.get_messages_count());
+            self.metrics
+                .decrement_segments(topic.get_segments_count().await);
+            let client_manager = self.client_manager.read().await;
+            client_manager
+                .delete_consumer_groups_for_topic(stream_id_value, topic.topic_id)
+                .await;
+            self.publish_event(ServerEvent::TopicDeleted {
+                stream_id: stream_id_value,
+                topic_id: topic.topic_id,
+            });
+            Ok(())
+        }
+        .await;
+        self.metadata_log.complete(entry_id).await?;
+        result
     }
 
     pub async fn purge_topic(
@@ -147,10 +380,123 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<(), IggyError> {
-        let stream = self.get_stream(stream_id)?;
+        let stream = self.get_stream(stream_id).await?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner
             .purge_topic(session.get_user_id(), stream.stream_id, topic.topic_id)?;
-        topic.purge().await
+        topic.purge().await?;
+        self.publish_event(ServerEvent::TopicPurged {
+            stream_id: stream.stream_id,
+            topic_id: topic.topic_id,
+        });
+        Ok(())
+    }
+
+    /// Freezes the topic, so that the messages can no longer be sent to it, while they can still be polled.
+    pub async fn freeze_topic(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.set_topic_frozen(session, stream_id, topic_id, true)
+            .await
+    }
+
+    pub async fn unfreeze_topic(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.set_topic_frozen(session, stream_id, topic_id, false)
+            .await
+    }
+
+    async fn set_topic_frozen(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        frozen: bool,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let _metadata_lock = self.metadata_lock.lock().await;
+        let stream_id_value;
+        let topic_id_value;
+        {
+            let stream = self.get_stream(stream_id).await?;
+            let topic = stream.get_topic(topic_id)?;
+            self.permissioner.update_topic(
+                session.get_user_id(),
+                stream.stream_id,
+                topic.topic_id,
+            )?;
+            if topic.frozen == frozen {
+                return Ok(());
+            }
+
+            stream_id_value = stream.stream_id;
+            topic_id_value = topic.topic_id;
+        }
+
+        let entry = match frozen {
+            true => MetadataEntry::FreezeTopic {
+                stream_id: stream_id_value,
+                topic_id: topic_id_value,
+            },
+            false => MetadataEntry::UnfreezeTopic {
+                stream_id: stream_id_value,
+                topic_id: topic_id_value,
+            },
+        };
+        let entry_id = self.metadata_log.append(entry).await?;
+        let result = self
+            .get_stream_mut(stream_id)
+            .await?
+            .set_topic_frozen(topic_id, frozen)
+            .await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        let event = match frozen {
+            true => ServerEvent::TopicFrozen {
+                stream_id: stream_id_value,
+                topic_id: topic_id_value,
+            },
+            false => ServerEvent::TopicUnfrozen {
+                stream_id: stream_id_value,
+                topic_id: topic_id_value,
+            },
+        };
+        self.publish_event(event);
+        Ok(())
+    }
+}
+
+impl SharedSystem {
+    /// Creates the missing topic before the messages are sent to it, if the topic auto-creation is enabled.
+    /// The stream is locked for writing only when the topic does not exist yet.
+    pub async fn auto_create_topic(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        {
+            let system = self.read();
+            if !system.config.topic.auto_create.enabled || topic_id.kind != IdKind::String {
+                return Ok(());
+            }
+
+            let Ok(stream) = system.get_stream(stream_id).await else {
+                return Ok(());
+            };
+            if stream.get_topic(topic_id).is_ok() {
+                return Ok(());
+            }
+        }
+
+        let system = self.read();
+        system.auto_create_topic(session, stream_id, topic_id).await
     }
 }
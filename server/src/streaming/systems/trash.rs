@@ -0,0 +1,322 @@
+use crate::configs::system::StorageBackend;
+use crate::streaming::metadata_log::entry::MetadataEntry;
+use crate::streaming::session::Session;
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::system::System;
+use crate::streaming::trash::entry::TrashEntry;
+use crate::streaming::utils::file;
+use iggy::error::IggyError;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::utils::timestamp::IggyTimestamp;
+use std::path::Path;
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+
+impl System {
+    /// Loads the soft deleted streams and topics, the entries left without the data in the trash are removed,
+    /// e.g. if the server stopped before the data was moved, which is then completed by the metadata log replay.
+    pub(crate) async fn load_trash(&mut self) -> Result<(), IggyError> {
+        info!("Loading trash...");
+        let mut entries = self.storage.trash.load_all().await?;
+        entries.sort_by_key(|entry| entry.topic_id);
+        for entry in entries {
+            let trash_path = match entry.topic_id {
+                Some(topic_id) => self
+                    .config
+                    .get_trashed_topic_path(entry.stream_id, topic_id),
+                None => self.config.get_trashed_stream_path(entry.stream_id),
+            };
+            if !Path::new(&trash_path).exists() {
+                warn!(
+                    "Trash entry: '{}' has no data at: {trash_path}, removing it...",
+                    entry.name
+                );
+                self.storage.trash.delete(&entry).await?;
+                continue;
+            }
+
+            let Some(topic_id) = entry.topic_id else {
+                self.trashed_streams.insert(entry.stream_id, entry);
+                continue;
+            };
+
+            // The trashed topics of the trashed stream are loaded once the stream is restored.
+            if let Some(stream) = self.streams.get(&entry.stream_id) {
+                stream.write().await.trashed_topics.insert(topic_id, entry);
+            }
+        }
+
+        info!(
+            "Loaded {} stream(s) from the trash.",
+            self.trashed_streams.len()
+        );
+        Ok(())
+    }
+
+    pub(crate) fn ensure_trash_available(&self) -> Result<(), IggyError> {
+        if self.config.storage.backend == StorageBackend::Memory {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        Ok(())
+    }
+
+    fn get_trashed_stream(&self, identifier: &Identifier) -> Result<&TrashEntry, IggyError> {
+        let entry = match identifier.kind {
+            IdKind::Numeric => self.trashed_streams.get(&identifier.get_u32_value()?),
+            // The same name could have been trashed more than once, the latest one is restored first.
+            IdKind::String => {
+                let name = identifier.get_cow_str_value()?;
+                self.trashed_streams
+                    .values()
+                    .filter(|entry| entry.name == name)
+                    .max_by_key(|entry| entry.deleted_at)
+            }
+            IdKind::Uuid => None,
+        };
+        entry.ok_or_else(|| IggyError::TrashedStreamNotFound(identifier.to_string()))
+    }
+
+    /// Moves the stream to the trash, its data and state are kept until it's restored or purged.
+    pub(crate) async fn move_stream_to_trash(&mut self, stream: &Stream) -> Result<(), IggyError> {
+        stream.persist_messages().await?;
+        // The entry is saved first, so that the stream moved to the trash is never left without it.
+        let entry = TrashEntry::stream(stream.stream_id, &stream.name);
+        self.storage.trash.save(&entry).await?;
+        let trash_path = self.config.get_trashed_stream_path(stream.stream_id);
+        if let Err(error) = file::move_dir(&stream.path, &trash_path).await {
+            error!(
+                "Cannot move stream with ID: {} to the trash. Error: {error}",
+                stream.stream_id
+            );
+            self.storage.trash.delete(&entry).await?;
+            return Err(IggyError::CannotMoveStreamToTrash(
+                stream.stream_id,
+                trash_path,
+            ));
+        }
+
+        info!("Moved stream with ID: {} to the trash.", stream.stream_id);
+        self.trashed_streams.insert(stream.stream_id, entry);
+        Ok(())
+    }
+
+    pub async fn undelete_stream(
+        &mut self,
+        session: &Session,
+        id: &Identifier,
+    ) -> Result<u32, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.ensure_trash_available()?;
+        let entry = self.get_trashed_stream(id)?.clone();
+        let stream_id = entry.stream_id;
+        self.permissioner
+            .delete_stream(session.get_user_id(), stream_id)?;
+        if self.streams_ids.contains_key(&entry.name) {
+            return Err(IggyError::StreamNameAlreadyExists(entry.name));
+        }
+
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::UndeleteStream { stream_id })
+            .await?;
+        let result = self.restore_stream(&entry).await;
+        self.metadata_log.complete(entry_id).await?;
+        result.map(|_| stream_id)
+    }
+
+    async fn restore_stream(&mut self, entry: &TrashEntry) -> Result<(), IggyError> {
+        let stream_id = entry.stream_id;
+        let mut stream = Stream::empty(stream_id, self.config.clone(), self.storage.clone());
+        let trash_path = self.config.get_trashed_stream_path(stream_id);
+        file::move_dir(&trash_path, &stream.path)
+            .await
+            .map_err(|_| IggyError::CannotMoveStreamToTrash(stream_id, trash_path.clone()))?;
+        if let Err(error) = stream.load().await {
+            error!(
+                "Cannot load stream with ID: {stream_id} restored from the trash. Error: {error}"
+            );
+            file::move_dir(&stream.path, &trash_path).await?;
+            return Err(error);
+        }
+
+        for topic_entry in self.load_trashed_topics(stream_id).await? {
+            if let Some(topic_id) = topic_entry.topic_id {
+                stream.trashed_topics.insert(topic_id, topic_entry);
+            }
+        }
+
+        self.storage.trash.delete(entry).await?;
+        self.trashed_streams.remove(&stream_id);
+        self.metrics.increment_streams(1);
+        self.metrics.increment_topics(stream.get_topics_count());
+        self.metrics
+            .increment_partitions(stream.get_partitions_count());
+        self.metrics
+            .increment_segments(stream.get_segments_count().await);
+        self.metrics.increment_messages(stream.get_messages_count());
+        self.permissioner
+            .set_stream_tenant(stream_id, stream.tenant_id);
+        self.streams_ids.insert(stream.name.clone(), stream_id);
+        self.streams_uuids.insert(stream.uuid, stream_id);
+        self.streams.insert(stream_id, RwLock::new(stream));
+        info!("Restored stream with ID: {stream_id} from the trash.");
+        Ok(())
+    }
+
+    pub async fn undelete_topic(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.ensure_trash_available()?;
+        let _metadata_lock = self.metadata_lock.lock().await;
+        let stream_id_value;
+        let topic_id_value;
+        {
+            let stream = self.get_stream(stream_id).await?;
+            let entry = stream.get_trashed_topic(topic_id)?;
+            stream_id_value = stream.stream_id;
+            topic_id_value = entry.topic_id.unwrap_or_default();
+            self.permissioner.delete_topic(
+                session.get_user_id(),
+                stream_id_value,
+                topic_id_value,
+            )?;
+        }
+
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::UndeleteTopic {
+                stream_id: stream_id_value,
+                topic_id: topic_id_value,
+            })
+            .await?;
+        let result = async {
+            let mut stream = self.get_stream_mut(stream_id).await?;
+            let topic_id = stream.restore_topic(topic_id).await?;
+            let topic = stream.get_topic(&Identifier::numeric(topic_id)?)?;
+            self.metrics.increment_topics(1);
+            self.metrics
+                .increment_partitions(topic.get_partitions_count());
+            self.metrics.increment_messages(topic.get_messages_count());
+            self.metrics
+                .increment_segments(topic.get_segments_count().await);
+            Ok(())
+        }
+        .await;
+        self.metadata_log.complete(entry_id).await?;
+        result
+    }
+
+    /// Deletes the streams and topics kept in the trash for longer than the retention, along with all their data.
+    /// The trashed topics of the trashed stream are purged along with the stream.
+    pub async fn purge_expired_trash(&mut self) -> Result<u32, IggyError> {
+        let now = IggyTimestamp::now().to_micros();
+        let retention = self.config.trash.retention;
+        let mut purged_count = 0;
+        let mut expired_topics = Vec::new();
+        for stream in self.streams.values() {
+            let stream = stream.read().await;
+            for (topic_id, entry) in stream.trashed_topics.iter() {
+                if entry.is_expired(now, retention) {
+                    expired_topics.push((stream.stream_id, *topic_id));
+                }
+            }
+        }
+
+        for (stream_id, topic_id) in expired_topics {
+            let entry_id = self
+                .metadata_log
+                .append(MetadataEntry::DeleteTopic {
+                    stream_id,
+                    topic_id,
+                    soft: false,
+                })
+                .await?;
+            let result = self
+                .get_stream_mut(&Identifier::numeric(stream_id)?)
+                .await?
+                .purge_trashed_topic(topic_id)
+                .await;
+            self.metadata_log.complete(entry_id).await?;
+            match result {
+                Ok(()) => purged_count += 1,
+                Err(error) => error!("Cannot purge topic with ID: {topic_id} for stream with ID: {stream_id} from the trash. Error: {error}"),
+            }
+        }
+
+        let expired_streams = self
+            .trashed_streams
+            .values()
+            .filter(|entry| entry.is_expired(now, retention))
+            .map(|entry| entry.stream_id)
+            .collect::<Vec<_>>();
+        for stream_id in expired_streams {
+            let entry_id = self
+                .metadata_log
+                .append(MetadataEntry::DeleteStream {
+                    stream_id,
+                    soft: false,
+                })
+                .await?;
+            let result = self.purge_trashed_stream(stream_id).await;
+            self.metadata_log.complete(entry_id).await?;
+            match result {
+                Ok(()) => purged_count += 1,
+                Err(error) => error!(
+                    "Cannot purge stream with ID: {stream_id} from the trash. Error: {error}"
+                ),
+            }
+        }
+
+        Ok(purged_count)
+    }
+
+    /// Moves the stream and its trashed topics back from the trash, so that all their data and state can be deleted.
+    async fn purge_trashed_stream(&mut self, stream_id: u32) -> Result<(), IggyError> {
+        let Some(entry) = self.trashed_streams.get(&stream_id).cloned() else {
+            return Err(IggyError::TrashedStreamNotFound(stream_id.to_string()));
+        };
+
+        let mut stream = Stream::empty(stream_id, self.config.clone(), self.storage.clone());
+        let trash_path = self.config.get_trashed_stream_path(stream_id);
+        file::move_dir(&trash_path, &stream.path)
+            .await
+            .map_err(|_| IggyError::CannotMoveStreamToTrash(stream_id, trash_path))?;
+        let topic_entries = self.load_trashed_topics(stream_id).await?;
+        for topic_entry in topic_entries.iter() {
+            let Some(topic_id) = topic_entry.topic_id else {
+                continue;
+            };
+            file::move_dir(
+                &self.config.get_trashed_topic_path(stream_id, topic_id),
+                &self.config.get_topic_path(stream_id, topic_id),
+            )
+            .await?;
+        }
+
+        stream.load().await?;
+        stream.delete().await?;
+        for topic_entry in topic_entries.iter() {
+            self.storage.trash.delete(topic_entry).await?;
+        }
+        self.storage.trash.delete(&entry).await?;
+        self.trashed_streams.remove(&stream_id);
+        info!("Purged stream with ID: {stream_id} from the trash.");
+        Ok(())
+    }
+
+    async fn load_trashed_topics(&self, stream_id: u32) -> Result<Vec<TrashEntry>, IggyError> {
+        Ok(self
+            .storage
+            .trash
+            .load_all()
+            .await?
+            .into_iter()
+            .filter(|entry| entry.stream_id == stream_id && entry.topic_id.is_some())
+            .collect())
+    }
+}
@@ -0,0 +1,259 @@
+use crate::streaming::metadata_log::entry::MetadataEntry;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::user_groups::user_group::UserGroup;
+use iggy::error::IggyError;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::models::permissions::Permissions;
+use iggy::models::user_info::UserId;
+use iggy::utils::text;
+use std::collections::BTreeSet;
+use tracing::info;
+
+impl System {
+    pub(crate) async fn load_user_groups(&mut self) -> Result<(), IggyError> {
+        info!("Loading user groups...");
+        let groups = self.storage.user_group.load_all().await?;
+        for group in groups {
+            self.permissioner.set_user_group(&group);
+            self.user_groups_ids.insert(group.name.clone(), group.id);
+            self.user_groups.insert(group.id, group);
+        }
+        info!("Initialized {} user group(s).", self.user_groups.len());
+        Ok(())
+    }
+
+    pub fn get_user_group(&self, identifier: &Identifier) -> Result<&UserGroup, IggyError> {
+        match identifier.kind {
+            IdKind::Numeric => {
+                let group_id = identifier.get_u32_value()?;
+                self.user_groups
+                    .get(&group_id)
+                    .ok_or(IggyError::UserGroupIdNotFound(group_id))
+            }
+            IdKind::String => {
+                let name = identifier.get_cow_str_value()?;
+                self.user_groups_ids
+                    .get(name.as_ref())
+                    .and_then(|group_id| self.user_groups.get(group_id))
+                    .ok_or_else(|| IggyError::UserGroupNameNotFound(name.to_string()))
+            }
+            // Only the streams, topics and users have the UUIDs.
+            IdKind::Uuid => Err(IggyError::InvalidUserGroupId),
+        }
+    }
+
+    pub fn find_user_group(
+        &self,
+        session: &Session,
+        identifier: &Identifier,
+    ) -> Result<&UserGroup, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_user_group(session.get_user_id())?;
+        self.get_user_group(identifier)
+    }
+
+    pub fn find_user_groups(&self, session: &Session) -> Result<Vec<&UserGroup>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_user_groups(session.get_user_id())?;
+        Ok(self.user_groups.values().collect())
+    }
+
+    pub async fn create_user_group(
+        &mut self,
+        session: &Session,
+        group_id: Option<u32>,
+        name: &str,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.create_user_group(session.get_user_id())?;
+        let name = text::to_lowercase_non_whitespace(name);
+        if self.user_groups_ids.contains_key(&name) {
+            return Err(IggyError::UserGroupNameAlreadyExists(name));
+        }
+
+        let id = match group_id {
+            Some(group_id) => group_id,
+            None => self
+                .user_groups
+                .keys()
+                .max()
+                .map_or(1, |group_id| group_id + 1),
+        };
+        if self.user_groups.contains_key(&id) {
+            return Err(IggyError::UserGroupIdAlreadyExists(id));
+        }
+
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::CreateUserGroup {
+                group_id: id,
+                name: name.clone(),
+                permissions: permissions.clone(),
+            })
+            .await?;
+        let group = UserGroup::new(id, &name, permissions);
+        let result = self.storage.user_group.save(&group).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        info!("Created user group with ID: {id}, name: '{name}'.");
+        self.permissioner.set_user_group(&group);
+        self.user_groups_ids.insert(name, id);
+        self.user_groups.insert(id, group);
+        Ok(())
+    }
+
+    pub async fn update_user_group_permissions(
+        &mut self,
+        session: &Session,
+        identifier: &Identifier,
+        permissions: Option<Permissions>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_user_group_permissions(session.get_user_id())?;
+        let mut group = self.get_user_group(identifier)?.clone();
+        let group_id = group.id;
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::UpdateUserGroupPermissions {
+                group_id,
+                permissions: permissions.clone(),
+            })
+            .await?;
+        group.permissions = permissions;
+        let members = group.members.clone();
+        let result = self.save_user_group(group).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        self.refresh_members_permissions(&members).await?;
+        info!("Updated permissions for user group with ID: {group_id}.");
+        Ok(())
+    }
+
+    pub async fn delete_user_group(
+        &mut self,
+        session: &Session,
+        identifier: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.delete_user_group(session.get_user_id())?;
+        let group = self.get_user_group(identifier)?;
+        let group_id = group.id;
+        let members = group.members.clone();
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::DeleteUserGroup { group_id })
+            .await?;
+        let result = self.storage.user_group.delete(group).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        if let Some(group) = self.user_groups.remove(&group_id) {
+            self.user_groups_ids.remove(&group.name);
+        }
+        self.permissioner.remove_user_group(group_id);
+        self.refresh_members_permissions(&members).await?;
+        info!("Deleted user group with ID: {group_id}.");
+        Ok(())
+    }
+
+    pub async fn add_user_group_member(
+        &mut self,
+        session: &Session,
+        group_id: &Identifier,
+        user_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .add_user_group_member(session.get_user_id())?;
+        let mut group = self.get_user_group(group_id)?.clone();
+        let user = self.get_user(user_id).await?;
+        if !group.members.insert(user.id) {
+            return Err(IggyError::UserGroupMemberAlreadyExists(user.id, group.id));
+        }
+
+        let group_id = group.id;
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::AddUserGroupMember {
+                group_id,
+                user_id: user.id,
+            })
+            .await?;
+        let result = self.save_user_group(group).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        self.permissioner.update_permissions_for_user(user);
+        info!("Added user with ID: {user_id} to user group with ID: {group_id}.");
+        Ok(())
+    }
+
+    pub async fn remove_user_group_member(
+        &mut self,
+        session: &Session,
+        group_id: &Identifier,
+        user_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .remove_user_group_member(session.get_user_id())?;
+        let mut group = self.get_user_group(group_id)?.clone();
+        let user = self.get_user(user_id).await?;
+        if !group.members.remove(&user.id) {
+            return Err(IggyError::UserGroupMemberNotFound(user.id, group.id));
+        }
+
+        let group_id = group.id;
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::RemoveUserGroupMember {
+                group_id,
+                user_id: user.id,
+            })
+            .await?;
+        let result = self.save_user_group(group).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        self.permissioner.update_permissions_for_user(user);
+        info!("Removed user with ID: {user_id} from user group with ID: {group_id}.");
+        Ok(())
+    }
+
+    /// Removes the deleted user from all the groups it belongs to.
+    pub(crate) async fn remove_user_from_groups(
+        &mut self,
+        user_id: UserId,
+    ) -> Result<(), IggyError> {
+        let groups = self
+            .user_groups
+            .values()
+            .filter(|group| group.members.contains(&user_id))
+            .cloned()
+            .collect::<Vec<_>>();
+        for mut group in groups {
+            group.members.remove(&user_id);
+            self.save_user_group(group).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn save_user_group(&mut self, group: UserGroup) -> Result<(), IggyError> {
+        self.storage.user_group.save(&group).await?;
+        self.permissioner.set_user_group(&group);
+        self.user_groups.insert(group.id, group);
+        Ok(())
+    }
+
+    /// Reloads the effective permissions of the members after the group permissions or membership have changed.
+    pub(crate) async fn refresh_members_permissions(
+        &mut self,
+        members: &BTreeSet<UserId>,
+    ) -> Result<(), IggyError> {
+        for user_id in members {
+            let user = self.storage.user.load_by_id(*user_id).await?;
+            self.permissioner.update_permissions_for_user(user);
+        }
+        Ok(())
+    }
+}
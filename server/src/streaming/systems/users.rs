@@ -1,17 +1,26 @@
+use crate::streaming::events::event::ServerEvent;
+use crate::streaming::metadata_log::entry::MetadataEntry;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
-use crate::streaming::users::user::User;
+use crate::streaming::systems::system_topics::AuditRecord;
+use crate::streaming::users::user::{User, ANONYMOUS_USER_ID};
 use crate::streaming::utils::crypto;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
+use iggy::models::permission_check::{PermissionAction, PermissionCheck};
 use iggy::models::permissions::Permissions;
+use iggy::models::user_info::UserId;
 use iggy::models::user_status::UserStatus;
 use iggy::utils::text;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tracing::log::error;
 use tracing::{info, warn};
+use uuid::Uuid;
 
-static USER_ID: AtomicU32 = AtomicU32::new(1);
+pub(super) static USER_ID: AtomicU32 = AtomicU32::new(1);
+/// The activity of the user is saved at most once an hour, the inactivity window is counted in days anyway.
+const USER_ACTIVITY_SAVE_INTERVAL: u64 = 60 * 60 * 1_000_000;
 
 impl System {
     pub(crate) async fn load_users(&mut self) -> Result<(), IggyError> {
@@ -25,11 +34,25 @@ impl System {
             users = self.storage.user.load_all().await?;
         }
 
+        for user in users.iter_mut().filter(|user| user.uuid.is_nil()) {
+            user.uuid = Uuid::new_v4();
+            self.storage.user.save(user).await?;
+            info!("Assigned UUID: {} to user with ID: {}.", user.uuid, user.id);
+        }
+
         let users_count = users.len();
         let current_user_id = users.iter().map(|user| user.id).max().unwrap_or(1);
         USER_ID.store(current_user_id + 1, Ordering::SeqCst);
         self.permissioner.init(users);
         info!("Initialized {} user(s).", users_count);
+        if self.config.anonymous_access.enabled {
+            self.permissioner
+                .init_permissions_for_user(User::anonymous(&self.config.anonymous_access));
+            info!(
+                "Enabled the anonymous access to {} stream(s).",
+                self.config.anonymous_access.streams.len()
+            );
+        }
         Ok(())
     }
 
@@ -43,11 +66,26 @@ impl System {
         let session_user_id = session.get_user_id();
         if user.id != session_user_id {
             self.permissioner.get_user(session_user_id)?;
+            self.permissioner.access_user(session_user_id, user.id)?;
         }
 
         Ok(user)
     }
 
+    pub async fn check_permission(
+        &self,
+        session: &Session,
+        user_id: &Identifier,
+        action: PermissionAction,
+        stream_id: Option<u32>,
+        topic_id: Option<u32>,
+    ) -> Result<PermissionCheck, IggyError> {
+        let user = self.find_user(session, user_id).await?;
+        Ok(self
+            .permissioner
+            .check_permission(&user, action, stream_id, topic_id))
+    }
+
     pub async fn get_user(&self, user_id: &Identifier) -> Result<User, IggyError> {
         Ok(match user_id.kind {
             IdKind::Numeric => {
@@ -62,13 +100,32 @@ impl System {
                     .load_by_username(&user_id.get_cow_str_value()?)
                     .await?
             }
+            IdKind::Uuid => {
+                let uuid = user_id.get_uuid_value()?;
+                self.storage
+                    .user
+                    .load_all()
+                    .await?
+                    .into_iter()
+                    .find(|user| user.uuid == uuid)
+                    .ok_or_else(|| IggyError::ResourceNotFound(format!("users:{uuid}")))?
+            }
         })
     }
 
     pub async fn get_users(&self, session: &Session) -> Result<Vec<User>, IggyError> {
         self.ensure_authenticated(session)?;
-        self.permissioner.get_users(session.get_user_id())?;
-        self.storage.user.load_all().await
+        let session_user_id = session.get_user_id();
+        self.permissioner.get_users(session_user_id)?;
+        let users = self.storage.user.load_all().await?;
+        Ok(users
+            .into_iter()
+            .filter(|user| {
+                self.permissioner
+                    .access_user(session_user_id, user.id)
+                    .is_ok()
+            })
+            .collect())
     }
 
     pub async fn create_user(
@@ -88,11 +145,21 @@ impl System {
         }
         let user_id = USER_ID.fetch_add(1, Ordering::SeqCst);
         info!("Creating user: {username} with ID: {user_id}...");
-        let user = User::new(user_id, &username, password, status, permissions);
-        self.storage.user.save(&user).await?;
+        let mut user = User::new(user_id, &username, password, status, permissions);
+        user.tenant_id = self.permissioner.get_user_tenant(session.get_user_id());
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::CreateUser {
+                user: Box::new(user.clone()),
+            })
+            .await?;
+        let result = self.storage.user.save(&user).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
         self.permissioner.init_permissions_for_user(user);
         info!("Created user: {username} with ID: {user_id}.");
         self.metrics.increment_users(1);
+        self.publish_event(ServerEvent::UserCreated { user_id, username });
         Ok(())
     }
 
@@ -104,18 +171,32 @@ impl System {
         self.ensure_authenticated(session)?;
         self.permissioner.delete_user(session.get_user_id())?;
         let user = self.get_user(user_id).await?;
+        self.permissioner
+            .access_user(session.get_user_id(), user.id)?;
         if user.is_root() {
             error!("Cannot delete the root user.");
             return Err(IggyError::CannotDeleteUser(user.id));
         }
 
         info!("Deleting user: {} with ID: {user_id}...", user.username);
-        self.storage.user.delete(&user).await?;
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::DeleteUser { user_id: user.id })
+            .await?;
+        let result = self.storage.user.delete(&user).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
         self.permissioner.delete_permissions_for_user(user.id);
+        self.users_activity.remove(&user.id);
+        self.remove_user_from_groups(user.id).await?;
         let mut client_manager = self.client_manager.write().await;
         client_manager.delete_clients_for_user(user.id).await?;
         info!("Deleted user: {} with ID: {user_id}.", user.username);
         self.metrics.decrement_users(1);
+        self.publish_event(ServerEvent::UserDeleted {
+            user_id: user.id,
+            username: user.username.clone(),
+        });
         Ok(user)
     }
 
@@ -128,7 +209,10 @@ impl System {
     ) -> Result<User, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner.update_user(session.get_user_id())?;
-        let mut user = self.get_user(user_id).await?;
+        let user = self.get_user(user_id).await?;
+        self.permissioner
+            .access_user(session.get_user_id(), user.id)?;
+        let mut updated_user = user.clone();
         if let Some(username) = username {
             let username = text::to_lowercase_non_whitespace(&username);
             let existing_user = self.storage.user.load_by_username(&username).await;
@@ -136,18 +220,41 @@ impl System {
                 error!("User: {username} already exists.");
                 return Err(IggyError::UserAlreadyExists);
             }
-            self.storage.user.delete(&user).await?;
-            user.username = username;
+            updated_user.username = username;
         }
 
         if let Some(status) = status {
-            user.status = status;
+            // The inactivity window of the reactivated user is counted from now on.
+            if status == UserStatus::Active && !user.is_active() {
+                updated_user.last_active_at = Some(IggyTimestamp::now().to_micros());
+            }
+            updated_user.status = status;
         }
 
-        info!("Updating user: {} with ID: {}...", user.username, user.id);
-        self.storage.user.save(&user).await?;
-        info!("Updated user: {} with ID: {}.", user.username, user.id);
-        Ok(user)
+        info!(
+            "Updating user: {} with ID: {}...",
+            updated_user.username, updated_user.id
+        );
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::UpdateUser {
+                user: Box::new(updated_user.clone()),
+            })
+            .await?;
+        let result = async {
+            if updated_user.username != user.username {
+                self.storage.user.delete(&user).await?;
+            }
+            self.storage.user.save(&updated_user).await
+        }
+        .await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        info!(
+            "Updated user: {} with ID: {}.",
+            updated_user.username, updated_user.id
+        );
+        Ok(updated_user)
     }
 
     pub async fn update_permissions(
@@ -160,19 +267,33 @@ impl System {
         self.permissioner
             .update_permissions(session.get_user_id())?;
         let mut user = self.get_user(user_id).await?;
+        self.permissioner
+            .access_user(session.get_user_id(), user.id)?;
         if user.is_root() {
             error!("Cannot change the root user permissions.");
             return Err(IggyError::CannotChangePermissions(user.id));
         }
 
         user.permissions = permissions;
+        user.revoke_sessions();
         let username = user.username.clone();
         info!(
             "Updating permissions for user: {} with ID: {user_id}...",
             username
         );
-        self.storage.user.save(&user).await?;
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::UpdatePermissions {
+                user_id: user.id,
+                permissions: user.permissions.clone(),
+            })
+            .await?;
+        let result = self.storage.user.save(&user).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        let revoked_user_id = user.id;
         self.permissioner.update_permissions_for_user(user);
+        self.clear_revoked_sessions(revoked_user_id).await?;
         info!(
             "Updated permissions for user: {} with ID: {user_id}.",
             username
@@ -180,18 +301,120 @@ impl System {
         Ok(())
     }
 
+    pub async fn update_user_expiry(
+        &mut self,
+        session: &Session,
+        user_id: &Identifier,
+        expires_at: Option<u64>,
+        max_inactive_days: Option<u32>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.update_user(session.get_user_id())?;
+        let mut user = self.get_user(user_id).await?;
+        self.permissioner
+            .access_user(session.get_user_id(), user.id)?;
+        if user.is_root() {
+            error!("Cannot set the root user expiry.");
+            return Err(IggyError::CannotSetUserExpiry(user.id));
+        }
+
+        // The inactivity window is counted from now on, rather than from the user's creation or the last login long ago.
+        if max_inactive_days.is_some() && user.max_inactive_days.is_none() {
+            user.last_active_at = Some(IggyTimestamp::now().to_micros());
+        }
+        user.expires_at = expires_at;
+        user.max_inactive_days = max_inactive_days;
+        let username = user.username.clone();
+        info!("Updating expiry for user: {username} with ID: {user_id}...");
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::UpdateUser {
+                user: Box::new(user.clone()),
+            })
+            .await?;
+        let result = self.storage.user.save(&user).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        self.permissioner.update_permissions_for_user(user);
+        info!("Updated expiry for user: {username} with ID: {user_id}.");
+        Ok(())
+    }
+
+    /// Disables the active users whose expiry has passed, or who haven't been active within their inactivity window,
+    /// revokes their sessions and audits the change. Returns the number of the disabled users.
+    pub async fn disable_expired_users(&mut self) -> Result<u32, IggyError> {
+        let now = IggyTimestamp::now().to_micros();
+        let users = self.storage.user.load_all().await?;
+        let mut disabled_users_count = 0;
+        for mut user in users {
+            if user.is_root() || !user.is_active() {
+                continue;
+            }
+
+            let reason = if user.is_expired(now) {
+                "expired"
+            } else if user.exceeds_inactivity(now) {
+                "inactive"
+            } else {
+                continue;
+            };
+
+            user.status = UserStatus::Inactive;
+            user.revoke_sessions();
+            let entry_id = self
+                .metadata_log
+                .append(MetadataEntry::UpdateUser {
+                    user: Box::new(user.clone()),
+                })
+                .await?;
+            let result = self.storage.user.save(&user).await;
+            self.metadata_log.complete(entry_id).await?;
+            if let Err(error) = result {
+                error!(
+                    "Cannot disable {reason} user: {} with ID: {}. Error: {error}",
+                    user.username, user.id
+                );
+                continue;
+            }
+
+            let user_id = user.id;
+            let username = user.username.clone();
+            self.permissioner.update_permissions_for_user(user);
+            self.clear_revoked_sessions(user_id).await?;
+            info!("Disabled {reason} user: {username} with ID: {user_id}.");
+            self.audit(AuditRecord {
+                timestamp: now,
+                user_id,
+                client_id: 0,
+                address: String::new(),
+                command: format!("user_{reason}"),
+                succeeded: true,
+                error_code: None,
+            });
+            self.publish_event(ServerEvent::UserDisabled {
+                user_id,
+                username,
+                reason: reason.to_string(),
+            });
+            disabled_users_count += 1;
+        }
+
+        Ok(disabled_users_count)
+    }
+
     pub async fn change_password(
-        &self,
+        &mut self,
         session: &Session,
         user_id: &Identifier,
         current_password: &str,
         new_password: &str,
     ) -> Result<(), IggyError> {
-        self.ensure_authenticated(session)?;
+        self.ensure_authenticated_user(session)?;
         let mut user = self.get_user(user_id).await?;
         let session_user_id = session.get_user_id();
         if user.id != session_user_id {
             self.permissioner.change_password(session_user_id)?;
+            self.permissioner.access_user(session_user_id, user.id)?;
         }
 
         if !crypto::verify_password(current_password, &user.password) {
@@ -207,11 +430,30 @@ impl System {
             user.username
         );
         user.password = crypto::hash_password(new_password);
-        self.storage.user.save(&user).await?;
-        info!(
-            "Changed password for user: {} with ID: {user_id}.",
-            user.username
-        );
+        user.revoke_sessions();
+        let entry_id = self
+            .metadata_log
+            .append(MetadataEntry::ChangePassword {
+                user_id: user.id,
+                password: user.password.clone(),
+            })
+            .await?;
+        let result = self.storage.user.save(&user).await;
+        self.metadata_log.complete(entry_id).await?;
+        result?;
+        let username = user.username.clone();
+        let revoked_user_id = user.id;
+        self.permissioner.update_permissions_for_user(user);
+        self.clear_revoked_sessions(revoked_user_id).await?;
+        info!("Changed password for user: {username} with ID: {user_id}.");
+        Ok(())
+    }
+
+    /// Clears the user ID of the connected clients, whose sessions were revoked by the change of the user's session epoch.
+    async fn clear_revoked_sessions(&self, user_id: UserId) -> Result<(), IggyError> {
+        let mut client_manager = self.client_manager.write().await;
+        client_manager.clear_user_id_for_user(user_id).await?;
+        info!("Revoked the active sessions of user with ID: {user_id}.");
         Ok(())
     }
 
@@ -231,7 +473,7 @@ impl System {
         password: Option<&str>,
         session: Option<&Session>,
     ) -> Result<User, IggyError> {
-        let user = match self.storage.user.load_by_username(username).await {
+        let mut user = match self.storage.user.load_by_username(username).await {
             Ok(user) => user,
             Err(_) => {
                 error!("Cannot login user: {username} (not found).");
@@ -245,6 +487,16 @@ impl System {
             return Err(IggyError::UserInactive);
         }
 
+        // The user is rejected even if it hasn't been disabled by the expiry checker yet.
+        let now = IggyTimestamp::now().to_micros();
+        if user.is_expired(now) || user.exceeds_inactivity(now) {
+            warn!(
+                "User: {username} with ID: {} has expired or exceeded the inactivity window.",
+                user.id
+            );
+            return Err(IggyError::UserInactive);
+        }
+
         if let Some(password) = password {
             if !crypto::verify_password(password, &user.password) {
                 warn!(
@@ -255,13 +507,30 @@ impl System {
             }
         }
 
+        if let Some(session) = session {
+            self.ensure_user_address_allowed(&user, session)?;
+        }
+
+        user.last_active_at = Some(now);
+        self.storage.user.save(&user).await?;
+        self.users_activity.insert(user.id, now);
         info!("Logged in user: {username} with ID: {}.", user.id);
         if session.is_none() {
             return Ok(user);
         }
 
         let session = session.unwrap();
-        if session.is_authenticated() {
+        // The stateless session (e.g. HTTP) is used only to check the address, it's not bound to any client.
+        if session.client_id == 0 {
+            return Ok(user);
+        }
+
+        // The revoked session is already cleared by the authentication check, so there's nothing to log out.
+        // The anonymous session has no user to log out, it's simply replaced by the logged in one.
+        if session.is_authenticated()
+            && !session.is_anonymous()
+            && self.ensure_authenticated(session).is_ok()
+        {
             warn!(
                 "User: {} with ID: {} was already authenticated, removing the previous session...",
                 user.username,
@@ -270,6 +539,7 @@ impl System {
             self.logout_user(session).await?;
         }
 
+        session.set_session_epoch(user.session_epoch);
         session.set_user_id(user.id);
         let mut client_manager = self.client_manager.write().await;
         client_manager
@@ -278,8 +548,59 @@ impl System {
         Ok(user)
     }
 
+    /// Records the activity of the authenticated user, e.g. the command handled within the long-lived session
+    /// or the refreshed token, so that the user still in use doesn't exceed its inactivity window.
+    pub async fn record_user_activity(&self, user_id: UserId) -> Result<(), IggyError> {
+        if user_id == 0 || user_id == ANONYMOUS_USER_ID {
+            return Ok(());
+        }
+
+        let now = IggyTimestamp::now().to_micros();
+        if self
+            .users_activity
+            .get(&user_id)
+            .is_some_and(|saved_at| now < *saved_at + USER_ACTIVITY_SAVE_INTERVAL)
+        {
+            return Ok(());
+        }
+
+        self.users_activity.insert(user_id, now);
+        let mut user = self.storage.user.load_by_id(user_id).await?;
+        // The user already past its expiry or the inactivity window is left to be disabled.
+        if !user.is_active() || user.is_expired(now) || user.exceeds_inactivity(now) {
+            return Ok(());
+        }
+
+        user.last_active_at = Some(now);
+        if let Err(error) = self.storage.user.save(&user).await {
+            self.users_activity.remove(&user_id);
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Checks the address of the session against the rules configured for the user (if any).
+    fn ensure_user_address_allowed(&self, user: &User, session: &Session) -> Result<(), IggyError> {
+        let Some(ip_filter) = self.config.user_ip_filters.get(&user.username) else {
+            return Ok(());
+        };
+
+        let address = session.ip_address;
+        if ip_filter.is_allowed(&address.ip()) {
+            return Ok(());
+        }
+
+        warn!(
+            "User: {} with ID: {} cannot log in from address: {address}, it's not allowed.",
+            user.username, user.id
+        );
+        self.audit_rejected_address(user.id, session.client_id, &address, "login_rejected");
+        Err(IggyError::AddressNotAllowed(address.ip().to_string()))
+    }
+
     pub async fn logout_user(&self, session: &Session) -> Result<(), IggyError> {
-        self.ensure_authenticated(session)?;
+        self.ensure_authenticated_user(session)?;
         let user = self
             .get_user(&Identifier::numeric(session.get_user_id())?)
             .await?;
@@ -0,0 +1,135 @@
+use crate::streaming::storage::{Storage, TenantStorage};
+use crate::streaming::tenants::tenant::Tenant;
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use sled::Db;
+use std::sync::Arc;
+use tracing::info;
+
+const KEY_PREFIX: &str = "tenants";
+
+#[derive(Debug)]
+pub struct FileTenantStorage {
+    db: Arc<Db>,
+}
+
+impl FileTenantStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FileTenantStorage {}
+unsafe impl Sync for FileTenantStorage {}
+
+#[async_trait]
+impl TenantStorage for FileTenantStorage {
+    async fn load_all(&self) -> Result<Vec<Tenant>, IggyError> {
+        let mut tenants = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+            let tenant = match data.with_context(|| {
+                format!(
+                    "Failed to load tenant, when searching for key: {}",
+                    KEY_PREFIX
+                )
+            }) {
+                Ok((_, value)) => match rmp_serde::from_slice::<Tenant>(&value).with_context(|| {
+                    format!(
+                        "Failed to deserialize tenant, when searching for key: {}",
+                        KEY_PREFIX
+                    )
+                }) {
+                    Ok(tenant) => tenant,
+                    Err(err) => {
+                        return Err(IggyError::CannotDeserializeResource(err));
+                    }
+                },
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            };
+            tenants.push(tenant);
+        }
+
+        Ok(tenants)
+    }
+}
+
+#[async_trait]
+impl Storage<Tenant> for FileTenantStorage {
+    async fn load(&self, tenant: &mut Tenant) -> Result<(), IggyError> {
+        let key = get_key(tenant.id);
+        let tenant_data = match self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load tenant with key: {}", key))
+        {
+            Ok(data) => {
+                if let Some(tenant_data) = data {
+                    tenant_data
+                } else {
+                    return Err(IggyError::ResourceNotFound(key));
+                }
+            }
+            Err(err) => {
+                return Err(IggyError::CannotLoadResource(err));
+            }
+        };
+
+        match rmp_serde::from_slice::<Tenant>(&tenant_data)
+            .with_context(|| format!("Failed to deserialize tenant with key: {}", key))
+        {
+            Ok(tenant_data) => {
+                *tenant = tenant_data;
+                Ok(())
+            }
+            Err(err) => Err(IggyError::CannotDeserializeResource(err)),
+        }
+    }
+
+    async fn save(&self, tenant: &Tenant) -> Result<(), IggyError> {
+        let key = get_key(tenant.id);
+        match rmp_serde::to_vec(&tenant)
+            .with_context(|| format!("Failed to serialize tenant with key: {}", key))
+        {
+            Ok(data) => {
+                if let Err(err) = self
+                    .db
+                    .insert(&key, data)
+                    .with_context(|| format!("Failed to insert tenant with key: {}", key))
+                {
+                    return Err(IggyError::CannotSaveResource(err));
+                }
+            }
+            Err(err) => {
+                return Err(IggyError::CannotSerializeResource(err));
+            }
+        }
+
+        info!(
+            "Saved tenant with ID: {}, name: {}.",
+            tenant.id, tenant.name
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, tenant: &Tenant) -> Result<(), IggyError> {
+        info!("Deleting tenant with ID: {}...", tenant.id);
+        let key = get_key(tenant.id);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete tenant with key: {}", key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        info!("Deleted tenant with ID: {}.", tenant.id);
+        Ok(())
+    }
+}
+
+fn get_key(tenant_id: u32) -> String {
+    format!("{}:{}", KEY_PREFIX, tenant_id)
+}
@@ -0,0 +1,58 @@
+use iggy::models::tenant::TenantQuotas;
+use iggy::tenants::TENANT_NAME_SEPARATOR;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Tenant {
+    pub id: u32,
+    pub name: String,
+    pub quotas: TenantQuotas,
+    pub created_at: u64,
+}
+
+/// The resources currently used by the tenant, checked against its quotas.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TenantUsage {
+    pub streams_count: u32,
+    pub topics_count: u32,
+    pub partitions_count: u32,
+    pub size_bytes: u64,
+    pub users_count: u32,
+}
+
+impl Tenant {
+    pub fn new(id: u32, name: &str, quotas: TenantQuotas) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            quotas,
+            created_at: IggyTimestamp::now().to_micros(),
+        }
+    }
+
+    /// Returns the name of the stream scoped by the tenant name, e.g. `orders` becomes `team.orders` for the tenant `team`.
+    /// The name which is already scoped is returned as it is.
+    pub fn get_stream_name(&self, name: &str) -> String {
+        let prefix = format!("{}{TENANT_NAME_SEPARATOR}", self.name);
+        if name.starts_with(&prefix) {
+            return name.to_string();
+        }
+
+        format!("{prefix}{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_name_should_be_scoped_by_tenant_name_only_once() {
+        let tenant = Tenant::new(1, "team", TenantQuotas::default());
+
+        assert_eq!(tenant.get_stream_name("orders"), "team.orders");
+        assert_eq!(tenant.get_stream_name("team.orders"), "team.orders");
+        assert_eq!(tenant.get_stream_name("teams.orders"), "team.teams.orders");
+    }
+}
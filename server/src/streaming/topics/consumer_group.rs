@@ -1,4 +1,5 @@
 use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
 use tokio::sync::RwLock;
 use tracing::trace;
@@ -9,6 +10,8 @@ pub struct ConsumerGroup {
     pub consumer_group_id: u32,
     pub name: String,
     pub partitions_count: u32,
+    /// The timestamp (in microseconds) of the group creation or the latest member joining or leaving it.
+    pub last_activity_at: u64,
     members: HashMap<u32, RwLock<ConsumerGroupMember>>,
 }
 
@@ -32,6 +35,7 @@ impl ConsumerGroup {
             consumer_group_id,
             name: name.to_string(),
             partitions_count,
+            last_activity_at: IggyTimestamp::now().to_micros(),
             members: HashMap::new(),
         }
     }
@@ -40,6 +44,10 @@ impl ConsumerGroup {
         self.members.values().collect()
     }
 
+    pub fn has_member(&self, member_id: u32) -> bool {
+        self.members.contains_key(&member_id)
+    }
+
     pub async fn reassign_partitions(&mut self, partitions_count: u32) {
         self.partitions_count = partitions_count;
         self.assign_partitions().await;
@@ -79,6 +87,7 @@ impl ConsumerGroup {
                 current_partition_id: 0,
             }),
         );
+        self.last_activity_at = IggyTimestamp::now().to_micros();
         trace!(
             "Added member with ID: {} to consumer group: {} for topic with ID: {}",
             member_id,
@@ -90,6 +99,7 @@ impl ConsumerGroup {
 
     pub async fn delete_member(&mut self, member_id: u32) {
         if self.members.remove(&member_id).is_some() {
+            self.last_activity_at = IggyTimestamp::now().to_micros();
             trace!(
                 "Deleted member with ID: {} in consumer group: {} for topic with ID: {}",
                 member_id,
@@ -164,6 +174,7 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            last_activity_at: 0,
             members: HashMap::new(),
         };
 
@@ -185,6 +196,7 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            last_activity_at: 0,
             members: HashMap::new(),
         };
 
@@ -210,6 +222,7 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            last_activity_at: 0,
             members: HashMap::new(),
         };
 
@@ -247,6 +260,7 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 1,
+            last_activity_at: 0,
             members: HashMap::new(),
         };
 
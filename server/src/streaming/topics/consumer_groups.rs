@@ -3,6 +3,7 @@ use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::utils::text;
+use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::info;
 
@@ -23,24 +24,26 @@ impl Topic {
         }
     }
 
-    pub fn get_consumer_groups(&self) -> Vec<&RwLock<ConsumerGroup>> {
-        self.consumer_groups.values().collect()
+    pub fn get_consumer_groups(&self) -> Vec<Arc<RwLock<ConsumerGroup>>> {
+        self.consumer_groups.values().cloned().collect()
     }
 
     pub fn get_consumer_group(
         &self,
         identifier: &Identifier,
-    ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
+    ) -> Result<Arc<RwLock<ConsumerGroup>>, IggyError> {
         match identifier.kind {
             IdKind::Numeric => self.get_consumer_group_by_id(identifier.get_u32_value().unwrap()),
             IdKind::String => self.get_consumer_group_by_name(&identifier.get_cow_str_value()?),
+            // Only the streams, topics and users have the UUIDs.
+            IdKind::Uuid => Err(IggyError::InvalidConsumerGroupId),
         }
     }
 
     pub fn get_consumer_group_by_name(
         &self,
         name: &str,
-    ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
+    ) -> Result<Arc<RwLock<ConsumerGroup>>, IggyError> {
         let consumer_group_id = self.consumer_groups_ids.get(name);
         if consumer_group_id.is_none() {
             return Err(IggyError::ConsumerGroupNameNotFound(
@@ -52,13 +55,16 @@ impl Topic {
         self.get_consumer_group_by_id(*consumer_group_id.unwrap())
     }
 
-    pub fn get_consumer_group_by_id(&self, id: u32) -> Result<&RwLock<ConsumerGroup>, IggyError> {
+    pub fn get_consumer_group_by_id(
+        &self,
+        id: u32,
+    ) -> Result<Arc<RwLock<ConsumerGroup>>, IggyError> {
         let consumer_group = self.consumer_groups.get(&id);
         if consumer_group.is_none() {
             return Err(IggyError::ConsumerGroupIdNotFound(id, self.topic_id));
         }
 
-        Ok(consumer_group.unwrap())
+        Ok(consumer_group.unwrap().clone())
     }
 
     pub async fn create_consumer_group(&mut self, id: u32, name: &str) -> Result<(), IggyError> {
@@ -66,6 +72,17 @@ impl Topic {
             return Err(IggyError::ConsumerGroupIdAlreadyExists(id, self.topic_id));
         }
 
+        if !self
+            .consumer_limits
+            .allows_consumer_group(self.consumer_groups.len() as u32)
+        {
+            return Err(IggyError::ConsumerGroupsLimitReached(
+                self.topic_id,
+                self.stream_id,
+                self.consumer_limits.max_consumer_groups.unwrap_or_default(),
+            ));
+        }
+
         let name = text::to_lowercase_non_whitespace(name);
         if self.consumer_groups_ids.contains_key(&name) {
             return Err(IggyError::ConsumerGroupNameAlreadyExists(
@@ -76,7 +93,8 @@ impl Topic {
 
         let consumer_group =
             ConsumerGroup::new(self.topic_id, id, &name, self.partitions.len() as u32);
-        self.consumer_groups.insert(id, RwLock::new(consumer_group));
+        self.consumer_groups
+            .insert(id, Arc::new(RwLock::new(consumer_group)));
         self.consumer_groups_ids.insert(name, id);
         let consumer_group = self.get_consumer_group_by_id(id)?;
         let consumer_group = consumer_group.read().await;
@@ -94,7 +112,7 @@ impl Topic {
     pub async fn delete_consumer_group(
         &mut self,
         id: &Identifier,
-    ) -> Result<RwLock<ConsumerGroup>, IggyError> {
+    ) -> Result<Arc<RwLock<ConsumerGroup>>, IggyError> {
         let consumer_group_id;
         {
             let consumer_group = self.get_consumer_group(id)?;
@@ -132,7 +150,24 @@ impl Topic {
     ) -> Result<(), IggyError> {
         let consumer_group = self.get_consumer_group(consumer_group_id)?;
         let mut consumer_group = consumer_group.write().await;
+        // The member which has already joined the group can join it again regardless of the limit.
+        if !consumer_group.has_member(member_id)
+            && !self
+                .consumer_limits
+                .allows_group_member(consumer_group.get_members().len() as u32)
+        {
+            return Err(IggyError::ConsumerGroupMembersLimitReached(
+                consumer_group.consumer_group_id,
+                self.topic_id,
+                self.consumer_limits.max_group_members.unwrap_or_default(),
+            ));
+        }
+
         consumer_group.add_member(member_id).await;
+        self.storage
+            .topic
+            .save_consumer_group(self, &consumer_group)
+            .await?;
         info!(
             "Member with ID: {} has joined consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
             member_id, consumer_group_id, self.topic_id, self.stream_id
@@ -148,18 +183,78 @@ impl Topic {
         let consumer_group = self.get_consumer_group(consumer_group_id)?;
         let mut consumer_group = consumer_group.write().await;
         consumer_group.delete_member(member_id).await;
+        self.storage
+            .topic
+            .save_consumer_group(self, &consumer_group)
+            .await?;
         info!(
             "Member with ID: {} has left consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
             member_id, consumer_group_id, self.topic_id, self.stream_id
         );
         Ok(())
     }
+
+    /// Returns the timestamp of the latest offset commit of the consumer group in any partition,
+    /// if it has committed any offset.
+    pub async fn get_consumer_group_last_commit(&self, consumer_group_id: u32) -> Option<u64> {
+        let mut last_commit = None;
+        for partition in self.partitions.values() {
+            let committed_at = partition
+                .read()
+                .await
+                .get_consumer_group_committed_at(consumer_group_id);
+            last_commit = last_commit.max(committed_at);
+        }
+        last_commit
+    }
+
+    /// Deletes the offsets committed by the consumer group in all the partitions, returns the number of the deleted offsets.
+    pub async fn delete_consumer_group_offsets(
+        &self,
+        consumer_group_id: u32,
+    ) -> Result<u32, IggyError> {
+        let mut deleted_count = 0;
+        for partition in self.partitions.values() {
+            if partition
+                .read()
+                .await
+                .delete_consumer_group_offset(consumer_group_id)
+                .await?
+            {
+                deleted_count += 1;
+            }
+        }
+        Ok(deleted_count)
+    }
+
+    /// Deletes the offsets of the inactive consumer group, while its latest activity (including the deleted commits)
+    /// is kept, so that the retention of the group itself is counted from it. Returns the number of the deleted offsets.
+    pub async fn expire_consumer_group_offsets(
+        &self,
+        consumer_group: &mut ConsumerGroup,
+        last_activity_at: u64,
+    ) -> Result<u32, IggyError> {
+        let deleted_count = self
+            .delete_consumer_group_offsets(consumer_group.consumer_group_id)
+            .await?;
+        consumer_group.last_activity_at = last_activity_at;
+        self.storage
+            .topic
+            .save_consumer_group(self, consumer_group)
+            .await?;
+        info!(
+            "Expired {} offset(s) of consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
+            deleted_count, consumer_group.consumer_group_id, self.topic_id, self.stream_id
+        );
+        Ok(deleted_count)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::configs::system::SystemConfig;
+    use crate::streaming::polling_consumer::PollingConsumer;
     use crate::streaming::storage::tests::get_test_system_storage;
     use std::sync::atomic::AtomicU64;
     use std::sync::Arc;
@@ -266,9 +361,8 @@ mod tests {
         assert!(result.is_ok());
         let consumer_group = topic
             .get_consumer_group(&Identifier::numeric(consumer_group_id).unwrap())
-            .unwrap()
-            .read()
-            .await;
+            .unwrap();
+        let consumer_group = consumer_group.read().await;
         let members = consumer_group.get_members();
         assert_eq!(members.len(), 1);
     }
@@ -293,13 +387,83 @@ mod tests {
         assert!(result.is_ok());
         let consumer_group = topic
             .get_consumer_group(&Identifier::numeric(consumer_group_id).unwrap())
-            .unwrap()
-            .read()
-            .await;
+            .unwrap();
+        let consumer_group = consumer_group.read().await;
         let members = consumer_group.get_members();
         assert!(members.is_empty())
     }
 
+    #[tokio::test]
+    async fn should_not_be_created_given_consumer_groups_limit_reached() {
+        let mut topic = get_topic();
+        topic.consumer_limits.max_consumer_groups = Some(1);
+        topic.create_consumer_group(1, "test1").await.unwrap();
+
+        let result = topic.create_consumer_group(2, "test2").await;
+
+        assert!(matches!(
+            result,
+            Err(IggyError::ConsumerGroupsLimitReached(_, _, 1))
+        ));
+        assert_eq!(topic.consumer_groups.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_be_joined_by_new_member_given_group_members_limit_reached() {
+        let consumer_group_id = Identifier::numeric(1).unwrap();
+        let mut topic = get_topic();
+        topic.consumer_limits.max_group_members = Some(1);
+        topic.create_consumer_group(1, "test").await.unwrap();
+        topic
+            .join_consumer_group(&consumer_group_id, 1)
+            .await
+            .unwrap();
+
+        let result = topic.join_consumer_group(&consumer_group_id, 2).await;
+
+        assert!(matches!(
+            result,
+            Err(IggyError::ConsumerGroupMembersLimitReached(1, _, 1))
+        ));
+        assert!(topic
+            .join_consumer_group(&consumer_group_id, 1)
+            .await
+            .is_ok());
+        let consumer_group = topic.get_consumer_group(&consumer_group_id).unwrap();
+        let consumer_group = consumer_group.read().await;
+        assert_eq!(consumer_group.get_members().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_delete_offsets_committed_by_consumer_group_in_all_partitions() {
+        let consumer_group_id = 1;
+        let topic = get_topic();
+        assert!(topic
+            .get_consumer_group_last_commit(consumer_group_id)
+            .await
+            .is_none());
+        for partition in topic.get_partitions() {
+            let partition = partition.read().await;
+            let consumer = PollingConsumer::ConsumerGroup(consumer_group_id, 1);
+            partition.store_consumer_offset(consumer, 0).await.unwrap();
+        }
+
+        let last_commit = topic
+            .get_consumer_group_last_commit(consumer_group_id)
+            .await;
+        let deleted_count = topic
+            .delete_consumer_group_offsets(consumer_group_id)
+            .await
+            .unwrap();
+
+        assert!(last_commit.is_some());
+        assert_eq!(deleted_count, topic.get_partitions_count());
+        assert!(topic
+            .get_consumer_group_last_commit(consumer_group_id)
+            .await
+            .is_none());
+    }
+
     fn get_topic() -> Topic {
         let storage = Arc::new(get_test_system_storage());
         let stream_id = 1;
@@ -0,0 +1,115 @@
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Message;
+use jsonschema::JSONSchema;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// The JSON Schema configured for the topic, compiled once and used to validate the payloads of the appended messages.
+#[derive(Clone)]
+pub struct MessageSchema {
+    schema: String,
+    compiled_schema: Arc<JSONSchema>,
+}
+
+impl MessageSchema {
+    pub fn compile(schema: &str) -> Result<Self, IggyError> {
+        let value = serde_json::from_str::<serde_json::Value>(schema)
+            .map_err(|error| IggyError::InvalidMessageSchema(error.to_string()))?;
+        let compiled_schema = JSONSchema::compile(&value)
+            .map_err(|error| IggyError::InvalidMessageSchema(error.to_string()))?;
+        Ok(Self {
+            schema: schema.to_string(),
+            compiled_schema: Arc::new(compiled_schema),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.schema
+    }
+
+    /// Validates the payload of each message, the first invalid one fails the whole batch.
+    pub fn validate(&self, messages: &[Message]) -> Result<(), IggyError> {
+        for message in messages {
+            let payload = serde_json::from_slice::<serde_json::Value>(&message.payload)
+                .map_err(|error| IggyError::MessageSchemaMismatch(message.id, error.to_string()))?;
+            let reason = match self.compiled_schema.validate(&payload) {
+                Ok(_) => continue,
+                Err(mut errors) => errors
+                    .next()
+                    .map(|error| format!("{error} at path: '{}'", error.instance_path))
+                    .unwrap_or_default(),
+            };
+            return Err(IggyError::MessageSchemaMismatch(message.id, reason));
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for MessageSchema {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessageSchema")
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+
+    const SCHEMA: &str = r#"{
+        "type": "object",
+        "properties": { "amount": { "type": "number" } },
+        "required": ["amount"]
+    }"#;
+
+    #[test]
+    fn should_accept_messages_matching_the_schema() {
+        let schema = MessageSchema::compile(SCHEMA).unwrap();
+        let messages = vec![Message::new(
+            Some(1),
+            Bytes::from(r#"{"amount": 10}"#),
+            None,
+        )];
+
+        assert!(schema.validate(&messages).is_ok());
+    }
+
+    #[test]
+    fn should_reject_messages_not_matching_the_schema() {
+        let schema = MessageSchema::compile(SCHEMA).unwrap();
+        let messages = vec![
+            Message::new(Some(1), Bytes::from(r#"{"amount": 10}"#), None),
+            Message::new(Some(2), Bytes::from(r#"{"amount": "ten"}"#), None),
+        ];
+
+        let result = schema.validate(&messages);
+
+        assert!(matches!(
+            result,
+            Err(IggyError::MessageSchemaMismatch(2, _))
+        ));
+    }
+
+    #[test]
+    fn should_reject_messages_with_payload_not_being_json() {
+        let schema = MessageSchema::compile(SCHEMA).unwrap();
+        let messages = vec![Message::new(Some(1), Bytes::from("amount: 10"), None)];
+
+        let result = schema.validate(&messages);
+
+        assert!(matches!(
+            result,
+            Err(IggyError::MessageSchemaMismatch(1, _))
+        ));
+    }
+
+    #[test]
+    fn should_not_compile_invalid_schema() {
+        let result = MessageSchema::compile(r#"{"type": "unknown"}"#);
+
+        assert!(matches!(result, Err(IggyError::InvalidMessageSchema(_))));
+    }
+}
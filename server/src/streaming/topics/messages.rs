@@ -1,17 +1,23 @@
+use crate::runtimes::PartitionShards;
 use crate::streaming::models::messages::PolledMessages;
+use crate::streaming::partitions::messages::AppendedMessages;
+use crate::streaming::partitions::writer::PartitionWriter;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::utils::file::folder_size;
 use crate::streaming::utils::hash;
 use iggy::error::IggyError;
+use iggy::messages::headers_filter::HeadersFilter;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
-use iggy::messages::send_messages::{Partitioning, PartitioningKind};
+use iggy::messages::send_messages::{Acks, Partitioning, PartitioningKind};
 use iggy::models::messages::Message;
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tracing::{info, trace, warn};
 
+const FILTERED_MESSAGES_BATCH_SIZE: u32 = 1000;
+
 impl Topic {
     pub fn get_messages_count(&self) -> u64 {
         self.messages_count.load(Ordering::SeqCst)
@@ -52,20 +58,201 @@ impl Topic {
             messages,
             partition_id,
             current_offset: partition.current_offset,
+            next_offset: None,
+        })
+    }
+
+    /// Returns up to `count` messages matching the headers filter, starting from the given strategy.
+    /// The messages are scanned in batches until enough of them are found or the end of the partition is reached,
+    /// except for the `Last` strategy, which only filters the last `count` messages.
+    /// The offset of the last scanned message is returned as well, so that it can be stored as the consumer offset.
+    pub async fn get_filtered_messages(
+        &self,
+        consumer: PollingConsumer,
+        partition_id: u32,
+        strategy: PollingStrategy,
+        count: u32,
+        filter: &HeadersFilter,
+    ) -> Result<(PolledMessages, Option<u64>), IggyError> {
+        if !self.has_partitions() {
+            return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
+        }
+
+        let partition = self.partitions.get(&partition_id);
+        if partition.is_none() {
+            return Err(IggyError::PartitionNotFound(
+                partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
+        let partition = partition.unwrap();
+        let partition = partition.read().await;
+        let batch_size = count.max(FILTERED_MESSAGES_BATCH_SIZE);
+        let value = strategy.value;
+        let mut batch = match strategy.kind {
+            PollingKind::Offset => partition.get_messages_by_offset(value, batch_size).await,
+            PollingKind::Timestamp => partition.get_messages_by_timestamp(value, batch_size).await,
+            PollingKind::First => partition.get_first_messages(batch_size).await,
+            PollingKind::Last => partition.get_last_messages(count).await,
+            PollingKind::Next => partition.get_next_messages(consumer, batch_size).await,
+        }?;
+
+        let mut messages = Vec::new();
+        let mut last_scanned_offset = None;
+        while let Some(last_message) = batch.last() {
+            let last_offset = last_message.offset;
+            for message in batch {
+                if messages.len() == count as usize {
+                    break;
+                }
+
+                last_scanned_offset = Some(message.offset);
+                if filter.matches(&message.headers) {
+                    messages.push(message);
+                }
+            }
+
+            if messages.len() == count as usize
+                || strategy.kind == PollingKind::Last
+                || last_offset >= partition.current_offset
+            {
+                break;
+            }
+
+            batch = partition
+                .get_messages_by_offset(last_offset + 1, batch_size)
+                .await?;
+        }
+
+        trace!(
+            "Found {} message(s) matching the filter: {} in partition with ID: {}, last scanned offset: {:?}",
+            messages.len(),
+            filter,
+            partition_id,
+            last_scanned_offset
+        );
+        Ok((
+            PolledMessages {
+                messages,
+                partition_id,
+                current_offset: partition.current_offset,
+                next_offset: None,
+            },
+            last_scanned_offset,
+        ))
+    }
+
+    /// Returns up to `count` messages (optionally matching the headers filter) read backward,
+    /// starting from the given offset (or the last one for the `Last` strategy), in the descending order of their offsets.
+    /// The messages are scanned in batches until enough of them are found or the start of the partition is reached.
+    pub async fn get_messages_backward(
+        &self,
+        partition_id: u32,
+        strategy: PollingStrategy,
+        count: u32,
+        filter: Option<&HeadersFilter>,
+    ) -> Result<PolledMessages, IggyError> {
+        if !self.has_partitions() {
+            return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
+        }
+
+        let partition = self.partitions.get(&partition_id);
+        if partition.is_none() {
+            return Err(IggyError::PartitionNotFound(
+                partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
+        let partition = partition.unwrap();
+        let partition = partition.read().await;
+        let start_offset = match strategy.kind {
+            PollingKind::Offset => strategy.value.min(partition.current_offset),
+            PollingKind::Last => partition.current_offset,
+            kind => {
+                return Err(IggyError::InvalidPollingMode(format!(
+                    "{kind} strategy is not supported for reading the messages backward"
+                )))
+            }
+        };
+
+        let batch_size = match filter {
+            Some(_) => count.max(FILTERED_MESSAGES_BATCH_SIZE),
+            None => count,
+        };
+        let mut messages = Vec::new();
+        let mut end_offset = start_offset;
+        loop {
+            let batch_start_offset = end_offset.saturating_sub(batch_size as u64 - 1);
+            let batch = partition
+                .get_messages_by_offset(
+                    batch_start_offset,
+                    (end_offset - batch_start_offset + 1) as u32,
+                )
+                .await?;
+            let Some(first_message) = batch.first() else {
+                break;
+            };
+
+            let first_offset = first_message.offset;
+            for message in batch.into_iter().rev() {
+                if messages.len() == count as usize {
+                    break;
+                }
+
+                if message.offset > end_offset {
+                    continue;
+                }
+
+                if filter.is_some_and(|filter| !filter.matches(&message.headers)) {
+                    continue;
+                }
+
+                messages.push(message);
+            }
+
+            if messages.len() == count as usize || first_offset == 0 {
+                break;
+            }
+
+            end_offset = first_offset - 1;
+        }
+
+        trace!(
+            "Found {} message(s) reading backward from offset: {} in partition with ID: {}",
+            messages.len(),
+            start_offset,
+            partition_id
+        );
+        Ok(PolledMessages {
+            messages,
+            partition_id,
+            current_offset: partition.current_offset,
+            next_offset: None,
         })
     }
 
+    /// Appends the messages to the partition chosen by the partitioning, there are no appended messages returned for `Acks::None`.
     pub async fn append_messages(
         &self,
         partitioning: &Partitioning,
         messages: Vec<Message>,
-    ) -> Result<(), IggyError> {
+        acks: Acks,
+        partition_shards: Option<&PartitionShards>,
+    ) -> Result<Option<AppendedMessages>, IggyError> {
+        if self.frozen {
+            return Err(IggyError::TopicFrozen(self.topic_id, self.stream_id));
+        }
+
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
         }
 
         if messages.is_empty() {
-            return Ok(());
+            return Ok(Some(AppendedMessages::default()));
         }
 
         let partition_id = match partitioning.kind {
@@ -78,7 +265,7 @@ impl Topic {
             }
         };
 
-        self.append_messages_to_partition(partition_id, messages)
+        self.append_messages_to_partition(partition_id, messages, acks, partition_shards)
             .await
     }
 
@@ -86,20 +273,41 @@ impl Topic {
         &self,
         partition_id: u32,
         messages: Vec<Message>,
-    ) -> Result<(), IggyError> {
-        let partition = self.partitions.get(&partition_id);
-        if partition.is_none() {
+        acks: Acks,
+        partition_shards: Option<&PartitionShards>,
+    ) -> Result<Option<AppendedMessages>, IggyError> {
+        // The messages are appended by the single writer of the partition, rather than under its lock taken by each producer.
+        let writer = self.get_partition_writer(partition_id, partition_shards)?;
+        writer.append(messages, acks).await
+    }
+
+    /// Returns the writer of the partition, which is spawned on the first use.
+    pub(crate) fn get_partition_writer(
+        &self,
+        partition_id: u32,
+        partition_shards: Option<&PartitionShards>,
+    ) -> Result<PartitionWriter, IggyError> {
+        let Some(partition) = self.partitions.get(&partition_id) else {
             return Err(IggyError::PartitionNotFound(
                 partition_id,
                 self.topic_id,
                 self.stream_id,
             ));
-        }
+        };
 
-        let partition = partition.unwrap();
-        let mut partition = partition.write().await;
-        partition.append_messages(messages).await?;
-        Ok(())
+        Ok(self
+            .partition_writers
+            .entry(partition_id)
+            .or_insert_with(|| {
+                PartitionWriter::spawn(
+                    self.stream_id,
+                    self.topic_id,
+                    partition_id,
+                    partition.clone(),
+                    partition_shards,
+                )
+            })
+            .clone())
     }
 
     fn get_next_partition_id(&self) -> u32 {
@@ -115,19 +323,7 @@ impl Topic {
     }
 
     fn calculate_partition_id_by_messages_key_hash(&self, messages_key: &[u8]) -> u32 {
-        let messages_key_hash = hash::calculate_32(messages_key);
-        let partitions_count = self.get_partitions_count();
-        let mut partition_id = messages_key_hash % partitions_count;
-        if partition_id == 0 {
-            partition_id = partitions_count;
-        }
-        trace!(
-            "Calculated partition ID: {} for messages key: {:?}, hash: {}",
-            partition_id,
-            messages_key,
-            messages_key_hash
-        );
-        partition_id
+        calculate_partition_id_by_messages_key_hash(messages_key, self.get_partitions_count())
     }
 
     pub(crate) async fn load_messages_from_disk_to_cache(&mut self) -> Result<(), IggyError> {
@@ -250,6 +446,25 @@ impl Topic {
     }
 }
 
+/// Returns the partition which the messages key is mapped to for the given partitions count.
+pub(crate) fn calculate_partition_id_by_messages_key_hash(
+    messages_key: &[u8],
+    partitions_count: u32,
+) -> u32 {
+    let messages_key_hash = hash::calculate_32(messages_key);
+    let mut partition_id = messages_key_hash % partitions_count;
+    if partition_id == 0 {
+        partition_id = partitions_count;
+    }
+    trace!(
+        "Calculated partition ID: {} for messages key: {:?}, hash: {}",
+        partition_id,
+        messages_key,
+        messages_key_hash
+    );
+    partition_id
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,7 +494,7 @@ mod tests {
                 None,
             )];
             topic
-                .append_messages(&partitioning, messages)
+                .append_messages(&partitioning, messages, Acks::Leader, None)
                 .await
                 .unwrap();
         }
@@ -315,7 +530,7 @@ mod tests {
                 None,
             )];
             topic
-                .append_messages(&partitioning, messages)
+                .append_messages(&partitioning, messages, Acks::Leader, None)
                 .await
                 .unwrap();
         }
@@ -333,6 +548,30 @@ mod tests {
         assert_eq!(read_messages_count, messages_count as usize);
     }
 
+    #[tokio::test]
+    async fn given_frozen_topic_messages_should_not_be_appended() {
+        let partitioning = Partitioning::partition_id(1);
+        let mut topic = init_topic(1);
+        topic.frozen = true;
+        let messages = vec![Message::empty(
+            1,
+            MessageState::Available,
+            1,
+            Bytes::from("test"),
+            1,
+            None,
+        )];
+
+        let result = topic
+            .append_messages(&partitioning, messages, Acks::Leader, None)
+            .await;
+
+        assert!(matches!(result, Err(IggyError::TopicFrozen(_, _))));
+        let partition = topic.get_partition(1).unwrap();
+        let partition = partition.read().await;
+        assert!(partition.cache.as_ref().unwrap().is_empty());
+    }
+
     #[test]
     fn given_multiple_partitions_calculate_next_partition_id_should_return_next_partition_id_using_round_robin(
     ) {
@@ -1,9 +1,11 @@
 pub mod consumer_group;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod message_schema;
 pub mod messages;
 pub mod partitions;
 pub mod persistence;
+pub mod repartitioning;
 pub mod segments;
 pub mod storage;
 pub mod topic;
@@ -1,10 +1,18 @@
+use crate::runtimes::PartitionShards;
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::writer::PartitionWriter;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
+use iggy::messages::send_messages::Acks;
+use iggy::models::messages::Message;
+use iggy::partitions::delete_partitions::DeletePartitionsMode;
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use tracing::info;
 
 const MAX_PARTITIONS_COUNT: u32 = 100_000;
+/// The maximum number of the messages read from the drained partition and appended to the target partition at once.
+const DRAIN_BATCH_SIZE: u32 = 1000;
 
 impl Topic {
     pub fn has_partitions(&self) -> bool {
@@ -61,6 +69,8 @@ impl Topic {
     pub async fn delete_persisted_partitions(
         &mut self,
         mut count: u32,
+        mode: DeletePartitionsMode,
+        partition_shards: Option<&PartitionShards>,
     ) -> Result<Option<DeletedPartitions>, IggyError> {
         if count == 0 {
             return Ok(None);
@@ -71,12 +81,43 @@ impl Topic {
             count = current_partitions_count;
         }
 
+        let remaining_partitions_count = current_partitions_count - count;
+        let deleted_partition_ids = remaining_partitions_count + 1..=current_partitions_count;
+        // Without any remaining partitions there's nowhere to drain the messages to.
+        if mode == DeletePartitionsMode::Refuse
+            || (mode == DeletePartitionsMode::Drain && remaining_partitions_count == 0)
+        {
+            for partition_id in deleted_partition_ids.clone() {
+                let partition = self.partitions.get(&partition_id).unwrap();
+                let messages_count = partition.read().await.get_messages_count();
+                if messages_count > 0 {
+                    return Err(IggyError::PartitionNotEmpty(partition_id, messages_count));
+                }
+            }
+        }
+
         let mut segments_count = 0;
         let mut messages_count = 0;
-        for partition_id in current_partitions_count - count + 1..=current_partitions_count {
+        let mut drained_partitions = Vec::new();
+        for partition_id in deleted_partition_ids {
+            self.partition_writers.remove(&partition_id);
             let partition = self.partitions.remove(&partition_id).unwrap();
             let partition = partition.read().await;
-            let partition_messages_count = partition.get_messages_count();
+            let mut partition_messages_count = partition.get_messages_count();
+            if mode == DeletePartitionsMode::Drain && partition_messages_count > 0 {
+                let target_partition_id = (partition_id - 1) % remaining_partitions_count + 1;
+                let target_partition = self.partitions.get(&target_partition_id).unwrap();
+                let target_writer =
+                    self.get_partition_writer(target_partition_id, partition_shards)?;
+                let drained_messages_count =
+                    drain_partition(&partition, target_partition, &target_writer).await?;
+                partition_messages_count -= drained_messages_count.min(partition_messages_count);
+                drained_partitions.push(DrainedPartition {
+                    partition_id,
+                    target_partition_id,
+                    messages_count: drained_messages_count,
+                });
+            }
             segments_count += partition.get_segments_count();
             messages_count += partition_messages_count;
             partition.delete().await?;
@@ -84,11 +125,84 @@ impl Topic {
         Ok(Some(DeletedPartitions {
             segments_count,
             messages_count,
+            drained_partitions,
         }))
     }
 }
 
+/// Appends all the messages of the partition to the target partition in batches (keeping their order, IDs and timestamps),
+/// and then moves the consumer offsets, so the consumers continue from the corresponding offsets in the target partition.
+/// The batches are appended by the writer of the target partition, after the messages already queued by the producers.
+async fn drain_partition(
+    source: &Partition,
+    target: &Arc<RwLock<Partition>>,
+    target_writer: &PartitionWriter,
+) -> Result<u64, IggyError> {
+    let Some(first_segment) = source.get_segments().first() else {
+        return Ok(0);
+    };
+
+    let first_offset = first_segment.start_offset;
+    let mut base_offset = None;
+    let mut offset = first_offset;
+    let mut drained_messages_count = 0;
+    while offset <= source.current_offset {
+        let messages = source
+            .get_messages_by_offset(offset, DRAIN_BATCH_SIZE)
+            .await?;
+        let Some(last_message) = messages.last() else {
+            break;
+        };
+
+        offset = last_message.offset + 1;
+        let batch_size = messages.len() as u64;
+        let messages = messages
+            .iter()
+            .map(|message| {
+                Message::create(
+                    0,
+                    message.state,
+                    message.timestamp,
+                    message.id,
+                    message.payload.clone(),
+                    message.checksum,
+                    message.headers.clone(),
+                )
+            })
+            .collect();
+        let appended_messages = target_writer
+            .append(messages, Acks::Leader)
+            .await?
+            .ok_or(IggyError::CannotAppendMessage)?;
+        // The producers can't send to the topic while it's being repartitioned, so the drained batches are contiguous.
+        base_offset.get_or_insert(appended_messages.base_offset);
+        drained_messages_count += batch_size;
+        info!(
+            "Drained {drained_messages_count} messages from partition with ID: {} to partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            source.partition_id, appended_messages.partition_id, source.topic_id, source.stream_id
+        );
+    }
+
+    let Some(base_offset) = base_offset else {
+        return Ok(0);
+    };
+
+    let mut target = target.write().await;
+    target.persist_unsaved_messages().await?;
+    target
+        .move_consumer_offsets(source, first_offset, base_offset)
+        .await?;
+    Ok(drained_messages_count)
+}
+
 pub struct DeletedPartitions {
     pub segments_count: u32,
     pub messages_count: u64,
+    pub drained_partitions: Vec<DrainedPartition>,
+}
+
+pub struct DrainedPartition {
+    pub partition_id: u32,
+    pub target_partition_id: u32,
+    pub messages_count: u64,
 }
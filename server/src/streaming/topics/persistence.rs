@@ -1,6 +1,6 @@
-use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
+use std::sync::Arc;
 use tokio::sync::RwLock;
 
 impl Topic {
@@ -15,12 +15,7 @@ impl Topic {
             );
             self.consumer_groups.insert(
                 consumer_group.consumer_group_id,
-                RwLock::new(ConsumerGroup::new(
-                    self.topic_id,
-                    consumer_group.consumer_group_id,
-                    &consumer_group.name,
-                    self.get_partitions_count(),
-                )),
+                Arc::new(RwLock::new(consumer_group)),
             );
         }
         Ok(())
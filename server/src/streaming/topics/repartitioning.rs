@@ -0,0 +1,180 @@
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::topics::messages::calculate_partition_id_by_messages_key_hash;
+use crate::streaming::topics::topic::Topic;
+use iggy::error::IggyError;
+use iggy::models::header::{HeaderKey, HeaderValue, PARTITION_KEY_HEADER, ROUTING_EPOCH_HEADER};
+use iggy::models::messages::Message;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+/// The maximum number of the messages read from the partition at once, the partition locks are released between the batches.
+const REPARTITIONING_BATCH_SIZE: u32 = 1000;
+
+/// The background job copying the keyed messages to the partitions their keys are mapped to after the partitions count has changed.
+/// It's aborted once dropped, i.e. when the partitions count changes again or the topic is deleted.
+#[derive(Debug)]
+pub struct RepartitioningJob {
+    handle: JoinHandle<()>,
+}
+
+impl Drop for RepartitioningJob {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+impl Topic {
+    /// Starts copying the keyed messages, which were stored in the partitions their keys were mapped to for the previous partitions count,
+    /// to the partitions their keys are mapped to now. The messages are read from the partition headers stamped on append,
+    /// and the copies get the current routing epoch header. The `on_copied` is called with the number of the messages copied by each batch,
+    /// and the `on_completed` with the total number of the copied messages once done.
+    pub(crate) fn start_repartitioning(
+        &mut self,
+        previous_partitions_count: u32,
+        on_copied: impl Fn(u64) + Send + 'static,
+        on_completed: impl FnOnce(u64) + Send + 'static,
+    ) {
+        let stream_id = self.stream_id;
+        let topic_id = self.topic_id;
+        let routing_epoch = self.routing_epoch;
+        let partitions = self.partitions.clone();
+        let handle = tokio::spawn(async move {
+            info!("Repartitioning topic with ID: {topic_id} for stream with ID: {stream_id} to routing epoch: {routing_epoch}...");
+            match repartition(
+                &partitions,
+                previous_partitions_count,
+                routing_epoch,
+                on_copied,
+            )
+            .await
+            {
+                Ok(messages_count) => {
+                    info!("Repartitioned topic with ID: {topic_id} for stream with ID: {stream_id} to routing epoch: {routing_epoch}, copied {messages_count} messages.");
+                    on_completed(messages_count);
+                }
+                Err(error) => {
+                    error!("Cannot repartition topic with ID: {topic_id} for stream with ID: {stream_id} to routing epoch: {routing_epoch}. Error: {error}");
+                }
+            }
+        });
+        self.repartitioning_job = Some(RepartitioningJob { handle });
+    }
+}
+
+async fn repartition(
+    partitions: &HashMap<u32, Arc<RwLock<Partition>>>,
+    previous_partitions_count: u32,
+    routing_epoch: u32,
+    on_copied: impl Fn(u64),
+) -> Result<u64, IggyError> {
+    let partitions_count = partitions.len() as u32;
+    let mut copied_messages_count = 0;
+    for partition_id in 1..=previous_partitions_count.min(partitions_count) {
+        let Some(partition) = partitions.get(&partition_id) else {
+            continue;
+        };
+
+        // The messages appended in the meantime are already routed with the current partitions count.
+        let (mut offset, end_offset) = {
+            let partition = partition.read().await;
+            match partition.get_segments().first() {
+                Some(segment) if partition.get_messages_count() > 0 => {
+                    (segment.start_offset, partition.current_offset)
+                }
+                _ => continue,
+            }
+        };
+
+        while offset <= end_offset {
+            let messages = partition
+                .read()
+                .await
+                .get_messages_by_offset(offset, REPARTITIONING_BATCH_SIZE)
+                .await?;
+            let Some(last_message) = messages.last() else {
+                break;
+            };
+
+            offset = last_message.offset + 1;
+            let mut copies = HashMap::<u32, Vec<Message>>::new();
+            for message in messages
+                .iter()
+                .filter(|message| message.offset <= end_offset)
+            {
+                let Some(messages_key) = get_partition_key(message) else {
+                    continue;
+                };
+
+                // Only the messages stored in the partition their key was mapped to are copied,
+                // so the copies left by the previously aborted job aren't copied again along with the originals.
+                if calculate_partition_id_by_messages_key_hash(
+                    messages_key,
+                    previous_partitions_count,
+                ) != partition_id
+                {
+                    continue;
+                }
+
+                let target_partition_id =
+                    calculate_partition_id_by_messages_key_hash(messages_key, partitions_count);
+                if target_partition_id == partition_id {
+                    continue;
+                }
+
+                copies
+                    .entry(target_partition_id)
+                    .or_default()
+                    .push(copy_message(message, routing_epoch)?);
+            }
+
+            for (target_partition_id, messages) in copies {
+                let messages_count = messages.len() as u64;
+                let target_partition = partitions.get(&target_partition_id).unwrap();
+                target_partition
+                    .write()
+                    .await
+                    .append_messages(messages)
+                    .await?;
+                copied_messages_count += messages_count;
+                on_copied(messages_count);
+            }
+
+            info!(
+                "Repartitioned messages up to offset: {} of {end_offset} for partition with ID: {partition_id}, copied {copied_messages_count} messages so far.",
+                offset - 1
+            );
+            tokio::task::yield_now().await;
+        }
+    }
+
+    Ok(copied_messages_count)
+}
+
+fn get_partition_key(message: &Message) -> Option<&[u8]> {
+    message
+        .headers
+        .as_ref()?
+        .iter()
+        .find(|(key, _)| key.as_str() == PARTITION_KEY_HEADER)
+        .and_then(|(_, value)| value.as_raw().ok())
+}
+
+fn copy_message(message: &Message, routing_epoch: u32) -> Result<Message, IggyError> {
+    let mut headers = message.headers.clone().unwrap_or_default();
+    headers.insert(
+        HeaderKey::new(ROUTING_EPOCH_HEADER)?,
+        HeaderValue::from_uint32(routing_epoch)?,
+    );
+    Ok(Message::create(
+        0,
+        message.state,
+        message.timestamp,
+        message.id,
+        message.payload.clone(),
+        message.checksum,
+        Some(headers),
+    ))
+}
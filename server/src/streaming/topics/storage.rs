@@ -1,11 +1,15 @@
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::storage::{Storage, TopicStorage};
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::message_schema::MessageSchema;
 use crate::streaming::topics::topic::Topic;
 use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::join_all;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::utils::byte_size::IggyByteSize;
 use serde::{Deserialize, Serialize};
 use sled::Db;
@@ -15,6 +19,7 @@ use tokio::fs;
 use tokio::fs::create_dir;
 use tokio::sync::{Mutex, RwLock};
 use tracing::{error, info};
+use uuid::Uuid;
 
 #[derive(Debug)]
 pub struct FileTopicStorage {
@@ -34,6 +39,8 @@ unsafe impl Sync for FileTopicStorage {}
 struct ConsumerGroupData {
     id: u32,
     name: String,
+    #[serde(default)]
+    last_activity_at: u64,
 }
 
 #[async_trait]
@@ -51,6 +58,7 @@ impl TopicStorage for FileTopicStorage {
         match rmp_serde::to_vec(&ConsumerGroupData {
             id: consumer_group.consumer_group_id,
             name: consumer_group.name.clone(),
+            last_activity_at: consumer_group.last_activity_at,
         })
         .with_context(|| format!("Failed to serialize consumer group with key: {}", key))
         {
@@ -99,12 +107,17 @@ impl TopicStorage for FileTopicStorage {
                     return Err(IggyError::CannotLoadResource(err));
                 }
             };
-            let consumer_group = ConsumerGroup::new(
+            let last_activity_at = consumer_group.last_activity_at;
+            let mut consumer_group = ConsumerGroup::new(
                 topic.topic_id,
                 consumer_group.id,
                 &consumer_group.name,
                 topic.get_partitions_count(),
             );
+            // The groups saved before the activity was tracked are treated as just created.
+            if last_activity_at > 0 {
+                consumer_group.last_activity_at = last_activity_at;
+            }
             consumer_groups.push(consumer_group);
         }
 
@@ -147,6 +160,22 @@ struct TopicData {
     message_expiry: Option<u32>,
     max_topic_size: Option<IggyByteSize>,
     replication_factor: u8,
+    #[serde(default)]
+    message_schema: Option<String>,
+    #[serde(default)]
+    timestamp_type: TimestampType,
+    #[serde(default)]
+    routing_epoch: u32,
+    #[serde(default)]
+    compression_algorithm: CompressionAlgorithm,
+    #[serde(default)]
+    uuid: Uuid,
+    #[serde(default)]
+    frozen: bool,
+    #[serde(default)]
+    consumer_limits: ConsumerLimits,
+    #[serde(default)]
+    max_message_size: Option<IggyByteSize>,
 }
 
 #[async_trait]
@@ -186,6 +215,23 @@ impl Storage<Topic> for FileTopicStorage {
         topic.message_expiry = topic_data.message_expiry;
         topic.max_topic_size = topic_data.max_topic_size;
         topic.replication_factor = topic_data.replication_factor;
+        topic.message_schema = topic_data
+            .message_schema
+            .as_deref()
+            .map(MessageSchema::compile)
+            .transpose()?;
+        topic.timestamp_type = topic_data.timestamp_type;
+        topic.routing_epoch = topic_data.routing_epoch;
+        topic.compression_algorithm = topic_data.compression_algorithm;
+        topic.frozen = topic_data.frozen;
+        topic.consumer_limits = topic_data.consumer_limits;
+        topic.max_message_size = topic_data.max_message_size;
+        // The topic saved without the UUID keeps the one generated on creation, which is saved right away to stay stable.
+        if topic_data.uuid.is_nil() {
+            self.save(topic).await?;
+        } else {
+            topic.uuid = topic_data.uuid;
+        }
 
         let dir_entries = fs::read_dir(&topic.partitions_path).await
             .with_context(|| format!("Failed to read partition with ID: {} for stream with ID: {} for topic with ID: {} and path: {}",
@@ -228,12 +274,18 @@ impl Storage<Topic> for FileTopicStorage {
 
         let stream_id = topic.stream_id;
         let topic_id = topic.topic_id;
+        let partitions_loader = topic.storage.partitions_loader.clone();
+        partitions_loader.add(unloaded_partitions.len());
         let loaded_partitions = Arc::new(Mutex::new(Vec::new()));
         let mut load_partitions = Vec::new();
         for mut partition in unloaded_partitions {
             let loaded_partitions = loaded_partitions.clone();
+            let partitions_loader = partitions_loader.clone();
             let load_partition = tokio::spawn(async move {
-                match partition.load().await {
+                let _permit = partitions_loader.acquire().await;
+                let result = partition.load().await;
+                partitions_loader.complete();
+                match result {
                     Ok(_) => {
                         loaded_partitions.lock().await.push(partition);
                     }
@@ -287,6 +339,17 @@ impl Storage<Topic> for FileTopicStorage {
             message_expiry: topic.message_expiry,
             max_topic_size: topic.max_topic_size,
             replication_factor: topic.replication_factor,
+            message_schema: topic
+                .message_schema
+                .as_ref()
+                .map(|message_schema| message_schema.as_str().to_string()),
+            timestamp_type: topic.timestamp_type,
+            routing_epoch: topic.routing_epoch,
+            compression_algorithm: topic.compression_algorithm.clone(),
+            uuid: topic.uuid,
+            frozen: topic.frozen,
+            consumer_limits: topic.consumer_limits,
+            max_message_size: topic.max_message_size,
         })
         .with_context(|| format!("Failed to serialize topic with key: {key}"))
         {
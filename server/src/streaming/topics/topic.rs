@@ -1,20 +1,31 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::writer::PartitionWriter;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::message_schema::MessageSchema;
+use crate::streaming::topics::repartitioning::RepartitioningJob;
 use core::fmt;
+use dashmap::DashMap;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
+use iggy::messages::send_messages;
+use iggy::models::consumer_limits::ConsumerLimits;
+use iggy::models::timestamp_type::TimestampType;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use uuid::Uuid;
 
 #[derive(Debug)]
 pub struct Topic {
     pub stream_id: u32,
     pub topic_id: u32,
+    /// The immutable UUID of the topic, which is kept across renames and ID reuse.
+    pub uuid: Uuid,
     pub name: String,
     pub path: String,
     pub partitions_path: String,
@@ -24,13 +35,32 @@ pub struct Topic {
     pub(crate) messages_count: Arc<AtomicU64>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) partitions: HashMap<u32, Arc<RwLock<Partition>>>,
+    /// The writers of the partitions, spawned on the first append to the partition.
+    pub(crate) partition_writers: DashMap<u32, PartitionWriter>,
     pub(crate) storage: Arc<SystemStorage>,
-    pub(crate) consumer_groups: HashMap<u32, RwLock<ConsumerGroup>>,
+    pub(crate) consumer_groups: HashMap<u32, Arc<RwLock<ConsumerGroup>>>,
     pub(crate) consumer_groups_ids: HashMap<String, u32>,
     pub(crate) current_partition_id: AtomicU32,
     pub message_expiry: Option<u32>,
     pub max_topic_size: Option<IggyByteSize>,
     pub replication_factor: u8,
+    pub message_schema: Option<MessageSchema>,
+    /// Whether the messages are stored with the append time or the create time provided by the producer.
+    pub timestamp_type: TimestampType,
+    /// The compression algorithm of the topic data, inherited from the stream or the server default.
+    pub compression_algorithm: CompressionAlgorithm,
+    /// Whether the partitions and messages are kept in memory only, regardless of the storage backend.
+    pub ephemeral: bool,
+    /// Incremented whenever the partitions count changes, as the messages key is then mapped to another partition.
+    pub routing_epoch: u32,
+    /// The job copying the keyed messages to their new partitions after the partitions count has changed, if any.
+    pub(crate) repartitioning_job: Option<RepartitioningJob>,
+    /// Whether the topic is read-only, i.e. the messages can't be appended, but can still be polled.
+    pub frozen: bool,
+    /// The caps on the consumer groups and their members, which are checked when the group is created or joined.
+    pub consumer_limits: ConsumerLimits,
+    /// The maximum size of the message payload, if not set, the server default is used.
+    pub max_message_size: Option<IggyByteSize>,
     pub created_at: u64,
 }
 
@@ -76,8 +106,10 @@ impl Topic {
         let mut topic = Topic {
             stream_id,
             topic_id,
+            uuid: Uuid::new_v4(),
             name: name.to_string(),
             partitions: HashMap::new(),
+            partition_writers: DashMap::new(),
             path,
             partitions_path,
             storage,
@@ -100,6 +132,15 @@ impl Topic {
             },
             max_topic_size,
             replication_factor,
+            message_schema: None,
+            timestamp_type: TimestampType::default(),
+            compression_algorithm: config.compression.default_algorithm.clone(),
+            ephemeral: false,
+            routing_epoch: 0,
+            repartitioning_job: None,
+            frozen: false,
+            consumer_limits: ConsumerLimits::default(),
+            max_message_size: None,
             config,
             created_at: IggyTimestamp::now().to_micros(),
         };
@@ -112,6 +153,40 @@ impl Topic {
         IggyByteSize::from(self.size_bytes.load(Ordering::SeqCst))
     }
 
+    /// Returns the maximum size of the message payload set for the topic or the server default, `None` means unlimited.
+    pub fn get_max_message_size(&self) -> Option<IggyByteSize> {
+        match self.max_message_size {
+            Some(max_message_size) => Some(max_message_size),
+            None => match self.config.topic.max_message_size.as_bytes_u64() {
+                0 => None,
+                _ => Some(self.config.topic.max_message_size),
+            },
+        }
+    }
+
+    /// Rejects the whole batch, if any of the messages has the payload bigger than the max message size of the topic.
+    pub fn validate_message_sizes(
+        &self,
+        messages: &[send_messages::Message],
+    ) -> Result<(), IggyError> {
+        let Some(max_message_size) = self.get_max_message_size() else {
+            return Ok(());
+        };
+
+        let max_message_size = max_message_size.as_bytes_u64();
+        for (index, message) in messages.iter().enumerate() {
+            let size = message.payload.len() as u64;
+            if size > max_message_size {
+                return Err(IggyError::MessageTooLarge(
+                    index as u32,
+                    size,
+                    max_message_size,
+                ));
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_partitions(&self) -> Vec<Arc<RwLock<Partition>>> {
         self.partitions.values().map(Arc::clone).collect()
     }
@@ -150,7 +225,9 @@ mod tests {
     use std::str::FromStr;
 
     use super::*;
+    use crate::configs::system::TopicConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use bytes::Bytes;
 
     #[test]
     fn should_be_created_given_valid_parameters() {
@@ -197,4 +274,40 @@ mod tests {
             assert_eq!(partition.segments.len(), 1);
         }
     }
+
+    #[test]
+    fn messages_bigger_than_max_message_size_should_be_rejected() {
+        let config = Arc::new(SystemConfig {
+            topic: TopicConfig {
+                max_message_size: IggyByteSize::from(10),
+                ..TopicConfig::default()
+            },
+            ..SystemConfig::default()
+        });
+        let mut topic = Topic::empty(1, 1, config, Arc::new(get_test_system_storage()));
+        let messages = vec![
+            send_messages::Message::new(None, Bytes::from(vec![0; 10]), None),
+            send_messages::Message::new(None, Bytes::from(vec![0; 11]), None),
+        ];
+
+        assert!(matches!(
+            topic.validate_message_sizes(&messages),
+            Err(IggyError::MessageTooLarge(1, 11, 10))
+        ));
+
+        topic.max_message_size = Some(IggyByteSize::from(20));
+        assert!(topic.validate_message_sizes(&messages).is_ok());
+    }
+
+    #[test]
+    fn message_size_should_be_unlimited_by_default() {
+        let topic = Topic::empty(
+            1,
+            1,
+            Arc::new(SystemConfig::default()),
+            Arc::new(get_test_system_storage()),
+        );
+
+        assert!(topic.get_max_message_size().is_none());
+    }
 }
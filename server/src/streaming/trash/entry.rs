@@ -0,0 +1,52 @@
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// The soft deleted stream or topic, which can be restored until the trash retention expires.
+/// The topic entry is identified by the stream and topic ID, the stream entry has no topic ID.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrashEntry {
+    pub stream_id: u32,
+    pub topic_id: Option<u32>,
+    pub name: String,
+    pub deleted_at: u64,
+}
+
+impl TrashEntry {
+    pub fn stream(stream_id: u32, name: &str) -> Self {
+        Self {
+            stream_id,
+            topic_id: None,
+            name: name.to_string(),
+            deleted_at: IggyTimestamp::now().to_micros(),
+        }
+    }
+
+    pub fn topic(stream_id: u32, topic_id: u32, name: &str) -> Self {
+        Self {
+            stream_id,
+            topic_id: Some(topic_id),
+            name: name.to_string(),
+            deleted_at: IggyTimestamp::now().to_micros(),
+        }
+    }
+
+    pub fn is_expired(&self, now: u64, retention: IggyDuration) -> bool {
+        self.deleted_at + retention.as_micros() <= now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_should_expire_once_retention_has_elapsed() {
+        let retention = "1 h".parse::<IggyDuration>().unwrap();
+        let entry = TrashEntry::topic(1, 2, "orders");
+
+        assert!(!entry.is_expired(entry.deleted_at, retention));
+        assert!(!entry.is_expired(entry.deleted_at + retention.as_micros() - 1, retention));
+        assert!(entry.is_expired(entry.deleted_at + retention.as_micros(), retention));
+    }
+}
@@ -0,0 +1,139 @@
+use crate::streaming::storage::{Storage, TrashStorage};
+use crate::streaming::trash::entry::TrashEntry;
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use sled::Db;
+use std::sync::Arc;
+use tracing::info;
+
+const KEY_PREFIX: &str = "trash";
+
+#[derive(Debug)]
+pub struct FileTrashStorage {
+    db: Arc<Db>,
+}
+
+impl FileTrashStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FileTrashStorage {}
+unsafe impl Sync for FileTrashStorage {}
+
+#[async_trait]
+impl TrashStorage for FileTrashStorage {
+    async fn load_all(&self) -> Result<Vec<TrashEntry>, IggyError> {
+        let mut entries = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+            let entry = match data.with_context(|| {
+                format!(
+                    "Failed to load trash entry, when searching for key: {}",
+                    KEY_PREFIX
+                )
+            }) {
+                Ok((_, value)) => {
+                    match rmp_serde::from_slice::<TrashEntry>(&value).with_context(|| {
+                        format!(
+                            "Failed to deserialize trash entry, when searching for key: {}",
+                            KEY_PREFIX
+                        )
+                    }) {
+                        Ok(entry) => entry,
+                        Err(err) => {
+                            return Err(IggyError::CannotDeserializeResource(err));
+                        }
+                    }
+                }
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            };
+            entries.push(entry);
+        }
+
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl Storage<TrashEntry> for FileTrashStorage {
+    async fn load(&self, entry: &mut TrashEntry) -> Result<(), IggyError> {
+        let key = get_key(entry);
+        let entry_data = match self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load trash entry with key: {}", key))
+        {
+            Ok(data) => {
+                if let Some(entry_data) = data {
+                    entry_data
+                } else {
+                    return Err(IggyError::ResourceNotFound(key));
+                }
+            }
+            Err(err) => {
+                return Err(IggyError::CannotLoadResource(err));
+            }
+        };
+
+        match rmp_serde::from_slice::<TrashEntry>(&entry_data)
+            .with_context(|| format!("Failed to deserialize trash entry with key: {}", key))
+        {
+            Ok(entry_data) => {
+                *entry = entry_data;
+                Ok(())
+            }
+            Err(err) => Err(IggyError::CannotDeserializeResource(err)),
+        }
+    }
+
+    async fn save(&self, entry: &TrashEntry) -> Result<(), IggyError> {
+        let key = get_key(entry);
+        match rmp_serde::to_vec(&entry)
+            .with_context(|| format!("Failed to serialize trash entry with key: {}", key))
+        {
+            Ok(data) => {
+                if let Err(err) = self
+                    .db
+                    .insert(&key, data)
+                    .with_context(|| format!("Failed to insert trash entry with key: {}", key))
+                {
+                    return Err(IggyError::CannotSaveResource(err));
+                }
+            }
+            Err(err) => {
+                return Err(IggyError::CannotSerializeResource(err));
+            }
+        }
+
+        info!("Saved trash entry with key: {key}, name: {}.", entry.name);
+        Ok(())
+    }
+
+    async fn delete(&self, entry: &TrashEntry) -> Result<(), IggyError> {
+        let key = get_key(entry);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete trash entry with key: {}", key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        info!("Deleted trash entry with key: {key}.");
+        Ok(())
+    }
+}
+
+fn get_key(entry: &TrashEntry) -> String {
+    match entry.topic_id {
+        Some(topic_id) => format!(
+            "{}:streams:{}:topics:{}",
+            KEY_PREFIX, entry.stream_id, topic_id
+        ),
+        None => format!("{}:streams:{}", KEY_PREFIX, entry.stream_id),
+    }
+}
@@ -0,0 +1,137 @@
+use crate::streaming::storage::{Storage, UserGroupStorage};
+use crate::streaming::user_groups::user_group::UserGroup;
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use sled::Db;
+use std::sync::Arc;
+use tracing::info;
+
+const KEY_PREFIX: &str = "user_groups";
+
+#[derive(Debug)]
+pub struct FileUserGroupStorage {
+    db: Arc<Db>,
+}
+
+impl FileUserGroupStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FileUserGroupStorage {}
+unsafe impl Sync for FileUserGroupStorage {}
+
+#[async_trait]
+impl UserGroupStorage for FileUserGroupStorage {
+    async fn load_all(&self) -> Result<Vec<UserGroup>, IggyError> {
+        let mut groups = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+            let group = match data.with_context(|| {
+                format!(
+                    "Failed to load user group, when searching for key: {}",
+                    KEY_PREFIX
+                )
+            }) {
+                Ok((_, value)) => {
+                    match rmp_serde::from_slice::<UserGroup>(&value).with_context(|| {
+                        format!(
+                            "Failed to deserialize user group, when searching for key: {}",
+                            KEY_PREFIX
+                        )
+                    }) {
+                        Ok(group) => group,
+                        Err(err) => {
+                            return Err(IggyError::CannotDeserializeResource(err));
+                        }
+                    }
+                }
+                Err(err) => {
+                    return Err(IggyError::CannotLoadResource(err));
+                }
+            };
+            groups.push(group);
+        }
+
+        Ok(groups)
+    }
+}
+
+#[async_trait]
+impl Storage<UserGroup> for FileUserGroupStorage {
+    async fn load(&self, group: &mut UserGroup) -> Result<(), IggyError> {
+        let key = get_key(group.id);
+        let group_data = match self
+            .db
+            .get(&key)
+            .with_context(|| format!("Failed to load user group with key: {}", key))
+        {
+            Ok(data) => {
+                if let Some(group_data) = data {
+                    group_data
+                } else {
+                    return Err(IggyError::ResourceNotFound(key));
+                }
+            }
+            Err(err) => {
+                return Err(IggyError::CannotLoadResource(err));
+            }
+        };
+
+        match rmp_serde::from_slice::<UserGroup>(&group_data)
+            .with_context(|| format!("Failed to deserialize user group with key: {}", key))
+        {
+            Ok(group_data) => {
+                *group = group_data;
+                Ok(())
+            }
+            Err(err) => Err(IggyError::CannotDeserializeResource(err)),
+        }
+    }
+
+    async fn save(&self, group: &UserGroup) -> Result<(), IggyError> {
+        let key = get_key(group.id);
+        match rmp_serde::to_vec(&group)
+            .with_context(|| format!("Failed to serialize user group with key: {}", key))
+        {
+            Ok(data) => {
+                if let Err(err) = self
+                    .db
+                    .insert(&key, data)
+                    .with_context(|| format!("Failed to insert user group with key: {}", key))
+                {
+                    return Err(IggyError::CannotSaveResource(err));
+                }
+            }
+            Err(err) => {
+                return Err(IggyError::CannotSerializeResource(err));
+            }
+        }
+
+        info!(
+            "Saved user group with ID: {}, name: {}.",
+            group.id, group.name
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, group: &UserGroup) -> Result<(), IggyError> {
+        info!("Deleting user group with ID: {}...", group.id);
+        let key = get_key(group.id);
+        if let Err(err) = self
+            .db
+            .remove(&key)
+            .with_context(|| format!("Failed to delete user group with key: {}", key))
+        {
+            return Err(IggyError::CannotDeleteResource(err));
+        }
+
+        info!("Deleted user group with ID: {}.", group.id);
+        Ok(())
+    }
+}
+
+fn get_key(group_id: u32) -> String {
+    format!("{}:{}", KEY_PREFIX, group_id)
+}
@@ -0,0 +1,26 @@
+use iggy::models::permissions::Permissions;
+use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UserGroup {
+    pub id: u32,
+    pub name: String,
+    pub permissions: Option<Permissions>,
+    pub members: BTreeSet<UserId>,
+    pub created_at: u64,
+}
+
+impl UserGroup {
+    pub fn new(id: u32, name: &str, permissions: Option<Permissions>) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            permissions,
+            members: BTreeSet::new(),
+            created_at: IggyTimestamp::now().to_micros(),
+        }
+    }
+}
@@ -1,8 +1,9 @@
+use crate::streaming::user_groups::user_group::UserGroup;
 use crate::streaming::users::user::User;
-use iggy::models::permissions::{GlobalPermissions, StreamPermissions};
+use iggy::models::permissions::{GlobalPermissions, Permissions, StreamPermissions};
 use iggy::models::user_info::UserId;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Permissioner {
@@ -12,6 +13,13 @@ pub struct Permissioner {
     pub(super) users_that_can_send_messages_to_all_streams: HashSet<UserId>,
     pub(super) users_that_can_poll_messages_from_specific_streams: HashSet<(UserId, u32)>,
     pub(super) users_that_can_send_messages_to_specific_streams: HashSet<(UserId, u32)>,
+    pub(super) users_tenants: HashMap<UserId, u32>,
+    pub(super) streams_tenants: HashMap<u32, u32>,
+    pub(super) users_session_epochs: HashMap<UserId, u32>,
+    pub(super) groups_permissions: HashMap<u32, Permissions>,
+    pub(super) groups_members: HashMap<u32, BTreeSet<UserId>>,
+    /// The internal stream the server produces into, only the server administrators can access its messages.
+    pub(super) system_stream_id: Option<u32>,
 }
 
 impl Permissioner {
@@ -22,11 +30,16 @@ impl Permissioner {
     }
 
     pub fn init_permissions_for_user(&mut self, user: User) {
-        if user.permissions.is_none() {
-            return;
+        self.users_session_epochs
+            .insert(user.id, user.session_epoch);
+        if let Some(tenant_id) = user.tenant_id {
+            self.users_tenants.insert(user.id, tenant_id);
         }
 
-        let permissions = user.permissions.unwrap();
+        let Some(permissions) = self.get_effective_permissions(&user) else {
+            return;
+        };
+
         if permissions.global.poll_messages {
             self.users_that_can_poll_messages_from_all_streams
                 .insert(user.id);
@@ -66,6 +79,8 @@ impl Permissioner {
 
     pub fn delete_permissions_for_user(&mut self, user_id: UserId) {
         self.users_permissions.remove(&user_id);
+        self.users_tenants.remove(&user_id);
+        self.users_session_epochs.remove(&user_id);
         self.users_that_can_poll_messages_from_all_streams
             .remove(&user_id);
         self.users_that_can_send_messages_to_all_streams
@@ -77,4 +92,345 @@ impl Permissioner {
         self.users_that_can_send_messages_to_specific_streams
             .retain(|(id, _)| *id != user_id);
     }
+
+    /// Returns the permissions of the user merged with the permissions of all the groups it belongs to.
+    pub fn get_effective_permissions(&self, user: &User) -> Option<Permissions> {
+        let mut groups_permissions = self
+            .groups_members
+            .iter()
+            .filter(|(_, members)| members.contains(&user.id))
+            .filter_map(|(group_id, _)| self.groups_permissions.get(group_id))
+            .peekable();
+        if groups_permissions.peek().is_none() {
+            return user.permissions.clone();
+        }
+
+        let mut permissions = user.permissions.clone().unwrap_or_default();
+        for group_permissions in groups_permissions {
+            merge_permissions(&mut permissions, group_permissions);
+        }
+        Some(permissions)
+    }
+
+    pub fn set_user_group(&mut self, group: &UserGroup) {
+        match &group.permissions {
+            Some(permissions) => self
+                .groups_permissions
+                .insert(group.id, permissions.clone()),
+            None => self.groups_permissions.remove(&group.id),
+        };
+        self.groups_members.insert(group.id, group.members.clone());
+    }
+
+    pub fn remove_user_group(&mut self, group_id: u32) {
+        self.groups_permissions.remove(&group_id);
+        self.groups_members.remove(&group_id);
+    }
+
+    /// Returns the current session epoch of the user, the sessions and tokens with a different one are revoked.
+    pub fn get_session_epoch(&self, user_id: UserId) -> Option<u32> {
+        self.users_session_epochs.get(&user_id).copied()
+    }
+
+    pub fn get_user_tenant(&self, user_id: UserId) -> Option<u32> {
+        self.users_tenants.get(&user_id).copied()
+    }
+
+    pub fn get_tenant_users_count(&self, tenant_id: u32) -> u32 {
+        self.users_tenants
+            .values()
+            .filter(|user_tenant_id| **user_tenant_id == tenant_id)
+            .count() as u32
+    }
+
+    pub fn set_stream_tenant(&mut self, stream_id: u32, tenant_id: Option<u32>) {
+        match tenant_id {
+            Some(tenant_id) => self.streams_tenants.insert(stream_id, tenant_id),
+            None => self.streams_tenants.remove(&stream_id),
+        };
+    }
+
+    pub fn set_system_stream(&mut self, stream_id: u32) {
+        self.system_stream_id = Some(stream_id);
+    }
+}
+
+/// Extends the permissions with the granted ones, the permission is allowed if it's allowed by any of them.
+fn merge_permissions(permissions: &mut Permissions, granted: &Permissions) {
+    let global = &mut permissions.global;
+    global.manage_servers |= granted.global.manage_servers;
+    global.read_servers |= granted.global.read_servers;
+    global.manage_users |= granted.global.manage_users;
+    global.read_users |= granted.global.read_users;
+    global.manage_streams |= granted.global.manage_streams;
+    global.read_streams |= granted.global.read_streams;
+    global.manage_topics |= granted.global.manage_topics;
+    global.read_topics |= granted.global.read_topics;
+    global.poll_messages |= granted.global.poll_messages;
+    global.send_messages |= granted.global.send_messages;
+    global.auto_create_topics |= granted.global.auto_create_topics;
+
+    let Some(granted_streams) = &granted.streams else {
+        return;
+    };
+
+    let streams = permissions.streams.get_or_insert_with(HashMap::new);
+    for (stream_id, granted_stream) in granted_streams {
+        let stream = streams.entry(*stream_id).or_default();
+        stream.manage_stream |= granted_stream.manage_stream;
+        stream.read_stream |= granted_stream.read_stream;
+        stream.manage_topics |= granted_stream.manage_topics;
+        stream.read_topics |= granted_stream.read_topics;
+        stream.poll_messages |= granted_stream.poll_messages;
+        stream.send_messages |= granted_stream.send_messages;
+        stream.auto_create_topics |= granted_stream.auto_create_topics;
+        stream.manage_partitions |= granted_stream.manage_partitions;
+        stream.manage_consumer_groups |= granted_stream.manage_consumer_groups;
+
+        let Some(granted_topics) = &granted_stream.topics else {
+            continue;
+        };
+
+        let topics = stream.topics.get_or_insert_with(HashMap::new);
+        for (topic_id, granted_topic) in granted_topics {
+            let topic = topics.entry(*topic_id).or_default();
+            topic.manage_topic |= granted_topic.manage_topic;
+            topic.read_topic |= granted_topic.read_topic;
+            topic.poll_messages |= granted_topic.poll_messages;
+            topic.send_messages |= granted_topic.send_messages;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::users::user::ANONYMOUS_USER_ID;
+    use iggy::models::permission_check::PermissionAction;
+    use iggy::models::permissions::TopicPermissions;
+    use iggy::models::user_status::UserStatus;
+
+    #[test]
+    fn effective_permissions_should_merge_user_and_groups_permissions() {
+        let user = User::new(
+            2,
+            "user",
+            "secret",
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    ..Default::default()
+                },
+                streams: Some(HashMap::from([(
+                    1,
+                    StreamPermissions {
+                        poll_messages: true,
+                        ..Default::default()
+                    },
+                )])),
+            }),
+        );
+        let mut group = UserGroup::new(
+            1,
+            "group",
+            Some(Permissions {
+                global: GlobalPermissions {
+                    send_messages: true,
+                    ..Default::default()
+                },
+                streams: Some(HashMap::from([(
+                    1,
+                    StreamPermissions {
+                        send_messages: true,
+                        topics: Some(HashMap::from([(
+                            1,
+                            TopicPermissions {
+                                manage_topic: true,
+                                ..Default::default()
+                            },
+                        )])),
+                        ..Default::default()
+                    },
+                )])),
+            }),
+        );
+        let mut permissioner = Permissioner::default();
+        permissioner.set_user_group(&group);
+        assert_eq!(
+            permissioner.get_effective_permissions(&user),
+            user.permissions
+        );
+
+        group.members.insert(user.id);
+        permissioner.set_user_group(&group);
+        let permissions = permissioner.get_effective_permissions(&user).unwrap();
+        assert!(permissions.global.read_streams);
+        assert!(permissions.global.send_messages);
+        let stream = &permissions.streams.unwrap()[&1];
+        assert!(stream.poll_messages);
+        assert!(stream.send_messages);
+        assert!(stream.topics.as_ref().unwrap()[&1].manage_topic);
+    }
+
+    #[test]
+    fn check_permission_should_explain_granting_and_missing_permissions() {
+        let user = User::new(
+            2,
+            "user",
+            "secret",
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions::default(),
+                streams: Some(HashMap::from([(
+                    1,
+                    StreamPermissions {
+                        poll_messages: true,
+                        ..Default::default()
+                    },
+                )])),
+            }),
+        );
+        let mut group = UserGroup::new(
+            3,
+            "group",
+            Some(Permissions {
+                global: GlobalPermissions {
+                    send_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+            }),
+        );
+        group.members.insert(user.id);
+        let mut permissioner = Permissioner::default();
+        permissioner.set_user_group(&group);
+        permissioner.init_permissions_for_user(user.clone());
+
+        let check =
+            permissioner.check_permission(&user, PermissionAction::PollMessages, Some(1), Some(1));
+        assert!(check.allowed);
+        assert_eq!(check.rule, "streams[1].poll_messages");
+        assert_eq!(check.group_id, None);
+
+        let check =
+            permissioner.check_permission(&user, PermissionAction::SendMessages, Some(1), Some(1));
+        assert!(check.allowed);
+        assert_eq!(check.rule, "global.send_messages");
+        assert_eq!(check.group_id, Some(group.id));
+
+        let check =
+            permissioner.check_permission(&user, PermissionAction::PollMessages, Some(2), Some(1));
+        assert!(!check.allowed);
+        assert_eq!(
+            check.rule,
+            "missing any of: global.poll_messages, streams[2].poll_messages, streams[2].topics[1].poll_messages"
+        );
+
+        let check =
+            permissioner.check_permission(&user, PermissionAction::ReadTopic, Some(1), Some(1));
+        assert!(!check.allowed);
+    }
+
+    #[test]
+    fn stream_permissions_should_allow_managing_only_partitions_or_consumer_groups() {
+        let user = User::new(
+            2,
+            "user",
+            "secret",
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions::default(),
+                streams: Some(HashMap::from([
+                    (
+                        1,
+                        StreamPermissions {
+                            manage_partitions: true,
+                            ..Default::default()
+                        },
+                    ),
+                    (
+                        2,
+                        StreamPermissions {
+                            manage_consumer_groups: true,
+                            ..Default::default()
+                        },
+                    ),
+                ])),
+            }),
+        );
+        let mut permissioner = Permissioner::default();
+        permissioner.init_permissions_for_user(user.clone());
+
+        assert!(permissioner.create_partitons(user.id, 1, 1).is_ok());
+        assert!(permissioner.delete_partitions(user.id, 1, 1).is_ok());
+        assert!(permissioner.get_topic(user.id, 1, 1).is_ok());
+        assert!(permissioner.create_consumer_group(user.id, 1, 1).is_err());
+        assert!(permissioner.update_topic(user.id, 1, 1).is_err());
+        assert!(permissioner.create_topic(user.id, 1).is_err());
+
+        assert!(permissioner.create_consumer_group(user.id, 2, 1).is_ok());
+        assert!(permissioner.delete_consumer_group(user.id, 2, 1).is_ok());
+        assert!(permissioner.create_partitons(user.id, 2, 1).is_err());
+        assert!(permissioner.delete_topic(user.id, 2, 1).is_err());
+        assert!(permissioner.poll_messages(user.id, 2, 1).is_err());
+
+        let check = permissioner.check_permission(
+            &user,
+            PermissionAction::ManageConsumerGroups,
+            Some(2),
+            Some(1),
+        );
+        assert!(check.allowed);
+        assert_eq!(check.rule, "streams[2].manage_consumer_groups");
+    }
+
+    #[test]
+    fn schemas_should_be_read_only_by_users_that_can_read_any_stream() {
+        let reader = User::new(
+            2,
+            "reader",
+            "secret",
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions::default(),
+                streams: Some(HashMap::from([(
+                    1,
+                    StreamPermissions {
+                        topics: Some(HashMap::from([(
+                            1,
+                            TopicPermissions {
+                                poll_messages: true,
+                                ..Default::default()
+                            },
+                        )])),
+                        ..Default::default()
+                    },
+                )])),
+            }),
+        );
+        let user = User::new(
+            3,
+            "user",
+            "secret",
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_users: true,
+                    ..Default::default()
+                },
+                streams: Some(HashMap::from([(1, StreamPermissions::default())])),
+            }),
+        );
+        let mut permissioner = Permissioner::default();
+        permissioner.init_permissions_for_user(reader.clone());
+        permissioner.init_permissions_for_user(user.clone());
+
+        assert!(permissioner.get_schema(reader.id).is_ok());
+        assert!(permissioner.get_schema_versions(reader.id).is_ok());
+        assert!(permissioner.register_schema(reader.id).is_err());
+        assert!(permissioner.get_schema(user.id).is_err());
+        assert!(permissioner.get_schema_versions(user.id).is_err());
+        assert!(permissioner.get_schema(ANONYMOUS_USER_ID).is_err());
+    }
 }
@@ -8,7 +8,7 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
-        self.update_topic(user_id, stream_id, topic_id)
+        self.manage_consumer_groups(user_id, stream_id, topic_id)
     }
 
     pub fn delete_consumer_group(
@@ -17,7 +17,7 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
-        self.update_topic(user_id, stream_id, topic_id)
+        self.manage_consumer_groups(user_id, stream_id, topic_id)
     }
 
     pub fn get_consumer_group(
@@ -55,4 +55,26 @@ impl Permissioner {
     ) -> Result<(), IggyError> {
         self.get_topic(user_id, stream_id, topic_id)
     }
+
+    /// The consumer groups can be managed by the users managing the topic, or the consumer groups of its stream.
+    fn manage_consumer_groups(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
+        if self.update_topic(user_id, stream_id, topic_id).is_ok() {
+            return Ok(());
+        }
+
+        if let Some(stream_permissions) = self.users_streams_permissions.get(&(user_id, stream_id))
+        {
+            if stream_permissions.manage_consumer_groups {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
 }
@@ -8,6 +8,11 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
+        if self.system_stream_id == Some(stream_id) {
+            return self.get_server_info(user_id);
+        }
+
         if self
             .users_that_can_poll_messages_from_all_streams
             .contains(&user_id)
@@ -53,6 +58,11 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
+        if self.system_stream_id == Some(stream_id) {
+            return self.manage_server(user_id);
+        }
+
         if self
             .users_that_can_send_messages_to_all_streams
             .contains(&user_id)
@@ -2,7 +2,11 @@ mod consumer_groups;
 pub mod consumer_offsets;
 mod messages;
 mod partitions;
+mod permission_check;
+mod schemas;
 mod streams;
 mod system;
+mod tenants;
 mod topics;
+mod user_groups;
 mod users;
@@ -8,7 +8,7 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
-        self.update_topic(user_id, stream_id, topic_id)
+        self.manage_partitions(user_id, stream_id, topic_id)
     }
 
     pub fn delete_partitions(
@@ -17,6 +17,28 @@ impl Permissioner {
         stream_id: u32,
         topic_id: u32,
     ) -> Result<(), IggyError> {
-        self.update_topic(user_id, stream_id, topic_id)
+        self.manage_partitions(user_id, stream_id, topic_id)
+    }
+
+    /// The partitions can be managed by the users managing the topic, or the partitions of its stream.
+    fn manage_partitions(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
+        if self.update_topic(user_id, stream_id, topic_id).is_ok() {
+            return Ok(());
+        }
+
+        if let Some(stream_permissions) = self.users_streams_permissions.get(&(user_id, stream_id))
+        {
+            if stream_permissions.manage_partitions {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
     }
 }
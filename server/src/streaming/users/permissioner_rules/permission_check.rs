@@ -0,0 +1,259 @@
+use crate::streaming::users::permissioner::Permissioner;
+use crate::streaming::users::user::User;
+use iggy::error::IggyError;
+use iggy::models::permission_check::{PermissionAction, PermissionCheck};
+use iggy::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
+use iggy::models::user_status::UserStatus;
+
+impl Permissioner {
+    /// Checks the action against the effective permissions of the user, the result is based on the same rules
+    /// which authorize the commands, and explains which permission (of the user or one of its groups) granted it,
+    /// or why it was denied.
+    pub fn check_permission(
+        &self,
+        user: &User,
+        action: PermissionAction,
+        stream_id: Option<u32>,
+        topic_id: Option<u32>,
+    ) -> PermissionCheck {
+        let stream_id = stream_id.unwrap_or_default();
+        let topic_id = topic_id.unwrap_or_default();
+        if user.status == UserStatus::Inactive {
+            return PermissionCheck {
+                allowed: false,
+                rule: "user_inactive".to_string(),
+                group_id: None,
+            };
+        }
+
+        let allowed = self
+            .authorize_action(user.id, action, stream_id, topic_id)
+            .is_ok();
+        let grants = self.get_action_grants(action, stream_id);
+        let granting = self.find_granting_rule(user, &grants, stream_id, topic_id);
+        match (allowed, granting) {
+            (true, Some((rule, group_id))) => PermissionCheck {
+                allowed,
+                rule,
+                group_id,
+            },
+            (true, None) => PermissionCheck {
+                allowed,
+                rule: "effective_permissions".to_string(),
+                group_id: None,
+            },
+            // The permission is granted, but the tenant the user is assigned to can't access the resource.
+            (false, Some(_)) => PermissionCheck {
+                allowed,
+                rule: "tenant_restriction".to_string(),
+                group_id: None,
+            },
+            (false, None) => PermissionCheck {
+                allowed,
+                rule: format!(
+                    "missing any of: {}",
+                    grants
+                        .iter()
+                        .map(|grant| grant.name(stream_id, topic_id))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                group_id: None,
+            },
+        }
+    }
+
+    fn authorize_action(
+        &self,
+        user_id: u32,
+        action: PermissionAction,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        match action {
+            PermissionAction::ReadServers => self.get_server_info(user_id),
+            PermissionAction::ManageServers => self.manage_server(user_id),
+            PermissionAction::ReadUsers => self.read_users(user_id),
+            PermissionAction::ManageUsers => self.manager_users(user_id),
+            PermissionAction::ReadStreams => self.get_streams(user_id),
+            PermissionAction::CreateStream => self.create_stream(user_id),
+            PermissionAction::ReadStream => self.get_stream(user_id, stream_id),
+            PermissionAction::ManageStream => self.update_stream(user_id, stream_id),
+            PermissionAction::ReadTopic => self.get_topic(user_id, stream_id, topic_id),
+            PermissionAction::CreateTopic => self.create_topic(user_id, stream_id),
+            PermissionAction::ManageTopic => self.update_topic(user_id, stream_id, topic_id),
+            PermissionAction::PollMessages => self.poll_messages(user_id, stream_id, topic_id),
+            PermissionAction::SendMessages => self.append_messages(user_id, stream_id, topic_id),
+            PermissionAction::ManagePartitions => {
+                self.create_partitons(user_id, stream_id, topic_id)
+            }
+            PermissionAction::ManageConsumerGroups => {
+                self.create_consumer_group(user_id, stream_id, topic_id)
+            }
+        }
+    }
+
+    /// Returns the permissions granting the action, in the same order as they're checked by the rules.
+    fn get_action_grants(&self, action: PermissionAction, stream_id: u32) -> Vec<Grant> {
+        let is_system_stream = self.system_stream_id == Some(stream_id);
+        match action {
+            PermissionAction::ReadServers => read_servers_grants(),
+            PermissionAction::PollMessages if is_system_stream => read_servers_grants(),
+            PermissionAction::ManageServers => manage_servers_grants(),
+            PermissionAction::SendMessages if is_system_stream => manage_servers_grants(),
+            PermissionAction::ReadUsers => vec![
+                Grant::Global("manage_users", |p| p.manage_users),
+                Grant::Global("read_users", |p| p.read_users),
+            ],
+            PermissionAction::ManageUsers => {
+                vec![Grant::Global("manage_users", |p| p.manage_users)]
+            }
+            PermissionAction::ReadStreams => vec![
+                Grant::Global("manage_streams", |p| p.manage_streams),
+                Grant::Global("read_streams", |p| p.read_streams),
+            ],
+            PermissionAction::CreateStream => {
+                vec![Grant::Global("manage_streams", |p| p.manage_streams)]
+            }
+            PermissionAction::ReadStream => vec![
+                Grant::Global("manage_streams", |p| p.manage_streams),
+                Grant::Global("read_streams", |p| p.read_streams),
+                Grant::Stream("manage_stream", |p| p.manage_stream),
+                Grant::Stream("read_stream", |p| p.read_stream),
+            ],
+            PermissionAction::ManageStream => vec![
+                Grant::Global("manage_streams", |p| p.manage_streams),
+                Grant::Stream("manage_stream", |p| p.manage_stream),
+            ],
+            PermissionAction::ReadTopic => vec![
+                Grant::Global("read_streams", |p| p.read_streams),
+                Grant::Global("manage_streams", |p| p.manage_streams),
+                Grant::Global("manage_topics", |p| p.manage_topics),
+                Grant::Global("read_topics", |p| p.read_topics),
+                Grant::Stream("manage_topics", |p| p.manage_topics),
+                Grant::Stream("read_topics", |p| p.read_topics),
+                Grant::Stream("manage_partitions", |p| p.manage_partitions),
+                Grant::Stream("manage_consumer_groups", |p| p.manage_consumer_groups),
+                Grant::Topic("manage_topic", |p| p.manage_topic),
+                Grant::Topic("read_topic", |p| p.read_topic),
+            ],
+            PermissionAction::CreateTopic => vec![
+                Grant::Global("manage_streams", |p| p.manage_streams),
+                Grant::Global("manage_topics", |p| p.manage_topics),
+                Grant::Stream("manage_topics", |p| p.manage_topics),
+            ],
+            PermissionAction::ManageTopic => vec![
+                Grant::Global("manage_streams", |p| p.manage_streams),
+                Grant::Global("manage_topics", |p| p.manage_topics),
+                Grant::Stream("manage_topics", |p| p.manage_topics),
+                Grant::Topic("manage_topic", |p| p.manage_topic),
+            ],
+            PermissionAction::PollMessages => vec![
+                Grant::Global("poll_messages", |p| p.poll_messages),
+                Grant::Stream("poll_messages", |p| p.poll_messages),
+                Grant::Topic("poll_messages", |p| p.poll_messages),
+            ],
+            PermissionAction::ManagePartitions => vec![
+                Grant::Global("manage_streams", |p| p.manage_streams),
+                Grant::Global("manage_topics", |p| p.manage_topics),
+                Grant::Stream("manage_topics", |p| p.manage_topics),
+                Grant::Topic("manage_topic", |p| p.manage_topic),
+                Grant::Stream("manage_partitions", |p| p.manage_partitions),
+            ],
+            PermissionAction::ManageConsumerGroups => vec![
+                Grant::Global("manage_streams", |p| p.manage_streams),
+                Grant::Global("manage_topics", |p| p.manage_topics),
+                Grant::Stream("manage_topics", |p| p.manage_topics),
+                Grant::Topic("manage_topic", |p| p.manage_topic),
+                Grant::Stream("manage_consumer_groups", |p| p.manage_consumer_groups),
+            ],
+            PermissionAction::SendMessages => vec![
+                Grant::Global("send_messages", |p| p.send_messages),
+                Grant::Stream("send_messages", |p| p.send_messages),
+                Grant::Topic("send_messages", |p| p.send_messages),
+            ],
+        }
+    }
+
+    /// Returns the first permission granting the action, the user's own permissions are checked before the groups ones.
+    fn find_granting_rule(
+        &self,
+        user: &User,
+        grants: &[Grant],
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Option<(String, Option<u32>)> {
+        let mut groups_ids = self
+            .groups_members
+            .iter()
+            .filter(|(_, members)| members.contains(&user.id))
+            .map(|(group_id, _)| *group_id)
+            .collect::<Vec<_>>();
+        groups_ids.sort();
+        let sources =
+            user.permissions
+                .iter()
+                .map(|p| (None, p))
+                .chain(groups_ids.into_iter().filter_map(|group_id| {
+                    self.groups_permissions
+                        .get(&group_id)
+                        .map(|p| (Some(group_id), p))
+                }));
+
+        for (group_id, permissions) in sources {
+            if let Some(grant) = grants
+                .iter()
+                .find(|grant| grant.is_granted(permissions, stream_id, topic_id))
+            {
+                return Some((grant.name(stream_id, topic_id), group_id));
+            }
+        }
+
+        None
+    }
+}
+
+fn read_servers_grants() -> Vec<Grant> {
+    vec![
+        Grant::Global("manage_servers", |p| p.manage_servers),
+        Grant::Global("read_servers", |p| p.read_servers),
+    ]
+}
+
+fn manage_servers_grants() -> Vec<Grant> {
+    vec![Grant::Global("manage_servers", |p| p.manage_servers)]
+}
+
+/// The single permission flag granting the action at the global, stream or topic level.
+enum Grant {
+    Global(&'static str, fn(&GlobalPermissions) -> bool),
+    Stream(&'static str, fn(&StreamPermissions) -> bool),
+    Topic(&'static str, fn(&TopicPermissions) -> bool),
+}
+
+impl Grant {
+    fn is_granted(&self, permissions: &Permissions, stream_id: u32, topic_id: u32) -> bool {
+        let stream = permissions
+            .streams
+            .as_ref()
+            .and_then(|streams| streams.get(&stream_id));
+        match self {
+            Grant::Global(_, granted) => granted(&permissions.global),
+            Grant::Stream(_, granted) => stream.is_some_and(granted),
+            Grant::Topic(_, granted) => stream
+                .and_then(|stream| stream.topics.as_ref())
+                .and_then(|topics| topics.get(&topic_id))
+                .is_some_and(granted),
+        }
+    }
+
+    fn name(&self, stream_id: u32, topic_id: u32) -> String {
+        match self {
+            Grant::Global(name, _) => format!("global.{name}"),
+            Grant::Stream(name, _) => format!("streams[{stream_id}].{name}"),
+            Grant::Topic(name, _) => format!("streams[{stream_id}].topics[{topic_id}].{name}"),
+        }
+    }
+}
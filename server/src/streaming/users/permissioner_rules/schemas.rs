@@ -0,0 +1,74 @@
+use crate::streaming::users::permissioner::Permissioner;
+use iggy::error::IggyError;
+
+impl Permissioner {
+    pub fn get_schema(&self, user_id: u32) -> Result<(), IggyError> {
+        self.read_schemas(user_id)
+    }
+
+    pub fn get_schema_versions(&self, user_id: u32) -> Result<(), IggyError> {
+        self.read_schemas(user_id)
+    }
+
+    pub fn register_schema(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_schemas(user_id)
+    }
+
+    pub fn update_schema_compatibility(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_schemas(user_id)
+    }
+
+    fn manage_schemas(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_streams || global_permissions.manage_topics {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    /// The schemas are not bound to the streams, so they can be read by the users that can read the messages of any stream,
+    /// e.g. to encode or decode the payloads, or read the topics of any stream.
+    fn read_schemas(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_streams
+                || global_permissions.read_streams
+                || global_permissions.manage_topics
+                || global_permissions.read_topics
+                || global_permissions.poll_messages
+                || global_permissions.send_messages
+            {
+                return Ok(());
+            }
+        }
+
+        let can_read_any_stream = self
+            .users_streams_permissions
+            .iter()
+            .filter(|((stream_user_id, _), _)| *stream_user_id == user_id)
+            .any(|(_, stream_permissions)| {
+                stream_permissions.manage_stream
+                    || stream_permissions.read_stream
+                    || stream_permissions.manage_topics
+                    || stream_permissions.read_topics
+                    || stream_permissions.poll_messages
+                    || stream_permissions.send_messages
+                    || stream_permissions.manage_partitions
+                    || stream_permissions.manage_consumer_groups
+                    || stream_permissions.topics.as_ref().is_some_and(|topics| {
+                        topics.values().any(|topic_permissions| {
+                            topic_permissions.manage_topic
+                                || topic_permissions.read_topic
+                                || topic_permissions.poll_messages
+                                || topic_permissions.send_messages
+                        })
+                    })
+            });
+        if can_read_any_stream {
+            return Ok(());
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+}
@@ -3,6 +3,7 @@ use iggy::error::IggyError;
 
 impl Permissioner {
     pub fn get_stream(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.read_streams {
                 return Ok(());
@@ -52,6 +53,7 @@ impl Permissioner {
     }
 
     fn manage_stream(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams {
                 return Ok(());
@@ -14,7 +14,29 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
-    fn get_server_info(&self, user_id: u32) -> Result<(), IggyError> {
+    pub fn get_connectors(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn manage_connectors(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_server(user_id)
+    }
+
+    pub(super) fn manage_server(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    pub(super) fn get_server_info(&self, user_id: u32) -> Result<(), IggyError> {
+        if self.is_tenant_user(user_id) {
+            return Err(IggyError::Unauthorized);
+        }
+
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_servers || global_permissions.read_servers {
                 return Ok(());
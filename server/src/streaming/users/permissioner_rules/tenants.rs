@@ -0,0 +1,91 @@
+use crate::streaming::users::permissioner::Permissioner;
+use iggy::error::IggyError;
+
+impl Permissioner {
+    pub fn get_tenant(&self, user_id: u32, tenant_id: u32) -> Result<(), IggyError> {
+        if let Some(user_tenant_id) = self.users_tenants.get(&user_id) {
+            return match *user_tenant_id == tenant_id {
+                true => Ok(()),
+                false => Err(IggyError::Unauthorized),
+            };
+        }
+
+        self.read_tenants(user_id)
+    }
+
+    pub fn get_tenants(&self, user_id: u32) -> Result<(), IggyError> {
+        if self.users_tenants.contains_key(&user_id) {
+            return Ok(());
+        }
+
+        self.read_tenants(user_id)
+    }
+
+    pub fn create_tenant(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_tenants(user_id)
+    }
+
+    pub fn update_tenant(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_tenants(user_id)
+    }
+
+    pub fn delete_tenant(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_tenants(user_id)
+    }
+
+    pub fn add_tenant_user(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_tenants(user_id)
+    }
+
+    pub fn remove_tenant_user(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_tenants(user_id)
+    }
+
+    /// The user assigned to the tenant can access only the users assigned to the same tenant.
+    pub fn access_user(&self, user_id: u32, accessed_user_id: u32) -> Result<(), IggyError> {
+        match self.users_tenants.get(&user_id) {
+            Some(tenant_id) if self.users_tenants.get(&accessed_user_id) != Some(tenant_id) => {
+                Err(IggyError::Unauthorized)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The user assigned to the tenant can access only the streams owned by the same tenant.
+    pub(super) fn access_stream(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        match self.users_tenants.get(&user_id) {
+            Some(tenant_id) if self.streams_tenants.get(&stream_id) != Some(tenant_id) => {
+                Err(IggyError::Unauthorized)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn is_tenant_user(&self, user_id: u32) -> bool {
+        self.users_tenants.contains_key(&user_id)
+    }
+
+    fn read_tenants(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers || global_permissions.read_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
+    fn manage_tenants(&self, user_id: u32) -> Result<(), IggyError> {
+        if self.is_tenant_user(user_id) {
+            return Err(IggyError::Unauthorized);
+        }
+
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+}
@@ -3,6 +3,7 @@ use iggy::error::IggyError;
 
 impl Permissioner {
     pub fn get_topic(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.read_streams
                 || global_permissions.manage_streams
@@ -15,12 +16,18 @@ impl Permissioner {
 
         if let Some(stream_permissions) = self.users_streams_permissions.get(&(user_id, stream_id))
         {
-            if stream_permissions.manage_topics || stream_permissions.read_topics {
+            if stream_permissions.manage_topics
+                || stream_permissions.read_topics
+                || stream_permissions.manage_partitions
+                || stream_permissions.manage_consumer_groups
+            {
                 return Ok(());
             }
 
-            if let Some(topic_permissions) =
-                stream_permissions.topics.as_ref().unwrap().get(&topic_id)
+            if let Some(topic_permissions) = stream_permissions
+                .topics
+                .as_ref()
+                .and_then(|topics| topics.get(&topic_id))
             {
                 if topic_permissions.manage_topic || topic_permissions.read_topic {
                     return Ok(());
@@ -32,6 +39,7 @@ impl Permissioner {
     }
 
     pub fn get_topics(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.read_streams
                 || global_permissions.manage_streams
@@ -44,12 +52,18 @@ impl Permissioner {
 
         if let Some(stream_permissions) = self.users_streams_permissions.get(&(user_id, stream_id))
         {
-            if stream_permissions.manage_topics || stream_permissions.read_topics {
+            if stream_permissions.manage_topics
+                || stream_permissions.read_topics
+                || stream_permissions.manage_partitions
+                || stream_permissions.manage_consumer_groups
+            {
                 return Ok(());
             }
 
-            if let Some(topic_permissions) =
-                stream_permissions.topics.as_ref().unwrap().get(&stream_id)
+            if let Some(topic_permissions) = stream_permissions
+                .topics
+                .as_ref()
+                .and_then(|topics| topics.get(&stream_id))
             {
                 if topic_permissions.manage_topic || topic_permissions.read_topic {
                     return Ok(());
@@ -61,6 +75,7 @@ impl Permissioner {
     }
 
     pub fn create_topic(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.manage_topics {
                 return Ok(());
@@ -77,6 +92,30 @@ impl Permissioner {
         Err(IggyError::Unauthorized)
     }
 
+    pub fn auto_create_topic(&self, user_id: u32, stream_id: u32) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_streams
+                || global_permissions.manage_topics
+                || global_permissions.auto_create_topics
+            {
+                return Ok(());
+            }
+        }
+
+        if let Some(stream_permissions) = self.users_streams_permissions.get(&(user_id, stream_id))
+        {
+            if stream_permissions.manage_stream
+                || stream_permissions.manage_topics
+                || stream_permissions.auto_create_topics
+            {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
     pub fn update_topic(
         &self,
         user_id: u32,
@@ -105,6 +144,7 @@ impl Permissioner {
     }
 
     fn manage_topic(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), IggyError> {
+        self.access_stream(user_id, stream_id)?;
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.manage_topics {
                 return Ok(());
@@ -117,8 +157,10 @@ impl Permissioner {
                 return Ok(());
             }
 
-            if let Some(topic_permissions) =
-                stream_permissions.topics.as_ref().unwrap().get(&topic_id)
+            if let Some(topic_permissions) = stream_permissions
+                .topics
+                .as_ref()
+                .and_then(|topics| topics.get(&topic_id))
             {
                 if topic_permissions.manage_topic {
                     return Ok(());
@@ -0,0 +1,49 @@
+use crate::streaming::users::permissioner::Permissioner;
+use iggy::error::IggyError;
+
+impl Permissioner {
+    pub fn get_user_group(&self, user_id: u32) -> Result<(), IggyError> {
+        self.read_user_groups(user_id)
+    }
+
+    pub fn get_user_groups(&self, user_id: u32) -> Result<(), IggyError> {
+        self.read_user_groups(user_id)
+    }
+
+    pub fn create_user_group(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_user_groups(user_id)
+    }
+
+    pub fn update_user_group_permissions(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_user_groups(user_id)
+    }
+
+    pub fn delete_user_group(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_user_groups(user_id)
+    }
+
+    pub fn add_user_group_member(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_user_groups(user_id)
+    }
+
+    pub fn remove_user_group_member(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_user_groups(user_id)
+    }
+
+    /// The user groups are shared by all the tenants, thus the user assigned to the tenant can't access them.
+    fn read_user_groups(&self, user_id: u32) -> Result<(), IggyError> {
+        if self.is_tenant_user(user_id) {
+            return Err(IggyError::Unauthorized);
+        }
+
+        self.read_users(user_id)
+    }
+
+    fn manage_user_groups(&self, user_id: u32) -> Result<(), IggyError> {
+        if self.is_tenant_user(user_id) {
+            return Err(IggyError::Unauthorized);
+        }
+
+        self.manager_users(user_id)
+    }
+}
@@ -113,10 +113,16 @@ impl Storage<User> for FileUserStorage {
         match user_data {
             Ok(user_data) => {
                 user.status = user_data.status;
+                user.uuid = user_data.uuid;
                 user.username = user_data.username;
                 user.password = user_data.password;
                 user.created_at = user_data.created_at;
                 user.permissions = user_data.permissions;
+                user.tenant_id = user_data.tenant_id;
+                user.session_epoch = user_data.session_epoch;
+                user.expires_at = user_data.expires_at;
+                user.max_inactive_days = user_data.max_inactive_days;
+                user.last_active_at = user_data.last_active_at;
                 Ok(())
             }
             Err(err) => {
@@ -1,29 +1,62 @@
+use crate::configs::system::AnonymousAccessConfig;
 use crate::streaming::utils::crypto;
+use iggy::models::permissions::{StreamPermissions, TopicPermissions};
 use iggy::models::user_status::UserStatus;
 use iggy::models::{permissions::Permissions, user_info::UserId};
 use iggy::users::defaults::*;
 use iggy::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// The ID of the pseudo-user the unauthenticated sessions are mapped to, if the anonymous access is enabled.
+pub const ANONYMOUS_USER_ID: UserId = UserId::MAX;
+const ANONYMOUS_USERNAME: &str = "anonymous";
+const MICROS_PER_DAY: u64 = 24 * 60 * 60 * 1_000_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: UserId,
+    /// The immutable UUID of the user, nil for the users saved before the UUIDs were introduced until they're loaded.
+    #[serde(default)]
+    pub uuid: Uuid,
     pub status: UserStatus,
     pub username: String,
     pub password: String,
     pub created_at: u64,
     pub permissions: Option<Permissions>,
+    #[serde(default)]
+    pub tenant_id: Option<u32>,
+    /// Incremented to invalidate all the active sessions and issued tokens of the user.
+    #[serde(default)]
+    pub session_epoch: u32,
+    /// The timestamp after which the user is disabled.
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+    /// The number of days without any activity after which the user is disabled.
+    #[serde(default)]
+    pub max_inactive_days: Option<u32>,
+    /// The timestamp of the last activity (login, refreshed token or command) or reactivation, the inactivity is counted
+    /// from the creation if not set.
+    #[serde(default)]
+    pub last_active_at: Option<u64>,
 }
 
 impl Default for User {
     fn default() -> Self {
         Self {
             id: 1,
+            uuid: Uuid::new_v4(),
             status: UserStatus::Active,
             username: "user".to_string(),
             password: "secret".to_string(),
             created_at: IggyTimestamp::now().to_micros(),
             permissions: None,
+            tenant_id: None,
+            session_epoch: 0,
+            expires_at: None,
+            max_inactive_days: None,
+            last_active_at: None,
         }
     }
 }
@@ -45,11 +78,17 @@ impl User {
     ) -> Self {
         Self {
             id,
+            uuid: Uuid::new_v4(),
             username: username.to_string(),
             password: crypto::hash_password(password),
             created_at: IggyTimestamp::now().to_micros(),
             status,
             permissions,
+            tenant_id: None,
+            session_epoch: 0,
+            expires_at: None,
+            max_inactive_days: None,
+            last_active_at: None,
         }
     }
 
@@ -63,6 +102,57 @@ impl User {
         )
     }
 
+    /// Creates the pseudo-user with the read-only access to the allowed streams and topics, it's never stored.
+    pub fn anonymous(config: &AnonymousAccessConfig) -> Self {
+        let streams = config
+            .streams
+            .iter()
+            .map(|stream| {
+                let read_stream = stream.topic_ids.is_empty();
+                let topics = match read_stream {
+                    true => None,
+                    false => Some(
+                        stream
+                            .topic_ids
+                            .iter()
+                            .map(|topic_id| {
+                                (
+                                    *topic_id,
+                                    TopicPermissions {
+                                        read_topic: true,
+                                        poll_messages: true,
+                                        ..Default::default()
+                                    },
+                                )
+                            })
+                            .collect(),
+                    ),
+                };
+                (
+                    stream.stream_id,
+                    StreamPermissions {
+                        read_stream,
+                        read_topics: read_stream,
+                        poll_messages: read_stream,
+                        topics,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        Self {
+            id: ANONYMOUS_USER_ID,
+            username: ANONYMOUS_USERNAME.to_string(),
+            password: String::new(),
+            permissions: Some(Permissions {
+                global: Default::default(),
+                streams: Some(streams),
+            }),
+            ..Default::default()
+        }
+    }
+
     pub fn is_root(&self) -> bool {
         self.id == DEFAULT_ROOT_USER_ID
     }
@@ -70,11 +160,30 @@ impl User {
     pub fn is_active(&self) -> bool {
         self.status == UserStatus::Active
     }
+
+    pub fn revoke_sessions(&mut self) {
+        self.session_epoch = self.session_epoch.wrapping_add(1);
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// Returns `true` if the user hasn't been active (nor was reactivated) within the inactivity window.
+    pub fn exceeds_inactivity(&self, now: u64) -> bool {
+        let Some(max_inactive_days) = self.max_inactive_days else {
+            return false;
+        };
+
+        let last_active_at = self.last_active_at.unwrap_or(self.created_at);
+        now >= last_active_at + max_inactive_days as u64 * MICROS_PER_DAY
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::configs::system::AnonymousStreamConfig;
 
     #[test]
     fn given_root_user_data_and_credentials_should_be_valid() {
@@ -96,4 +205,66 @@ mod tests {
         let user = User::new(1, "test", "test", status, None);
         assert_eq!(user.status, status);
     }
+
+    #[test]
+    fn anonymous_user_should_have_read_only_access_to_allowed_streams_and_topics() {
+        let config = AnonymousAccessConfig {
+            enabled: true,
+            streams: vec![
+                AnonymousStreamConfig {
+                    stream_id: 1,
+                    topic_ids: vec![],
+                },
+                AnonymousStreamConfig {
+                    stream_id: 2,
+                    topic_ids: vec![3],
+                },
+            ],
+        };
+        let user = User::anonymous(&config);
+        assert_eq!(user.id, ANONYMOUS_USER_ID);
+        let permissions = user.permissions.unwrap();
+        assert_eq!(permissions.global, Default::default());
+        let streams = permissions.streams.unwrap();
+        let stream = streams.get(&1).unwrap();
+        assert!(stream.read_stream && stream.read_topics && stream.poll_messages);
+        assert!(!stream.manage_stream && !stream.manage_topics && !stream.send_messages);
+        let stream = streams.get(&2).unwrap();
+        assert!(!stream.read_stream && !stream.poll_messages);
+        let topic = stream.topics.as_ref().unwrap().get(&3).unwrap();
+        assert!(topic.read_topic && topic.poll_messages);
+        assert!(!topic.manage_topic && !topic.send_messages);
+    }
+
+    #[test]
+    fn revoking_sessions_should_increment_session_epoch() {
+        let mut user = User::new(1, "test", "test", UserStatus::Active, None);
+        assert_eq!(user.session_epoch, 0);
+        user.revoke_sessions();
+        assert_eq!(user.session_epoch, 1);
+    }
+
+    #[test]
+    fn user_should_be_expired_once_expiry_passes() {
+        let mut user = User::new(1, "test", "test", UserStatus::Active, None);
+        let now = IggyTimestamp::now().to_micros();
+        assert!(!user.is_expired(now));
+        user.expires_at = Some(now + 1);
+        assert!(!user.is_expired(now));
+        user.expires_at = Some(now);
+        assert!(user.is_expired(now));
+    }
+
+    #[test]
+    fn user_should_exceed_inactivity_since_last_activity_or_creation() {
+        let mut user = User::new(1, "test", "test", UserStatus::Active, None);
+        let now = user.created_at + 10 * MICROS_PER_DAY;
+        assert!(!user.exceeds_inactivity(now));
+        user.max_inactive_days = Some(10);
+        assert!(user.exceeds_inactivity(now));
+        user.last_active_at = Some(user.created_at + MICROS_PER_DAY);
+        assert!(!user.exceeds_inactivity(now));
+        user.max_inactive_days = Some(9);
+        assert!(user.exceeds_inactivity(now));
+    }
 }
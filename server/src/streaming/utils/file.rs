@@ -1,6 +1,6 @@
 use atone::Vc;
 use std::path::{Path, PathBuf};
-use tokio::fs::{read_dir, File, OpenOptions};
+use tokio::fs::{create_dir_all, read_dir, rename, File, OpenOptions};
 
 pub async fn open(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().read(true).open(path).await
@@ -14,6 +14,15 @@ pub async fn write(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().create(true).write(true).open(path).await
 }
 
+pub async fn overwrite(path: &str) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(path)
+        .await
+}
+
 pub async fn folder_size<P>(path: P) -> std::io::Result<u64>
 where
     P: Into<PathBuf> + AsRef<Path>,
@@ -37,3 +46,11 @@ where
     }
     Ok(total_size)
 }
+
+/// Moves the directory by renaming it, the parent directory of the destination is created if needed.
+pub async fn move_dir(source: &str, destination: &str) -> std::io::Result<()> {
+    if let Some(parent) = Path::new(destination).parent() {
+        create_dir_all(parent).await?;
+    }
+    rename(source, destination).await
+}
@@ -2,3 +2,4 @@ pub mod crypto;
 pub mod file;
 pub mod hash;
 pub mod random_id;
+pub mod varint;
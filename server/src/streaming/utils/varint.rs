@@ -0,0 +1,104 @@
+use bytes::{Buf, BufMut};
+
+/// Writes the value using the LEB128 encoding, 7 bits per byte, so that the small values take a single byte.
+pub fn put_u64(bytes: &mut impl BufMut, mut value: u64) {
+    while value >= 0x80 {
+        bytes.put_u8((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.put_u8(value as u8);
+}
+
+/// Writes the signed value using the zigzag encoding, so that the small negative values take a single byte as well.
+pub fn put_i64(bytes: &mut impl BufMut, value: i64) {
+    put_u64(bytes, ((value << 1) ^ (value >> 63)) as u64);
+}
+
+/// Reads the LEB128 encoded value, returns `None` if the bytes end before the value does or it doesn't fit into `u64`.
+pub fn get_u64(bytes: &mut impl Buf) -> Option<u64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    while shift < 64 {
+        if !bytes.has_remaining() {
+            return None;
+        }
+
+        let byte = bytes.get_u8();
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+        shift += 7;
+    }
+    None
+}
+
+/// Reads the zigzag encoded value.
+pub fn get_i64(bytes: &mut impl Buf) -> Option<i64> {
+    let value = get_u64(bytes)?;
+    Some(((value >> 1) as i64) ^ -((value & 1) as i64))
+}
+
+/// Returns the number of bytes taken by the LEB128 encoded value.
+pub fn get_u64_size(value: u64) -> u32 {
+    let bits = 64 - (value | 1).leading_zeros();
+    bits.div_ceil(7)
+}
+
+/// Returns the number of bytes taken by the zigzag encoded value.
+pub fn get_i64_size(value: i64) -> u32 {
+    get_u64_size(((value << 1) ^ (value >> 63)) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::BytesMut;
+
+    #[test]
+    fn unsigned_values_should_be_encoded_and_decoded() {
+        for value in [
+            0,
+            1,
+            127,
+            128,
+            300,
+            16_383,
+            16_384,
+            u32::MAX as u64,
+            u64::MAX,
+        ] {
+            let mut bytes = BytesMut::new();
+            put_u64(&mut bytes, value);
+            assert_eq!(bytes.len() as u32, get_u64_size(value));
+            assert_eq!(get_u64(&mut bytes.freeze()), Some(value));
+        }
+    }
+
+    #[test]
+    fn signed_values_should_be_encoded_and_decoded() {
+        for value in [0, 1, -1, 63, -64, 64, i32::MIN as i64, i64::MAX, i64::MIN] {
+            let mut bytes = BytesMut::new();
+            put_i64(&mut bytes, value);
+            assert_eq!(bytes.len() as u32, get_i64_size(value));
+            assert_eq!(get_i64(&mut bytes.freeze()), Some(value));
+        }
+    }
+
+    #[test]
+    fn small_values_should_take_single_byte() {
+        assert_eq!(get_u64_size(0), 1);
+        assert_eq!(get_u64_size(127), 1);
+        assert_eq!(get_u64_size(128), 2);
+        assert_eq!(get_i64_size(-64), 1);
+        assert_eq!(get_u64_size(u64::MAX), 10);
+    }
+
+    #[test]
+    fn truncated_value_should_not_be_decoded() {
+        let mut bytes = BytesMut::new();
+        put_u64(&mut bytes, 300);
+        bytes.truncate(1);
+        assert_eq!(get_u64(&mut bytes.freeze()), None);
+    }
+}
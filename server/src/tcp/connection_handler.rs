@@ -1,29 +1,51 @@
 use crate::binary::command;
+use crate::binary::connection_limiter::ConnectionLimiter;
 use crate::binary::sender::Sender;
+use crate::configs::server::{BackpressureConfig, HeartbeatConfig};
 use crate::server_error::ServerError;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::Command;
+use iggy::utils::buffer_pool::BufferPool;
 use std::io::ErrorKind;
 use std::net::SocketAddr;
-use tracing::{debug, error, info};
+use tokio::task::yield_now;
+use tokio::time::timeout;
+use tracing::{debug, error, info, warn};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
+const DISCARD_BUFFER_LENGTH: usize = 64 * 1024;
 
 pub(crate) async fn handle_connection(
     address: SocketAddr,
     sender: &mut dyn Sender,
+    heartbeat: HeartbeatConfig,
+    backpressure: BackpressureConfig,
     system: SharedSystem,
 ) -> Result<(), ServerError> {
     let client_id = system.read().add_client(&address, Transport::Tcp).await;
+    let limiter = ConnectionLimiter::new(&backpressure);
 
-    let session = Session::from_client_id(client_id, address);
+    let session = Session::from_client_id(client_id, address).with_transport(Transport::Tcp);
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
     loop {
-        let read_length = sender.read(&mut initial_buffer).await?;
+        let read_length = match heartbeat.enabled {
+            true => timeout(
+                heartbeat.interval.get_duration(),
+                sender.read(&mut initial_buffer),
+            )
+            .await
+            .map_err(|_| {
+                ServerError::HeartbeatTimeout(format!(
+                    "no command received from client: {address} within: {}",
+                    heartbeat.interval
+                ))
+            })??,
+            false => sender.read(&mut initial_buffer).await?,
+        };
         if read_length != INITIAL_BYTES_LENGTH {
             return Err(ServerError::CommandLengthError(format!(
                 "Unable to read the TCP request length, expected: {INITIAL_BYTES_LENGTH} bytes, received: {read_length} bytes."
@@ -32,16 +54,43 @@ pub(crate) async fn handle_connection(
 
         let length = u32::from_le_bytes(initial_buffer);
         debug!("Received a TCP request, length: {length}");
-        let mut command_buffer = BytesMut::with_capacity(length as usize);
+        let permit = match limiter.try_acquire(length) {
+            Ok(permit) => permit,
+            Err(error) => {
+                warn!("Throttling the TCP request from client: {address}, length: {length}, reason: {error}");
+                discard(sender, length as usize).await?;
+                sender.send_error_response(error).await?;
+                continue;
+            }
+        };
+
+        let buffer_pool = BufferPool::global();
+        let mut command_buffer = buffer_pool.acquire(length as usize);
         command_buffer.put_bytes(0, length as usize);
         sender.read(&mut command_buffer).await?;
-        let command = Command::from_bytes(command_buffer.freeze())?;
+        let command = Command::from_bytes(buffer_pool.freeze(command_buffer))?;
         debug!("Received a TCP command: {command}, payload size: {length}");
         command::handle(&command, sender, &session, system.clone()).await?;
         debug!("Sent a TCP response.");
+        drop(permit);
+        if limiter.is_enabled() {
+            // Let the other connections make progress when the client keeps sending the commands without a pause.
+            yield_now().await;
+        }
     }
 }
 
+/// Reads and drops the payload of the rejected request, so that the next request can be read from the stream.
+async fn discard(sender: &mut dyn Sender, mut length: usize) -> Result<(), ServerError> {
+    let mut buffer = vec![0u8; length.min(DISCARD_BUFFER_LENGTH)];
+    while length > 0 {
+        let chunk_length = length.min(buffer.len());
+        sender.read(&mut buffer[..chunk_length]).await?;
+        length -= chunk_length;
+    }
+    Ok(())
+}
+
 pub(crate) fn handle_error(error: ServerError) {
     match error {
         ServerError::IoError(error) => match error.kind() {
@@ -62,6 +111,9 @@ pub(crate) fn handle_error(error: ServerError) {
             }
         },
         ServerError::SdkError(_) => {}
+        ServerError::HeartbeatTimeout(reason) => {
+            warn!("Connection has been closed, heartbeat timeout: {reason}.");
+        }
         _ => {
             error!("Connection has failed: {error}");
         }
@@ -3,5 +3,6 @@ pub mod sender;
 pub mod tcp_listener;
 mod tcp_sender;
 pub mod tcp_server;
+mod tcp_socket;
 pub mod tcp_tls_listener;
 pub mod tcp_tls_sender;
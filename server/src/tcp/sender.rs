@@ -1,8 +1,10 @@
+use bytes::{BufMut, BytesMut};
 use iggy::error::IggyError;
+use iggy::utils::buffer_pool::BufferPool;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tracing::debug;
 
-const STATUS_OK: &[u8] = &[0; 4];
+pub(crate) const STATUS_OK: &[u8] = &[0; 4];
 
 pub(crate) async fn read<T>(stream: &mut T, buffer: &mut [u8]) -> Result<usize, IggyError>
 where
@@ -49,10 +51,19 @@ where
     T: AsyncRead + AsyncWrite + Unpin,
 {
     debug!("Sending response with status: {:?}...", status);
-    let length = (payload.len() as u32).to_le_bytes();
-    stream
-        .write_all(&[status, &length, payload].as_slice().concat())
-        .await?;
+    let buffer_pool = BufferPool::global();
+    let mut response = buffer_pool.acquire(status.len() + 4 + payload.len());
+    put_response(&mut response, status, payload);
+    let result = stream.write_all(&response).await;
+    buffer_pool.release(response);
+    result?;
     debug!("Sent response with status: {:?}", status);
     Ok(())
 }
+
+/// Appends the response to the buffer in the same format, in which it's sent on its own.
+pub(crate) fn put_response(buffer: &mut BytesMut, status: &[u8], payload: &[u8]) {
+    buffer.put_slice(status);
+    buffer.put_u32_le(payload.len() as u32);
+    buffer.put_slice(payload);
+}
@@ -1,16 +1,28 @@
+use crate::configs::ip_filter::IpFilterConfig;
+use crate::configs::server::{BackpressureConfig, HeartbeatConfig};
+use crate::configs::tcp::{TcpResponseCoalescingConfig, TcpSocketConfig};
+use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
 use crate::tcp::tcp_sender::TcpSender;
+use crate::tcp::tcp_socket;
 use std::net::SocketAddr;
-use tokio::net::TcpListener;
 use tokio::sync::oneshot;
 use tracing::{error, info};
 
-pub async fn start(address: &str, system: SharedSystem) -> SocketAddr {
+pub async fn start(
+    address: &str,
+    socket: TcpSocketConfig,
+    response_coalescing: TcpResponseCoalescingConfig,
+    heartbeat: HeartbeatConfig,
+    backpressure: BackpressureConfig,
+    ip_filter: IpFilterConfig,
+    system: SharedSystem,
+) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
-        let listener = TcpListener::bind(&address)
+        let listener = tcp_socket::bind(&address, &socket)
             .await
             .expect("Unable to start TCP TLS server.");
 
@@ -28,12 +40,31 @@ pub async fn start(address: &str, system: SharedSystem) -> SocketAddr {
         loop {
             match listener.accept().await {
                 Ok((stream, address)) => {
+                    if system.read().is_draining() {
+                        info!(
+                            "Rejected new TCP connection: {address}, the server is shutting down."
+                        );
+                        continue;
+                    }
+
+                    if !ip_filter.is_allowed(&address.ip()) {
+                        system.read().reject_connection(&address, Transport::Tcp);
+                        continue;
+                    }
+
                     info!("Accepted new TCP connection: {}", address);
                     let system = system.clone();
-                    let mut sender = TcpSender { stream };
+                    tcp_socket::configure(&stream, &socket);
+                    let mut sender = TcpSender::new(stream, &response_coalescing);
                     tokio::spawn(async move {
-                        if let Err(error) =
-                            handle_connection(address, &mut sender, system.clone()).await
+                        if let Err(error) = handle_connection(
+                            address,
+                            &mut sender,
+                            heartbeat,
+                            backpressure,
+                            system.clone(),
+                        )
+                        .await
                         {
                             handle_error(error);
                             system.read().delete_client(&address).await;
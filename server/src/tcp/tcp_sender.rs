@@ -1,12 +1,104 @@
 use crate::binary::sender::Sender;
+use crate::configs::tcp::TcpResponseCoalescingConfig;
 use crate::tcp::sender;
 use async_trait::async_trait;
+use bytes::{Buf, BytesMut};
 use iggy::error::IggyError;
+use std::io::ErrorKind;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
 
+const READ_AHEAD_SIZE: usize = 16 * 1024;
+
 #[derive(Debug)]
 pub struct TcpSender {
     pub(crate) stream: TcpStream,
+    pub(crate) coalescing: Option<ResponseCoalescing>,
+}
+
+/// The responses are buffered, as long as the client has already sent the next requests (e.g. when pipelining the polls),
+/// and they're written together once there's no request left to handle or the buffer is full, so the tiny responses don't end up in separate packets.
+/// As the buffer is always flushed before waiting for the next request, no response is delayed.
+#[derive(Debug)]
+pub(crate) struct ResponseCoalescing {
+    max_size: usize,
+    responses: BytesMut,
+    read_ahead: BytesMut,
+}
+
+impl ResponseCoalescing {
+    pub fn new(config: &TcpResponseCoalescingConfig) -> Option<Self> {
+        if !config.enabled {
+            return None;
+        }
+
+        let max_size = config.max_size.as_bytes_u64() as usize;
+        Some(Self {
+            max_size,
+            responses: BytesMut::with_capacity(max_size),
+            read_ahead: BytesMut::new(),
+        })
+    }
+}
+
+impl TcpSender {
+    pub fn new(stream: TcpStream, config: &TcpResponseCoalescingConfig) -> Self {
+        Self {
+            stream,
+            coalescing: ResponseCoalescing::new(config),
+        }
+    }
+
+    async fn read_buffered(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        let Some(coalescing) = self.coalescing.as_mut() else {
+            return sender::read(&mut self.stream, buffer).await;
+        };
+
+        let mut position = 0;
+        loop {
+            let length = coalescing.read_ahead.len().min(buffer.len() - position);
+            buffer[position..position + length].copy_from_slice(&coalescing.read_ahead[..length]);
+            coalescing.read_ahead.advance(length);
+            position += length;
+            if position == buffer.len() {
+                return Ok(position);
+            }
+
+            if coalescing.responses.is_empty() {
+                self.stream.read_exact(&mut buffer[position..]).await?;
+                return Ok(buffer.len());
+            }
+
+            // The pending responses are kept only while the next request is already available, otherwise the client might be waiting for them.
+            coalescing.read_ahead.reserve(READ_AHEAD_SIZE);
+            match self.stream.try_read_buf(&mut coalescing.read_ahead) {
+                Ok(0) => {
+                    return Err(IggyError::from(std::io::Error::from(
+                        ErrorKind::UnexpectedEof,
+                    )))
+                }
+                Ok(_) => continue,
+                Err(error) if error.kind() == ErrorKind::WouldBlock => {
+                    self.stream.write_all(&coalescing.responses).await?;
+                    coalescing.responses.clear();
+                }
+                Err(error) => return Err(IggyError::from(error)),
+            }
+        }
+    }
+
+    async fn send_buffered(&mut self, status: &[u8], payload: &[u8]) -> Result<(), IggyError> {
+        let Some(coalescing) = self.coalescing.as_mut() else {
+            return sender::send_response(&mut self.stream, status, payload).await;
+        };
+
+        sender::put_response(&mut coalescing.responses, status, payload);
+        if coalescing.responses.len() >= coalescing.max_size {
+            self.stream.write_all(&coalescing.responses).await?;
+            coalescing.responses.clear();
+        }
+        Ok(())
+    }
 }
 
 unsafe impl Send for TcpSender {}
@@ -15,18 +107,19 @@ unsafe impl Sync for TcpSender {}
 #[async_trait]
 impl Sender for TcpSender {
     async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
-        sender::read(&mut self.stream, buffer).await
+        self.read_buffered(buffer).await
     }
 
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
-        sender::send_empty_ok_response(&mut self.stream).await
+        self.send_buffered(sender::STATUS_OK, &[]).await
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
-        sender::send_ok_response(&mut self.stream, payload).await
+        self.send_buffered(sender::STATUS_OK, payload).await
     }
 
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        sender::send_error_response(&mut self.stream, error).await
+        self.send_buffered(&error.as_code().to_le_bytes(), &[])
+            .await
     }
 }
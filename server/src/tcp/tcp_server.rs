@@ -1,3 +1,4 @@
+use crate::configs::server::{BackpressureConfig, HeartbeatConfig};
 use crate::configs::tcp::TcpConfig;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::{tcp_listener, tcp_tls_listener};
@@ -6,16 +7,44 @@ use tracing::info;
 
 /// Starts the TCP server.
 /// Returns the address the server is listening on.
-pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
+pub async fn start(
+    config: TcpConfig,
+    heartbeat: HeartbeatConfig,
+    backpressure: BackpressureConfig,
+    system: SharedSystem,
+) -> SocketAddr {
     let server_name = if config.tls.enabled {
         "Iggy TCP TLS"
     } else {
         "Iggy TCP"
     };
     info!("Initializing {server_name} server...");
+    // The responses are coalesced only without TLS, as the TLS stream can't be checked for the pending requests without blocking.
     let addr = match config.tls.enabled {
-        true => tcp_tls_listener::start(&config.address, config.tls, system).await,
-        false => tcp_listener::start(&config.address, system).await,
+        true => {
+            tcp_tls_listener::start(
+                &config.address,
+                config.tls,
+                config.socket,
+                heartbeat,
+                backpressure,
+                config.ip_filter,
+                system,
+            )
+            .await
+        }
+        false => {
+            tcp_listener::start(
+                &config.address,
+                config.socket,
+                config.response_coalescing,
+                heartbeat,
+                backpressure,
+                config.ip_filter,
+                system,
+            )
+            .await
+        }
     };
     info!("{server_name} server has started on: {:?}", addr);
     addr
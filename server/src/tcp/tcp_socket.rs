@@ -0,0 +1,46 @@
+use crate::configs::tcp::TcpSocketConfig;
+use std::io::{Error, ErrorKind};
+use tokio::net::{lookup_host, TcpListener, TcpSocket, TcpStream};
+use tracing::warn;
+
+const LISTEN_BACKLOG: u32 = 1024;
+
+/// Binds the listener for the provided address. Unless the defaults are overridden, the OS settings are used as they are.
+/// The buffer sizes are set on the listening socket, so they're inherited by the accepted connections,
+/// and the receive buffer is in place before the handshake, which determines the TCP window scaling.
+pub(crate) async fn bind(address: &str, config: &TcpSocketConfig) -> Result<TcpListener, Error> {
+    if !config.override_defaults {
+        return TcpListener::bind(address).await;
+    }
+
+    let address = lookup_host(address).await?.next().ok_or_else(|| {
+        Error::new(
+            ErrorKind::AddrNotAvailable,
+            format!("Unable to resolve TCP address: {address}"),
+        )
+    })?;
+    let socket = match address.is_ipv4() {
+        true => TcpSocket::new_v4()?,
+        false => TcpSocket::new_v6()?,
+    };
+    #[cfg(not(windows))]
+    socket.set_reuseaddr(true)?;
+    socket.set_recv_buffer_size(config.recv_buffer_size.as_bytes_u64() as u32)?;
+    socket.set_send_buffer_size(config.send_buffer_size.as_bytes_u64() as u32)?;
+    socket.bind(address)?;
+    socket.listen(LISTEN_BACKLOG)
+}
+
+/// Applies the per-connection options, which aren't inherited from the listening socket.
+pub(crate) fn configure(stream: &TcpStream, config: &TcpSocketConfig) {
+    if !config.override_defaults {
+        return;
+    }
+
+    if let Err(error) = stream.set_nodelay(config.nodelay) {
+        warn!(
+            "Failed to set TCP_NODELAY: {} on the TCP connection. Error: {error}",
+            config.nodelay
+        );
+    }
+}
@@ -1,16 +1,27 @@
 use std::net::SocketAddr;
 
-use crate::configs::tcp::TcpTlsConfig;
+use crate::configs::ip_filter::IpFilterConfig;
+use crate::configs::server::{BackpressureConfig, HeartbeatConfig};
+use crate::configs::tcp::{TcpSocketConfig, TcpTlsConfig};
+use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
+use crate::tcp::tcp_socket;
 use crate::tcp::tcp_tls_sender::TcpTlsSender;
-use tokio::net::TcpListener;
 use tokio::sync::oneshot;
 use tokio_native_tls::native_tls;
 use tokio_native_tls::native_tls::Identity;
 use tracing::{error, info};
 
-pub(crate) async fn start(address: &str, config: TcpTlsConfig, system: SharedSystem) -> SocketAddr {
+pub(crate) async fn start(
+    address: &str,
+    config: TcpTlsConfig,
+    socket: TcpSocketConfig,
+    heartbeat: HeartbeatConfig,
+    backpressure: BackpressureConfig,
+    ip_filter: IpFilterConfig,
+    system: SharedSystem,
+) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
@@ -30,7 +41,7 @@ pub(crate) async fn start(address: &str, config: TcpTlsConfig, system: SharedSys
                 .unwrap(),
         );
 
-        let listener = TcpListener::bind(&address)
+        let listener = tcp_socket::bind(&address, &socket)
             .await
             .expect("Unable to start TCP TLS server.");
 
@@ -48,14 +59,31 @@ pub(crate) async fn start(address: &str, config: TcpTlsConfig, system: SharedSys
         loop {
             match listener.accept().await {
                 Ok((stream, address)) => {
+                    if system.read().is_draining() {
+                        info!("Rejected new TCP TLS connection: {address}, the server is shutting down.");
+                        continue;
+                    }
+
+                    if !ip_filter.is_allowed(&address.ip()) {
+                        system.read().reject_connection(&address, Transport::Tcp);
+                        continue;
+                    }
+
                     info!("Accepted new TCP TLS connection: {}", address);
+                    tcp_socket::configure(&stream, &socket);
                     let acceptor = acceptor.clone();
                     let stream = acceptor.accept(stream).await.unwrap();
                     let system = system.clone();
                     let mut sender = TcpTlsSender { stream };
                     tokio::spawn(async move {
-                        if let Err(error) =
-                            handle_connection(address, &mut sender, system.clone()).await
+                        if let Err(error) = handle_connection(
+                            address,
+                            &mut sender,
+                            heartbeat,
+                            backpressure,
+                            system.clone(),
+                        )
+                        .await
                         {
                             handle_error(error);
                             system.read().delete_client(&address).await;
@@ -0,0 +1,79 @@
+use iggy::client::{ConsumerOffsetClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::identifier::Identifier;
+use iggy::topics::get_topic::GetTopic;
+use std::time::Duration;
+use tokio::time::Instant;
+
+const CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Waits until the topic contains the expected number of messages (e.g. sent in the background by the tested application),
+/// panics if it contains more messages or the count isn't reached within the timeout.
+pub async fn await_message_count(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    expected_count: u64,
+    timeout: Duration,
+) {
+    let started_at = Instant::now();
+    loop {
+        let topic = client
+            .get_topic(&GetTopic {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            })
+            .await
+            .unwrap_or_else(|error| {
+                panic!("Cannot get topic: {topic_id} in stream: {stream_id}, {error}")
+            });
+        if topic.messages_count == expected_count {
+            return;
+        }
+
+        assert!(
+            topic.messages_count < expected_count,
+            "Topic: {topic_id} in stream: {stream_id} contains {} messages, expected {expected_count}.",
+            topic.messages_count
+        );
+        assert!(
+            started_at.elapsed() < timeout,
+            "Topic: {topic_id} in stream: {stream_id} contains {} messages, expected {expected_count} within {} ms.",
+            topic.messages_count,
+            timeout.as_millis()
+        );
+        tokio::time::sleep(CHECK_INTERVAL).await;
+    }
+}
+
+/// Asserts the offsets stored by the consumer in the partitions of the topic, given as `(partition_id, offset)` pairs.
+pub async fn assert_offsets(
+    client: &IggyClient,
+    consumer: &Consumer,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    expected_offsets: &[(u32, u64)],
+) {
+    for (partition_id, expected_offset) in expected_offsets {
+        let offset = client
+            .get_consumer_offset(&GetConsumerOffset {
+                consumer: Consumer {
+                    kind: consumer.kind,
+                    id: consumer.id.clone(),
+                },
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id: Some(*partition_id),
+            })
+            .await
+            .unwrap_or_else(|error| {
+                panic!("Cannot get offset of consumer: {consumer} for partition: {partition_id} of topic: {topic_id} in stream: {stream_id}, {error}")
+            });
+        assert_eq!(
+            offset.stored_offset, *expected_offset,
+            "Unexpected offset of consumer: {consumer} for partition: {partition_id} of topic: {topic_id} in stream: {stream_id}."
+        );
+    }
+}
@@ -0,0 +1,37 @@
+//! The in-process Iggy server for the integration tests of the client applications,
+//! booted in memory or in a temporary directory, without the docker or the server binary.
+//!
+//! ```no_run
+//! use iggy::bootstrap::definition::BootstrapDefinition;
+//! use iggy::identifier::Identifier;
+//! use iggy_test::assertions::await_message_count;
+//! use iggy_test::test_server::TestServer;
+//! use std::time::Duration;
+//!
+//! # async fn test() {
+//! let fixture = BootstrapDefinition::from_toml(
+//!     r#"
+//!     [[streams]]
+//!     name = "orders"
+//!     topics = [{ name = "created", partitions_count = 3 }]
+//!     "#,
+//! )
+//! .unwrap();
+//! let server = TestServer::builder().fixture(fixture).start().await.unwrap();
+//! let client = server.client().await.unwrap();
+//! // ... run the application sending the messages with the client
+//! await_message_count(
+//!     &client,
+//!     &Identifier::named("orders").unwrap(),
+//!     &Identifier::named("created").unwrap(),
+//!     10,
+//!     Duration::from_secs(5),
+//! )
+//! .await;
+//! server.shutdown().await.unwrap();
+//! # }
+//! ```
+
+pub mod assertions;
+pub mod test_cluster;
+pub mod test_server;
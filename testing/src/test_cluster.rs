@@ -0,0 +1,58 @@
+use crate::test_server::{TestServer, TestServerBuilder};
+use iggy::clients::client::IggyClient;
+use iggy::error::IggyError;
+use server::server_error::ServerError;
+
+/// The independent test servers (there's no replication between them yet) with the same configuration and fixture,
+/// e.g. to test the clients switching between the servers or the applications using more than one server.
+pub struct TestCluster {
+    nodes: Vec<TestServer>,
+}
+
+impl TestCluster {
+    /// Starts the nodes keeping the data in memory, without any streams.
+    pub async fn start(nodes_count: usize) -> Result<Self, ServerError> {
+        Self::start_with(TestServer::builder(), nodes_count).await
+    }
+
+    /// Starts the nodes configured by the builder, the already started nodes are shut down if any of them fails.
+    pub async fn start_with(
+        builder: TestServerBuilder,
+        nodes_count: usize,
+    ) -> Result<Self, ServerError> {
+        let mut nodes = Vec::with_capacity(nodes_count);
+        for _ in 0..nodes_count {
+            nodes.push(builder.clone().start().await?);
+        }
+        Ok(Self { nodes })
+    }
+
+    pub fn nodes(&self) -> &[TestServer] {
+        &self.nodes
+    }
+
+    pub fn node(&self, index: usize) -> &TestServer {
+        &self.nodes[index]
+    }
+
+    pub fn node_mut(&mut self, index: usize) -> &mut TestServer {
+        &mut self.nodes[index]
+    }
+
+    /// Returns the clients connected to each of the nodes (in the same order) and logged in as the root user.
+    pub async fn clients(&self) -> Result<Vec<IggyClient>, IggyError> {
+        let mut clients = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            clients.push(node.client().await?);
+        }
+        Ok(clients)
+    }
+
+    /// Shuts down all the nodes and removes their temporary directories.
+    pub async fn shutdown(self) -> Result<(), ServerError> {
+        for node in self.nodes {
+            node.shutdown().await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,245 @@
+use iggy::bootstrap::definition::BootstrapDefinition;
+use iggy::client::{Client, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::error::IggyError;
+use iggy::tcp::client::TcpClient;
+use iggy::tcp::config::TcpClientConfig;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::users::login_user::LoginUser;
+use server::configs::server::ServerConfig;
+use server::configs::system::{BootstrapConfig, StorageBackend, StorageConfig, SystemConfig};
+use server::iggy_server::IggyServer;
+use server::server_error::ServerError;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::warn;
+use uuid::Uuid;
+
+const LOCAL_ADDRESS: &str = "127.0.0.1:0";
+const DIRECTORY_PREFIX: &str = "iggy_test_";
+const FIXTURE_FILE: &str = "fixture.json";
+
+type ConfigureServer = Arc<dyn Fn(&mut ServerConfig) + Send + Sync>;
+type ConfigureSystem = Arc<dyn Fn(&mut SystemConfig) + Send + Sync>;
+
+/// The storage of the test server, in both cases the temporary directory of the server is removed once it's shut down.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TestStorage {
+    /// The streams, topics and messages are kept in memory, so they are lost on restart.
+    #[default]
+    Memory,
+    /// The streams, topics and messages are persisted in the temporary directory, so they are available after restart.
+    TempDir,
+}
+
+/// Configures the test server, which listens only on the TCP port assigned by the system on the loopback interface.
+#[derive(Clone, Default)]
+pub struct TestServerBuilder {
+    storage: TestStorage,
+    fixture: Option<Arc<BootstrapDefinition>>,
+    configure_server: Option<ConfigureServer>,
+    configure_system: Option<ConfigureSystem>,
+}
+
+impl TestServerBuilder {
+    pub fn storage(mut self, storage: TestStorage) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// The streams, topics and users created on startup (and again after restart, if missing).
+    pub fn fixture(mut self, fixture: BootstrapDefinition) -> Self {
+        self.fixture = Some(Arc::new(fixture));
+        self
+    }
+
+    /// Overrides the server configuration, e.g. to enable the HTTP listener or the message saver.
+    /// The system configuration is set up separately by `configure_system`.
+    pub fn configure(
+        mut self,
+        configure: impl Fn(&mut ServerConfig) + Send + Sync + 'static,
+    ) -> Self {
+        self.configure_server = Some(Arc::new(configure));
+        self
+    }
+
+    /// Overrides the system configuration, except for its path, storage and bootstrap, which are managed by the test server.
+    pub fn configure_system(
+        mut self,
+        configure: impl Fn(&mut SystemConfig) + Send + Sync + 'static,
+    ) -> Self {
+        self.configure_system = Some(Arc::new(configure));
+        self
+    }
+
+    pub async fn start(self) -> Result<TestServer, ServerError> {
+        let path =
+            std::env::temp_dir().join(format!("{DIRECTORY_PREFIX}{}", Uuid::new_v4().to_u128_le()));
+        tokio::fs::create_dir_all(&path).await?;
+        match self.start_server(&path).await {
+            Ok(server) => Ok(TestServer {
+                builder: self,
+                server: Some(server),
+                path,
+            }),
+            Err(error) => {
+                remove_directory(&path);
+                Err(error)
+            }
+        }
+    }
+
+    async fn start_server(&self, path: &Path) -> Result<IggyServer, ServerError> {
+        IggyServer::start(self.create_config(path).await?).await
+    }
+
+    async fn create_config(&self, path: &Path) -> Result<ServerConfig, ServerError> {
+        let mut system = SystemConfig::default();
+        if let Some(configure_system) = &self.configure_system {
+            configure_system(&mut system);
+        }
+        system.path = path.to_string_lossy().to_string();
+        system.storage = StorageConfig {
+            backend: match self.storage {
+                TestStorage::Memory => StorageBackend::Memory,
+                TestStorage::TempDir => StorageBackend::File,
+            },
+            ..StorageConfig::default()
+        };
+        if let Some(fixture) = &self.fixture {
+            let fixture_path = path.join(FIXTURE_FILE);
+            let fixture = serde_json::to_string(fixture.as_ref())
+                .expect("Cannot serialize the fixture definition");
+            tokio::fs::write(&fixture_path, fixture).await?;
+            system.bootstrap = BootstrapConfig {
+                enabled: true,
+                path: fixture_path.to_string_lossy().to_string(),
+                update_existing: false,
+            };
+        }
+
+        let mut config = ServerConfig::default();
+        config.tcp.address = LOCAL_ADDRESS.to_string();
+        config.http.enabled = false;
+        config.http.address = LOCAL_ADDRESS.to_string();
+        config.quic.enabled = false;
+        config.quic.address = LOCAL_ADDRESS.to_string();
+        if let Some(configure_server) = &self.configure_server {
+            configure_server(&mut config);
+        }
+        config.tcp.enabled = true;
+        config.system = Arc::new(system);
+        Ok(config)
+    }
+}
+
+/// The server running in the test process, stopped and removed along with its data once shut down or dropped.
+pub struct TestServer {
+    builder: TestServerBuilder,
+    server: Option<IggyServer>,
+    path: PathBuf,
+}
+
+impl TestServer {
+    pub fn builder() -> TestServerBuilder {
+        TestServerBuilder::default()
+    }
+
+    /// Starts the server keeping the data in memory, without any streams.
+    pub async fn start() -> Result<Self, ServerError> {
+        Self::builder().start().await
+    }
+
+    pub fn config(&self) -> &ServerConfig {
+        self.server().config()
+    }
+
+    /// Returns the temporary directory of the server.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn tcp_address(&self) -> SocketAddr {
+        self.server()
+            .tcp_address()
+            .expect("TCP listener of the test server is not running")
+    }
+
+    pub fn http_address(&self) -> Option<SocketAddr> {
+        self.server().http_address()
+    }
+
+    pub fn quic_address(&self) -> Option<SocketAddr> {
+        self.server().quic_address()
+    }
+
+    /// Returns the TCP client connected to the server and logged in as the root user.
+    pub async fn client(&self) -> Result<IggyClient, IggyError> {
+        let client = TcpClient::create(Arc::new(TcpClientConfig {
+            server_address: self.tcp_address().to_string(),
+            ..TcpClientConfig::default()
+        }))?;
+        client.connect().await?;
+        let client = IggyClient::new(Box::new(client));
+        client
+            .login_user(&LoginUser {
+                username: DEFAULT_ROOT_USERNAME.to_string(),
+                password: DEFAULT_ROOT_PASSWORD.to_string(),
+            })
+            .await?;
+        Ok(client)
+    }
+
+    /// Shuts down the server and starts it again on the same directory, the listeners are bound to the new ports.
+    /// The data kept in memory is lost, while the one persisted in the temporary directory is loaded again.
+    pub async fn restart(&mut self) -> Result<(), ServerError> {
+        if let Some(server) = self.server.take() {
+            server.shutdown().await?;
+        }
+        self.server = Some(self.builder.start_server(&self.path).await?);
+        Ok(())
+    }
+
+    /// Shuts down the server and removes its temporary directory.
+    pub async fn shutdown(mut self) -> Result<(), ServerError> {
+        if let Some(server) = self.server.take() {
+            server.shutdown().await?;
+        }
+        Ok(())
+    }
+
+    fn server(&self) -> &IggyServer {
+        self.server
+            .as_ref()
+            .expect("Test server has failed to restart")
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        // The server must be stopped before its directory is removed, which can't be awaited in the drop,
+        // so it's done on a separate thread, not to block the runtime of the test.
+        if let Some(server) = self.server.take() {
+            let shutdown = std::thread::spawn(move || {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map(|runtime| runtime.block_on(server.shutdown()))
+            });
+            if !matches!(shutdown.join(), Ok(Ok(Ok(())))) {
+                warn!("Test server has failed to shut down gracefully.");
+            }
+        }
+        remove_directory(&self.path);
+    }
+}
+
+fn remove_directory(path: &Path) {
+    if let Err(error) = std::fs::remove_dir_all(path) {
+        warn!(
+            "Cannot remove the test server directory: {}, {error}",
+            path.display()
+        );
+    }
+}
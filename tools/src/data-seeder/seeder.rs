@@ -3,7 +3,9 @@ use iggy::clients::client::IggyClient;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::consumer_limits::ConsumerLimits;
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::timestamp_type::TimestampType;
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::get_topics::GetTopics;
@@ -27,18 +29,21 @@ async fn create_streams(client: &IggyClient) -> Result<(), IggyError> {
         .create_stream(&CreateStream {
             stream_id: Some(PROD_STREAM_ID),
             name: "prod".to_string(),
+            ..CreateStream::default()
         })
         .await?;
     client
         .create_stream(&CreateStream {
             stream_id: Some(TEST_STREAM_ID),
             name: "test".to_string(),
+            ..CreateStream::default()
         })
         .await?;
     client
         .create_stream(&CreateStream {
             stream_id: Some(DEV_STREAM_ID),
             name: "dev".to_string(),
+            ..CreateStream::default()
         })
         .await?;
     Ok(())
@@ -56,6 +61,11 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await?;
 
@@ -68,6 +78,11 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await?;
 
@@ -80,6 +95,11 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await?;
 
@@ -92,6 +112,11 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await?;
 
@@ -104,6 +129,11 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 message_expiry: None,
                 max_topic_size: None,
                 replication_factor: 1,
+                message_schema: None,
+                ephemeral: false,
+                timestamp_type: TimestampType::default(),
+                consumer_limits: ConsumerLimits::default(),
+                max_message_size: None,
             })
             .await?;
     }
@@ -153,6 +183,7 @@ async fn send_messages(client: &IggyClient) -> Result<(), IggyError> {
                         topic_id: Identifier::numeric(topic.id)?,
                         partitioning: Partitioning::balanced(),
                         messages,
+                        acks: None,
                     })
                     .await?;
                 messages = Vec::new();